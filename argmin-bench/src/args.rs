@@ -0,0 +1,285 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Command line arguments
+
+use crate::functions::TestFunction;
+use crate::profile::Measure;
+use crate::report::Format;
+use crate::solvers::BenchSolver;
+use argmin::argmin_error;
+use argmin::core::Error;
+use std::path::PathBuf;
+
+/// Usage information printed for `--help`
+pub const USAGE: &str = "\
+Runs argmin's solvers on test functions and compares them.
+
+Usage: argmin-bench [OPTIONS]
+
+Options:
+  -s, --solvers <LIST>    Comma-separated solvers [default: all]
+                          (lbfgs, bfgs, cg, newton-cg, steepest-descent, nelder-mead)
+  -f, --functions <LIST>  Comma-separated test functions [default: all, none with --cutest]
+                          (sphere, rosenbrock, rastrigin, ackley, griewank, schwefel,
+                           styblinski-tang)
+  -c, --cutest <LIST>     Comma-separated directories of compiled CUTEst problems
+                          (requires the `cutest` feature)
+  -d, --dims <LIST>       Comma-separated numbers of dimensions [default: 2,10]
+  -n, --max-iters <N>     Maximum number of iterations per run [default: 1000]
+  -t, --tol <TOL>         Runs stop and count as solved once the best cost is within TOL of
+                          the global minimum [default: 1e-6]
+      --csv               Write CSV instead of a table
+      --profiles <DIR>    Write performance and data profiles of the solvers as CSV to DIR
+      --measure <MEASURE> Cost compared by the profiles [default: evaluations]
+                          (evaluations, iterations, time)
+      --plot              Additionally plot the profiles as SVG
+      --require-solved    Exit with status 1 if any run was not solved
+  -h, --help              Print this help
+";
+
+/// Benchmark configuration
+#[derive(Clone, Debug, PartialEq)]
+pub struct Args {
+    /// Solvers to run
+    pub solvers: Vec<BenchSolver>,
+    /// Test functions to minimize
+    pub functions: Vec<TestFunction>,
+    /// Directories of CUTEst problems to minimize
+    pub cutest: Vec<PathBuf>,
+    /// Numbers of dimensions
+    pub dims: Vec<usize>,
+    /// Maximum number of iterations per run
+    pub max_iters: u64,
+    /// Distance of the best cost to the global minimum at which a run stops and counts as solved
+    pub tol: f64,
+    /// Output format
+    pub format: Format,
+    /// Directory to which the profiles are written
+    pub profiles: Option<PathBuf>,
+    /// Cost compared by the profiles
+    pub measure: Measure,
+    /// Whether the profiles are plotted
+    pub plot: bool,
+    /// Whether unsolved runs result in a nonzero exit status
+    pub require_solved: bool,
+    /// Whether only the usage information was requested
+    pub help: bool,
+}
+
+impl Default for Args {
+    fn default() -> Self {
+        Args {
+            solvers: BenchSolver::ALL.to_vec(),
+            functions: TestFunction::ALL.to_vec(),
+            cutest: vec![],
+            dims: vec![2, 10],
+            max_iters: 1000,
+            tol: 1e-6,
+            format: Format::Table,
+            profiles: None,
+            measure: Measure::Evaluations,
+            plot: false,
+            require_solved: false,
+            help: false,
+        }
+    }
+}
+
+impl Args {
+    /// Parses the arguments (without the name of the binary)
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, Error> {
+        let mut parsed = Args::default();
+        let mut functions = None;
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            // Support both `--option value` and `--option=value`
+            let (name, inline) = match arg.split_once('=') {
+                Some((name, value)) if name.starts_with("--") => {
+                    (name.to_string(), Some(value.to_string()))
+                }
+                _ => (arg, None),
+            };
+            let mut value = || -> Result<String, Error> {
+                inline.clone().or_else(|| args.next()).ok_or_else(|| {
+                    argmin_error!(InvalidParameter, format!("`{name}` requires a value."))
+                })
+            };
+            match name.as_str() {
+                "-s" | "--solvers" => {
+                    parsed.solvers = list(&value()?, BenchSolver::from_name)?;
+                }
+                "-f" | "--functions" => {
+                    functions = Some(list(&value()?, TestFunction::from_name)?);
+                }
+                "-c" | "--cutest" => {
+                    parsed.cutest = list(&value()?, |d| Ok(PathBuf::from(d)))?;
+                }
+                "-d" | "--dims" => {
+                    parsed.dims = list(&value()?, |d| {
+                        d.parse::<usize>()
+                            .ok()
+                            .filter(|&d| d > 0)
+                            .ok_or_else(|| invalid("dimension", d))
+                    })?;
+                }
+                "-n" | "--max-iters" => {
+                    let v = value()?;
+                    parsed.max_iters =
+                        v.parse().map_err(|_| invalid("number of iterations", &v))?;
+                }
+                "-t" | "--tol" => {
+                    let v = value()?;
+                    parsed.tol = v
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|t| *t >= 0.0)
+                        .ok_or_else(|| invalid("tolerance", &v))?;
+                }
+                "--csv" => parsed.format = Format::Csv,
+                "--profiles" => parsed.profiles = Some(PathBuf::from(value()?)),
+                "--measure" => parsed.measure = Measure::from_name(&value()?)?,
+                "--plot" => parsed.plot = true,
+                "--require-solved" => parsed.require_solved = true,
+                "-h" | "--help" => parsed.help = true,
+                _ => {
+                    return Err(argmin_error!(
+                        InvalidParameter,
+                        format!("Unknown argument `{name}`.")
+                    ))
+                }
+            }
+        }
+        if parsed.plot && parsed.profiles.is_none() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`--plot` requires `--profiles`."
+            ));
+        }
+        // Only the CUTEst problems are run unless test functions are selected explicitly
+        parsed.functions = functions.unwrap_or_else(|| {
+            if parsed.cutest.is_empty() {
+                TestFunction::ALL.to_vec()
+            } else {
+                vec![]
+            }
+        });
+        Ok(parsed)
+    }
+
+    /// Combinations of solver, test function and dimension to run
+    ///
+    /// Dimensions which are too small for a test function are skipped.
+    pub fn runs(&self) -> Vec<(BenchSolver, TestFunction, usize)> {
+        let mut runs = vec![];
+        for &function in &self.functions {
+            for &dim in self.dims.iter().filter(|&&d| d >= function.min_dim()) {
+                for &solver in &self.solvers {
+                    runs.push((solver, function, dim));
+                }
+            }
+        }
+        runs
+    }
+}
+
+/// Parses a comma-separated list
+fn list<T>(value: &str, parse: impl Fn(&str) -> Result<T, Error>) -> Result<Vec<T>, Error> {
+    value.split(',').map(|v| parse(v.trim())).collect()
+}
+
+/// Error for an invalid value
+fn invalid(what: &str, value: &str) -> Error {
+    argmin_error!(InvalidParameter, format!("Invalid {what} `{value}`."))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, Error> {
+        Args::parse(args.iter().map(|a| a.to_string()))
+    }
+
+    #[test]
+    fn test_default() {
+        let args = parse(&[]).unwrap();
+        assert_eq!(args, Args::default());
+        assert_eq!(args.runs().len(), 6 * 7 * 2);
+    }
+
+    #[test]
+    fn test_parse() {
+        let args = parse(&[
+            "--solvers",
+            "lbfgs,nelder-mead",
+            "-f",
+            "sphere, rosenbrock",
+            "--dims=1,3",
+            "-n",
+            "50",
+            "--tol",
+            "1e-3",
+            "--csv",
+            "--require-solved",
+            "--profiles",
+            "out",
+            "--measure=time",
+            "--plot",
+        ])
+        .unwrap();
+        assert_eq!(
+            args.solvers,
+            vec![BenchSolver::Lbfgs, BenchSolver::NelderMead]
+        );
+        assert_eq!(
+            args.functions,
+            vec![TestFunction::Sphere, TestFunction::Rosenbrock]
+        );
+        assert_eq!(args.dims, vec![1, 3]);
+        assert_eq!(args.max_iters, 50);
+        assert_eq!(args.tol.to_ne_bytes(), 1e-3f64.to_ne_bytes());
+        assert_eq!(args.format, Format::Csv);
+        assert!(args.require_solved);
+        assert_eq!(args.profiles, Some(PathBuf::from("out")));
+        assert_eq!(args.measure, Measure::Time);
+        assert!(args.plot);
+        // Rosenbrock is not defined in one dimension
+        assert_eq!(args.runs().len(), 2 * 2 + 2);
+    }
+
+    #[test]
+    fn test_parse_cutest() {
+        let args = parse(&["--cutest", "ROSENBR,problems/WOODS"]).unwrap();
+        assert_eq!(
+            args.cutest,
+            vec![PathBuf::from("ROSENBR"), PathBuf::from("problems/WOODS")]
+        );
+        assert!(args.functions.is_empty());
+
+        let args = parse(&["-c", "ROSENBR", "-f", "sphere"]).unwrap();
+        assert_eq!(args.functions, vec![TestFunction::Sphere]);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for args in [
+            &["--solvers", "newton"][..],
+            &["--functions", "sphere,"],
+            &["--dims", "0"],
+            &["--dims", "two"],
+            &["--max-iters", "-1"],
+            &["--tol", "-1"],
+            &["--tol"],
+            &["--verbose"],
+            &["--measure", "memory"],
+            &["--plot"],
+        ] {
+            assert!(parse(args).is_err(), "{args:?}");
+        }
+    }
+}
@@ -0,0 +1,354 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Problems of the CUTEst test set
+//!
+//! [`CutestProblem`] loads a problem of [CUTEst](https://github.com/ralna/CUTEst) which was
+//! decoded from its SIF file with `sifdecoder` and compiled into a shared library linked against
+//! the CUTEst library. The directory of a problem has to contain the `OUTSDIF.d` written by
+//! `sifdecoder` and exactly one shared library (`.so`, `.dylib` or `.dll`):
+//!
+//! ```text
+//! cd ROSENBR && sifdecoder ROSENBR.SIF
+//! gfortran -shared -fPIC -o libROSENBR.so ELFUN.f GROUP.f RANGE.f EXTER.f \
+//!     -L$CUTEST/objects/$MYARCH/double -lcutest
+//! ```
+//!
+//! Only unconstrained problems are supported; bounds on the variables are available via
+//! [`bounds`](`CutestProblem::bounds`), but are not enforced by the solvers of the benchmark.
+//! Only available with the `cutest` feature.
+
+use argmin::argmin_error;
+use argmin::core::{CostFunction, Error, Gradient, Hessian};
+use libloading::Library;
+use std::ffi::{c_char, c_int};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Fortran `INTEGER`
+type Integer = c_int;
+
+type Dimen = unsafe extern "C" fn(*mut Integer, *const Integer, *mut Integer, *mut Integer);
+type Setup = unsafe extern "C" fn(
+    *mut Integer,
+    *const Integer,
+    *const Integer,
+    *const Integer,
+    *const Integer,
+    *mut f64,
+    *mut f64,
+    *mut f64,
+);
+type Probname = unsafe extern "C" fn(*mut Integer, *mut c_char);
+type Eval = unsafe extern "C" fn(*mut Integer, *const Integer, *const f64, *mut f64);
+type DenseHessian =
+    unsafe extern "C" fn(*mut Integer, *const Integer, *const f64, *const Integer, *mut f64);
+type Terminate = unsafe extern "C" fn(*mut Integer);
+type FortranOpen = unsafe extern "C" fn(*const Integer, *const c_char, *mut Integer);
+type FortranClose = unsafe extern "C" fn(*const Integer, *mut Integer);
+
+/// Fortran unit from which `OUTSDIF.d` is read
+const FUNIT: Integer = 42;
+/// Fortran unit for messages of CUTEst (standard output)
+const IOUT: Integer = 6;
+/// Fortran unit used internally by CUTEst
+const IO_BUFFER: Integer = 11;
+/// Bounds with an absolute value of at least this are infinite in CUTEst
+const INFINITY: f64 = 1e20;
+
+/// Loaded library with the evaluation routines of a problem
+struct Routines {
+    ufn: Eval,
+    ugr: Eval,
+    udh: DenseHessian,
+    uterminate: Terminate,
+    // Must be dropped after the function pointers are no longer used
+    _library: Library,
+}
+
+impl Drop for Routines {
+    fn drop(&mut self) {
+        let mut status = 0;
+        // SAFETY: `uterminate` belongs to the still loaded library, which was set up in `load`.
+        unsafe { (self.uterminate)(&mut status) };
+    }
+}
+
+/// # CUTEst problem
+///
+/// Unconstrained problem of the CUTEst test set, loaded from the directory of a decoded and
+/// compiled problem (see the [module documentation](`crate::cutest`)). Implements
+/// [`CostFunction`], [`Gradient`] and [`Hessian`] (dense) on `Vec<f64>`.
+///
+/// CUTEst keeps the problem in global state of the library, therefore all evaluations are
+/// serialized and the library should not be loaded by several `CutestProblem`s at once. Clones
+/// share the loaded library.
+#[derive(Clone)]
+pub struct CutestProblem {
+    /// Name of the problem
+    name: String,
+    /// Starting point
+    x0: Vec<f64>,
+    /// Lower bounds
+    lower: Vec<f64>,
+    /// Upper bounds
+    upper: Vec<f64>,
+    /// Evaluation routines
+    routines: Arc<Mutex<Routines>>,
+}
+
+impl CutestProblem {
+    /// Loads the problem from `dir`, which contains `OUTSDIF.d` and the compiled shared library
+    pub fn load(dir: impl AsRef<Path>) -> Result<Self, Error> {
+        let dir = dir.as_ref();
+        let outsdif = dir.join("OUTSDIF.d");
+        if !outsdif.is_file() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                format!("`CutestProblem`: `{}` does not exist.", outsdif.display())
+            ));
+        }
+        let library = shared_library(dir)?;
+        // SAFETY: loading a library runs its initialization routines. The library is expected to
+        // be a problem compiled against CUTEst, whose routines have the signatures above.
+        unsafe {
+            let library = Library::new(&library)?;
+            let cdimen = *library.get::<Dimen>(b"cutest_cdimen_\0")?;
+            let usetup = *library.get::<Setup>(b"cutest_usetup_\0")?;
+            let probname = *library.get::<Probname>(b"cutest_probname_\0")?;
+            let fortran_open = *library.get::<FortranOpen>(b"fortran_open_\0")?;
+            let fortran_close = *library.get::<FortranClose>(b"fortran_close_\0")?;
+            let ufn = *library.get::<Eval>(b"cutest_ufn_\0")?;
+            let ugr = *library.get::<Eval>(b"cutest_ugr_\0")?;
+            let udh = *library.get::<DenseHessian>(b"cutest_udh_\0")?;
+            let uterminate = *library.get::<Terminate>(b"cutest_uterminate_\0")?;
+
+            let path = outsdif
+                .to_str()
+                .and_then(|p| std::ffi::CString::new(p).ok())
+                .ok_or_else(|| -> Error {
+                    argmin_error!(
+                        InvalidParameter,
+                        format!("`CutestProblem`: invalid path `{}`.", outsdif.display())
+                    )
+                })?;
+            let mut ierr = 0;
+            fortran_open(&FUNIT, path.as_ptr(), &mut ierr);
+            check(ierr, "opening `OUTSDIF.d`")?;
+
+            let (mut status, mut n, mut m) = (0, 0, 0);
+            cdimen(&mut status, &FUNIT, &mut n, &mut m);
+            if status != 0 || m > 0 {
+                fortran_close(&FUNIT, &mut ierr);
+                check(status, "determining the dimensions")?;
+                return Err(argmin_error!(
+                    NotImplemented,
+                    "`CutestProblem`: constrained problems are not supported."
+                ));
+            }
+
+            let len = n as usize;
+            let (mut x0, mut lower, mut upper) = (vec![0.0; len], vec![0.0; len], vec![0.0; len]);
+            usetup(
+                &mut status,
+                &FUNIT,
+                &IOUT,
+                &IO_BUFFER,
+                &n,
+                x0.as_mut_ptr(),
+                lower.as_mut_ptr(),
+                upper.as_mut_ptr(),
+            );
+            fortran_close(&FUNIT, &mut ierr);
+            check(status, "setting up the problem")?;
+
+            // The name is padded with spaces to 10 characters
+            let mut name = [0 as c_char; 10];
+            probname(&mut status, name.as_mut_ptr());
+            check(status, "reading the name")?;
+            let name: Vec<u8> = name.iter().map(|&c| c as u8).collect();
+
+            // Only created once the problem is set up, since it terminates the problem when dropped
+            let routines = Routines {
+                ufn,
+                ugr,
+                udh,
+                uterminate,
+                _library: library,
+            };
+
+            Ok(CutestProblem {
+                name: String::from_utf8_lossy(&name).trim().to_string(),
+                x0,
+                lower: lower.into_iter().map(infinite).collect(),
+                upper: upper.into_iter().map(infinite).collect(),
+                routines: Arc::new(Mutex::new(routines)),
+            })
+        }
+    }
+
+    /// Name of the problem
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Number of variables
+    pub fn dim(&self) -> usize {
+        self.x0.len()
+    }
+
+    /// Starting point of the problem
+    pub fn init_param(&self) -> Vec<f64> {
+        self.x0.clone()
+    }
+
+    /// Lower and upper bounds on the variables (infinite if unbounded)
+    pub fn bounds(&self) -> (&[f64], &[f64]) {
+        (&self.lower, &self.upper)
+    }
+
+    /// Calls `eval` with the routines and the number of variables after checking the length of `x`
+    fn eval<T>(
+        &self,
+        x: &[f64],
+        eval: impl FnOnce(&Routines, Integer, &mut Integer) -> T,
+    ) -> Result<T, Error> {
+        if x.len() != self.dim() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                format!(
+                    "`CutestProblem`: parameter vector has length {}, expected {}.",
+                    x.len(),
+                    self.dim()
+                )
+            ));
+        }
+        let routines = self.routines.lock().map_err(|_| -> Error {
+            argmin_error!(PotentialBug, "`CutestProblem`: poisoned lock.")
+        })?;
+        let mut status = 0;
+        let out = eval(&routines, self.dim() as Integer, &mut status);
+        check(status, "evaluating the problem")?;
+        Ok(out)
+    }
+}
+
+impl CostFunction for CutestProblem {
+    type Param = Vec<f64>;
+    type Output = f64;
+
+    fn cost(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+        self.eval(x, |routines, n, status| {
+            let mut f = 0.0;
+            // SAFETY: `x` has length `n` (checked in `eval`).
+            unsafe { (routines.ufn)(status, &n, x.as_ptr(), &mut f) };
+            f
+        })
+    }
+}
+
+impl Gradient for CutestProblem {
+    type Param = Vec<f64>;
+    type Gradient = Vec<f64>;
+
+    fn gradient(&self, x: &Self::Param) -> Result<Self::Gradient, Error> {
+        self.eval(x, |routines, n, status| {
+            let mut g = vec![0.0; x.len()];
+            // SAFETY: `x` and `g` have length `n`.
+            unsafe { (routines.ugr)(status, &n, x.as_ptr(), g.as_mut_ptr()) };
+            g
+        })
+    }
+}
+
+impl Hessian for CutestProblem {
+    type Param = Vec<f64>;
+    type Hessian = Vec<Vec<f64>>;
+
+    fn hessian(&self, x: &Self::Param) -> Result<Self::Hessian, Error> {
+        self.eval(x, |routines, n, status| {
+            let mut h = vec![0.0; x.len() * x.len()];
+            // SAFETY: `x` has length `n` and `h` holds an `n` x `n` matrix with leading
+            // dimension `n`.
+            unsafe { (routines.udh)(status, &n, x.as_ptr(), &n, h.as_mut_ptr()) };
+            // Column-major, but the Hessian is symmetric
+            h.chunks(x.len()).map(<[f64]>::to_vec).collect()
+        })
+    }
+}
+
+/// Returns the only shared library in `dir`
+fn shared_library(dir: &Path) -> Result<PathBuf, Error> {
+    let mut libraries = std::fs::read_dir(dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    libraries.retain(|p| {
+        p.extension()
+            .is_some_and(|ext| ext == "so" || ext == "dylib" || ext == "dll")
+    });
+    match libraries.len() {
+        1 => Ok(libraries.remove(0)),
+        n => Err(argmin_error!(
+            InvalidParameter,
+            format!(
+                "`CutestProblem`: expected one shared library in `{}`, found {n}.",
+                dir.display()
+            )
+        )),
+    }
+}
+
+/// Converts a nonzero status or error code of CUTEst into an error
+fn check(status: Integer, what: &str) -> Result<(), Error> {
+    if status == 0 {
+        Ok(())
+    } else {
+        Err(argmin_error!(
+            ConditionViolated,
+            format!("`CutestProblem`: {what} failed with status {status}.")
+        ))
+    }
+}
+
+/// Maps the bounds which CUTEst uses for unbounded variables to infinity
+fn infinite(bound: f64) -> f64 {
+    if bound >= INFINITY {
+        f64::INFINITY
+    } else if bound <= -INFINITY {
+        f64::NEG_INFINITY
+    } else {
+        bound
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_errors() {
+        let dir = std::env::temp_dir().join("argmin-bench-cutest-test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let err = CutestProblem::load(&dir).err().unwrap();
+        assert!(err.to_string().contains("OUTSDIF.d"), "{err}");
+
+        std::fs::write(dir.join("OUTSDIF.d"), "").unwrap();
+        let err = CutestProblem::load(&dir).err().unwrap();
+        assert!(err.to_string().contains("found 0"), "{err}");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_infinite() {
+        assert_eq!(infinite(1e20).to_ne_bytes(), f64::INFINITY.to_ne_bytes());
+        assert_eq!(
+            infinite(-1e21).to_ne_bytes(),
+            f64::NEG_INFINITY.to_ne_bytes()
+        );
+        assert_eq!(infinite(3.5).to_ne_bytes(), 3.5f64.to_ne_bytes());
+    }
+}
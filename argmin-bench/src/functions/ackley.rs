@@ -0,0 +1,65 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Ackley function
+//!
+//! `f(x) = -20 exp(-0.2 sqrt(1/n sum_i x_i^2)) - exp(1/n sum_i cos(2 pi x_i)) + 20 + e`
+//!
+//! The first term is not differentiable at the origin, where its contribution to the gradient
+//! and the Hessian is omitted.
+
+use std::f64::consts::{E, PI};
+
+/// Root mean square `r` and `exp(1/n sum_i cos(2 pi x_i))`
+fn terms(x: &[f64]) -> (f64, f64) {
+    let n = x.len() as f64;
+    let r = (x.iter().map(|xi| xi.powi(2)).sum::<f64>() / n).sqrt();
+    let s = (x.iter().map(|xi| (2.0 * PI * xi).cos()).sum::<f64>() / n).exp();
+    (r, s)
+}
+
+pub fn cost(x: &[f64]) -> f64 {
+    let (r, s) = terms(x);
+    -20.0 * (-0.2 * r).exp() - s + 20.0 + E
+}
+
+pub fn gradient(x: &[f64]) -> Vec<f64> {
+    let n = x.len() as f64;
+    let (r, s) = terms(x);
+    x.iter()
+        .map(|xi| {
+            let g = 2.0 * PI * s * (2.0 * PI * xi).sin() / n;
+            if r > 0.0 {
+                g + 4.0 * (-0.2 * r).exp() * xi / (n * r)
+            } else {
+                g
+            }
+        })
+        .collect()
+}
+
+pub fn hessian(x: &[f64]) -> Vec<Vec<f64>> {
+    let n = x.len() as f64;
+    let (r, s) = terms(x);
+    let sin: Vec<f64> = x.iter().map(|xi| (2.0 * PI * xi).sin()).collect();
+    let cos: Vec<f64> = x.iter().map(|xi| (2.0 * PI * xi).cos()).collect();
+    (0..x.len())
+        .map(|i| {
+            (0..x.len())
+                .map(|j| {
+                    let delta = if i == j { 1.0 } else { 0.0 };
+                    let mut h = 4.0 * PI.powi(2) * s / n * (delta * cos[i] - sin[i] * sin[j] / n);
+                    if r > 0.0 {
+                        h += 4.0 * (-0.2 * r).exp() / (n * r)
+                            * (delta - (0.2 * r + 1.0) * x[i] * x[j] / (n * r.powi(2)));
+                    }
+                    h
+                })
+                .collect()
+        })
+        .collect()
+}
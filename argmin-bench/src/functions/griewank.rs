@@ -0,0 +1,58 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Griewank function
+//!
+//! `f(x) = 1 + 1/4000 sum_i x_i^2 - prod_i cos(x_i / sqrt(i))` (with `i = 1, ..., n`)
+
+/// `x_i / sqrt(i)` and `1 / sqrt(i)`
+fn scaled(x: &[f64]) -> impl Iterator<Item = (f64, f64)> + '_ {
+    x.iter().enumerate().map(|(i, xi)| {
+        let w = 1.0 / ((i + 1) as f64).sqrt();
+        (xi * w, w)
+    })
+}
+
+/// Product of `cos(x_k / sqrt(k))` over all `k` except `i` and `j`
+fn product_except(x: &[f64], i: usize, j: usize) -> f64 {
+    scaled(x)
+        .enumerate()
+        .filter(|(k, _)| *k != i && *k != j)
+        .map(|(_, (z, _))| z.cos())
+        .product()
+}
+
+pub fn cost(x: &[f64]) -> f64 {
+    1.0 + x.iter().map(|xi| xi.powi(2)).sum::<f64>() / 4000.0
+        - scaled(x).map(|(z, _)| z.cos()).product::<f64>()
+}
+
+pub fn gradient(x: &[f64]) -> Vec<f64> {
+    scaled(x)
+        .enumerate()
+        .map(|(i, (z, w))| x[i] / 2000.0 + w * z.sin() * product_except(x, i, i))
+        .collect()
+}
+
+pub fn hessian(x: &[f64]) -> Vec<Vec<f64>> {
+    let z: Vec<(f64, f64)> = scaled(x).collect();
+    (0..x.len())
+        .map(|i| {
+            (0..x.len())
+                .map(|j| {
+                    let (zi, wi) = z[i];
+                    let (zj, wj) = z[j];
+                    if i == j {
+                        1.0 / 2000.0 + wi.powi(2) * zi.cos() * product_except(x, i, i)
+                    } else {
+                        -wi * wj * zi.sin() * zj.sin() * product_except(x, i, j)
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
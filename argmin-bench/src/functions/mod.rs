@@ -0,0 +1,278 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Test functions with analytic gradients and Hessians
+//!
+//! All functions are defined for an arbitrary number of dimensions. Besides the cost function,
+//! gradient and Hessian, [`TestFunction`] provides the location and value of the global minimum,
+//! the usual search domain and the starting point used by the benchmark.
+
+mod ackley;
+mod griewank;
+mod rastrigin;
+mod rosenbrock;
+mod schwefel;
+mod sphere;
+mod styblinskitang;
+
+use argmin::argmin_error;
+use argmin::core::{Bounds, CostFunction, Error, Gradient, Hessian};
+
+/// Test functions which are defined for an arbitrary number of dimensions
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TestFunction {
+    /// Sphere function, minimum `0` at `(0, ..., 0)`, started from `(1, ..., 1)`
+    Sphere,
+    /// Rosenbrock function (`a = 1`, `b = 100`), minimum `0` at `(1, ..., 1)`, started from
+    /// `(-1.2, 1, -1.2, 1, ...)`
+    Rosenbrock,
+    /// Rastrigin function, minimum `0` at `(0, ..., 0)`, started from `(0.3, ..., 0.3)`
+    Rastrigin,
+    /// Ackley function, minimum `0` at `(0, ..., 0)`, started from `(0.3, ..., 0.3)`
+    Ackley,
+    /// Griewank function, minimum `0` at `(0, ..., 0)`, started from `(1, ..., 1)`
+    Griewank,
+    /// Schwefel function, minimum (approximately) `0` at `(420.9687, ..., 420.9687)`, started
+    /// from `(400, ..., 400)`
+    Schwefel,
+    /// Styblinski-Tang function, minimum `-39.16617 n` at `(-2.903534, ..., -2.903534)`, started
+    /// from `(0, ..., 0)`
+    StyblinskiTang,
+}
+
+impl TestFunction {
+    /// All test functions
+    pub const ALL: [TestFunction; 7] = [
+        TestFunction::Sphere,
+        TestFunction::Rosenbrock,
+        TestFunction::Rastrigin,
+        TestFunction::Ackley,
+        TestFunction::Griewank,
+        TestFunction::Schwefel,
+        TestFunction::StyblinskiTang,
+    ];
+
+    /// Name used on the command line and in reports
+    pub fn name(&self) -> &'static str {
+        match self {
+            TestFunction::Sphere => "sphere",
+            TestFunction::Rosenbrock => "rosenbrock",
+            TestFunction::Rastrigin => "rastrigin",
+            TestFunction::Ackley => "ackley",
+            TestFunction::Griewank => "griewank",
+            TestFunction::Schwefel => "schwefel",
+            TestFunction::StyblinskiTang => "styblinski-tang",
+        }
+    }
+
+    /// Test function with the given name
+    pub fn from_name(name: &str) -> Result<Self, Error> {
+        TestFunction::ALL
+            .into_iter()
+            .find(|f| f.name() == name)
+            .ok_or_else(|| argmin_error!(InvalidParameter, format!("Unknown function `{name}`.")))
+    }
+
+    /// Smallest number of dimensions the function is defined for
+    pub fn min_dim(&self) -> usize {
+        match self {
+            TestFunction::Rosenbrock => 2,
+            _ => 1,
+        }
+    }
+
+    /// Location of the global minimum in `n` dimensions
+    pub fn argmin(&self, n: usize) -> Vec<f64> {
+        match self {
+            TestFunction::Rosenbrock => vec![1.0; n],
+            TestFunction::Schwefel => vec![420.9687462275036; n],
+            TestFunction::StyblinskiTang => vec![-2.903534027771177; n],
+            _ => vec![0.0; n],
+        }
+    }
+
+    /// Value of the global minimum in `n` dimensions
+    pub fn minimum(&self, n: usize) -> f64 {
+        match self {
+            // The offset of the Schwefel function is rounded, therefore the minimum is not
+            // exactly `0`
+            TestFunction::Schwefel => schwefel::cost(&self.argmin(n)),
+            TestFunction::StyblinskiTang => -39.16616570377142 * n as f64,
+            _ => 0.0,
+        }
+    }
+
+    /// Usual search domain of each coordinate
+    pub fn domain(&self) -> (f64, f64) {
+        match self {
+            TestFunction::Sphere | TestFunction::Rastrigin => (-5.12, 5.12),
+            TestFunction::Rosenbrock => (-5.0, 10.0),
+            TestFunction::Ackley => (-32.768, 32.768),
+            TestFunction::Griewank => (-600.0, 600.0),
+            TestFunction::Schwefel => (-500.0, 500.0),
+            TestFunction::StyblinskiTang => (-5.0, 5.0),
+        }
+    }
+
+    /// Search domain in `n` dimensions, for instance for population-based solvers
+    pub fn bounds(&self, n: usize) -> Result<Bounds<Vec<f64>>, Error> {
+        let (lower, upper) = self.domain();
+        Bounds::new(vec![lower; n], vec![upper; n])
+    }
+
+    /// Initial parameter vector in `n` dimensions
+    pub fn init_param(&self, n: usize) -> Vec<f64> {
+        match self {
+            TestFunction::Sphere | TestFunction::Griewank => vec![1.0; n],
+            TestFunction::Rosenbrock => (0..n)
+                .map(|i| if i % 2 == 0 { -1.2 } else { 1.0 })
+                .collect(),
+            TestFunction::Rastrigin | TestFunction::Ackley => vec![0.3; n],
+            TestFunction::Schwefel => vec![400.0; n],
+            TestFunction::StyblinskiTang => vec![0.0; n],
+        }
+    }
+}
+
+impl CostFunction for TestFunction {
+    type Param = Vec<f64>;
+    type Output = f64;
+
+    fn cost(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+        Ok(match self {
+            TestFunction::Sphere => sphere::cost(x),
+            TestFunction::Rosenbrock => rosenbrock::cost(x),
+            TestFunction::Rastrigin => rastrigin::cost(x),
+            TestFunction::Ackley => ackley::cost(x),
+            TestFunction::Griewank => griewank::cost(x),
+            TestFunction::Schwefel => schwefel::cost(x),
+            TestFunction::StyblinskiTang => styblinskitang::cost(x),
+        })
+    }
+}
+
+impl Gradient for TestFunction {
+    type Param = Vec<f64>;
+    type Gradient = Vec<f64>;
+
+    fn gradient(&self, x: &Self::Param) -> Result<Self::Gradient, Error> {
+        Ok(match self {
+            TestFunction::Sphere => sphere::gradient(x),
+            TestFunction::Rosenbrock => rosenbrock::gradient(x),
+            TestFunction::Rastrigin => rastrigin::gradient(x),
+            TestFunction::Ackley => ackley::gradient(x),
+            TestFunction::Griewank => griewank::gradient(x),
+            TestFunction::Schwefel => schwefel::gradient(x),
+            TestFunction::StyblinskiTang => styblinskitang::gradient(x),
+        })
+    }
+}
+
+impl Hessian for TestFunction {
+    type Param = Vec<f64>;
+    type Hessian = Vec<Vec<f64>>;
+
+    fn hessian(&self, x: &Self::Param) -> Result<Self::Hessian, Error> {
+        Ok(match self {
+            TestFunction::Sphere => sphere::hessian(x),
+            TestFunction::Rosenbrock => rosenbrock::hessian(x),
+            TestFunction::Rastrigin => rastrigin::hessian(x),
+            TestFunction::Ackley => ackley::hessian(x),
+            TestFunction::Griewank => griewank::hessian(x),
+            TestFunction::Schwefel => schwefel::hessian(x),
+            TestFunction::StyblinskiTang => styblinskitang::hessian(x),
+        })
+    }
+}
+
+/// Diagonal matrix with the given diagonal
+fn diagonal(diag: impl Iterator<Item = f64>) -> Vec<Vec<f64>> {
+    let diag: Vec<f64> = diag.collect();
+    (0..diag.len())
+        .map(|i| {
+            let mut row = vec![0.0; diag.len()];
+            row[i] = diag[i];
+            row
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use argmin::derivatives::{check_gradient, check_hessian};
+
+    /// Points at which the derivatives are checked, away from the points where the Ackley and
+    /// Schwefel functions are not differentiable
+    const POINTS: [[f64; 3]; 3] = [[0.7, -0.4, 1.3], [-1.2, 1.0, 0.1], [3.1, -2.2, 4.5]];
+
+    #[test]
+    fn test_names() {
+        for function in TestFunction::ALL {
+            assert_eq!(TestFunction::from_name(function.name()).unwrap(), function);
+        }
+        assert!(TestFunction::from_name("himmelblau").is_err());
+    }
+
+    #[test]
+    fn test_gradients() {
+        for function in TestFunction::ALL {
+            for param in POINTS {
+                let check = check_gradient(&function, &param, 1e-5).unwrap();
+                assert!(check.passed(), "{}: {check:?}", function.name());
+            }
+        }
+    }
+
+    #[test]
+    fn test_hessians() {
+        for function in TestFunction::ALL {
+            for param in POINTS {
+                let check = check_hessian(&function, &param, 1e-5).unwrap();
+                assert!(check.passed(), "{}: {check:?}", function.name());
+            }
+        }
+    }
+
+    #[test]
+    fn test_minimum() {
+        for function in TestFunction::ALL {
+            let argmin = function.argmin(4);
+            let cost = function.cost(&argmin).unwrap();
+            assert!((cost - function.minimum(4)).abs() < 1e-10);
+            let gradient = function.gradient(&argmin).unwrap();
+            assert!(
+                gradient.iter().all(|g| g.abs() < 1e-6),
+                "{}: {gradient:?}",
+                function.name()
+            );
+            let (lower, upper) = function.domain();
+            assert!(argmin.iter().all(|x| (lower..=upper).contains(x)));
+            assert!(function.bounds(4).is_ok());
+        }
+        assert!(TestFunction::Schwefel.minimum(4).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_testfunctions() {
+        use argmin_testfunctions::{ackley, rastrigin, rosenbrock, sphere, styblinski_tang};
+
+        for param in POINTS {
+            let param = param.to_vec();
+            for (function, expected) in [
+                (TestFunction::Sphere, sphere(&param)),
+                (TestFunction::Rosenbrock, rosenbrock(&param, 1.0, 100.0)),
+                (TestFunction::Rastrigin, rastrigin(&param)),
+                (TestFunction::Ackley, ackley(&param)),
+                (TestFunction::StyblinskiTang, styblinski_tang(&param)),
+            ] {
+                let cost = function.cost(&param).unwrap();
+                assert!((cost - expected).abs() < 1e-10, "{}", function.name());
+            }
+        }
+    }
+}
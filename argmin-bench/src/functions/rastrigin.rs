@@ -0,0 +1,32 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Rastrigin function
+//!
+//! `f(x) = 10 n + sum_i (x_i^2 - 10 cos(2 pi x_i))`
+
+use std::f64::consts::PI;
+
+pub fn cost(x: &[f64]) -> f64 {
+    10.0 * x.len() as f64
+        + x.iter()
+            .map(|xi| xi.powi(2) - 10.0 * (2.0 * PI * xi).cos())
+            .sum::<f64>()
+}
+
+pub fn gradient(x: &[f64]) -> Vec<f64> {
+    x.iter()
+        .map(|xi| 2.0 * xi + 20.0 * PI * (2.0 * PI * xi).sin())
+        .collect()
+}
+
+pub fn hessian(x: &[f64]) -> Vec<Vec<f64>> {
+    super::diagonal(
+        x.iter()
+            .map(|xi| 2.0 + 40.0 * PI.powi(2) * (2.0 * PI * xi).cos()),
+    )
+}
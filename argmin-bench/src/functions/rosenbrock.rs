@@ -0,0 +1,48 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Rosenbrock function
+//!
+//! `f(x) = sum_{i=1}^{n-1} (1 - x_i)^2 + 100 (x_{i+1} - x_i^2)^2`
+
+pub fn cost(x: &[f64]) -> f64 {
+    x.windows(2)
+        .map(|w| (1.0 - w[0]).powi(2) + 100.0 * (w[1] - w[0].powi(2)).powi(2))
+        .sum()
+}
+
+pub fn gradient(x: &[f64]) -> Vec<f64> {
+    let n = x.len();
+    (0..n)
+        .map(|i| {
+            let mut g = 0.0;
+            if i + 1 < n {
+                g += -2.0 * (1.0 - x[i]) - 400.0 * x[i] * (x[i + 1] - x[i].powi(2));
+            }
+            if i > 0 {
+                g += 200.0 * (x[i] - x[i - 1].powi(2));
+            }
+            g
+        })
+        .collect()
+}
+
+pub fn hessian(x: &[f64]) -> Vec<Vec<f64>> {
+    let n = x.len();
+    let mut h = vec![vec![0.0; n]; n];
+    for i in 0..n {
+        if i + 1 < n {
+            h[i][i] += 2.0 - 400.0 * (x[i + 1] - x[i].powi(2)) + 800.0 * x[i].powi(2);
+            h[i][i + 1] = -400.0 * x[i];
+            h[i + 1][i] = -400.0 * x[i];
+        }
+        if i > 0 {
+            h[i][i] += 200.0;
+        }
+    }
+    h
+}
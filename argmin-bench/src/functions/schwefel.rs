@@ -0,0 +1,40 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Schwefel function
+//!
+//! `f(x) = 418.9828872724338 n - sum_i x_i sin(sqrt(|x_i|))`
+//!
+//! The function is not twice differentiable at `x_i = 0`, where the second derivative is set to
+//! `0`.
+
+/// Constant which makes the global minimum (approximately) `0`
+const OFFSET: f64 = 418.9828872724338;
+
+pub fn cost(x: &[f64]) -> f64 {
+    OFFSET * x.len() as f64 - x.iter().map(|xi| xi * xi.abs().sqrt().sin()).sum::<f64>()
+}
+
+pub fn gradient(x: &[f64]) -> Vec<f64> {
+    x.iter()
+        .map(|xi| {
+            let t = xi.abs().sqrt();
+            -(t.sin() + 0.5 * t * t.cos())
+        })
+        .collect()
+}
+
+pub fn hessian(x: &[f64]) -> Vec<Vec<f64>> {
+    super::diagonal(x.iter().map(|xi| {
+        let t = xi.abs().sqrt();
+        if t > 0.0 {
+            -xi.signum() * (3.0 * t.cos() - t * t.sin()) / (4.0 * t)
+        } else {
+            0.0
+        }
+    }))
+}
@@ -0,0 +1,22 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Sphere function
+//!
+//! `f(x) = sum_i x_i^2`
+
+pub fn cost(x: &[f64]) -> f64 {
+    x.iter().map(|xi| xi.powi(2)).sum()
+}
+
+pub fn gradient(x: &[f64]) -> Vec<f64> {
+    x.iter().map(|xi| 2.0 * xi).collect()
+}
+
+pub fn hessian(x: &[f64]) -> Vec<Vec<f64>> {
+    super::diagonal(x.iter().map(|_| 2.0))
+}
@@ -0,0 +1,27 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Styblinski-Tang function
+//!
+//! `f(x) = 1/2 sum_i (x_i^4 - 16 x_i^2 + 5 x_i)`
+
+pub fn cost(x: &[f64]) -> f64 {
+    0.5 * x
+        .iter()
+        .map(|xi| xi.powi(4) - 16.0 * xi.powi(2) + 5.0 * xi)
+        .sum::<f64>()
+}
+
+pub fn gradient(x: &[f64]) -> Vec<f64> {
+    x.iter()
+        .map(|xi| 2.0 * xi.powi(3) - 16.0 * xi + 2.5)
+        .collect()
+}
+
+pub fn hessian(x: &[f64]) -> Vec<Vec<f64>> {
+    super::diagonal(x.iter().map(|xi| 6.0 * xi.powi(2) - 16.0))
+}
@@ -0,0 +1,21 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Benchmarking of argmin's solvers
+//!
+//! Library part of the `argmin-bench` command-line benchmark runner. The test functions in
+//! [`functions`] come with analytic gradients and Hessians as well as the location and value of
+//! their global minimum and can also be used on their own, for instance in examples. With the
+//! `cutest` feature, problems of the CUTEst test set can be loaded via [`cutest`].
+
+pub mod args;
+#[cfg(feature = "cutest")]
+pub mod cutest;
+pub mod functions;
+pub mod profile;
+pub mod report;
+pub mod solvers;
@@ -0,0 +1,130 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Command-line benchmark runner for argmin
+//!
+//! Runs a selection of solvers on test functions with analytic gradients and Hessians (see
+//! [`argmin_bench::functions`]) in a configurable number of dimensions and prints a comparison table or CSV with the number of iterations,
+//! function evaluations, the best cost and the time of each run. Runs which return an error are
+//! reported as such instead of aborting the benchmark. With `--require-solved`, the exit status
+//! is `1` if any run did not reach the global minimum within the tolerance, which allows using
+//! the runner for detecting regressions in CI. With `--profiles`, performance and data profiles of
+//! the solvers over all problems are written as CSV (and with `--plot` as SVG), see
+//! [`argmin_bench::profile`].
+//!
+//! ```text
+//! argmin-bench --solvers lbfgs,cg --functions rosenbrock --dims 2,10,100 --csv > bench.csv
+//! ```
+
+use argmin::core::Error;
+use argmin_bench::args::{Args, USAGE};
+use argmin_bench::profile::{Measure, Profile};
+use argmin_bench::solvers::Record;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{e}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    if args.help {
+        print!("{USAGE}");
+        return ExitCode::SUCCESS;
+    }
+    let mut records: Vec<_> = args
+        .runs()
+        .into_iter()
+        .map(|(solver, function, dim)| solver.run(function, dim, args.max_iters, args.tol))
+        .collect();
+    match cutest(&args) {
+        Ok(cutest_records) => records.extend(cutest_records),
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::from(2);
+        }
+    }
+    if let Err(e) = args.format.write(&records, &mut std::io::stdout().lock()) {
+        eprintln!("{e}");
+        return ExitCode::FAILURE;
+    }
+    if let Some(dir) = &args.profiles {
+        if let Err(e) = write_profiles(dir, &records, args.measure, args.plot) {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    }
+    if args.require_solved && records.iter().any(|r| !r.solved) {
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+/// Writes the performance and data profiles of `records` to `dir` as CSV and optionally as SVG
+fn write_profiles(
+    dir: &Path,
+    records: &[Record],
+    measure: Measure,
+    plot: bool,
+) -> std::io::Result<()> {
+    std::fs::create_dir_all(dir)?;
+    for profile in [
+        Profile::performance(records, measure),
+        Profile::data(records, measure),
+    ] {
+        let name = profile.kind.name();
+        let mut csv = BufWriter::new(File::create(dir.join(format!("{name}.csv")))?);
+        profile.write_csv(&mut csv)?;
+        csv.flush()?;
+        if plot {
+            let mut svg = BufWriter::new(File::create(dir.join(format!("{name}.svg")))?);
+            profile.write_svg(&mut svg)?;
+            svg.flush()?;
+        }
+    }
+    Ok(())
+}
+
+/// Runs all solvers on the CUTEst problems
+#[cfg(feature = "cutest")]
+fn cutest(args: &Args) -> Result<Vec<Record>, Error> {
+    use argmin_bench::cutest::CutestProblem;
+
+    let mut records = vec![];
+    for dir in &args.cutest {
+        let problem = CutestProblem::load(dir)?;
+        for solver in &args.solvers {
+            records.push(solver.run_problem(
+                problem.clone(),
+                problem.name(),
+                problem.init_param(),
+                None,
+                args.max_iters,
+                args.tol,
+            ));
+        }
+    }
+    Ok(records)
+}
+
+/// Fails if CUTEst problems were requested, since the `cutest` feature is disabled
+#[cfg(not(feature = "cutest"))]
+fn cutest(args: &Args) -> Result<Vec<Record>, Error> {
+    if args.cutest.is_empty() {
+        Ok(vec![])
+    } else {
+        Err(argmin::argmin_error!(
+            NotImplemented,
+            "`--cutest` requires argmin-bench to be built with the `cutest` feature."
+        ))
+    }
+}
@@ -0,0 +1,475 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Performance and data profiles
+//!
+//! Profiles summarize the comparison of several solvers over a set of problems (all combinations
+//! of test function and dimension of the runs) in one plot per solver:
+//!
+//! * The performance profile (Dolan and Moré) of a solver is the fraction of problems it solved
+//!   with a cost (see [`Measure`]) of at most `tau` times the cost of the best solver on each
+//!   problem.
+//! * The data profile (Moré and Wild) of a solver is the fraction of problems it solved with a
+//!   cost of at most `alpha (n + 1)`, where `n` is the number of dimensions of the problem. For
+//!   evaluations, `n + 1` is the number of evaluations of a simplex gradient.
+//!
+//! Runs which were not solved (see [`Record::solved`]) have infinite cost.
+//!
+//! ## References
+//!
+//! Elizabeth D. Dolan and Jorge J. Moré (2002). Benchmarking optimization software with
+//! performance profiles. Mathematical Programming 91, 201-213.
+//!
+//! Jorge J. Moré and Stefan M. Wild (2009). Benchmarking derivative-free optimization
+//! algorithms. SIAM Journal on Optimization 20(1), 172-191.
+
+use crate::solvers::Record;
+use argmin::argmin_error;
+use argmin::core::Error;
+use std::io::{self, Write};
+
+/// Cost of a run compared by the profiles
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Measure {
+    /// Number of cost function and gradient evaluations
+    Evaluations,
+    /// Number of iterations
+    Iterations,
+    /// Wall-clock time
+    Time,
+}
+
+impl Measure {
+    /// All measures
+    pub const ALL: [Measure; 3] = [Measure::Evaluations, Measure::Iterations, Measure::Time];
+
+    /// Name used on the command line
+    pub fn name(&self) -> &'static str {
+        match self {
+            Measure::Evaluations => "evaluations",
+            Measure::Iterations => "iterations",
+            Measure::Time => "time",
+        }
+    }
+
+    /// Measure with the given name
+    pub fn from_name(name: &str) -> Result<Self, Error> {
+        Measure::ALL
+            .into_iter()
+            .find(|m| m.name() == name)
+            .ok_or_else(|| argmin_error!(InvalidParameter, format!("Unknown measure `{name}`.")))
+    }
+
+    /// Cost of `record`, infinite if the run was not solved
+    fn of(&self, record: &Record) -> f64 {
+        if !record.solved {
+            return f64::INFINITY;
+        }
+        match self {
+            Measure::Evaluations => (record.cost_count + record.gradient_count) as f64,
+            Measure::Iterations => record.iters as f64,
+            Measure::Time => record.time.as_secs_f64() * 1000.0,
+        }
+    }
+}
+
+/// Kind of a profile
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProfileKind {
+    /// Dolan-Moré performance profile over the performance ratio `tau`
+    Performance,
+    /// Moré-Wild data profile over the budget `alpha` in units of `n + 1`
+    Data,
+}
+
+impl ProfileKind {
+    /// Name used for files and the header of the CSV
+    pub fn name(&self) -> &'static str {
+        match self {
+            ProfileKind::Performance => "performance",
+            ProfileKind::Data => "data",
+        }
+    }
+
+    /// Name of the variable on the horizontal axis
+    fn variable(&self) -> &'static str {
+        match self {
+            ProfileKind::Performance => "tau",
+            ProfileKind::Data => "alpha",
+        }
+    }
+}
+
+/// Profile of several solvers
+///
+/// The fraction of solved problems of each solver is a step function which changes only at the
+/// breakpoints [`x`](`Profile::x`).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Profile {
+    /// Kind of the profile
+    pub kind: ProfileKind,
+    /// Compared cost of the runs
+    pub measure: Measure,
+    /// Number of problems
+    pub problems: usize,
+    /// Breakpoints in increasing order
+    pub x: Vec<f64>,
+    /// Names of the solvers and their fraction of solved problems at each breakpoint
+    pub solvers: Vec<(&'static str, Vec<f64>)>,
+}
+
+impl Profile {
+    /// Performance profile of the solvers in `records`
+    pub fn performance(records: &[Record], measure: Measure) -> Self {
+        let (solvers, costs) = costs(records, measure);
+        // Performance ratios with respect to the best solver on each problem
+        let ratios: Vec<Vec<f64>> = costs
+            .iter()
+            .map(|problem| {
+                let best = problem
+                    .iter()
+                    .map(|(_, c)| *c)
+                    .fold(f64::INFINITY, f64::min);
+                problem
+                    .iter()
+                    .map(|(_, c)| if *c <= best { 1.0 } else { c / best })
+                    .collect()
+            })
+            .collect();
+        Profile::new(ProfileKind::Performance, measure, solvers, ratios)
+    }
+
+    /// Data profile of the solvers in `records`
+    pub fn data(records: &[Record], measure: Measure) -> Self {
+        let (solvers, costs) = costs(records, measure);
+        let budgets: Vec<Vec<f64>> = costs
+            .iter()
+            .map(|problem| {
+                problem
+                    .iter()
+                    .map(|(dim, c)| c / (dim + 1) as f64)
+                    .collect()
+            })
+            .collect();
+        Profile::new(ProfileKind::Data, measure, solvers, budgets)
+    }
+
+    /// Profile of the `values` (one row per problem, one column per solver)
+    fn new(
+        kind: ProfileKind,
+        measure: Measure,
+        solvers: Vec<&'static str>,
+        values: Vec<Vec<f64>>,
+    ) -> Self {
+        let mut x: Vec<f64> = values
+            .iter()
+            .flatten()
+            .copied()
+            .filter(|v| v.is_finite())
+            .collect();
+        if kind == ProfileKind::Performance {
+            x.push(1.0);
+        }
+        x.sort_by(f64::total_cmp);
+        x.dedup();
+        let problems = values.len();
+        let solvers = solvers
+            .into_iter()
+            .enumerate()
+            .map(|(s, name)| {
+                let fractions = x
+                    .iter()
+                    .map(|x| {
+                        let count = values.iter().filter(|p| p[s] <= *x).count();
+                        count as f64 / problems.max(1) as f64
+                    })
+                    .collect();
+                (name, fractions)
+            })
+            .collect();
+        Profile {
+            kind,
+            measure,
+            problems,
+            x,
+            solvers,
+        }
+    }
+
+    /// Writes CSV with one line per solver and breakpoint
+    pub fn write_csv(&self, out: &mut impl Write) -> io::Result<()> {
+        writeln!(out, "solver,{},fraction", self.kind.variable())?;
+        for (solver, fractions) in &self.solvers {
+            for (x, fraction) in self.x.iter().zip(fractions) {
+                writeln!(out, "{solver},{x},{fraction}")?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes an SVG plot of the profile
+    ///
+    /// The performance ratio of performance profiles is plotted on a logarithmic (base 2) axis.
+    pub fn write_svg(&self, out: &mut impl Write) -> io::Result<()> {
+        const WIDTH: f64 = 640.0;
+        const HEIGHT: f64 = 400.0;
+        const LEFT: f64 = 60.0;
+        const RIGHT: f64 = 480.0;
+        const TOP: f64 = 40.0;
+        const BOTTOM: f64 = 340.0;
+        const COLORS: [&str; 8] = [
+            "#1f77b4", "#ff7f0e", "#2ca02c", "#d62728", "#9467bd", "#8c564b", "#e377c2", "#7f7f7f",
+        ];
+
+        let log = self.kind == ProfileKind::Performance;
+        let scale = |x: f64| if log { x.log2() } else { x };
+        let x_max = self.x.last().map_or(1.0, |x| scale(*x)).max(1.0) * 1.05;
+        let px = |x: f64| LEFT + (RIGHT - LEFT) * scale(x) / x_max;
+        let py = |y: f64| BOTTOM - (BOTTOM - TOP) * y;
+
+        writeln!(
+            out,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{WIDTH}\" height=\"{HEIGHT}\" \
+             font-family=\"sans-serif\" font-size=\"12\">"
+        )?;
+        writeln!(
+            out,
+            "<text x=\"{}\" y=\"20\" text-anchor=\"middle\" font-size=\"14\">{} profile ({}, {} \
+             problems)</text>",
+            (LEFT + RIGHT) / 2.0,
+            capitalize(self.kind.name()),
+            self.measure.name(),
+            self.problems
+        )?;
+        // Axes, grid and ticks
+        writeln!(
+            out,
+            "<path d=\"M{LEFT},{TOP}V{BOTTOM}H{RIGHT}\" fill=\"none\" stroke=\"black\"/>"
+        )?;
+        for i in 0..=5 {
+            let y = f64::from(i) / 5.0;
+            writeln!(
+                out,
+                "<path d=\"M{LEFT},{0}H{RIGHT}\" stroke=\"#ddd\"/><text x=\"{1}\" y=\"{2}\" \
+                 text-anchor=\"end\">{y}</text>",
+                py(y),
+                LEFT - 6.0,
+                py(y) + 4.0
+            )?;
+        }
+        let ticks: Vec<f64> = if log {
+            (0..=x_max.floor() as i32).map(|k| 2f64.powi(k)).collect()
+        } else {
+            (0..=5).map(|i| f64::from(i) * x_max / 5.0).collect()
+        };
+        for tick in ticks {
+            writeln!(
+                out,
+                "<text x=\"{}\" y=\"{}\" text-anchor=\"middle\">{}</text>",
+                px(tick),
+                BOTTOM + 18.0,
+                format_tick(tick)
+            )?;
+        }
+        writeln!(
+            out,
+            "<text x=\"{}\" y=\"{}\" text-anchor=\"middle\">{}{}</text>",
+            (LEFT + RIGHT) / 2.0,
+            BOTTOM + 40.0,
+            self.kind.variable(),
+            if log { " (log scale)" } else { "" }
+        )?;
+        // Step functions and legend
+        for (i, (solver, fractions)) in self.solvers.iter().enumerate() {
+            let color = COLORS[i % COLORS.len()];
+            let mut path = format!("M{},{}", px(if log { 1.0 } else { 0.0 }), py(0.0));
+            for (x, fraction) in self.x.iter().zip(fractions) {
+                path.push_str(&format!("H{}V{}", px(*x), py(*fraction)));
+            }
+            path.push_str(&format!("H{RIGHT}"));
+            writeln!(
+                out,
+                "<path d=\"{path}\" fill=\"none\" stroke=\"{color}\" stroke-width=\"2\"/>"
+            )?;
+            let y = TOP + 10.0 + 20.0 * i as f64;
+            writeln!(
+                out,
+                "<path d=\"M{},{y}h20\" stroke=\"{color}\" stroke-width=\"2\"/><text x=\"{}\" \
+                 y=\"{}\">{solver}</text>",
+                RIGHT + 15.0,
+                RIGHT + 40.0,
+                y + 4.0
+            )?;
+        }
+        writeln!(out, "</svg>")
+    }
+}
+
+/// Names of the solvers and, for each problem, the dimension and cost of each solver
+///
+/// Problems are identified by function and dimension; missing runs have infinite cost.
+#[allow(clippy::type_complexity)]
+fn costs(records: &[Record], measure: Measure) -> (Vec<&'static str>, Vec<Vec<(usize, f64)>>) {
+    let mut solvers: Vec<&'static str> = vec![];
+    let mut problems: Vec<(&str, usize)> = vec![];
+    for record in records {
+        if !solvers.contains(&record.solver) {
+            solvers.push(record.solver);
+        }
+        if !problems.contains(&(&record.function, record.dim)) {
+            problems.push((&record.function, record.dim));
+        }
+    }
+    let costs = problems
+        .iter()
+        .map(|(function, dim)| {
+            solvers
+                .iter()
+                .map(|solver| {
+                    let cost = records
+                        .iter()
+                        .filter(|r| r.solver == *solver && r.function == *function && r.dim == *dim)
+                        .map(|r| measure.of(r))
+                        .fold(f64::INFINITY, f64::min);
+                    (*dim, cost)
+                })
+                .collect()
+        })
+        .collect();
+    (solvers, costs)
+}
+
+/// Formats a tick label without unnecessary decimals
+fn format_tick(x: f64) -> String {
+    if x.fract() == 0.0 {
+        format!("{x:.0}")
+    } else {
+        format!("{x:.1}")
+    }
+}
+
+/// Upper-cases the first letter of `s`
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    chars
+        .next()
+        .map(|c| c.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn record(
+        solver: &'static str,
+        function: &str,
+        dim: usize,
+        evals: u64,
+        solved: bool,
+    ) -> Record {
+        Record {
+            solver,
+            function: function.to_string(),
+            dim,
+            termination: String::new(),
+            iters: 1,
+            cost_count: evals,
+            gradient_count: 0,
+            best_cost: 0.0,
+            error: 0.0,
+            time: Duration::from_millis(1),
+            solved,
+        }
+    }
+
+    fn records() -> Vec<Record> {
+        vec![
+            record("a", "f", 2, 10, true),
+            record("b", "f", 2, 20, true),
+            record("a", "g", 3, 40, true),
+            record("b", "g", 3, 10, true),
+            record("a", "h", 2, 30, false),
+            record("b", "h", 2, 60, true),
+        ]
+    }
+
+    fn assert_fractions(profile: &Profile, solver: usize, expected: &[f64]) {
+        let fractions = &profile.solvers[solver].1;
+        assert_eq!(fractions.len(), expected.len());
+        for (f, e) in fractions.iter().zip(expected) {
+            assert!((f - e).abs() < 1e-12, "{fractions:?}");
+        }
+    }
+
+    #[test]
+    fn test_performance_profile() {
+        let profile = Profile::performance(&records(), Measure::Evaluations);
+        assert_eq!(profile.problems, 3);
+        assert_eq!(profile.x, vec![1.0, 2.0, 4.0]);
+        assert_eq!(profile.solvers[0].0, "a");
+        // `a`: best on `f`, 4 times the best on `g`, failed on `h`
+        assert_fractions(&profile, 0, &[1.0 / 3.0, 1.0 / 3.0, 2.0 / 3.0]);
+        // `b`: 2 times the best on `f`, best on `g` and `h`
+        assert_fractions(&profile, 1, &[2.0 / 3.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_data_profile() {
+        let profile = Profile::data(&records(), Measure::Evaluations);
+        // Budgets of `a`: 10 / 3, 40 / 4 and failed, of `b`: 20 / 3, 10 / 4 and 60 / 3
+        assert_eq!(profile.x, vec![2.5, 10.0 / 3.0, 20.0 / 3.0, 10.0, 20.0]);
+        assert_fractions(
+            &profile,
+            0,
+            &[0.0, 1.0 / 3.0, 1.0 / 3.0, 2.0 / 3.0, 2.0 / 3.0],
+        );
+        assert_fractions(
+            &profile,
+            1,
+            &[1.0 / 3.0, 1.0 / 3.0, 2.0 / 3.0, 2.0 / 3.0, 1.0],
+        );
+    }
+
+    #[test]
+    fn test_missing_runs() {
+        let records = vec![record("a", "f", 2, 10, true), record("b", "g", 2, 10, true)];
+        let profile = Profile::performance(&records, Measure::Iterations);
+        assert_eq!(profile.x, vec![1.0]);
+        assert_fractions(&profile, 0, &[0.5]);
+        assert_fractions(&profile, 1, &[0.5]);
+    }
+
+    #[test]
+    fn test_measure_names() {
+        for measure in Measure::ALL {
+            assert_eq!(Measure::from_name(measure.name()).unwrap(), measure);
+        }
+        assert!(Measure::from_name("memory").is_err());
+    }
+
+    #[test]
+    fn test_output() {
+        let profile = Profile::performance(&records(), Measure::Evaluations);
+        let mut csv = Vec::new();
+        profile.write_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 1 + 2 * 3);
+        assert_eq!(lines[0], "solver,tau,fraction");
+        assert_eq!(lines[4], "b,1,0.6666666666666666");
+
+        let mut svg = Vec::new();
+        profile.write_svg(&mut svg).unwrap();
+        let svg = String::from_utf8(svg).unwrap();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert!(svg.contains("Performance profile (evaluations, 3 problems)"));
+        assert_eq!(svg.matches("stroke-width=\"2\"").count(), 2 * 2);
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Comparison table and CSV output
+
+use crate::solvers::Record;
+use std::io::{self, Write};
+
+/// Column headers
+const HEADER: [&str; 11] = [
+    "solver",
+    "function",
+    "dim",
+    "solved",
+    "iters",
+    "cost_evals",
+    "grad_evals",
+    "best_cost",
+    "error",
+    "time_ms",
+    "termination",
+];
+
+/// Output format
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Format {
+    /// Aligned table for the terminal
+    Table,
+    /// Comma-separated values, one line per run
+    Csv,
+}
+
+impl Format {
+    /// Writes `records` to `out` in this format
+    pub fn write(&self, records: &[Record], out: &mut impl Write) -> io::Result<()> {
+        match self {
+            Format::Table => write_table(records, out),
+            Format::Csv => write_csv(records, out),
+        }
+    }
+}
+
+/// Cells of a record in the order of [`HEADER`]
+fn cells(record: &Record) -> [String; 11] {
+    [
+        record.solver.to_string(),
+        record.function.to_string(),
+        record.dim.to_string(),
+        if record.solved { "yes" } else { "no" }.to_string(),
+        record.iters.to_string(),
+        record.cost_count.to_string(),
+        record.gradient_count.to_string(),
+        format!("{:.6e}", record.best_cost),
+        format!("{:.3e}", record.error),
+        format!("{:.3}", record.time.as_secs_f64() * 1000.0),
+        record.termination.clone(),
+    ]
+}
+
+/// Writes an aligned table; numeric columns are right-aligned
+fn write_table(records: &[Record], out: &mut impl Write) -> io::Result<()> {
+    let rows: Vec<[String; 11]> = records.iter().map(cells).collect();
+    let mut widths = HEADER.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+    let header = HEADER.map(str::to_string);
+    for row in std::iter::once(&header).chain(&rows) {
+        let mut line = String::new();
+        for (i, (cell, width)) in row.iter().zip(widths).enumerate() {
+            if i > 0 {
+                line.push_str("  ");
+            }
+            if (2..10).contains(&i) {
+                line.push_str(&format!("{cell:>width$}"));
+            } else {
+                line.push_str(&format!("{cell:<width$}"));
+            }
+        }
+        writeln!(out, "{}", line.trim_end())?;
+    }
+    Ok(())
+}
+
+/// Writes CSV with a header line; the termination reason is quoted
+fn write_csv(records: &[Record], out: &mut impl Write) -> io::Result<()> {
+    writeln!(out, "{}", HEADER.join(","))?;
+    for record in records {
+        let mut row = cells(record);
+        row[7] = record.best_cost.to_string();
+        row[8] = record.error.to_string();
+        row[10] = format!("\"{}\"", record.termination.replace('"', "\"\""));
+        writeln!(out, "{}", row.join(","))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn record() -> Record {
+        Record {
+            solver: "lbfgs",
+            function: "sphere".to_string(),
+            dim: 2,
+            termination: "Solver \"converged\"".to_string(),
+            iters: 3,
+            cost_count: 5,
+            gradient_count: 4,
+            best_cost: 0.5,
+            error: 0.5,
+            time: Duration::from_millis(2),
+            solved: false,
+        }
+    }
+
+    #[test]
+    fn test_csv() {
+        let mut out = Vec::new();
+        Format::Csv.write(&[record()], &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "solver,function,dim,solved,iters,cost_evals,grad_evals,best_cost,error,time_ms,\
+             termination\n\
+             lbfgs,sphere,2,no,3,5,4,0.5,0.5,2.000,\"Solver \"\"converged\"\"\"\n"
+        );
+    }
+
+    #[test]
+    fn test_table() {
+        let mut out = Vec::new();
+        Format::Table.write(&[record()], &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("solver  function  dim  solved  iters"));
+        assert!(lines[1].starts_with("lbfgs   sphere      2      no      3"));
+        assert!(lines[1].ends_with("Solver \"converged\""));
+    }
+}
@@ -0,0 +1,313 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Solvers which can be benchmarked
+
+use crate::functions::TestFunction;
+use argmin::argmin_error;
+use argmin::core::{
+    CostFunction, DeserializeOwnedAlias, Error, Executor, Gradient, Hessian, IterState,
+    SerializeAlias, Solver, State, TerminationReason,
+};
+use argmin::solver::conjugategradient::{beta::PolakRibiere, NonlinearConjugateGradient};
+use argmin::solver::gradientdescent::SteepestDescent;
+use argmin::solver::linesearch::MoreThuenteLineSearch;
+use argmin::solver::neldermead::NelderMead;
+use argmin::solver::newton::NewtonCG;
+use argmin::solver::quasinewton::{BFGS, LBFGS};
+use std::time::{Duration, Instant};
+
+/// Solvers with their default configuration
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BenchSolver {
+    /// L-BFGS with a memory of 7 and More-Thuente line search
+    Lbfgs,
+    /// BFGS with More-Thuente line search, starting from the identity
+    Bfgs,
+    /// Nonlinear conjugate gradient (Polak-Ribiere) with More-Thuente line search, restarted every
+    /// 10 iterations
+    ConjugateGradient,
+    /// Newton-CG with More-Thuente line search
+    NewtonCg,
+    /// Steepest descent with More-Thuente line search
+    SteepestDescent,
+    /// Nelder-Mead
+    NelderMead,
+}
+
+impl BenchSolver {
+    /// All solvers
+    pub const ALL: [BenchSolver; 6] = [
+        BenchSolver::Lbfgs,
+        BenchSolver::Bfgs,
+        BenchSolver::ConjugateGradient,
+        BenchSolver::NewtonCg,
+        BenchSolver::SteepestDescent,
+        BenchSolver::NelderMead,
+    ];
+
+    /// Name used on the command line and in reports
+    pub fn name(&self) -> &'static str {
+        match self {
+            BenchSolver::Lbfgs => "lbfgs",
+            BenchSolver::Bfgs => "bfgs",
+            BenchSolver::ConjugateGradient => "cg",
+            BenchSolver::NewtonCg => "newton-cg",
+            BenchSolver::SteepestDescent => "steepest-descent",
+            BenchSolver::NelderMead => "nelder-mead",
+        }
+    }
+
+    /// Solver with the given name
+    pub fn from_name(name: &str) -> Result<Self, Error> {
+        BenchSolver::ALL
+            .into_iter()
+            .find(|s| s.name() == name)
+            .ok_or_else(|| argmin_error!(InvalidParameter, format!("Unknown solver `{name}`.")))
+    }
+
+    /// Minimizes `function` in `dim` dimensions with at most `max_iters` iterations
+    ///
+    /// The run stops once the best cost is within `tol` of the global minimum. Errors of the
+    /// solver do not abort the benchmark, they are reported in the returned [`Record`].
+    pub fn run(&self, function: TestFunction, dim: usize, max_iters: u64, tol: f64) -> Record {
+        self.run_problem(
+            function,
+            function.name(),
+            function.init_param(dim),
+            Some(function.minimum(dim)),
+            max_iters,
+            tol,
+        )
+    }
+
+    /// Minimizes `problem` (named `name`) starting from `x0` with at most `max_iters` iterations
+    ///
+    /// If the global `minimum` is known, the run stops once the best cost is within `tol` of it
+    /// and counts as solved. Otherwise, the run counts as solved if the solver converged or
+    /// reached the target cost. Errors of the solver are reported in the returned [`Record`].
+    pub fn run_problem<O: BenchProblem>(
+        &self,
+        problem: O,
+        name: &str,
+        x0: Vec<f64>,
+        minimum: Option<f64>,
+        max_iters: u64,
+        tol: f64,
+    ) -> Record {
+        let dim = x0.len();
+        let target = minimum.map_or(f64::NEG_INFINITY, |minimum| minimum + tol);
+        let start = Instant::now();
+        let linesearch = MoreThuenteLineSearch::new();
+        let outcome = match self {
+            BenchSolver::Lbfgs => run(
+                problem,
+                LBFGS::new(linesearch, 7),
+                |state: IterState<_, _, (), (), f64>| {
+                    state.param(x0).max_iters(max_iters).target_cost(target)
+                },
+            ),
+            BenchSolver::Bfgs => {
+                let eye = (0..dim)
+                    .map(|i| (0..dim).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+                    .collect::<Vec<Vec<f64>>>();
+                run(
+                    problem,
+                    BFGS::new(linesearch),
+                    |state: IterState<_, _, (), _, f64>| {
+                        state
+                            .param(x0)
+                            .inv_hessian(eye)
+                            .max_iters(max_iters)
+                            .target_cost(target)
+                    },
+                )
+            }
+            BenchSolver::ConjugateGradient => run(
+                problem,
+                NonlinearConjugateGradient::new(linesearch, PolakRibiere::new())
+                    .restart_iters(10)
+                    .restart_orthogonality(0.1),
+                |state: IterState<_, _, (), (), f64>| {
+                    state.param(x0).max_iters(max_iters).target_cost(target)
+                },
+            ),
+            BenchSolver::NewtonCg => run(
+                problem,
+                NewtonCG::new(linesearch),
+                |state: IterState<_, _, (), _, f64>| {
+                    state.param(x0).max_iters(max_iters).target_cost(target)
+                },
+            ),
+            BenchSolver::SteepestDescent => run(
+                problem,
+                SteepestDescent::new(linesearch),
+                |state: IterState<_, _, (), (), f64>| {
+                    state.param(x0).max_iters(max_iters).target_cost(target)
+                },
+            ),
+            BenchSolver::NelderMead => run(
+                problem,
+                NelderMead::new(initial_simplex(&x0)),
+                |state: IterState<_, (), (), (), f64>| {
+                    state.max_iters(max_iters).target_cost(target)
+                },
+            ),
+        };
+        let time = start.elapsed();
+        let (termination, converged, iters, cost_count, gradient_count, best_cost) = match outcome {
+            Ok(outcome) => outcome,
+            Err(e) => (format!("Error: {e}"), false, 0, 0, 0, f64::NAN),
+        };
+        let error = minimum.map_or(f64::NAN, |minimum| best_cost - minimum);
+        Record {
+            solver: self.name(),
+            function: name.to_string(),
+            dim,
+            termination,
+            iters,
+            cost_count,
+            gradient_count,
+            best_cost,
+            error,
+            time,
+            solved: if minimum.is_some() {
+                error.abs() <= tol
+            } else {
+                converged
+            },
+        }
+    }
+}
+
+/// Result of a single benchmark run
+#[derive(Clone, Debug)]
+pub struct Record {
+    /// Name of the solver
+    pub solver: &'static str,
+    /// Name of the test function or problem
+    pub function: String,
+    /// Number of dimensions
+    pub dim: usize,
+    /// Reason for termination, or the error returned by the solver
+    pub termination: String,
+    /// Number of iterations
+    pub iters: u64,
+    /// Number of cost function evaluations
+    pub cost_count: u64,
+    /// Number of gradient evaluations
+    pub gradient_count: u64,
+    /// Best cost function value (`NaN` if the solver returned an error)
+    pub best_cost: f64,
+    /// Difference between the best cost function value and the global minimum (`NaN` if the
+    /// minimum is unknown)
+    pub error: f64,
+    /// Wall-clock time
+    pub time: Duration,
+    /// Whether the global minimum was reached within the tolerance (or the solver converged, if
+    /// the minimum is unknown)
+    pub solved: bool,
+}
+
+/// Problems which can be benchmarked: cost function, gradient and dense Hessian on `Vec<f64>`
+pub trait BenchProblem:
+    CostFunction<Param = Vec<f64>, Output = f64>
+    + Gradient<Param = Vec<f64>, Gradient = Vec<f64>>
+    + Hessian<Param = Vec<f64>, Hessian = Vec<Vec<f64>>>
+{
+}
+
+impl<T> BenchProblem for T where
+    T: CostFunction<Param = Vec<f64>, Output = f64>
+        + Gradient<Param = Vec<f64>, Gradient = Vec<f64>>
+        + Hessian<Param = Vec<f64>, Hessian = Vec<Vec<f64>>>
+{
+}
+
+/// Termination reason, whether the solver converged, iterations, cost and gradient evaluations
+/// and best cost of a run
+type Outcome = (String, bool, u64, u64, u64, f64);
+
+/// Runs `solver` on `problem` with the state initialized by `init`
+fn run<O, S, I>(problem: O, solver: S, init: impl FnOnce(I) -> I) -> Result<Outcome, Error>
+where
+    O: BenchProblem,
+    S: Solver<O, I>,
+    I: State<Param = Vec<f64>, Float = f64> + SerializeAlias + DeserializeOwnedAlias,
+{
+    let res = Executor::new(problem, solver).configure(init).run()?;
+    let state = res.state();
+    let counts = state.get_func_counts();
+    Ok((
+        state.get_termination_status().to_string(),
+        matches!(
+            state.get_termination_reason(),
+            Some(TerminationReason::SolverConverged | TerminationReason::TargetCostReached)
+        ),
+        state.get_iter(),
+        counts.get("cost_count").copied().unwrap_or(0),
+        counts.get("gradient_count").copied().unwrap_or(0),
+        state.get_best_cost(),
+    ))
+}
+
+/// Initial simplex for Nelder-Mead: `x0` and `x0` with each coordinate perturbed by 5% (or by
+/// `0.00025` if it is zero)
+fn initial_simplex(x0: &[f64]) -> Vec<Vec<f64>> {
+    let mut simplex = vec![x0.to_vec()];
+    for i in 0..x0.len() {
+        let mut vertex = x0.to_vec();
+        vertex[i] = if vertex[i] == 0.0 {
+            0.00025
+        } else {
+            1.05 * vertex[i]
+        };
+        simplex.push(vertex);
+    }
+    simplex
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_names() {
+        for solver in BenchSolver::ALL {
+            assert_eq!(BenchSolver::from_name(solver.name()).unwrap(), solver);
+        }
+        assert!(BenchSolver::from_name("newton").is_err());
+    }
+
+    #[test]
+    fn test_run_all() {
+        for solver in BenchSolver::ALL {
+            let record = solver.run(TestFunction::Sphere, 2, 500, 1e-6);
+            assert_eq!(record.solver, solver.name());
+            assert_eq!(record.function, "sphere");
+            assert_eq!(record.dim, 2);
+            assert!(record.solved, "{record:?}");
+            assert!(record.iters > 0);
+            assert!(record.cost_count > 0);
+        }
+    }
+
+    #[test]
+    fn test_run_rosenbrock() {
+        let record = BenchSolver::Lbfgs.run(TestFunction::Rosenbrock, 10, 1000, 1e-8);
+        assert!(record.solved, "{record:?}");
+        assert!(record.gradient_count > 0);
+    }
+
+    #[test]
+    fn test_run_max_iters() {
+        let record = BenchSolver::SteepestDescent.run(TestFunction::Rosenbrock, 2, 3, 1e-8);
+        assert!(!record.solved);
+        assert_eq!(record.iters, 3);
+        assert_eq!(record.termination, "Maximum number of iterations reached");
+    }
+}
@@ -0,0 +1,54 @@
+/*
+ * Copyright 2018-2022 argmin developers
+ *
+ * Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+ * http://opensource.org/licenses/MIT>, at your option. This file may not be
+ * copied, modified, or distributed except according to those terms.
+ */
+
+#include <stdio.h>
+
+#include "argmin.h"
+
+typedef struct {
+    double a;
+    double b;
+} rosenbrock_params;
+
+static int rosenbrock(const double *x, size_t n, double *cost, void *user_data) {
+    const rosenbrock_params *p = user_data;
+    (void)n;
+    *cost = (p->a - x[0]) * (p->a - x[0]) + p->b * (x[1] - x[0] * x[0]) * (x[1] - x[0] * x[0]);
+    return 0;
+}
+
+static int rosenbrock_gradient(const double *x, size_t n, double *grad, void *user_data) {
+    const rosenbrock_params *p = user_data;
+    (void)n;
+    grad[0] = -2.0 * (p->a - x[0]) - 4.0 * p->b * x[0] * (x[1] - x[0] * x[0]);
+    grad[1] = 2.0 * p->b * (x[1] - x[0] * x[0]);
+    return 0;
+}
+
+int main(void) {
+    rosenbrock_params params = {1.0, 100.0};
+    argmin_problem *problem = argmin_problem_new(2, rosenbrock, rosenbrock_gradient, &params);
+
+    argmin_options options = argmin_options_default();
+    options.solver = ARGMIN_SOLVER_LBFGS;
+    options.max_iters = 100;
+
+    double x[2] = {-1.2, 1.0};
+    argmin_result result;
+    argmin_status status = argmin_minimize(problem, &options, x, &result);
+    argmin_problem_free(problem);
+
+    if (status != ARGMIN_STATUS_OK) {
+        fprintf(stderr, "error: %s\n", argmin_last_error());
+        return 1;
+    }
+    printf("x = [%f, %f], cost = %e, iterations = %llu\n", x[0], x[1], result.cost,
+           (unsigned long long)result.iters);
+    return 0;
+}
@@ -0,0 +1,112 @@
+/*
+ * Copyright 2018-2022 argmin developers
+ *
+ * Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+ * http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+ * http://opensource.org/licenses/MIT>, at your option. This file may not be
+ * copied, modified, or distributed except according to those terms.
+ */
+
+/*
+ * C API for argmin
+ *
+ * See the documentation of the `argmin-capi` crate for details.
+ */
+
+#ifndef ARGMIN_H
+#define ARGMIN_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Cost function callback: writes f(x) to `cost`, returns 0 on success */
+typedef int (*argmin_cost_fn)(const double *x, size_t n, double *cost, void *user_data);
+
+/* Gradient callback: writes the gradient (of length n) to `grad`, returns 0 on success */
+typedef int (*argmin_gradient_fn)(const double *x, size_t n, double *grad, void *user_data);
+
+/* Opaque problem handle */
+typedef struct ArgminProblem argmin_problem;
+
+typedef enum {
+    ARGMIN_SOLVER_LBFGS = 0,
+    ARGMIN_SOLVER_BFGS = 1,
+    ARGMIN_SOLVER_CONJUGATE_GRADIENT = 2,
+    ARGMIN_SOLVER_STEEPEST_DESCENT = 3,
+    ARGMIN_SOLVER_NELDER_MEAD = 4,
+} argmin_solver;
+
+typedef struct {
+    argmin_solver solver;
+    /* Maximum number of iterations */
+    uint64_t max_iters;
+    /* Target cost function value; the solver stops once it is reached */
+    double target_cost;
+    /* Number of stored gradient differences of L-BFGS */
+    size_t lbfgs_memory;
+    /* Lower and upper bounds (arrays of length n), or NULL for an unbounded problem */
+    const double *lower;
+    const double *upper;
+} argmin_options;
+
+typedef enum {
+    ARGMIN_STATUS_OK = 0,
+    ARGMIN_STATUS_INVALID_ARGUMENT = 1,
+    ARGMIN_STATUS_SOLVER_ERROR = 2,
+} argmin_status;
+
+typedef enum {
+    ARGMIN_TERMINATION_NOT_TERMINATED = 0,
+    ARGMIN_TERMINATION_MAX_ITERS_REACHED = 1,
+    ARGMIN_TERMINATION_TARGET_COST_REACHED = 2,
+    ARGMIN_TERMINATION_SOLVER_CONVERGED = 3,
+    ARGMIN_TERMINATION_SOLVER_EXIT = 4,
+} argmin_termination;
+
+typedef struct {
+    /* Cost function value at the best parameter vector */
+    double cost;
+    /* Number of iterations */
+    uint64_t iters;
+    /* Number of cost function and gradient evaluations */
+    uint64_t cost_count;
+    uint64_t gradient_count;
+    argmin_termination termination;
+} argmin_result;
+
+/*
+ * Creates a problem with n parameters. `gradient` may be NULL, in which case gradient based
+ * solvers use central finite differences. `user_data` is passed to both callbacks. Returns NULL
+ * if n is zero.
+ */
+argmin_problem *argmin_problem_new(size_t n, argmin_cost_fn cost, argmin_gradient_fn gradient,
+                                   void *user_data);
+
+/* Frees a problem; does nothing if `problem` is NULL */
+void argmin_problem_free(argmin_problem *problem);
+
+/* Default options: L-BFGS with a memory of 7, at most 1000 iterations, no bounds */
+argmin_options argmin_options_default(void);
+
+/*
+ * Minimizes `problem` starting from `x` (of length n), which is overwritten with the best
+ * parameter vector on success. `options` (NULL for the defaults) and `result` may be NULL.
+ */
+argmin_status argmin_minimize(const argmin_problem *problem, const argmin_options *options,
+                              double *x, argmin_result *result);
+
+/*
+ * Description of the last error on the calling thread, or NULL. Valid until the next call of a
+ * function of this API on the same thread.
+ */
+const char *argmin_last_error(void);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* ARGMIN_H */
@@ -0,0 +1,654 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! C API for argmin
+//!
+//! Exposes argmin's solvers via a C ABI, such that they can be called from C, C++, Fortran (via
+//! `bind(C)`) or any other language with a C FFI. The corresponding header is
+//! `include/argmin.h`.
+//!
+//! A problem is an opaque handle ([`ArgminProblem`]) created from function pointers for the cost
+//! function and (optionally) the gradient, together with a pointer to user data which is passed
+//! to both. The solver is configured with [`ArgminOptions`] and [`argmin_minimize`] writes the
+//! best parameter vector back into the initial parameter vector and fills an [`ArgminResult`].
+//! If no gradient is given, gradient based solvers use [`FiniteDiff`] with central differences.
+//! Box constraints are handled by [`TransformedProblem`].
+//!
+//! Errors are reported via the returned [`ArgminStatus`]; a description of the last error on the
+//! calling thread is available from [`argmin_last_error`].
+
+use argmin::core::{
+    Bounds, CostFunction, DeserializeOwnedAlias, Error, Executor, Gradient, IterState,
+    SerializeAlias, Solver, State, TerminationReason, TerminationStatus, TransformedProblem,
+};
+use argmin::derivatives::{FiniteDiff, FiniteDiffScheme};
+use argmin::solver::conjugategradient::{beta::PolakRibiere, NonlinearConjugateGradient};
+use argmin::solver::gradientdescent::SteepestDescent;
+use argmin::solver::linesearch::MoreThuenteLineSearch;
+use argmin::solver::neldermead::NelderMead;
+use argmin::solver::quasinewton::{BFGS, LBFGS};
+use argmin::{argmin_error, argmin_error_closure};
+use std::cell::RefCell;
+use std::ffi::CString;
+use std::os::raw::{c_char, c_int, c_void};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr;
+use std::slice;
+
+/// Cost function callback
+///
+/// Called with the parameter vector `x` of length `n` and the user data given to
+/// [`argmin_problem_new`]. Writes the cost function value to `cost` and returns `0` on success;
+/// any other return value aborts the optimization.
+pub type ArgminCostFn =
+    unsafe extern "C" fn(x: *const f64, n: usize, cost: *mut f64, user_data: *mut c_void) -> c_int;
+
+/// Gradient callback
+///
+/// Called with the parameter vector `x` of length `n` and the user data given to
+/// [`argmin_problem_new`]. Writes the gradient (of length `n`) to `grad` and returns `0` on
+/// success; any other return value aborts the optimization.
+pub type ArgminGradientFn =
+    unsafe extern "C" fn(x: *const f64, n: usize, grad: *mut f64, user_data: *mut c_void) -> c_int;
+
+/// Opaque problem handle
+///
+/// Created with [`argmin_problem_new`] and freed with [`argmin_problem_free`].
+pub struct ArgminProblem {
+    /// Number of parameters
+    n: usize,
+    /// Cost function
+    cost: ArgminCostFn,
+    /// Gradient
+    gradient: Option<ArgminGradientFn>,
+    /// User data passed to the callbacks
+    user_data: *mut c_void,
+}
+
+impl CostFunction for &ArgminProblem {
+    type Param = Vec<f64>;
+    type Output = f64;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        let mut cost = 0.0;
+        // SAFETY: the caller of `argmin_problem_new` guarantees that the callback is valid for
+        // parameter vectors of length `n`.
+        let code = unsafe { (self.cost)(param.as_ptr(), self.n, &mut cost, self.user_data) };
+        if code != 0 {
+            return Err(argmin_error!(
+                ConditionViolated,
+                format!("Cost function callback returned error code {code}.")
+            ));
+        }
+        Ok(cost)
+    }
+}
+
+impl Gradient for &ArgminProblem {
+    type Param = Vec<f64>;
+    type Gradient = Vec<f64>;
+
+    fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+        let gradient = self.gradient.ok_or_else(argmin_error_closure!(
+            NotImplemented,
+            "Gradient requested, but no gradient callback given."
+        ))?;
+        let mut grad = vec![0.0; self.n];
+        // SAFETY: see `cost`; `grad` has length `n`.
+        let code = unsafe { gradient(param.as_ptr(), self.n, grad.as_mut_ptr(), self.user_data) };
+        if code != 0 {
+            return Err(argmin_error!(
+                ConditionViolated,
+                format!("Gradient callback returned error code {code}.")
+            ));
+        }
+        Ok(grad)
+    }
+}
+
+/// Solvers available via the C API
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ArgminSolver {
+    /// L-BFGS with More-Thuente line search
+    Lbfgs = 0,
+    /// BFGS with More-Thuente line search, starting from the identity
+    Bfgs = 1,
+    /// Nonlinear conjugate gradient (Polak-Ribiere) with More-Thuente line search, restarted every
+    /// 10 iterations or when consecutive gradients are far from orthogonal
+    ConjugateGradient = 2,
+    /// Steepest descent with More-Thuente line search
+    SteepestDescent = 3,
+    /// Nelder-Mead, starting from a simplex around the initial parameter vector
+    NelderMead = 4,
+}
+
+/// Solver configuration
+///
+/// Should be initialized with [`argmin_options_default`].
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ArgminOptions {
+    /// Solver
+    pub solver: ArgminSolver,
+    /// Maximum number of iterations
+    pub max_iters: u64,
+    /// Target cost function value; the solver stops once it is reached
+    pub target_cost: f64,
+    /// Number of stored gradient differences of L-BFGS
+    pub lbfgs_memory: usize,
+    /// Lower bounds (array of length `n`), or null for an unbounded problem
+    pub lower: *const f64,
+    /// Upper bounds (array of length `n`), or null for an unbounded problem
+    pub upper: *const f64,
+}
+
+/// Status returned by the functions of the C API
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ArgminStatus {
+    /// Success
+    Ok = 0,
+    /// Invalid argument, such as a null pointer or invalid bounds
+    InvalidArgument = 1,
+    /// The solver failed, for instance because a callback returned an error
+    SolverError = 2,
+}
+
+/// Reason for the termination of a solver
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ArgminTermination {
+    /// Solver did not terminate
+    NotTerminated = 0,
+    /// Reached maximum number of iterations
+    MaxItersReached = 1,
+    /// Reached target cost function value
+    TargetCostReached = 2,
+    /// Converged
+    SolverConverged = 3,
+    /// Solver exit with another reason
+    SolverExit = 4,
+}
+
+/// Result of [`argmin_minimize`]
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct ArgminResult {
+    /// Cost function value at the best parameter vector
+    pub cost: f64,
+    /// Number of iterations
+    pub iters: u64,
+    /// Number of cost function evaluations
+    pub cost_count: u64,
+    /// Number of gradient evaluations
+    pub gradient_count: u64,
+    /// Reason for termination
+    pub termination: ArgminTermination,
+}
+
+impl Default for ArgminResult {
+    fn default() -> Self {
+        ArgminResult {
+            cost: f64::INFINITY,
+            iters: 0,
+            cost_count: 0,
+            gradient_count: 0,
+            termination: ArgminTermination::NotTerminated,
+        }
+    }
+}
+
+thread_local! {
+    /// Description of the last error on this thread
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Stores the description of an error for `argmin_last_error` and returns `status`
+fn set_last_error(status: ArgminStatus, msg: impl ToString) -> ArgminStatus {
+    let msg = CString::new(msg.to_string().replace('\0', "")).unwrap_or_default();
+    LAST_ERROR.with(|e| *e.borrow_mut() = Some(msg));
+    status
+}
+
+/// Runs `solver` on `problem` with the state initialized by `init` and returns the best parameter
+/// vector
+fn run<O, S, I>(
+    problem: O,
+    solver: S,
+    init: impl FnOnce(I) -> I,
+    result: &mut ArgminResult,
+) -> Result<Vec<f64>, Error>
+where
+    S: Solver<O, I>,
+    I: State<Param = Vec<f64>, Float = f64> + SerializeAlias + DeserializeOwnedAlias,
+{
+    let res = Executor::new(problem, solver).configure(init).run()?;
+    let state = res.state();
+    let counts = state.get_func_counts();
+    result.cost = state.get_best_cost();
+    result.iters = state.get_iter();
+    result.cost_count = counts.get("cost_count").copied().unwrap_or(0);
+    result.gradient_count = counts.get("gradient_count").copied().unwrap_or(0);
+    result.termination = match state.get_termination_status() {
+        TerminationStatus::NotTerminated => ArgminTermination::NotTerminated,
+        TerminationStatus::Terminated(TerminationReason::MaxItersReached) => {
+            ArgminTermination::MaxItersReached
+        }
+        TerminationStatus::Terminated(TerminationReason::TargetCostReached) => {
+            ArgminTermination::TargetCostReached
+        }
+        TerminationStatus::Terminated(TerminationReason::SolverConverged) => {
+            ArgminTermination::SolverConverged
+        }
+        TerminationStatus::Terminated(_) => ArgminTermination::SolverExit,
+    };
+    state
+        .get_best_param()
+        .cloned()
+        .ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "Solver did not return a parameter vector."
+        ))
+}
+
+/// Initial simplex for Nelder-Mead: `x0` and `x0` with each coordinate perturbed by 5% (or by
+/// `0.00025` if it is zero)
+fn initial_simplex(x0: &[f64]) -> Vec<Vec<f64>> {
+    let mut simplex = vec![x0.to_vec()];
+    for i in 0..x0.len() {
+        let mut vertex = x0.to_vec();
+        vertex[i] = if vertex[i] == 0.0 {
+            0.00025
+        } else {
+            1.05 * vertex[i]
+        };
+        simplex.push(vertex);
+    }
+    simplex
+}
+
+/// Runs the configured solver on a problem with cost function and gradient
+fn solve<O>(
+    problem: O,
+    x0: Vec<f64>,
+    options: &ArgminOptions,
+    result: &mut ArgminResult,
+) -> Result<Vec<f64>, Error>
+where
+    O: CostFunction<Param = Vec<f64>, Output = f64>
+        + Gradient<Param = Vec<f64>, Gradient = Vec<f64>>,
+{
+    let max_iters = options.max_iters;
+    let target_cost = options.target_cost;
+    let linesearch = MoreThuenteLineSearch::new();
+    match options.solver {
+        ArgminSolver::Lbfgs => run(
+            problem,
+            LBFGS::new(linesearch, options.lbfgs_memory),
+            |state: IterState<_, _, (), (), f64>| {
+                state
+                    .param(x0)
+                    .max_iters(max_iters)
+                    .target_cost(target_cost)
+            },
+            result,
+        ),
+        ArgminSolver::Bfgs => {
+            let n = x0.len();
+            let eye = (0..n)
+                .map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+                .collect::<Vec<Vec<f64>>>();
+            run(
+                problem,
+                BFGS::new(linesearch),
+                |state: IterState<_, _, (), _, f64>| {
+                    state
+                        .param(x0)
+                        .inv_hessian(eye)
+                        .max_iters(max_iters)
+                        .target_cost(target_cost)
+                },
+                result,
+            )
+        }
+        ArgminSolver::ConjugateGradient => run(
+            problem,
+            NonlinearConjugateGradient::new(linesearch, PolakRibiere::new())
+                .restart_iters(10)
+                .restart_orthogonality(0.1),
+            |state: IterState<_, _, (), (), f64>| {
+                state
+                    .param(x0)
+                    .max_iters(max_iters)
+                    .target_cost(target_cost)
+            },
+            result,
+        ),
+        ArgminSolver::SteepestDescent => run(
+            problem,
+            SteepestDescent::new(linesearch),
+            |state: IterState<_, _, (), (), f64>| {
+                state
+                    .param(x0)
+                    .max_iters(max_iters)
+                    .target_cost(target_cost)
+            },
+            result,
+        ),
+        ArgminSolver::NelderMead => run(
+            problem,
+            NelderMead::new(initial_simplex(&x0)),
+            |state: IterState<_, (), (), (), f64>| {
+                state.max_iters(max_iters).target_cost(target_cost)
+            },
+            result,
+        ),
+    }
+}
+
+/// Runs the configured solver on a problem, optionally subject to box constraints
+fn solve_bounded<O>(
+    problem: O,
+    x0: Vec<f64>,
+    bounds: Option<(Vec<f64>, Vec<f64>)>,
+    options: &ArgminOptions,
+    result: &mut ArgminResult,
+) -> Result<Vec<f64>, Error>
+where
+    O: CostFunction<Param = Vec<f64>, Output = f64>
+        + Gradient<Param = Vec<f64>, Gradient = Vec<f64>>,
+{
+    match bounds {
+        None => solve(problem, x0, options, result),
+        Some((lower, upper)) => {
+            let bounds = Bounds::new(lower, upper)?;
+            // Only used for mapping the best parameter vector back after the run
+            let transform = TransformedProblem::new((), bounds.clone());
+            let problem = TransformedProblem::new(problem, bounds);
+            let x0 = problem.to_unbounded(&x0)?;
+            let best = solve(problem, x0, options, result)?;
+            Ok(transform.to_bounded(&best))
+        }
+    }
+}
+
+/// Creates a problem with `n` parameters from a cost function and an (optional) gradient
+///
+/// `gradient` may be null, in which case gradient based solvers approximate the gradient by
+/// central finite differences. `user_data` is passed to both callbacks and may be null. Returns
+/// null if `n` is zero. The problem must be freed with [`argmin_problem_free`].
+///
+/// # Safety
+///
+/// The callbacks must be safe to call with parameter vectors of length `n` and `user_data` for as
+/// long as the problem is used.
+#[no_mangle]
+pub unsafe extern "C" fn argmin_problem_new(
+    n: usize,
+    cost: ArgminCostFn,
+    gradient: Option<ArgminGradientFn>,
+    user_data: *mut c_void,
+) -> *mut ArgminProblem {
+    if n == 0 {
+        set_last_error(
+            ArgminStatus::InvalidArgument,
+            "Number of parameters must be positive.",
+        );
+        return ptr::null_mut();
+    }
+    Box::into_raw(Box::new(ArgminProblem {
+        n,
+        cost,
+        gradient,
+        user_data,
+    }))
+}
+
+/// Frees a problem created with [`argmin_problem_new`]
+///
+/// Does nothing if `problem` is null.
+///
+/// # Safety
+///
+/// `problem` must be null or have been returned by [`argmin_problem_new`] and not been freed
+/// before.
+#[no_mangle]
+pub unsafe extern "C" fn argmin_problem_free(problem: *mut ArgminProblem) {
+    if !problem.is_null() {
+        drop(Box::from_raw(problem));
+    }
+}
+
+/// Returns the default options: L-BFGS with a memory of 7, at most 1000 iterations, no target
+/// cost and no bounds
+#[no_mangle]
+pub extern "C" fn argmin_options_default() -> ArgminOptions {
+    ArgminOptions {
+        solver: ArgminSolver::Lbfgs,
+        max_iters: 1000,
+        target_cost: f64::NEG_INFINITY,
+        lbfgs_memory: 7,
+        lower: ptr::null(),
+        upper: ptr::null(),
+    }
+}
+
+/// Minimizes `problem` starting from `x`
+///
+/// `x` (of length `n`, see [`argmin_problem_new`]) holds the initial parameter vector and is
+/// overwritten with the best parameter vector found if the solver succeeds. `options` may be null,
+/// in which case the defaults of [`argmin_options_default`] are used. `result` may be null.
+///
+/// # Safety
+///
+/// `problem` must have been returned by [`argmin_problem_new`], `x` must point to `n` valid
+/// `double`s, and `options` and `result` must be null or valid pointers. The bounds in `options`
+/// must be null or point to `n` valid `double`s.
+#[no_mangle]
+pub unsafe extern "C" fn argmin_minimize(
+    problem: *const ArgminProblem,
+    options: *const ArgminOptions,
+    x: *mut f64,
+    result: *mut ArgminResult,
+) -> ArgminStatus {
+    if problem.is_null() || x.is_null() {
+        return set_last_error(
+            ArgminStatus::InvalidArgument,
+            "`problem` and `x` must not be null.",
+        );
+    }
+    let problem = &*problem;
+    let options = options
+        .as_ref()
+        .copied()
+        .unwrap_or_else(|| argmin_options_default());
+    if options.lower.is_null() != options.upper.is_null() {
+        return set_last_error(
+            ArgminStatus::InvalidArgument,
+            "Either both or none of the bounds must be given.",
+        );
+    }
+    if options.solver == ArgminSolver::Lbfgs && options.lbfgs_memory == 0 {
+        return set_last_error(
+            ArgminStatus::InvalidArgument,
+            "L-BFGS memory must be positive.",
+        );
+    }
+    let x = slice::from_raw_parts_mut(x, problem.n);
+    let bounds = (!options.lower.is_null()).then(|| {
+        (
+            slice::from_raw_parts(options.lower, problem.n).to_vec(),
+            slice::from_raw_parts(options.upper, problem.n).to_vec(),
+        )
+    });
+
+    let mut res = ArgminResult::default();
+    let x0 = x.to_vec();
+    let outcome = catch_unwind(AssertUnwindSafe(|| {
+        if problem.gradient.is_some() {
+            solve_bounded(problem, x0, bounds, &options, &mut res)
+        } else {
+            let problem = FiniteDiff::new(problem).with_scheme(FiniteDiffScheme::Central);
+            solve_bounded(problem, x0, bounds, &options, &mut res)
+        }
+    }));
+    if let Some(result) = result.as_mut() {
+        *result = res;
+    }
+    match outcome {
+        Ok(Ok(best)) => {
+            x.copy_from_slice(&best);
+            ArgminStatus::Ok
+        }
+        Ok(Err(e)) => set_last_error(ArgminStatus::SolverError, e),
+        Err(_) => set_last_error(ArgminStatus::SolverError, "Solver panicked."),
+    }
+}
+
+/// Returns a description of the last error on the calling thread, or null if there was none
+///
+/// The string is owned by the library and valid until the next call of a function of the C API
+/// on the same thread.
+#[no_mangle]
+pub extern "C" fn argmin_last_error() -> *const c_char {
+    LAST_ERROR.with(|e| e.borrow().as_ref().map_or(ptr::null(), |msg| msg.as_ptr()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    unsafe extern "C" fn rosenbrock(
+        x: *const f64,
+        n: usize,
+        cost: *mut f64,
+        _user_data: *mut c_void,
+    ) -> c_int {
+        let x = slice::from_raw_parts(x, n);
+        *cost = (1.0 - x[0]).powi(2) + 100.0 * (x[1] - x[0].powi(2)).powi(2);
+        0
+    }
+
+    unsafe extern "C" fn rosenbrock_gradient(
+        x: *const f64,
+        n: usize,
+        grad: *mut f64,
+        user_data: *mut c_void,
+    ) -> c_int {
+        if !user_data.is_null() {
+            *(user_data as *mut u64) += 1;
+        }
+        let x = slice::from_raw_parts(x, n);
+        let grad = slice::from_raw_parts_mut(grad, n);
+        grad[0] = -2.0 * (1.0 - x[0]) - 400.0 * x[0] * (x[1] - x[0].powi(2));
+        grad[1] = 200.0 * (x[1] - x[0].powi(2));
+        0
+    }
+
+    unsafe extern "C" fn failing(
+        _x: *const f64,
+        _n: usize,
+        _cost: *mut f64,
+        _user_data: *mut c_void,
+    ) -> c_int {
+        -1
+    }
+
+    fn last_error() -> String {
+        unsafe { CStr::from_ptr(argmin_last_error()) }
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[test]
+    fn test_solvers() {
+        for (solver, with_gradient) in [
+            (ArgminSolver::Lbfgs, true),
+            (ArgminSolver::Lbfgs, false),
+            (ArgminSolver::Bfgs, true),
+            (ArgminSolver::ConjugateGradient, true),
+            (ArgminSolver::NelderMead, false),
+        ] {
+            let mut count = 0u64;
+            let gradient = with_gradient.then_some(rosenbrock_gradient as ArgminGradientFn);
+            let problem = unsafe {
+                argmin_problem_new(
+                    2,
+                    rosenbrock,
+                    gradient,
+                    &mut count as *mut u64 as *mut c_void,
+                )
+            };
+            let mut options = argmin_options_default();
+            options.solver = solver;
+            options.max_iters = 10000;
+            let mut x = [-1.2, 1.0];
+            let mut result = ArgminResult::default();
+            let status = unsafe { argmin_minimize(problem, &options, x.as_mut_ptr(), &mut result) };
+            unsafe { argmin_problem_free(problem) };
+            assert_eq!(status, ArgminStatus::Ok, "{solver:?}: {}", last_error());
+            assert!((x[0] - 1.0).abs() < 1e-3, "{solver:?}: {x:?}");
+            assert!((x[1] - 1.0).abs() < 1e-3, "{solver:?}: {x:?}");
+            assert!(result.cost < 1e-6);
+            assert!(result.iters > 0);
+            assert!(result.cost_count > 0);
+            if with_gradient {
+                assert_eq!(result.gradient_count, count);
+            }
+            assert_ne!(result.termination, ArgminTermination::NotTerminated);
+        }
+    }
+
+    #[test]
+    fn test_bounds() {
+        let problem = unsafe {
+            argmin_problem_new(2, rosenbrock, Some(rosenbrock_gradient), ptr::null_mut())
+        };
+        let lower = [0.0, 0.0];
+        let upper = [0.8, f64::INFINITY];
+        let mut options = argmin_options_default();
+        options.lower = lower.as_ptr();
+        options.upper = upper.as_ptr();
+        let mut x = [0.5, 0.5];
+        let status = unsafe { argmin_minimize(problem, &options, x.as_mut_ptr(), ptr::null_mut()) };
+        unsafe { argmin_problem_free(problem) };
+        assert_eq!(status, ArgminStatus::Ok);
+        assert!((x[0] - 0.8).abs() < 1e-3);
+        assert!((x[1] - 0.64).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(unsafe { argmin_problem_new(0, rosenbrock, None, ptr::null_mut()) }.is_null());
+        assert_eq!(last_error(), "Number of parameters must be positive.");
+
+        let mut x = [1.0, 2.0];
+        let status =
+            unsafe { argmin_minimize(ptr::null(), ptr::null(), x.as_mut_ptr(), ptr::null_mut()) };
+        assert_eq!(status, ArgminStatus::InvalidArgument);
+        assert_eq!(last_error(), "`problem` and `x` must not be null.");
+
+        let problem = unsafe { argmin_problem_new(2, failing, None, ptr::null_mut()) };
+        let status =
+            unsafe { argmin_minimize(problem, ptr::null(), x.as_mut_ptr(), ptr::null_mut()) };
+        assert_eq!(status, ArgminStatus::SolverError);
+        assert_eq!(
+            last_error(),
+            "Condition violated: \"Cost function callback returned error code -1.\""
+        );
+        assert_eq!(x, [1.0, 2.0]);
+
+        let mut options = argmin_options_default();
+        let lower = [0.0, 0.0];
+        options.lower = lower.as_ptr();
+        let status = unsafe { argmin_minimize(problem, &options, x.as_mut_ptr(), ptr::null_mut()) };
+        assert_eq!(status, ArgminStatus::InvalidArgument);
+        unsafe { argmin_problem_free(problem) };
+    }
+}
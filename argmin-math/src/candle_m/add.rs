@@ -0,0 +1,71 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminAdd;
+use candle_core::Tensor;
+
+macro_rules! make_add {
+    ($t:ty) => {
+        impl ArgminAdd<$t, Tensor> for Tensor {
+            #[inline]
+            fn add(&self, other: &$t) -> Tensor {
+                self.affine(1.0, f64::from(*other))
+                    .expect("candle: failed to add scalar")
+            }
+        }
+
+        impl ArgminAdd<Tensor, Tensor> for $t {
+            #[inline]
+            fn add(&self, other: &Tensor) -> Tensor {
+                other
+                    .affine(1.0, f64::from(*self))
+                    .expect("candle: failed to add scalar")
+            }
+        }
+    };
+}
+
+make_add!(f32);
+make_add!(f64);
+
+impl ArgminAdd<Tensor, Tensor> for Tensor {
+    #[inline]
+    fn add(&self, other: &Tensor) -> Tensor {
+        Tensor::add(self, other).expect("candle: failed to add tensors")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::candle_m::tests::{tensor, to_vec};
+
+    #[test]
+    fn test_add_tensor_scalar() {
+        let a = tensor(&[1.0, 2.0, 3.0]);
+        let res = <Tensor as ArgminAdd<f64, Tensor>>::add(&a, &2.0);
+        assert_eq!(to_vec(&res), vec![3.0, 4.0, 5.0]);
+        let res = <f32 as ArgminAdd<Tensor, Tensor>>::add(&2.0, &a);
+        assert_eq!(to_vec(&res), vec![3.0, 4.0, 5.0]);
+    }
+
+    #[test]
+    fn test_add_tensor_tensor() {
+        let a = tensor(&[1.0, 2.0, 3.0]);
+        let b = tensor(&[4.0, 5.0, 6.0]);
+        let res = <Tensor as ArgminAdd<Tensor, Tensor>>::add(&a, &b);
+        assert_eq!(to_vec(&res), vec![5.0, 7.0, 9.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_tensor_tensor_panic() {
+        let a = tensor(&[1.0, 2.0, 3.0]);
+        let b = tensor(&[4.0, 5.0]);
+        <Tensor as ArgminAdd<Tensor, Tensor>>::add(&a, &b);
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+use crate::candle_m::to_scalar;
+use crate::ArgminBound;
+use candle_core::Tensor;
+
+/// Number of elements of `a` which are smaller than or equal to the respective element of `b`
+fn count_le(a: &Tensor, b: &Tensor) -> Option<usize> {
+    let count = a
+        .le(b)
+        .and_then(|m| m.to_dtype(candle_core::DType::U32))
+        .and_then(|m| m.sum_all())
+        .ok()?;
+    Some(to_scalar::<u32>(&count) as usize)
+}
+
+impl ArgminBound for Tensor {
+    fn valid_bounds(lower: &Self, upper: &Self) -> bool {
+        lower.shape() == upper.shape() && count_le(lower, upper) == Some(lower.elem_count())
+    }
+
+    fn within_bounds(&self, lower: &Self, upper: &Self) -> bool {
+        self.shape() == lower.shape()
+            && self.shape() == upper.shape()
+            && count_le(lower, self) == Some(self.elem_count())
+            && count_le(self, upper) == Some(self.elem_count())
+    }
+
+    fn clamp_to_bounds(&self, lower: &Self, upper: &Self) -> Self {
+        self.maximum(lower)
+            .and_then(|t| t.minimum(upper))
+            .expect("candle: failed to clamp tensor to bounds")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::candle_m::tests::{tensor, to_vec};
+
+    #[test]
+    fn test_valid_bounds() {
+        let l = tensor(&[0.0, 1.0]);
+        let u = tensor(&[1.0, 1.0]);
+        assert!(Tensor::valid_bounds(&l, &u));
+        assert!(!Tensor::valid_bounds(&u, &l));
+        assert!(!Tensor::valid_bounds(&l, &tensor(&[1.0])));
+    }
+
+    #[test]
+    fn test_within_bounds() {
+        let l = tensor(&[0.0, 0.0]);
+        let u = tensor(&[1.0, 1.0]);
+        assert!(tensor(&[0.5, 1.0]).within_bounds(&l, &u));
+        assert!(!tensor(&[0.5, 1.5]).within_bounds(&l, &u));
+        assert!(!tensor(&[0.5]).within_bounds(&l, &u));
+    }
+
+    #[test]
+    fn test_clamp_to_bounds() {
+        let l = tensor(&[0.0, 0.0, 0.0]);
+        let u = tensor(&[1.0, 1.0, 1.0]);
+        let res = tensor(&[-1.0, 0.5, 2.0]).clamp_to_bounds(&l, &u);
+        assert_eq!(to_vec(&res), vec![0.0, 0.5, 1.0]);
+    }
+}
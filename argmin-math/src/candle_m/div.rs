@@ -0,0 +1,72 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminDiv;
+use candle_core::Tensor;
+
+macro_rules! make_div {
+    ($t:ty) => {
+        impl ArgminDiv<$t, Tensor> for Tensor {
+            #[inline]
+            fn div(&self, other: &$t) -> Tensor {
+                self.affine(1.0 / f64::from(*other), 0.0)
+                    .expect("candle: failed to divide by scalar")
+            }
+        }
+
+        impl ArgminDiv<Tensor, Tensor> for $t {
+            #[inline]
+            fn div(&self, other: &Tensor) -> Tensor {
+                other
+                    .recip()
+                    .and_then(|r| r.affine(f64::from(*self), 0.0))
+                    .expect("candle: failed to divide scalar by tensor")
+            }
+        }
+    };
+}
+
+make_div!(f32);
+make_div!(f64);
+
+impl ArgminDiv<Tensor, Tensor> for Tensor {
+    #[inline]
+    fn div(&self, other: &Tensor) -> Tensor {
+        Tensor::div(self, other).expect("candle: failed to divide tensors")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::candle_m::tests::{tensor, to_vec};
+
+    #[test]
+    fn test_div_tensor_scalar() {
+        let a = tensor(&[1.0, 2.0, 4.0]);
+        let res = <Tensor as ArgminDiv<f64, Tensor>>::div(&a, &2.0);
+        assert_eq!(to_vec(&res), vec![0.5, 1.0, 2.0]);
+        let res = <f64 as ArgminDiv<Tensor, Tensor>>::div(&2.0, &a);
+        assert_eq!(to_vec(&res), vec![2.0, 1.0, 0.5]);
+    }
+
+    #[test]
+    fn test_div_tensor_tensor() {
+        let a = tensor(&[4.0, 5.0, 6.0]);
+        let b = tensor(&[2.0, 5.0, 4.0]);
+        let res = <Tensor as ArgminDiv<Tensor, Tensor>>::div(&a, &b);
+        assert_eq!(to_vec(&res), vec![2.0, 1.0, 1.5]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_div_tensor_tensor_panic() {
+        let a = tensor(&[1.0, 2.0, 3.0]);
+        let b = tensor(&[4.0, 5.0]);
+        <Tensor as ArgminDiv<Tensor, Tensor>>::div(&a, &b);
+    }
+}
@@ -0,0 +1,134 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+use crate::candle_m::to_scalar;
+use crate::ArgminDot;
+use candle_core::Tensor;
+
+macro_rules! make_dot {
+    ($t:ty) => {
+        impl ArgminDot<Tensor, $t> for Tensor {
+            #[inline]
+            fn dot(&self, other: &Tensor) -> $t {
+                let prod = self
+                    .mul(other)
+                    .and_then(|p| p.sum_all())
+                    .expect("candle: failed to compute dot product");
+                to_scalar(&prod)
+            }
+        }
+
+        impl ArgminDot<$t, Tensor> for Tensor {
+            #[inline]
+            fn dot(&self, other: &$t) -> Tensor {
+                self.affine(f64::from(*other), 0.0)
+                    .expect("candle: failed to multiply by scalar")
+            }
+        }
+
+        impl ArgminDot<Tensor, Tensor> for $t {
+            #[inline]
+            fn dot(&self, other: &Tensor) -> Tensor {
+                other
+                    .affine(f64::from(*self), 0.0)
+                    .expect("candle: failed to multiply by scalar")
+            }
+        }
+    };
+}
+
+make_dot!(f32);
+make_dot!(f64);
+
+/// Matrix-matrix and matrix-vector products
+///
+/// If `self` is a matrix and `other` a vector, the result is a vector. If both are vectors, the
+/// result is their outer product.
+impl ArgminDot<Tensor, Tensor> for Tensor {
+    #[inline]
+    fn dot(&self, other: &Tensor) -> Tensor {
+        match (self.rank(), other.rank()) {
+            (2, 1) => other
+                .unsqueeze(1)
+                .and_then(|o| self.matmul(&o))
+                .and_then(|r| r.squeeze(1)),
+            (1, 1) => self
+                .unsqueeze(1)
+                .and_then(|s| other.unsqueeze(0).and_then(|o| s.matmul(&o))),
+            (1, 2) => self
+                .unsqueeze(0)
+                .and_then(|s| s.matmul(other))
+                .and_then(|r| r.squeeze(0)),
+            _ => self.matmul(other),
+        }
+        .expect("candle: failed to compute matrix product")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::candle_m::tests::{tensor, to_vec};
+    use candle_core::Device;
+
+    #[test]
+    fn test_dot_vec_vec() {
+        let a = tensor(&[1.0, 2.0, 3.0]);
+        let b = tensor(&[4.0, 5.0, 6.0]);
+        let res: f64 = ArgminDot::dot(&a, &b);
+        assert_eq!(res.to_ne_bytes(), 32.0f64.to_ne_bytes());
+        let res: f32 = ArgminDot::dot(&a, &b);
+        assert_eq!(res.to_ne_bytes(), 32.0f32.to_ne_bytes());
+    }
+
+    #[test]
+    fn test_dot_scalar() {
+        let a = tensor(&[1.0, 2.0, 3.0]);
+        let res: Tensor = ArgminDot::dot(&a, &2.0f64);
+        assert_eq!(to_vec(&res), vec![2.0, 4.0, 6.0]);
+        let res: Tensor = ArgminDot::dot(&2.0f32, &a);
+        assert_eq!(to_vec(&res), vec![2.0, 4.0, 6.0]);
+    }
+
+    #[test]
+    fn test_dot_mat_vec() {
+        let m = Tensor::new(&[[1.0f64, 2.0], [3.0, 4.0]], &Device::Cpu).unwrap();
+        let v = tensor(&[1.0, 1.0]);
+        let res: Tensor = ArgminDot::dot(&m, &v);
+        assert_eq!(to_vec(&res), vec![3.0, 7.0]);
+        let res: Tensor = ArgminDot::dot(&v, &m);
+        assert_eq!(to_vec(&res), vec![4.0, 6.0]);
+    }
+
+    #[test]
+    fn test_dot_mat_mat() {
+        let m = Tensor::new(&[[1.0f64, 2.0], [3.0, 4.0]], &Device::Cpu).unwrap();
+        let res: Tensor = ArgminDot::dot(&m, &m);
+        assert_eq!(
+            res.to_vec2::<f64>().unwrap(),
+            vec![vec![7.0, 10.0], vec![15.0, 22.0]]
+        );
+    }
+
+    #[test]
+    fn test_dot_outer() {
+        let a = tensor(&[1.0, 2.0]);
+        let b = tensor(&[3.0, 4.0]);
+        let res: Tensor = ArgminDot::dot(&a, &b);
+        assert_eq!(
+            res.to_vec2::<f64>().unwrap(),
+            vec![vec![3.0, 4.0], vec![6.0, 8.0]]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_dot_vec_vec_panic() {
+        let a = tensor(&[1.0, 2.0, 3.0]);
+        let b = tensor(&[4.0, 5.0]);
+        let _: f64 = ArgminDot::dot(&a, &b);
+    }
+}
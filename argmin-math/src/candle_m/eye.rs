@@ -0,0 +1,46 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+use crate::ArgminEye;
+use candle_core::{DType, Device, Tensor};
+
+impl ArgminEye for Tensor {
+    /// Identity matrix of type `f64` on the CPU
+    #[inline]
+    fn eye(n: usize) -> Tensor {
+        Tensor::eye(n, DType::F64, &Device::Cpu).expect("candle: failed to create tensor")
+    }
+
+    /// Identity matrix with the number of rows, type and device of `self`
+    #[inline]
+    fn eye_like(&self) -> Tensor {
+        let n = self.dims().first().copied().unwrap_or(1);
+        Tensor::eye(n, self.dtype(), self.device()).expect("candle: failed to create tensor")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eye() {
+        let e = <Tensor as ArgminEye>::eye(2);
+        assert_eq!(
+            e.to_vec2::<f64>().unwrap(),
+            vec![vec![1.0, 0.0], vec![0.0, 1.0]]
+        );
+    }
+
+    #[test]
+    fn test_eye_like() {
+        let m = Tensor::zeros((3, 3), DType::F32, &Device::Cpu).unwrap();
+        let e = m.eye_like();
+        assert_eq!(e.dtype(), DType::F32);
+        assert_eq!(e.dims(), &[3, 3]);
+        assert_eq!(e.to_vec2::<f32>().unwrap()[1], vec![0.0, 1.0, 0.0]);
+    }
+}
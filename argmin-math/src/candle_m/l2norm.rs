@@ -0,0 +1,43 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+use crate::candle_m::to_scalar;
+use crate::ArgminL2Norm;
+use candle_core::Tensor;
+
+macro_rules! make_l2norm {
+    ($t:ty) => {
+        impl ArgminL2Norm<$t> for Tensor {
+            #[inline]
+            fn l2_norm(&self) -> $t {
+                let norm = self
+                    .sqr()
+                    .and_then(|a| a.sum_all())
+                    .and_then(|a| a.sqrt())
+                    .expect("candle: failed to compute L2 norm");
+                to_scalar(&norm)
+            }
+        }
+    };
+}
+
+make_l2norm!(f32);
+make_l2norm!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::candle_m::tests::tensor;
+
+    #[test]
+    fn test_l2norm() {
+        let a = tensor(&[4.0, -3.0]);
+        let res: f64 = a.l2_norm();
+        assert_eq!(res.to_ne_bytes(), 5.0f64.to_ne_bytes());
+        let res: f32 = a.l2_norm();
+        assert_eq!(res.to_ne_bytes(), 5.0f32.to_ne_bytes());
+    }
+}
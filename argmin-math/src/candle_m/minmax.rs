@@ -0,0 +1,42 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+use crate::ArgminMinMax;
+use candle_core::Tensor;
+
+impl ArgminMinMax for Tensor {
+    #[inline]
+    fn min(x: &Self, y: &Self) -> Self {
+        x.minimum(y)
+            .expect("candle: failed to compute element-wise minimum")
+    }
+
+    #[inline]
+    fn max(x: &Self, y: &Self) -> Self {
+        x.maximum(y)
+            .expect("candle: failed to compute element-wise maximum")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::candle_m::tests::{tensor, to_vec};
+
+    #[test]
+    fn test_minmax() {
+        let a = tensor(&[1.0, 5.0, 3.0]);
+        let b = tensor(&[4.0, 2.0, 3.0]);
+        assert_eq!(
+            to_vec(&<Tensor as ArgminMinMax>::min(&a, &b)),
+            vec![1.0, 2.0, 3.0]
+        );
+        assert_eq!(
+            to_vec(&<Tensor as ArgminMinMax>::max(&a, &b)),
+            vec![4.0, 5.0, 3.0]
+        );
+    }
+}
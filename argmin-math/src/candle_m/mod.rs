@@ -0,0 +1,61 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+mod add;
+mod bound;
+mod div;
+mod dot;
+mod eye;
+mod l1norm;
+mod l2norm;
+mod minmax;
+mod mul;
+mod signum;
+mod sub;
+mod transpose;
+mod zero;
+
+pub use add::*;
+pub use bound::*;
+pub use div::*;
+pub use dot::*;
+pub use eye::*;
+pub use l1norm::*;
+pub use l2norm::*;
+pub use minmax::*;
+pub use mul::*;
+pub use signum::*;
+pub use sub::*;
+pub use transpose::*;
+pub use zero::*;
+
+use candle_core::{Tensor, WithDType};
+
+/// Converts a tensor with a single element to a scalar of type `F`
+fn to_scalar<F: WithDType>(t: &Tensor) -> F {
+    t.to_dtype(F::DTYPE)
+        .and_then(|t| t.to_scalar::<F>())
+        .expect("candle: failed to convert tensor to scalar")
+}
+
+#[cfg(test)]
+mod tests {
+    use candle_core::{Device, Tensor};
+
+    /// Tensor of type `f64` on the CPU
+    pub(crate) fn tensor(data: &[f64]) -> Tensor {
+        Tensor::new(data, &Device::Cpu).unwrap()
+    }
+
+    /// Elements of a tensor of rank 1 as `f64`
+    pub(crate) fn to_vec(t: &Tensor) -> Vec<f64> {
+        t.to_dtype(candle_core::DType::F64)
+            .unwrap()
+            .to_vec1::<f64>()
+            .unwrap()
+    }
+}
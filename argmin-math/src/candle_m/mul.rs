@@ -0,0 +1,71 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminMul;
+use candle_core::Tensor;
+
+macro_rules! make_mul {
+    ($t:ty) => {
+        impl ArgminMul<$t, Tensor> for Tensor {
+            #[inline]
+            fn mul(&self, other: &$t) -> Tensor {
+                self.affine(f64::from(*other), 0.0)
+                    .expect("candle: failed to multiply by scalar")
+            }
+        }
+
+        impl ArgminMul<Tensor, Tensor> for $t {
+            #[inline]
+            fn mul(&self, other: &Tensor) -> Tensor {
+                other
+                    .affine(f64::from(*self), 0.0)
+                    .expect("candle: failed to multiply by scalar")
+            }
+        }
+    };
+}
+
+make_mul!(f32);
+make_mul!(f64);
+
+impl ArgminMul<Tensor, Tensor> for Tensor {
+    #[inline]
+    fn mul(&self, other: &Tensor) -> Tensor {
+        Tensor::mul(self, other).expect("candle: failed to multiply tensors")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::candle_m::tests::{tensor, to_vec};
+
+    #[test]
+    fn test_mul_tensor_scalar() {
+        let a = tensor(&[1.0, 2.0, 3.0]);
+        let res = <Tensor as ArgminMul<f64, Tensor>>::mul(&a, &2.0);
+        assert_eq!(to_vec(&res), vec![2.0, 4.0, 6.0]);
+        let res = <f32 as ArgminMul<Tensor, Tensor>>::mul(&2.0, &a);
+        assert_eq!(to_vec(&res), vec![2.0, 4.0, 6.0]);
+    }
+
+    #[test]
+    fn test_mul_tensor_tensor() {
+        let a = tensor(&[1.0, 2.0, 3.0]);
+        let b = tensor(&[4.0, 5.0, 6.0]);
+        let res = <Tensor as ArgminMul<Tensor, Tensor>>::mul(&a, &b);
+        assert_eq!(to_vec(&res), vec![4.0, 10.0, 18.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mul_tensor_tensor_panic() {
+        let a = tensor(&[1.0, 2.0, 3.0]);
+        let b = tensor(&[4.0, 5.0]);
+        <Tensor as ArgminMul<Tensor, Tensor>>::mul(&a, &b);
+    }
+}
@@ -0,0 +1,27 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+use crate::ArgminSignum;
+use candle_core::Tensor;
+
+impl ArgminSignum for Tensor {
+    #[inline]
+    fn signum(self) -> Self {
+        self.sign().expect("candle: failed to compute signum")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::candle_m::tests::{tensor, to_vec};
+
+    #[test]
+    fn test_signum() {
+        let a = tensor(&[-2.0, 0.0, 3.0]);
+        assert_eq!(to_vec(&a.signum()), vec![-1.0, 0.0, 1.0]);
+    }
+}
@@ -0,0 +1,71 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminSub;
+use candle_core::Tensor;
+
+macro_rules! make_sub {
+    ($t:ty) => {
+        impl ArgminSub<$t, Tensor> for Tensor {
+            #[inline]
+            fn sub(&self, other: &$t) -> Tensor {
+                self.affine(1.0, -f64::from(*other))
+                    .expect("candle: failed to subtract scalar")
+            }
+        }
+
+        impl ArgminSub<Tensor, Tensor> for $t {
+            #[inline]
+            fn sub(&self, other: &Tensor) -> Tensor {
+                other
+                    .affine(-1.0, f64::from(*self))
+                    .expect("candle: failed to subtract from scalar")
+            }
+        }
+    };
+}
+
+make_sub!(f32);
+make_sub!(f64);
+
+impl ArgminSub<Tensor, Tensor> for Tensor {
+    #[inline]
+    fn sub(&self, other: &Tensor) -> Tensor {
+        Tensor::sub(self, other).expect("candle: failed to subtract tensors")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::candle_m::tests::{tensor, to_vec};
+
+    #[test]
+    fn test_sub_tensor_scalar() {
+        let a = tensor(&[1.0, 2.0, 3.0]);
+        let res = <Tensor as ArgminSub<f64, Tensor>>::sub(&a, &2.0);
+        assert_eq!(to_vec(&res), vec![-1.0, 0.0, 1.0]);
+        let res = <f64 as ArgminSub<Tensor, Tensor>>::sub(&2.0, &a);
+        assert_eq!(to_vec(&res), vec![1.0, 0.0, -1.0]);
+    }
+
+    #[test]
+    fn test_sub_tensor_tensor() {
+        let a = tensor(&[4.0, 5.0, 6.0]);
+        let b = tensor(&[1.0, 2.0, 4.0]);
+        let res = <Tensor as ArgminSub<Tensor, Tensor>>::sub(&a, &b);
+        assert_eq!(to_vec(&res), vec![3.0, 3.0, 2.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sub_tensor_tensor_panic() {
+        let a = tensor(&[1.0, 2.0, 3.0]);
+        let b = tensor(&[4.0, 5.0]);
+        <Tensor as ArgminSub<Tensor, Tensor>>::sub(&a, &b);
+    }
+}
@@ -0,0 +1,40 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+use crate::ArgminTranspose;
+use candle_core::Tensor;
+
+/// Transposes a matrix; vectors are returned unchanged.
+impl ArgminTranspose<Tensor> for Tensor {
+    #[inline]
+    fn t(self) -> Tensor {
+        if self.rank() < 2 {
+            self
+        } else {
+            Tensor::t(&self).expect("candle: failed to transpose tensor")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::candle_m::tests::{tensor, to_vec};
+    use candle_core::Device;
+
+    #[test]
+    fn test_transpose() {
+        let m = Tensor::new(&[[1.0f64, 2.0], [3.0, 4.0]], &Device::Cpu).unwrap();
+        let res = <Tensor as ArgminTranspose<Tensor>>::t(m);
+        assert_eq!(
+            res.to_vec2::<f64>().unwrap(),
+            vec![vec![1.0, 3.0], vec![2.0, 4.0]]
+        );
+        let v = tensor(&[1.0, 2.0]);
+        let res = <Tensor as ArgminTranspose<Tensor>>::t(v);
+        assert_eq!(to_vec(&res), vec![1.0, 2.0]);
+    }
+}
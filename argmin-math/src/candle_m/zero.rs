@@ -0,0 +1,30 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+use crate::ArgminZeroLike;
+use candle_core::Tensor;
+
+impl ArgminZeroLike for Tensor {
+    #[inline]
+    fn zero_like(&self) -> Tensor {
+        self.zeros_like().expect("candle: failed to create tensor")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::candle_m::tests::{tensor, to_vec};
+    use candle_core::DType;
+
+    #[test]
+    fn test_zero_like() {
+        let a = tensor(&[1.0, 2.0]).to_dtype(DType::F32).unwrap();
+        let res = a.zero_like();
+        assert_eq!(res.dtype(), DType::F32);
+        assert_eq!(to_vec(&res), vec![0.0, 0.0]);
+    }
+}
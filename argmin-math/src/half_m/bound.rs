@@ -0,0 +1,80 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminBound;
+use half::{bf16, f16};
+
+macro_rules! make_bound {
+    ($t:ty) => {
+        impl ArgminBound for Vec<$t> {
+            fn valid_bounds(lower: &Self, upper: &Self) -> bool {
+                lower.len() == upper.len() && lower.iter().zip(upper.iter()).all(|(l, u)| l <= u)
+            }
+
+            fn within_bounds(&self, lower: &Self, upper: &Self) -> bool {
+                self.len() == lower.len()
+                    && self.len() == upper.len()
+                    && self
+                        .iter()
+                        .zip(lower.iter().zip(upper.iter()))
+                        .all(|(x, (l, u))| l <= x && x <= u)
+            }
+
+            fn clamp_to_bounds(&self, lower: &Self, upper: &Self) -> Self {
+                assert_eq!(self.len(), lower.len());
+                assert_eq!(self.len(), upper.len());
+
+                self.iter()
+                    .zip(lower.iter().zip(upper.iter()))
+                    .map(|(x, (l, u))| {
+                        if x < l {
+                            *l
+                        } else if x > u {
+                            *u
+                        } else {
+                            *x
+                        }
+                    })
+                    .collect()
+            }
+        }
+    };
+}
+
+make_bound!(f16);
+make_bound!(bf16);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_bound_vec_ $t>]() {
+                    let v = |x: [f32; 3]| -> Vec<$t> { x.into_iter().map(<$t>::from_f32).collect() };
+                    let lower = v([1.0, 2.0, 3.0]);
+                    let upper = v([4.0, 5.0, 6.0]);
+                    assert!(<Vec<$t> as ArgminBound>::valid_bounds(&lower, &upper));
+                    assert!(!<Vec<$t> as ArgminBound>::valid_bounds(&upper, &lower));
+                    assert!(!<Vec<$t> as ArgminBound>::valid_bounds(&vec![<$t>::NAN; 3], &upper));
+
+                    let inside = v([1.0, 3.0, 6.0]);
+                    let outside = v([0.0, 3.0, 7.0]);
+                    assert!(inside.within_bounds(&lower, &upper));
+                    assert!(!outside.within_bounds(&lower, &upper));
+                    assert_eq!(outside.clamp_to_bounds(&lower, &upper), inside);
+                }
+            }
+        };
+    }
+
+    make_test!(f16);
+    make_test!(bf16);
+}
@@ -0,0 +1,95 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminDot;
+use half::{bf16, f16};
+
+// Products of two half precision numbers are exact in `f32`, therefore only the summation
+// introduces rounding errors.
+macro_rules! make_dot {
+    ($t:ty) => {
+        impl ArgminDot<Vec<$t>, f32> for Vec<$t> {
+            #[inline]
+            fn dot(&self, other: &Vec<$t>) -> f32 {
+                self.iter()
+                    .zip(other.iter())
+                    .map(|(a, b)| a.to_f32() * b.to_f32())
+                    .sum()
+            }
+        }
+
+        impl ArgminDot<f32, Vec<$t>> for Vec<$t> {
+            #[inline]
+            fn dot(&self, other: &f32) -> Vec<$t> {
+                self.iter()
+                    .map(|a| <$t>::from_f32(a.to_f32() * other))
+                    .collect()
+            }
+        }
+
+        impl ArgminDot<Vec<$t>, Vec<$t>> for f32 {
+            #[inline]
+            fn dot(&self, other: &Vec<$t>) -> Vec<$t> {
+                other
+                    .iter()
+                    .map(|a| <$t>::from_f32(a.to_f32() * self))
+                    .collect()
+            }
+        }
+    };
+}
+
+make_dot!(f16);
+make_dot!(bf16);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_vec_vec_ $t>]() {
+                    let a: Vec<$t> = vec![1.0, 2.0, 3.0].into_iter().map(<$t>::from_f32).collect();
+                    let b: Vec<$t> = vec![4.0, 5.0, 6.0].into_iter().map(<$t>::from_f32).collect();
+                    let res: f32 = a.dot(&b);
+                    assert_relative_eq!(res, 32.0, epsilon = f32::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_vec_vec_accumulation_ $t>]() {
+                    // The sum exceeds the largest representable value of `f16` and the
+                    // precision of both `f16` and `bf16`.
+                    let a = vec![<$t>::from_f32(256.0); 1025];
+                    let res: f32 = a.dot(&a);
+                    assert_relative_eq!(res, 256.0 * 256.0 * 1025.0, epsilon = f32::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_vec_scalar_ $t>]() {
+                    let a: Vec<$t> = vec![1.0, 2.0, 3.0].into_iter().map(<$t>::from_f32).collect();
+                    let res: Vec<$t> = a.dot(&2.0f32);
+                    let res2: Vec<$t> = 2.0f32.dot(&a);
+                    for ((r, r2), t) in res.iter().zip(res2.iter()).zip([2.0, 4.0, 6.0]) {
+                        assert_relative_eq!(r.to_f32(), t, epsilon = f32::EPSILON);
+                        assert_relative_eq!(r2.to_f32(), t, epsilon = f32::EPSILON);
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f16);
+    make_test!(bf16);
+}
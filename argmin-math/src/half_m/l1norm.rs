@@ -0,0 +1,45 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminL1Norm;
+use half::{bf16, f16};
+
+macro_rules! make_l1norm {
+    ($t:ty) => {
+        impl ArgminL1Norm<f32> for Vec<$t> {
+            #[inline]
+            fn l1_norm(&self) -> f32 {
+                self.iter().map(|a| a.to_f32().abs()).sum()
+            }
+        }
+    };
+}
+
+make_l1norm!(f16);
+make_l1norm!(bf16);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_l1norm_ $t>]() {
+                    let a: Vec<$t> = vec![4.0, -3.0].into_iter().map(<$t>::from_f32).collect();
+                    assert_relative_eq!(a.l1_norm(), 7.0, epsilon = f32::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(f16);
+    make_test!(bf16);
+}
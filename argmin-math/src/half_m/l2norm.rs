@@ -0,0 +1,54 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminL2Norm;
+use half::{bf16, f16};
+
+macro_rules! make_norm {
+    ($t:ty) => {
+        impl ArgminL2Norm<f32> for Vec<$t> {
+            #[inline]
+            fn l2_norm(&self) -> f32 {
+                self.iter().map(|a| a.to_f32().powi(2)).sum::<f32>().sqrt()
+            }
+        }
+    };
+}
+
+make_norm!(f16);
+make_norm!(bf16);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_norm_ $t>]() {
+                    let a: Vec<$t> = vec![4.0, -3.0].into_iter().map(<$t>::from_f32).collect();
+                    assert_relative_eq!(a.l2_norm(), 5.0, epsilon = f32::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_norm_overflow_ $t>]() {
+                    // Squares are accumulated in `f32` and do not overflow `f16`.
+                    let a = vec![<$t>::from_f32(1024.0); 4];
+                    assert_relative_eq!(a.l2_norm(), 2048.0, epsilon = f32::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(f16);
+    make_test!(bf16);
+}
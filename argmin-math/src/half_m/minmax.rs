@@ -0,0 +1,63 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminMinMax;
+use half::{bf16, f16};
+
+macro_rules! make_minmax {
+    ($t:ty) => {
+        impl ArgminMinMax for Vec<$t> {
+            fn min(x: &Self, y: &Self) -> Self {
+                assert!(!x.is_empty());
+                assert_eq!(x.len(), y.len());
+
+                x.iter()
+                    .zip(y.iter())
+                    .map(|(a, b)| if a < b { *a } else { *b })
+                    .collect()
+            }
+
+            fn max(x: &Self, y: &Self) -> Self {
+                assert!(!x.is_empty());
+                assert_eq!(x.len(), y.len());
+
+                x.iter()
+                    .zip(y.iter())
+                    .map(|(a, b)| if a > b { *a } else { *b })
+                    .collect()
+            }
+        }
+    };
+}
+
+make_minmax!(f16);
+make_minmax!(bf16);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_minmax_vec_vec_ $t>]() {
+                    let a: Vec<$t> = vec![1.0, 4.0, 8.0].into_iter().map(<$t>::from_f32).collect();
+                    let b: Vec<$t> = vec![2.0, 3.0, 4.0].into_iter().map(<$t>::from_f32).collect();
+                    let target_max: Vec<$t> = vec![2.0, 4.0, 8.0].into_iter().map(<$t>::from_f32).collect();
+                    let target_min: Vec<$t> = vec![1.0, 3.0, 4.0].into_iter().map(<$t>::from_f32).collect();
+                    assert_eq!(<Vec<$t> as ArgminMinMax>::max(&a, &b), target_max);
+                    assert_eq!(<Vec<$t> as ArgminMinMax>::min(&a, &b), target_min);
+                }
+            }
+        };
+    }
+
+    make_test!(f16);
+    make_test!(bf16);
+}
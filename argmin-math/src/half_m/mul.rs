@@ -0,0 +1,110 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminMul;
+use half::{bf16, f16};
+
+// The scalar is an `f32`, which is the floating point type of the solver state when the
+// parameter vector is stored in half precision.
+macro_rules! make_mul {
+    ($t:ty) => {
+        impl ArgminMul<f32, Vec<$t>> for Vec<$t> {
+            #[inline]
+            fn mul(&self, other: &f32) -> Vec<$t> {
+                self.iter()
+                    .map(|a| <$t>::from_f32(a.to_f32() * other))
+                    .collect()
+            }
+        }
+
+        impl ArgminMul<Vec<$t>, Vec<$t>> for f32 {
+            #[inline]
+            fn mul(&self, other: &Vec<$t>) -> Vec<$t> {
+                other
+                    .iter()
+                    .map(|a| <$t>::from_f32(self * a.to_f32()))
+                    .collect()
+            }
+        }
+
+        impl ArgminMul<Vec<$t>, Vec<$t>> for Vec<$t> {
+            #[inline]
+            fn mul(&self, other: &Vec<$t>) -> Vec<$t> {
+                let n1 = self.len();
+                let n2 = other.len();
+                assert!(n1 > 0);
+                assert!(n2 > 0);
+                assert_eq!(n1, n2);
+                self.iter()
+                    .zip(other.iter())
+                    .map(|(a, b)| <$t>::from_f32(a.to_f32() * b.to_f32()))
+                    .collect()
+            }
+        }
+    };
+}
+
+make_mul!(f16);
+make_mul!(bf16);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_mul_vec_scalar_ $t>]() {
+                    let a: Vec<$t> = vec![1.0, 4.0, 8.0].into_iter().map(<$t>::from_f32).collect();
+                    let res = <Vec<$t> as ArgminMul<f32, Vec<$t>>>::mul(&a, &2.0);
+                    for (r, t) in res.iter().zip([2.0, 8.0, 16.0]) {
+                        assert_relative_eq!(r.to_f32(), t, epsilon = f32::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mul_scalar_vec_ $t>]() {
+                    let a: Vec<$t> = vec![1.0, 4.0, 8.0].into_iter().map(<$t>::from_f32).collect();
+                    let res = <f32 as ArgminMul<Vec<$t>, Vec<$t>>>::mul(&2.0, &a);
+                    for (r, t) in res.iter().zip([2.0, 8.0, 16.0]) {
+                        assert_relative_eq!(r.to_f32(), t, epsilon = f32::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_mul_vec_vec_ $t>]() {
+                    let a: Vec<$t> = vec![1.0, 4.0, 8.0].into_iter().map(<$t>::from_f32).collect();
+                    let b: Vec<$t> = vec![3.0, 2.0, 1.0].into_iter().map(<$t>::from_f32).collect();
+                    let res = <Vec<$t> as ArgminMul<Vec<$t>, Vec<$t>>>::mul(&a, &b);
+                    for (r, t) in res.iter().zip([3.0, 8.0, 8.0]) {
+                        assert_relative_eq!(r.to_f32(), t, epsilon = f32::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_mul_vec_vec_panic_ $t>]() {
+                    let a = vec![<$t>::ONE; 2];
+                    let b = vec![<$t>::ONE; 3];
+                    <Vec<$t> as ArgminMul<Vec<$t>, Vec<$t>>>::mul(&a, &b);
+                }
+            }
+        };
+    }
+
+    make_test!(f16);
+    make_test!(bf16);
+}
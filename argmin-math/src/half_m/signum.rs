@@ -0,0 +1,47 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminSignum;
+use half::{bf16, f16};
+
+macro_rules! make_signum {
+    ($t:ty) => {
+        impl ArgminSignum for Vec<$t> {
+            fn signum(mut self) -> Self {
+                for x in &mut self {
+                    *x = x.signum();
+                }
+                self
+            }
+        }
+    };
+}
+
+make_signum!(f16);
+make_signum!(bf16);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_signum_ $t>]() {
+                    let a: Vec<$t> = vec![3.0, -4.0, -8.0].into_iter().map(<$t>::from_f32).collect();
+                    let b: Vec<$t> = vec![1.0, -1.0, -1.0].into_iter().map(<$t>::from_f32).collect();
+                    assert_eq!(a.signum(), b);
+                }
+            }
+        };
+    }
+
+    make_test!(f16);
+    make_test!(bf16);
+}
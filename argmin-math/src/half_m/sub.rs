@@ -0,0 +1,110 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminSub;
+use half::{bf16, f16};
+
+// The scalar is an `f32`, which is the floating point type of the solver state when the
+// parameter vector is stored in half precision.
+macro_rules! make_sub {
+    ($t:ty) => {
+        impl ArgminSub<f32, Vec<$t>> for Vec<$t> {
+            #[inline]
+            fn sub(&self, other: &f32) -> Vec<$t> {
+                self.iter()
+                    .map(|a| <$t>::from_f32(a.to_f32() - other))
+                    .collect()
+            }
+        }
+
+        impl ArgminSub<Vec<$t>, Vec<$t>> for f32 {
+            #[inline]
+            fn sub(&self, other: &Vec<$t>) -> Vec<$t> {
+                other
+                    .iter()
+                    .map(|a| <$t>::from_f32(self - a.to_f32()))
+                    .collect()
+            }
+        }
+
+        impl ArgminSub<Vec<$t>, Vec<$t>> for Vec<$t> {
+            #[inline]
+            fn sub(&self, other: &Vec<$t>) -> Vec<$t> {
+                let n1 = self.len();
+                let n2 = other.len();
+                assert!(n1 > 0);
+                assert!(n2 > 0);
+                assert_eq!(n1, n2);
+                self.iter()
+                    .zip(other.iter())
+                    .map(|(a, b)| <$t>::from_f32(a.to_f32() - b.to_f32()))
+                    .collect()
+            }
+        }
+    };
+}
+
+make_sub!(f16);
+make_sub!(bf16);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_sub_vec_scalar_ $t>]() {
+                    let a: Vec<$t> = vec![3.0, 4.0, 8.0].into_iter().map(<$t>::from_f32).collect();
+                    let res = <Vec<$t> as ArgminSub<f32, Vec<$t>>>::sub(&a, &2.0);
+                    for (r, t) in res.iter().zip([1.0, 2.0, 6.0]) {
+                        assert_relative_eq!(r.to_f32(), t, epsilon = f32::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_sub_scalar_vec_ $t>]() {
+                    let a: Vec<$t> = vec![3.0, 4.0, 8.0].into_iter().map(<$t>::from_f32).collect();
+                    let res = <f32 as ArgminSub<Vec<$t>, Vec<$t>>>::sub(&2.0, &a);
+                    for (r, t) in res.iter().zip([-1.0, -2.0, -6.0]) {
+                        assert_relative_eq!(r.to_f32(), t, epsilon = f32::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_sub_vec_vec_ $t>]() {
+                    let a: Vec<$t> = vec![3.0, 4.0, 8.0].into_iter().map(<$t>::from_f32).collect();
+                    let b: Vec<$t> = vec![1.0, 2.0, 1.0].into_iter().map(<$t>::from_f32).collect();
+                    let res = <Vec<$t> as ArgminSub<Vec<$t>, Vec<$t>>>::sub(&a, &b);
+                    for (r, t) in res.iter().zip([2.0, 2.0, 7.0]) {
+                        assert_relative_eq!(r.to_f32(), t, epsilon = f32::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_sub_vec_vec_panic_ $t>]() {
+                    let a = vec![<$t>::ONE; 2];
+                    let b = vec![<$t>::ONE; 3];
+                    <Vec<$t> as ArgminSub<Vec<$t>, Vec<$t>>>::sub(&a, &b);
+                }
+            }
+        };
+    }
+
+    make_test!(f16);
+    make_test!(bf16);
+}
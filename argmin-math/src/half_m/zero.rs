@@ -0,0 +1,52 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminZero, ArgminZeroLike};
+use half::{bf16, f16};
+
+macro_rules! make_zero {
+    ($t:ty) => {
+        impl ArgminZero for $t {
+            #[inline]
+            fn zero() -> $t {
+                <$t>::ZERO
+            }
+        }
+
+        impl ArgminZeroLike for $t {
+            #[inline]
+            fn zero_like(&self) -> $t {
+                <$t>::ZERO
+            }
+        }
+    };
+}
+
+make_zero!(f16);
+make_zero!(bf16);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_zero_ $t>]() {
+                    assert_eq!(<$t as ArgminZero>::zero().to_f32().to_ne_bytes(), 0.0f32.to_ne_bytes());
+                    let a = vec![<$t>::ONE; 3].zero_like();
+                    assert_eq!(a, vec![<$t>::ZERO; 3]);
+                }
+            }
+        };
+    }
+
+    make_test!(f16);
+    make_test!(bf16);
+}
@@ -0,0 +1,97 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminDiv, Interval};
+use alloc::vec::Vec;
+use num_traits::Float;
+
+impl<F: Float> ArgminDiv<Interval<F>, Interval<F>> for Interval<F> {
+    #[inline]
+    fn div(&self, other: &Interval<F>) -> Interval<F> {
+        *self / *other
+    }
+}
+
+impl<F: Float> ArgminDiv<Interval<F>, Vec<Interval<F>>> for Vec<Interval<F>> {
+    #[inline]
+    fn div(&self, other: &Interval<F>) -> Vec<Interval<F>> {
+        self.iter().map(|a| *a / *other).collect()
+    }
+}
+
+impl<F: Float> ArgminDiv<Vec<Interval<F>>, Vec<Interval<F>>> for Interval<F> {
+    #[inline]
+    fn div(&self, other: &Vec<Interval<F>>) -> Vec<Interval<F>> {
+        other.iter().map(|a| *self / *a).collect()
+    }
+}
+
+impl<F: Float> ArgminDiv<Vec<Interval<F>>, Vec<Interval<F>>> for Vec<Interval<F>> {
+    #[inline]
+    fn div(&self, other: &Vec<Interval<F>>) -> Vec<Interval<F>> {
+        assert_eq!(self.len(), other.len());
+        self.iter()
+            .zip(other.iter())
+            .map(|(a, b)| *a / *b)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points(x: &[f64]) -> Vec<Interval<f64>> {
+        x.iter().map(|x| Interval::point(*x)).collect()
+    }
+
+    fn check(res: &[Interval<f64>], target: &[f64]) {
+        assert_eq!(res.len(), target.len());
+        for (r, t) in res.iter().zip(target.iter()) {
+            assert!(r.contains(*t), "{t} not in {r:?}");
+            assert!(r.width() < 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_vec_vec() {
+        let a = points(&[1.0, 2.0]);
+        let b = points(&[1.0, 2.0]);
+        check(
+            &<Vec<Interval<f64>> as ArgminDiv<_, _>>::div(&a, &b),
+            &[1.0, 1.0],
+        );
+        let c = points(&[2.0, 3.0]);
+        check(
+            &<Vec<Interval<f64>> as ArgminDiv<_, _>>::div(&c, &b),
+            &[2.0, 1.5],
+        );
+    }
+
+    #[test]
+    fn test_vec_scalar() {
+        let a = points(&[1.0, 2.0, 3.0]);
+        let s = Interval::point(0.5);
+        check(
+            &<Vec<Interval<f64>> as ArgminDiv<_, _>>::div(&a, &s),
+            &[2.0, 4.0, 6.0],
+        );
+        let b = points(&[1.0, 2.0, 3.0]);
+        check(
+            &<Interval<f64> as ArgminDiv<_, _>>::div(&s, &b),
+            &[0.5, 0.25, 0.16666666666666666],
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vec_vec_panic() {
+        let a = points(&[1.0, 2.0]);
+        let b = points(&[1.0]);
+        <Vec<Interval<f64>> as ArgminDiv<_, _>>::div(&a, &b);
+    }
+}
@@ -0,0 +1,58 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminDot, Interval};
+use alloc::vec::Vec;
+use num_traits::Float;
+
+impl<F: Float> ArgminDot<Vec<Interval<F>>, Interval<F>> for Vec<Interval<F>> {
+    #[inline]
+    fn dot(&self, other: &Vec<Interval<F>>) -> Interval<F> {
+        self.iter()
+            .zip(other.iter())
+            .fold(Interval::point(F::zero()), |acc, (a, b)| acc + *a * *b)
+    }
+}
+
+impl<F: Float> ArgminDot<Interval<F>, Vec<Interval<F>>> for Vec<Interval<F>> {
+    #[inline]
+    fn dot(&self, other: &Interval<F>) -> Vec<Interval<F>> {
+        self.iter().map(|a| *a * *other).collect()
+    }
+}
+
+impl<F: Float> ArgminDot<Vec<Interval<F>>, Vec<Interval<F>>> for Interval<F> {
+    #[inline]
+    fn dot(&self, other: &Vec<Interval<F>>) -> Vec<Interval<F>> {
+        other.iter().map(|a| *self * *a).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vec_vec() {
+        let a = vec![Interval::new(1.0, 2.0), Interval::new(-1.0, 1.0)];
+        let b = vec![Interval::point(3.0), Interval::new(2.0, 4.0)];
+        let res: Interval<f64> = a.dot(&b);
+        // [3, 6] + [-4, 4]
+        assert!(res.is_subset(&Interval::new(-1.0 - 1e-14, 10.0 + 1e-14)));
+        assert!(Interval::new(-1.0, 10.0).is_subset(&res));
+    }
+
+    #[test]
+    fn test_scalar() {
+        let a = vec![Interval::new(1.0, 2.0)];
+        let s = Interval::point(-2.0);
+        let res: Vec<Interval<f64>> = a.dot(&s);
+        assert!(Interval::new(-4.0, -2.0).is_subset(&res[0]));
+        let res: Vec<Interval<f64>> = s.dot(&a);
+        assert!(Interval::new(-4.0, -2.0).is_subset(&res[0]));
+    }
+}
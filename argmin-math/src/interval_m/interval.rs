@@ -0,0 +1,517 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use core::ops::{Add, Div, Mul, Neg, Sub};
+use num_traits::Float;
+
+/// Closed interval `[lo, hi]` of floating point numbers
+///
+/// Arithmetic is rounded outwards: the bounds of every result are moved away from the interval by
+/// at least one unit in the last place. The result therefore encloses the exact result of the
+/// operation applied to any points of the operands, which makes `Interval` suitable for verified
+/// bounds on the range of a function over a box. Results of `exp` and `ln` are widened by two
+/// units in the last place, assuming that the math library is accurate to one unit.
+///
+/// Dividing by an interval which contains zero yields the entire real line.
+///
+/// # Example
+///
+/// ```
+/// use argmin_math::Interval;
+///
+/// let x = Interval::new(-1.0f64, 2.0);
+/// // f(x) = x^2 - x over [-1, 2]; the exact range is [-0.25, 2]
+/// let y = x.sqr() - x;
+/// assert!(y.lo() <= -0.25 && y.hi() >= 2.0);
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Interval<F> {
+    lo: F,
+    hi: F,
+}
+
+/// Round `x` down by at least one unit in the last place
+#[inline]
+fn down<F: Float>(x: F) -> F {
+    if x.is_finite() {
+        x - x.abs() * F::epsilon() - F::min_positive_value()
+    } else {
+        x
+    }
+}
+
+/// Round `x` up by at least one unit in the last place
+#[inline]
+fn up<F: Float>(x: F) -> F {
+    if x.is_finite() {
+        x + x.abs() * F::epsilon() + F::min_positive_value()
+    } else {
+        x
+    }
+}
+
+/// Product of two bounds where zero times infinity is zero
+#[inline]
+fn mul_bound<F: Float>(a: F, b: F) -> F {
+    if a.is_zero() || b.is_zero() {
+        F::zero()
+    } else {
+        a * b
+    }
+}
+
+impl<F: Float> Interval<F> {
+    /// Construct the interval `[lo, hi]`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lo > hi` or if one of the bounds is NaN.
+    pub fn new(lo: F, hi: F) -> Self {
+        assert!(
+            lo <= hi,
+            "Interval: lower bound must not be larger than upper bound and bounds must not be NaN"
+        );
+        Interval { lo, hi }
+    }
+
+    /// Construct the degenerate interval `[x, x]`
+    pub fn point(x: F) -> Self {
+        Interval::new(x, x)
+    }
+
+    /// The entire real line `[-inf, inf]`
+    pub fn entire() -> Self {
+        Interval {
+            lo: F::neg_infinity(),
+            hi: F::infinity(),
+        }
+    }
+
+    /// Lower bound
+    pub fn lo(&self) -> F {
+        self.lo
+    }
+
+    /// Upper bound
+    pub fn hi(&self) -> F {
+        self.hi
+    }
+
+    /// Midpoint
+    pub fn mid(&self) -> F {
+        let two = F::one() + F::one();
+        self.lo / two + self.hi / two
+    }
+
+    /// Width `hi - lo`
+    pub fn width(&self) -> F {
+        self.hi - self.lo
+    }
+
+    /// Returns `true` if `x` lies in the interval
+    pub fn contains(&self, x: F) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    /// Returns `true` if `self` lies in `other`
+    pub fn is_subset(&self, other: &Self) -> bool {
+        other.lo <= self.lo && self.hi <= other.hi
+    }
+
+    /// Intersection of `self` and `other`, `None` if they are disjoint
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let lo = self.lo.max(other.lo);
+        let hi = self.hi.min(other.hi);
+        if lo <= hi {
+            Some(Interval { lo, hi })
+        } else {
+            None
+        }
+    }
+
+    /// Smallest interval which contains `self` and `other`
+    pub fn hull(&self, other: &Self) -> Self {
+        Interval {
+            lo: self.lo.min(other.lo),
+            hi: self.hi.max(other.hi),
+        }
+    }
+
+    /// Split the interval at its midpoint
+    pub fn bisect(&self) -> (Self, Self) {
+        let mid = self.mid();
+        (
+            Interval {
+                lo: self.lo,
+                hi: mid,
+            },
+            Interval {
+                lo: mid,
+                hi: self.hi,
+            },
+        )
+    }
+
+    /// Absolute value
+    pub fn abs(self) -> Self {
+        if self.lo >= F::zero() {
+            self
+        } else if self.hi <= F::zero() {
+            -self
+        } else {
+            Interval {
+                lo: F::zero(),
+                hi: (-self.lo).max(self.hi),
+            }
+        }
+    }
+
+    /// Square
+    ///
+    /// This is tighter than `x * x`, which treats both factors as independent.
+    pub fn sqr(self) -> Self {
+        let a = self.abs();
+        Interval {
+            lo: down(a.lo * a.lo).max(F::zero()),
+            hi: up(a.hi * a.hi),
+        }
+    }
+
+    /// Integer power
+    ///
+    /// Negative exponents are computed as `1 / x^(-n)`.
+    pub fn powi(self, n: i32) -> Self {
+        if n < 0 {
+            return Interval::point(F::one()) / self.powi(-n);
+        }
+        let n = n as u32;
+        if n == 0 {
+            Interval::point(F::one())
+        } else if n % 2 == 1 {
+            // odd powers are monotonically increasing
+            Interval {
+                lo: pow_point(self.lo, n).lo,
+                hi: pow_point(self.hi, n).hi,
+            }
+        } else {
+            let a = self.abs();
+            Interval {
+                lo: pow_point(a.lo, n).lo.max(F::zero()),
+                hi: pow_point(a.hi, n).hi,
+            }
+        }
+    }
+
+    /// Square root
+    ///
+    /// The part of the interval below zero is ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the interval lies entirely below zero.
+    pub fn sqrt(self) -> Self {
+        assert!(
+            self.hi >= F::zero(),
+            "Interval: square root of a negative interval"
+        );
+        Interval {
+            lo: down(self.lo.max(F::zero()).sqrt()).max(F::zero()),
+            hi: up(self.hi.sqrt()),
+        }
+    }
+
+    /// Exponential function
+    pub fn exp(self) -> Self {
+        Interval {
+            lo: down(down(self.lo.exp())).max(F::zero()),
+            hi: up(up(self.hi.exp())),
+        }
+    }
+
+    /// Natural logarithm
+    ///
+    /// The part of the interval below zero is ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the interval lies entirely below zero.
+    pub fn ln(self) -> Self {
+        assert!(
+            self.hi >= F::zero(),
+            "Interval: logarithm of a negative interval"
+        );
+        let lo = if self.lo > F::zero() {
+            down(down(self.lo.ln()))
+        } else {
+            F::neg_infinity()
+        };
+        Interval {
+            lo,
+            hi: up(up(self.hi.ln())),
+        }
+    }
+}
+
+/// Enclosure of `x^n` by binary exponentiation in interval arithmetic
+fn pow_point<F: Float>(x: F, mut n: u32) -> Interval<F> {
+    let mut base = Interval::point(x);
+    let mut out = Interval::point(F::one());
+    while n > 0 {
+        if n % 2 == 1 {
+            out = out * base;
+        }
+        base = base * base;
+        n /= 2;
+    }
+    out
+}
+
+impl<F: Float> From<F> for Interval<F> {
+    fn from(x: F) -> Self {
+        Interval::point(x)
+    }
+}
+
+impl<F: Float> Neg for Interval<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Interval {
+            lo: -self.hi,
+            hi: -self.lo,
+        }
+    }
+}
+
+impl<F: Float> Add for Interval<F> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Interval {
+            lo: down(self.lo + other.lo),
+            hi: up(self.hi + other.hi),
+        }
+    }
+}
+
+impl<F: Float> Sub for Interval<F> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Interval {
+            lo: down(self.lo - other.hi),
+            hi: up(self.hi - other.lo),
+        }
+    }
+}
+
+impl<F: Float> Mul for Interval<F> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let p = [
+            mul_bound(self.lo, other.lo),
+            mul_bound(self.lo, other.hi),
+            mul_bound(self.hi, other.lo),
+            mul_bound(self.hi, other.hi),
+        ];
+        let lo = p[1..].iter().fold(p[0], |acc, x| acc.min(*x));
+        let hi = p[1..].iter().fold(p[0], |acc, x| acc.max(*x));
+        Interval {
+            lo: down(lo),
+            hi: up(hi),
+        }
+    }
+}
+
+impl<F: Float> Div for Interval<F> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        if other.contains(F::zero()) {
+            return Interval::entire();
+        }
+        let p = [
+            self.lo / other.lo,
+            self.lo / other.hi,
+            self.hi / other.lo,
+            self.hi / other.hi,
+        ];
+        let lo = p[1..].iter().fold(p[0], |acc, x| acc.min(*x));
+        let hi = p[1..].iter().fold(p[0], |acc, x| acc.max(*x));
+        Interval {
+            lo: down(lo),
+            hi: up(hi),
+        }
+    }
+}
+
+/// Serialized as the tuple `(lo, hi)`.
+#[cfg(feature = "interval-serde")]
+impl<F: serde::Serialize> serde::Serialize for Interval<F> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        (&self.lo, &self.hi).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "interval-serde")]
+impl<'de, F: Float + serde::Deserialize<'de>> serde::Deserialize<'de> for Interval<F> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (lo, hi) = <(F, F)>::deserialize(deserializer)?;
+        if lo <= hi {
+            Ok(Interval { lo, hi })
+        } else {
+            Err(serde::de::Error::custom(
+                "Interval: lower bound must not be larger than upper bound",
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Check that `i` encloses `f` evaluated on a grid over `x`
+    fn check_enclosure(x: Interval<f64>, i: Interval<f64>, f: impl Fn(f64) -> f64) {
+        for k in 0..=100 {
+            let p = x.lo() + (x.hi() - x.lo()) * (k as f64) / 100.0;
+            let v = f(p);
+            assert!(i.contains(v), "{v} not in {i:?}");
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let a = Interval::new(-1.0f64, 2.0);
+        assert_eq!(a.lo().to_ne_bytes(), (-1.0f64).to_ne_bytes());
+        assert_eq!(a.hi().to_ne_bytes(), 2.0f64.to_ne_bytes());
+        assert_eq!(a.mid().to_ne_bytes(), 0.5f64.to_ne_bytes());
+        assert_eq!(a.width().to_ne_bytes(), 3.0f64.to_ne_bytes());
+        assert!(a.contains(0.0));
+        assert!(!a.contains(2.5));
+        assert_eq!(Interval::from(1.5f32), Interval::point(1.5f32));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_invalid() {
+        Interval::new(1.0, -1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_nan() {
+        Interval::new(f64::NAN, 1.0);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = Interval::new(0.0, 2.0);
+        let b = Interval::new(1.0, 3.0);
+        assert_eq!(a.intersection(&b), Some(Interval::new(1.0, 2.0)));
+        assert_eq!(a.intersection(&Interval::new(2.5, 3.0)), None);
+        assert_eq!(a.hull(&b), Interval::new(0.0, 3.0));
+        assert!(Interval::new(0.5, 1.0).is_subset(&a));
+        assert!(!b.is_subset(&a));
+        let (l, r) = a.bisect();
+        assert_eq!(l, Interval::new(0.0, 1.0));
+        assert_eq!(r, Interval::new(1.0, 2.0));
+    }
+
+    #[test]
+    fn test_arithmetic_encloses() {
+        let a = Interval::new(-1.5, 2.0);
+        let b = Interval::new(0.5, 3.0);
+        let c = Interval::new(-4.0, -0.1);
+        for (x, y) in [(a, b), (a, c), (b, c), (c, c), (b, b)] {
+            let s = x + y;
+            let d = x - y;
+            let m = x * y;
+            let q = x / y;
+            for i in 0..=10 {
+                for j in 0..=10 {
+                    let p = x.lo() + x.width() * (i as f64) / 10.0;
+                    let r = y.lo() + y.width() * (j as f64) / 10.0;
+                    assert!(s.contains(p + r));
+                    assert!(d.contains(p - r));
+                    assert!(m.contains(p * r));
+                    assert!(q.contains(p / r));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_outward_rounding() {
+        // 0.1 + 0.2 is not representable, the exact sum of the two doubles must be enclosed
+        let s = Interval::point(0.1) + Interval::point(0.2);
+        assert!(s.lo() < 0.1 + 0.2 && 0.1 + 0.2 < s.hi());
+        let p = Interval::point(1.0 / 3.0) * Interval::point(3.0);
+        assert!(p.lo() < 1.0 && 1.0 < p.hi());
+    }
+
+    #[test]
+    fn test_div_by_zero() {
+        let q = Interval::new(1.0, 2.0) / Interval::new(-1.0, 1.0);
+        assert_eq!(q, Interval::entire());
+    }
+
+    #[test]
+    fn test_dependency() {
+        // `sqr` is tighter than the product of independent factors
+        let x = Interval::new(-1.0, 2.0);
+        assert!(x.sqr().lo() >= 0.0);
+        assert!((x * x).lo() < 0.0);
+        check_enclosure(x, x.sqr(), |p| p * p);
+    }
+
+    #[test]
+    fn test_functions() {
+        let x = Interval::new(-2.0, 1.5);
+        check_enclosure(x, x.abs(), f64::abs);
+        check_enclosure(x, x.powi(3), |p| p.powi(3));
+        check_enclosure(x, x.powi(4), |p| p.powi(4));
+        assert!(x.powi(4).lo() >= 0.0);
+        check_enclosure(x, x.exp(), f64::exp);
+        assert_eq!(x.powi(0), Interval::point(1.0));
+        let y = Interval::new(0.5, 4.0);
+        check_enclosure(y, y.sqrt(), f64::sqrt);
+        check_enclosure(y, y.ln(), f64::ln);
+        check_enclosure(y, y.powi(-2), |p| p.powi(-2));
+        assert!(x.ln().lo().is_infinite() && x.ln().lo() < 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sqrt_negative() {
+        Interval::new(-2.0, -1.0).sqrt();
+    }
+
+    #[test]
+    fn test_overflow() {
+        let s = Interval::point(f64::MAX) + Interval::point(f64::MAX);
+        assert!(s.hi().is_infinite() && s.hi() > 0.0);
+    }
+
+    #[cfg(feature = "interval-serde")]
+    #[test]
+    fn test_serde() {
+        let a = Interval::new(-1.0, 2.5);
+        let json = serde_json::to_string(&a).unwrap();
+        assert_eq!(json, "[-1.0,2.5]");
+        let b: Interval<f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(a, b);
+        assert!(serde_json::from_str::<Interval<f64>>("[1.0,-1.0]").is_err());
+    }
+}
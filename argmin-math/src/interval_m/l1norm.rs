@@ -0,0 +1,31 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminL1Norm, Interval};
+use alloc::vec::Vec;
+use num_traits::Float;
+
+impl<F: Float> ArgminL1Norm<Interval<F>> for Vec<Interval<F>> {
+    #[inline]
+    fn l1_norm(&self) -> Interval<F> {
+        self.iter()
+            .fold(Interval::point(F::zero()), |acc, a| acc + a.abs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_l1norm() {
+        let a = vec![Interval::new(-2.0, 1.0), Interval::point(-3.0)];
+        let res: Interval<f64> = a.l1_norm();
+        assert!(Interval::new(3.0, 5.0).is_subset(&res));
+        assert!(res.width() < 2.0 + 1e-14);
+    }
+}
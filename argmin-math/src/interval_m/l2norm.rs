@@ -0,0 +1,32 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminL2Norm, Interval};
+use alloc::vec::Vec;
+use num_traits::Float;
+
+impl<F: Float> ArgminL2Norm<Interval<F>> for Vec<Interval<F>> {
+    #[inline]
+    fn l2_norm(&self) -> Interval<F> {
+        self.iter()
+            .fold(Interval::point(F::zero()), |acc, a| acc + a.sqr())
+            .sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_l2norm() {
+        let a = vec![Interval::new(-3.0, 3.0), Interval::point(4.0)];
+        let res: Interval<f64> = a.l2_norm();
+        assert!(Interval::new(4.0, 5.0).is_subset(&res));
+        assert!(res.width() < 1.0 + 1e-14);
+    }
+}
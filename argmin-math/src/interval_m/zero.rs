@@ -0,0 +1,35 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminZero, ArgminZeroLike, Interval};
+use num_traits::Float;
+
+impl<F: Float> ArgminZero for Interval<F> {
+    #[inline]
+    fn zero() -> Self {
+        Interval::point(F::zero())
+    }
+}
+
+impl<F: Float> ArgminZeroLike for Interval<F> {
+    #[inline]
+    fn zero_like(&self) -> Self {
+        Interval::point(F::zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zero() {
+        assert_eq!(<Interval<f64> as ArgminZero>::zero(), Interval::point(0.0));
+        let a = vec![Interval::new(1.0, 2.0); 3];
+        assert_eq!(a.zero_like(), vec![Interval::point(0.0); 3]);
+    }
+}
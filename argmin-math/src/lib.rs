@@ -34,9 +34,61 @@
 //!
 //! | Feature                | Default | Comment                                               |
 //! |------------------------|---------|-------------------------------------------------------|
+//! | `std`                  | yes     | use the standard library (see `no_std` section below) |
 //! | `primitives`           | yes     | basic integer and floating point types                |
 //! | `vec`                  | yes     | `Vec`s (basic functionality)                          |
 //!
+//! ### SIMD for `Vec`
+//!
+//! | Feature    | Default | Comment                                                          |
+//! |------------|---------|------------------------------------------------------------------|
+//! | `vec-simd` | no      | explicit SIMD for `Vec<f32>` and `Vec<f64>` via the `wide` crate |
+//!
+//! Dot products, norms, the addition and subtraction of two vectors and the multiplication with a
+//! scalar dominate the runtime of many solvers on cheap cost functions. With `vec-simd` these
+//! operations process `Vec<f32>` and `Vec<f64>` in chunks of 8 and 4 lanes, respectively, instead
+//! of relying on the auto-vectorizer (which does not reorder floating point sums). `ArgminScaledAdd`
+//! and `ArgminScaledSub` benefit as well since they are built from these operations. Reductions
+//! keep one partial sum per lane, therefore dot products and norms may differ from the sequential
+//! sum in the last bits. The feature is also available in `no_std` environments.
+//!
+//! ### Compensated summation for `Vec`
+//!
+//! | Feature           | Default | Comment                                                      |
+//! |-------------------|---------|--------------------------------------------------------------|
+//! | `vec-compensated` | no      | compensated dot products and norms of `Vec<f32>`, `Vec<f64>` |
+//!
+//! The rounding error of a naively accumulated sum grows with the number of summands. For very
+//! long vectors, dot products and norms may therefore be too inaccurate to decide whether a
+//! line search step decreases the cost function, which shows up as spurious line search
+//! failures. With `vec-compensated`, dot products, l1- and l2-norms of `Vec<f32>` and `Vec<f64>`
+//! are accumulated with Neumaier's variant of Kahan summation, whose error is bounded by a few
+//! ulps independent of the length, at the cost of about four additional floating point
+//! operations per element (`Vec<f32>` is accumulated in `f64`). If `vec-simd` is enabled as well, the compensated reductions replace
+//! the SIMD ones; all other operations keep using SIMD. The feature is also available in
+//! `no_std` environments.
+//!
+//! ### Mixed precision for `Vec`
+//!
+//! | Feature     | Default | Comment                                                        |
+//! |-------------|---------|----------------------------------------------------------------|
+//! | `vec-mixed` | no      | `Vec<f32>` parameter vectors with an `f64` floating point type |
+//!
+//! For large problems, storing parameter vectors and gradients in `f32` halves the memory
+//! footprint and bandwidth compared to `f64`. Accumulating dot products and norms in `f32`
+//! however loses accuracy quickly with growing dimension, which may stall line searches and
+//! quasi-Newton updates. With `vec-mixed`, `Vec<f32>` additionally implements the traits with
+//! `f64` as floating point type: dot products and norms are accumulated in `f64` with compensated
+//! summation and element-wise operations with `f64` scalars are computed in `f64` and rounded to
+//! `f32` once. The switch is the floating point type of the solver: `LBFGS<_, Vec<f32>, Vec<f32>,
+//! f32>` keeps the (faster) pure `f32` arithmetic, `LBFGS<_, Vec<f32>, Vec<f32>, f64>` uses mixed
+//! precision. The reductions then cost a few more floating point operations per element, which
+//! mostly hides behind the memory traffic.
+//!
+//! Note that with this feature `Vec<f32>` implements `ArgminDot`, `ArgminL1Norm` and
+//! `ArgminL2Norm` for two output types, therefore calls like `x.l2_norm()` may need a type
+//! annotation. The feature is also available in `no_std` environments.
+//!
 //! ### `ndarray`
 //!
 //! | Feature                         | Default | Comment                                                            |
@@ -73,6 +125,28 @@
 //! ndarray-linalg = { version = "<appropriate_version>", features = ["<linalg_backend>"] }
 //! ```
 //!
+//! For `f32` and `f64`, dot products, norms, additions, subtractions, multiplications with a
+//! scalar and scaled additions/subtractions are also implemented for borrowed `ArrayView1`s. The
+//! results are owned `Array1`s. This allows computing on parts of arrays without copying them
+//! first:
+//!
+//! ```
+//! # #[cfg(feature = "ndarray_0_15")]
+//! # {
+//! # extern crate ndarray_0_15 as ndarray;
+//! use argmin_math::{ArgminDot, ArgminScaledAdd};
+//! use ndarray::{array, s, Array1, ArrayView1};
+//!
+//! let x = array![1.0, 2.0, 3.0, 4.0];
+//! let head: ArrayView1<f64> = x.slice(s![..2]);
+//! let tail: ArrayView1<f64> = x.slice(s![2..]);
+//! let d = <ArrayView1<f64> as ArgminDot<ArrayView1<f64>, f64>>::dot(&head, &tail);
+//! assert_eq!(d, 11.0);
+//! let y: Array1<f64> = array![1.0, 1.0].scaled_add(&2.0, &tail);
+//! assert_eq!(y, array![7.0, 9.0]);
+//! # }
+//! ```
+//!
 //! ### `nalgebra`
 //!
 //! | Feature                | Default | Comment                                  |
@@ -88,6 +162,135 @@
 //! | `nalgebra_v0_29`       | no      | version 0.29                             |
 //! | `nalgebra_v0_29-serde` | no      | version 0.29 + serde support             |
 //!
+//! Besides dynamically sized matrices (`DVector`, `DMatrix`), all traits are implemented for
+//! statically sized matrices (`SVector<f64, N>`, `SMatrix<f64, R, C>` and aliases such as
+//! `Vector3`). Their entries are stored on the stack, therefore the math operations on small
+//! fixed-size problems do not allocate on the heap.
+//!
+//! Sparse matrices (`CsrMatrix` and `CscMatrix` of `nalgebra-sparse`) are supported with
+//! `nalgebra` 0.32. This allows sparse Hessians and Jacobians to be used with solvers which only
+//! require matrix-vector products, such as Newton-CG and the trust region method with the
+//! Steihaug subproblem solver. Sparse matrices implement the products with dense vectors and
+//! matrices (`ArgminDot`), addition and subtraction of sparse matrices (`ArgminAdd`, `ArgminSub`,
+//! `ArgminScaledAdd`, `ArgminScaledSub`), scaling (`ArgminMul`), the Frobenius norm
+//! (`ArgminL2Norm`) and `ArgminTranspose`. Operations which would densify the matrix, such as
+//! adding a scalar, are not implemented.
+//!
+//! | Feature                              | Default | Comment                                  |
+//! |--------------------------------------|---------|------------------------------------------|
+//! | `nalgebra_latest-sparse`             | no      | latest supported version                 |
+//! | `nalgebra_latest-sparse-serde`       | no      | latest supported version + serde support |
+//! | `nalgebra_v0_32-sparse`              | no      | `nalgebra` 0.32, `nalgebra-sparse` 0.9   |
+//! | `nalgebra_v0_32-sparse-serde`        | no      | `nalgebra` 0.32, `nalgebra-sparse` 0.9 + serde support |
+//!
+//! ### Half precision floats
+//!
+//! Parameter vectors of type `Vec<f16>` and `Vec<bf16>` (from the `half` crate) halve the memory
+//! footprint compared to `Vec<f32>`. The floating point type of the solver is `f32` in this
+//! case: dot products and norms are accumulated in `f32` and scalars passed to element-wise
+//! operations are `f32` as well. The result of every element-wise operation is rounded to half
+//! precision, which limits the achievable accuracy to roughly three (`f16`) or two (`bf16`)
+//! significant digits.
+//!
+//! | Feature             | Default | Comment                                  |
+//! |---------------------|---------|------------------------------------------|
+//! | `half_latest`       | no      | latest supported version                 |
+//! | `half_latest-serde` | no      | latest supported version + serde support |
+//! | `half_v2`           | no      | version 2                                |
+//! | `half_v2-serde`     | no      | version 2 + serde support                |
+//!
+//! ### Extended precision floats
+//!
+//! Parameter vectors of type `Vec<BigFloat>` (from the `num-bigfloat` crate) together with
+//! `BigFloat` as the floating point type of the solver carry out all computations with 40
+//! significant decimal digits (between 129 and 130 bits, `num_bigfloat::EPSILON` is `1e-39`).
+//! This is useful for ill-conditioned problems and for validating results obtained in `f64`, at
+//! the cost of a much slower arithmetic. Using `BigFloat` as floating point type requires the
+//! `num-bigfloat` feature of `argmin`.
+//!
+//! Note that `BigFloat` is not an arbitrary precision type: the precision is fixed by the
+//! `num-bigfloat` crate and can neither be increased nor lowered. Results can therefore not be
+//! validated beyond 40 significant digits, and tolerances below `1e-39` relative to the magnitude
+//! of the values are never reached.
+//!
+//! | Feature                     | Default | Comment                                  |
+//! |-----------------------------|---------|------------------------------------------|
+//! | `num-bigfloat_latest`       | no      | latest supported version                 |
+//! | `num-bigfloat_latest-serde` | no      | latest supported version + serde support |
+//! | `num-bigfloat_v1`           | no      | version 1                                |
+//! | `num-bigfloat_v1-serde`     | no      | version 1 + serde support                |
+//!
+//! ### Dual numbers
+//!
+//! Parameter vectors of type `Vec<Dual<f32>>` and `Vec<Dual<f64>>` (from the `num-dual` crate)
+//! with a dual number as scalar propagate first derivatives through all operations
+//! (forward-mode automatic differentiation). This allows writing cost functions generic over the
+//! scalar type in terms of the math traits and evaluating their directional derivatives. The
+//! `ForwardDiff` adapter of argmin (behind its `num-dual` feature) uses this to derive gradients
+//! and Hessians automatically.
+//!
+//! | Feature                 | Default | Comment                                  |
+//! |-------------------------|---------|------------------------------------------|
+//! | `num-dual_latest`       | no      | latest supported version                 |
+//! | `num-dual_latest-serde` | no      | latest supported version + serde support |
+//! | `num-dual_v0_15`        | no      | version 0.15                             |
+//! | `num-dual_v0_15-serde`  | no      | version 0.15 + serde support             |
+//!
+//! ### Interval arithmetic
+//!
+//! `Interval<F>` is a closed interval of `f32` or `f64` with outward rounded arithmetic, its
+//! operations therefore produce verified enclosures of the exact results. `Vec<Interval<F>>`
+//! (a box) implements `ArgminAdd`, `ArgminSub`, `ArgminMul`, `ArgminDiv` (element-wise and with
+//! an interval scalar), `ArgminDot`, `ArgminL1Norm`, `ArgminL2Norm` and `ArgminZeroLike`, with
+//! intervals as results of the reductions. A cost function with `Vec<Interval<F>>` as parameter
+//! vector and `Interval<F>` as output thus yields rigorous bounds on the range of the function
+//! over a box, which is the building block of interval branch-and-bound methods (see the
+//! `interval_branch_and_bound` example of argmin).
+//!
+//! | Feature          | Default | Comment                         |
+//! |------------------|---------|---------------------------------|
+//! | `interval`       | no      | `Interval` type                 |
+//! | `interval-serde` | no      | `Interval` type + serde support |
+//!
+//! The `interval` feature is also available in `no_std` environments.
+//!
+//! ### GPU
+//!
+//! `WgpuVector` is a vector of `f32` which resides in GPU memory. Element-wise operations
+//! (`ArgminAdd`, `ArgminSub`, `ArgminMul`, `ArgminDiv` with vectors and scalars), the fused
+//! `ArgminScaledAdd`/`ArgminScaledSub` (axpy), `ArgminDot`, `ArgminL1Norm` and `ArgminL2Norm`
+//! are computed by compute shaders via `wgpu`. This allows first-order solvers (for instance
+//! steepest descent or nonlinear conjugate gradient with a line search) to keep the parameter
+//! vector and the gradient on the GPU. Only the scalars produced by dot products and norms are
+//! transferred back to the host, which makes the backend worthwhile for very large problems.
+//!
+//! Vectors are created on a `WgpuContext`, which holds the device and the compiled kernels.
+//! Deserialized vectors are placed on `WgpuContext::shared()`. Any adapter supported by `wgpu`
+//! (Vulkan, Metal, DX12 or OpenGL with compute shaders) can be used.
+//!
+//! | Feature             | Default | Comment                                  |
+//! |---------------------|---------|------------------------------------------|
+//! | `wgpu_latest`       | no      | latest supported version                 |
+//! | `wgpu_latest-serde` | no      | latest supported version + serde support |
+//! | `wgpu_v24`          | no      | version 24                               |
+//! | `wgpu_v24-serde`    | no      | version 24 + serde support               |
+//!
+//! ### candle
+//!
+//! `candle_core::Tensor` of dtype `f32` or `f64` can be used as parameter vector, which allows
+//! the weights of a neural network to be optimized directly with gradient-based solvers such as
+//! L-BFGS, nonlinear conjugate gradient or trust region methods. Dot products and norms return
+//! `f32` or `f64`, tensors of rank 2 act as matrices in `ArgminDot`, `ArgminTranspose` and
+//! `ArgminEye`. Operations are executed on the device of the tensors, failures of candle (for
+//! instance mismatching shapes) result in a panic.
+//!
+//! `Tensor` does not implement the serde traits, therefore argmin has to be used without its
+//! `serde1` feature (`default-features = false, features = ["std"]`).
+//!
+//! | Feature         | Default | Comment                  |
+//! |-----------------|---------|--------------------------|
+//! | `candle_latest` | no      | latest supported version |
+//! | `candle_v0_11`  | no      | version 0.11             |
 //!
 //! ## Choosing a backend
 //!
@@ -105,6 +308,13 @@
 //! trait definitions. If another backend is chosen, `primitives` will automatically be turned on
 //! again.
 //!
+//! ### `no_std`
+//!
+//! Without the `std` feature the crate only depends on `core` and `alloc`. This is supported for
+//! `primitives`, `vec` and the `nalgebra_*` features (without `*-sparse`). The `ArgminRandom`
+//! implementations require `std` and are not available in this case. All other backends
+//! automatically enable `std`.
+//!
 //! ### Example
 //!
 //! Activate support for the latest supported `ndarray` version:
@@ -114,6 +324,56 @@
 #![doc = concat!("argmin-math = { version = \"", env!("CARGO_PKG_VERSION"), "\", features = [\"ndarray_latest-serde\"] }")]
 //! ```
 //!
+//! # Complex parameter vectors
+//!
+//! Parameter vectors of type `Vec<Complex<f32>>` and `Vec<Complex<f64>>` can be optimized with
+//! solvers which use a real floating point type (`f32` and `f64`, respectively). For this
+//! purpose, complex vectors are treated like real vectors of twice the length which hold the real
+//! and imaginary parts:
+//!
+//! * `ArgminDot` with a real output computes the real inner product `Re(sum_i conj(a_i) b_i)`.
+//! * Gradients of a real-valued cost function `f(z)` must be provided in Wirtinger form, that is
+//!   `2 df/dconj(z) = df/dx + i df/dy` for `z = x + iy`.
+//! * `ArgminMinMax`, `ArgminSignum` and `ArgminL1Norm` act on real and imaginary parts
+//!   separately.
+//!
+//! With this convention the directional derivative of `f` at `z` in direction `h` is the real
+//! inner product of gradient and direction, which is what line searches and quasi-Newton updates
+//! rely on. The gradient `df/dz`, which for real-valued `f` is the complex conjugate of
+//! `df/dconj(z)`, does not have this property unless it is real.
+//!
+//! ```
+//! # #[cfg(feature = "vec")]
+//! # {
+//! # extern crate num_complex_0_4 as num_complex;
+//! use argmin_math::ArgminDot;
+//! use num_complex::Complex;
+//!
+//! // f(z) = sum_i |z_i|^4 = sum_i (z_i conj(z_i))^2, therefore 2 df/dconj(z_i) = 4 |z_i|^2 z_i
+//! let f = |z: &[Complex<f64>]| z.iter().map(|z| z.norm_sqr().powi(2)).sum::<f64>();
+//! let z = vec![Complex::new(1.0, 2.0), Complex::new(-0.5, 0.3)];
+//! let grad: Vec<Complex<f64>> = z.iter().map(|z| 4.0 * z.norm_sqr() * z).collect();
+//!
+//! let h = vec![Complex::new(0.3, -0.7), Complex::new(1.1, 0.2)];
+//! let t = 1e-6;
+//! let z_plus: Vec<_> = z.iter().zip(h.iter()).map(|(z, h)| z + t * h).collect();
+//! let z_minus: Vec<_> = z.iter().zip(h.iter()).map(|(z, h)| z - t * h).collect();
+//! let directional_derivative = (f(&z_plus) - f(&z_minus)) / (2.0 * t);
+//!
+//! let dot: f64 = grad.dot(&h);
+//! assert!((dot - directional_derivative).abs() < 1e-6);
+//!
+//! // The conjugate convention does not give the directional derivative
+//! let grad_conj: Vec<Complex<f64>> = grad.iter().map(|g| g.conj()).collect();
+//! let dot_conj: f64 = grad_conj.dot(&h);
+//! assert!((dot_conj - directional_derivative).abs() > 1.0);
+//! # }
+//! ```
+//!
+//! Note that `ArgminMul` of two complex vectors is the element-wise complex product, which does
+//! not fit this interpretation. Solvers relying on it in a real sense, such as L1 regularization
+//! in L-BFGS (OWL-QN), are therefore not meaningful for complex parameter vectors.
+//!
 //! # Semantic versioning
 //!
 //! This crate follows semantic versioning. Adding a new backend or a new version of a backend is
@@ -162,11 +422,14 @@
 //! in the work by you, as defined in the Apache-2.0 license, shall be dual licensed as above,
 //! without any additional terms or conditions.
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![warn(missing_docs)]
 // Explicitly disallow EQ comparison of floats. (This clippy lint is denied by default; however,
 // this is just to make sure that it will always stay this way.)
 #![deny(clippy::float_cmp)]
 
+extern crate alloc;
+
 cfg_if::cfg_if! {
     if #[cfg(feature = "nalgebra_0_32")] {
         extern crate nalgebra_0_32 as nalgebra;
@@ -179,6 +442,23 @@ cfg_if::cfg_if! {
     }
 }
 
+#[cfg(feature = "nalgebra-sparse_0_9")]
+extern crate nalgebra_sparse_0_9 as nalgebra_sparse;
+
+#[cfg(feature = "half_2")]
+extern crate half_2 as half;
+#[cfg(feature = "num-dual_0_15")]
+extern crate num_dual_0_15 as num_dual;
+
+#[cfg(feature = "num-bigfloat_1")]
+extern crate num_bigfloat_1 as num_bigfloat;
+
+#[cfg(feature = "wgpu_24")]
+extern crate wgpu_24 as wgpu;
+
+#[cfg(feature = "candle-core_0_11")]
+extern crate candle_core_0_11 as candle_core;
+
 cfg_if::cfg_if! {
     if #[cfg(feature = "ndarray_0_15")] {
         extern crate ndarray_0_15 as ndarray;
@@ -224,12 +504,48 @@ mod nalgebra_m;
 #[cfg(feature = "nalgebra_all")]
 pub use crate::nalgebra_m::*;
 
+#[cfg(feature = "nalgebra-sparse_0_9")]
+mod nalgebra_sparse_m;
+#[cfg(feature = "nalgebra-sparse_0_9")]
+pub use crate::nalgebra_sparse_m::*;
+
+#[cfg(feature = "half_2")]
+mod half_m;
+#[cfg(feature = "half_2")]
+pub use crate::half_m::*;
+
+#[cfg(feature = "num-bigfloat_1")]
+mod num_bigfloat_m;
+#[cfg(feature = "num-bigfloat_1")]
+pub use crate::num_bigfloat_m::*;
+
+#[cfg(feature = "num-dual_0_15")]
+mod num_dual_m;
+#[cfg(feature = "num-dual_0_15")]
+pub use crate::num_dual_m::*;
+
+#[cfg(feature = "interval")]
+mod interval_m;
+#[cfg(feature = "interval")]
+pub use crate::interval_m::*;
+
+#[cfg(feature = "wgpu_24")]
+mod wgpu_m;
+#[cfg(feature = "wgpu_24")]
+pub use crate::wgpu_m::*;
+
+#[cfg(feature = "candle-core_0_11")]
+mod candle_m;
+#[cfg(feature = "candle-core_0_11")]
+pub use crate::candle_m::*;
+
 #[cfg(feature = "vec")]
 mod vec;
 #[cfg(feature = "vec")]
 pub use crate::vec::*;
 
 use anyhow::Error;
+use rand::Rng;
 
 /// Dot/scalar product of `T` and `self`
 pub trait ArgminDot<T, U> {
@@ -320,6 +636,61 @@ pub trait ArgminL2Norm<U> {
     fn l2_norm(&self) -> U;
 }
 
+/// Linear operator acting on `P`, defined by its action on a vector
+///
+/// This allows using matrices which are only available as routines computing matrix-vector
+/// products (for instance Hessians or Jacobians of large problems) with solvers which only need
+/// such products. All types which implement `ArgminDot<P, P>`, `ArgminTranspose` and `Clone`
+/// (i.e. the matrix types of all backends) implement this trait.
+///
+/// Computing a product may fail (for instance if it requires evaluating a user-provided function),
+/// in which case the error is returned to the solver.
+///
+/// # Example
+///
+/// ```
+/// use anyhow::Error;
+/// use argmin_math::LinearOperator;
+///
+/// /// Second-order finite difference operator (negative 1D Laplacian)
+/// struct Laplacian {}
+///
+/// impl LinearOperator<Vec<f64>> for Laplacian {
+///     fn apply(&self, x: &Vec<f64>) -> Result<Vec<f64>, Error> {
+///         let n = x.len();
+///         Ok((0..n)
+///             .map(|i| {
+///                 let left = if i > 0 { x[i - 1] } else { 0.0 };
+///                 let right = if i + 1 < n { x[i + 1] } else { 0.0 };
+///                 2.0 * x[i] - left - right
+///             })
+///             .collect())
+///     }
+///
+///     fn apply_transpose(&self, x: &Vec<f64>) -> Result<Vec<f64>, Error> {
+///         // symmetric
+///         self.apply(x)
+///     }
+/// }
+///
+/// assert_eq!(Laplacian {}.apply(&vec![1.0, 2.0, 3.0]).unwrap(), vec![0.0, 0.0, 4.0]);
+/// ```
+pub trait LinearOperator<P> {
+    /// Compute the product of the operator with `x`
+    fn apply(&self, x: &P) -> Result<P, Error>;
+    /// Compute the product of the transposed operator with `x`
+    fn apply_transpose(&self, x: &P) -> Result<P, Error>;
+}
+
+/// Compute the l2-norm (`U`) of `self` weighted element-wise by `T` (`sqrt(sum_i w_i x_i^2)`)
+///
+/// Weighted norms measure variables with different scales in a common metric, for instance with
+/// `w_i = 1 / s_i^2` where `s_i` is the typical magnitude of the i-th variable.
+pub trait ArgminWeightedL2Norm<T, U> {
+    /// Compute the weighted l2-norm (`U`) of `self` with weights `w`
+    fn weighted_l2_norm(&self, w: &T) -> U;
+}
+
 // Sub-optimal: self is moved. ndarray however offers array views...
 /// Return the transpose (`U`) of `self`
 pub trait ArgminTranspose<U> {
@@ -333,10 +704,35 @@ pub trait ArgminInv<T> {
     fn inv(&self) -> Result<T, Error>;
 }
 
+/// Solve the linear system `self * x = b` for `x` (`T`) via a Cholesky factorization of `self`
+///
+/// `self` must be a symmetric and positive definite square matrix.
+pub trait ArgminCholeskySolve<T> {
+    /// Solve `self * x = b`
+    fn cholesky_solve(&self, b: &T) -> Result<T, Error>;
+}
+
+/// Solve the linear system `self * x = b` for `x` (`T`) via an LU factorization of `self` with
+/// partial pivoting
+///
+/// `self` must be a non-singular square matrix.
+pub trait ArgminLuSolve<T> {
+    /// Solve `self * x = b`
+    fn lu_solve(&self, b: &T) -> Result<T, Error>;
+}
+
+/// Solve the linear system `self * x = b` for `x` (`T`) via a QR factorization of `self`
+///
+/// `self` must be a non-singular square matrix.
+pub trait ArgminQrSolve<T> {
+    /// Solve `self * x = b`
+    fn qr_solve(&self, b: &T) -> Result<T, Error>;
+}
+
 /// Create a random number
 pub trait ArgminRandom {
-    /// Get a random element between min and max,
-    fn rand_from_range(min: &Self, max: &Self) -> Self;
+    /// Get a random element between min and max, drawn from `rng`
+    fn rand_from_range<R: Rng>(min: &Self, max: &Self, rng: &mut R) -> Self;
 }
 
 /// Minimum and Maximum of type `T`
@@ -347,6 +743,46 @@ pub trait ArgminMinMax {
     fn max(x: &Self, y: &Self) -> Self;
 }
 
+/// Element-wise lower and upper bounds
+pub trait ArgminBound {
+    /// Returns `true` if `lower` and `upper` have the same shape and `lower <= upper` holds
+    /// element-wise
+    fn valid_bounds(lower: &Self, upper: &Self) -> bool;
+    /// Returns `true` if `self` has the shape of the bounds and every element lies within
+    /// `[lower, upper]`
+    fn within_bounds(&self, lower: &Self, upper: &Self) -> bool;
+    /// Clamp every element of `self` to `[lower, upper]`
+    #[must_use]
+    fn clamp_to_bounds(&self, lower: &Self, upper: &Self) -> Self;
+}
+
+/// Projection onto a closed convex set
+///
+/// Implementations are consumed by the projection-based solvers of argmin (projected gradient,
+/// Frank-Wolfe and mirror descent).
+///
+/// # Example
+///
+/// ```
+/// use anyhow::Error;
+/// use argmin_math::Projection;
+///
+/// /// Projection onto the nonnegative orthant
+/// struct Nonnegative {}
+///
+/// impl Projection<Vec<f64>> for Nonnegative {
+///     fn project(&self, param: &Vec<f64>) -> Result<Vec<f64>, Error> {
+///         Ok(param.iter().map(|x| x.max(0.0)).collect())
+///     }
+/// }
+///
+/// assert_eq!(Nonnegative {}.project(&vec![-1.0, 2.0]).unwrap(), vec![0.0, 2.0]);
+/// ```
+pub trait Projection<P> {
+    /// Returns the point of the set closest to `param`
+    fn project(&self, param: &P) -> Result<P, Error>;
+}
+
 /// Returns a number that represents the sign of `self`.
 pub trait ArgminSignum {
     /// Returns a number that represents the sign of `self`.
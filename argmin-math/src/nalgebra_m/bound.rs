@@ -0,0 +1,123 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminBound;
+
+use nalgebra::{
+    base::{allocator::Allocator, dimension::Dim, Scalar},
+    DefaultAllocator, OMatrix,
+};
+
+impl<N, R, C> ArgminBound for OMatrix<N, R, C>
+where
+    N: Scalar + Copy + PartialOrd,
+    R: Dim,
+    C: Dim,
+    DefaultAllocator: Allocator<N, R, C>,
+{
+    #[inline]
+    fn valid_bounds(lower: &OMatrix<N, R, C>, upper: &OMatrix<N, R, C>) -> bool {
+        lower.shape() == upper.shape() && lower.iter().zip(upper.iter()).all(|(l, u)| l <= u)
+    }
+
+    #[inline]
+    fn within_bounds(&self, lower: &OMatrix<N, R, C>, upper: &OMatrix<N, R, C>) -> bool {
+        self.shape() == lower.shape()
+            && self.shape() == upper.shape()
+            && self
+                .iter()
+                .zip(lower.iter().zip(upper.iter()))
+                .all(|(x, (l, u))| l <= x && x <= u)
+    }
+
+    #[inline]
+    fn clamp_to_bounds(
+        &self,
+        lower: &OMatrix<N, R, C>,
+        upper: &OMatrix<N, R, C>,
+    ) -> OMatrix<N, R, C> {
+        self.zip_zip_map(lower, upper, |x, l, u| {
+            if x < l {
+                l
+            } else if x > u {
+                u
+            } else {
+                x
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::{DVector, Matrix2, Vector3};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_bound_vec_ $t>]() {
+                    let lower = Vector3::new(1 as $t, 2 as $t, 3 as $t);
+                    let upper = Vector3::new(4 as $t, 5 as $t, 6 as $t);
+                    assert!(<Vector3<$t> as ArgminBound>::valid_bounds(&lower, &upper));
+                    assert!(!<Vector3<$t> as ArgminBound>::valid_bounds(&upper, &lower));
+
+                    let inside = Vector3::new(1 as $t, 3 as $t, 6 as $t);
+                    let outside = Vector3::new(0 as $t, 3 as $t, 7 as $t);
+                    assert!(inside.within_bounds(&lower, &upper));
+                    assert!(!outside.within_bounds(&lower, &upper));
+
+                    assert_eq!(inside.clamp_to_bounds(&lower, &upper), inside);
+                    assert_eq!(
+                        outside.clamp_to_bounds(&lower, &upper),
+                        Vector3::new(1 as $t, 3 as $t, 6 as $t)
+                    );
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_bound_mat_ $t>]() {
+                    let lower = Matrix2::new(1 as $t, 2 as $t, 3 as $t, 4 as $t);
+                    let upper = Matrix2::new(5 as $t, 6 as $t, 7 as $t, 8 as $t);
+                    assert!(<Matrix2<$t> as ArgminBound>::valid_bounds(&lower, &upper));
+                    assert!(!<Matrix2<$t> as ArgminBound>::valid_bounds(&upper, &lower));
+
+                    let outside = Matrix2::new(0 as $t, 9 as $t, 5 as $t, 4 as $t);
+                    assert!(!outside.within_bounds(&lower, &upper));
+                    assert_eq!(
+                        outside.clamp_to_bounds(&lower, &upper),
+                        Matrix2::new(1 as $t, 6 as $t, 5 as $t, 4 as $t)
+                    );
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(isize);
+    make_test!(usize);
+    make_test!(f32);
+    make_test!(f64);
+
+    #[test]
+    fn test_bound_dvector_shape() {
+        let lower = DVector::from_vec(vec![0.0f64, 0.0]);
+        let upper = DVector::from_vec(vec![1.0f64, 1.0, 1.0]);
+        assert!(!<DVector<f64> as ArgminBound>::valid_bounds(&lower, &upper));
+        assert!(!DVector::from_vec(vec![0.5f64, 0.5]).within_bounds(&lower, &upper));
+    }
+}
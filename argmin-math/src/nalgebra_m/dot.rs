@@ -88,7 +88,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use nalgebra::{Matrix3, RowVector3, Vector3};
+    use nalgebra::{Matrix3, RowVector3, SMatrix, SVector, Vector3};
     use paste::item;
 
     macro_rules! make_test {
@@ -169,6 +169,23 @@ mod tests {
                 }
             }
 
+            item! {
+                #[test]
+                fn [<test_smat_svec_ $t>]() {
+                    let a = SMatrix::<$t, 2, 3>::new(
+                        1 as $t, 2 as $t, 3 as $t,
+                        4 as $t, 5 as $t, 6 as $t
+                    );
+                    let b = SVector::<$t, 3>::new(1 as $t, 2 as $t, 3 as $t);
+                    let res = SVector::<$t, 2>::new(14 as $t, 32 as $t);
+                    let product: SVector<$t, 2> =
+                        <SMatrix<$t, 2, 3> as ArgminDot<SVector<$t, 3>, SVector<$t, 2>>>::dot(&a, &b);
+                    for i in 0..2 {
+                        assert!((((res[i] - product[i]) as f64).abs()) < std::f64::EPSILON);
+                    }
+                }
+            }
+
             item! {
                 #[test]
                 fn [<test_mat_mat_ $t>]() {
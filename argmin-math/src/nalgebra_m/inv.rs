@@ -6,11 +6,11 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::{ArgminInv, Error};
+use core::fmt;
 use nalgebra::{
     base::{allocator::Allocator, dimension::Dim, storage::Storage},
     ComplexField, DefaultAllocator, OMatrix, SquareMatrix,
 };
-use std::fmt;
 
 #[derive(Debug, thiserror::Error, PartialEq)]
 struct InverseError;
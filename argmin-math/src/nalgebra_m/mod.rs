@@ -6,6 +6,7 @@
 // copied, modified, or distributed except according to those terms.
 
 mod add;
+mod bound;
 mod conj;
 mod div;
 mod dot;
@@ -15,15 +16,18 @@ mod l1norm;
 mod l2norm;
 mod minmax;
 mod mul;
+#[cfg(feature = "std")]
 mod random;
 mod scaledadd;
 mod scaledsub;
 mod signum;
+mod solve;
 mod sub;
 mod transpose;
 mod zero;
 
 pub use add::*;
+pub use bound::*;
 pub use conj::*;
 pub use div::*;
 pub use dot::*;
@@ -33,10 +37,12 @@ pub use l1norm::*;
 pub use l2norm::*;
 pub use minmax::*;
 pub use mul::*;
+#[cfg(feature = "std")]
 pub use random::*;
 pub use scaledadd::*;
 pub use scaledsub::*;
 pub use signum::*;
+pub use solve::*;
 pub use sub::*;
 pub use transpose::*;
 pub use zero::*;
@@ -0,0 +1,134 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminCholeskySolve, ArgminLuSolve, ArgminQrSolve, Error};
+use nalgebra::{
+    base::{allocator::Allocator, dimension::DimMin, storage::Storage},
+    ComplexField, DefaultAllocator, OVector, SquareMatrix,
+};
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+enum SolveError {
+    #[error("Matrix is not positive definite")]
+    NotPositiveDefinite,
+    #[error("Singular matrix")]
+    Singular,
+}
+
+impl<N, D, S> ArgminCholeskySolve<OVector<N, D>> for SquareMatrix<N, D, S>
+where
+    N: ComplexField,
+    D: DimMin<D, Output = D>,
+    S: Storage<N, D, D>,
+    DefaultAllocator: Allocator<N, D, D> + Allocator<N, D>,
+{
+    #[inline]
+    fn cholesky_solve(&self, b: &OVector<N, D>) -> Result<OVector<N, D>, Error> {
+        match self.clone_owned().cholesky() {
+            Some(c) => Ok(c.solve(b)),
+            None => Err(SolveError::NotPositiveDefinite.into()),
+        }
+    }
+}
+
+impl<N, D, S> ArgminLuSolve<OVector<N, D>> for SquareMatrix<N, D, S>
+where
+    N: ComplexField,
+    D: DimMin<D, Output = D>,
+    S: Storage<N, D, D>,
+    DefaultAllocator: Allocator<N, D, D> + Allocator<N, D> + Allocator<(usize, usize), D>,
+{
+    #[inline]
+    fn lu_solve(&self, b: &OVector<N, D>) -> Result<OVector<N, D>, Error> {
+        match self.clone_owned().lu().solve(b) {
+            Some(x) => Ok(x),
+            None => Err(SolveError::Singular.into()),
+        }
+    }
+}
+
+impl<N, D, S> ArgminQrSolve<OVector<N, D>> for SquareMatrix<N, D, S>
+where
+    N: ComplexField,
+    D: DimMin<D, Output = D>,
+    S: Storage<N, D, D>,
+    DefaultAllocator: Allocator<N, D, D> + Allocator<N, D>,
+{
+    #[inline]
+    fn qr_solve(&self, b: &OVector<N, D>) -> Result<OVector<N, D>, Error> {
+        match self.clone_owned().qr().solve(b) {
+            Some(x) => Ok(x),
+            None => Err(SolveError::Singular.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use nalgebra::{DMatrix, DVector, Matrix2, Matrix3, Vector2, Vector3};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_cholesky_solve_ $t>]() {
+                    let a = Matrix3::<$t>::new(
+                        4.0, 2.0, -2.0,
+                        2.0, 10.0, 4.0,
+                        -2.0, 4.0, 9.0,
+                    );
+                    let b = Vector3::<$t>::new(6.0, 26.0, 15.0);
+                    let x = a.cholesky_solve(&b).unwrap();
+                    for (xi, ti) in x.iter().zip([1.0, 2.0, 1.0].iter()) {
+                        assert_relative_eq!(*xi, *ti, epsilon = 100.0 * <$t>::EPSILON);
+                    }
+                    let a = DMatrix::<$t>::from_row_slice(2, 2, &[1.0, 2.0, 2.0, 1.0]);
+                    let b = DVector::<$t>::from_vec(vec![1.0, 1.0]);
+                    assert_eq!(
+                        a.cholesky_solve(&b).unwrap_err().to_string(),
+                        "Matrix is not positive definite"
+                    );
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_lu_qr_solve_ $t>]() {
+                    let a = DMatrix::<$t>::from_row_slice(
+                        3,
+                        3,
+                        &[0.0, 2.0, 1.0, 1.0, -1.0, 0.0, 3.0, 0.0, -2.0],
+                    );
+                    let b = DVector::<$t>::from_vec(vec![5.0, -1.0, 1.0]);
+                    let x = a.lu_solve(&b).unwrap();
+                    for (xi, ti) in x.iter().zip([1.0, 2.0, 1.0].iter()) {
+                        assert_relative_eq!(*xi, *ti, epsilon = 100.0 * <$t>::EPSILON);
+                    }
+                    let x = a.qr_solve(&b).unwrap();
+                    for (xi, ti) in x.iter().zip([1.0, 2.0, 1.0].iter()) {
+                        assert_relative_eq!(*xi, *ti, epsilon = 100.0 * <$t>::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_solve_singular_ $t>]() {
+                    let a = Matrix2::<$t>::new(1.0, 2.0, 2.0, 4.0);
+                    let b = Vector2::<$t>::new(1.0, 1.0);
+                    assert_eq!(a.lu_solve(&b).unwrap_err().to_string(), "Singular matrix");
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
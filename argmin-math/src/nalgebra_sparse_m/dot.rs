@@ -0,0 +1,132 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminDot;
+
+use num_traits::{One, Zero};
+
+use nalgebra::{base::Scalar, ClosedAdd, ClosedDiv, ClosedMul, ClosedSub, DMatrix, DVector};
+use nalgebra_sparse::{CscMatrix, CsrMatrix};
+use std::ops::Neg;
+
+macro_rules! make_dot {
+    ($sparse:ident) => {
+        /// Sparse matrix times dense vector
+        impl<N> ArgminDot<DVector<N>, DVector<N>> for $sparse<N>
+        where
+            N: Scalar
+                + Zero
+                + One
+                + ClosedAdd
+                + ClosedSub
+                + ClosedMul
+                + ClosedDiv
+                + Neg<Output = N>,
+        {
+            #[inline]
+            fn dot(&self, other: &DVector<N>) -> DVector<N> {
+                self * other
+            }
+        }
+
+        /// Sparse matrix times dense matrix
+        impl<N> ArgminDot<DMatrix<N>, DMatrix<N>> for $sparse<N>
+        where
+            N: Scalar
+                + Zero
+                + One
+                + ClosedAdd
+                + ClosedSub
+                + ClosedMul
+                + ClosedDiv
+                + Neg<Output = N>,
+        {
+            #[inline]
+            fn dot(&self, other: &DMatrix<N>) -> DMatrix<N> {
+                self * other
+            }
+        }
+
+        /// Sparse matrix times sparse matrix
+        impl<N> ArgminDot<$sparse<N>, $sparse<N>> for $sparse<N>
+        where
+            N: Scalar
+                + Zero
+                + One
+                + ClosedAdd
+                + ClosedSub
+                + ClosedMul
+                + ClosedDiv
+                + Neg<Output = N>,
+        {
+            #[inline]
+            fn dot(&self, other: &$sparse<N>) -> $sparse<N> {
+                self * other
+            }
+        }
+    };
+}
+
+make_dot!(CsrMatrix);
+make_dot!(CscMatrix);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::{DMatrix, DVector};
+    use nalgebra_sparse::CooMatrix;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_sparse_dot_ $t>]() {
+                    // [[1, 0, 2], [0, 3, 0]]
+                    let mut coo = CooMatrix::new(2, 3);
+                    coo.push(0, 0, 1 as $t);
+                    coo.push(0, 2, 2 as $t);
+                    coo.push(1, 1, 3 as $t);
+                    let csr = CsrMatrix::from(&coo);
+                    let csc = CscMatrix::from(&coo);
+                    let dense = DMatrix::from_row_slice(2, 3, &[
+                        1 as $t, 0 as $t, 2 as $t,
+                        0 as $t, 3 as $t, 0 as $t,
+                    ]);
+
+                    let v = DVector::from_vec(vec![1 as $t, 2 as $t, 3 as $t]);
+                    let target = &dense * &v;
+                    assert_eq!(<CsrMatrix<$t> as ArgminDot<_, DVector<$t>>>::dot(&csr, &v), target);
+                    assert_eq!(<CscMatrix<$t> as ArgminDot<_, DVector<$t>>>::dot(&csc, &v), target);
+
+                    let m = DMatrix::from_row_slice(3, 2, &[
+                        1 as $t, 2 as $t,
+                        3 as $t, 4 as $t,
+                        5 as $t, 6 as $t,
+                    ]);
+                    let target = &dense * &m;
+                    assert_eq!(<CsrMatrix<$t> as ArgminDot<_, DMatrix<$t>>>::dot(&csr, &m), target);
+                    assert_eq!(<CscMatrix<$t> as ArgminDot<_, DMatrix<$t>>>::dot(&csc, &m), target);
+
+                    let csr_t = csr.transpose();
+                    let target = &dense * dense.transpose();
+                    let res = <CsrMatrix<$t> as ArgminDot<_, CsrMatrix<$t>>>::dot(&csr, &csr_t);
+                    assert_eq!(DMatrix::from(&res), target);
+                    let res = <CscMatrix<$t> as ArgminDot<_, CscMatrix<$t>>>::dot(&csc, &csc.transpose());
+                    assert_eq!(DMatrix::from(&res), target);
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(f32);
+    make_test!(f64);
+}
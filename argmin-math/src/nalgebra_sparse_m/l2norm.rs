@@ -0,0 +1,57 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminL2Norm;
+
+use nalgebra::{DVectorView, SimdComplexField};
+use nalgebra_sparse::{CscMatrix, CsrMatrix};
+
+macro_rules! make_l2norm {
+    ($sparse:ident) => {
+        /// Frobenius norm of the explicitly stored entries
+        impl<N> ArgminL2Norm<N::SimdRealField> for $sparse<N>
+        where
+            N: SimdComplexField,
+        {
+            #[inline]
+            fn l2_norm(&self) -> N::SimdRealField {
+                DVectorView::from_slice(self.values(), self.nnz()).norm()
+            }
+        }
+    };
+}
+
+make_l2norm!(CsrMatrix);
+make_l2norm!(CscMatrix);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use nalgebra_sparse::CooMatrix;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_sparse_l2norm_ $t>]() {
+                    let mut coo = CooMatrix::new(3, 3);
+                    coo.push(0, 2, 4 as $t);
+                    coo.push(2, 1, -3 as $t);
+                    let res = <CsrMatrix<$t> as ArgminL2Norm<$t>>::l2_norm(&CsrMatrix::from(&coo));
+                    assert_relative_eq!(res, 5 as $t, epsilon = $t::EPSILON);
+                    let res = <CscMatrix<$t> as ArgminL2Norm<$t>>::l2_norm(&CscMatrix::from(&coo));
+                    assert_relative_eq!(res, 5 as $t, epsilon = $t::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,20 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+mod add;
+mod dot;
+mod l2norm;
+mod mul;
+mod sub;
+mod transpose;
+
+pub use add::*;
+pub use dot::*;
+pub use l2norm::*;
+pub use mul::*;
+pub use sub::*;
+pub use transpose::*;
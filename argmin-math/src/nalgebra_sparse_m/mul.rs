@@ -0,0 +1,101 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminMul;
+
+use nalgebra::{base::Scalar, ClosedMul};
+use nalgebra_sparse::{CscMatrix, CsrMatrix};
+
+macro_rules! make_mul {
+    ($sparse:ident) => {
+        impl<N> ArgminMul<N, $sparse<N>> for $sparse<N>
+        where
+            N: Scalar + ClosedMul,
+        {
+            #[inline]
+            fn mul(&self, other: &N) -> $sparse<N> {
+                let mut res = self.clone();
+                for value in res.values_mut() {
+                    *value *= other.clone();
+                }
+                res
+            }
+        }
+
+        impl<N> ArgminMul<$sparse<N>, $sparse<N>> for N
+        where
+            N: Scalar + ClosedMul,
+        {
+            #[inline]
+            fn mul(&self, other: &$sparse<N>) -> $sparse<N> {
+                let mut res = other.clone();
+                for value in res.values_mut() {
+                    *value *= self.clone();
+                }
+                res
+            }
+        }
+    };
+}
+
+make_mul!(CsrMatrix);
+make_mul!(CscMatrix);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::DMatrix;
+    use nalgebra_sparse::CooMatrix;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_sparse_mul_scalar_ $t>]() {
+                    let mut coo = CooMatrix::new(2, 2);
+                    coo.push(0, 1, 2 as $t);
+                    coo.push(1, 0, 3 as $t);
+                    let target = DMatrix::from_row_slice(2, 2, &[
+                        0 as $t, 4 as $t,
+                        6 as $t, 0 as $t,
+                    ]);
+                    let csr = <CsrMatrix<$t> as ArgminMul<$t, _>>::mul(&CsrMatrix::from(&coo), &(2 as $t));
+                    assert_eq!(DMatrix::from(&csr), target);
+                    let csc = <CscMatrix<$t> as ArgminMul<$t, _>>::mul(&CscMatrix::from(&coo), &(2 as $t));
+                    assert_eq!(DMatrix::from(&csc), target);
+                }
+
+                #[test]
+                fn [<test_sparse_mul_scalar_left_ $t>]() {
+                    let mut coo = CooMatrix::new(2, 2);
+                    coo.push(0, 1, 2 as $t);
+                    coo.push(1, 0, 3 as $t);
+                    let target = DMatrix::from_row_slice(2, 2, &[
+                        0 as $t, 4 as $t,
+                        6 as $t, 0 as $t,
+                    ]);
+                    let csr = <$t as ArgminMul<CsrMatrix<$t>, _>>::mul(&(2 as $t), &CsrMatrix::from(&coo));
+                    assert_eq!(DMatrix::from(&csr), target);
+                    let csc = <$t as ArgminMul<CscMatrix<$t>, _>>::mul(&(2 as $t), &CscMatrix::from(&coo));
+                    assert_eq!(DMatrix::from(&csc), target);
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,103 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminSub;
+
+use nalgebra::{base::Scalar, ClosedAdd, ClosedMul, ClosedSub};
+use nalgebra_sparse::{CscMatrix, CsrMatrix};
+use num_traits::{One, Zero};
+use std::ops::Neg;
+
+macro_rules! make_sub {
+    ($sparse:ident) => {
+        impl<N> ArgminSub<$sparse<N>, $sparse<N>> for $sparse<N>
+        where
+            N: Scalar + Zero + One + ClosedAdd + ClosedSub + ClosedMul + Neg<Output = N>,
+        {
+            #[inline]
+            fn sub(&self, other: &$sparse<N>) -> $sparse<N> {
+                self - other
+            }
+        }
+    };
+}
+
+make_sub!(CsrMatrix);
+make_sub!(CscMatrix);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ArgminScaledSub;
+    use nalgebra::DMatrix;
+    use nalgebra_sparse::CooMatrix;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_sparse_sub_ $t>]() {
+                    // Sparsity patterns of both matrices differ
+                    let mut a = CooMatrix::new(2, 2);
+                    a.push(0, 1, 2 as $t);
+                    a.push(1, 1, 5 as $t);
+                    let mut b = CooMatrix::new(2, 2);
+                    b.push(1, 0, 3 as $t);
+                    b.push(1, 1, 4 as $t);
+                    let target = DMatrix::from_row_slice(2, 2, &[
+                        0 as $t, 2 as $t,
+                        -3 as $t, 1 as $t,
+                    ]);
+                    let csr = <CsrMatrix<$t> as ArgminSub<_, _>>::sub(
+                        &CsrMatrix::from(&a),
+                        &CsrMatrix::from(&b),
+                    );
+                    assert_eq!(DMatrix::from(&csr), target);
+                    let csc = <CscMatrix<$t> as ArgminSub<_, _>>::sub(
+                        &CscMatrix::from(&a),
+                        &CscMatrix::from(&b),
+                    );
+                    assert_eq!(DMatrix::from(&csc), target);
+                }
+
+                #[test]
+                fn [<test_sparse_scaledsub_ $t>]() {
+                    let mut a = CooMatrix::new(2, 2);
+                    a.push(0, 1, 2 as $t);
+                    a.push(1, 1, 9 as $t);
+                    let mut b = CooMatrix::new(2, 2);
+                    b.push(1, 0, 3 as $t);
+                    b.push(1, 1, 4 as $t);
+                    let target = DMatrix::from_row_slice(2, 2, &[
+                        0 as $t, 2 as $t,
+                        -6 as $t, 1 as $t,
+                    ]);
+                    let csr = <CsrMatrix<$t> as ArgminScaledSub<_, _, _>>::scaled_sub(
+                        &CsrMatrix::from(&a),
+                        &(2 as $t),
+                        &CsrMatrix::from(&b),
+                    );
+                    assert_eq!(DMatrix::from(&csr), target);
+                    let csc = <CscMatrix<$t> as ArgminScaledSub<_, _, _>>::scaled_sub(
+                        &CscMatrix::from(&a),
+                        &(2 as $t),
+                        &CscMatrix::from(&b),
+                    );
+                    assert_eq!(DMatrix::from(&csc), target);
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(i16);
+    make_test!(i32);
+    make_test!(i64);
+    make_test!(f32);
+    make_test!(f64);
+}
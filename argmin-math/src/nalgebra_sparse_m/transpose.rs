@@ -0,0 +1,69 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminTranspose;
+
+use nalgebra::base::Scalar;
+use nalgebra_sparse::{CscMatrix, CsrMatrix};
+
+macro_rules! make_transpose {
+    ($sparse:ident) => {
+        impl<N> ArgminTranspose<$sparse<N>> for $sparse<N>
+        where
+            N: Scalar,
+        {
+            #[inline]
+            fn t(self) -> $sparse<N> {
+                self.transpose()
+            }
+        }
+    };
+}
+
+make_transpose!(CsrMatrix);
+make_transpose!(CscMatrix);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nalgebra::DMatrix;
+    use nalgebra_sparse::CooMatrix;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_sparse_transpose_ $t>]() {
+                    let mut coo = CooMatrix::new(2, 3);
+                    coo.push(0, 2, 1 as $t);
+                    coo.push(1, 0, 4 as $t);
+                    let target = DMatrix::from_row_slice(3, 2, &[
+                        0 as $t, 4 as $t,
+                        0 as $t, 0 as $t,
+                        1 as $t, 0 as $t,
+                    ]);
+                    let csr = <CsrMatrix<$t> as ArgminTranspose<_>>::t(CsrMatrix::from(&coo));
+                    assert_eq!(DMatrix::from(&csr), target);
+                    let csc = <CscMatrix<$t> as ArgminTranspose<_>>::t(CscMatrix::from(&coo));
+                    assert_eq!(DMatrix::from(&csc), target);
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,124 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminBound;
+use ndarray::{Array, Dimension};
+
+macro_rules! make_bound {
+    ($t:ty) => {
+        impl<D> ArgminBound for Array<$t, D>
+        where
+            D: Dimension,
+        {
+            #[inline]
+            fn valid_bounds(lower: &Self, upper: &Self) -> bool {
+                lower.shape() == upper.shape() && lower.iter().zip(upper).all(|(l, u)| l <= u)
+            }
+
+            #[inline]
+            fn within_bounds(&self, lower: &Self, upper: &Self) -> bool {
+                self.shape() == lower.shape()
+                    && self.shape() == upper.shape()
+                    && self
+                        .iter()
+                        .zip(lower.iter().zip(upper))
+                        .all(|(x, (l, u))| l <= x && x <= u)
+            }
+
+            #[inline]
+            fn clamp_to_bounds(&self, lower: &Self, upper: &Self) -> Self {
+                assert_eq!(self.shape(), lower.shape());
+                assert_eq!(self.shape(), upper.shape());
+                let mut out = self.clone();
+                for (x, (&l, &u)) in out.iter_mut().zip(lower.iter().zip(upper)) {
+                    if *x < l {
+                        *x = l;
+                    } else if *x > u {
+                        *x = u;
+                    }
+                }
+                out
+            }
+        }
+    };
+}
+
+make_bound!(isize);
+make_bound!(usize);
+make_bound!(i8);
+make_bound!(u8);
+make_bound!(i16);
+make_bound!(u16);
+make_bound!(i32);
+make_bound!(u32);
+make_bound!(i64);
+make_bound!(u64);
+make_bound!(f32);
+make_bound!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{array, Array1, Array2};
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_bound_vec_ $t>]() {
+                    let lower = array![1 as $t, 2 as $t, 3 as $t];
+                    let upper = array![4 as $t, 5 as $t, 6 as $t];
+                    assert!(<Array1<$t> as ArgminBound>::valid_bounds(&lower, &upper));
+                    assert!(!<Array1<$t> as ArgminBound>::valid_bounds(&upper, &lower));
+                    assert!(!<Array1<$t> as ArgminBound>::valid_bounds(&lower, &array![4 as $t]));
+
+                    let inside = array![1 as $t, 3 as $t, 6 as $t];
+                    let outside = array![0 as $t, 3 as $t, 7 as $t];
+                    assert!(inside.within_bounds(&lower, &upper));
+                    assert!(!outside.within_bounds(&lower, &upper));
+
+                    assert_eq!(inside.clamp_to_bounds(&lower, &upper), inside);
+                    assert_eq!(
+                        outside.clamp_to_bounds(&lower, &upper),
+                        array![1 as $t, 3 as $t, 6 as $t]
+                    );
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_bound_mat_ $t>]() {
+                    let lower = array![[1 as $t, 2 as $t], [3 as $t, 4 as $t]];
+                    let upper = array![[5 as $t, 6 as $t], [7 as $t, 8 as $t]];
+                    assert!(<Array2<$t> as ArgminBound>::valid_bounds(&lower, &upper));
+                    assert!(!<Array2<$t> as ArgminBound>::valid_bounds(&upper, &lower));
+
+                    let outside = array![[0 as $t, 9 as $t], [5 as $t, 4 as $t]];
+                    assert!(!outside.within_bounds(&lower, &upper));
+                    assert_eq!(
+                        outside.clamp_to_bounds(&lower, &upper),
+                        array![[1 as $t, 6 as $t], [5 as $t, 4 as $t]]
+                    );
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(isize);
+    make_test!(usize);
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -6,7 +6,7 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::ArgminDot;
-use ndarray::{Array1, Array2};
+use ndarray::{Array1, Array2, ArrayView1};
 use num_complex::Complex;
 
 macro_rules! make_dot_ndarray {
@@ -142,6 +142,36 @@ make_dot_ndarray!(usize);
 make_dot_ndarray!(f32);
 make_dot_ndarray!(f64);
 
+// Dot products involving borrowed one-dimensional views. This allows computing on (parts of)
+// arrays without copying them into an owned `Array1` first.
+macro_rules! make_dot_view {
+    ($t:ty) => {
+        impl<'a, 'b> ArgminDot<ArrayView1<'b, $t>, $t> for ArrayView1<'a, $t> {
+            #[inline]
+            fn dot(&self, other: &ArrayView1<'b, $t>) -> $t {
+                ndarray::ArrayView1::dot(self, other)
+            }
+        }
+
+        impl<'a> ArgminDot<Array1<$t>, $t> for ArrayView1<'a, $t> {
+            #[inline]
+            fn dot(&self, other: &Array1<$t>) -> $t {
+                ndarray::ArrayView1::dot(self, other)
+            }
+        }
+
+        impl<'a> ArgminDot<ArrayView1<'a, $t>, $t> for Array1<$t> {
+            #[inline]
+            fn dot(&self, other: &ArrayView1<'a, $t>) -> $t {
+                ndarray::Array1::dot(self, other)
+            }
+        }
+    };
+}
+
+make_dot_view!(f32);
+make_dot_view!(f64);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -500,4 +530,26 @@ mod tests {
     make_test!(usize);
     make_test!(f32);
     make_test!(f64);
+
+    macro_rules! make_view_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_dot_view_ $t>]() {
+                    let a = array![1 as $t, 2 as $t, 3 as $t, 4 as $t];
+                    let b = array![4 as $t, 5 as $t, 6 as $t];
+                    let av = a.slice(ndarray::s![1..]);
+                    let res: $t = <ArrayView1<$t> as ArgminDot<ArrayView1<$t>, $t>>::dot(&av, &b.view());
+                    assert!((((res - 47 as $t) as f64).abs()) < std::f64::EPSILON);
+                    let res: $t = <ArrayView1<$t> as ArgminDot<Array1<$t>, $t>>::dot(&av, &b);
+                    assert!((((res - 47 as $t) as f64).abs()) < std::f64::EPSILON);
+                    let res: $t = <Array1<$t> as ArgminDot<ArrayView1<$t>, $t>>::dot(&b, &av);
+                    assert!((((res - 47 as $t) as f64).abs()) < std::f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_view_test!(f32);
+    make_view_test!(f64);
 }
@@ -6,7 +6,7 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::ArgminL1Norm;
-use ndarray::Array1;
+use ndarray::{Array1, ArrayView1};
 use num_complex::Complex;
 
 macro_rules! make_l1norm {
@@ -67,10 +67,24 @@ make_l1norm_complex!(Complex<usize>, usize);
 make_l1norm_complex!(Complex<f32>, f32);
 make_l1norm_complex!(Complex<f64>, f64);
 
+macro_rules! make_l1norm_view {
+    ($t:ty) => {
+        impl<'a> ArgminL1Norm<$t> for ArrayView1<'a, $t> {
+            #[inline]
+            fn l1_norm(&self) -> $t {
+                self.iter().map(|a| a.abs()).sum()
+            }
+        }
+    };
+}
+
+make_l1norm_view!(f32);
+make_l1norm_view!(f64);
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use ndarray::{array, Array1};
+    use ndarray::array;
     use paste::item;
 
     macro_rules! make_test {
@@ -141,4 +155,20 @@ mod tests {
     make_test_signed!(i64);
     make_test_signed!(f32);
     make_test_signed!(f64);
+
+    macro_rules! make_view_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_l1norm_view_ $t>]() {
+                    let a = array![100 as $t, 4 as $t, -3 as $t];
+                    let res = <ArrayView1<$t> as ArgminL1Norm<$t>>::l1_norm(&a.slice(ndarray::s![1..]));
+                    assert!((((res - 7 as $t) as f64).abs()) < std::f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_view_test!(f32);
+    make_view_test!(f64);
 }
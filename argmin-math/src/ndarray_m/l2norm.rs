@@ -6,7 +6,7 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::ArgminL2Norm;
-use ndarray::Array1;
+use ndarray::{Array1, ArrayView1};
 use num_complex::Complex;
 use num_integer::Roots;
 
@@ -79,10 +79,24 @@ make_norm_complex!(Complex<usize>, usize);
 make_norm_complex!(Complex<f32>, f32);
 make_norm_complex!(Complex<f64>, f64);
 
+macro_rules! make_l2norm_view {
+    ($t:ty) => {
+        impl<'a> ArgminL2Norm<$t> for ArrayView1<'a, $t> {
+            #[inline]
+            fn l2_norm(&self) -> $t {
+                self.iter().map(|a| a.powi(2)).sum::<$t>().sqrt()
+            }
+        }
+    };
+}
+
+make_l2norm_view!(f32);
+make_l2norm_view!(f64);
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use ndarray::{array, Array1};
+    use ndarray::array;
     use paste::item;
 
     macro_rules! make_test {
@@ -153,4 +167,20 @@ mod tests {
     make_test_signed!(i64);
     make_test_signed!(f32);
     make_test_signed!(f64);
+
+    macro_rules! make_view_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_l2norm_view_ $t>]() {
+                    let a = array![100 as $t, 4 as $t, -3 as $t];
+                    let res = <ArrayView1<$t> as ArgminL2Norm<$t>>::l2_norm(&a.slice(ndarray::s![1..]));
+                    assert!((((res - 5 as $t) as f64).abs()) < std::f64::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_view_test!(f32);
+    make_view_test!(f64);
 }
@@ -6,6 +6,7 @@
 // copied, modified, or distributed except according to those terms.
 
 mod add;
+mod bound;
 mod conj;
 mod div;
 mod dot;
@@ -29,6 +30,7 @@ mod transpose;
 mod zero;
 
 pub use add::*;
+pub use bound::*;
 pub use conj::*;
 pub use div::*;
 pub use dot::*;
@@ -6,7 +6,7 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::ArgminMul;
-use ndarray::{Array1, Array2};
+use ndarray::{Array1, Array2, ArrayView1};
 use num_complex::Complex;
 
 macro_rules! make_mul {
@@ -263,6 +263,27 @@ make_complex_integer_mul!(u64);
 make_complex_integer_mul!(isize);
 make_complex_integer_mul!(usize);
 
+macro_rules! make_mul_view {
+    ($t:ty) => {
+        impl<'a> ArgminMul<$t, Array1<$t>> for ArrayView1<'a, $t> {
+            #[inline]
+            fn mul(&self, other: &$t) -> Array1<$t> {
+                self.mapv(|s| s * *other)
+            }
+        }
+
+        impl<'a> ArgminMul<ArrayView1<'a, $t>, Array1<$t>> for $t {
+            #[inline]
+            fn mul(&self, other: &ArrayView1<'a, $t>) -> Array1<$t> {
+                other.mapv(|o| *self * o)
+            }
+        }
+    };
+}
+
+make_mul_view!(f32);
+make_mul_view!(f64);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -660,4 +681,24 @@ mod tests {
     make_test!(usize);
     make_test!(f32);
     make_test!(f64);
+
+    macro_rules! make_view_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_mul_view_scalar_ $t>]() {
+                    let a = array![0 as $t, 1 as $t, 4 as $t, 8 as $t];
+                    let av = a.slice(ndarray::s![1..]);
+                    let target = array![2 as $t, 8 as $t, 16 as $t];
+                    let res = <ArrayView1<$t> as ArgminMul<$t, Array1<$t>>>::mul(&av, &(2 as $t));
+                    assert_eq!(res, target);
+                    let res = <$t as ArgminMul<ArrayView1<$t>, Array1<$t>>>::mul(&(2 as $t), &av);
+                    assert_eq!(res, target);
+                }
+            }
+        };
+    }
+
+    make_view_test!(f32);
+    make_view_test!(f64);
 }
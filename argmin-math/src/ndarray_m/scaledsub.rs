@@ -5,10 +5,33 @@
 // http://opensource.org/licenses/MIT>, at your option. This file may not be
 // copied, modified, or distributed except according to those terms.
 
+use crate::ArgminScaledSub;
+use ndarray::{Array1, ArrayView1};
+
+// Owned arrays and scalars are covered by the generic implementation in terms of `ArgminMul` and
+// `ArgminSub`. For a borrowed `vec` this would require a view as the result of the
+// multiplication, therefore the update is computed directly on a copy of `self`.
+macro_rules! make_scaledsub_view {
+    ($t:ty) => {
+        impl<'a> ArgminScaledSub<ArrayView1<'a, $t>, $t, Array1<$t>> for Array1<$t> {
+            #[inline]
+            fn scaled_sub(&self, factor: &$t, vec: &ArrayView1<'a, $t>) -> Array1<$t> {
+                assert_eq!(self.len(), vec.len());
+                let mut out = self.clone();
+                ndarray::ArrayBase::scaled_add(&mut out, -*factor, vec);
+                out
+            }
+        }
+    };
+}
+
+make_scaledsub_view!(f32);
+make_scaledsub_view!(f64);
+
 #[cfg(test)]
 mod tests {
-    use crate::ArgminScaledSub;
-    use ndarray::{array, Array1, Array2};
+    use super::*;
+    use ndarray::{array, Array2};
     use paste::item;
 
     macro_rules! make_test {
@@ -161,4 +184,32 @@ mod tests {
     make_test!(u64);
     make_test!(f32);
     make_test!(f64);
+
+    macro_rules! make_view_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_scaledsub_view_ $t>]() {
+                    let a = array![1 as $t, 2 as $t, 3 as $t];
+                    let c = array![0 as $t, 4 as $t, 5 as $t, 6 as $t];
+                    let cv = c.slice(ndarray::s![1..]);
+                    let res = <Array1<$t> as ArgminScaledSub<ArrayView1<$t>, $t, Array1<$t>>>::scaled_sub(&a, &(2 as $t), &cv);
+                    assert_eq!(res, array![-7 as $t, -8 as $t, -9 as $t]);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_scaledsub_view_panic_ $t>]() {
+                    let a = array![1 as $t, 2 as $t, 3 as $t];
+                    let c = array![4 as $t];
+                    <Array1<$t> as ArgminScaledSub<ArrayView1<$t>, $t, Array1<$t>>>::scaled_sub(&a, &(2 as $t), &c.view());
+                }
+            }
+        };
+    }
+
+    make_view_test!(f32);
+    make_view_test!(f64);
 }
@@ -6,7 +6,7 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::ArgminSub;
-use ndarray::{Array1, Array2};
+use ndarray::{Array1, Array2, ArrayView1};
 use num_complex::Complex;
 
 macro_rules! make_sub {
@@ -61,6 +61,34 @@ make_sub!(f64);
 make_sub!(Complex<f32>);
 make_sub!(Complex<f64>);
 
+macro_rules! make_sub_view {
+    ($t:ty) => {
+        impl<'a> ArgminSub<ArrayView1<'a, $t>, Array1<$t>> for Array1<$t> {
+            #[inline]
+            fn sub(&self, other: &ArrayView1<'a, $t>) -> Array1<$t> {
+                self - other
+            }
+        }
+
+        impl<'a> ArgminSub<Array1<$t>, Array1<$t>> for ArrayView1<'a, $t> {
+            #[inline]
+            fn sub(&self, other: &Array1<$t>) -> Array1<$t> {
+                self - other
+            }
+        }
+
+        impl<'a, 'b> ArgminSub<ArrayView1<'b, $t>, Array1<$t>> for ArrayView1<'a, $t> {
+            #[inline]
+            fn sub(&self, other: &ArrayView1<'b, $t>) -> Array1<$t> {
+                self - other
+            }
+        }
+    };
+}
+
+make_sub_view!(f32);
+make_sub_view!(f64);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -223,4 +251,37 @@ mod tests {
     make_test!(u64);
     make_test!(f32);
     make_test!(f64);
+
+    macro_rules! make_view_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_sub_view_ $t>]() {
+                    let a = array![0 as $t, 3 as $t, 4 as $t, 5 as $t];
+                    let b = array![4 as $t, 5 as $t, 6 as $t];
+                    let av = a.slice(ndarray::s![1..]);
+                    let target = array![-1 as $t, -1 as $t, -1 as $t];
+                    let res = <ArrayView1<$t> as ArgminSub<ArrayView1<$t>, Array1<$t>>>::sub(&av, &b.view());
+                    assert_eq!(res, target);
+                    let res = <ArrayView1<$t> as ArgminSub<Array1<$t>, Array1<$t>>>::sub(&av, &b);
+                    assert_eq!(res, target);
+                    let res = <Array1<$t> as ArgminSub<ArrayView1<$t>, Array1<$t>>>::sub(&av.to_owned(), &b.view());
+                    assert_eq!(res, target);
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_sub_view_panic_ $t>]() {
+                    let a = array![1 as $t, 4 as $t];
+                    let b = array![41 as $t, 38 as $t, 34 as $t];
+                    <Array1<$t> as ArgminSub<ArrayView1<$t>, Array1<$t>>>::sub(&a, &b.view());
+                }
+            }
+        };
+    }
+
+    make_view_test!(f32);
+    make_view_test!(f64);
 }
@@ -0,0 +1,96 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminAdd;
+use num_bigfloat::BigFloat;
+
+impl ArgminAdd<BigFloat, BigFloat> for BigFloat {
+    #[inline]
+    fn add(&self, other: &BigFloat) -> BigFloat {
+        *self + *other
+    }
+}
+
+impl ArgminAdd<BigFloat, Vec<BigFloat>> for Vec<BigFloat> {
+    #[inline]
+    fn add(&self, other: &BigFloat) -> Vec<BigFloat> {
+        self.iter().map(|a| *a + *other).collect()
+    }
+}
+
+impl ArgminAdd<Vec<BigFloat>, Vec<BigFloat>> for BigFloat {
+    #[inline]
+    fn add(&self, other: &Vec<BigFloat>) -> Vec<BigFloat> {
+        other.iter().map(|a| *self + *a).collect()
+    }
+}
+
+impl ArgminAdd<Vec<BigFloat>, Vec<BigFloat>> for Vec<BigFloat> {
+    #[inline]
+    fn add(&self, other: &Vec<BigFloat>) -> Vec<BigFloat> {
+        let n1 = self.len();
+        let n2 = other.len();
+        assert!(n1 > 0);
+        assert!(n2 > 0);
+        assert_eq!(n1, n2);
+        self.iter()
+            .zip(other.iter())
+            .map(|(a, b)| *a + *b)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v(x: &[f64]) -> Vec<BigFloat> {
+        x.iter().map(|x| BigFloat::from_f64(*x)).collect()
+    }
+
+    #[test]
+    fn test_add_scalar_scalar() {
+        let a = BigFloat::from_f64(1.0);
+        let b = BigFloat::parse("1e-30").unwrap();
+        let res = <BigFloat as ArgminAdd<BigFloat, BigFloat>>::add(&a, &b);
+        // Not representable in `f64`
+        assert_eq!(res - a, b);
+    }
+
+    #[test]
+    fn test_add_vec_scalar() {
+        let a = v(&[1.0, 4.0, 8.0]);
+        let b = BigFloat::from_f64(2.0);
+        let target = v(&[3.0, 6.0, 10.0]);
+        assert_eq!(
+            <Vec<BigFloat> as ArgminAdd<BigFloat, Vec<BigFloat>>>::add(&a, &b),
+            target
+        );
+        assert_eq!(
+            <BigFloat as ArgminAdd<Vec<BigFloat>, Vec<BigFloat>>>::add(&b, &a),
+            target
+        );
+    }
+
+    #[test]
+    fn test_add_vec_vec() {
+        let a = v(&[1.0, 4.0, 8.0]);
+        let b = v(&[3.0, 2.0, 1.0]);
+        assert_eq!(
+            <Vec<BigFloat> as ArgminAdd<Vec<BigFloat>, Vec<BigFloat>>>::add(&a, &b),
+            v(&[4.0, 6.0, 9.0])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_vec_vec_panic() {
+        let a = v(&[1.0, 4.0]);
+        let b = v(&[3.0, 2.0, 1.0]);
+        <Vec<BigFloat> as ArgminAdd<Vec<BigFloat>, Vec<BigFloat>>>::add(&a, &b);
+    }
+}
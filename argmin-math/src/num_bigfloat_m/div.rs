@@ -0,0 +1,95 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminDiv;
+use num_bigfloat::BigFloat;
+
+impl ArgminDiv<BigFloat, BigFloat> for BigFloat {
+    #[inline]
+    fn div(&self, other: &BigFloat) -> BigFloat {
+        *self / *other
+    }
+}
+
+impl ArgminDiv<BigFloat, Vec<BigFloat>> for Vec<BigFloat> {
+    #[inline]
+    fn div(&self, other: &BigFloat) -> Vec<BigFloat> {
+        self.iter().map(|a| *a / *other).collect()
+    }
+}
+
+impl ArgminDiv<Vec<BigFloat>, Vec<BigFloat>> for BigFloat {
+    #[inline]
+    fn div(&self, other: &Vec<BigFloat>) -> Vec<BigFloat> {
+        other.iter().map(|a| *self / *a).collect()
+    }
+}
+
+impl ArgminDiv<Vec<BigFloat>, Vec<BigFloat>> for Vec<BigFloat> {
+    #[inline]
+    fn div(&self, other: &Vec<BigFloat>) -> Vec<BigFloat> {
+        let n1 = self.len();
+        let n2 = other.len();
+        assert!(n1 > 0);
+        assert!(n2 > 0);
+        assert_eq!(n1, n2);
+        self.iter()
+            .zip(other.iter())
+            .map(|(a, b)| *a / *b)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v(x: &[f64]) -> Vec<BigFloat> {
+        x.iter().map(|x| BigFloat::from_f64(*x)).collect()
+    }
+
+    #[test]
+    fn test_div_scalar_scalar() {
+        let a = BigFloat::from_f64(1.0);
+        let b = BigFloat::from_f64(3.0);
+        let res = <BigFloat as ArgminDiv<BigFloat, BigFloat>>::div(&a, &b);
+        let target = BigFloat::parse("0.3333333333333333333333333333333333333333").unwrap();
+        assert!((res - target).abs() <= BigFloat::parse("1e-39").unwrap());
+    }
+
+    #[test]
+    fn test_div_vec_scalar() {
+        let a = v(&[2.0, 4.0, 8.0]);
+        let b = BigFloat::from_f64(2.0);
+        assert_eq!(
+            <Vec<BigFloat> as ArgminDiv<BigFloat, Vec<BigFloat>>>::div(&a, &b),
+            v(&[1.0, 2.0, 4.0])
+        );
+        assert_eq!(
+            <BigFloat as ArgminDiv<Vec<BigFloat>, Vec<BigFloat>>>::div(&b, &a),
+            v(&[1.0, 0.5, 0.25])
+        );
+    }
+
+    #[test]
+    fn test_div_vec_vec() {
+        let a = v(&[4.0, 4.0, 8.0]);
+        let b = v(&[2.0, 8.0, 1.0]);
+        assert_eq!(
+            <Vec<BigFloat> as ArgminDiv<Vec<BigFloat>, Vec<BigFloat>>>::div(&a, &b),
+            v(&[2.0, 0.5, 8.0])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_div_vec_vec_panic() {
+        let a = v(&[1.0, 4.0]);
+        let b = v(&[3.0, 2.0, 1.0]);
+        <Vec<BigFloat> as ArgminDiv<Vec<BigFloat>, Vec<BigFloat>>>::div(&a, &b);
+    }
+}
@@ -0,0 +1,85 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminDot;
+use num_bigfloat::BigFloat;
+
+impl ArgminDot<BigFloat, BigFloat> for BigFloat {
+    #[inline]
+    fn dot(&self, other: &BigFloat) -> BigFloat {
+        *self * *other
+    }
+}
+
+impl ArgminDot<Vec<BigFloat>, BigFloat> for Vec<BigFloat> {
+    #[inline]
+    fn dot(&self, other: &Vec<BigFloat>) -> BigFloat {
+        self.iter().zip(other.iter()).map(|(a, b)| *a * *b).sum()
+    }
+}
+
+impl ArgminDot<BigFloat, Vec<BigFloat>> for Vec<BigFloat> {
+    #[inline]
+    fn dot(&self, other: &BigFloat) -> Vec<BigFloat> {
+        self.iter().map(|a| *a * *other).collect()
+    }
+}
+
+impl ArgminDot<Vec<BigFloat>, Vec<BigFloat>> for BigFloat {
+    #[inline]
+    fn dot(&self, other: &Vec<BigFloat>) -> Vec<BigFloat> {
+        other.iter().map(|a| *self * *a).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v(x: &[f64]) -> Vec<BigFloat> {
+        x.iter().map(|x| BigFloat::from_f64(*x)).collect()
+    }
+
+    #[test]
+    fn test_vec_vec() {
+        let a = v(&[1.0, 2.0, 3.0]);
+        let b = v(&[4.0, 5.0, 6.0]);
+        let res: BigFloat = a.dot(&b);
+        assert_eq!(res, BigFloat::from_f64(32.0));
+    }
+
+    #[test]
+    fn test_vec_vec_cancellation() {
+        // In `f64`, the small contribution is lost when it is added to the large ones.
+        let a = vec![
+            BigFloat::from_f64(1e20),
+            BigFloat::from_f64(1.0),
+            BigFloat::from_f64(-1e20),
+        ];
+        let b = v(&[1.0, 1.0, 1.0]);
+        let res: BigFloat = a.dot(&b);
+        assert_eq!(res, BigFloat::from_f64(1.0));
+    }
+
+    #[test]
+    fn test_vec_scalar() {
+        let a = v(&[1.0, 2.0, 3.0]);
+        let b = BigFloat::from_f64(2.0);
+        let res: Vec<BigFloat> = a.dot(&b);
+        let res2: Vec<BigFloat> = b.dot(&a);
+        assert_eq!(res, v(&[2.0, 4.0, 6.0]));
+        assert_eq!(res2, v(&[2.0, 4.0, 6.0]));
+    }
+
+    #[test]
+    fn test_scalar_scalar() {
+        let a = BigFloat::from_f64(3.0);
+        let b = BigFloat::from_f64(4.0);
+        let res: BigFloat = a.dot(&b);
+        assert_eq!(res, BigFloat::from_f64(12.0));
+    }
+}
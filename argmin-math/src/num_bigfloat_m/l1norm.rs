@@ -0,0 +1,35 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminL1Norm;
+use num_bigfloat::BigFloat;
+
+impl ArgminL1Norm<BigFloat> for BigFloat {
+    #[inline]
+    fn l1_norm(&self) -> BigFloat {
+        self.abs()
+    }
+}
+
+impl ArgminL1Norm<BigFloat> for Vec<BigFloat> {
+    #[inline]
+    fn l1_norm(&self) -> BigFloat {
+        self.iter().map(|a| a.abs()).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_l1norm() {
+        let a = vec![BigFloat::from_f64(4.0), BigFloat::from_f64(-3.0)];
+        assert_eq!(a.l1_norm(), BigFloat::from_f64(7.0));
+        assert_eq!(BigFloat::from_f64(-3.0).l1_norm(), BigFloat::from_f64(3.0));
+    }
+}
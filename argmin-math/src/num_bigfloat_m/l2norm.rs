@@ -0,0 +1,42 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminL2Norm;
+use num_bigfloat::BigFloat;
+
+impl ArgminL2Norm<BigFloat> for BigFloat {
+    #[inline]
+    fn l2_norm(&self) -> BigFloat {
+        self.abs()
+    }
+}
+
+impl ArgminL2Norm<BigFloat> for Vec<BigFloat> {
+    #[inline]
+    fn l2_norm(&self) -> BigFloat {
+        self.iter().map(|a| *a * *a).sum::<BigFloat>().sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_l2norm() {
+        let a = vec![BigFloat::from_f64(4.0), BigFloat::from_f64(-3.0)];
+        assert_eq!(a.l2_norm(), BigFloat::from_f64(5.0));
+        assert_eq!(BigFloat::from_f64(-3.0).l2_norm(), BigFloat::from_f64(3.0));
+    }
+
+    #[test]
+    fn test_l2norm_precision() {
+        let a = vec![BigFloat::from_f64(1.0), BigFloat::from_f64(1.0)];
+        let target = BigFloat::parse("1.414213562373095048801688724209698078569").unwrap();
+        assert!((a.l2_norm() - target).abs() <= BigFloat::parse("1e-38").unwrap());
+    }
+}
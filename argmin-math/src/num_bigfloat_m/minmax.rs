@@ -0,0 +1,62 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminMinMax;
+use num_bigfloat::BigFloat;
+
+impl ArgminMinMax for Vec<BigFloat> {
+    fn min(x: &Self, y: &Self) -> Self {
+        assert!(!x.is_empty());
+        assert_eq!(x.len(), y.len());
+
+        x.iter()
+            .zip(y.iter())
+            .map(|(a, b)| if a < b { *a } else { *b })
+            .collect()
+    }
+
+    fn max(x: &Self, y: &Self) -> Self {
+        assert!(!x.is_empty());
+        assert_eq!(x.len(), y.len());
+
+        x.iter()
+            .zip(y.iter())
+            .map(|(a, b)| if a > b { *a } else { *b })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v(x: &[f64]) -> Vec<BigFloat> {
+        x.iter().map(|x| BigFloat::from_f64(*x)).collect()
+    }
+
+    #[test]
+    fn test_minmax() {
+        let a = v(&[1.0, 4.0, -8.0]);
+        let b = v(&[2.0, 3.0, -9.0]);
+        assert_eq!(
+            <Vec<BigFloat> as ArgminMinMax>::min(&a, &b),
+            v(&[1.0, 3.0, -9.0])
+        );
+        assert_eq!(
+            <Vec<BigFloat> as ArgminMinMax>::max(&a, &b),
+            v(&[2.0, 4.0, -8.0])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_minmax_panic() {
+        let a = v(&[1.0, 4.0]);
+        let b = v(&[2.0, 3.0, -9.0]);
+        <Vec<BigFloat> as ArgminMinMax>::min(&a, &b);
+    }
+}
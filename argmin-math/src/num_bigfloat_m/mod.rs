@@ -0,0 +1,54 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+mod add;
+mod bound;
+mod div;
+mod dot;
+mod l1norm;
+mod l2norm;
+mod minmax;
+mod mul;
+mod signum;
+mod sub;
+mod zero;
+
+pub use add::*;
+pub use bound::*;
+pub use div::*;
+pub use dot::*;
+pub use l1norm::*;
+pub use l2norm::*;
+pub use minmax::*;
+pub use mul::*;
+pub use signum::*;
+pub use sub::*;
+pub use zero::*;
+
+#[cfg(test)]
+mod tests {
+    use num_bigfloat::{BigFloat, EPSILON, ONE};
+
+    /// `BigFloat` has a fixed precision of 40 significant decimal digits (between 129 and 130
+    /// bits) which can not be changed.
+    #[test]
+    fn test_precision() {
+        // A change in the 40th significant digit is representable ...
+        assert_eq!(EPSILON, BigFloat::parse("1e-39").unwrap());
+        assert!(ONE + EPSILON > ONE);
+        // ... a change in the 41st significant digit is not.
+        assert_eq!(ONE + BigFloat::parse("1e-40").unwrap(), ONE);
+        assert!(EPSILON < BigFloat::from_f64(2.0f64.powi(-129)));
+        assert!(EPSILON > BigFloat::from_f64(2.0f64.powi(-130)));
+
+        // Rounding errors are of the order of `EPSILON`
+        let two = BigFloat::from(2u8);
+        let err = (two.sqrt() * two.sqrt() - two).abs();
+        assert!(err > BigFloat::from(0u8));
+        assert!(err <= two * EPSILON);
+    }
+}
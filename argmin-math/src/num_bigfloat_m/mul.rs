@@ -0,0 +1,96 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminMul;
+use num_bigfloat::BigFloat;
+
+impl ArgminMul<BigFloat, BigFloat> for BigFloat {
+    #[inline]
+    fn mul(&self, other: &BigFloat) -> BigFloat {
+        *self * *other
+    }
+}
+
+impl ArgminMul<BigFloat, Vec<BigFloat>> for Vec<BigFloat> {
+    #[inline]
+    fn mul(&self, other: &BigFloat) -> Vec<BigFloat> {
+        self.iter().map(|a| *a * *other).collect()
+    }
+}
+
+impl ArgminMul<Vec<BigFloat>, Vec<BigFloat>> for BigFloat {
+    #[inline]
+    fn mul(&self, other: &Vec<BigFloat>) -> Vec<BigFloat> {
+        other.iter().map(|a| *self * *a).collect()
+    }
+}
+
+impl ArgminMul<Vec<BigFloat>, Vec<BigFloat>> for Vec<BigFloat> {
+    #[inline]
+    fn mul(&self, other: &Vec<BigFloat>) -> Vec<BigFloat> {
+        let n1 = self.len();
+        let n2 = other.len();
+        assert!(n1 > 0);
+        assert!(n2 > 0);
+        assert_eq!(n1, n2);
+        self.iter()
+            .zip(other.iter())
+            .map(|(a, b)| *a * *b)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v(x: &[f64]) -> Vec<BigFloat> {
+        x.iter().map(|x| BigFloat::from_f64(*x)).collect()
+    }
+
+    #[test]
+    fn test_mul_scalar_scalar() {
+        let a = BigFloat::parse("1.0000000000000000000000000001").unwrap();
+        let res = <BigFloat as ArgminMul<BigFloat, BigFloat>>::mul(&a, &a);
+        let target = BigFloat::parse("1.0000000000000000000000000002").unwrap();
+        // The square term 1e-56 is below the precision of `BigFloat`
+        assert_eq!(res, target);
+    }
+
+    #[test]
+    fn test_mul_vec_scalar() {
+        let a = v(&[1.0, 4.0, 8.0]);
+        let b = BigFloat::from_f64(2.0);
+        let target = v(&[2.0, 8.0, 16.0]);
+        assert_eq!(
+            <Vec<BigFloat> as ArgminMul<BigFloat, Vec<BigFloat>>>::mul(&a, &b),
+            target
+        );
+        assert_eq!(
+            <BigFloat as ArgminMul<Vec<BigFloat>, Vec<BigFloat>>>::mul(&b, &a),
+            target
+        );
+    }
+
+    #[test]
+    fn test_mul_vec_vec() {
+        let a = v(&[1.0, 4.0, 8.0]);
+        let b = v(&[3.0, 2.0, 1.0]);
+        assert_eq!(
+            <Vec<BigFloat> as ArgminMul<Vec<BigFloat>, Vec<BigFloat>>>::mul(&a, &b),
+            v(&[3.0, 8.0, 8.0])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mul_vec_vec_panic() {
+        let a = v(&[1.0, 4.0]);
+        let b = v(&[3.0, 2.0, 1.0]);
+        <Vec<BigFloat> as ArgminMul<Vec<BigFloat>, Vec<BigFloat>>>::mul(&a, &b);
+    }
+}
@@ -0,0 +1,32 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminSignum;
+use num_bigfloat::BigFloat;
+
+impl ArgminSignum for Vec<BigFloat> {
+    fn signum(mut self) -> Self {
+        for x in &mut self {
+            *x = x.signum();
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signum() {
+        let a = vec![BigFloat::from_f64(3.0), BigFloat::from_f64(-4.0)];
+        assert_eq!(
+            a.signum(),
+            vec![BigFloat::from_f64(1.0), BigFloat::from_f64(-1.0)]
+        );
+    }
+}
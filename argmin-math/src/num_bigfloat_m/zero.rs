@@ -0,0 +1,36 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminZero, ArgminZeroLike};
+use num_bigfloat::{BigFloat, ZERO};
+
+impl ArgminZero for BigFloat {
+    #[inline]
+    fn zero() -> BigFloat {
+        ZERO
+    }
+}
+
+impl ArgminZeroLike for BigFloat {
+    #[inline]
+    fn zero_like(&self) -> BigFloat {
+        ZERO
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigfloat::ONE;
+
+    #[test]
+    fn test_zero() {
+        assert_eq!(<BigFloat as ArgminZero>::zero(), ZERO);
+        assert_eq!(ONE.zero_like(), ZERO);
+        assert_eq!(vec![ONE; 3].zero_like(), vec![ZERO; 3]);
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminDiv;
+use num_dual::Dual;
+
+macro_rules! make_div {
+    ($t:ty) => {
+        impl ArgminDiv<Dual<$t>, Dual<$t>> for Dual<$t> {
+            #[inline]
+            fn div(&self, other: &Dual<$t>) -> Dual<$t> {
+                *self / *other
+            }
+        }
+
+        impl ArgminDiv<Dual<$t>, Vec<Dual<$t>>> for Vec<Dual<$t>> {
+            #[inline]
+            fn div(&self, other: &Dual<$t>) -> Vec<Dual<$t>> {
+                self.iter().map(|a| *a / *other).collect()
+            }
+        }
+
+        impl ArgminDiv<Vec<Dual<$t>>, Vec<Dual<$t>>> for Dual<$t> {
+            #[inline]
+            fn div(&self, other: &Vec<Dual<$t>>) -> Vec<Dual<$t>> {
+                other.iter().map(|a| *self / *a).collect()
+            }
+        }
+
+        impl ArgminDiv<Vec<Dual<$t>>, Vec<Dual<$t>>> for Vec<Dual<$t>> {
+            #[inline]
+            fn div(&self, other: &Vec<Dual<$t>>) -> Vec<Dual<$t>> {
+                let n1 = self.len();
+                let n2 = other.len();
+                assert!(n1 > 0);
+                assert!(n2 > 0);
+                assert_eq!(n1, n2);
+                self.iter()
+                    .zip(other.iter())
+                    .map(|(a, b)| *a / *b)
+                    .collect()
+            }
+        }
+    };
+}
+
+make_div!(f32);
+make_div!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_div_ $t>]() {
+                    // Derivatives with respect to `x` of `a * x / b` with `x = 2`
+                    let a = vec![Dual::new(2.0 as $t, 1.0), Dual::new(3.0, 1.0)];
+                    let b = vec![Dual::new(4.0 as $t, 0.0), Dual::new(-1.0, 0.0)];
+                    let s = Dual::new(2.0 as $t, 1.0);
+                    let cases = [
+                        (<Vec<Dual<$t>> as ArgminDiv<_, _>>::div(&a, &b), a.iter().zip(b.iter()).map(|(a, b)| *a / *b).collect::<Vec<_>>()),
+                        (<Vec<Dual<$t>> as ArgminDiv<_, _>>::div(&a, &s), a.iter().map(|a| *a / s).collect()),
+                        (<Dual<$t> as ArgminDiv<_, _>>::div(&s, &b), b.iter().map(|b| s / *b).collect()),
+                        (vec![<Dual<$t> as ArgminDiv<_, _>>::div(&s, &b[0])], vec![s / b[0]]),
+                    ];
+                    for (res, target) in cases {
+                        assert_eq!(res.len(), target.len());
+                        for (r, t) in res.iter().zip(target.iter()) {
+                            assert_relative_eq!(r.re, t.re, epsilon = <$t>::EPSILON);
+                            assert_relative_eq!(r.eps, t.eps, epsilon = <$t>::EPSILON);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                #[should_panic]
+                fn [<test_div_vec_vec_panic_ $t>]() {
+                    let a = vec![Dual::new(1.0 as $t, 0.0); 2];
+                    let b = vec![Dual::new(1.0 as $t, 0.0); 3];
+                    <Vec<Dual<$t>> as ArgminDiv<Vec<Dual<$t>>, Vec<Dual<$t>>>>::div(&a, &b);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,80 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminDot;
+use num_dual::Dual;
+
+macro_rules! make_dot {
+    ($t:ty) => {
+        impl ArgminDot<Vec<Dual<$t>>, Dual<$t>> for Vec<Dual<$t>> {
+            #[inline]
+            fn dot(&self, other: &Vec<Dual<$t>>) -> Dual<$t> {
+                self.iter().zip(other.iter()).map(|(a, b)| *a * *b).sum()
+            }
+        }
+
+        impl ArgminDot<Dual<$t>, Vec<Dual<$t>>> for Vec<Dual<$t>> {
+            #[inline]
+            fn dot(&self, other: &Dual<$t>) -> Vec<Dual<$t>> {
+                self.iter().map(|a| *a * *other).collect()
+            }
+        }
+
+        impl ArgminDot<Vec<Dual<$t>>, Vec<Dual<$t>>> for Dual<$t> {
+            #[inline]
+            fn dot(&self, other: &Vec<Dual<$t>>) -> Vec<Dual<$t>> {
+                other.iter().map(|a| *self * *a).collect()
+            }
+        }
+    };
+}
+
+make_dot!(f32);
+make_dot!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_dot_vec_vec_ $t>]() {
+                    // d/dx (x, 2x) . (3, x) = d/dx (3x + 2x^2) = 3 + 4x
+                    let x = 1.5 as $t;
+                    let a = vec![Dual::new(x, 1.0), Dual::new(2.0 * x, 2.0)];
+                    let b = vec![Dual::new(3.0 as $t, 0.0), Dual::new(x, 1.0)];
+                    let res: Dual<$t> = a.dot(&b);
+                    assert_relative_eq!(res.re, 3.0 * x + 2.0 * x * x, epsilon = <$t>::EPSILON);
+                    assert_relative_eq!(res.eps, 3.0 + 4.0 * x, epsilon = <$t>::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_dot_scalar_ $t>]() {
+                    let a = vec![Dual::new(1.0 as $t, 1.0), Dual::new(2.0, 0.0)];
+                    let s = Dual::new(3.0 as $t, 1.0);
+                    let res: Vec<Dual<$t>> = a.dot(&s);
+                    let res2: Vec<Dual<$t>> = s.dot(&a);
+                    for r in [res, res2] {
+                        assert_relative_eq!(r[0].re, 3.0, epsilon = <$t>::EPSILON);
+                        assert_relative_eq!(r[0].eps, 4.0, epsilon = <$t>::EPSILON);
+                        assert_relative_eq!(r[1].re, 6.0, epsilon = <$t>::EPSILON);
+                        assert_relative_eq!(r[1].eps, 2.0, epsilon = <$t>::EPSILON);
+                    }
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,49 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminL1Norm;
+use num_dual::Dual;
+use num_traits::Signed;
+
+macro_rules! make_l1norm {
+    ($t:ty) => {
+        impl ArgminL1Norm<Dual<$t>> for Vec<Dual<$t>> {
+            #[inline]
+            fn l1_norm(&self) -> Dual<$t> {
+                self.iter().map(|a| a.abs()).sum()
+            }
+        }
+    };
+}
+
+make_l1norm!(f32);
+make_l1norm!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_l1norm_ $t>]() {
+                    // d/dx (|x| + |-2x|) = 3 for x > 0
+                    let a = vec![Dual::new(1.0 as $t, 1.0), Dual::new(-2.0, -2.0)];
+                    let res: Dual<$t> = a.l1_norm();
+                    assert_relative_eq!(res.re, 3.0, epsilon = <$t>::EPSILON);
+                    assert_relative_eq!(res.eps, 3.0, epsilon = <$t>::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
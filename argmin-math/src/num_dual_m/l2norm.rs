@@ -0,0 +1,48 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminL2Norm;
+use num_dual::{Dual, DualNum};
+
+macro_rules! make_l2norm {
+    ($t:ty) => {
+        impl ArgminL2Norm<Dual<$t>> for Vec<Dual<$t>> {
+            #[inline]
+            fn l2_norm(&self) -> Dual<$t> {
+                self.iter().map(|a| a.powi(2)).sum::<Dual<$t>>().sqrt()
+            }
+        }
+    };
+}
+
+make_l2norm!(f32);
+make_l2norm!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_l2norm_ $t>]() {
+                    // d/dx ||(3x, 4x)|| = 5
+                    let a = vec![Dual::new(3.0 as $t, 3.0), Dual::new(4.0, 4.0)];
+                    let res: Dual<$t> = a.l2_norm();
+                    assert_relative_eq!(res.re, 5.0, epsilon = <$t>::EPSILON);
+                    assert_relative_eq!(res.eps, 5.0, epsilon = <$t>::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -0,0 +1,24 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+mod add;
+mod div;
+mod dot;
+mod l1norm;
+mod l2norm;
+mod mul;
+mod sub;
+mod zero;
+
+pub use add::*;
+pub use div::*;
+pub use dot::*;
+pub use l1norm::*;
+pub use l2norm::*;
+pub use mul::*;
+pub use sub::*;
+pub use zero::*;
@@ -0,0 +1,56 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminZero, ArgminZeroLike};
+use num_dual::Dual;
+use num_traits::Zero;
+
+macro_rules! make_zero {
+    ($t:ty) => {
+        impl ArgminZero for Dual<$t> {
+            #[inline]
+            fn zero() -> Dual<$t> {
+                <Dual<$t> as Zero>::zero()
+            }
+        }
+
+        impl ArgminZeroLike for Dual<$t> {
+            #[inline]
+            fn zero_like(&self) -> Dual<$t> {
+                <Dual<$t> as Zero>::zero()
+            }
+        }
+    };
+}
+
+make_zero!(f32);
+make_zero!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_zero_like_ $t>]() {
+                    let a = vec![Dual::new(1.0 as $t, 2.0); 3];
+                    for z in a.zero_like() {
+                        assert!(z.re.is_zero() && z.eps.is_zero());
+                    }
+                    let z = <Dual<$t> as ArgminZero>::zero();
+                    assert!(z.re.is_zero() && z.eps.is_zero());
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -7,6 +7,8 @@
 
 use crate::ArgminL2Norm;
 use num_complex::Complex;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
 
 macro_rules! make_norm_unsigned {
     ($t:ty) => {
@@ -0,0 +1,92 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminDot, ArgminTranspose, Error, LinearOperator};
+
+impl<P, T> LinearOperator<P> for T
+where
+    T: ArgminDot<P, P> + ArgminTranspose<T> + Clone,
+{
+    #[inline]
+    fn apply(&self, x: &P) -> Result<P, Error> {
+        Ok(self.dot(x))
+    }
+
+    #[inline]
+    fn apply_transpose(&self, x: &P) -> Result<P, Error> {
+        Ok(self.clone().t().dot(x))
+    }
+}
+
+#[cfg(feature = "vec")]
+#[cfg(test)]
+mod tests_vec {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_ $t>]() {
+                    let a = vec![
+                        vec![1 as $t, 2 as $t, 3 as $t],
+                        vec![4 as $t, 5 as $t, 6 as $t],
+                        vec![7 as $t, 8 as $t, 9 as $t],
+                    ];
+                    let x = vec![1 as $t, 0 as $t, 2 as $t];
+                    assert_eq!(a.apply(&x).unwrap(), vec![7 as $t, 16 as $t, 25 as $t]);
+                    assert_eq!(a.apply_transpose(&x).unwrap(), vec![15 as $t, 18 as $t, 21 as $t]);
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+}
+
+#[cfg(feature = "ndarray_all")]
+#[cfg(test)]
+mod tests_ndarray {
+    use super::*;
+    use ndarray::array;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_ $t>]() {
+                    let a = array![
+                        [1 as $t, 2 as $t, 3 as $t],
+                        [4 as $t, 5 as $t, 6 as $t],
+                        [7 as $t, 8 as $t, 9 as $t],
+                    ];
+                    let x = array![1 as $t, 0 as $t, 2 as $t];
+                    assert_eq!(a.apply(&x).unwrap(), array![7 as $t, 16 as $t, 25 as $t]);
+                    assert_eq!(a.apply_transpose(&x).unwrap(), array![15 as $t, 18 as $t, 21 as $t]);
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+}
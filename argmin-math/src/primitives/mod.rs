@@ -11,13 +11,16 @@ mod div;
 mod dot;
 mod l1norm;
 mod l2norm;
+mod linearoperator;
 mod mul;
+#[cfg(feature = "std")]
 mod random;
 mod scaledadd;
 mod scaledsub;
 mod sub;
 mod transpose;
 mod weighteddot;
+mod weightedl2norm;
 mod zero;
 
 pub use add::*;
@@ -26,11 +29,14 @@ pub use div::*;
 pub use dot::*;
 pub use l1norm::*;
 pub use l2norm::*;
+pub use linearoperator::*;
 pub use mul::*;
+#[cfg(feature = "std")]
 pub use random::*;
 pub use scaledadd::*;
 pub use scaledsub::*;
 pub use sub::*;
 pub use transpose::*;
 pub use weighteddot::*;
+pub use weightedl2norm::*;
 pub use zero::*;
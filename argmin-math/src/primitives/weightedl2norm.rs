@@ -0,0 +1,71 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminDot, ArgminMul, ArgminWeightedL2Norm};
+use num_traits::Float;
+
+impl<T, U> ArgminWeightedL2Norm<T, U> for T
+where
+    T: ArgminMul<T, T> + ArgminDot<T, U>,
+    U: Float,
+{
+    #[inline]
+    fn weighted_l2_norm(&self, w: &T) -> U {
+        self.dot(&self.mul(w)).sqrt()
+    }
+}
+
+#[cfg(feature = "vec")]
+#[cfg(test)]
+mod tests_vec {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_ $t>]() {
+                    let a = vec![3 as $t, 1 as $t, 2 as $t];
+                    let w = vec![1 as $t, 6 as $t, 0.25 as $t];
+                    let res: $t = a.weighted_l2_norm(&w);
+                    assert_relative_eq!(res, 4 as $t, epsilon = $t::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
+
+#[cfg(feature = "ndarray_all")]
+#[cfg(test)]
+mod tests_ndarray {
+    use super::*;
+    use approx::assert_relative_eq;
+    use ndarray::array;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_ $t>]() {
+                    let a = array![3 as $t, 1 as $t, 2 as $t];
+                    let w = array![1 as $t, 6 as $t, 0.25 as $t];
+                    let res: $t = a.weighted_l2_norm(&w);
+                    assert_relative_eq!(res, 4 as $t, epsilon = $t::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
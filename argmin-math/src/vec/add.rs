@@ -6,6 +6,8 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::ArgminAdd;
+use alloc::vec::Vec;
+use num_complex::Complex;
 
 macro_rules! make_add {
     ($t:ty) => {
@@ -23,18 +25,6 @@ macro_rules! make_add {
             }
         }
 
-        impl ArgminAdd<Vec<$t>, Vec<$t>> for Vec<$t> {
-            #[inline]
-            fn add(&self, other: &Vec<$t>) -> Vec<$t> {
-                let n1 = self.len();
-                let n2 = other.len();
-                assert!(n1 > 0);
-                assert!(n2 > 0);
-                assert_eq!(n1, n2);
-                self.iter().zip(other.iter()).map(|(a, b)| a + b).collect()
-            }
-        }
-
         impl ArgminAdd<Vec<Vec<$t>>, Vec<Vec<$t>>> for Vec<Vec<$t>> {
             #[inline]
             fn add(&self, other: &Vec<Vec<$t>>) -> Vec<Vec<$t>> {
@@ -72,6 +62,23 @@ macro_rules! make_add {
     };
 }
 
+// The SIMD implementations for `f32` and `f64` are in `simd.rs`.
+macro_rules! make_add_vec_vec {
+    ($t:ty) => {
+        impl ArgminAdd<Vec<$t>, Vec<$t>> for Vec<$t> {
+            #[inline]
+            fn add(&self, other: &Vec<$t>) -> Vec<$t> {
+                let n1 = self.len();
+                let n2 = other.len();
+                assert!(n1 > 0);
+                assert!(n2 > 0);
+                assert_eq!(n1, n2);
+                self.iter().zip(other.iter()).map(|(a, b)| a + b).collect()
+            }
+        }
+    };
+}
+
 make_add!(isize);
 make_add!(usize);
 make_add!(i8);
@@ -85,6 +92,68 @@ make_add!(u64);
 make_add!(f32);
 make_add!(f64);
 
+// Real scalars act on complex vectors as in complex arithmetic (only the real part is shifted).
+macro_rules! make_add_real_scalar {
+    ($t:ty) => {
+        impl ArgminAdd<$t, Vec<Complex<$t>>> for Vec<Complex<$t>> {
+            #[inline]
+            fn add(&self, other: &$t) -> Vec<Complex<$t>> {
+                self.iter().map(|a| a + other).collect()
+            }
+        }
+
+        impl ArgminAdd<Vec<Complex<$t>>, Vec<Complex<$t>>> for $t {
+            #[inline]
+            fn add(&self, other: &Vec<Complex<$t>>) -> Vec<Complex<$t>> {
+                other.iter().map(|a| self + a).collect()
+            }
+        }
+    };
+}
+
+make_add!(Complex<isize>);
+make_add!(Complex<usize>);
+make_add!(Complex<i8>);
+make_add!(Complex<i16>);
+make_add!(Complex<i32>);
+make_add!(Complex<i64>);
+make_add!(Complex<u8>);
+make_add!(Complex<u16>);
+make_add!(Complex<u32>);
+make_add!(Complex<u64>);
+make_add!(Complex<f32>);
+make_add!(Complex<f64>);
+
+make_add_vec_vec!(isize);
+make_add_vec_vec!(usize);
+make_add_vec_vec!(i8);
+make_add_vec_vec!(i16);
+make_add_vec_vec!(i32);
+make_add_vec_vec!(i64);
+make_add_vec_vec!(u8);
+make_add_vec_vec!(u16);
+make_add_vec_vec!(u32);
+make_add_vec_vec!(u64);
+#[cfg(not(feature = "vec-simd"))]
+make_add_vec_vec!(f32);
+#[cfg(not(feature = "vec-simd"))]
+make_add_vec_vec!(f64);
+make_add_vec_vec!(Complex<isize>);
+make_add_vec_vec!(Complex<usize>);
+make_add_vec_vec!(Complex<i8>);
+make_add_vec_vec!(Complex<i16>);
+make_add_vec_vec!(Complex<i32>);
+make_add_vec_vec!(Complex<i64>);
+make_add_vec_vec!(Complex<u8>);
+make_add_vec_vec!(Complex<u16>);
+make_add_vec_vec!(Complex<u32>);
+make_add_vec_vec!(Complex<u64>);
+make_add_vec_vec!(Complex<f32>);
+make_add_vec_vec!(Complex<f64>);
+
+make_add_real_scalar!(f32);
+make_add_real_scalar!(f64);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -265,4 +334,27 @@ mod tests {
     make_test!(u64);
     make_test!(f32);
     make_test!(f64);
+
+    #[test]
+    fn test_add_vec_vec_complex() {
+        let a = vec![Complex::new(1.0f64, 2.0), Complex::new(3.0, -1.0)];
+        let b = vec![Complex::new(2.0f64, 1.0), Complex::new(-3.0, 4.0)];
+        let res =
+            <Vec<Complex<f64>> as ArgminAdd<Vec<Complex<f64>>, Vec<Complex<f64>>>>::add(&a, &b);
+        assert_eq!(res, vec![Complex::new(3.0, 3.0), Complex::new(0.0, 3.0)]);
+    }
+
+    #[test]
+    fn test_add_real_scalar_complex() {
+        let a = vec![Complex::new(1.0f64, 2.0), Complex::new(3.0, -1.0)];
+        let target = vec![Complex::new(3.0, 2.0), Complex::new(5.0, -1.0)];
+        assert_eq!(
+            <Vec<Complex<f64>> as ArgminAdd<f64, Vec<Complex<f64>>>>::add(&a, &2.0),
+            target
+        );
+        assert_eq!(
+            <f64 as ArgminAdd<Vec<Complex<f64>>, Vec<Complex<f64>>>>::add(&2.0, &a),
+            target
+        );
+    }
 }
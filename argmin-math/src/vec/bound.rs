@@ -0,0 +1,125 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::ArgminBound;
+use alloc::vec::Vec;
+
+macro_rules! make_bound {
+    ($t:ty) => {
+        impl ArgminBound for Vec<$t> {
+            fn valid_bounds(lower: &Self, upper: &Self) -> bool {
+                lower.len() == upper.len() && lower.iter().zip(upper.iter()).all(|(l, u)| l <= u)
+            }
+
+            fn within_bounds(&self, lower: &Self, upper: &Self) -> bool {
+                self.len() == lower.len()
+                    && self.len() == upper.len()
+                    && self
+                        .iter()
+                        .zip(lower.iter().zip(upper.iter()))
+                        .all(|(x, (l, u))| l <= x && x <= u)
+            }
+
+            fn clamp_to_bounds(&self, lower: &Self, upper: &Self) -> Self {
+                assert_eq!(self.len(), lower.len());
+                assert_eq!(self.len(), upper.len());
+
+                self.iter()
+                    .zip(lower.iter().zip(upper.iter()))
+                    .map(|(x, (l, u))| {
+                        if x < l {
+                            *l
+                        } else if x > u {
+                            *u
+                        } else {
+                            *x
+                        }
+                    })
+                    .collect()
+            }
+        }
+    };
+}
+
+make_bound!(isize);
+make_bound!(usize);
+make_bound!(i8);
+make_bound!(u8);
+make_bound!(i16);
+make_bound!(u16);
+make_bound!(i32);
+make_bound!(u32);
+make_bound!(i64);
+make_bound!(u64);
+make_bound!(f32);
+make_bound!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_bound_vec_ $t>]() {
+                    let lower = vec![1 as $t, 2 as $t, 3 as $t];
+                    let upper = vec![4 as $t, 5 as $t, 6 as $t];
+                    assert!(<Vec<$t> as ArgminBound>::valid_bounds(&lower, &upper));
+                    assert!(<Vec<$t> as ArgminBound>::valid_bounds(&lower, &lower));
+                    assert!(!<Vec<$t> as ArgminBound>::valid_bounds(&upper, &lower));
+                    assert!(!<Vec<$t> as ArgminBound>::valid_bounds(&lower, &vec![4 as $t]));
+
+                    let inside = vec![1 as $t, 3 as $t, 6 as $t];
+                    let outside = vec![0 as $t, 3 as $t, 7 as $t];
+                    assert!(inside.within_bounds(&lower, &upper));
+                    assert!(!outside.within_bounds(&lower, &upper));
+                    assert!(!vec![2 as $t].within_bounds(&lower, &upper));
+
+                    assert_eq!(inside.clamp_to_bounds(&lower, &upper), inside);
+                    assert_eq!(
+                        outside.clamp_to_bounds(&lower, &upper),
+                        vec![1 as $t, 3 as $t, 6 as $t]
+                    );
+                }
+            }
+        };
+    }
+
+    make_test!(i8);
+    make_test!(u8);
+    make_test!(i16);
+    make_test!(u16);
+    make_test!(i32);
+    make_test!(u32);
+    make_test!(i64);
+    make_test!(u64);
+    make_test!(isize);
+    make_test!(usize);
+    make_test!(f32);
+    make_test!(f64);
+
+    #[test]
+    fn test_bound_vec_nan() {
+        let lower = vec![0.0f64, f64::NEG_INFINITY];
+        let upper = vec![1.0f64, f64::INFINITY];
+        assert!(<Vec<f64> as ArgminBound>::valid_bounds(&lower, &upper));
+        assert!(!<Vec<f64> as ArgminBound>::valid_bounds(
+            &vec![f64::NAN, 0.0],
+            &upper
+        ));
+        assert!(vec![0.5, -1e300].within_bounds(&lower, &upper));
+        assert!(!vec![f64::NAN, 0.0].within_bounds(&lower, &upper));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_clamp_to_bounds_vec_wrong_length() {
+        let _ = vec![1.0f64].clamp_to_bounds(&vec![0.0, 0.0], &vec![1.0, 1.0]);
+    }
+}
@@ -0,0 +1,138 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Compensated reductions of `Vec<f32>` and `Vec<f64>`
+//!
+//! Dot products and norms are accumulated with Neumaier's variant of Kahan summation, which
+//! carries the rounding error of every addition in a second accumulator. The error of the result
+//! is therefore bounded by a few ulps independent of the length of the vector, whereas the error
+//! of naive summation grows linearly with the length. This costs about four additional floating
+//! point operations per element. `Vec<f32>` is accumulated in `f64`.
+
+#[cfg(feature = "vec-compensated")]
+use crate::{ArgminDot, ArgminL1Norm, ArgminL2Norm};
+#[cfg(feature = "vec-compensated")]
+use alloc::vec::Vec;
+use num_traits::Float;
+
+/// Compensated sum of `iter` (Neumaier)
+#[inline]
+pub(crate) fn compensated_sum<F: Float, I: Iterator<Item = F>>(iter: I) -> F {
+    let mut sum = F::zero();
+    let mut c = F::zero();
+    for x in iter {
+        let t = sum + x;
+        if sum.abs() >= x.abs() {
+            c = c + ((sum - t) + x);
+        } else {
+            c = c + ((x - t) + sum);
+        }
+        sum = t;
+    }
+    sum + c
+}
+
+#[cfg(feature = "vec-compensated")]
+macro_rules! make_compensated {
+    ($t:ty, $acc:ty) => {
+        impl ArgminDot<Vec<$t>, $t> for Vec<$t> {
+            #[inline]
+            fn dot(&self, other: &Vec<$t>) -> $t {
+                compensated_sum(
+                    self.iter()
+                        .zip(other.iter())
+                        .map(|(a, b)| <$acc>::from(*a) * <$acc>::from(*b)),
+                ) as $t
+            }
+        }
+
+        impl ArgminL1Norm<$t> for Vec<$t> {
+            #[inline]
+            fn l1_norm(&self) -> $t {
+                compensated_sum(self.iter().map(|a| <$acc>::from(a.abs()))) as $t
+            }
+        }
+
+        impl ArgminL2Norm<$t> for Vec<$t> {
+            #[inline]
+            fn l2_norm(&self) -> $t {
+                compensated_sum(self.iter().map(|a| <$acc>::from(*a).powi(2))).sqrt() as $t
+            }
+        }
+    };
+}
+
+// The compensation term of `f32` itself accumulates rounding errors which become noticeable for
+// vectors with millions of elements. Products of two `f32` are exact in `f64`, therefore `f32`
+// reductions are carried out in `f64` and rounded once.
+#[cfg(feature = "vec-compensated")]
+make_compensated!(f32, f64);
+#[cfg(feature = "vec-compensated")]
+make_compensated!(f64, f64);
+
+#[cfg(all(test, feature = "vec-compensated"))]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_compensated_sum_ $t>]() {
+                    // Naive summation returns 0
+                    let a: Vec<$t> = vec![1.0, 1.0e20, 1.0, -1.0e20];
+                    let b: Vec<$t> = vec![1.0; 4];
+                    let res: $t = a.dot(&b);
+                    assert_relative_eq!(res, 2.0, epsilon = <$t>::EPSILON);
+                    let res: $t = a.l1_norm();
+                    assert_relative_eq!(res, 2.0e20, max_relative = <$t>::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_compensated_long_ $t>]() {
+                    // Sequential summation of a million small values loses several digits
+                    let n = 1_000_000;
+                    let a: Vec<$t> = vec![0.1; n];
+                    let b: Vec<$t> = vec![1.0; n];
+                    let exact = f64::from(0.1 as $t) * n as f64;
+                    let res: $t = a.dot(&b);
+                    assert_relative_eq!(f64::from(res), exact, max_relative = f64::from(<$t>::EPSILON));
+                    let res: $t = a.l1_norm();
+                    assert_relative_eq!(f64::from(res), exact, max_relative = f64::from(<$t>::EPSILON));
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_compensated_norms_ $t>]() {
+                    let a: Vec<$t> = vec![3.0, -4.0];
+                    let res: $t = a.l2_norm();
+                    assert_relative_eq!(res, 5.0, epsilon = <$t>::EPSILON);
+                    let res: $t = a.l1_norm();
+                    assert_relative_eq!(res, 7.0, epsilon = <$t>::EPSILON);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_compensated_dot_truncates_ $t>]() {
+                    let a: Vec<$t> = vec![1.0; 11];
+                    let b: Vec<$t> = vec![2.0; 9];
+                    let res: $t = a.dot(&b);
+                    assert_relative_eq!(res, 18.0, epsilon = <$t>::EPSILON);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
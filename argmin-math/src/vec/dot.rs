@@ -7,17 +7,11 @@
 
 use crate::ArgminDot;
 use crate::ArgminTranspose;
+use alloc::{vec, vec::Vec};
 use num_complex::Complex;
 
 macro_rules! make_dot_vec {
     ($t:ty) => {
-        impl ArgminDot<Vec<$t>, $t> for Vec<$t> {
-            #[inline]
-            fn dot(&self, other: &Vec<$t>) -> $t {
-                self.iter().zip(other.iter()).map(|(a, b)| a * b).sum()
-            }
-        }
-
         impl ArgminDot<$t, Vec<$t>> for Vec<$t> {
             #[inline]
             fn dot(&self, other: &$t) -> Vec<$t> {
@@ -94,6 +88,18 @@ macro_rules! make_dot_vec {
     };
 }
 
+// The SIMD implementations for `f32` and `f64` are in `simd.rs`.
+macro_rules! make_dot_vec_vec {
+    ($t:ty) => {
+        impl ArgminDot<Vec<$t>, $t> for Vec<$t> {
+            #[inline]
+            fn dot(&self, other: &Vec<$t>) -> $t {
+                self.iter().zip(other.iter()).map(|(a, b)| a * b).sum()
+            }
+        }
+    };
+}
+
 make_dot_vec!(f32);
 make_dot_vec!(f64);
 make_dot_vec!(i8);
@@ -119,6 +125,53 @@ make_dot_vec!(Complex<u64>);
 make_dot_vec!(Complex<isize>);
 make_dot_vec!(Complex<usize>);
 
+#[cfg(not(any(feature = "vec-simd", feature = "vec-compensated")))]
+make_dot_vec_vec!(f32);
+#[cfg(not(any(feature = "vec-simd", feature = "vec-compensated")))]
+make_dot_vec_vec!(f64);
+make_dot_vec_vec!(i8);
+make_dot_vec_vec!(i16);
+make_dot_vec_vec!(i32);
+make_dot_vec_vec!(i64);
+make_dot_vec_vec!(u8);
+make_dot_vec_vec!(u16);
+make_dot_vec_vec!(u32);
+make_dot_vec_vec!(u64);
+make_dot_vec_vec!(isize);
+make_dot_vec_vec!(usize);
+make_dot_vec_vec!(Complex<f32>);
+make_dot_vec_vec!(Complex<f64>);
+make_dot_vec_vec!(Complex<i8>);
+make_dot_vec_vec!(Complex<i16>);
+make_dot_vec_vec!(Complex<i32>);
+make_dot_vec_vec!(Complex<i64>);
+make_dot_vec_vec!(Complex<u8>);
+make_dot_vec_vec!(Complex<u16>);
+make_dot_vec_vec!(Complex<u32>);
+make_dot_vec_vec!(Complex<u64>);
+make_dot_vec_vec!(Complex<isize>);
+make_dot_vec_vec!(Complex<usize>);
+
+// Real inner product `Re(sum_i conj(a_i) b_i)` of complex vectors. This is the standard inner
+// product of the vectors of real and imaginary parts, which together with gradients in Wirtinger
+// form lets solvers treat complex vectors like real vectors of twice the length.
+macro_rules! make_dot_complex_real {
+    ($t:ty) => {
+        impl ArgminDot<Vec<Complex<$t>>, $t> for Vec<Complex<$t>> {
+            #[inline]
+            fn dot(&self, other: &Vec<Complex<$t>>) -> $t {
+                self.iter()
+                    .zip(other.iter())
+                    .map(|(a, b)| a.re * b.re + a.im * b.im)
+                    .sum()
+            }
+        }
+    };
+}
+
+make_dot_complex_real!(f32);
+make_dot_complex_real!(f64);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -563,4 +616,12 @@ mod tests {
     make_test!(u64);
     make_test!(f32);
     make_test!(f64);
+
+    #[test]
+    fn test_vec_vec_complex_real() {
+        let a = vec![Complex::new(1.0f64, 2.0), Complex::new(3.0, -1.0)];
+        let b = vec![Complex::new(2.0f64, 1.0), Complex::new(-3.0, 4.0)];
+        let res: f64 = a.dot(&b);
+        assert_relative_eq!(res, 2.0 + 2.0 - 9.0 - 4.0, epsilon = f64::EPSILON);
+    }
 }
@@ -6,6 +6,7 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::ArgminEye;
+use alloc::{vec, vec::Vec};
 
 macro_rules! make_eye {
     ($t:ty) => {
@@ -6,6 +6,7 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::ArgminL1Norm;
+use alloc::vec::Vec;
 use num_complex::Complex;
 
 macro_rules! make_l1norm {
@@ -51,7 +52,9 @@ make_l1norm!(i16);
 make_l1norm!(i32);
 make_l1norm!(i64);
 make_l1norm!(isize);
+#[cfg(not(any(feature = "vec-simd", feature = "vec-compensated")))]
 make_l1norm!(f32);
+#[cfg(not(any(feature = "vec-simd", feature = "vec-compensated")))]
 make_l1norm!(f64);
 make_l1norm_complex!(Complex<i8>, i8);
 make_l1norm_complex!(Complex<i16>, i16);
@@ -6,9 +6,13 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::ArgminL2Norm;
+use alloc::vec::Vec;
 use num_complex::Complex;
 use num_integer::Roots;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
 
+#[cfg(not(any(feature = "vec-simd", feature = "vec-compensated")))]
 macro_rules! make_norm_float {
     ($t:ty) => {
         impl ArgminL2Norm<$t> for Vec<$t> {
@@ -63,7 +67,9 @@ make_norm_integer!(i16);
 make_norm_integer!(i32);
 make_norm_integer!(i64);
 make_norm_integer!(isize);
+#[cfg(not(any(feature = "vec-simd", feature = "vec-compensated")))]
 make_norm_float!(f32);
+#[cfg(not(any(feature = "vec-simd", feature = "vec-compensated")))]
 make_norm_float!(f64);
 make_norm_complex!(Complex<i8>, i8);
 make_norm_complex!(Complex<i16>, i16);
@@ -6,6 +6,8 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::ArgminMinMax;
+use alloc::vec::Vec;
+use num_complex::Complex;
 
 macro_rules! make_minmax {
     ($t:ty) => {
@@ -68,6 +70,36 @@ make_minmax!(u64);
 make_minmax!(f32);
 make_minmax!(f64);
 
+// Complex numbers are not ordered, therefore real and imaginary part are treated separately.
+macro_rules! make_minmax_complex {
+    ($t:ty) => {
+        impl ArgminMinMax for Vec<Complex<$t>> {
+            fn min(x: &Self, y: &Self) -> Self {
+                assert!(!x.is_empty());
+                assert_eq!(x.len(), y.len());
+
+                x.iter()
+                    .zip(y.iter())
+                    .map(|(a, b)| Complex::new(a.re.min(b.re), a.im.min(b.im)))
+                    .collect()
+            }
+
+            fn max(x: &Self, y: &Self) -> Self {
+                assert!(!x.is_empty());
+                assert_eq!(x.len(), y.len());
+
+                x.iter()
+                    .zip(y.iter())
+                    .map(|(a, b)| Complex::new(a.re.max(b.re), a.im.max(b.im)))
+                    .collect()
+            }
+        }
+    };
+}
+
+make_minmax_complex!(f32);
+make_minmax_complex!(f64);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -136,4 +168,18 @@ mod tests {
     make_test!(usize);
     make_test!(f32);
     make_test!(f64);
+
+    #[test]
+    fn test_minmax_vec_vec_complex() {
+        let a = vec![Complex::new(1.0f64, 4.0), Complex::new(-3.0, 2.0)];
+        let b = vec![Complex::new(2.0f64, 3.0), Complex::new(-4.0, 5.0)];
+        assert_eq!(
+            <Vec<Complex<f64>> as ArgminMinMax>::min(&a, &b),
+            vec![Complex::new(1.0, 3.0), Complex::new(-4.0, 2.0)]
+        );
+        assert_eq!(
+            <Vec<Complex<f64>> as ArgminMinMax>::max(&a, &b),
+            vec![Complex::new(2.0, 4.0), Complex::new(-3.0, 5.0)]
+        );
+    }
 }
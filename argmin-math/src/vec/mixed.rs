@@ -0,0 +1,178 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Mixed precision: `Vec<f32>` parameter vectors with an `f64` floating point type
+//!
+//! Reductions convert every element to `f64` before multiplying. Products of two `f32` are exact
+//! in `f64`, therefore only the summation introduces rounding errors, which is compensated
+//! (Neumaier's variant of Kahan summation). The result of a reduction thus is the correctly
+//! rounded `f64` value up to a few ulps, independent of the length of the vector. Element-wise
+//! operations with an `f64` scalar are computed in `f64` and rounded to `f32` once.
+
+use super::compensated::compensated_sum;
+use crate::{ArgminAdd, ArgminDiv, ArgminDot, ArgminL1Norm, ArgminL2Norm, ArgminMul, ArgminSub};
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+impl ArgminDot<Vec<f32>, f64> for Vec<f32> {
+    #[inline]
+    fn dot(&self, other: &Vec<f32>) -> f64 {
+        compensated_sum(
+            self.iter()
+                .zip(other.iter())
+                .map(|(a, b)| f64::from(*a) * f64::from(*b)),
+        )
+    }
+}
+
+impl ArgminDot<f64, Vec<f32>> for Vec<f32> {
+    #[inline]
+    fn dot(&self, other: &f64) -> Vec<f32> {
+        self.iter()
+            .map(|a| (f64::from(*a) * other) as f32)
+            .collect()
+    }
+}
+
+impl ArgminDot<Vec<f32>, Vec<f32>> for f64 {
+    #[inline]
+    fn dot(&self, other: &Vec<f32>) -> Vec<f32> {
+        other
+            .iter()
+            .map(|a| (f64::from(*a) * self) as f32)
+            .collect()
+    }
+}
+
+impl ArgminL1Norm<f64> for Vec<f32> {
+    #[inline]
+    fn l1_norm(&self) -> f64 {
+        compensated_sum(self.iter().map(|a| f64::from(a.abs())))
+    }
+}
+
+impl ArgminL2Norm<f64> for Vec<f32> {
+    #[inline]
+    fn l2_norm(&self) -> f64 {
+        compensated_sum(self.iter().map(|a| f64::from(*a).powi(2))).sqrt()
+    }
+}
+
+macro_rules! make_elementwise {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl $trait<f64, Vec<f32>> for Vec<f32> {
+            #[inline]
+            fn $method(&self, other: &f64) -> Vec<f32> {
+                self.iter().map(|a| (f64::from(*a) $op other) as f32).collect()
+            }
+        }
+
+        impl $trait<Vec<f32>, Vec<f32>> for f64 {
+            #[inline]
+            fn $method(&self, other: &Vec<f32>) -> Vec<f32> {
+                other.iter().map(|a| (self $op f64::from(*a)) as f32).collect()
+            }
+        }
+    };
+}
+
+make_elementwise!(ArgminAdd, add, +);
+make_elementwise!(ArgminSub, sub, -);
+make_elementwise!(ArgminMul, mul, *);
+make_elementwise!(ArgminDiv, div, /);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ArgminScaledAdd, ArgminScaledSub};
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_dot() {
+        let a: Vec<f32> = vec![1.0, 2.0, 3.0];
+        let b: Vec<f32> = vec![4.0, 5.0, 6.0];
+        let res: f64 = a.dot(&b);
+        assert_relative_eq!(res, 32.0, epsilon = f64::EPSILON);
+    }
+
+    #[test]
+    fn test_dot_scalar() {
+        let a: Vec<f32> = vec![1.0, 2.0, 3.0];
+        let res: Vec<f32> = a.dot(&2.0f64);
+        assert_eq!(res, vec![2.0, 4.0, 6.0]);
+        let res: Vec<f32> = 2.0f64.dot(&a);
+        assert_eq!(res, vec![2.0, 4.0, 6.0]);
+    }
+
+    #[test]
+    fn test_dot_accuracy() {
+        // Summing 0.1f32 ten million times sequentially in `f32` is off by several percent. In
+        // mixed precision the result is exact up to the representation error of 0.1f32 itself.
+        let n = 10_000_000;
+        let a: Vec<f32> = vec![0.1; n];
+        let b: Vec<f32> = vec![1.0; n];
+        let res: f64 = a.dot(&b);
+        assert_relative_eq!(res, n as f64 * f64::from(0.1f32), max_relative = 1e-15);
+    }
+
+    #[test]
+    fn test_compensation() {
+        // Cancellation which plain `f64` summation gets wrong
+        let a: Vec<f32> = vec![1.0, 1.0e20, 1.0, -1.0e20];
+        let b: Vec<f32> = vec![1.0; 4];
+        let res: f64 = a.dot(&b);
+        assert_relative_eq!(res, 2.0, epsilon = f64::EPSILON);
+    }
+
+    #[test]
+    fn test_norms() {
+        let a: Vec<f32> = vec![3.0, -4.0];
+        let res: f64 = a.l2_norm();
+        assert_relative_eq!(res, 5.0, epsilon = f64::EPSILON);
+        let res: f64 = a.l1_norm();
+        assert_relative_eq!(res, 7.0, epsilon = f64::EPSILON);
+    }
+
+    #[test]
+    fn test_elementwise() {
+        let a: Vec<f32> = vec![1.0, 2.0, 4.0];
+        assert_eq!(
+            <Vec<f32> as ArgminAdd<f64, _>>::add(&a, &1.0),
+            vec![2.0, 3.0, 5.0]
+        );
+        assert_eq!(<f64 as ArgminAdd<_, _>>::add(&1.0, &a), vec![2.0, 3.0, 5.0]);
+        assert_eq!(
+            <Vec<f32> as ArgminSub<f64, _>>::sub(&a, &1.0),
+            vec![0.0, 1.0, 3.0]
+        );
+        assert_eq!(
+            <f64 as ArgminSub<_, _>>::sub(&1.0, &a),
+            vec![0.0, -1.0, -3.0]
+        );
+        assert_eq!(
+            <Vec<f32> as ArgminMul<f64, _>>::mul(&a, &0.5),
+            vec![0.5, 1.0, 2.0]
+        );
+        assert_eq!(<f64 as ArgminMul<_, _>>::mul(&0.5, &a), vec![0.5, 1.0, 2.0]);
+        assert_eq!(
+            <Vec<f32> as ArgminDiv<f64, _>>::div(&a, &2.0),
+            vec![0.5, 1.0, 2.0]
+        );
+        assert_eq!(<f64 as ArgminDiv<_, _>>::div(&4.0, &a), vec![4.0, 2.0, 1.0]);
+    }
+
+    #[test]
+    fn test_scaled() {
+        let a: Vec<f32> = vec![1.0, 2.0];
+        let b: Vec<f32> = vec![3.0, 4.0];
+        let res = <Vec<f32> as ArgminScaledAdd<Vec<f32>, f64, Vec<f32>>>::scaled_add(&a, &2.0, &b);
+        assert_eq!(res, vec![7.0, 10.0]);
+        let res = <Vec<f32> as ArgminScaledSub<Vec<f32>, f64, Vec<f32>>>::scaled_sub(&a, &2.0, &b);
+        assert_eq!(res, vec![-5.0, -6.0]);
+    }
+}
@@ -6,6 +6,9 @@
 // copied, modified, or distributed except according to those terms.
 
 mod add;
+mod bound;
+#[cfg(any(feature = "vec-compensated", feature = "vec-mixed"))]
+mod compensated;
 mod conj;
 mod div;
 mod dot;
@@ -13,16 +16,23 @@ mod eye;
 mod l1norm;
 mod l2norm;
 mod minmax;
+#[cfg(feature = "vec-mixed")]
+mod mixed;
 mod mul;
+#[cfg(feature = "std")]
 mod random;
 mod scaledadd;
 mod scaledsub;
 mod signum;
+#[cfg(feature = "vec-simd")]
+mod simd;
+mod solve;
 mod sub;
 mod transpose;
 mod zero;
 
 pub use add::*;
+pub use bound::*;
 pub use conj::*;
 pub use div::*;
 pub use dot::*;
@@ -31,10 +41,12 @@ pub use l1norm::*;
 pub use l2norm::*;
 pub use minmax::*;
 pub use mul::*;
+#[cfg(feature = "std")]
 pub use random::*;
 pub use scaledadd::*;
 pub use scaledsub::*;
 pub use signum::*;
+pub use solve::*;
 pub use sub::*;
 pub use transpose::*;
 pub use zero::*;
@@ -6,24 +6,11 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::ArgminMul;
+use alloc::vec::Vec;
 use num_complex::Complex;
 
 macro_rules! make_mul {
     ($t:ty) => {
-        impl ArgminMul<$t, Vec<$t>> for Vec<$t> {
-            #[inline]
-            fn mul(&self, other: &$t) -> Vec<$t> {
-                self.iter().map(|a| a * other).collect()
-            }
-        }
-
-        impl ArgminMul<Vec<$t>, Vec<$t>> for $t {
-            #[inline]
-            fn mul(&self, other: &Vec<$t>) -> Vec<$t> {
-                other.iter().map(|a| a * self).collect()
-            }
-        }
-
         impl ArgminMul<Vec<$t>, Vec<$t>> for Vec<$t> {
             #[inline]
             fn mul(&self, other: &Vec<$t>) -> Vec<$t> {
@@ -72,6 +59,25 @@ macro_rules! make_mul {
     };
 }
 
+// The SIMD implementations for `f32` and `f64` are in `simd.rs`.
+macro_rules! make_mul_scalar {
+    ($t:ty) => {
+        impl ArgminMul<$t, Vec<$t>> for Vec<$t> {
+            #[inline]
+            fn mul(&self, other: &$t) -> Vec<$t> {
+                self.iter().map(|a| a * other).collect()
+            }
+        }
+
+        impl ArgminMul<Vec<$t>, Vec<$t>> for $t {
+            #[inline]
+            fn mul(&self, other: &Vec<$t>) -> Vec<$t> {
+                other.iter().map(|a| a * self).collect()
+            }
+        }
+    };
+}
+
 make_mul!(isize);
 make_mul!(usize);
 make_mul!(i8);
@@ -97,6 +103,55 @@ make_mul!(Complex<u64>);
 make_mul!(Complex<f32>);
 make_mul!(Complex<f64>);
 
+make_mul_scalar!(isize);
+make_mul_scalar!(usize);
+make_mul_scalar!(i8);
+make_mul_scalar!(u8);
+make_mul_scalar!(i16);
+make_mul_scalar!(u16);
+make_mul_scalar!(i32);
+make_mul_scalar!(u32);
+make_mul_scalar!(i64);
+make_mul_scalar!(u64);
+#[cfg(not(feature = "vec-simd"))]
+make_mul_scalar!(f32);
+#[cfg(not(feature = "vec-simd"))]
+make_mul_scalar!(f64);
+make_mul_scalar!(Complex<isize>);
+make_mul_scalar!(Complex<usize>);
+make_mul_scalar!(Complex<i8>);
+make_mul_scalar!(Complex<u8>);
+make_mul_scalar!(Complex<i16>);
+make_mul_scalar!(Complex<u16>);
+make_mul_scalar!(Complex<i32>);
+make_mul_scalar!(Complex<u32>);
+make_mul_scalar!(Complex<i64>);
+make_mul_scalar!(Complex<u64>);
+make_mul_scalar!(Complex<f32>);
+make_mul_scalar!(Complex<f64>);
+
+// Real scalars act on complex vectors as in complex arithmetic (real and imaginary part are scaled).
+macro_rules! make_mul_real_scalar {
+    ($t:ty) => {
+        impl ArgminMul<$t, Vec<Complex<$t>>> for Vec<Complex<$t>> {
+            #[inline]
+            fn mul(&self, other: &$t) -> Vec<Complex<$t>> {
+                self.iter().map(|a| a * other).collect()
+            }
+        }
+
+        impl ArgminMul<Vec<Complex<$t>>, Vec<Complex<$t>>> for $t {
+            #[inline]
+            fn mul(&self, other: &Vec<Complex<$t>>) -> Vec<Complex<$t>> {
+                other.iter().map(|a| self * a).collect()
+            }
+        }
+    };
+}
+
+make_mul_real_scalar!(f32);
+make_mul_real_scalar!(f64);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -428,4 +483,18 @@ mod tests {
     make_test!(u64);
     make_test!(f32);
     make_test!(f64);
+
+    #[test]
+    fn test_mul_real_scalar_complex() {
+        let a = vec![Complex::new(1.0f64, 2.0), Complex::new(3.0, -1.0)];
+        let target = vec![Complex::new(2.0, 4.0), Complex::new(6.0, -2.0)];
+        assert_eq!(
+            <Vec<Complex<f64>> as ArgminMul<f64, Vec<Complex<f64>>>>::mul(&a, &2.0),
+            target
+        );
+        assert_eq!(
+            <f64 as ArgminMul<Vec<Complex<f64>>, Vec<Complex<f64>>>>::mul(&2.0, &a),
+            target
+        );
+    }
 }
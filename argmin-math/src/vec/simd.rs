@@ -0,0 +1,228 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Explicit SIMD implementations of the hot operations of `Vec<f32>` and `Vec<f64>`
+//!
+//! Vectors are processed in chunks of `f32x8` and `f64x4` lanes of the `wide` crate, which maps
+//! them to the SIMD instructions available on the target (SSE/AVX, NEON, WASM SIMD) and falls
+//! back to scalar code otherwise. The remainder which does not fill a whole chunk is handled
+//! element by element. Reductions accumulate one partial sum per lane, therefore their results
+//! may differ from the sequential sum in the last bits. With the `vec-compensated` feature the
+//! compensated reductions of `compensated.rs` are used instead of the SIMD ones.
+
+use crate::{ArgminAdd, ArgminMul, ArgminSub};
+#[cfg(not(feature = "vec-compensated"))]
+use crate::{ArgminDot, ArgminL1Norm, ArgminL2Norm};
+use alloc::vec::Vec;
+#[cfg(all(not(feature = "std"), not(feature = "vec-compensated")))]
+use num_traits::Float;
+use wide::{f32x8, f64x4};
+
+#[cfg(not(feature = "vec-compensated"))]
+macro_rules! make_simd_reductions {
+    ($t:ty, $simd:ty, $lanes:expr) => {
+        impl ArgminDot<Vec<$t>, $t> for Vec<$t> {
+            #[inline]
+            fn dot(&self, other: &Vec<$t>) -> $t {
+                let n = self.len().min(other.len());
+                let (a, b) = (&self[..n], &other[..n]);
+                let mut acc = <$simd>::splat(0.0);
+                for (x, y) in a.chunks_exact($lanes).zip(b.chunks_exact($lanes)) {
+                    acc +=
+                        <$simd>::new(x.try_into().unwrap()) * <$simd>::new(y.try_into().unwrap());
+                }
+                let tail = n - n % $lanes;
+                acc.reduce_add()
+                    + a[tail..]
+                        .iter()
+                        .zip(b[tail..].iter())
+                        .map(|(x, y)| x * y)
+                        .sum::<$t>()
+            }
+        }
+
+        impl ArgminL2Norm<$t> for Vec<$t> {
+            #[inline]
+            fn l2_norm(&self) -> $t {
+                <Self as ArgminDot<Vec<$t>, $t>>::dot(self, self).sqrt()
+            }
+        }
+
+        impl ArgminL1Norm<$t> for Vec<$t> {
+            #[inline]
+            fn l1_norm(&self) -> $t {
+                let mut acc = <$simd>::splat(0.0);
+                let chunks = self.chunks_exact($lanes);
+                let rest = chunks.remainder();
+                for x in chunks {
+                    acc += <$simd>::new(x.try_into().unwrap()).abs();
+                }
+                acc.reduce_add() + rest.iter().map(|x| x.abs()).sum::<$t>()
+            }
+        }
+    };
+}
+
+macro_rules! make_simd {
+    ($t:ty, $simd:ty, $lanes:expr) => {
+        impl ArgminAdd<Vec<$t>, Vec<$t>> for Vec<$t> {
+            #[inline]
+            fn add(&self, other: &Vec<$t>) -> Vec<$t> {
+                let n1 = self.len();
+                let n2 = other.len();
+                assert!(n1 > 0);
+                assert!(n2 > 0);
+                assert_eq!(n1, n2);
+                let mut out = Vec::with_capacity(n1);
+                for (x, y) in self.chunks_exact($lanes).zip(other.chunks_exact($lanes)) {
+                    let z =
+                        <$simd>::new(x.try_into().unwrap()) + <$simd>::new(y.try_into().unwrap());
+                    out.extend_from_slice(&z.to_array());
+                }
+                let tail = n1 - n1 % $lanes;
+                out.extend(
+                    self[tail..]
+                        .iter()
+                        .zip(other[tail..].iter())
+                        .map(|(x, y)| x + y),
+                );
+                out
+            }
+        }
+
+        impl ArgminSub<Vec<$t>, Vec<$t>> for Vec<$t> {
+            #[inline]
+            fn sub(&self, other: &Vec<$t>) -> Vec<$t> {
+                let n1 = self.len();
+                let n2 = other.len();
+                assert!(n1 > 0);
+                assert_eq!(n1, n2);
+                let mut out = Vec::with_capacity(n1);
+                for (x, y) in self.chunks_exact($lanes).zip(other.chunks_exact($lanes)) {
+                    let z =
+                        <$simd>::new(x.try_into().unwrap()) - <$simd>::new(y.try_into().unwrap());
+                    out.extend_from_slice(&z.to_array());
+                }
+                let tail = n1 - n1 % $lanes;
+                out.extend(
+                    self[tail..]
+                        .iter()
+                        .zip(other[tail..].iter())
+                        .map(|(x, y)| x - y),
+                );
+                out
+            }
+        }
+
+        impl ArgminMul<$t, Vec<$t>> for Vec<$t> {
+            #[inline]
+            fn mul(&self, other: &$t) -> Vec<$t> {
+                let factor = <$simd>::splat(*other);
+                let mut out = Vec::with_capacity(self.len());
+                let chunks = self.chunks_exact($lanes);
+                let rest = chunks.remainder();
+                for x in chunks {
+                    out.extend_from_slice(
+                        &(<$simd>::new(x.try_into().unwrap()) * factor).to_array(),
+                    );
+                }
+                out.extend(rest.iter().map(|x| x * other));
+                out
+            }
+        }
+
+        impl ArgminMul<Vec<$t>, Vec<$t>> for $t {
+            #[inline]
+            fn mul(&self, other: &Vec<$t>) -> Vec<$t> {
+                other.mul(self)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "vec-compensated"))]
+make_simd_reductions!(f32, f32x8, 8);
+#[cfg(not(feature = "vec-compensated"))]
+make_simd_reductions!(f64, f64x4, 4);
+make_simd!(f32, f32x8, 8);
+make_simd!(f64, f64x4, 4);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ArgminDot, ArgminL1Norm, ArgminL2Norm, ArgminScaledAdd};
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_simd_matches_scalar_ $t>]() {
+                    // All lengths up to a few chunks, so that every remainder length is covered
+                    for n in 1..40 {
+                        let a: Vec<$t> = (0..n).map(|i| (i as $t) * 0.75 - 7.0).collect();
+                        let b: Vec<$t> = (0..n).map(|i| 3.0 - (i as $t) * 0.5).collect();
+
+                        let dot: $t = a.iter().zip(b.iter()).map(|(x, y)| x * y).sum();
+                        let res: $t = a.dot(&b);
+                        assert_relative_eq!(res, dot, max_relative = 10.0 * <$t>::EPSILON);
+                        let l2: $t = a.iter().map(|x| x * x).sum::<$t>().sqrt();
+                        let res: $t = a.l2_norm();
+                        assert_relative_eq!(res, l2, max_relative = 10.0 * <$t>::EPSILON);
+                        let l1: $t = a.iter().map(|x| x.abs()).sum();
+                        let res: $t = a.l1_norm();
+                        assert_relative_eq!(res, l1, max_relative = 10.0 * <$t>::EPSILON);
+
+                        let sum = <Vec<$t> as ArgminAdd<Vec<$t>, Vec<$t>>>::add(&a, &b);
+                        let diff = <Vec<$t> as ArgminSub<Vec<$t>, Vec<$t>>>::sub(&a, &b);
+                        let scaled = <Vec<$t> as ArgminMul<$t, Vec<$t>>>::mul(&a, &1.5);
+                        let scaled_left = <$t as ArgminMul<Vec<$t>, Vec<$t>>>::mul(&1.5, &a);
+                        let axpy: Vec<$t> = a.scaled_add(&1.5, &b);
+                        assert_eq!(sum.len(), n);
+                        assert_eq!(diff.len(), n);
+                        assert_eq!(scaled.len(), n);
+                        assert_eq!(axpy.len(), n);
+                        for i in 0..n {
+                            assert_relative_eq!(sum[i], a[i] + b[i]);
+                            assert_relative_eq!(diff[i], a[i] - b[i]);
+                            assert_relative_eq!(scaled[i], a[i] * 1.5);
+                            assert_relative_eq!(scaled_left[i], a[i] * 1.5);
+                            assert_relative_eq!(axpy[i], a[i] + 1.5 * b[i]);
+                        }
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_simd_dot_truncates_ $t>]() {
+                    // Like the scalar implementation, the shorter vector determines the length
+                    let a: Vec<$t> = vec![1.0; 11];
+                    let b: Vec<$t> = vec![2.0; 9];
+                    let res: $t = a.dot(&b);
+                    assert_relative_eq!(res, 18.0);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_simd_empty_ $t>]() {
+                    let a: Vec<$t> = vec![];
+                    let res: $t = a.dot(&a);
+                    assert_relative_eq!(res, 0.0);
+                    let res: $t = a.l1_norm();
+                    assert_relative_eq!(res, 0.0);
+                    assert_eq!(<Vec<$t> as ArgminMul<$t, Vec<$t>>>::mul(&a, &2.0), a);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
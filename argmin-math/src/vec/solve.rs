@@ -0,0 +1,292 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminCholeskySolve, ArgminLuSolve, ArgminQrSolve, Error};
+use alloc::{vec, vec::Vec};
+use num_traits::Float;
+
+#[derive(Debug, thiserror::Error, PartialEq)]
+enum SolveError {
+    #[error("Matrix is not square or does not match the length of the right-hand side")]
+    Shape,
+    #[error("Matrix is not positive definite")]
+    NotPositiveDefinite,
+    #[error("Singular matrix")]
+    Singular,
+    #[error("Matrix or right-hand side contains non-finite entries")]
+    NotFinite,
+}
+
+fn check_shape<F>(a: &[Vec<F>], b: &[F]) -> Result<(), Error> {
+    let n = b.len();
+    if a.len() != n || a.iter().any(|row| row.len() != n) {
+        return Err(SolveError::Shape.into());
+    }
+    Ok(())
+}
+
+fn check_finite<F: Float>(a: &[Vec<F>], b: &[F]) -> Result<(), Error> {
+    if a.iter().flatten().chain(b.iter()).any(|x| !x.is_finite()) {
+        return Err(SolveError::NotFinite.into());
+    }
+    Ok(())
+}
+
+/// Solves `r * x = y` in place for an upper triangular `r` (only the upper triangle is read)
+///
+/// `r` is considered singular if a diagonal element is negligible compared to the largest one.
+fn solve_upper<F: Float>(r: &[Vec<F>], y: &mut [F]) -> Result<(), Error> {
+    let n = y.len();
+    let max_diag = (0..n).fold(F::zero(), |acc, i| acc.max(r[i][i].abs()));
+    let tol = max_diag * F::epsilon() * F::from(n).unwrap();
+    for i in (0..n).rev() {
+        if r[i][i].abs() <= tol || r[i][i].is_nan() {
+            return Err(SolveError::Singular.into());
+        }
+        let s = (i + 1..n).fold(y[i], |acc, j| acc - r[i][j] * y[j]);
+        y[i] = s / r[i][i];
+    }
+    Ok(())
+}
+
+fn cholesky_solve<F: Float>(a: &[Vec<F>], b: &[F]) -> Result<Vec<F>, Error> {
+    check_shape(a, b)?;
+    check_finite(a, b)?;
+    let n = b.len();
+    // Lower triangular factor `L` with `A = L * L^T`
+    let mut l = vec![vec![F::zero(); n]; n];
+    for j in 0..n {
+        let d = (0..j).fold(a[j][j], |acc, k| acc - l[j][k] * l[j][k]);
+        if d <= F::zero() || d.is_nan() {
+            return Err(SolveError::NotPositiveDefinite.into());
+        }
+        l[j][j] = d.sqrt();
+        for i in j + 1..n {
+            let s = (0..j).fold(a[i][j], |acc, k| acc - l[i][k] * l[j][k]);
+            l[i][j] = s / l[j][j];
+        }
+    }
+    // L * y = b
+    let mut x = b.to_vec();
+    for i in 0..n {
+        let s = (0..i).fold(x[i], |acc, k| acc - l[i][k] * x[k]);
+        x[i] = s / l[i][i];
+    }
+    // L^T * x = y
+    for i in (0..n).rev() {
+        let s = (i + 1..n).fold(x[i], |acc, k| acc - l[k][i] * x[k]);
+        x[i] = s / l[i][i];
+    }
+    Ok(x)
+}
+
+fn lu_solve<F: Float>(a: &[Vec<F>], b: &[F]) -> Result<Vec<F>, Error> {
+    check_shape(a, b)?;
+    check_finite(a, b)?;
+    let n = b.len();
+    let mut u = a.to_vec();
+    let mut x = b.to_vec();
+    // Gaussian elimination with partial pivoting; the multipliers (`L`) are applied to `b` right
+    // away instead of being stored.
+    for k in 0..n {
+        // Comparisons with NaN (from overflowing intermediate results) are false, which keeps
+        // the search from panicking; a NaN pivot is rejected below
+        let p = (k + 1..n).fold(k, |p, i| if u[i][k].abs() > u[p][k].abs() { i } else { p });
+        if u[p][k] == F::zero() || u[p][k].is_nan() {
+            return Err(SolveError::Singular.into());
+        }
+        u.swap(k, p);
+        x.swap(k, p);
+        let (upper, lower) = u.split_at_mut(k + 1);
+        let pivot = &upper[k];
+        let xk = x[k];
+        for (row, xi) in lower.iter_mut().zip(x[k + 1..].iter_mut()) {
+            let f = row[k] / pivot[k];
+            for (rj, pj) in row[k..].iter_mut().zip(pivot[k..].iter()) {
+                *rj = *rj - f * *pj;
+            }
+            *xi = *xi - f * xk;
+        }
+    }
+    solve_upper(&u, &mut x)?;
+    Ok(x)
+}
+
+fn qr_solve<F: Float>(a: &[Vec<F>], b: &[F]) -> Result<Vec<F>, Error> {
+    check_shape(a, b)?;
+    check_finite(a, b)?;
+    let n = b.len();
+    let mut r = a.to_vec();
+    let mut x = b.to_vec();
+    // Householder reflections `H_k = I - 2 v v^T / (v^T v)` reduce `A` to the upper triangular
+    // `R = Q^T * A` and are applied to `b` on the fly, which yields `Q^T * b`.
+    for k in 0..n {
+        let norm = (k..n).fold(F::zero(), |acc, i| acc.hypot(r[i][k]));
+        if norm == F::zero() {
+            continue;
+        }
+        let alpha = if r[k][k] > F::zero() { -norm } else { norm };
+        let mut v: Vec<F> = (k..n).map(|i| r[i][k]).collect();
+        v[0] = v[0] - alpha;
+        let vtv = v.iter().fold(F::zero(), |acc, vi| acc + *vi * *vi);
+        let two = F::one() + F::one();
+        let s: Vec<F> = (k..n)
+            .map(|j| (k..n).fold(F::zero(), |acc, i| acc + v[i - k] * r[i][j]) * two / vtv)
+            .collect();
+        for (row, vi) in r[k..].iter_mut().zip(v.iter()) {
+            for (rj, sj) in row[k..].iter_mut().zip(s.iter()) {
+                *rj = *rj - *sj * *vi;
+            }
+        }
+        let s = (k..n).fold(F::zero(), |acc, i| acc + v[i - k] * x[i]) * two / vtv;
+        for (xi, vi) in x[k..].iter_mut().zip(v.iter()) {
+            *xi = *xi - s * *vi;
+        }
+    }
+    solve_upper(&r, &mut x)?;
+    Ok(x)
+}
+
+macro_rules! make_solve {
+    ($t:ty) => {
+        impl ArgminCholeskySolve<Vec<$t>> for Vec<Vec<$t>> {
+            #[inline]
+            fn cholesky_solve(&self, b: &Vec<$t>) -> Result<Vec<$t>, Error> {
+                cholesky_solve(self, b)
+            }
+        }
+
+        impl ArgminLuSolve<Vec<$t>> for Vec<Vec<$t>> {
+            #[inline]
+            fn lu_solve(&self, b: &Vec<$t>) -> Result<Vec<$t>, Error> {
+                lu_solve(self, b)
+            }
+        }
+
+        impl ArgminQrSolve<Vec<$t>> for Vec<Vec<$t>> {
+            #[inline]
+            fn qr_solve(&self, b: &Vec<$t>) -> Result<Vec<$t>, Error> {
+                qr_solve(self, b)
+            }
+        }
+    };
+}
+
+make_solve!(f32);
+make_solve!(f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use paste::item;
+
+    macro_rules! make_test {
+        ($t:ty) => {
+            item! {
+                #[test]
+                fn [<test_cholesky_solve_ $t>]() {
+                    let a: Vec<Vec<$t>> = vec![
+                        vec![4.0, 2.0, -2.0],
+                        vec![2.0, 10.0, 4.0],
+                        vec![-2.0, 4.0, 9.0],
+                    ];
+                    let b: Vec<$t> = vec![6.0, 26.0, 15.0];
+                    let x = a.cholesky_solve(&b).unwrap();
+                    for (xi, ti) in x.iter().zip([1.0, 2.0, 1.0].iter()) {
+                        assert_relative_eq!(*xi, *ti, epsilon = 100.0 * <$t>::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_cholesky_solve_indefinite_ $t>]() {
+                    let a: Vec<Vec<$t>> = vec![vec![1.0, 2.0], vec![2.0, 1.0]];
+                    let b: Vec<$t> = vec![1.0, 1.0];
+                    assert_eq!(
+                        a.cholesky_solve(&b).unwrap_err().to_string(),
+                        "Matrix is not positive definite"
+                    );
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_lu_solve_ $t>]() {
+                    // Requires pivoting since the upper left element is zero
+                    let a: Vec<Vec<$t>> = vec![
+                        vec![0.0, 2.0, 1.0],
+                        vec![1.0, -1.0, 0.0],
+                        vec![3.0, 0.0, -2.0],
+                    ];
+                    let b: Vec<$t> = vec![5.0, -1.0, 1.0];
+                    let x = a.lu_solve(&b).unwrap();
+                    for (xi, ti) in x.iter().zip([1.0, 2.0, 1.0].iter()) {
+                        assert_relative_eq!(*xi, *ti, epsilon = 100.0 * <$t>::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_qr_solve_ $t>]() {
+                    let a: Vec<Vec<$t>> = vec![
+                        vec![0.0, 2.0, 1.0],
+                        vec![1.0, -1.0, 0.0],
+                        vec![3.0, 0.0, -2.0],
+                    ];
+                    let b: Vec<$t> = vec![5.0, -1.0, 1.0];
+                    let x = a.qr_solve(&b).unwrap();
+                    for (xi, ti) in x.iter().zip([1.0, 2.0, 1.0].iter()) {
+                        assert_relative_eq!(*xi, *ti, epsilon = 100.0 * <$t>::EPSILON);
+                    }
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_solve_singular_ $t>]() {
+                    let a: Vec<Vec<$t>> = vec![vec![1.0, 2.0], vec![2.0, 4.0]];
+                    let b: Vec<$t> = vec![1.0, 1.0];
+                    assert_eq!(a.lu_solve(&b).unwrap_err().to_string(), "Singular matrix");
+                    assert_eq!(a.qr_solve(&b).unwrap_err().to_string(), "Singular matrix");
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_solve_not_finite_ $t>]() {
+                    let msg = "Matrix or right-hand side contains non-finite entries";
+                    let a: Vec<Vec<$t>> = vec![vec![1.0, <$t>::NAN], vec![2.0, 4.0]];
+                    let b: Vec<$t> = vec![1.0, 1.0];
+                    assert_eq!(a.cholesky_solve(&b).unwrap_err().to_string(), msg);
+                    assert_eq!(a.lu_solve(&b).unwrap_err().to_string(), msg);
+                    assert_eq!(a.qr_solve(&b).unwrap_err().to_string(), msg);
+                    let a: Vec<Vec<$t>> = vec![vec![1.0, 0.0], vec![0.0, 1.0]];
+                    let b: Vec<$t> = vec![<$t>::INFINITY, 1.0];
+                    assert_eq!(a.lu_solve(&b).unwrap_err().to_string(), msg);
+                }
+            }
+
+            item! {
+                #[test]
+                fn [<test_solve_shape_ $t>]() {
+                    let a: Vec<Vec<$t>> = vec![vec![1.0, 2.0], vec![2.0, 4.0]];
+                    let b: Vec<$t> = vec![1.0, 1.0, 1.0];
+                    let msg = "Matrix is not square or does not match the length of the right-hand side";
+                    assert_eq!(a.cholesky_solve(&b).unwrap_err().to_string(), msg);
+                    assert_eq!(a.lu_solve(&b).unwrap_err().to_string(), msg);
+                    assert_eq!(a.qr_solve(&b).unwrap_err().to_string(), msg);
+                }
+            }
+        };
+    }
+
+    make_test!(f32);
+    make_test!(f64);
+}
@@ -6,6 +6,7 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::ArgminSub;
+use alloc::vec::Vec;
 use num_complex::Complex;
 
 macro_rules! make_sub {
@@ -24,17 +25,6 @@ macro_rules! make_sub {
             }
         }
 
-        impl ArgminSub<Vec<$t>, Vec<$t>> for Vec<$t> {
-            #[inline]
-            fn sub(&self, other: &Vec<$t>) -> Vec<$t> {
-                let n1 = self.len();
-                let n2 = other.len();
-                assert!(n1 > 0);
-                assert_eq!(n1, n2);
-                self.iter().zip(other.iter()).map(|(a, b)| a - b).collect()
-            }
-        }
-
         impl ArgminSub<Vec<Vec<$t>>, Vec<Vec<$t>>> for Vec<Vec<$t>> {
             #[inline]
             fn sub(&self, other: &Vec<Vec<$t>>) -> Vec<Vec<$t>> {
@@ -72,6 +62,22 @@ macro_rules! make_sub {
     };
 }
 
+// The SIMD implementations for `f32` and `f64` are in `simd.rs`.
+macro_rules! make_sub_vec_vec {
+    ($t:ty) => {
+        impl ArgminSub<Vec<$t>, Vec<$t>> for Vec<$t> {
+            #[inline]
+            fn sub(&self, other: &Vec<$t>) -> Vec<$t> {
+                let n1 = self.len();
+                let n2 = other.len();
+                assert!(n1 > 0);
+                assert_eq!(n1, n2);
+                self.iter().zip(other.iter()).map(|(a, b)| a - b).collect()
+            }
+        }
+    };
+}
+
 make_sub!(isize);
 make_sub!(usize);
 make_sub!(i8);
@@ -97,6 +103,55 @@ make_sub!(Complex<u64>);
 make_sub!(Complex<f32>);
 make_sub!(Complex<f64>);
 
+make_sub_vec_vec!(isize);
+make_sub_vec_vec!(usize);
+make_sub_vec_vec!(i8);
+make_sub_vec_vec!(i16);
+make_sub_vec_vec!(i32);
+make_sub_vec_vec!(i64);
+make_sub_vec_vec!(u8);
+make_sub_vec_vec!(u16);
+make_sub_vec_vec!(u32);
+make_sub_vec_vec!(u64);
+#[cfg(not(feature = "vec-simd"))]
+make_sub_vec_vec!(f32);
+#[cfg(not(feature = "vec-simd"))]
+make_sub_vec_vec!(f64);
+make_sub_vec_vec!(Complex<isize>);
+make_sub_vec_vec!(Complex<usize>);
+make_sub_vec_vec!(Complex<i8>);
+make_sub_vec_vec!(Complex<i16>);
+make_sub_vec_vec!(Complex<i32>);
+make_sub_vec_vec!(Complex<i64>);
+make_sub_vec_vec!(Complex<u8>);
+make_sub_vec_vec!(Complex<u16>);
+make_sub_vec_vec!(Complex<u32>);
+make_sub_vec_vec!(Complex<u64>);
+make_sub_vec_vec!(Complex<f32>);
+make_sub_vec_vec!(Complex<f64>);
+
+// Real scalars act on complex vectors as in complex arithmetic (only the real part is shifted).
+macro_rules! make_sub_real_scalar {
+    ($t:ty) => {
+        impl ArgminSub<$t, Vec<Complex<$t>>> for Vec<Complex<$t>> {
+            #[inline]
+            fn sub(&self, other: &$t) -> Vec<Complex<$t>> {
+                self.iter().map(|a| a - other).collect()
+            }
+        }
+
+        impl ArgminSub<Vec<Complex<$t>>, Vec<Complex<$t>>> for $t {
+            #[inline]
+            fn sub(&self, other: &Vec<Complex<$t>>) -> Vec<Complex<$t>> {
+                other.iter().map(|a| self - a).collect()
+            }
+        }
+    };
+}
+
+make_sub_real_scalar!(f32);
+make_sub_real_scalar!(f64);
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -387,4 +442,17 @@ mod tests {
     make_test!(u64);
     make_test!(f32);
     make_test!(f64);
+
+    #[test]
+    fn test_sub_real_scalar_complex() {
+        let a = vec![Complex::new(1.0f64, 2.0), Complex::new(3.0, -1.0)];
+        assert_eq!(
+            <Vec<Complex<f64>> as ArgminSub<f64, Vec<Complex<f64>>>>::sub(&a, &2.0),
+            vec![Complex::new(-1.0, 2.0), Complex::new(1.0, -1.0)]
+        );
+        assert_eq!(
+            <f64 as ArgminSub<Vec<Complex<f64>>, Vec<Complex<f64>>>>::sub(&2.0, &a),
+            vec![Complex::new(1.0, -2.0), Complex::new(-1.0, 1.0)]
+        );
+    }
 }
@@ -9,6 +9,7 @@
 // ArrayViews, which would probably make it more efficient.
 
 use crate::ArgminTranspose;
+use alloc::{vec, vec::Vec};
 use num_complex::Complex;
 
 macro_rules! make_transpose {
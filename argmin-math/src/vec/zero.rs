@@ -6,6 +6,7 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::ArgminZeroLike;
+use alloc::{vec, vec::Vec};
 
 impl<T> ArgminZeroLike for Vec<T>
 where
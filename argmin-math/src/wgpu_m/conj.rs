@@ -0,0 +1,28 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminConj, WgpuVector};
+
+impl ArgminConj for WgpuVector {
+    #[inline]
+    fn conj(&self) -> WgpuVector {
+        self.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wgpu_m::tests::context;
+
+    #[test]
+    fn test_conj() {
+        let Some(ctx) = context() else { return };
+        let a = WgpuVector::from_slice(&ctx, &[1.0, -2.0]);
+        assert_eq!(a.conj().to_vec(), vec![1.0, -2.0]);
+    }
+}
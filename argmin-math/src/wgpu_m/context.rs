@@ -0,0 +1,519 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use anyhow::{anyhow, Error};
+use std::sync::{mpsc, Arc, OnceLock};
+use wgpu::util::DeviceExt;
+
+/// Number of invocations per workgroup (must match `WORKGROUP_SIZE` in the shader)
+const WORKGROUP_SIZE: u32 = 256;
+
+/// Upper bound on the number of workgroups of an element-wise kernel
+const MAX_WORKGROUPS: u32 = 65535;
+
+/// Upper bound on the number of workgroups of a reduction. Every workgroup produces one partial
+/// result which is summed up on the host.
+const MAX_REDUCTION_WORKGROUPS: u32 = 1024;
+
+const SHADER: &str = r#"
+struct Params {
+    n: u32,
+    stride: u32,
+    alpha: f32,
+    pad: u32,
+}
+
+const WORKGROUP_SIZE: u32 = 256u;
+
+@group(0) @binding(0) var<storage, read> a: array<f32>;
+@group(0) @binding(1) var<storage, read> b: array<f32>;
+@group(0) @binding(2) var<storage, read_write> out: array<f32>;
+@group(0) @binding(3) var<uniform> params: Params;
+
+var<workgroup> partial: array<f32, WORKGROUP_SIZE>;
+
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn add(@builtin(global_invocation_id) gid: vec3<u32>) {
+    for (var i = gid.x; i < params.n; i += params.stride) {
+        out[i] = a[i] + b[i];
+    }
+}
+
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn sub(@builtin(global_invocation_id) gid: vec3<u32>) {
+    for (var i = gid.x; i < params.n; i += params.stride) {
+        out[i] = a[i] - b[i];
+    }
+}
+
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn mul(@builtin(global_invocation_id) gid: vec3<u32>) {
+    for (var i = gid.x; i < params.n; i += params.stride) {
+        out[i] = a[i] * b[i];
+    }
+}
+
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn div(@builtin(global_invocation_id) gid: vec3<u32>) {
+    for (var i = gid.x; i < params.n; i += params.stride) {
+        out[i] = a[i] / b[i];
+    }
+}
+
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn add_scalar(@builtin(global_invocation_id) gid: vec3<u32>) {
+    for (var i = gid.x; i < params.n; i += params.stride) {
+        out[i] = a[i] + params.alpha;
+    }
+}
+
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn sub_scalar(@builtin(global_invocation_id) gid: vec3<u32>) {
+    for (var i = gid.x; i < params.n; i += params.stride) {
+        out[i] = a[i] - params.alpha;
+    }
+}
+
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn mul_scalar(@builtin(global_invocation_id) gid: vec3<u32>) {
+    for (var i = gid.x; i < params.n; i += params.stride) {
+        out[i] = a[i] * params.alpha;
+    }
+}
+
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn div_scalar(@builtin(global_invocation_id) gid: vec3<u32>) {
+    for (var i = gid.x; i < params.n; i += params.stride) {
+        out[i] = a[i] / params.alpha;
+    }
+}
+
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn axpy(@builtin(global_invocation_id) gid: vec3<u32>) {
+    for (var i = gid.x; i < params.n; i += params.stride) {
+        out[i] = a[i] + params.alpha * b[i];
+    }
+}
+
+fn store_workgroup_sum(acc: f32, lid: u32, wid: u32) {
+    partial[lid] = acc;
+    workgroupBarrier();
+    for (var s = WORKGROUP_SIZE / 2u; s > 0u; s = s / 2u) {
+        if (lid < s) {
+            partial[lid] += partial[lid + s];
+        }
+        workgroupBarrier();
+    }
+    if (lid == 0u) {
+        out[wid] = partial[0];
+    }
+}
+
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn dot(
+    @builtin(global_invocation_id) gid: vec3<u32>,
+    @builtin(local_invocation_id) lid: vec3<u32>,
+    @builtin(workgroup_id) wid: vec3<u32>,
+) {
+    var acc = 0.0;
+    for (var i = gid.x; i < params.n; i += params.stride) {
+        acc += a[i] * b[i];
+    }
+    store_workgroup_sum(acc, lid.x, wid.x);
+}
+
+@compute @workgroup_size(WORKGROUP_SIZE)
+fn abs_sum(
+    @builtin(global_invocation_id) gid: vec3<u32>,
+    @builtin(local_invocation_id) lid: vec3<u32>,
+    @builtin(workgroup_id) wid: vec3<u32>,
+) {
+    var acc = 0.0;
+    for (var i = gid.x; i < params.n; i += params.stride) {
+        acc += abs(a[i]);
+    }
+    store_workgroup_sum(acc, lid.x, wid.x);
+}
+"#;
+
+/// Compute kernels of the shader, in the order of `Kernel::ALL`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Kernel {
+    /// `out = a + b`
+    Add,
+    /// `out = a - b`
+    Sub,
+    /// `out = a * b`
+    Mul,
+    /// `out = a / b`
+    Div,
+    /// `out = a + alpha`
+    AddScalar,
+    /// `out = a - alpha`
+    SubScalar,
+    /// `out = a * alpha`
+    MulScalar,
+    /// `out = a / alpha`
+    DivScalar,
+    /// `out = a + alpha * b`
+    Axpy,
+    /// Partial sums of `a * b`
+    Dot,
+    /// Partial sums of `|a|`
+    AbsSum,
+}
+
+impl Kernel {
+    const ALL: [Kernel; 11] = [
+        Kernel::Add,
+        Kernel::Sub,
+        Kernel::Mul,
+        Kernel::Div,
+        Kernel::AddScalar,
+        Kernel::SubScalar,
+        Kernel::MulScalar,
+        Kernel::DivScalar,
+        Kernel::Axpy,
+        Kernel::Dot,
+        Kernel::AbsSum,
+    ];
+
+    fn entry_point(self) -> &'static str {
+        match self {
+            Kernel::Add => "add",
+            Kernel::Sub => "sub",
+            Kernel::Mul => "mul",
+            Kernel::Div => "div",
+            Kernel::AddScalar => "add_scalar",
+            Kernel::SubScalar => "sub_scalar",
+            Kernel::MulScalar => "mul_scalar",
+            Kernel::DivScalar => "div_scalar",
+            Kernel::Axpy => "axpy",
+            Kernel::Dot => "dot",
+            Kernel::AbsSum => "abs_sum",
+        }
+    }
+}
+
+struct Inner {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    layout: wgpu::BindGroupLayout,
+    pipelines: Vec<wgpu::ComputePipeline>,
+}
+
+/// GPU device and compiled compute kernels shared by `WgpuVector`s
+///
+/// Cloning a context is cheap: all clones refer to the same device. Vectors can only be combined
+/// with vectors which were created on the same device.
+#[derive(Clone)]
+pub struct WgpuContext {
+    inner: Arc<Inner>,
+}
+
+impl WgpuContext {
+    /// Create a context on the default GPU adapter.
+    ///
+    /// Returns an error if no adapter is available or if the adapter does not support compute
+    /// shaders.
+    pub fn new() -> Result<Self, Error> {
+        let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor::default());
+        let adapter =
+            pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions::default()))
+                .ok_or_else(|| anyhow!("wgpu: no suitable GPU adapter found"))?;
+        if !adapter
+            .get_downlevel_capabilities()
+            .flags
+            .contains(wgpu::DownlevelFlags::COMPUTE_SHADERS)
+        {
+            return Err(anyhow!(
+                "wgpu: adapter `{}` does not support compute shaders",
+                adapter.get_info().name
+            ));
+        }
+        let (device, queue) = pollster::block_on(adapter.request_device(
+            &wgpu::DeviceDescriptor {
+                label: Some("argmin-math"),
+                required_features: wgpu::Features::empty(),
+                required_limits: adapter.limits(),
+                memory_hints: wgpu::MemoryHints::Performance,
+            },
+            None,
+        ))?;
+        Ok(Self::from_device(device, queue))
+    }
+
+    /// Create a context on an existing device.
+    ///
+    /// The device must support compute shaders.
+    pub fn from_device(device: wgpu::Device, queue: wgpu::Queue) -> Self {
+        let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("argmin-math"),
+            source: wgpu::ShaderSource::Wgsl(SHADER.into()),
+        });
+        let storage = |binding, read_only| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::COMPUTE,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Storage { read_only },
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        };
+        let layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("argmin-math"),
+            entries: &[
+                storage(0, true),
+                storage(1, true),
+                storage(2, false),
+                wgpu::BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        });
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("argmin-math"),
+            bind_group_layouts: &[&layout],
+            push_constant_ranges: &[],
+        });
+        let pipelines = Kernel::ALL
+            .iter()
+            .map(|kernel| {
+                device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                    label: Some(kernel.entry_point()),
+                    layout: Some(&pipeline_layout),
+                    module: &module,
+                    entry_point: Some(kernel.entry_point()),
+                    compilation_options: Default::default(),
+                    cache: None,
+                })
+            })
+            .collect();
+        WgpuContext {
+            inner: Arc::new(Inner {
+                device,
+                queue,
+                layout,
+                pipelines,
+            }),
+        }
+    }
+
+    /// Context on the default GPU adapter which is shared within the process.
+    ///
+    /// The context is created on first use. Deserialized `WgpuVector`s live on this context.
+    pub fn shared() -> Result<Self, Error> {
+        static SHARED: OnceLock<Result<WgpuContext, String>> = OnceLock::new();
+        SHARED
+            .get_or_init(|| WgpuContext::new().map_err(|e| e.to_string()))
+            .clone()
+            .map_err(|e| anyhow!(e))
+    }
+
+    /// The underlying `wgpu` device
+    pub fn device(&self) -> &wgpu::Device {
+        &self.inner.device
+    }
+
+    /// Returns `true` if both contexts refer to the same device
+    pub(crate) fn same_device(&self, other: &WgpuContext) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+
+    /// Allocate a zero-initialized storage buffer holding `len` floats
+    pub(crate) fn zeros(&self, len: usize) -> wgpu::Buffer {
+        self.inner.device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: buffer_size(len),
+            usage: wgpu::BufferUsages::STORAGE
+                | wgpu::BufferUsages::COPY_SRC
+                | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        })
+    }
+
+    /// Upload `data` to a new storage buffer
+    pub(crate) fn upload(&self, data: &[f32]) -> wgpu::Buffer {
+        let buffer = self.zeros(data.len());
+        if !data.is_empty() {
+            let bytes: Vec<u8> = data.iter().flat_map(|x| x.to_ne_bytes()).collect();
+            self.inner.queue.write_buffer(&buffer, 0, &bytes);
+        }
+        buffer
+    }
+
+    /// Copy the first `len` floats of `buffer` to a new storage buffer
+    pub(crate) fn duplicate(&self, buffer: &wgpu::Buffer, len: usize) -> wgpu::Buffer {
+        let copy = self.zeros(len);
+        if len > 0 {
+            let mut encoder = self
+                .inner
+                .device
+                .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+            encoder.copy_buffer_to_buffer(buffer, 0, &copy, 0, byte_len(len));
+            self.inner.queue.submit(Some(encoder.finish()));
+        }
+        copy
+    }
+
+    /// Read the first `len` floats of `buffer` back to the host
+    pub(crate) fn download(&self, buffer: &wgpu::Buffer, len: usize) -> Vec<f32> {
+        if len == 0 {
+            return vec![];
+        }
+        let device = &self.inner.device;
+        let staging = device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: byte_len(len),
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        encoder.copy_buffer_to_buffer(buffer, 0, &staging, 0, byte_len(len));
+        self.inner.queue.submit(Some(encoder.finish()));
+        let slice = staging.slice(..);
+        let (sender, receiver) = mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            // The receiver is alive until the result has been received below.
+            let _ = sender.send(result);
+        });
+        device.poll(wgpu::Maintain::Wait);
+        receiver
+            .recv()
+            .expect("wgpu: buffer mapping callback was not called")
+            .expect("wgpu: failed to map buffer");
+        let data = slice
+            .get_mapped_range()
+            .chunks_exact(4)
+            .map(|bytes| f32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+            .collect();
+        staging.unmap();
+        data
+    }
+
+    /// Run an element-wise kernel on the first `len` floats of `a` and `b` and return the result
+    /// in a new buffer
+    pub(crate) fn elementwise(
+        &self,
+        kernel: Kernel,
+        a: &wgpu::Buffer,
+        b: &wgpu::Buffer,
+        alpha: f32,
+        len: usize,
+    ) -> wgpu::Buffer {
+        let out = self.zeros(len);
+        let groups = workgroups(len, MAX_WORKGROUPS);
+        self.dispatch(kernel, a, b, &out, alpha, len, groups);
+        out
+    }
+
+    /// Run a reduction kernel on the first `len` floats of `a` and `b` and sum up the partial
+    /// results of all workgroups
+    pub(crate) fn reduce(
+        &self,
+        kernel: Kernel,
+        a: &wgpu::Buffer,
+        b: &wgpu::Buffer,
+        len: usize,
+    ) -> f32 {
+        let groups = workgroups(len, MAX_REDUCTION_WORKGROUPS);
+        let partial = self.zeros(groups as usize);
+        self.dispatch(kernel, a, b, &partial, 0.0, len, groups);
+        self.download(&partial, groups as usize).iter().sum()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn dispatch(
+        &self,
+        kernel: Kernel,
+        a: &wgpu::Buffer,
+        b: &wgpu::Buffer,
+        out: &wgpu::Buffer,
+        alpha: f32,
+        len: usize,
+        groups: u32,
+    ) {
+        if groups == 0 {
+            return;
+        }
+        let n = u32::try_from(len).expect("wgpu: vectors are limited to u32::MAX elements");
+        let stride = groups * WORKGROUP_SIZE;
+        let params: Vec<u8> = [n.to_ne_bytes(), stride.to_ne_bytes(), alpha.to_ne_bytes()]
+            .iter()
+            .flatten()
+            .copied()
+            .chain([0; 4])
+            .collect();
+        let device = &self.inner.device;
+        let params = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: None,
+            contents: &params,
+            usage: wgpu::BufferUsages::UNIFORM,
+        });
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout: &self.inner.layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: a.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: b.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: out.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: params.as_entire_binding(),
+                },
+            ],
+        });
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+            pass.set_pipeline(&self.inner.pipelines[kernel as usize]);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.dispatch_workgroups(groups, 1, 1);
+        }
+        self.inner.queue.submit(Some(encoder.finish()));
+    }
+}
+
+impl std::fmt::Debug for WgpuContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WgpuContext").finish_non_exhaustive()
+    }
+}
+
+/// Size in bytes of `len` floats
+fn byte_len(len: usize) -> u64 {
+    (len * std::mem::size_of::<f32>()) as u64
+}
+
+/// Size of a buffer holding `len` floats. Empty vectors are backed by a buffer of one element
+/// because storage bindings must not be empty.
+fn buffer_size(len: usize) -> u64 {
+    byte_len(len.max(1))
+}
+
+/// Number of workgroups needed to cover `len` elements, capped at `max`. Kernels loop over the
+/// remaining elements.
+fn workgroups(len: usize, max: u32) -> u32 {
+    len.div_ceil(WORKGROUP_SIZE as usize).min(max as usize) as u32
+}
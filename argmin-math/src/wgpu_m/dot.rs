@@ -0,0 +1,62 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::wgpu_m::context::Kernel;
+use crate::{ArgminDot, WgpuVector};
+
+impl ArgminDot<WgpuVector, f32> for WgpuVector {
+    /// Partial sums are computed per workgroup on the device and added up on the host
+    #[inline]
+    fn dot(&self, other: &WgpuVector) -> f32 {
+        self.reduce(Kernel::Dot, other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wgpu_m::tests::context;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_dot() {
+        let Some(ctx) = context() else { return };
+        let a = WgpuVector::from_slice(&ctx, &[1.0, 2.0, 3.0]);
+        let b = WgpuVector::from_slice(&ctx, &[4.0, 5.0, 6.0]);
+        assert_relative_eq!(a.dot(&b), 32.0, epsilon = f32::EPSILON);
+        assert_relative_eq!(a.dot(&a), 14.0, epsilon = f32::EPSILON);
+    }
+
+    #[test]
+    fn test_dot_large() {
+        // More elements than the reduction has invocations, so every invocation accumulates
+        // several elements.
+        let Some(ctx) = context() else { return };
+        let n = 1_000_003;
+        let a = WgpuVector::from_slice(&ctx, &vec![1.0; n]);
+        let b = WgpuVector::from_slice(&ctx, &vec![0.5; n]);
+        assert_relative_eq!(a.dot(&b), 0.5 * n as f32, max_relative = 1e-6);
+    }
+
+    #[test]
+    fn test_dot_empty() {
+        let Some(ctx) = context() else { return };
+        let a = WgpuVector::from_slice(&ctx, &[]);
+        assert_relative_eq!(a.dot(&a), 0.0, epsilon = f32::EPSILON);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_dot_panic() {
+        let Some(ctx) = context() else {
+            panic!("no GPU adapter")
+        };
+        let a = WgpuVector::from_slice(&ctx, &[1.0, 2.0]);
+        let b = WgpuVector::from_slice(&ctx, &[1.0]);
+        a.dot(&b);
+    }
+}
@@ -0,0 +1,29 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::{ArgminDot, ArgminL2Norm, WgpuVector};
+
+impl ArgminL2Norm<f32> for WgpuVector {
+    #[inline]
+    fn l2_norm(&self) -> f32 {
+        self.dot(self).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wgpu_m::tests::context;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_l2norm() {
+        let Some(ctx) = context() else { return };
+        let a = WgpuVector::from_slice(&ctx, &[4.0, -3.0]);
+        assert_relative_eq!(a.l2_norm(), 5.0, epsilon = f32::EPSILON);
+    }
+}
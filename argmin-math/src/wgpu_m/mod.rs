@@ -0,0 +1,51 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+mod add;
+mod conj;
+mod context;
+mod div;
+mod dot;
+mod l1norm;
+mod l2norm;
+mod mul;
+mod scaledadd;
+mod scaledsub;
+mod sub;
+mod vector;
+mod zero;
+
+pub use add::*;
+pub use conj::*;
+pub use context::WgpuContext;
+pub use div::*;
+pub use dot::*;
+pub use l1norm::*;
+pub use l2norm::*;
+pub use mul::*;
+pub use scaledadd::*;
+pub use scaledsub::*;
+pub use sub::*;
+pub use vector::WgpuVector;
+pub use zero::*;
+
+#[cfg(test)]
+mod tests {
+    use super::WgpuContext;
+
+    /// Shared context for the tests, or `None` if the machine has no usable GPU adapter, in which
+    /// case the tests return early.
+    pub(crate) fn context() -> Option<WgpuContext> {
+        match WgpuContext::shared() {
+            Ok(ctx) => Some(ctx),
+            Err(e) => {
+                eprintln!("skipping wgpu test: {e}");
+                None
+            }
+        }
+    }
+}
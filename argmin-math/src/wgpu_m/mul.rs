@@ -0,0 +1,66 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::wgpu_m::context::Kernel;
+use crate::{ArgminMul, WgpuVector};
+
+impl ArgminMul<f32, WgpuVector> for WgpuVector {
+    #[inline]
+    fn mul(&self, other: &f32) -> WgpuVector {
+        self.map(Kernel::MulScalar, *other)
+    }
+}
+
+impl ArgminMul<WgpuVector, WgpuVector> for WgpuVector {
+    #[inline]
+    fn mul(&self, other: &WgpuVector) -> WgpuVector {
+        self.zip(Kernel::Mul, other, 0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wgpu_m::tests::context;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_mul_vec_scalar() {
+        let Some(ctx) = context() else { return };
+        let data: Vec<f32> = (1..=1000).map(|i| i as f32).collect();
+        let a = WgpuVector::from_slice(&ctx, &data);
+        let res = <WgpuVector as ArgminMul<f32, WgpuVector>>::mul(&a, &4.0).to_vec();
+        for (x, r) in data.iter().zip(res.iter()) {
+            assert_relative_eq!(x * 4.0, *r, epsilon = f32::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_mul_vec_vec() {
+        let Some(ctx) = context() else { return };
+        let data_a: Vec<f32> = (1..=1000).map(|i| i as f32).collect();
+        let data_b: Vec<f32> = (1..=1000).map(|i| 0.5 * i as f32 + 3.0).collect();
+        let a = WgpuVector::from_slice(&ctx, &data_a);
+        let b = WgpuVector::from_slice(&ctx, &data_b);
+        let res = <WgpuVector as ArgminMul<WgpuVector, WgpuVector>>::mul(&a, &b).to_vec();
+        assert_eq!(res.len(), 1000);
+        for i in 0..1000 {
+            assert_relative_eq!(data_a[i] * data_b[i], res[i], epsilon = f32::EPSILON);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mul_vec_vec_panic() {
+        let Some(ctx) = context() else {
+            panic!("no GPU adapter")
+        };
+        let a = WgpuVector::from_slice(&ctx, &[1.0, 2.0]);
+        let b = WgpuVector::from_slice(&ctx, &[1.0]);
+        <WgpuVector as ArgminMul<WgpuVector, WgpuVector>>::mul(&a, &b);
+    }
+}
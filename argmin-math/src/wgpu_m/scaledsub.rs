@@ -0,0 +1,36 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::wgpu_m::context::Kernel;
+use crate::{ArgminScaledSub, WgpuVector};
+
+/// Fused `self - factor * vec` in a single kernel
+impl ArgminScaledSub<WgpuVector, f32, WgpuVector> for WgpuVector {
+    #[inline]
+    fn scaled_sub(&self, factor: &f32, vec: &WgpuVector) -> WgpuVector {
+        self.zip(Kernel::Axpy, vec, -*factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wgpu_m::tests::context;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_scaledsub() {
+        let Some(ctx) = context() else { return };
+        let a = WgpuVector::from_slice(&ctx, &[1.0, 2.0, 3.0]);
+        let b = WgpuVector::from_slice(&ctx, &[4.0, 5.0, 6.0]);
+        let res = a.scaled_sub(&0.5, &b).to_vec();
+        let target = [-1.0, -0.5, 0.0];
+        for i in 0..3 {
+            assert_relative_eq!(target[i], res[i], epsilon = f32::EPSILON);
+        }
+    }
+}
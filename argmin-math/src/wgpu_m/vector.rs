@@ -0,0 +1,220 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::context::{Kernel, WgpuContext};
+
+/// Vector of `f32` which resides in GPU memory
+///
+/// All arithmetic is carried out by compute kernels on the device of the `WgpuContext` the vector
+/// was created with. Only the scalar results of dot products and norms are transferred back to the
+/// host. Cloning a vector copies its buffer on the device.
+///
+/// # Example
+///
+/// ```
+/// use argmin_math::{ArgminDot, ArgminScaledAdd, WgpuContext, WgpuVector};
+///
+/// # fn main() -> Result<(), anyhow::Error> {
+/// # let Ok(ctx) = WgpuContext::shared() else { return Ok(()) };
+/// # #[cfg(any())]
+/// let ctx = WgpuContext::new()?;
+/// let x = WgpuVector::from_slice(&ctx, &[1.0, 2.0, 3.0]);
+/// let y = WgpuVector::from_slice(&ctx, &[1.0, 1.0, 1.0]);
+/// let z: WgpuVector = x.scaled_add(&2.0, &y);
+/// assert_eq!(z.to_vec(), vec![3.0, 4.0, 5.0]);
+/// assert_eq!(x.dot(&y), 6.0);
+/// # Ok(())
+/// # }
+/// ```
+pub struct WgpuVector {
+    ctx: WgpuContext,
+    buffer: wgpu::Buffer,
+    len: usize,
+}
+
+impl WgpuVector {
+    /// Copy `data` to the device of `ctx`
+    pub fn from_slice(ctx: &WgpuContext, data: &[f32]) -> Self {
+        WgpuVector {
+            ctx: ctx.clone(),
+            buffer: ctx.upload(data),
+            len: data.len(),
+        }
+    }
+
+    /// Vector of `len` zeros on the device of `ctx`
+    pub fn zeros(ctx: &WgpuContext, len: usize) -> Self {
+        WgpuVector {
+            ctx: ctx.clone(),
+            buffer: ctx.zeros(len),
+            len,
+        }
+    }
+
+    /// Copy the vector back to the host
+    pub fn to_vec(&self) -> Vec<f32> {
+        self.ctx.download(&self.buffer, self.len)
+    }
+
+    /// Number of elements
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the vector has no elements
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The context the vector was created with
+    pub fn context(&self) -> &WgpuContext {
+        &self.ctx
+    }
+
+    /// Panics if `other` cannot be combined with `self` element-wise
+    fn check_compatible(&self, other: &WgpuVector) {
+        assert!(
+            self.ctx.same_device(&other.ctx),
+            "WgpuVector: vectors live on different devices"
+        );
+        assert_eq!(self.len, other.len, "WgpuVector: length mismatch");
+    }
+
+    /// Apply an element-wise `kernel` to `self` and `other`
+    pub(crate) fn zip(&self, kernel: Kernel, other: &WgpuVector, alpha: f32) -> WgpuVector {
+        self.check_compatible(other);
+        WgpuVector {
+            ctx: self.ctx.clone(),
+            buffer: self
+                .ctx
+                .elementwise(kernel, &self.buffer, &other.buffer, alpha, self.len),
+            len: self.len,
+        }
+    }
+
+    /// Apply an element-wise `kernel` involving `self` and the scalar `alpha`
+    pub(crate) fn map(&self, kernel: Kernel, alpha: f32) -> WgpuVector {
+        WgpuVector {
+            ctx: self.ctx.clone(),
+            buffer: self
+                .ctx
+                .elementwise(kernel, &self.buffer, &self.buffer, alpha, self.len),
+            len: self.len,
+        }
+    }
+
+    /// Apply a reduction `kernel` to `self` and `other`
+    pub(crate) fn reduce(&self, kernel: Kernel, other: &WgpuVector) -> f32 {
+        self.check_compatible(other);
+        self.ctx
+            .reduce(kernel, &self.buffer, &other.buffer, self.len)
+    }
+}
+
+impl Clone for WgpuVector {
+    fn clone(&self) -> Self {
+        WgpuVector {
+            ctx: self.ctx.clone(),
+            buffer: self.ctx.duplicate(&self.buffer, self.len),
+            len: self.len,
+        }
+    }
+}
+
+impl std::fmt::Debug for WgpuVector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WgpuVector")
+            .field("len", &self.len)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Serialized as a sequence of `f32`. This transfers the vector to the host.
+#[cfg(feature = "wgpu_v24-serde")]
+impl serde::Serialize for WgpuVector {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.to_vec().serialize(serializer)
+    }
+}
+
+/// Deserialized vectors are uploaded to `WgpuContext::shared()`.
+#[cfg(feature = "wgpu_v24-serde")]
+impl<'de> serde::Deserialize<'de> for WgpuVector {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let data = Vec::<f32>::deserialize(deserializer)?;
+        let ctx = WgpuContext::shared().map_err(serde::de::Error::custom)?;
+        Ok(WgpuVector::from_slice(&ctx, &data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wgpu_m::tests::context;
+
+    #[test]
+    fn test_roundtrip() {
+        let Some(ctx) = context() else { return };
+        let data: Vec<f32> = (0..1000).map(|i| i as f32 * 0.5).collect();
+        let a = WgpuVector::from_slice(&ctx, &data);
+        assert_eq!(a.len(), 1000);
+        assert!(!a.is_empty());
+        assert_eq!(a.to_vec(), data);
+    }
+
+    #[test]
+    fn test_empty() {
+        let Some(ctx) = context() else { return };
+        let a = WgpuVector::from_slice(&ctx, &[]);
+        assert!(a.is_empty());
+        assert_eq!(a.to_vec(), Vec::<f32>::new());
+        assert_eq!(a.clone().to_vec(), Vec::<f32>::new());
+    }
+
+    #[test]
+    fn test_zeros() {
+        let Some(ctx) = context() else { return };
+        assert_eq!(WgpuVector::zeros(&ctx, 3).to_vec(), vec![0.0; 3]);
+    }
+
+    #[test]
+    fn test_clone_is_deep() {
+        let Some(ctx) = context() else { return };
+        let a = WgpuVector::from_slice(&ctx, &[1.0, 2.0]);
+        let b = a.clone();
+        drop(a);
+        assert_eq!(b.to_vec(), vec![1.0, 2.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_length_mismatch() {
+        let Some(ctx) = context() else {
+            panic!("no GPU adapter")
+        };
+        let a = WgpuVector::from_slice(&ctx, &[1.0, 2.0]);
+        let b = WgpuVector::from_slice(&ctx, &[1.0]);
+        a.zip(Kernel::Add, &b, 0.0);
+    }
+
+    #[cfg(feature = "wgpu_v24-serde")]
+    #[test]
+    fn test_serde() {
+        let Some(ctx) = context() else { return };
+        let a = WgpuVector::from_slice(&ctx, &[1.0, -2.5]);
+        let json = serde_json::to_string(&a).unwrap();
+        assert_eq!(json, "[1.0,-2.5]");
+        let b: WgpuVector = serde_json::from_str(&json).unwrap();
+        assert_eq!(b.to_vec(), vec![1.0, -2.5]);
+    }
+}
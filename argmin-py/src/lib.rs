@@ -0,0 +1,354 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Python bindings for argmin
+//!
+//! Exposes the `minimize` function, which runs one of argmin's solvers on a cost function (and
+//! optionally a gradient) given as Python callables, with numpy arrays as parameter vectors. Box
+//! constraints are handled by [`TransformedProblem`], observers by a Python callable which is
+//! called after every iteration. If no gradient is given, gradient based solvers use
+//! [`FiniteDiff`] with central differences.
+//!
+//! The bindings are built with [maturin](https://github.com/PyO3/maturin): `maturin develop`
+//! in this directory installs the `argmin` Python module into the active virtual environment.
+
+use argmin::core::observers::{Observe, ObserverMode};
+use argmin::core::{
+    Bounds, CostFunction, DeserializeOwnedAlias, Error, Executor, Gradient, IterState,
+    SerializeAlias, Solver, State, TerminationReason, TransformedProblem, KV,
+};
+use argmin::derivatives::{FiniteDiff, FiniteDiffScheme};
+use argmin::solver::conjugategradient::{beta::PolakRibiere, NonlinearConjugateGradient};
+use argmin::solver::gradientdescent::SteepestDescent;
+use argmin::solver::linesearch::MoreThuenteLineSearch;
+use argmin::solver::neldermead::NelderMead;
+use argmin::solver::quasinewton::{BFGS, LBFGS};
+use argmin::{argmin_error, argmin_error_closure};
+use numpy::{IntoPyArray, PyArray1, PyReadonlyArray1};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+/// Problem whose cost function and gradient are Python callables
+///
+/// Both callables are called with the parameter vector as numpy array. The cost function must
+/// return a float, the gradient a sequence of floats. Exceptions raised in the callables abort
+/// the optimization and are re-raised by `minimize`.
+struct PyProblem {
+    /// Cost function
+    cost: PyObject,
+    /// Gradient
+    gradient: Option<PyObject>,
+}
+
+impl CostFunction for PyProblem {
+    type Param = Vec<f64>;
+    type Output = f64;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        Python::with_gil(|py| {
+            let x = PyArray1::from_slice(py, param);
+            Ok(self.cost.call1(py, (x,))?.extract(py)?)
+        })
+    }
+}
+
+impl Gradient for PyProblem {
+    type Param = Vec<f64>;
+    type Gradient = Vec<f64>;
+
+    fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+        let gradient = self.gradient.as_ref().ok_or_else(argmin_error_closure!(
+            NotImplemented,
+            "`PyProblem`: gradient requested, but no gradient callable given."
+        ))?;
+        Python::with_gil(|py| {
+            let x = PyArray1::from_slice(py, param);
+            Ok(gradient.call1(py, (x,))?.extract(py)?)
+        })
+    }
+}
+
+/// Observer which calls a Python callable with the iteration number, the current parameter
+/// vector and the current cost function value
+struct PyObserver {
+    /// Callback
+    callback: PyObject,
+}
+
+impl<I> Observe<I> for PyObserver
+where
+    I: State<Param = Vec<f64>, Float = f64>,
+{
+    fn observe_iter(&mut self, state: &I, _kv: &KV) -> Result<(), Error> {
+        Python::with_gil(|py| {
+            let x = state
+                .get_param()
+                .map(|p| PyArray1::from_slice(py, p).to_object(py))
+                .unwrap_or_else(|| py.None());
+            self.callback
+                .call1(py, (state.get_iter(), x, state.get_cost()))?;
+            Ok(())
+        })
+    }
+}
+
+/// Result of `minimize`, modeled after `scipy.optimize.OptimizeResult`
+#[pyclass(get_all)]
+struct OptimizeResult {
+    /// Best parameter vector
+    x: Py<PyArray1<f64>>,
+    /// Cost function value at `x`
+    fun: f64,
+    /// Number of iterations
+    nit: u64,
+    /// Number of cost function evaluations
+    nfev: u64,
+    /// Number of gradient evaluations
+    njev: u64,
+    /// Whether the solver converged
+    success: bool,
+    /// Reason for termination
+    message: String,
+}
+
+#[pymethods]
+impl OptimizeResult {
+    fn __repr__(&self) -> String {
+        format!(
+            "OptimizeResult(fun={}, nit={}, nfev={}, njev={}, success={}, message={:?})",
+            self.fun, self.nit, self.nfev, self.njev, self.success, self.message
+        )
+    }
+}
+
+/// Outcome of a run, independent of solver and problem
+struct Outcome {
+    /// Best parameter vector (in the variables of the solver)
+    param: Vec<f64>,
+    /// Best cost function value
+    cost: f64,
+    /// Number of iterations
+    iters: u64,
+    /// Function evaluation counts
+    cost_count: u64,
+    /// Gradient evaluation counts
+    gradient_count: u64,
+    /// Whether the solver converged
+    converged: bool,
+    /// Reason for termination
+    message: String,
+}
+
+/// Options shared by all solvers
+struct Options {
+    /// Maximum number of iterations
+    max_iters: u64,
+    /// Callback called after each iteration
+    callback: Option<PyObject>,
+}
+
+/// Runs `solver` on `problem` with the state initialized by `init` and collects the outcome
+fn run<O, S, I>(
+    problem: O,
+    solver: S,
+    init: impl FnOnce(I) -> I,
+    options: &Options,
+) -> Result<Outcome, Error>
+where
+    S: Solver<O, I>,
+    I: State<Param = Vec<f64>, Float = f64> + SerializeAlias + DeserializeOwnedAlias,
+{
+    let mut executor = Executor::new(problem, solver).configure(init);
+    if let Some(callback) = options.callback.as_ref() {
+        let callback = Python::with_gil(|py| callback.clone_ref(py));
+        executor = executor.add_observer(PyObserver { callback }, ObserverMode::Always);
+    }
+    let res = executor.run()?;
+    let state = res.state();
+    let counts = state.get_func_counts();
+    Ok(Outcome {
+        param: state
+            .get_best_param()
+            .cloned()
+            .ok_or_else(argmin_error_closure!(
+                PotentialBug,
+                "Solver did not return a parameter vector."
+            ))?,
+        cost: state.get_best_cost(),
+        iters: state.get_iter(),
+        cost_count: counts.get("cost_count").copied().unwrap_or(0),
+        gradient_count: counts.get("gradient_count").copied().unwrap_or(0),
+        converged: matches!(
+            state.get_termination_reason(),
+            Some(TerminationReason::SolverConverged | TerminationReason::TargetCostReached)
+        ),
+        message: state
+            .get_termination_reason()
+            .map(|r| r.text().to_string())
+            .unwrap_or_default(),
+    })
+}
+
+/// Initial simplex for Nelder-Mead: `x0` and `x0` with each coordinate perturbed by 5% (or by
+/// `0.00025` if it is zero), as in `scipy.optimize`
+fn initial_simplex(x0: &[f64]) -> Vec<Vec<f64>> {
+    let mut simplex = vec![x0.to_vec()];
+    for i in 0..x0.len() {
+        let mut vertex = x0.to_vec();
+        vertex[i] = if vertex[i] == 0.0 {
+            0.00025
+        } else {
+            1.05 * vertex[i]
+        };
+        simplex.push(vertex);
+    }
+    simplex
+}
+
+/// Runs the solver `method` on a problem with cost function and gradient
+fn solve<O>(problem: O, method: &str, x0: Vec<f64>, options: &Options) -> Result<Outcome, Error>
+where
+    O: CostFunction<Param = Vec<f64>, Output = f64>
+        + Gradient<Param = Vec<f64>, Gradient = Vec<f64>>,
+{
+    let max_iters = options.max_iters;
+    let linesearch = MoreThuenteLineSearch::new();
+    match method {
+        "lbfgs" | "l-bfgs" => run(
+            problem,
+            LBFGS::new(linesearch, 7),
+            |state: IterState<_, _, (), (), f64>| state.param(x0).max_iters(max_iters),
+            options,
+        ),
+        "bfgs" => {
+            let n = x0.len();
+            let eye = (0..n)
+                .map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+                .collect::<Vec<Vec<f64>>>();
+            run(
+                problem,
+                BFGS::new(linesearch),
+                |state: IterState<_, _, (), _, f64>| {
+                    state.param(x0).inv_hessian(eye).max_iters(max_iters)
+                },
+                options,
+            )
+        }
+        "cg" => run(
+            problem,
+            NonlinearConjugateGradient::new(linesearch, PolakRibiere::new()),
+            |state: IterState<_, _, (), (), f64>| state.param(x0).max_iters(max_iters),
+            options,
+        ),
+        "steepestdescent" => run(
+            problem,
+            SteepestDescent::new(linesearch),
+            |state: IterState<_, _, (), (), f64>| state.param(x0).max_iters(max_iters),
+            options,
+        ),
+        "neldermead" | "nelder-mead" => run(
+            problem,
+            NelderMead::new(initial_simplex(&x0)),
+            |state: IterState<_, (), (), (), f64>| state.max_iters(max_iters),
+            options,
+        ),
+        _ => Err(argmin_error!(
+            InvalidParameter,
+            format!(
+                "Unknown method {method:?}; expected one of \"lbfgs\", \"bfgs\", \"cg\", \
+                 \"steepestdescent\" or \"neldermead\"."
+            )
+        )),
+    }
+}
+
+/// Runs the solver `method` on a problem, optionally subject to box constraints
+fn solve_bounded<O>(
+    problem: O,
+    method: &str,
+    x0: Vec<f64>,
+    bounds: Option<(Vec<f64>, Vec<f64>)>,
+    options: &Options,
+) -> Result<Outcome, Error>
+where
+    O: CostFunction<Param = Vec<f64>, Output = f64>
+        + Gradient<Param = Vec<f64>, Gradient = Vec<f64>>,
+{
+    match bounds {
+        None => solve(problem, method, x0, options),
+        Some((lower, upper)) => {
+            let bounds = Bounds::new(lower, upper)?;
+            // Only used for mapping the best parameter vector back after the run
+            let transform = TransformedProblem::new((), bounds.clone());
+            let problem = TransformedProblem::new(problem, bounds);
+            let x0 = problem.to_unbounded(&x0)?;
+            let mut outcome = solve(problem, method, x0, options)?;
+            outcome.param = transform.to_bounded(&outcome.param);
+            Ok(outcome)
+        }
+    }
+}
+
+/// Minimizes a scalar function of one or more variables
+///
+/// `fun` is called with the parameter vector as numpy array and returns a float. `jac` (if given)
+/// returns the gradient; otherwise gradient based methods approximate it by central finite
+/// differences. `bounds` is a tuple of lower and upper bounds (which may be infinite). `callback`
+/// is called after every iteration with the iteration number, the current parameter vector and
+/// cost function value.
+#[pyfunction]
+#[allow(clippy::too_many_arguments)]
+#[pyo3(signature = (fun, x0, method = "lbfgs", jac = None, bounds = None, max_iters = 1000, callback = None))]
+fn minimize(
+    py: Python<'_>,
+    fun: PyObject,
+    x0: PyReadonlyArray1<'_, f64>,
+    method: &str,
+    jac: Option<PyObject>,
+    bounds: Option<(Vec<f64>, Vec<f64>)>,
+    max_iters: u64,
+    callback: Option<PyObject>,
+) -> PyResult<OptimizeResult> {
+    let x0 = x0.as_array().to_vec();
+    if x0.is_empty() {
+        return Err(PyValueError::new_err("`x0` must not be empty"));
+    }
+    let method = method.to_lowercase();
+    let options = Options {
+        max_iters,
+        callback,
+    };
+    let has_gradient = jac.is_some();
+    let problem = PyProblem {
+        cost: fun,
+        gradient: jac,
+    };
+    let outcome = if has_gradient {
+        solve_bounded(problem, &method, x0, bounds, &options)?
+    } else {
+        let problem = FiniteDiff::new(problem).with_scheme(FiniteDiffScheme::Central);
+        solve_bounded(problem, &method, x0, bounds, &options)?
+    };
+    Ok(OptimizeResult {
+        x: outcome.param.into_pyarray(py).to_owned(),
+        fun: outcome.cost,
+        nit: outcome.iters,
+        nfev: outcome.cost_count,
+        njev: outcome.gradient_count,
+        success: outcome.converged,
+        message: outcome.message,
+    })
+}
+
+/// argmin: mathematical optimization in Rust
+#[pymodule]
+#[pyo3(name = "argmin")]
+fn argmin_py(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(minimize, m)?)?;
+    m.add_class::<OptimizeResult>()?;
+    Ok(())
+}
@@ -0,0 +1,88 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use argmin::core::observers::{ObserverMode, SlogLogger};
+use argmin::core::{CostFunction, Error, Executor, Gradient, InequalityConstraint};
+use argmin::solver::barrier::BarrierMethod;
+use argmin::solver::linesearch::{condition::ArmijoCondition, BacktrackingLineSearch};
+use argmin::solver::quasinewton::LBFGS;
+use argmin_testfunctions::{rosenbrock_2d, rosenbrock_2d_derivative};
+
+/// Rosenbrock function restricted to the unit disk
+struct ConstrainedRosenbrock {
+    a: f64,
+    b: f64,
+}
+
+impl CostFunction for ConstrainedRosenbrock {
+    type Param = Vec<f64>;
+    type Output = f64;
+
+    fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+        Ok(rosenbrock_2d(p, self.a, self.b))
+    }
+}
+
+impl Gradient for ConstrainedRosenbrock {
+    type Param = Vec<f64>;
+    type Gradient = Vec<f64>;
+
+    fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+        Ok(rosenbrock_2d_derivative(p, self.a, self.b))
+    }
+}
+
+impl InequalityConstraint for ConstrainedRosenbrock {
+    type Param = Vec<f64>;
+    type Gradient = Vec<f64>;
+    type Float = f64;
+
+    /// x^2 + y^2 - 1 <= 0
+    fn inequality_constraints(&self, p: &Self::Param) -> Result<Vec<f64>, Error> {
+        Ok(vec![p[0].powi(2) + p[1].powi(2) - 1.0])
+    }
+
+    fn inequality_jacobian(&self, p: &Self::Param) -> Result<Vec<Self::Gradient>, Error> {
+        Ok(vec![vec![2.0 * p[0], 2.0 * p[1]]])
+    }
+}
+
+fn run() -> Result<(), Error> {
+    // Define cost function
+    let cost = ConstrainedRosenbrock { a: 1.0, b: 100.0 };
+
+    // Define initial parameter vector (must be strictly feasible)
+    let init_param: Vec<f64> = vec![0.0, 0.0];
+
+    // Set up inner solver for the unconstrained subproblems. The backtracking line search rejects
+    // infeasible steps, for which the barrier function is infinite.
+    let linesearch = BacktrackingLineSearch::new(ArmijoCondition::new(1e-4)?);
+    let inner = LBFGS::new(linesearch, 7);
+
+    // Set up barrier method
+    let solver = BarrierMethod::new(inner).with_tolerance(1e-8)?;
+
+    // Run solver
+    let res = Executor::new(cost, solver)
+        .configure(|state| state.param(init_param).max_iters(20))
+        .add_observer(SlogLogger::term(), ObserverMode::Always)
+        .run()?;
+
+    // Wait a second (lets the logger flush everything before printing again)
+    std::thread::sleep(std::time::Duration::from_secs(1));
+
+    // Print result
+    println!("{res}");
+    Ok(())
+}
+
+fn main() {
+    if let Err(ref e) = run() {
+        println!("{e}");
+        std::process::exit(1);
+    }
+}
@@ -7,7 +7,7 @@
 
 use argmin::core::observers::{ObserverMode, SlogLogger};
 use argmin::core::{CostFunction, Error, Executor};
-use argmin::solver::brent::BrentRoot;
+use argmin::solver::rootfinding::BrentRoot;
 
 /// Test function generalise from Wikipedia example
 struct TestFunc {
@@ -8,6 +8,7 @@
 use argmin::core::observers::{ObserverMode, SlogLogger};
 use argmin::core::{Error, Executor, Jacobian, Operator};
 use argmin::solver::gaussnewton::GaussNewton;
+use argmin::solver::linearsolver::CholeskySolver;
 
 use nalgebra::{DMatrix, DVector};
 
@@ -70,8 +71,9 @@ fn run() -> Result<(), Error> {
     // Define initial parameter vector
     let init_param: DVector<f64> = DVector::from_vec(vec![0.9, 0.2]);
 
-    // Set up solver
-    let solver: GaussNewton<f64> = GaussNewton::new();
+    // Set up solver. The normal equations are solved via a Cholesky factorization instead of
+    // inverting `J^T J`.
+    let solver: GaussNewton<f64, _> = GaussNewton::new().with_linear_solver(CholeskySolver::new());
 
     // Run solver
     let res = Executor::new(cost, solver)
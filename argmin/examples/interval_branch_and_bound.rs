@@ -0,0 +1,156 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Verified global minimization with interval branch-and-bound
+//!
+//! The cost function is evaluated over boxes of intervals, which yields rigorous lower bounds of
+//! the cost function on each box. Boxes whose lower bound exceeds the best known cost are
+//! discarded, all others are bisected. The result is an enclosure `[lower, upper]` of the global
+//! minimum.
+
+use argmin::core::observers::{ObserverMode, SlogLogger};
+use argmin::core::{
+    CostFunction, Error, Executor, IterState, Problem, Solver, State, TerminationReason,
+    TerminationStatus, KV,
+};
+use argmin::kv;
+use argmin_math::Interval;
+
+/// Six-hump camel function
+///
+/// `f(x, y) = (4 - 2.1 x^2 + x^4 / 3) x^2 + x y + (-4 + 4 y^2) y^2`
+///
+/// The global minimum `-1.0316...` is attained at `(0.0898, -0.7126)` and `(-0.0898, 0.7126)`.
+struct SixHumpCamel {}
+
+impl CostFunction for SixHumpCamel {
+    type Param = Vec<Interval<f64>>;
+    type Output = Interval<f64>;
+
+    fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+        let (x, y) = (p[0], p[1]);
+        let c = Interval::point;
+        Ok((c(4.0) - c(2.1) * x.sqr() + x.powi(4) / c(3.0)) * x.sqr()
+            + x * y
+            + (c(-4.0) + c(4.0) * y.sqr()) * y.sqr())
+    }
+}
+
+/// Interval branch-and-bound
+struct IntervalBranchAndBound {
+    /// Boxes which may still contain the global minimum, with their lower bounds
+    boxes: Vec<(Vec<Interval<f64>>, f64)>,
+    /// Width of the enclosure of the global minimum at which the search stops
+    tol: f64,
+}
+
+impl IntervalBranchAndBound {
+    fn new(domain: Vec<Interval<f64>>, tol: f64) -> Self {
+        IntervalBranchAndBound {
+            boxes: vec![(domain, f64::NEG_INFINITY)],
+            tol,
+        }
+    }
+
+    /// Lowest lower bound of all remaining boxes
+    fn lower_bound(&self) -> f64 {
+        self.boxes
+            .iter()
+            .map(|(_, lo)| *lo)
+            .fold(f64::INFINITY, f64::min)
+    }
+}
+
+impl<O> Solver<O, IterState<Vec<f64>, (), (), (), f64>> for IntervalBranchAndBound
+where
+    O: CostFunction<Param = Vec<Interval<f64>>, Output = Interval<f64>>,
+{
+    const NAME: &'static str = "Interval branch-and-bound";
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<Vec<f64>, (), (), (), f64>,
+    ) -> Result<(IterState<Vec<f64>, (), (), (), f64>, Option<KV>), Error> {
+        // Branch on the box with the lowest lower bound
+        let idx = self
+            .boxes
+            .iter()
+            .enumerate()
+            .min_by(|a, b| a.1 .1.total_cmp(&b.1 .1))
+            .map(|(i, _)| i)
+            .unwrap();
+        let (b, _) = self.boxes.swap_remove(idx);
+
+        // The cost at the midpoint, evaluated on a degenerate box, is a rigorous upper bound of
+        // the global minimum
+        let mid: Vec<f64> = b.iter().map(Interval::mid).collect();
+        let upper = problem
+            .cost(&mid.iter().map(|m| Interval::point(*m)).collect())?
+            .hi();
+        if upper < state.get_best_cost() {
+            state = state.param(mid).cost(upper);
+        }
+        let best = state.get_best_cost().min(state.get_cost());
+
+        // Bisect along the widest dimension and keep the parts which may contain the minimum
+        let dim = (0..b.len())
+            .max_by(|i, j| b[*i].width().total_cmp(&b[*j].width()))
+            .unwrap();
+        let (left, right) = b[dim].bisect();
+        for part in [left, right] {
+            let mut child = b.clone();
+            child[dim] = part;
+            let lower = problem.cost(&child)?.lo();
+            if lower <= best {
+                self.boxes.push((child, lower));
+            }
+        }
+        // Discard all boxes which cannot contain the minimum anymore
+        self.boxes.retain(|(_, lo)| *lo <= best);
+
+        let lower = self.lower_bound();
+        Ok((
+            state,
+            Some(kv!("boxes" => self.boxes.len() as u64; "lower_bound" => lower;)),
+        ))
+    }
+
+    fn terminate(&mut self, state: &IterState<Vec<f64>, (), (), (), f64>) -> TerminationStatus {
+        if self.boxes.is_empty() || state.get_best_cost() - self.lower_bound() < self.tol {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+fn run() -> Result<(), Error> {
+    let domain = vec![Interval::new(-3.0, 3.0), Interval::new(-2.0, 2.0)];
+    let solver = IntervalBranchAndBound::new(domain, 1e-3);
+
+    let res = Executor::new(SixHumpCamel {}, solver)
+        .configure(|state| state.max_iters(100_000))
+        .add_observer(SlogLogger::term(), ObserverMode::Every(1000))
+        .run()?;
+
+    // Wait a second (lets the logger flush everything before printing again)
+    std::thread::sleep(std::time::Duration::from_secs(1));
+
+    let lower = res.solver.lower_bound();
+    let upper = res.state.get_best_cost();
+    println!("global minimum in [{lower}, {upper}]");
+    println!("attained near {:?}", res.state.get_best_param().unwrap());
+    println!("{res}");
+    Ok(())
+}
+
+fn main() {
+    if let Err(ref e) = run() {
+        println!("{e}");
+        std::process::exit(1);
+    }
+}
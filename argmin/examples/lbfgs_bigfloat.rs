@@ -0,0 +1,119 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use argmin::core::observers::{ObserverMode, SlogLogger};
+use argmin::core::{CostFunction, Error, Executor, Gradient};
+use argmin::solver::linesearch::MoreThuenteLineSearch;
+use argmin::solver::quasinewton::LBFGS;
+use num_bigfloat::{BigFloat, ONE, ZERO};
+
+/// Quadratic `f(x) = 1/2 x^T H x - b^T x` with the Hilbert matrix `H_ij = 1 / (i + j + 1)`
+///
+/// `b` is chosen such that the minimum is at `x = (1, ..., 1)`. The Hilbert matrix is notoriously
+/// ill-conditioned, which limits the accuracy achievable in `f64`.
+struct Hilbert {
+    matrix: Vec<Vec<BigFloat>>,
+    rhs: Vec<BigFloat>,
+}
+
+impl Hilbert {
+    fn new(n: usize) -> Self {
+        let matrix: Vec<Vec<BigFloat>> = (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|j| ONE / BigFloat::from_u64((i + j + 1) as u64))
+                    .collect()
+            })
+            .collect();
+        let rhs = matrix.iter().map(|row| row.iter().copied().sum()).collect();
+        Hilbert { matrix, rhs }
+    }
+
+    fn apply(&self, x: &[BigFloat]) -> Vec<BigFloat> {
+        self.matrix
+            .iter()
+            .map(|row| row.iter().zip(x.iter()).map(|(h, x)| *h * *x).sum())
+            .collect()
+    }
+}
+
+impl CostFunction for Hilbert {
+    type Param = Vec<BigFloat>;
+    type Output = BigFloat;
+
+    fn cost(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+        Ok(self
+            .apply(x)
+            .iter()
+            .zip(x.iter().zip(self.rhs.iter()))
+            .map(|(hx, (x, b))| (BigFloat::from_f64(0.5) * *hx - *b) * *x)
+            .sum())
+    }
+}
+
+impl Gradient for Hilbert {
+    type Param = Vec<BigFloat>;
+    type Gradient = Vec<BigFloat>;
+
+    fn gradient(&self, x: &Self::Param) -> Result<Self::Gradient, Error> {
+        Ok(self
+            .apply(x)
+            .iter()
+            .zip(self.rhs.iter())
+            .map(|(hx, b)| *hx - *b)
+            .collect())
+    }
+}
+
+fn run() -> Result<(), Error> {
+    let n = 6;
+
+    // Define cost function
+    let cost = Hilbert::new(n);
+
+    // Define initial parameter vector
+    let init_param = vec![ZERO; n];
+
+    // set up a line search
+    let linesearch = MoreThuenteLineSearch::new();
+
+    // Set up solver. The tolerances are far below what `f64` could achieve.
+    let solver = LBFGS::new(linesearch, 7)
+        .with_tolerance_grad(BigFloat::parse("1e-30").unwrap())?
+        .with_tolerance_cost(BigFloat::parse("1e-39").unwrap())?;
+
+    // Run solver
+    let res = Executor::new(cost, solver)
+        .configure(|state| state.param(init_param).max_iters(1000))
+        .add_observer(SlogLogger::term(), ObserverMode::Always)
+        .run()?;
+
+    // Wait a second (lets the logger flush everything before printing again)
+    std::thread::sleep(std::time::Duration::from_secs(1));
+
+    // Print result
+    println!("{res}");
+
+    // Distance to the exact solution `x = (1, ..., 1)`
+    let error = res
+        .state
+        .best_param
+        .unwrap()
+        .iter()
+        .map(|x| (*x - ONE) * (*x - ONE))
+        .sum::<BigFloat>()
+        .sqrt();
+    println!("Distance to solution: {error}");
+    Ok(())
+}
+
+fn main() {
+    if let Err(ref e) = run() {
+        println!("{e}");
+        std::process::exit(1);
+    }
+}
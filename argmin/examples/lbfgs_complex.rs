@@ -0,0 +1,135 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use argmin::core::observers::{ObserverMode, SlogLogger};
+use argmin::core::{CostFunction, Error, Executor, Gradient};
+use argmin::solver::linesearch::MoreThuenteLineSearch;
+use argmin::solver::quasinewton::LBFGS;
+use num_complex::Complex;
+use rand::{Rng, SeedableRng};
+use rand_xoshiro::Xoshiro256PlusPlus;
+
+type C64 = Complex<f64>;
+
+/// Phase retrieval: recover `z` from intensity measurements `y_k = |a_k^H z|^2`
+///
+/// `f(z) = sum_k (|a_k^H z|^2 - y_k)^2`
+struct PhaseRetrieval {
+    measurement_vectors: Vec<Vec<C64>>,
+    intensities: Vec<f64>,
+}
+
+impl PhaseRetrieval {
+    /// Returns `a_k^H z` for all measurement vectors
+    fn project(&self, z: &[C64]) -> Vec<C64> {
+        self.measurement_vectors
+            .iter()
+            .map(|a| a.iter().zip(z.iter()).map(|(a, z)| a.conj() * z).sum())
+            .collect()
+    }
+}
+
+impl CostFunction for PhaseRetrieval {
+    type Param = Vec<C64>;
+    type Output = f64;
+
+    fn cost(&self, z: &Self::Param) -> Result<Self::Output, Error> {
+        Ok(self
+            .project(z)
+            .iter()
+            .zip(self.intensities.iter())
+            .map(|(u, y)| (u.norm_sqr() - y).powi(2))
+            .sum())
+    }
+}
+
+impl Gradient for PhaseRetrieval {
+    type Param = Vec<C64>;
+    type Gradient = Vec<C64>;
+
+    /// Gradient in Wirtinger form: `2 df/dconj(z) = sum_k 4 (|a_k^H z|^2 - y_k) (a_k^H z) a_k`
+    fn gradient(&self, z: &Self::Param) -> Result<Self::Gradient, Error> {
+        let mut grad = vec![C64::new(0.0, 0.0); z.len()];
+        for ((u, y), a) in self
+            .project(z)
+            .iter()
+            .zip(self.intensities.iter())
+            .zip(self.measurement_vectors.iter())
+        {
+            let factor = 4.0 * (u.norm_sqr() - y) * u;
+            for (g, a) in grad.iter_mut().zip(a.iter()) {
+                *g += factor * a;
+            }
+        }
+        Ok(grad)
+    }
+}
+
+fn run() -> Result<(), Error> {
+    let n = 16;
+    let m = 8 * n;
+    let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+    let mut random_vector = |len: usize| -> Vec<C64> {
+        (0..len)
+            .map(|_| C64::new(rng.gen_range(-1.0..1.0), rng.gen_range(-1.0..1.0)))
+            .collect()
+    };
+
+    // Generate measurements of an unknown signal
+    let signal = random_vector(n);
+    let measurement_vectors: Vec<Vec<C64>> = (0..m).map(|_| random_vector(n)).collect();
+    let mut cost = PhaseRetrieval {
+        measurement_vectors,
+        intensities: vec![],
+    };
+    cost.intensities = cost.project(&signal).iter().map(|u| u.norm_sqr()).collect();
+
+    // Define initial parameter vector
+    let init_param = random_vector(n);
+
+    // set up a line search
+    let linesearch = MoreThuenteLineSearch::new();
+
+    // Set up solver
+    let solver = LBFGS::new(linesearch, 7);
+
+    // Run solver
+    let res = Executor::new(cost, solver)
+        .configure(|state| state.param(init_param).max_iters(200))
+        .add_observer(SlogLogger::term(), ObserverMode::Always)
+        .run()?;
+
+    // Wait a second (lets the logger flush everything before printing again)
+    std::thread::sleep(std::time::Duration::from_secs(1));
+
+    // Print result
+    println!("{res}");
+
+    // The signal can only be recovered up to a global phase
+    let estimate = res.state.best_param.unwrap();
+    let overlap: C64 = estimate
+        .iter()
+        .zip(signal.iter())
+        .map(|(e, s)| e.conj() * s)
+        .sum();
+    let phase = overlap / overlap.norm();
+    let error = estimate
+        .iter()
+        .zip(signal.iter())
+        .map(|(e, s)| (e * phase - s).norm_sqr())
+        .sum::<f64>()
+        .sqrt();
+    println!("Distance to signal (up to global phase): {error:e}");
+    Ok(())
+}
+
+fn main() {
+    if let Err(ref e) = run() {
+        println!("{e}");
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use argmin::core::observers::{ObserverMode, SlogLogger};
+use argmin::core::{DualCostFunction, Error, Executor, ForwardDiff};
+use argmin::solver::linesearch::MoreThuenteLineSearch;
+use argmin::solver::quasinewton::LBFGS;
+use num_dual::DualNum;
+
+/// Extended Rosenbrock function; the gradient is computed by `ForwardDiff`
+struct Rosenbrock {}
+
+impl DualCostFunction for Rosenbrock {
+    type Float = f64;
+
+    fn cost<D: DualNum<Primitive = f64> + Copy>(&self, p: &[D]) -> Result<D, Error> {
+        Ok(p.windows(2)
+            .map(|w| (-w[0] + 1.0).powi(2) + (w[1] - w[0].powi(2)).powi(2) * 100.0)
+            .sum())
+    }
+}
+
+fn run() -> Result<(), Error> {
+    // Define cost function
+    let cost = ForwardDiff::new(Rosenbrock {});
+
+    // Define initial parameter vector
+    let init_param: Vec<f64> = vec![-1.2, 1.0, -1.2, 1.0];
+
+    // set up a line search
+    let linesearch = MoreThuenteLineSearch::new();
+
+    // Set up solver
+    let solver = LBFGS::new(linesearch, 7);
+
+    // Run solver
+    let res = Executor::new(cost, solver)
+        .configure(|state| state.param(init_param).max_iters(100))
+        .add_observer(SlogLogger::term(), ObserverMode::Always)
+        .run()?;
+
+    // Wait a second (lets the logger flush everything before printing again)
+    std::thread::sleep(std::time::Duration::from_secs(1));
+
+    // Print result
+    println!("{res}");
+    Ok(())
+}
+
+fn main() {
+    if let Err(ref e) = run() {
+        println!("{e}");
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,93 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use argmin::core::observers::{ObserverMode, SlogLogger};
+use argmin::core::{CostFunction, Error, Executor, Gradient, State};
+use argmin::solver::linesearch::MoreThuenteLineSearch;
+use argmin::solver::quasinewton::LBFGS;
+use half::f16;
+
+/// Separable quadratic `f(x) = 1/2 sum_i d_i (x_i - c_i)^2`
+///
+/// The parameter vector is stored in half precision, cost function values are `f32`.
+struct Quadratic {
+    scale: Vec<f32>,
+    center: Vec<f32>,
+}
+
+impl Quadratic {
+    fn new(n: usize) -> Self {
+        Quadratic {
+            scale: (0..n).map(|i| 1.0 + (i % 10) as f32).collect(),
+            center: (0..n).map(|i| ((i % 7) as f32 - 3.0) / 4.0).collect(),
+        }
+    }
+}
+
+impl CostFunction for Quadratic {
+    type Param = Vec<f16>;
+    type Output = f32;
+
+    fn cost(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+        Ok(x.iter()
+            .zip(self.scale.iter().zip(self.center.iter()))
+            .map(|(x, (d, c))| 0.5 * d * (x.to_f32() - c).powi(2))
+            .sum())
+    }
+}
+
+impl Gradient for Quadratic {
+    type Param = Vec<f16>;
+    type Gradient = Vec<f16>;
+
+    fn gradient(&self, x: &Self::Param) -> Result<Self::Gradient, Error> {
+        Ok(x.iter()
+            .zip(self.scale.iter().zip(self.center.iter()))
+            .map(|(x, (d, c))| f16::from_f32(d * (x.to_f32() - c)))
+            .collect())
+    }
+}
+
+fn run() -> Result<(), Error> {
+    let n = 1_000_000;
+
+    // Define cost function
+    let cost = Quadratic::new(n);
+
+    // Define initial parameter vector (2 MB instead of 4 MB for `f32` or 8 MB for `f64`)
+    let init_param = vec![f16::ZERO; n];
+
+    // set up a line search
+    let linesearch = MoreThuenteLineSearch::new();
+
+    // Set up solver. The tolerances must account for the limited precision of `f16`.
+    let solver = LBFGS::new(linesearch, 7)
+        .with_tolerance_grad(1e-1)?
+        .with_tolerance_cost(1e-4)?;
+
+    // Run solver
+    let res = Executor::new(cost, solver)
+        .configure(|state| state.param(init_param).max_iters(50))
+        .add_observer(SlogLogger::term(), ObserverMode::Always)
+        .run()?;
+
+    // Wait a second (lets the logger flush everything before printing again)
+    std::thread::sleep(std::time::Duration::from_secs(1));
+
+    // Print result (the parameter vector is too large to be printed)
+    println!("cost (best):   {}", res.state.get_best_cost());
+    println!("iters (total): {}", res.state.get_iter());
+    println!("termination:   {}", res.state.get_termination_status());
+    Ok(())
+}
+
+fn main() {
+    if let Err(ref e) = run() {
+        println!("{e}");
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use argmin::core::observers::{ObserverMode, SlogLogger};
+use argmin::core::{CostFunction, Error, Executor, Gradient, State};
+use argmin::solver::linesearch::MoreThuenteLineSearch;
+use argmin::solver::quasinewton::LBFGS;
+
+/// Separable quadratic `f(x) = 1/2 sum_i d_i (x_i - c_i)^2`
+///
+/// The parameter vector and the gradient are stored in `f32`, cost function values, dot products
+/// and norms are `f64` (requires the `vec-mixed` feature of `argmin-math`).
+struct Quadratic {
+    scale: Vec<f32>,
+    center: Vec<f32>,
+}
+
+impl Quadratic {
+    fn new(n: usize) -> Self {
+        Quadratic {
+            scale: (0..n).map(|i| 1.0 + (i % 100) as f32).collect(),
+            center: (0..n).map(|i| ((i % 7) as f32 - 3.0) / 4.0).collect(),
+        }
+    }
+}
+
+impl CostFunction for Quadratic {
+    type Param = Vec<f32>;
+    type Output = f64;
+
+    fn cost(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+        Ok(x.iter()
+            .zip(self.scale.iter().zip(self.center.iter()))
+            .map(|(x, (d, c))| 0.5 * f64::from(*d) * (f64::from(*x) - f64::from(*c)).powi(2))
+            .sum())
+    }
+}
+
+impl Gradient for Quadratic {
+    type Param = Vec<f32>;
+    type Gradient = Vec<f32>;
+
+    fn gradient(&self, x: &Self::Param) -> Result<Self::Gradient, Error> {
+        Ok(x.iter()
+            .zip(self.scale.iter().zip(self.center.iter()))
+            .map(|(x, (d, c))| d * (x - c))
+            .collect())
+    }
+}
+
+fn run() -> Result<(), Error> {
+    let n = 1_000_000;
+
+    // Define cost function
+    let cost = Quadratic::new(n);
+
+    // Define initial parameter vector (4 MB instead of 8 MB for `f64`)
+    let init_param = vec![0.0f32; n];
+
+    // set up a line search
+    let linesearch = MoreThuenteLineSearch::new();
+
+    // Set up solver. The floating point type is `f64`, the tolerances are limited by the
+    // precision of the `f32` parameter vector though.
+    let solver: LBFGS<_, Vec<f32>, Vec<f32>, f64> = LBFGS::new(linesearch, 7)
+        .with_tolerance_grad(1e-3)?
+        .with_tolerance_cost(1e-10)?;
+
+    // Run solver
+    let res = Executor::new(cost, solver)
+        .configure(|state| state.param(init_param).max_iters(100))
+        .add_observer(SlogLogger::term(), ObserverMode::Always)
+        .run()?;
+
+    // Wait a second (lets the logger flush everything before printing again)
+    std::thread::sleep(std::time::Duration::from_secs(1));
+
+    // Print result (the parameter vector is too large to be printed)
+    println!("cost (best):   {}", res.state.get_best_cost());
+    println!("iters (total): {}", res.state.get_iter());
+    println!("termination:   {}", res.state.get_termination_status());
+    Ok(())
+}
+
+fn main() {
+    if let Err(ref e) = run() {
+        println!("{e}");
+        std::process::exit(1);
+    }
+}
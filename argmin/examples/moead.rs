@@ -0,0 +1,65 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use argmin::core::{Error, Executor, MultiCostFunction};
+use argmin::solver::moead::{simplex_lattice_weights, MOEAD};
+use std::f64::consts::FRAC_PI_2;
+
+/// DTLZ2 test problem with three objectives, whose Pareto front is the positive octant of the
+/// unit sphere
+struct Dtlz2 {}
+
+impl MultiCostFunction for Dtlz2 {
+    type Param = Vec<f64>;
+    type Float = f64;
+
+    fn multi_cost(&self, p: &Self::Param) -> Result<Vec<Self::Float>, Error> {
+        let g: f64 = p[2..].iter().map(|x| (x - 0.5).powi(2)).sum();
+        let (a, b) = (p[0] * FRAC_PI_2, p[1] * FRAC_PI_2);
+        Ok(vec![
+            (1.0 + g) * a.cos() * b.cos(),
+            (1.0 + g) * a.cos() * b.sin(),
+            (1.0 + g) * a.sin(),
+        ])
+    }
+}
+
+fn run() -> Result<(), Error> {
+    let problem = Dtlz2 {};
+
+    // 91 evenly spread weight vectors for three objectives
+    let weights = simplex_lattice_weights(3, 12);
+    let solver = MOEAD::new((vec![0.0; 12], vec![1.0; 12]), weights)?;
+
+    let res = Executor::new(problem, solver)
+        .configure(|state| state.max_iters(300))
+        .run()?;
+
+    // Print solutions of the subproblems
+    let costs = res.state.get_population_costs().unwrap();
+    for c in costs {
+        println!(
+            "f = ({:.3}, {:.3}, {:.3}), |f| = {:.4}",
+            c[0],
+            c[1],
+            c[2],
+            c.iter().map(|x| x.powi(2)).sum::<f64>().sqrt()
+        );
+    }
+    println!(
+        "Archive of non-dominated solutions: {} points",
+        res.state.get_pareto_front().unwrap().len()
+    );
+
+    Ok(())
+}
+
+fn main() {
+    if let Err(ref e) = run() {
+        println!("{e}");
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use argmin::core::observers::{ObserverMode, SlogLogger};
+use argmin::core::{CostFunction, Error, Executor, Gradient, Hessian, State};
+use argmin::solver::linesearch::MoreThuenteLineSearch;
+use argmin::solver::newton::NewtonCG;
+use nalgebra::DVector;
+use nalgebra_sparse::{CooMatrix, CsrMatrix};
+
+/// Discretized nonlinear 1D Poisson problem
+///
+/// `f(u) = 1/2 u^T A u + 1/4 sum_i u_i^4 - b^T u`
+///
+/// where `A` is the (tridiagonal) finite difference Laplacian. The Hessian `A + diag(3 u_i^2)` is
+/// sparse and never densified.
+struct Poisson {
+    laplacian: CsrMatrix<f64>,
+    rhs: DVector<f64>,
+}
+
+impl Poisson {
+    fn new(n: usize) -> Self {
+        Poisson {
+            laplacian: CsrMatrix::from(&tridiagonal(n, &DVector::zeros(n))),
+            rhs: DVector::from_element(n, 1.0),
+        }
+    }
+}
+
+/// Returns the finite difference Laplacian with `diag` added to the main diagonal
+fn tridiagonal(n: usize, diag: &DVector<f64>) -> CooMatrix<f64> {
+    let mut coo = CooMatrix::new(n, n);
+    for i in 0..n {
+        coo.push(i, i, 2.0 + diag[i]);
+        if i > 0 {
+            coo.push(i, i - 1, -1.0);
+        }
+        if i + 1 < n {
+            coo.push(i, i + 1, -1.0);
+        }
+    }
+    coo
+}
+
+impl CostFunction for Poisson {
+    type Param = DVector<f64>;
+    type Output = f64;
+
+    fn cost(&self, u: &Self::Param) -> Result<Self::Output, Error> {
+        let au = &self.laplacian * u;
+        Ok(0.5 * u.dot(&au) + 0.25 * u.map(|x| x.powi(4)).sum() - self.rhs.dot(u))
+    }
+}
+
+impl Gradient for Poisson {
+    type Param = DVector<f64>;
+    type Gradient = DVector<f64>;
+
+    fn gradient(&self, u: &Self::Param) -> Result<Self::Gradient, Error> {
+        Ok(&self.laplacian * u + u.map(|x| x.powi(3)) - &self.rhs)
+    }
+}
+
+impl Hessian for Poisson {
+    type Param = DVector<f64>;
+    type Hessian = CsrMatrix<f64>;
+
+    fn hessian(&self, u: &Self::Param) -> Result<Self::Hessian, Error> {
+        Ok(CsrMatrix::from(&tridiagonal(
+            u.len(),
+            &u.map(|x| 3.0 * x.powi(2)),
+        )))
+    }
+}
+
+fn run() -> Result<(), Error> {
+    let n = 1000;
+
+    // Define cost function
+    let cost = Poisson::new(n);
+
+    // Define initial parameter vector
+    let init_param = DVector::zeros(n);
+
+    // set up line search
+    let linesearch = MoreThuenteLineSearch::new();
+
+    // Set up solver
+    let solver = NewtonCG::new(linesearch);
+
+    // Run solver
+    let res = Executor::new(cost, solver)
+        .configure(|state| state.param(init_param).max_iters(20))
+        .add_observer(SlogLogger::term(), ObserverMode::Always)
+        .run()?;
+
+    // Wait a second (lets the logger flush everything before printing again)
+    std::thread::sleep(std::time::Duration::from_secs(1));
+
+    // Print result (the parameter vector is too large to be printed)
+    println!("cost (best):   {}", res.state.get_best_cost());
+    println!("iters (total): {}", res.state.get_iter());
+    println!("termination:   {}", res.state.get_termination_status());
+    Ok(())
+}
+
+fn main() {
+    if let Err(ref e) = run() {
+        println!("{e}");
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use argmin::core::observers::{ObserverMode, SlogLogger};
+use argmin::core::{Error, Executor, Gradient, Hessian};
+use argmin::solver::newton::Newton;
+use nalgebra::{Matrix2, Vector2};
+
+/// Rosenbrock function `f(x, y) = (a - x)^2 + b (y - x^2)^2`
+///
+/// Parameter vector, gradient and Hessian are statically sized and live on the stack.
+struct Rosenbrock {
+    a: f64,
+    b: f64,
+}
+
+impl Gradient for Rosenbrock {
+    type Param = Vector2<f64>;
+    type Gradient = Vector2<f64>;
+
+    fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+        let (x, y) = (p[0], p[1]);
+        Ok(Vector2::new(
+            -2.0 * (self.a - x) - 4.0 * self.b * x * (y - x.powi(2)),
+            2.0 * self.b * (y - x.powi(2)),
+        ))
+    }
+}
+
+impl Hessian for Rosenbrock {
+    type Param = Vector2<f64>;
+    type Hessian = Matrix2<f64>;
+
+    fn hessian(&self, p: &Self::Param) -> Result<Self::Hessian, Error> {
+        let (x, y) = (p[0], p[1]);
+        let offdiag = -4.0 * self.b * x;
+        Ok(Matrix2::new(
+            2.0 - 4.0 * self.b * (y - 3.0 * x.powi(2)),
+            offdiag,
+            offdiag,
+            2.0 * self.b,
+        ))
+    }
+}
+
+fn run() -> Result<(), Error> {
+    // Define cost function
+    let cost = Rosenbrock { a: 1.0, b: 100.0 };
+
+    // Define initial parameter vector
+    let init_param = Vector2::new(-1.2, 1.0);
+
+    // Set up solver
+    let solver: Newton<f64> = Newton::new();
+
+    // Run solver
+    let res = Executor::new(cost, solver)
+        .configure(|state| state.param(init_param).max_iters(8))
+        .add_observer(SlogLogger::term(), ObserverMode::Always)
+        .run()?;
+
+    // Wait a second (lets the logger flush everything before printing again)
+    std::thread::sleep(std::time::Duration::from_secs(1));
+
+    // Print result
+    println!("{res}");
+    Ok(())
+}
+
+fn main() {
+    if let Err(ref e) = run() {
+        println!("{e}");
+        std::process::exit(1);
+    }
+}
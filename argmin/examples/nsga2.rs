@@ -0,0 +1,50 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use argmin::core::{Error, Executor, MultiCostFunction};
+use argmin::solver::nsga2::NSGA2;
+
+/// ZDT1 test problem with a convex Pareto front `f2 = 1 - sqrt(f1)`
+struct Zdt1 {}
+
+impl MultiCostFunction for Zdt1 {
+    type Param = Vec<f64>;
+    type Float = f64;
+
+    fn multi_cost(&self, param: &Self::Param) -> Result<Vec<Self::Float>, Error> {
+        let n = param.len() as f64;
+        let g = 1.0 + 9.0 * param[1..].iter().sum::<f64>() / (n - 1.0);
+        let f1 = param[0];
+        let f2 = g * (1.0 - (f1 / g).sqrt());
+        Ok(vec![f1, f2])
+    }
+}
+
+fn run() -> Result<(), Error> {
+    let problem = Zdt1 {};
+
+    let solver = NSGA2::new((vec![0.0; 10], vec![1.0; 10]), 50)?;
+
+    let res = Executor::new(problem, solver)
+        .configure(|state| state.max_iters(250))
+        .run()?;
+
+    // Print Pareto front
+    let front_costs = res.state.get_pareto_front_costs().unwrap();
+    println!("Pareto front ({} points):", front_costs.len());
+    for costs in front_costs {
+        println!("f1 = {:.4}, f2 = {:.4}", costs[0], costs[1]);
+    }
+
+    Ok(())
+}
+
+fn main() {
+    if let Err(ref e) = run() {
+        println!("{e}");
+    }
+}
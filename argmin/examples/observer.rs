@@ -6,7 +6,9 @@
 // copied, modified, or distributed except according to those terms.
 
 use argmin::core::observers::{Observe, ObserverMode};
-use argmin::core::{ArgminFloat, CostFunction, Error, Executor, PopulationState, State, KV};
+use argmin::core::{
+    ArgminFloat, Bounds, CostFunction, Error, Executor, PopulationState, State, KV,
+};
 use argmin::solver::particleswarm::{Particle, ParticleSwarm};
 use argmin_testfunctions::himmelblau;
 use gnuplot::{Color, PointSize};
@@ -233,7 +235,7 @@ fn run() -> Result<(), Error> {
         .surface(Surface::new(Himmelblau {}, (-4.0, -4.0, 4.0, 4.0), 0.1));
 
     {
-        let solver = ParticleSwarm::new((vec![-4.0, -4.0], vec![4.0, 4.0]), 40);
+        let solver = ParticleSwarm::new(Bounds::new(vec![-4.0, -4.0], vec![4.0, 4.0])?, 40);
 
         let executor = Executor::new(cost_function, solver).configure(|state| state.max_iters(15));
 
@@ -5,7 +5,7 @@
 // http://opensource.org/licenses/MIT>, at your option. This file may not be
 // copied, modified, or distributed except according to those terms.
 
-use argmin::core::{CostFunction, Error, Executor};
+use argmin::core::{Bounds, CostFunction, Error, Executor};
 use argmin::solver::particleswarm::ParticleSwarm;
 use argmin_testfunctions::himmelblau;
 use nalgebra::{dvector, DVector};
@@ -24,7 +24,7 @@ impl CostFunction for Himmelblau {
 fn run() -> Result<(), Error> {
     let cost_function = Himmelblau {};
 
-    let solver = ParticleSwarm::new((dvector![-4.0, -4.0], dvector![4.0, 4.0]), 40);
+    let solver = ParticleSwarm::new(Bounds::new(dvector![-4.0, -4.0], dvector![4.0, 4.0])?, 40);
 
     let res = Executor::new(cost_function, solver)
         .configure(|state| state.max_iters(100))
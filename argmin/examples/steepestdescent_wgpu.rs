@@ -0,0 +1,76 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use argmin::core::observers::{ObserverMode, SlogLogger};
+use argmin::core::{CostFunction, Error, Executor, Gradient};
+use argmin::solver::gradientdescent::SteepestDescent;
+use argmin::solver::linesearch::MoreThuenteLineSearch;
+use argmin_math::{ArgminDot, ArgminL2Norm, ArgminMul, ArgminSub, WgpuContext, WgpuVector};
+
+/// Separable quadratic `0.5 * sum_i d_i (x_i - c_i)^2` with all data resident on the GPU
+struct Quadratic {
+    center: WgpuVector,
+    scaling: WgpuVector,
+}
+
+impl CostFunction for Quadratic {
+    type Param = WgpuVector;
+    type Output = f32;
+
+    fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+        let diff: WgpuVector = p.sub(&self.center);
+        let scaled: WgpuVector = diff.mul(&self.scaling);
+        Ok(0.5 * scaled.dot(&diff))
+    }
+}
+
+impl Gradient for Quadratic {
+    type Param = WgpuVector;
+    type Gradient = WgpuVector;
+
+    fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+        let diff: WgpuVector = p.sub(&self.center);
+        Ok(diff.mul(&self.scaling))
+    }
+}
+
+fn run() -> Result<(), Error> {
+    let n = 1_000_000;
+    let ctx = WgpuContext::new()?;
+
+    let center: Vec<f32> = (0..n).map(|i| (i % 7) as f32 - 3.0).collect();
+    let scaling: Vec<f32> = (0..n).map(|i| 1.0 + (i % 3) as f32).collect();
+    let cost = Quadratic {
+        center: WgpuVector::from_slice(&ctx, &center),
+        scaling: WgpuVector::from_slice(&ctx, &scaling),
+    };
+    let init_param = WgpuVector::zeros(&ctx, n);
+
+    let linesearch = MoreThuenteLineSearch::new();
+    let solver = SteepestDescent::new(linesearch);
+
+    let res = Executor::new(cost, solver)
+        .configure(|state| state.param(init_param).max_iters(20).target_cost(1e-6))
+        .add_observer(SlogLogger::term(), ObserverMode::Always)
+        .run()?;
+
+    // Wait a second (lets the logger flush everything first)
+    std::thread::sleep(std::time::Duration::from_secs(1));
+
+    let best = res.state().best_param.as_ref().unwrap();
+    let distance: WgpuVector = best.sub(&WgpuVector::from_slice(&ctx, &center));
+    println!("{res}");
+    println!("Distance to minimum: {}", distance.l2_norm());
+    Ok(())
+}
+
+fn main() {
+    if let Err(ref e) = run() {
+        println!("{e}");
+        std::process::exit(1);
+    }
+}
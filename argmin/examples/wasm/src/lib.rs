@@ -0,0 +1,84 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use argmin::core::observers::{Observe, ObserverMode};
+use argmin::core::{CostFunction, Error, Executor, Gradient, State, KV};
+use argmin::solver::linesearch::MoreThuenteLineSearch;
+use argmin::solver::quasinewton::LBFGS;
+use js_sys::{Float64Array, Function};
+use wasm_bindgen::prelude::*;
+
+struct Rosenbrock {
+    a: f64,
+    b: f64,
+}
+
+impl CostFunction for Rosenbrock {
+    type Param = Vec<f64>;
+    type Output = f64;
+
+    fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+        Ok((self.a - p[0]).powi(2) + self.b * (p[1] - p[0].powi(2)).powi(2))
+    }
+}
+
+impl Gradient for Rosenbrock {
+    type Param = Vec<f64>;
+    type Gradient = Vec<f64>;
+
+    fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+        Ok(vec![
+            -2.0 * (self.a - p[0]) - 4.0 * self.b * p[0] * (p[1] - p[0].powi(2)),
+            2.0 * self.b * (p[1] - p[0].powi(2)),
+        ])
+    }
+}
+
+/// Observer which passes the iteration number, the parameter vector and the cost function value
+/// to a JavaScript function
+struct JsObserver {
+    callback: Function,
+}
+
+impl<I> Observe<I> for JsObserver
+where
+    I: State<Param = Vec<f64>, Float = f64>,
+{
+    fn observe_iter(&mut self, state: &I, _kv: &KV) -> Result<(), Error> {
+        let param = Float64Array::from(state.get_param().map_or(&[][..], |p| &p[..]));
+        self.callback
+            .call3(
+                &JsValue::NULL,
+                &JsValue::from(state.get_iter() as f64),
+                &param,
+                &JsValue::from(state.get_cost()),
+            )
+            .map_err(|e| Error::msg(format!("callback failed: {e:?}")))?;
+        Ok(())
+    }
+}
+
+/// Minimizes the Rosenbrock function with L-BFGS starting from `(x, y)` and returns the best
+/// parameter vector. `callback` is called after every iteration with the iteration number, the
+/// current parameter vector and cost function value.
+#[wasm_bindgen]
+pub fn minimize_rosenbrock(
+    x: f64,
+    y: f64,
+    max_iters: u64,
+    callback: Function,
+) -> Result<Vec<f64>, JsError> {
+    let problem = Rosenbrock { a: 1.0, b: 100.0 };
+    let linesearch = MoreThuenteLineSearch::new();
+    let solver = LBFGS::new(linesearch, 7);
+    let res = Executor::new(problem, solver)
+        .configure(|state| state.param(vec![x, y]).max_iters(max_iters))
+        .add_observer(JsObserver { callback }, ObserverMode::Always)
+        .run()
+        .map_err(|e| JsError::new(&e.to_string()))?;
+    Ok(res.state().get_best_param().cloned().unwrap_or_default())
+}
@@ -0,0 +1,451 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Solver configuration from TOML and YAML files
+//!
+//! A [`Config`] describes a solver (one of the [`Method`]s of [`minimize`](`crate::minimize()`)
+//! and its hyperparameters), the termination criteria, observers and checkpointing. It can be
+//! read from TOML or YAML and turned into an [`Executor`], such that experiments can be changed
+//! without recompiling. Only available with the `config` feature.
+//!
+//! ```toml
+//! [solver]
+//! method = "LBFGS"
+//! lbfgs_memory = 10
+//! tolerance_grad = 1e-8
+//!
+//! [termination]
+//! max_iters = 500
+//! target_cost = 1e-12
+//!
+//! [[observers]]
+//! kind = "terminal"
+//! mode = { Every = 10 }
+//!
+//! [[observers]]
+//! kind = "param_file"
+//! directory = "params"
+//! prefix = "rosenbrock"
+//! serializer = "JSON"
+//!
+//! [checkpointing]
+//! directory = ".checkpoints"
+//! name = "rosenbrock"
+//! frequency = { Every = 20 }
+//! ```
+//!
+//! Observers are of kind `terminal` (logs to the terminal), `log_file` (logs to the file `path`,
+//! optionally truncating it with `truncate = true`) or `param_file` (writes the parameter vectors
+//! to files, see [`WriteToFile`]). Their `mode` defaults to `Always`.
+//!
+//! # Example
+//!
+//! ```
+//! # use argmin::core::{CostFunction, Error, Gradient, State};
+//! # use argmin_testfunctions::{rosenbrock_2d, rosenbrock_2d_derivative};
+//! use argmin::config::Config;
+//!
+//! # struct Rosenbrock {}
+//! # impl CostFunction for Rosenbrock {
+//! #     type Param = Vec<f64>;
+//! #     type Output = f64;
+//! #     fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+//! #         Ok(rosenbrock_2d(p, 1.0, 100.0))
+//! #     }
+//! # }
+//! # impl Gradient for Rosenbrock {
+//! #     type Param = Vec<f64>;
+//! #     type Gradient = Vec<f64>;
+//! #     fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+//! #         Ok(rosenbrock_2d_derivative(p, 1.0, 100.0))
+//! #     }
+//! # }
+//! # fn main() -> Result<(), Error> {
+//! let config: Config<f64> = Config::from_yaml(
+//!     r#"
+//! solver:
+//!   method: ConjugateGradient
+//! termination:
+//!   max_iters: 2000
+//! "#,
+//! )?;
+//!
+//! let res = config.executor(Rosenbrock {}, vec![-1.2, 1.0])?.run()?;
+//! # let best = res.state().get_best_param().unwrap();
+//! # assert!((best[0] - 1.0).abs() < 1e-3 && (best[1] - 1.0).abs() < 1e-3);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::core::checkpointing::{CheckpointingFrequency, FileCheckpoint};
+use crate::core::observers::{ObserverMode, SlogLogger, WriteToFile, WriteToFileSerializer};
+use crate::core::{
+    ArgminFloat, DeserializeOwnedAlias, Error, Executor, IterState, SerializeAlias, Solver, State,
+};
+use crate::minimize::executor;
+use crate::{Method, MethodSolver, Options};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Declarative description of solver, termination criteria, observers and checkpointing
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config<F> {
+    /// Solver and its hyperparameters
+    pub solver: SolverConfig<F>,
+    /// Termination criteria
+    #[serde(default = "TerminationConfig::default")]
+    pub termination: TerminationConfig<F>,
+    /// Observers
+    #[serde(default)]
+    pub observers: Vec<ObserverConfig>,
+    /// Checkpointing
+    pub checkpointing: Option<CheckpointConfig>,
+}
+
+/// Solver and its hyperparameters
+///
+/// Hyperparameters which are not given keep the defaults of [`Options`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SolverConfig<F> {
+    /// Method
+    pub method: Method,
+    /// Number of stored gradient differences of L-BFGS
+    pub lbfgs_memory: Option<usize>,
+    /// Tolerance for the stopping criterion based on the norm of the gradient (L-BFGS only)
+    pub tolerance_grad: Option<F>,
+    /// Tolerance for the stopping criterion based on the change of the cost (L-BFGS only)
+    pub tolerance_cost: Option<F>,
+}
+
+/// Termination criteria
+///
+/// Criteria which are not given keep the defaults of [`Options`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TerminationConfig<F> {
+    /// Maximum number of iterations
+    pub max_iters: Option<u64>,
+    /// Target cost function value
+    pub target_cost: Option<F>,
+}
+
+impl<F> Default for TerminationConfig<F> {
+    fn default() -> Self {
+        TerminationConfig {
+            max_iters: None,
+            target_cost: None,
+        }
+    }
+}
+
+/// Observer and the mode in which it is called
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ObserverConfig {
+    /// Kind of observer
+    #[serde(flatten)]
+    pub kind: ObserverKind,
+    /// When to call the observer
+    #[serde(default)]
+    pub mode: ObserverMode,
+}
+
+/// Kinds of observers available in a [`Config`]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ObserverKind {
+    /// Log to the terminal ([`SlogLogger::term`])
+    Terminal,
+    /// Log to a file ([`SlogLogger::file`])
+    LogFile {
+        /// Path of the log file
+        path: String,
+        /// Whether to clear an existing log file
+        #[serde(default)]
+        truncate: bool,
+    },
+    /// Write parameter vectors to files ([`WriteToFile`])
+    ParamFile {
+        /// Directory of the files
+        directory: String,
+        /// Prefix of the file names
+        prefix: String,
+        /// Serializer
+        serializer: WriteToFileSerializer,
+    },
+}
+
+/// Checkpointing to a file (see [`FileCheckpoint`])
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CheckpointConfig {
+    /// Directory of the checkpoint
+    pub directory: String,
+    /// Name of the checkpoint file (without extension)
+    pub name: String,
+    /// How often to write a checkpoint
+    #[serde(default)]
+    pub frequency: CheckpointingFrequency,
+}
+
+impl<F> Config<F>
+where
+    F: ArgminFloat,
+{
+    /// Parses a configuration from TOML
+    pub fn from_toml(s: &str) -> Result<Self, Error>
+    where
+        F: DeserializeOwnedAlias,
+    {
+        Ok(toml::from_str(s)?)
+    }
+
+    /// Parses a configuration from YAML
+    pub fn from_yaml(s: &str) -> Result<Self, Error>
+    where
+        F: DeserializeOwnedAlias,
+    {
+        Ok(serde_yaml::from_str(s)?)
+    }
+
+    /// Reads a configuration from a file, whose format is determined by its extension (`.toml`,
+    /// `.yaml` or `.yml`)
+    pub fn from_file<N: AsRef<Path>>(path: N) -> Result<Self, Error>
+    where
+        F: DeserializeOwnedAlias,
+    {
+        let path = path.as_ref();
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Self::from_toml(&std::fs::read_to_string(path)?),
+            Some("yaml" | "yml") => Self::from_yaml(&std::fs::read_to_string(path)?),
+            _ => Err(argmin_error!(
+                InvalidParameter,
+                format!(
+                    "`Config`: unknown format of {}; expected `.toml`, `.yaml` or `.yml`.",
+                    path.display()
+                )
+            )),
+        }
+    }
+
+    /// Returns the [`Options`] described by solver and termination criteria
+    pub fn options(&self) -> Result<Options<F>, Error> {
+        let mut options = Options::default();
+        if let Some(memory) = self.solver.lbfgs_memory {
+            options = options.with_lbfgs_memory(memory)?;
+        }
+        if let Some(tol_grad) = self.solver.tolerance_grad {
+            options = options.with_tolerance_grad(tol_grad)?;
+        }
+        if let Some(tol_cost) = self.solver.tolerance_cost {
+            options = options.with_tolerance_cost(tol_cost)?;
+        }
+        if let Some(max_iters) = self.termination.max_iters {
+            options = options.with_max_iters(max_iters);
+        }
+        if let Some(target_cost) = self.termination.target_cost {
+            options = options.with_target_cost(target_cost);
+        }
+        Ok(options)
+    }
+
+    /// Creates an [`Executor`] for `problem`, starting from `init_param`, with solver, observers
+    /// and checkpointing as described by the configuration
+    #[allow(clippy::type_complexity)]
+    pub fn executor<O, P, G>(
+        &self,
+        problem: O,
+        init_param: P,
+    ) -> Result<Executor<O, MethodSolver<P, G, F>, IterState<P, G, (), (), F>>, Error>
+    where
+        P: Serialize,
+        IterState<P, G, (), (), F>:
+            State<Param = P, Float = F> + SerializeAlias + DeserializeOwnedAlias + 'static,
+        MethodSolver<P, G, F>:
+            Solver<O, IterState<P, G, (), (), F>> + SerializeAlias + DeserializeOwnedAlias,
+    {
+        let mut executor = executor(problem, init_param, self.solver.method, &self.options()?)?;
+        for observer in self.observers.iter() {
+            executor = match &observer.kind {
+                ObserverKind::Terminal => executor.add_observer(SlogLogger::term(), observer.mode),
+                ObserverKind::LogFile { path, truncate } => {
+                    executor.add_observer(SlogLogger::file(path, *truncate)?, observer.mode)
+                }
+                ObserverKind::ParamFile {
+                    directory,
+                    prefix,
+                    serializer,
+                } => executor.add_observer(
+                    WriteToFile::new(directory, prefix, *serializer),
+                    observer.mode,
+                ),
+            };
+        }
+        if let Some(checkpoint) = self.checkpointing.as_ref() {
+            executor = executor.checkpointing(FileCheckpoint::new(
+                &checkpoint.directory,
+                &checkpoint.name,
+                checkpoint.frequency,
+            ));
+        }
+        Ok(executor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, CostFunction, Gradient};
+    use argmin_testfunctions::{rosenbrock_2d, rosenbrock_2d_derivative};
+
+    struct Rosenbrock {}
+
+    impl CostFunction for Rosenbrock {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(rosenbrock_2d(p, 1.0, 100.0))
+        }
+    }
+
+    impl Gradient for Rosenbrock {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(rosenbrock_2d_derivative(p, 1.0, 100.0))
+        }
+    }
+
+    const TOML: &str = r#"
+[solver]
+method = "LBFGS"
+lbfgs_memory = 10
+tolerance_grad = 1e-8
+
+[termination]
+max_iters = 500
+
+[[observers]]
+kind = "terminal"
+mode = { Every = 10 }
+
+[[observers]]
+kind = "param_file"
+directory = "params"
+prefix = "rosenbrock"
+serializer = "JSON"
+
+[checkpointing]
+directory = ".checkpoints"
+name = "rosenbrock"
+frequency = { Every = 20 }
+"#;
+
+    const YAML: &str = r#"
+solver:
+  method: LBFGS
+  lbfgs_memory: 10
+  tolerance_grad: 1.0e-8
+termination:
+  max_iters: 500
+observers:
+  - kind: terminal
+    mode: !Every 10
+  - kind: param_file
+    directory: params
+    prefix: rosenbrock
+    serializer: JSON
+checkpointing:
+  directory: .checkpoints
+  name: rosenbrock
+  frequency: !Every 20
+"#;
+
+    #[test]
+    fn test_parse() {
+        let expected = Config {
+            solver: SolverConfig {
+                method: Method::LBFGS,
+                lbfgs_memory: Some(10),
+                tolerance_grad: Some(1e-8),
+                tolerance_cost: None,
+            },
+            termination: TerminationConfig {
+                max_iters: Some(500),
+                target_cost: None,
+            },
+            observers: vec![
+                ObserverConfig {
+                    kind: ObserverKind::Terminal,
+                    mode: ObserverMode::Every(10),
+                },
+                ObserverConfig {
+                    kind: ObserverKind::ParamFile {
+                        directory: "params".to_string(),
+                        prefix: "rosenbrock".to_string(),
+                        serializer: WriteToFileSerializer::JSON,
+                    },
+                    mode: ObserverMode::Always,
+                },
+            ],
+            checkpointing: Some(CheckpointConfig {
+                directory: ".checkpoints".to_string(),
+                name: "rosenbrock".to_string(),
+                frequency: CheckpointingFrequency::Every(20),
+            }),
+        };
+        assert_eq!(Config::<f64>::from_toml(TOML).unwrap(), expected);
+        assert_eq!(Config::<f64>::from_yaml(YAML).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_defaults() {
+        let config: Config<f64> =
+            Config::from_toml("[solver]\nmethod = \"SteepestDescent\"").unwrap();
+        assert_eq!(config.solver.method, Method::SteepestDescent);
+        assert_eq!(config.termination, TerminationConfig::default());
+        assert!(config.observers.is_empty());
+        assert!(config.checkpointing.is_none());
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(Config::<f64>::from_toml("[solver]\nmethod = \"Foo\"").is_err());
+        assert!(Config::<f64>::from_toml("[solver]\nmethod = \"LBFGS\"\nfoo = 1").is_err());
+        assert!(Config::<f64>::from_yaml("termination:\n  max_iters: 10").is_err());
+
+        let config: Config<f64> =
+            Config::from_toml("[solver]\nmethod = \"LBFGS\"\nlbfgs_memory = 0").unwrap();
+        assert_error!(
+            config.options(),
+            ArgminError,
+            "Invalid parameter: \"`Options`: L-BFGS memory must be > 0.\""
+        );
+        assert_error!(
+            Config::<f64>::from_file("config.json"),
+            ArgminError,
+            "Invalid parameter: \"`Config`: unknown format of config.json; expected `.toml`, \
+             `.yaml` or `.yml`.\""
+        );
+    }
+
+    #[test]
+    fn test_executor() {
+        let config: Config<f64> =
+            Config::from_toml("[solver]\nmethod = \"LBFGS\"\n[termination]\nmax_iters = 5")
+                .unwrap();
+        let res = config
+            .executor(Rosenbrock {}, vec![-1.2, 1.0])
+            .unwrap()
+            .run()
+            .unwrap();
+        assert_eq!(res.state().get_iter(), 5);
+    }
+}
@@ -0,0 +1,141 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::Error;
+use argmin_math::ArgminBound;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Element-wise lower and upper bounds on the parameter vector
+///
+/// Describes the box `{x : lower <= x <= upper}`. Infinite values are allowed and indicate that a
+/// variable is unbounded in that direction. The bounds are validated on construction, which
+/// guarantees that `lower` and `upper` have the same shape and that `lower <= upper` holds for
+/// every element.
+///
+/// Bounds can be provided by an optimization problem via the
+/// [`BoxConstraint`](`crate::core::BoxConstraint`) trait and are accepted by solvers which support
+/// them, for instance [`ParticleSwarm`](`crate::solver::particleswarm::ParticleSwarm`),
+/// [`RandomSearch`](`crate::solver::randomsearch::RandomSearch`) and
+/// [`BoxProjection`](`crate::solver::projectedgradient::BoxProjection`).
+///
+/// # Example
+///
+/// ```
+/// # use argmin::core::{Bounds, Error};
+/// # fn main() -> Result<(), Error> {
+/// let bounds = Bounds::new(vec![0.0f64, f64::NEG_INFINITY], vec![1.0, 2.0])?;
+///
+/// assert!(bounds.contains(&vec![0.5, -100.0]));
+/// assert!(!bounds.contains(&vec![1.5, 0.0]));
+/// assert_eq!(bounds.clamp(&vec![1.5, 3.0]), vec![1.0, 2.0]);
+///
+/// // Lower bound exceeds upper bound
+/// assert!(Bounds::new(vec![1.0f64], vec![0.0]).is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct Bounds<P> {
+    /// Lower bound
+    lower: P,
+    /// Upper bound
+    upper: P,
+}
+
+impl<P> Bounds<P>
+where
+    P: ArgminBound,
+{
+    /// Construct a new instance of `Bounds` from lower and upper bounds
+    ///
+    /// Returns an error if `lower` and `upper` differ in shape or if `lower <= upper` does not
+    /// hold for every element (this includes NaN entries).
+    pub fn new(lower: P, upper: P) -> Result<Self, Error> {
+        if !P::valid_bounds(&lower, &upper) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                concat!(
+                    "`Bounds`: lower and upper bound must have the same shape and ",
+                    "lower <= upper must hold element-wise."
+                )
+            ));
+        }
+        Ok(Bounds { lower, upper })
+    }
+
+    /// Returns `true` if every element of `param` lies within the bounds
+    pub fn contains(&self, param: &P) -> bool {
+        param.within_bounds(&self.lower, &self.upper)
+    }
+
+    /// Returns `param` with every element clamped to the bounds
+    pub fn clamp(&self, param: &P) -> P {
+        param.clamp_to_bounds(&self.lower, &self.upper)
+    }
+}
+
+impl<P> Bounds<P> {
+    /// Returns a reference to the lower bound
+    pub fn lower(&self) -> &P {
+        &self.lower
+    }
+
+    /// Returns a reference to the upper bound
+    pub fn upper(&self) -> &P {
+        &self.upper
+    }
+
+    /// Returns lower and upper bound as a tuple `(lower, upper)`
+    pub fn into_inner(self) -> (P, P) {
+        (self.lower, self.upper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_error;
+    use crate::core::ArgminError;
+
+    #[test]
+    fn test_new() {
+        let bounds = Bounds::new(vec![-1.0f64, 0.0], vec![1.0, 0.0]).unwrap();
+        assert_eq!(bounds.lower(), &vec![-1.0, 0.0]);
+        assert_eq!(bounds.upper(), &vec![1.0, 0.0]);
+        assert_eq!(bounds.into_inner(), (vec![-1.0, 0.0], vec![1.0, 0.0]));
+    }
+
+    #[test]
+    fn test_new_invalid() {
+        for (lower, upper) in [
+            (vec![1.0f64, 0.0], vec![0.0, 1.0]),
+            (vec![0.0, 0.0], vec![1.0]),
+            (vec![f64::NAN], vec![1.0]),
+        ] {
+            assert_error!(
+                Bounds::new(lower, upper),
+                ArgminError,
+                concat!(
+                    "Invalid parameter: \"`Bounds`: lower and upper bound must have the same ",
+                    "shape and lower <= upper must hold element-wise.\""
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_contains_and_clamp() {
+        let bounds = Bounds::new(vec![-1.0f64, f64::NEG_INFINITY], vec![1.0, 0.0]).unwrap();
+        assert!(bounds.contains(&vec![-1.0, -1e10]));
+        assert!(!bounds.contains(&vec![0.0, 0.5]));
+        assert!(!bounds.contains(&vec![0.0]));
+        assert_eq!(bounds.clamp(&vec![-2.0, 0.5]), vec![-1.0, 0.0]);
+        assert_eq!(bounds.clamp(&vec![0.5, -3.0]), vec![0.5, -3.0]);
+    }
+}
@@ -106,8 +106,10 @@ mod file;
 pub use crate::core::checkpointing::file::FileCheckpoint;
 
 use crate::core::Error;
-use std::default::Default;
-use std::fmt::Display;
+use core::default::Default;
+use core::fmt::Display;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
 
 /// An interface for checkpointing methods
 ///
@@ -208,6 +210,7 @@ pub trait Checkpoint<S, I> {
 /// assert_eq!(CheckpointingFrequency::default(), CheckpointingFrequency::Always);
 /// ```
 #[derive(Clone, Eq, PartialEq, Debug, Hash, Copy, Default)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
 pub enum CheckpointingFrequency {
     /// Never create checkpoint
     Never,
@@ -219,7 +222,7 @@ pub enum CheckpointingFrequency {
 }
 
 impl Display for CheckpointingFrequency {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         match *self {
             CheckpointingFrequency::Never => write!(f, "Never"),
             CheckpointingFrequency::Every(i) => write!(f, "Every({i})"),
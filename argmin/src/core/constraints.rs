@@ -0,0 +1,485 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{ArgminFloat, EqualityConstraint, Error, InequalityConstraint};
+use alloc::{format, vec, vec::Vec};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Linear equality and inequality constraints
+///
+/// Describes the feasible set
+///
+/// `{x : A x = b, l <= C x <= u}`
+///
+/// of a problem with `n` parameters. Matrices are given as vectors of rows. Entries of `l` and `u`
+/// may be infinite, which indicates that the corresponding row is only bounded from one side.
+/// Dimensions are validated when the constraints are added.
+///
+/// Besides computing residuals and violations, which allows checking the feasibility of any
+/// parameter vector (for instance the result of an optimization run), the constraints can be
+/// converted into the form `G x <= h` via [`to_inequality_form`](`Self::to_inequality_form`),
+/// which is expected by [`QuadraticProgram`](`crate::core::QuadraticProgram`) (together with
+/// [`equality_matrix`](`Self::equality_matrix`) and [`equality_rhs`](`Self::equality_rhs`)).
+///
+/// # Example
+///
+/// ```
+/// # use argmin::core::{Error, LinearConstraints};
+/// # fn main() -> Result<(), Error> {
+/// // x + y = 1 and 0 <= x - y <= inf
+/// let constraints = LinearConstraints::new(2)
+///     .with_equality(vec![vec![1.0f64, 1.0]], vec![1.0])?
+///     .with_inequality(vec![vec![1.0, -1.0]], vec![0.0], vec![f64::INFINITY])?;
+///
+/// assert_eq!(constraints.equality_residuals(&[1.0, 1.0])?, vec![1.0]);
+/// assert_eq!(constraints.inequality_violations(&[0.0, 1.0])?, vec![1.0]);
+/// assert!(constraints.is_feasible(&[0.75, 0.25], 1e-12)?);
+///
+/// // -x + y <= 0
+/// let (g, h) = constraints.to_inequality_form();
+/// assert_eq!(g, vec![vec![-1.0, 1.0]]);
+/// assert_eq!(h, vec![0.0]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct LinearConstraints<F> {
+    /// Number of parameters
+    num_params: usize,
+    /// Matrix `A` of the equality constraints
+    a_eq: Vec<Vec<F>>,
+    /// Right-hand side `b` of the equality constraints
+    b_eq: Vec<F>,
+    /// Matrix `C` of the inequality constraints
+    c_ineq: Vec<Vec<F>>,
+    /// Lower bounds `l` on `C x`
+    lower: Vec<F>,
+    /// Upper bounds `u` on `C x`
+    upper: Vec<F>,
+}
+
+impl<F> LinearConstraints<F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of `LinearConstraints` for a problem with `num_params` parameters
+    /// and without any constraints
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::LinearConstraints;
+    /// let constraints: LinearConstraints<f64> = LinearConstraints::new(3);
+    /// # assert_eq!(constraints.num_equality(), 0);
+    /// ```
+    pub fn new(num_params: usize) -> Self {
+        LinearConstraints {
+            num_params,
+            a_eq: vec![],
+            b_eq: vec![],
+            c_ineq: vec![],
+            lower: vec![],
+            upper: vec![],
+        }
+    }
+
+    /// Add equality constraints `A x = b`
+    ///
+    /// Every row of `A` must have `num_params` finite entries and `b` must have one finite entry
+    /// per row of `A`. Can be called multiple times, the constraints are appended.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Error, LinearConstraints};
+    /// # fn main() -> Result<(), Error> {
+    /// let constraints = LinearConstraints::new(2).with_equality(vec![vec![1.0f64, 1.0]], vec![1.0])?;
+    /// # assert_eq!(constraints.num_equality(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_equality(mut self, a: Vec<Vec<F>>, b: Vec<F>) -> Result<Self, Error> {
+        self.check_matrix(&a, "with_equality")?;
+        if b.len() != a.len() || b.iter().any(|v| !v.is_finite()) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`LinearConstraints`: `b` must contain one finite entry per row of `A`."
+            ));
+        }
+        self.a_eq.extend(a);
+        self.b_eq.extend(b);
+        Ok(self)
+    }
+
+    /// Add inequality constraints `l <= C x <= u`
+    ///
+    /// Every row of `C` must have `num_params` finite entries. `lower` and `upper` must have one
+    /// entry per row of `C` and `lower <= upper` must hold element-wise. Infinite entries are
+    /// allowed. Can be called multiple times, the constraints are appended.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Error, LinearConstraints};
+    /// # fn main() -> Result<(), Error> {
+    /// // x - y <= 2
+    /// let constraints = LinearConstraints::new(2).with_inequality(
+    ///     vec![vec![1.0f64, -1.0]],
+    ///     vec![f64::NEG_INFINITY],
+    ///     vec![2.0],
+    /// )?;
+    /// # assert_eq!(constraints.num_inequality(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_inequality(
+        mut self,
+        c: Vec<Vec<F>>,
+        lower: Vec<F>,
+        upper: Vec<F>,
+    ) -> Result<Self, Error> {
+        self.check_matrix(&c, "with_inequality")?;
+        if lower.len() != c.len()
+            || upper.len() != c.len()
+            || !lower.iter().zip(upper.iter()).all(|(l, u)| l <= u)
+        {
+            return Err(argmin_error!(
+                InvalidParameter,
+                concat!(
+                    "`LinearConstraints`: `lower` and `upper` must contain one entry per row of ",
+                    "`C` and `lower <= upper` must hold element-wise."
+                )
+            ));
+        }
+        self.c_ineq.extend(c);
+        self.lower.extend(lower);
+        self.upper.extend(upper);
+        Ok(self)
+    }
+
+    /// Checks that all rows of `matrix` have `num_params` finite entries
+    fn check_matrix(&self, matrix: &[Vec<F>], method: &str) -> Result<(), Error> {
+        if matrix
+            .iter()
+            .any(|row| row.len() != self.num_params || row.iter().any(|v| !v.is_finite()))
+        {
+            return Err(argmin_error!(
+                InvalidParameter,
+                format!(
+                    "`LinearConstraints`: All rows passed to `{method}` must have {} finite entries.",
+                    self.num_params
+                )
+            ));
+        }
+        Ok(())
+    }
+
+    /// Checks that `param` has `num_params` entries
+    fn check_param(&self, param: &[F]) -> Result<(), Error> {
+        if param.len() != self.num_params {
+            return Err(argmin_error!(
+                InvalidParameter,
+                format!(
+                    "`LinearConstraints`: Parameter vector must have {} entries.",
+                    self.num_params
+                )
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the number of parameters
+    pub fn num_params(&self) -> usize {
+        self.num_params
+    }
+
+    /// Returns the number of equality constraints
+    pub fn num_equality(&self) -> usize {
+        self.a_eq.len()
+    }
+
+    /// Returns the number of inequality constraints
+    pub fn num_inequality(&self) -> usize {
+        self.c_ineq.len()
+    }
+
+    /// Returns the matrix `A` of the equality constraints
+    pub fn equality_matrix(&self) -> &Vec<Vec<F>> {
+        &self.a_eq
+    }
+
+    /// Returns the right-hand side `b` of the equality constraints
+    pub fn equality_rhs(&self) -> &Vec<F> {
+        &self.b_eq
+    }
+
+    /// Returns the matrix `C` of the inequality constraints
+    pub fn inequality_matrix(&self) -> &Vec<Vec<F>> {
+        &self.c_ineq
+    }
+
+    /// Returns the lower and upper bounds `(l, u)` of the inequality constraints
+    pub fn inequality_bounds(&self) -> (&Vec<F>, &Vec<F>) {
+        (&self.lower, &self.upper)
+    }
+
+    /// Returns the residuals `A x - b` of the equality constraints
+    pub fn equality_residuals(&self, param: &[F]) -> Result<Vec<F>, Error> {
+        self.check_param(param)?;
+        Ok(self
+            .a_eq
+            .iter()
+            .zip(self.b_eq.iter())
+            .map(|(row, &b)| dot(row, param) - b)
+            .collect())
+    }
+
+    /// Returns the violations `max(0, l - C x, C x - u)` of the inequality constraints
+    pub fn inequality_violations(&self, param: &[F]) -> Result<Vec<F>, Error> {
+        self.check_param(param)?;
+        Ok(self
+            .c_ineq
+            .iter()
+            .zip(self.lower.iter().zip(self.upper.iter()))
+            .map(|(row, (&l, &u))| {
+                let cx = dot(row, param);
+                (l - cx).max(cx - u).max(float!(0.0))
+            })
+            .collect())
+    }
+
+    /// Returns the largest constraint violation of `param`
+    ///
+    /// This is the maximum of the absolute equality residuals and the inequality violations and
+    /// `0` if there are no constraints.
+    pub fn max_violation(&self, param: &[F]) -> Result<F, Error> {
+        Ok(self
+            .equality_residuals(param)?
+            .into_iter()
+            .map(|r| r.abs())
+            .chain(self.inequality_violations(param)?)
+            .fold(float!(0.0), |acc, v| acc.max(v)))
+    }
+
+    /// Returns `true` if no constraint is violated by more than `tol`
+    pub fn is_feasible(&self, param: &[F], tol: F) -> Result<bool, Error> {
+        Ok(self.max_violation(param)? <= tol)
+    }
+
+    /// Returns the inequality constraints in the form `G x <= h`
+    ///
+    /// Each row of `C` with a finite upper bound yields the row `c^T x <= u` and each row with a
+    /// finite lower bound yields the row `-c^T x <= -l`. Rows with infinite bounds are omitted.
+    pub fn to_inequality_form(&self) -> (Vec<Vec<F>>, Vec<F>) {
+        let mut g = vec![];
+        let mut h = vec![];
+        for (row, (&l, &u)) in self
+            .c_ineq
+            .iter()
+            .zip(self.lower.iter().zip(self.upper.iter()))
+        {
+            if u.is_finite() {
+                g.push(row.clone());
+                h.push(u);
+            }
+            if l.is_finite() {
+                g.push(row.iter().map(|&v| -v).collect());
+                h.push(-l);
+            }
+        }
+        (g, h)
+    }
+}
+
+/// The equality constraints `A x - b = 0`
+impl<F: ArgminFloat> EqualityConstraint for LinearConstraints<F> {
+    type Param = Vec<F>;
+    type Gradient = Vec<F>;
+    type Float = F;
+
+    fn equality_constraints(&self, param: &Self::Param) -> Result<Vec<F>, Error> {
+        self.equality_residuals(param)
+    }
+
+    fn equality_jacobian(&self, param: &Self::Param) -> Result<Vec<Vec<F>>, Error> {
+        self.check_param(param)?;
+        Ok(self.a_eq.clone())
+    }
+}
+
+/// The inequality constraints in the form `G x - h <= 0` (see
+/// [`to_inequality_form`](`LinearConstraints::to_inequality_form`))
+impl<F: ArgminFloat> InequalityConstraint for LinearConstraints<F> {
+    type Param = Vec<F>;
+    type Gradient = Vec<F>;
+    type Float = F;
+
+    fn inequality_constraints(&self, param: &Self::Param) -> Result<Vec<F>, Error> {
+        self.check_param(param)?;
+        let (g, h) = self.to_inequality_form();
+        Ok(g.iter()
+            .zip(h.iter())
+            .map(|(row, &h)| dot(row, param) - h)
+            .collect())
+    }
+
+    fn inequality_jacobian(&self, param: &Self::Param) -> Result<Vec<Vec<F>>, Error> {
+        self.check_param(param)?;
+        Ok(self.to_inequality_form().0)
+    }
+}
+
+/// Dot product of a row of a constraint matrix and the parameter vector
+fn dot<F: ArgminFloat>(row: &[F], param: &[F]) -> F {
+    row.iter()
+        .zip(param.iter())
+        .fold(float!(0.0), |acc, (&a, &x)| acc + a * x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_error;
+    use crate::core::ArgminError;
+
+    fn constraints() -> LinearConstraints<f64> {
+        // x + y + z = 1, 0 <= x - y <= 1, z <= 0.5
+        LinearConstraints::new(3)
+            .with_equality(vec![vec![1.0, 1.0, 1.0]], vec![1.0])
+            .unwrap()
+            .with_inequality(
+                vec![vec![1.0, -1.0, 0.0], vec![0.0, 0.0, 1.0]],
+                vec![0.0, f64::NEG_INFINITY],
+                vec![1.0, 0.5],
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        let LinearConstraints {
+            num_params,
+            a_eq,
+            b_eq,
+            c_ineq,
+            lower,
+            upper,
+        } = LinearConstraints::<f64>::new(3);
+        assert_eq!(num_params, 3);
+        assert!(a_eq.is_empty());
+        assert!(b_eq.is_empty());
+        assert!(c_ineq.is_empty());
+        assert!(lower.is_empty());
+        assert!(upper.is_empty());
+    }
+
+    #[test]
+    fn test_accessors() {
+        let c = constraints();
+        assert_eq!(c.num_params(), 3);
+        assert_eq!(c.num_equality(), 1);
+        assert_eq!(c.num_inequality(), 2);
+        assert_eq!(c.equality_matrix(), &vec![vec![1.0, 1.0, 1.0]]);
+        assert_eq!(c.equality_rhs(), &vec![1.0]);
+        assert_eq!(c.inequality_matrix().len(), 2);
+        assert_eq!(
+            c.inequality_bounds(),
+            (&vec![0.0, f64::NEG_INFINITY], &vec![1.0, 0.5])
+        );
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_error!(
+            LinearConstraints::new(2).with_equality(vec![vec![1.0f64]], vec![1.0]),
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`LinearConstraints`: All rows passed to `with_equality` ",
+                "must have 2 finite entries.\""
+            )
+        );
+        assert_error!(
+            LinearConstraints::new(1).with_equality(vec![vec![1.0f64]], vec![]),
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`LinearConstraints`: `b` must contain one finite entry per ",
+                "row of `A`.\""
+            )
+        );
+        assert_error!(
+            LinearConstraints::new(1).with_inequality(vec![vec![f64::NAN]], vec![0.0], vec![1.0]),
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`LinearConstraints`: All rows passed to `with_inequality` ",
+                "must have 1 finite entries.\""
+            )
+        );
+        for (lower, upper) in [(vec![1.0f64], vec![0.0]), (vec![f64::NAN], vec![0.0])] {
+            assert_error!(
+                LinearConstraints::new(1).with_inequality(vec![vec![1.0f64]], lower, upper),
+                ArgminError,
+                concat!(
+                    "Invalid parameter: \"`LinearConstraints`: `lower` and `upper` must contain ",
+                    "one entry per row of `C` and `lower <= upper` must hold element-wise.\""
+                )
+            );
+        }
+        assert_error!(
+            constraints().max_violation(&[1.0, 2.0]),
+            ArgminError,
+            "Invalid parameter: \"`LinearConstraints`: Parameter vector must have 3 entries.\""
+        );
+    }
+
+    #[test]
+    fn test_residuals() {
+        let c = constraints();
+        let param = vec![0.25, 0.5, 1.0];
+        assert_eq!(c.equality_residuals(&param).unwrap(), vec![0.75]);
+        assert_eq!(c.inequality_violations(&param).unwrap(), vec![0.25, 0.5]);
+        assert_eq!(
+            c.max_violation(&param).unwrap().to_ne_bytes(),
+            0.75f64.to_ne_bytes()
+        );
+        assert!(!c.is_feasible(&param, 0.5).unwrap());
+        assert!(c.is_feasible(&[0.5, 0.25, 0.25], 1e-12).unwrap());
+    }
+
+    #[test]
+    fn test_to_inequality_form() {
+        let (g, h) = constraints().to_inequality_form();
+        assert_eq!(
+            g,
+            vec![
+                vec![1.0, -1.0, 0.0],
+                vec![-1.0, 1.0, -0.0],
+                vec![0.0, 0.0, 1.0]
+            ]
+        );
+        assert_eq!(h, vec![1.0, -0.0, 0.5]);
+    }
+
+    #[test]
+    fn test_constraint_traits() {
+        let c = constraints();
+        let p = vec![0.5, 0.25, 0.25];
+        assert_eq!(c.equality_constraints(&p).unwrap(), vec![0.0]);
+        assert_eq!(c.equality_jacobian(&p).unwrap(), vec![vec![1.0, 1.0, 1.0]]);
+        assert_eq!(
+            c.inequality_constraints(&p).unwrap(),
+            vec![-0.75, -0.25, -0.25]
+        );
+        assert_eq!(c.inequality_jacobian(&p).unwrap(), c.to_inequality_form().0);
+        assert_error!(
+            c.inequality_jacobian(&vec![1.0]),
+            ArgminError,
+            "Invalid parameter: \"`LinearConstraints`: Parameter vector must have 3 entries.\""
+        );
+    }
+}
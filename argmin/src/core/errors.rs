@@ -7,6 +7,7 @@
 
 //! # Errors
 
+use alloc::string::String;
 use thiserror::Error;
 
 /// Argmin error type
@@ -8,12 +8,45 @@
 use crate::core::checkpointing::Checkpoint;
 use crate::core::observers::{Observe, ObserverMode, Observers};
 use crate::core::{
-    DeserializeOwnedAlias, Error, OptimizationResult, Problem, SerializeAlias, Solver, State,
-    TerminationReason, TerminationStatus, KV,
+    ArgminFloat, BoxConstraint, CostFunction, DeserializeOwnedAlias, EqualityConstraint, Error,
+    FeasibilityReport, Gradient, InequalityConstraint, OptimizationResult, Problem, SerializeAlias,
+    Solver, State, TerminationReason, TerminationStatus, KV,
 };
-use instant;
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::Arc;
+use crate::derivatives::check_gradient;
+use alloc::{boxed::Box, format, sync::Arc, vec::Vec};
+use argmin_math::{ArgminBound, ArgminL2Norm, ArgminSub};
+use core::sync::atomic::{AtomicBool, Ordering};
+#[cfg(all(
+    feature = "std",
+    any(not(target_arch = "wasm32"), feature = "wasm-bindgen")
+))]
+use instant::Instant;
+
+/// Stand-in for a clock when `std` is not available or on `wasm32` without the `wasm-bindgen`
+/// feature (where `std::time::Instant` panics). Measured durations are always zero.
+#[cfg(not(all(
+    feature = "std",
+    any(not(target_arch = "wasm32"), feature = "wasm-bindgen")
+)))]
+#[derive(Clone, Copy)]
+struct Instant;
+
+#[cfg(not(all(
+    feature = "std",
+    any(not(target_arch = "wasm32"), feature = "wasm-bindgen")
+)))]
+impl Instant {
+    fn now() -> Self {
+        Instant
+    }
+
+    fn elapsed(&self) -> core::time::Duration {
+        core::time::Duration::ZERO
+    }
+}
+
+/// Computes the violation of a group of constraints at the final parameter vector of a state
+type FeasibilityCheck<O, I> = fn(&O, &I) -> Result<f64, Error>;
 
 /// Solves an optimization problem with a solver
 pub struct Executor<O, S, I> {
@@ -31,6 +64,12 @@ pub struct Executor<O, S, I> {
     ctrlc: bool,
     /// Indicates whether to time execution or not
     timer: bool,
+    /// Check of the bounds
+    bounds_check: Option<FeasibilityCheck<O, I>>,
+    /// Check of the equality constraints
+    equality_check: Option<FeasibilityCheck<O, I>>,
+    /// Check of the inequality constraints
+    inequality_check: Option<FeasibilityCheck<O, I>>,
 }
 
 impl<O, S, I> Executor<O, S, I>
@@ -68,7 +107,13 @@ where
             observers: Observers::new(),
             checkpoint: None,
             ctrlc: true,
-            timer: true,
+            timer: cfg!(all(
+                feature = "std",
+                any(not(target_arch = "wasm32"), feature = "wasm-bindgen")
+            )),
+            bounds_check: None,
+            equality_check: None,
+            inequality_check: None,
         }
     }
 
@@ -136,7 +181,7 @@ where
             }
         }
         let total_time = if self.timer {
-            Some(instant::Instant::now())
+            Some(Instant::now())
         } else {
             None
         };
@@ -214,7 +259,7 @@ where
 
             // Start time measurement
             let start = if self.timer {
-                Some(instant::Instant::now())
+                Some(Instant::now())
             } else {
                 None
             };
@@ -268,7 +313,55 @@ where
             state = state.terminate_with(TerminationReason::KeyboardInterrupt);
         }
 
-        Ok(OptimizationResult::new(self.problem, self.solver, state))
+        let feasibility = self.feasibility(&state)?;
+
+        if !self.observers.is_empty() {
+            let kv = if let Some(feasibility) = feasibility.as_ref() {
+                feasibility.kv()
+            } else {
+                KV::new()
+            };
+            self.observers.observe_final(&state, &kv)?;
+        }
+
+        let mut result = OptimizationResult::new(self.problem, self.solver, state);
+        result.feasibility = feasibility;
+        Ok(result)
+    }
+
+    /// Runs the enabled feasibility checks on the final state.
+    ///
+    /// Returns `None` if no check is enabled or if the state does not hold a parameter vector.
+    /// The constraints are evaluated directly on the user defined problem and therefore do not
+    /// contribute to the function evaluation counts.
+    fn feasibility(&self, state: &I) -> Result<Option<FeasibilityReport>, Error> {
+        let checks = [
+            self.bounds_check,
+            self.equality_check,
+            self.inequality_check,
+        ];
+        if checks.iter().all(Option::is_none) || final_param(state).is_none() {
+            return Ok(None);
+        }
+        let problem = self
+            .problem
+            .problem
+            .as_ref()
+            .ok_or_else(argmin_error_closure!(
+                PotentialBug,
+                "`Executor`: Problem not available for feasibility checks"
+            ))?;
+        let mut report = FeasibilityReport::new();
+        if let Some(check) = self.bounds_check {
+            report = report.bounds(check(problem, state)?);
+        }
+        if let Some(check) = self.equality_check {
+            report = report.equality(check(problem, state)?);
+        }
+        if let Some(check) = self.inequality_check {
+            report = report.inequality(check(problem, state)?);
+        }
+        Ok(Some(report))
     }
 
     /// Adds an observer to the executor. Observers are required to implement the
@@ -375,6 +468,10 @@ where
 
     /// Enables or disables timing of individual iterations (default: enabled).
     ///
+    /// Timing requires a clock and is therefore only available with the `std` feature and, on
+    /// `wasm32`, the `wasm-bindgen` feature (which uses `performance.now()` of the browser).
+    /// Otherwise it is disabled by default and all measured durations are zero.
+    ///
     /// # Example
     ///
     /// ```
@@ -395,6 +492,302 @@ where
         self.timer = timer;
         self
     }
+
+    /// Enables checking the final parameter vector against the bounds of the problem (default:
+    /// disabled).
+    ///
+    /// Requires the problem to implement [`BoxConstraint`]. After the run, the Euclidean distance
+    /// of the best parameter vector (or the current one if there is no best) to the box is stored
+    /// in the [`FeasibilityReport`] of the [`OptimizationResult`] and passed to the observers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{BoxConstraint, Bounds, Error, Executor, IterState};
+    /// # use argmin::core::test_utils::TestSolver;
+    /// #
+    /// # struct UnitBox {}
+    /// #
+    /// # impl BoxConstraint for UnitBox {
+    /// #     type Param = Vec<f64>;
+    /// #
+    /// #     fn bounds(&self) -> Result<Bounds<Vec<f64>>, Error> {
+    /// #         Bounds::new(vec![0.0, 0.0], vec![1.0, 1.0])
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// // `UnitBox` implements `BoxConstraint`
+    /// let res = Executor::new(UnitBox {}, TestSolver::new())
+    ///     .configure(|state: IterState<_, (), (), (), f64>| {
+    ///         state.param(vec![2.0, 0.5]).max_iters(1)
+    ///     })
+    ///     .check_bounds()
+    ///     .run()?;
+    ///
+    /// assert_eq!(res.feasibility().unwrap().get_bounds(), Some(1.0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn check_bounds(mut self) -> Self
+    where
+        O: BoxConstraint<Param = I::Param>,
+        I::Param: ArgminBound + ArgminSub<I::Param, I::Param> + ArgminL2Norm<I::Float>,
+    {
+        self.bounds_check = Some(bounds_violation::<O, I>);
+        self
+    }
+
+    /// Enables checking the final parameter vector against the equality constraints of the
+    /// problem (default: disabled).
+    ///
+    /// Requires the problem to implement [`EqualityConstraint`]. After the run, the largest
+    /// absolute constraint value at the best parameter vector (or the current one if there is no
+    /// best) is stored in the [`FeasibilityReport`] of the [`OptimizationResult`] and passed to
+    /// the observers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{EqualityConstraint, Error, Executor, IterState};
+    /// # use argmin::core::test_utils::TestSolver;
+    /// #
+    /// # struct Plane {}
+    /// #
+    /// # impl EqualityConstraint for Plane {
+    /// #     type Param = Vec<f64>;
+    /// #     type Gradient = Vec<f64>;
+    /// #     type Float = f64;
+    /// #
+    /// #     fn equality_constraints(&self, p: &Self::Param) -> Result<Vec<f64>, Error> {
+    /// #         Ok(vec![p[0] + p[1] - 1.0])
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// // `Plane` implements `EqualityConstraint`
+    /// let res = Executor::new(Plane {}, TestSolver::new())
+    ///     .configure(|state: IterState<_, (), (), (), f64>| {
+    ///         state.param(vec![0.25, 0.25]).max_iters(1)
+    ///     })
+    ///     .check_equality_constraints()
+    ///     .run()?;
+    ///
+    /// assert_eq!(res.feasibility().unwrap().get_equality(), Some(0.5));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn check_equality_constraints(mut self) -> Self
+    where
+        O: EqualityConstraint<Param = I::Param, Float = I::Float>,
+    {
+        self.equality_check = Some(equality_violation::<O, I>);
+        self
+    }
+
+    /// Enables checking the final parameter vector against the inequality constraints of the
+    /// problem (default: disabled).
+    ///
+    /// Requires the problem to implement [`InequalityConstraint`]. After the run, the largest
+    /// positive constraint value at the best parameter vector (or the current one if there is no
+    /// best) is stored in the [`FeasibilityReport`] of the [`OptimizationResult`] and passed to
+    /// the observers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Error, Executor, InequalityConstraint, IterState};
+    /// # use argmin::core::test_utils::TestSolver;
+    /// #
+    /// # struct UnitDisk {}
+    /// #
+    /// # impl InequalityConstraint for UnitDisk {
+    /// #     type Param = Vec<f64>;
+    /// #     type Gradient = Vec<f64>;
+    /// #     type Float = f64;
+    /// #
+    /// #     fn inequality_constraints(&self, p: &Self::Param) -> Result<Vec<f64>, Error> {
+    /// #         Ok(vec![p[0].powi(2) + p[1].powi(2) - 1.0])
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// // `UnitDisk` implements `InequalityConstraint`
+    /// let res = Executor::new(UnitDisk {}, TestSolver::new())
+    ///     .configure(|state: IterState<_, (), (), (), f64>| {
+    ///         state.param(vec![0.5, 0.5]).max_iters(1)
+    ///     })
+    ///     .check_inequality_constraints()
+    ///     .run()?;
+    ///
+    /// assert_eq!(res.feasibility().unwrap().get_inequality(), Some(0.0));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn check_inequality_constraints(mut self) -> Self
+    where
+        O: InequalityConstraint<Param = I::Param, Float = I::Float>,
+    {
+        self.inequality_check = Some(inequality_violation::<O, I>);
+        self
+    }
+
+    /// Checks the gradient of the problem at the initial parameter vector before running the
+    /// solver.
+    ///
+    /// The gradient is compared against central finite differences of the cost function with
+    /// [`check_gradient`]. If the discrepancy of any component exceeds `tol`, an error describing
+    /// the worst component is returned. This catches mistakes in hand-written gradients, which
+    /// otherwise typically show up as line searches which fail or solvers which do not converge.
+    /// The initial parameter vector must be set via [`configure`](`Executor::configure`) first.
+    /// The evaluations do not contribute to the function evaluation counts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{CostFunction, Error, Executor, Gradient, IterState};
+    /// # use argmin::core::test_utils::TestSolver;
+    /// #
+    /// # struct Parabola {}
+    /// #
+    /// # impl CostFunction for Parabola {
+    /// #     type Param = Vec<f64>;
+    /// #     type Output = f64;
+    /// #
+    /// #     fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+    /// #         Ok(p[0].powi(2) + p[1].powi(2))
+    /// #     }
+    /// # }
+    /// #
+    /// # impl Gradient for Parabola {
+    /// #     type Param = Vec<f64>;
+    /// #     type Gradient = Vec<f64>;
+    /// #
+    /// #     fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+    /// #         Ok(vec![2.0 * p[0], 2.0 * p[1]])
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() -> Result<(), Error> {
+    /// // `Parabola` implements `CostFunction` and `Gradient`
+    /// let res = Executor::new(Parabola {}, TestSolver::new())
+    ///     .configure(|state: IterState<_, (), (), (), f64>| {
+    ///         state.param(vec![1.0, 2.0]).max_iters(1)
+    ///     })
+    ///     .validate(1e-6)?
+    ///     .run()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn validate<F>(self, tol: F) -> Result<Self, Error>
+    where
+        O: CostFunction<Param = Vec<F>, Output = F> + Gradient<Param = Vec<F>, Gradient = Vec<F>>,
+        I: State<Param = Vec<F>, Float = F>,
+        F: ArgminFloat,
+    {
+        let param = self
+            .state
+            .as_ref()
+            .and_then(|state| state.get_param())
+            .ok_or_else(argmin_error_closure!(
+                NotInitialized,
+                "`Executor::validate`: Initial parameter vector required."
+            ))?;
+        let problem = self
+            .problem
+            .problem
+            .as_ref()
+            .ok_or_else(argmin_error_closure!(
+                PotentialBug,
+                "`Executor`: Problem not available for validation"
+            ))?;
+        let check = check_gradient(problem, param, tol)?;
+        if !check.passed() {
+            return Err(argmin_error!(
+                ConditionViolated,
+                format!(
+                    "`Executor::validate`: Gradient component {} is {}, but finite differences \
+                     give {} (discrepancy {} exceeds tolerance {}).",
+                    check.index(),
+                    check.analytic(),
+                    check.approximation(),
+                    check.error(),
+                    tol
+                )
+            ));
+        }
+        Ok(self)
+    }
+}
+
+/// Returns the best parameter vector of `state` or the current one if there is no best
+fn final_param<I: State>(state: &I) -> Option<&I::Param> {
+    state.get_best_param().or_else(|| state.get_param())
+}
+
+/// Converts a violation to `f64`
+fn violation_to_f64<F: ArgminFloat>(violation: F) -> f64 {
+    violation.to_f64().unwrap_or(f64::NAN)
+}
+
+/// Euclidean distance of the final parameter vector to the bounds
+fn bounds_violation<O, I>(problem: &O, state: &I) -> Result<f64, Error>
+where
+    O: BoxConstraint<Param = I::Param>,
+    I: State,
+    I::Param: ArgminBound + ArgminSub<I::Param, I::Param> + ArgminL2Norm<I::Float>,
+{
+    let param = final_param(state).ok_or_else(argmin_error_closure!(
+        PotentialBug,
+        "`Executor`: No parameter vector available for feasibility check"
+    ))?;
+    let bounds = problem.bounds()?;
+    let distance = param.sub(&bounds.clamp(param)).l2_norm();
+    Ok(violation_to_f64(distance))
+}
+
+/// Largest absolute value of the equality constraints at the final parameter vector
+fn equality_violation<O, I>(problem: &O, state: &I) -> Result<f64, Error>
+where
+    O: EqualityConstraint<Param = I::Param, Float = I::Float>,
+    I: State,
+{
+    let param = final_param(state).ok_or_else(argmin_error_closure!(
+        PotentialBug,
+        "`Executor`: No parameter vector available for feasibility check"
+    ))?;
+    let values = problem.equality_constraints(param)?;
+    Ok(max_violation(
+        values.into_iter().map(|h| violation_to_f64(h).abs()),
+    ))
+}
+
+/// Largest positive value of the inequality constraints at the final parameter vector
+fn inequality_violation<O, I>(problem: &O, state: &I) -> Result<f64, Error>
+where
+    O: InequalityConstraint<Param = I::Param, Float = I::Float>,
+    I: State,
+{
+    let param = final_param(state).ok_or_else(argmin_error_closure!(
+        PotentialBug,
+        "`Executor`: No parameter vector available for feasibility check"
+    ))?;
+    let values = problem.inequality_constraints(param)?;
+    Ok(max_violation(values.into_iter().map(violation_to_f64)))
+}
+
+/// Largest of the given violations (at least `0`); NaN values are propagated
+fn max_violation(violations: impl Iterator<Item = f64>) -> f64 {
+    violations.fold(0.0, |acc, v| {
+        if acc.is_nan() || v.is_nan() {
+            f64::NAN
+        } else {
+            acc.max(v)
+        }
+    })
 }
 
 #[cfg(test)]
@@ -669,4 +1062,147 @@ mod tests {
         // Delete old checkpointing file
         let _ = std::fs::remove_file(".checkpoints/init_test.arg");
     }
+
+    #[test]
+    fn test_feasibility() {
+        use crate::core::observers::Observe;
+        use crate::core::{Bounds, LinearConstraints};
+        use std::sync::Mutex;
+
+        #[derive(Clone)]
+        struct ConstrainedProblem {
+            constraints: LinearConstraints<f64>,
+        }
+
+        impl BoxConstraint for ConstrainedProblem {
+            type Param = Vec<f64>;
+
+            fn bounds(&self) -> Result<Bounds<Vec<f64>>, Error> {
+                Bounds::new(vec![0.0, 0.0], vec![0.5, 0.5])
+            }
+        }
+
+        impl EqualityConstraint for ConstrainedProblem {
+            type Param = Vec<f64>;
+            type Gradient = Vec<f64>;
+            type Float = f64;
+
+            fn equality_constraints(&self, param: &Vec<f64>) -> Result<Vec<f64>, Error> {
+                self.constraints.equality_constraints(param)
+            }
+        }
+
+        impl InequalityConstraint for ConstrainedProblem {
+            type Param = Vec<f64>;
+            type Gradient = Vec<f64>;
+            type Float = f64;
+
+            fn inequality_constraints(&self, param: &Vec<f64>) -> Result<Vec<f64>, Error> {
+                self.constraints.inequality_constraints(param)
+            }
+        }
+
+        struct FinalObserver {
+            kv: Arc<Mutex<Option<KV>>>,
+        }
+
+        impl<I> Observe<I> for FinalObserver {
+            fn observe_final(&mut self, _state: &I, kv: &KV) -> Result<(), Error> {
+                *self.kv.lock().unwrap() = Some(kv.clone());
+                Ok(())
+            }
+        }
+
+        // x + y = 1, x - y <= 0.25
+        let problem = ConstrainedProblem {
+            constraints: LinearConstraints::new(2)
+                .with_equality(vec![vec![1.0, 1.0]], vec![1.0])
+                .unwrap()
+                .with_inequality(vec![vec![1.0, -1.0]], vec![f64::NEG_INFINITY], vec![0.25])
+                .unwrap(),
+        };
+
+        // No checks enabled
+        let res = Executor::new(problem.clone(), TestSolver::new())
+            .configure(|state| state.param(vec![1.0f64, 0.5]).max_iters(2))
+            .run()
+            .unwrap();
+        assert!(res.feasibility().is_none());
+
+        let kv = Arc::new(Mutex::new(None));
+        let res = Executor::new(problem, TestSolver::new())
+            .configure(|state| state.param(vec![1.0f64, 0.5]).max_iters(2))
+            .check_bounds()
+            .check_equality_constraints()
+            .check_inequality_constraints()
+            .add_observer(FinalObserver { kv: kv.clone() }, ObserverMode::Always)
+            .run()
+            .unwrap();
+
+        let report = res.feasibility().unwrap();
+        assert_relative_eq!(report.get_bounds().unwrap(), 0.5, epsilon = f64::EPSILON);
+        assert_relative_eq!(report.get_equality().unwrap(), 0.5, epsilon = f64::EPSILON);
+        assert_relative_eq!(
+            report.get_inequality().unwrap(),
+            0.25,
+            epsilon = f64::EPSILON
+        );
+        assert!(!report.is_feasible(1e-6));
+
+        // Constraint evaluations for the report are not counted
+        assert!(res.state().get_func_counts().is_empty());
+
+        // The report is passed to the observers
+        assert_eq!(kv.lock().unwrap().as_ref().unwrap(), &report.kv());
+    }
+
+    #[test]
+    fn test_validate() {
+        use crate::core::ArgminError;
+
+        /// `x^2 + y^2`, optionally with a wrong derivative with respect to `y`
+        struct Parabola {
+            buggy: bool,
+        }
+
+        impl CostFunction for Parabola {
+            type Param = Vec<f64>;
+            type Output = f64;
+
+            fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(p[0].powi(2) + p[1].powi(2))
+            }
+        }
+
+        impl Gradient for Parabola {
+            type Param = Vec<f64>;
+            type Gradient = Vec<f64>;
+
+            fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+                let dy = if self.buggy { p[1] } else { 2.0 * p[1] };
+                Ok(vec![2.0 * p[0], dy])
+            }
+        }
+
+        let res = Executor::new(Parabola { buggy: false }, TestSolver::new())
+            .configure(|state: IterState<_, (), (), (), f64>| state.param(vec![1.0, 2.0]))
+            .validate(1e-6);
+        assert!(res.is_ok());
+
+        let res = Executor::new(Parabola { buggy: true }, TestSolver::new())
+            .configure(|state: IterState<_, (), (), (), f64>| state.param(vec![1.0, 2.0]))
+            .validate(1e-6);
+        let err = res.err().unwrap().downcast::<ArgminError>().unwrap();
+        assert!(matches!(err, ArgminError::ConditionViolated { .. }));
+        assert!(err
+            .to_string()
+            .starts_with("Condition violated: \"`Executor::validate`: Gradient component 1 is 2,"));
+
+        let res = Executor::new(Parabola { buggy: false }, TestSolver::new()).validate(1e-6);
+        assert_error!(
+            res,
+            ArgminError,
+            "Not initialized: \"`Executor::validate`: Initial parameter vector required.\""
+        );
+    }
 }
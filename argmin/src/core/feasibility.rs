@@ -0,0 +1,188 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{KvValue, KV};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Summary of the constraint violations of the final parameter vector
+///
+/// Holds the largest violation of each group of constraints which was checked:
+///
+/// * `bounds`: Euclidean distance of the parameter vector to the box defined by
+///   [`BoxConstraint`](`crate::core::BoxConstraint`)
+/// * `equality`: largest absolute value of the constraints defined by
+///   [`EqualityConstraint`](`crate::core::EqualityConstraint`)
+/// * `inequality`: largest positive value of the constraints defined by
+///   [`InequalityConstraint`](`crate::core::InequalityConstraint`)
+///
+/// Groups which were not checked are `None`. Like all floats stored in [`KV`], the violations are
+/// stored as `f64`.
+///
+/// A report is computed at the end of a run if the corresponding checks were enabled on the
+/// [`Executor`](`crate::core::Executor`). It is attached to the
+/// [`OptimizationResult`](`crate::core::OptimizationResult`) and passed to the observers.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::core::FeasibilityReport;
+/// let report = FeasibilityReport::new().bounds(0.0).inequality(1e-3);
+///
+/// assert_eq!(report.get_equality(), None);
+/// assert_eq!(report.max_violation(), 1e-3);
+/// assert!(!report.is_feasible(1e-6));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct FeasibilityReport {
+    /// Violation of the bounds
+    bounds: Option<f64>,
+    /// Largest violation of the equality constraints
+    equality: Option<f64>,
+    /// Largest violation of the inequality constraints
+    inequality: Option<f64>,
+}
+
+impl FeasibilityReport {
+    /// Construct a new empty instance of `FeasibilityReport`
+    pub fn new() -> Self {
+        FeasibilityReport::default()
+    }
+
+    /// Set the violation of the bounds
+    #[must_use]
+    pub fn bounds(mut self, violation: f64) -> Self {
+        self.bounds = Some(violation);
+        self
+    }
+
+    /// Set the largest violation of the equality constraints
+    #[must_use]
+    pub fn equality(mut self, violation: f64) -> Self {
+        self.equality = Some(violation);
+        self
+    }
+
+    /// Set the largest violation of the inequality constraints
+    #[must_use]
+    pub fn inequality(mut self, violation: f64) -> Self {
+        self.inequality = Some(violation);
+        self
+    }
+
+    /// Returns the violation of the bounds if they were checked
+    pub fn get_bounds(&self) -> Option<f64> {
+        self.bounds
+    }
+
+    /// Returns the largest violation of the equality constraints if they were checked
+    pub fn get_equality(&self) -> Option<f64> {
+        self.equality
+    }
+
+    /// Returns the largest violation of the inequality constraints if they were checked
+    pub fn get_inequality(&self) -> Option<f64> {
+        self.inequality
+    }
+
+    /// Returns the largest violation over all checked groups (`0` if nothing was checked)
+    ///
+    /// A NaN violation in any group is propagated, which renders the report infeasible.
+    pub fn max_violation(&self) -> f64 {
+        [self.bounds, self.equality, self.inequality]
+            .iter()
+            .flatten()
+            .fold(0.0, |acc: f64, &v| {
+                if acc.is_nan() || v.is_nan() {
+                    f64::NAN
+                } else {
+                    acc.max(v)
+                }
+            })
+    }
+
+    /// Returns `true` if no checked group is violated by more than `tol`
+    pub fn is_feasible(&self, tol: f64) -> bool {
+        self.max_violation() <= tol
+    }
+
+    /// Returns the report as key-value store
+    ///
+    /// Contains the entries `feasibility_bounds`, `feasibility_equality` and
+    /// `feasibility_inequality` for the checked groups as well as `feasibility_max_violation`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::FeasibilityReport;
+    /// let kv = FeasibilityReport::new().equality(0.5).kv();
+    ///
+    /// assert_eq!(kv.get("feasibility_equality").unwrap().get_float(), Some(0.5));
+    /// assert_eq!(kv.get("feasibility_max_violation").unwrap().get_float(), Some(0.5));
+    /// assert!(kv.get("feasibility_bounds").is_none());
+    /// ```
+    pub fn kv(&self) -> KV {
+        let mut kv = KV::new();
+        for (key, value) in [
+            ("feasibility_bounds", self.bounds),
+            ("feasibility_equality", self.equality),
+            ("feasibility_inequality", self.inequality),
+        ] {
+            if let Some(value) = value {
+                kv.insert(key, KvValue::Float(value));
+            }
+        }
+        kv.insert(
+            "feasibility_max_violation",
+            KvValue::Float(self.max_violation()),
+        );
+        kv
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let report = FeasibilityReport::new();
+        assert_eq!(report.get_bounds(), None);
+        assert_eq!(report.get_equality(), None);
+        assert_eq!(report.get_inequality(), None);
+        assert_eq!(report.max_violation().to_ne_bytes(), 0.0f64.to_ne_bytes());
+        assert!(report.is_feasible(0.0));
+        assert_eq!(report.kv().kv.len(), 1);
+    }
+
+    #[test]
+    fn test_max_violation() {
+        let report = FeasibilityReport::new()
+            .bounds(0.1)
+            .equality(0.3)
+            .inequality(0.2);
+        assert_eq!(report.get_bounds(), Some(0.1));
+        assert_eq!(report.get_equality(), Some(0.3));
+        assert_eq!(report.get_inequality(), Some(0.2));
+        assert_eq!(report.max_violation().to_ne_bytes(), 0.3f64.to_ne_bytes());
+        assert!(report.is_feasible(0.3));
+        assert!(!report.is_feasible(0.2));
+
+        let kv = report.kv();
+        assert_eq!(kv.kv.len(), 4);
+        assert_eq!(kv.get("feasibility_bounds").unwrap().get_float(), Some(0.1));
+        assert_eq!(
+            kv.get("feasibility_inequality").unwrap().get_float(),
+            Some(0.2)
+        );
+
+        let report = FeasibilityReport::new().bounds(f64::NAN).equality(1.0);
+        assert!(report.max_violation().is_nan());
+        assert!(!report.is_feasible(f64::INFINITY));
+    }
+}
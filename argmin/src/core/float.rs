@@ -6,8 +6,8 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::core::{kv::KvValue, DeserializeOwnedAlias, SendAlias, SerializeAlias};
+use core::fmt::{Debug, Display};
 use num_traits::{Float, FloatConst, FromPrimitive, ToPrimitive};
-use std::fmt::{Debug, Display};
 
 /// An alias for float types (`f32`, `f64`) which combines multiple commonly needed traits from
 /// `num_traits`, `std::fmt` and for serialization/deserialization (the latter only if the `serde1`
@@ -0,0 +1,226 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{ArgminFloat, CostFunction, Error, Gradient, Hessian};
+use alloc::{vec, vec::Vec};
+use num_dual::{Dual, DualNum, DualNumFloat, HyperDual};
+
+/// Defines a cost function which is generic over the scalar type.
+///
+/// Implementing the cost function once for any [`DualNum`] allows [`ForwardDiff`] to evaluate it
+/// with plain floats as well as with dual numbers, which yields exact derivatives without having
+/// to write them by hand.
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::{DualCostFunction, Error};
+/// use num_dual::DualNum;
+///
+/// struct Rosenbrock {}
+///
+/// impl DualCostFunction for Rosenbrock {
+///     type Float = f64;
+///
+///     fn cost<D: DualNum<Primitive = f64> + Copy>(&self, p: &[D]) -> Result<D, Error> {
+///         Ok((-p[0] + 1.0).powi(2) + (p[1] - p[0].powi(2)).powi(2) * 100.0)
+///     }
+/// }
+/// ```
+pub trait DualCostFunction {
+    /// Underlying floating point type
+    type Float: DualNumFloat;
+
+    /// Compute cost function for a parameter vector of (dual) numbers
+    fn cost<D: DualNum<Primitive = Self::Float> + Copy>(&self, param: &[D]) -> Result<D, Error>;
+}
+
+/// # Forward-mode automatic differentiation
+///
+/// Wraps a [`DualCostFunction`] and implements [`CostFunction`], [`Gradient`] and [`Hessian`] for
+/// it with `Vec<F>` as parameter vector, `Vec<F>` as gradient and `Vec<Vec<F>>` as Hessian.
+///
+/// The gradient requires one evaluation of the cost function with dual numbers per parameter,
+/// the Hessian one evaluation with hyper-dual numbers per element of its upper triangle. Forward
+/// mode is therefore well suited for problems with few parameters; the derivatives are exact up
+/// to floating point errors.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::core::{DualCostFunction, Error, ForwardDiff, Gradient};
+/// # use num_dual::DualNum;
+/// # struct Rosenbrock {}
+/// # impl DualCostFunction for Rosenbrock {
+/// #     type Float = f64;
+/// #     fn cost<D: DualNum<Primitive = f64> + Copy>(&self, p: &[D]) -> Result<D, Error> {
+/// #         Ok((-p[0] + 1.0).powi(2) + (p[1] - p[0].powi(2)).powi(2) * 100.0)
+/// #     }
+/// # }
+/// # fn main() -> Result<(), Error> {
+/// let problem = ForwardDiff::new(Rosenbrock {});
+///
+/// let grad = problem.gradient(&vec![1.0, 1.0])?;
+/// # assert!(grad[0].abs() < 1e-12 && grad[1].abs() < 1e-12);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ForwardDiff<C> {
+    /// Wrapped cost function
+    inner: C,
+}
+
+impl<C> ForwardDiff<C> {
+    /// Construct a new instance of `ForwardDiff`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::ForwardDiff;
+    /// # struct UserDefinedProblem {};
+    /// let problem = ForwardDiff::new(UserDefinedProblem {});
+    /// ```
+    pub fn new(inner: C) -> Self {
+        ForwardDiff { inner }
+    }
+
+    /// Returns a reference to the wrapped cost function
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    /// Returns the wrapped cost function
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+}
+
+impl<C, F> CostFunction for ForwardDiff<C>
+where
+    C: DualCostFunction<Float = F>,
+    F: ArgminFloat + DualNumFloat<Primitive = F>,
+{
+    type Param = Vec<F>;
+    type Output = F;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        self.inner.cost(param)
+    }
+}
+
+impl<C, F> Gradient for ForwardDiff<C>
+where
+    C: DualCostFunction<Float = F>,
+    F: ArgminFloat + DualNumFloat<Primitive = F>,
+{
+    type Param = Vec<F>;
+    type Gradient = Vec<F>;
+
+    fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+        let mut x: Vec<Dual<F>> = param.iter().map(|p| Dual::from_re(*p)).collect();
+        (0..param.len())
+            .map(|i| {
+                x[i].eps = F::one();
+                let df = self.inner.cost(&x)?.eps;
+                x[i].eps = F::zero();
+                Ok(df)
+            })
+            .collect()
+    }
+}
+
+impl<C, F> Hessian for ForwardDiff<C>
+where
+    C: DualCostFunction<Float = F>,
+    F: ArgminFloat + DualNumFloat<Primitive = F>,
+{
+    type Param = Vec<F>;
+    type Hessian = Vec<Vec<F>>;
+
+    fn hessian(&self, param: &Self::Param) -> Result<Self::Hessian, Error> {
+        let n = param.len();
+        let mut x: Vec<HyperDual<F>> = param.iter().map(|p| HyperDual::from_re(*p)).collect();
+        let mut hessian = vec![vec![F::zero(); n]; n];
+        for i in 0..n {
+            x[i].eps1 = F::one();
+            for j in i..n {
+                x[j].eps2 = F::one();
+                let h = self.inner.cost(&x)?.eps1eps2;
+                x[j].eps2 = F::zero();
+                hessian[i][j] = h;
+                hessian[j][i] = h;
+            }
+            x[i].eps1 = F::zero();
+        }
+        Ok(hessian)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+    use argmin_testfunctions::{rosenbrock_2d, rosenbrock_2d_derivative, rosenbrock_2d_hessian};
+
+    test_trait_impl!(forward_diff, ForwardDiff<Rosenbrock>);
+
+    #[derive(Clone, Debug, Default)]
+    struct Rosenbrock {}
+
+    impl DualCostFunction for Rosenbrock {
+        type Float = f64;
+
+        fn cost<D: DualNum<Primitive = f64> + Copy>(&self, p: &[D]) -> Result<D, Error> {
+            Ok((-p[0] + 1.0).powi(2) + (p[1] - p[0].powi(2)).powi(2) * 100.0)
+        }
+    }
+
+    #[test]
+    fn test_cost() {
+        let problem = ForwardDiff::new(Rosenbrock {});
+        let param = vec![-1.2, 1.5];
+        assert_relative_eq!(
+            CostFunction::cost(&problem, &param).unwrap(),
+            rosenbrock_2d(&param, 1.0, 100.0),
+            epsilon = f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_gradient() {
+        let problem = ForwardDiff::new(Rosenbrock {});
+        for param in [vec![-1.2, 1.5], vec![0.3, -2.0], vec![1.0, 1.0]] {
+            let grad = problem.gradient(&param).unwrap();
+            let target = rosenbrock_2d_derivative(&param, 1.0, 100.0);
+            for (g, t) in grad.iter().zip(target.iter()) {
+                assert_relative_eq!(*g, *t, epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hessian() {
+        let problem = ForwardDiff::new(Rosenbrock {});
+        for param in [vec![-1.2, 1.5], vec![0.3, -2.0], vec![1.0, 1.0]] {
+            let hessian = problem.hessian(&param).unwrap();
+            let target = rosenbrock_2d_hessian(&param, 1.0, 100.0);
+            assert_relative_eq!(hessian[0][0], target[0], epsilon = 1e-12);
+            assert_relative_eq!(hessian[0][1], target[1], epsilon = 1e-12);
+            assert_relative_eq!(hessian[1][0], target[2], epsilon = 1e-12);
+            assert_relative_eq!(hessian[1][1], target[3], epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_inner() {
+        let problem = ForwardDiff::new(Rosenbrock {});
+        let _: &Rosenbrock = problem.inner();
+        let _: Rosenbrock = problem.into_inner();
+    }
+}
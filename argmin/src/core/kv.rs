@@ -5,9 +5,13 @@
 // http://opensource.org/licenses/MIT>, at your option. This file may not be
 // copied, modified, or distributed except according to those terms.
 
-use std::collections::HashMap;
-use std::fmt;
-use std::fmt::{Debug, Display};
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+use core::fmt::{Debug, Display};
 
 /// Types available for use in [`KV`](KV).
 ///
@@ -203,6 +207,14 @@ impl From<f32> for KvValue {
     }
 }
 
+/// Loggers only need an approximate value, therefore `BigFloat` is rounded to `f64`.
+#[cfg(feature = "num-bigfloat")]
+impl From<num_bigfloat::BigFloat> for KvValue {
+    fn from(x: num_bigfloat::BigFloat) -> KvValue {
+        KvValue::Float(x.to_f64())
+    }
+}
+
 impl From<i64> for KvValue {
     fn from(x: i64) -> KvValue {
         KvValue::Int(x)
@@ -282,7 +294,7 @@ impl Display for KvValue {
 #[derive(Clone, Default, PartialEq)]
 pub struct KV {
     /// The actual key value storage
-    pub kv: HashMap<&'static str, KvValue>,
+    pub kv: BTreeMap<&'static str, KvValue>,
 }
 
 impl Debug for KV {
@@ -313,7 +325,9 @@ impl KV {
     /// # assert_eq!(kv.kv.len(), 0);
     /// ```
     pub fn new() -> Self {
-        KV { kv: HashMap::new() }
+        KV {
+            kv: BTreeMap::new(),
+        }
     }
 
     /// Insert a key-value pair
@@ -395,7 +409,7 @@ impl KV {
     }
 }
 
-impl std::iter::FromIterator<(&'static str, KvValue)> for KV {
+impl core::iter::FromIterator<(&'static str, KvValue)> for KV {
     fn from_iter<I: IntoIterator<Item = (&'static str, KvValue)>>(iter: I) -> Self {
         let mut c = KV::new();
         for i in iter {
@@ -405,7 +419,7 @@ impl std::iter::FromIterator<(&'static str, KvValue)> for KV {
     }
 }
 
-impl std::iter::Extend<(&'static str, KvValue)> for KV {
+impl core::iter::Extend<(&'static str, KvValue)> for KV {
     fn extend<I: IntoIterator<Item = (&'static str, KvValue)>>(&mut self, iter: I) {
         for i in iter {
             self.insert(i.0, i.1);
@@ -0,0 +1,321 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{ArgminFloat, CostFunction, Error, Gradient, Jacobian, Operator};
+use argmin_math::{ArgminDot, ArgminL2Norm, ArgminTranspose};
+
+/// Defines a nonlinear least-squares problem `min 1/2 * ||r(x)||^2` by its residuals `r(x)`.
+///
+/// The Jacobian of the residuals is optional; without it, only solvers which do not require
+/// derivatives can be used (or it can be approximated, see
+/// [`FiniteDiff`](`crate::derivatives::FiniteDiff`)). Wrapped in a [`LeastSquaresProblem`], the
+/// residuals can be minimized with [`GaussNewton`](`crate::solver::gaussnewton::GaussNewton`)
+/// as well as with general solvers which only see the cost function and its gradient.
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::{Error, LeastSquares};
+///
+/// /// Rosenbrock function as sum of squares
+/// struct Rosenbrock {}
+///
+/// impl LeastSquares for Rosenbrock {
+///     type Param = Vec<f64>;
+///     type Residuals = Vec<f64>;
+///     type Jacobian = Vec<Vec<f64>>;
+///     type Float = f64;
+///
+///     fn residuals(&self, p: &Self::Param) -> Result<Self::Residuals, Error> {
+///         Ok(vec![10.0 * (p[1] - p[0].powi(2)), 1.0 - p[0]])
+///     }
+///
+///     fn jacobian(&self, p: &Self::Param) -> Result<Self::Jacobian, Error> {
+///         Ok(vec![vec![-20.0 * p[0], 10.0], vec![-1.0, 0.0]])
+///     }
+/// }
+/// ```
+pub trait LeastSquares {
+    /// Type of the parameter vector
+    type Param;
+    /// Type of the residual vector
+    type Residuals;
+    /// Type of the Jacobian of the residuals
+    type Jacobian;
+    /// Floating point precision of the cost function
+    type Float: ArgminFloat;
+
+    /// Compute the residuals
+    fn residuals(&self, param: &Self::Param) -> Result<Self::Residuals, Error>;
+
+    /// Compute the Jacobian of the residuals
+    ///
+    /// Returns a `NotImplemented` error by default.
+    fn jacobian(&self, _param: &Self::Param) -> Result<Self::Jacobian, Error> {
+        Err(argmin_error!(
+            NotImplemented,
+            "Method `jacobian` of LeastSquares trait not implemented!"
+        ))
+    }
+}
+
+/// # Least-squares problem
+///
+/// Wraps a [`LeastSquares`] problem and implements
+///
+/// * [`Operator`] and [`Jacobian`], which return the residuals and their Jacobian, as required by
+///   [`GaussNewton`](`crate::solver::gaussnewton::GaussNewton`) and
+///   [`GaussNewtonLS`](`crate::solver::gaussnewton::GaussNewtonLS`),
+/// * [`CostFunction`], which returns `1/2 * ||r(x)||^2`, and
+/// * [`Gradient`], which returns `J(x)^T r(x)`,
+///
+/// such that the same problem can be solved with least-squares and general solvers alike.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::core::{CostFunction, Error, Gradient, LeastSquares, LeastSquaresProblem};
+/// # struct Rosenbrock {}
+/// # impl LeastSquares for Rosenbrock {
+/// #     type Param = Vec<f64>;
+/// #     type Residuals = Vec<f64>;
+/// #     type Jacobian = Vec<Vec<f64>>;
+/// #     type Float = f64;
+/// #     fn residuals(&self, p: &Self::Param) -> Result<Self::Residuals, Error> {
+/// #         Ok(vec![10.0 * (p[1] - p[0].powi(2)), 1.0 - p[0]])
+/// #     }
+/// #     fn jacobian(&self, p: &Self::Param) -> Result<Self::Jacobian, Error> {
+/// #         Ok(vec![vec![-20.0 * p[0], 10.0], vec![-1.0, 0.0]])
+/// #     }
+/// # }
+/// # fn main() -> Result<(), Error> {
+/// let problem = LeastSquaresProblem::new(Rosenbrock {});
+///
+/// let cost = problem.cost(&vec![0.0, 1.0])?;
+/// let grad = problem.gradient(&vec![0.0, 1.0])?;
+/// # assert_eq!(cost, 50.5);
+/// # assert_eq!(grad, vec![-1.0, 100.0]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct LeastSquaresProblem<L> {
+    /// Wrapped least-squares problem
+    inner: L,
+}
+
+impl<L> LeastSquaresProblem<L> {
+    /// Construct a new instance of `LeastSquaresProblem`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::LeastSquaresProblem;
+    /// # struct UserDefinedProblem {};
+    /// let problem = LeastSquaresProblem::new(UserDefinedProblem {});
+    /// ```
+    pub fn new(inner: L) -> Self {
+        LeastSquaresProblem { inner }
+    }
+
+    /// Returns a reference to the wrapped least-squares problem
+    pub fn inner(&self) -> &L {
+        &self.inner
+    }
+
+    /// Returns the wrapped least-squares problem
+    pub fn into_inner(self) -> L {
+        self.inner
+    }
+}
+
+impl<L> Operator for LeastSquaresProblem<L>
+where
+    L: LeastSquares,
+{
+    type Param = L::Param;
+    type Output = L::Residuals;
+
+    fn apply(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        self.inner.residuals(param)
+    }
+}
+
+impl<L> Jacobian for LeastSquaresProblem<L>
+where
+    L: LeastSquares,
+{
+    type Param = L::Param;
+    type Jacobian = L::Jacobian;
+
+    fn jacobian(&self, param: &Self::Param) -> Result<Self::Jacobian, Error> {
+        self.inner.jacobian(param)
+    }
+}
+
+impl<L, F> CostFunction for LeastSquaresProblem<L>
+where
+    L: LeastSquares<Float = F>,
+    L::Residuals: ArgminL2Norm<F>,
+    F: ArgminFloat,
+{
+    type Param = L::Param;
+    type Output = F;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        let norm = self.inner.residuals(param)?.l2_norm();
+        Ok(float!(0.5) * norm * norm)
+    }
+}
+
+impl<L> Gradient for LeastSquaresProblem<L>
+where
+    L: LeastSquares,
+    L::Jacobian: ArgminTranspose<L::Jacobian> + ArgminDot<L::Residuals, L::Param>,
+{
+    type Param = L::Param;
+    type Gradient = L::Param;
+
+    fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+        let residuals = self.inner.residuals(param)?;
+        Ok(self.inner.jacobian(param)?.t().dot(&residuals))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Executor, State};
+    #[cfg(feature = "std")]
+    use crate::solver::gaussnewton::GaussNewton;
+    #[cfg(feature = "std")]
+    use crate::solver::linearsolver::CGSolver;
+    #[cfg(feature = "std")]
+    use crate::solver::linesearch::MoreThuenteLineSearch;
+    use crate::solver::neldermead::NelderMead;
+    #[cfg(feature = "std")]
+    use crate::solver::quasinewton::LBFGS;
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+    use argmin_testfunctions::{rosenbrock_2d, rosenbrock_2d_derivative};
+
+    test_trait_impl!(least_squares_problem, LeastSquaresProblem<Rosenbrock>);
+
+    /// Rosenbrock function (scaled by `1/2`) as sum of squares
+    #[derive(Clone, Debug, Default)]
+    struct Rosenbrock {}
+
+    impl LeastSquares for Rosenbrock {
+        type Param = Vec<f64>;
+        type Residuals = Vec<f64>;
+        type Jacobian = Vec<Vec<f64>>;
+        type Float = f64;
+
+        fn residuals(&self, p: &Self::Param) -> Result<Self::Residuals, Error> {
+            Ok(vec![10.0 * (p[1] - p[0].powi(2)), 1.0 - p[0]])
+        }
+
+        fn jacobian(&self, p: &Self::Param) -> Result<Self::Jacobian, Error> {
+            Ok(vec![vec![-20.0 * p[0], 10.0], vec![-1.0, 0.0]])
+        }
+    }
+
+    #[test]
+    fn test_cost_and_gradient() {
+        let problem = LeastSquaresProblem::new(Rosenbrock {});
+        for param in [vec![-1.2, 1.5], vec![0.3, -2.0], vec![1.0, 1.0]] {
+            assert_relative_eq!(
+                problem.cost(&param).unwrap(),
+                0.5 * rosenbrock_2d(&param, 1.0, 100.0),
+                epsilon = 1e-12
+            );
+            let grad = problem.gradient(&param).unwrap();
+            let target = rosenbrock_2d_derivative(&param, 1.0, 100.0);
+            for (g, t) in grad.iter().zip(target.iter()) {
+                assert_relative_eq!(*g, 0.5 * t, epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_operator_and_jacobian() {
+        let problem = LeastSquaresProblem::new(Rosenbrock {});
+        let param = vec![0.0, 1.0];
+        assert_eq!(problem.apply(&param).unwrap(), vec![10.0, 1.0]);
+        assert_eq!(
+            Jacobian::jacobian(&problem, &param).unwrap(),
+            vec![vec![0.0, 10.0], vec![-1.0, 0.0]]
+        );
+    }
+
+    #[test]
+    fn test_jacobian_not_implemented() {
+        struct Residuals {}
+
+        impl LeastSquares for Residuals {
+            type Param = Vec<f64>;
+            type Residuals = Vec<f64>;
+            type Jacobian = Vec<Vec<f64>>;
+            type Float = f64;
+
+            fn residuals(&self, p: &Self::Param) -> Result<Self::Residuals, Error> {
+                Ok(p.clone())
+            }
+        }
+
+        let problem = LeastSquaresProblem::new(Residuals {});
+        assert_eq!(
+            problem.cost(&vec![3.0, 4.0]).unwrap().to_ne_bytes(),
+            12.5f64.to_ne_bytes()
+        );
+        assert_error!(
+            problem.gradient(&vec![3.0, 4.0]),
+            ArgminError,
+            "Not implemented: \"Method `jacobian` of LeastSquares trait not implemented!\""
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_solvers() {
+        let init_param = vec![-1.2, 1.0];
+
+        let solver = GaussNewton::new().with_linear_solver(CGSolver::new().with_tolerance(1e-12));
+        let res = Executor::new(LeastSquaresProblem::new(Rosenbrock {}), solver)
+            .configure(|state| state.param(init_param.clone()).max_iters(20))
+            .run()
+            .unwrap();
+        let best = res.state().get_best_param().unwrap();
+        assert_relative_eq!(best[0], 1.0, epsilon = 1e-6);
+        assert_relative_eq!(best[1], 1.0, epsilon = 1e-6);
+
+        let linesearch: MoreThuenteLineSearch<Vec<f64>, Vec<f64>, f64> =
+            MoreThuenteLineSearch::new();
+        let res = Executor::new(
+            LeastSquaresProblem::new(Rosenbrock {}),
+            LBFGS::new(linesearch, 7),
+        )
+        .configure(|state| state.param(init_param).max_iters(100))
+        .run()
+        .unwrap();
+        let best = res.state().get_best_param().unwrap();
+        assert_relative_eq!(best[0], 1.0, epsilon = 1e-4);
+        assert_relative_eq!(best[1], 1.0, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn test_nelder_mead() {
+        let solver = NelderMead::new(vec![vec![-1.2, 1.0], vec![-1.0, 1.0], vec![-1.2, 1.2]])
+            .with_sd_tolerance(1e-10)
+            .unwrap();
+        let res = Executor::new(LeastSquaresProblem::new(Rosenbrock {}), solver)
+            .configure(|state| state.max_iters(500))
+            .run()
+            .unwrap();
+        assert!(res.state().get_best_cost() < 1e-8);
+    }
+}
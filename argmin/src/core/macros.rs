@@ -27,7 +27,7 @@
 #[macro_export]
 macro_rules! kv {
     ($($k:expr =>  $v:expr;)*) => {
-        $crate::core::KV { kv: std::collections::HashMap::from([ $(($k, $v.into())),* ]) }
+        <$crate::core::KV as ::core::iter::FromIterator<_>>::from_iter([ $(($k, $v.into())),* ])
     };
 }
 
@@ -39,7 +39,7 @@ macro_rules! check_param {
         match $param {
             None => {
                 return Err($crate::core::ArgminError::$error {
-                    text: $msg.to_string(),
+                    text: $crate::__ToString::to_string(&$msg),
                 }
                 .into());
             }
@@ -56,7 +56,7 @@ macro_rules! check_param {
 macro_rules! argmin_error {
     ($error_type:ident, $msg:expr) => {
         $crate::core::ArgminError::$error_type {
-            text: $msg.to_string(),
+            text: $crate::__ToString::to_string(&$msg),
         }
         .into()
     };
@@ -103,7 +103,7 @@ macro_rules! bulk {
             )]
             fn [<bulk_ $method_name>]<P>(&self, params: &[P]) -> Result<Vec<$output>, Error>
             where
-                P: std::borrow::Borrow<$input> + SyncAlias,
+                P: ::core::borrow::Borrow<$input> + SyncAlias,
                 $output: SendAlias,
                 Self: SyncAlias,
             {
@@ -0,0 +1,173 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::ArgminFloat;
+use argmin_math::{ArgminDot, ArgminL2Norm, ArgminMul, ArgminWeightedL2Norm};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Inner product and induced norm on `P`
+///
+/// Solvers measure the size of gradients (for termination) and of steps (for trust regions) in
+/// the Euclidean norm by default. If the variables have very different scales, a metric which
+/// takes the scales into account can be provided to the solvers which support it.
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::Metric;
+///
+/// /// Maximum norm. Only the norm is used by solvers, the inner product is the Euclidean one.
+/// struct MaxNorm {}
+///
+/// impl Metric<Vec<f64>, f64> for MaxNorm {
+///     fn inner(&self, a: &Vec<f64>, b: &Vec<f64>) -> f64 {
+///         a.iter().zip(b.iter()).map(|(a, b)| a * b).sum()
+///     }
+///
+///     fn norm(&self, x: &Vec<f64>) -> f64 {
+///         x.iter().fold(0.0, |acc, x| acc.max(x.abs()))
+///     }
+/// }
+/// ```
+pub trait Metric<P, F> {
+    /// Inner product of `a` and `b`
+    fn inner(&self, a: &P, b: &P) -> F;
+
+    /// Norm of `x`
+    ///
+    /// Defaults to the norm induced by the inner product.
+    fn norm(&self, x: &P) -> F
+    where
+        F: ArgminFloat,
+    {
+        self.inner(x, x).sqrt()
+    }
+}
+
+/// # Euclidean metric
+///
+/// The standard inner product `a^T b` and the l2-norm. This is the default metric of all solvers.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::core::{EuclideanMetric, Metric};
+/// let metric = EuclideanMetric::new();
+/// assert_eq!(metric.norm(&vec![3.0f64, 4.0]), 5.0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct EuclideanMetric {}
+
+impl EuclideanMetric {
+    /// Construct a new instance of [`EuclideanMetric`]
+    pub fn new() -> Self {
+        EuclideanMetric {}
+    }
+}
+
+impl<P, F> Metric<P, F> for EuclideanMetric
+where
+    P: ArgminDot<P, F> + ArgminL2Norm<F>,
+{
+    fn inner(&self, a: &P, b: &P) -> F {
+        a.dot(b)
+    }
+
+    fn norm(&self, x: &P) -> F {
+        x.l2_norm()
+    }
+}
+
+/// # Diagonal metric
+///
+/// The weighted inner product `sum_i w_i a_i b_i` and the induced norm `sqrt(sum_i w_i x_i^2)`
+/// with positive weights `w`. Choosing `w_i = 1 / s_i^2`, where `s_i` is the typical magnitude of
+/// the i-th variable, measures all variables relative to their scale.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::core::{DiagonalMetric, Metric};
+/// let metric = DiagonalMetric::new(vec![1.0f64, 1e-6]);
+/// let norm: f64 = metric.norm(&vec![3.0, 4000.0]);
+/// assert!((norm - 5.0).abs() < 1e-12);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct DiagonalMetric<P> {
+    /// Weights
+    weights: P,
+}
+
+impl<P> DiagonalMetric<P> {
+    /// Construct a new instance of [`DiagonalMetric`] from the weights
+    pub fn new(weights: P) -> Self {
+        DiagonalMetric { weights }
+    }
+}
+
+impl<P, F> Metric<P, F> for DiagonalMetric<P>
+where
+    P: ArgminDot<P, F> + ArgminMul<P, P> + ArgminWeightedL2Norm<P, F>,
+{
+    fn inner(&self, a: &P, b: &P) -> F {
+        a.dot(&b.mul(&self.weights))
+    }
+
+    fn norm(&self, x: &P) -> F {
+        x.weighted_l2_norm(&self.weights)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(euclidean_metric, EuclideanMetric);
+    test_trait_impl!(diagonal_metric, DiagonalMetric<Vec<f64>>);
+
+    #[test]
+    fn test_euclidean_metric() {
+        let metric = EuclideanMetric::new();
+        let a = vec![1.0f64, 2.0];
+        let b = vec![3.0f64, -4.0];
+        assert_relative_eq!(metric.inner(&a, &b), -5.0, epsilon = f64::EPSILON);
+        assert_relative_eq!(metric.norm(&b), 5.0, epsilon = f64::EPSILON);
+    }
+
+    #[test]
+    fn test_diagonal_metric() {
+        let metric = DiagonalMetric::new(vec![4.0f64, 0.25]);
+        let a = vec![1.0f64, 2.0];
+        let b = vec![3.0f64, -4.0];
+        let inner: f64 = metric.inner(&a, &b);
+        assert_relative_eq!(inner, 10.0, epsilon = f64::EPSILON);
+        let norm: f64 = metric.norm(&b);
+        assert_relative_eq!(norm, 40.0f64.sqrt(), epsilon = f64::EPSILON);
+    }
+
+    #[test]
+    fn test_default_norm() {
+        struct Scaled {}
+
+        impl Metric<Vec<f64>, f64> for Scaled {
+            fn inner(&self, a: &Vec<f64>, b: &Vec<f64>) -> f64 {
+                4.0 * a.iter().zip(b.iter()).map(|(a, b)| a * b).sum::<f64>()
+            }
+        }
+
+        assert_relative_eq!(
+            Scaled {}.norm(&vec![3.0, 4.0]),
+            10.0,
+            epsilon = f64::EPSILON
+        );
+    }
+}
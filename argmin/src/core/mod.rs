@@ -13,18 +13,31 @@
 /// Macros
 #[macro_use]
 pub mod macros;
+/// Bounds on the parameter vector
+mod bounds;
 pub mod checkpointing;
+/// Linear constraints
+mod constraints;
 /// Error handling
 mod errors;
 /// Executor
 mod executor;
+/// Feasibility of the final parameter vector
+mod feasibility;
 /// Trait alias for float types
 mod float;
+#[cfg(feature = "num-dual")]
+mod forwarddiff;
 /// Key value data structure
 mod kv;
+/// Least-squares problems defined by their residuals
+mod leastsquares;
+mod metric;
 pub mod observers;
 /// Trait alias for `Send` and `Sync`
 mod parallelization;
+/// Periodic variables
+mod periodic;
 /// Traits and structs for defining and handling optimization problems
 mod problem;
 /// Definition of the return type of the solvers
@@ -33,25 +46,45 @@ mod result;
 mod serialization;
 /// `Solver` trait
 mod solver;
+/// Parametrization of symmetric positive definite matrices
+mod spd;
 /// iteration state
 mod state;
 /// Definition of termination reasons
 mod termination;
 /// Convenience utilities for testing
 pub mod test_utils;
+/// Bound handling via variable transformations
+mod transform;
 
+#[cfg(feature = "std")]
 pub use crate::solver::conjugategradient::beta::NLCGBetaUpdate;
 pub use crate::solver::linesearch::LineSearch;
+#[cfg(feature = "std")]
 pub use crate::solver::trustregion::TrustRegionRadius;
 pub use anyhow::Error;
+pub use bounds::Bounds;
+pub use constraints::LinearConstraints;
 pub use errors::ArgminError;
 pub use executor::Executor;
+pub use feasibility::FeasibilityReport;
 pub use float::ArgminFloat;
+#[cfg(feature = "num-dual")]
+pub use forwarddiff::{DualCostFunction, ForwardDiff};
 pub use kv::{KvValue, KV};
+pub use leastsquares::{LeastSquares, LeastSquaresProblem};
+pub use metric::{DiagonalMetric, EuclideanMetric, Metric};
 pub use parallelization::{SendAlias, SyncAlias};
-pub use problem::{CostFunction, Gradient, Hessian, Jacobian, LinearProgram, Operator, Problem};
+pub use periodic::{Periodic, PeriodicProblem};
+pub use problem::{
+    BoxConstraint, CostFunction, EqualityConstraint, FisherInformation, Gradient, Hessian,
+    InequalityConstraint, Jacobian, LinearProgram, MultiCostFunction, Operator, Problem,
+    QuadraticProgram,
+};
 pub use result::OptimizationResult;
 pub use serialization::{DeserializeOwnedAlias, SerializeAlias};
 pub use solver::Solver;
-pub use state::{IterState, LinearProgramState, PopulationState, State};
+pub use spd::{SpdParametrization, SpdProblem};
+pub use state::{IterState, LinearProgramState, ParetoState, PopulationState, State};
 pub use termination::{TerminationReason, TerminationStatus};
+pub use transform::{BoundTransform, TransformedProblem};
@@ -11,7 +11,7 @@
 
 use crate::core::observers::Observe;
 use crate::core::{Error, State, KV};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::default::Default;
 use std::fs::File;
 use std::io::BufWriter;
@@ -112,7 +112,7 @@ where
 /// let bincode = WriteToFileSerializer::Bincode;
 /// let json = WriteToFileSerializer::JSON;
 /// ```
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum WriteToFileSerializer {
     /// Use [`bincode`](https://crates.io/crates/bincode) for creating binary files
     Bincode,
@@ -137,8 +137,9 @@ use serde::{Deserialize, Serialize};
 pub use slog_logger::*;
 
 use crate::core::{Error, State, KV};
-use std::default::Default;
-use std::sync::{Arc, Mutex};
+use alloc::{rc::Rc, vec, vec::Vec};
+use core::cell::RefCell;
+use core::default::Default;
 
 /// An interface which every observer is required to implement
 ///
@@ -186,9 +187,19 @@ pub trait Observe<I> {
     fn observe_iter(&mut self, _state: &I, _kv: &KV) -> Result<(), Error> {
         Ok(())
     }
+
+    /// Called once after the solver has terminated
+    ///
+    /// Has access to the final `state` of the solver and to a key-value store `kv` with
+    /// information about the result, such as the
+    /// [`FeasibilityReport`](`crate::core::FeasibilityReport`) if feasibility checks were enabled
+    /// on the [`Executor`](`crate::core::Executor`).
+    fn observe_final(&mut self, _state: &I, _kv: &KV) -> Result<(), Error> {
+        Ok(())
+    }
 }
 
-type ObserversVec<I> = Vec<(Arc<Mutex<dyn Observe<I>>>, ObserverMode)>;
+type ObserversVec<I> = Vec<(Rc<RefCell<dyn Observe<I>>>, ObserverMode)>;
 
 /// Container for observers.
 ///
@@ -241,7 +252,7 @@ impl<I> Observers<I> {
         observer: OBS,
         mode: ObserverMode,
     ) -> &mut Self {
-        self.observers.push((Arc::new(Mutex::new(observer)), mode));
+        self.observers.push((Rc::new(RefCell::new(observer)), mode));
         self
     }
 
@@ -268,7 +279,7 @@ impl<I: State> Observe<I> for Observers<I> {
     /// After initialization of the solver, this loops over all stored observers and calls them.
     fn observe_init(&mut self, name: &str, kv: &KV) -> Result<(), Error> {
         for l in self.observers.iter() {
-            l.0.lock().unwrap().observe_init(name, kv)?
+            l.0.borrow_mut().observe_init(name, kv)?
         }
         Ok(())
     }
@@ -280,7 +291,7 @@ impl<I: State> Observe<I> for Observers<I> {
     fn observe_iter(&mut self, state: &I, kv: &KV) -> Result<(), Error> {
         for l in self.observers.iter_mut() {
             let iter = state.get_iter();
-            let observer = &mut l.0.lock().unwrap();
+            let observer = &mut l.0.borrow_mut();
             match l.1 {
                 ObserverMode::Always => observer.observe_iter(state, kv),
                 ObserverMode::Every(i) if iter % i == 0 => observer.observe_iter(state, kv),
@@ -290,6 +301,18 @@ impl<I: State> Observe<I> for Observers<I> {
         }
         Ok(())
     }
+
+    /// Called once after the solver has terminated.
+    ///
+    /// Loops over all observers which are not deactivated and calls them.
+    fn observe_final(&mut self, state: &I, kv: &KV) -> Result<(), Error> {
+        for l in self.observers.iter_mut() {
+            if l.1 != ObserverMode::Never {
+                l.0.borrow_mut().observe_final(state, kv)?
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Indicates when to call an observer.
@@ -327,11 +350,13 @@ mod tests {
     fn test_observers() {
         use crate::core::observers::Observe;
         use crate::core::{Error, IterState, KV};
+        use std::sync::{Arc, Mutex};
 
         struct TestStor {
             pub solver_name: String,
             pub init_called: usize,
             pub iter_called: usize,
+            pub final_called: usize,
         }
 
         impl TestStor {
@@ -340,6 +365,7 @@ mod tests {
                     solver_name: String::new(),
                     init_called: 0,
                     iter_called: 0,
+                    final_called: 0,
                 }))
             }
         }
@@ -359,6 +385,11 @@ mod tests {
                 self.data.lock().unwrap().iter_called += 1;
                 Ok(())
             }
+
+            fn observe_final(&mut self, _state: &I, _kv: &KV) -> Result<(), Error> {
+                self.data.lock().unwrap().final_called += 1;
+                Ok(())
+            }
         }
 
         let test_stor_1 = TestStor::new();
@@ -451,5 +482,13 @@ mod tests {
         assert_eq!(storages[2].lock().unwrap().iter_called, 2);
         assert_eq!(storages[3].lock().unwrap().init_called, 1);
         assert_eq!(storages[3].lock().unwrap().iter_called, 2);
+
+        obs.observe_final(&state, &kv!()).unwrap();
+
+        // all but the deactivated observer are called once after termination
+        assert_eq!(storages[0].lock().unwrap().final_called, 0);
+        for s in storages[1..].iter() {
+            assert_eq!(s.lock().unwrap().final_called, 1);
+        }
     }
 }
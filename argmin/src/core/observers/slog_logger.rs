@@ -185,6 +185,14 @@ where
         info!(self.logger, ""; LogState(state), kv);
         Ok(())
     }
+
+    /// Logs the final key-value store if it contains any entries
+    fn observe_final(&mut self, state: &I, kv: &KV) -> Result<(), Error> {
+        if !kv.kv.is_empty() {
+            info!(self.logger, "Terminated"; LogState(state), kv);
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,442 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{ArgminFloat, CostFunction, Error, Gradient, Hessian};
+use alloc::{format, vec, vec::Vec};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Element-wise periodicity of the parameter vector
+///
+/// Declares some variables as periodic with a range `[lower, upper)`, such as angles in
+/// `[-pi, pi)`, while the remaining variables are ordinary real numbers. Points which differ by
+/// multiples of `upper - lower` in a periodic variable are equivalent; [`wrap`](`Periodic::wrap`)
+/// maps every point to its representative inside the ranges and
+/// [`difference`](`Periodic::difference`) and [`distance`](`Periodic::distance`) take the shortest
+/// way around.
+///
+/// Problems can be wrapped via [`PeriodicProblem`] and [`Periodic`] implements
+/// [`Manifold`](`crate::solver::riemannian::Manifold`), such that the steps of the
+/// [Riemannian solvers](`crate::solver::riemannian`) are wrapped as well.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::core::{Error, Periodic};
+/// # use std::f64::consts::PI;
+/// # fn main() -> Result<(), Error> {
+/// // The second variable is an angle
+/// let periodic = Periodic::new(2).with_angle(1)?;
+///
+/// let x = periodic.wrap(&[5.0, 3.0 * PI / 2.0])?;
+/// # assert_eq!(x[0], 5.0);
+/// # assert!((x[1] + PI / 2.0).abs() < 1e-15);
+/// let d = periodic.distance(&[0.0, 0.9 * PI], &[0.0, -0.9 * PI])?;
+/// # assert!((d - 0.2 * PI).abs() < 1e-14);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct Periodic<F> {
+    /// Range `(lower, upper)` of every periodic variable, `None` for all others
+    ranges: Vec<Option<(F, F)>>,
+}
+
+impl<F> Periodic<F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`Periodic`] for `n` variables, none of which is periodic
+    pub fn new(n: usize) -> Self {
+        Periodic {
+            ranges: vec![None; n],
+        }
+    }
+
+    /// Declare the variable `index` as periodic with range `[lower, upper)`
+    ///
+    /// Returns an error if `index` is out of range or if `lower < upper` does not hold for finite
+    /// `lower` and `upper`.
+    pub fn with_range(mut self, index: usize, lower: F, upper: F) -> Result<Self, Error> {
+        if index >= self.ranges.len() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                format!(
+                    "`Periodic`: index must be smaller than the number of variables ({}).",
+                    self.ranges.len()
+                )
+            ));
+        }
+        if !(lower.is_finite() && upper.is_finite() && lower < upper) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Periodic`: range must be finite with lower < upper."
+            ));
+        }
+        self.ranges[index] = Some((lower, upper));
+        Ok(self)
+    }
+
+    /// Declare the variable `index` as an angle in `[-pi, pi)`
+    pub fn with_angle(self, index: usize) -> Result<Self, Error> {
+        self.with_range(index, -F::PI(), F::PI())
+    }
+
+    /// Number of variables
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    /// Returns `true` if there are no variables
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Returns `true` if the variable `index` is periodic
+    pub fn is_periodic(&self, index: usize) -> bool {
+        matches!(self.ranges.get(index), Some(Some(_)))
+    }
+
+    /// Maps every periodic variable into its range `[lower, upper)`
+    ///
+    /// All other variables are returned unchanged. Returns an error if `param` does not have one
+    /// entry per variable.
+    pub fn wrap(&self, param: &[F]) -> Result<Vec<F>, Error> {
+        self.check_len(param)?;
+        Ok(param
+            .iter()
+            .zip(self.ranges.iter())
+            .map(|(&x, range)| match range {
+                Some((lower, upper)) => wrap(x, *lower, *upper),
+                None => x,
+            })
+            .collect())
+    }
+
+    /// Shortest difference `a - b`
+    ///
+    /// Periodic variables are mapped to `[-period / 2, period / 2)`. Returns an error if `a` or
+    /// `b` does not have one entry per variable.
+    pub fn difference(&self, a: &[F], b: &[F]) -> Result<Vec<F>, Error> {
+        self.check_len(a)?;
+        self.check_len(b)?;
+        Ok(a.iter()
+            .zip(b.iter())
+            .zip(self.ranges.iter())
+            .map(|((&a, &b), range)| match range {
+                Some((lower, upper)) => {
+                    let half = (*upper - *lower) / float!(2.0);
+                    wrap(a - b, -half, half)
+                }
+                None => a - b,
+            })
+            .collect())
+    }
+
+    /// Euclidean norm of the shortest difference of `a` and `b`
+    pub fn distance(&self, a: &[F], b: &[F]) -> Result<F, Error> {
+        Ok(self
+            .difference(a, b)?
+            .iter()
+            .fold(float!(0.0), |acc, d| acc + *d * *d)
+            .sqrt())
+    }
+
+    /// Returns an error if `param` does not have one entry per variable
+    fn check_len(&self, param: &[F]) -> Result<(), Error> {
+        if param.len() != self.len() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                format!(
+                    "`Periodic`: parameter vector must have {} entries.",
+                    self.len()
+                )
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Maps `x` into `[lower, upper)`
+fn wrap<F: ArgminFloat>(x: F, lower: F, upper: F) -> F {
+    let period = upper - lower;
+    let y = x - period * ((x - lower) / period).floor();
+    // Guard against rounding onto (or slightly beyond) the excluded upper end
+    if y >= upper || y < lower {
+        lower
+    } else {
+        y
+    }
+}
+
+/// # Problems with periodic variables
+///
+/// Wraps a problem whose parameter vector contains periodic variables (see [`Periodic`]). The
+/// cost function, gradient and Hessian of the wrapped problem are always evaluated at the
+/// representative inside the ranges, therefore the wrapped problem only needs to handle
+/// parameters within the ranges. Since wrapping is a translation by multiples of the period, the
+/// gradient and Hessian are unchanged.
+///
+/// Solvers which are not aware of the periodicity may return parameters outside of the ranges;
+/// these are mapped to their representatives with [`wrap`](`PeriodicProblem::wrap`).
+///
+/// # Example
+///
+/// ```
+/// # use argmin::core::{CostFunction, Error, Periodic, PeriodicProblem};
+/// # struct UserDefinedProblem {};
+/// #
+/// # impl CostFunction for UserDefinedProblem {
+/// #     type Param = Vec<f64>;
+/// #     type Output = f64;
+/// #
+/// #     fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+/// #         Ok(1.0 - p[0].cos())
+/// #     }
+/// # }
+/// # fn main() -> Result<(), Error> {
+/// let problem = PeriodicProblem::new(UserDefinedProblem {}, Periodic::new(1).with_angle(0)?);
+///
+/// // Run a solver on `problem`, then map the best parameter vector into the ranges
+/// let param = problem.wrap(&[7.0])?;
+/// # assert!((param[0] - (7.0 - 2.0 * std::f64::consts::PI)).abs() < 1e-15);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct PeriodicProblem<O, F> {
+    /// Wrapped problem
+    problem: O,
+    /// Periodic variables
+    periodic: Periodic<F>,
+}
+
+impl<O, F> PeriodicProblem<O, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`PeriodicProblem`] from a problem and its periodic variables
+    pub fn new(problem: O, periodic: Periodic<F>) -> Self {
+        PeriodicProblem { problem, periodic }
+    }
+
+    /// Maps every periodic variable into its range, see [`Periodic::wrap`]
+    pub fn wrap(&self, param: &[F]) -> Result<Vec<F>, Error> {
+        self.periodic.wrap(param)
+    }
+
+    /// Returns the periodic variables
+    pub fn periodic(&self) -> &Periodic<F> {
+        &self.periodic
+    }
+
+    /// Returns the wrapped problem
+    pub fn into_inner(self) -> O {
+        self.problem
+    }
+}
+
+impl<O, F> CostFunction for PeriodicProblem<O, F>
+where
+    O: CostFunction<Param = Vec<F>, Output = F>,
+    F: ArgminFloat,
+{
+    type Param = Vec<F>;
+    type Output = F;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        self.problem.cost(&self.wrap(param)?)
+    }
+}
+
+impl<O, F> Gradient for PeriodicProblem<O, F>
+where
+    O: Gradient<Param = Vec<F>, Gradient = Vec<F>>,
+    F: ArgminFloat,
+{
+    type Param = Vec<F>;
+    type Gradient = Vec<F>;
+
+    fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+        self.problem.gradient(&self.wrap(param)?)
+    }
+}
+
+impl<O, F> Hessian for PeriodicProblem<O, F>
+where
+    O: Hessian<Param = Vec<F>>,
+    F: ArgminFloat,
+{
+    type Param = Vec<F>;
+    type Hessian = O::Hessian;
+
+    fn hessian(&self, param: &Self::Param) -> Result<Self::Hessian, Error> {
+        self.problem.hessian(&self.wrap(param)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::test_utils::TestProblem;
+    use crate::core::ArgminError;
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+    use core::f64::consts::PI;
+
+    test_trait_impl!(periodic, Periodic<f64>);
+    test_trait_impl!(periodic_problem, PeriodicProblem<TestProblem, f64>);
+
+    /// `(x - 1)^2 + 1 - cos(theta - 3)`, minimized at `x = 1` and `theta = 3 + 2 k pi`
+    #[derive(Clone)]
+    struct Pendulum {}
+
+    impl CostFunction for Pendulum {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            assert!((-PI..PI).contains(&p[1]));
+            Ok((p[0] - 1.0).powi(2) + 1.0 - (p[1] - 3.0).cos())
+        }
+    }
+
+    impl Gradient for Pendulum {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            assert!((-PI..PI).contains(&p[1]));
+            Ok(vec![2.0 * (p[0] - 1.0), (p[1] - 3.0).sin()])
+        }
+    }
+
+    #[test]
+    fn test_wrap() {
+        let periodic: Periodic<f64> = Periodic::new(3)
+            .with_angle(1)
+            .unwrap()
+            .with_range(2, 0.0, 1.0)
+            .unwrap();
+        assert_eq!(periodic.len(), 3);
+        assert!(!periodic.is_periodic(0));
+        assert!(periodic.is_periodic(1));
+        assert!(!periodic.is_periodic(3));
+        let x = periodic.wrap(&[10.0, 7.0, -0.25]).unwrap();
+        assert_eq!(x[0].to_ne_bytes(), 10.0f64.to_ne_bytes());
+        assert_relative_eq!(x[1], 7.0 - 2.0 * PI, epsilon = 1e-15);
+        assert_relative_eq!(x[2], 0.75, epsilon = 1e-15);
+        // The upper end of a range is mapped to the lower end
+        assert_eq!(periodic.wrap(&[0.0, PI, 1.0]).unwrap()[1..], [-PI, 0.0]);
+    }
+
+    #[test]
+    fn test_distance() {
+        let periodic = Periodic::new(2).with_angle(1).unwrap();
+        let d = periodic.difference(&[1.0, 3.0], &[-1.0, -3.0]).unwrap();
+        assert_relative_eq!(d[0], 2.0, epsilon = 1e-15);
+        assert_relative_eq!(d[1], 6.0 - 2.0 * PI, epsilon = 1e-15);
+        assert_relative_eq!(
+            periodic
+                .distance(&[0.0, 3.0], &[0.0, 3.0 + 4.0 * PI])
+                .unwrap(),
+            0.0,
+            epsilon = 1e-14
+        );
+        assert_relative_eq!(
+            periodic
+                .distance(&[4.0, 0.5], &[0.0, 0.5 - 3.0 - 6.0 * PI])
+                .unwrap(),
+            5.0,
+            epsilon = 1e-13
+        );
+    }
+
+    #[test]
+    fn test_length_mismatch() {
+        let periodic: Periodic<f64> = Periodic::new(2).with_angle(1).unwrap();
+        let msg = "Invalid parameter: \"`Periodic`: parameter vector must have 2 entries.\"";
+        assert_error!(periodic.wrap(&[0.0, 1.0, 7.0]), ArgminError, msg);
+        assert_error!(periodic.wrap(&[7.0]), ArgminError, msg);
+        assert_error!(periodic.difference(&[0.0], &[0.0, 1.0]), ArgminError, msg);
+        assert_error!(periodic.difference(&[0.0, 1.0], &[0.0]), ArgminError, msg);
+        assert_error!(
+            periodic.distance(&[0.0, 1.0, 2.0], &[0.0, 1.0]),
+            ArgminError,
+            msg
+        );
+        let problem = PeriodicProblem::new(Pendulum {}, periodic);
+        assert_error!(problem.cost(&vec![0.0]), ArgminError, msg);
+    }
+
+    #[test]
+    fn test_invalid_range() {
+        assert_error!(
+            Periodic::new(2).with_range(2, 0.0, 1.0),
+            ArgminError,
+            "Invalid parameter: \"`Periodic`: index must be smaller than the number of variables (2).\""
+        );
+        for (lower, upper) in [
+            (1.0, 1.0),
+            (1.0, 0.0),
+            (0.0, f64::INFINITY),
+            (f64::NAN, 1.0),
+        ] {
+            assert_error!(
+                Periodic::new(2).with_range(0, lower, upper),
+                ArgminError,
+                "Invalid parameter: \"`Periodic`: range must be finite with lower < upper.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_periodic_problem() {
+        let problem = PeriodicProblem::new(Pendulum {}, Periodic::new(2).with_angle(1).unwrap());
+        let p = vec![0.5, 3.0 + 20.0 * PI];
+        assert_relative_eq!(problem.cost(&p).unwrap(), 0.25, epsilon = 1e-12);
+        let grad = problem.gradient(&p).unwrap();
+        assert_relative_eq!(grad[0], -1.0, epsilon = 1e-15);
+        assert_relative_eq!(grad[1], 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_solve() {
+        use crate::core::{Executor, State};
+        use crate::solver::linesearch::MoreThuenteLineSearch;
+        use crate::solver::quasinewton::LBFGS;
+        let problem = PeriodicProblem::new(Pendulum {}, Periodic::new(2).with_angle(1).unwrap());
+        let solver = LBFGS::new(MoreThuenteLineSearch::new(), 5);
+        let res = Executor::new(problem.clone(), solver)
+            .configure(|state| state.param(vec![0.0, -3.0]).max_iters(100))
+            .run()
+            .unwrap();
+        let x = problem.wrap(res.state().get_best_param().unwrap()).unwrap();
+        assert_relative_eq!(x[0], 1.0, epsilon = 1e-6);
+        assert_relative_eq!(x[1], 3.0, epsilon = 1e-6);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_solve_riemannian() {
+        use crate::core::{Executor, State};
+        use crate::solver::riemannian::RiemannianGradientDescent;
+        // The steps are wrapped by the solver, therefore `Pendulum` is only evaluated within the
+        // range and the best parameter vector lies within the range as well
+        let periodic = Periodic::new(2).with_angle(1).unwrap();
+        let res = Executor::new(Pendulum {}, RiemannianGradientDescent::new(periodic))
+            .configure(|state| state.param(vec![0.0, -3.0]).max_iters(1000))
+            .run()
+            .unwrap();
+        let x = res.state().get_best_param().unwrap();
+        assert_relative_eq!(x[0], 1.0, epsilon = 1e-6);
+        assert_relative_eq!(x[1], 3.0, epsilon = 1e-6);
+    }
+}
@@ -5,10 +5,10 @@
 // http://opensource.org/licenses/MIT>, at your option. This file may not be
 // copied, modified, or distributed except according to those terms.
 
-use crate::core::{ArgminFloat, Error, SendAlias, SyncAlias};
+use crate::core::{ArgminFloat, Bounds, Error, SendAlias, SyncAlias};
+use alloc::{collections::BTreeMap, vec, vec::Vec};
 #[cfg(feature = "rayon")]
 use rayon::prelude::*;
-use std::collections::HashMap;
 
 /// Wrapper around problems defined by users.
 ///
@@ -20,7 +20,7 @@ pub struct Problem<O> {
     /// Problem defined by user
     pub problem: Option<O>,
     /// Keeps track of how often methods of `problem` have been called.
-    pub counts: HashMap<&'static str, u64>,
+    pub counts: BTreeMap<&'static str, u64>,
 }
 
 impl<O> Problem<O> {
@@ -30,7 +30,7 @@ impl<O> Problem<O> {
     ///
     /// ```
     /// # use argmin::core::Problem;
-    /// # use std::collections::HashMap;
+    /// # use std::collections::BTreeMap;
     /// #
     /// # #[derive(Eq, PartialEq, Debug)]
     /// # struct UserDefinedProblem {};
@@ -38,12 +38,12 @@ impl<O> Problem<O> {
     /// let wrapped_problem = Problem::new(UserDefinedProblem {});
     /// #
     /// # assert_eq!(wrapped_problem.problem.unwrap(), UserDefinedProblem {});
-    /// # assert_eq!(wrapped_problem.counts, HashMap::new());
+    /// # assert_eq!(wrapped_problem.counts, BTreeMap::new());
     /// ```
     pub fn new(problem: O) -> Self {
         Problem {
             problem: Some(problem),
-            counts: HashMap::new(),
+            counts: BTreeMap::new(),
         }
     }
 
@@ -71,11 +71,11 @@ impl<O> Problem<O> {
     /// # // possible to `impl` a type from another crate.
     /// # // Probably somewhat related to https://github.com/rust-lang/rust/issues/50784
     /// # use argmin::core::Error;
-    /// # use std::collections::HashMap;
+    /// # use std::collections::BTreeMap;
     /// #
     /// # pub struct Problem<O> {
     /// #     pub problem: Option<O>,
-    /// #     pub counts: HashMap<&'static str, u64>,
+    /// #     pub counts: BTreeMap<&'static str, u64>,
     /// # }
     /// # impl<O> Problem<O> {
     /// #     pub fn problem<T, F: FnOnce(&O) -> Result<T, Error>>(
@@ -114,7 +114,7 @@ impl<O> Problem<O> {
     /// #         Ok(())
     /// #     }
     /// # }
-    /// # let mut problem = Problem { problem: Some(TestProblem {}), counts: HashMap::new() };
+    /// # let mut problem = Problem { problem: Some(TestProblem {}), counts: BTreeMap::new() };
     /// # let param = ();
     /// let new_param = problem.anneal(&param, 1.0f64);
     /// # assert_eq!(problem.counts["anneal_count"], 1)
@@ -157,7 +157,7 @@ impl<O> Problem<O> {
     ///
     /// ```
     /// # use argmin::core::Problem;
-    /// # use std::collections::HashMap;
+    /// # use std::collections::BTreeMap;
     /// #
     /// # #[derive(Eq, PartialEq, Debug)]
     /// # struct UserDefinedProblem {};
@@ -167,7 +167,7 @@ impl<O> Problem<O> {
     ///
     /// assert_eq!(user_problem.unwrap(), UserDefinedProblem {});
     /// assert!(problem.problem.is_none());
-    /// # assert_eq!(problem.counts, HashMap::new());
+    /// # assert_eq!(problem.counts, BTreeMap::new());
     /// ```
     pub fn take_problem(&mut self) -> Option<O> {
         self.problem.take()
@@ -383,6 +383,11 @@ pub trait CostFunction {
 
 /// Defines the computation of the gradient.
 ///
+/// For a real-valued cost function of a complex parameter vector `z = x + iy`, the gradient must
+/// be returned in conjugate Wirtinger form `2 df/dconj(z) = df/dx + i df/dy`. This is the
+/// gradient with respect to the real inner product `Re(sum_i conj(a_i) b_i)` which solvers use
+/// for complex vectors (see the documentation of `argmin-math`).
+///
 /// # Example
 ///
 /// ```
@@ -478,7 +483,205 @@ pub trait Jacobian {
     bulk!(jacobian, Self::Param, Self::Jacobian);
 }
 
-/// Defines a linear Program
+/// Defines the computation of the Fisher information matrix (or any other Riemannian metric) of
+/// a statistical model, as used by the natural gradient method.
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::{FisherInformation, Error};
+///
+/// /// Normal distribution parameterized by mean and standard deviation
+/// struct Normal {}
+///
+/// impl FisherInformation for Normal {
+///     type Param = Vec<f64>;
+///     type FisherInformation = Vec<Vec<f64>>;
+///
+///     fn fisher_information(&self, param: &Self::Param) -> Result<Self::FisherInformation, Error> {
+///         let var = param[1].powi(2);
+///         Ok(vec![vec![1.0 / var, 0.0], vec![0.0, 2.0 / var]])
+///     }
+/// }
+/// ```
+pub trait FisherInformation {
+    /// Type of the parameter vector
+    type Param;
+    /// Type of the Fisher information matrix
+    type FisherInformation;
+
+    /// Compute Fisher information matrix
+    fn fisher_information(&self, param: &Self::Param) -> Result<Self::FisherInformation, Error>;
+}
+
+/// Defines the computation of a vector of cost function values for multi-objective optimization.
+///
+/// All objectives are minimized.
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::{MultiCostFunction, Error};
+///
+/// /// Schaffer function N. 1
+/// struct Schaffer {}
+///
+/// impl MultiCostFunction for Schaffer {
+///     type Param = Vec<f64>;
+///     type Float = f64;
+///
+///     fn multi_cost(&self, param: &Self::Param) -> Result<Vec<Self::Float>, Error> {
+///         Ok(vec![param[0].powi(2), (param[0] - 2.0).powi(2)])
+///     }
+/// }
+/// ```
+pub trait MultiCostFunction {
+    /// Type of the parameter vector
+    type Param;
+    /// Precision of floats
+    type Float;
+
+    /// Compute the values of all objectives
+    fn multi_cost(&self, param: &Self::Param) -> Result<Vec<Self::Float>, Error>;
+
+    bulk!(multi_cost, Self::Param, Vec<Self::Float>);
+}
+
+/// Defines inequality constraints of the form `g_i(x) <= 0`.
+///
+/// The constraint values are returned as a `Vec` with one entry per constraint. The Jacobian of
+/// the constraints is returned row-wise, i.e. as one gradient per constraint. Solvers which do not
+/// need derivatives of the constraints only require
+/// [`inequality_constraints`](`InequalityConstraint::inequality_constraints`) to be implemented.
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::{InequalityConstraint, Error};
+///
+/// struct UnitDisk {}
+///
+/// impl InequalityConstraint for UnitDisk {
+///     type Param = Vec<f64>;
+///     type Gradient = Vec<f64>;
+///     type Float = f64;
+///
+///     /// x^2 + y^2 - 1 <= 0
+///     fn inequality_constraints(&self, p: &Self::Param) -> Result<Vec<f64>, Error> {
+///         Ok(vec![p[0].powi(2) + p[1].powi(2) - 1.0])
+///     }
+///
+///     fn inequality_jacobian(&self, p: &Self::Param) -> Result<Vec<Vec<f64>>, Error> {
+///         Ok(vec![vec![2.0 * p[0], 2.0 * p[1]]])
+///     }
+/// }
+/// ```
+pub trait InequalityConstraint {
+    /// Type of the parameter vector
+    type Param;
+    /// Type of the gradient of a single constraint
+    type Gradient;
+    /// Precision of floats
+    type Float;
+
+    /// Compute the values `g_i(x)` of all inequality constraints
+    fn inequality_constraints(&self, param: &Self::Param) -> Result<Vec<Self::Float>, Error>;
+
+    /// Compute the gradients of all inequality constraints
+    fn inequality_jacobian(&self, _param: &Self::Param) -> Result<Vec<Self::Gradient>, Error> {
+        Err(argmin_error!(
+            NotImplemented,
+            "Method `inequality_jacobian` of InequalityConstraint trait not implemented!"
+        ))
+    }
+}
+
+/// Defines equality constraints of the form `h_j(x) = 0`.
+///
+/// The counterpart of [`InequalityConstraint`]: constraint values are returned as a `Vec` with one
+/// entry per constraint and the Jacobian is returned row-wise, i.e. as one gradient per
+/// constraint. Both traits can be implemented on the same problem, which allows constrained
+/// solvers to handle mixed constraints via a common interface.
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::{EqualityConstraint, Error};
+///
+/// struct UnitCircle {}
+///
+/// impl EqualityConstraint for UnitCircle {
+///     type Param = Vec<f64>;
+///     type Gradient = Vec<f64>;
+///     type Float = f64;
+///
+///     /// x^2 + y^2 - 1 = 0
+///     fn equality_constraints(&self, p: &Self::Param) -> Result<Vec<f64>, Error> {
+///         Ok(vec![p[0].powi(2) + p[1].powi(2) - 1.0])
+///     }
+///
+///     fn equality_jacobian(&self, p: &Self::Param) -> Result<Vec<Vec<f64>>, Error> {
+///         Ok(vec![vec![2.0 * p[0], 2.0 * p[1]]])
+///     }
+/// }
+/// ```
+pub trait EqualityConstraint {
+    /// Type of the parameter vector
+    type Param;
+    /// Type of the gradient of a single constraint
+    type Gradient;
+    /// Precision of floats
+    type Float;
+
+    /// Compute the values `h_j(x)` of all equality constraints
+    fn equality_constraints(&self, param: &Self::Param) -> Result<Vec<Self::Float>, Error>;
+
+    /// Compute the gradients of all equality constraints
+    fn equality_jacobian(&self, _param: &Self::Param) -> Result<Vec<Self::Gradient>, Error> {
+        Err(argmin_error!(
+            NotImplemented,
+            "Method `equality_jacobian` of EqualityConstraint trait not implemented!"
+        ))
+    }
+}
+
+/// Defines element-wise lower and upper bounds on the parameter vector
+///
+/// Solvers which support simple bounds, such as
+/// [`ParticleSwarm`](`crate::solver::particleswarm::ParticleSwarm`),
+/// [`RandomSearch`](`crate::solver::randomsearch::RandomSearch`) and
+/// [`LatinHypercube`](`crate::solver::randomsearch::LatinHypercube`), take them as [`Bounds`] on
+/// construction, which can be obtained from a problem via this trait. A bound-constrained problem
+/// can also be mapped to an unconstrained one via
+/// [`TransformedProblem::from_problem`](`crate::core::TransformedProblem::from_problem`).
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::{BoxConstraint, Bounds, Error};
+///
+/// struct Problem {}
+///
+/// impl BoxConstraint for Problem {
+///     type Param = Vec<f64>;
+///
+///     /// 0 <= x <= 1, y unbounded from below
+///     fn bounds(&self) -> Result<Bounds<Self::Param>, Error> {
+///         Bounds::new(vec![0.0, f64::NEG_INFINITY], vec![1.0, 2.0])
+///     }
+/// }
+/// ```
+pub trait BoxConstraint {
+    /// Type of the parameter vector
+    type Param;
+
+    /// Returns the bounds on the parameter vector
+    fn bounds(&self) -> Result<Bounds<Self::Param>, Error>;
+}
+
+/// Defines a linear program in standard form
+///
+/// `min_x c^T x` subject to `A x = b` and `x >= 0`
 ///
 /// # Example
 ///
@@ -510,8 +713,7 @@ pub trait LinearProgram {
     /// Precision of floats
     type Float: ArgminFloat;
 
-    /// TODO c for linear programs
-    /// Those three could maybe be merged into a single function; name unclear
+    /// Coefficients `c` of the objective function
     fn c(&self) -> Result<Vec<Self::Float>, Error> {
         Err(argmin_error!(
             NotImplemented,
@@ -519,7 +721,7 @@ pub trait LinearProgram {
         ))
     }
 
-    /// TODO b for linear programs
+    /// Right-hand side `b` of the equality constraints
     fn b(&self) -> Result<Vec<Self::Float>, Error> {
         Err(argmin_error!(
             NotImplemented,
@@ -527,7 +729,7 @@ pub trait LinearProgram {
         ))
     }
 
-    /// TODO A for linear programs
+    /// Matrix `A` of the equality constraints, given as a vector of rows
     #[allow(non_snake_case)]
     fn A(&self) -> Result<Vec<Vec<Self::Float>>, Error> {
         Err(argmin_error!(
@@ -537,6 +739,85 @@ pub trait LinearProgram {
     }
 }
 
+/// Defines a quadratic program
+///
+/// `min_x 1/2 x^T Q x + c^T x` subject to `A_eq x = b_eq` and `A_ineq x <= b_ineq`
+///
+/// Matrices are given as vectors of rows. Without constraints, `A_eq`, `b_eq`, `A_ineq` and
+/// `b_ineq` are empty, which is the default.
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::{QuadraticProgram, Error};
+///
+/// struct Problem {}
+///
+/// impl QuadraticProgram for Problem {
+///     type Param = Vec<f64>;
+///     type Float = f64;
+///
+///     fn Q(&self) -> Result<Vec<Vec<Self::Float>>, Error> {
+///         Ok(vec![vec![2.0, 0.0], vec![0.0, 2.0]])
+///     }
+///
+///     fn c(&self) -> Result<Vec<Self::Float>, Error> {
+///         Ok(vec![-2.0, -5.0])
+///     }
+///
+///     fn A_ineq(&self) -> Result<Vec<Vec<Self::Float>>, Error> {
+///         Ok(vec![vec![1.0, 2.0]])
+///     }
+///
+///     fn b_ineq(&self) -> Result<Vec<Self::Float>, Error> {
+///         Ok(vec![6.0])
+///     }
+/// }
+/// ```
+#[allow(non_snake_case)]
+pub trait QuadraticProgram {
+    /// Type of the parameter vector
+    type Param;
+    /// Precision of floats
+    type Float: ArgminFloat;
+
+    /// Symmetric matrix `Q` of the quadratic term
+    fn Q(&self) -> Result<Vec<Vec<Self::Float>>, Error> {
+        Err(argmin_error!(
+            NotImplemented,
+            "Method `Q` of QuadraticProgram trait not implemented!"
+        ))
+    }
+
+    /// Coefficients `c` of the linear term
+    fn c(&self) -> Result<Vec<Self::Float>, Error> {
+        Err(argmin_error!(
+            NotImplemented,
+            "Method `c` of QuadraticProgram trait not implemented!"
+        ))
+    }
+
+    /// Matrix `A_eq` of the equality constraints
+    fn A_eq(&self) -> Result<Vec<Vec<Self::Float>>, Error> {
+        Ok(vec![])
+    }
+
+    /// Right-hand side `b_eq` of the equality constraints
+    fn b_eq(&self) -> Result<Vec<Self::Float>, Error> {
+        Ok(vec![])
+    }
+
+    /// Matrix `A_ineq` of the inequality constraints
+    fn A_ineq(&self) -> Result<Vec<Vec<Self::Float>>, Error> {
+        Ok(vec![])
+    }
+
+    /// Right-hand side `b_ineq` of the inequality constraints
+    fn b_ineq(&self) -> Result<Vec<Self::Float>, Error> {
+        Ok(vec![])
+    }
+}
+
 /// Wraps a call to `apply` defined in the `Operator` trait and as such allows to call `apply` on
 /// an instance of `Problem`. Internally, the number of evaluations of `apply` is counted.
 impl<O: Operator> Problem<O> {
@@ -607,7 +888,7 @@ impl<O: Operator> Problem<O> {
     /// ```
     pub fn bulk_apply<P>(&mut self, params: &Vec<P>) -> Result<Vec<O::Output>, Error>
     where
-        P: std::borrow::Borrow<O::Param> + SyncAlias,
+        P: core::borrow::Borrow<O::Param> + SyncAlias,
         O::Output: SendAlias,
         O: SyncAlias,
     {
@@ -688,7 +969,7 @@ impl<O: CostFunction> Problem<O> {
     /// ```
     pub fn bulk_cost<P>(&mut self, params: &Vec<P>) -> Result<Vec<O::Output>, Error>
     where
-        P: std::borrow::Borrow<O::Param> + SyncAlias,
+        P: core::borrow::Borrow<O::Param> + SyncAlias,
         O::Output: SendAlias,
         O: SyncAlias,
     {
@@ -769,7 +1050,7 @@ impl<O: Gradient> Problem<O> {
     /// ```
     pub fn bulk_gradient<P>(&mut self, params: &Vec<P>) -> Result<Vec<O::Gradient>, Error>
     where
-        P: std::borrow::Borrow<O::Param> + SyncAlias,
+        P: core::borrow::Borrow<O::Param> + SyncAlias,
         O::Gradient: SendAlias,
         O: SyncAlias,
     {
@@ -849,7 +1130,7 @@ impl<O: Hessian> Problem<O> {
     /// ```
     pub fn bulk_hessian<P>(&mut self, params: &Vec<P>) -> Result<Vec<O::Hessian>, Error>
     where
-        P: std::borrow::Borrow<O::Param> + SyncAlias,
+        P: core::borrow::Borrow<O::Param> + SyncAlias,
         O::Hessian: SendAlias,
         O: SyncAlias,
     {
@@ -928,7 +1209,7 @@ impl<O: Jacobian> Problem<O> {
     /// ```
     pub fn bulk_jacobian<P>(&mut self, params: &Vec<P>) -> Result<Vec<O::Jacobian>, Error>
     where
-        P: std::borrow::Borrow<O::Param> + SyncAlias,
+        P: core::borrow::Borrow<O::Param> + SyncAlias,
         O::Jacobian: SendAlias,
         O: SyncAlias,
     {
@@ -938,6 +1219,322 @@ impl<O: Jacobian> Problem<O> {
     }
 }
 
+/// Wraps calls to `fisher_information` defined in the `FisherInformation` trait and as such allows
+/// to call this method on an instance of `Problem`. Internally, the number of evaluations is
+/// counted.
+impl<O: FisherInformation> Problem<O> {
+    /// Calls `fisher_information` defined in the `FisherInformation` trait and keeps track of the
+    /// number of evaluations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Problem, FisherInformation, Error};
+    /// #
+    /// # #[derive(Eq, PartialEq, Debug, Clone)]
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl FisherInformation for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type FisherInformation = Vec<Vec<f64>>;
+    /// #
+    /// #     fn fisher_information(&self, param: &Self::Param) -> Result<Self::FisherInformation, Error> {
+    /// #         Ok(vec![vec![1.0f64, 0.0f64], vec![0.0f64, 1.0f64]])
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `FisherInformation`.
+    /// let mut problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let param = vec![2.0f64, 1.0f64];
+    ///
+    /// let res = problem1.fisher_information(&param);
+    ///
+    /// assert_eq!(problem1.counts["fisher_information_count"], 1);
+    /// # assert_eq!(res.unwrap(), vec![vec![1.0f64, 0.0f64], vec![0.0f64, 1.0f64]]);
+    /// ```
+    pub fn fisher_information(&mut self, param: &O::Param) -> Result<O::FisherInformation, Error> {
+        self.problem("fisher_information_count", |problem| {
+            problem.fisher_information(param)
+        })
+    }
+}
+
+/// Wraps a call to `multi_cost` defined in the `MultiCostFunction` trait and as such allows to
+/// call `multi_cost` on an instance of `Problem`. Internally, the number of evaluations of
+/// `multi_cost` is counted.
+impl<O: MultiCostFunction> Problem<O> {
+    /// Calls `multi_cost` defined in the `MultiCostFunction` trait and keeps track of the number
+    /// of evaluations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Problem, MultiCostFunction, Error};
+    /// #
+    /// # #[derive(Eq, PartialEq, Debug, Clone)]
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl MultiCostFunction for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type Float = f64;
+    /// #
+    /// #     fn multi_cost(&self, param: &Self::Param) -> Result<Vec<Self::Float>, Error> {
+    /// #         Ok(vec![1.0f64, 2.0f64])
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `MultiCostFunction`.
+    /// let mut problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let param = vec![2.0f64, 1.0f64];
+    ///
+    /// let res = problem1.multi_cost(&param);
+    ///
+    /// assert_eq!(problem1.counts["multi_cost_count"], 1);
+    /// # assert_eq!(res.unwrap(), vec![1.0f64, 2.0f64]);
+    /// ```
+    pub fn multi_cost(&mut self, param: &O::Param) -> Result<Vec<O::Float>, Error> {
+        self.problem("multi_cost_count", |problem| problem.multi_cost(param))
+    }
+
+    /// Calls `bulk_multi_cost` defined in the `MultiCostFunction` trait and keeps track of the
+    /// number of evaluations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Problem, MultiCostFunction, Error};
+    /// #
+    /// # #[derive(Eq, PartialEq, Debug, Clone)]
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl MultiCostFunction for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type Float = f64;
+    /// #
+    /// #     fn multi_cost(&self, param: &Self::Param) -> Result<Vec<Self::Float>, Error> {
+    /// #         Ok(vec![1.0f64, 2.0f64])
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `MultiCostFunction`.
+    /// let mut problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let param1 = vec![2.0f64, 1.0f64];
+    /// let param2 = vec![3.0f64, 5.0f64];
+    /// let params = vec![&param1, &param2];
+    ///
+    /// let res = problem1.bulk_multi_cost(&params);
+    ///
+    /// assert_eq!(problem1.counts["multi_cost_count"], 2);
+    /// # let res = res.unwrap();
+    /// # assert_eq!(res[0], vec![1.0f64, 2.0f64]);
+    /// # assert_eq!(res[1], vec![1.0f64, 2.0f64]);
+    /// ```
+    pub fn bulk_multi_cost<P>(&mut self, params: &[P]) -> Result<Vec<Vec<O::Float>>, Error>
+    where
+        P: core::borrow::Borrow<O::Param> + SyncAlias,
+        O::Float: SendAlias,
+        O: SyncAlias,
+    {
+        self.bulk_problem("multi_cost_count", params.len(), |problem| {
+            problem.bulk_multi_cost(params)
+        })
+    }
+}
+
+/// Wraps calls to `inequality_constraints` and `inequality_jacobian` defined in the
+/// `InequalityConstraint` trait and as such allows to call those methods on an instance of
+/// `Problem`. Internally, the number of evaluations is counted.
+impl<O: InequalityConstraint> Problem<O> {
+    /// Calls `inequality_constraints` defined in the `InequalityConstraint` trait and keeps track
+    /// of the number of evaluations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Problem, InequalityConstraint, Error};
+    /// #
+    /// # #[derive(Eq, PartialEq, Debug, Clone)]
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl InequalityConstraint for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type Gradient = Vec<f64>;
+    /// #     type Float = f64;
+    /// #
+    /// #     fn inequality_constraints(&self, param: &Self::Param) -> Result<Vec<f64>, Error> {
+    /// #         Ok(vec![param[0] - 1.0])
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `InequalityConstraint`.
+    /// let mut problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let param = vec![2.0f64, 1.0f64];
+    ///
+    /// let res = problem1.inequality_constraints(&param);
+    ///
+    /// assert_eq!(problem1.counts["inequality_count"], 1);
+    /// # assert_eq!(res.unwrap(), vec![1.0f64]);
+    /// ```
+    pub fn inequality_constraints(&mut self, param: &O::Param) -> Result<Vec<O::Float>, Error> {
+        self.problem("inequality_count", |problem| {
+            problem.inequality_constraints(param)
+        })
+    }
+
+    /// Calls `inequality_jacobian` defined in the `InequalityConstraint` trait and keeps track of
+    /// the number of evaluations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Problem, InequalityConstraint, Error};
+    /// #
+    /// # #[derive(Eq, PartialEq, Debug, Clone)]
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl InequalityConstraint for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type Gradient = Vec<f64>;
+    /// #     type Float = f64;
+    /// #
+    /// #     fn inequality_constraints(&self, param: &Self::Param) -> Result<Vec<f64>, Error> {
+    /// #         Ok(vec![param[0] - 1.0])
+    /// #     }
+    /// #
+    /// #     fn inequality_jacobian(&self, param: &Self::Param) -> Result<Vec<Vec<f64>>, Error> {
+    /// #         Ok(vec![vec![1.0, 0.0]])
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `InequalityConstraint`.
+    /// let mut problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let param = vec![2.0f64, 1.0f64];
+    ///
+    /// let res = problem1.inequality_jacobian(&param);
+    ///
+    /// assert_eq!(problem1.counts["inequality_jacobian_count"], 1);
+    /// # assert_eq!(res.unwrap(), vec![vec![1.0f64, 0.0]]);
+    /// ```
+    pub fn inequality_jacobian(&mut self, param: &O::Param) -> Result<Vec<O::Gradient>, Error> {
+        self.problem("inequality_jacobian_count", |problem| {
+            problem.inequality_jacobian(param)
+        })
+    }
+}
+
+/// Wraps calls to `equality_constraints` and `equality_jacobian` defined in the
+/// `EqualityConstraint` trait and as such allows to call those methods on an instance of
+/// `Problem`. Internally, the number of evaluations is counted.
+impl<O: EqualityConstraint> Problem<O> {
+    /// Calls `equality_constraints` defined in the `EqualityConstraint` trait and keeps track of
+    /// the number of evaluations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Problem, EqualityConstraint, Error};
+    /// #
+    /// # #[derive(Eq, PartialEq, Debug, Clone)]
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl EqualityConstraint for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type Gradient = Vec<f64>;
+    /// #     type Float = f64;
+    /// #
+    /// #     fn equality_constraints(&self, param: &Self::Param) -> Result<Vec<f64>, Error> {
+    /// #         Ok(vec![param[0] - 1.0])
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `EqualityConstraint`.
+    /// let mut problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let param = vec![2.0f64, 1.0f64];
+    ///
+    /// let res = problem1.equality_constraints(&param);
+    ///
+    /// assert_eq!(problem1.counts["equality_count"], 1);
+    /// # assert_eq!(res.unwrap(), vec![1.0f64]);
+    /// ```
+    pub fn equality_constraints(&mut self, param: &O::Param) -> Result<Vec<O::Float>, Error> {
+        self.problem("equality_count", |problem| {
+            problem.equality_constraints(param)
+        })
+    }
+
+    /// Calls `equality_jacobian` defined in the `EqualityConstraint` trait and keeps track of the
+    /// number of evaluations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Problem, EqualityConstraint, Error};
+    /// #
+    /// # #[derive(Eq, PartialEq, Debug, Clone)]
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl EqualityConstraint for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type Gradient = Vec<f64>;
+    /// #     type Float = f64;
+    /// #
+    /// #     fn equality_constraints(&self, param: &Self::Param) -> Result<Vec<f64>, Error> {
+    /// #         Ok(vec![param[0] - 1.0])
+    /// #     }
+    /// #
+    /// #     fn equality_jacobian(&self, param: &Self::Param) -> Result<Vec<Vec<f64>>, Error> {
+    /// #         Ok(vec![vec![1.0, 0.0]])
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `EqualityConstraint`.
+    /// let mut problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let param = vec![2.0f64, 1.0f64];
+    ///
+    /// let res = problem1.equality_jacobian(&param);
+    ///
+    /// assert_eq!(problem1.counts["equality_jacobian_count"], 1);
+    /// # assert_eq!(res.unwrap(), vec![vec![1.0f64, 0.0]]);
+    /// ```
+    pub fn equality_jacobian(&mut self, param: &O::Param) -> Result<Vec<O::Gradient>, Error> {
+        self.problem("equality_jacobian_count", |problem| {
+            problem.equality_jacobian(param)
+        })
+    }
+}
+
+/// Wraps calls to `bounds` defined in the `BoxConstraint` trait and as such allows to call this
+/// method on an instance of `Problem`.
+impl<O: BoxConstraint> Problem<O> {
+    /// Calls `bounds` defined in the `BoxConstraint` trait.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Bounds, BoxConstraint, Problem, Error};
+    /// #
+    /// # #[derive(Eq, PartialEq, Debug, Clone)]
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl BoxConstraint for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #
+    /// #     fn bounds(&self) -> Result<Bounds<Self::Param>, Error> {
+    /// #         Bounds::new(vec![0.0, 0.0], vec![1.0, 1.0])
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `BoxConstraint`.
+    /// let problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let bounds = problem1.bounds();
+    ///
+    /// # assert_eq!(bounds.unwrap().upper(), &vec![1.0, 1.0]);
+    /// ```
+    pub fn bounds(&self) -> Result<Bounds<O::Param>, Error> {
+        self.problem.as_ref().unwrap().bounds()
+    }
+}
+
 /// Wraps a calls to `c`, `b` and `A` defined in the `LinearProgram` trait and as such allows to
 /// call those methods on an instance of `Problem`.
 impl<O: LinearProgram> Problem<O> {
@@ -5,24 +5,47 @@
 // http://opensource.org/licenses/MIT>, at your option. This file may not be
 // copied, modified, or distributed except according to those terms.
 
-use crate::core::{ArgminFloat, Problem, Solver, State};
+use crate::core::{ArgminFloat, FeasibilityReport, Problem, Solver, State};
+use alloc::{format, string::String};
+use core::cmp::Ordering;
+use core::fmt;
 use num_traits::{Float, FromPrimitive};
-use std::cmp::Ordering;
-use std::fmt;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
 
 /// Result of an optimization returned by after running an `Executor`.
 ///
 /// Consists of the problem and the final state of the solver.
 /// Both can be accessed via deconstructing or via the methods
 /// [`problem`](`OptimizationResult::problem`) and [`state`](`OptimizationResult::state`).
+///
+/// With the `serde1` feature, the result can be serialized (for instance to JSON or bincode) if
+/// solver and state are serializable. The problem is not serialized and is empty after
+/// deserialization; the function evaluation counts are part of the state. Note that JSON cannot
+/// represent infinite values, which states usually contain (for instance the default target cost),
+/// and therefore cannot be deserialized again; a binary format such as bincode round-trips.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
 pub struct OptimizationResult<O, S, I> {
     /// Problem
+    #[cfg_attr(feature = "serde1", serde(skip, default = "empty_problem"))]
     pub problem: Problem<O>,
     /// Solver
     pub solver: S,
     /// Iteration state
     pub state: I,
+    /// Constraint violations of the final parameter vector (only available if feasibility
+    /// checks were enabled on the `Executor`)
+    pub feasibility: Option<FeasibilityReport>,
+}
+
+/// Problem of a deserialized `OptimizationResult`
+#[cfg(feature = "serde1")]
+fn empty_problem<O>() -> Problem<O> {
+    Problem {
+        problem: None,
+        counts: Default::default(),
+    }
 }
 
 impl<O, S, I> OptimizationResult<O, S, I> {
@@ -43,7 +66,7 @@ impl<O, S, I> OptimizationResult<O, S, I> {
     /// let solver = SomeSolver {};
     ///
     /// let result = OptimizationResult::new(Problem::new(rosenbrock), solver, state);
-    /// # let OptimizationResult { mut problem, solver, state } = result;
+    /// # let OptimizationResult { mut problem, solver, state, .. } = result;
     /// # assert_eq!(problem.take_problem().unwrap(), TestProblem::new());
     /// # assert_eq!(solver, SomeSolver {});
     /// ```
@@ -52,6 +75,7 @@ impl<O, S, I> OptimizationResult<O, S, I> {
             problem,
             solver,
             state,
+            feasibility: None,
         }
     }
 
@@ -114,15 +138,40 @@ impl<O, S, I> OptimizationResult<O, S, I> {
     pub fn state(&self) -> &I {
         &self.state
     }
+
+    /// Returns a reference to the feasibility report.
+    ///
+    /// The report is only available if feasibility checks were enabled on the
+    /// [`Executor`](`crate::core::Executor`) (see for instance
+    /// [`Executor::check_bounds`](`crate::core::Executor::check_bounds`)).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Problem, OptimizationResult, IterState, State};
+    /// #
+    /// # struct Rosenbrock {}
+    /// # let solver = ();
+    /// #
+    /// # let state: IterState<Vec<f64>, (), (), (), f64> = IterState::new();
+    /// #
+    /// # let result = OptimizationResult::new(Problem::new(Rosenbrock {}), solver, state);
+    /// #
+    /// let feasibility = result.feasibility();
+    /// # assert!(feasibility.is_none());
+    /// ```
+    pub fn feasibility(&self) -> Option<&FeasibilityReport> {
+        self.feasibility.as_ref()
+    }
 }
 
-impl<O, S, I> std::fmt::Display for OptimizationResult<O, S, I>
+impl<O, S, I> core::fmt::Display for OptimizationResult<O, S, I>
 where
     I: State,
     I::Param: fmt::Debug,
     S: Solver<O, I>,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         writeln!(f, "OptimizationResult:")?;
         writeln!(f, "    Solver:        {}", S::NAME)?;
         writeln!(
@@ -145,6 +194,9 @@ where
         if let Some(time) = self.state.get_time() {
             writeln!(f, "    time:          {time:?}")?;
         }
+        if let Some(feasibility) = self.feasibility.as_ref() {
+            writeln!(f, "    max violation: {}", feasibility.max_violation())?;
+        }
         Ok(())
     }
 }
@@ -203,4 +255,42 @@ mod tests {
     );
 
     // TODO: More tests, in particular the checking that the output is as intended.
+
+    #[test]
+    #[cfg(feature = "serde1")]
+    fn test_serde() {
+        use crate::core::{Executor, State};
+        use crate::solver::neldermead::NelderMead;
+
+        type Result = OptimizationResult<
+            TestProblem,
+            NelderMead<Vec<f64>, f64>,
+            IterState<Vec<f64>, (), (), (), f64>,
+        >;
+
+        let solver = NelderMead::new(vec![vec![1.0, 2.0], vec![2.0, 2.0], vec![1.0, 3.0]]);
+        let result: Result = Executor::new(TestProblem::new(), solver)
+            .configure(|state| state.max_iters(3))
+            .run()
+            .unwrap();
+
+        let json: serde_json::Value = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["state"]["iter"], result.state.get_iter());
+        assert_eq!(
+            json["state"]["counts"]["cost_count"],
+            result.state.get_func_counts()["cost_count"]
+        );
+        assert!(json.get("problem").is_none());
+
+        let bytes = bincode::serialize(&result).unwrap();
+        let deserialized: Result = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(deserialized.state, result.state);
+        assert_eq!(
+            deserialized.state.get_func_counts(),
+            result.state.get_func_counts()
+        );
+        assert_eq!(deserialized.feasibility, result.feasibility);
+        assert!(deserialized.problem.problem.is_none());
+        assert!(deserialized.problem.counts.is_empty());
+    }
 }
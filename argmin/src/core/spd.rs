@@ -0,0 +1,398 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{ArgminFloat, CostFunction, Error, Gradient};
+use alloc::{format, vec, vec::Vec};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Parametrization of the Cholesky factor `L` of a symmetric positive definite matrix `S = L L^T`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub enum SpdParametrization {
+    /// The entries of the lower triangle of `L` are used as variables
+    ///
+    /// `S` is positive definite as long as no diagonal entry of `L` is zero.
+    Cholesky,
+    /// Like `Cholesky`, but the diagonal entries of `L` are replaced by their logarithms
+    ///
+    /// Every parameter vector corresponds to a positive definite `S`.
+    LogCholesky,
+}
+
+impl Default for SpdParametrization {
+    /// The default for `SpdParametrization` is `LogCholesky`
+    fn default() -> SpdParametrization {
+        SpdParametrization::LogCholesky
+    }
+}
+
+/// # Optimization over symmetric positive definite matrices
+///
+/// Wraps a problem whose parameter is a symmetric positive definite (SPD) `n x n` matrix `S`
+/// (stored as `Vec<Vec<F>>` of rows) and turns it into an unconstrained problem in the
+/// `n (n + 1) / 2` entries of the lower triangle of the Cholesky factor `L` of `S = L L^T`, stored
+/// row by row (`L_00, L_10, L_11, L_20, ...`). With [`SpdParametrization::LogCholesky`] (the
+/// default) the logarithms of the diagonal entries of `L` are used instead of the diagonal
+/// entries themselves. This is commonly needed for covariance estimation.
+///
+/// [`CostFunction`] is implemented if the wrapped problem implements [`CostFunction`] and
+/// [`Gradient`] is implemented if the wrapped problem implements [`Gradient`]. The gradient of
+/// the wrapped problem is taken with respect to all `n^2` entries of `S` and transformed via the
+/// chain rule, `dL = (G + G^T) L`. Initial guesses have to be mapped to the unconstrained
+/// variables with [`to_unconstrained`](`SpdProblem::to_unconstrained`) and results mapped back
+/// with [`to_spd`](`SpdProblem::to_spd`).
+///
+/// # Example
+///
+/// ```
+/// # use argmin::core::{CostFunction, Error, SpdProblem};
+/// # struct UserDefinedProblem {};
+/// #
+/// # impl CostFunction for UserDefinedProblem {
+/// #     type Param = Vec<Vec<f64>>;
+/// #     type Output = f64;
+/// #
+/// #     fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+/// #         Ok(p[0][0] + p[1][1])
+/// #     }
+/// # }
+/// # fn main() -> Result<(), Error> {
+/// let problem = SpdProblem::new(UserDefinedProblem {}, 2);
+///
+/// // Map initial guess to unconstrained variables
+/// let init_param = problem.to_unconstrained(&vec![vec![1.0, 0.0], vec![0.0, 1.0]])?;
+/// # assert_eq!(init_param, vec![0.0, 0.0, 0.0]);
+///
+/// // Run an unconstrained solver on `problem`, then map the result back
+/// let param = problem.to_spd(&init_param);
+/// # assert_eq!(param, vec![vec![1.0, 0.0], vec![0.0, 1.0]]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct SpdProblem<O> {
+    /// Wrapped problem
+    problem: O,
+    /// Number of rows and columns of the matrix
+    dim: usize,
+    /// Parametrization of the Cholesky factor
+    parametrization: SpdParametrization,
+}
+
+impl<O> SpdProblem<O> {
+    /// Construct a new instance of [`SpdProblem`] from a problem and the dimension `n` of its
+    /// `n x n` matrix parameter
+    pub fn new(problem: O, dim: usize) -> Self {
+        SpdProblem {
+            problem,
+            dim,
+            parametrization: SpdParametrization::default(),
+        }
+    }
+
+    /// Set the parametrization of the Cholesky factor
+    ///
+    /// Defaults to [`SpdParametrization::LogCholesky`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{SpdParametrization, SpdProblem};
+    /// # struct UserDefinedProblem {};
+    /// let problem = SpdProblem::new(UserDefinedProblem {}, 3)
+    ///     .with_parametrization(SpdParametrization::Cholesky);
+    /// ```
+    #[must_use]
+    pub fn with_parametrization(mut self, parametrization: SpdParametrization) -> Self {
+        self.parametrization = parametrization;
+        self
+    }
+
+    /// Number of unconstrained variables, `n (n + 1) / 2`
+    pub fn num_params(&self) -> usize {
+        self.dim * (self.dim + 1) / 2
+    }
+
+    /// Returns the wrapped problem
+    pub fn into_inner(self) -> O {
+        self.problem
+    }
+
+    /// Maps the unconstrained variables to the Cholesky factor `L`
+    pub fn to_factor<F: ArgminFloat>(&self, param: &[F]) -> Vec<Vec<F>> {
+        let mut factor = vec![vec![float!(0.0); self.dim]; self.dim];
+        let mut params = param.iter();
+        for (i, row) in factor.iter_mut().enumerate() {
+            for (j, entry) in row.iter_mut().take(i + 1).enumerate() {
+                let p = *params.next().unwrap();
+                *entry = match self.parametrization {
+                    SpdParametrization::LogCholesky if i == j => p.exp(),
+                    _ => p,
+                };
+            }
+        }
+        factor
+    }
+
+    /// Maps the unconstrained variables to the matrix `S = L L^T`
+    pub fn to_spd<F: ArgminFloat>(&self, param: &[F]) -> Vec<Vec<F>> {
+        let factor = self.to_factor(param);
+        let mut spd = vec![vec![float!(0.0); self.dim]; self.dim];
+        for i in 0..self.dim {
+            for j in 0..=i {
+                let s = (0..=j).fold(float!(0.0), |acc, k| acc + factor[i][k] * factor[j][k]);
+                spd[i][j] = s;
+                spd[j][i] = s;
+            }
+        }
+        spd
+    }
+
+    /// Maps a symmetric positive definite matrix to the unconstrained variables
+    ///
+    /// Only the lower triangle of `spd` is read. The Cholesky factor is the one with positive
+    /// diagonal entries.
+    pub fn to_unconstrained<F: ArgminFloat>(&self, spd: &[Vec<F>]) -> Result<Vec<F>, Error> {
+        if spd.len() != self.dim || spd.iter().any(|row| row.len() != self.dim) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                format!(
+                    "`SpdProblem`: Parameter must be a {} x {} matrix.",
+                    self.dim, self.dim
+                )
+            ));
+        }
+        let mut factor = vec![vec![float!(0.0); self.dim]; self.dim];
+        let mut param = Vec::with_capacity(self.num_params());
+        for i in 0..self.dim {
+            for j in 0..=i {
+                let s = (0..j).fold(spd[i][j], |acc, k| acc - factor[i][k] * factor[j][k]);
+                factor[i][j] = if i == j {
+                    if s.is_nan() || s <= float!(0.0) {
+                        return Err(argmin_error!(
+                            InvalidParameter,
+                            "`SpdProblem`: Parameter must be positive definite."
+                        ));
+                    }
+                    s.sqrt()
+                } else {
+                    s / factor[j][j]
+                };
+                param.push(match self.parametrization {
+                    SpdParametrization::LogCholesky if i == j => factor[i][j].ln(),
+                    _ => factor[i][j],
+                });
+            }
+        }
+        Ok(param)
+    }
+}
+
+impl<O, F> CostFunction for SpdProblem<O>
+where
+    O: CostFunction<Param = Vec<Vec<F>>, Output = F>,
+    F: ArgminFloat,
+{
+    type Param = Vec<F>;
+    type Output = F;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        self.problem.cost(&self.to_spd(param))
+    }
+}
+
+impl<O, F> Gradient for SpdProblem<O>
+where
+    O: Gradient<Param = Vec<Vec<F>>, Gradient = Vec<Vec<F>>>,
+    F: ArgminFloat,
+{
+    type Param = Vec<F>;
+    type Gradient = Vec<F>;
+
+    fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+        let factor = self.to_factor(param);
+        let grad = self.problem.gradient(&self.to_spd(param))?;
+        let mut out = Vec::with_capacity(self.num_params());
+        for i in 0..self.dim {
+            for j in 0..=i {
+                // ((G + G^T) L)_ij
+                let d = (j..self.dim).fold(float!(0.0), |acc, k| {
+                    acc + (grad[i][k] + grad[k][i]) * factor[k][j]
+                });
+                out.push(match self.parametrization {
+                    SpdParametrization::LogCholesky if i == j => d * factor[i][i],
+                    _ => d,
+                });
+            }
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::test_utils::TestProblem;
+    use crate::core::ArgminError;
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(spd_problem, SpdProblem<TestProblem>);
+    test_trait_impl!(spd_parametrization, SpdParametrization);
+
+    /// Negative log-likelihood `ln det S + tr(S^-1 C)` of a zero mean Gaussian with covariance `S`
+    /// given the `2 x 2` sample covariance `C`, minimized by `S = C`
+    #[derive(Clone)]
+    struct Likelihood {
+        sample: [[f64; 2]; 2],
+    }
+
+    fn inverse(s: &[Vec<f64>]) -> [[f64; 2]; 2] {
+        let det = s[0][0] * s[1][1] - s[0][1] * s[1][0];
+        [
+            [s[1][1] / det, -s[0][1] / det],
+            [-s[1][0] / det, s[0][0] / det],
+        ]
+    }
+
+    impl CostFunction for Likelihood {
+        type Param = Vec<Vec<f64>>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            let inv = inverse(p);
+            let det = p[0][0] * p[1][1] - p[0][1] * p[1][0];
+            let trace: f64 = (0..2)
+                .flat_map(|i| (0..2).map(move |j| (i, j)))
+                .map(|(i, j)| inv[i][j] * self.sample[j][i])
+                .sum();
+            Ok(det.ln() + trace)
+        }
+    }
+
+    impl Gradient for Likelihood {
+        type Param = Vec<Vec<f64>>;
+        type Gradient = Vec<Vec<f64>>;
+
+        /// `S^-T - S^-T C^T S^-T`
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            let inv = inverse(p);
+            Ok((0..2)
+                .map(|i| {
+                    (0..2)
+                        .map(|j| {
+                            let sics: f64 = (0..2)
+                                .flat_map(|k| (0..2).map(move |l| (k, l)))
+                                .map(|(k, l)| inv[k][i] * self.sample[l][k] * inv[j][l])
+                                .sum();
+                            inv[j][i] - sics
+                        })
+                        .collect()
+                })
+                .collect())
+        }
+    }
+
+    fn likelihood() -> Likelihood {
+        Likelihood {
+            sample: [[2.0, 0.6], [0.6, 0.5]],
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let spd: Vec<Vec<f64>> = vec![
+            vec![4.0, 2.0, -1.0],
+            vec![2.0, 5.0, 0.5],
+            vec![-1.0, 0.5, 3.0],
+        ];
+        for parametrization in [
+            SpdParametrization::Cholesky,
+            SpdParametrization::LogCholesky,
+        ] {
+            let problem =
+                SpdProblem::new(TestProblem::new(), 3).with_parametrization(parametrization);
+            let param = problem.to_unconstrained(&spd).unwrap();
+            assert_eq!(param.len(), problem.num_params());
+            let factor = problem.to_factor(&param);
+            assert_relative_eq!(factor[0][0], 2.0, epsilon = 1e-15);
+            assert_relative_eq!(factor[1][0], 1.0, epsilon = 1e-15);
+            assert_eq!(factor[0][1].to_ne_bytes(), 0.0f64.to_ne_bytes());
+            let spd2 = problem.to_spd(&param);
+            for (a, b) in spd.iter().flatten().zip(spd2.iter().flatten()) {
+                assert_relative_eq!(a, b, epsilon = 1e-14);
+            }
+        }
+        // Arbitrary log-Cholesky variables give positive definite matrices
+        let problem = SpdProblem::new(TestProblem::new(), 2);
+        let spd = problem.to_spd(&[-2.0, 3.0, -2.0]);
+        assert!(problem.to_unconstrained(&spd).is_ok());
+    }
+
+    #[test]
+    fn test_to_unconstrained_invalid() {
+        let problem = SpdProblem::new(TestProblem::new(), 2);
+        assert_error!(
+            problem.to_unconstrained(&[vec![1.0, 2.0], vec![2.0, 1.0]]),
+            ArgminError,
+            "Invalid parameter: \"`SpdProblem`: Parameter must be positive definite.\""
+        );
+        assert_error!(
+            problem.to_unconstrained(&[vec![1.0, 0.0]]),
+            ArgminError,
+            "Invalid parameter: \"`SpdProblem`: Parameter must be a 2 x 2 matrix.\""
+        );
+    }
+
+    #[test]
+    fn test_gradient() {
+        let y = vec![0.3, -0.2, 0.1];
+        for parametrization in [
+            SpdParametrization::Cholesky,
+            SpdParametrization::LogCholesky,
+        ] {
+            let problem = SpdProblem::new(likelihood(), 2).with_parametrization(parametrization);
+            let grad = problem.gradient(&y).unwrap();
+            // Compare with central differences
+            for i in 0..y.len() {
+                let mut yp = y.clone();
+                let mut ym = y.clone();
+                yp[i] += 1e-6;
+                ym[i] -= 1e-6;
+                let fd = (problem.cost(&yp).unwrap() - problem.cost(&ym).unwrap()) / 2e-6;
+                assert_relative_eq!(grad[i], fd, epsilon = 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_solve() {
+        use crate::core::{Executor, State};
+        use crate::solver::linesearch::MoreThuenteLineSearch;
+        use crate::solver::quasinewton::LBFGS;
+        let problem = SpdProblem::new(likelihood(), 2);
+        let init_param = problem
+            .to_unconstrained(&[vec![1.0, 0.0], vec![0.0, 1.0]])
+            .unwrap();
+        let solver = LBFGS::new(MoreThuenteLineSearch::new(), 5);
+        let res = Executor::new(problem.clone(), solver)
+            .configure(|state| state.param(init_param).max_iters(200))
+            .run()
+            .unwrap();
+        let spd = problem.to_spd(res.state().get_best_param().unwrap());
+        for (a, b) in spd
+            .iter()
+            .flatten()
+            .zip(likelihood().sample.iter().flatten())
+        {
+            assert_relative_eq!(a, b, epsilon = 1e-6);
+        }
+    }
+}
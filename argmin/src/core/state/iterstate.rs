@@ -6,10 +6,12 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::core::{ArgminFloat, Problem, State, TerminationReason, TerminationStatus};
-use instant;
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
 /// Maintains the state from iteration to iteration of a solver
 ///
@@ -77,9 +79,9 @@ pub struct IterState<P, G, J, H, F> {
     /// Maximum number of iterations
     pub max_iters: u64,
     /// Evaluation counts
-    pub counts: HashMap<String, u64>,
+    pub counts: BTreeMap<String, u64>,
     /// Time required so far
-    pub time: Option<instant::Duration>,
+    pub time: Option<core::time::Duration>,
     /// Status of optimization execution
     pub termination_status: TerminationStatus,
 }
@@ -111,7 +113,7 @@ where
     /// ```
     #[must_use]
     pub fn param(mut self, param: P) -> Self {
-        std::mem::swap(&mut self.prev_param, &mut self.param);
+        core::mem::swap(&mut self.prev_param, &mut self.param);
         self.param = Some(param);
         self
     }
@@ -137,7 +139,7 @@ where
     /// ```
     #[must_use]
     pub fn gradient(mut self, gradient: G) -> Self {
-        std::mem::swap(&mut self.prev_grad, &mut self.grad);
+        core::mem::swap(&mut self.prev_grad, &mut self.grad);
         self.grad = Some(gradient);
         self
     }
@@ -163,7 +165,7 @@ where
     /// ```
     #[must_use]
     pub fn hessian(mut self, hessian: H) -> Self {
-        std::mem::swap(&mut self.prev_hessian, &mut self.hessian);
+        core::mem::swap(&mut self.prev_hessian, &mut self.hessian);
         self.hessian = Some(hessian);
         self
     }
@@ -189,7 +191,7 @@ where
     /// ```
     #[must_use]
     pub fn inv_hessian(mut self, inv_hessian: H) -> Self {
-        std::mem::swap(&mut self.prev_inv_hessian, &mut self.inv_hessian);
+        core::mem::swap(&mut self.prev_inv_hessian, &mut self.inv_hessian);
         self.inv_hessian = Some(inv_hessian);
         self
     }
@@ -215,7 +217,7 @@ where
     /// ```
     #[must_use]
     pub fn jacobian(mut self, jacobian: J) -> Self {
-        std::mem::swap(&mut self.prev_jacobian, &mut self.jacobian);
+        core::mem::swap(&mut self.prev_jacobian, &mut self.jacobian);
         self.jacobian = Some(jacobian);
         self
     }
@@ -239,7 +241,7 @@ where
     /// ```
     #[must_use]
     pub fn cost(mut self, cost: F) -> Self {
-        std::mem::swap(&mut self.prev_cost, &mut self.cost);
+        core::mem::swap(&mut self.prev_cost, &mut self.cost);
         self.cost = cost;
         self
     }
@@ -877,8 +879,6 @@ where
     /// # Example
     ///
     /// ```
-    /// # extern crate instant;
-    /// # use instant;
     /// # use argmin::core::{IterState, State, ArgminFloat, TerminationStatus};
     /// let state: IterState<Vec<f64>, Vec<f64>, Vec<Vec<f64>>, Vec<Vec<f64>>, f64> = IterState::new();
     /// # assert!(state.param.is_none());
@@ -902,7 +902,7 @@ where
     /// # assert_eq!(state.last_best_iter, 0);
     /// # assert_eq!(state.max_iters, std::u64::MAX);
     /// # assert_eq!(state.counts.len(), 0);
-    /// # assert_eq!(state.time.unwrap(), instant::Duration::new(0, 0));
+    /// # assert_eq!(state.time.unwrap(), std::time::Duration::new(0, 0));
     /// # assert_eq!(state.termination_status, TerminationStatus::NotTerminated);
     /// ```
     fn new() -> Self {
@@ -926,9 +926,9 @@ where
             prev_jacobian: None,
             iter: 0,
             last_best_iter: 0,
-            max_iters: std::u64::MAX,
-            counts: HashMap::new(),
-            time: Some(instant::Duration::new(0, 0)),
+            max_iters: core::u64::MAX,
+            counts: BTreeMap::new(),
+            time: Some(core::time::Duration::new(0, 0)),
             termination_status: TerminationStatus::NotTerminated,
         }
     }
@@ -990,10 +990,10 @@ where
         {
             // If there is no parameter vector, then also don't set the best param.
             if let Some(param) = self.param.as_ref().cloned() {
-                std::mem::swap(&mut self.prev_best_param, &mut self.best_param);
+                core::mem::swap(&mut self.prev_best_param, &mut self.best_param);
                 self.best_param = Some(param);
             }
-            std::mem::swap(&mut self.prev_best_cost, &mut self.best_cost);
+            core::mem::swap(&mut self.prev_best_cost, &mut self.best_cost);
             self.best_cost = self.cost;
             self.last_best_iter = self.iter;
         }
@@ -1058,14 +1058,12 @@ where
     /// # Example
     ///
     /// ```
-    /// # extern crate instant;
-    /// # use instant;
     /// # use argmin::core::{IterState, State, ArgminFloat, TerminationReason};
     /// # let mut state: IterState<Vec<f64>, (), (), (), f64> = IterState::new();
-    /// let state = state.time(Some(instant::Duration::new(0, 12)));
-    /// # assert_eq!(state.time.unwrap(), instant::Duration::new(0, 12));
+    /// let state = state.time(Some(std::time::Duration::new(0, 12)));
+    /// # assert_eq!(state.time.unwrap(), std::time::Duration::new(0, 12));
     /// ```
-    fn time(&mut self, time: Option<instant::Duration>) -> &mut Self {
+    fn time(&mut self, time: Option<core::time::Duration>) -> &mut Self {
         self.time = time;
         self
     }
@@ -1196,14 +1194,12 @@ where
     /// # Example
     ///
     /// ```
-    /// # extern crate instant;
-    /// # use instant;
     /// # use argmin::core::{IterState, State, ArgminFloat};
     /// # let mut state: IterState<Vec<f64>, (), (), (), f64> = IterState::new();
     /// let time = state.get_time();
-    /// # assert_eq!(time.unwrap(), instant::Duration::new(0, 0));
+    /// # assert_eq!(time.unwrap(), std::time::Duration::new(0, 0));
     /// ```
-    fn get_time(&self) -> Option<instant::Duration> {
+    fn get_time(&self) -> Option<core::time::Duration> {
         self.time
     }
 
@@ -1225,10 +1221,10 @@ where
     /// Set all function evaluation counts to the evaluation counts of another `Problem`.
     ///
     /// ```
-    /// # use std::collections::HashMap;
+    /// # use std::collections::BTreeMap;
     /// # use argmin::core::{Problem, IterState, State, ArgminFloat};
     /// # let mut state: IterState<Vec<f64>, (), (), (), f64> = IterState::new();
-    /// # assert_eq!(state.counts, HashMap::new());
+    /// # assert_eq!(state.counts, BTreeMap::new());
     /// # state.counts.insert("test2".to_string(), 10u64);
     /// #
     /// # #[derive(Eq, PartialEq, Debug)]
@@ -1238,7 +1234,7 @@ where
     /// # problem.counts.insert("test1", 10u64);
     /// # problem.counts.insert("test2", 2);
     /// state.func_counts(&problem);
-    /// # let mut hm = HashMap::new();
+    /// # let mut hm = BTreeMap::new();
     /// # hm.insert("test1".to_string(), 10u64);
     /// # hm.insert("test2".to_string(), 2u64);
     /// # assert_eq!(state.counts, hm);
@@ -1255,17 +1251,17 @@ where
     /// # Example
     ///
     /// ```
-    /// # use std::collections::HashMap;
+    /// # use std::collections::BTreeMap;
     /// # use argmin::core::{IterState, State, ArgminFloat};
     /// # let mut state: IterState<Vec<f64>, (), (), (), f64> = IterState::new();
-    /// # assert_eq!(state.counts, HashMap::new());
+    /// # assert_eq!(state.counts, BTreeMap::new());
     /// # state.counts.insert("test2".to_string(), 10u64);
     /// let counts = state.get_func_counts();
-    /// # let mut hm = HashMap::new();
+    /// # let mut hm = BTreeMap::new();
     /// # hm.insert("test2".to_string(), 10u64);
     /// # assert_eq!(*counts, hm);
     /// ```
-    fn get_func_counts(&self) -> &HashMap<String, u64> {
+    fn get_func_counts(&self) -> &BTreeMap<String, u64> {
         &self.counts
     }
 
@@ -6,10 +6,12 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::core::{ArgminFloat, Problem, State, TerminationReason, TerminationStatus};
-use instant;
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
 /// Maintains the state from iteration to iteration of a solver
 ///
@@ -56,9 +58,9 @@ pub struct LinearProgramState<P, F> {
     /// Maximum number of iterations
     pub max_iters: u64,
     /// Evaluation counts
-    pub counts: HashMap<String, u64>,
+    pub counts: BTreeMap<String, u64>,
     /// Time required so far
-    pub time: Option<instant::Duration>,
+    pub time: Option<core::time::Duration>,
     /// Status of optimization execution
     pub termination_status: TerminationStatus,
 }
@@ -86,7 +88,7 @@ impl<P, F> LinearProgramState<P, F> {
     /// ```
     #[must_use]
     pub fn param(mut self, param: P) -> Self {
-        std::mem::swap(&mut self.prev_param, &mut self.param);
+        core::mem::swap(&mut self.prev_param, &mut self.param);
         self.param = Some(param);
         self
     }
@@ -147,7 +149,7 @@ impl<P, F> LinearProgramState<P, F> {
     /// ```
     #[must_use]
     pub fn cost(mut self, cost: F) -> Self {
-        std::mem::swap(&mut self.prev_cost, &mut self.cost);
+        core::mem::swap(&mut self.prev_cost, &mut self.cost);
         self.cost = cost;
         self
     }
@@ -168,9 +170,7 @@ where
     /// # Example
     ///
     /// ```
-    /// # extern crate instant;
-    /// # use instant;
-    /// # use std::collections::HashMap;
+    /// # use std::collections::BTreeMap;
     /// # use argmin::core::TerminationStatus;
     /// use argmin::core::{LinearProgramState, State};
     /// let state: LinearProgramState<Vec<f64>, f64> = LinearProgramState::new();
@@ -187,8 +187,8 @@ where
     /// # assert_eq!(state.iter, 0);
     /// # assert_eq!(state.last_best_iter, 0);
     /// # assert_eq!(state.max_iters, std::u64::MAX);
-    /// # assert_eq!(state.counts, HashMap::new());
-    /// # assert_eq!(state.time.unwrap(), instant::Duration::new(0, 0));
+    /// # assert_eq!(state.counts, BTreeMap::new());
+    /// # assert_eq!(state.time.unwrap(), std::time::Duration::new(0, 0));
     /// # assert_eq!(state.termination_status, TerminationStatus::NotTerminated);
     /// ```
     fn new() -> Self {
@@ -204,9 +204,9 @@ where
             target_cost: Self::Float::neg_infinity(),
             iter: 0,
             last_best_iter: 0,
-            max_iters: std::u64::MAX,
-            counts: HashMap::new(),
-            time: Some(instant::Duration::new(0, 0)),
+            max_iters: core::u64::MAX,
+            counts: BTreeMap::new(),
+            time: Some(core::time::Duration::new(0, 0)),
             termination_status: TerminationStatus::NotTerminated,
         }
     }
@@ -268,9 +268,9 @@ where
         {
             let param = (*self.param.as_ref().unwrap()).clone();
             let cost = self.cost;
-            std::mem::swap(&mut self.prev_best_param, &mut self.best_param);
+            core::mem::swap(&mut self.prev_best_param, &mut self.best_param);
             self.best_param = Some(param);
-            std::mem::swap(&mut self.prev_best_cost, &mut self.best_cost);
+            core::mem::swap(&mut self.prev_best_cost, &mut self.best_cost);
             self.best_cost = cost;
             self.last_best_iter = self.iter;
         }
@@ -335,14 +335,12 @@ where
     /// # Example
     ///
     /// ```
-    /// # extern crate instant;
-    /// # use instant;
     /// # use argmin::core::{LinearProgramState, State, ArgminFloat, TerminationReason};
     /// # let mut state: LinearProgramState<Vec<f64>, f64> = LinearProgramState::new();
-    /// let state = state.time(Some(instant::Duration::new(0, 12)));
-    /// # assert_eq!(state.time.unwrap(), instant::Duration::new(0, 12));
+    /// let state = state.time(Some(std::time::Duration::new(0, 12)));
+    /// # assert_eq!(state.time.unwrap(), std::time::Duration::new(0, 12));
     /// ```
-    fn time(&mut self, time: Option<instant::Duration>) -> &mut Self {
+    fn time(&mut self, time: Option<core::time::Duration>) -> &mut Self {
         self.time = time;
         self
     }
@@ -473,14 +471,12 @@ where
     /// # Example
     ///
     /// ```
-    /// # extern crate instant;
-    /// # use instant;
     /// # use argmin::core::{LinearProgramState, State, ArgminFloat};
     /// # let mut state: LinearProgramState<Vec<f64>, f64> = LinearProgramState::new();
     /// let time = state.get_time();
-    /// # assert_eq!(time.unwrap(), instant::Duration::new(0, 0));
+    /// # assert_eq!(time.unwrap(), std::time::Duration::new(0, 0));
     /// ```
-    fn get_time(&self) -> Option<instant::Duration> {
+    fn get_time(&self) -> Option<core::time::Duration> {
         self.time
     }
 
@@ -502,10 +498,10 @@ where
     /// Set all function evaluation counts to the evaluation counts of another `Problem`.
     ///
     /// ```
-    /// # use std::collections::HashMap;
+    /// # use std::collections::BTreeMap;
     /// # use argmin::core::{Problem, LinearProgramState, State, ArgminFloat};
     /// # let mut state: LinearProgramState<Vec<f64>, f64> = LinearProgramState::new();
-    /// # assert_eq!(state.counts, HashMap::new());
+    /// # assert_eq!(state.counts, BTreeMap::new());
     /// # state.counts.insert("test2".to_string(), 10u64);
     /// #
     /// # #[derive(Eq, PartialEq, Debug)]
@@ -515,7 +511,7 @@ where
     /// # problem.counts.insert("test1", 10u64);
     /// # problem.counts.insert("test2", 2);
     /// state.func_counts(&problem);
-    /// # let mut hm = HashMap::new();
+    /// # let mut hm = BTreeMap::new();
     /// # hm.insert("test1".to_string(), 10u64);
     /// # hm.insert("test2".to_string(), 2u64);
     /// # assert_eq!(state.counts, hm);
@@ -532,17 +528,17 @@ where
     /// # Example
     ///
     /// ```
-    /// # use std::collections::HashMap;
+    /// # use std::collections::BTreeMap;
     /// # use argmin::core::{LinearProgramState, State, ArgminFloat};
     /// # let mut state: LinearProgramState<Vec<f64>, f64> = LinearProgramState::new();
-    /// # assert_eq!(state.counts, HashMap::new());
+    /// # assert_eq!(state.counts, BTreeMap::new());
     /// # state.counts.insert("test2".to_string(), 10u64);
     /// let counts = state.get_func_counts();
-    /// # let mut hm = HashMap::new();
+    /// # let mut hm = BTreeMap::new();
     /// # hm.insert("test2".to_string(), 10u64);
     /// # assert_eq!(*counts, hm);
     /// ```
-    fn get_func_counts(&self) -> &HashMap<String, u64> {
+    fn get_func_counts(&self) -> &BTreeMap<String, u64> {
         &self.counts
     }
 
@@ -7,14 +7,16 @@
 
 pub mod iterstate;
 pub mod linearprogramstate;
+pub mod paretostate;
 pub mod populationstate;
 
 pub use iterstate::IterState;
 pub use linearprogramstate::LinearProgramState;
+pub use paretostate::ParetoState;
 pub use populationstate::PopulationState;
 
 use crate::core::{ArgminFloat, Problem, TerminationReason, TerminationStatus};
-use std::collections::HashMap;
+use alloc::{collections::BTreeMap, string::String};
 
 /// Minimal interface which struct used for managing state in solvers have to implement.
 ///
@@ -88,13 +90,13 @@ pub trait State {
     fn func_counts<O>(&mut self, problem: &Problem<O>);
 
     /// Returns current cost function evaluation count
-    fn get_func_counts(&self) -> &HashMap<String, u64>;
+    fn get_func_counts(&self) -> &BTreeMap<String, u64>;
 
     /// Set time required since the beginning of the optimization until the current iteration
-    fn time(&mut self, time: Option<instant::Duration>) -> &mut Self;
+    fn time(&mut self, time: Option<core::time::Duration>) -> &mut Self;
 
     /// Get time passed since the beginning of the optimization until the current iteration
-    fn get_time(&self) -> Option<instant::Duration>;
+    fn get_time(&self) -> Option<core::time::Duration>;
 
     /// Returns iteration number where the last best parameter vector was found
     fn get_last_best_iter(&self) -> u64;
@@ -0,0 +1,595 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{ArgminFloat, Problem, State, TerminationReason, TerminationStatus};
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Maintains the state from iteration to iteration of a multi-objective population-based solver
+///
+/// This struct is passed from one iteration of an algorithm to the next.
+///
+/// Keeps track of
+///
+/// * population and the objective values of each individual
+/// * current approximation of the Pareto front and the associated objective values
+/// * current iteration number
+/// * iteration number where the Pareto front was last updated
+/// * maximum number of iterations that will be executed
+/// * problem function evaluation counts
+/// * elapsed time
+/// * termination status
+///
+/// Since there is no single best solution of a multi-objective problem, the scalar cost function
+/// value required by [`State`] is always infinite and the target cost is never reached.
+/// [`get_param`](`State::get_param`) and [`get_best_param`](`State::get_best_param`) return the
+/// first member of the current Pareto front. The entire front is available via
+/// [`get_pareto_front`](`ParetoState::get_pareto_front`) and
+/// [`get_pareto_front_costs`](`ParetoState::get_pareto_front_costs`).
+#[derive(Clone, Default, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct ParetoState<P, F> {
+    /// All members of the population
+    pub population: Option<Vec<P>>,
+    /// Objective values of all members of the population
+    pub population_costs: Option<Vec<Vec<F>>>,
+    /// Non-dominated members of the population
+    pub pareto_front: Option<Vec<P>>,
+    /// Objective values of the non-dominated members of the population
+    pub pareto_front_costs: Option<Vec<Vec<F>>>,
+    /// Current iteration
+    pub iter: u64,
+    /// Iteration number of last update of the Pareto front
+    pub last_best_iter: u64,
+    /// Maximum number of iterations
+    pub max_iters: u64,
+    /// Evaluation counts
+    pub counts: BTreeMap<String, u64>,
+    /// Time required so far
+    pub time: Option<core::time::Duration>,
+    /// Status of optimization execution
+    pub termination_status: TerminationStatus,
+}
+
+impl<P, F> ParetoState<P, F>
+where
+    Self: State<Float = F>,
+    F: ArgminFloat,
+{
+    /// Set population and the objective values of its members.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{ParetoState, State};
+    /// # let state: ParetoState<Vec<f64>, f64> = ParetoState::new();
+    /// # assert!(state.population.is_none());
+    /// let state = state.population(vec![vec![0.0f64], vec![1.0]], vec![vec![0.0, 1.0], vec![1.0, 0.0]]);
+    /// # assert_eq!(state.population.as_ref().unwrap()[1][0].to_ne_bytes(), 1.0f64.to_ne_bytes());
+    /// # assert_eq!(state.population_costs.as_ref().unwrap()[1][1].to_ne_bytes(), 0.0f64.to_ne_bytes());
+    /// ```
+    #[must_use]
+    pub fn population(mut self, population: Vec<P>, costs: Vec<Vec<F>>) -> Self {
+        self.population = Some(population);
+        self.population_costs = Some(costs);
+        self
+    }
+
+    /// Set the Pareto front and the objective values of its members.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{ParetoState, State};
+    /// # let state: ParetoState<Vec<f64>, f64> = ParetoState::new();
+    /// # assert!(state.pareto_front.is_none());
+    /// let state = state.pareto_front(vec![vec![0.0f64]], vec![vec![0.0, 1.0]]);
+    /// # assert_eq!(state.pareto_front.as_ref().unwrap()[0][0].to_ne_bytes(), 0.0f64.to_ne_bytes());
+    /// # assert_eq!(state.pareto_front_costs.as_ref().unwrap()[0][1].to_ne_bytes(), 1.0f64.to_ne_bytes());
+    /// ```
+    #[must_use]
+    pub fn pareto_front(mut self, front: Vec<P>, costs: Vec<Vec<F>>) -> Self {
+        self.pareto_front = Some(front);
+        self.pareto_front_costs = Some(costs);
+        self
+    }
+
+    /// Set maximum number of iterations
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{ParetoState, State};
+    /// # let state: ParetoState<Vec<f64>, f64> = ParetoState::new();
+    /// # assert_eq!(state.max_iters, u64::MAX);
+    /// let state = state.max_iters(1000);
+    /// # assert_eq!(state.max_iters, 1000);
+    /// ```
+    #[must_use]
+    pub fn max_iters(mut self, iters: u64) -> Self {
+        self.max_iters = iters;
+        self
+    }
+
+    /// Returns a reference to the population
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{ParetoState, State};
+    /// # let state: ParetoState<Vec<f64>, f64> = ParetoState::new();
+    /// # assert!(state.get_population().is_none());
+    /// # let state = state.population(vec![vec![1.0f64]], vec![vec![2.0]]);
+    /// let population = state.get_population();  // Option<&Vec<P>>
+    /// # assert_eq!(population.unwrap()[0][0].to_ne_bytes(), 1.0f64.to_ne_bytes());
+    /// ```
+    pub fn get_population(&self) -> Option<&Vec<P>> {
+        self.population.as_ref()
+    }
+
+    /// Takes population and replaces it internally with `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{ParetoState, State};
+    /// # let state: ParetoState<Vec<f64>, f64> = ParetoState::new();
+    /// # let mut state = state.population(vec![vec![1.0f64]], vec![vec![2.0]]);
+    /// let population = state.take_population();  // Option<Vec<P>>
+    /// # assert!(state.take_population().is_none());
+    /// # assert_eq!(population.unwrap()[0][0].to_ne_bytes(), 1.0f64.to_ne_bytes());
+    /// ```
+    pub fn take_population(&mut self) -> Option<Vec<P>> {
+        self.population.take()
+    }
+
+    /// Returns a reference to the objective values of the population
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{ParetoState, State};
+    /// # let state: ParetoState<Vec<f64>, f64> = ParetoState::new();
+    /// # assert!(state.get_population_costs().is_none());
+    /// # let state = state.population(vec![vec![1.0f64]], vec![vec![2.0]]);
+    /// let costs = state.get_population_costs();  // Option<&Vec<Vec<F>>>
+    /// # assert_eq!(costs.unwrap()[0][0].to_ne_bytes(), 2.0f64.to_ne_bytes());
+    /// ```
+    pub fn get_population_costs(&self) -> Option<&Vec<Vec<F>>> {
+        self.population_costs.as_ref()
+    }
+
+    /// Takes the objective values of the population and replaces them internally with `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{ParetoState, State};
+    /// # let state: ParetoState<Vec<f64>, f64> = ParetoState::new();
+    /// # let mut state = state.population(vec![vec![1.0f64]], vec![vec![2.0]]);
+    /// let costs = state.take_population_costs();  // Option<Vec<Vec<F>>>
+    /// # assert!(state.take_population_costs().is_none());
+    /// # assert_eq!(costs.unwrap()[0][0].to_ne_bytes(), 2.0f64.to_ne_bytes());
+    /// ```
+    pub fn take_population_costs(&mut self) -> Option<Vec<Vec<F>>> {
+        self.population_costs.take()
+    }
+
+    /// Returns a reference to the current Pareto front
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{ParetoState, State};
+    /// # let state: ParetoState<Vec<f64>, f64> = ParetoState::new();
+    /// # assert!(state.get_pareto_front().is_none());
+    /// # let state = state.pareto_front(vec![vec![1.0f64]], vec![vec![2.0]]);
+    /// let front = state.get_pareto_front();  // Option<&Vec<P>>
+    /// # assert_eq!(front.unwrap()[0][0].to_ne_bytes(), 1.0f64.to_ne_bytes());
+    /// ```
+    pub fn get_pareto_front(&self) -> Option<&Vec<P>> {
+        self.pareto_front.as_ref()
+    }
+
+    /// Takes the current Pareto front and replaces it internally with `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{ParetoState, State};
+    /// # let state: ParetoState<Vec<f64>, f64> = ParetoState::new();
+    /// # let mut state = state.pareto_front(vec![vec![1.0f64]], vec![vec![2.0]]);
+    /// let front = state.take_pareto_front();  // Option<Vec<P>>
+    /// # assert!(state.take_pareto_front().is_none());
+    /// # assert_eq!(front.unwrap()[0][0].to_ne_bytes(), 1.0f64.to_ne_bytes());
+    /// ```
+    pub fn take_pareto_front(&mut self) -> Option<Vec<P>> {
+        self.pareto_front.take()
+    }
+
+    /// Returns a reference to the objective values of the current Pareto front
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{ParetoState, State};
+    /// # let state: ParetoState<Vec<f64>, f64> = ParetoState::new();
+    /// # assert!(state.get_pareto_front_costs().is_none());
+    /// # let state = state.pareto_front(vec![vec![1.0f64]], vec![vec![2.0]]);
+    /// let costs = state.get_pareto_front_costs();  // Option<&Vec<Vec<F>>>
+    /// # assert_eq!(costs.unwrap()[0][0].to_ne_bytes(), 2.0f64.to_ne_bytes());
+    /// ```
+    pub fn get_pareto_front_costs(&self) -> Option<&Vec<Vec<F>>> {
+        self.pareto_front_costs.as_ref()
+    }
+
+    /// Takes the objective values of the current Pareto front and replaces them internally with
+    /// `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{ParetoState, State};
+    /// # let state: ParetoState<Vec<f64>, f64> = ParetoState::new();
+    /// # let mut state = state.pareto_front(vec![vec![1.0f64]], vec![vec![2.0]]);
+    /// let costs = state.take_pareto_front_costs();  // Option<Vec<Vec<F>>>
+    /// # assert!(state.take_pareto_front_costs().is_none());
+    /// # assert_eq!(costs.unwrap()[0][0].to_ne_bytes(), 2.0f64.to_ne_bytes());
+    /// ```
+    pub fn take_pareto_front_costs(&mut self) -> Option<Vec<Vec<F>>> {
+        self.pareto_front_costs.take()
+    }
+}
+
+impl<P, F> State for ParetoState<P, F>
+where
+    P: Clone,
+    F: ArgminFloat,
+{
+    /// Type of an individual
+    type Param = P;
+    /// Floating point precision
+    type Float = F;
+
+    /// Create a new ParetoState instance
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{ParetoState, State, TerminationStatus};
+    /// let state: ParetoState<Vec<f64>, f64> = ParetoState::new();
+    /// # assert!(state.population.is_none());
+    /// # assert!(state.population_costs.is_none());
+    /// # assert!(state.pareto_front.is_none());
+    /// # assert!(state.pareto_front_costs.is_none());
+    /// # assert_eq!(state.iter, 0);
+    /// # assert_eq!(state.last_best_iter, 0);
+    /// # assert_eq!(state.max_iters, u64::MAX);
+    /// # assert_eq!(state.counts.len(), 0);
+    /// # assert_eq!(state.time.unwrap(), std::time::Duration::new(0, 0));
+    /// # assert_eq!(state.termination_status, TerminationStatus::NotTerminated);
+    /// ```
+    fn new() -> Self {
+        ParetoState {
+            population: None,
+            population_costs: None,
+            pareto_front: None,
+            pareto_front_costs: None,
+            iter: 0,
+            last_best_iter: 0,
+            max_iters: u64::MAX,
+            counts: BTreeMap::new(),
+            time: Some(core::time::Duration::new(0, 0)),
+            termination_status: TerminationStatus::NotTerminated,
+        }
+    }
+
+    /// Marks the current iteration as the one of the last update of the Pareto front, if a front
+    /// is present.
+    ///
+    /// Solvers are expected to only ever replace the Pareto front with one which is not worse.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{ParetoState, State};
+    /// let mut state: ParetoState<Vec<f64>, f64> = ParetoState::new();
+    /// state.iter = 3;
+    /// state.update();
+    /// assert_eq!(state.last_best_iter, 0);
+    ///
+    /// let mut state = state.pareto_front(vec![vec![1.0]], vec![vec![1.0, 2.0]]);
+    /// state.update();
+    /// assert_eq!(state.last_best_iter, 3);
+    /// assert!(state.is_best());
+    /// ```
+    fn update(&mut self) {
+        if self.pareto_front.is_some() {
+            self.last_best_iter = self.iter;
+        }
+    }
+
+    /// Returns a reference to the first member of the current Pareto front
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{ParetoState, State};
+    /// # let state: ParetoState<Vec<f64>, f64> = ParetoState::new();
+    /// # assert!(state.get_param().is_none());
+    /// # let state = state.pareto_front(vec![vec![1.0f64], vec![2.0]], vec![vec![2.0], vec![1.0]]);
+    /// let param = state.get_param();  // Option<&P>
+    /// # assert_eq!(param.unwrap()[0].to_ne_bytes(), 1.0f64.to_ne_bytes());
+    /// ```
+    fn get_param(&self) -> Option<&P> {
+        self.pareto_front.as_ref().and_then(|front| front.first())
+    }
+
+    /// Returns a reference to the first member of the current Pareto front
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{ParetoState, State};
+    /// # let state: ParetoState<Vec<f64>, f64> = ParetoState::new();
+    /// # assert!(state.get_best_param().is_none());
+    /// # let state = state.pareto_front(vec![vec![1.0f64], vec![2.0]], vec![vec![2.0], vec![1.0]]);
+    /// let best_param = state.get_best_param();  // Option<&P>
+    /// # assert_eq!(best_param.unwrap()[0].to_ne_bytes(), 1.0f64.to_ne_bytes());
+    /// ```
+    fn get_best_param(&self) -> Option<&P> {
+        self.get_param()
+    }
+
+    /// Sets the termination status to [`Terminated`](`TerminationStatus::Terminated`) with the given reason
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{ParetoState, State, TerminationReason, TerminationStatus};
+    /// # let mut state: ParetoState<Vec<f64>, f64> = ParetoState::new();
+    /// # assert_eq!(state.termination_status, TerminationStatus::NotTerminated);
+    /// let state = state.terminate_with(TerminationReason::MaxItersReached);
+    /// # assert_eq!(state.termination_status, TerminationStatus::Terminated(TerminationReason::MaxItersReached));
+    /// ```
+    fn terminate_with(mut self, reason: TerminationReason) -> Self {
+        self.termination_status = TerminationStatus::Terminated(reason);
+        self
+    }
+
+    /// Sets the time required so far.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{ParetoState, State};
+    /// # let mut state: ParetoState<Vec<f64>, f64> = ParetoState::new();
+    /// let state = state.time(Some(std::time::Duration::new(0, 12)));
+    /// # assert_eq!(state.time.unwrap(), std::time::Duration::new(0, 12));
+    /// ```
+    fn time(&mut self, time: Option<core::time::Duration>) -> &mut Self {
+        self.time = time;
+        self
+    }
+
+    /// Returns infinity, since multi-objective problems have no scalar cost function value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{ParetoState, State};
+    /// # let state: ParetoState<Vec<f64>, f64> = ParetoState::new();
+    /// let cost = state.get_cost();
+    /// # assert_eq!(cost.to_ne_bytes(), f64::INFINITY.to_ne_bytes());
+    /// ```
+    fn get_cost(&self) -> Self::Float {
+        F::infinity()
+    }
+
+    /// Returns infinity, since multi-objective problems have no scalar cost function value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{ParetoState, State};
+    /// # let state: ParetoState<Vec<f64>, f64> = ParetoState::new();
+    /// let best_cost = state.get_best_cost();
+    /// # assert_eq!(best_cost.to_ne_bytes(), f64::INFINITY.to_ne_bytes());
+    /// ```
+    fn get_best_cost(&self) -> Self::Float {
+        F::infinity()
+    }
+
+    /// Returns negative infinity, hence the target cost is never reached.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{ParetoState, State};
+    /// # let state: ParetoState<Vec<f64>, f64> = ParetoState::new();
+    /// let target_cost = state.get_target_cost();
+    /// # assert_eq!(target_cost.to_ne_bytes(), f64::NEG_INFINITY.to_ne_bytes());
+    /// ```
+    fn get_target_cost(&self) -> Self::Float {
+        F::neg_infinity()
+    }
+
+    /// Returns current number of iterations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{ParetoState, State};
+    /// # let mut state: ParetoState<Vec<f64>, f64> = ParetoState::new();
+    /// # state.iter = 12;
+    /// let iter = state.get_iter();
+    /// # assert_eq!(iter, 12);
+    /// ```
+    fn get_iter(&self) -> u64 {
+        self.iter
+    }
+
+    /// Returns iteration number of the last update of the Pareto front
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{ParetoState, State};
+    /// # let mut state: ParetoState<Vec<f64>, f64> = ParetoState::new();
+    /// # state.last_best_iter = 12;
+    /// let last_best_iter = state.get_last_best_iter();
+    /// # assert_eq!(last_best_iter, 12);
+    /// ```
+    fn get_last_best_iter(&self) -> u64 {
+        self.last_best_iter
+    }
+
+    /// Returns the maximum number of iterations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{ParetoState, State};
+    /// # let mut state: ParetoState<Vec<f64>, f64> = ParetoState::new();
+    /// # state.max_iters = 12;
+    /// let max_iters = state.get_max_iters();
+    /// # assert_eq!(max_iters, 12);
+    /// ```
+    fn get_max_iters(&self) -> u64 {
+        self.max_iters
+    }
+
+    /// Returns the termination status.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{ParetoState, State, TerminationStatus};
+    /// # let mut state: ParetoState<Vec<f64>, f64> = ParetoState::new();
+    /// let termination_status = state.get_termination_status();
+    /// # assert_eq!(*termination_status, TerminationStatus::NotTerminated);
+    /// ```
+    fn get_termination_status(&self) -> &TerminationStatus {
+        &self.termination_status
+    }
+
+    /// Returns the termination reason if terminated, otherwise None.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{ParetoState, State, TerminationReason};
+    /// # let mut state: ParetoState<Vec<f64>, f64> = ParetoState::new();
+    /// let termination_reason = state.get_termination_reason();
+    /// # assert_eq!(termination_reason, None);
+    /// ```
+    fn get_termination_reason(&self) -> Option<&TerminationReason> {
+        match &self.termination_status {
+            TerminationStatus::Terminated(reason) => Some(reason),
+            TerminationStatus::NotTerminated => None,
+        }
+    }
+
+    /// Returns the time elapsed since the start of the optimization.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{ParetoState, State};
+    /// # let mut state: ParetoState<Vec<f64>, f64> = ParetoState::new();
+    /// let time = state.get_time();
+    /// # assert_eq!(time.unwrap(), std::time::Duration::new(0, 0));
+    /// ```
+    fn get_time(&self) -> Option<core::time::Duration> {
+        self.time
+    }
+
+    /// Increments the number of iterations by one
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{ParetoState, State};
+    /// # let mut state: ParetoState<Vec<f64>, f64> = ParetoState::new();
+    /// # assert_eq!(state.iter, 0);
+    /// state.increment_iter();
+    /// # assert_eq!(state.iter, 1);
+    /// ```
+    fn increment_iter(&mut self) {
+        self.iter += 1;
+    }
+
+    /// Set all function evaluation counts to the evaluation counts of another `Problem`.
+    ///
+    /// ```
+    /// # use std::collections::BTreeMap;
+    /// # use argmin::core::{Problem, ParetoState, State};
+    /// # let mut state: ParetoState<Vec<f64>, f64> = ParetoState::new();
+    /// # assert_eq!(state.counts, BTreeMap::new());
+    /// # state.counts.insert("test2".to_string(), 10u64);
+    /// #
+    /// # #[derive(Eq, PartialEq, Debug)]
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # let mut problem = Problem::new(UserDefinedProblem {});
+    /// # problem.counts.insert("test1", 10u64);
+    /// # problem.counts.insert("test2", 2);
+    /// state.func_counts(&problem);
+    /// # let mut hm = BTreeMap::new();
+    /// # hm.insert("test1".to_string(), 10u64);
+    /// # hm.insert("test2".to_string(), 2u64);
+    /// # assert_eq!(state.counts, hm);
+    /// ```
+    fn func_counts<O>(&mut self, problem: &Problem<O>) {
+        for (k, &v) in problem.counts.iter() {
+            let count = self.counts.entry(k.to_string()).or_insert(0);
+            *count = v
+        }
+    }
+
+    /// Returns function evaluation counts
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::collections::BTreeMap;
+    /// # use argmin::core::{ParetoState, State};
+    /// # let mut state: ParetoState<Vec<f64>, f64> = ParetoState::new();
+    /// # assert_eq!(state.counts, BTreeMap::new());
+    /// # state.counts.insert("test2".to_string(), 10u64);
+    /// let counts = state.get_func_counts();
+    /// # let mut hm = BTreeMap::new();
+    /// # hm.insert("test2".to_string(), 10u64);
+    /// # assert_eq!(*counts, hm);
+    /// ```
+    fn get_func_counts(&self) -> &BTreeMap<String, u64> {
+        &self.counts
+    }
+
+    /// Returns whether the Pareto front was updated in the current iteration.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{ParetoState, State};
+    /// # let mut state: ParetoState<Vec<f64>, f64> = ParetoState::new();
+    /// # state.last_best_iter = 12;
+    /// # state.iter = 12;
+    /// let is_best = state.is_best();
+    /// # assert!(is_best);
+    /// # state.iter = 21;
+    /// # assert!(!state.is_best());
+    /// ```
+    fn is_best(&self) -> bool {
+        self.last_best_iter == self.iter
+    }
+}
@@ -6,10 +6,13 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::core::{ArgminFloat, Problem, State, TerminationReason, TerminationStatus};
-use instant;
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
 /// Maintains the state from iteration to iteration of a population-based solver
 ///
@@ -58,9 +61,9 @@ pub struct PopulationState<P, F> {
     /// Maximum number of iterations
     pub max_iters: u64,
     /// Evaluation counts
-    pub counts: HashMap<String, u64>,
+    pub counts: BTreeMap<String, u64>,
     /// Time required so far
-    pub time: Option<instant::Duration>,
+    pub time: Option<core::time::Duration>,
     /// Status of optimization execution
     pub termination_status: TerminationStatus,
 }
@@ -92,7 +95,7 @@ where
     /// ```
     #[must_use]
     pub fn individual(mut self, individual: P) -> Self {
-        std::mem::swap(&mut self.prev_individual, &mut self.individual);
+        core::mem::swap(&mut self.prev_individual, &mut self.individual);
         self.individual = Some(individual);
         self
     }
@@ -115,7 +118,7 @@ where
     /// ```
     #[must_use]
     pub fn cost(mut self, cost: F) -> Self {
-        std::mem::swap(&mut self.prev_cost, &mut self.cost);
+        core::mem::swap(&mut self.prev_cost, &mut self.cost);
         self.cost = cost;
         self
     }
@@ -447,8 +450,6 @@ where
     /// # Example
     ///
     /// ```
-    /// # extern crate instant;
-    /// # use instant;
     /// # use argmin::core::{PopulationState, State, ArgminFloat, TerminationStatus};
     /// let state: PopulationState<Vec<f64>, f64> = PopulationState::new();
     /// # assert!(state.individual.is_none());
@@ -465,7 +466,7 @@ where
     /// # assert_eq!(state.last_best_iter, 0);
     /// # assert_eq!(state.max_iters, std::u64::MAX);
     /// # assert_eq!(state.counts.len(), 0);
-    /// # assert_eq!(state.time.unwrap(), instant::Duration::new(0, 0));
+    /// # assert_eq!(state.time.unwrap(), std::time::Duration::new(0, 0));
     /// # assert_eq!(state.termination_status, TerminationStatus::NotTerminated);
     /// ```
     fn new() -> Self {
@@ -482,9 +483,9 @@ where
             population: None,
             iter: 0,
             last_best_iter: 0,
-            max_iters: std::u64::MAX,
-            counts: HashMap::new(),
-            time: Some(instant::Duration::new(0, 0)),
+            max_iters: core::u64::MAX,
+            counts: BTreeMap::new(),
+            time: Some(core::time::Duration::new(0, 0)),
             termination_status: TerminationStatus::NotTerminated,
         }
     }
@@ -546,10 +547,10 @@ where
         {
             // If there is no individual, then also don't set the best individual.
             if let Some(individual) = self.individual.as_ref().cloned() {
-                std::mem::swap(&mut self.prev_best_individual, &mut self.best_individual);
+                core::mem::swap(&mut self.prev_best_individual, &mut self.best_individual);
                 self.best_individual = Some(individual);
             }
-            std::mem::swap(&mut self.prev_best_cost, &mut self.best_cost);
+            core::mem::swap(&mut self.prev_best_cost, &mut self.best_cost);
             self.best_cost = self.cost;
             self.last_best_iter = self.iter;
         }
@@ -614,14 +615,12 @@ where
     /// # Example
     ///
     /// ```
-    /// # extern crate instant;
-    /// # use instant;
     /// # use argmin::core::{PopulationState, State, ArgminFloat, TerminationReason};
     /// # let mut state: PopulationState<Vec<f64>, f64> = PopulationState::new();
-    /// let state = state.time(Some(instant::Duration::new(0, 12)));
-    /// # assert_eq!(state.time.unwrap(), instant::Duration::new(0, 12));
+    /// let state = state.time(Some(std::time::Duration::new(0, 12)));
+    /// # assert_eq!(state.time.unwrap(), std::time::Duration::new(0, 12));
     /// ```
-    fn time(&mut self, time: Option<instant::Duration>) -> &mut Self {
+    fn time(&mut self, time: Option<core::time::Duration>) -> &mut Self {
         self.time = time;
         self
     }
@@ -752,14 +751,12 @@ where
     /// # Example
     ///
     /// ```
-    /// # extern crate instant;
-    /// # use instant;
     /// # use argmin::core::{PopulationState, State, ArgminFloat};
     /// # let mut state: PopulationState<Vec<f64>, f64> = PopulationState::new();
     /// let time = state.get_time();
-    /// # assert_eq!(time.unwrap(), instant::Duration::new(0, 0));
+    /// # assert_eq!(time.unwrap(), std::time::Duration::new(0, 0));
     /// ```
-    fn get_time(&self) -> Option<instant::Duration> {
+    fn get_time(&self) -> Option<core::time::Duration> {
         self.time
     }
 
@@ -781,10 +778,10 @@ where
     /// Set all function evaluation counts to the evaluation counts of another `Problem`.
     ///
     /// ```
-    /// # use std::collections::HashMap;
+    /// # use std::collections::BTreeMap;
     /// # use argmin::core::{Problem, PopulationState, State, ArgminFloat};
     /// # let mut state: PopulationState<Vec<f64>, f64> = PopulationState::new();
-    /// # assert_eq!(state.counts, HashMap::new());
+    /// # assert_eq!(state.counts, BTreeMap::new());
     /// # state.counts.insert("test2".to_string(), 10u64);
     /// #
     /// # #[derive(Eq, PartialEq, Debug)]
@@ -794,7 +791,7 @@ where
     /// # problem.counts.insert("test1", 10u64);
     /// # problem.counts.insert("test2", 2);
     /// state.func_counts(&problem);
-    /// # let mut hm = HashMap::new();
+    /// # let mut hm = BTreeMap::new();
     /// # hm.insert("test1".to_string(), 10u64);
     /// # hm.insert("test2".to_string(), 2u64);
     /// # assert_eq!(state.counts, hm);
@@ -811,17 +808,17 @@ where
     /// # Example
     ///
     /// ```
-    /// # use std::collections::HashMap;
+    /// # use std::collections::BTreeMap;
     /// # use argmin::core::{PopulationState, State, ArgminFloat};
     /// # let mut state: PopulationState<Vec<f64>, f64> = PopulationState::new();
-    /// # assert_eq!(state.counts, HashMap::new());
+    /// # assert_eq!(state.counts, BTreeMap::new());
     /// # state.counts.insert("test2".to_string(), 10u64);
     /// let counts = state.get_func_counts();
-    /// # let mut hm = HashMap::new();
+    /// # let mut hm = BTreeMap::new();
     /// # hm.insert("test2".to_string(), 10u64);
     /// # assert_eq!(*counts, hm);
     /// ```
-    fn get_func_counts(&self) -> &HashMap<String, u64> {
+    fn get_func_counts(&self) -> &BTreeMap<String, u64> {
         &self.counts
     }
 
@@ -5,6 +5,7 @@
 // http://opensource.org/licenses/MIT>, at your option. This file may not be
 // copied, modified, or distributed except according to those terms.
 
+use alloc::string::{String, ToString};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 
@@ -38,8 +39,8 @@ impl TerminationStatus {
     }
 }
 
-impl std::fmt::Display for TerminationStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for TerminationStatus {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         match self {
             TerminationStatus::Terminated(reason) => f.write_str(reason.text()),
             TerminationStatus::NotTerminated => f.write_str("Running"),
@@ -103,8 +104,8 @@ impl TerminationReason {
     }
 }
 
-impl std::fmt::Display for TerminationReason {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Display for TerminationReason {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(f, "{}", self.text())
     }
 }
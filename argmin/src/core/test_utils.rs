@@ -8,10 +8,14 @@
 use crate::core::{
     CostFunction, Error, Gradient, Hessian, IterState, Jacobian, Operator, Problem, Solver, KV,
 };
+#[cfg(feature = "std")]
 use crate::solver::simulatedannealing::Anneal;
+use alloc::{vec, vec::Vec};
+use core::fmt::Debug;
+#[cfg(not(any(feature = "std", test)))]
+use num_traits::Float;
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
-use std::fmt::Debug;
 
 /// Pseudo problem useful for testing
 ///
@@ -178,6 +182,7 @@ impl Jacobian for TestProblem {
     }
 }
 
+#[cfg(feature = "std")]
 impl Anneal for TestProblem {
     type Param = Vec<f64>;
     type Output = Vec<f64>;
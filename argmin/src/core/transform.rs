@@ -0,0 +1,418 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{ArgminFloat, Bounds, BoxConstraint, CostFunction, Error, Gradient};
+use alloc::{format, vec::Vec};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Transformation applied to variables which are bounded from below and above
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub enum BoundTransform {
+    /// `x = l + (u - l) / (1 + exp(-y))`
+    Logit,
+    /// `x = l + (u - l) * (1 + tanh(y)) / 2`
+    Tanh,
+}
+
+impl Default for BoundTransform {
+    /// The default for `BoundTransform` is `Logit`
+    fn default() -> BoundTransform {
+        BoundTransform::Logit
+    }
+}
+
+/// # Bound handling via variable transformations
+///
+/// Wraps a problem whose parameters are subject to element-wise bounds `l <= x <= u` and turns it
+/// into an unconstrained problem in the variables `y`, which can be solved by any unconstrained
+/// solver. Depending on which bounds of an element are finite, the following transformations are
+/// used:
+///
+/// * no finite bound: `x = y`
+/// * only `l` finite: `x = l + exp(y)`
+/// * only `u` finite: `x = u - exp(y)`
+/// * `l` and `u` finite: the [`BoundTransform`] chosen via
+///   [`with_transform`](`TransformedProblem::with_transform`) (default: `Logit`)
+/// * `l == u`: `x = l`
+///
+/// [`CostFunction`] is implemented if the wrapped problem implements [`CostFunction`] and
+/// [`Gradient`] is implemented if the wrapped problem implements [`Gradient`]; the gradient is
+/// transformed via the chain rule. Initial guesses have to be mapped to the unconstrained
+/// variables with [`to_unbounded`](`TransformedProblem::to_unbounded`) and results mapped back
+/// with [`to_bounded`](`TransformedProblem::to_bounded`).
+///
+/// Note that the bounds can only be approached asymptotically; solutions which lie on the bounds
+/// correspond to infinitely large `y`.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::core::{Bounds, CostFunction, Error, TransformedProblem};
+/// # struct UserDefinedProblem {};
+/// #
+/// # impl CostFunction for UserDefinedProblem {
+/// #     type Param = Vec<f64>;
+/// #     type Output = f64;
+/// #
+/// #     fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+/// #         Ok(p[0].powi(2) + p[1].powi(2))
+/// #     }
+/// # }
+/// # fn main() -> Result<(), Error> {
+/// let bounds = Bounds::new(vec![0.0f64, 1.0], vec![1.0, f64::INFINITY])?;
+/// let problem = TransformedProblem::new(UserDefinedProblem {}, bounds);
+///
+/// // Map initial guess to unconstrained variables
+/// let init_param = problem.to_unbounded(&[0.5, 2.0])?;
+/// # assert!(init_param[0].abs() < 1e-12);
+/// # assert!(init_param[1].abs() < 1e-12);
+///
+/// // Run an unconstrained solver on `problem`, then map the result back
+/// let param = problem.to_bounded(&init_param);
+/// # assert_eq!(param, vec![0.5, 2.0]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct TransformedProblem<O, F> {
+    /// Wrapped problem
+    problem: O,
+    /// Lower bounds
+    lower: Vec<F>,
+    /// Upper bounds
+    upper: Vec<F>,
+    /// Transformation for variables with finite lower and upper bounds
+    transform: BoundTransform,
+}
+
+impl<O, F> TransformedProblem<O, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`TransformedProblem`] from a problem and bounds
+    pub fn new(problem: O, bounds: Bounds<Vec<F>>) -> Self {
+        let (lower, upper) = bounds.into_inner();
+        TransformedProblem {
+            problem,
+            lower,
+            upper,
+            transform: BoundTransform::default(),
+        }
+    }
+
+    /// Construct a new instance of [`TransformedProblem`] from a problem which provides its
+    /// bounds via [`BoxConstraint`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Bounds, BoxConstraint, Error, TransformedProblem};
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl BoxConstraint for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #
+    /// #     fn bounds(&self) -> Result<Bounds<Vec<f64>>, Error> {
+    /// #         Bounds::new(vec![0.0, 0.0], vec![1.0, 1.0])
+    /// #     }
+    /// # }
+    /// # fn main() -> Result<(), Error> {
+    /// let problem = TransformedProblem::from_problem(UserDefinedProblem {})?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_problem(problem: O) -> Result<Self, Error>
+    where
+        O: BoxConstraint<Param = Vec<F>>,
+    {
+        let bounds = problem.bounds()?;
+        Ok(TransformedProblem::new(problem, bounds))
+    }
+
+    /// Set the transformation for variables with finite lower and upper bounds
+    ///
+    /// Defaults to [`BoundTransform::Logit`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Bounds, BoundTransform, Error, TransformedProblem};
+    /// # struct UserDefinedProblem {};
+    /// # fn main() -> Result<(), Error> {
+    /// # let bounds = Bounds::new(vec![0.0f64], vec![1.0])?;
+    /// let problem = TransformedProblem::new(UserDefinedProblem {}, bounds)
+    ///     .with_transform(BoundTransform::Tanh);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_transform(mut self, transform: BoundTransform) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    /// Maps unconstrained variables `y` to the bounded variables `x`
+    ///
+    /// Every element of the result lies within the bounds.
+    pub fn to_bounded(&self, param: &[F]) -> Vec<F> {
+        param
+            .iter()
+            .zip(self.lower.iter().zip(self.upper.iter()))
+            .map(|(&y, (&l, &u))| self.forward(y, l, u).0)
+            .collect()
+    }
+
+    /// Maps bounded variables `x` to the unconstrained variables `y`
+    ///
+    /// Every finite bound must be strictly satisfied (except for fixed variables with `l == u`),
+    /// since points on the bounds correspond to infinitely large `y`.
+    pub fn to_unbounded(&self, param: &[F]) -> Result<Vec<F>, Error> {
+        if param.len() != self.lower.len() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                format!(
+                    "`TransformedProblem`: Parameter vector must have {} entries.",
+                    self.lower.len()
+                )
+            ));
+        }
+        param
+            .iter()
+            .zip(self.lower.iter().zip(self.upper.iter()))
+            .map(|(&x, (&l, &u))| self.inverse(x, l, u))
+            .collect()
+    }
+
+    /// Returns the wrapped problem
+    pub fn into_inner(self) -> O {
+        self.problem
+    }
+
+    /// Returns `x(y)` and the derivative `dx/dy` for a single element
+    fn forward(&self, y: F, l: F, u: F) -> (F, F) {
+        match (l.is_finite(), u.is_finite()) {
+            (false, false) => (y, float!(1.0)),
+            (true, false) => {
+                let e = y.exp();
+                (l + e, e)
+            }
+            (false, true) => {
+                let e = y.exp();
+                (u - e, -e)
+            }
+            (true, true) if l == u => (l, float!(0.0)),
+            (true, true) => {
+                let width = u - l;
+                let (t, dt) = match self.transform {
+                    BoundTransform::Logit => {
+                        let s = float!(1.0) / (float!(1.0) + (-y).exp());
+                        (s, s * (float!(1.0) - s))
+                    }
+                    BoundTransform::Tanh => {
+                        let th = y.tanh();
+                        (
+                            float!(0.5) * (float!(1.0) + th),
+                            float!(0.5) * (float!(1.0) - th * th),
+                        )
+                    }
+                };
+                // Guard against rounding slightly beyond the bounds
+                ((l + width * t).max(l).min(u), width * dt)
+            }
+        }
+    }
+
+    /// Returns `y(x)` for a single element
+    fn inverse(&self, x: F, l: F, u: F) -> Result<F, Error> {
+        let inside = match (l.is_finite(), u.is_finite()) {
+            (false, false) => true,
+            (true, false) => x > l,
+            (false, true) => x < u,
+            (true, true) => (x > l && x < u) || (l == u && x == l),
+        };
+        if !inside {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`TransformedProblem`: Parameter vector must lie strictly within the bounds."
+            ));
+        }
+        Ok(match (l.is_finite(), u.is_finite()) {
+            (false, false) => x,
+            (true, false) => (x - l).ln(),
+            (false, true) => (u - x).ln(),
+            (true, true) if l == u => float!(0.0),
+            (true, true) => {
+                let t = (x - l) / (u - l);
+                match self.transform {
+                    BoundTransform::Logit => (t / (float!(1.0) - t)).ln(),
+                    BoundTransform::Tanh => (float!(2.0) * t - float!(1.0)).atanh(),
+                }
+            }
+        })
+    }
+}
+
+impl<O, F> CostFunction for TransformedProblem<O, F>
+where
+    O: CostFunction<Param = Vec<F>, Output = F>,
+    F: ArgminFloat,
+{
+    type Param = Vec<F>;
+    type Output = F;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        self.problem.cost(&self.to_bounded(param))
+    }
+}
+
+impl<O, F> Gradient for TransformedProblem<O, F>
+where
+    O: Gradient<Param = Vec<F>, Gradient = Vec<F>>,
+    F: ArgminFloat,
+{
+    type Param = Vec<F>;
+    type Gradient = Vec<F>;
+
+    fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+        let (x, dxdy): (Vec<F>, Vec<F>) = param
+            .iter()
+            .zip(self.lower.iter().zip(self.upper.iter()))
+            .map(|(&y, (&l, &u))| self.forward(y, l, u))
+            .unzip();
+        Ok(self
+            .problem
+            .gradient(&x)?
+            .iter()
+            .zip(dxdy.iter())
+            .map(|(&g, &d)| g * d)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::test_utils::TestProblem;
+    use crate::core::ArgminError;
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(transformed_problem, TransformedProblem<TestProblem, f64>);
+    test_trait_impl!(bound_transform, BoundTransform);
+
+    #[derive(Clone)]
+    struct Quadratic {}
+
+    impl CostFunction for Quadratic {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(p.iter().map(|x| (x - 2.0).powi(2)).sum())
+        }
+    }
+
+    impl Gradient for Quadratic {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(p.iter().map(|x| 2.0 * (x - 2.0)).collect())
+        }
+    }
+
+    fn bounds() -> Bounds<Vec<f64>> {
+        Bounds::new(
+            vec![f64::NEG_INFINITY, 0.0, f64::NEG_INFINITY, -1.0, 3.0],
+            vec![f64::INFINITY, f64::INFINITY, 1.0, 1.0, 3.0],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let x = vec![5.0, 0.5, -3.0, 0.25, 3.0];
+        for transform in [BoundTransform::Logit, BoundTransform::Tanh] {
+            let problem = TransformedProblem::new(Quadratic {}, bounds()).with_transform(transform);
+            let y = problem.to_unbounded(&x).unwrap();
+            let x2 = problem.to_bounded(&y);
+            for (a, b) in x.iter().zip(x2.iter()) {
+                assert_relative_eq!(a, b, epsilon = 1e-12);
+            }
+            // Arbitrary unconstrained variables are mapped into the bounds
+            let x3 = problem.to_bounded(&[-100.0, -100.0, 100.0, 100.0, 1.0]);
+            assert!(bounds().contains(&x3));
+        }
+    }
+
+    #[test]
+    fn test_to_unbounded_invalid() {
+        let problem = TransformedProblem::new(Quadratic {}, bounds());
+        assert_error!(
+            problem.to_unbounded(&[0.0, 0.0, 0.0, 0.0, 3.0]),
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`TransformedProblem`: Parameter vector must lie strictly ",
+                "within the bounds.\""
+            )
+        );
+        assert_error!(
+            problem.to_unbounded(&[0.0]),
+            ArgminError,
+            "Invalid parameter: \"`TransformedProblem`: Parameter vector must have 5 entries.\""
+        );
+    }
+
+    #[test]
+    fn test_gradient() {
+        let y = vec![0.3, -0.2, 0.1, 0.4, 0.0];
+        for transform in [BoundTransform::Logit, BoundTransform::Tanh] {
+            let problem = TransformedProblem::new(Quadratic {}, bounds()).with_transform(transform);
+            let grad = problem.gradient(&y).unwrap();
+            // Compare with central differences
+            for i in 0..y.len() {
+                let mut yp = y.clone();
+                let mut ym = y.clone();
+                yp[i] += 1e-6;
+                ym[i] -= 1e-6;
+                let fd = (problem.cost(&yp).unwrap() - problem.cost(&ym).unwrap()) / 2e-6;
+                assert_relative_eq!(grad[i], fd, epsilon = 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_solve() {
+        use crate::core::{Executor, State};
+        use crate::solver::linesearch::MoreThuenteLineSearch;
+        use crate::solver::quasinewton::LBFGS;
+        // The unconstrained minimum at 2.0 violates the upper bounds of the third and fourth
+        // element
+        let bounds = Bounds::new(
+            vec![f64::NEG_INFINITY, 0.0, f64::NEG_INFINITY, -1.0],
+            vec![f64::INFINITY, f64::INFINITY, 1.0, 1.5],
+        )
+        .unwrap();
+        let problem = TransformedProblem::new(Quadratic {}, bounds.clone());
+        let init_param = problem.to_unbounded(&[0.0, 1.0, 0.0, 0.0]).unwrap();
+        let solver = LBFGS::new(MoreThuenteLineSearch::new(), 5);
+        let res = Executor::new(problem.clone(), solver)
+            .configure(|state| state.param(init_param).max_iters(200))
+            .run()
+            .unwrap();
+        let x = problem.to_bounded(res.state().get_best_param().unwrap());
+        assert!(bounds.contains(&x));
+        assert_relative_eq!(x[0], 2.0, epsilon = 1e-6);
+        assert_relative_eq!(x[1], 2.0, epsilon = 1e-6);
+        assert_relative_eq!(x[2], 1.0, epsilon = 1e-3);
+        assert_relative_eq!(x[3], 1.5, epsilon = 1e-3);
+    }
+}
@@ -0,0 +1,347 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{ArgminFloat, CostFunction, Error, Gradient, Hessian, Jacobian, Operator};
+use crate::derivatives::{FiniteDiff, FiniteDiffScheme};
+use alloc::vec::Vec;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Result of comparing analytic derivatives against finite differences
+///
+/// Holds the component with the largest discrepancy. The discrepancy is measured as
+/// `|analytic - approximation| / max(1, |analytic|)`, which is an absolute error for small and a
+/// relative error for large derivatives. `I` is the type of the index of a component: `usize` for
+/// gradients and `(usize, usize)` (row and column) for Hessians.
+///
+/// Created by [`check_gradient`] and [`check_hessian`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct DerivativeCheck<F, I> {
+    /// Index of the worst component
+    index: I,
+    /// Analytic derivative of the worst component
+    analytic: F,
+    /// Finite difference approximation of the worst component
+    approximation: F,
+    /// Discrepancy of the worst component
+    error: F,
+    /// Tolerance
+    tol: F,
+}
+
+impl<F, I> DerivativeCheck<F, I>
+where
+    F: ArgminFloat,
+    I: Copy,
+{
+    /// Returns the index of the component with the largest discrepancy
+    pub fn index(&self) -> I {
+        self.index
+    }
+
+    /// Returns the analytic derivative of the component with the largest discrepancy
+    pub fn analytic(&self) -> F {
+        self.analytic
+    }
+
+    /// Returns the finite difference approximation of the component with the largest discrepancy
+    pub fn approximation(&self) -> F {
+        self.approximation
+    }
+
+    /// Returns the largest discrepancy
+    pub fn error(&self) -> F {
+        self.error
+    }
+
+    /// Returns `true` if the largest discrepancy does not exceed the tolerance
+    ///
+    /// NaN discrepancies never pass.
+    pub fn passed(&self) -> bool {
+        self.error <= self.tol
+    }
+
+    /// Finds the worst component of `analytic` compared to `approximation`
+    fn worst(
+        analytic: impl Iterator<Item = (I, F)>,
+        approximation: impl Iterator<Item = F>,
+        tol: F,
+    ) -> Result<Self, Error> {
+        analytic
+            .zip(approximation)
+            .map(|((index, analytic), approximation)| {
+                let error = (analytic - approximation).abs() / analytic.abs().max(float!(1.0));
+                DerivativeCheck {
+                    index,
+                    analytic,
+                    approximation,
+                    error,
+                    tol,
+                }
+            })
+            .reduce(|worst, check| {
+                // NaN discrepancies take precedence
+                if worst.error.is_nan() || check.error <= worst.error {
+                    worst
+                } else {
+                    check
+                }
+            })
+            .ok_or_else(argmin_error_closure!(
+                InvalidParameter,
+                "Derivative check requires a non-empty parameter vector."
+            ))
+    }
+}
+
+/// Evaluates the cost function of a borrowed problem
+struct CostOf<'a, P>(&'a P);
+
+impl<P> CostFunction for CostOf<'_, P>
+where
+    P: CostFunction,
+{
+    type Param = P::Param;
+    type Output = P::Output;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        self.0.cost(param)
+    }
+}
+
+/// Evaluates the gradient of a borrowed problem as an operator
+struct GradientOf<'a, P>(&'a P);
+
+impl<P> Operator for GradientOf<'_, P>
+where
+    P: Gradient,
+{
+    type Param = P::Param;
+    type Output = P::Gradient;
+
+    fn apply(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        self.0.gradient(param)
+    }
+}
+
+/// Compares the gradient of `problem` at `param` against central finite differences of its cost
+/// function
+///
+/// Returns the component with the largest discrepancy. An error is returned if the gradient does
+/// not have the same length as `param` or if `param` is empty.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::core::{CostFunction, Error, Gradient};
+/// # use argmin::derivatives::check_gradient;
+/// struct Parabola {}
+///
+/// impl CostFunction for Parabola {
+///     type Param = Vec<f64>;
+///     type Output = f64;
+///
+///     fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+///         Ok(p[0].powi(2) + 2.0 * p[1].powi(2))
+///     }
+/// }
+///
+/// impl Gradient for Parabola {
+///     type Param = Vec<f64>;
+///     type Gradient = Vec<f64>;
+///
+///     fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+///         // Wrong: should be `4.0 * p[1]`
+///         Ok(vec![2.0 * p[0], 2.0 * p[1]])
+///     }
+/// }
+///
+/// # fn main() -> Result<(), Error> {
+/// let check = check_gradient(&Parabola {}, &[1.0, 1.0], 1e-6)?;
+///
+/// assert!(!check.passed());
+/// assert_eq!(check.index(), 1);
+/// # Ok(())
+/// # }
+/// ```
+pub fn check_gradient<P, F>(
+    problem: &P,
+    param: &[F],
+    tol: F,
+) -> Result<DerivativeCheck<F, usize>, Error>
+where
+    P: CostFunction<Param = Vec<F>, Output = F> + Gradient<Param = Vec<F>, Gradient = Vec<F>>,
+    F: ArgminFloat,
+{
+    let param = param.to_vec();
+    let analytic = problem.gradient(&param)?;
+    if analytic.len() != param.len() {
+        return Err(argmin_error!(
+            InvalidParameter,
+            "`check_gradient`: gradient and parameter vector differ in length."
+        ));
+    }
+    let approximation = FiniteDiff::new(CostOf(problem))
+        .with_scheme(FiniteDiffScheme::Central)
+        .gradient(&param)?;
+    DerivativeCheck::worst(
+        analytic.into_iter().enumerate(),
+        approximation.into_iter(),
+        tol,
+    )
+}
+
+/// Compares the Hessian of `problem` at `param` against central finite differences of its
+/// gradient
+///
+/// Returns the element with the largest discrepancy. Since the gradient is differentiated, it
+/// should have been checked with [`check_gradient`] first. An error is returned if the Hessian is
+/// not a square matrix matching the length of `param` or if `param` is empty.
+pub fn check_hessian<P, F>(
+    problem: &P,
+    param: &[F],
+    tol: F,
+) -> Result<DerivativeCheck<F, (usize, usize)>, Error>
+where
+    P: Gradient<Param = Vec<F>, Gradient = Vec<F>> + Hessian<Param = Vec<F>, Hessian = Vec<Vec<F>>>,
+    F: ArgminFloat,
+{
+    let n = param.len();
+    let param = param.to_vec();
+    let analytic = problem.hessian(&param)?;
+    if analytic.len() != n || analytic.iter().any(|row| row.len() != n) {
+        return Err(argmin_error!(
+            InvalidParameter,
+            "`check_hessian`: Hessian is not a square matrix matching the parameter vector."
+        ));
+    }
+    let approximation = FiniteDiff::new(GradientOf(problem))
+        .with_scheme(FiniteDiffScheme::Central)
+        .jacobian(&param)?;
+    DerivativeCheck::worst(
+        analytic
+            .into_iter()
+            .enumerate()
+            .flat_map(|(i, row)| row.into_iter().enumerate().map(move |(j, h)| ((i, j), h))),
+        approximation.into_iter().flatten(),
+        tol,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+    use approx::assert_relative_eq;
+    use argmin_testfunctions::{rosenbrock_2d, rosenbrock_2d_derivative, rosenbrock_2d_hessian};
+
+    /// Rosenbrock function with a bug in the derivatives if `buggy` is set
+    struct Rosenbrock {
+        buggy: bool,
+    }
+
+    impl CostFunction for Rosenbrock {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(rosenbrock_2d(p, 1.0, 100.0))
+        }
+    }
+
+    impl Gradient for Rosenbrock {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            let mut g = rosenbrock_2d_derivative(p, 1.0, 100.0);
+            if self.buggy {
+                g[1] *= 1.01;
+            }
+            Ok(g)
+        }
+    }
+
+    impl Hessian for Rosenbrock {
+        type Param = Vec<f64>;
+        type Hessian = Vec<Vec<f64>>;
+
+        fn hessian(&self, p: &Self::Param) -> Result<Self::Hessian, Error> {
+            let h = rosenbrock_2d_hessian(p, 1.0, 100.0);
+            let h10 = if self.buggy { 0.0 } else { h[2] };
+            Ok(vec![vec![h[0], h[1]], vec![h10, h[3]]])
+        }
+    }
+
+    #[test]
+    fn test_check_gradient() {
+        let param = [-1.2, 1.5];
+        let check = check_gradient(&Rosenbrock { buggy: false }, &param, 1e-6).unwrap();
+        assert!(check.passed());
+        assert!(check.error() < 1e-8);
+
+        let check = check_gradient(&Rosenbrock { buggy: true }, &param, 1e-6).unwrap();
+        assert!(!check.passed());
+        assert_eq!(check.index(), 1);
+        assert_relative_eq!(check.analytic(), 1.01 * 12.0, epsilon = 1e-12);
+        assert_relative_eq!(check.approximation(), 12.0, epsilon = 1e-6);
+        assert_relative_eq!(check.error(), 0.12 / 12.12, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_check_hessian() {
+        let param = [-1.2, 1.5];
+        let check = check_hessian(&Rosenbrock { buggy: false }, &param, 1e-6).unwrap();
+        assert!(check.passed());
+
+        // The buggy Hessian is compared against the (buggy) gradient which is scaled by `1.01`
+        let check = check_hessian(&Rosenbrock { buggy: true }, &param, 1e-6).unwrap();
+        assert!(!check.passed());
+        assert_eq!(check.index(), (1, 0));
+        assert_eq!(check.analytic().to_ne_bytes(), 0.0f64.to_ne_bytes());
+        assert_relative_eq!(check.approximation(), 1.01 * 480.0, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn test_check_invalid() {
+        /// Constant function with a gradient of the wrong length
+        struct Constant {}
+
+        impl CostFunction for Constant {
+            type Param = Vec<f64>;
+            type Output = f64;
+
+            fn cost(&self, _p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(1.0)
+            }
+        }
+
+        impl Gradient for Constant {
+            type Param = Vec<f64>;
+            type Gradient = Vec<f64>;
+
+            fn gradient(&self, _p: &Self::Param) -> Result<Self::Gradient, Error> {
+                Ok(vec![])
+            }
+        }
+
+        assert_error!(
+            check_gradient(&Constant {}, &[1.0], 1e-6),
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`check_gradient`: gradient and parameter vector differ in ",
+                "length.\""
+            )
+        );
+        assert_error!(
+            check_gradient(&Constant {}, &[], 1e-6),
+            ArgminError,
+            "Invalid parameter: \"Derivative check requires a non-empty parameter vector.\""
+        );
+    }
+}
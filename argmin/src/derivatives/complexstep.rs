@@ -0,0 +1,287 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{ArgminFloat, CostFunction, Error, Gradient};
+use alloc::vec::Vec;
+use core::ops::{Add, Div, Mul, Sub};
+use num_complex::{Complex, ComplexFloat};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Defines a cost function which is generic over real and complex scalars.
+///
+/// Implementing the cost function once for any [`ComplexFloat`] allows [`ComplexStep`] to evaluate
+/// it with plain floats as well as with complex numbers. The cost function must be analytic,
+/// which means that it must not use `abs`, comparisons or other operations which are not defined
+/// for complex numbers (or discard the imaginary part).
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::Error;
+/// use argmin::derivatives::ComplexCostFunction;
+/// use num_complex::ComplexFloat;
+///
+/// struct Rosenbrock {}
+///
+/// impl ComplexCostFunction for Rosenbrock {
+///     type Float = f64;
+///
+///     fn cost<T>(&self, p: &[T]) -> Result<T, Error>
+///     where
+///         T: ComplexFloat<Real = f64>
+///             + std::ops::Add<f64, Output = T>
+///             + std::ops::Sub<f64, Output = T>
+///             + std::ops::Mul<f64, Output = T>
+///             + std::ops::Div<f64, Output = T>,
+///     {
+///         Ok((-p[0] + 1.0).powi(2) + (p[1] - p[0].powi(2)).powi(2) * 100.0)
+///     }
+/// }
+/// ```
+pub trait ComplexCostFunction {
+    /// Underlying floating point type
+    type Float: ArgminFloat;
+
+    /// Compute cost function for a parameter vector of real or complex numbers
+    fn cost<T>(&self, param: &[T]) -> Result<T, Error>
+    where
+        T: ComplexFloat<Real = Self::Float>
+            + Add<Self::Float, Output = T>
+            + Sub<Self::Float, Output = T>
+            + Mul<Self::Float, Output = T>
+            + Div<Self::Float, Output = T>;
+}
+
+/// # Complex-step differentiation
+///
+/// Wraps a [`ComplexCostFunction`] and implements [`CostFunction`] and [`Gradient`] for it with
+/// `Vec<F>` as parameter vector and gradient.
+///
+/// The `i`-th partial derivative is computed as `Im(f(x + i h e_i)) / h`. In contrast to finite
+/// differences there is no subtraction of nearly equal function values, therefore `h` can be
+/// chosen tiny and the gradient is accurate to machine precision. It requires one evaluation of
+/// the cost function with complex numbers per parameter.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::core::{Error, Gradient};
+/// # use argmin::derivatives::{ComplexCostFunction, ComplexStep};
+/// # use num_complex::ComplexFloat;
+/// # use std::ops::{Add, Div, Mul, Sub};
+/// # struct Rosenbrock {}
+/// # impl ComplexCostFunction for Rosenbrock {
+/// #     type Float = f64;
+/// #     fn cost<T>(&self, p: &[T]) -> Result<T, Error>
+/// #     where
+/// #         T: ComplexFloat<Real = f64>
+/// #             + Add<f64, Output = T>
+/// #             + Sub<f64, Output = T>
+/// #             + Mul<f64, Output = T>
+/// #             + Div<f64, Output = T>,
+/// #     {
+/// #         Ok((-p[0] + 1.0).powi(2) + (p[1] - p[0].powi(2)).powi(2) * 100.0)
+/// #     }
+/// # }
+/// # fn main() -> Result<(), Error> {
+/// let problem = ComplexStep::new(Rosenbrock {});
+///
+/// let grad = problem.gradient(&vec![-1.2, 1.0])?;
+/// # assert!((grad[0] + 215.6).abs() < 1e-12 && (grad[1] + 88.0).abs() < 1e-12);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct ComplexStep<C, F = f64> {
+    /// Wrapped cost function
+    inner: C,
+    /// Imaginary step size
+    step: F,
+}
+
+impl<C, F> ComplexStep<C, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of `ComplexStep`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::derivatives::ComplexStep;
+    /// # struct UserDefinedProblem {};
+    /// let problem: ComplexStep<_, f64> = ComplexStep::new(UserDefinedProblem {});
+    /// ```
+    pub fn new(inner: C) -> Self {
+        ComplexStep {
+            inner,
+            step: F::epsilon() * F::epsilon(),
+        }
+    }
+
+    /// Set the imaginary step size `h`
+    ///
+    /// Must be positive. Defaults to `EPSILON^2`, which is small enough to make the truncation
+    /// error negligible while keeping the imaginary parts of intermediate results from
+    /// underflowing.
+    pub fn with_step(mut self, step: F) -> Result<Self, Error> {
+        if step <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ComplexStep`: step size must be positive."
+            ));
+        }
+        self.step = step;
+        Ok(self)
+    }
+
+    /// Returns a reference to the wrapped cost function
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    /// Returns the wrapped cost function
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+}
+
+impl<C, F> CostFunction for ComplexStep<C, F>
+where
+    C: ComplexCostFunction<Float = F>,
+    F: ArgminFloat + ComplexFloat<Real = F>,
+{
+    type Param = Vec<F>;
+    type Output = F;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        self.inner.cost(param)
+    }
+}
+
+impl<C, F> Gradient for ComplexStep<C, F>
+where
+    C: ComplexCostFunction<Float = F>,
+    F: ArgminFloat + ComplexFloat<Real = F>,
+{
+    type Param = Vec<F>;
+    type Gradient = Vec<F>;
+
+    fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+        let mut x: Vec<Complex<F>> = param.iter().map(|p| Complex::new(*p, F::zero())).collect();
+        (0..param.len())
+            .map(|i| {
+                x[i].im = self.step;
+                let df = self.inner.cost(&x)?.im / self.step;
+                x[i].im = F::zero();
+                Ok(df)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+    use argmin_testfunctions::{rosenbrock_2d, rosenbrock_2d_derivative};
+
+    test_trait_impl!(complex_step, ComplexStep<Rosenbrock, f64>);
+
+    #[derive(Clone, Debug, Default)]
+    struct Rosenbrock {}
+
+    impl ComplexCostFunction for Rosenbrock {
+        type Float = f64;
+
+        fn cost<T>(&self, p: &[T]) -> Result<T, Error>
+        where
+            T: ComplexFloat<Real = f64>
+                + Add<f64, Output = T>
+                + Sub<f64, Output = T>
+                + Mul<f64, Output = T>
+                + Div<f64, Output = T>,
+        {
+            Ok((-p[0] + 1.0).powi(2) + (p[1] - p[0].powi(2)).powi(2) * 100.0)
+        }
+    }
+
+    #[test]
+    fn test_cost() {
+        let problem = ComplexStep::new(Rosenbrock {});
+        let param = vec![-1.2, 1.5];
+        assert_relative_eq!(
+            problem.cost(&param).unwrap(),
+            rosenbrock_2d(&param, 1.0, 100.0),
+            epsilon = f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_gradient() {
+        let problem = ComplexStep::new(Rosenbrock {});
+        for param in [vec![-1.2, 1.5], vec![0.3, -2.0], vec![1.0, 1.0]] {
+            let grad = problem.gradient(&param).unwrap();
+            let target = rosenbrock_2d_derivative(&param, 1.0, 100.0);
+            for (g, t) in grad.iter().zip(target.iter()) {
+                assert_relative_eq!(*g, *t, epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_gradient_no_cancellation() {
+        // Finite differences lose all significant digits for `exp(x) / (sin(x)^3 + cos(x)^3)` at
+        // large function values with tiny steps, the complex step does not.
+        struct Squire {}
+
+        impl ComplexCostFunction for Squire {
+            type Float = f64;
+
+            fn cost<T>(&self, p: &[T]) -> Result<T, Error>
+            where
+                T: ComplexFloat<Real = f64>
+                    + Add<f64, Output = T>
+                    + Sub<f64, Output = T>
+                    + Mul<f64, Output = T>
+                    + Div<f64, Output = T>,
+            {
+                Ok(p[0].exp() / (p[0].sin().powi(3) + p[0].cos().powi(3)).sqrt())
+            }
+        }
+
+        let problem = ComplexStep::new(Squire {});
+        let grad = problem.gradient(&vec![1.5]).unwrap();
+        assert_relative_eq!(grad[0], 4.053_427_893_898_621, max_relative = 1e-14);
+    }
+
+    #[test]
+    fn test_with_step() {
+        let problem: ComplexStep<_, f64> = ComplexStep::new(Rosenbrock {});
+        assert_eq!(
+            problem.step.to_ne_bytes(),
+            (f64::EPSILON * f64::EPSILON).to_ne_bytes()
+        );
+        let problem = problem.with_step(1e-100).unwrap();
+        assert_eq!(problem.step.to_ne_bytes(), 1e-100f64.to_ne_bytes());
+        let _: &Rosenbrock = problem.inner();
+        let _: Rosenbrock = problem.into_inner();
+
+        for step in [0.0, -1.0] {
+            let res = ComplexStep::new(Rosenbrock {}).with_step(step);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`ComplexStep`: step size must be positive.\""
+            );
+        }
+    }
+}
@@ -0,0 +1,357 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{ArgminFloat, CostFunction, Error, Gradient, Hessian};
+use alloc::sync::Arc;
+use argmin_math::{ArgminL2Norm, ArgminMul, ArgminScaledAdd, ArgminSub, LinearOperator};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Hessian-vector products by finite differences of the gradient
+///
+/// Wraps a problem which implements [`CostFunction`] and [`Gradient`] and implements [`Hessian`]
+/// for it. The "Hessian" is a [`HessianVectorProduct`], which is not a matrix but a
+/// [`LinearOperator`] approximating the product of the Hessian with a vector `v` by
+///
+/// `H(x) * v ≈ (g(x + h * v) - g(x)) / h`,
+///
+/// which costs a single gradient evaluation per product (the gradient at `x` is computed once
+/// when the Hessian is requested). This allows using solvers which only need Hessian-vector
+/// products, such as [`NewtonCG`](`crate::solver::newton::NewtonCG`) and
+/// [`TrustRegion`](`crate::solver::trustregion::TrustRegion`) with
+/// [`GLTR`](`crate::solver::trustregion::GLTR`) or
+/// [`Steihaug`](`crate::solver::trustregion::Steihaug`), without implementing the Hessian.
+///
+/// The step is `h = sqrt(EPSILON) * (1 + ||x||) / ||v||`. Since the products are computed outside
+/// of the solver, the gradient evaluations they require are not included in the function
+/// evaluation counts.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::core::{CostFunction, Error, Gradient, Hessian};
+/// # use argmin::derivatives::FiniteDiffHessianProduct;
+/// # use argmin_math::LinearOperator;
+/// struct Parabola {}
+///
+/// impl CostFunction for Parabola {
+///     type Param = Vec<f64>;
+///     type Output = f64;
+///
+///     fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+///         Ok(p[0].powi(2) + 3.0 * p[1].powi(2))
+///     }
+/// }
+///
+/// impl Gradient for Parabola {
+///     type Param = Vec<f64>;
+///     type Gradient = Vec<f64>;
+///
+///     fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+///         Ok(vec![2.0 * p[0], 6.0 * p[1]])
+///     }
+/// }
+///
+/// # fn main() -> Result<(), Error> {
+/// let problem = FiniteDiffHessianProduct::new(Parabola {});
+///
+/// let hessian = problem.hessian(&vec![1.0, 1.0])?;
+/// let hv = hessian.apply(&vec![1.0, 1.0])?;
+/// # assert!((hv[0] - 2.0).abs() < 1e-6 && (hv[1] - 6.0).abs() < 1e-6);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct FiniteDiffHessianProduct<O, F = f64> {
+    /// Wrapped problem, shared with the Hessian-vector product operators
+    inner: Arc<O>,
+    /// Relative step size
+    eta: F,
+}
+
+impl<O, F> Clone for FiniteDiffHessianProduct<O, F>
+where
+    F: Copy,
+{
+    fn clone(&self) -> Self {
+        FiniteDiffHessianProduct {
+            inner: Arc::clone(&self.inner),
+            eta: self.eta,
+        }
+    }
+}
+
+impl<O, F> FiniteDiffHessianProduct<O, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of `FiniteDiffHessianProduct`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::derivatives::FiniteDiffHessianProduct;
+    /// # struct UserDefinedProblem {};
+    /// let problem: FiniteDiffHessianProduct<_, f64> =
+    ///     FiniteDiffHessianProduct::new(UserDefinedProblem {});
+    /// ```
+    pub fn new(inner: O) -> Self {
+        FiniteDiffHessianProduct {
+            inner: Arc::new(inner),
+            eta: F::epsilon().sqrt(),
+        }
+    }
+
+    /// Returns a reference to the wrapped problem
+    pub fn inner(&self) -> &O {
+        &self.inner
+    }
+}
+
+impl<O, F> CostFunction for FiniteDiffHessianProduct<O, F>
+where
+    O: CostFunction,
+{
+    type Param = O::Param;
+    type Output = O::Output;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        self.inner.cost(param)
+    }
+}
+
+impl<O, F> Gradient for FiniteDiffHessianProduct<O, F>
+where
+    O: Gradient,
+{
+    type Param = O::Param;
+    type Gradient = O::Gradient;
+
+    fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+        self.inner.gradient(param)
+    }
+}
+
+impl<O, P, F> Hessian for FiniteDiffHessianProduct<O, F>
+where
+    O: Gradient<Param = P, Gradient = P>,
+    P: Clone,
+    F: ArgminFloat,
+{
+    type Param = P;
+    type Hessian = HessianVectorProduct<O, P, F>;
+
+    fn hessian(&self, param: &Self::Param) -> Result<Self::Hessian, Error> {
+        Ok(HessianVectorProduct {
+            problem: Some(Arc::clone(&self.inner)),
+            gradient: self.inner.gradient(param)?,
+            param: param.clone(),
+            eta: self.eta,
+        })
+    }
+}
+
+/// Hessian of a problem at a point, available only via Hessian-vector products
+///
+/// Created by [`FiniteDiffHessianProduct`]; see there for details. Products are computed with the
+/// gradient of the wrapped problem, which is not serialized. An instance restored from a
+/// checkpoint therefore cannot compute products; solvers request a new Hessian in each iteration
+/// anyway.
+///
+/// # Errors
+///
+/// [`apply`](`LinearOperator::apply`) returns an error if the gradient cannot be evaluated or if
+/// the instance was deserialized.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct HessianVectorProduct<O, P, F> {
+    /// Problem providing the gradient
+    #[cfg_attr(feature = "serde1", serde(skip))]
+    problem: Option<Arc<O>>,
+    /// Point at which the Hessian is evaluated
+    param: P,
+    /// Gradient at `param`
+    gradient: P,
+    /// Relative step size
+    eta: F,
+}
+
+impl<O, P, F> Clone for HessianVectorProduct<O, P, F>
+where
+    P: Clone,
+    F: Copy,
+{
+    fn clone(&self) -> Self {
+        HessianVectorProduct {
+            problem: self.problem.clone(),
+            param: self.param.clone(),
+            gradient: self.gradient.clone(),
+            eta: self.eta,
+        }
+    }
+}
+
+impl<O, P, F> LinearOperator<P> for HessianVectorProduct<O, P, F>
+where
+    O: Gradient<Param = P, Gradient = P>,
+    P: ArgminScaledAdd<P, F, P> + ArgminSub<P, P> + ArgminMul<F, P> + ArgminL2Norm<F>,
+    F: ArgminFloat,
+{
+    fn apply(&self, v: &P) -> Result<P, Error> {
+        let v_norm = v.l2_norm();
+        if v_norm == float!(0.0) {
+            return Ok(v.mul(&float!(0.0)));
+        }
+        let h = self.eta * (float!(1.0) + self.param.l2_norm()) / v_norm;
+        let problem = self.problem.as_ref().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            "`HessianVectorProduct`: problem not available after deserialization."
+        ))?;
+        let gradient = problem.gradient(&self.param.scaled_add(&h, v))?;
+        Ok(gradient.sub(&self.gradient).mul(&(float!(1.0) / h)))
+    }
+
+    fn apply_transpose(&self, v: &P) -> Result<P, Error> {
+        // symmetric
+        self.apply(v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+    #[cfg(feature = "std")]
+    use crate::core::{Executor, State};
+    #[cfg(feature = "std")]
+    use crate::solver::linesearch::MoreThuenteLineSearch;
+    #[cfg(feature = "std")]
+    use crate::solver::newton::NewtonCG;
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+    use argmin_testfunctions::{rosenbrock_2d, rosenbrock_2d_derivative, rosenbrock_2d_hessian};
+
+    test_trait_impl!(
+        finite_diff_hessian_product,
+        FiniteDiffHessianProduct<Rosenbrock, f64>
+    );
+
+    test_trait_impl!(
+        hessian_vector_product,
+        HessianVectorProduct<Rosenbrock, Vec<f64>, f64>
+    );
+
+    #[derive(Clone, Debug, Default)]
+    #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+    struct Rosenbrock {}
+
+    impl CostFunction for Rosenbrock {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(rosenbrock_2d(p, 1.0, 100.0))
+        }
+    }
+
+    impl Gradient for Rosenbrock {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(rosenbrock_2d_derivative(p, 1.0, 100.0))
+        }
+    }
+
+    impl Hessian for Rosenbrock {
+        type Param = Vec<f64>;
+        type Hessian = Vec<Vec<f64>>;
+
+        fn hessian(&self, p: &Self::Param) -> Result<Self::Hessian, Error> {
+            let h = rosenbrock_2d_hessian(p, 1.0, 100.0);
+            Ok(vec![vec![h[0], h[1]], vec![h[2], h[3]]])
+        }
+    }
+
+    #[test]
+    fn test_apply() {
+        let problem = FiniteDiffHessianProduct::new(Rosenbrock {});
+        let param = vec![-1.2, 1.5];
+        let hessian = problem.hessian(&param).unwrap();
+        let target = rosenbrock_2d_hessian(&param, 1.0, 100.0);
+        for v in [vec![1.0, 0.0], vec![0.0, 1.0], vec![0.3, -2.0]] {
+            let hv = hessian.apply(&v).unwrap();
+            let target = [
+                target[0] * v[0] + target[1] * v[1],
+                target[2] * v[0] + target[3] * v[1],
+            ];
+            for (a, t) in hv.iter().zip(target.iter()) {
+                assert_relative_eq!(*a, *t, max_relative = 1e-5);
+            }
+            assert_eq!(hessian.apply_transpose(&v).unwrap(), hv);
+        }
+        assert_eq!(hessian.apply(&vec![0.0, 0.0]).unwrap(), vec![0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_apply_errors() {
+        struct Failing {}
+
+        impl Gradient for Failing {
+            type Param = Vec<f64>;
+            type Gradient = Vec<f64>;
+
+            fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+                if p[0] > 1.0 {
+                    Err(argmin_error!(ConditionViolated, "outside of domain"))
+                } else {
+                    Ok(p.clone())
+                }
+            }
+        }
+
+        let problem: FiniteDiffHessianProduct<_, f64> = FiniteDiffHessianProduct::new(Failing {});
+        let mut hessian = problem.hessian(&vec![1.0, 0.0]).unwrap();
+        assert_error!(
+            hessian.apply(&vec![1.0, 0.0]),
+            ArgminError,
+            "Condition violated: \"outside of domain\""
+        );
+
+        // Deserialized instances have no access to the problem
+        hessian.problem = None;
+        assert_error!(
+            hessian.apply(&vec![1.0, 0.0]),
+            ArgminError,
+            "Not initialized: \"`HessianVectorProduct`: problem not available after deserialization.\""
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_newton_cg() {
+        // Newton-CG takes the same path with Hessian-vector products as with the exact Hessian
+        let linesearch: MoreThuenteLineSearch<Vec<f64>, Vec<f64>, f64> =
+            MoreThuenteLineSearch::new();
+        let exact = Executor::new(Rosenbrock {}, NewtonCG::new(linesearch.clone()))
+            .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(20))
+            .run()
+            .unwrap();
+        let res = Executor::new(
+            FiniteDiffHessianProduct::new(Rosenbrock {}),
+            NewtonCG::new(linesearch),
+        )
+        .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(20))
+        .run()
+        .unwrap();
+        let target = exact.state().get_best_param().unwrap();
+        let best = res.state().get_best_param().unwrap();
+        assert_relative_eq!(best[0], target[0], epsilon = 1e-5);
+        assert_relative_eq!(best[1], target[1], epsilon = 1e-5);
+    }
+}
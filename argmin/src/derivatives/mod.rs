@@ -0,0 +1,872 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Numerical derivatives
+//!
+//! [`FiniteDiff`] wraps a problem which only implements [`CostFunction`] and/or [`Operator`] and
+//! approximates [`Gradient`], [`Hessian`] and [`Jacobian`] by finite differences. This allows
+//! using derivative-based solvers on black-box problems. Parameter vectors are `Vec<F>`,
+//! gradients `Vec<F>` and Hessians and Jacobians `Vec<Vec<F>>`.
+//!
+//! ## Step sizes
+//!
+//! The step size is chosen per variable as `h_i = eta * max(|x_i|, s_i)`, where `s_i` is the
+//! typical magnitude of the `i`-th variable (`1` by default, see
+//! [`with_typical_values`](`FiniteDiff::with_typical_values`)) and `eta` balances truncation and
+//! rounding errors of the respective difference scheme: `sqrt(EPSILON)` for forward differences,
+//! `EPSILON^(1/3)` for central differences and `EPSILON^(1/4)` for second derivatives. Steps are
+//! adjusted such that `x_i + h_i` is exactly representable.
+//!
+//! Forward differences (the default) require `n` additional cost function evaluations per
+//! gradient and are accurate to about half of the significant digits; central differences
+//! require `2 n` evaluations and are accurate to about two thirds. Hessians are always computed
+//! with central differences of the cost function, which requires `2 n^2 + 1` evaluations.
+//! [Richardson extrapolation](`FiniteDiffScheme::Richardson`) chooses the step adaptively per
+//! variable and typically achieves close to full accuracy at the cost of up to `20 n`
+//! evaluations.
+//!
+//! ## Sparse Jacobians
+//!
+//! If each residual only depends on a few variables, columns of the Jacobian which do not share a
+//! nonzero row can be estimated from the same residual evaluation by perturbing all of their
+//! variables at once (Curtis, Powell and Reid). Given the sparsity pattern via
+//! [`with_sparsity`](`FiniteDiff::with_sparsity`), the columns are grouped greedily, which
+//! reduces the number of residual evaluations from `n` to the number of groups. For a banded
+//! Jacobian with bandwidth `b` this is `b` instead of `n`.
+//!
+//! If exact derivatives can be obtained, they should be preferred, for instance via
+//! `ForwardDiff` (automatic differentiation, requires the `num-dual` feature). For cost functions
+//! which are generic over the scalar type, [`ComplexStep`] computes gradients accurate to machine
+//! precision. Models built with reverse-mode automatic differentiation libraries can be plugged
+//! in via [`ReverseDiff`]. [`FiniteDiffHessianProduct`] approximates Hessian-vector products by
+//! finite differences of the gradient for solvers which do not need the full Hessian.
+//! [`GaussianSmoothing`] estimates gradients of non-differentiable or noisy functions from random
+//! directional differences.
+//!
+//! # Example
+//!
+//! ```
+//! # use argmin::core::{CostFunction, Error, Gradient};
+//! # use argmin::derivatives::{FiniteDiff, FiniteDiffScheme};
+//! struct Rosenbrock {}
+//!
+//! impl CostFunction for Rosenbrock {
+//!     type Param = Vec<f64>;
+//!     type Output = f64;
+//!
+//!     fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+//!         Ok((1.0 - p[0]).powi(2) + 100.0 * (p[1] - p[0].powi(2)).powi(2))
+//!     }
+//! }
+//!
+//! # fn main() -> Result<(), Error> {
+//! let problem = FiniteDiff::new(Rosenbrock {}).with_scheme(FiniteDiffScheme::Central);
+//! let grad = problem.gradient(&vec![1.0, 1.0])?;
+//! # assert!(grad[0].abs() < 1e-6 && grad[1].abs() < 1e-6);
+//! # Ok(())
+//! # }
+//! ```
+
+mod check;
+mod complexstep;
+mod hvp;
+mod reverse;
+mod smoothing;
+
+pub use check::{check_gradient, check_hessian, DerivativeCheck};
+pub use complexstep::{ComplexCostFunction, ComplexStep};
+pub use hvp::{FiniteDiffHessianProduct, HessianVectorProduct};
+pub use reverse::{ReverseCostFunction, ReverseDiff};
+pub use smoothing::GaussianSmoothing;
+
+use crate::core::{ArgminFloat, CostFunction, Error, Gradient, Hessian, Jacobian, Operator};
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Difference scheme for first derivatives
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub enum FiniteDiffScheme {
+    /// `(f(x + h) - f(x)) / h`
+    #[default]
+    Forward,
+    /// `(f(x + h) - f(x - h)) / (2 h)`
+    Central,
+    /// Central differences with Richardson extrapolation to zero step size
+    ///
+    /// Starts with a large step, which is successively reduced by a factor of `1.4`. The central
+    /// differences of all steps are extrapolated with Neville's algorithm (Ridders' method) and
+    /// the extrapolation with the smallest error estimate is chosen for each variable. The
+    /// iteration stops early once the error grows, but requires up to `20` function evaluations per
+    /// variable. This is considerably more accurate than plain central differences and more robust
+    /// for functions with a little noise, since the steps are large.
+    Richardson,
+}
+
+/// # Finite differences
+///
+/// Wraps a problem and approximates its derivatives by finite differences (see the
+/// [module documentation](`crate::derivatives`)). [`CostFunction`] and [`Operator`] are passed
+/// through to the wrapped problem.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct FiniteDiff<P, F = f64> {
+    /// Wrapped problem
+    inner: P,
+    /// Difference scheme for first derivatives
+    scheme: FiniteDiffScheme,
+    /// Typical magnitudes of the variables
+    typical_values: Option<Vec<F>>,
+    /// Sparsity pattern of the Jacobian
+    sparsity: Option<JacobianSparsity>,
+}
+
+/// Sparsity pattern of a Jacobian with its columns grouped for finite differences
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+struct JacobianSparsity {
+    /// Number of rows
+    rows: usize,
+    /// Rows of the nonzero elements of each column
+    columns: Vec<Vec<usize>>,
+    /// Groups of columns which do not share any nonzero row
+    groups: Vec<Vec<usize>>,
+}
+
+impl JacobianSparsity {
+    /// Groups the columns of the pattern (nonzero columns of each row) greedily: each column is
+    /// assigned to the first group in which none of its rows is taken yet (Curtis-Powell-Reid).
+    fn new(pattern: &[Vec<usize>]) -> Self {
+        let n = pattern.iter().flatten().map(|j| j + 1).max().unwrap_or(0);
+        let mut columns = vec![vec![]; n];
+        for (i, row) in pattern.iter().enumerate() {
+            for &j in row {
+                if !columns[j].contains(&i) {
+                    columns[j].push(i);
+                }
+            }
+        }
+        // Groups of columns and the rows taken by each group
+        let mut groups: Vec<(Vec<usize>, Vec<bool>)> = vec![];
+        for (j, rows) in columns.iter().enumerate().filter(|(_, r)| !r.is_empty()) {
+            match groups
+                .iter_mut()
+                .find(|(_, taken)| rows.iter().all(|&i| !taken[i]))
+            {
+                Some((group, taken)) => {
+                    group.push(j);
+                    rows.iter().for_each(|&i| taken[i] = true);
+                }
+                None => {
+                    let mut taken = vec![false; pattern.len()];
+                    rows.iter().for_each(|&i| taken[i] = true);
+                    groups.push((vec![j], taken));
+                }
+            }
+        }
+        JacobianSparsity {
+            rows: pattern.len(),
+            columns,
+            groups: groups.into_iter().map(|(group, _)| group).collect(),
+        }
+    }
+}
+
+impl<P, F> FiniteDiff<P, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of `FiniteDiff`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::derivatives::FiniteDiff;
+    /// # struct UserDefinedProblem {};
+    /// let problem: FiniteDiff<_, f64> = FiniteDiff::new(UserDefinedProblem {});
+    /// ```
+    pub fn new(inner: P) -> Self {
+        FiniteDiff {
+            inner,
+            scheme: FiniteDiffScheme::Forward,
+            typical_values: None,
+            sparsity: None,
+        }
+    }
+
+    /// Set the difference scheme for gradients and Jacobians
+    ///
+    /// Defaults to [`FiniteDiffScheme::Forward`].
+    pub fn with_scheme(mut self, scheme: FiniteDiffScheme) -> Self {
+        self.scheme = scheme;
+        self
+    }
+
+    /// Set the typical magnitudes of the variables
+    ///
+    /// The step sizes are scaled by `max(|x_i|, s_i)`, therefore variables whose magnitude is far
+    /// from `1` should be given their typical magnitude `s_i` such that steps are neither too
+    /// small nor too large close to zero. All values must be positive. Defaults to `1` for all
+    /// variables.
+    pub fn with_typical_values(mut self, typical_values: Vec<F>) -> Result<Self, Error> {
+        if typical_values.iter().any(|s| *s <= float!(0.0)) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`FiniteDiff`: typical values must be positive."
+            ));
+        }
+        self.typical_values = Some(typical_values);
+        Ok(self)
+    }
+
+    /// Set the sparsity pattern of the Jacobian
+    ///
+    /// `pattern[i]` holds the indices of the variables the `i`-th residual depends on. Columns
+    /// without nonzero elements are never perturbed and are zero in the Jacobian. Only affects
+    /// [`Jacobian`]; the number of residual evaluations reduces to the number of column groups
+    /// (plus one for forward differences), see
+    /// [`num_column_groups`](`FiniteDiff::num_column_groups`). With a sparsity pattern,
+    /// [`FiniteDiffScheme::Richardson`] falls back to central differences.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::derivatives::FiniteDiff;
+    /// # struct UserDefinedProblem {};
+    /// // Tridiagonal Jacobian of 5 residuals
+    /// let pattern = (0..5usize)
+    ///     .map(|i| (i.saturating_sub(1)..(i + 2).min(5)).collect())
+    ///     .collect();
+    /// let problem: FiniteDiff<_, f64> =
+    ///     FiniteDiff::new(UserDefinedProblem {}).with_sparsity(pattern);
+    /// assert_eq!(problem.num_column_groups(), Some(3));
+    /// ```
+    pub fn with_sparsity(mut self, pattern: Vec<Vec<usize>>) -> Self {
+        self.sparsity = Some(JacobianSparsity::new(&pattern));
+        self
+    }
+
+    /// Returns the number of column groups of the sparsity pattern, if set
+    pub fn num_column_groups(&self) -> Option<usize> {
+        self.sparsity.as_ref().map(|s| s.groups.len())
+    }
+
+    /// Returns a reference to the wrapped problem
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// Returns the wrapped problem
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    /// Step size for variable `i` at `x_i` with relative step `eta`
+    fn step(&self, i: usize, x: F, eta: F) -> Result<F, Error> {
+        let typical = match self.typical_values.as_ref() {
+            Some(s) => *s.get(i).ok_or_else(argmin_error_closure!(
+                InvalidParameter,
+                "`FiniteDiff`: number of typical values does not match parameter vector."
+            ))?,
+            None => float!(1.0),
+        };
+        let h = eta * x.abs().max(typical);
+        // Exactly representable difference between `x + h` and `x`
+        Ok((x + h) - x)
+    }
+
+    /// Relative step of the first derivatives
+    fn eta(&self) -> F {
+        match self.scheme {
+            FiniteDiffScheme::Forward => F::epsilon().sqrt(),
+            FiniteDiffScheme::Central | FiniteDiffScheme::Richardson => F::epsilon().cbrt(),
+        }
+    }
+
+    /// Derivatives of `f` with respect to all variables at `x` by Richardson extrapolation of
+    /// central differences (Ridders' method)
+    ///
+    /// `f` may be vector valued; the error estimate is the largest error of all components.
+    fn extrapolate(
+        &self,
+        x: &[F],
+        f: impl Fn(&Vec<F>) -> Result<Vec<F>, Error>,
+    ) -> Result<Vec<Vec<F>>, Error> {
+        /// Maximum number of step sizes
+        const STEPS: usize = 10;
+        let shrink: F = float!(1.4);
+        let safe: F = float!(2.0);
+        let mut x = x.to_vec();
+        (0..x.len())
+            .map(|i| {
+                let xi = x[i];
+                let mut h = self.step(i, xi, float!(0.1))?;
+                let mut central = |h: F| -> Result<Vec<F>, Error> {
+                    x[i] = xi + h;
+                    let f1 = f(&x)?;
+                    x[i] = xi - h;
+                    let f2 = f(&x)?;
+                    x[i] = xi;
+                    Ok(f1
+                        .iter()
+                        .zip(f2.iter())
+                        .map(|(a, b)| (*a - *b) / (h + h))
+                        .collect())
+                };
+                // Neville tableau: `table[k][j]` is the `j`-th extrapolation of the `k`-th step
+                let mut table: Vec<Vec<Vec<F>>> = vec![vec![central(h)?]];
+                let mut best = table[0][0].clone();
+                let mut error = F::infinity();
+                for k in 1..STEPS {
+                    h = h / shrink;
+                    let mut row = vec![central(h)?];
+                    let mut factor = shrink * shrink;
+                    for j in 1..=k {
+                        let extrapolated: Vec<F> = row[j - 1]
+                            .iter()
+                            .zip(table[k - 1][j - 1].iter())
+                            .map(|(a, b)| (*a * factor - *b) / (factor - float!(1.0)))
+                            .collect();
+                        factor = factor * shrink * shrink;
+                        let deviation = |other: &Vec<F>| {
+                            extrapolated
+                                .iter()
+                                .zip(other.iter())
+                                .fold(float!(0.0), |acc: F, (a, b)| acc.max((*a - *b).abs()))
+                        };
+                        let estimate = deviation(&row[j - 1]).max(deviation(&table[k - 1][j - 1]));
+                        if estimate <= error {
+                            error = estimate;
+                            best.clone_from(&extrapolated);
+                        }
+                        row.push(extrapolated);
+                    }
+                    // Stop once the highest order extrapolation deteriorates
+                    let growth = row[k]
+                        .iter()
+                        .zip(table[k - 1][k - 1].iter())
+                        .fold(float!(0.0), |acc: F, (a, b)| acc.max((*a - *b).abs()));
+                    table.push(row);
+                    if growth >= safe * error {
+                        break;
+                    }
+                }
+                Ok(best)
+            })
+            .collect()
+    }
+
+    /// Derivatives of `f` with respect to all variables at `x`
+    ///
+    /// `combine(f(x + h e_i), f(x) or f(x - h e_i), h)` computes the derivative from the function
+    /// values.
+    fn differentiate<T, G>(
+        &self,
+        x: &[F],
+        fx: impl FnOnce() -> Result<T, Error>,
+        f: impl Fn(&Vec<F>) -> Result<T, Error>,
+        combine: impl Fn(T, &T, F) -> G,
+    ) -> Result<Vec<G>, Error> {
+        let eta = self.eta();
+        let mut x = x.to_vec();
+        match self.scheme {
+            FiniteDiffScheme::Forward => {
+                let f0 = fx()?;
+                (0..x.len())
+                    .map(|i| {
+                        let xi = x[i];
+                        let h = self.step(i, xi, eta)?;
+                        x[i] = xi + h;
+                        let f1 = f(&x)?;
+                        x[i] = xi;
+                        Ok(combine(f1, &f0, h))
+                    })
+                    .collect()
+            }
+            // Richardson extrapolation is handled by `extrapolate`
+            FiniteDiffScheme::Central | FiniteDiffScheme::Richardson => (0..x.len())
+                .map(|i| {
+                    let xi = x[i];
+                    let h = self.step(i, xi, eta)?;
+                    x[i] = xi + h;
+                    let f1 = f(&x)?;
+                    x[i] = xi - h;
+                    let f2 = f(&x)?;
+                    x[i] = xi;
+                    Ok(combine(f1, &f2, h + h))
+                })
+                .collect(),
+        }
+    }
+}
+
+impl<P, F> CostFunction for FiniteDiff<P, F>
+where
+    P: CostFunction,
+{
+    type Param = P::Param;
+    type Output = P::Output;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        self.inner.cost(param)
+    }
+}
+
+impl<P, F> Operator for FiniteDiff<P, F>
+where
+    P: Operator,
+{
+    type Param = P::Param;
+    type Output = P::Output;
+
+    fn apply(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        self.inner.apply(param)
+    }
+}
+
+impl<P, F> Gradient for FiniteDiff<P, F>
+where
+    P: CostFunction<Param = Vec<F>, Output = F>,
+    F: ArgminFloat,
+{
+    type Param = Vec<F>;
+    type Gradient = Vec<F>;
+
+    fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+        if self.scheme == FiniteDiffScheme::Richardson {
+            return Ok(self
+                .extrapolate(param, |x| Ok(vec![self.inner.cost(x)?]))?
+                .into_iter()
+                .map(|d| d[0])
+                .collect());
+        }
+        self.differentiate(
+            param,
+            || self.inner.cost(param),
+            |x| self.inner.cost(x),
+            |f1, f2, h| (f1 - *f2) / h,
+        )
+    }
+}
+
+impl<P, F> Jacobian for FiniteDiff<P, F>
+where
+    P: Operator<Param = Vec<F>, Output = Vec<F>>,
+    F: ArgminFloat,
+{
+    type Param = Vec<F>;
+    type Jacobian = Vec<Vec<F>>;
+
+    /// Returns the Jacobian with one row per output and one column per variable
+    fn jacobian(&self, param: &Self::Param) -> Result<Self::Jacobian, Error> {
+        if let Some(sparsity) = self.sparsity.as_ref() {
+            return self.sparse_jacobian(sparsity, param);
+        }
+        // Columns of the Jacobian
+        let columns = if self.scheme == FiniteDiffScheme::Richardson {
+            self.extrapolate(param, |x| self.inner.apply(x))?
+        } else {
+            self.differentiate(
+                param,
+                || self.inner.apply(param),
+                |x| self.inner.apply(x),
+                |f1, f2, h| {
+                    f1.iter()
+                        .zip(f2.iter())
+                        .map(|(a, b)| (*a - *b) / h)
+                        .collect::<Vec<F>>()
+                },
+            )?
+        };
+        let m = columns.first().map(|c| c.len()).unwrap_or(0);
+        Ok((0..m)
+            .map(|i| columns.iter().map(|c| c[i]).collect())
+            .collect())
+    }
+}
+
+impl<P, F> FiniteDiff<P, F>
+where
+    P: Operator<Param = Vec<F>, Output = Vec<F>>,
+    F: ArgminFloat,
+{
+    /// Jacobian with the given sparsity, perturbing all columns of a group at once
+    fn sparse_jacobian(
+        &self,
+        sparsity: &JacobianSparsity,
+        param: &[F],
+    ) -> Result<Vec<Vec<F>>, Error> {
+        let n = param.len();
+        if sparsity.columns.len() > n {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`FiniteDiff`: sparsity pattern refers to variables beyond the parameter vector."
+            ));
+        }
+        let apply = |x: &Vec<F>| -> Result<Vec<F>, Error> {
+            let fx = self.inner.apply(x)?;
+            if fx.len() != sparsity.rows {
+                return Err(argmin_error!(
+                    InvalidParameter,
+                    "`FiniteDiff`: sparsity pattern does not match the number of outputs."
+                ));
+            }
+            Ok(fx)
+        };
+        let eta = self.eta();
+        let mut x = param.to_vec();
+        let f0 = match self.scheme {
+            FiniteDiffScheme::Forward => Some(apply(&x)?),
+            FiniteDiffScheme::Central | FiniteDiffScheme::Richardson => None,
+        };
+        let mut jacobian = vec![vec![float!(0.0); n]; sparsity.rows];
+        for group in sparsity.groups.iter() {
+            let h = group
+                .iter()
+                .map(|&j| self.step(j, param[j], eta))
+                .collect::<Result<Vec<F>, Error>>()?;
+            group
+                .iter()
+                .zip(h.iter())
+                .for_each(|(&j, hj)| x[j] = param[j] + *hj);
+            let f1 = apply(&x)?;
+            let f2_central;
+            let (f2, factor) = match f0.as_ref() {
+                Some(f0) => (f0, float!(1.0)),
+                None => {
+                    group
+                        .iter()
+                        .zip(h.iter())
+                        .for_each(|(&j, hj)| x[j] = param[j] - *hj);
+                    f2_central = apply(&x)?;
+                    (&f2_central, float!(2.0))
+                }
+            };
+            group.iter().for_each(|&j| x[j] = param[j]);
+            for (&j, hj) in group.iter().zip(h.iter()) {
+                for &i in sparsity.columns[j].iter() {
+                    jacobian[i][j] = (f1[i] - f2[i]) / (factor * *hj);
+                }
+            }
+        }
+        Ok(jacobian)
+    }
+}
+
+impl<P, F> Hessian for FiniteDiff<P, F>
+where
+    P: CostFunction<Param = Vec<F>, Output = F>,
+    F: ArgminFloat,
+{
+    type Param = Vec<F>;
+    type Hessian = Vec<Vec<F>>;
+
+    fn hessian(&self, param: &Self::Param) -> Result<Self::Hessian, Error> {
+        let n = param.len();
+        let eta = F::epsilon().powf(float!(0.25));
+        let h = (0..n)
+            .map(|i| self.step(i, param[i], eta))
+            .collect::<Result<Vec<F>, Error>>()?;
+        let f0 = self.inner.cost(param)?;
+        let mut x = param.clone();
+        let mut hessian = vec![vec![float!(0.0); n]; n];
+        for i in 0..n {
+            x[i] = param[i] + h[i];
+            let fp = self.inner.cost(&x)?;
+            x[i] = param[i] - h[i];
+            let fm = self.inner.cost(&x)?;
+            hessian[i][i] = (fp - float!(2.0) * f0 + fm) / (h[i] * h[i]);
+            for j in i + 1..n {
+                let mut f = [float!(0.0); 4];
+                for (k, (si, sj)) in [(1.0, 1.0), (1.0, -1.0), (-1.0, 1.0), (-1.0, -1.0)]
+                    .into_iter()
+                    .enumerate()
+                {
+                    x[i] = param[i] + float!(si) * h[i];
+                    x[j] = param[j] + float!(sj) * h[j];
+                    f[k] = self.inner.cost(&x)?;
+                }
+                x[j] = param[j];
+                let hij = (f[0] - f[1] - f[2] + f[3]) / (float!(4.0) * h[i] * h[j]);
+                hessian[i][j] = hij;
+                hessian[j][i] = hij;
+            }
+            x[i] = param[i];
+        }
+        Ok(hessian)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+    use argmin_testfunctions::{rosenbrock_2d, rosenbrock_2d_derivative, rosenbrock_2d_hessian};
+
+    test_trait_impl!(finite_diff, FiniteDiff<Rosenbrock, f64>);
+
+    #[derive(Clone, Debug, Default)]
+    struct Rosenbrock {}
+
+    impl CostFunction for Rosenbrock {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(rosenbrock_2d(p, 1.0, 100.0))
+        }
+    }
+
+    impl Operator for Rosenbrock {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+
+        fn apply(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(vec![1.0 - p[0], 10.0 * (p[1] - p[0].powi(2))])
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let problem: FiniteDiff<_, f64> = FiniteDiff::new(Rosenbrock {});
+        assert_eq!(problem.scheme, FiniteDiffScheme::Forward);
+        assert!(problem.typical_values.is_none());
+        assert!(problem.sparsity.is_none());
+        assert_eq!(problem.num_column_groups(), None);
+        let _: &Rosenbrock = problem.inner();
+        let _: Rosenbrock = problem.into_inner();
+    }
+
+    #[test]
+    fn test_gradient() {
+        // (scheme, absolute tolerance, relative tolerance)
+        for (scheme, epsilon, max_relative) in [
+            (FiniteDiffScheme::Forward, 1e-5, 1e-6),
+            (FiniteDiffScheme::Central, 1e-7, 1e-8),
+            (FiniteDiffScheme::Richardson, 1e-10, 1e-12),
+        ] {
+            let problem = FiniteDiff::new(Rosenbrock {}).with_scheme(scheme);
+            for param in [vec![-1.2, 1.5], vec![0.3, -2.0], vec![1.0, 1.0]] {
+                let grad = problem.gradient(&param).unwrap();
+                let target = rosenbrock_2d_derivative(&param, 1.0, 100.0);
+                for (g, t) in grad.iter().zip(target.iter()) {
+                    assert_relative_eq!(*g, *t, epsilon = epsilon, max_relative = max_relative);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_jacobian() {
+        for (scheme, epsilon) in [
+            (FiniteDiffScheme::Forward, 1e-6),
+            (FiniteDiffScheme::Central, 1e-9),
+            (FiniteDiffScheme::Richardson, 1e-12),
+        ] {
+            let problem = FiniteDiff::new(Rosenbrock {}).with_scheme(scheme);
+            let param = vec![-1.2, 1.5];
+            let jacobian = problem.jacobian(&param).unwrap();
+            let target = [[-1.0, 0.0], [-20.0 * param[0], 10.0]];
+            assert_eq!(jacobian.len(), 2);
+            for (row, target_row) in jacobian.iter().zip(target.iter()) {
+                for (j, t) in row.iter().zip(target_row.iter()) {
+                    assert_relative_eq!(*j, *t, epsilon = epsilon, max_relative = epsilon);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_hessian() {
+        let problem: FiniteDiff<_, f64> = FiniteDiff::new(Rosenbrock {});
+        for param in [vec![-1.2, 1.5], vec![0.3, -2.0], vec![1.0, 1.0]] {
+            let hessian = problem.hessian(&param).unwrap();
+            let target = rosenbrock_2d_hessian(&param, 1.0, 100.0);
+            assert_relative_eq!(hessian[0][0], target[0], max_relative = 1e-5);
+            assert_relative_eq!(hessian[0][1], target[1], max_relative = 1e-5);
+            assert_relative_eq!(hessian[1][0], target[2], max_relative = 1e-5);
+            assert_relative_eq!(hessian[1][1], target[3], max_relative = 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_typical_values() {
+        // The step for a variable of magnitude 1e6 at zero must not be tiny
+        struct Scaled {}
+
+        impl CostFunction for Scaled {
+            type Param = Vec<f64>;
+            type Output = f64;
+
+            fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok((p[0] * 1e-6).powi(2) + (p[0] * 1e-6).sin())
+            }
+        }
+
+        let problem = FiniteDiff::new(Scaled {})
+            .with_scheme(FiniteDiffScheme::Central)
+            .with_typical_values(vec![1e6])
+            .unwrap();
+        let grad = problem.gradient(&vec![0.0]).unwrap();
+        assert_relative_eq!(grad[0], 1e-6, max_relative = 1e-8);
+
+        let res = FiniteDiff::new(Scaled {}).with_typical_values(vec![0.0]);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`FiniteDiff`: typical values must be positive.\""
+        );
+
+        let problem = FiniteDiff::new(Scaled {})
+            .with_typical_values(vec![1.0, 2.0])
+            .unwrap();
+        assert!(problem.gradient(&vec![0.0]).is_ok());
+        let problem = FiniteDiff::new(Rosenbrock {})
+            .with_typical_values(vec![1.0])
+            .unwrap();
+        assert_error!(
+            problem.gradient(&vec![0.0, 0.0]),
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`FiniteDiff`: number of typical values does not match ",
+                "parameter vector.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_column_groups() {
+        // Arrow pattern: the first column conflicts with all others
+        let sparsity = JacobianSparsity::new(&[vec![0, 1], vec![0, 2], vec![0, 3], vec![0, 4]]);
+        assert_eq!(sparsity.rows, 4);
+        assert_eq!(
+            sparsity.columns,
+            vec![vec![0, 1, 2, 3], vec![0], vec![1], vec![2], vec![3]]
+        );
+        assert_eq!(sparsity.groups, vec![vec![0], vec![1, 2, 3, 4]]);
+
+        // Empty columns are skipped, duplicates are ignored
+        let sparsity = JacobianSparsity::new(&[vec![2, 2], vec![4]]);
+        assert_eq!(sparsity.columns[2], vec![0]);
+        assert_eq!(sparsity.groups, vec![vec![2, 4]]);
+    }
+
+    #[test]
+    fn test_sparse_jacobian() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        /// Broyden tridiagonal function
+        struct Broyden {
+            evaluations: AtomicUsize,
+        }
+
+        impl Operator for Broyden {
+            type Param = Vec<f64>;
+            type Output = Vec<f64>;
+
+            fn apply(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+                self.evaluations.fetch_add(1, Ordering::SeqCst);
+                let n = x.len();
+                Ok((0..n)
+                    .map(|i| {
+                        let left = if i > 0 { x[i - 1] } else { 0.0 };
+                        let right = if i + 1 < n { x[i + 1] } else { 0.0 };
+                        (3.0 - 2.0 * x[i]) * x[i] - left - 2.0 * right + 1.0
+                    })
+                    .collect())
+            }
+        }
+
+        let n: usize = 20;
+        let param: Vec<f64> = (0..n).map(|i| -1.0 + 0.1 * i as f64).collect();
+        let pattern: Vec<Vec<usize>> = (0..n)
+            .map(|i| (i.saturating_sub(1)..(i + 2).min(n)).collect())
+            .collect();
+
+        for (scheme, evaluations, epsilon) in [
+            (FiniteDiffScheme::Forward, 4, 1e-6),
+            (FiniteDiffScheme::Central, 6, 1e-9),
+        ] {
+            let problem = FiniteDiff::new(Broyden {
+                evaluations: AtomicUsize::new(0),
+            })
+            .with_scheme(scheme)
+            .with_sparsity(pattern.clone());
+            assert_eq!(problem.num_column_groups(), Some(3));
+            let jacobian = problem.jacobian(&param).unwrap();
+            assert_eq!(
+                problem.inner().evaluations.load(Ordering::SeqCst),
+                evaluations
+            );
+            for (i, row) in jacobian.iter().enumerate() {
+                for (j, value) in row.iter().enumerate() {
+                    let target = if i == j {
+                        3.0 - 4.0 * param[i]
+                    } else if j + 1 == i {
+                        -1.0
+                    } else if j == i + 1 {
+                        -2.0
+                    } else {
+                        0.0
+                    };
+                    assert_relative_eq!(*value, target, epsilon = epsilon);
+                }
+            }
+        }
+
+        let problem = FiniteDiff::new(Broyden {
+            evaluations: AtomicUsize::new(0),
+        })
+        .with_sparsity(pattern);
+        assert_error!(
+            problem.jacobian(&vec![0.0; n - 1]),
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`FiniteDiff`: sparsity pattern refers to variables beyond ",
+                "the parameter vector.\""
+            )
+        );
+        assert_error!(
+            problem.jacobian(&vec![0.0; n + 1]),
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`FiniteDiff`: sparsity pattern does not match the number ",
+                "of outputs.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_richardson() {
+        // Squire and Trapp: `exp(x) / sqrt(sin(x)^3 + cos(x)^3)` at `x = 1.5`
+        struct Squire {}
+
+        impl CostFunction for Squire {
+            type Param = Vec<f64>;
+            type Output = f64;
+
+            fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(p[0].exp() / (p[0].sin().powi(3) + p[0].cos().powi(3)).sqrt())
+            }
+        }
+
+        let target = 4.053_427_893_898_621;
+        let central = FiniteDiff::new(Squire {})
+            .with_scheme(FiniteDiffScheme::Central)
+            .gradient(&vec![1.5])
+            .unwrap();
+        let richardson = FiniteDiff::new(Squire {})
+            .with_scheme(FiniteDiffScheme::Richardson)
+            .gradient(&vec![1.5])
+            .unwrap();
+        assert_relative_eq!(richardson[0], target, max_relative = 1e-12);
+        assert!((richardson[0] - target).abs() < 0.01 * (central[0] - target).abs());
+    }
+}
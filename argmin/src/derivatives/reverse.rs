@@ -0,0 +1,270 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{CostFunction, Error, Gradient};
+
+/// Defines a cost function whose gradient is obtained by reverse-mode automatic differentiation.
+///
+/// Reverse-mode AD libraries (such as the autodiff backends of `burn` or `candle`, or tape based
+/// crates) record the evaluation of the cost function on a tape and obtain the full gradient by a
+/// single backward pass. Implementing this trait only requires converting the parameter vector to
+/// the tensor type of the library, evaluating the model, calling its backward pass and converting
+/// the result back. [`ReverseDiff`] then provides [`CostFunction`] and [`Gradient`], which allows
+/// using such models with gradient based solvers like
+/// [`LBFGS`](`crate::solver::quasinewton::LBFGS`) or
+/// [`NonlinearConjugateGradient`](`crate::solver::conjugategradient::NonlinearConjugateGradient`).
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::Error;
+/// use argmin::derivatives::ReverseCostFunction;
+///
+/// struct Model {}
+///
+/// impl ReverseCostFunction for Model {
+///     type Param = Vec<f64>;
+///     type Output = f64;
+///
+///     fn cost_and_gradient(&self, p: &Self::Param) -> Result<(f64, Vec<f64>), Error> {
+///         // Typically: convert `p` into a tensor which tracks gradients, evaluate the model,
+///         // call `backward()` on the result and read out the gradient of the input tensor.
+///         Ok((p[0].powi(2), vec![2.0 * p[0]]))
+///     }
+/// }
+/// ```
+pub trait ReverseCostFunction {
+    /// Type of the parameter vector
+    type Param;
+    /// Type of the return value of the cost function
+    type Output;
+
+    /// Evaluates the cost function and its gradient with respect to `param` (forward and backward
+    /// pass)
+    fn cost_and_gradient(&self, param: &Self::Param) -> Result<(Self::Output, Self::Param), Error>;
+
+    /// Evaluates only the cost function (forward pass)
+    ///
+    /// Defaults to discarding the gradient of [`cost_and_gradient`](`Self::cost_and_gradient`).
+    /// Should be overridden if the library allows evaluating the model without recording a tape.
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        Ok(self.cost_and_gradient(param)?.0)
+    }
+}
+
+/// # Reverse-mode automatic differentiation
+///
+/// Wraps a [`ReverseCostFunction`] and implements [`CostFunction`] and [`Gradient`] for it. The
+/// gradient has the same type as the parameter vector.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::core::{Error, Gradient};
+/// # use argmin::derivatives::{ReverseCostFunction, ReverseDiff};
+/// # struct Model {}
+/// # impl ReverseCostFunction for Model {
+/// #     type Param = Vec<f64>;
+/// #     type Output = f64;
+/// #     fn cost_and_gradient(&self, p: &Self::Param) -> Result<(f64, Vec<f64>), Error> {
+/// #         Ok((p[0].powi(2), vec![2.0 * p[0]]))
+/// #     }
+/// # }
+/// # fn main() -> Result<(), Error> {
+/// let problem = ReverseDiff::new(Model {});
+///
+/// let grad = problem.gradient(&vec![3.0])?;
+/// # assert_eq!(grad, vec![6.0]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ReverseDiff<C> {
+    /// Wrapped cost function
+    inner: C,
+}
+
+impl<C> ReverseDiff<C> {
+    /// Construct a new instance of `ReverseDiff`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::derivatives::ReverseDiff;
+    /// # struct UserDefinedProblem {};
+    /// let problem = ReverseDiff::new(UserDefinedProblem {});
+    /// ```
+    pub fn new(inner: C) -> Self {
+        ReverseDiff { inner }
+    }
+
+    /// Returns a reference to the wrapped cost function
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    /// Returns the wrapped cost function
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+}
+
+impl<C> CostFunction for ReverseDiff<C>
+where
+    C: ReverseCostFunction,
+{
+    type Param = C::Param;
+    type Output = C::Output;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        self.inner.cost(param)
+    }
+}
+
+impl<C> Gradient for ReverseDiff<C>
+where
+    C: ReverseCostFunction,
+{
+    type Param = C::Param;
+    type Gradient = C::Param;
+
+    fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+        Ok(self.inner.cost_and_gradient(param)?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+    use argmin_testfunctions::{rosenbrock_2d, rosenbrock_2d_derivative};
+    use std::cell::RefCell;
+
+    test_trait_impl!(reverse_diff, ReverseDiff<Rosenbrock>);
+
+    /// Minimal Wengert list which records products and sums of variables
+    #[derive(Default)]
+    struct Tape {
+        /// Partial derivatives of each node with respect to (at most) two parents
+        nodes: RefCell<Vec<[(usize, f64); 2]>>,
+    }
+
+    #[derive(Clone, Copy)]
+    struct Var<'t> {
+        tape: &'t Tape,
+        index: usize,
+        value: f64,
+    }
+
+    impl Tape {
+        fn push(&self, parents: [(usize, f64); 2], value: f64) -> Var<'_> {
+            let mut nodes = self.nodes.borrow_mut();
+            nodes.push(parents);
+            Var {
+                tape: self,
+                index: nodes.len() - 1,
+                value,
+            }
+        }
+
+        fn var(&self, value: f64) -> Var<'_> {
+            let index = self.nodes.borrow().len();
+            self.push([(index, 0.0), (index, 0.0)], value)
+        }
+
+        fn backward(&self, output: Var<'_>) -> Vec<f64> {
+            let nodes = self.nodes.borrow();
+            let mut adjoints = vec![0.0; nodes.len()];
+            adjoints[output.index] = 1.0;
+            for (i, parents) in nodes.iter().enumerate().rev() {
+                for (parent, partial) in parents {
+                    adjoints[*parent] += partial * adjoints[i];
+                }
+            }
+            adjoints
+        }
+    }
+
+    impl<'t> Var<'t> {
+        fn add(self, other: Var<'t>) -> Var<'t> {
+            self.tape.push(
+                [(self.index, 1.0), (other.index, 1.0)],
+                self.value + other.value,
+            )
+        }
+
+        fn mul(self, other: Var<'t>) -> Var<'t> {
+            self.tape.push(
+                [(self.index, other.value), (other.index, self.value)],
+                self.value * other.value,
+            )
+        }
+
+        fn scale(self, factor: f64) -> Var<'t> {
+            self.tape.push(
+                [(self.index, factor), (self.index, 0.0)],
+                factor * self.value,
+            )
+        }
+
+        fn shift(self, offset: f64) -> Var<'t> {
+            self.tape
+                .push([(self.index, 1.0), (self.index, 0.0)], self.value + offset)
+        }
+    }
+
+    #[derive(Clone, Debug, Default)]
+    struct Rosenbrock {}
+
+    impl ReverseCostFunction for Rosenbrock {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost_and_gradient(&self, p: &Self::Param) -> Result<(f64, Vec<f64>), Error> {
+            let tape = Tape::default();
+            let x = tape.var(p[0]);
+            let y = tape.var(p[1]);
+            // (1 - x)^2 + 100 (y - x^2)^2
+            let a = x.scale(-1.0).shift(1.0);
+            let b = y.add(x.mul(x).scale(-1.0));
+            let f = a.mul(a).add(b.mul(b).scale(100.0));
+            let adjoints = tape.backward(f);
+            Ok((f.value, vec![adjoints[x.index], adjoints[y.index]]))
+        }
+    }
+
+    #[test]
+    fn test_cost() {
+        let problem = ReverseDiff::new(Rosenbrock {});
+        let param = vec![-1.2, 1.5];
+        assert_relative_eq!(
+            problem.cost(&param).unwrap(),
+            rosenbrock_2d(&param, 1.0, 100.0),
+            epsilon = f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_gradient() {
+        let problem = ReverseDiff::new(Rosenbrock {});
+        for param in [vec![-1.2, 1.5], vec![0.3, -2.0], vec![1.0, 1.0]] {
+            let grad = problem.gradient(&param).unwrap();
+            let target = rosenbrock_2d_derivative(&param, 1.0, 100.0);
+            for (g, t) in grad.iter().zip(target.iter()) {
+                assert_relative_eq!(*g, *t, epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_inner() {
+        let problem = ReverseDiff::new(Rosenbrock {});
+        let _: &Rosenbrock = problem.inner();
+        let _: Rosenbrock = problem.into_inner();
+    }
+}
@@ -0,0 +1,327 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{ArgminFloat, CostFunction, Error, Gradient};
+use alloc::{vec, vec::Vec};
+use core::sync::atomic::{AtomicUsize, Ordering};
+use rand::{Rng, SeedableRng};
+use rand_xoshiro::Xoshiro256PlusPlus;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Gaussian smoothing gradient estimator
+///
+/// Wraps a problem which implements [`CostFunction`] and estimates its gradient from random
+/// directional differences (Nesterov and Spokoiny):
+///
+/// `g(x) ≈ 1/N * sum_k (f(x + mu * u_k) - f(x)) / mu * u_k`,
+///
+/// where the directions `u_k` are drawn from a standard normal distribution. This is an unbiased
+/// estimate of the gradient of the smoothed function `f_mu(x) = E[f(x + mu * u)]`, which is
+/// differentiable even if `f` is not. It therefore allows using first-order methods such as
+/// [`SteepestDescent`](`crate::solver::gradientdescent::SteepestDescent`) on non-differentiable
+/// or noisy black-box functions. Each gradient requires `N + 1` cost function evaluations,
+/// independent of the number of parameters.
+///
+/// The smoothing radius `mu` should be of the order of the noise or the size of the features which
+/// are to be smoothed out. The variance of the estimate decreases with the number of samples `N`.
+/// Each call to [`gradient`](`Gradient::gradient`) draws new directions from an RNG seeded with the
+/// seed (see [`with_seed`](`GaussianSmoothing::with_seed`)) and the number of previous calls,
+/// which makes runs reproducible.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::core::{CostFunction, Error, Gradient};
+/// # use argmin::derivatives::GaussianSmoothing;
+/// struct Abs {}
+///
+/// impl CostFunction for Abs {
+///     type Param = Vec<f64>;
+///     type Output = f64;
+///
+///     fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+///         Ok(p[0].abs() + p[1].abs())
+///     }
+/// }
+///
+/// # fn main() -> Result<(), Error> {
+/// let problem = GaussianSmoothing::new(Abs {})
+///     .with_samples(1000)?
+///     .with_radius(0.01)?;
+///
+/// let grad = problem.gradient(&vec![1.0, -1.0])?;
+/// # assert!((grad[0] - 1.0).abs() < 0.2 && (grad[1] + 1.0).abs() < 0.2);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct GaussianSmoothing<P, F = f64> {
+    /// Wrapped problem
+    inner: P,
+    /// Number of random directions per gradient
+    num_samples: usize,
+    /// Smoothing radius
+    radius: F,
+    /// Seed of the RNG
+    seed: u64,
+    /// Number of gradients computed so far
+    #[cfg_attr(feature = "serde1", serde(skip))]
+    calls: AtomicUsize,
+}
+
+impl<P, F> Clone for GaussianSmoothing<P, F>
+where
+    P: Clone,
+    F: Copy,
+{
+    fn clone(&self) -> Self {
+        GaussianSmoothing {
+            inner: self.inner.clone(),
+            num_samples: self.num_samples,
+            radius: self.radius,
+            seed: self.seed,
+            calls: AtomicUsize::new(self.calls.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+impl<P, F> GaussianSmoothing<P, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of `GaussianSmoothing`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::derivatives::GaussianSmoothing;
+    /// # struct UserDefinedProblem {};
+    /// let problem: GaussianSmoothing<_, f64> = GaussianSmoothing::new(UserDefinedProblem {});
+    /// ```
+    pub fn new(inner: P) -> Self {
+        GaussianSmoothing {
+            inner,
+            num_samples: 20,
+            radius: F::epsilon().sqrt(),
+            seed: 0,
+            calls: AtomicUsize::new(0),
+        }
+    }
+
+    /// Set the number of random directions per gradient
+    ///
+    /// Must be positive. Defaults to `20`.
+    pub fn with_samples(mut self, num_samples: usize) -> Result<Self, Error> {
+        if num_samples == 0 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`GaussianSmoothing`: number of samples must be positive."
+            ));
+        }
+        self.num_samples = num_samples;
+        Ok(self)
+    }
+
+    /// Set the smoothing radius `mu`
+    ///
+    /// Must be positive. Defaults to `sqrt(EPSILON)`, which is only suitable for smooth functions
+    /// without noise; otherwise it should be increased to the scale of the noise.
+    pub fn with_radius(mut self, radius: F) -> Result<Self, Error> {
+        if radius <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`GaussianSmoothing`: radius must be positive."
+            ));
+        }
+        self.radius = radius;
+        Ok(self)
+    }
+
+    /// Set the seed of the RNG
+    ///
+    /// Defaults to `0`.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Returns a reference to the wrapped problem
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+}
+
+impl<P, F> CostFunction for GaussianSmoothing<P, F>
+where
+    P: CostFunction,
+{
+    type Param = P::Param;
+    type Output = P::Output;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        self.inner.cost(param)
+    }
+}
+
+impl<P, F> Gradient for GaussianSmoothing<P, F>
+where
+    P: CostFunction<Param = Vec<F>, Output = F>,
+    F: ArgminFloat,
+{
+    type Param = Vec<F>;
+    type Gradient = Vec<F>;
+
+    fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+        let call = self.calls.fetch_add(1, Ordering::Relaxed);
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(self.seed.wrapping_add(call as u64));
+        let n = param.len();
+        let f0 = self.inner.cost(param)?;
+        let mut grad = vec![float!(0.0); n];
+        let mut u = vec![float!(0.0); n];
+        let mut x = param.clone();
+        for _ in 0..self.num_samples {
+            for i in (0..n).step_by(2) {
+                // Box-Muller transform, `1 - U` lies in (0, 1]
+                let r = (float!(-2.0) * float!(1.0 - rng.gen::<f64>()).ln()).sqrt();
+                let theta = float!(core::f64::consts::TAU * rng.gen::<f64>());
+                u[i] = r * theta.cos();
+                if i + 1 < n {
+                    u[i + 1] = r * theta.sin();
+                }
+            }
+            for i in 0..n {
+                x[i] = param[i] + self.radius * u[i];
+            }
+            let df = (self.inner.cost(&x)? - f0) / self.radius;
+            for i in 0..n {
+                grad[i] = grad[i] + df * u[i];
+            }
+        }
+        let scale = float!(1.0) / F::from_usize(self.num_samples).unwrap();
+        Ok(grad.into_iter().map(|g| g * scale).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Executor};
+    use crate::solver::gradientdescent::SteepestDescent;
+    use crate::solver::linesearch::{condition::ArmijoCondition, BacktrackingLineSearch};
+    use crate::test_trait_impl;
+
+    test_trait_impl!(gaussian_smoothing, GaussianSmoothing<Quadratic, f64>);
+
+    /// `sum_i (i + 1) * x_i^2`
+    #[derive(Clone, Debug, Default)]
+    struct Quadratic {}
+
+    impl CostFunction for Quadratic {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(p.iter()
+                .enumerate()
+                .map(|(i, x)| (i + 1) as f64 * x.powi(2))
+                .sum())
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let problem: GaussianSmoothing<_, f64> = GaussianSmoothing::new(Quadratic {});
+        assert_eq!(problem.num_samples, 20);
+        assert_eq!(
+            problem.radius.to_ne_bytes(),
+            f64::EPSILON.sqrt().to_ne_bytes()
+        );
+        assert_eq!(problem.seed, 0);
+    }
+
+    #[test]
+    fn test_builders() {
+        let problem: GaussianSmoothing<_, f64> = GaussianSmoothing::new(Quadratic {})
+            .with_samples(5)
+            .unwrap()
+            .with_radius(0.5)
+            .unwrap()
+            .with_seed(7);
+        assert_eq!(problem.num_samples, 5);
+        assert_eq!(problem.radius.to_ne_bytes(), 0.5f64.to_ne_bytes());
+        assert_eq!(problem.seed, 7);
+
+        assert_error!(
+            GaussianSmoothing::<_, f64>::new(Quadratic {}).with_samples(0),
+            ArgminError,
+            "Invalid parameter: \"`GaussianSmoothing`: number of samples must be positive.\""
+        );
+        for radius in [0.0, -1.0] {
+            assert_error!(
+                GaussianSmoothing::new(Quadratic {}).with_radius(radius),
+                ArgminError,
+                "Invalid parameter: \"`GaussianSmoothing`: radius must be positive.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_gradient() {
+        let problem = GaussianSmoothing::new(Quadratic {})
+            .with_samples(20000)
+            .unwrap();
+        let param = vec![1.0, -2.0, 0.5];
+        let target = [2.0, -8.0, 3.0];
+        let grad = problem.gradient(&param).unwrap();
+        let norm = target.iter().map(|t: &f64| t.powi(2)).sum::<f64>().sqrt();
+        for (g, t) in grad.iter().zip(target.iter()) {
+            assert!((g - t).abs() < 0.05 * norm);
+        }
+    }
+
+    #[test]
+    fn test_reproducible() {
+        let problem = GaussianSmoothing::new(Quadratic {}).with_seed(3);
+        let param = vec![1.0, -2.0, 0.5];
+        let first = problem.gradient(&param).unwrap();
+        let second = problem.gradient(&param).unwrap();
+        assert_ne!(first, second);
+
+        let problem = GaussianSmoothing::new(Quadratic {}).with_seed(3);
+        assert_eq!(problem.gradient(&param).unwrap(), first);
+        assert_eq!(problem.clone().gradient(&param).unwrap(), second);
+    }
+
+    #[test]
+    fn test_steepest_descent_nonsmooth() {
+        /// `|x_0| + |x_1|`, which is not differentiable at the minimum
+        struct Abs {}
+
+        impl CostFunction for Abs {
+            type Param = Vec<f64>;
+            type Output = f64;
+
+            fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(p[0].abs() + p[1].abs())
+            }
+        }
+
+        let problem = GaussianSmoothing::new(Abs {})
+            .with_samples(50)
+            .unwrap()
+            .with_radius(1e-3)
+            .unwrap();
+        let linesearch = BacktrackingLineSearch::new(ArmijoCondition::new(1e-4).unwrap());
+        let res = Executor::new(problem, SteepestDescent::new(linesearch))
+            .configure(|state| state.param(vec![2.0, -1.5]).max_iters(50))
+            .run()
+            .unwrap();
+        assert!(res.state().get_best_cost() < 0.1);
+    }
+}
@@ -32,6 +32,25 @@
 //!
 //! * [Checkpointing](`crate::core::checkpointing`)
 //! * [Observers](`crate::core::observers`)
+//! * [`minimize`](`crate::minimize()`), which runs L-BFGS, nonlinear conjugate gradient or steepest
+//!   descent on a differentiable problem in a single call, without assembling `Executor`, solver
+//!   and line search by hand
+//! * A [registry](`crate::registry`) of solvers which are selected and configured by name at
+//!   runtime
+//!
+//! # WebAssembly
+//!
+//! argmin compiles to `wasm32-unknown-unknown`. For use in the browser, the default features
+//! should be disabled and the `wasm-bindgen` feature enabled, which provides a clock for timing
+//! iterations and a source of randomness for the stochastic solvers:
+//!
+//! ```toml
+//! argmin = { version = "*", default-features = false, features = ["wasm-bindgen"] }
+//! ```
+//!
+//! The `slog-logger` feature is not suitable for the browser since its loggers spawn a thread,
+//! neither are the `ctrlc` feature and observers or checkpoints which write to files. A complete
+//! browser example can be found in `examples/wasm`.
 //!
 //!
 //! # Algorithms
@@ -40,14 +59,34 @@
 //!   - [Backtracking line search](`crate::solver::linesearch::BacktrackingLineSearch`)
 //!   - [More-Thuente line search](`crate::solver::linesearch::MoreThuenteLineSearch`)
 //!   - [Hager-Zhang line search](`crate::solver::linesearch::HagerZhangLineSearch`)
+//!   - [Strong Wolfe line search](`crate::solver::linesearch::StrongWolfeLineSearch`)
+//!   - [Non-monotone line search](`crate::solver::linesearch::NonmonotoneLineSearch`)
+//!   - [Exact line search](`crate::solver::linesearch::ExactLineSearch`)
+//!   - [Goldstein line search](`crate::solver::linesearch::GoldsteinLineSearch`)
+//!   - [Line search for noisy objectives](`crate::solver::linesearch::NoisyLineSearch`)
 //!
 //! - [Trust region method](`crate::solver::trustregion::TrustRegion`)
 //!   - [Cauchy point method](`crate::solver::trustregion::CauchyPoint`)
 //!   - [Dogleg method](`crate::solver::trustregion::Dogleg`)
 //!   - [Steihaug method](`crate::solver::trustregion::Steihaug`)
+//!   - [Generalized Lanczos trust region (GLTR) method](`crate::solver::trustregion::GLTR`)
 //!   
 //! - [Steepest descent](`crate::solver::gradientdescent::SteepestDescent`)
 //!
+//! - [Heavy-ball method](`crate::solver::gradientdescent::HeavyBall`)
+//!
+//! - [Projected gradient descent](`crate::solver::projectedgradient::ProjectedGradient`)
+//!
+//! - [Frank-Wolfe method](`crate::solver::frankwolfe::FrankWolfe`)
+//!
+//! - [Mirror descent](`crate::solver::mirrordescent::MirrorDescent`)
+//!
+//! - [Natural gradient descent](`crate::solver::naturalgradient::NaturalGradient`)
+//!
+//! - [Riemannian optimization](`crate::solver::riemannian`) on the sphere, the Stiefel manifold, SO(3) and SE(3)
+//!   - [Riemannian gradient descent](`crate::solver::riemannian::RiemannianGradientDescent`)
+//!   - [Riemannian conjugate gradient](`crate::solver::riemannian::RiemannianConjugateGradient`)
+//!
 //! - [Conjugate gradient methods](`crate::solver::conjugategradient`)
 //!   - [Conjugate gradient method](`crate::solver::conjugategradient::ConjugateGradient`)
 //!   - [Nonlinear conjugate gradient method](`crate::solver::conjugategradient::NonlinearConjugateGradient`)
@@ -56,12 +95,15 @@
 //!   - [Newton's method](`crate::solver::newton::Newton`)
 //!   - [Newton-CG](solver/newton/newton_cg/struct.NewtonCG.html)
 //!
+//! - [Adaptive cubic regularization (ARC)](`crate::solver::cubicregularization::CubicRegularization`)
+//!
 //! - [Quasi-Newton methods](`crate::solver::quasinewton`)
 //!   - [BFGS](`crate::solver::quasinewton::BFGS`)
-//!   - [L-BFGS](`crate::solver::quasinewton::LBFGS`)
+//!   - [L-BFGS](`crate::solver::quasinewton::LBFGS`) (including OWL-QN for L1-regularized problems)
 //!   - [DFP](`crate::solver::quasinewton::DFP`)
 //!   - [SR1](`crate::solver::quasinewton::SR1`)
 //!   - [SR1-TrustRegion](`crate::solver::quasinewton::SR1TrustRegion`)
+//!   - [L-SR1-TrustRegion](`crate::solver::quasinewton::LSR1TrustRegion`)
 //!
 //! - [Gauss-Newton methods](`crate::solver::gaussnewton`)
 //!   - [Gauss-Newton method](`crate::solver::gaussnewton::GaussNewton`)
@@ -69,18 +111,69 @@
 //!
 //! - [Golden-section search](`crate::solver::goldensectionsearch::GoldenSectionSearch`)
 //!
+//! - [Bracketing of a minimum](`crate::solver::bracketing::Bracketing`)
+//!
+//! - [Primal-dual interior point method](`crate::solver::interiorpoint::InteriorPoint`)
+//!
+//! - [Quadratic penalty method](`crate::solver::penalty::PenaltyMethod`)
+//!
+//! - [Logarithmic barrier method](`crate::solver::barrier::BarrierMethod`)
+//!
+//! - [Branch-and-bound](`crate::solver::branchandbound::BranchAndBound`)
+//!
+//! - [Majorization-minimization](`crate::solver::majorizationminimization::MajorizationMinimization`)
+//!
 //! - [Landweber iteration](`crate::solver::landweber::Landweber`)
 //!
+//! - [Simplex method](`crate::solver::simplex::Simplex`)
+//!
+//! - [Active-set method](`crate::solver::activeset::ActiveSet`)
+//!
+//! - [Non-negative least squares](`crate::solver::nnls::NNLS`)
+//!
+//! - [Variance-reduced stochastic gradient methods](`crate::solver::variancereduction`)
+//!   - [SAG](`crate::solver::variancereduction::SAG`)
+//!   - [SAGA](`crate::solver::variancereduction::SAGA`)
+//!   - [SVRG](`crate::solver::variancereduction::SVRG`)
+//!
 //! - [Brent's methods](`crate::solver::brent`)
 //!   - [Brent's minimization method](`crate::solver::brent::BrentOpt`)
 //!   - [Brent's root finding method](`crate::solver::brent::BrentRoot`)
 //!
+//! - [Root finding methods](`crate::solver::rootfinding`)
+//!   - [Bisection method](`crate::solver::rootfinding::Bisection`)
+//!   - [Brent-Dekker method](`crate::solver::rootfinding::BrentRoot`)
+//!   - [Halley's method](`crate::solver::rootfinding::HalleyRoot`)
+//!   - [Newton's method](`crate::solver::rootfinding::NewtonRoot`)
+//!   - [Regula falsi (Illinois)](`crate::solver::rootfinding::RegulaFalsi`)
+//!   - [Secant method](`crate::solver::rootfinding::Secant`)
+//!
+//! - [Fixed-point iteration](`crate::solver::fixedpoint::FixedPointIteration`) with Aitken and
+//!   Anderson acceleration
+//!
 //! - [Nelder-Mead method](`crate::solver::neldermead::NelderMead`)
 //!
 //! - [Simulated Annealing](`crate::solver::simulatedannealing::SimulatedAnnealing`)
+//!   - [Parallel tempering](`crate::solver::simulatedannealing::ParallelTempering`)
 //!
 //! - [Particle Swarm Optimization](`crate::solver::particleswarm::ParticleSwarm`)
 //!
+//! - [Evolution strategies](`crate::solver::evolutionstrategy::EvolutionStrategy`)
+//!
+//! - [Cross-entropy method](`crate::solver::crossentropy::CrossEntropy`)
+//!
+//! - [Random search](`crate::solver::randomsearch::RandomSearch`)
+//!
+//! - [Latin hypercube sampling](`crate::solver::randomsearch::LatinHypercube`)
+//!
+//! - [NSGA-II](`crate::solver::nsga2::NSGA2`)
+//!
+//! - [MOEA/D](`crate::solver::moead::MOEAD`)
+//!
+//! - [Weighted-sum method](`crate::solver::weightedsum::WeightedSum`)
+//!
+//! - [Epsilon-constraint method](`crate::solver::epsilonconstraint::EpsilonConstraint`)
+//!
 //! ## External solvers compatible with argmin
 //!
 //! External solvers which implement the `Solver` trait are compatible with argmins `Executor`,
@@ -107,18 +200,48 @@
 //! in the work by you, as defined in the Apache-2.0 license, shall be dual licensed as above,
 //! without any additional terms or conditions.
 
+#![cfg_attr(all(not(feature = "std"), not(test)), no_std)]
 #![warn(missing_docs)]
 #![allow(unused_attributes)]
 // Explicitly disallow EQ comparison of floats. (This clippy lint is denied by default; however,
 // this is just to make sure that it will always stay this way.)
 #![deny(clippy::float_cmp)]
 
+extern crate alloc;
+
+// Used by the exported macros, which cannot rely on `alloc` being linked by the calling crate.
+#[doc(hidden)]
+pub use alloc::string::ToString as __ToString;
+
+#[cfg(all(not(feature = "std"), any(feature = "ctrlc", feature = "rayon")))]
+compile_error!("The `ctrlc` and `rayon` features require the `std` feature.");
+
 #[macro_use]
 pub mod core;
 
+pub mod derivatives;
+
 /// Solvers
 pub mod solver;
 
+/// One-call interface to the most common solvers
+#[cfg(feature = "std")]
+mod minimize;
+#[cfg(feature = "std")]
+pub use minimize::{minimize, Method, MethodSolver, Options};
+
+#[cfg(feature = "std")]
+pub mod registry;
+
+#[cfg(feature = "config")]
+pub mod config;
+
+#[cfg(feature = "remote")]
+pub mod remote;
+
+#[cfg(feature = "mpi")]
+pub mod mpi;
+
 #[cfg(test)]
 #[cfg(feature = "_ndarrayl")]
 mod tests;
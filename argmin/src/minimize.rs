@@ -0,0 +1,430 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+#[cfg(feature = "slog-logger")]
+use crate::core::observers::{ObserverMode, SlogLogger};
+use crate::core::{
+    ArgminFloat, DeserializeOwnedAlias, Error, Executor, IterState, OptimizationResult, Problem,
+    SerializeAlias, Solver, State, TerminationStatus, KV,
+};
+use crate::solver::conjugategradient::{beta::PolakRibiere, NonlinearConjugateGradient};
+use crate::solver::gradientdescent::SteepestDescent;
+use crate::solver::linesearch::MoreThuenteLineSearch;
+use crate::solver::quasinewton::LBFGS;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Optimization methods available via [`minimize`]
+///
+/// All methods use a [`MoreThuenteLineSearch`] and therefore require the gradient of the cost
+/// function.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+#[allow(clippy::upper_case_acronyms)]
+pub enum Method {
+    /// [`LBFGS`], with the memory and tolerances of [`Options`]
+    LBFGS,
+    /// [`NonlinearConjugateGradient`] with the Polak-Ribiere update, restarted every 10 iterations
+    /// or when consecutive gradients are far from orthogonal
+    ConjugateGradient,
+    /// [`SteepestDescent`]
+    SteepestDescent,
+}
+
+/// Options of [`minimize`]
+///
+/// # Example
+///
+/// ```
+/// # use argmin::core::Error;
+/// use argmin::Options;
+///
+/// # fn main() -> Result<(), Error> {
+/// let options: Options<f64> = Options::default()
+///     .with_max_iters(100)
+///     .with_tolerance_grad(1e-8)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct Options<F> {
+    /// Maximum number of iterations
+    max_iters: u64,
+    /// Target cost function value
+    target_cost: F,
+    /// Tolerance for the stopping criterion based on the norm of the gradient
+    tol_grad: F,
+    /// Tolerance for the stopping criterion based on the change of the cost
+    tol_cost: F,
+    /// Memory of L-BFGS
+    lbfgs_memory: usize,
+    /// Whether to log the progress to the terminal
+    #[cfg(feature = "slog-logger")]
+    logging: bool,
+}
+
+impl<F> Default for Options<F>
+where
+    F: ArgminFloat,
+{
+    /// At most 1000 iterations, no target cost, a gradient tolerance of `sqrt(EPSILON)`, a cost
+    /// tolerance of `EPSILON`, an L-BFGS memory of 7 and no logging
+    fn default() -> Self {
+        Options {
+            max_iters: 1000,
+            target_cost: F::neg_infinity(),
+            tol_grad: F::epsilon().sqrt(),
+            tol_cost: F::epsilon(),
+            lbfgs_memory: 7,
+            #[cfg(feature = "slog-logger")]
+            logging: false,
+        }
+    }
+}
+
+impl<F> Options<F>
+where
+    F: ArgminFloat,
+{
+    /// Set the maximum number of iterations
+    ///
+    /// Defaults to `1000`.
+    pub fn with_max_iters(mut self, max_iters: u64) -> Self {
+        self.max_iters = max_iters;
+        self
+    }
+
+    /// Set the target cost function value; the optimization stops once it is reached
+    ///
+    /// Defaults to `-Inf`.
+    pub fn with_target_cost(mut self, target_cost: F) -> Self {
+        self.target_cost = target_cost;
+        self
+    }
+
+    /// Set the tolerance for the stopping criterion based on the norm of the gradient
+    ///
+    /// Only used by [`Method::LBFGS`]. Must be non-negative; defaults to `sqrt(EPSILON)`.
+    pub fn with_tolerance_grad(mut self, tol_grad: F) -> Result<Self, Error> {
+        if tol_grad < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Options`: gradient tolerance must be >= 0."
+            ));
+        }
+        self.tol_grad = tol_grad;
+        Ok(self)
+    }
+
+    /// Set the tolerance for the stopping criterion based on the change of the cost
+    ///
+    /// Only used by [`Method::LBFGS`]. Must be non-negative; defaults to `EPSILON`.
+    pub fn with_tolerance_cost(mut self, tol_cost: F) -> Result<Self, Error> {
+        if tol_cost < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Options`: cost tolerance must be >= 0."
+            ));
+        }
+        self.tol_cost = tol_cost;
+        Ok(self)
+    }
+
+    /// Set the number of stored gradient differences of [`Method::LBFGS`]
+    ///
+    /// Must be positive; defaults to `7`.
+    pub fn with_lbfgs_memory(mut self, memory: usize) -> Result<Self, Error> {
+        if memory == 0 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Options`: L-BFGS memory must be > 0."
+            ));
+        }
+        self.lbfgs_memory = memory;
+        Ok(self)
+    }
+
+    /// Log the progress of the optimization to the terminal (via [`SlogLogger::term`])
+    ///
+    /// Defaults to `false`.
+    #[cfg(feature = "slog-logger")]
+    pub fn with_logging(mut self, logging: bool) -> Self {
+        self.logging = logging;
+        self
+    }
+}
+
+/// Solver used by [`minimize`], which dispatches to the solver of the chosen [`Method`]
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+#[allow(clippy::upper_case_acronyms)]
+pub enum MethodSolver<P, G, F> {
+    /// L-BFGS
+    LBFGS(LBFGS<MoreThuenteLineSearch<P, G, F>, P, G, F>),
+    /// Nonlinear conjugate gradient
+    ConjugateGradient(
+        NonlinearConjugateGradient<P, MoreThuenteLineSearch<P, G, F>, PolakRibiere, F>,
+    ),
+    /// Steepest descent
+    SteepestDescent(SteepestDescent<MoreThuenteLineSearch<P, G, F>>),
+}
+
+impl<P, G, F> MethodSolver<P, G, F> {
+    /// Returns the method this solver was created for
+    pub fn method(&self) -> Method {
+        match self {
+            MethodSolver::LBFGS(_) => Method::LBFGS,
+            MethodSolver::ConjugateGradient(_) => Method::ConjugateGradient,
+            MethodSolver::SteepestDescent(_) => Method::SteepestDescent,
+        }
+    }
+}
+
+impl<O, P, G, F> Solver<O, IterState<P, G, (), (), F>> for MethodSolver<P, G, F>
+where
+    IterState<P, G, (), (), F>: State,
+    LBFGS<MoreThuenteLineSearch<P, G, F>, P, G, F>: Solver<O, IterState<P, G, (), (), F>>,
+    NonlinearConjugateGradient<P, MoreThuenteLineSearch<P, G, F>, PolakRibiere, F>:
+        Solver<O, IterState<P, G, (), (), F>>,
+    SteepestDescent<MoreThuenteLineSearch<P, G, F>>: Solver<O, IterState<P, G, (), (), F>>,
+{
+    const NAME: &'static str = "minimize";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        match self {
+            MethodSolver::LBFGS(solver) => solver.init(problem, state),
+            MethodSolver::ConjugateGradient(solver) => solver.init(problem, state),
+            MethodSolver::SteepestDescent(solver) => solver.init(problem, state),
+        }
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        match self {
+            MethodSolver::LBFGS(solver) => solver.next_iter(problem, state),
+            MethodSolver::ConjugateGradient(solver) => solver.next_iter(problem, state),
+            MethodSolver::SteepestDescent(solver) => solver.next_iter(problem, state),
+        }
+    }
+
+    fn terminate(&mut self, state: &IterState<P, G, (), (), F>) -> TerminationStatus {
+        match self {
+            MethodSolver::LBFGS(solver) => solver.terminate(state),
+            MethodSolver::ConjugateGradient(solver) => solver.terminate(state),
+            MethodSolver::SteepestDescent(solver) => solver.terminate(state),
+        }
+    }
+}
+
+/// Minimizes `problem` with `method`, starting from `init_param`
+///
+/// Wires up the solver of the chosen [`Method`] with a [`MoreThuenteLineSearch`], configures the
+/// initial state according to `options` and runs an [`Executor`]. This covers the common case of
+/// minimizing a differentiable function in a single call; solvers which need more configuration
+/// can still be assembled by hand.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::core::{CostFunction, Error, Gradient, State};
+/// # use argmin_testfunctions::{rosenbrock_2d, rosenbrock_2d_derivative};
+/// use argmin::{minimize, Method, Options};
+///
+/// struct Rosenbrock {}
+///
+/// impl CostFunction for Rosenbrock {
+///     type Param = Vec<f64>;
+///     type Output = f64;
+///
+///     fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+///         Ok(rosenbrock_2d(p, 1.0, 100.0))
+///     }
+/// }
+///
+/// impl Gradient for Rosenbrock {
+///     type Param = Vec<f64>;
+///     type Gradient = Vec<f64>;
+///
+///     fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+///         Ok(rosenbrock_2d_derivative(p, 1.0, 100.0))
+///     }
+/// }
+///
+/// # fn main() -> Result<(), Error> {
+/// let res = minimize(Rosenbrock {}, vec![-1.2, 1.0], Method::LBFGS, Options::default())?;
+/// let best = res.state().get_best_param().unwrap();
+/// # assert!((best[0] - 1.0).abs() < 1e-4 && (best[1] - 1.0).abs() < 1e-4);
+/// # Ok(())
+/// # }
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn minimize<O, P, G, F>(
+    problem: O,
+    init_param: P,
+    method: Method,
+    options: Options<F>,
+) -> Result<OptimizationResult<O, MethodSolver<P, G, F>, IterState<P, G, (), (), F>>, Error>
+where
+    F: ArgminFloat,
+    IterState<P, G, (), (), F>: State<Float = F> + SerializeAlias + DeserializeOwnedAlias,
+    MethodSolver<P, G, F>: Solver<O, IterState<P, G, (), (), F>>,
+{
+    let executor = executor(problem, init_param, method, &options)?;
+    #[cfg(feature = "slog-logger")]
+    let executor = if options.logging {
+        executor.add_observer(SlogLogger::term(), ObserverMode::Always)
+    } else {
+        executor
+    };
+    executor.run()
+}
+
+/// Creates an `Executor` with the solver of `method` and the initial state configured according
+/// to `options` (without observers)
+#[allow(clippy::type_complexity)]
+pub(crate) fn executor<O, P, G, F>(
+    problem: O,
+    init_param: P,
+    method: Method,
+    options: &Options<F>,
+) -> Result<Executor<O, MethodSolver<P, G, F>, IterState<P, G, (), (), F>>, Error>
+where
+    F: ArgminFloat,
+    IterState<P, G, (), (), F>: State<Float = F> + SerializeAlias + DeserializeOwnedAlias,
+    MethodSolver<P, G, F>: Solver<O, IterState<P, G, (), (), F>>,
+{
+    let linesearch = MoreThuenteLineSearch::new();
+    let solver = match method {
+        Method::LBFGS => MethodSolver::LBFGS(
+            LBFGS::new(linesearch, options.lbfgs_memory)
+                .with_tolerance_grad(options.tol_grad)?
+                .with_tolerance_cost(options.tol_cost)?,
+        ),
+        Method::ConjugateGradient => MethodSolver::ConjugateGradient(
+            NonlinearConjugateGradient::new(linesearch, PolakRibiere::new())
+                .restart_iters(10)
+                .restart_orthogonality(float!(0.1)),
+        ),
+        Method::SteepestDescent => MethodSolver::SteepestDescent(SteepestDescent::new(linesearch)),
+    };
+    let (max_iters, target_cost) = (options.max_iters, options.target_cost);
+    Ok(Executor::new(problem, solver).configure(|state| {
+        state
+            .param(init_param)
+            .max_iters(max_iters)
+            .target_cost(target_cost)
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, CostFunction, Gradient};
+    use crate::test_trait_impl;
+    use argmin_testfunctions::{rosenbrock_2d, rosenbrock_2d_derivative};
+
+    test_trait_impl!(method_solver, MethodSolver<Vec<f64>, Vec<f64>, f64>);
+
+    #[derive(Clone, Debug, Default)]
+    struct Rosenbrock {}
+
+    impl CostFunction for Rosenbrock {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(rosenbrock_2d(p, 1.0, 100.0))
+        }
+    }
+
+    impl Gradient for Rosenbrock {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(rosenbrock_2d_derivative(p, 1.0, 100.0))
+        }
+    }
+
+    #[test]
+    fn test_options() {
+        let options: Options<f64> = Options::default();
+        assert_eq!(options.max_iters, 1000);
+        assert_eq!(options.lbfgs_memory, 7);
+        assert_eq!(
+            options.tol_grad.to_ne_bytes(),
+            f64::EPSILON.sqrt().to_ne_bytes()
+        );
+
+        let options = options
+            .with_max_iters(10)
+            .with_target_cost(1.0)
+            .with_tolerance_grad(1e-3)
+            .unwrap()
+            .with_tolerance_cost(1e-4)
+            .unwrap()
+            .with_lbfgs_memory(3)
+            .unwrap();
+        assert_eq!(options.max_iters, 10);
+        assert_eq!(options.target_cost.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(options.tol_grad.to_ne_bytes(), 1e-3f64.to_ne_bytes());
+        assert_eq!(options.tol_cost.to_ne_bytes(), 1e-4f64.to_ne_bytes());
+        assert_eq!(options.lbfgs_memory, 3);
+
+        assert_error!(
+            Options::default().with_tolerance_grad(-1.0),
+            ArgminError,
+            "Invalid parameter: \"`Options`: gradient tolerance must be >= 0.\""
+        );
+        assert_error!(
+            Options::default().with_tolerance_cost(-1.0),
+            ArgminError,
+            "Invalid parameter: \"`Options`: cost tolerance must be >= 0.\""
+        );
+        assert_error!(
+            Options::<f64>::default().with_lbfgs_memory(0),
+            ArgminError,
+            "Invalid parameter: \"`Options`: L-BFGS memory must be > 0.\""
+        );
+    }
+
+    #[test]
+    fn test_minimize() {
+        for method in [
+            Method::LBFGS,
+            Method::ConjugateGradient,
+            Method::SteepestDescent,
+        ] {
+            let options = Options::default().with_max_iters(20000);
+            let res = minimize(Rosenbrock {}, vec![-1.2, 1.0], method, options).unwrap();
+            assert_eq!(res.solver().method(), method);
+            let best = res.state().get_best_param().unwrap();
+            assert!((best[0] - 1.0).abs() < 1e-3, "{method:?}: {best:?}");
+            assert!((best[1] - 1.0).abs() < 1e-3, "{method:?}: {best:?}");
+        }
+    }
+
+    #[test]
+    fn test_minimize_options() {
+        let options = Options::default().with_max_iters(3);
+        let res = minimize(Rosenbrock {}, vec![-1.2, 1.0], Method::LBFGS, options);
+        assert_eq!(res.unwrap().state().get_iter(), 3);
+
+        let options = Options::default().with_target_cost(1.0);
+        let res = minimize(Rosenbrock {}, vec![-1.2, 1.0], Method::LBFGS, options).unwrap();
+        assert!(res.state().get_best_cost() <= 1.0);
+        assert!(res.state().get_iter() < 20);
+    }
+}
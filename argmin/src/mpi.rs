@@ -0,0 +1,277 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Distributed evaluation of populations with MPI
+//!
+//! [`MpiProblem`] wraps a [`CostFunction`] and distributes the evaluations of `bulk_cost` over
+//! the ranks of an MPI communicator. This is used by the population based solvers, such as
+//! [`ParticleSwarm`](`crate::solver::particleswarm::ParticleSwarm`),
+//! [`EvolutionStrategy`](`crate::solver::evolutionstrategy::EvolutionStrategy`) and
+//! [`CrossEntropy`](`crate::solver::crossentropy::CrossEntropy`), which evaluate all individuals
+//! of a generation at once. Only available with the `mpi` feature, which requires an MPI
+//! installation (see [rsmpi](https://github.com/rsmpi/rsmpi)).
+//!
+//! All ranks run the same program and wrap the same problem. The root (rank `0`) runs the solver,
+//! while all other ranks call [`serve`](`MpiProblem::serve`), which waits for populations until
+//! the root is done. For each call to `bulk_cost`, the root broadcasts the parameter vectors,
+//! every rank (including the root) evaluates a contiguous share of them and the results are
+//! gathered on the root. Single evaluations via `cost` are computed on the root only. Parameter
+//! vectors and cost function values are encoded with `bincode`, therefore they have to implement
+//! `Serialize` and `Deserialize`.
+//!
+//! Once the `MpiProblem` of the root is dropped (usually together with the `OptimizationResult`),
+//! the other ranks return from `serve`.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use argmin::core::{Bounds, CostFunction, Error, Executor};
+//! use argmin::mpi::MpiProblem;
+//! use argmin::solver::particleswarm::ParticleSwarm;
+//!
+//! struct Simulation {}
+//!
+//! impl CostFunction for Simulation {
+//!     type Param = Vec<f64>;
+//!     type Output = f64;
+//!
+//!     fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+//!         // expensive simulation
+//!         Ok(p.iter().map(|x| x * x).sum())
+//!     }
+//! }
+//!
+//! # fn main() -> Result<(), Error> {
+//! let universe = mpi::initialize().unwrap();
+//! let problem = MpiProblem::new(Simulation {}, universe.world());
+//!
+//! if problem.is_root() {
+//!     let solver = ParticleSwarm::new(Bounds::new(vec![-4.0; 10], vec![4.0; 10])?, 256);
+//!     let res = Executor::new(problem, solver)
+//!         .configure(|state| state.max_iters(100))
+//!         .run()?;
+//!     println!("{res}");
+//! } else {
+//!     problem.serve()?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The program is started with `mpirun -n <number of ranks> <program>`.
+
+use crate::core::{CostFunction, Error, SendAlias, SyncAlias};
+use ::mpi::topology::Communicator;
+use ::mpi::traits::{Destination, Root, Source};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::borrow::Borrow;
+use std::ops::Range;
+
+/// Rank which runs the solver
+const ROOT: i32 = 0;
+
+/// # MPI problem
+///
+/// Distributes `bulk_cost` of the wrapped problem over the ranks of an MPI communicator (see the
+/// [module documentation](`crate::mpi`)).
+pub struct MpiProblem<O, C: Communicator> {
+    /// Wrapped problem
+    problem: O,
+    /// Communicator whose ranks evaluate the populations
+    comm: C,
+}
+
+impl<O, C: Communicator> MpiProblem<O, C> {
+    /// Construct a new instance of `MpiProblem` from a problem and a communicator
+    pub fn new(problem: O, comm: C) -> Self {
+        MpiProblem { problem, comm }
+    }
+
+    /// Returns `true` on the rank which runs the solver
+    pub fn is_root(&self) -> bool {
+        self.comm.rank() == ROOT
+    }
+
+    /// Returns the wrapped problem
+    pub fn inner(&self) -> &O {
+        &self.problem
+    }
+
+    /// Indices of the parameter vectors out of `n` which are evaluated by `rank`
+    fn share(&self, n: usize, rank: i32) -> Range<usize> {
+        let size = self.comm.size() as usize;
+        let chunk = (n + size - 1) / size;
+        let start = (rank as usize * chunk).min(n);
+        start..(start + chunk).min(n)
+    }
+
+    /// Broadcasts a message from the root to all ranks
+    fn broadcast(&self, bytes: &mut Vec<u8>) {
+        let root = self.comm.process_at_rank(ROOT);
+        let mut len = bytes.len() as u64;
+        root.broadcast_into(&mut len);
+        bytes.resize(len as usize, 0);
+        root.broadcast_into(&mut bytes[..]);
+    }
+}
+
+impl<O, C> MpiProblem<O, C>
+where
+    O: CostFunction,
+    O::Param: DeserializeOwned,
+    O::Output: Serialize,
+    C: Communicator,
+{
+    /// Evaluates the share of this rank of the populations broadcast by the root until the root
+    /// is done
+    ///
+    /// Errors of the cost function are sent to the root, which returns them from `bulk_cost`.
+    /// Must not be called on the root.
+    pub fn serve(&self) -> Result<(), Error> {
+        if self.is_root() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`MpiProblem`: `serve` must not be called on the root."
+            ));
+        }
+        loop {
+            let mut bytes = vec![];
+            self.broadcast(&mut bytes);
+            let params: Option<Vec<O::Param>> = bincode::deserialize(&bytes)?;
+            let Some(params) = params else {
+                return Ok(());
+            };
+            let results: Result<Vec<O::Output>, String> = params
+                [self.share(params.len(), self.comm.rank())]
+            .iter()
+            .map(|p| self.problem.cost(p).map_err(|e| e.to_string()))
+            .collect();
+            let bytes = bincode::serialize(&results)?;
+            self.comm.process_at_rank(ROOT).send(&bytes[..]);
+        }
+    }
+}
+
+impl<O, C> CostFunction for MpiProblem<O, C>
+where
+    O: CostFunction,
+    O::Param: Serialize,
+    O::Output: DeserializeOwned,
+    C: Communicator,
+{
+    type Param = O::Param;
+    type Output = O::Output;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        self.problem.cost(param)
+    }
+
+    fn bulk_cost<P>(&self, params: &[P]) -> Result<Vec<Self::Output>, Error>
+    where
+        P: Borrow<Self::Param> + SyncAlias,
+        Self::Output: SendAlias,
+        Self: SyncAlias,
+    {
+        if !self.is_root() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`MpiProblem`: `bulk_cost` must only be called on the root."
+            ));
+        }
+        let params: Vec<&O::Param> = params.iter().map(Borrow::borrow).collect();
+        let mut bytes = bincode::serialize(&Some(&params))?;
+        self.broadcast(&mut bytes);
+        let own: Result<Vec<O::Output>, Error> = params[self.share(params.len(), ROOT)]
+            .iter()
+            .map(|p| self.problem.cost(p))
+            .collect();
+        // Results have to be received from all ranks, even if an evaluation failed
+        let mut results = Vec::with_capacity(params.len());
+        let mut error = own.map(|own| results.extend(own)).err();
+        for rank in 1..self.comm.size() {
+            let (bytes, _) = self.comm.process_at_rank(rank).receive_vec::<u8>();
+            let share: Result<Vec<O::Output>, String> = bincode::deserialize(&bytes)?;
+            match share {
+                Ok(share) => results.extend(share),
+                Err(e) if error.is_none() => {
+                    error = Some(argmin_error!(
+                        ConditionViolated,
+                        format!("`MpiProblem`: evaluation on rank {rank} failed: {e}")
+                    ))
+                }
+                Err(_) => {}
+            }
+        }
+        match error {
+            Some(e) => Err(e),
+            None => Ok(results),
+        }
+    }
+}
+
+impl<O, C: Communicator> Drop for MpiProblem<O, C> {
+    /// Signals the other ranks that the root is done
+    fn drop(&mut self) {
+        if self.is_root() {
+            // `None` encodes to a single byte, serialization cannot fail
+            let mut bytes = vec![0u8];
+            self.broadcast(&mut bytes);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Bounds, Executor};
+    use crate::solver::particleswarm::ParticleSwarm;
+
+    struct Sphere {}
+
+    impl CostFunction for Sphere {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(p.iter().map(|x| x * x).sum())
+        }
+    }
+
+    // MPI can only be initialized once per process, therefore everything is tested in a single
+    // test, which runs with a single rank unless started via `mpirun`.
+    #[test]
+    fn test_mpi_problem() {
+        let universe = ::mpi::initialize().unwrap();
+        let problem = MpiProblem::new(Sphere {}, universe.world());
+
+        // The shares of all ranks cover all parameter vectors without overlap
+        let mut end = 0;
+        for rank in 0..universe.world().size() {
+            let share = problem.share(10, rank);
+            assert_eq!(share.start, end);
+            end = share.end;
+        }
+        assert_eq!(end, 10);
+
+        if problem.is_root() {
+            let params = vec![vec![1.0, 2.0], vec![3.0, 0.0], vec![0.0, 0.0]];
+            assert_eq!(problem.bulk_cost(&params).unwrap(), vec![5.0, 9.0, 0.0]);
+            assert!(problem.serve().is_err());
+
+            let solver =
+                ParticleSwarm::new(Bounds::new(vec![-2.0, -2.0], vec![2.0, 2.0]).unwrap(), 20);
+            let res = Executor::new(problem, solver)
+                .configure(|state| state.max_iters(30))
+                .run()
+                .unwrap();
+            assert!(res.state.best_cost < 1e-2);
+        } else {
+            problem.serve().unwrap();
+        }
+    }
+}
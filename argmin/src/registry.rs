@@ -0,0 +1,811 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Registry of solvers selectable by name
+//!
+//! A [`Registry`] maps names such as `"lbfgs"` or `"neldermead"` to factories of boxed solvers
+//! ([`DynSolver`]), which are configured by a uniform map of named options ([`OptionMap`]). This
+//! allows choosing the algorithm at runtime, for instance from command line arguments, config
+//! files or language bindings, without naming any solver type. Similar to NLopt, all solvers work
+//! on problems with `Vec<F>` parameter vectors which provide both cost function and gradient;
+//! derivative-free solvers never call the gradient.
+//!
+//! [`Registry::new`] contains the following solvers. The options `max_iters` and `target_cost`
+//! are accepted by all of them, unknown options are rejected.
+//!
+//! | Name               | Solver                         | Options                                       |
+//! |--------------------|--------------------------------|-----------------------------------------------|
+//! | `lbfgs`            | [`LBFGS`]                      | `memory`, `tol_grad`, `tol_cost`              |
+//! | `cg`               | [`NonlinearConjugateGradient`] | `restart_iters`, `restart_orthogonality`      |
+//! | `steepest-descent` | [`SteepestDescent`]            |                                               |
+//! | `neldermead`       | [`NelderMead`]                 | `step`, `sd_tolerance`                        |
+//! | `particleswarm`    | [`ParticleSwarm`]              | `lower`, `upper` (required), `particles`, `seed` |
+//! | `crossentropy`     | [`CrossEntropy`]               | `samples`, `elites`, `std`, `smoothing`, `tol_std`, `seed` |
+//!
+//! Gradient based solvers use a [`MoreThuenteLineSearch`]. The initial simplex of Nelder-Mead
+//! consists of the initial parameter vector and one vertex per coordinate, which is moved by
+//! `step` times its value (or by `step / 200` if it is zero). Particle swarm optimization samples
+//! its particles from the box `[lower, upper]` in every coordinate and only uses the length of
+//! the initial parameter vector. The population based solvers draw their random numbers from a
+//! generator seeded with `seed` if given, which makes their results reproducible. Further solvers can be added with [`Registry::register`].
+//!
+//! # Example
+//!
+//! ```
+//! # use argmin::core::{CostFunction, Error, Gradient};
+//! # use argmin_testfunctions::{rosenbrock_2d, rosenbrock_2d_derivative};
+//! use argmin::registry::{OptionMap, Registry};
+//!
+//! # struct Rosenbrock {}
+//! # impl CostFunction for Rosenbrock {
+//! #     type Param = Vec<f64>;
+//! #     type Output = f64;
+//! #     fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+//! #         Ok(rosenbrock_2d(p, 1.0, 100.0))
+//! #     }
+//! # }
+//! # impl Gradient for Rosenbrock {
+//! #     type Param = Vec<f64>;
+//! #     type Gradient = Vec<f64>;
+//! #     fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+//! #         Ok(rosenbrock_2d_derivative(p, 1.0, 100.0))
+//! #     }
+//! # }
+//! # fn main() -> Result<(), Error> {
+//! let registry = Registry::new();
+//!
+//! // e.g. read from the command line
+//! let name = "neldermead";
+//! let options = OptionMap::from([("max_iters".to_string(), 500.0), ("step".to_string(), 0.1)]);
+//!
+//! let summary = registry.minimize(name, Rosenbrock {}, vec![-1.2, 1.0], &options)?;
+//! println!("{:?} {}", summary.best_param, summary.best_cost);
+//! # assert!(summary.best_cost < 1e-6);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::core::{
+    ArgminFloat, Bounds, DeserializeOwnedAlias, Error, Executor, IterState, PopulationState,
+    SerializeAlias, Solver, State, SyncAlias, TerminationStatus,
+};
+use crate::solver::conjugategradient::{beta::PolakRibiere, NonlinearConjugateGradient};
+use crate::solver::crossentropy::CrossEntropy;
+use crate::solver::gradientdescent::SteepestDescent;
+use crate::solver::linesearch::MoreThuenteLineSearch;
+use crate::solver::neldermead::NelderMead;
+use crate::solver::particleswarm::{Particle, ParticleSwarm};
+use crate::solver::quasinewton::LBFGS;
+use argmin_math::{ArgminAdd, ArgminBound, ArgminMul, ArgminRandom, ArgminSub, ArgminZeroLike};
+use rand::SeedableRng;
+use rand_xoshiro::Xoshiro256PlusPlus;
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+
+/// Options of a solver by name
+pub type OptionMap<F> = BTreeMap<String, F>;
+
+/// Options accepted by all solvers
+const COMMON_OPTIONS: [&str; 2] = ["max_iters", "target_cost"];
+
+/// Outcome of a run, independent of the solver
+#[derive(Clone, Debug)]
+pub struct Summary<F> {
+    /// Best parameter vector
+    pub best_param: Vec<F>,
+    /// Cost function value of `best_param`
+    pub best_cost: F,
+    /// Number of iterations
+    pub iters: u64,
+    /// Function evaluation counts
+    pub func_counts: BTreeMap<String, u64>,
+    /// Termination status
+    pub termination_status: TerminationStatus,
+}
+
+/// Solver behind a trait object, as created by the factories of a [`Registry`]
+pub trait DynSolver<O, F> {
+    /// Minimizes `problem` starting from `init_param` for at most `max_iters` iterations or until
+    /// `target_cost` is reached
+    fn run(
+        self: Box<Self>,
+        problem: O,
+        init_param: Vec<F>,
+        max_iters: u64,
+        target_cost: F,
+    ) -> Result<Summary<F>, Error>;
+}
+
+/// Creates a solver from its options
+type Factory<O, F> = Box<dyn Fn(&OptionMap<F>) -> Result<Box<dyn DynSolver<O, F>>, Error>>;
+
+/// Registered solver
+struct Entry<O, F> {
+    /// Names of the options specific to the solver
+    options: &'static [&'static str],
+    /// Factory
+    factory: Factory<O, F>,
+}
+
+/// # Registry of solvers
+///
+/// Maps names to factories of [`DynSolver`]s (see the [module documentation](`crate::registry`)).
+pub struct Registry<O, F> {
+    /// Solvers by name
+    entries: BTreeMap<&'static str, Entry<O, F>>,
+}
+
+impl<O, F> Registry<O, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a registry without any solvers
+    pub fn empty() -> Self {
+        Registry {
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Add (or replace) the solver `name` with the solver specific `options`, which is created by
+    /// `factory`
+    ///
+    /// The factory is only called with options out of `options`; it should fall back to defaults
+    /// for options which are not given.
+    pub fn register<Fa>(
+        &mut self,
+        name: &'static str,
+        options: &'static [&'static str],
+        factory: Fa,
+    ) where
+        Fa: Fn(&OptionMap<F>) -> Result<Box<dyn DynSolver<O, F>>, Error> + 'static,
+    {
+        self.entries.insert(
+            name,
+            Entry {
+                options,
+                factory: Box::new(factory),
+            },
+        );
+    }
+
+    /// Returns the names of all registered solvers in alphabetical order
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.entries.keys().copied()
+    }
+
+    /// Returns the names of the options specific to the solver `name`
+    pub fn options(&self, name: &str) -> Option<&'static [&'static str]> {
+        self.entries.get(name).map(|entry| entry.options)
+    }
+
+    /// Creates the solver `name` from `options`
+    ///
+    /// Fails if the solver is unknown or an option is neither specific to the solver nor one of
+    /// `max_iters` and `target_cost` (which are ignored here).
+    pub fn solver(
+        &self,
+        name: &str,
+        options: &OptionMap<F>,
+    ) -> Result<Box<dyn DynSolver<O, F>>, Error> {
+        let entry = self.entries.get(name).ok_or_else(|| -> Error {
+            argmin_error!(
+                InvalidParameter,
+                format!(
+                    "`Registry`: unknown solver {name:?}; expected one of {:?}.",
+                    self.names().collect::<Vec<_>>()
+                )
+            )
+        })?;
+        if let Some(key) = options.keys().find(|key| {
+            !entry.options.contains(&key.as_str()) && !COMMON_OPTIONS.contains(&key.as_str())
+        }) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                format!(
+                    "`Registry`: unknown option {key:?} of solver {name:?}; expected one of {:?}.",
+                    entry
+                        .options
+                        .iter()
+                        .chain(&COMMON_OPTIONS)
+                        .collect::<Vec<_>>()
+                )
+            ));
+        }
+        (entry.factory)(options)
+    }
+
+    /// Minimizes `problem` with the solver `name`, starting from `init_param`
+    ///
+    /// `max_iters` defaults to `1000`, `target_cost` to `-Inf`.
+    pub fn minimize(
+        &self,
+        name: &str,
+        problem: O,
+        init_param: Vec<F>,
+        options: &OptionMap<F>,
+    ) -> Result<Summary<F>, Error> {
+        let solver = self.solver(name, options)?;
+        let max_iters = count(options, "max_iters", 1000)?;
+        let target_cost = options
+            .get("target_cost")
+            .copied()
+            .unwrap_or_else(F::neg_infinity);
+        solver.run(problem, init_param, max_iters, target_cost)
+    }
+}
+
+impl<O, F> Registry<O, F>
+where
+    F: ArgminFloat,
+    Vec<F>: ArgminAdd<Vec<F>, Vec<F>>
+        + ArgminSub<Vec<F>, Vec<F>>
+        + ArgminMul<F, Vec<F>>
+        + ArgminRandom
+        + ArgminZeroLike
+        + ArgminBound
+        + SyncAlias,
+    IterState<Vec<F>, Vec<F>, (), (), F>: SerializeAlias + DeserializeOwnedAlias,
+    IterState<Vec<F>, (), (), (), F>: SerializeAlias + DeserializeOwnedAlias,
+    PopulationState<Particle<Vec<F>, F>, F>: SerializeAlias + DeserializeOwnedAlias,
+    LBFGS<MoreThuenteLineSearch<Vec<F>, Vec<F>, F>, Vec<F>, Vec<F>, F>:
+        Solver<O, IterState<Vec<F>, Vec<F>, (), (), F>> + 'static,
+    NonlinearConjugateGradient<Vec<F>, MoreThuenteLineSearch<Vec<F>, Vec<F>, F>, PolakRibiere, F>:
+        Solver<O, IterState<Vec<F>, Vec<F>, (), (), F>> + 'static,
+    SteepestDescent<MoreThuenteLineSearch<Vec<F>, Vec<F>, F>>:
+        Solver<O, IterState<Vec<F>, Vec<F>, (), (), F>> + 'static,
+    NelderMead<Vec<F>, F>: Solver<O, IterState<Vec<F>, (), (), (), F>>,
+    ParticleSwarm<Vec<F>, F>: Solver<O, PopulationState<Particle<Vec<F>, F>, F>>,
+    CrossEntropy<F, Xoshiro256PlusPlus>: Solver<O, IterState<Vec<F>, (), (), (), F>> + 'static,
+{
+    /// Construct a registry of the solvers listed in the
+    /// [module documentation](`crate::registry`)
+    pub fn new() -> Self {
+        let mut registry = Registry::empty();
+        registry.register("lbfgs", &["memory", "tol_grad", "tol_cost"], |options| {
+            let mut solver = LBFGS::new(
+                MoreThuenteLineSearch::new(),
+                count(options, "memory", 7)? as usize,
+            );
+            if let Some(&tol) = options.get("tol_grad") {
+                solver = solver.with_tolerance_grad(tol)?;
+            }
+            if let Some(&tol) = options.get("tol_cost") {
+                solver = solver.with_tolerance_cost(tol)?;
+            }
+            Ok(Box::new(Iterative::new(solver)))
+        });
+        registry.register(
+            "cg",
+            &["restart_iters", "restart_orthogonality"],
+            |options| {
+                let solver = NonlinearConjugateGradient::new(
+                    MoreThuenteLineSearch::new(),
+                    PolakRibiere::new(),
+                )
+                .restart_iters(count(options, "restart_iters", 10)?)
+                .restart_orthogonality(
+                    options
+                        .get("restart_orthogonality")
+                        .copied()
+                        .unwrap_or_else(|| float!(0.1)),
+                );
+                Ok(Box::new(Iterative::new(solver)))
+            },
+        );
+        registry.register("steepest-descent", &[], |_| {
+            Ok(Box::new(Iterative::new(SteepestDescent::new(
+                MoreThuenteLineSearch::new(),
+            ))))
+        });
+        registry.register("neldermead", &["step", "sd_tolerance"], |options| {
+            Ok(Box::new(Simplex {
+                step: options.get("step").copied().unwrap_or_else(|| float!(0.05)),
+                sd_tolerance: options.get("sd_tolerance").copied(),
+            }))
+        });
+        registry.register(
+            "particleswarm",
+            &["lower", "upper", "particles", "seed"],
+            |options| {
+                let bound = |key: &str| {
+                    options.get(key).copied().ok_or_else(|| -> Error {
+                        argmin_error!(
+                            InvalidParameter,
+                            format!(
+                                "`Registry`: solver \"particleswarm\" requires option {key:?}."
+                            )
+                        )
+                    })
+                };
+                Ok(Box::new(Swarm {
+                    lower: bound("lower")?,
+                    upper: bound("upper")?,
+                    particles: count(options, "particles", 40)? as usize,
+                    rng: rng(options)?,
+                }))
+            },
+        );
+        registry.register(
+            "crossentropy",
+            &["samples", "elites", "std", "smoothing", "tol_std", "seed"],
+            |options| {
+                let mut solver = CrossEntropy::new_with_rng(
+                    count(options, "samples", 100)? as usize,
+                    count(options, "elites", 10)? as usize,
+                    rng(options)?,
+                )?;
+                if let Some(&std) = options.get("std") {
+                    solver = solver.with_std(std)?;
+                }
+                if let Some(&smoothing) = options.get("smoothing") {
+                    solver = solver.with_smoothing(smoothing)?;
+                }
+                if let Some(&tol) = options.get("tol_std") {
+                    solver = solver.with_tolerance_std(tol)?;
+                }
+                Ok(Box::new(Iterative::new(solver)))
+            },
+        );
+        registry
+    }
+}
+
+impl<O, F> Default for Registry<O, F>
+where
+    F: ArgminFloat,
+    Vec<F>: ArgminAdd<Vec<F>, Vec<F>>
+        + ArgminSub<Vec<F>, Vec<F>>
+        + ArgminMul<F, Vec<F>>
+        + ArgminRandom
+        + ArgminZeroLike
+        + ArgminBound
+        + SyncAlias,
+    IterState<Vec<F>, Vec<F>, (), (), F>: SerializeAlias + DeserializeOwnedAlias,
+    IterState<Vec<F>, (), (), (), F>: SerializeAlias + DeserializeOwnedAlias,
+    PopulationState<Particle<Vec<F>, F>, F>: SerializeAlias + DeserializeOwnedAlias,
+    LBFGS<MoreThuenteLineSearch<Vec<F>, Vec<F>, F>, Vec<F>, Vec<F>, F>:
+        Solver<O, IterState<Vec<F>, Vec<F>, (), (), F>> + 'static,
+    NonlinearConjugateGradient<Vec<F>, MoreThuenteLineSearch<Vec<F>, Vec<F>, F>, PolakRibiere, F>:
+        Solver<O, IterState<Vec<F>, Vec<F>, (), (), F>> + 'static,
+    SteepestDescent<MoreThuenteLineSearch<Vec<F>, Vec<F>, F>>:
+        Solver<O, IterState<Vec<F>, Vec<F>, (), (), F>> + 'static,
+    NelderMead<Vec<F>, F>: Solver<O, IterState<Vec<F>, (), (), (), F>>,
+    ParticleSwarm<Vec<F>, F>: Solver<O, PopulationState<Particle<Vec<F>, F>, F>>,
+    CrossEntropy<F, Xoshiro256PlusPlus>: Solver<O, IterState<Vec<F>, (), (), (), F>> + 'static,
+{
+    /// The registry of the solvers listed in the [module documentation](`crate::registry`), see
+    /// [`Registry::new`]
+    fn default() -> Self {
+        Registry::new()
+    }
+}
+
+/// Returns the option `key` as non-negative integer, or `default` if it is not given
+fn count<F: ArgminFloat>(options: &OptionMap<F>, key: &str, default: u64) -> Result<u64, Error> {
+    match options.get(key) {
+        None => Ok(default),
+        Some(&value) if value >= float!(0.0) && value.fract().is_zero() => {
+            value.to_u64().ok_or_else(|| -> Error {
+                argmin_error!(
+                    InvalidParameter,
+                    format!("`Registry`: option {key:?} is too large.")
+                )
+            })
+        }
+        Some(_) => Err(argmin_error!(
+            InvalidParameter,
+            format!("`Registry`: option {key:?} must be a non-negative integer.")
+        )),
+    }
+}
+
+/// Returns a RNG seeded with the option `seed`, or from entropy if it is not given
+fn rng<F: ArgminFloat>(options: &OptionMap<F>) -> Result<Xoshiro256PlusPlus, Error> {
+    Ok(match options.get("seed") {
+        Some(_) => Xoshiro256PlusPlus::seed_from_u64(count(options, "seed", 0)?),
+        None => Xoshiro256PlusPlus::from_entropy(),
+    })
+}
+
+/// Runs `solver` on `problem` with the state initialized by `init` and summarizes the result;
+/// `param` maps the parameter vectors of the state to `Vec<F>`
+fn run<O, S, I, F>(
+    problem: O,
+    solver: S,
+    init: impl FnOnce(I) -> I,
+    param: impl Fn(&I::Param) -> Vec<F>,
+) -> Result<Summary<F>, Error>
+where
+    S: Solver<O, I>,
+    I: State<Float = F> + SerializeAlias + DeserializeOwnedAlias,
+    F: ArgminFloat,
+{
+    let res = Executor::new(problem, solver).configure(init).run()?;
+    let state = res.state();
+    Ok(Summary {
+        best_param: state.get_best_param().map(param).ok_or_else(|| -> Error {
+            argmin_error!(PotentialBug, "Solver did not return a parameter vector.")
+        })?,
+        best_cost: state.get_best_cost(),
+        iters: state.get_iter(),
+        func_counts: state.get_func_counts().clone(),
+        termination_status: state.get_termination_status().clone(),
+    })
+}
+
+/// Solver which works on an [`IterState`] initialized with the initial parameter vector
+struct Iterative<S, G> {
+    /// Solver
+    solver: S,
+    /// Type of the gradient of the state
+    gradient: PhantomData<G>,
+}
+
+impl<S, G> Iterative<S, G> {
+    /// Wraps `solver`
+    fn new(solver: S) -> Self {
+        Iterative {
+            solver,
+            gradient: PhantomData,
+        }
+    }
+}
+
+impl<O, S, G, F> DynSolver<O, F> for Iterative<S, G>
+where
+    S: Solver<O, IterState<Vec<F>, G, (), (), F>>,
+    IterState<Vec<F>, G, (), (), F>: SerializeAlias + DeserializeOwnedAlias,
+    F: ArgminFloat,
+{
+    fn run(
+        self: Box<Self>,
+        problem: O,
+        init_param: Vec<F>,
+        max_iters: u64,
+        target_cost: F,
+    ) -> Result<Summary<F>, Error> {
+        run(
+            problem,
+            self.solver,
+            |state: IterState<Vec<F>, G, (), (), F>| {
+                state
+                    .param(init_param)
+                    .max_iters(max_iters)
+                    .target_cost(target_cost)
+            },
+            Vec::clone,
+        )
+    }
+}
+
+/// Nelder-Mead, whose initial simplex is built around the initial parameter vector
+struct Simplex<F> {
+    /// Relative size of the initial simplex
+    step: F,
+    /// Tolerance for the standard deviation of the cost function values of the simplex
+    sd_tolerance: Option<F>,
+}
+
+impl<O, F> DynSolver<O, F> for Simplex<F>
+where
+    NelderMead<Vec<F>, F>: Solver<O, IterState<Vec<F>, (), (), (), F>>,
+    IterState<Vec<F>, (), (), (), F>: SerializeAlias + DeserializeOwnedAlias,
+    Vec<F>: ArgminAdd<Vec<F>, Vec<F>> + ArgminSub<Vec<F>, Vec<F>> + ArgminMul<F, Vec<F>>,
+    F: ArgminFloat,
+{
+    fn run(
+        self: Box<Self>,
+        problem: O,
+        init_param: Vec<F>,
+        max_iters: u64,
+        target_cost: F,
+    ) -> Result<Summary<F>, Error> {
+        let mut simplex = vec![init_param.clone()];
+        for i in 0..init_param.len() {
+            let mut vertex = init_param.clone();
+            vertex[i] = if vertex[i].is_zero() {
+                self.step / float!(200.0)
+            } else {
+                (float!(1.0) + self.step) * vertex[i]
+            };
+            simplex.push(vertex);
+        }
+        let mut solver = NelderMead::new(simplex);
+        if let Some(tol) = self.sd_tolerance {
+            solver = solver.with_sd_tolerance(tol)?;
+        }
+        run(
+            problem,
+            solver,
+            |state: IterState<Vec<F>, (), (), (), F>| {
+                state.max_iters(max_iters).target_cost(target_cost)
+            },
+            Vec::clone,
+        )
+    }
+}
+
+/// Particle swarm optimization within a box which is the same in every coordinate
+struct Swarm<F> {
+    /// Lower bound of every coordinate
+    lower: F,
+    /// Upper bound of every coordinate
+    upper: F,
+    /// Number of particles
+    particles: usize,
+    /// Random number generator
+    rng: Xoshiro256PlusPlus,
+}
+
+impl<O, F> DynSolver<O, F> for Swarm<F>
+where
+    ParticleSwarm<Vec<F>, F>: Solver<O, PopulationState<Particle<Vec<F>, F>, F>>,
+    PopulationState<Particle<Vec<F>, F>, F>: SerializeAlias + DeserializeOwnedAlias,
+    Vec<F>: ArgminSub<Vec<F>, Vec<F>>
+        + ArgminMul<F, Vec<F>>
+        + ArgminRandom
+        + ArgminZeroLike
+        + ArgminBound
+        + SyncAlias,
+    F: ArgminFloat,
+{
+    fn run(
+        self: Box<Self>,
+        problem: O,
+        init_param: Vec<F>,
+        max_iters: u64,
+        target_cost: F,
+    ) -> Result<Summary<F>, Error> {
+        let n = init_param.len();
+        let bounds = Bounds::new(vec![self.lower; n], vec![self.upper; n])?;
+        run(
+            problem,
+            ParticleSwarm::new_with_rng(bounds, self.particles, self.rng),
+            |state: PopulationState<Particle<Vec<F>, F>, F>| {
+                state.max_iters(max_iters).target_cost(target_cost)
+            },
+            |particle| particle.position.clone(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, CostFunction, Gradient, TerminationReason};
+    use argmin_testfunctions::{
+        rosenbrock_2d, rosenbrock_2d_derivative, sphere, sphere_derivative,
+    };
+
+    #[derive(Clone, Debug, Default)]
+    struct Rosenbrock {}
+
+    impl CostFunction for Rosenbrock {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(rosenbrock_2d(p, 1.0, 100.0))
+        }
+    }
+
+    impl Gradient for Rosenbrock {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(rosenbrock_2d_derivative(p, 1.0, 100.0))
+        }
+    }
+
+    #[derive(Clone, Debug, Default)]
+    struct Sphere {}
+
+    impl CostFunction for Sphere {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(sphere(p))
+        }
+    }
+
+    impl Gradient for Sphere {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(sphere_derivative(p))
+        }
+    }
+
+    fn options(options: &[(&str, f64)]) -> OptionMap<f64> {
+        options.iter().map(|(k, v)| (k.to_string(), *v)).collect()
+    }
+
+    #[test]
+    fn test_names() {
+        let registry: Registry<Sphere, f64> = Registry::new();
+        assert_eq!(
+            registry.names().collect::<Vec<_>>(),
+            vec![
+                "cg",
+                "crossentropy",
+                "lbfgs",
+                "neldermead",
+                "particleswarm",
+                "steepest-descent"
+            ]
+        );
+        assert_eq!(
+            registry.options("lbfgs").unwrap(),
+            &["memory", "tol_grad", "tol_cost"]
+        );
+        assert!(registry.options("cmaes").is_none());
+        assert_eq!(Registry::<Sphere, f64>::empty().names().count(), 0);
+        assert!(Registry::<Sphere, f64>::default()
+            .names()
+            .eq(Registry::<Sphere, f64>::new().names()));
+    }
+
+    #[test]
+    fn test_minimize() {
+        let registry = Registry::new();
+        for (name, options) in [
+            ("lbfgs", options(&[("memory", 5.0)])),
+            ("cg", options(&[("max_iters", 20000.0)])),
+            ("steepest-descent", options(&[("max_iters", 20000.0)])),
+            (
+                "neldermead",
+                options(&[("step", 0.1), ("sd_tolerance", 1e-12)]),
+            ),
+        ] {
+            let summary = registry
+                .minimize(name, Rosenbrock {}, vec![-1.2, 1.0], &options)
+                .unwrap();
+            let best = &summary.best_param;
+            assert!((best[0] - 1.0).abs() < 1e-3, "{name}: {best:?}");
+            assert!((best[1] - 1.0).abs() < 1e-3, "{name}: {best:?}");
+            assert!(summary.func_counts["cost_count"] > 0);
+        }
+    }
+
+    #[test]
+    fn test_minimize_population() {
+        let registry = Registry::new();
+        for (name, options) in [
+            (
+                "particleswarm",
+                options(&[
+                    ("lower", -4.0),
+                    ("upper", 4.0),
+                    ("max_iters", 100.0),
+                    ("seed", 42.0),
+                ]),
+            ),
+            (
+                "crossentropy",
+                options(&[
+                    ("std", 2.0),
+                    ("smoothing", 0.7),
+                    ("max_iters", 200.0),
+                    ("seed", 42.0),
+                ]),
+            ),
+        ] {
+            let summary = registry
+                .minimize(name, Sphere {}, vec![2.0, -1.5, 3.0], &options)
+                .unwrap();
+            assert_eq!(summary.best_param.len(), 3);
+            assert!(summary.best_cost < 1e-3, "{name}: {}", summary.best_cost);
+        }
+    }
+
+    #[test]
+    fn test_common_options() {
+        let registry = Registry::new();
+        let summary = registry
+            .minimize(
+                "lbfgs",
+                Rosenbrock {},
+                vec![-1.2, 1.0],
+                &options(&[("max_iters", 3.0)]),
+            )
+            .unwrap();
+        assert_eq!(summary.iters, 3);
+        assert_eq!(
+            summary.termination_status,
+            TerminationStatus::Terminated(TerminationReason::MaxItersReached)
+        );
+
+        let summary = registry
+            .minimize(
+                "neldermead",
+                Rosenbrock {},
+                vec![-1.2, 1.0],
+                &options(&[("target_cost", 1.0)]),
+            )
+            .unwrap();
+        assert!(summary.best_cost <= 1.0);
+        assert_eq!(
+            summary.termination_status,
+            TerminationStatus::Terminated(TerminationReason::TargetCostReached)
+        );
+    }
+
+    #[test]
+    fn test_register() {
+        let mut registry = Registry::empty();
+        registry.register("sd", &[], |_| {
+            Ok(Box::new(Iterative::new(SteepestDescent::new(
+                MoreThuenteLineSearch::new(),
+            ))))
+        });
+        assert_eq!(registry.names().collect::<Vec<_>>(), vec!["sd"]);
+        let summary = registry
+            .minimize(
+                "sd",
+                Sphere {},
+                vec![1.0, 2.0],
+                &options(&[("target_cost", 1e-10)]),
+            )
+            .unwrap();
+        assert!(summary.best_cost < 1e-10);
+    }
+
+    #[test]
+    fn test_errors() {
+        let registry: Registry<Rosenbrock, f64> = Registry::new();
+        let x0 = vec![-1.2, 1.0];
+        assert_error!(
+            registry.minimize("cmaes", Rosenbrock {}, x0.clone(), &OptionMap::new()),
+            ArgminError,
+            "Invalid parameter: \"`Registry`: unknown solver \\\"cmaes\\\"; expected one of \
+             [\\\"cg\\\", \\\"crossentropy\\\", \\\"lbfgs\\\", \\\"neldermead\\\", \
+             \\\"particleswarm\\\", \\\"steepest-descent\\\"].\""
+        );
+        assert_error!(
+            registry.minimize(
+                "steepest-descent",
+                Rosenbrock {},
+                x0.clone(),
+                &options(&[("memory", 3.0)])
+            ),
+            ArgminError,
+            "Invalid parameter: \"`Registry`: unknown option \\\"memory\\\" of solver \
+             \\\"steepest-descent\\\"; expected one of [\\\"max_iters\\\", \\\"target_cost\\\"].\""
+        );
+        assert_error!(
+            registry.minimize(
+                "lbfgs",
+                Rosenbrock {},
+                x0.clone(),
+                &options(&[("max_iters", 1.5)])
+            ),
+            ArgminError,
+            "Invalid parameter: \"`Registry`: option \\\"max_iters\\\" must be a non-negative \
+             integer.\""
+        );
+        assert_error!(
+            registry.minimize(
+                "particleswarm",
+                Rosenbrock {},
+                x0.clone(),
+                &options(&[("lower", -1.0)])
+            ),
+            ArgminError,
+            "Invalid parameter: \"`Registry`: solver \\\"particleswarm\\\" requires option \
+             \\\"upper\\\".\""
+        );
+        // Errors of the solvers themselves are passed on
+        assert_error!(
+            registry.minimize("lbfgs", Rosenbrock {}, x0, &options(&[("tol_grad", -1.0)])),
+            ArgminError,
+            "Invalid parameter: \"`L-BFGS`: gradient tolerance must be >= 0.\""
+        );
+    }
+}
@@ -0,0 +1,569 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Remote evaluation of cost functions and gradients
+//!
+//! [`RemoteProblem`] implements [`CostFunction`] and [`Gradient`] by sending the parameter
+//! vectors to one or more worker services over HTTP, for instance a cluster of simulators. Only
+//! available with the `remote` feature.
+//!
+//! ## Protocol
+//!
+//! Each request is a `POST` to the URL of a worker with a JSON body which names the operation
+//! (`"cost"` or `"gradient"`) and contains a batch of parameter vectors:
+//!
+//! ```json
+//! {"operation": "cost", "params": [[1.0, 2.0], [0.5, -1.0]]}
+//! ```
+//!
+//! A worker answers with status `200` and one result per parameter vector, in the same order:
+//!
+//! ```json
+//! {"results": [100.0, 156.25]}
+//! ```
+//!
+//! Any other status or a body of the form `{"error": "..."}` indicates a failure. Parameter
+//! vectors, cost function values and gradients are encoded with serde, therefore any type which
+//! implements `Serialize` and `Deserialize` can be used.
+//!
+//! ## Batching, retries and timeouts
+//!
+//! `cost` and `gradient` send a single parameter vector; consecutive calls are distributed over
+//! the workers in a round-robin fashion. `bulk_cost` and `bulk_gradient`, which are used by
+//! population based solvers such as [`ParticleSwarm`](`crate::solver::particleswarm::ParticleSwarm`),
+//! split the parameter vectors into batches of at most
+//! [`with_batch_size`](`RemoteProblem::with_batch_size`) vectors and send the batches to the
+//! workers concurrently.
+//!
+//! A request which fails (connection error, timeout, unexpected status or malformed response) is
+//! repeated on the next worker up to [`with_retries`](`RemoteProblem::with_retries`) times.
+//! Every request is subject to the timeout set via
+//! [`with_timeout`](`RemoteProblem::with_timeout`). If all attempts fail, the error of the last
+//! attempt is returned, which terminates the optimization.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use argmin::core::{Error, Executor};
+//! use argmin::remote::RemoteProblem;
+//! use argmin::solver::neldermead::NelderMead;
+//! use std::time::Duration;
+//!
+//! # fn main() -> Result<(), Error> {
+//! let problem: RemoteProblem = RemoteProblem::new(vec![
+//!     "http://simulator-1:8080/evaluate".to_string(),
+//!     "http://simulator-2:8080/evaluate".to_string(),
+//! ])?
+//! .with_timeout(Duration::from_secs(60))
+//! .with_retries(3);
+//!
+//! let solver = NelderMead::new(vec![vec![-1.0, 3.0], vec![2.0, 1.5], vec![2.0, -1.0]]);
+//! let res = Executor::new(problem, solver)
+//!     .configure(|state| state.max_iters(100))
+//!     .run()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::core::{CostFunction, Error, Gradient, SendAlias, SyncAlias};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::borrow::Borrow;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+/// Body of a request
+#[derive(Serialize)]
+struct Request<'a, P> {
+    /// `"cost"` or `"gradient"`
+    operation: &'a str,
+    /// Parameter vectors to evaluate
+    params: &'a [&'a P],
+}
+
+/// Body of a response
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Response<T> {
+    /// One result per parameter vector
+    Results {
+        /// Results
+        results: Vec<T>,
+    },
+    /// Failure reported by the worker
+    Error {
+        /// Description of the failure
+        error: String,
+    },
+}
+
+/// # Remote problem
+///
+/// Evaluates cost functions and gradients on worker services via HTTP (see the
+/// [module documentation](`crate::remote`) for the protocol).
+///
+/// `P` is the type of the parameter vector, `O` the type of the cost function value and `G` the
+/// type of the gradient.
+pub struct RemoteProblem<P = Vec<f64>, O = f64, G = P> {
+    /// URLs of the workers
+    endpoints: Vec<String>,
+    /// HTTP client
+    agent: ureq::Agent,
+    /// Timeout per request
+    timeout: Duration,
+    /// Number of times a failed request is repeated
+    retries: usize,
+    /// Maximum number of parameter vectors per request
+    batch_size: usize,
+    /// Worker which receives the next request
+    next: AtomicUsize,
+    /// Function pointer, such that the problem is `Send` and `Sync` independent of the types
+    #[allow(clippy::type_complexity)]
+    _phantom: PhantomData<fn(&P) -> (O, G)>,
+}
+
+impl<P, O, G> RemoteProblem<P, O, G> {
+    /// Construct a new instance of `RemoteProblem` from the URLs of the workers
+    ///
+    /// The timeout defaults to 30 seconds, failed requests are repeated twice and batches contain
+    /// at most 64 parameter vectors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::remote::RemoteProblem;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let problem: RemoteProblem = RemoteProblem::new(vec!["http://localhost:8080".to_string()])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(endpoints: Vec<String>) -> Result<Self, Error> {
+        if endpoints.is_empty() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`RemoteProblem`: at least one endpoint is required."
+            ));
+        }
+        let timeout = Duration::from_secs(30);
+        Ok(RemoteProblem {
+            endpoints,
+            agent: ureq::AgentBuilder::new().timeout(timeout).build(),
+            timeout,
+            retries: 2,
+            batch_size: 64,
+            next: AtomicUsize::new(0),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Set the timeout of a request, including connecting, sending and receiving
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::remote::RemoteProblem;
+    /// # use argmin::core::Error;
+    /// # use std::time::Duration;
+    /// # fn main() -> Result<(), Error> {
+    /// let problem: RemoteProblem = RemoteProblem::new(vec!["http://localhost:8080".to_string()])?
+    ///     .with_timeout(Duration::from_secs(5));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self.agent = ureq::AgentBuilder::new().timeout(timeout).build();
+        self
+    }
+
+    /// Set how often a failed request is repeated (on the next worker)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::remote::RemoteProblem;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let problem: RemoteProblem = RemoteProblem::new(vec!["http://localhost:8080".to_string()])?
+    ///     .with_retries(5);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_retries(mut self, retries: usize) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// Set the maximum number of parameter vectors sent in a single request
+    ///
+    /// Must be larger than 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::remote::RemoteProblem;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let problem: RemoteProblem = RemoteProblem::new(vec!["http://localhost:8080".to_string()])?
+    ///     .with_batch_size(16)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_batch_size(mut self, batch_size: usize) -> Result<Self, Error> {
+        if batch_size == 0 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`RemoteProblem`: batch size must be > 0."
+            ));
+        }
+        self.batch_size = batch_size;
+        Ok(self)
+    }
+
+    /// Returns the URLs of the workers
+    pub fn endpoints(&self) -> &[String] {
+        &self.endpoints
+    }
+
+    /// Returns the timeout of a request
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+}
+
+impl<P: Serialize + Sync, O, G> RemoteProblem<P, O, G> {
+    /// Evaluates `operation` for all `params`, batched and distributed over the workers
+    fn evaluate<T: DeserializeOwned + Send>(
+        &self,
+        operation: &str,
+        params: &[&P],
+    ) -> Result<Vec<T>, Error> {
+        let batches: Vec<&[&P]> = params.chunks(self.batch_size).collect();
+        let first = self.next.fetch_add(batches.len(), Ordering::Relaxed);
+        if batches.len() == 1 {
+            return self.send_with_retries(operation, batches[0], first);
+        }
+        std::thread::scope(|s| {
+            let handles: Vec<_> = batches
+                .iter()
+                .enumerate()
+                .map(|(i, batch)| {
+                    s.spawn(move || self.send_with_retries(operation, batch, first + i))
+                })
+                .collect();
+            let mut results = Vec::with_capacity(params.len());
+            for handle in handles {
+                let batch = handle.join().map_err(|_| -> Error {
+                    argmin_error!(PotentialBug, "`RemoteProblem`: request thread panicked.")
+                })??;
+                results.extend(batch);
+            }
+            Ok(results)
+        })
+    }
+
+    /// Sends a batch to worker `worker`, repeating failed requests on the following workers
+    fn send_with_retries<T: DeserializeOwned>(
+        &self,
+        operation: &str,
+        params: &[&P],
+        worker: usize,
+    ) -> Result<Vec<T>, Error> {
+        let mut attempt = 0;
+        loop {
+            let endpoint = &self.endpoints[(worker + attempt) % self.endpoints.len()];
+            match self.send(endpoint, operation, params) {
+                Ok(results) => return Ok(results),
+                Err(e) if attempt >= self.retries => return Err(e),
+                Err(_) => attempt += 1,
+            }
+        }
+    }
+
+    /// Sends a single request
+    fn send<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        operation: &str,
+        params: &[&P],
+    ) -> Result<Vec<T>, Error> {
+        let body = serde_json::to_string(&Request { operation, params })?;
+        let response = self
+            .agent
+            .post(endpoint)
+            .set("Content-Type", "application/json")
+            .send_string(&body)
+            .map_err(Box::new)?
+            .into_string()?;
+        match serde_json::from_str(&response)? {
+            Response::Results { results } if results.len() == params.len() => Ok(results),
+            Response::Results { results } => Err(argmin_error!(
+                ConditionViolated,
+                format!(
+                    "`RemoteProblem`: `{endpoint}` returned {} results for {} parameter vectors.",
+                    results.len(),
+                    params.len()
+                )
+            )),
+            Response::Error { error } => Err(argmin_error!(
+                ConditionViolated,
+                format!("`RemoteProblem`: `{endpoint}` failed: {error}")
+            )),
+        }
+    }
+}
+
+impl<P, O, G> CostFunction for RemoteProblem<P, O, G>
+where
+    P: Serialize + Sync,
+    O: DeserializeOwned + Send,
+{
+    type Param = P;
+    type Output = O;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        Ok(self.evaluate("cost", &[param])?.remove(0))
+    }
+
+    fn bulk_cost<Q>(&self, params: &[Q]) -> Result<Vec<Self::Output>, Error>
+    where
+        Q: Borrow<Self::Param> + SyncAlias,
+        Self::Output: SendAlias,
+        Self: SyncAlias,
+    {
+        let params: Vec<&P> = params.iter().map(Borrow::borrow).collect();
+        self.evaluate("cost", &params)
+    }
+}
+
+impl<P, O, G> Gradient for RemoteProblem<P, O, G>
+where
+    P: Serialize + Sync,
+    G: DeserializeOwned + Send,
+{
+    type Param = P;
+    type Gradient = G;
+
+    fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+        Ok(self.evaluate("gradient", &[param])?.remove(0))
+    }
+
+    fn bulk_gradient<Q>(&self, params: &[Q]) -> Result<Vec<Self::Gradient>, Error>
+    where
+        Q: Borrow<Self::Param> + SyncAlias,
+        Self::Gradient: SendAlias,
+        Self: SyncAlias,
+    {
+        let params: Vec<&P> = params.iter().map(Borrow::borrow).collect();
+        self.evaluate("gradient", &params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Bounds, Executor};
+    use crate::solver::linesearch::MoreThuenteLineSearch;
+    use crate::solver::particleswarm::ParticleSwarm;
+    use crate::solver::quasinewton::LBFGS;
+    use serde_json::{json, Value};
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::Arc;
+
+    /// Worker which evaluates the sphere function; `handler` may override the response body.
+    /// Returns the URL and the number of parameter vectors it received.
+    fn worker(handler: fn(&Value) -> Option<String>) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/evaluate", listener.local_addr().unwrap());
+        let received = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&received);
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line == "\r\n" {
+                        break;
+                    }
+                    if let Some(l) = line.to_lowercase().strip_prefix("content-length:") {
+                        length = l.trim().parse().unwrap();
+                    }
+                }
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+                let request: Value = serde_json::from_slice(&body).unwrap();
+                let params = request["params"].as_array().unwrap();
+                counter.fetch_add(params.len(), Ordering::SeqCst);
+                let response = handler(&request).unwrap_or_else(|| {
+                    let results: Vec<Value> = params
+                        .iter()
+                        .map(|p| {
+                            let p: Vec<f64> = serde_json::from_value(p.clone()).unwrap();
+                            if request["operation"] == "cost" {
+                                json!(p.iter().map(|x| x * x).sum::<f64>())
+                            } else {
+                                json!(p.iter().map(|x| 2.0 * x).collect::<Vec<f64>>())
+                            }
+                        })
+                        .collect();
+                    json!({ "results": results }).to_string()
+                });
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\
+                     Connection: close\r\n\r\n{}",
+                    response.len(),
+                    response
+                );
+            }
+        });
+        (url, received)
+    }
+
+    /// URL on which no worker is listening
+    fn unreachable() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        format!("http://{}/evaluate", listener.local_addr().unwrap())
+    }
+
+    #[test]
+    fn test_new() {
+        let problem: RemoteProblem = RemoteProblem::new(vec!["http://a".to_string()]).unwrap();
+        assert_eq!(problem.endpoints(), &["http://a".to_string()]);
+        assert_eq!(problem.timeout(), Duration::from_secs(30));
+        assert_eq!(problem.retries, 2);
+        assert_eq!(problem.batch_size, 64);
+        assert_error!(
+            RemoteProblem::<Vec<f64>>::new(vec![]),
+            ArgminError,
+            "Invalid parameter: \"`RemoteProblem`: at least one endpoint is required.\""
+        );
+        assert_error!(
+            problem.with_batch_size(0),
+            ArgminError,
+            "Invalid parameter: \"`RemoteProblem`: batch size must be > 0.\""
+        );
+    }
+
+    #[test]
+    fn test_cost_and_gradient() {
+        let (url, received) = worker(|_| None);
+        let problem: RemoteProblem = RemoteProblem::new(vec![url]).unwrap();
+        let cost = problem.cost(&vec![1.0, 2.0]).unwrap();
+        assert_eq!(cost.to_ne_bytes(), 5.0f64.to_ne_bytes());
+        assert_eq!(problem.gradient(&vec![1.0, 2.0]).unwrap(), vec![2.0, 4.0]);
+        assert_eq!(received.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_bulk_batches() {
+        let (url1, received1) = worker(|_| None);
+        let (url2, received2) = worker(|_| None);
+        let problem: RemoteProblem = RemoteProblem::new(vec![url1, url2])
+            .unwrap()
+            .with_batch_size(3)
+            .unwrap();
+        let params: Vec<Vec<f64>> = (0..10).map(|i| vec![f64::from(i)]).collect();
+        let costs = problem.bulk_cost(&params).unwrap();
+        let expected: Vec<f64> = (0..10).map(|i| f64::from(i * i)).collect();
+        assert_eq!(costs, expected);
+        let gradients = problem.bulk_gradient(&params).unwrap();
+        assert_eq!(gradients[4], vec![8.0]);
+        // Batches of 3, 3, 3 and 1 vectors per call, distributed round-robin
+        assert_eq!(received1.load(Ordering::SeqCst), 2 * (3 + 3));
+        assert_eq!(received2.load(Ordering::SeqCst), 2 * (3 + 1));
+    }
+
+    #[test]
+    fn test_retry_on_next_worker() {
+        let (url, received) = worker(|_| None);
+        let problem: RemoteProblem = RemoteProblem::new(vec![unreachable(), url])
+            .unwrap()
+            .with_retries(1);
+        for _ in 0..4 {
+            let cost = problem.cost(&vec![3.0]).unwrap();
+            assert_eq!(cost.to_ne_bytes(), 9.0f64.to_ne_bytes());
+        }
+        assert_eq!(received.load(Ordering::SeqCst), 4);
+    }
+
+    #[test]
+    fn test_retries_exhausted() {
+        let problem: RemoteProblem = RemoteProblem::new(vec![unreachable()])
+            .unwrap()
+            .with_retries(2);
+        assert!(problem.cost(&vec![3.0]).is_err());
+    }
+
+    #[test]
+    fn test_worker_errors() {
+        let (url, _) = worker(|_| Some(json!({ "error": "simulation diverged" }).to_string()));
+        let problem: RemoteProblem = RemoteProblem::new(vec![url]).unwrap().with_retries(0);
+        assert_error!(
+            problem.cost(&vec![3.0]),
+            ArgminError,
+            format!(
+                "Condition violated: \"`RemoteProblem`: `{}` failed: simulation diverged\"",
+                problem.endpoints()[0]
+            )
+        );
+        let (url, _) = worker(|_| Some(json!({ "results": [] }).to_string()));
+        let problem: RemoteProblem = RemoteProblem::new(vec![url]).unwrap().with_retries(0);
+        assert_error!(
+            problem.cost(&vec![3.0]),
+            ArgminError,
+            format!(
+                "Condition violated: \"`RemoteProblem`: `{}` returned 0 results for 1 parameter \
+                 vectors.\"",
+                problem.endpoints()[0]
+            )
+        );
+    }
+
+    #[test]
+    fn test_timeout() {
+        let (url, _) = worker(|_| {
+            std::thread::sleep(Duration::from_millis(500));
+            None
+        });
+        let problem: RemoteProblem = RemoteProblem::new(vec![url])
+            .unwrap()
+            .with_timeout(Duration::from_millis(100))
+            .with_retries(0);
+        assert!(problem.cost(&vec![3.0]).is_err());
+    }
+
+    #[test]
+    fn test_solvers() {
+        let (url1, _) = worker(|_| None);
+        let (url2, _) = worker(|_| None);
+        let problem: RemoteProblem = RemoteProblem::new(vec![url1.clone(), url2.clone()]).unwrap();
+        let res = Executor::new(problem, LBFGS::new(MoreThuenteLineSearch::new(), 5))
+            .configure(|state| state.param(vec![1.0, -2.0]).max_iters(20))
+            .run()
+            .unwrap();
+        assert!(res.state().get_best_cost() < 1e-10);
+
+        let problem: RemoteProblem = RemoteProblem::new(vec![url1, url2]).unwrap();
+        let solver = ParticleSwarm::new(Bounds::new(vec![-2.0, -2.0], vec![2.0, 2.0]).unwrap(), 20);
+        let res = Executor::new(problem, solver)
+            .configure(|state| state.max_iters(30))
+            .run()
+            .unwrap();
+        assert!(res.state().get_best_cost() < 1e-2);
+        assert_eq!(res.problem.counts["cost_count"], 20 * 31);
+    }
+}
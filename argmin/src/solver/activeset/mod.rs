@@ -0,0 +1,794 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Active-set method
+//!
+//! Dense primal active-set method for small convex quadratic programs. See [`ActiveSet`] for
+//! details.
+//!
+//! ## Reference
+//!
+//! Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
+//! Springer. ISBN 0-387-30303-0.
+
+use crate::core::{
+    ArgminFloat, Error, Executor, IterState, LinearProgram, Problem, QuadraticProgram, Solver,
+    State, TerminationReason, TerminationStatus, KV,
+};
+use crate::solver::simplex::Simplex;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Active-set method
+///
+/// Solves convex quadratic programs
+///
+/// `min_x 1/2 x^T Q x + c^T x` subject to `A_eq x = b_eq` and `A_ineq x <= b_ineq`
+///
+/// as defined by the [`QuadraticProgram`] trait.
+///
+/// This is the primal active-set method. It keeps a working set of constraints which are treated
+/// as equalities, consisting of all equality constraints and a linearly independent subset of
+/// the active inequality constraints. In each iteration, the quadratic program restricted to the
+/// working set is solved via its KKT system. If the resulting step is zero, the Lagrange
+/// multipliers of the working set are checked: the solver terminates if all of them are
+/// non-negative and otherwise removes the inequality constraint with the most negative
+/// multiplier. If the step is nonzero, it is taken as far as feasibility permits and a blocking
+/// constraint is added to the working set.
+///
+/// The method starts from a feasible point. If the initial parameter vector provided via the
+/// `configure` method of the [`Executor`] is feasible, it is used. Otherwise, or if none is
+/// provided, a feasible vertex is computed with the [`Simplex`] method. If the problem is
+/// infeasible, the solver terminates with [`TerminationReason::SolverExit`].
+///
+/// `Q` needs to be positive definite on the null space of the working set, which holds for
+/// instance if `Q` is positive definite. The rows of `A_eq` must be linearly independent.
+/// The KKT system is solved by dense Gaussian elimination, which makes the solver only suitable
+/// for small problems. The size of the working set is reported via the observers (`working_set`).
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`QuadraticProgram`].
+///
+/// ## Example
+///
+/// ```
+/// # use argmin::core::{Error, Executor, QuadraticProgram, State};
+/// # use argmin::solver::activeset::ActiveSet;
+/// /// Minimum variance portfolio of three uncorrelated assets
+/// struct Portfolio {}
+///
+/// impl QuadraticProgram for Portfolio {
+///     type Param = Vec<f64>;
+///     type Float = f64;
+///
+///     fn Q(&self) -> Result<Vec<Vec<f64>>, Error> {
+///         Ok(vec![
+///             vec![2.0, 0.0, 0.0],
+///             vec![0.0, 4.0, 0.0],
+///             vec![0.0, 0.0, 8.0],
+///         ])
+///     }
+///
+///     fn c(&self) -> Result<Vec<f64>, Error> {
+///         Ok(vec![0.0; 3])
+///     }
+///
+///     fn A_eq(&self) -> Result<Vec<Vec<f64>>, Error> {
+///         Ok(vec![vec![1.0; 3]])
+///     }
+///
+///     fn b_eq(&self) -> Result<Vec<f64>, Error> {
+///         Ok(vec![1.0])
+///     }
+///
+///     fn A_ineq(&self) -> Result<Vec<Vec<f64>>, Error> {
+///         Ok(vec![
+///             vec![-1.0, 0.0, 0.0],
+///             vec![0.0, -1.0, 0.0],
+///             vec![0.0, 0.0, -1.0],
+///         ])
+///     }
+///
+///     fn b_ineq(&self) -> Result<Vec<f64>, Error> {
+///         Ok(vec![0.0; 3])
+///     }
+/// }
+///
+/// # fn main() -> Result<(), Error> {
+/// let res = Executor::new(Portfolio {}, ActiveSet::new())
+///     .configure(|state| state.max_iters(100))
+///     .run()?;
+/// let weights = res.state.get_best_param().unwrap();
+/// # assert!((weights[0] - 4.0 / 7.0).abs() < 1e-10);
+/// # assert!((weights[1] - 2.0 / 7.0).abs() < 1e-10);
+/// # assert!((weights[2] - 1.0 / 7.0).abs() < 1e-10);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// ## Reference
+///
+/// Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
+/// Springer. ISBN 0-387-30303-0.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct ActiveSet<F> {
+    /// Tolerance on feasibility, step size and multipliers
+    tol: F,
+    /// Matrix of the quadratic term
+    q: Vec<Vec<F>>,
+    /// Linear term
+    c: Vec<F>,
+    /// Equality constraints
+    a_eq: Vec<Vec<F>>,
+    b_eq: Vec<F>,
+    /// Inequality constraints
+    a_ineq: Vec<Vec<F>>,
+    b_ineq: Vec<F>,
+    /// Indices of inequality constraints in the working set
+    working: Vec<usize>,
+    /// Whether the optimality conditions are satisfied
+    converged: bool,
+}
+
+impl<F> ActiveSet<F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`ActiveSet`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::activeset::ActiveSet;
+    /// let active_set: ActiveSet<f64> = ActiveSet::new();
+    /// ```
+    pub fn new() -> Self {
+        ActiveSet {
+            tol: F::epsilon().sqrt(),
+            q: vec![],
+            c: vec![],
+            a_eq: vec![],
+            b_eq: vec![],
+            a_ineq: vec![],
+            b_ineq: vec![],
+            working: vec![],
+            converged: false,
+        }
+    }
+
+    /// Set the tolerance on feasibility, step size and Lagrange multipliers
+    ///
+    /// Must be larger than 0 and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::activeset::ActiveSet;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let active_set = ActiveSet::new().with_tolerance(1e-10f64)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ActiveSet`: tolerance must be > 0."
+            ));
+        }
+        self.tol = tol;
+        Ok(self)
+    }
+
+    /// Objective function value at `x`
+    fn objective(&self, x: &[F]) -> F {
+        self.q
+            .iter()
+            .zip(self.c.iter())
+            .zip(x.iter())
+            .fold(float!(0.0), |acc, ((row, c), xi)| {
+                acc + *xi * (float!(0.5) * dot(row, x) + *c)
+            })
+    }
+
+    /// Whether `x` satisfies all constraints
+    fn is_feasible(&self, x: &[F]) -> bool {
+        self.a_eq
+            .iter()
+            .zip(self.b_eq.iter())
+            .all(|(a, b)| (dot(a, x) - *b).abs() <= self.tol)
+            && self
+                .a_ineq
+                .iter()
+                .zip(self.b_ineq.iter())
+                .all(|(a, b)| dot(a, x) - *b <= self.tol)
+    }
+
+    /// Computes a feasible vertex with the simplex method, if the problem is feasible. The free
+    /// variables are split as `x = x_plus - x_minus` and slack variables are added to the
+    /// inequality constraints.
+    fn feasible_point(&self) -> Result<Option<Vec<F>>, Error> {
+        let n = self.c.len();
+        let m = self.a_ineq.len();
+        let row = |a: &[F], slack: Option<usize>| -> Vec<F> {
+            a.iter()
+                .cloned()
+                .chain(a.iter().map(|v| -*v))
+                .chain((0..m).map(|k| {
+                    if Some(k) == slack {
+                        float!(1.0)
+                    } else {
+                        float!(0.0)
+                    }
+                }))
+                .collect()
+        };
+        let lp = FeasibilityProblem {
+            c: vec![float!(0.0); 2 * n + m],
+            b: self
+                .b_eq
+                .iter()
+                .chain(self.b_ineq.iter())
+                .cloned()
+                .collect(),
+            a: self
+                .a_eq
+                .iter()
+                .map(|a| row(a, None))
+                .chain(self.a_ineq.iter().enumerate().map(|(k, a)| row(a, Some(k))))
+                .collect(),
+        };
+        let res = Executor::new(lp, Simplex::new()).ctrlc(false).run()?;
+        if res.state.get_termination_reason() != Some(&TerminationReason::SolverConverged) {
+            return Ok(None);
+        }
+        let z = res
+            .state
+            .get_best_param()
+            .ok_or_else(argmin_error_closure!(
+                PotentialBug,
+                "`ActiveSet`: No `param` returned by simplex method"
+            ))?;
+        Ok(Some((0..n).map(|i| z[i] - z[n + i]).collect()))
+    }
+
+    /// Adds the active inequality constraints at `x` to the working set, as long as they are
+    /// linearly independent of the equality constraints and the constraints added before.
+    fn initial_working_set(&mut self, x: &[F]) {
+        // Orthonormal basis of the span of the working set (Gram-Schmidt)
+        let mut basis: Vec<Vec<F>> = vec![];
+        let add = |a: &[F], basis: &mut Vec<Vec<F>>| -> bool {
+            let mut v = a.to_vec();
+            for u in basis.iter() {
+                let proj = dot(u, &v);
+                for (vi, ui) in v.iter_mut().zip(u.iter()) {
+                    *vi = *vi - proj * *ui;
+                }
+            }
+            let norm = dot(&v, &v).sqrt();
+            if norm <= self.tol * dot(a, a).sqrt().max(float!(1.0)) {
+                return false;
+            }
+            basis.push(v.iter().map(|vi| *vi / norm).collect());
+            true
+        };
+        for a in self.a_eq.iter() {
+            add(a, &mut basis);
+        }
+        self.working = self
+            .a_ineq
+            .iter()
+            .zip(self.b_ineq.iter())
+            .enumerate()
+            .filter(|(_, (a, b))| (dot(a, x) - **b).abs() <= self.tol)
+            .filter(|(_, (a, _))| add(a, &mut basis))
+            .map(|(i, _)| i)
+            .collect();
+    }
+
+    /// Solves the KKT system of the quadratic program restricted to the working set at `x` and
+    /// returns the step and the Lagrange multipliers of the working set (equality constraints
+    /// first).
+    fn solve_kkt(&self, x: &[F]) -> Result<(Vec<F>, Vec<F>), Error> {
+        let n = x.len();
+        let rows: Vec<&Vec<F>> = self
+            .a_eq
+            .iter()
+            .chain(self.working.iter().map(|&i| &self.a_ineq[i]))
+            .collect();
+        let size = n + rows.len();
+        let mut kkt = vec![vec![float!(0.0); size]; size];
+        for (row, q) in kkt.iter_mut().zip(self.q.iter()) {
+            row[..n].copy_from_slice(q);
+        }
+        for (k, a) in rows.iter().enumerate() {
+            for (i, &v) in a.iter().enumerate() {
+                kkt[n + k][i] = v;
+                kkt[i][n + k] = v;
+            }
+        }
+        // Gradient of the objective
+        let mut rhs: Vec<F> = self
+            .q
+            .iter()
+            .zip(self.c.iter())
+            .map(|(row, c)| -(dot(row, x) + *c))
+            .collect();
+        rhs.resize(size, float!(0.0));
+
+        let sol = solve_linear_system(kkt, rhs).ok_or_else(argmin_error_closure!(
+            ConditionViolated,
+            concat!(
+                "`ActiveSet`: KKT system is singular. `Q` must be positive definite on the ",
+                "null space of the working set."
+            )
+        ))?;
+        // Q p + A_W^T mu = -g, therefore the multipliers of `a^T x <= b` are `mu`
+        let (p, mu) = sol.split_at(n);
+        Ok((p.to_vec(), mu.to_vec()))
+    }
+}
+
+impl<F> Default for ActiveSet<F>
+where
+    F: ArgminFloat,
+{
+    fn default() -> Self {
+        ActiveSet::new()
+    }
+}
+
+impl<O, F> Solver<O, IterState<Vec<F>, (), (), (), F>> for ActiveSet<F>
+where
+    O: QuadraticProgram<Param = Vec<F>, Float = F>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Active-set method";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<Vec<F>, (), (), (), F>,
+    ) -> Result<(IterState<Vec<F>, (), (), (), F>, Option<KV>), Error> {
+        let qp = problem.problem.as_ref().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`ActiveSet`: No problem set."
+        ))?;
+        self.q = qp.Q()?;
+        self.c = qp.c()?;
+        self.a_eq = qp.A_eq()?;
+        self.b_eq = qp.b_eq()?;
+        self.a_ineq = qp.A_ineq()?;
+        self.b_ineq = qp.b_ineq()?;
+        self.converged = false;
+
+        let n = self.c.len();
+        if self.q.len() != n || self.q.iter().any(|row| row.len() != n) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ActiveSet`: `Q` must be a square matrix matching the length of `c`."
+            ));
+        }
+        if self.a_eq.len() != self.b_eq.len()
+            || self.a_ineq.len() != self.b_ineq.len()
+            || self
+                .a_eq
+                .iter()
+                .chain(self.a_ineq.iter())
+                .any(|row| row.len() != n)
+        {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ActiveSet`: dimensions of constraint matrices and right-hand sides do not match."
+            ));
+        }
+
+        if state.get_param().is_some_and(|param| param.len() != n) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ActiveSet`: initial parameter vector must match the length of `c`."
+            ));
+        }
+        let param = match state.take_param() {
+            Some(param) if self.is_feasible(&param) => param,
+            _ => match self.feasible_point()? {
+                Some(param) => param,
+                None => {
+                    return Ok((
+                        state.terminate_with(TerminationReason::SolverExit(
+                            "Quadratic program is infeasible".to_string(),
+                        )),
+                        None,
+                    ))
+                }
+            },
+        };
+        self.initial_working_set(&param);
+        let cost = self.objective(&param);
+
+        Ok((state.param(param).cost(cost), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        _problem: &mut Problem<O>,
+        mut state: IterState<Vec<F>, (), (), (), F>,
+    ) -> Result<(IterState<Vec<F>, (), (), (), F>, Option<KV>), Error> {
+        let mut param = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`ActiveSet`: Parameter vector in state not set."
+        ))?;
+        let (p, mu) = self.solve_kkt(&param)?;
+
+        if p.iter().all(|pi| pi.abs() <= self.tol) {
+            // Multipliers of the inequality constraints in the working set must be non-negative
+            let (k, min_mu) = mu[self.a_eq.len()..].iter().enumerate().fold(
+                (0, F::infinity()),
+                |(k, m), (i, mu_i)| if *mu_i < m { (i, *mu_i) } else { (k, m) },
+            );
+            if min_mu >= -self.tol {
+                self.converged = true;
+            } else {
+                self.working.remove(k);
+            }
+        } else {
+            // Largest step which keeps all constraints satisfied
+            let (alpha, blocking) = self
+                .a_ineq
+                .iter()
+                .zip(self.b_ineq.iter())
+                .enumerate()
+                .filter(|(i, _)| !self.working.contains(i))
+                .map(|(i, (a, b))| (i, dot(a, &p), *b - dot(a, &param)))
+                .filter(|(_, ap, _)| *ap > self.tol)
+                .fold((float!(1.0), None), |(alpha, blocking), (i, ap, slack)| {
+                    let t = (slack / ap).max(float!(0.0));
+                    if t < alpha {
+                        (t, Some(i))
+                    } else {
+                        (alpha, blocking)
+                    }
+                });
+            for (x, pi) in param.iter_mut().zip(p.iter()) {
+                *x = *x + alpha * *pi;
+            }
+            if let Some(i) = blocking {
+                self.working.push(i);
+            }
+        }
+
+        let cost = self.objective(&param);
+        Ok((
+            state.param(param).cost(cost),
+            Some(kv!("working_set" => self.working.len() as u64;)),
+        ))
+    }
+
+    fn terminate(&mut self, _state: &IterState<Vec<F>, (), (), (), F>) -> TerminationStatus {
+        if self.converged {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+/// Linear program in standard form used to find a feasible point
+struct FeasibilityProblem<F> {
+    c: Vec<F>,
+    b: Vec<F>,
+    a: Vec<Vec<F>>,
+}
+
+impl<F> LinearProgram for FeasibilityProblem<F>
+where
+    F: ArgminFloat,
+{
+    type Param = Vec<F>;
+    type Float = F;
+
+    fn c(&self) -> Result<Vec<F>, Error> {
+        Ok(self.c.clone())
+    }
+
+    fn b(&self) -> Result<Vec<F>, Error> {
+        Ok(self.b.clone())
+    }
+
+    fn A(&self) -> Result<Vec<Vec<F>>, Error> {
+        Ok(self.a.clone())
+    }
+}
+
+fn dot<F: ArgminFloat>(a: &[F], b: &[F]) -> F {
+    a.iter()
+        .zip(b.iter())
+        .fold(float!(0.0), |acc, (x, y)| acc + *x * *y)
+}
+
+/// Solves `A x = b` by Gaussian elimination with partial pivoting. Returns `None` if `A` is
+/// singular.
+fn solve_linear_system<F: ArgminFloat>(mut a: Vec<Vec<F>>, mut b: Vec<F>) -> Option<Vec<F>> {
+    let n = b.len();
+    let scale = a
+        .iter()
+        .flat_map(|row| row.iter())
+        .fold(float!(0.0), |acc: F, v| acc.max(v.abs()));
+    for k in 0..n {
+        let pivot = (k..n).fold(k, |p, i| if a[i][k].abs() > a[p][k].abs() { i } else { p });
+        if a[pivot][k].abs() <= F::epsilon() * float!(n as f64) * scale {
+            return None;
+        }
+        a.swap(k, pivot);
+        b.swap(k, pivot);
+        for i in k + 1..n {
+            let factor = a[i][k] / a[k][k];
+            if factor != float!(0.0) {
+                let (upper, lower) = a.split_at_mut(i);
+                for (v, w) in lower[0][k..].iter_mut().zip(upper[k][k..].iter()) {
+                    *v = *v - factor * *w;
+                }
+                b[i] = b[i] - factor * b[k];
+            }
+        }
+    }
+    let mut x = vec![float!(0.0); n];
+    for k in (0..n).rev() {
+        let sum = a[k][k + 1..]
+            .iter()
+            .zip(x[k + 1..].iter())
+            .fold(b[k], |acc, (v, xi)| acc - *v * *xi);
+        x[k] = sum / a[k][k];
+    }
+    Some(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(active_set, ActiveSet<f64>);
+
+    #[derive(Default)]
+    struct Qp {
+        q: Vec<Vec<f64>>,
+        c: Vec<f64>,
+        a_eq: Vec<Vec<f64>>,
+        b_eq: Vec<f64>,
+        a_ineq: Vec<Vec<f64>>,
+        b_ineq: Vec<f64>,
+    }
+
+    #[allow(non_snake_case)]
+    impl QuadraticProgram for Qp {
+        type Param = Vec<f64>;
+        type Float = f64;
+
+        fn Q(&self) -> Result<Vec<Vec<f64>>, Error> {
+            Ok(self.q.clone())
+        }
+
+        fn c(&self) -> Result<Vec<f64>, Error> {
+            Ok(self.c.clone())
+        }
+
+        fn A_eq(&self) -> Result<Vec<Vec<f64>>, Error> {
+            Ok(self.a_eq.clone())
+        }
+
+        fn b_eq(&self) -> Result<Vec<f64>, Error> {
+            Ok(self.b_eq.clone())
+        }
+
+        fn A_ineq(&self) -> Result<Vec<Vec<f64>>, Error> {
+            Ok(self.a_ineq.clone())
+        }
+
+        fn b_ineq(&self) -> Result<Vec<f64>, Error> {
+            Ok(self.b_ineq.clone())
+        }
+    }
+
+    /// Example 16.4 of Nocedal and Wright: `min (x - 1)^2 + (y - 2.5)^2` subject to five linear
+    /// inequality constraints, with solution `(1.4, 1.7)`
+    fn example() -> Qp {
+        Qp {
+            q: vec![vec![2.0, 0.0], vec![0.0, 2.0]],
+            c: vec![-2.0, -5.0],
+            a_ineq: vec![
+                vec![-1.0, 2.0],
+                vec![1.0, 2.0],
+                vec![1.0, -2.0],
+                vec![-1.0, 0.0],
+                vec![0.0, -1.0],
+            ],
+            b_ineq: vec![2.0, 6.0, 2.0, 0.0, 0.0],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let ActiveSet {
+            tol,
+            q,
+            c,
+            a_eq,
+            b_eq,
+            a_ineq,
+            b_ineq,
+            working,
+            converged,
+        } = ActiveSet::<f64>::new();
+        assert_eq!(tol.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert!(q.is_empty());
+        assert!(c.is_empty());
+        assert!(a_eq.is_empty());
+        assert!(b_eq.is_empty());
+        assert!(a_ineq.is_empty());
+        assert!(b_ineq.is_empty());
+        assert!(working.is_empty());
+        assert!(!converged);
+    }
+
+    #[test]
+    fn test_with_tolerance() {
+        let active_set = ActiveSet::new().with_tolerance(1e-10f64).unwrap();
+        assert_eq!(active_set.tol.to_ne_bytes(), 1e-10f64.to_ne_bytes());
+
+        for tol in [0.0, -1.0] {
+            let res = ActiveSet::new().with_tolerance(tol);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`ActiveSet`: tolerance must be > 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_dimensions() {
+        let qp = Qp {
+            q: vec![vec![1.0, 0.0]],
+            c: vec![0.0, 0.0],
+            ..Default::default()
+        };
+        let res = Executor::new(qp, ActiveSet::new()).run();
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`ActiveSet`: `Q` must be a square matrix matching the ",
+                "length of `c`.\""
+            )
+        );
+
+        let qp = Qp {
+            a_ineq: vec![vec![1.0, 0.0]],
+            ..example()
+        };
+        let res = Executor::new(qp, ActiveSet::new()).run();
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`ActiveSet`: dimensions of constraint matrices and ",
+                "right-hand sides do not match.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_solve_linear_system() {
+        let a = vec![
+            vec![0.0, 2.0, 1.0],
+            vec![1.0, 1.0, 0.0],
+            vec![2.0, 0.0, 3.0],
+        ];
+        let x = solve_linear_system(a, vec![7.0, 3.0, 11.0]).unwrap();
+        assert_relative_eq!(x[0], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(x[1], 2.0, epsilon = 1e-12);
+        assert_relative_eq!(x[2], 3.0, epsilon = 1e-12);
+
+        let a = vec![vec![1.0, 2.0], vec![2.0, 4.0]];
+        assert!(solve_linear_system(a, vec![1.0, 2.0]).is_none());
+    }
+
+    #[test]
+    fn test_inequality_constraints() {
+        // Starting point of Nocedal and Wright and no starting point
+        for init in [Some(vec![2.0, 0.0]), None] {
+            let res = Executor::new(example(), ActiveSet::new())
+                .configure(|state| {
+                    let state = state.max_iters(100);
+                    match init {
+                        Some(init) => state.param(init),
+                        None => state,
+                    }
+                })
+                .run()
+                .unwrap();
+            assert_eq!(
+                res.state.get_termination_reason(),
+                Some(&TerminationReason::SolverConverged)
+            );
+            let x = res.state.get_best_param().unwrap();
+            assert_relative_eq!(x[0], 1.4, epsilon = 1e-12);
+            assert_relative_eq!(x[1], 1.7, epsilon = 1e-12);
+            assert_relative_eq!(res.state.get_best_cost(), 0.8 - 7.25, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_equality_constraints() {
+        let qp = Qp {
+            q: vec![
+                vec![2.0, 0.0, 0.0],
+                vec![0.0, 2.0, 0.0],
+                vec![0.0, 0.0, 2.0],
+            ],
+            c: vec![0.0; 3],
+            a_eq: vec![vec![1.0, 1.0, 1.0]],
+            b_eq: vec![3.0],
+            ..Default::default()
+        };
+        let res = Executor::new(qp, ActiveSet::new())
+            .configure(|state| state.param(vec![10.0, 0.0, 0.0]).max_iters(100))
+            .run()
+            .unwrap();
+        for x in res.state.get_best_param().unwrap() {
+            assert_relative_eq!(*x, 1.0, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_infeasible() {
+        let qp = Qp {
+            q: vec![vec![2.0]],
+            c: vec![0.0],
+            a_ineq: vec![vec![1.0], vec![-1.0]],
+            b_ineq: vec![-1.0, -1.0],
+            ..Default::default()
+        };
+        let res = Executor::new(qp, ActiveSet::new())
+            .configure(|state| state.param(vec![0.0]).max_iters(100))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverExit(
+                "Quadratic program is infeasible".to_string()
+            ))
+        );
+        assert_eq!(res.state.get_iter(), 0);
+    }
+
+    #[test]
+    fn test_singular() {
+        let qp = Qp {
+            q: vec![vec![0.0]],
+            c: vec![1.0],
+            ..Default::default()
+        };
+        let res = Executor::new(qp, ActiveSet::new())
+            .configure(|state| state.param(vec![0.0]).max_iters(100))
+            .run();
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Condition violated: \"`ActiveSet`: KKT system is singular. `Q` must be positive ",
+                "definite on the null space of the working set.\""
+            )
+        );
+    }
+}
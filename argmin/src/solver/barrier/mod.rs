@@ -0,0 +1,649 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Logarithmic barrier method
+//!
+//! Meta-solver which turns a problem with inequality constraints `g_i(x) <= 0` into a sequence of
+//! unconstrained problems which can be solved by any of the unconstrained solvers. See
+//! [`BarrierMethod`] for details.
+//!
+//! ## Reference
+//!
+//! Stephen Boyd and Lieven Vandenberghe (2004). Convex Optimization.
+//! Cambridge University Press. ISBN 0-521-83378-7.
+
+use crate::core::{
+    ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, Executor, Gradient, Hessian,
+    InequalityConstraint, IterState, OptimizationResult, Problem, SerializeAlias, Solver, State,
+    TerminationReason, TerminationStatus, KV,
+};
+use argmin_math::{ArgminAdd, ArgminDot, ArgminMul, ArgminScaledAdd};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Logarithmic barrier problem
+///
+/// Wraps a problem with inequality constraints `g_i(x) <= 0` and turns it into the unconstrained
+/// problem
+///
+/// `min_x f(x) - mu * sum_i ln(-g_i(x))`
+///
+/// for a given barrier parameter `mu`. This is the problem the inner solver of [`BarrierMethod`]
+/// operates on.
+///
+/// For parameter vectors which are not strictly feasible, the cost function returns infinity.
+/// The gradient and Hessian are only meaningful for strictly feasible parameter vectors.
+///
+/// [`CostFunction`] is implemented if the wrapped problem implements [`CostFunction`] and
+/// [`InequalityConstraint`]. [`Gradient`] is implemented if the wrapped problem additionally
+/// implements [`Gradient`] and
+/// [`inequality_jacobian`](`InequalityConstraint::inequality_jacobian`). [`Hessian`] is
+/// implemented if the wrapped problem additionally implements [`Hessian`]; the curvature of the
+/// constraints is neglected.
+#[derive(Clone, Debug)]
+pub struct BarrierProblem<O, F> {
+    /// Wrapped problem
+    problem: O,
+    /// Barrier parameter
+    mu: F,
+}
+
+impl<O, F> BarrierProblem<O, F> {
+    /// Construct a new instance of [`BarrierProblem`] from a problem and a barrier parameter
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::barrier::BarrierProblem;
+    /// # struct UserDefinedProblem {};
+    /// let problem = BarrierProblem::new(UserDefinedProblem {}, 0.1f64);
+    /// ```
+    pub fn new(problem: O, mu: F) -> Self {
+        BarrierProblem { problem, mu }
+    }
+
+    /// Returns the wrapped problem
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::barrier::BarrierProblem;
+    /// # #[derive(Debug, Eq, PartialEq)]
+    /// # struct UserDefinedProblem {};
+    /// let problem = BarrierProblem::new(UserDefinedProblem {}, 0.1f64);
+    /// assert_eq!(problem.into_inner(), UserDefinedProblem {});
+    /// ```
+    pub fn into_inner(self) -> O {
+        self.problem
+    }
+}
+
+impl<O, P, F> CostFunction for BarrierProblem<O, F>
+where
+    O: CostFunction<Param = P, Output = F> + InequalityConstraint<Param = P, Float = F>,
+    F: ArgminFloat,
+{
+    type Param = P;
+    type Output = F;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        let constraints = self.problem.inequality_constraints(param)?;
+        if constraints.iter().any(|c| *c >= float!(0.0)) {
+            return Ok(F::infinity());
+        }
+        let barrier = constraints
+            .iter()
+            .map(|c| (-*c).ln())
+            .fold(float!(0.0), |a, b| a + b);
+        Ok(self.problem.cost(param)? - self.mu * barrier)
+    }
+}
+
+impl<O, P, G, F> Gradient for BarrierProblem<O, F>
+where
+    O: Gradient<Param = P, Gradient = G> + InequalityConstraint<Param = P, Gradient = G, Float = F>,
+    G: ArgminScaledAdd<G, F, G>,
+    F: ArgminFloat,
+{
+    type Param = P;
+    type Gradient = G;
+
+    fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+        let grad = self.problem.gradient(param)?;
+        let constraints = self.problem.inequality_constraints(param)?;
+        let jacobian = self.problem.inequality_jacobian(param)?;
+        Ok(constraints
+            .iter()
+            .zip(jacobian.iter())
+            .fold(grad, |acc, (c, a)| acc.scaled_add(&(-self.mu / *c), a)))
+    }
+}
+
+impl<O, P, G, H, F> Hessian for BarrierProblem<O, F>
+where
+    O: Hessian<Param = P, Hessian = H> + InequalityConstraint<Param = P, Gradient = G, Float = F>,
+    G: ArgminMul<F, G> + ArgminDot<G, H>,
+    H: ArgminAdd<H, H>,
+    F: ArgminFloat,
+{
+    type Param = P;
+    type Hessian = H;
+
+    fn hessian(&self, param: &Self::Param) -> Result<Self::Hessian, Error> {
+        let hessian = self.problem.hessian(param)?;
+        let constraints = self.problem.inequality_constraints(param)?;
+        let jacobian = self.problem.inequality_jacobian(param)?;
+        Ok(constraints
+            .iter()
+            .zip(jacobian.iter())
+            .fold(hessian, |acc, (c, a)| {
+                acc.add(&a.mul(&(self.mu / c.powi(2))).dot(a))
+            }))
+    }
+}
+
+/// # Logarithmic barrier method
+///
+/// Solves problems of the form
+///
+/// `min_x f(x)` subject to `g_i(x) <= 0` for `i = 1, ..., m`
+///
+/// by solving a sequence of unconstrained problems
+///
+/// `min_x f(x) - mu_k * sum_i ln(-g_i(x))`
+///
+/// (see [`BarrierProblem`]) with a decreasing barrier parameter `mu_k`. Each of these
+/// unconstrained problems is solved by an inner solver, which can be any solver operating on an
+/// [`IterState`]. The inner solver is run for at most
+/// [`with_inner_max_iters`](`BarrierMethod::with_inner_max_iters`) iterations, starting from the
+/// solution of the previous unconstrained problem. If an initial Hessian or inverse Hessian is
+/// provided via the [`configure`](`crate::core::Executor::configure`) method of the
+/// [`Executor`], it is passed on to each run of the inner solver.
+///
+/// The barrier function is infinite outside of the strictly feasible region. Therefore the inner
+/// solver must be able to cope with infinite cost function values, which is for instance the case
+/// for solvers using the [`BacktrackingLineSearch`](`crate::solver::linesearch::BacktrackingLineSearch`)
+/// or for [`NelderMead`](`crate::solver::neldermead::NelderMead`). The initial parameter vector
+/// must be strictly feasible, i.e. `g_i(x_0) < 0` for all `i`.
+///
+/// After each outer iteration the barrier parameter is multiplied by the factor set via
+/// [`with_barrier_reduction`](`BarrierMethod::with_barrier_reduction`). For convex problems,
+/// `m * mu_k` is an upper bound on the suboptimality of the solution of the `k`-th unconstrained
+/// problem (duality gap). The algorithm stops once this bound falls below the tolerance set via
+/// [`with_tolerance`](`BarrierMethod::with_tolerance`).
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`] and
+/// [`InequalityConstraint`]. Depending on the inner solver, [`Gradient`], [`Hessian`] and
+/// [`inequality_jacobian`](`InequalityConstraint::inequality_jacobian`) may be required as well.
+///
+/// ## Reference
+///
+/// Stephen Boyd and Lieven Vandenberghe (2004). Convex Optimization.
+/// Cambridge University Press. ISBN 0-521-83378-7.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct BarrierMethod<S, F> {
+    /// Inner solver
+    solver: S,
+    /// Barrier parameter
+    mu: F,
+    /// Factor by which the barrier parameter is reduced
+    mu_factor: F,
+    /// Tolerance on the duality gap
+    tol: F,
+    /// Maximum number of iterations of the inner solver
+    inner_max_iters: u64,
+    /// Duality gap of the current iterate
+    gap: F,
+}
+
+impl<S, F> BarrierMethod<S, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`BarrierMethod`]
+    ///
+    /// Takes the inner solver used to solve the unconstrained problems.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::barrier::BarrierMethod;
+    /// # use argmin::solver::neldermead::NelderMead;
+    /// # let inner: NelderMead<Vec<f64>, f64> = NelderMead::new(vec![vec![0.0], vec![1.0]]);
+    /// let barrier: BarrierMethod<_, f64> = BarrierMethod::new(inner);
+    /// ```
+    pub fn new(solver: S) -> Self {
+        BarrierMethod {
+            solver,
+            mu: float!(1.0),
+            mu_factor: float!(0.1),
+            tol: F::epsilon().sqrt(),
+            inner_max_iters: 1000,
+            gap: F::infinity(),
+        }
+    }
+
+    /// Set the initial barrier parameter
+    ///
+    /// Must be larger than 0 and defaults to `1.0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::barrier::BarrierMethod;
+    /// # use argmin::solver::neldermead::NelderMead;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let inner: NelderMead<Vec<f64>, f64> = NelderMead::new(vec![vec![0.0], vec![1.0]]);
+    /// let barrier: BarrierMethod<_, f64> = BarrierMethod::new(inner).with_barrier_parameter(10.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_barrier_parameter(mut self, mu: F) -> Result<Self, Error> {
+        if mu <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`BarrierMethod`: barrier parameter must be > 0."
+            ));
+        }
+        self.mu = mu;
+        Ok(self)
+    }
+
+    /// Set the factor by which the barrier parameter is reduced after each outer iteration
+    ///
+    /// Must be in `(0, 1)` and defaults to `0.1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::barrier::BarrierMethod;
+    /// # use argmin::solver::neldermead::NelderMead;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let inner: NelderMead<Vec<f64>, f64> = NelderMead::new(vec![vec![0.0], vec![1.0]]);
+    /// let barrier: BarrierMethod<_, f64> = BarrierMethod::new(inner).with_barrier_reduction(0.5)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_barrier_reduction(mut self, factor: F) -> Result<Self, Error> {
+        if factor <= float!(0.0) || factor >= float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`BarrierMethod`: barrier reduction factor must be in (0, 1)."
+            ));
+        }
+        self.mu_factor = factor;
+        Ok(self)
+    }
+
+    /// Set the tolerance on the duality gap `m * mu`
+    ///
+    /// Must be non-negative and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::barrier::BarrierMethod;
+    /// # use argmin::solver::neldermead::NelderMead;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let inner: NelderMead<Vec<f64>, f64> = NelderMead::new(vec![vec![0.0], vec![1.0]]);
+    /// let barrier: BarrierMethod<_, f64> = BarrierMethod::new(inner).with_tolerance(1e-6)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`BarrierMethod`: tolerance must be >= 0."
+            ));
+        }
+        self.tol = tol;
+        Ok(self)
+    }
+
+    /// Set the maximum number of iterations of the inner solver per outer iteration
+    ///
+    /// Must be larger than 0 and defaults to `1000`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::barrier::BarrierMethod;
+    /// # use argmin::solver::neldermead::NelderMead;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let inner: NelderMead<Vec<f64>, f64> = NelderMead::new(vec![vec![0.0], vec![1.0]]);
+    /// let barrier: BarrierMethod<_, f64> = BarrierMethod::new(inner).with_inner_max_iters(100)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_inner_max_iters(mut self, iters: u64) -> Result<Self, Error> {
+        if iters == 0 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`BarrierMethod`: maximum number of inner iterations must be > 0."
+            ));
+        }
+        self.inner_max_iters = iters;
+        Ok(self)
+    }
+}
+
+impl<O, S, P, G, J, H, F> Solver<O, IterState<P, G, J, H, F>> for BarrierMethod<S, F>
+where
+    O: CostFunction<Param = P, Output = F> + InequalityConstraint<Param = P, Float = F>,
+    S: Clone + Solver<BarrierProblem<O, F>, IterState<P, G, J, H, F>>,
+    P: Clone + SerializeAlias + DeserializeOwnedAlias,
+    G: SerializeAlias + DeserializeOwnedAlias,
+    J: SerializeAlias + DeserializeOwnedAlias,
+    H: Clone + SerializeAlias + DeserializeOwnedAlias,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Logarithmic barrier method";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, G, J, H, F>,
+    ) -> Result<(IterState<P, G, J, H, F>, Option<KV>), Error> {
+        let param = state.get_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`BarrierMethod` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        let constraints = problem.inequality_constraints(param)?;
+        if constraints.iter().any(|c| *c >= float!(0.0)) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`BarrierMethod`: initial parameter vector must be strictly feasible."
+            ));
+        }
+        self.gap = F::infinity();
+        let cost = problem.cost(param)?;
+        Ok((state.cost(cost), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, J, H, F>,
+    ) -> Result<(IterState<P, G, J, H, F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`BarrierMethod`: Parameter vector in state not set."
+        ))?;
+        let hessian = state.get_hessian().cloned();
+        let inv_hessian = state.get_inv_hessian().cloned();
+        let inner_max_iters = self.inner_max_iters;
+
+        let OptimizationResult {
+            problem: mut inner_problem,
+            state: mut inner_state,
+            ..
+        } = Executor::new(
+            BarrierProblem::new(
+                problem.take_problem().ok_or_else(argmin_error_closure!(
+                    PotentialBug,
+                    "`BarrierMethod`: Failed to take `problem` for inner solver"
+                ))?,
+                self.mu,
+            ),
+            self.solver.clone(),
+        )
+        .configure(|config| {
+            let config = config.param(param).max_iters(inner_max_iters);
+            let config = match hessian {
+                Some(hessian) => config.hessian(hessian),
+                None => config,
+            };
+            match inv_hessian {
+                Some(inv_hessian) => config.inv_hessian(inv_hessian),
+                None => config,
+            }
+        })
+        .ctrlc(false)
+        .run()?;
+
+        // Get back problem and function evaluation counts
+        problem.problem = inner_problem.take_problem().map(BarrierProblem::into_inner);
+        problem.consume_func_counts(inner_problem);
+
+        let new_param = inner_state
+            .take_best_param()
+            .ok_or_else(argmin_error_closure!(
+                PotentialBug,
+                "`BarrierMethod`: No `param` returned by inner solver"
+            ))?;
+
+        let num_constraints = problem.inequality_constraints(&new_param)?.len();
+        self.gap = F::from_usize(num_constraints).unwrap() * self.mu;
+        let cost = problem.cost(&new_param)?;
+
+        let kv = kv!(
+            "barrier" => self.mu;
+            "duality_gap" => self.gap;
+            "inner_iters" => inner_state.get_iter();
+        );
+
+        self.mu = self.mu * self.mu_factor;
+
+        Ok((state.param(new_param).cost(cost), Some(kv)))
+    }
+
+    fn terminate(&mut self, _state: &IterState<P, G, J, H, F>) -> TerminationStatus {
+        if self.gap <= self.tol {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+    use crate::solver::linesearch::{condition::ArmijoCondition, BacktrackingLineSearch};
+    use crate::solver::quasinewton::LBFGS;
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    type Inner = LBFGS<
+        BacktrackingLineSearch<Vec<f64>, Vec<f64>, ArmijoCondition<f64>, f64>,
+        Vec<f64>,
+        Vec<f64>,
+        f64,
+    >;
+
+    test_trait_impl!(barrier_method, BarrierMethod<Inner, f64>);
+
+    /// min (x - 2)^2 + (y - 1)^2 s.t. x + y <= 1
+    struct HalfPlaneProblem {}
+
+    impl CostFunction for HalfPlaneProblem {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok((p[0] - 2.0).powi(2) + (p[1] - 1.0).powi(2))
+        }
+    }
+
+    impl Gradient for HalfPlaneProblem {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(vec![2.0 * (p[0] - 2.0), 2.0 * (p[1] - 1.0)])
+        }
+    }
+
+    impl InequalityConstraint for HalfPlaneProblem {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+        type Float = f64;
+
+        fn inequality_constraints(&self, p: &Self::Param) -> Result<Vec<f64>, Error> {
+            Ok(vec![p[0] + p[1] - 1.0])
+        }
+
+        fn inequality_jacobian(&self, _p: &Self::Param) -> Result<Vec<Vec<f64>>, Error> {
+            Ok(vec![vec![1.0, 1.0]])
+        }
+    }
+
+    fn inner() -> Inner {
+        LBFGS::new(
+            BacktrackingLineSearch::new(ArmijoCondition::new(1e-4).unwrap()),
+            5,
+        )
+    }
+
+    #[test]
+    fn test_new() {
+        let BarrierMethod {
+            solver: _,
+            mu,
+            mu_factor,
+            tol,
+            inner_max_iters,
+            gap,
+        } = BarrierMethod::<_, f64>::new(inner());
+        assert_eq!(mu.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(mu_factor.to_ne_bytes(), 0.1f64.to_ne_bytes());
+        assert_eq!(tol.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert_eq!(inner_max_iters, 1000);
+        assert!(gap.is_infinite());
+    }
+
+    #[test]
+    fn test_with_barrier_parameter() {
+        for mu in [f64::EPSILON, 1.0, 100.0] {
+            let bm = BarrierMethod::<_, f64>::new(inner())
+                .with_barrier_parameter(mu)
+                .unwrap();
+            assert_eq!(bm.mu.to_ne_bytes(), mu.to_ne_bytes());
+        }
+
+        for mu in [0.0, -1.0] {
+            let res = BarrierMethod::new(inner()).with_barrier_parameter(mu);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`BarrierMethod`: barrier parameter must be > 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_barrier_reduction() {
+        for factor in [f64::EPSILON, 0.5, 1.0 - f64::EPSILON] {
+            let bm = BarrierMethod::<_, f64>::new(inner())
+                .with_barrier_reduction(factor)
+                .unwrap();
+            assert_eq!(bm.mu_factor.to_ne_bytes(), factor.to_ne_bytes());
+        }
+
+        for factor in [0.0, 1.0, -1.0, 2.0] {
+            let res = BarrierMethod::new(inner()).with_barrier_reduction(factor);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`BarrierMethod`: barrier reduction factor must be in (0, 1).\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_tolerance() {
+        for tol in [0.0, 1e-10, 1.0] {
+            let bm = BarrierMethod::<_, f64>::new(inner())
+                .with_tolerance(tol)
+                .unwrap();
+            assert_eq!(bm.tol.to_ne_bytes(), tol.to_ne_bytes());
+        }
+
+        for tol in [-f64::EPSILON, -1.0] {
+            let res = BarrierMethod::new(inner()).with_tolerance(tol);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`BarrierMethod`: tolerance must be >= 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_inner_max_iters() {
+        for iters in [1, 10, 1000] {
+            let bm = BarrierMethod::<_, f64>::new(inner())
+                .with_inner_max_iters(iters)
+                .unwrap();
+            assert_eq!(bm.inner_max_iters, iters);
+        }
+
+        let res = BarrierMethod::<_, f64>::new(inner()).with_inner_max_iters(0);
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`BarrierMethod`: maximum number of inner iterations ",
+                "must be > 0.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_barrier_problem() {
+        let problem = BarrierProblem::new(HalfPlaneProblem {}, 1.0);
+
+        // strictly feasible: g = -1
+        let p = vec![0.0, 0.0];
+        assert_relative_eq!(problem.cost(&p).unwrap(), 5.0, epsilon = f64::EPSILON);
+        assert_eq!(problem.gradient(&p).unwrap(), vec![-3.0, -1.0]);
+
+        // infeasible
+        let p = vec![1.0, 1.0];
+        assert!(problem.cost(&p).unwrap().is_infinite());
+    }
+
+    #[test]
+    fn test_solver() {
+        // Infeasible initial guess
+        let res = Executor::new(HalfPlaneProblem {}, BarrierMethod::new(inner()))
+            .configure(|state| state.param(vec![1.0, 1.0]).max_iters(100))
+            .run();
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`BarrierMethod`: initial parameter vector must be ",
+                "strictly feasible.\""
+            )
+        );
+
+        let res = Executor::new(HalfPlaneProblem {}, BarrierMethod::new(inner()))
+            .configure(|state| state.param(vec![0.0, 0.0]).max_iters(100))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let best = res.state.get_best_param().unwrap();
+        assert_relative_eq!(best[0], 1.0, epsilon = 1e-6);
+        assert_relative_eq!(best[1], 0.0, epsilon = 1e-6);
+        assert!(best[0] + best[1] < 1.0);
+    }
+}
@@ -0,0 +1,380 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Bracketing of a minimum
+//!
+//! Finds an interval which contains a minimum of a univariate function, as required by
+//! [`GoldenSectionSearch`](`crate::solver::goldensectionsearch::GoldenSectionSearch`) and
+//! [`BrentOpt`](`crate::solver::brent::BrentOpt`). See [`Bracketing`] for details.
+//!
+//! ## Reference
+//!
+//! William H. Press, Saul A. Teukolsky, William T. Vetterling and Brian P. Flannery (2007).
+//! Numerical Recipes: The Art of Scientific Computing (3rd ed.), section 10.1.
+//! Cambridge University Press. ISBN 978-0-521-88068-8.
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, IterState, Problem, Solver, TerminationReason,
+    TerminationStatus, KV,
+};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Bracketing of a minimum
+///
+/// Expands an initial guess `x0` into a bracket `(a, b, c)` with `a < b < c`, `f(b) <= f(a)` and
+/// `f(b) < f(c)`, which guarantees that a (local) minimum of a continuous function lies within
+/// `[a, c]`.
+///
+/// The function is first evaluated at `x0` and `x0 + step`. The search then proceeds downhill
+/// from the point with the larger function value, where the distance to the next point is
+/// increased by a constant factor (see [`with_growth`](`Bracketing::with_growth`)) in each
+/// iteration, until the function value increases again.
+///
+/// The initial guess needs to be provided via the `configure` method of the
+/// [`Executor`](`crate::core::Executor`). The solver terminates with
+/// [`TerminationReason::SolverConverged`] once a bracket has been found, which can then be
+/// obtained from the returned solver via [`bracket`](`Bracketing::bracket`) and handed off to
+/// [`GoldenSectionSearch`](`crate::solver::goldensectionsearch::GoldenSectionSearch`) or
+/// [`BrentOpt`](`crate::solver::brent::BrentOpt`). The middle point `b` is the parameter
+/// vector of the state. If the function decreases monotonically, the solver stops when the
+/// maximum number of iterations is reached and no bracket is available.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`].
+///
+/// ## Example
+///
+/// ```
+/// # use argmin::core::{CostFunction, Error, Executor, State};
+/// # use argmin::solver::bracketing::Bracketing;
+/// # use argmin::solver::brent::BrentOpt;
+/// # struct Problem {}
+/// # impl CostFunction for Problem {
+/// #     type Param = f64;
+/// #     type Output = f64;
+/// #     fn cost(&self, x: &f64) -> Result<f64, Error> {
+/// #         Ok((x - 42.0).powi(2))
+/// #     }
+/// # }
+/// # fn main() -> Result<(), Error> {
+/// let res = Executor::new(Problem {}, Bracketing::new(1.0)?)
+///     .configure(|state| state.param(0.0).max_iters(100))
+///     .run()?;
+/// let (a, _b, c) = res.solver.bracket().unwrap();
+///
+/// let res = Executor::new(Problem {}, BrentOpt::new(a, c)).run()?;
+/// # assert!((res.state.get_best_param().unwrap() - 42.0).abs() < 1e-4);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// ## Reference
+///
+/// William H. Press, Saul A. Teukolsky, William T. Vetterling and Brian P. Flannery (2007).
+/// Numerical Recipes: The Art of Scientific Computing (3rd ed.), section 10.1.
+/// Cambridge University Press. ISBN 978-0-521-88068-8.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct Bracketing<F> {
+    /// Initial step
+    step: F,
+    /// Growth factor of the steps
+    growth: F,
+    /// Points in the order in which they are visited
+    a: F,
+    b: F,
+    c: F,
+    /// Function values at `a`, `b` and `c`
+    fa: F,
+    fb: F,
+    fc: F,
+}
+
+impl<F> Bracketing<F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`Bracketing`]
+    ///
+    /// Takes the initial step, which must be larger than 0. The direction of the search is
+    /// determined automatically.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::bracketing::Bracketing;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let bracketing = Bracketing::new(0.1f64)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(step: F) -> Result<Self, Error> {
+        if step <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Bracketing`: step must be > 0."
+            ));
+        }
+        Ok(Bracketing {
+            step,
+            growth: float!(1.618_033_988_749_895),
+            a: F::nan(),
+            b: F::nan(),
+            c: F::nan(),
+            fa: F::nan(),
+            fb: F::nan(),
+            fc: F::nan(),
+        })
+    }
+
+    /// Set the factor by which the steps grow in each iteration
+    ///
+    /// Must be larger than 1 and defaults to the golden ratio.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::bracketing::Bracketing;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let bracketing = Bracketing::new(0.1f64)?.with_growth(2.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_growth(mut self, growth: F) -> Result<Self, Error> {
+        if growth <= float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Bracketing`: growth factor must be > 1."
+            ));
+        }
+        self.growth = growth;
+        Ok(self)
+    }
+
+    /// Returns the bracket `(a, b, c)` with `a < b < c`, `f(b) <= f(a)` and `f(b) < f(c)` if one
+    /// has been found.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::bracketing::Bracketing;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let bracketing = Bracketing::new(0.1f64)?;
+    /// assert!(bracketing.bracket().is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn bracket(&self) -> Option<(F, F, F)> {
+        if self.fb <= self.fa && self.fb < self.fc {
+            Some((self.a.min(self.c), self.b, self.a.max(self.c)))
+        } else {
+            None
+        }
+    }
+}
+
+impl<O, F> Solver<O, IterState<F, (), (), (), F>> for Bracketing<F>
+where
+    O: CostFunction<Param = F, Output = F>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Bracketing";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<F, (), (), (), F>,
+    ) -> Result<(IterState<F, (), (), (), F>, Option<KV>), Error> {
+        let x0 = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`Bracketing` requires an initial estimate. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        let f0 = problem.cost(&x0)?;
+        let x1 = x0 + self.step;
+        let f1 = problem.cost(&x1)?;
+
+        // Go downhill from `a` to `b`
+        (self.a, self.fa, self.b, self.fb) = if f1 > f0 {
+            (x1, f1, x0, f0)
+        } else {
+            (x0, f0, x1, f1)
+        };
+        self.c = self.b + self.growth * (self.b - self.a);
+        self.fc = problem.cost(&self.c)?;
+
+        Ok((state.param(self.b).cost(self.fb), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<F, (), (), (), F>,
+    ) -> Result<(IterState<F, (), (), (), F>, Option<KV>), Error> {
+        (self.a, self.fa) = (self.b, self.fb);
+        (self.b, self.fb) = (self.c, self.fc);
+        self.c = self.b + self.growth * (self.b - self.a);
+        self.fc = problem.cost(&self.c)?;
+
+        Ok((state.param(self.b).cost(self.fb), None))
+    }
+
+    fn terminate(&mut self, _state: &IterState<F, (), (), (), F>) -> TerminationStatus {
+        if self.bracket().is_some() {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Executor, State};
+    use crate::solver::goldensectionsearch::GoldenSectionSearch;
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(bracketing, Bracketing<f64>);
+
+    struct Parabola {}
+
+    impl CostFunction for Parabola {
+        type Param = f64;
+        type Output = f64;
+
+        fn cost(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+            Ok((x - 3.0).powi(2))
+        }
+    }
+
+    fn run(x0: f64, step: f64) -> Bracketing<f64> {
+        let res = Executor::new(Parabola {}, Bracketing::new(step).unwrap())
+            .configure(|state| state.param(x0).max_iters(100))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        res.solver
+    }
+
+    #[test]
+    fn test_new() {
+        let Bracketing {
+            step,
+            growth,
+            a,
+            b,
+            c,
+            fa,
+            fb,
+            fc,
+        } = Bracketing::new(0.1f64).unwrap();
+        assert_eq!(step.to_ne_bytes(), 0.1f64.to_ne_bytes());
+        assert_eq!(growth.to_ne_bytes(), 1.618_033_988_749_895f64.to_ne_bytes());
+        for x in [a, b, c, fa, fb, fc] {
+            assert!(x.is_nan());
+        }
+
+        for step in [0.0, -1.0] {
+            let res = Bracketing::new(step);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`Bracketing`: step must be > 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_growth() {
+        let bracketing = Bracketing::new(0.1f64).unwrap().with_growth(2.0).unwrap();
+        assert_eq!(bracketing.growth.to_ne_bytes(), 2.0f64.to_ne_bytes());
+
+        for growth in [1.0, 0.5, -1.0] {
+            let res = Bracketing::new(0.1f64).unwrap().with_growth(growth);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`Bracketing`: growth factor must be > 1.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_init_param_not_initialized() {
+        let mut bracketing = Bracketing::new(0.1f64).unwrap();
+        let res = bracketing.init(&mut Problem::new(Parabola {}), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`Bracketing` requires an initial estimate. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_bracket() {
+        // Minimum to the right and to the left of the initial guess
+        for x0 in [-10.0, 0.0, 2.95, 5.0, 100.0] {
+            let (a, b, c) = run(x0, 0.1).bracket().unwrap();
+            assert!(a < b && b < c);
+            assert!(a <= 3.0 && 3.0 <= c);
+            let f = |x: f64| (x - 3.0).powi(2);
+            assert!(f(b) <= f(a) && f(b) < f(c));
+        }
+    }
+
+    #[test]
+    fn test_no_bracket() {
+        struct Linear {}
+
+        impl CostFunction for Linear {
+            type Param = f64;
+            type Output = f64;
+
+            fn cost(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(-x)
+            }
+        }
+
+        let res = Executor::new(Linear {}, Bracketing::new(0.1).unwrap())
+            .configure(|state| state.param(0.0).max_iters(10))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::MaxItersReached)
+        );
+        assert!(res.solver.bracket().is_none());
+    }
+
+    #[test]
+    fn test_golden_section_search() {
+        let (a, b, c) = run(-10.0, 0.5).bracket().unwrap();
+        let gss = GoldenSectionSearch::new(a, c)
+            .unwrap()
+            .with_tolerance(1e-6)
+            .unwrap();
+        let res = Executor::new(Parabola {}, gss)
+            .configure(|state| state.param(b).max_iters(1000))
+            .run()
+            .unwrap();
+        assert_relative_eq!(*res.state.get_best_param().unwrap(), 3.0, epsilon = 1e-4);
+    }
+}
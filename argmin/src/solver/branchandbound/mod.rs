@@ -0,0 +1,826 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Branch-and-bound
+//!
+//! Meta-solver for small mixed-integer nonlinear problems, which solves a tree of continuous
+//! relaxations with any solver able to handle bound constraints. See [`BranchAndBound`] for
+//! details.
+//!
+//! ## Reference
+//!
+//! Pietro Belotti, Christian Kirches, Sven Leyffer, Jeff Linderoth, James Luedtke and Ashutosh
+//! Mahajan (2013). Mixed-integer nonlinear optimization. Acta Numerica 22, 1-131.
+
+use crate::core::{
+    ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, Executor, Gradient, Hessian,
+    InequalityConstraint, IterState, OptimizationResult, Problem, SerializeAlias, Solver, State,
+    TerminationReason, TerminationStatus, KV,
+};
+use crate::solver::penalty::{feasible_first_cost, max_violation};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Relaxed problem
+///
+/// Wraps a problem and restricts it to the box `lower <= x <= upper`, where infinite bounds are
+/// allowed. The box is exposed as the inequality constraints `x_i - upper_i <= 0` and
+/// `lower_i - x_i <= 0` for all finite bounds. This is the problem the inner solver of
+/// [`BranchAndBound`] operates on.
+///
+/// [`CostFunction`], [`Gradient`] and [`Hessian`] are forwarded to the wrapped problem.
+#[derive(Clone, Debug)]
+pub struct RelaxedProblem<O, F> {
+    /// Wrapped problem
+    problem: O,
+    /// Lower bounds
+    lower: Vec<F>,
+    /// Upper bounds
+    upper: Vec<F>,
+}
+
+impl<O, F> RelaxedProblem<O, F> {
+    /// Construct a new instance of [`RelaxedProblem`] from a problem and lower and upper bounds
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::branchandbound::RelaxedProblem;
+    /// # struct UserDefinedProblem {};
+    /// let problem = RelaxedProblem::new(
+    ///     UserDefinedProblem {},
+    ///     vec![0.0f64, f64::NEG_INFINITY],
+    ///     vec![1.0, 2.0],
+    /// );
+    /// ```
+    pub fn new(problem: O, lower: Vec<F>, upper: Vec<F>) -> Self {
+        RelaxedProblem {
+            problem,
+            lower,
+            upper,
+        }
+    }
+
+    /// Returns the wrapped problem
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::branchandbound::RelaxedProblem;
+    /// # #[derive(Debug, Eq, PartialEq)]
+    /// # struct UserDefinedProblem {};
+    /// let problem = RelaxedProblem::new(UserDefinedProblem {}, vec![0.0f64], vec![1.0]);
+    /// assert_eq!(problem.into_inner(), UserDefinedProblem {});
+    /// ```
+    pub fn into_inner(self) -> O {
+        self.problem
+    }
+}
+
+impl<O, F> CostFunction for RelaxedProblem<O, F>
+where
+    O: CostFunction,
+{
+    type Param = O::Param;
+    type Output = O::Output;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        self.problem.cost(param)
+    }
+}
+
+impl<O, F> Gradient for RelaxedProblem<O, F>
+where
+    O: Gradient,
+{
+    type Param = O::Param;
+    type Gradient = O::Gradient;
+
+    fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+        self.problem.gradient(param)
+    }
+}
+
+impl<O, F> Hessian for RelaxedProblem<O, F>
+where
+    O: Hessian,
+{
+    type Param = O::Param;
+    type Hessian = O::Hessian;
+
+    fn hessian(&self, param: &Self::Param) -> Result<Self::Hessian, Error> {
+        self.problem.hessian(param)
+    }
+}
+
+impl<O, F> InequalityConstraint for RelaxedProblem<O, F>
+where
+    F: ArgminFloat,
+{
+    type Param = Vec<F>;
+    type Gradient = Vec<F>;
+    type Float = F;
+
+    fn inequality_constraints(&self, param: &Self::Param) -> Result<Vec<F>, Error> {
+        let upper = param
+            .iter()
+            .zip(self.upper.iter())
+            .filter(|(_, u)| u.is_finite())
+            .map(|(x, u)| *x - *u);
+        let lower = param
+            .iter()
+            .zip(self.lower.iter())
+            .filter(|(_, l)| l.is_finite())
+            .map(|(x, l)| *l - *x);
+        Ok(upper.chain(lower).collect())
+    }
+
+    fn inequality_jacobian(&self, param: &Self::Param) -> Result<Vec<Vec<F>>, Error> {
+        let unit = |i: usize, sign: F| {
+            let mut e = vec![float!(0.0); param.len()];
+            e[i] = sign;
+            e
+        };
+        let upper = self
+            .upper
+            .iter()
+            .enumerate()
+            .filter(|(_, u)| u.is_finite())
+            .map(|(i, _)| unit(i, float!(1.0)));
+        let lower = self
+            .lower
+            .iter()
+            .enumerate()
+            .filter(|(_, l)| l.is_finite())
+            .map(|(i, _)| unit(i, float!(-1.0)));
+        Ok(upper.chain(lower).collect())
+    }
+}
+
+/// Node of the branch-and-bound tree
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+struct Node<F> {
+    /// Lower bounds
+    lower: Vec<F>,
+    /// Upper bounds
+    upper: Vec<F>,
+    /// Starting point of the inner solver
+    param: Vec<F>,
+}
+
+/// # Branch-and-bound
+///
+/// Solves problems of the form
+///
+/// `min_x f(x)` subject to `lower <= x <= upper` and `x_i` integer for `i` in `I`
+///
+/// where `I` is a set of indices of integer variables. The continuous relaxation of the problem
+/// (which drops the integrality constraints) is solved by an inner solver. If the solution `x` of
+/// the relaxation violates the integrality of a variable `x_i`, the problem is split into two
+/// subproblems with the additional bounds `x_i <= floor(x_i)` and `x_i >= ceil(x_i)`, which are
+/// explored depth-first. Each iteration of the solver processes one node of the resulting tree.
+/// Subproblems whose relaxation is not better than the best integer solution found so far
+/// (the incumbent) are discarded. The solver terminates once all nodes have been processed.
+///
+/// The inner solver operates on a [`RelaxedProblem`], which exposes the bounds of the current
+/// node as inequality constraints. Any solver which can handle these constraints can be used, for
+/// instance [`PenaltyMethod`](`crate::solver::penalty::PenaltyMethod`) or
+/// [`BarrierMethod`](`crate::solver::barrier::BarrierMethod`) combined with an unconstrained
+/// solver. The inner solver is run for at most
+/// [`with_inner_max_iters`](`BranchAndBound::with_inner_max_iters`) iterations and its solution
+/// is clamped to the bounds of the node. If an initial Hessian or inverse Hessian is provided via
+/// the [`configure`](`crate::core::Executor::configure`) method of the [`Executor`], it is
+/// passed on to each run of the inner solver.
+///
+/// A variable is considered integral if its distance to the closest integer is at most the
+/// tolerance set via [`with_integrality_tolerance`](`BranchAndBound::with_integrality_tolerance`).
+/// Integral solutions are rounded before they are accepted as incumbent. Following the same
+/// feasible-first rule as [`PenaltyMethod`](`crate::solver::penalty::PenaltyMethod`), only
+/// incumbents are stored in the state; as long as no integer solution has been found, the cost
+/// stays infinite.
+/// The cost of the relaxation of the current node and the number of open nodes are reported via
+/// the observers (`relaxation_cost` and `open_nodes`).
+///
+/// The solution is guaranteed to be optimal only if the relaxations are convex and solved to
+/// optimality by the inner solver. Otherwise the solver acts as a heuristic. Since the number of
+/// nodes can grow exponentially in the number of integer variables, bounds on the integer
+/// variables (see [`with_bounds`](`BranchAndBound::with_bounds`)) are strongly recommended.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`]. Depending on the inner
+/// solver, [`Gradient`] and [`Hessian`] may be required as well.
+///
+/// ## Reference
+///
+/// Pietro Belotti, Christian Kirches, Sven Leyffer, Jeff Linderoth, James Luedtke and Ashutosh
+/// Mahajan (2013). Mixed-integer nonlinear optimization. Acta Numerica 22, 1-131.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct BranchAndBound<S, F> {
+    /// Inner solver
+    solver: S,
+    /// Indices of integer variables
+    integers: Vec<usize>,
+    /// Lower bounds of all variables
+    lower: Vec<F>,
+    /// Upper bounds of all variables
+    upper: Vec<F>,
+    /// Tolerance on the integrality of variables
+    tol_int: F,
+    /// Maximum number of iterations of the inner solver
+    inner_max_iters: u64,
+    /// Open nodes
+    nodes: Vec<Node<F>>,
+}
+
+impl<S, F> BranchAndBound<S, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`BranchAndBound`]
+    ///
+    /// Takes the inner solver used to solve the relaxations and the indices of the integer
+    /// variables.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::branchandbound::BranchAndBound;
+    /// # use argmin::solver::neldermead::NelderMead;
+    /// # let inner: NelderMead<Vec<f64>, f64> = NelderMead::new(vec![vec![0.0], vec![1.0]]);
+    /// let bnb: BranchAndBound<_, f64> = BranchAndBound::new(inner, vec![0]);
+    /// ```
+    pub fn new(solver: S, integers: Vec<usize>) -> Self {
+        BranchAndBound {
+            solver,
+            integers,
+            lower: vec![],
+            upper: vec![],
+            tol_int: float!(1e-6),
+            inner_max_iters: 1000,
+            nodes: vec![],
+        }
+    }
+
+    /// Set lower and upper bounds of all variables
+    ///
+    /// Infinite bounds are allowed. By default, all variables are unbounded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::branchandbound::BranchAndBound;
+    /// # use argmin::solver::neldermead::NelderMead;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let inner: NelderMead<Vec<f64>, f64> = NelderMead::new(vec![vec![0.0], vec![1.0]]);
+    /// let bnb = BranchAndBound::new(inner, vec![0])
+    ///     .with_bounds(vec![0.0f64, f64::NEG_INFINITY], vec![10.0, f64::INFINITY])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_bounds(mut self, lower: Vec<F>, upper: Vec<F>) -> Result<Self, Error> {
+        if lower.len() != upper.len() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`BranchAndBound`: lower and upper bound must have the same length."
+            ));
+        }
+        if lower.iter().zip(upper.iter()).any(|(l, u)| l > u) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`BranchAndBound`: lower bound must be smaller than or equal to upper bound."
+            ));
+        }
+        self.lower = lower;
+        self.upper = upper;
+        Ok(self)
+    }
+
+    /// Set the tolerance on the integrality of variables
+    ///
+    /// Must be in `[0, 0.5)` and defaults to `1e-6`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::branchandbound::BranchAndBound;
+    /// # use argmin::solver::neldermead::NelderMead;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let inner: NelderMead<Vec<f64>, f64> = NelderMead::new(vec![vec![0.0], vec![1.0]]);
+    /// let bnb: BranchAndBound<_, f64> =
+    ///     BranchAndBound::new(inner, vec![0]).with_integrality_tolerance(1e-4)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_integrality_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol < float!(0.0) || tol >= float!(0.5) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`BranchAndBound`: integrality tolerance must be in [0, 0.5)."
+            ));
+        }
+        self.tol_int = tol;
+        Ok(self)
+    }
+
+    /// Set the maximum number of iterations of the inner solver per node
+    ///
+    /// Must be larger than 0 and defaults to `1000`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::branchandbound::BranchAndBound;
+    /// # use argmin::solver::neldermead::NelderMead;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let inner: NelderMead<Vec<f64>, f64> = NelderMead::new(vec![vec![0.0], vec![1.0]]);
+    /// let bnb: BranchAndBound<_, f64> =
+    ///     BranchAndBound::new(inner, vec![0]).with_inner_max_iters(100)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_inner_max_iters(mut self, iters: u64) -> Result<Self, Error> {
+        if iters == 0 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`BranchAndBound`: maximum number of inner iterations must be > 0."
+            ));
+        }
+        self.inner_max_iters = iters;
+        Ok(self)
+    }
+
+    /// Maximum distance of an integer variable to the closest integer
+    fn integrality_violation(&self, param: &[F]) -> F {
+        max_violation(
+            &self
+                .integers
+                .iter()
+                .map(|&i| (param[i] - param[i].round()).abs())
+                .collect::<Vec<F>>(),
+        )
+    }
+
+    /// Returns the integer variable which is farthest from integrality, if any exceeds the
+    /// tolerance.
+    fn branching_variable(&self, param: &[F]) -> Option<usize> {
+        self.integers
+            .iter()
+            .map(|&i| (i, (param[i] - param[i].round()).abs()))
+            .filter(|(_, d)| *d > self.tol_int)
+            .fold(None, |acc: Option<(usize, F)>, (i, d)| match acc {
+                Some((_, best)) if best >= d => acc,
+                _ => Some((i, d)),
+            })
+            .map(|(i, _)| i)
+    }
+}
+
+impl<O, S, G, J, H, F> Solver<O, IterState<Vec<F>, G, J, H, F>> for BranchAndBound<S, F>
+where
+    O: CostFunction<Param = Vec<F>, Output = F>,
+    S: Clone + Solver<RelaxedProblem<O, F>, IterState<Vec<F>, G, J, H, F>>,
+    G: SerializeAlias + DeserializeOwnedAlias,
+    J: SerializeAlias + DeserializeOwnedAlias,
+    H: Clone + SerializeAlias + DeserializeOwnedAlias,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Branch-and-bound";
+
+    fn init(
+        &mut self,
+        _problem: &mut Problem<O>,
+        state: IterState<Vec<F>, G, J, H, F>,
+    ) -> Result<(IterState<Vec<F>, G, J, H, F>, Option<KV>), Error> {
+        let param = state.get_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`BranchAndBound` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        let n = param.len();
+        if self.lower.is_empty() {
+            self.lower = vec![F::neg_infinity(); n];
+            self.upper = vec![F::infinity(); n];
+        }
+        if self.lower.len() != n {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`BranchAndBound`: bounds and initial parameter vector must have the same length."
+            ));
+        }
+        if self.integers.iter().any(|&i| i >= n) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`BranchAndBound`: index of integer variable out of bounds."
+            ));
+        }
+        // Integer variables can only take integer values within their bounds
+        for &i in self.integers.iter() {
+            self.lower[i] = self.lower[i].ceil();
+            self.upper[i] = self.upper[i].floor();
+        }
+        self.nodes = if self
+            .lower
+            .iter()
+            .zip(self.upper.iter())
+            .all(|(l, u)| l <= u)
+        {
+            vec![Node {
+                lower: self.lower.clone(),
+                upper: self.upper.clone(),
+                param: param.clone(),
+            }]
+        } else {
+            vec![]
+        };
+        Ok((state, None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<Vec<F>, G, J, H, F>,
+    ) -> Result<(IterState<Vec<F>, G, J, H, F>, Option<KV>), Error> {
+        let Node {
+            lower,
+            upper,
+            param,
+        } = self.nodes.pop().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`BranchAndBound`: No open node left."
+        ))?;
+        let hessian = state.get_hessian().cloned();
+        let inv_hessian = state.get_inv_hessian().cloned();
+        let inner_max_iters = self.inner_max_iters;
+
+        let OptimizationResult {
+            problem: mut inner_problem,
+            state: mut inner_state,
+            ..
+        } = Executor::new(
+            RelaxedProblem::new(
+                problem.take_problem().ok_or_else(argmin_error_closure!(
+                    PotentialBug,
+                    "`BranchAndBound`: Failed to take `problem` for inner solver"
+                ))?,
+                lower.clone(),
+                upper.clone(),
+            ),
+            self.solver.clone(),
+        )
+        .configure(|config| {
+            let config = config.param(param).max_iters(inner_max_iters);
+            let config = match hessian {
+                Some(hessian) => config.hessian(hessian),
+                None => config,
+            };
+            match inv_hessian {
+                Some(inv_hessian) => config.inv_hessian(inv_hessian),
+                None => config,
+            }
+        })
+        .ctrlc(false)
+        .run()?;
+
+        // Get back problem and function evaluation counts
+        problem.problem = inner_problem.take_problem().map(RelaxedProblem::into_inner);
+        problem.consume_func_counts(inner_problem);
+
+        let relaxed_param: Vec<F> = inner_state
+            .take_best_param()
+            .ok_or_else(argmin_error_closure!(
+                PotentialBug,
+                "`BranchAndBound`: No `param` returned by inner solver"
+            ))?
+            .iter()
+            .zip(lower.iter().zip(upper.iter()))
+            .map(|(x, (l, u))| x.max(*l).min(*u))
+            .collect();
+        let relaxed_cost = problem.cost(&relaxed_param)?;
+
+        let kv = |open_nodes: usize| {
+            kv!(
+                "relaxation_cost" => relaxed_cost;
+                "open_nodes" => open_nodes as u64;
+            )
+        };
+
+        // Prune nodes which cannot improve on the incumbent
+        if relaxed_cost >= state.get_best_cost() {
+            return Ok((state, Some(kv(self.nodes.len()))));
+        }
+
+        match self.branching_variable(&relaxed_param) {
+            None => {
+                let violation = self.integrality_violation(&relaxed_param);
+                let mut new_param = relaxed_param;
+                for &i in self.integers.iter() {
+                    new_param[i] = new_param[i].round();
+                }
+                let new_cost =
+                    feasible_first_cost(problem.cost(&new_param)?, violation, self.tol_int);
+                let state = if new_cost < state.get_best_cost() {
+                    state.param(new_param).cost(new_cost)
+                } else {
+                    state
+                };
+                Ok((state, Some(kv(self.nodes.len()))))
+            }
+            Some(i) => {
+                let x = relaxed_param[i];
+                let mut down = Node {
+                    lower: lower.clone(),
+                    upper: upper.clone(),
+                    param: relaxed_param.clone(),
+                };
+                down.upper[i] = x.floor();
+                down.param[i] = x.floor();
+                let mut up = Node {
+                    lower,
+                    upper,
+                    param: relaxed_param,
+                };
+                up.lower[i] = x.ceil();
+                up.param[i] = x.ceil();
+                // The child in the direction of rounding is explored first
+                let children = if x - x.floor() < float!(0.5) {
+                    [up, down]
+                } else {
+                    [down, up]
+                };
+                self.nodes.extend(
+                    children
+                        .into_iter()
+                        .filter(|node| node.lower[i] <= node.upper[i]),
+                );
+                Ok((state, Some(kv(self.nodes.len()))))
+            }
+        }
+    }
+
+    fn terminate(&mut self, _state: &IterState<Vec<F>, G, J, H, F>) -> TerminationStatus {
+        if self.nodes.is_empty() {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+    use crate::solver::linesearch::MoreThuenteLineSearch;
+    use crate::solver::penalty::PenaltyMethod;
+    use crate::solver::quasinewton::LBFGS;
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    type Inner = PenaltyMethod<
+        LBFGS<MoreThuenteLineSearch<Vec<f64>, Vec<f64>, f64>, Vec<f64>, Vec<f64>, f64>,
+        f64,
+    >;
+
+    test_trait_impl!(branch_and_bound, BranchAndBound<Inner, f64>);
+
+    /// `f(x, y) = (x - 1.4)^2 + 2 (y - 2.6)^2 + 0.8 (x - 1.4) (y - 2.6)`
+    struct Quadratic {}
+
+    impl CostFunction for Quadratic {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            let (dx, dy) = (p[0] - 1.4, p[1] - 2.6);
+            Ok(dx.powi(2) + 2.0 * dy.powi(2) + 0.8 * dx * dy)
+        }
+    }
+
+    impl Gradient for Quadratic {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            let (dx, dy) = (p[0] - 1.4, p[1] - 2.6);
+            Ok(vec![2.0 * dx + 0.8 * dy, 4.0 * dy + 0.8 * dx])
+        }
+    }
+
+    fn inner() -> Inner {
+        PenaltyMethod::new(LBFGS::new(MoreThuenteLineSearch::new(), 5))
+    }
+
+    fn run(bnb: BranchAndBound<Inner, f64>) -> (Vec<f64>, f64) {
+        let res = Executor::new(Quadratic {}, bnb)
+            .configure(|state| state.param(vec![0.0, 0.0]).max_iters(100))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        (
+            res.state.get_best_param().unwrap().clone(),
+            res.state.get_best_cost(),
+        )
+    }
+
+    #[test]
+    fn test_new() {
+        let BranchAndBound {
+            solver: _,
+            integers,
+            lower,
+            upper,
+            tol_int,
+            inner_max_iters,
+            nodes,
+        } = BranchAndBound::<_, f64>::new(inner(), vec![0, 1]);
+        assert_eq!(integers, vec![0, 1]);
+        assert!(lower.is_empty());
+        assert!(upper.is_empty());
+        assert_eq!(tol_int.to_ne_bytes(), 1e-6f64.to_ne_bytes());
+        assert_eq!(inner_max_iters, 1000);
+        assert!(nodes.is_empty());
+    }
+
+    #[test]
+    fn test_with_bounds() {
+        let bnb = BranchAndBound::new(inner(), vec![0])
+            .with_bounds(vec![0.0, f64::NEG_INFINITY], vec![0.0, 1.0])
+            .unwrap();
+        assert_eq!(bnb.lower, vec![0.0, f64::NEG_INFINITY]);
+        assert_eq!(bnb.upper, vec![0.0, 1.0]);
+
+        let res = BranchAndBound::new(inner(), vec![0]).with_bounds(vec![0.0], vec![1.0, 1.0]);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`BranchAndBound`: lower and upper bound must have the same length.\""
+        );
+
+        let res = BranchAndBound::new(inner(), vec![0]).with_bounds(vec![1.0], vec![0.0]);
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`BranchAndBound`: lower bound must be smaller than or ",
+                "equal to upper bound.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_with_integrality_tolerance() {
+        for tol in [0.0, 1e-3, 0.49] {
+            let bnb = BranchAndBound::<_, f64>::new(inner(), vec![0])
+                .with_integrality_tolerance(tol)
+                .unwrap();
+            assert_eq!(bnb.tol_int.to_ne_bytes(), tol.to_ne_bytes());
+        }
+
+        for tol in [-1e-3, 0.5, 1.0] {
+            let res = BranchAndBound::new(inner(), vec![0]).with_integrality_tolerance(tol);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`BranchAndBound`: integrality tolerance must be in [0, 0.5).\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_inner_max_iters() {
+        let bnb = BranchAndBound::<_, f64>::new(inner(), vec![0])
+            .with_inner_max_iters(10)
+            .unwrap();
+        assert_eq!(bnb.inner_max_iters, 10);
+
+        let res = BranchAndBound::<_, f64>::new(inner(), vec![0]).with_inner_max_iters(0);
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`BranchAndBound`: maximum number of inner iterations ",
+                "must be > 0.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_init() {
+        let res = Executor::new(Quadratic {}, BranchAndBound::new(inner(), vec![0])).run();
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`BranchAndBound` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+
+        let res = Executor::new(Quadratic {}, BranchAndBound::new(inner(), vec![2]))
+            .configure(|state| state.param(vec![0.0, 0.0]))
+            .run();
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`BranchAndBound`: index of integer variable out of bounds.\""
+        );
+
+        let bnb = BranchAndBound::new(inner(), vec![0])
+            .with_bounds(vec![0.0], vec![1.0])
+            .unwrap();
+        let res = Executor::new(Quadratic {}, bnb)
+            .configure(|state| state.param(vec![0.0, 0.0]))
+            .run();
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`BranchAndBound`: bounds and initial parameter vector ",
+                "must have the same length.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_relaxed_problem() {
+        let problem = RelaxedProblem::new((), vec![0.0, f64::NEG_INFINITY], vec![1.0, 2.0]);
+        let p = vec![0.5, 3.0];
+        assert_eq!(
+            problem.inequality_constraints(&p).unwrap(),
+            vec![-0.5, 1.0, -0.5]
+        );
+        assert_eq!(
+            problem.inequality_jacobian(&p).unwrap(),
+            vec![vec![1.0, 0.0], vec![0.0, 1.0], vec![-1.0, 0.0]]
+        );
+    }
+
+    #[test]
+    fn test_mixed_integer() {
+        // For fixed integer x, the optimal y is 2.6 - 0.2 * (x - 1.4)
+        let expected = (-5..=5)
+            .map(|x| {
+                let x = x as f64;
+                let p = vec![x, 2.6 - 0.2 * (x - 1.4)];
+                let c = Quadratic {}.cost(&p).unwrap();
+                (p, c)
+            })
+            .fold(
+                (vec![], f64::INFINITY),
+                |a, b| if b.1 < a.1 { b } else { a },
+            );
+
+        let (param, cost) = run(BranchAndBound::new(inner(), vec![0]));
+        assert_eq!(param[0].to_ne_bytes(), expected.0[0].to_ne_bytes());
+        assert_relative_eq!(param[1], expected.0[1], epsilon = 1e-6);
+        assert_relative_eq!(cost, expected.1, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_pure_integer() {
+        let expected = (-5..=5)
+            .flat_map(|x| (-5..=5).map(move |y| vec![x as f64, y as f64]))
+            .map(|p| {
+                let c = Quadratic {}.cost(&p).unwrap();
+                (p, c)
+            })
+            .fold(
+                (vec![], f64::INFINITY),
+                |a, b| if b.1 < a.1 { b } else { a },
+            );
+
+        let bnb = BranchAndBound::new(inner(), vec![0, 1])
+            .with_bounds(vec![-5.0, -5.0], vec![5.0, 5.0])
+            .unwrap();
+        let (param, cost) = run(bnb);
+        assert_eq!(param, expected.0);
+        assert_relative_eq!(cost, expected.1, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_infeasible_bounds() {
+        let bnb = BranchAndBound::new(inner(), vec![0])
+            .with_bounds(vec![0.2, -1.0], vec![0.8, 1.0])
+            .unwrap();
+        let res = Executor::new(Quadratic {}, bnb)
+            .configure(|state| state.param(vec![0.0, 0.0]).max_iters(100))
+            .run()
+            .unwrap();
+        assert_eq!(res.state.get_iter(), 0);
+        assert!(res.state.get_best_cost().is_infinite());
+    }
+}
@@ -63,6 +63,13 @@ impl<F: ArgminFloat> BrentOpt<F> {
     /// Constructor
     ///
     /// The values `min` and `max` must bracket the minimum of the function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::brent::BrentOpt;
+    /// let brent = BrentOpt::new(-1.0f64, 2.0);
+    /// ```
     pub fn new(min: F, max: F) -> Self {
         BrentOpt {
             eps: F::epsilon().sqrt(),
@@ -90,6 +97,14 @@ impl<F: ArgminFloat> BrentOpt<F> {
     /// It is useless to set `eps` to less than the square root of the
     /// machine precision (`F::epsilon().sqrt()`), which is its default
     /// value.  The default value of `t` is `1e-5`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::brent::BrentOpt;
+    /// let brent = BrentOpt::new(-1.0f64, 2.0).set_tolerance(1e-8, 1e-10);
+    /// ```
+    #[must_use]
     pub fn set_tolerance(mut self, eps: F, t: F) -> Self {
         self.eps = eps;
         self.t = t;
@@ -28,9 +28,11 @@
 //! ### References
 //!
 //! <https://en.wikipedia.org/wiki/Brent%27s_method>
+//!
+//! `BrentRoot` is part of the [root finding methods](`crate::solver::rootfinding`) and only
+//! re-exported here.
 
 mod brentopt;
-mod brentroot;
 
+pub use crate::solver::rootfinding::BrentRoot;
 pub use brentopt::BrentOpt;
-pub use brentroot::BrentRoot;
@@ -8,7 +8,10 @@
 use crate::core::{
     ArgminFloat, Error, IterState, Operator, Problem, SerializeAlias, Solver, State, KV,
 };
-use argmin_math::{ArgminConj, ArgminDot, ArgminL2Norm, ArgminMul, ArgminScaledAdd, ArgminSub};
+use crate::solver::conjugategradient::{IdentityPreconditioner, Preconditioner};
+use argmin_math::{
+    ArgminConj, ArgminDot, ArgminL2Norm, ArgminMul, ArgminScaledAdd, ArgminSub, LinearOperator,
+};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 
@@ -21,9 +24,16 @@ use serde::{Deserialize, Serialize};
 ///
 /// Requires an initial parameter vector.
 ///
+/// A [`Preconditioner`] `M` can be set via
+/// [`with_preconditioner`](`ConjugateGradient::with_preconditioner`), in which case the
+/// preconditioned CG method is used. It converges much faster than plain CG if `M^-1 * A` is
+/// better conditioned than `A`, which is essential for stiff systems such as those derived from
+/// PDEs. By default, no preconditioning is applied.
+///
 /// ## Requirements on the optimization problem
 ///
-/// The optimization problem is required to implement [`Operator`].
+/// The optimization problem is required to implement [`Operator`]. Matrices and matrix-free
+/// operators implementing [`LinearOperator`] can be used via [`LinearOperatorProblem`].
 ///
 /// ## Reference
 ///
@@ -31,16 +41,18 @@ use serde::{Deserialize, Serialize};
 /// Springer. ISBN 0-387-30303-0.
 #[derive(Clone)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
-pub struct ConjugateGradient<P, F> {
+pub struct ConjugateGradient<P, F, M = IdentityPreconditioner> {
     /// b (right hand side of `A * x = b`)
     b: P,
+    /// Preconditioner
+    preconditioner: M,
     /// Residual
     r: Option<P>,
     /// p
     p: Option<P>,
     /// previous p
     p_prev: Option<P>,
-    /// r^T * r
+    /// r^T * M^-1 * r
     rtr: F,
 }
 
@@ -62,12 +74,42 @@ where
     pub fn new(b: P) -> Self {
         ConjugateGradient {
             b,
+            preconditioner: IdentityPreconditioner::new(),
             r: None,
             p: None,
             p_prev: None,
             rtr: F::nan(),
         }
     }
+}
+
+impl<P, F, M> ConjugateGradient<P, F, M>
+where
+    F: ArgminFloat,
+{
+    /// Set preconditioner
+    ///
+    /// `preconditioner` must implement [`Preconditioner`], i.e. apply `M^-1` to a vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::conjugategradient::{ConjugateGradient, JacobiPreconditioner};
+    /// # let b = vec![1.0f64, 1.0];
+    /// let preconditioner = JacobiPreconditioner::new(vec![4.0f64, 3.0]);
+    /// let cg: ConjugateGradient<_, f64, _> =
+    ///     ConjugateGradient::new(b).with_preconditioner(preconditioner);
+    /// ```
+    pub fn with_preconditioner<M2>(self, preconditioner: M2) -> ConjugateGradient<P, F, M2> {
+        ConjugateGradient {
+            b: self.b,
+            preconditioner,
+            r: self.r,
+            p: self.p,
+            p_prev: self.p_prev,
+            rtr: self.rtr,
+        }
+    }
 
     /// Return the previous search direction (Needed by [`NewtonCG`](`crate::solver::newton::NewtonCG`))
     ///
@@ -89,9 +131,10 @@ where
     }
 }
 
-impl<P, O, F> Solver<O, IterState<P, (), (), (), F>> for ConjugateGradient<P, F>
+impl<P, O, F, M> Solver<O, IterState<P, (), (), (), F>> for ConjugateGradient<P, F, M>
 where
     O: Operator<Param = P, Output = P>,
+    M: Preconditioner<P>,
     P: Clone
         + SerializeAlias
         + ArgminDot<P, F>
@@ -117,8 +160,9 @@ where
         ))?;
         let ap = problem.apply(init_param)?;
         let r0 = self.b.sub(&ap).mul(&(float!(-1.0)));
-        self.p = Some(r0.mul(&(float!(-1.0))));
-        self.rtr = r0.dot(&r0.conj());
+        let z0 = self.preconditioner.apply_inverse(&r0)?;
+        self.p = Some(z0.mul(&(float!(-1.0))));
+        self.rtr = r0.dot(&z0.conj());
         self.r = Some(r0);
         Ok((state, None))
     }
@@ -146,10 +190,11 @@ where
         ))?;
         let new_param = state_param.scaled_add(&alpha, &p);
         let r = r.scaled_add(&alpha, &apk);
-        let rtr_n = r.dot(&r.conj());
+        let z = self.preconditioner.apply_inverse(&r)?;
+        let rtr_n = r.dot(&z.conj());
         let beta = rtr_n.div(self.rtr);
         self.rtr = rtr_n;
-        let p_n = r.mul(&(float!(-1.0))).scaled_add(&beta, &p);
+        let p_n = z.mul(&(float!(-1.0))).scaled_add(&beta, &p);
         let norm = r.dot(&r.conj()).l2_norm();
 
         self.p = Some(p_n);
@@ -163,6 +208,58 @@ where
     }
 }
 
+/// Wraps a [`LinearOperator`] such that it can be used as [`Operator`]
+///
+/// This allows solving `A * x = b` with [`ConjugateGradient`] when `A` is a matrix or only
+/// available as a routine computing matrix-vector products.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::core::{Executor, State};
+/// # use argmin::solver::conjugategradient::{ConjugateGradient, LinearOperatorProblem};
+/// # use argmin::core::Error;
+/// # fn main() -> Result<(), Error> {
+/// let a = vec![vec![4.0f64, 1.0], vec![1.0, 3.0]];
+/// let cg: ConjugateGradient<_, f64> = ConjugateGradient::new(vec![1.0f64, 2.0]);
+///
+/// let res = Executor::new(LinearOperatorProblem::new(&a), cg)
+///     .configure(|state| state.param(vec![0.0, 0.0]).max_iters(2))
+///     .run()?;
+/// # let x = res.state().get_best_param().unwrap();
+/// # assert!((x[0] - 1.0 / 11.0).abs() < 1e-12 && (x[1] - 7.0 / 11.0).abs() < 1e-12);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct LinearOperatorProblem<'a, L, P> {
+    /// Linear operator
+    operator: &'a L,
+    phantom: std::marker::PhantomData<P>,
+}
+
+impl<'a, L, P> LinearOperatorProblem<'a, L, P> {
+    /// Constructs an instance of [`LinearOperatorProblem`]
+    pub fn new(operator: &'a L) -> Self {
+        LinearOperatorProblem {
+            operator,
+            phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<L, P> Operator for LinearOperatorProblem<'_, L, P>
+where
+    L: LinearOperator<P>,
+{
+    type Param = P;
+    type Output = P;
+
+    fn apply(&self, p: &P) -> Result<P, Error> {
+        LinearOperator::apply(self.operator, p)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -172,11 +269,17 @@ mod tests {
 
     test_trait_impl!(conjugate_gradient, ConjugateGradient<Vec<f64>, f64>);
 
+    test_trait_impl!(
+        linear_operator_problem,
+        LinearOperatorProblem<Vec<Vec<f64>>, Vec<f64>>
+    );
+
     #[test]
     fn test_new() {
         let cg: ConjugateGradient<_, f64> = ConjugateGradient::new(vec![1.0f64, 2.0]);
         let ConjugateGradient {
             b,
+            preconditioner: _,
             r,
             p,
             p_prev,
@@ -190,6 +293,30 @@ mod tests {
         assert!(rtr.is_nan());
     }
 
+    #[test]
+    fn test_preconditioned() {
+        use crate::core::Executor;
+        use crate::solver::conjugategradient::JacobiPreconditioner;
+
+        // Badly scaled system which diagonal scaling turns into the identity
+        let a = vec![
+            vec![1e6, 0.0, 0.0],
+            vec![0.0, 1.0, 0.0],
+            vec![0.0, 0.0, 1e-6],
+        ];
+        let b = vec![1e6, 2.0, 3e-6];
+        let cg: ConjugateGradient<_, f64, _> = ConjugateGradient::new(b)
+            .with_preconditioner(JacobiPreconditioner::new(vec![1e6, 1.0, 1e-6]));
+        let res = Executor::new(LinearOperatorProblem::new(&a), cg)
+            .configure(|state| state.param(vec![0.0; 3]).max_iters(1))
+            .run()
+            .unwrap();
+        let x = res.state().get_best_param().unwrap();
+        assert_relative_eq!(x[0], 1.0, epsilon = 1e-10);
+        assert_relative_eq!(x[1], 2.0, epsilon = 1e-10);
+        assert_relative_eq!(x[2], 3.0, epsilon = 1e-10);
+    }
+
     #[test]
     fn test_get_prev_p_not_initialized() {
         let cg: ConjugateGradient<_, f64> = ConjugateGradient::new(vec![1.0f64, 2.0]);
@@ -239,6 +366,7 @@ mod tests {
 
         let ConjugateGradient {
             b,
+            preconditioner: _,
             r,
             p,
             p_prev,
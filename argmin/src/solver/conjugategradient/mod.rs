@@ -17,8 +17,10 @@
 
 mod cg;
 mod nonlinear_cg;
+mod preconditioner;
 
 pub mod beta;
 
-pub use self::cg::ConjugateGradient;
+pub use self::cg::{ConjugateGradient, LinearOperatorProblem};
 pub use self::nonlinear_cg::NonlinearConjugateGradient;
+pub use self::preconditioner::{IdentityPreconditioner, JacobiPreconditioner, Preconditioner};
@@ -9,6 +9,7 @@ use crate::core::{
     ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, Executor, Gradient, IterState,
     LineSearch, NLCGBetaUpdate, OptimizationResult, Problem, SerializeAlias, Solver, State, KV,
 };
+use crate::solver::linesearch::linesearch_kv;
 use argmin_math::{ArgminAdd, ArgminDot, ArgminL2Norm, ArgminMul};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
@@ -178,6 +179,7 @@ where
         // Run solver
         let OptimizationResult {
             problem: line_problem,
+            solver: linesearch,
             state: mut line_state,
             ..
         } = Executor::new(
@@ -190,6 +192,7 @@ where
         .configure(|state| state.param(xk).gradient(grad.clone()).cost(cur_cost))
         .ctrlc(false)
         .run()?;
+        self.linesearch = linesearch;
 
         // takes care of the counts of function evaluations
         problem.consume_problem(line_problem);
@@ -224,10 +227,13 @@ where
 
         Ok((
             state.param(xk1).cost(cost).gradient(new_grad),
-            Some(kv!("beta" => self.beta;
-             "restart_iter" => restart_iter;
-             "restart_orthogonality" => restart_orthogonality;
-            )),
+            Some(
+                kv!("beta" => self.beta;
+                 "restart_iter" => restart_iter;
+                 "restart_orthogonality" => restart_orthogonality;
+                )
+                .merge(linesearch_kv(&self.linesearch, &line_state)),
+            ),
         ))
     }
 }
@@ -452,8 +458,22 @@ mod tests {
         assert!(kv.is_none());
         let (mut state, kv) = nlcg.next_iter(&mut problem, state).unwrap();
         state.update();
-        let kv2 = kv!("beta" => 0.0; "restart_iter" => false; "restart_orthogonality" => false;);
-        assert_eq!(kv.unwrap(), kv2);
+        let kv = kv.unwrap();
+        assert_eq!(kv.get("beta").unwrap().get_float(), Some(0.0));
+        assert_eq!(kv.get("restart_iter").unwrap().get_bool(), Some(false));
+        assert_eq!(
+            kv.get("restart_orthogonality").unwrap().get_bool(),
+            Some(false)
+        );
+        // The line search only finds a tiny step along the search direction
+        assert_eq!(kv.get("ls_iters").unwrap().get_uint(), Some(356));
+        assert_eq!(kv.get("ls_cost_count").unwrap().get_uint(), Some(357));
+        assert_eq!(kv.get("ls_converged").unwrap().get_bool(), Some(true));
+        assert_relative_eq!(
+            kv.get("ls_step_length").unwrap().get_float().unwrap(),
+            0.9f64.powi(356),
+            max_relative = 1e-12
+        );
         assert_relative_eq!(
             state.param.as_ref().unwrap()[0],
             1.0f64,
@@ -0,0 +1,132 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::Error;
+use argmin_math::ArgminDiv;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Preconditioner `M` of a linear system `A * x = b`
+///
+/// Preconditioned solvers only need the application of the inverse `M^-1` to a vector. `M` should
+/// be symmetric and positive definite and approximate `A` such that `M^-1 * A` is better
+/// conditioned than `A`, while `M^-1 * r` is cheap to compute (for instance an incomplete
+/// Cholesky factorization or a multigrid cycle for systems derived from PDEs).
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::Error;
+/// use argmin::solver::conjugategradient::Preconditioner;
+///
+/// /// Diagonal preconditioner `M = diag(2, 4)`
+/// struct MyPreconditioner {}
+///
+/// impl Preconditioner<Vec<f64>> for MyPreconditioner {
+///     fn apply_inverse(&self, r: &Vec<f64>) -> Result<Vec<f64>, Error> {
+///         Ok(vec![r[0] / 2.0, r[1] / 4.0])
+///     }
+/// }
+/// ```
+pub trait Preconditioner<P> {
+    /// Returns `M^-1 * r`
+    fn apply_inverse(&self, r: &P) -> Result<P, Error>;
+}
+
+/// # Identity preconditioner
+///
+/// `M = I`, i.e. no preconditioning. Serves as the default preconditioner of
+/// [`ConjugateGradient`](`crate::solver::conjugategradient::ConjugateGradient`) and
+/// [`NewtonCG`](`crate::solver::newton::NewtonCG`).
+///
+/// # Example
+///
+/// ```
+/// # use argmin::solver::conjugategradient::{IdentityPreconditioner, Preconditioner};
+/// let preconditioner = IdentityPreconditioner::new();
+/// assert_eq!(preconditioner.apply_inverse(&vec![1.0f64, 2.0]).unwrap(), vec![1.0, 2.0]);
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct IdentityPreconditioner {}
+
+impl IdentityPreconditioner {
+    /// Construct a new instance of [`IdentityPreconditioner`]
+    pub fn new() -> Self {
+        IdentityPreconditioner {}
+    }
+}
+
+impl<P> Preconditioner<P> for IdentityPreconditioner
+where
+    P: Clone,
+{
+    fn apply_inverse(&self, r: &P) -> Result<P, Error> {
+        Ok(r.clone())
+    }
+}
+
+/// # Jacobi preconditioner
+///
+/// `M = diag(d)`, where `d` usually is the diagonal of `A`. All elements of `d` must be positive.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::solver::conjugategradient::{JacobiPreconditioner, Preconditioner};
+/// let preconditioner = JacobiPreconditioner::new(vec![2.0f64, 4.0]);
+/// assert_eq!(preconditioner.apply_inverse(&vec![1.0, 2.0]).unwrap(), vec![0.5, 0.5]);
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct JacobiPreconditioner<P> {
+    /// Diagonal of `M`
+    diagonal: P,
+}
+
+impl<P> JacobiPreconditioner<P> {
+    /// Construct a new instance of [`JacobiPreconditioner`] from the diagonal of `M`
+    pub fn new(diagonal: P) -> Self {
+        JacobiPreconditioner { diagonal }
+    }
+}
+
+impl<P> Preconditioner<P> for JacobiPreconditioner<P>
+where
+    P: ArgminDiv<P, P>,
+{
+    fn apply_inverse(&self, r: &P) -> Result<P, Error> {
+        Ok(r.div(&self.diagonal))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_trait_impl;
+
+    test_trait_impl!(identity_preconditioner, IdentityPreconditioner);
+    test_trait_impl!(jacobi_preconditioner, JacobiPreconditioner<Vec<f64>>);
+
+    #[test]
+    fn test_identity_preconditioner() {
+        let preconditioner = IdentityPreconditioner::new();
+        assert_eq!(
+            preconditioner.apply_inverse(&vec![-2.0f64, 3.0]).unwrap(),
+            vec![-2.0, 3.0]
+        );
+    }
+
+    #[test]
+    fn test_jacobi_preconditioner() {
+        let preconditioner = JacobiPreconditioner::new(vec![2.0f64, 0.5]);
+        assert_eq!(
+            preconditioner.apply_inverse(&vec![-2.0, 3.0]).unwrap(),
+            vec![-1.0, 6.0]
+        );
+    }
+}
@@ -0,0 +1,468 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Cross-entropy method
+//!
+//! Derivative-free optimization by iteratively fitting a sampling distribution to the best
+//! samples. See [`CrossEntropy`] for details.
+//!
+//! ## Reference
+//!
+//! Pieter-Tjerk de Boer, Dirk P. Kroese, Shie Mannor and Reuven Y. Rubinstein (2005). A tutorial
+//! on the cross-entropy method. Annals of Operations Research 134, 19-67.
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, IterState, Problem, SerializeAlias, Solver, State, SyncAlias,
+    TerminationReason, TerminationStatus, KV,
+};
+use crate::solver::evolutionstrategy::standard_normal;
+use rand::prelude::*;
+use rand_xoshiro::Xoshiro256PlusPlus;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Cross-entropy method
+///
+/// Maintains a normal distribution with diagonal covariance over the parameter space. In each
+/// iteration, a number of samples is drawn from the distribution and the cost function is
+/// evaluated for each of them. The mean and standard deviation of the distribution are then
+/// replaced by the mean and standard deviation of the best samples (the elites). Optionally, the
+/// update is smoothed (see [`with_smoothing`](`CrossEntropy::with_smoothing`)):
+///
+/// `mean_{k+1} = alpha * mean_elites + (1 - alpha) * mean_k`
+///
+/// and equivalently for the standard deviation. The method only requires cost function
+/// evaluations and is therefore popular for simulation-based problems and in control.
+///
+/// The initial parameter vector is used as the mean of the initial distribution and needs to be
+/// provided via the `configure` method of the [`Executor`](`crate::core::Executor`). The
+/// algorithm terminates when all standard deviations fall below a tolerance (see
+/// [`with_tolerance_std`](`CrossEntropy::with_tolerance_std`)).
+///
+/// The parameter vector in the state is the best sample of the current iteration.
+///
+/// The `rayon` feature enables parallel computation of the cost function of the samples.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`].
+///
+/// ## Reference
+///
+/// Pieter-Tjerk de Boer, Dirk P. Kroese, Shie Mannor and Reuven Y. Rubinstein (2005). A tutorial
+/// on the cross-entropy method. Annals of Operations Research 134, 19-67.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct CrossEntropy<F, R> {
+    /// Number of samples per iteration
+    num_samples: usize,
+    /// Number of elite samples
+    num_elites: usize,
+    /// Initial standard deviation
+    initial_std: F,
+    /// Smoothing factor of the distribution update
+    smoothing: F,
+    /// Tolerance for the stopping criterion based on the standard deviation
+    tol_std: F,
+    /// Mean of the sampling distribution
+    mean: Vec<F>,
+    /// Standard deviation of the sampling distribution
+    std: Vec<F>,
+    /// Random number generator
+    rng: R,
+}
+
+impl<F> CrossEntropy<F, Xoshiro256PlusPlus>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`CrossEntropy`]
+    ///
+    /// Takes the number of samples per iteration and the number of elite samples, which must lie
+    /// in `[1, num_samples]`.
+    ///
+    /// Uses the `Xoshiro256PlusPlus` RNG internally. For use of another RNG, consider using
+    /// [`CrossEntropy::new_with_rng`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::crossentropy::CrossEntropy;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let cem: CrossEntropy<f64, _> = CrossEntropy::new(100, 10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(num_samples: usize, num_elites: usize) -> Result<Self, Error> {
+        CrossEntropy::new_with_rng(num_samples, num_elites, Xoshiro256PlusPlus::from_entropy())
+    }
+}
+
+impl<F, R> CrossEntropy<F, R>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`CrossEntropy`]
+    ///
+    /// Takes the number of samples per iteration, the number of elite samples, which must lie in
+    /// `[1, num_samples]`, and a RNG which must implement `rand::Rng` (and `serde::Serialize` if
+    /// the `serde1` feature is enabled).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::crossentropy::CrossEntropy;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let my_rng = ();
+    /// let cem: CrossEntropy<f64, _> = CrossEntropy::new_with_rng(100, 10, my_rng)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_with_rng(num_samples: usize, num_elites: usize, rng: R) -> Result<Self, Error> {
+        if num_elites < 1 || num_elites > num_samples {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`CrossEntropy`: Number of elites must be in [1, num_samples]."
+            ));
+        }
+        Ok(CrossEntropy {
+            num_samples,
+            num_elites,
+            initial_std: float!(1.0),
+            smoothing: float!(1.0),
+            tol_std: F::epsilon().sqrt(),
+            mean: vec![],
+            std: vec![],
+            rng,
+        })
+    }
+
+    /// Set the standard deviation of the initial sampling distribution
+    ///
+    /// Must be larger than 0 and defaults to `1.0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::crossentropy::CrossEntropy;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let cem: CrossEntropy<f64, _> = CrossEntropy::new(100, 10)?.with_std(5.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_std(mut self, std: F) -> Result<Self, Error> {
+        if std <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`CrossEntropy`: Standard deviation must be > 0."
+            ));
+        }
+        self.initial_std = std;
+        Ok(self)
+    }
+
+    /// Set the smoothing factor `alpha` of the distribution update
+    ///
+    /// Must lie in `(0, 1]` and defaults to `1.0` (no smoothing). Smaller values slow down the
+    /// adaptation of the distribution and thereby reduce the risk of premature convergence.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::crossentropy::CrossEntropy;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let cem: CrossEntropy<f64, _> = CrossEntropy::new(100, 10)?.with_smoothing(0.7)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_smoothing(mut self, alpha: F) -> Result<Self, Error> {
+        if alpha <= float!(0.0) || alpha > float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`CrossEntropy`: Smoothing factor must be in (0, 1]."
+            ));
+        }
+        self.smoothing = alpha;
+        Ok(self)
+    }
+
+    /// Set tolerance for the stopping criterion based on the standard deviation
+    ///
+    /// The algorithm terminates when all standard deviations of the sampling distribution are
+    /// below this tolerance. Must be non-negative and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::crossentropy::CrossEntropy;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let cem: CrossEntropy<f64, _> = CrossEntropy::new(100, 10)?.with_tolerance_std(1e-6)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance_std(mut self, tol_std: F) -> Result<Self, Error> {
+        if tol_std < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`CrossEntropy`: Standard deviation tolerance must be >= 0."
+            ));
+        }
+        self.tol_std = tol_std;
+        Ok(self)
+    }
+}
+
+impl<O, F, R> Solver<O, IterState<Vec<F>, (), (), (), F>> for CrossEntropy<F, R>
+where
+    O: CostFunction<Param = Vec<F>, Output = F> + SyncAlias,
+    F: ArgminFloat + SyncAlias,
+    R: Rng + SerializeAlias,
+{
+    const NAME: &'static str = "Cross-entropy method";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<Vec<F>, (), (), (), F>,
+    ) -> Result<(IterState<Vec<F>, (), (), (), F>, Option<KV>), Error> {
+        let param = state.get_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`CrossEntropy` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        self.mean = param.clone();
+        self.std = vec![self.initial_std; param.len()];
+
+        let cost = state.get_cost();
+        let cost = if cost.is_infinite() && cost.is_sign_positive() {
+            problem.cost(param)?
+        } else {
+            cost
+        };
+
+        Ok((state.cost(cost), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<Vec<F>, (), (), (), F>,
+    ) -> Result<(IterState<Vec<F>, (), (), (), F>, Option<KV>), Error> {
+        let samples: Vec<Vec<F>> = (0..self.num_samples)
+            .map(|_| {
+                self.mean
+                    .iter()
+                    .zip(self.std.iter())
+                    .map(|(&m, &s)| m + s * standard_normal(&mut self.rng))
+                    .collect()
+            })
+            .collect();
+        let costs = problem.bulk_cost(&samples)?;
+
+        let mut order: Vec<usize> = (0..self.num_samples).collect();
+        order.sort_by(|&a, &b| {
+            costs[a]
+                .partial_cmp(&costs[b])
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        let elites = &order[..self.num_elites];
+
+        // Fit the distribution to the elites
+        let num_elites = F::from_usize(self.num_elites).unwrap();
+        let alpha = self.smoothing;
+        for (i, (mean_i, std_i)) in self.mean.iter_mut().zip(self.std.iter_mut()).enumerate() {
+            let mean = elites
+                .iter()
+                .fold(float!(0.0), |acc, &k| acc + samples[k][i])
+                / num_elites;
+            let var = elites
+                .iter()
+                .fold(float!(0.0), |acc, &k| acc + (samples[k][i] - mean).powi(2))
+                / num_elites;
+            *mean_i = alpha * mean + (float!(1.0) - alpha) * *mean_i;
+            *std_i = alpha * var.sqrt() + (float!(1.0) - alpha) * *std_i;
+        }
+
+        let max_std = self.std.iter().fold(float!(0.0), |acc: F, &s| acc.max(s));
+        let best = order[0];
+        let best_cost = costs[best];
+
+        Ok((
+            state
+                .param(samples.into_iter().nth(best).unwrap())
+                .cost(best_cost),
+            Some(kv!("max_std" => max_std;)),
+        ))
+    }
+
+    fn terminate(&mut self, _state: &IterState<Vec<F>, (), (), (), F>) -> TerminationStatus {
+        if !self.std.is_empty() && self.std.iter().all(|&s| s < self.tol_std) {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{test_utils::TestProblem, ArgminError, Executor};
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(cross_entropy, CrossEntropy<f64, Xoshiro256PlusPlus>);
+
+    struct Sphere {}
+
+    impl CostFunction for Sphere {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(p.iter().map(|x| (x - 1.0).powi(2)).sum())
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let cem: CrossEntropy<f64, _> = CrossEntropy::new(100, 10).unwrap();
+        let CrossEntropy {
+            num_samples,
+            num_elites,
+            initial_std,
+            smoothing,
+            tol_std,
+            mean,
+            std,
+            rng: _,
+        } = cem;
+        assert_eq!(num_samples, 100);
+        assert_eq!(num_elites, 10);
+        assert_eq!(initial_std.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(smoothing.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(tol_std.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert!(mean.is_empty());
+        assert!(std.is_empty());
+
+        for (num_samples, num_elites) in [(100, 0), (10, 11)] {
+            let res: Result<CrossEntropy<f64, _>, _> = CrossEntropy::new(num_samples, num_elites);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`CrossEntropy`: Number of elites must be in [1, num_samples].\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_std() {
+        let cem: CrossEntropy<f64, _> = CrossEntropy::new(100, 10).unwrap();
+        let cem = cem.with_std(5.0).unwrap();
+        assert_eq!(cem.initial_std.to_ne_bytes(), 5.0f64.to_ne_bytes());
+
+        for std in [0.0, -1.0] {
+            let cem: CrossEntropy<f64, _> = CrossEntropy::new(100, 10).unwrap();
+            let res = cem.with_std(std);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`CrossEntropy`: Standard deviation must be > 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_smoothing() {
+        for alpha in [f64::EPSILON, 0.7, 1.0] {
+            let cem: CrossEntropy<f64, _> = CrossEntropy::new(100, 10).unwrap();
+            let cem = cem.with_smoothing(alpha).unwrap();
+            assert_eq!(cem.smoothing.to_ne_bytes(), alpha.to_ne_bytes());
+        }
+
+        for alpha in [0.0, -1.0, 1.0 + f64::EPSILON] {
+            let cem: CrossEntropy<f64, _> = CrossEntropy::new(100, 10).unwrap();
+            let res = cem.with_smoothing(alpha);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`CrossEntropy`: Smoothing factor must be in (0, 1].\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_tolerance_std() {
+        let cem: CrossEntropy<f64, _> = CrossEntropy::new(100, 10).unwrap();
+        let cem = cem.with_tolerance_std(1e-6).unwrap();
+        assert_eq!(cem.tol_std.to_ne_bytes(), 1e-6f64.to_ne_bytes());
+
+        let cem: CrossEntropy<f64, _> = CrossEntropy::new(100, 10).unwrap();
+        let res = cem.with_tolerance_std(-1.0);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`CrossEntropy`: Standard deviation tolerance must be >= 0.\""
+        );
+    }
+
+    #[test]
+    fn test_init_param_not_initialized() {
+        let mut cem: CrossEntropy<f64, _> = CrossEntropy::new(100, 10).unwrap();
+        let res = cem.init(&mut Problem::new(TestProblem::new()), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`CrossEntropy` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_init() {
+        let mut cem: CrossEntropy<f64, _> =
+            CrossEntropy::new(100, 10).unwrap().with_std(2.0).unwrap();
+        let mut problem = Problem::new(Sphere {});
+        let state = IterState::new().param(vec![0.0, 3.0]);
+        let (state, kv) = cem.init(&mut problem, state).unwrap();
+
+        assert!(kv.is_none());
+        assert_eq!(cem.mean, vec![0.0, 3.0]);
+        assert_eq!(cem.std, vec![2.0, 2.0]);
+        assert_eq!(state.get_cost().to_ne_bytes(), 5.0f64.to_ne_bytes());
+        assert_eq!(problem.counts["cost_count"], 1);
+    }
+
+    #[test]
+    fn test_solver() {
+        let cem = CrossEntropy::new_with_rng(100, 10, Xoshiro256PlusPlus::seed_from_u64(42))
+            .unwrap()
+            .with_std(3.0)
+            .unwrap()
+            .with_smoothing(0.7)
+            .unwrap();
+        let res = Executor::new(Sphere {}, cem)
+            .configure(|state| state.param(vec![-2.0, 4.0, 0.0]).max_iters(1000))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        for x in res.state.get_best_param().unwrap() {
+            assert_relative_eq!(*x, 1.0, epsilon = 1e-6);
+        }
+    }
+}
@@ -0,0 +1,595 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Cubic regularization of Newton's method
+//!
+//! Globalizes Newton's method by adding a cubic regularization term to the quadratic model
+//! instead of using a line search or a trust region. See [`CubicRegularization`] for details.
+//!
+//! ## References
+//!
+//! Yurii Nesterov and Boris T. Polyak (2006). Cubic regularization of Newton method and its
+//! global performance. Mathematical Programming 108, 177-205.
+//!
+//! Coralia Cartis, Nicholas I. M. Gould and Philippe L. Toint (2011). Adaptive cubic
+//! regularisation methods for unconstrained optimization. Part I: motivation, convergence and
+//! numerical results. Mathematical Programming 127, 245-295.
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, Gradient, Hessian, IterState, Problem, SerializeAlias,
+    Solver, TerminationReason, TerminationStatus, KV,
+};
+use crate::solver::trustregion::tridiagonal;
+use argmin_math::{
+    ArgminAdd, ArgminDot, ArgminL2Norm, ArgminMul, ArgminScaledAdd, ArgminZeroLike, LinearOperator,
+};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Adaptive cubic regularization (ARC)
+///
+/// In each iteration, a step `s` is computed as an approximate minimizer of the cubic model
+///
+/// `m(s) = f(x) + g^T s + 1/2 s^T H s + sigma/3 ||s||^3`,
+///
+/// where `g` and `H` are the gradient and the Hessian at the current point `x`. The cubic term
+/// keeps the model bounded from below even if `H` is indefinite. The step is accepted if the
+/// ratio `rho` of actual to predicted reduction is at least `eta1`. The regularization parameter
+/// `sigma` is halved after very successful steps (`rho >= eta2`) and doubled after unsuccessful
+/// ones, thereby playing the role of an inverse trust region radius.
+///
+/// The model is minimized over a Krylov subspace which is built with the Lanczos method, such that
+/// only Hessian-vector products are required. The Lanczos iterations stop once the gradient of the
+/// model is sufficiently small relative to the length of the step and the gradient norm, or after
+/// a maximum number of iterations (see
+/// [`with_max_krylov_iters`](`CubicRegularization::with_max_krylov_iters`)).
+///
+/// The algorithm terminates when the norm of the gradient falls below a tolerance (see
+/// [`with_tolerance_grad`](`CubicRegularization::with_tolerance_grad`)).
+///
+/// The initial parameter vector needs to be provided via the `configure` method of the
+/// [`Executor`](`crate::core::Executor`).
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`], [`Gradient`] and
+/// [`Hessian`]. The Hessian only needs to support products with parameter vectors and can be any
+/// [`LinearOperator`], for instance
+/// [`FiniteDiffHessianProduct`](`crate::derivatives::FiniteDiffHessianProduct`).
+///
+/// ## References
+///
+/// Coralia Cartis, Nicholas I. M. Gould and Philippe L. Toint (2011). Adaptive cubic
+/// regularisation methods for unconstrained optimization. Part I: motivation, convergence and
+/// numerical results. Mathematical Programming 127, 245-295.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct CubicRegularization<F> {
+    /// Regularization parameter
+    sigma: F,
+    /// Threshold of `rho` for successful steps
+    eta1: F,
+    /// Threshold of `rho` for very successful steps
+    eta2: F,
+    /// Tolerance for the stopping criterion based on the gradient norm
+    tol_grad: F,
+    /// Maximum number of Lanczos iterations per step
+    max_krylov_iters: u64,
+}
+
+impl<F> CubicRegularization<F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`CubicRegularization`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::cubicregularization::CubicRegularization;
+    /// let arc: CubicRegularization<f64> = CubicRegularization::new();
+    /// ```
+    pub fn new() -> Self {
+        CubicRegularization {
+            sigma: float!(1.0),
+            eta1: float!(0.1),
+            eta2: float!(0.9),
+            tol_grad: F::epsilon().sqrt(),
+            max_krylov_iters: 100,
+        }
+    }
+
+    /// Set the initial regularization parameter `sigma`
+    ///
+    /// Must be larger than 0 and defaults to `1.0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::cubicregularization::CubicRegularization;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let arc: CubicRegularization<f64> = CubicRegularization::new().with_sigma(10.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_sigma(mut self, sigma: F) -> Result<Self, Error> {
+        if sigma <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`CubicRegularization`: sigma must be > 0."
+            ));
+        }
+        self.sigma = sigma;
+        Ok(self)
+    }
+
+    /// Set the thresholds `eta1` and `eta2` of the ratio of actual to predicted reduction for
+    /// successful and very successful steps, respectively
+    ///
+    /// Must satisfy `0 < eta1 <= eta2 < 1`. Defaults to `eta1 = 0.1` and `eta2 = 0.9`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::cubicregularization::CubicRegularization;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let arc: CubicRegularization<f64> = CubicRegularization::new().with_eta(0.2, 0.8)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_eta(mut self, eta1: F, eta2: F) -> Result<Self, Error> {
+        if eta1 <= float!(0.0) || eta1 > eta2 || eta2 >= float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`CubicRegularization`: eta1 and eta2 must satisfy 0 < eta1 <= eta2 < 1."
+            ));
+        }
+        self.eta1 = eta1;
+        self.eta2 = eta2;
+        Ok(self)
+    }
+
+    /// Set tolerance for the stopping criterion based on the gradient norm
+    ///
+    /// Must be non-negative and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::cubicregularization::CubicRegularization;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let arc: CubicRegularization<f64> = CubicRegularization::new().with_tolerance_grad(1e-6)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance_grad(mut self, tol_grad: F) -> Result<Self, Error> {
+        if tol_grad < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`CubicRegularization`: gradient tolerance must be >= 0."
+            ));
+        }
+        self.tol_grad = tol_grad;
+        Ok(self)
+    }
+
+    /// Set maximum number of Lanczos iterations used to compute a step
+    ///
+    /// Must be larger than 0 and defaults to `100`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::cubicregularization::CubicRegularization;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let arc: CubicRegularization<f64> = CubicRegularization::new().with_max_krylov_iters(20)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_max_krylov_iters(mut self, max_krylov_iters: u64) -> Result<Self, Error> {
+        if max_krylov_iters == 0 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`CubicRegularization`: maximum number of Krylov iterations must be > 0."
+            ));
+        }
+        self.max_krylov_iters = max_krylov_iters;
+        Ok(self)
+    }
+}
+
+impl<F> Default for CubicRegularization<F>
+where
+    F: ArgminFloat,
+{
+    fn default() -> Self {
+        CubicRegularization::new()
+    }
+}
+
+impl<O, P, H, F> Solver<O, IterState<P, P, (), H, F>> for CubicRegularization<F>
+where
+    O: CostFunction<Param = P, Output = F>
+        + Gradient<Param = P, Gradient = P>
+        + Hessian<Param = P, Hessian = H>,
+    P: Clone
+        + SerializeAlias
+        + ArgminAdd<P, P>
+        + ArgminMul<F, P>
+        + ArgminL2Norm<F>
+        + ArgminDot<P, F>
+        + ArgminScaledAdd<P, F, P>
+        + ArgminZeroLike,
+    H: LinearOperator<P>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Cubic regularization";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, P, (), H, F>,
+    ) -> Result<(IterState<P, P, (), H, F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`CubicRegularization` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+
+        let grad = state
+            .take_gradient()
+            .map(Result::Ok)
+            .unwrap_or_else(|| problem.gradient(&param))?;
+
+        let hessian = state
+            .take_hessian()
+            .map(Result::Ok)
+            .unwrap_or_else(|| problem.hessian(&param))?;
+
+        let cost = state.get_cost();
+        let cost = if cost.is_infinite() && cost.is_sign_positive() {
+            problem.cost(&param)?
+        } else {
+            cost
+        };
+
+        Ok((
+            state
+                .param(param)
+                .cost(cost)
+                .gradient(grad)
+                .hessian(hessian),
+            None,
+        ))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, P, (), H, F>,
+    ) -> Result<(IterState<P, P, (), H, F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`CubicRegularization`: Parameter vector in state not set."
+        ))?;
+
+        let grad = state.take_gradient().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`CubicRegularization`: Gradient in state not set."
+        ))?;
+
+        let hessian = state.take_hessian().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`CubicRegularization`: Hessian in state not set."
+        ))?;
+
+        let cost = state.get_cost();
+        let g_norm = grad.l2_norm();
+
+        // Minimize the cubic model over a growing Krylov subspace built with the Lanczos method
+        let mut q = vec![grad.mul(&(float!(1.0) / g_norm))];
+        let mut alpha: Vec<F> = vec![];
+        let mut beta: Vec<F> = vec![];
+        let (hk, lambda) = loop {
+            let j = alpha.len();
+            let mut w = hessian.apply(&q[j])?;
+            if j > 0 {
+                w = w.scaled_add(&(-beta[j - 1]), &q[j - 1]);
+            }
+            let alpha_j: F = q[j].dot(&w);
+            let w = w.scaled_add(&(-alpha_j), &q[j]);
+            let beta_j = w.l2_norm();
+            alpha.push(alpha_j);
+
+            let (hk, lambda) = tridiagonal::cubic_regularization(&alpha, &beta, g_norm, self.sigma);
+
+            // Norm of the gradient of the model: beta_j * |h_j|
+            let residual = beta_j * hk[j].abs();
+            let tol = float!(0.1) * float!(1.0f64).min(tridiagonal::norm(&hk)) * g_norm;
+            if residual <= tol
+                || beta_j <= F::epsilon() * g_norm
+                || alpha.len() as u64 >= self.max_krylov_iters
+            {
+                break (hk, lambda);
+            }
+
+            beta.push(beta_j);
+            q.push(w.mul(&(float!(1.0) / beta_j)));
+        };
+        let krylov_iters = alpha.len() as u64;
+
+        let step = q
+            .iter()
+            .zip(hk.iter())
+            .fold(q[0].zero_like(), |acc, (qi, hi)| acc.scaled_add(hi, qi));
+        let step_norm = tridiagonal::norm(&hk);
+        let model = tridiagonal::model(&alpha, &beta, g_norm, &hk)
+            + self.sigma / float!(3.0) * step_norm.powi(3);
+
+        let new_param = param.add(&step);
+        let new_cost = problem.cost(&new_param)?;
+        let rho = (cost - new_cost) / (-model);
+
+        let cur_sigma = self.sigma;
+        self.sigma = if rho >= self.eta2 {
+            (float!(0.5) * self.sigma).max(F::epsilon())
+        } else if rho < self.eta1 {
+            float!(2.0) * self.sigma
+        } else {
+            self.sigma
+        };
+
+        let kv = kv!(
+            "sigma" => cur_sigma;
+            "rho" => rho;
+            "krylov_iters" => krylov_iters;
+            "lambda" => lambda;
+        );
+
+        Ok((
+            if rho >= self.eta1 {
+                let grad = problem.gradient(&new_param)?;
+                let hessian = problem.hessian(&new_param)?;
+                state
+                    .param(new_param)
+                    .cost(new_cost)
+                    .gradient(grad)
+                    .hessian(hessian)
+            } else {
+                state
+                    .param(param)
+                    .cost(cost)
+                    .gradient(grad)
+                    .hessian(hessian)
+            },
+            Some(kv),
+        ))
+    }
+
+    fn terminate(&mut self, state: &IterState<P, P, (), H, F>) -> TerminationStatus {
+        match state.get_gradient() {
+            Some(grad) if grad.l2_norm() < self.tol_grad => {
+                TerminationStatus::Terminated(TerminationReason::SolverConverged)
+            }
+            _ => TerminationStatus::NotTerminated,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::test_utils::TestProblem;
+    use crate::core::{ArgminError, Executor, State};
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+    use argmin_testfunctions::{rosenbrock_2d, rosenbrock_2d_derivative, rosenbrock_2d_hessian};
+
+    test_trait_impl!(cubic_regularization, CubicRegularization<f64>);
+
+    #[test]
+    fn test_new() {
+        let arc: CubicRegularization<f64> = CubicRegularization::new();
+        let CubicRegularization {
+            sigma,
+            eta1,
+            eta2,
+            tol_grad,
+            max_krylov_iters,
+        } = arc;
+
+        assert_eq!(sigma.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(eta1.to_ne_bytes(), 0.1f64.to_ne_bytes());
+        assert_eq!(eta2.to_ne_bytes(), 0.9f64.to_ne_bytes());
+        assert_eq!(tol_grad.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert_eq!(max_krylov_iters, 100);
+    }
+
+    #[test]
+    fn test_with_sigma() {
+        // correct parameters
+        for sigma in [f64::EPSILON, 1e-2, 1.0, 100.0] {
+            let arc: CubicRegularization<f64> = CubicRegularization::new();
+            let res = arc.with_sigma(sigma);
+            assert!(res.is_ok());
+
+            let arc = res.unwrap();
+            assert_eq!(arc.sigma.to_ne_bytes(), sigma.to_ne_bytes());
+        }
+
+        // incorrect parameters
+        for sigma in [0.0, -f64::EPSILON, -1.0] {
+            let arc: CubicRegularization<f64> = CubicRegularization::new();
+            let res = arc.with_sigma(sigma);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`CubicRegularization`: sigma must be > 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_eta() {
+        // correct parameters
+        for (eta1, eta2) in [(0.1, 0.9), (0.5, 0.5), (f64::EPSILON, 1.0 - f64::EPSILON)] {
+            let arc: CubicRegularization<f64> = CubicRegularization::new();
+            let res = arc.with_eta(eta1, eta2);
+            assert!(res.is_ok());
+
+            let arc = res.unwrap();
+            assert_eq!(arc.eta1.to_ne_bytes(), eta1.to_ne_bytes());
+            assert_eq!(arc.eta2.to_ne_bytes(), eta2.to_ne_bytes());
+        }
+
+        // incorrect parameters
+        for (eta1, eta2) in [(0.0, 0.9), (-0.1, 0.9), (0.9, 0.1), (0.1, 1.0)] {
+            let arc: CubicRegularization<f64> = CubicRegularization::new();
+            let res = arc.with_eta(eta1, eta2);
+            assert_error!(
+                res,
+                ArgminError,
+                concat!(
+                    "Invalid parameter: \"`CubicRegularization`: ",
+                    "eta1 and eta2 must satisfy 0 < eta1 <= eta2 < 1.\""
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_tolerance_grad() {
+        // correct parameters
+        for tol in [0.0, 1e-10, 1.0] {
+            let arc: CubicRegularization<f64> = CubicRegularization::new();
+            let res = arc.with_tolerance_grad(tol);
+            assert!(res.is_ok());
+
+            let arc = res.unwrap();
+            assert_eq!(arc.tol_grad.to_ne_bytes(), tol.to_ne_bytes());
+        }
+
+        // incorrect parameters
+        for tol in [-f64::EPSILON, -1.0] {
+            let arc: CubicRegularization<f64> = CubicRegularization::new();
+            let res = arc.with_tolerance_grad(tol);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`CubicRegularization`: gradient tolerance must be >= 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_max_krylov_iters() {
+        let arc: CubicRegularization<f64> = CubicRegularization::new();
+        let arc = arc.with_max_krylov_iters(5).unwrap();
+        assert_eq!(arc.max_krylov_iters, 5);
+
+        let arc: CubicRegularization<f64> = CubicRegularization::new();
+        let res = arc.with_max_krylov_iters(0);
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`CubicRegularization`: ",
+                "maximum number of Krylov iterations must be > 0.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_init_param_not_initialized() {
+        let mut arc: CubicRegularization<f64> = CubicRegularization::new();
+        let res = arc.init(&mut Problem::new(TestProblem::new()), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`CubicRegularization` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_init() {
+        let param: Vec<f64> = vec![1.0, 2.0];
+        let mut arc: CubicRegularization<f64> = CubicRegularization::new();
+        let state: IterState<Vec<f64>, Vec<f64>, (), Vec<Vec<f64>>, f64> =
+            IterState::new().param(param.clone());
+        let mut problem = Problem::new(TestProblem::new());
+        let (mut state_out, kv) = arc.init(&mut problem, state).unwrap();
+
+        assert!(kv.is_none());
+        assert_eq!(state_out.take_param().unwrap(), param);
+        assert_eq!(state_out.take_gradient().unwrap(), param);
+        assert_eq!(
+            state_out.take_hessian().unwrap(),
+            vec![param.clone(), param.clone()]
+        );
+        assert_eq!(state_out.get_cost().to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(problem.counts["cost_count"], 1);
+        assert_eq!(problem.counts["gradient_count"], 1);
+        assert_eq!(problem.counts["hessian_count"], 1);
+    }
+
+    #[test]
+    fn test_solver() {
+        struct Rosenbrock {}
+
+        impl CostFunction for Rosenbrock {
+            type Param = Vec<f64>;
+            type Output = f64;
+
+            fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(rosenbrock_2d(p, 1.0, 100.0))
+            }
+        }
+
+        impl Gradient for Rosenbrock {
+            type Param = Vec<f64>;
+            type Gradient = Vec<f64>;
+
+            fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+                Ok(rosenbrock_2d_derivative(p, 1.0, 100.0))
+            }
+        }
+
+        impl Hessian for Rosenbrock {
+            type Param = Vec<f64>;
+            type Hessian = Vec<Vec<f64>>;
+
+            fn hessian(&self, p: &Self::Param) -> Result<Self::Hessian, Error> {
+                let h = rosenbrock_2d_hessian(p, 1.0, 100.0);
+                Ok(vec![vec![h[0], h[1]], vec![h[2], h[3]]])
+            }
+        }
+
+        let arc = CubicRegularization::new()
+            .with_tolerance_grad(1e-8)
+            .unwrap();
+        let res = Executor::new(Rosenbrock {}, arc)
+            .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(1000))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        for p in res.state.get_best_param().unwrap() {
+            assert_relative_eq!(*p, 1.0, epsilon = 1e-6);
+        }
+    }
+}
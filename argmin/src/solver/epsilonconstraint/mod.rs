@@ -0,0 +1,597 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Epsilon-constraint method
+//!
+//! Turns a multi-objective problem into a series of constrained single-objective problems by
+//! minimizing one objective subject to upper bounds on all others. See
+//! [`EpsilonConstraintProblem`] and [`EpsilonConstraint`] for details.
+//!
+//! ## Reference
+//!
+//! Kaisa Miettinen (1999). Nonlinear Multiobjective Optimization.
+//! Springer. ISBN 0-7923-8278-1.
+
+use crate::core::{
+    ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, Executor, Gradient,
+    InequalityConstraint, IterState, Jacobian, MultiCostFunction, OptimizationResult, ParetoState,
+    Problem, SerializeAlias, Solver, State, TerminationReason, TerminationStatus, KV,
+};
+use crate::solver::penalty::{feasible_first_cost, max_violation};
+use crate::solver::weightedsum::push_solution;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Epsilon-constraint problem
+///
+/// Wraps a multi-objective problem with objectives `f_i(x)` and turns it into the constrained
+/// single-objective problem
+///
+/// `min_x f_k(x) s.t. f_i(x) <= epsilon_i for all i != k`
+///
+/// where `k` is the index of the objective to be minimized and `epsilon` holds the upper bounds
+/// of all other objectives (in order, skipping objective `k`). This is the problem the inner
+/// solver of [`EpsilonConstraint`] operates on, which therefore has to be able to handle
+/// [`InequalityConstraint`]s, for instance
+/// [`PenaltyMethod`](`crate::solver::penalty::PenaltyMethod`).
+///
+/// [`CostFunction`] is implemented if the wrapped problem implements [`MultiCostFunction`].
+/// [`InequalityConstraint`] is implemented if the wrapped problem additionally implements
+/// [`Jacobian`], where the Jacobian is given as a vector holding the gradient of each objective.
+/// [`Gradient`] and the Jacobian of the constraints are computed from the Jacobian of the wrapped
+/// problem. If the inner solver does not require
+/// derivatives, [`jacobian`](`Jacobian::jacobian`) may simply return an error.
+#[derive(Clone, Debug)]
+pub struct EpsilonConstraintProblem<O, F> {
+    /// Wrapped problem
+    problem: O,
+    /// Index of the objective to be minimized
+    objective: usize,
+    /// Upper bounds of all other objectives
+    epsilon: Vec<F>,
+}
+
+impl<O, F> EpsilonConstraintProblem<O, F> {
+    /// Construct a new instance of [`EpsilonConstraintProblem`]
+    ///
+    /// Takes the problem, the index of the objective to be minimized and the upper bounds of all
+    /// other objectives.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::epsilonconstraint::EpsilonConstraintProblem;
+    /// # struct UserDefinedProblem {};
+    /// let problem = EpsilonConstraintProblem::new(UserDefinedProblem {}, 0, vec![0.5f64]);
+    /// ```
+    pub fn new(problem: O, objective: usize, epsilon: Vec<F>) -> Self {
+        EpsilonConstraintProblem {
+            problem,
+            objective,
+            epsilon,
+        }
+    }
+
+    /// Return the wrapped problem
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::epsilonconstraint::EpsilonConstraintProblem;
+    /// # struct UserDefinedProblem {};
+    /// # let problem = EpsilonConstraintProblem::new(UserDefinedProblem {}, 0, vec![0.5f64]);
+    /// let inner: UserDefinedProblem = problem.into_inner();
+    /// ```
+    pub fn into_inner(self) -> O {
+        self.problem
+    }
+
+    /// Check that the number of objectives matches the number of bounds
+    fn check_len(&self, num_objectives: usize) -> Result<(), Error> {
+        if num_objectives != self.epsilon.len() + 1 || self.objective >= num_objectives {
+            return Err(argmin_error!(
+                InvalidParameter,
+                format!(
+                    concat!(
+                        "`EpsilonConstraintProblem`: Number of objectives ({}) does not match ",
+                        "number of bounds ({}) or index of objective ({})"
+                    ),
+                    num_objectives,
+                    self.epsilon.len(),
+                    self.objective
+                )
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl<O, P, F> CostFunction for EpsilonConstraintProblem<O, F>
+where
+    O: MultiCostFunction<Param = P, Float = F>,
+    F: ArgminFloat,
+{
+    type Param = P;
+    type Output = F;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        let costs = self.problem.multi_cost(param)?;
+        self.check_len(costs.len())?;
+        Ok(costs[self.objective])
+    }
+}
+
+impl<O, P, G, F> Gradient for EpsilonConstraintProblem<O, F>
+where
+    O: Jacobian<Param = P, Jacobian = Vec<G>>,
+{
+    type Param = P;
+    type Gradient = G;
+
+    fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+        let mut jacobian = self.problem.jacobian(param)?;
+        self.check_len(jacobian.len())?;
+        Ok(jacobian.swap_remove(self.objective))
+    }
+}
+
+impl<O, P, G, F> InequalityConstraint for EpsilonConstraintProblem<O, F>
+where
+    O: MultiCostFunction<Param = P, Float = F> + Jacobian<Param = P, Jacobian = Vec<G>>,
+    F: ArgminFloat,
+{
+    type Param = P;
+    type Gradient = G;
+    type Float = F;
+
+    fn inequality_constraints(&self, param: &Self::Param) -> Result<Vec<F>, Error> {
+        let mut costs = self.problem.multi_cost(param)?;
+        self.check_len(costs.len())?;
+        costs.remove(self.objective);
+        Ok(costs
+            .iter()
+            .zip(self.epsilon.iter())
+            .map(|(c, e)| *c - *e)
+            .collect())
+    }
+
+    fn inequality_jacobian(&self, param: &Self::Param) -> Result<Vec<G>, Error> {
+        let mut jacobian = self.problem.jacobian(param)?;
+        self.check_len(jacobian.len())?;
+        jacobian.remove(self.objective);
+        Ok(jacobian)
+    }
+}
+
+/// # Epsilon-constraint method
+///
+/// Meta-solver which approximates the Pareto front of a multi-objective problem by minimizing a
+/// single objective subject to upper bounds on all other objectives (see
+/// [`EpsilonConstraintProblem`]) for a number of bound vectors. In contrast to the
+/// [`WeightedSum`](`crate::solver::weightedsum::WeightedSum`) method, Pareto optimal points on
+/// nonconvex parts of the Pareto front can be found as well.
+///
+/// The constrained subproblems are solved with an inner solver which is able to handle
+/// [`InequalityConstraint`]s, such as [`PenaltyMethod`](`crate::solver::penalty::PenaltyMethod`)
+/// or [`BarrierMethod`](`crate::solver::barrier::BarrierMethod`).
+///
+/// In each iteration, the subproblem for the next bound vector is solved by running the inner
+/// solver to completion, starting from a copy of the provided initial state of the inner solver.
+/// This state is used to provide the initial parameter vector and the maximum number of
+/// iterations of the inner solver. The solution of each subproblem is stored in the population
+/// of the [`ParetoState`], its non-dominated subset forms the Pareto front. The solver terminates
+/// once all bound vectors have been processed.
+///
+/// The inner solver may fail to satisfy the bounds. Solutions are therefore stored according to
+/// a feasible-first rule: If a solution exceeds any of the bounds by more than the tolerance set
+/// via [`with_tolerance`](`EpsilonConstraint::with_tolerance`), all of its costs are stored as
+/// infinity. Such a solution is dominated by every feasible solution and only remains in the
+/// Pareto front as long as no feasible solution has been found. The value of the minimized
+/// objective and the maximum bound violation are reported via the observers (`objective` and
+/// `max_violation`).
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`MultiCostFunction`] and [`Jacobian`] as
+/// well as all traits the inner solver requires from the [`EpsilonConstraintProblem`].
+///
+/// ## Reference
+///
+/// Kaisa Miettinen (1999). Nonlinear Multiobjective Optimization.
+/// Springer. ISBN 0-7923-8278-1.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct EpsilonConstraint<S, I, F> {
+    /// Inner solver
+    solver: S,
+    /// Index of the objective to be minimized
+    objective: usize,
+    /// Upper bounds of all other objectives
+    bounds: Vec<Vec<F>>,
+    /// Tolerance on the violation of the bounds
+    tol: F,
+    /// Initial state of the inner solver
+    init_state: I,
+}
+
+impl<S, I, F> EpsilonConstraint<S, I, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`EpsilonConstraint`]
+    ///
+    /// Takes the inner solver, the index of the objective to be minimized, the bound vectors and
+    /// the initial state of the inner solver. Each bound vector holds the upper bounds of all
+    /// objectives except the minimized one (in order). All bound vectors must be of the same
+    /// length and the index of the objective must not exceed this length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::epsilonconstraint::EpsilonConstraint;
+    /// # use argmin::solver::neldermead::NelderMead;
+    /// # use argmin::solver::penalty::PenaltyMethod;
+    /// # use argmin::core::{Error, IterState, State};
+    /// # fn main() -> Result<(), Error> {
+    /// let inner: NelderMead<Vec<f64>, f64> = NelderMead::new(vec![vec![0.0], vec![1.0]]);
+    /// let bounds = vec![vec![0.25], vec![1.0], vec![2.25]];
+    /// let init_state: IterState<Vec<f64>, (), (), (), f64> = IterState::new().param(vec![0.0]);
+    /// let penalty: PenaltyMethod<_, f64> = PenaltyMethod::new(inner);
+    /// let solver = EpsilonConstraint::new(penalty, 0, bounds, init_state)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(
+        solver: S,
+        objective: usize,
+        bounds: Vec<Vec<F>>,
+        init_state: I,
+    ) -> Result<Self, Error> {
+        if bounds.is_empty() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`EpsilonConstraint`: At least one bound vector is required."
+            ));
+        }
+        let num_bounds = bounds[0].len();
+        if num_bounds == 0 || bounds.iter().any(|b| b.len() != num_bounds) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`EpsilonConstraint`: Bound vectors must be non-empty and of equal length."
+            ));
+        }
+        if objective > num_bounds {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`EpsilonConstraint`: Index of objective must be <= length of bound vectors."
+            ));
+        }
+        Ok(EpsilonConstraint {
+            solver,
+            objective,
+            bounds,
+            tol: F::epsilon().sqrt(),
+            init_state,
+        })
+    }
+
+    /// Set the tolerance on the violation of the bounds
+    ///
+    /// Solutions which exceed a bound by more than the tolerance are considered infeasible. Must
+    /// be non-negative and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::epsilonconstraint::EpsilonConstraint;
+    /// # use argmin::solver::neldermead::NelderMead;
+    /// # use argmin::solver::penalty::PenaltyMethod;
+    /// # use argmin::core::{Error, IterState, State};
+    /// # fn main() -> Result<(), Error> {
+    /// # let inner: NelderMead<Vec<f64>, f64> = NelderMead::new(vec![vec![0.0], vec![1.0]]);
+    /// # let init_state: IterState<Vec<f64>, (), (), (), f64> = IterState::new().param(vec![0.0]);
+    /// # let penalty: PenaltyMethod<_, f64> = PenaltyMethod::new(inner);
+    /// let solver = EpsilonConstraint::new(penalty, 0, vec![vec![1.0]], init_state)?
+    ///     .with_tolerance(1e-6)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`EpsilonConstraint`: tolerance must be >= 0."
+            ));
+        }
+        self.tol = tol;
+        Ok(self)
+    }
+}
+
+impl<O, S, P, G, J, H, F> Solver<O, ParetoState<P, F>>
+    for EpsilonConstraint<S, IterState<P, G, J, H, F>, F>
+where
+    O: MultiCostFunction<Param = P, Float = F>,
+    S: Clone + Solver<EpsilonConstraintProblem<O, F>, IterState<P, G, J, H, F>>,
+    P: Clone + SerializeAlias + DeserializeOwnedAlias,
+    G: Clone + SerializeAlias + DeserializeOwnedAlias,
+    J: Clone + SerializeAlias + DeserializeOwnedAlias,
+    H: Clone + SerializeAlias + DeserializeOwnedAlias,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Epsilon-constraint method";
+
+    fn init(
+        &mut self,
+        _problem: &mut Problem<O>,
+        state: ParetoState<P, F>,
+    ) -> Result<(ParetoState<P, F>, Option<KV>), Error> {
+        Ok((
+            state
+                .population(vec![], vec![])
+                .pareto_front(vec![], vec![]),
+            None,
+        ))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: ParetoState<P, F>,
+    ) -> Result<(ParetoState<P, F>, Option<KV>), Error> {
+        let epsilon = self
+            .bounds
+            .get(state.get_iter() as usize)
+            .ok_or_else(argmin_error_closure!(
+                PotentialBug,
+                "`EpsilonConstraint`: No bound vector left."
+            ))?
+            .clone();
+        let init_state = self.init_state.clone();
+
+        let OptimizationResult {
+            problem: mut inner_problem,
+            state: mut inner_state,
+            ..
+        } = Executor::new(
+            EpsilonConstraintProblem::new(
+                problem.take_problem().ok_or_else(argmin_error_closure!(
+                    PotentialBug,
+                    "`EpsilonConstraint`: Failed to take `problem` for inner solver"
+                ))?,
+                self.objective,
+                epsilon.clone(),
+            ),
+            self.solver.clone(),
+        )
+        .configure(|_| init_state)
+        .ctrlc(false)
+        .run()?;
+
+        // Get back problem and function evaluation counts
+        problem.problem = inner_problem
+            .take_problem()
+            .map(EpsilonConstraintProblem::into_inner);
+        problem.consume_func_counts(inner_problem);
+
+        let param = inner_state
+            .take_best_param()
+            .ok_or_else(argmin_error_closure!(
+                PotentialBug,
+                "`EpsilonConstraint`: No `param` returned by inner solver"
+            ))?;
+        let mut costs = problem.multi_cost(&param)?;
+        if costs.len() != self.bounds[0].len() + 1 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                format!(
+                    "`EpsilonConstraint`: Number of objectives ({}) does not match length of bound vectors ({}) + 1",
+                    costs.len(),
+                    self.bounds[0].len()
+                )
+            ));
+        }
+        let objective = costs[self.objective];
+        let violation = max_violation(
+            &costs
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i != self.objective)
+                .zip(epsilon.iter())
+                .map(|((_, c), e)| *c - *e)
+                .collect::<Vec<F>>(),
+        );
+        for cost in costs.iter_mut() {
+            *cost = feasible_first_cost(*cost, violation, self.tol);
+        }
+
+        let state = push_solution(state, param, costs);
+        let front_size = state.get_pareto_front().map(Vec::len).unwrap_or(0);
+
+        let kv = kv!(
+            "objective" => objective;
+            "max_violation" => violation;
+            "inner_iters" => inner_state.get_iter();
+            "pareto_front_size" => front_size as u64;
+        );
+
+        Ok((state, Some(kv)))
+    }
+
+    fn terminate(&mut self, state: &ParetoState<P, F>) -> TerminationStatus {
+        if state.get_iter() >= self.bounds.len() as u64 {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+    use crate::solver::linesearch::MoreThuenteLineSearch;
+    use crate::solver::penalty::PenaltyMethod;
+    use crate::solver::quasinewton::LBFGS;
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    type Inner = PenaltyMethod<
+        LBFGS<MoreThuenteLineSearch<Vec<f64>, Vec<f64>, f64>, Vec<f64>, Vec<f64>, f64>,
+        f64,
+    >;
+
+    test_trait_impl!(
+        epsilon_constraint,
+        EpsilonConstraint<Inner, IterState<Vec<f64>, Vec<f64>, (), (), f64>, f64>
+    );
+
+    fn inner() -> Inner {
+        PenaltyMethod::new(LBFGS::new(MoreThuenteLineSearch::new(), 5))
+    }
+
+    /// f_1(x) = x^2, f_2(x) = (x - 2)^2
+    struct Schaffer {}
+
+    impl MultiCostFunction for Schaffer {
+        type Param = Vec<f64>;
+        type Float = f64;
+
+        fn multi_cost(&self, p: &Self::Param) -> Result<Vec<Self::Float>, Error> {
+            Ok(vec![p[0].powi(2), (p[0] - 2.0).powi(2)])
+        }
+    }
+
+    impl Jacobian for Schaffer {
+        type Param = Vec<f64>;
+        type Jacobian = Vec<Vec<f64>>;
+
+        fn jacobian(&self, p: &Self::Param) -> Result<Self::Jacobian, Error> {
+            Ok(vec![vec![2.0 * p[0]], vec![2.0 * (p[0] - 2.0)]])
+        }
+    }
+
+    #[test]
+    fn test_problem() {
+        let problem = EpsilonConstraintProblem::new(Schaffer {}, 1, vec![0.5]);
+        let p = vec![1.0];
+        assert_relative_eq!(problem.cost(&p).unwrap(), 1.0, epsilon = f64::EPSILON);
+        assert_relative_eq!(
+            problem.gradient(&p).unwrap()[0],
+            -2.0,
+            epsilon = f64::EPSILON
+        );
+        let constraints = problem.inequality_constraints(&p).unwrap();
+        assert_eq!(constraints.len(), 1);
+        assert_relative_eq!(constraints[0], 0.5, epsilon = f64::EPSILON);
+        let jacobian = problem.inequality_jacobian(&p).unwrap();
+        assert_eq!(jacobian.len(), 1);
+        assert_relative_eq!(jacobian[0][0], 2.0, epsilon = f64::EPSILON);
+
+        let res = EpsilonConstraintProblem::new(Schaffer {}, 2, vec![0.5]).cost(&p);
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`EpsilonConstraintProblem`: Number of objectives (2) does ",
+                "not match number of bounds (1) or index of objective (2)\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_new() {
+        let init_state: IterState<Vec<f64>, Vec<f64>, (), (), f64> = IterState::new();
+
+        let res = EpsilonConstraint::new(inner(), 0, Vec::<Vec<f64>>::new(), init_state.clone());
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`EpsilonConstraint`: At least one bound vector is required.\""
+        );
+
+        for bounds in [vec![vec![]], vec![vec![1.0, 0.0], vec![1.0]]] {
+            let res = EpsilonConstraint::new(inner(), 0, bounds, init_state.clone());
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`EpsilonConstraint`: Bound vectors must be non-empty and of equal length.\""
+            );
+        }
+
+        let res = EpsilonConstraint::new(inner(), 2, vec![vec![1.0]], init_state.clone());
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`EpsilonConstraint`: Index of objective must be <= length of bound vectors.\""
+        );
+
+        let solver = EpsilonConstraint::new(inner(), 1, vec![vec![1.0]], init_state).unwrap();
+        assert_eq!(solver.objective, 1);
+        assert_eq!(solver.bounds.len(), 1);
+    }
+
+    #[test]
+    fn test_with_tolerance() {
+        let init_state: IterState<Vec<f64>, Vec<f64>, (), (), f64> = IterState::new();
+        let solver = EpsilonConstraint::new(inner(), 0, vec![vec![1.0f64]], init_state).unwrap();
+        assert_eq!(solver.tol.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+
+        for tol in [0.0f64, 1e-10, 1.0] {
+            let solver = solver.clone().with_tolerance(tol).unwrap();
+            assert_eq!(solver.tol.to_ne_bytes(), tol.to_ne_bytes());
+        }
+
+        for tol in [-f64::EPSILON, -1.0] {
+            let res = solver.clone().with_tolerance(tol);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`EpsilonConstraint`: tolerance must be >= 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_solver_infeasible() {
+        // (x - 2)^2 <= -1 cannot be satisfied
+        let bounds = vec![vec![-1.0], vec![1.0]];
+        let init_state = IterState::new().param(vec![3.0]).max_iters(10);
+        let solver = EpsilonConstraint::new(inner(), 0, bounds, init_state).unwrap();
+        let res = Executor::new(Schaffer {}, solver).run().unwrap();
+
+        let population_costs = res.state.get_population_costs().unwrap();
+        assert_eq!(population_costs.len(), 2);
+        assert!(population_costs[0].iter().all(|c| c.is_infinite()));
+        assert!(population_costs[1].iter().all(|c| c.is_finite()));
+        // Only the feasible solution is part of the Pareto front
+        let front = res.state.get_pareto_front().unwrap();
+        assert_eq!(front.len(), 1);
+        assert_relative_eq!(front[0][0], 1.0, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn test_solver() {
+        let bounds = vec![vec![0.25], vec![1.0], vec![2.25]];
+        let init_state = IterState::new().param(vec![3.0]).max_iters(50);
+        let solver = EpsilonConstraint::new(inner(), 0, bounds, init_state).unwrap();
+        let res = Executor::new(Schaffer {}, solver).run().unwrap();
+
+        assert_eq!(res.state.get_iter(), 3);
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        // min x^2 s.t. (x - 2)^2 <= epsilon is solved by x = 2 - sqrt(epsilon)
+        let population = res.state.get_population().unwrap();
+        for (x, expected) in population.iter().zip([1.5, 1.0, 0.5]) {
+            assert_relative_eq!(x[0], expected, epsilon = 1e-4);
+        }
+        assert_eq!(res.state.get_pareto_front().unwrap().len(), 3);
+        assert_eq!(res.problem.counts["multi_cost_count"], 3);
+    }
+}
@@ -0,0 +1,628 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Evolution strategies
+//!
+//! Classical `(μ/ρ, λ)` and `(μ/ρ + λ)` evolution strategies with self-adaptive mutation step
+//! sizes. See [`EvolutionStrategy`] for details.
+//!
+//! ## Reference
+//!
+//! Hans-Georg Beyer and Hans-Paul Schwefel (2002). Evolution strategies – A comprehensive
+//! introduction. Natural Computing 1, 3-52.
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, PopulationState, Problem, SerializeAlias, Solver, SyncAlias,
+    KV,
+};
+use rand::prelude::*;
+use rand_xoshiro::Xoshiro256PlusPlus;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Sample from the standard normal distribution using the Box-Muller transform
+pub(crate) fn standard_normal<F: ArgminFloat, R: Rng>(rng: &mut R) -> F {
+    // `gen` samples from [0, 1), therefore `u1` lies in (0, 1]
+    let u1: f64 = 1.0 - rng.gen::<f64>();
+    let u2: f64 = rng.gen();
+    float!((-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos())
+}
+
+/// Selection scheme of [`EvolutionStrategy`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub enum Selection {
+    /// `(μ, λ)`: The parents of the next generation are selected from the offspring only
+    #[default]
+    Comma,
+    /// `(μ + λ)`: The parents of the next generation are selected from both the parents and the
+    /// offspring (elitist)
+    Plus,
+}
+
+/// A single individual of an [`EvolutionStrategy`]
+///
+/// Consists of the parameter vector, one mutation step size per parameter and the cost function
+/// value.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct Individual<F> {
+    /// Parameter vector
+    pub param: Vec<F>,
+    /// Mutation step sizes
+    pub step_sizes: Vec<F>,
+    /// Cost function value
+    pub cost: F,
+}
+
+impl<F> Individual<F>
+where
+    F: ArgminFloat,
+{
+    /// Create a new individual with a given parameter vector.
+    ///
+    /// The step sizes are set to the initial step size of the [`EvolutionStrategy`] and the cost
+    /// function value is computed during initialization of the solver.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::evolutionstrategy::Individual;
+    /// let individual: Individual<f64> = Individual::new(vec![0.0, 1.4]);
+    /// ```
+    pub fn new(param: Vec<F>) -> Self {
+        Individual {
+            param,
+            step_sizes: vec![],
+            cost: F::infinity(),
+        }
+    }
+}
+
+/// # Evolution strategy
+///
+/// In each iteration (generation), `λ` offspring are created from a population of `μ` parents.
+/// For each offspring, `ρ` parents are chosen at random and their parameter vectors and step sizes
+/// are averaged (intermediate recombination). The offspring is then mutated by adding normally
+/// distributed noise to each parameter, where the standard deviation is given by the step size of
+/// the respective parameter. The step sizes themselves are mutated beforehand with a log-normal
+/// distribution (self-adaptation):
+///
+/// `s_i' = s_i * exp(τ' * N(0, 1) + τ * N_i(0, 1))`,
+///
+/// `x_i' = x_i + s_i' * N_i(0, 1)`,
+///
+/// with `τ' = 1/sqrt(2n)` and `τ = 1/sqrt(2 sqrt(n))`, where `n` is the number of parameters.
+/// Thereby, step sizes which lead to good offspring are inherited. As each parameter has its own
+/// step size, the method adapts well to separable problems with differently scaled parameters.
+///
+/// The `μ` best individuals form the next generation. With [`Selection::Comma`] (default), they
+/// are chosen from the offspring only, which allows the strategy to forget misadapted step sizes.
+/// With [`Selection::Plus`] they are chosen from both parents and offspring, which guarantees that
+/// the best individual is never lost.
+///
+/// The initial population needs to be provided via the `configure` method of the
+/// [`Executor`](`crate::core::Executor`), either as a full population of `μ` individuals via
+/// [`PopulationState::population`] or as a single [`Individual`] via
+/// [`PopulationState::individual`], which is then copied `μ` times.
+///
+/// The algorithm has no convergence criterion on its own and stops when the maximum number of
+/// iterations or the target cost is reached.
+///
+/// The `rayon` feature enables parallel computation of the cost function of the offspring.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`].
+///
+/// ## Reference
+///
+/// Hans-Georg Beyer and Hans-Paul Schwefel (2002). Evolution strategies – A comprehensive
+/// introduction. Natural Computing 1, 3-52.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct EvolutionStrategy<F, R> {
+    /// Number of parents
+    mu: usize,
+    /// Number of parents involved in the recombination of one offspring
+    rho: usize,
+    /// Number of offspring
+    lambda: usize,
+    /// Selection scheme
+    selection: Selection,
+    /// Initial mutation step size
+    step_size: F,
+    /// Random number generator
+    rng: R,
+}
+
+impl<F> EvolutionStrategy<F, Xoshiro256PlusPlus>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`EvolutionStrategy`]
+    ///
+    /// Takes the number of parents `μ`, which must be at least 1, and the number of offspring
+    /// `λ`, which must be at least `μ`.
+    ///
+    /// Uses the `Xoshiro256PlusPlus` RNG internally. For use of another RNG, consider using
+    /// [`EvolutionStrategy::new_with_rng`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::evolutionstrategy::EvolutionStrategy;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let es: EvolutionStrategy<f64, _> = EvolutionStrategy::new(5, 35)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(mu: usize, lambda: usize) -> Result<Self, Error> {
+        EvolutionStrategy::new_with_rng(mu, lambda, Xoshiro256PlusPlus::from_entropy())
+    }
+}
+
+impl<F, R> EvolutionStrategy<F, R>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`EvolutionStrategy`]
+    ///
+    /// Takes the number of parents `μ`, which must be at least 1, the number of offspring `λ`,
+    /// which must be at least `μ`, and a RNG which must implement `rand::Rng` (and
+    /// `serde::Serialize` if the `serde1` feature is enabled).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::evolutionstrategy::EvolutionStrategy;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let my_rng = ();
+    /// let es: EvolutionStrategy<f64, _> = EvolutionStrategy::new_with_rng(5, 35, my_rng)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_with_rng(mu: usize, lambda: usize, rng: R) -> Result<Self, Error> {
+        if mu < 1 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`EvolutionStrategy`: Number of parents must be >= 1."
+            ));
+        }
+        if lambda < mu {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`EvolutionStrategy`: Number of offspring must be >= number of parents."
+            ));
+        }
+        Ok(EvolutionStrategy {
+            mu,
+            rho: mu.min(2),
+            lambda,
+            selection: Selection::Comma,
+            step_size: float!(1.0),
+            rng,
+        })
+    }
+
+    /// Set the number of parents `ρ` which are recombined to create one offspring
+    ///
+    /// Must be in `[1, μ]` and defaults to `min(2, μ)`. With `ρ = 1`, offspring are mutated copies
+    /// of a single parent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::evolutionstrategy::EvolutionStrategy;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let es: EvolutionStrategy<f64, _> = EvolutionStrategy::new(5, 35)?.with_recombination(5)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_recombination(mut self, rho: usize) -> Result<Self, Error> {
+        if rho < 1 || rho > self.mu {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`EvolutionStrategy`: Number of recombined parents must be in [1, mu]."
+            ));
+        }
+        self.rho = rho;
+        Ok(self)
+    }
+
+    /// Set the selection scheme
+    ///
+    /// Defaults to [`Selection::Comma`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::evolutionstrategy::{EvolutionStrategy, Selection};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let es: EvolutionStrategy<f64, _> =
+    ///     EvolutionStrategy::new(5, 35)?.with_selection(Selection::Plus);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_selection(mut self, selection: Selection) -> Self {
+        self.selection = selection;
+        self
+    }
+
+    /// Set the initial mutation step size
+    ///
+    /// Used for all individuals provided without step sizes. Must be larger than 0 and defaults
+    /// to `1.0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::evolutionstrategy::EvolutionStrategy;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let es: EvolutionStrategy<f64, _> = EvolutionStrategy::new(5, 35)?.with_step_size(0.1)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_step_size(mut self, step_size: F) -> Result<Self, Error> {
+        if step_size <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`EvolutionStrategy`: Step size must be > 0."
+            ));
+        }
+        self.step_size = step_size;
+        Ok(self)
+    }
+}
+
+impl<F, R> EvolutionStrategy<F, R>
+where
+    F: ArgminFloat,
+    R: Rng,
+{
+    /// Creates one offspring via intermediate recombination and self-adaptive mutation
+    fn offspring(&mut self, parents: &[Individual<F>]) -> Individual<F> {
+        let n = parents[0].param.len();
+        let chosen: Vec<&Individual<F>> =
+            parents.choose_multiple(&mut self.rng, self.rho).collect();
+        let rho = F::from_usize(self.rho).unwrap();
+        let mean = |select: fn(&Individual<F>) -> &Vec<F>| -> Vec<F> {
+            (0..n)
+                .map(|i| {
+                    chosen
+                        .iter()
+                        .fold(float!(0.0), |acc, ind| acc + select(ind)[i])
+                        / rho
+                })
+                .collect()
+        };
+        let param = mean(|ind| &ind.param);
+        let step_sizes = mean(|ind| &ind.step_sizes);
+
+        let n_f = F::from_usize(n).unwrap();
+        let tau_global = float!(1.0) / (float!(2.0) * n_f).sqrt();
+        let tau_local = float!(1.0) / (float!(2.0) * n_f.sqrt()).sqrt();
+        let global: F = tau_global * standard_normal(&mut self.rng);
+
+        let step_sizes: Vec<F> = step_sizes
+            .into_iter()
+            .map(|s| {
+                let local: F = tau_local * standard_normal(&mut self.rng);
+                (s * (global + local).exp()).max(F::min_positive_value())
+            })
+            .collect();
+        let param = param
+            .into_iter()
+            .zip(step_sizes.iter())
+            .map(|(x, &s)| x + s * standard_normal(&mut self.rng))
+            .collect();
+
+        Individual {
+            param,
+            step_sizes,
+            cost: F::infinity(),
+        }
+    }
+}
+
+/// Sorts individuals by their cost function values such that the first one is the best one
+fn sort_by_cost<F: ArgminFloat>(individuals: &mut [Individual<F>]) {
+    individuals.sort_by(|a, b| {
+        a.cost
+            .partial_cmp(&b.cost)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+}
+
+impl<O, F, R> Solver<O, PopulationState<Individual<F>, F>> for EvolutionStrategy<F, R>
+where
+    O: CostFunction<Param = Vec<F>, Output = F> + SyncAlias,
+    F: ArgminFloat + SyncAlias,
+    R: Rng + SerializeAlias,
+{
+    const NAME: &'static str = "Evolution strategy";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: PopulationState<Individual<F>, F>,
+    ) -> Result<(PopulationState<Individual<F>, F>, Option<KV>), Error> {
+        let mut population = match (state.take_population(), state.take_individual()) {
+            (Some(population), _) if population.len() == self.mu => population,
+            (Some(population), _) => {
+                return Err(argmin_error!(
+                    InvalidParameter,
+                    format!(
+                        "`EvolutionStrategy`: Provided population is of length {}, expected {}",
+                        population.len(),
+                        self.mu
+                    )
+                ))
+            }
+            (None, Some(individual)) => vec![individual; self.mu],
+            (None, None) => {
+                return Err(argmin_error!(
+                    NotInitialized,
+                    concat!(
+                        "`EvolutionStrategy` requires an initial population or individual. ",
+                        "Please provide either via `Executor`s `configure` method."
+                    )
+                ))
+            }
+        };
+
+        for individual in population.iter_mut() {
+            if individual.step_sizes.is_empty() {
+                individual.step_sizes = vec![self.step_size; individual.param.len()];
+            }
+        }
+
+        let params: Vec<&Vec<F>> = population.iter().map(|ind| &ind.param).collect();
+        let costs = problem.bulk_cost(&params)?;
+        for (individual, cost) in population.iter_mut().zip(costs) {
+            individual.cost = cost;
+        }
+        sort_by_cost(&mut population);
+
+        Ok((
+            state
+                .individual(population[0].clone())
+                .cost(population[0].cost)
+                .population(population),
+            None,
+        ))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: PopulationState<Individual<F>, F>,
+    ) -> Result<(PopulationState<Individual<F>, F>, Option<KV>), Error> {
+        let parents = state.take_population().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`EvolutionStrategy`: No population in state."
+        ))?;
+
+        let mut offspring: Vec<Individual<F>> =
+            (0..self.lambda).map(|_| self.offspring(&parents)).collect();
+
+        let params: Vec<&Vec<F>> = offspring.iter().map(|ind| &ind.param).collect();
+        let costs = problem.bulk_cost(&params)?;
+        for (individual, cost) in offspring.iter_mut().zip(costs) {
+            individual.cost = cost;
+        }
+
+        let mut population = match self.selection {
+            Selection::Comma => offspring,
+            Selection::Plus => parents.into_iter().chain(offspring).collect(),
+        };
+        sort_by_cost(&mut population);
+        population.truncate(self.mu);
+
+        let mean_step_size = population
+            .iter()
+            .flat_map(|ind| ind.step_sizes.iter())
+            .fold(float!(0.0), |acc, &s| acc + s)
+            / F::from_usize(population.len() * population[0].step_sizes.len()).unwrap();
+
+        Ok((
+            state
+                .individual(population[0].clone())
+                .cost(population[0].cost)
+                .population(population),
+            Some(kv!("mean_step_size" => mean_step_size;)),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{test_utils::TestProblem, ArgminError, Executor, State};
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(evolution_strategy, EvolutionStrategy<f64, Xoshiro256PlusPlus>);
+
+    struct Sphere {}
+
+    impl CostFunction for Sphere {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(p.iter().map(|x| x.powi(2)).sum())
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let es: EvolutionStrategy<f64, _> = EvolutionStrategy::new(5, 35).unwrap();
+        let EvolutionStrategy {
+            mu,
+            rho,
+            lambda,
+            selection,
+            step_size,
+            rng: _,
+        } = es;
+        assert_eq!(mu, 5);
+        assert_eq!(rho, 2);
+        assert_eq!(lambda, 35);
+        assert_eq!(selection, Selection::Comma);
+        assert_eq!(step_size.to_ne_bytes(), 1.0f64.to_ne_bytes());
+
+        let es: EvolutionStrategy<f64, _> = EvolutionStrategy::new(1, 1).unwrap();
+        assert_eq!(es.rho, 1);
+
+        let res: Result<EvolutionStrategy<f64, _>, _> = EvolutionStrategy::new(0, 10);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`EvolutionStrategy`: Number of parents must be >= 1.\""
+        );
+
+        let res: Result<EvolutionStrategy<f64, _>, _> = EvolutionStrategy::new(5, 4);
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`EvolutionStrategy`: ",
+                "Number of offspring must be >= number of parents.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_with_recombination() {
+        for rho in [1, 3, 5] {
+            let es: EvolutionStrategy<f64, _> = EvolutionStrategy::new(5, 35).unwrap();
+            let es = es.with_recombination(rho).unwrap();
+            assert_eq!(es.rho, rho);
+        }
+
+        for rho in [0, 6] {
+            let es: EvolutionStrategy<f64, _> = EvolutionStrategy::new(5, 35).unwrap();
+            let res = es.with_recombination(rho);
+            assert_error!(
+                res,
+                ArgminError,
+                concat!(
+                    "Invalid parameter: \"`EvolutionStrategy`: ",
+                    "Number of recombined parents must be in [1, mu].\""
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_selection() {
+        let es: EvolutionStrategy<f64, _> = EvolutionStrategy::new(5, 35).unwrap();
+        assert_eq!(
+            es.with_selection(Selection::Plus).selection,
+            Selection::Plus
+        );
+    }
+
+    #[test]
+    fn test_with_step_size() {
+        let es: EvolutionStrategy<f64, _> = EvolutionStrategy::new(5, 35).unwrap();
+        let es = es.with_step_size(0.1).unwrap();
+        assert_eq!(es.step_size.to_ne_bytes(), 0.1f64.to_ne_bytes());
+
+        for step_size in [0.0, -1.0] {
+            let es: EvolutionStrategy<f64, _> = EvolutionStrategy::new(5, 35).unwrap();
+            let res = es.with_step_size(step_size);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`EvolutionStrategy`: Step size must be > 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_init_not_initialized() {
+        let mut es: EvolutionStrategy<f64, _> = EvolutionStrategy::new(5, 35).unwrap();
+        let res = es.init(
+            &mut Problem::new(TestProblem::new()),
+            PopulationState::new(),
+        );
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`EvolutionStrategy` requires an initial population or ",
+                "individual. Please provide either via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_init_population_wrong_size() {
+        let mut es: EvolutionStrategy<f64, _> = EvolutionStrategy::new(3, 10).unwrap();
+        let state = PopulationState::new().population(vec![Individual::new(vec![1.0, 2.0]); 2]);
+        let res = es.init(&mut Problem::new(TestProblem::new()), state);
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`EvolutionStrategy`: ",
+                "Provided population is of length 2, expected 3\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_init_individual() {
+        let mut es: EvolutionStrategy<f64, _> = EvolutionStrategy::new(3, 10)
+            .unwrap()
+            .with_step_size(0.5)
+            .unwrap();
+        let state = PopulationState::new().individual(Individual::new(vec![1.0, 2.0]));
+        let mut problem = Problem::new(Sphere {});
+        let (mut state, kv) = es.init(&mut problem, state).unwrap();
+
+        assert!(kv.is_none());
+        let population = state.take_population().unwrap();
+        assert_eq!(population.len(), 3);
+        for individual in population {
+            assert_eq!(individual.param, vec![1.0, 2.0]);
+            assert_eq!(individual.step_sizes, vec![0.5, 0.5]);
+            assert_eq!(individual.cost.to_ne_bytes(), 5.0f64.to_ne_bytes());
+        }
+        assert_eq!(state.get_cost().to_ne_bytes(), 5.0f64.to_ne_bytes());
+        assert_eq!(problem.counts["cost_count"], 3);
+    }
+
+    #[test]
+    fn test_solver() {
+        for selection in [Selection::Comma, Selection::Plus] {
+            let es = EvolutionStrategy::new_with_rng(5, 35, Xoshiro256PlusPlus::seed_from_u64(42))
+                .unwrap()
+                .with_selection(selection);
+            let res = Executor::new(Sphere {}, es)
+                .configure(|state| {
+                    state
+                        .individual(Individual::new(vec![3.0, -2.0, 1.0, 4.0, -5.0]))
+                        .max_iters(500)
+                })
+                .run()
+                .unwrap();
+            assert_eq!(res.problem.counts["cost_count"], 5 + 500 * 35);
+            for x in &res.state.get_best_param().unwrap().param {
+                assert_relative_eq!(*x, 0.0, epsilon = 1e-6);
+            }
+        }
+    }
+}
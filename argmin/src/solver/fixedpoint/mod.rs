@@ -0,0 +1,538 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Fixed-point iteration
+//!
+//! Solves equations of the form `x = g(x)` by iterating `x_{k+1} = g(x_k)`, optionally
+//! accelerated by Aitken's delta-squared process or Anderson mixing.
+//! See [`FixedPointIteration`] for details.
+//!
+//! ## References
+//!
+//! <https://en.wikipedia.org/wiki/Fixed-point_iteration>
+//!
+//! Homer F. Walker and Peng Ni (2011). Anderson acceleration for fixed-point iterations.
+//! SIAM Journal on Numerical Analysis 49 (4), 1715-1735.
+
+use crate::core::{
+    ArgminFloat, Error, IterState, Operator, Problem, Solver, State, TerminationReason,
+    TerminationStatus, KV,
+};
+use argmin_math::{ArgminDot, ArgminL2Norm, ArgminScaledSub, ArgminSub};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Acceleration schemes of [`FixedPointIteration`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub enum Acceleration {
+    /// Plain fixed-point iteration `x_{k+1} = g(x_k)`
+    #[default]
+    None,
+    /// Aitken's delta-squared process in its vector form (Irons-Tuck). Each iteration evaluates
+    /// `g` twice and extrapolates from the two resulting steps.
+    Aitken,
+    /// Anderson mixing with the given depth, which combines the last `depth` iterates such that
+    /// the linearized residual is minimized in a least-squares sense.
+    Anderson(usize),
+}
+
+/// # Fixed-point iteration
+///
+/// Finds a fixed point `x = g(x)` of an operator `g` by iterating `x_{k+1} = g(x_k)`. This
+/// converges if `g` is a contraction close to the fixed point, which is typically the case for
+/// the self-consistency loops found in physics codes. Convergence of the plain iteration is only
+/// linear and may be slow. It can be sped up by [`Acceleration::Aitken`] or
+/// [`Acceleration::Anderson`] (see [`with_acceleration`](`FixedPointIteration::with_acceleration`)).
+///
+/// The cost of the state is the norm of the residual `||g(x) - x||` at the current iterate and
+/// the solver terminates once it falls below the tolerance (see
+/// [`with_tolerance`](`FixedPointIteration::with_tolerance`)).
+///
+/// The initial guess needs to be provided via the `configure` method of the
+/// [`Executor`](`crate::core::Executor`).
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`Operator`], which applies `g`.
+///
+/// ## References
+///
+/// <https://en.wikipedia.org/wiki/Fixed-point_iteration>
+///
+/// Homer F. Walker and Peng Ni (2011). Anderson acceleration for fixed-point iterations.
+/// SIAM Journal on Numerical Analysis 49 (4), 1715-1735.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct FixedPointIteration<P, F> {
+    /// Tolerance on the norm of the residual
+    tol: F,
+    /// Acceleration scheme
+    acceleration: Acceleration,
+    /// `g(x)` at the current iterate
+    gx: Option<P>,
+    /// Residual `g(x) - x` at the current iterate
+    residual: Option<P>,
+    /// Past values of `g(x)` and the residuals (Anderson mixing only)
+    history: VecDeque<(P, P)>,
+}
+
+impl<P, F> FixedPointIteration<P, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`FixedPointIteration`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::fixedpoint::FixedPointIteration;
+    /// let fixed_point: FixedPointIteration<Vec<f64>, f64> = FixedPointIteration::new();
+    /// ```
+    pub fn new() -> Self {
+        FixedPointIteration {
+            tol: F::epsilon().sqrt(),
+            acceleration: Acceleration::None,
+            gx: None,
+            residual: None,
+            history: VecDeque::new(),
+        }
+    }
+
+    /// Set tolerance on the norm of the residual `||g(x) - x||`.
+    ///
+    /// Must be larger than `0` and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::fixedpoint::FixedPointIteration;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let fixed_point: FixedPointIteration<Vec<f64>, f64> =
+    ///     FixedPointIteration::new().with_tolerance(1e-12)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`FixedPointIteration`: Tolerance must be larger than 0."
+            ));
+        }
+        self.tol = tol;
+        Ok(self)
+    }
+
+    /// Set acceleration scheme.
+    ///
+    /// Defaults to [`Acceleration::None`]. The depth of [`Acceleration::Anderson`] must be larger
+    /// than `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::fixedpoint::{Acceleration, FixedPointIteration};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let fixed_point: FixedPointIteration<Vec<f64>, f64> =
+    ///     FixedPointIteration::new().with_acceleration(Acceleration::Anderson(5))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_acceleration(mut self, acceleration: Acceleration) -> Result<Self, Error> {
+        if acceleration == Acceleration::Anderson(0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`FixedPointIteration`: Depth of Anderson mixing must be larger than 0."
+            ));
+        }
+        self.acceleration = acceleration;
+        Ok(self)
+    }
+}
+
+impl<P, F> Default for FixedPointIteration<P, F>
+where
+    F: ArgminFloat,
+{
+    fn default() -> FixedPointIteration<P, F> {
+        FixedPointIteration::new()
+    }
+}
+
+impl<P, F> FixedPointIteration<P, F>
+where
+    P: Clone + ArgminSub<P, P> + ArgminScaledSub<P, F, P> + ArgminDot<P, F>,
+    F: ArgminFloat,
+{
+    /// Aitken extrapolation from `x`, `x1 = g(x)` and `x2 = g(x1)`
+    fn aitken(x: &P, x1: &P, x2: &P) -> P {
+        let d1 = x1.sub(x);
+        let d2 = x2.sub(x1);
+        let dd = d2.sub(&d1);
+        let denom = dd.dot(&dd);
+        if denom > float!(0.0) {
+            x2.scaled_sub(&(d2.dot(&dd) / denom), &d2)
+        } else {
+            x2.clone()
+        }
+    }
+
+    /// Anderson mixing based on the stored history. Falls back to `g(x)` if the least-squares
+    /// problem is singular and returns an error if it contains non-finite entries (which happens
+    /// when the iteration diverges).
+    fn anderson(&self, gx: &P) -> Result<P, Error> {
+        let m = self.history.len() - 1;
+        let df: Vec<P> = (0..m)
+            .map(|i| self.history[i + 1].1.sub(&self.history[i].1))
+            .collect();
+        let r = &self.history[m].1;
+        let mut a: Vec<Vec<F>> = df
+            .iter()
+            .map(|di| df.iter().map(|dj| di.dot(dj)).collect())
+            .collect();
+        let b: Vec<F> = df.iter().map(|di| di.dot(r)).collect();
+        // Tikhonov regularization keeps the normal equations well-posed when the differences are
+        // (nearly) linearly dependent
+        let reg = (0..m).map(|i| a[i][i]).fold(F::zero(), F::max) * float!(1e-10);
+        for (i, row) in a.iter_mut().enumerate() {
+            row[i] = row[i] + reg;
+        }
+        if a.iter().flatten().chain(b.iter()).any(|x| !x.is_finite()) {
+            return Err(argmin_error!(
+                ConditionViolated,
+                "`FixedPointIteration`: Non-finite residuals in Anderson mixing; the iteration diverged."
+            ));
+        }
+        Ok(match solve_linear_system(a, b) {
+            Some(gamma) => gamma.iter().enumerate().fold(gx.clone(), |x, (i, g)| {
+                x.scaled_sub(g, &self.history[i + 1].0.sub(&self.history[i].0))
+            }),
+            None => gx.clone(),
+        })
+    }
+}
+
+/// Solves the small dense linear system `a * x = b` via Gaussian elimination with partial
+/// pivoting. Returns `None` if the system is singular.
+fn solve_linear_system<F: ArgminFloat>(mut a: Vec<Vec<F>>, mut b: Vec<F>) -> Option<Vec<F>> {
+    let n = b.len();
+    for k in 0..n {
+        // Comparisons with NaN are false, which keeps the search from panicking; a NaN pivot is
+        // rejected below
+        let pivot = (k + 1..n).fold(k, |p, i| if a[i][k].abs() > a[p][k].abs() { i } else { p });
+        if a[pivot][k].abs() <= F::min_positive_value() || !a[pivot][k].is_finite() {
+            return None;
+        }
+        a.swap(k, pivot);
+        b.swap(k, pivot);
+        for i in (k + 1)..n {
+            let factor = a[i][k] / a[k][k];
+            let (upper, lower) = a.split_at_mut(i);
+            for (aij, akj) in lower[0][k..].iter_mut().zip(&upper[k][k..]) {
+                *aij = *aij - factor * *akj;
+            }
+            b[i] = b[i] - factor * b[k];
+        }
+    }
+    let mut x = vec![F::zero(); n];
+    for k in (0..n).rev() {
+        let sum = ((k + 1)..n).fold(b[k], |acc, j| acc - a[k][j] * x[j]);
+        x[k] = sum / a[k][k];
+    }
+    Some(x)
+}
+
+impl<O, P, F> Solver<O, IterState<P, (), (), (), F>> for FixedPointIteration<P, F>
+where
+    O: Operator<Param = P, Output = P>,
+    P: Clone + ArgminSub<P, P> + ArgminScaledSub<P, F, P> + ArgminDot<P, F> + ArgminL2Norm<F>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Fixed-point iteration";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        let param = state.get_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`FixedPointIteration` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        let gx = problem.apply(param)?;
+        let residual = gx.sub(param);
+        let norm = residual.l2_norm();
+        self.gx = Some(gx);
+        self.residual = Some(residual);
+        self.history.clear();
+        Ok((state.cost(norm), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`FixedPointIteration`: Parameter vector in state not set."
+        ))?;
+        let gx = self.gx.take().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`FixedPointIteration`: g(x) not set."
+        ))?;
+        let residual = self.residual.take().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`FixedPointIteration`: Residual not set."
+        ))?;
+
+        let new_param = match self.acceleration {
+            Acceleration::None => gx,
+            Acceleration::Aitken => {
+                let x2 = problem.apply(&gx)?;
+                Self::aitken(&param, &gx, &x2)
+            }
+            Acceleration::Anderson(depth) => {
+                self.history.push_back((gx.clone(), residual));
+                if self.history.len() > depth + 1 {
+                    self.history.pop_front();
+                }
+                if self.history.len() > 1 {
+                    self.anderson(&gx)?
+                } else {
+                    gx
+                }
+            }
+        };
+
+        let gx = problem.apply(&new_param)?;
+        let residual = gx.sub(&new_param);
+        let norm = residual.l2_norm();
+        self.gx = Some(gx);
+        self.residual = Some(residual);
+        Ok((state.param(new_param).cost(norm), None))
+    }
+
+    fn terminate(&mut self, state: &IterState<P, (), (), (), F>) -> TerminationStatus {
+        if state.get_cost() <= self.tol {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Executor};
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(fixed_point, FixedPointIteration<Vec<f64>, f64>);
+
+    /// `g(x) = cos(x)` (element-wise), with fixed point `x_i = 0.7390851332151607`
+    struct Cosine {}
+
+    impl Operator for Cosine {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+
+        fn apply(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(x.iter().map(|xi| xi.cos()).collect())
+        }
+    }
+
+    /// Linear contraction `g(x) = A x + b` with fixed point `[1, 2]`
+    struct Linear {}
+
+    impl Operator for Linear {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+
+        fn apply(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(vec![
+                0.9 * x[0] + 0.05 * x[1],
+                0.05 * x[0] + 0.8 * x[1] + 0.35,
+            ])
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let FixedPointIteration {
+            tol,
+            acceleration,
+            gx,
+            residual,
+            history,
+        } = FixedPointIteration::<Vec<f64>, f64>::new();
+        assert_eq!(tol.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert_eq!(acceleration, Acceleration::None);
+        assert!(gx.is_none());
+        assert!(residual.is_none());
+        assert!(history.is_empty());
+    }
+
+    #[test]
+    fn test_with_tolerance() {
+        let solver: FixedPointIteration<Vec<f64>, f64> =
+            FixedPointIteration::new().with_tolerance(1e-4).unwrap();
+        assert_eq!(solver.tol.to_ne_bytes(), 1e-4f64.to_ne_bytes());
+
+        for tol in [0.0, -1.0] {
+            let res: Result<FixedPointIteration<Vec<f64>, f64>, _> =
+                FixedPointIteration::new().with_tolerance(tol);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`FixedPointIteration`: Tolerance must be larger than 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_acceleration() {
+        for acceleration in [
+            Acceleration::None,
+            Acceleration::Aitken,
+            Acceleration::Anderson(3),
+        ] {
+            let solver: FixedPointIteration<Vec<f64>, f64> = FixedPointIteration::new()
+                .with_acceleration(acceleration)
+                .unwrap();
+            assert_eq!(solver.acceleration, acceleration);
+        }
+
+        let res: Result<FixedPointIteration<Vec<f64>, f64>, _> =
+            FixedPointIteration::new().with_acceleration(Acceleration::Anderson(0));
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`FixedPointIteration`: ",
+                "Depth of Anderson mixing must be larger than 0.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_not_initialized() {
+        let res = Executor::new(Cosine {}, FixedPointIteration::new()).run();
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`FixedPointIteration` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_solve_linear_system() {
+        let a = vec![vec![0.0, 2.0], vec![1.0, 1.0]];
+        let x = solve_linear_system(a, vec![4.0, 3.0]).unwrap();
+        assert_relative_eq!(x[0], 1.0, epsilon = f64::EPSILON);
+        assert_relative_eq!(x[1], 2.0, epsilon = f64::EPSILON);
+
+        let a = vec![vec![1.0, 2.0], vec![2.0, 4.0]];
+        assert!(solve_linear_system(a, vec![1.0, 2.0]).is_none());
+        let a = vec![vec![f64::NAN, 2.0], vec![2.0, 4.0]];
+        assert!(solve_linear_system(a, vec![1.0, 2.0]).is_none());
+    }
+
+    #[test]
+    fn test_solver() {
+        let mut iters = vec![];
+        for acceleration in [
+            Acceleration::None,
+            Acceleration::Aitken,
+            Acceleration::Anderson(3),
+        ] {
+            let solver = FixedPointIteration::new()
+                .with_tolerance(1e-10)
+                .unwrap()
+                .with_acceleration(acceleration)
+                .unwrap();
+            let res = Executor::new(Cosine {}, solver)
+                .configure(|state| state.param(vec![1.0, 0.0]).max_iters(200))
+                .run()
+                .unwrap();
+            assert_eq!(
+                res.state.get_termination_reason(),
+                Some(&TerminationReason::SolverConverged)
+            );
+            let param = res.state.get_best_param().unwrap();
+            assert_relative_eq!(param[0], 0.7390851332151607, epsilon = 1e-9);
+            assert_relative_eq!(param[1], 0.7390851332151607, epsilon = 1e-9);
+            iters.push(res.state.get_iter());
+        }
+        // Both acceleration schemes need far fewer iterations than the plain iteration
+        assert!(iters[1] < iters[0] / 3);
+        assert!(iters[2] < iters[0] / 3);
+    }
+
+    #[test]
+    fn test_anderson_linear() {
+        // For a linear operator in two dimensions, Anderson mixing with depth 2 converges
+        // (in exact arithmetic) after three iterations
+        let solver = FixedPointIteration::new()
+            .with_tolerance(1e-10)
+            .unwrap()
+            .with_acceleration(Acceleration::Anderson(2))
+            .unwrap();
+        let res = Executor::new(Linear {}, solver)
+            .configure(|state| state.param(vec![0.0, 0.0]).max_iters(100))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        assert!(res.state.get_iter() <= 4);
+        let param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(param[0], 1.0, epsilon = 1e-9);
+        assert_relative_eq!(param[1], 2.0, epsilon = 1e-9);
+    }
+
+    /// Diverging map `g(x) = 1e200 x + 1`
+    struct Diverging {}
+
+    impl Operator for Diverging {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+
+        fn apply(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(x.iter().map(|xi| 1e200 * xi + 1.0).collect())
+        }
+    }
+
+    #[test]
+    fn test_anderson_diverging() {
+        let solver = FixedPointIteration::new()
+            .with_acceleration(Acceleration::Anderson(2))
+            .unwrap();
+        let res = Executor::new(Diverging {}, solver)
+            .configure(|state| state.param(vec![0.0]).max_iters(10))
+            .run();
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Condition violated: \"`FixedPointIteration`: Non-finite residuals in Anderson ",
+                "mixing; the iteration diverged.\""
+            )
+        );
+    }
+}
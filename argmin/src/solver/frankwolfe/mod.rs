@@ -0,0 +1,437 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Frank-Wolfe method (conditional gradient method)
+//!
+//! Minimization over a compact convex set which only requires a linear minimization oracle of the
+//! set instead of a projection. See [`FrankWolfe`] for details.
+//!
+//! # Available linear minimization oracles
+//!
+//! * [`SimplexOracle`]
+//! * [`L1BallOracle`]
+//! * [`L2BallOracle`]
+//!
+//! User-defined sets are supported by implementing the [`LinearMinimizationOracle`] trait.
+//!
+//! ## Reference
+//!
+//! Martin Jaggi (2013). Revisiting Frank-Wolfe: Projection-Free Sparse Convex Optimization.
+//! International Conference on Machine Learning.
+
+mod oracle;
+
+pub use oracle::{L1BallOracle, L2BallOracle, LinearMinimizationOracle, SimplexOracle};
+
+use crate::core::{
+    ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, Gradient, IterState, Problem,
+    SerializeAlias, Solver, State, TerminationReason, TerminationStatus, KV,
+};
+use crate::solver::projectedgradient::IdentityProjection;
+use argmin_math::{ArgminDot, ArgminScaledAdd, ArgminSub, Projection};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Frank-Wolfe method (conditional gradient method)
+///
+/// Minimizes a function over a compact convex set `C` via the iteration
+///
+/// `s_k = argmin_{s in C} <grad f(x_k), s>`
+///
+/// `x_{k+1} = x_k + gamma_k * (s_k - x_k)`
+///
+/// The linear subproblem is solved by a linear minimization oracle, which is provided as an
+/// implementation of the [`LinearMinimizationOracle`] trait. This is useful for sets such as the
+/// simplex, L1 balls or nuclear-norm balls, where the linear subproblem is much cheaper than a
+/// projection. As all iterates are convex combinations of points in `C`, the initial parameter
+/// vector must be in `C`. If a projection onto `C` is available, an infeasible initial parameter
+/// vector can be projected onto `C` via [`with_projection`](`FrankWolfe::with_projection`).
+///
+/// By default the step length `gamma_k = 2 / (k + 2)` is used. Alternatively, a backtracking line
+/// search on `gamma` in `(0, 1]` can be enabled via
+/// [`with_line_search`](`FrankWolfe::with_line_search`).
+///
+/// The algorithm stops once the Frank-Wolfe gap `<grad f(x_k), x_k - s_k>`, which for convex
+/// problems is an upper bound on the suboptimality, falls below the tolerance set via
+/// [`with_tolerance`](`FrankWolfe::with_tolerance`).
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`] and [`Gradient`].
+///
+/// ## Reference
+///
+/// Martin Jaggi (2013). Revisiting Frank-Wolfe: Projection-Free Sparse Convex Optimization.
+/// International Conference on Machine Learning.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct FrankWolfe<L, F, Q = IdentityProjection> {
+    /// Linear minimization oracle
+    oracle: L,
+    /// Projection of the initial parameter vector onto the feasible set
+    projection: Option<Q>,
+    /// Whether the step length is determined by a backtracking line search
+    line_search: bool,
+    /// Tolerance on the Frank-Wolfe gap
+    tol: F,
+    /// Frank-Wolfe gap of the current iterate
+    gap: F,
+}
+
+impl<L, F> FrankWolfe<L, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`FrankWolfe`]
+    ///
+    /// Takes the linear minimization oracle of the feasible set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::frankwolfe::{FrankWolfe, SimplexOracle};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let oracle = SimplexOracle::new(1.0)?;
+    /// let fw: FrankWolfe<_, f64> = FrankWolfe::new(oracle);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(oracle: L) -> Self {
+        FrankWolfe {
+            oracle,
+            projection: None,
+            line_search: false,
+            tol: F::epsilon().sqrt(),
+            gap: F::infinity(),
+        }
+    }
+}
+
+impl<L, F, Q> FrankWolfe<L, F, Q>
+where
+    F: ArgminFloat,
+{
+    /// Project the initial parameter vector onto the feasible set
+    ///
+    /// Takes a projection onto the set the linear minimization oracle minimizes over, which is
+    /// applied to the initial parameter vector during initialization. The iterates remain feasible
+    /// without further projections. By default, the initial parameter vector is used as is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::frankwolfe::{FrankWolfe, SimplexOracle};
+    /// # use argmin::solver::projectedgradient::SimplexProjection;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let oracle = SimplexOracle::new(1.0)?;
+    /// let projection = SimplexProjection::new(1.0)?;
+    /// let fw: FrankWolfe<_, f64, _> = FrankWolfe::new(oracle).with_projection(projection);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_projection<Q2>(self, projection: Q2) -> FrankWolfe<L, F, Q2> {
+        FrankWolfe {
+            oracle: self.oracle,
+            projection: Some(projection),
+            line_search: self.line_search,
+            tol: self.tol,
+            gap: self.gap,
+        }
+    }
+
+    /// Enable or disable the backtracking line search for the step length
+    ///
+    /// If disabled (default), the step length `2 / (k + 2)` is used in iteration `k`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::frankwolfe::{FrankWolfe, SimplexOracle};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let oracle = SimplexOracle::new(1.0)?;
+    /// let fw: FrankWolfe<_, f64> = FrankWolfe::new(oracle).with_line_search(true);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_line_search(mut self, line_search: bool) -> Self {
+        self.line_search = line_search;
+        self
+    }
+
+    /// Set the tolerance on the Frank-Wolfe gap
+    ///
+    /// Must be non-negative and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::frankwolfe::{FrankWolfe, SimplexOracle};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let oracle = SimplexOracle::new(1.0)?;
+    /// let fw: FrankWolfe<_, f64> = FrankWolfe::new(oracle).with_tolerance(1e-6)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`FrankWolfe`: tolerance must be >= 0."
+            ));
+        }
+        self.tol = tol;
+        Ok(self)
+    }
+}
+
+impl<O, L, Q, P, G, F> Solver<O, IterState<P, G, (), (), F>> for FrankWolfe<L, F, Q>
+where
+    O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
+    L: LinearMinimizationOracle<P, G>,
+    Q: Projection<P>,
+    P: Clone + SerializeAlias + DeserializeOwnedAlias + ArgminSub<P, P> + ArgminScaledAdd<P, F, P>,
+    G: Clone + SerializeAlias + DeserializeOwnedAlias + ArgminDot<P, F>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Frank-Wolfe";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`FrankWolfe` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        // Cost and gradient provided in the state refer to the unprojected parameter vector
+        let (param, cost, grad) = match self.projection.as_ref() {
+            Some(projection) => (projection.project(&param)?, F::infinity(), None),
+            None => (param, state.get_cost(), state.take_gradient()),
+        };
+        let cost = if cost.is_infinite() {
+            problem.cost(&param)?
+        } else {
+            cost
+        };
+        let grad = grad
+            .map(Result::Ok)
+            .unwrap_or_else(|| problem.gradient(&param))?;
+        self.gap = F::infinity();
+        Ok((state.param(param).cost(cost).gradient(grad), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`FrankWolfe`: Parameter vector in state not set."
+        ))?;
+        let grad = state.take_gradient().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`FrankWolfe`: Gradient in state not set."
+        ))?;
+        let cost = state.get_cost();
+
+        let vertex = self.oracle.minimize_linear(&grad)?;
+        let direction = vertex.sub(&param);
+        let slope: F = grad.dot(&direction);
+        self.gap = -slope;
+        if self.gap <= self.tol {
+            return Ok((
+                state.param(param).gradient(grad).cost(cost),
+                Some(kv!("gap" => self.gap;)),
+            ));
+        }
+
+        let (gamma, new_param, new_cost) = if self.line_search {
+            let mut gamma = float!(1.0);
+            loop {
+                let new_param = param.scaled_add(&gamma, &direction);
+                let new_cost = problem.cost(&new_param)?;
+                if new_cost <= cost + float!(1e-4) * gamma * slope {
+                    break (gamma, new_param, new_cost);
+                }
+                gamma = gamma * float!(0.5);
+                if gamma <= F::epsilon() {
+                    return Ok((
+                        state.param(param).gradient(grad).cost(cost).terminate_with(
+                            TerminationReason::SolverExit(
+                                "Line search failed to find a sufficient decrease".to_string(),
+                            ),
+                        ),
+                        Some(kv!("gap" => self.gap;)),
+                    ));
+                }
+            }
+        } else {
+            let gamma = float!(2.0) / (F::from_u64(state.get_iter()).unwrap() + float!(2.0));
+            let new_param = param.scaled_add(&gamma, &direction);
+            let new_cost = problem.cost(&new_param)?;
+            (gamma, new_param, new_cost)
+        };
+
+        let new_grad = problem.gradient(&new_param)?;
+        let kv = kv!(
+            "gap" => self.gap;
+            "step_length" => gamma;
+        );
+        Ok((
+            state.param(new_param).gradient(new_grad).cost(new_cost),
+            Some(kv),
+        ))
+    }
+
+    fn terminate(&mut self, _state: &IterState<P, G, (), (), F>) -> TerminationStatus {
+        if self.gap <= self.tol {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Executor};
+    use crate::solver::projectedgradient::SimplexProjection;
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(frank_wolfe, FrankWolfe<SimplexOracle<f64>, f64>);
+
+    /// f(x) = (x - 0.7)^2 + (y - 0.1)^2 + (z - 0.2)^2
+    struct Quadratic {}
+
+    impl CostFunction for Quadratic {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok((p[0] - 0.7).powi(2) + (p[1] - 0.1).powi(2) + (p[2] - 0.2).powi(2))
+        }
+    }
+
+    impl Gradient for Quadratic {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(vec![
+                2.0 * (p[0] - 0.7),
+                2.0 * (p[1] - 0.1),
+                2.0 * (p[2] - 0.2),
+            ])
+        }
+    }
+
+    fn oracle() -> SimplexOracle<f64> {
+        SimplexOracle::new(1.0).unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        let FrankWolfe {
+            oracle: _,
+            projection,
+            line_search,
+            tol,
+            gap,
+        } = FrankWolfe::<_, f64>::new(oracle());
+        assert!(projection.is_none());
+        assert!(!line_search);
+        assert_eq!(tol.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert!(gap.is_infinite());
+    }
+
+    #[test]
+    fn test_with_projection() {
+        let fw = FrankWolfe::<_, f64>::new(oracle())
+            .with_line_search(true)
+            .with_projection(SimplexProjection::new(1.0).unwrap());
+        assert!(fw.projection.is_some());
+        assert!(fw.line_search);
+    }
+
+    #[test]
+    fn test_with_line_search() {
+        let fw = FrankWolfe::<_, f64>::new(oracle()).with_line_search(true);
+        assert!(fw.line_search);
+    }
+
+    #[test]
+    fn test_with_tolerance() {
+        for tol in [0.0, 1e-10, 1.0] {
+            let fw = FrankWolfe::<_, f64>::new(oracle())
+                .with_tolerance(tol)
+                .unwrap();
+            assert_eq!(fw.tol.to_ne_bytes(), tol.to_ne_bytes());
+        }
+
+        for tol in [-f64::EPSILON, -1.0] {
+            let res = FrankWolfe::new(oracle()).with_tolerance(tol);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`FrankWolfe`: tolerance must be >= 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_solver() {
+        // The unconstrained minimizer lies in the simplex
+        for line_search in [false, true] {
+            let solver = FrankWolfe::new(oracle())
+                .with_line_search(line_search)
+                .with_tolerance(1e-4)
+                .unwrap();
+            let res = Executor::new(Quadratic {}, solver)
+                .configure(|state| state.param(vec![1.0, 0.0, 0.0]).max_iters(100_000))
+                .run()
+                .unwrap();
+            assert_eq!(
+                res.state.get_termination_reason(),
+                Some(&TerminationReason::SolverConverged)
+            );
+            let best = res.state.get_best_param().unwrap();
+            assert_relative_eq!(best[0], 0.7, epsilon = 1e-2);
+            assert_relative_eq!(best[1], 0.1, epsilon = 1e-2);
+            assert_relative_eq!(best[2], 0.2, epsilon = 1e-2);
+            assert_relative_eq!(best.iter().sum::<f64>(), 1.0, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_solver_projected_initial_param() {
+        // The initial parameter vector is not in the simplex and is projected onto it
+        let solver = FrankWolfe::new(oracle())
+            .with_tolerance(1e-4)
+            .unwrap()
+            .with_projection(SimplexProjection::new(1.0).unwrap());
+        let res = Executor::new(Quadratic {}, solver)
+            .configure(|state| state.param(vec![2.0, 0.0, 0.0]).max_iters(100_000))
+            .run()
+            .unwrap();
+        let best = res.state.get_best_param().unwrap();
+        assert_relative_eq!(best[0], 0.7, epsilon = 1e-2);
+        assert_relative_eq!(best[1], 0.1, epsilon = 1e-2);
+        assert_relative_eq!(best[2], 0.2, epsilon = 1e-2);
+        assert_relative_eq!(best.iter().sum::<f64>(), 1.0, epsilon = 1e-12);
+    }
+}
@@ -0,0 +1,275 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{ArgminFloat, Error};
+use argmin_math::{ArgminL2Norm, ArgminScaledSub};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Interface of a linear minimization oracle over a compact convex set `C`.
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::Error;
+/// use argmin::solver::frankwolfe::LinearMinimizationOracle;
+///
+/// /// Linear minimization over the unit square `[0, 1]^n`
+/// struct UnitSquare {}
+///
+/// impl LinearMinimizationOracle<Vec<f64>, Vec<f64>> for UnitSquare {
+///     fn minimize_linear(&self, gradient: &Vec<f64>) -> Result<Vec<f64>, Error> {
+///         Ok(gradient.iter().map(|g| if *g > 0.0 { 0.0 } else { 1.0 }).collect())
+///     }
+/// }
+/// ```
+pub trait LinearMinimizationOracle<P, G> {
+    /// Returns a minimizer `s` of the linear function `<gradient, s>` over the set.
+    fn minimize_linear(&self, gradient: &G) -> Result<P, Error>;
+}
+
+/// # Linear minimization oracle of a simplex
+///
+/// Minimizes over the set `{x : x_i >= 0, sum_i x_i = radius}` by selecting the vertex
+/// corresponding to the smallest element of the gradient.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::solver::frankwolfe::{LinearMinimizationOracle, SimplexOracle};
+/// # use argmin::core::Error;
+/// # fn main() -> Result<(), Error> {
+/// let oracle = SimplexOracle::new(1.0f64)?;
+/// assert_eq!(oracle.minimize_linear(&vec![1.0, -2.0, 0.5])?, vec![0.0, 1.0, 0.0]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct SimplexOracle<F> {
+    /// Sum of the elements
+    radius: F,
+}
+
+impl<F> SimplexOracle<F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`SimplexOracle`]
+    ///
+    /// The radius is the sum of the elements of the points in the simplex and must be larger than
+    /// 0.
+    pub fn new(radius: F) -> Result<Self, Error> {
+        if radius <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`SimplexOracle`: radius must be > 0."
+            ));
+        }
+        Ok(SimplexOracle { radius })
+    }
+}
+
+impl<F> LinearMinimizationOracle<Vec<F>, Vec<F>> for SimplexOracle<F>
+where
+    F: ArgminFloat,
+{
+    fn minimize_linear(&self, gradient: &Vec<F>) -> Result<Vec<F>, Error> {
+        let mut vertex = vec![float!(0.0); gradient.len()];
+        if let Some(idx) = argmin_index(gradient.iter().cloned()) {
+            vertex[idx] = self.radius;
+        }
+        Ok(vertex)
+    }
+}
+
+/// # Linear minimization oracle of an L1 ball
+///
+/// Minimizes over the set `{x : ||x||_1 <= radius}` by selecting the signed vertex corresponding
+/// to the largest absolute element of the gradient.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::solver::frankwolfe::{L1BallOracle, LinearMinimizationOracle};
+/// # use argmin::core::Error;
+/// # fn main() -> Result<(), Error> {
+/// let oracle = L1BallOracle::new(2.0f64)?;
+/// assert_eq!(oracle.minimize_linear(&vec![1.0, -3.0, 0.5])?, vec![0.0, 2.0, 0.0]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct L1BallOracle<F> {
+    /// Radius of the ball
+    radius: F,
+}
+
+impl<F> L1BallOracle<F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`L1BallOracle`]
+    ///
+    /// The radius must be larger than 0.
+    pub fn new(radius: F) -> Result<Self, Error> {
+        if radius <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`L1BallOracle`: radius must be > 0."
+            ));
+        }
+        Ok(L1BallOracle { radius })
+    }
+}
+
+impl<F> LinearMinimizationOracle<Vec<F>, Vec<F>> for L1BallOracle<F>
+where
+    F: ArgminFloat,
+{
+    fn minimize_linear(&self, gradient: &Vec<F>) -> Result<Vec<F>, Error> {
+        let mut vertex = vec![float!(0.0); gradient.len()];
+        if let Some(idx) = argmin_index(gradient.iter().map(|g| -g.abs())) {
+            if gradient[idx] > float!(0.0) {
+                vertex[idx] = -self.radius;
+            } else if gradient[idx] < float!(0.0) {
+                vertex[idx] = self.radius;
+            }
+        }
+        Ok(vertex)
+    }
+}
+
+/// # Linear minimization oracle of an L2 ball
+///
+/// Minimizes over the set `{x : ||x - center|| <= radius}`. The minimizer is
+/// `center - radius * gradient / ||gradient||`.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::solver::frankwolfe::{L2BallOracle, LinearMinimizationOracle};
+/// # use argmin::core::Error;
+/// # fn main() -> Result<(), Error> {
+/// let oracle = L2BallOracle::new(vec![0.0f64, 0.0], 1.0)?;
+/// assert_eq!(oracle.minimize_linear(&vec![0.0, 2.0])?, vec![0.0, -1.0]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct L2BallOracle<P, F> {
+    /// Center of the ball
+    center: P,
+    /// Radius of the ball
+    radius: F,
+}
+
+impl<P, F> L2BallOracle<P, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`L2BallOracle`] from the center and the radius of the ball
+    ///
+    /// The radius must be larger than 0.
+    pub fn new(center: P, radius: F) -> Result<Self, Error> {
+        if radius <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`L2BallOracle`: radius must be > 0."
+            ));
+        }
+        Ok(L2BallOracle { center, radius })
+    }
+}
+
+impl<P, G, F> LinearMinimizationOracle<P, G> for L2BallOracle<P, F>
+where
+    P: Clone + ArgminScaledSub<G, F, P>,
+    G: ArgminL2Norm<F>,
+    F: ArgminFloat,
+{
+    fn minimize_linear(&self, gradient: &G) -> Result<P, Error> {
+        let norm = gradient.l2_norm();
+        if norm == float!(0.0) {
+            return Ok(self.center.clone());
+        }
+        Ok(self.center.scaled_sub(&(self.radius / norm), gradient))
+    }
+}
+
+/// Index of the smallest element
+fn argmin_index<F: ArgminFloat>(iter: impl Iterator<Item = F>) -> Option<usize> {
+    iter.enumerate()
+        .fold(None, |acc: Option<(usize, F)>, (i, x)| match acc {
+            Some((_, best)) if best <= x => acc,
+            _ => Some((i, x)),
+        })
+        .map(|(i, _)| i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+    use crate::test_trait_impl;
+
+    test_trait_impl!(simplex_oracle, SimplexOracle<f64>);
+    test_trait_impl!(l1_ball_oracle, L1BallOracle<f64>);
+    test_trait_impl!(l2_ball_oracle, L2BallOracle<Vec<f64>, f64>);
+
+    #[test]
+    fn test_simplex_oracle() {
+        let oracle = SimplexOracle::new(2.0f64).unwrap();
+        assert_eq!(
+            oracle.minimize_linear(&vec![3.0, 1.0, 2.0]).unwrap(),
+            vec![0.0, 2.0, 0.0]
+        );
+        assert_error!(
+            SimplexOracle::new(0.0f64),
+            ArgminError,
+            "Invalid parameter: \"`SimplexOracle`: radius must be > 0.\""
+        );
+    }
+
+    #[test]
+    fn test_l1_ball_oracle() {
+        let oracle = L1BallOracle::new(1.0f64).unwrap();
+        assert_eq!(
+            oracle.minimize_linear(&vec![3.0, 1.0, -2.0]).unwrap(),
+            vec![-1.0, 0.0, 0.0]
+        );
+        assert_eq!(
+            oracle.minimize_linear(&vec![0.0, 0.0]).unwrap(),
+            vec![0.0, 0.0]
+        );
+        assert_error!(
+            L1BallOracle::new(-1.0f64),
+            ArgminError,
+            "Invalid parameter: \"`L1BallOracle`: radius must be > 0.\""
+        );
+    }
+
+    #[test]
+    fn test_l2_ball_oracle() {
+        let oracle = L2BallOracle::new(vec![1.0f64, 1.0], 2.0).unwrap();
+        assert_eq!(
+            oracle.minimize_linear(&vec![3.0, 0.0]).unwrap(),
+            vec![-1.0, 1.0]
+        );
+        assert_eq!(
+            oracle.minimize_linear(&vec![0.0, 0.0]).unwrap(),
+            vec![1.0, 1.0]
+        );
+        assert_error!(
+            L2BallOracle::new(vec![0.0f64], 0.0),
+            ArgminError,
+            "Invalid parameter: \"`L2BallOracle`: radius must be > 0.\""
+        );
+    }
+}
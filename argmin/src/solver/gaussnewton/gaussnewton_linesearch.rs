@@ -10,7 +10,9 @@ use crate::core::{
     Jacobian, LineSearch, Operator, OptimizationResult, Problem, SerializeAlias, Solver,
     TerminationReason, TerminationStatus, KV,
 };
-use argmin_math::{ArgminDot, ArgminInv, ArgminL2Norm, ArgminMul, ArgminTranspose};
+use crate::solver::linearsolver::{InverseSolver, LinearSolver};
+use crate::solver::linesearch::linesearch_kv;
+use argmin_math::{ArgminDot, ArgminL2Norm, ArgminMul, ArgminTranspose};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 
@@ -20,6 +22,9 @@ use serde::{Deserialize, Serialize};
 ///
 /// Requires an initial parameter vector.
 ///
+/// The solver for the normal equations can be set via
+/// [`with_linear_solver`](`GaussNewtonLS::with_linear_solver`) (see [`GaussNewton`](`crate::solver::gaussnewton::GaussNewton`)).
+///
 /// ## Requirements on the optimization problem
 ///
 /// The optimization problem is required to implement [`Operator`] and [`Jacobian`].
@@ -30,11 +35,13 @@ use serde::{Deserialize, Serialize};
 /// Springer. ISBN 0-387-30303-0.
 #[derive(Clone)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
-pub struct GaussNewtonLS<L, F> {
+pub struct GaussNewtonLS<L, F, S = InverseSolver> {
     /// linesearch
     linesearch: L,
     /// Tolerance for the stopping criterion based on cost difference
     tol: F,
+    /// Solver for the normal equations
+    linear_solver: S,
 }
 
 impl<L, F: ArgminFloat> GaussNewtonLS<L, F> {
@@ -51,9 +58,12 @@ impl<L, F: ArgminFloat> GaussNewtonLS<L, F> {
         GaussNewtonLS {
             linesearch,
             tol: F::epsilon().sqrt(),
+            linear_solver: InverseSolver::new(),
         }
     }
+}
 
+impl<L, F: ArgminFloat, S> GaussNewtonLS<L, F, S> {
     /// Set tolerance for the stopping criterion based on cost difference.
     ///
     /// Tolerance must be larger than zero and defaults to `sqrt(EPSILON)`.
@@ -79,22 +89,42 @@ impl<L, F: ArgminFloat> GaussNewtonLS<L, F> {
         self.tol = tol;
         Ok(self)
     }
+
+    /// Set the solver for the normal equations `J^T J * p = J^T r`
+    ///
+    /// Defaults to [`InverseSolver`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::gaussnewton::GaussNewtonLS;
+    /// # use argmin::solver::linearsolver::CholeskySolver;
+    /// # let linesearch = ();
+    /// let gauss_newton_ls: GaussNewtonLS<_, f64, _> =
+    ///     GaussNewtonLS::new(linesearch).with_linear_solver(CholeskySolver::new());
+    /// ```
+    pub fn with_linear_solver<S2>(self, linear_solver: S2) -> GaussNewtonLS<L, F, S2> {
+        GaussNewtonLS {
+            linesearch: self.linesearch,
+            tol: self.tol,
+            linear_solver,
+        }
+    }
 }
 
-impl<O, L, F, P, G, J, U> Solver<O, IterState<P, G, J, (), F>> for GaussNewtonLS<L, F>
+impl<O, L, F, P, G, J, U, S> Solver<O, IterState<P, G, J, (), F>> for GaussNewtonLS<L, F, S>
 where
     O: Operator<Param = P, Output = U> + Jacobian<Param = P, Jacobian = J>,
-    P: Clone + SerializeAlias + DeserializeOwnedAlias + ArgminMul<F, P>,
-    G: Clone + SerializeAlias + DeserializeOwnedAlias,
+    P: Clone + SerializeAlias + DeserializeOwnedAlias,
+    G: Clone + SerializeAlias + DeserializeOwnedAlias + ArgminMul<F, P>,
     U: ArgminL2Norm<F>,
     J: Clone
         + SerializeAlias
         + DeserializeOwnedAlias
         + ArgminTranspose<J>
-        + ArgminInv<J>
         + ArgminDot<J, J>
-        + ArgminDot<G, P>
         + ArgminDot<U, G>,
+    S: LinearSolver<J, G>,
     L: Clone + LineSearch<P, F> + Solver<LineSearchProblem<O, F>, IterState<P, G, (), (), F>>,
     F: ArgminFloat,
 {
@@ -117,13 +147,16 @@ where
         let jacobian_t = jacobian.clone().t();
         let grad = jacobian_t.dot(&residuals);
 
-        let p: P = jacobian_t.dot(&jacobian).inv()?.dot(&grad);
+        let p: G = self
+            .linear_solver
+            .solve(&jacobian_t.dot(&jacobian), &grad)?;
 
         self.linesearch.search_direction(p.mul(&(float!(-1.0))));
 
         // perform linesearch
         let OptimizationResult {
             problem: mut line_problem,
+            solver: linesearch,
             state: mut linesearch_state,
             ..
         } = Executor::new(
@@ -136,6 +169,7 @@ where
         .configure(|config| config.param(param).gradient(grad).cost(residuals.l2_norm()))
         .ctrlc(false)
         .run()?;
+        self.linesearch = linesearch;
 
         // Here we cannot use `consume_problem` because the problem we need is hidden inside a
         // `LineSearchProblem` hidden inside a `Problem`. Therefore we have to split this in two
@@ -162,7 +196,7 @@ where
                         ))?,
                 )
                 .cost(linesearch_state.get_cost()),
-            None,
+            Some(linesearch_kv(&self.linesearch, &linesearch_state)),
         ))
     }
 
@@ -246,6 +280,7 @@ mod tests {
         let GaussNewtonLS {
             linesearch: ls,
             tol: t,
+            linear_solver: _,
         } = GaussNewtonLS::<_, f64>::new(MyLinesearch {});
 
         assert_eq!(ls, MyLinesearch {});
@@ -437,7 +472,11 @@ mod tests {
         let (mut state, kv) = gnls.next_iter(&mut problem, state).unwrap();
         state.update();
 
-        assert!(kv.is_none());
+        let kv2 = kv!(
+            "ls_iters" => 0u64; "ls_cost_count" => 1u64; "ls_converged" => true;
+            "ls_step_length" => 1.0;
+        );
+        assert_eq!(kv.unwrap(), kv2);
 
         assert_relative_eq!(
             state.param.as_ref().unwrap()[0],
@@ -9,7 +9,8 @@ use crate::core::{
     ArgminFloat, Error, IterState, Jacobian, Operator, Problem, Solver, State, TerminationReason,
     TerminationStatus, KV,
 };
-use argmin_math::{ArgminDot, ArgminInv, ArgminL2Norm, ArgminMul, ArgminSub, ArgminTranspose};
+use crate::solver::linearsolver::{InverseSolver, LinearSolver};
+use argmin_math::{ArgminDot, ArgminL2Norm, ArgminMul, ArgminSub, ArgminTranspose};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 
@@ -19,6 +20,12 @@ use serde::{Deserialize, Serialize};
 ///
 /// Requires an initial parameter vector.
 ///
+/// By default the normal equations `J^T J * p = J^T r` are solved by computing the inverse of
+/// `J^T J`. A different [`LinearSolver`] can be set via
+/// [`with_linear_solver`](`GaussNewton::with_linear_solver`). Since `J^T J` is symmetric and
+/// positive (semi-)definite, a [`CholeskySolver`](`crate::solver::linearsolver::CholeskySolver`)
+/// is usually the best choice.
+///
 /// ## Requirements on the optimization problem
 ///
 /// The optimization problem is required to implement [`Operator`] and [`Jacobian`].
@@ -29,11 +36,13 @@ use serde::{Deserialize, Serialize};
 /// Springer. ISBN 0-387-30303-0.
 #[derive(Clone)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
-pub struct GaussNewton<F> {
+pub struct GaussNewton<F, S = InverseSolver> {
     /// gamma
     gamma: F,
     /// Tolerance for the stopping criterion based on cost difference
     tol: F,
+    /// Solver for the normal equations
+    linear_solver: S,
 }
 
 impl<F: ArgminFloat> GaussNewton<F> {
@@ -49,9 +58,12 @@ impl<F: ArgminFloat> GaussNewton<F> {
         GaussNewton {
             gamma: float!(1.0),
             tol: F::epsilon().sqrt(),
+            linear_solver: InverseSolver::new(),
         }
     }
+}
 
+impl<F: ArgminFloat, S> GaussNewton<F, S> {
     /// Set step width gamma.
     ///
     /// Gamma must be within `(0, 1]`. Defaults to `1.0`.
@@ -101,6 +113,26 @@ impl<F: ArgminFloat> GaussNewton<F> {
         self.tol = tol;
         Ok(self)
     }
+
+    /// Set the solver for the normal equations `J^T J * p = J^T r`
+    ///
+    /// Defaults to [`InverseSolver`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::gaussnewton::GaussNewton;
+    /// # use argmin::solver::linearsolver::CholeskySolver;
+    /// let gauss_newton: GaussNewton<f64, _> =
+    ///     GaussNewton::new().with_linear_solver(CholeskySolver::new());
+    /// ```
+    pub fn with_linear_solver<S2>(self, linear_solver: S2) -> GaussNewton<F, S2> {
+        GaussNewton {
+            gamma: self.gamma,
+            tol: self.tol,
+            linear_solver,
+        }
+    }
 }
 
 impl<F: ArgminFloat> Default for GaussNewton<F> {
@@ -109,17 +141,13 @@ impl<F: ArgminFloat> Default for GaussNewton<F> {
     }
 }
 
-impl<O, F, P, J, U> Solver<O, IterState<P, (), J, (), F>> for GaussNewton<F>
+impl<O, F, P, J, U, S> Solver<O, IterState<P, (), J, (), F>> for GaussNewton<F, S>
 where
     O: Operator<Param = P, Output = U> + Jacobian<Param = P, Jacobian = J>,
     P: Clone + ArgminSub<P, P> + ArgminMul<F, P>,
     U: ArgminL2Norm<F>,
-    J: Clone
-        + ArgminTranspose<J>
-        + ArgminInv<J>
-        + ArgminDot<J, J>
-        + ArgminDot<U, P>
-        + ArgminDot<P, P>,
+    J: Clone + ArgminTranspose<J> + ArgminDot<J, J> + ArgminDot<U, P>,
+    S: LinearSolver<J, P>,
     F: ArgminFloat,
 {
     const NAME: &'static str = "Gauss-Newton method";
@@ -139,12 +167,10 @@ where
         let residuals = problem.apply(param)?;
         let jacobian = problem.jacobian(param)?;
 
-        let p = jacobian
-            .clone()
-            .t()
-            .dot(&jacobian)
-            .inv()?
-            .dot(&jacobian.t().dot(&residuals));
+        let jacobian_t = jacobian.clone().t();
+        let p = self
+            .linear_solver
+            .solve(&jacobian_t.dot(&jacobian), &jacobian_t.dot(&residuals))?;
 
         let new_param = param.sub(&p.mul(&self.gamma));
 
@@ -166,14 +192,17 @@ mod tests {
     #[cfg(feature = "_ndarrayl")]
     use crate::core::Executor;
     use crate::test_trait_impl;
-    #[cfg(feature = "_ndarrayl")]
     use approx::assert_relative_eq;
 
     test_trait_impl!(gauss_newton_method, GaussNewton<f64>);
 
     #[test]
     fn test_new() {
-        let GaussNewton { tol: t, gamma: g } = GaussNewton::<f64>::new();
+        let GaussNewton {
+            tol: t,
+            gamma: g,
+            linear_solver: _,
+        } = GaussNewton::<f64>::new();
 
         assert_eq!(g.to_ne_bytes(), (1.0f64).to_ne_bytes());
         assert_eq!(t.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
@@ -379,4 +408,50 @@ mod tests {
         assert_relative_eq!(param[0], -0.7, epsilon = f64::EPSILON.sqrt());
         assert_relative_eq!(param[1], 0.15, epsilon = f64::EPSILON.sqrt());
     }
+
+    #[test]
+    fn test_with_linear_solver() {
+        use crate::solver::linearsolver::{CholeskySolver, QRSolver};
+
+        // Linear residuals `r(p) = A * p - y`, the minimum `p = [1, 2]` is found in one iteration
+        struct LeastSquares {}
+
+        impl Operator for LeastSquares {
+            type Param = Vec<f64>;
+            type Output = Vec<f64>;
+
+            fn apply(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(vec![
+                    p[0] + 2.0 * p[1] - 5.0,
+                    3.0 * p[0] + 4.0 * p[1] - 11.0,
+                ])
+            }
+        }
+
+        impl Jacobian for LeastSquares {
+            type Param = Vec<f64>;
+            type Jacobian = Vec<Vec<f64>>;
+
+            fn jacobian(&self, _p: &Self::Param) -> Result<Self::Jacobian, Error> {
+                Ok(vec![vec![1.0, 2.0], vec![3.0, 4.0]])
+            }
+        }
+
+        let mut problem = Problem::new(LeastSquares {});
+        let mut gn = GaussNewton::new().with_linear_solver(CholeskySolver::new());
+        let (state, _) = gn
+            .next_iter(&mut problem, IterState::new().param(vec![0.0, 0.0]))
+            .unwrap();
+        let param = state.get_param().unwrap();
+        assert_relative_eq!(param[0], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(param[1], 2.0, epsilon = 1e-12);
+
+        let mut gn = GaussNewton::new().with_linear_solver(QRSolver::new());
+        let (state, _) = gn
+            .next_iter(&mut problem, IterState::new().param(vec![0.0, 0.0]))
+            .unwrap();
+        let param = state.get_param().unwrap();
+        assert_relative_eq!(param[0], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(param[1], 2.0, epsilon = 1e-12);
+    }
 }
@@ -41,6 +41,11 @@ const G2: f64 = 1.0 - G1;
 ///
 /// The `min_bound` and `max_bound` arguments define values that bracket the expected minimum.
 ///
+/// For smooth functions, [`BrentOpt`](`crate::solver::brent::BrentOpt`) usually converges
+/// considerably faster thanks to its parabolic interpolation steps while retaining the robustness
+/// of the golden-section search, and is therefore the recommended default for univariate
+/// minimization.
+///
 /// Requires an initial guess which is to be provided via [`Executor`](`crate::core::Executor`)s
 /// `configure` method.
 ///
@@ -0,0 +1,341 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, Gradient, IterState, Problem, SerializeAlias, Solver, KV,
+};
+use argmin_math::{ArgminAdd, ArgminDot, ArgminMul, ArgminSub};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Heavy-ball method
+///
+/// Polyak's heavy-ball method adds a momentum term to gradient descent with a fixed step size:
+///
+/// `x_{k+1} = x_k - step_size * \nabla f(x_k) + momentum * (x_k - x_{k-1})`
+///
+/// On ill-conditioned problems, the momentum term damps oscillations across narrow valleys and
+/// accelerates progress along them.
+///
+/// Optionally, the momentum is reset whenever it points uphill, i.e. when
+/// `\nabla f(x_k)^T (x_k - x_{k-1}) > 0` (gradient-based adaptive restart, see
+/// [`with_adaptive_restart`](`HeavyBall::with_adaptive_restart`)). This removes the oscillations
+/// caused by a too large momentum without requiring knowledge of the condition number of the
+/// problem.
+///
+/// The initial parameter vector needs to be provided via the `configure` method of the
+/// [`Executor`](`crate::core::Executor`).
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`] and [`Gradient`].
+///
+/// ## References
+///
+/// Boris T. Polyak (1964). Some methods of speeding up the convergence of iteration methods.
+/// USSR Computational Mathematics and Mathematical Physics 4(5), 1-17.
+///
+/// Brendan O'Donoghue and Emmanuel Candès (2015). Adaptive restart for accelerated gradient
+/// schemes. Foundations of Computational Mathematics 15, 715-732.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct HeavyBall<P, F> {
+    /// Step size
+    step_size: F,
+    /// Momentum
+    momentum: F,
+    /// Reset momentum when it points uphill
+    adaptive_restart: bool,
+    /// Previous parameter vector
+    prev_param: Option<P>,
+}
+
+impl<P, F> HeavyBall<P, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`HeavyBall`]
+    ///
+    /// Takes the step size, which must be larger than 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::gradientdescent::HeavyBall;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let hb: HeavyBall<Vec<f64>, f64> = HeavyBall::new(0.01)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(step_size: F) -> Result<Self, Error> {
+        if step_size <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`HeavyBall`: Step size must be > 0."
+            ));
+        }
+        Ok(HeavyBall {
+            step_size,
+            momentum: float!(0.9),
+            adaptive_restart: false,
+            prev_param: None,
+        })
+    }
+
+    /// Set momentum
+    ///
+    /// Must lie in `[0, 1)` and defaults to `0.9`. A momentum of `0` results in gradient descent
+    /// with a fixed step size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::gradientdescent::HeavyBall;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let hb: HeavyBall<Vec<f64>, f64> = HeavyBall::new(0.01)?.with_momentum(0.5)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_momentum(mut self, momentum: F) -> Result<Self, Error> {
+        if momentum < float!(0.0) || momentum >= float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`HeavyBall`: Momentum must be in [0, 1)."
+            ));
+        }
+        self.momentum = momentum;
+        Ok(self)
+    }
+
+    /// Enable or disable adaptive restart
+    ///
+    /// If enabled, the momentum is reset whenever it points in a direction of ascent.
+    /// Defaults to `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::gradientdescent::HeavyBall;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let hb: HeavyBall<Vec<f64>, f64> = HeavyBall::new(0.01)?.with_adaptive_restart(true);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_adaptive_restart(mut self, adaptive_restart: bool) -> Self {
+        self.adaptive_restart = adaptive_restart;
+        self
+    }
+}
+
+impl<O, P, G, F> Solver<O, IterState<P, G, (), (), F>> for HeavyBall<P, F>
+where
+    O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
+    P: Clone + SerializeAlias + ArgminAdd<P, P> + ArgminSub<P, P> + ArgminMul<F, P>,
+    G: Clone + SerializeAlias + ArgminMul<F, P> + ArgminDot<P, F>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Heavy ball";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`HeavyBall` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+
+        let grad = state
+            .take_gradient()
+            .map(Result::Ok)
+            .unwrap_or_else(|| problem.gradient(&param))?;
+
+        let cost = state.get_cost();
+        let cost = if cost.is_infinite() && cost.is_sign_positive() {
+            problem.cost(&param)?
+        } else {
+            cost
+        };
+
+        self.prev_param = None;
+        Ok((state.param(param).cost(cost).gradient(grad), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`HeavyBall`: Parameter vector in state not set."
+        ))?;
+        let grad = state.take_gradient().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`HeavyBall`: Gradient in state not set."
+        ))?;
+
+        let mut new_param = param.sub(&grad.mul(&self.step_size));
+        let mut restart = false;
+        if let Some(prev_param) = self.prev_param.take() {
+            let velocity = param.sub(&prev_param);
+            restart = self.adaptive_restart && grad.dot(&velocity) > float!(0.0);
+            if !restart {
+                new_param = new_param.add(&velocity.mul(&self.momentum));
+            }
+        }
+
+        let new_cost = problem.cost(&new_param)?;
+        let new_grad = problem.gradient(&new_param)?;
+        self.prev_param = Some(param);
+
+        Ok((
+            state.param(new_param).cost(new_cost).gradient(new_grad),
+            Some(kv!("restart" => restart;)),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::test_utils::TestProblem;
+    use crate::core::{ArgminError, Executor, State};
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(heavy_ball, HeavyBall<Vec<f64>, f64>);
+
+    /// Ill-conditioned quadratic `f(x) = 1/2 * (x_0^2 + 100 * x_1^2)`
+    struct Quadratic {}
+
+    impl CostFunction for Quadratic {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(0.5 * (p[0].powi(2) + 100.0 * p[1].powi(2)))
+        }
+    }
+
+    impl Gradient for Quadratic {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(vec![p[0], 100.0 * p[1]])
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let HeavyBall {
+            step_size,
+            momentum,
+            adaptive_restart,
+            prev_param,
+        }: HeavyBall<Vec<f64>, f64> = HeavyBall::new(0.1).unwrap();
+        assert_eq!(step_size.to_ne_bytes(), 0.1f64.to_ne_bytes());
+        assert_eq!(momentum.to_ne_bytes(), 0.9f64.to_ne_bytes());
+        assert!(!adaptive_restart);
+        assert!(prev_param.is_none());
+
+        for step_size in [0.0, -1.0] {
+            let res: Result<HeavyBall<Vec<f64>, f64>, _> = HeavyBall::new(step_size);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`HeavyBall`: Step size must be > 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_momentum() {
+        for momentum in [0.0, 0.5, 1.0 - f64::EPSILON] {
+            let hb: HeavyBall<Vec<f64>, f64> = HeavyBall::new(0.1).unwrap();
+            let hb = hb.with_momentum(momentum).unwrap();
+            assert_eq!(hb.momentum.to_ne_bytes(), momentum.to_ne_bytes());
+        }
+
+        for momentum in [-f64::EPSILON, -1.0, 1.0, 2.0] {
+            let hb: HeavyBall<Vec<f64>, f64> = HeavyBall::new(0.1).unwrap();
+            let res = hb.with_momentum(momentum);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`HeavyBall`: Momentum must be in [0, 1).\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_adaptive_restart() {
+        let hb: HeavyBall<Vec<f64>, f64> = HeavyBall::new(0.1).unwrap();
+        assert!(hb.with_adaptive_restart(true).adaptive_restart);
+    }
+
+    #[test]
+    fn test_init_param_not_initialized() {
+        let mut hb: HeavyBall<Vec<f64>, f64> = HeavyBall::new(0.1).unwrap();
+        let res = hb.init(&mut Problem::new(TestProblem::new()), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`HeavyBall` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_next_iter() {
+        let mut hb: HeavyBall<Vec<f64>, f64> = HeavyBall::new(0.01).unwrap();
+        let mut problem = Problem::new(Quadratic {});
+        let state = IterState::new().param(vec![1.0, 1.0]);
+        let (state, _) = hb.init(&mut problem, state).unwrap();
+
+        // First step is a plain gradient step
+        let (state, _) = hb.next_iter(&mut problem, state).unwrap();
+        let param = state.get_param().unwrap();
+        assert_relative_eq!(param[0], 0.99, epsilon = f64::EPSILON);
+        assert_relative_eq!(param[1], 0.0, epsilon = f64::EPSILON);
+
+        // Second step adds the momentum term
+        let (state, _) = hb.next_iter(&mut problem, state).unwrap();
+        let param = state.get_param().unwrap();
+        assert_relative_eq!(param[0], 0.99 - 0.0099 - 0.009, epsilon = f64::EPSILON);
+        assert_relative_eq!(param[1], -0.9, epsilon = f64::EPSILON);
+    }
+
+    #[test]
+    fn test_solver() {
+        for adaptive_restart in [false, true] {
+            let hb = HeavyBall::new(0.01)
+                .unwrap()
+                .with_momentum(0.8)
+                .unwrap()
+                .with_adaptive_restart(adaptive_restart);
+            let res = Executor::new(Quadratic {}, hb)
+                .configure(|state| state.param(vec![1.0, 1.0]).max_iters(1000))
+                .run()
+                .unwrap();
+            let param = res.state.get_best_param().unwrap();
+            assert_relative_eq!(param[0], 0.0, epsilon = 1e-6);
+            assert_relative_eq!(param[1], 0.0, epsilon = 1e-6);
+        }
+    }
+}
@@ -7,13 +7,19 @@
 
 //! # Gradient descent methods
 //!
-//! [`SteepestDescent`]
+//! * [`SteepestDescent`]
+//! * [`HeavyBall`]: Polyak's heavy-ball method with optional adaptive restart
 //!
-//! ## Reference
+//! ## References
 //!
 //! Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
 //! Springer. ISBN 0-387-30303-0.
+//!
+//! Boris T. Polyak (1964). Some methods of speeding up the convergence of iteration methods.
+//! USSR Computational Mathematics and Mathematical Physics 4(5), 1-17.
 
+mod heavyball;
 mod steepestdescent;
 
+pub use self::heavyball::*;
 pub use self::steepestdescent::*;
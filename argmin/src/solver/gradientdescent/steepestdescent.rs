@@ -9,6 +9,7 @@ use crate::core::{
     ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, Executor, Gradient, IterState,
     LineSearch, OptimizationResult, Problem, SerializeAlias, Solver, KV,
 };
+use crate::solver::linesearch::linesearch_kv;
 use argmin_math::ArgminMul;
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
@@ -81,6 +82,7 @@ where
         // Run line search
         let OptimizationResult {
             problem: line_problem,
+            solver: linesearch,
             state: mut linesearch_state,
             ..
         } = Executor::new(
@@ -93,6 +95,7 @@ where
         .configure(|config| config.param(param_new).gradient(new_grad).cost(new_cost))
         .ctrlc(false)
         .run()?;
+        self.linesearch = linesearch;
 
         // Get back problem and function evaluation counts
         problem.consume_problem(line_problem);
@@ -108,7 +111,7 @@ where
                         ))?,
                 )
                 .cost(linesearch_state.get_cost()),
-            None,
+            Some(linesearch_kv(&self.linesearch, &linesearch_state)),
         ))
     }
 }
@@ -185,7 +188,16 @@ mod tests {
             )
             .unwrap();
 
-        assert!(kv.is_none());
+        // Backtracking accepts the third trial step 0.9^3
+        let kv = kv.unwrap();
+        assert_eq!(kv.get("ls_iters").unwrap().get_uint(), Some(3));
+        assert_eq!(kv.get("ls_cost_count").unwrap().get_uint(), Some(4));
+        assert_eq!(kv.get("ls_converged").unwrap().get_bool(), Some(true));
+        assert_relative_eq!(
+            kv.get("ls_step_length").unwrap().get_float().unwrap(),
+            0.729,
+            epsilon = 1e-12
+        );
 
         assert_relative_eq!(
             state.param.as_ref().unwrap()[0],
@@ -0,0 +1,597 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Primal-dual interior point method
+//!
+//! Interior point method for smooth optimization problems with nonlinear inequality constraints
+//! `g_i(x) <= 0`. See [`InteriorPoint`] for details.
+//!
+//! ## Reference
+//!
+//! Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
+//! Springer. ISBN 0-387-30303-0.
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, Gradient, Hessian, InequalityConstraint, IterState, Problem,
+    Solver, State, TerminationReason, TerminationStatus, KV,
+};
+use argmin_math::{ArgminAdd, ArgminDot, ArgminInv, ArgminL2Norm, ArgminMul, ArgminScaledAdd};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Primal-dual interior point method
+///
+/// Solves problems of the form
+///
+/// `min_x f(x)` subject to `g_i(x) <= 0` for `i = 1, ..., m`
+///
+/// by following the central path of the log-barrier problem
+///
+/// `min_x f(x) - mu * sum_i ln(-g_i(x))`
+///
+/// for a decreasing sequence of barrier parameters `mu`. In each iteration, a Newton step on the
+/// perturbed KKT conditions `grad f(x) + sum_i lambda_i grad g_i(x) = 0`,
+/// `-lambda_i g_i(x) = mu` is computed for both the primal variables `x` and the dual variables
+/// `lambda`. The step is shortened by a fraction-to-boundary rule and a backtracking line search
+/// on the barrier function, such that all iterates remain strictly feasible. The barrier
+/// parameter is only reduced once the current iterate is sufficiently close to the central path
+/// (centrality step), otherwise another Newton step is taken for the same `mu`.
+///
+/// The curvature of the constraints is not taken into account in the Newton system. This is exact
+/// for linear constraints and still yields descent directions for nonlinear constraints.
+///
+/// The initial parameter vector must be strictly feasible, i.e. `g_i(x_0) < 0` for all `i`.
+///
+/// The algorithm stops if the KKT residual (the maximum of the norm of the gradient of the
+/// Lagrangian and the complementarity `-lambda_i g_i(x)`) falls below the tolerance set via
+/// [`with_tolerance`](`InteriorPoint::with_tolerance`).
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`], [`Gradient`], [`Hessian`]
+/// and [`InequalityConstraint`] (including
+/// [`inequality_jacobian`](`InequalityConstraint::inequality_jacobian`)).
+///
+/// ## Reference
+///
+/// Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
+/// Springer. ISBN 0-387-30303-0.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct InteriorPoint<F> {
+    /// Barrier parameter
+    mu: F,
+    /// Factor by which the barrier parameter is reduced
+    mu_factor: F,
+    /// Fraction-to-boundary parameter
+    tau: F,
+    /// Tolerance on the KKT residual
+    tol: F,
+    /// Estimates of the Lagrange multipliers
+    lambda: Vec<F>,
+    /// KKT residual of the previous iterate
+    kkt_error: F,
+}
+
+impl<F> InteriorPoint<F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`InteriorPoint`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::interiorpoint::InteriorPoint;
+    /// let ip: InteriorPoint<f64> = InteriorPoint::new();
+    /// ```
+    pub fn new() -> Self {
+        InteriorPoint {
+            mu: float!(0.1),
+            mu_factor: float!(0.2),
+            tau: float!(0.995),
+            tol: F::epsilon().sqrt(),
+            lambda: vec![],
+            kkt_error: F::infinity(),
+        }
+    }
+
+    /// Set the initial barrier parameter
+    ///
+    /// Must be larger than 0 and defaults to `0.1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::interiorpoint::InteriorPoint;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let ip: InteriorPoint<f64> = InteriorPoint::new().with_barrier_parameter(1.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_barrier_parameter(mut self, mu: F) -> Result<Self, Error> {
+        if mu <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`InteriorPoint`: barrier parameter must be > 0."
+            ));
+        }
+        self.mu = mu;
+        Ok(self)
+    }
+
+    /// Set the factor by which the barrier parameter is reduced once the iterate is close to the
+    /// central path
+    ///
+    /// Must be in `(0, 1)` and defaults to `0.2`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::interiorpoint::InteriorPoint;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let ip: InteriorPoint<f64> = InteriorPoint::new().with_barrier_reduction(0.5)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_barrier_reduction(mut self, factor: F) -> Result<Self, Error> {
+        if factor <= float!(0.0) || factor >= float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`InteriorPoint`: barrier reduction factor must be in (0, 1)."
+            ));
+        }
+        self.mu_factor = factor;
+        Ok(self)
+    }
+
+    /// Set the fraction-to-boundary parameter `tau`
+    ///
+    /// Steps are shortened such that the slacks `-g_i(x)` and the Lagrange multipliers are
+    /// reduced by at most the fraction `tau`. Must be in `(0, 1)` and defaults to `0.995`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::interiorpoint::InteriorPoint;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let ip: InteriorPoint<f64> = InteriorPoint::new().with_fraction_to_boundary(0.99)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_fraction_to_boundary(mut self, tau: F) -> Result<Self, Error> {
+        if tau <= float!(0.0) || tau >= float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`InteriorPoint`: fraction-to-boundary parameter must be in (0, 1)."
+            ));
+        }
+        self.tau = tau;
+        Ok(self)
+    }
+
+    /// Set the tolerance on the KKT residual
+    ///
+    /// Must be non-negative and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::interiorpoint::InteriorPoint;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let ip: InteriorPoint<f64> = InteriorPoint::new().with_tolerance(1e-10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`InteriorPoint`: tolerance must be >= 0."
+            ));
+        }
+        self.tol = tol;
+        Ok(self)
+    }
+}
+
+impl<F> Default for InteriorPoint<F>
+where
+    F: ArgminFloat,
+{
+    fn default() -> InteriorPoint<F> {
+        InteriorPoint::new()
+    }
+}
+
+/// Value of the barrier function `f - mu * sum_i ln(-g_i)`. Returns `None` if any of the
+/// constraints is not strictly satisfied.
+fn barrier<F: ArgminFloat>(cost: F, constraints: &[F], mu: F) -> Option<F> {
+    if constraints.iter().any(|c| *c >= float!(0.0)) {
+        return None;
+    }
+    Some(
+        cost - mu
+            * constraints
+                .iter()
+                .map(|c| (-*c).ln())
+                .fold(float!(0.0), |a, b| a + b),
+    )
+}
+
+impl<O, P, G, H, F> Solver<O, IterState<P, G, (), H, F>> for InteriorPoint<F>
+where
+    O: CostFunction<Param = P, Output = F>
+        + Gradient<Param = P, Gradient = G>
+        + Hessian<Param = P, Hessian = H>
+        + InequalityConstraint<Param = P, Gradient = G, Float = F>,
+    P: Clone + ArgminScaledAdd<P, F, P>,
+    G: Clone
+        + ArgminScaledAdd<G, F, G>
+        + ArgminMul<F, G>
+        + ArgminDot<G, H>
+        + ArgminDot<P, F>
+        + ArgminL2Norm<F>,
+    H: ArgminAdd<H, H> + ArgminInv<H> + ArgminDot<G, P>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Interior point method";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, G, (), H, F>,
+    ) -> Result<(IterState<P, G, (), H, F>, Option<KV>), Error> {
+        let param = state.get_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`InteriorPoint` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        let constraints = problem.inequality_constraints(param)?;
+        if constraints.iter().any(|c| *c >= float!(0.0)) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`InteriorPoint`: initial parameter vector must be strictly feasible."
+            ));
+        }
+        // start on the central path for the initial barrier parameter
+        self.lambda = constraints.iter().map(|c| self.mu / (-*c)).collect();
+        self.kkt_error = F::infinity();
+        let cost = problem.cost(param)?;
+        Ok((state.cost(cost), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), H, F>,
+    ) -> Result<(IterState<P, G, (), H, F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`InteriorPoint`: Parameter vector in state not set."
+        ))?;
+        let cost = state.get_cost();
+        let grad = problem.gradient(&param)?;
+        let hessian = problem.hessian(&param)?;
+        let constraints = problem.inequality_constraints(&param)?;
+        let jacobian = problem.inequality_jacobian(&param)?;
+
+        if constraints.len() != self.lambda.len() || jacobian.len() != self.lambda.len() {
+            return Err(argmin_error!(
+                ConditionViolated,
+                "`InteriorPoint`: number of constraints or constraint gradients changed."
+            ));
+        }
+
+        let slack: Vec<F> = constraints.iter().map(|c| -*c).collect();
+
+        // KKT residuals: stationarity of the Lagrangian and complementarity
+        let lagrangian_grad = jacobian
+            .iter()
+            .zip(self.lambda.iter())
+            .fold(grad.clone(), |acc, (a, l)| acc.scaled_add(l, a));
+        let stationarity = lagrangian_grad.l2_norm();
+        let complementarity = self
+            .lambda
+            .iter()
+            .zip(slack.iter())
+            .map(|(l, s)| *l * *s)
+            .fold(float!(0.0), F::max);
+        self.kkt_error = stationarity.max(complementarity);
+
+        // Centrality: reduce the barrier parameter only when close to the central path
+        let centrality = self
+            .lambda
+            .iter()
+            .zip(slack.iter())
+            .map(|(l, s)| (*l * *s - self.mu).abs())
+            .fold(stationarity, F::max);
+        if centrality <= float!(10.0) * self.mu {
+            self.mu = (self.mu * self.mu_factor).max(F::epsilon());
+        }
+
+        // Newton system (H + sum_i lambda_i / s_i * a_i a_i^T) d = -(grad + sum_i mu / s_i * a_i)
+        let mut mat = hessian;
+        let mut barrier_grad = grad;
+        for ((a, l), s) in jacobian.iter().zip(self.lambda.iter()).zip(slack.iter()) {
+            let outer: H = a.mul(&(*l / *s)).dot(a);
+            mat = mat.add(&outer);
+            barrier_grad = barrier_grad.scaled_add(&(self.mu / *s), a);
+        }
+        // `neg_dir` is the negative of the Newton direction
+        let neg_dir: P = mat.inv()?.dot(&barrier_grad);
+        let dir_deriv: F = -barrier_grad.dot(&neg_dir);
+
+        // change of the constraints along the direction: a_i^T d
+        let jd: Vec<F> = jacobian.iter().map(|a| -a.dot(&neg_dir)).collect();
+        let dlambda: Vec<F> = self
+            .lambda
+            .iter()
+            .zip(slack.iter())
+            .zip(jd.iter())
+            .map(|((l, s), jd)| (self.mu - *l * *s + *l * *jd) / *s)
+            .collect();
+
+        // Fraction-to-boundary rule for the primal and the dual step
+        let alpha_primal = slack
+            .iter()
+            .zip(jd.iter())
+            .filter(|(_, jd)| **jd > float!(0.0))
+            .map(|(s, jd)| self.tau * *s / *jd)
+            .fold(float!(1.0), F::min);
+        let alpha_dual = self
+            .lambda
+            .iter()
+            .zip(dlambda.iter())
+            .filter(|(_, dl)| **dl < float!(0.0))
+            .map(|(l, dl)| -self.tau * *l / *dl)
+            .fold(float!(1.0), F::min);
+
+        // Backtracking line search on the barrier function which keeps the iterates strictly
+        // feasible
+        let phi = barrier(cost, &constraints, self.mu).ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`InteriorPoint`: current parameter vector is infeasible."
+        ))?;
+        let mut alpha = alpha_primal;
+        let mut accepted = None;
+        while alpha > F::epsilon() {
+            let new_param = param.scaled_add(&(-alpha), &neg_dir);
+            let new_constraints = problem.inequality_constraints(&new_param)?;
+            if new_constraints.iter().all(|c| *c < float!(0.0)) {
+                let new_cost = problem.cost(&new_param)?;
+                let new_phi = barrier(new_cost, &new_constraints, self.mu).unwrap();
+                if new_phi <= phi + float!(1e-4) * alpha * dir_deriv {
+                    accepted = Some((new_param, new_cost));
+                    break;
+                }
+            }
+            alpha = alpha * float!(0.5);
+        }
+
+        let kv = kv!(
+            "mu" => self.mu;
+            "kkt_error" => self.kkt_error;
+            "step_length" => alpha;
+        );
+
+        let (new_param, new_cost) = match accepted {
+            Some(accepted) => accepted,
+            None => {
+                return Ok((
+                    state
+                        .param(param)
+                        .cost(cost)
+                        .terminate_with(TerminationReason::SolverExit(
+                            "Line search failed to find a sufficient decrease".to_string(),
+                        )),
+                    Some(kv),
+                ))
+            }
+        };
+
+        for (l, dl) in self.lambda.iter_mut().zip(dlambda.iter()) {
+            *l = *l + alpha_dual * *dl;
+        }
+
+        Ok((state.param(new_param).cost(new_cost), Some(kv)))
+    }
+
+    fn terminate(&mut self, _state: &IterState<P, G, (), H, F>) -> TerminationStatus {
+        if self.kkt_error <= self.tol {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+    #[cfg(feature = "_ndarrayl")]
+    use crate::core::Executor;
+    use crate::test_trait_impl;
+
+    test_trait_impl!(interior_point, InteriorPoint<f64>);
+
+    #[test]
+    fn test_new() {
+        let ip: InteriorPoint<f64> = InteriorPoint::new();
+        let InteriorPoint {
+            mu,
+            mu_factor,
+            tau,
+            tol,
+            lambda,
+            kkt_error,
+        } = ip;
+        assert_eq!(mu.to_ne_bytes(), 0.1f64.to_ne_bytes());
+        assert_eq!(mu_factor.to_ne_bytes(), 0.2f64.to_ne_bytes());
+        assert_eq!(tau.to_ne_bytes(), 0.995f64.to_ne_bytes());
+        assert_eq!(tol.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert!(lambda.is_empty());
+        assert!(kkt_error.is_infinite());
+    }
+
+    #[test]
+    fn test_with_barrier_parameter() {
+        for mu in [f64::EPSILON, 0.1, 1.0, 100.0] {
+            let ip: InteriorPoint<f64> = InteriorPoint::new().with_barrier_parameter(mu).unwrap();
+            assert_eq!(ip.mu.to_ne_bytes(), mu.to_ne_bytes());
+        }
+
+        for mu in [0.0, -f64::EPSILON, -1.0] {
+            let res = InteriorPoint::new().with_barrier_parameter(mu);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`InteriorPoint`: barrier parameter must be > 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_barrier_reduction() {
+        for factor in [f64::EPSILON, 0.1, 0.5, 1.0 - f64::EPSILON] {
+            let ip: InteriorPoint<f64> =
+                InteriorPoint::new().with_barrier_reduction(factor).unwrap();
+            assert_eq!(ip.mu_factor.to_ne_bytes(), factor.to_ne_bytes());
+        }
+
+        for factor in [0.0, 1.0, -1.0, 2.0] {
+            let res = InteriorPoint::new().with_barrier_reduction(factor);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`InteriorPoint`: barrier reduction factor must be in (0, 1).\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_fraction_to_boundary() {
+        for tau in [f64::EPSILON, 0.9, 0.995] {
+            let ip: InteriorPoint<f64> =
+                InteriorPoint::new().with_fraction_to_boundary(tau).unwrap();
+            assert_eq!(ip.tau.to_ne_bytes(), tau.to_ne_bytes());
+        }
+
+        for tau in [0.0, 1.0, -1.0, 2.0] {
+            let res = InteriorPoint::new().with_fraction_to_boundary(tau);
+            assert_error!(
+                res,
+                ArgminError,
+                concat!(
+                    "Invalid parameter: \"`InteriorPoint`: fraction-to-boundary parameter ",
+                    "must be in (0, 1).\""
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_tolerance() {
+        for tol in [0.0, 1e-10, 1.0] {
+            let ip: InteriorPoint<f64> = InteriorPoint::new().with_tolerance(tol).unwrap();
+            assert_eq!(ip.tol.to_ne_bytes(), tol.to_ne_bytes());
+        }
+
+        for tol in [-f64::EPSILON, -1.0] {
+            let res = InteriorPoint::new().with_tolerance(tol);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`InteriorPoint`: tolerance must be >= 0.\""
+            );
+        }
+    }
+
+    #[cfg(feature = "_ndarrayl")]
+    #[test]
+    fn test_solver() {
+        use approx::assert_relative_eq;
+        use ndarray::{array, Array1, Array2};
+
+        /// min (x - 2)^2 + (y - 1)^2 s.t. x^2 + y^2 <= 1
+        struct DiskProblem {}
+
+        impl CostFunction for DiskProblem {
+            type Param = Array1<f64>;
+            type Output = f64;
+
+            fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok((p[0] - 2.0).powi(2) + (p[1] - 1.0).powi(2))
+            }
+        }
+
+        impl Gradient for DiskProblem {
+            type Param = Array1<f64>;
+            type Gradient = Array1<f64>;
+
+            fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+                Ok(array![2.0 * (p[0] - 2.0), 2.0 * (p[1] - 1.0)])
+            }
+        }
+
+        impl Hessian for DiskProblem {
+            type Param = Array1<f64>;
+            type Hessian = Array2<f64>;
+
+            fn hessian(&self, _p: &Self::Param) -> Result<Self::Hessian, Error> {
+                Ok(array![[2.0, 0.0], [0.0, 2.0]])
+            }
+        }
+
+        impl InequalityConstraint for DiskProblem {
+            type Param = Array1<f64>;
+            type Gradient = Array1<f64>;
+            type Float = f64;
+
+            fn inequality_constraints(&self, p: &Self::Param) -> Result<Vec<f64>, Error> {
+                Ok(vec![p[0].powi(2) + p[1].powi(2) - 1.0])
+            }
+
+            fn inequality_jacobian(&self, p: &Self::Param) -> Result<Vec<Array1<f64>>, Error> {
+                Ok(vec![array![2.0 * p[0], 2.0 * p[1]]])
+            }
+        }
+
+        // Infeasible initial guess
+        let res = Executor::new(DiskProblem {}, InteriorPoint::new())
+            .configure(|state| state.param(array![1.0, 1.0]))
+            .run();
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`InteriorPoint`: initial parameter vector must be ",
+                "strictly feasible.\""
+            )
+        );
+
+        let res = Executor::new(DiskProblem {}, InteriorPoint::new())
+            .configure(|state| state.param(array![0.0, 0.0]).max_iters(100))
+            .run()
+            .unwrap();
+
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let best = res.state.get_best_param().unwrap();
+        let norm = 5.0f64.sqrt();
+        assert_relative_eq!(best[0], 2.0 / norm, epsilon = 1e-6);
+        assert_relative_eq!(best[1], 1.0 / norm, epsilon = 1e-6);
+    }
+}
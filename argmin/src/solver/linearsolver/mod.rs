@@ -0,0 +1,312 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Linear solvers
+//!
+//! Strategies for solving the linear systems `A * x = b` which arise in second order methods,
+//! such as the Newton equations `H * p = g` of [`Newton`](`crate::solver::newton::Newton`) and
+//! the normal equations `J^T J * p = J^T r` of
+//! [`GaussNewton`](`crate::solver::gaussnewton::GaussNewton`).
+//!
+//! * [`InverseSolver`]: Computes the inverse of `A` explicitly. This is the default and only
+//!   kept for backwards compatibility; it is the slowest and least accurate strategy.
+//! * [`CholeskySolver`]: Cholesky factorization. The method of choice for symmetric and positive
+//!   definite `A`, such as the Hessian close to a minimum or the normal equations.
+//! * [`LUSolver`]: LU factorization with partial pivoting, for general non-singular `A`.
+//! * [`QRSolver`]: QR factorization, which is more robust than LU for ill-conditioned `A`.
+//! * [`CGSolver`]: Conjugate gradient method. Only requires matrix-vector products, therefore `A`
+//!   can be any [`LinearOperator`], which suits large (and sparse) systems with symmetric and
+//!   positive definite `A`. The solution is approximate.
+//!
+//! The factorizations are provided by the `ArgminCholeskySolve`, `ArgminLuSolve` and
+//! `ArgminQrSolve` traits of argmin-math, which are implemented for `Vec<Vec<f32>>`,
+//! `Vec<Vec<f64>>` and square `nalgebra` matrices.
+
+use crate::core::{ArgminFloat, Error, IterState, Problem, SerializeAlias, Solver, State};
+use crate::solver::conjugategradient::{ConjugateGradient, LinearOperatorProblem};
+use argmin_math::{
+    ArgminCholeskySolve, ArgminConj, ArgminDot, ArgminInv, ArgminL2Norm, ArgminLuSolve, ArgminMul,
+    ArgminQrSolve, ArgminScaledAdd, ArgminSub, ArgminZeroLike, LinearOperator,
+};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Solves linear systems `A * x = b`
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::Error;
+/// use argmin::solver::linearsolver::LinearSolver;
+///
+/// /// Solves diagonal systems
+/// struct DiagonalSolver {}
+///
+/// impl LinearSolver<Vec<f64>, Vec<f64>> for DiagonalSolver {
+///     fn solve(&self, a: &Vec<f64>, b: &Vec<f64>) -> Result<Vec<f64>, Error> {
+///         Ok(b.iter().zip(a.iter()).map(|(b, a)| b / a).collect())
+///     }
+/// }
+/// ```
+pub trait LinearSolver<A, B> {
+    /// Returns `x` such that `a * x = b`
+    fn solve(&self, a: &A, b: &B) -> Result<B, Error>;
+}
+
+/// # Explicit inverse
+///
+/// Computes `x = A^-1 * b` via [`ArgminInv`].
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct InverseSolver {}
+
+impl InverseSolver {
+    /// Construct a new instance of [`InverseSolver`]
+    pub fn new() -> Self {
+        InverseSolver {}
+    }
+}
+
+impl<A, B> LinearSolver<A, B> for InverseSolver
+where
+    A: ArgminInv<A> + ArgminDot<B, B>,
+{
+    fn solve(&self, a: &A, b: &B) -> Result<B, Error> {
+        Ok(a.inv()?.dot(b))
+    }
+}
+
+/// # Cholesky factorization
+///
+/// Requires `A` to be symmetric and positive definite and returns an error otherwise.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::solver::linearsolver::{CholeskySolver, LinearSolver};
+/// let a = vec![vec![4.0f64, 2.0], vec![2.0, 3.0]];
+/// let x = CholeskySolver::new().solve(&a, &vec![2.0, 3.0]).unwrap();
+/// # assert!((x[0]).abs() < 1e-12 && (x[1] - 1.0).abs() < 1e-12);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct CholeskySolver {}
+
+impl CholeskySolver {
+    /// Construct a new instance of [`CholeskySolver`]
+    pub fn new() -> Self {
+        CholeskySolver {}
+    }
+}
+
+impl<A, B> LinearSolver<A, B> for CholeskySolver
+where
+    A: ArgminCholeskySolve<B>,
+{
+    fn solve(&self, a: &A, b: &B) -> Result<B, Error> {
+        a.cholesky_solve(b)
+    }
+}
+
+/// # LU factorization
+///
+/// LU factorization with partial pivoting. Returns an error if `A` is singular.
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct LUSolver {}
+
+impl LUSolver {
+    /// Construct a new instance of [`LUSolver`]
+    pub fn new() -> Self {
+        LUSolver {}
+    }
+}
+
+impl<A, B> LinearSolver<A, B> for LUSolver
+where
+    A: ArgminLuSolve<B>,
+{
+    fn solve(&self, a: &A, b: &B) -> Result<B, Error> {
+        a.lu_solve(b)
+    }
+}
+
+/// # QR factorization
+///
+/// Returns an error if `A` is singular.
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct QRSolver {}
+
+impl QRSolver {
+    /// Construct a new instance of [`QRSolver`]
+    pub fn new() -> Self {
+        QRSolver {}
+    }
+}
+
+impl<A, B> LinearSolver<A, B> for QRSolver
+where
+    A: ArgminQrSolve<B>,
+{
+    fn solve(&self, a: &A, b: &B) -> Result<B, Error> {
+        a.qr_solve(b)
+    }
+}
+
+/// # Conjugate gradient method
+///
+/// Approximately solves `A * x = b` with [`ConjugateGradient`], starting from `x = 0`. `A` must be
+/// symmetric and positive definite and is only used via [`LinearOperator::apply`]. The iteration
+/// stops once the residual satisfies `||b - A * x|| <= tol * ||b||` or after `max_iters`
+/// iterations.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::solver::linearsolver::{CGSolver, LinearSolver};
+/// let a = vec![vec![4.0f64, 2.0], vec![2.0, 3.0]];
+/// let solver = CGSolver::new().with_tolerance(1e-10).with_max_iters(10);
+/// let x = solver.solve(&a, &vec![2.0, 3.0]).unwrap();
+/// # assert!((x[0]).abs() < 1e-8 && (x[1] - 1.0).abs() < 1e-8);
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct CGSolver<F> {
+    /// Relative tolerance on the residual
+    tol: F,
+    /// Maximum number of iterations
+    max_iters: u64,
+}
+
+impl<F> CGSolver<F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`CGSolver`]
+    ///
+    /// The tolerance defaults to `sqrt(EPSILON)` and the maximum number of iterations to `100`.
+    pub fn new() -> Self {
+        CGSolver {
+            tol: F::epsilon().sqrt(),
+            max_iters: 100,
+        }
+    }
+
+    /// Set the relative tolerance on the residual
+    pub fn with_tolerance(mut self, tol: F) -> Self {
+        self.tol = tol;
+        self
+    }
+
+    /// Set the maximum number of iterations
+    pub fn with_max_iters(mut self, max_iters: u64) -> Self {
+        self.max_iters = max_iters;
+        self
+    }
+}
+
+impl<F> Default for CGSolver<F>
+where
+    F: ArgminFloat,
+{
+    fn default() -> Self {
+        CGSolver::new()
+    }
+}
+
+impl<A, B, F> LinearSolver<A, B> for CGSolver<F>
+where
+    A: LinearOperator<B>,
+    B: Clone
+        + SerializeAlias
+        + ArgminDot<B, F>
+        + ArgminSub<B, B>
+        + ArgminScaledAdd<B, F, B>
+        + ArgminConj
+        + ArgminMul<F, B>
+        + ArgminZeroLike,
+    F: ArgminFloat + ArgminL2Norm<F>,
+{
+    fn solve(&self, a: &A, b: &B) -> Result<B, Error> {
+        let mut problem = Problem::new(LinearOperatorProblem::new(a));
+        let mut cg: ConjugateGradient<B, F> = ConjugateGradient::new(b.clone());
+        // The cost of the CG state is the squared norm of the residual
+        let target = self.tol.powi(2) * b.dot(b);
+        let x0 = b.zero_like();
+        if target <= float!(0.0) {
+            return Ok(x0);
+        }
+        let (mut state, _) = cg.init(&mut problem, IterState::new().param(x0))?;
+        for _ in 0..self.max_iters {
+            (state, _) = cg.next_iter(&mut problem, state)?;
+            if state.get_cost() <= target {
+                break;
+            }
+        }
+        Ok(state.take_param().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(inverse_solver, InverseSolver);
+    test_trait_impl!(cholesky_solver, CholeskySolver);
+    test_trait_impl!(lu_solver, LUSolver);
+    test_trait_impl!(qr_solver, QRSolver);
+    test_trait_impl!(cg_solver, CGSolver<f64>);
+
+    fn check<S: LinearSolver<Vec<Vec<f64>>, Vec<f64>>>(solver: S, epsilon: f64) {
+        let a = vec![
+            vec![4.0, 2.0, -2.0],
+            vec![2.0, 10.0, 4.0],
+            vec![-2.0, 4.0, 9.0],
+        ];
+        let x = solver.solve(&a, &vec![6.0, 26.0, 15.0]).unwrap();
+        assert_relative_eq!(x[0], 1.0, epsilon = epsilon);
+        assert_relative_eq!(x[1], 2.0, epsilon = epsilon);
+        assert_relative_eq!(x[2], 1.0, epsilon = epsilon);
+    }
+
+    #[test]
+    fn test_factorizations() {
+        check(CholeskySolver::new(), 1e-12);
+        check(LUSolver::new(), 1e-12);
+        check(QRSolver::new(), 1e-12);
+    }
+
+    #[test]
+    fn test_cg() {
+        let solver: CGSolver<f64> = CGSolver::new();
+        assert_eq!(solver.tol.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert_eq!(solver.max_iters, 100);
+        check(solver.with_tolerance(1e-12), 1e-10);
+    }
+
+    #[test]
+    fn test_cg_max_iters() {
+        // A single CG step is a steepest descent step, which is exact for `A = 2 * I`
+        let a = vec![vec![2.0, 0.0], vec![0.0, 2.0]];
+        let solver: CGSolver<f64> = CGSolver::new().with_max_iters(1);
+        let x = solver.solve(&a, &vec![2.0f64, -4.0]).unwrap();
+        assert_relative_eq!(x[0], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(x[1], -2.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_cg_zero_rhs() {
+        let a = vec![vec![2.0, 0.0], vec![0.0, 2.0]];
+        let x = CGSolver::<f64>::new()
+            .solve(&a, &vec![0.0f64, 0.0])
+            .unwrap();
+        assert_eq!(x, vec![0.0, 0.0]);
+    }
+}
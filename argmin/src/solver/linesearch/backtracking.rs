@@ -10,10 +10,143 @@ use crate::core::{
     Solver, State, TerminationReason, TerminationStatus, KV,
 };
 use crate::solver::linesearch::condition::*;
-use argmin_math::ArgminScaledAdd;
+use argmin_math::{ArgminDot, ArgminScaledAdd};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 
+/// Heuristic for the initial step length of a [`BacktrackingLineSearch`]
+///
+/// All heuristics except `Fixed` use information from the previous line search. In the first
+/// iteration, or whenever the estimate is not a positive finite number, they fall back to the
+/// fixed initial step length.
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub enum InitialStep {
+    /// Always start from the initial step length (default)
+    #[default]
+    Fixed,
+    /// Scale the previously accepted step length by the ratio of the previous and the current
+    /// directional derivative: `alpha_{k-1} * g_{k-1}^T d_{k-1} / g_k^T d_k`
+    PreviousStep,
+    /// Barzilai-Borwein step length `s^T s / s^T y` with `s = x_k - x_{k-1}` and
+    /// `y = g_k - g_{k-1}`, projected onto the current search direction
+    BarzilaiBorwein,
+    /// Minimizer of the quadratic interpolating the change in cost of the previous iteration and
+    /// the current directional derivative: `min(alpha, 1.01 * 2 * (f_k - f_{k-1}) / g_k^T d_k)`
+    QuadraticFit,
+}
+
+/// Estimates the initial step length of a [`BacktrackingLineSearch`]
+pub trait InitialStepEstimator<P, G, F> {
+    /// Initial step length of the current line search
+    ///
+    /// `alpha` is the step length set via
+    /// [`initial_step_length`](`LineSearch::initial_step_length`), `cost` and `grad` are cost
+    /// and gradient at the initial point and `direction` is the search direction.
+    fn initial_step(&mut self, alpha: F, cost: F, grad: &G, direction: &P) -> F;
+
+    /// Record the step length accepted by the current line search
+    fn accepted_step(&mut self, alpha: F);
+}
+
+/// Starts every line search from the step length set via
+/// [`initial_step_length`](`LineSearch::initial_step_length`) (default)
+#[derive(Clone, Copy, Eq, PartialEq, Debug, Default)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct FixedInitialStep;
+
+impl<P, G, F> InitialStepEstimator<P, G, F> for FixedInitialStep {
+    fn initial_step(&mut self, alpha: F, _cost: F, _grad: &G, _direction: &P) -> F {
+        alpha
+    }
+
+    fn accepted_step(&mut self, _alpha: F) {}
+}
+
+/// Information about the previous line search
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+struct StepHistory<P, F> {
+    /// Initial cost
+    cost: F,
+    /// Initial directional derivative
+    dg: F,
+    /// Accepted step length
+    alpha: F,
+    /// Search direction (only stored for Barzilai-Borwein)
+    direction: Option<P>,
+    /// Squared norm of the search direction
+    direction_norm2: F,
+}
+
+/// Estimates the initial step length from the previous line search with one of the heuristics
+/// of [`InitialStep`]
+///
+/// Created via [`with_initial_step`](`BacktrackingLineSearch::with_initial_step`).
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct InitialStepHeuristic<P, F> {
+    /// Heuristic
+    method: InitialStep,
+    /// Previous line search
+    history: Option<StepHistory<P, F>>,
+}
+
+impl<P, F> InitialStepHeuristic<P, F> {
+    /// Construct a new instance of `InitialStepHeuristic`
+    fn new(method: InitialStep) -> Self {
+        InitialStepHeuristic {
+            method,
+            history: None,
+        }
+    }
+}
+
+impl<P, G, F> InitialStepEstimator<P, G, F> for InitialStepHeuristic<P, F>
+where
+    P: Clone + ArgminDot<P, F>,
+    G: ArgminDot<P, F>,
+    F: ArgminFloat,
+{
+    fn initial_step(&mut self, alpha: F, cost: F, grad: &G, direction: &P) -> F {
+        let dg = grad.dot(direction);
+        let direction_norm2 = direction.dot(direction);
+
+        let estimate = self
+            .history
+            .as_ref()
+            .and_then(|prev| match self.method {
+                InitialStep::Fixed => None,
+                InitialStep::PreviousStep => Some(prev.alpha * prev.dg / dg),
+                InitialStep::BarzilaiBorwein => prev.direction.as_ref().map(|prev_direction| {
+                    let sy = prev.alpha * (grad.dot(prev_direction) - prev.dg);
+                    let ss = prev.alpha * prev.alpha * prev.direction_norm2;
+                    -(ss / sy) * dg / direction_norm2
+                }),
+                InitialStep::QuadraticFit => {
+                    Some((float!(2.02) * (cost - prev.cost) / dg).min(alpha))
+                }
+            })
+            .filter(|alpha| alpha.is_finite() && *alpha > float!(0.0))
+            .unwrap_or(alpha);
+
+        self.history = Some(StepHistory {
+            cost,
+            dg,
+            alpha: estimate,
+            direction: (self.method == InitialStep::BarzilaiBorwein).then(|| direction.clone()),
+            direction_norm2,
+        });
+        estimate
+    }
+
+    fn accepted_step(&mut self, alpha: F) {
+        if let Some(history) = self.history.as_mut() {
+            history.alpha = alpha;
+        }
+    }
+}
+
 /// # Backtracking line search
 ///
 /// The Backtracking line search is a method which finds a step length from a given point along a
@@ -28,10 +161,13 @@ use serde::{Deserialize, Serialize};
 /// Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
 /// Springer. ISBN 0-387-30303-0.
 ///
+/// The initial step length of each line search is either fixed or estimated from the previous
+/// line search (see [`with_initial_step`](`BacktrackingLineSearch::with_initial_step`)).
+///
 /// Wikipedia: <https://en.wikipedia.org/wiki/Backtracking_line_search>
 #[derive(Clone, Eq, PartialEq, Debug)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
-pub struct BacktrackingLineSearch<P, G, L, F> {
+pub struct BacktrackingLineSearch<P, G, L, F, S = FixedInitialStep> {
     /// initial parameter vector
     init_param: Option<P>,
     /// initial cost
@@ -44,8 +180,12 @@ pub struct BacktrackingLineSearch<P, G, L, F> {
     rho: F,
     /// Stopping condition
     condition: L,
+    /// Initial step length
+    init_alpha: F,
     /// alpha
     alpha: F,
+    /// Initial step length estimator
+    initial_step: S,
 }
 
 impl<P, G, L, F> BacktrackingLineSearch<P, G, L, F>
@@ -71,10 +211,17 @@ where
             search_direction: None,
             rho: float!(0.9),
             condition,
+            init_alpha: float!(1.0),
             alpha: float!(1.0),
+            initial_step: FixedInitialStep,
         }
     }
+}
 
+impl<P, G, L, F, S> BacktrackingLineSearch<P, G, L, F, S>
+where
+    F: ArgminFloat,
+{
     /// Set contraction factor rho
     ///
     /// This factor must be in (0, 1).
@@ -102,9 +249,53 @@ where
         self.rho = rho;
         Ok(self)
     }
+
+    /// Set heuristic for the initial step length
+    ///
+    /// By default, every line search starts from the step length set via
+    /// [`initial_step_length`](`LineSearch::initial_step_length`) (`1.0` by default). The
+    /// heuristics of [`InitialStep`] estimate the initial step length from the previous line
+    /// search, which requires both parameter vector and gradient to implement [`ArgminDot`].
+    /// The previous line search is stored in the line search returned to the solver after each
+    /// iteration.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::linesearch::{BacktrackingLineSearch, InitialStep};
+    /// # use argmin::solver::linesearch::condition::ArmijoCondition;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let backtracking: BacktrackingLineSearch<Vec<f64>, Vec<f64>, _, f64, _> =
+    ///     BacktrackingLineSearch::new(ArmijoCondition::new(0.0001f64)?)
+    ///         .with_initial_step(InitialStep::BarzilaiBorwein);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_initial_step(
+        self,
+        initial_step: InitialStep,
+    ) -> BacktrackingLineSearch<P, G, L, F, InitialStepHeuristic<P, F>>
+    where
+        P: Clone + ArgminDot<P, F>,
+        G: ArgminDot<P, F>,
+    {
+        BacktrackingLineSearch {
+            init_param: self.init_param,
+            init_cost: self.init_cost,
+            init_grad: self.init_grad,
+            search_direction: self.search_direction,
+            rho: self.rho,
+            condition: self.condition,
+            init_alpha: self.init_alpha,
+            alpha: self.alpha,
+            initial_step: InitialStepHeuristic::new(initial_step),
+        }
+    }
 }
 
-impl<P, G, L, F> LineSearch<P, F> for BacktrackingLineSearch<P, G, L, F>
+impl<P, G, L, F, S> LineSearch<P, F> for BacktrackingLineSearch<P, G, L, F, S>
 where
     F: ArgminFloat,
 {
@@ -121,12 +312,18 @@ where
                 "LineSearch: Initial alpha must be > 0."
             ));
         }
+        self.init_alpha = alpha;
         self.alpha = alpha;
         Ok(())
     }
+
+    /// Accepted step length
+    fn step_length(&self) -> Option<F> {
+        Some(self.alpha)
+    }
 }
 
-impl<P, G, L, F> BacktrackingLineSearch<P, G, L, F>
+impl<P, G, L, F, S> BacktrackingLineSearch<P, G, L, F, S>
 where
     P: ArgminScaledAdd<P, F, P>,
     L: LineSearchCondition<P, G, F>,
@@ -174,12 +371,14 @@ where
     }
 }
 
-impl<O, P, G, L, F> Solver<O, IterState<P, G, (), (), F>> for BacktrackingLineSearch<P, G, L, F>
+impl<O, P, G, L, F, S> Solver<O, IterState<P, G, (), (), F>>
+    for BacktrackingLineSearch<P, G, L, F, S>
 where
     P: Clone + SerializeAlias + ArgminScaledAdd<P, F, P>,
     G: SerializeAlias + ArgminScaledAdd<P, F, P>,
     O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
     L: LineSearchCondition<P, G, F> + SerializeAlias,
+    S: InitialStepEstimator<P, G, F> + SerializeAlias,
     F: ArgminFloat,
 {
     const NAME: &'static str = "Backtracking line search";
@@ -217,6 +416,13 @@ where
             .map(Result::Ok)
             .unwrap_or_else(|| problem.gradient(&init_param))?;
 
+        self.alpha = self.initial_step.initial_step(
+            self.init_alpha,
+            self.init_cost,
+            &init_grad,
+            self.search_direction.as_ref().unwrap(),
+        );
+
         self.init_param = Some(init_param);
         self.init_grad = Some(init_grad);
         let state = self.backtracking_step(problem, state)?;
@@ -242,6 +448,7 @@ where
             self.search_direction.as_ref().unwrap(),
             self.alpha,
         ) {
+            self.initial_step.accepted_step(self.alpha);
             TerminationStatus::Terminated(TerminationReason::SolverConverged)
         } else {
             TerminationStatus::NotTerminated
@@ -295,7 +502,53 @@ mod tests {
         assert_eq!(ls.init_grad, None);
         assert_eq!(ls.search_direction, None);
         assert_eq!(ls.rho.to_ne_bytes(), 0.9f64.to_ne_bytes());
+        assert_eq!(ls.init_alpha.to_ne_bytes(), 1.0f64.to_ne_bytes());
         assert_eq!(ls.alpha.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(ls.initial_step, FixedInitialStep);
+    }
+
+    #[test]
+    fn test_with_initial_step() {
+        let ls: BacktrackingLineSearch<Vec<f64>, Vec<f64>, ArmijoCondition<f64>, f64, _> =
+            BacktrackingLineSearch::new(ArmijoCondition::new(0.01).unwrap())
+                .with_initial_step(InitialStep::QuadraticFit);
+        assert_eq!(ls.initial_step.method, InitialStep::QuadraticFit);
+        assert!(ls.initial_step.history.is_none());
+        assert_eq!(ls, ls.clone());
+    }
+
+    #[test]
+    fn test_initial_step_heuristics() {
+        for (initial_step, expected) in [
+            (InitialStep::Fixed, 1.0),
+            (InitialStep::PreviousStep, 0.9 * 20.0 / 12.8),
+            (InitialStep::BarzilaiBorwein, 0.5),
+            (InitialStep::QuadraticFit, 2.02 * 1.8 / 12.8),
+        ] {
+            let mut ls: BacktrackingLineSearch<Vec<f64>, Vec<f64>, ArmijoCondition<f64>, f64, _> =
+                BacktrackingLineSearch::new(ArmijoCondition::new(0.01).unwrap())
+                    .with_initial_step(initial_step);
+
+            // First line search starts from the fixed step length and accepts alpha = 0.9
+            ls.search_direction(vec![-4.0, -2.0]);
+            let res = Executor::new(BTTestProblem {}, ls)
+                .configure(|config| config.param(vec![2.0, 1.0]).max_iters(10))
+                .run()
+                .unwrap();
+            let param = res.state.get_param().unwrap();
+            assert_relative_eq!(param[0], -1.6, epsilon = 1e-12);
+            assert_relative_eq!(param[1], -0.8, epsilon = 1e-12);
+
+            // Second line search estimates the initial step length from the returned line search
+            let mut ls = res.solver;
+            ls.search_direction(vec![3.2, 1.6]);
+            ls.init(
+                &mut Problem::new(BTTestProblem {}),
+                IterState::new().param(vec![-1.6, -0.8]),
+            )
+            .unwrap();
+            assert_relative_eq!(ls.alpha, expected, epsilon = 1e-12);
+        }
     }
 
     #[test]
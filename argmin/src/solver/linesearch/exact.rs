@@ -0,0 +1,320 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, Gradient, Hessian, IterState, LineSearch, Problem,
+    SerializeAlias, Solver, State, TerminationReason, KV,
+};
+use argmin_math::{ArgminDot, ArgminScaledAdd};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Exact line search
+///
+/// Computes the step length which exactly minimizes a quadratic function along the search
+/// direction `d`:
+///
+/// `alpha = -g(x)^T d / (d^T H(x) d)`
+///
+/// For non-quadratic functions this is the minimizer of the second order Taylor approximation at
+/// `x`. The line search requires a single iteration, in which the Hessian is evaluated once and
+/// multiplied with the search direction. The Hessian must be positive definite along the search
+/// direction.
+///
+/// Exact steps are mostly useful for conjugate gradient methods and benchmarks, where they remove
+/// the line search as a source of noise. The initial step length set via
+/// [`initial_step_length`](`LineSearch::initial_step_length`) is ignored.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`], [`Gradient`] and
+/// [`Hessian`].
+///
+/// ## Reference
+///
+/// Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
+/// Springer. ISBN 0-387-30303-0.
+#[derive(Clone, Eq, PartialEq, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct ExactLineSearch<P, G, F> {
+    /// Search direction
+    search_direction: Option<P>,
+    /// Initial parameter vector
+    init_param: Option<P>,
+    /// Initial directional derivative
+    init_dg: F,
+    /// Computed step length
+    alpha: F,
+    /// Gradient type
+    _gradient: core::marker::PhantomData<G>,
+}
+
+impl<P, G, F> ExactLineSearch<P, G, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of `ExactLineSearch`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::linesearch::ExactLineSearch;
+    /// let els: ExactLineSearch<Vec<f64>, Vec<f64>, f64> = ExactLineSearch::new();
+    /// ```
+    pub fn new() -> Self {
+        ExactLineSearch {
+            search_direction: None,
+            init_param: None,
+            init_dg: F::nan(),
+            alpha: F::nan(),
+            _gradient: core::marker::PhantomData,
+        }
+    }
+}
+
+impl<P, G, F> Default for ExactLineSearch<P, G, F>
+where
+    F: ArgminFloat,
+{
+    fn default() -> Self {
+        ExactLineSearch::new()
+    }
+}
+
+impl<P, G, F> LineSearch<P, F> for ExactLineSearch<P, G, F>
+where
+    F: ArgminFloat,
+{
+    /// Set search direction
+    fn search_direction(&mut self, search_direction: P) {
+        self.search_direction = Some(search_direction);
+    }
+
+    /// The step length is computed exactly, therefore the initial step length is ignored.
+    fn initial_step_length(&mut self, _alpha: F) -> Result<(), Error> {
+        Ok(())
+    }
+
+    /// Accepted step length
+    fn step_length(&self) -> Option<F> {
+        Some(self.alpha)
+    }
+}
+
+impl<O, P, G, H, F> Solver<O, IterState<P, G, (), (), F>> for ExactLineSearch<P, G, F>
+where
+    O: CostFunction<Param = P, Output = F>
+        + Gradient<Param = P, Gradient = G>
+        + Hessian<Param = P, Hessian = H>,
+    P: Clone + SerializeAlias + ArgminDot<P, F> + ArgminScaledAdd<P, F, P>,
+    G: SerializeAlias + ArgminDot<P, F>,
+    H: ArgminDot<P, P>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Exact line search";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        check_param!(
+            self.search_direction,
+            concat!(
+                "`ExactLineSearch`: Search direction not initialized. ",
+                "Call `search_direction` before executing the solver."
+            )
+        );
+
+        let init_param = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`ExactLineSearch` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+
+        let init_grad = state
+            .take_gradient()
+            .map(Result::Ok)
+            .unwrap_or_else(|| problem.gradient(&init_param))?;
+
+        self.init_dg = init_grad.dot(self.search_direction.as_ref().unwrap());
+        if self.init_dg >= float!(0.0) {
+            return Err(argmin_error!(
+                ConditionViolated,
+                "`ExactLineSearch`: Search direction must be a descent direction."
+            ));
+        }
+
+        self.init_param = Some(init_param);
+        Ok((state, None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        let init_param = self.init_param.as_ref().unwrap();
+        let direction = self.search_direction.as_ref().unwrap();
+
+        let curvature = direction.dot(&problem.hessian(init_param)?.dot(direction));
+        if curvature <= float!(0.0) || !curvature.is_finite() {
+            return Err(argmin_error!(
+                ConditionViolated,
+                "`ExactLineSearch`: Hessian must be positive definite along the search direction."
+            ));
+        }
+
+        self.alpha = -self.init_dg / curvature;
+        let param = init_param.scaled_add(&self.alpha, direction);
+        let cost = problem.cost(&param)?;
+        let grad = problem.gradient(&param)?;
+
+        Ok((
+            state
+                .param(param)
+                .cost(cost)
+                .gradient(grad)
+                .terminate_with(TerminationReason::SolverConverged),
+            Some(kv!("alpha" => self.alpha;)),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_error;
+    use crate::core::{ArgminError, Executor};
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(exact, ExactLineSearch<Vec<f64>, Vec<f64>, f64>);
+
+    /// `f(x) = 0.5 x^T A x - b^T x` with `A = [[3, 1], [1, 2]]` and `b = [1, 1]`
+    #[derive(Clone)]
+    struct Quadratic {}
+
+    impl CostFunction for Quadratic {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(0.5 * (3.0 * p[0] * p[0] + 2.0 * p[0] * p[1] + 2.0 * p[1] * p[1]) - p[0] - p[1])
+        }
+    }
+
+    impl Gradient for Quadratic {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(vec![3.0 * p[0] + p[1] - 1.0, p[0] + 2.0 * p[1] - 1.0])
+        }
+    }
+
+    impl Hessian for Quadratic {
+        type Param = Vec<f64>;
+        type Hessian = Vec<Vec<f64>>;
+
+        fn hessian(&self, _p: &Self::Param) -> Result<Self::Hessian, Error> {
+            Ok(vec![vec![3.0, 1.0], vec![1.0, 2.0]])
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let ExactLineSearch {
+            search_direction,
+            init_param,
+            init_dg,
+            alpha,
+            _gradient,
+        }: ExactLineSearch<Vec<f64>, Vec<f64>, f64> = ExactLineSearch::new();
+
+        assert!(search_direction.is_none());
+        assert!(init_param.is_none());
+        assert!(init_dg.is_nan());
+        assert!(alpha.is_nan());
+    }
+
+    #[test]
+    fn test_init() {
+        let mut els: ExactLineSearch<Vec<f64>, Vec<f64>, f64> = ExactLineSearch::new();
+        let res = els.init(&mut Problem::new(Quadratic {}), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`ExactLineSearch`: Search direction not initialized. ",
+                "Call `search_direction` before executing the solver.\""
+            )
+        );
+
+        els.search_direction(vec![-1.0, 0.0]);
+        let res = els.init(&mut Problem::new(Quadratic {}), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`ExactLineSearch` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+
+        let res = els.init(
+            &mut Problem::new(Quadratic {}),
+            IterState::new().param(vec![0.0, 0.0]),
+        );
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Condition violated: \"`ExactLineSearch`: ",
+                "Search direction must be a descent direction.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_exact_step() {
+        let mut els = ExactLineSearch::new();
+        els.search_direction(vec![1.0, 0.0]);
+        let res = Executor::new(Quadratic {}, els)
+            .configure(|state| state.param(vec![0.0, 0.0]).max_iters(10))
+            .run()
+            .unwrap();
+        assert_eq!(res.state.get_iter(), 1);
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        // Minimum of 1.5 x^2 - x is at x = 1/3
+        let param = res.state.get_param().unwrap();
+        assert_relative_eq!(param[0], 1.0 / 3.0, epsilon = 1e-12);
+        assert_relative_eq!(param[1], 0.0, epsilon = 1e-12);
+        // Gradient is orthogonal to the search direction
+        assert_relative_eq!(res.state.get_gradient().unwrap()[0], 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_nonlinear_cg() {
+        use crate::solver::conjugategradient::{beta::FletcherReeves, NonlinearConjugateGradient};
+        // With exact line searches, conjugate gradient converges in n iterations on quadratics
+        let solver = NonlinearConjugateGradient::new(ExactLineSearch::new(), FletcherReeves::new());
+        let res = Executor::new(Quadratic {}, solver)
+            .configure(|state| state.param(vec![0.0, 0.0]).max_iters(2))
+            .run()
+            .unwrap();
+        let param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(param[0], 0.2, epsilon = 1e-12);
+        assert_relative_eq!(param[1], 0.4, epsilon = 1e-12);
+    }
+}
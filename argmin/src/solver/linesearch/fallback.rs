@@ -0,0 +1,498 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{
+    ArgminFloat, Error, IterState, LineSearch, Problem, SerializeAlias, Solver, State,
+    TerminationReason, TerminationStatus, KV,
+};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Fallback line search
+///
+/// Combines two line searches: The primary line search (for instance
+/// [`MoreThuenteLineSearch`](`crate::solver::linesearch::MoreThuenteLineSearch`)) is tried first.
+/// If it fails, either by returning an error or by terminating for any reason other than
+/// [`TerminationReason::SolverConverged`], the fallback line search (for instance a robust
+/// [`BacktrackingLineSearch`](`crate::solver::linesearch::BacktrackingLineSearch`)) is run from
+/// the same initial point. This way a single hard iteration does not abort the entire
+/// optimization.
+///
+/// Both line searches are run for at most
+/// [`with_max_iters`](`FallbackLineSearch::with_max_iters`) iterations. Errors of the fallback
+/// line search are returned to the caller. Whether the fallback was used is reported via the
+/// `fallback` key of the KV store.
+///
+/// ## Requirements on the optimization problem
+///
+/// The requirements of both line searches apply.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct FallbackLineSearch<A, B, P, G, F> {
+    /// Primary line search
+    primary: A,
+    /// Fallback line search
+    fallback: B,
+    /// Maximum number of iterations of each line search
+    max_iters: u64,
+    /// Initial parameter vector
+    init_param: Option<P>,
+    /// Initial gradient
+    init_grad: Option<G>,
+    /// Initial cost
+    init_cost: F,
+    /// Whether the fallback line search was used in the most recent run
+    used_fallback: bool,
+}
+
+impl<A, B, P, G, F> FallbackLineSearch<A, B, P, G, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of `FallbackLineSearch`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::linesearch::{
+    /// #     BacktrackingLineSearch, FallbackLineSearch, MoreThuenteLineSearch,
+    /// #     condition::ArmijoCondition,
+    /// # };
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let primary: MoreThuenteLineSearch<Vec<f64>, Vec<f64>, f64> = MoreThuenteLineSearch::new();
+    /// let fallback: BacktrackingLineSearch<Vec<f64>, Vec<f64>, _, f64> =
+    ///     BacktrackingLineSearch::new(ArmijoCondition::new(1e-4)?);
+    ///
+    /// let linesearch: FallbackLineSearch<_, _, Vec<f64>, Vec<f64>, f64> =
+    ///     FallbackLineSearch::new(primary, fallback);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(primary: A, fallback: B) -> Self {
+        FallbackLineSearch {
+            primary,
+            fallback,
+            max_iters: 100,
+            init_param: None,
+            init_grad: None,
+            init_cost: F::infinity(),
+            used_fallback: false,
+        }
+    }
+
+    /// Set the maximum number of iterations of each line search
+    ///
+    /// Must be larger than 0 and defaults to `100`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::linesearch::{
+    /// #     BacktrackingLineSearch, FallbackLineSearch, MoreThuenteLineSearch,
+    /// #     condition::ArmijoCondition,
+    /// # };
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let primary: MoreThuenteLineSearch<Vec<f64>, Vec<f64>, f64> = MoreThuenteLineSearch::new();
+    /// # let fallback: BacktrackingLineSearch<Vec<f64>, Vec<f64>, _, f64> =
+    /// #     BacktrackingLineSearch::new(ArmijoCondition::new(1e-4)?);
+    /// let linesearch: FallbackLineSearch<_, _, Vec<f64>, Vec<f64>, f64> =
+    ///     FallbackLineSearch::new(primary, fallback).with_max_iters(20)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_max_iters(mut self, max_iters: u64) -> Result<Self, Error> {
+        if max_iters < 1 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`FallbackLineSearch`: max_iters must be > 0."
+            ));
+        }
+        self.max_iters = max_iters;
+        Ok(self)
+    }
+}
+
+impl<A, B, P, G, F> LineSearch<P, F> for FallbackLineSearch<A, B, P, G, F>
+where
+    A: LineSearch<P, F>,
+    B: LineSearch<P, F>,
+    P: Clone,
+    F: ArgminFloat,
+{
+    /// Set search direction of both line searches
+    fn search_direction(&mut self, search_direction: P) {
+        self.primary.search_direction(search_direction.clone());
+        self.fallback.search_direction(search_direction);
+    }
+
+    /// Set initial step length of both line searches
+    fn initial_step_length(&mut self, alpha: F) -> Result<(), Error> {
+        self.primary.initial_step_length(alpha)?;
+        self.fallback.initial_step_length(alpha)
+    }
+
+    /// Accepted step length of the line search which was used in the most recent run
+    fn step_length(&self) -> Option<F> {
+        if self.used_fallback {
+            self.fallback.step_length()
+        } else {
+            self.primary.step_length()
+        }
+    }
+}
+
+impl<A, B, P, G, F> FallbackLineSearch<A, B, P, G, F>
+where
+    P: Clone,
+    G: Clone,
+    F: ArgminFloat,
+{
+    /// Initial state of a line search
+    fn initial_state(&self) -> IterState<P, G, (), (), F> {
+        let mut state = IterState::new()
+            .param(self.init_param.clone().unwrap())
+            .cost(self.init_cost)
+            .max_iters(self.max_iters);
+        if let Some(grad) = self.init_grad.clone() {
+            state = state.gradient(grad);
+        }
+        state
+    }
+}
+
+/// Runs `solver` from `state` until it terminates.
+///
+/// The line search is run directly on `problem` instead of through an
+/// [`Executor`](`crate::core::Executor`), such that the problem is not lost in case of an error.
+fn run<O, S, P, G, F>(
+    solver: &mut S,
+    problem: &mut Problem<O>,
+    state: IterState<P, G, (), (), F>,
+) -> Result<IterState<P, G, (), (), F>, Error>
+where
+    S: Solver<O, IterState<P, G, (), (), F>>,
+    P: Clone,
+    F: ArgminFloat,
+{
+    let (mut state, _) = solver.init(problem, state)?;
+    state.update();
+    loop {
+        if !state.terminated() {
+            if let TerminationStatus::Terminated(reason) = solver.terminate_internal(&state) {
+                state = state.terminate_with(reason);
+            }
+        }
+        if state.terminated() {
+            return Ok(state);
+        }
+        let (new_state, _) = solver.next_iter(problem, state)?;
+        state = new_state;
+        state.update();
+        state.increment_iter();
+    }
+}
+
+impl<O, A, B, P, G, F> Solver<O, IterState<P, G, (), (), F>> for FallbackLineSearch<A, B, P, G, F>
+where
+    A: Solver<O, IterState<P, G, (), (), F>>,
+    B: Solver<O, IterState<P, G, (), (), F>>,
+    P: Clone + SerializeAlias,
+    G: Clone + SerializeAlias,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Fallback line search";
+
+    fn init(
+        &mut self,
+        _problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        self.init_param = Some(state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`FallbackLineSearch` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?);
+        self.init_grad = state.take_gradient();
+        self.init_cost = state.get_cost();
+        Ok((state, None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        let init_state = self.initial_state();
+        let primary = run(&mut self.primary, problem, init_state);
+        let (mut result, fallback) = match primary {
+            Ok(result)
+                if result.get_termination_reason() == Some(&TerminationReason::SolverConverged) =>
+            {
+                (result, false)
+            }
+            _ => (
+                {
+                    let init_state = self.initial_state();
+                    run(&mut self.fallback, problem, init_state)?
+                },
+                true,
+            ),
+        };
+        self.used_fallback = fallback;
+
+        let reason = result
+            .get_termination_reason()
+            .cloned()
+            .unwrap_or(TerminationReason::SolverConverged);
+        let cost = result.get_cost();
+        let mut state = state.cost(cost).terminate_with(reason);
+        if let Some(param) = result.take_param() {
+            state = state.param(param);
+        }
+        if let Some(grad) = result.take_gradient() {
+            state = state.gradient(grad);
+        }
+        Ok((state, Some(kv!("fallback" => fallback;))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_error;
+    use crate::core::{ArgminError, CostFunction, Executor, Gradient, Hessian};
+    use crate::solver::linesearch::{
+        condition::ArmijoCondition, BacktrackingLineSearch, ExactLineSearch,
+    };
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    type Fallback = FallbackLineSearch<
+        ExactLineSearch<Vec<f64>, Vec<f64>, f64>,
+        BacktrackingLineSearch<Vec<f64>, Vec<f64>, ArmijoCondition<f64>, f64>,
+        Vec<f64>,
+        Vec<f64>,
+        f64,
+    >;
+
+    test_trait_impl!(fallback, Fallback);
+
+    /// `f(x) = x^4 - x^2`, which has negative curvature at `x = 0.1`
+    #[derive(Clone)]
+    struct DoubleWell {}
+
+    impl CostFunction for DoubleWell {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(p[0].powi(4) - p[0].powi(2))
+        }
+    }
+
+    impl Gradient for DoubleWell {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(vec![4.0 * p[0].powi(3) - 2.0 * p[0]])
+        }
+    }
+
+    impl Hessian for DoubleWell {
+        type Param = Vec<f64>;
+        type Hessian = Vec<Vec<f64>>;
+
+        fn hessian(&self, p: &Self::Param) -> Result<Self::Hessian, Error> {
+            Ok(vec![vec![12.0 * p[0].powi(2) - 2.0]])
+        }
+    }
+
+    fn fallback() -> Fallback {
+        FallbackLineSearch::new(
+            ExactLineSearch::new(),
+            BacktrackingLineSearch::new(ArmijoCondition::new(1e-4).unwrap()),
+        )
+    }
+
+    #[test]
+    fn test_new() {
+        let FallbackLineSearch {
+            primary,
+            fallback: fb,
+            max_iters,
+            init_param,
+            init_grad,
+            init_cost,
+            used_fallback,
+        } = fallback();
+
+        // `ExactLineSearch` contains NaN, therefore compare the debug output
+        assert_eq!(
+            format!("{primary:?}"),
+            format!("{:?}", ExactLineSearch::<Vec<f64>, Vec<f64>, f64>::new())
+        );
+        assert_eq!(
+            fb,
+            BacktrackingLineSearch::new(ArmijoCondition::new(1e-4).unwrap())
+        );
+        assert_eq!(max_iters, 100);
+        assert!(init_param.is_none());
+        assert!(init_grad.is_none());
+        assert!(init_cost.is_infinite());
+        assert!(!used_fallback);
+    }
+
+    #[test]
+    fn test_with_max_iters() {
+        let ls = fallback().with_max_iters(5).unwrap();
+        assert_eq!(ls.max_iters, 5);
+
+        assert_error!(
+            fallback().with_max_iters(0),
+            ArgminError,
+            "Invalid parameter: \"`FallbackLineSearch`: max_iters must be > 0.\""
+        );
+    }
+
+    #[test]
+    fn test_search_direction() {
+        let mut ls = fallback();
+        ls.search_direction(vec![1.0]);
+        ls.initial_step_length(0.5).unwrap();
+
+        let mut exact: ExactLineSearch<Vec<f64>, Vec<f64>, f64> = ExactLineSearch::new();
+        exact.search_direction(vec![1.0]);
+        assert_eq!(format!("{:?}", ls.primary), format!("{exact:?}"));
+
+        let mut backtracking = BacktrackingLineSearch::new(ArmijoCondition::new(1e-4).unwrap());
+        backtracking.search_direction(vec![1.0]);
+        backtracking.initial_step_length(0.5).unwrap();
+        assert_eq!(ls.fallback, backtracking);
+    }
+
+    #[test]
+    fn test_init_param_not_initialized() {
+        let res = fallback().init(&mut Problem::new(DoubleWell {}), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`FallbackLineSearch` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_primary() {
+        // Positive curvature at x = 1: exact line search succeeds
+        let mut ls = fallback();
+        ls.search_direction(vec![-1.0]);
+        let res = Executor::new(DoubleWell {}, ls)
+            .configure(|state| state.param(vec![1.0]))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        // Newton step from x = 1: 1 - 2 / 10
+        assert_relative_eq!(res.state.get_param().unwrap()[0], 0.8, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_fallback() {
+        // Negative curvature at x = 0.1: exact line search fails, backtracking takes over
+        let mut ls = fallback();
+        ls.search_direction(vec![1.0]);
+        let res = Executor::new(DoubleWell {}, ls)
+            .configure(|state| state.param(vec![0.1]))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        assert_relative_eq!(res.state.get_param().unwrap()[0], 0.91, epsilon = 1e-12);
+        assert_relative_eq!(res.solver.step_length().unwrap(), 0.81, epsilon = 1e-12);
+        assert_eq!(res.state.get_func_counts()["hessian_count"], 1);
+    }
+
+    #[test]
+    fn test_fallback_max_iters() {
+        // A primary line search which does not converge within `max_iters` triggers the fallback
+        let mut ls = FallbackLineSearch::new(
+            BacktrackingLineSearch::new(ArmijoCondition::new(0.4).unwrap())
+                .rho(0.99)
+                .unwrap(),
+            ExactLineSearch::new(),
+        )
+        .with_max_iters(2)
+        .unwrap();
+        ls.search_direction(vec![-1.0]);
+        let res = Executor::new(DoubleWell {}, ls)
+            .configure(|state| state.param(vec![1.0]))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        assert_relative_eq!(res.state.get_param().unwrap()[0], 0.8, epsilon = 1e-12);
+    }
+
+    // LBFGS is only available with `std`
+    #[cfg(feature = "std")]
+    mod lbfgs {
+        use super::*;
+        use crate::solver::linesearch::MoreThuenteLineSearch;
+        use crate::solver::quasinewton::LBFGS;
+        use argmin_testfunctions::{rosenbrock_2d, rosenbrock_2d_derivative};
+
+        #[derive(Clone)]
+        struct Rosenbrock {}
+
+        impl CostFunction for Rosenbrock {
+            type Param = Vec<f64>;
+            type Output = f64;
+
+            fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(rosenbrock_2d(p, 1.0, 100.0))
+            }
+        }
+
+        impl Gradient for Rosenbrock {
+            type Param = Vec<f64>;
+            type Gradient = Vec<f64>;
+
+            fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+                Ok(rosenbrock_2d_derivative(p, 1.0, 100.0))
+            }
+        }
+
+        #[test]
+        fn test_lbfgs() {
+            let linesearch = FallbackLineSearch::new(
+                MoreThuenteLineSearch::new(),
+                BacktrackingLineSearch::new(ArmijoCondition::new(1e-4).unwrap()),
+            );
+            let solver = LBFGS::new(linesearch, 7);
+            let res = Executor::new(Rosenbrock {}, solver)
+                .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(100))
+                .run()
+                .unwrap();
+            let best = res.state.get_best_param().unwrap();
+            assert_relative_eq!(best[0], 1.0, epsilon = 1e-6);
+            assert_relative_eq!(best[1], 1.0, epsilon = 1e-6);
+        }
+    }
+}
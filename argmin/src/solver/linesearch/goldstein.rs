@@ -0,0 +1,499 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, Gradient, IterState, LineSearch, Problem, SerializeAlias,
+    Solver, State, TerminationReason, KV,
+};
+use alloc::string::ToString;
+use argmin_math::{ArgminDot, ArgminScaledAdd};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Goldstein line search
+///
+/// Finds a step length `alpha` which satisfies the Goldstein conditions
+///
+/// `f(x) + (1 - c) * alpha * g(x)^T d <= f(x + alpha * d) <= f(x) + c * alpha * g(x)^T d`
+///
+/// with `0 < c < 1/2`. The upper bound ensures sufficient decrease, while the lower bound keeps
+/// the step length from becoming too short. In contrast to the Wolfe conditions, only function
+/// values are needed to check the conditions.
+///
+/// Starting from the initial step length, the step length is increased by a constant factor (see
+/// [`with_expansion`](`GoldsteinLineSearch::with_expansion`)) as long as it is too short. Once a
+/// step length which is too long has been found, the interval between the longest step length
+/// which is too short and the shortest step length which is too long is bisected until an
+/// acceptable step length is found. If the interval becomes too small, the line search
+/// terminates with [`TerminationReason::SolverExit`] and returns the longest step length which
+/// was too short.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`] and [`Gradient`].
+///
+/// ## Reference
+///
+/// Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
+/// Springer. ISBN 0-387-30303-0.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct GoldsteinLineSearch<P, G, F> {
+    /// initial parameter vector
+    init_param: Option<P>,
+    /// initial cost
+    init_cost: F,
+    /// initial directional derivative
+    init_dg: F,
+    /// Search direction
+    search_direction: Option<P>,
+    /// Parameter c of the Goldstein conditions
+    c: F,
+    /// Expansion factor
+    expansion: F,
+    /// Longest step length which was too short and its cost
+    lo: (F, F),
+    /// Shortest step length which was too long
+    hi: F,
+    /// Initial step length
+    init_alpha: F,
+    /// alpha
+    alpha: F,
+    /// Gradient type
+    _gradient: core::marker::PhantomData<G>,
+}
+
+impl<P, G, F> GoldsteinLineSearch<P, G, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of `GoldsteinLineSearch`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::linesearch::GoldsteinLineSearch;
+    /// let gls: GoldsteinLineSearch<Vec<f64>, Vec<f64>, f64> = GoldsteinLineSearch::new();
+    /// ```
+    pub fn new() -> Self {
+        GoldsteinLineSearch {
+            init_param: None,
+            init_cost: F::infinity(),
+            init_dg: F::nan(),
+            search_direction: None,
+            c: float!(0.25),
+            expansion: float!(2.0),
+            lo: (float!(0.0), F::infinity()),
+            hi: F::infinity(),
+            init_alpha: float!(1.0),
+            alpha: float!(1.0),
+            _gradient: core::marker::PhantomData,
+        }
+    }
+
+    /// Set parameter c of the Goldstein conditions
+    ///
+    /// Must be in (0, 0.5) and defaults to `0.25`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::Error;
+    /// # use argmin::solver::linesearch::GoldsteinLineSearch;
+    /// # fn main() -> Result<(), Error> {
+    /// let gls: GoldsteinLineSearch<Vec<f64>, Vec<f64>, f64> =
+    ///     GoldsteinLineSearch::new().with_c(0.1)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_c(mut self, c: F) -> Result<Self, Error> {
+        if c <= float!(0.0) || c >= float!(0.5) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`GoldsteinLineSearch`: Parameter c must be in (0, 0.5)."
+            ));
+        }
+        self.c = c;
+        Ok(self)
+    }
+
+    /// Set the factor by which too short step lengths are increased
+    ///
+    /// Must be larger than 1 and defaults to `2.0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::Error;
+    /// # use argmin::solver::linesearch::GoldsteinLineSearch;
+    /// # fn main() -> Result<(), Error> {
+    /// let gls: GoldsteinLineSearch<Vec<f64>, Vec<f64>, f64> =
+    ///     GoldsteinLineSearch::new().with_expansion(4.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_expansion(mut self, expansion: F) -> Result<Self, Error> {
+        if expansion <= float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`GoldsteinLineSearch`: expansion factor must be > 1."
+            ));
+        }
+        self.expansion = expansion;
+        Ok(self)
+    }
+}
+
+impl<P, G, F> Default for GoldsteinLineSearch<P, G, F>
+where
+    F: ArgminFloat,
+{
+    fn default() -> Self {
+        GoldsteinLineSearch::new()
+    }
+}
+
+impl<P, G, F> LineSearch<P, F> for GoldsteinLineSearch<P, G, F>
+where
+    F: ArgminFloat,
+{
+    /// Set search direction
+    fn search_direction(&mut self, search_direction: P) {
+        self.search_direction = Some(search_direction);
+    }
+
+    /// Set initial step length
+    fn initial_step_length(&mut self, alpha: F) -> Result<(), Error> {
+        if alpha <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`GoldsteinLineSearch`: Initial alpha must be > 0."
+            ));
+        }
+        self.init_alpha = alpha;
+        self.alpha = alpha;
+        Ok(())
+    }
+
+    /// Accepted step length
+    fn step_length(&self) -> Option<F> {
+        Some(self.alpha)
+    }
+}
+
+impl<O, P, G, F> Solver<O, IterState<P, G, (), (), F>> for GoldsteinLineSearch<P, G, F>
+where
+    O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
+    P: Clone + SerializeAlias + ArgminScaledAdd<P, F, P>,
+    G: SerializeAlias + ArgminDot<P, F>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Goldstein line search";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        check_param!(
+            self.search_direction,
+            concat!(
+                "`GoldsteinLineSearch`: Search direction not initialized. ",
+                "Call `search_direction` before executing the solver."
+            )
+        );
+
+        let init_param = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`GoldsteinLineSearch` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+
+        let cost = state.get_cost();
+        self.init_cost = if cost.is_infinite() {
+            problem.cost(&init_param)?
+        } else {
+            cost
+        };
+
+        let init_grad = state
+            .take_gradient()
+            .map(Result::Ok)
+            .unwrap_or_else(|| problem.gradient(&init_param))?;
+
+        self.init_dg = init_grad.dot(self.search_direction.as_ref().unwrap());
+        if self.init_dg >= float!(0.0) {
+            return Err(argmin_error!(
+                ConditionViolated,
+                "`GoldsteinLineSearch`: Search direction must be a descent direction."
+            ));
+        }
+
+        self.alpha = self.init_alpha;
+        self.lo = (float!(0.0), self.init_cost);
+        self.hi = F::infinity();
+        self.init_param = Some(init_param);
+        Ok((state, None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        let init_param = self.init_param.as_ref().unwrap();
+        let direction = self.search_direction.as_ref().unwrap();
+
+        let param = init_param.scaled_add(&self.alpha, direction);
+        let cost = problem.cost(&param)?;
+
+        let decrease = self.alpha * self.init_dg;
+        if cost > self.init_cost + self.c * decrease {
+            // Step too long
+            self.hi = self.alpha;
+        } else if cost < self.init_cost + (float!(1.0) - self.c) * decrease {
+            // Step too short
+            self.lo = (self.alpha, cost);
+        } else {
+            return Ok((
+                state
+                    .param(param)
+                    .cost(cost)
+                    .terminate_with(TerminationReason::SolverConverged),
+                None,
+            ));
+        }
+
+        self.alpha = if self.hi.is_finite() {
+            float!(0.5) * (self.lo.0 + self.hi)
+        } else {
+            self.alpha * self.expansion
+        };
+
+        if self.hi.is_finite() && self.hi - self.lo.0 <= F::epsilon() * self.hi {
+            let (alpha, cost) = self.lo;
+            self.alpha = alpha;
+            return Ok((
+                state
+                    .param(init_param.scaled_add(&alpha, direction))
+                    .cost(cost)
+                    .terminate_with(TerminationReason::SolverExit(
+                        "Interval of uncertainty too small".to_string(),
+                    )),
+                None,
+            ));
+        }
+
+        Ok((state.param(param).cost(cost), None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_error;
+    use crate::core::{ArgminError, Executor};
+    use crate::solver::gradientdescent::SteepestDescent;
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+    use argmin_testfunctions::{rosenbrock_2d, rosenbrock_2d_derivative};
+
+    test_trait_impl!(goldstein, GoldsteinLineSearch<Vec<f64>, Vec<f64>, f64>);
+
+    #[derive(Clone)]
+    struct Rosenbrock {}
+
+    impl CostFunction for Rosenbrock {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(rosenbrock_2d(p, 1.0, 100.0))
+        }
+    }
+
+    impl Gradient for Rosenbrock {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(rosenbrock_2d_derivative(p, 1.0, 100.0))
+        }
+    }
+
+    /// `f(x) = x^2` in one dimension
+    #[derive(Clone)]
+    struct Parabola {}
+
+    impl CostFunction for Parabola {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(p[0].powi(2))
+        }
+    }
+
+    impl Gradient for Parabola {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(vec![2.0 * p[0]])
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let GoldsteinLineSearch {
+            init_param,
+            init_cost,
+            init_dg,
+            search_direction,
+            c,
+            expansion,
+            lo,
+            hi,
+            init_alpha,
+            alpha,
+            _gradient,
+        }: GoldsteinLineSearch<Vec<f64>, Vec<f64>, f64> = GoldsteinLineSearch::new();
+
+        assert!(init_param.is_none());
+        assert!(init_cost.is_infinite());
+        assert!(init_dg.is_nan());
+        assert!(search_direction.is_none());
+        assert_eq!(c.to_ne_bytes(), 0.25f64.to_ne_bytes());
+        assert_eq!(expansion.to_ne_bytes(), 2.0f64.to_ne_bytes());
+        assert_eq!(lo.0.to_ne_bytes(), 0.0f64.to_ne_bytes());
+        assert!(lo.1.is_infinite());
+        assert!(hi.is_infinite());
+        assert_eq!(init_alpha.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(alpha.to_ne_bytes(), 1.0f64.to_ne_bytes());
+    }
+
+    #[test]
+    fn test_with_c() {
+        let gls: GoldsteinLineSearch<Vec<f64>, Vec<f64>, f64> =
+            GoldsteinLineSearch::new().with_c(0.1).unwrap();
+        assert_eq!(gls.c.to_ne_bytes(), 0.1f64.to_ne_bytes());
+
+        for c in [0.0, 0.5, -0.1, 1.0] {
+            let res: Result<GoldsteinLineSearch<Vec<f64>, Vec<f64>, f64>, _> =
+                GoldsteinLineSearch::new().with_c(c);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`GoldsteinLineSearch`: Parameter c must be in (0, 0.5).\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_expansion() {
+        let gls: GoldsteinLineSearch<Vec<f64>, Vec<f64>, f64> =
+            GoldsteinLineSearch::new().with_expansion(3.0).unwrap();
+        assert_eq!(gls.expansion.to_ne_bytes(), 3.0f64.to_ne_bytes());
+
+        for expansion in [1.0, 0.5, -1.0] {
+            let res: Result<GoldsteinLineSearch<Vec<f64>, Vec<f64>, f64>, _> =
+                GoldsteinLineSearch::new().with_expansion(expansion);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`GoldsteinLineSearch`: expansion factor must be > 1.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_initial_step_length() {
+        let mut gls: GoldsteinLineSearch<Vec<f64>, Vec<f64>, f64> = GoldsteinLineSearch::new();
+        assert!(gls.initial_step_length(0.5).is_ok());
+        assert_eq!(gls.alpha.to_ne_bytes(), 0.5f64.to_ne_bytes());
+
+        assert_error!(
+            gls.initial_step_length(0.0),
+            ArgminError,
+            "Invalid parameter: \"`GoldsteinLineSearch`: Initial alpha must be > 0.\""
+        );
+    }
+
+    #[test]
+    fn test_init() {
+        let mut gls: GoldsteinLineSearch<Vec<f64>, Vec<f64>, f64> = GoldsteinLineSearch::new();
+        let res = gls.init(&mut Problem::new(Parabola {}), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`GoldsteinLineSearch`: Search direction not initialized. ",
+                "Call `search_direction` before executing the solver.\""
+            )
+        );
+
+        gls.search_direction(vec![1.0]);
+        let res = gls.init(&mut Problem::new(Parabola {}), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`GoldsteinLineSearch` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+
+        let res = gls.init(
+            &mut Problem::new(Parabola {}),
+            IterState::new().param(vec![1.0]),
+        );
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Condition violated: \"`GoldsteinLineSearch`: ",
+                "Search direction must be a descent direction.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_step_lengths() {
+        // Accepted immediately, expanded from too short steps, bisected from too long steps
+        for (alpha, expected, iters) in [(1.0, 1.0, 1), (0.1, 0.8, 4), (3.0, 1.5, 2)] {
+            let mut gls = GoldsteinLineSearch::new();
+            gls.search_direction(vec![-1.0]);
+            gls.initial_step_length(alpha).unwrap();
+            let res = Executor::new(Parabola {}, gls)
+                .configure(|state| state.param(vec![1.0]).max_iters(100))
+                .run()
+                .unwrap();
+            assert_eq!(
+                res.state.get_termination_reason(),
+                Some(&TerminationReason::SolverConverged)
+            );
+            assert_eq!(res.state.get_iter(), iters);
+            assert_relative_eq!(
+                res.state.get_param().unwrap()[0],
+                1.0 - expected,
+                epsilon = 1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn test_steepest_descent() {
+        let solver = SteepestDescent::new(GoldsteinLineSearch::new());
+        let res = Executor::new(Rosenbrock {}, solver)
+            .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(10))
+            .run()
+            .unwrap();
+        assert!(res.state.get_best_cost() < rosenbrock_2d(&[-1.2, 1.0], 1.0, 100.0));
+    }
+}
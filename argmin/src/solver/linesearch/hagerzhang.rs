@@ -480,7 +480,10 @@ where
     }
 }
 
-impl<P, G, F> LineSearch<P, F> for HagerZhangLineSearch<P, G, F> {
+impl<P, G, F> LineSearch<P, F> for HagerZhangLineSearch<P, G, F>
+where
+    F: ArgminFloat,
+{
     /// Set search direction
     fn search_direction(&mut self, search_direction: P) {
         self.search_direction = Some(search_direction);
@@ -491,6 +494,11 @@ impl<P, G, F> LineSearch<P, F> for HagerZhangLineSearch<P, G, F> {
         self.c_x_init = alpha;
         Ok(())
     }
+
+    /// Accepted step length
+    fn step_length(&self) -> Option<F> {
+        Some(self.best_x)
+    }
 }
 
 impl<P, G, O, F> Solver<O, IterState<P, G, (), (), F>> for HagerZhangLineSearch<P, G, F>
@@ -17,8 +17,14 @@
 //! ## Available line searches
 //!
 //! * [Backtracking line search](`BacktrackingLineSearch`)
+//! * [Exact line search](`ExactLineSearch`) for quadratic objectives
+//! * [Goldstein line search](`GoldsteinLineSearch`)
+//! * [Fallback line search](`FallbackLineSearch`) which combines two line searches
+//! * [Noisy line search](`NoisyLineSearch`) for objectives with noisy function values
 //! * [More-Thuente line search](`MoreThuenteLineSearch`)
 //! * [Hager-Zhang line search](`HagerZhangLineSearch`)
+//! * [Non-monotone line search](`NonmonotoneLineSearch`)
+//! * [Strong Wolfe line search](`StrongWolfeLineSearch`)
 //!
 //! ## References
 //!
@@ -33,15 +39,32 @@
 //! descent and an efficient line search." SIAM J. Optim. 16(1), 2006, 170-192.
 //! DOI: <https://doi.org/10.1137/030601880>
 
+use crate::core::{State, TerminationReason, KV};
+
 mod backtracking;
 /// Acceptance conditions
 pub mod condition;
+mod exact;
+mod fallback;
+mod goldstein;
 mod hagerzhang;
 mod morethuente;
+mod noisy;
+mod nonmonotone;
+mod strongwolfe;
 
-pub use self::backtracking::BacktrackingLineSearch;
+pub use self::backtracking::{
+    BacktrackingLineSearch, FixedInitialStep, InitialStep, InitialStepEstimator,
+    InitialStepHeuristic,
+};
+pub use self::exact::ExactLineSearch;
+pub use self::fallback::FallbackLineSearch;
+pub use self::goldstein::GoldsteinLineSearch;
 pub use self::hagerzhang::HagerZhangLineSearch;
 pub use self::morethuente::MoreThuenteLineSearch;
+pub use self::noisy::NoisyLineSearch;
+pub use self::nonmonotone::NonmonotoneLineSearch;
+pub use self::strongwolfe::StrongWolfeLineSearch;
 
 /// # Line search trait
 ///
@@ -84,4 +107,39 @@ pub trait LineSearch<P, F> {
     ///
     /// This indicates the first step length which will be tried.
     fn initial_step_length(&mut self, step_length: F) -> Result<(), crate::core::Error>;
+
+    /// Step length accepted in the most recent run of the line search
+    ///
+    /// Solvers use this to report the outcome of the line search to observers. Returns `None` by
+    /// default, which indicates that the line search does not provide this information.
+    fn step_length(&self) -> Option<F> {
+        None
+    }
+}
+
+/// Collects diagnostics of a finished line search run for the KV store of the calling solver
+///
+/// Reports the number of iterations (`ls_iters`), the number of cost function evaluations
+/// (`ls_cost_count`), whether the line search converged (`ls_converged`) and, if available, the
+/// accepted step length (`ls_step_length`).
+pub(crate) fn linesearch_kv<L, I>(linesearch: &L, state: &I) -> KV
+where
+    L: LineSearch<I::Param, I::Float>,
+    I: State,
+{
+    let converged = state.get_termination_reason() == Some(&TerminationReason::SolverConverged);
+    let cost_count = state
+        .get_func_counts()
+        .get("cost_count")
+        .copied()
+        .unwrap_or(0);
+    let kv = kv!(
+        "ls_iters" => state.get_iter();
+        "ls_cost_count" => cost_count;
+        "ls_converged" => converged;
+    );
+    match linesearch.step_length() {
+        Some(alpha) => kv.merge(kv!("ls_step_length" => alpha;)),
+        None => kv,
+    }
 }
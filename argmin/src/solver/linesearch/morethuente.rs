@@ -13,10 +13,11 @@ use crate::core::{
     ArgminFloat, CostFunction, Error, Gradient, IterState, LineSearch, Problem, SerializeAlias,
     Solver, State, TerminationReason, KV,
 };
+use alloc::vec;
 use argmin_math::{ArgminDot, ArgminScaledAdd};
+use core::default::Default;
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
-use std::default::Default;
 
 /// # More-Thuente line search
 ///
@@ -294,6 +295,11 @@ where
         self.alpha = alpha;
         Ok(())
     }
+
+    /// Accepted step length
+    fn step_length(&self) -> Option<F> {
+        Some(self.stp.x)
+    }
 }
 
 impl<P, G, O, F> Solver<O, IterState<P, G, (), (), F>> for MoreThuenteLineSearch<P, G, F>
@@ -356,6 +362,7 @@ where
 
         self.stage1 = true;
         self.brackt = false;
+        self.infoc = 1;
 
         self.dgtest = self.ftol * self.dginit;
         self.width = self.stpmax - self.stpmin;
@@ -408,9 +415,9 @@ where
         let new_grad = problem.gradient(&new_param)?;
         let cur_cost = self.f;
         let cur_param = new_param;
-        let cur_grad = new_grad.clone();
         // self.stx.fx = new_cost;
         let dg = self.search_direction.as_ref().unwrap().dot(&new_grad);
+        let cur_grad = new_grad;
         let ftest1 = self.finit + self.stp.x * self.dgtest;
         // self.stp.fx = new_cost;
         // self.stp.gx = dg;
@@ -0,0 +1,642 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, Gradient, IterState, LineSearch, Problem, SerializeAlias,
+    Solver, State, TerminationReason, KV,
+};
+use alloc::{string::ToString, vec::Vec};
+use argmin_math::{ArgminDot, ArgminScaledAdd};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Noisy line search
+///
+/// Backtracking line search for objectives whose function values are subject to noise, for
+/// instance because they are estimated by Monte Carlo simulation.
+///
+/// The cost function is evaluated [`with_samples`](`NoisyLineSearch::with_samples`) times at the
+/// initial point as well as at each trial point. A step length `alpha` is accepted if the sample
+/// means satisfy the Armijo condition with a safety margin of `z` standard errors of the
+/// difference of the means:
+///
+/// `mean f(x + alpha * d) + z * sqrt(s_0^2 / n + s_alpha^2 / n) <= mean f(x) + c * alpha * g^T d`
+///
+/// where `s_0^2` and `s_alpha^2` are the sample variances and `n` is the number of samples. The
+/// factor `z` is set via [`with_confidence`](`NoisyLineSearch::with_confidence`). With `z = 0`
+/// and a deterministic cost function, this is a backtracking line search with the Armijo
+/// condition. Otherwise, the step length is reduced by the contraction factor `rho`. If the step
+/// length falls below [`with_min_step_length`](`NoisyLineSearch::with_min_step_length`), the line
+/// search terminates with [`TerminationReason::SolverExit`] and returns the initial point, since
+/// no decrease can be established reliably.
+///
+/// The cost provided by the calling solver is ignored, because a single sample does not allow to
+/// estimate the noise level. The gradient (which may be noisy as well) is only used for the
+/// directional derivative.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`] and [`Gradient`].
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct NoisyLineSearch<P, G, F> {
+    /// initial parameter vector
+    init_param: Option<P>,
+    /// mean of the cost at the initial point
+    init_cost: F,
+    /// variance of the mean of the cost at the initial point
+    init_var: F,
+    /// initial directional derivative
+    init_dg: F,
+    /// Search direction
+    search_direction: Option<P>,
+    /// Contraction factor rho
+    rho: F,
+    /// Sufficient decrease parameter
+    c: F,
+    /// Number of samples per point
+    samples: usize,
+    /// Number of standard errors used as safety margin
+    confidence: F,
+    /// Minimum step length
+    min_step: F,
+    /// Initial step length
+    init_alpha: F,
+    /// alpha
+    alpha: F,
+    /// Gradient type
+    _gradient: core::marker::PhantomData<G>,
+}
+
+impl<P, G, F> NoisyLineSearch<P, G, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of `NoisyLineSearch`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::linesearch::NoisyLineSearch;
+    /// let nls: NoisyLineSearch<Vec<f64>, Vec<f64>, f64> = NoisyLineSearch::new();
+    /// ```
+    pub fn new() -> Self {
+        NoisyLineSearch {
+            init_param: None,
+            init_cost: F::infinity(),
+            init_var: F::nan(),
+            init_dg: F::nan(),
+            search_direction: None,
+            rho: float!(0.5),
+            c: float!(1e-4),
+            samples: 10,
+            confidence: float!(1.0),
+            min_step: float!(1e-10),
+            init_alpha: float!(1.0),
+            alpha: float!(1.0),
+            _gradient: core::marker::PhantomData,
+        }
+    }
+
+    /// Set contraction factor rho
+    ///
+    /// This factor must be in (0, 1) and defaults to `0.5`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::Error;
+    /// # use argmin::solver::linesearch::NoisyLineSearch;
+    /// # fn main() -> Result<(), Error> {
+    /// let nls: NoisyLineSearch<Vec<f64>, Vec<f64>, f64> = NoisyLineSearch::new().with_rho(0.8)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_rho(mut self, rho: F) -> Result<Self, Error> {
+        if rho <= float!(0.0) || rho >= float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`NoisyLineSearch`: Contraction factor rho must be in (0, 1)."
+            ));
+        }
+        self.rho = rho;
+        Ok(self)
+    }
+
+    /// Set sufficient decrease parameter c
+    ///
+    /// This parameter must be in (0, 1) and defaults to `1e-4`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::Error;
+    /// # use argmin::solver::linesearch::NoisyLineSearch;
+    /// # fn main() -> Result<(), Error> {
+    /// let nls: NoisyLineSearch<Vec<f64>, Vec<f64>, f64> = NoisyLineSearch::new().with_c(1e-3)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_c(mut self, c: F) -> Result<Self, Error> {
+        if c <= float!(0.0) || c >= float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`NoisyLineSearch`: Parameter c must be in (0, 1)."
+            ));
+        }
+        self.c = c;
+        Ok(self)
+    }
+
+    /// Set the number of cost function evaluations per point
+    ///
+    /// Must be at least 2 and defaults to `10`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::Error;
+    /// # use argmin::solver::linesearch::NoisyLineSearch;
+    /// # fn main() -> Result<(), Error> {
+    /// let nls: NoisyLineSearch<Vec<f64>, Vec<f64>, f64> = NoisyLineSearch::new().with_samples(50)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_samples(mut self, samples: usize) -> Result<Self, Error> {
+        if samples < 2 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`NoisyLineSearch`: number of samples must be >= 2."
+            ));
+        }
+        self.samples = samples;
+        Ok(self)
+    }
+
+    /// Set the safety margin in number of standard errors
+    ///
+    /// Must be non-negative and defaults to `1.0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::Error;
+    /// # use argmin::solver::linesearch::NoisyLineSearch;
+    /// # fn main() -> Result<(), Error> {
+    /// let nls: NoisyLineSearch<Vec<f64>, Vec<f64>, f64> =
+    ///     NoisyLineSearch::new().with_confidence(2.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_confidence(mut self, confidence: F) -> Result<Self, Error> {
+        if confidence < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`NoisyLineSearch`: confidence must be >= 0."
+            ));
+        }
+        self.confidence = confidence;
+        Ok(self)
+    }
+
+    /// Set the minimum step length
+    ///
+    /// Must be positive and defaults to `1e-10`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::Error;
+    /// # use argmin::solver::linesearch::NoisyLineSearch;
+    /// # fn main() -> Result<(), Error> {
+    /// let nls: NoisyLineSearch<Vec<f64>, Vec<f64>, f64> =
+    ///     NoisyLineSearch::new().with_min_step_length(1e-6)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_min_step_length(mut self, min_step: F) -> Result<Self, Error> {
+        if min_step <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`NoisyLineSearch`: minimum step length must be > 0."
+            ));
+        }
+        self.min_step = min_step;
+        Ok(self)
+    }
+
+    /// Returns mean of `samples` cost function evaluations at `param` and the variance of the mean
+    fn sample<O>(&self, problem: &mut Problem<O>, param: &P) -> Result<(F, F), Error>
+    where
+        O: CostFunction<Param = P, Output = F>,
+    {
+        let n = float!(self.samples as f64);
+        let costs = (0..self.samples)
+            .map(|_| problem.cost(param))
+            .collect::<Result<Vec<F>, Error>>()?;
+        let mean = costs.iter().fold(float!(0.0), |acc, &c| acc + c) / n;
+        let var = costs
+            .iter()
+            .fold(float!(0.0), |acc, &c| acc + (c - mean) * (c - mean))
+            / (n - float!(1.0));
+        Ok((mean, var / n))
+    }
+}
+
+impl<P, G, F> Default for NoisyLineSearch<P, G, F>
+where
+    F: ArgminFloat,
+{
+    fn default() -> Self {
+        NoisyLineSearch::new()
+    }
+}
+
+impl<P, G, F> LineSearch<P, F> for NoisyLineSearch<P, G, F>
+where
+    F: ArgminFloat,
+{
+    /// Set search direction
+    fn search_direction(&mut self, search_direction: P) {
+        self.search_direction = Some(search_direction);
+    }
+
+    /// Set initial step length
+    fn initial_step_length(&mut self, alpha: F) -> Result<(), Error> {
+        if alpha <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`NoisyLineSearch`: Initial alpha must be > 0."
+            ));
+        }
+        self.init_alpha = alpha;
+        self.alpha = alpha;
+        Ok(())
+    }
+
+    /// Accepted step length
+    fn step_length(&self) -> Option<F> {
+        Some(self.alpha)
+    }
+}
+
+impl<O, P, G, F> Solver<O, IterState<P, G, (), (), F>> for NoisyLineSearch<P, G, F>
+where
+    O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
+    P: Clone + SerializeAlias + ArgminScaledAdd<P, F, P>,
+    G: SerializeAlias + ArgminDot<P, F>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Noisy line search";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        check_param!(
+            self.search_direction,
+            concat!(
+                "`NoisyLineSearch`: Search direction not initialized. ",
+                "Call `search_direction` before executing the solver."
+            )
+        );
+
+        let init_param = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`NoisyLineSearch` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+
+        let init_grad = state
+            .take_gradient()
+            .map(Result::Ok)
+            .unwrap_or_else(|| problem.gradient(&init_param))?;
+
+        self.init_dg = init_grad.dot(self.search_direction.as_ref().unwrap());
+        if self.init_dg >= float!(0.0) {
+            return Err(argmin_error!(
+                ConditionViolated,
+                "`NoisyLineSearch`: Search direction must be a descent direction."
+            ));
+        }
+
+        self.alpha = self.init_alpha;
+        (self.init_cost, self.init_var) = self.sample(problem, &init_param)?;
+        self.init_param = Some(init_param);
+        Ok((
+            state,
+            Some(kv!("init_cost_std_err" => self.init_var.sqrt();)),
+        ))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        let init_param = self.init_param.as_ref().unwrap();
+        let param = init_param.scaled_add(&self.alpha, self.search_direction.as_ref().unwrap());
+        let (cost, var) = self.sample(problem, &param)?;
+
+        let margin = self.confidence * (self.init_var + var).sqrt();
+        let kv = kv!("alpha" => self.alpha; "cost_std_err" => var.sqrt(););
+        if cost + margin <= self.init_cost + self.c * self.alpha * self.init_dg {
+            return Ok((
+                state
+                    .param(param)
+                    .cost(cost)
+                    .terminate_with(TerminationReason::SolverConverged),
+                Some(kv),
+            ));
+        }
+
+        self.alpha = self.alpha * self.rho;
+        if self.alpha < self.min_step {
+            // The initial point is returned
+            self.alpha = float!(0.0);
+            return Ok((
+                state
+                    .param(init_param.clone())
+                    .cost(self.init_cost)
+                    .terminate_with(TerminationReason::SolverExit(
+                        "No reliable decrease found".to_string(),
+                    )),
+                Some(kv),
+            ));
+        }
+
+        Ok((state.param(param).cost(cost), Some(kv)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_error;
+    use crate::core::{ArgminError, Executor};
+    use crate::solver::gradientdescent::SteepestDescent;
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+    use rand::{Rng, SeedableRng};
+    use rand_xoshiro::Xoshiro256PlusPlus;
+    use std::sync::Mutex;
+
+    test_trait_impl!(noisy, NoisyLineSearch<Vec<f64>, Vec<f64>, f64>);
+
+    /// `f(x) = x_0^2 + 10 * x_1^2 + noise` with noise uniformly distributed in `[-noise, noise]`
+    struct NoisyParabola {
+        noise: f64,
+        rng: Mutex<Xoshiro256PlusPlus>,
+    }
+
+    impl NoisyParabola {
+        fn new(noise: f64) -> Self {
+            NoisyParabola {
+                noise,
+                rng: Mutex::new(Xoshiro256PlusPlus::seed_from_u64(42)),
+            }
+        }
+    }
+
+    impl CostFunction for NoisyParabola {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            let noise = if self.noise > 0.0 {
+                self.rng.lock().unwrap().gen_range(-self.noise..self.noise)
+            } else {
+                0.0
+            };
+            Ok(p.iter()
+                .zip([1.0, 10.0])
+                .map(|(x, w)| w * x * x)
+                .sum::<f64>()
+                + noise)
+        }
+    }
+
+    impl Gradient for NoisyParabola {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(p.iter()
+                .zip([1.0, 10.0])
+                .map(|(x, w)| 2.0 * w * x)
+                .collect())
+        }
+    }
+
+    fn run(
+        ls: NoisyLineSearch<Vec<f64>, Vec<f64>, f64>,
+        noise: f64,
+    ) -> IterState<Vec<f64>, Vec<f64>, (), (), f64> {
+        let mut ls = ls;
+        ls.search_direction(vec![-2.0]);
+        Executor::new(NoisyParabola::new(noise), ls)
+            .configure(|state| state.param(vec![1.0]))
+            .run()
+            .unwrap()
+            .state
+    }
+
+    #[test]
+    fn test_new() {
+        let NoisyLineSearch {
+            init_param,
+            init_cost,
+            init_var,
+            init_dg,
+            search_direction,
+            rho,
+            c,
+            samples,
+            confidence,
+            min_step,
+            init_alpha,
+            alpha,
+            _gradient,
+        }: NoisyLineSearch<Vec<f64>, Vec<f64>, f64> = NoisyLineSearch::new();
+
+        assert!(init_param.is_none());
+        assert!(init_cost.is_infinite());
+        assert!(init_var.is_nan());
+        assert!(init_dg.is_nan());
+        assert!(search_direction.is_none());
+        assert_eq!(rho.to_ne_bytes(), 0.5f64.to_ne_bytes());
+        assert_eq!(c.to_ne_bytes(), 1e-4f64.to_ne_bytes());
+        assert_eq!(samples, 10);
+        assert_eq!(confidence.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(min_step.to_ne_bytes(), 1e-10f64.to_ne_bytes());
+        assert_eq!(init_alpha.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(alpha.to_ne_bytes(), 1.0f64.to_ne_bytes());
+    }
+
+    #[test]
+    fn test_builders() {
+        let nls: NoisyLineSearch<Vec<f64>, Vec<f64>, f64> = NoisyLineSearch::new()
+            .with_rho(0.8)
+            .unwrap()
+            .with_c(0.1)
+            .unwrap()
+            .with_samples(2)
+            .unwrap()
+            .with_confidence(0.0)
+            .unwrap()
+            .with_min_step_length(1e-3)
+            .unwrap();
+        assert_eq!(nls.rho.to_ne_bytes(), 0.8f64.to_ne_bytes());
+        assert_eq!(nls.c.to_ne_bytes(), 0.1f64.to_ne_bytes());
+        assert_eq!(nls.samples, 2);
+        assert_eq!(nls.confidence.to_ne_bytes(), 0.0f64.to_ne_bytes());
+        assert_eq!(nls.min_step.to_ne_bytes(), 1e-3f64.to_ne_bytes());
+    }
+
+    #[test]
+    fn test_builder_errors() {
+        type Nls = NoisyLineSearch<Vec<f64>, Vec<f64>, f64>;
+        for rho in [0.0, 1.0] {
+            assert_error!(
+                Nls::new().with_rho(rho),
+                ArgminError,
+                "Invalid parameter: \"`NoisyLineSearch`: Contraction factor rho must be in (0, 1).\""
+            );
+        }
+        for c in [0.0, 1.0] {
+            assert_error!(
+                Nls::new().with_c(c),
+                ArgminError,
+                "Invalid parameter: \"`NoisyLineSearch`: Parameter c must be in (0, 1).\""
+            );
+        }
+        for samples in [0, 1] {
+            assert_error!(
+                Nls::new().with_samples(samples),
+                ArgminError,
+                "Invalid parameter: \"`NoisyLineSearch`: number of samples must be >= 2.\""
+            );
+        }
+        assert_error!(
+            Nls::new().with_confidence(-1.0),
+            ArgminError,
+            "Invalid parameter: \"`NoisyLineSearch`: confidence must be >= 0.\""
+        );
+        assert_error!(
+            Nls::new().with_min_step_length(0.0),
+            ArgminError,
+            "Invalid parameter: \"`NoisyLineSearch`: minimum step length must be > 0.\""
+        );
+        assert_error!(
+            Nls::new().initial_step_length(0.0),
+            ArgminError,
+            "Invalid parameter: \"`NoisyLineSearch`: Initial alpha must be > 0.\""
+        );
+    }
+
+    #[test]
+    fn test_init() {
+        let mut nls: NoisyLineSearch<Vec<f64>, Vec<f64>, f64> = NoisyLineSearch::new();
+        let res = nls.init(&mut Problem::new(NoisyParabola::new(0.0)), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`NoisyLineSearch`: Search direction not initialized. ",
+                "Call `search_direction` before executing the solver.\""
+            )
+        );
+
+        nls.search_direction(vec![1.0]);
+        let res = nls.init(&mut Problem::new(NoisyParabola::new(0.0)), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`NoisyLineSearch` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+
+        let res = nls.init(
+            &mut Problem::new(NoisyParabola::new(0.0)),
+            IterState::new().param(vec![1.0]),
+        );
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Condition violated: \"`NoisyLineSearch`: ",
+                "Search direction must be a descent direction.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_deterministic() {
+        // Without noise this is a backtracking line search with the Armijo condition:
+        // alpha = 1 overshoots to x = -1, alpha = 0.5 hits the minimum.
+        let state = run(NoisyLineSearch::new(), 0.0);
+        assert_eq!(
+            state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        assert_relative_eq!(state.get_param().unwrap()[0], 0.0, epsilon = f64::EPSILON);
+        assert_eq!(state.get_func_counts()["cost_count"], 30);
+    }
+
+    #[test]
+    fn test_noisy() {
+        // Decreases of the cost of at most 1.0 are not significant compared to noise of
+        // magnitude 10
+        let state = run(
+            NoisyLineSearch::new()
+                .with_samples(100)
+                .unwrap()
+                .with_confidence(5.0)
+                .unwrap(),
+            10.0,
+        );
+        assert_eq!(
+            state.get_termination_reason(),
+            Some(&TerminationReason::SolverExit(
+                "No reliable decrease found".to_string()
+            ))
+        );
+        assert_relative_eq!(state.get_param().unwrap()[0], 1.0, epsilon = f64::EPSILON);
+
+        // With little noise, the minimum is found as in the deterministic case
+        let state = run(
+            NoisyLineSearch::new()
+                .with_samples(100)
+                .unwrap()
+                .with_confidence(5.0)
+                .unwrap(),
+            0.01,
+        );
+        assert_eq!(
+            state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        assert_relative_eq!(state.get_param().unwrap()[0], 0.0, epsilon = f64::EPSILON);
+    }
+
+    #[test]
+    fn test_steepest_descent() {
+        let solver = SteepestDescent::new(NoisyLineSearch::new().with_samples(20).unwrap());
+        let res = Executor::new(NoisyParabola::new(1e-3), solver)
+            .configure(|state| state.param(vec![1.5, -0.7]).max_iters(100))
+            .run()
+            .unwrap();
+        let param = res.state.get_param().unwrap();
+        assert!(param[0].abs() < 0.05);
+        assert!(param[1].abs() < 0.05);
+    }
+}
@@ -0,0 +1,591 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, Gradient, IterState, LineSearch, Problem, SerializeAlias,
+    Solver, State, TerminationReason, TerminationStatus, KV,
+};
+use alloc::collections::VecDeque;
+use argmin_math::{ArgminDot, ArgminScaledAdd};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Non-monotone line search
+///
+/// Backtracking line search with the non-monotone acceptance rule of Grippo, Lampariello and
+/// Lucidi. A step length `alpha` is accepted if
+///
+/// `f(x + alpha * d) <= max_{0 <= j < M} f(x_{k-j}) + c * alpha * g(x)^T d`,
+///
+/// where the maximum is taken over the costs at the initial points of the last `M` line searches
+/// (including the current one). Otherwise, the step length is reduced by the contraction factor
+/// `rho`. For `M = 1` this is identical to a backtracking line search with the Armijo condition.
+///
+/// Allowing the cost to increase occasionally avoids the tiny steps monotone line searches are
+/// prone to in narrow curved valleys of nonconvex problems. This line search is usually paired
+/// with methods which provide a good initial step length, such as Barzilai-Borwein or spectral
+/// gradient methods.
+///
+/// The history of costs is stored in the line search itself. Solvers take the line search back
+/// from the result of each line search, which keeps the history across iterations, while every
+/// optimization run starts from the (empty) history of the instance passed to the solver.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`] and [`Gradient`].
+///
+/// ## Reference
+///
+/// L. Grippo, F. Lampariello and S. Lucidi (1986). A Nonmonotone Line Search Technique for
+/// Newton's Method. SIAM Journal on Numerical Analysis, 23(4), 707–716.
+/// DOI: <https://doi.org/10.1137/0723046>
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct NonmonotoneLineSearch<P, G, F> {
+    /// initial parameter vector
+    init_param: Option<P>,
+    /// reference cost (maximum of the last `memory` costs)
+    ref_cost: F,
+    /// initial directional derivative
+    init_dg: F,
+    /// Search direction
+    search_direction: Option<P>,
+    /// Contraction factor rho
+    rho: F,
+    /// Sufficient decrease parameter
+    c: F,
+    /// Number of past costs considered
+    memory: usize,
+    /// Costs at the initial points of past line searches
+    history: VecDeque<F>,
+    /// Initial step length
+    init_alpha: F,
+    /// alpha
+    alpha: F,
+    /// Gradient type
+    _gradient: core::marker::PhantomData<G>,
+}
+
+impl<P, G, F> NonmonotoneLineSearch<P, G, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of `NonmonotoneLineSearch`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::linesearch::NonmonotoneLineSearch;
+    /// let nmls: NonmonotoneLineSearch<Vec<f64>, Vec<f64>, f64> = NonmonotoneLineSearch::new();
+    /// ```
+    pub fn new() -> Self {
+        NonmonotoneLineSearch {
+            init_param: None,
+            ref_cost: F::infinity(),
+            init_dg: F::nan(),
+            search_direction: None,
+            rho: float!(0.5),
+            c: float!(1e-4),
+            memory: 10,
+            history: VecDeque::new(),
+            init_alpha: float!(1.0),
+            alpha: float!(1.0),
+            _gradient: core::marker::PhantomData,
+        }
+    }
+
+    /// Set contraction factor rho
+    ///
+    /// This factor must be in (0, 1) and defaults to `0.5`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::Error;
+    /// # use argmin::solver::linesearch::NonmonotoneLineSearch;
+    /// # fn main() -> Result<(), Error> {
+    /// let nmls: NonmonotoneLineSearch<Vec<f64>, Vec<f64>, f64> =
+    ///     NonmonotoneLineSearch::new().with_rho(0.8)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_rho(mut self, rho: F) -> Result<Self, Error> {
+        if rho <= float!(0.0) || rho >= float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`NonmonotoneLineSearch`: Contraction factor rho must be in (0, 1)."
+            ));
+        }
+        self.rho = rho;
+        Ok(self)
+    }
+
+    /// Set sufficient decrease parameter c
+    ///
+    /// This parameter must be in (0, 1) and defaults to `1e-4`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::Error;
+    /// # use argmin::solver::linesearch::NonmonotoneLineSearch;
+    /// # fn main() -> Result<(), Error> {
+    /// let nmls: NonmonotoneLineSearch<Vec<f64>, Vec<f64>, f64> =
+    ///     NonmonotoneLineSearch::new().with_c(1e-3)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_c(mut self, c: F) -> Result<Self, Error> {
+        if c <= float!(0.0) || c >= float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`NonmonotoneLineSearch`: Parameter c must be in (0, 1)."
+            ));
+        }
+        self.c = c;
+        Ok(self)
+    }
+
+    /// Set the number of past costs `M` the current cost is compared to
+    ///
+    /// Must be at least 1 and defaults to `10`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::Error;
+    /// # use argmin::solver::linesearch::NonmonotoneLineSearch;
+    /// # fn main() -> Result<(), Error> {
+    /// let nmls: NonmonotoneLineSearch<Vec<f64>, Vec<f64>, f64> =
+    ///     NonmonotoneLineSearch::new().with_memory(5)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_memory(mut self, memory: usize) -> Result<Self, Error> {
+        if memory < 1 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`NonmonotoneLineSearch`: memory must be >= 1."
+            ));
+        }
+        self.memory = memory;
+        Ok(self)
+    }
+
+    /// Add `cost` to the history and return the maximum of the last `memory` costs
+    fn push_history(&mut self, cost: F) -> F {
+        self.history.push_back(cost);
+        while self.history.len() > self.memory {
+            self.history.pop_front();
+        }
+        self.history
+            .iter()
+            .fold(F::neg_infinity(), |acc, &c| acc.max(c))
+    }
+}
+
+impl<P, G, F> Default for NonmonotoneLineSearch<P, G, F>
+where
+    F: ArgminFloat,
+{
+    fn default() -> Self {
+        NonmonotoneLineSearch::new()
+    }
+}
+
+impl<P, G, F> LineSearch<P, F> for NonmonotoneLineSearch<P, G, F>
+where
+    F: ArgminFloat,
+{
+    /// Set search direction
+    fn search_direction(&mut self, search_direction: P) {
+        self.search_direction = Some(search_direction);
+    }
+
+    /// Set initial step length
+    fn initial_step_length(&mut self, alpha: F) -> Result<(), Error> {
+        if alpha <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`NonmonotoneLineSearch`: Initial alpha must be > 0."
+            ));
+        }
+        self.init_alpha = alpha;
+        self.alpha = alpha;
+        Ok(())
+    }
+
+    /// Accepted step length
+    fn step_length(&self) -> Option<F> {
+        Some(self.alpha)
+    }
+}
+
+impl<P, G, F> NonmonotoneLineSearch<P, G, F>
+where
+    P: ArgminScaledAdd<P, F, P>,
+    IterState<P, G, (), (), F>: State<Float = F>,
+    F: ArgminFloat,
+{
+    /// Evaluate the cost at the current step length
+    fn step<O>(
+        &self,
+        problem: &mut Problem<O>,
+        state: IterState<P, G, (), (), F>,
+    ) -> Result<IterState<P, G, (), (), F>, Error>
+    where
+        O: CostFunction<Param = P, Output = F>,
+    {
+        let new_param = self
+            .init_param
+            .as_ref()
+            .unwrap()
+            .scaled_add(&self.alpha, self.search_direction.as_ref().unwrap());
+        let cost = problem.cost(&new_param)?;
+        Ok(state.param(new_param).cost(cost))
+    }
+}
+
+impl<O, P, G, F> Solver<O, IterState<P, G, (), (), F>> for NonmonotoneLineSearch<P, G, F>
+where
+    O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
+    P: Clone + SerializeAlias + ArgminScaledAdd<P, F, P>,
+    G: SerializeAlias + ArgminDot<P, F>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Non-monotone line search";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        check_param!(
+            self.search_direction,
+            concat!(
+                "`NonmonotoneLineSearch`: Search direction not initialized. ",
+                "Call `search_direction` before executing the solver."
+            )
+        );
+
+        let init_param = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`NonmonotoneLineSearch` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+
+        let cost = state.get_cost();
+        let init_cost = if cost.is_infinite() {
+            problem.cost(&init_param)?
+        } else {
+            cost
+        };
+
+        let init_grad = state
+            .take_gradient()
+            .map(Result::Ok)
+            .unwrap_or_else(|| problem.gradient(&init_param))?;
+
+        self.init_dg = init_grad.dot(self.search_direction.as_ref().unwrap());
+        if self.init_dg >= float!(0.0) {
+            return Err(argmin_error!(
+                ConditionViolated,
+                "`NonmonotoneLineSearch`: Search direction must be a descent direction."
+            ));
+        }
+
+        self.ref_cost = self.push_history(init_cost);
+        self.alpha = self.init_alpha;
+        self.init_param = Some(init_param);
+        let state = self.step(problem, state)?;
+        Ok((state, Some(kv!("reference_cost" => self.ref_cost;))))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        self.alpha = self.alpha * self.rho;
+        let state = self.step(problem, state)?;
+        Ok((state, None))
+    }
+
+    fn terminate(&mut self, state: &IterState<P, G, (), (), F>) -> TerminationStatus {
+        if state.cost <= self.ref_cost + self.c * self.alpha * self.init_dg {
+            TerminationStatus::Terminated(TerminationReason::SolverConverged)
+        } else {
+            TerminationStatus::NotTerminated
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_error;
+    use crate::core::{ArgminError, Executor};
+    use crate::solver::gradientdescent::SteepestDescent;
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+    use argmin_testfunctions::{rosenbrock_2d, rosenbrock_2d_derivative};
+
+    test_trait_impl!(nonmonotone, NonmonotoneLineSearch<Vec<f64>, Vec<f64>, f64>);
+
+    #[derive(Clone)]
+    struct Rosenbrock {}
+
+    impl CostFunction for Rosenbrock {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(rosenbrock_2d(p, 1.0, 100.0))
+        }
+    }
+
+    impl Gradient for Rosenbrock {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(rosenbrock_2d_derivative(p, 1.0, 100.0))
+        }
+    }
+
+    /// `f(x) = x^2` in one dimension
+    #[derive(Clone)]
+    struct Parabola {}
+
+    impl CostFunction for Parabola {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(p[0].powi(2))
+        }
+    }
+
+    impl Gradient for Parabola {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(vec![2.0 * p[0]])
+        }
+    }
+
+    type Nmls = NonmonotoneLineSearch<Vec<f64>, Vec<f64>, f64>;
+
+    /// Runs the line search from `x` along `d` and returns it together with the accepted point
+    fn run(ls: Nmls, x: f64, d: f64) -> (Nmls, f64) {
+        let mut ls = ls;
+        ls.search_direction(vec![d]);
+        let res = Executor::new(Parabola {}, ls)
+            .configure(|state| state.param(vec![x]).max_iters(100))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        (res.solver, res.state.get_param().unwrap()[0])
+    }
+
+    #[test]
+    fn test_new() {
+        let NonmonotoneLineSearch {
+            init_param,
+            ref_cost,
+            init_dg,
+            search_direction,
+            rho,
+            c,
+            memory,
+            history,
+            init_alpha,
+            alpha,
+            _gradient,
+        }: NonmonotoneLineSearch<Vec<f64>, Vec<f64>, f64> = NonmonotoneLineSearch::new();
+
+        assert!(init_param.is_none());
+        assert!(ref_cost.is_infinite());
+        assert!(init_dg.is_nan());
+        assert!(search_direction.is_none());
+        assert_eq!(rho.to_ne_bytes(), 0.5f64.to_ne_bytes());
+        assert_eq!(c.to_ne_bytes(), 1e-4f64.to_ne_bytes());
+        assert_eq!(memory, 10);
+        assert!(history.is_empty());
+        assert_eq!(init_alpha.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(alpha.to_ne_bytes(), 1.0f64.to_ne_bytes());
+    }
+
+    #[test]
+    fn test_with_rho() {
+        let nmls: NonmonotoneLineSearch<Vec<f64>, Vec<f64>, f64> =
+            NonmonotoneLineSearch::new().with_rho(0.8).unwrap();
+        assert_eq!(nmls.rho.to_ne_bytes(), 0.8f64.to_ne_bytes());
+
+        for rho in [0.0, 1.0, -0.5, 1.5] {
+            let res: Result<NonmonotoneLineSearch<Vec<f64>, Vec<f64>, f64>, _> =
+                NonmonotoneLineSearch::new().with_rho(rho);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`NonmonotoneLineSearch`: Contraction factor rho must be in (0, 1).\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_c() {
+        let nmls: NonmonotoneLineSearch<Vec<f64>, Vec<f64>, f64> =
+            NonmonotoneLineSearch::new().with_c(0.1).unwrap();
+        assert_eq!(nmls.c.to_ne_bytes(), 0.1f64.to_ne_bytes());
+
+        for c in [0.0, 1.0, -0.5, 1.5] {
+            let res: Result<NonmonotoneLineSearch<Vec<f64>, Vec<f64>, f64>, _> =
+                NonmonotoneLineSearch::new().with_c(c);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`NonmonotoneLineSearch`: Parameter c must be in (0, 1).\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_memory() {
+        let nmls: NonmonotoneLineSearch<Vec<f64>, Vec<f64>, f64> =
+            NonmonotoneLineSearch::new().with_memory(1).unwrap();
+        assert_eq!(nmls.memory, 1);
+
+        let res: Result<NonmonotoneLineSearch<Vec<f64>, Vec<f64>, f64>, _> =
+            NonmonotoneLineSearch::new().with_memory(0);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`NonmonotoneLineSearch`: memory must be >= 1.\""
+        );
+    }
+
+    #[test]
+    fn test_initial_step_length() {
+        let mut nmls: NonmonotoneLineSearch<Vec<f64>, Vec<f64>, f64> = NonmonotoneLineSearch::new();
+        assert!(nmls.initial_step_length(0.5).is_ok());
+        assert_eq!(nmls.init_alpha.to_ne_bytes(), 0.5f64.to_ne_bytes());
+
+        assert_error!(
+            nmls.initial_step_length(0.0),
+            ArgminError,
+            "Invalid parameter: \"`NonmonotoneLineSearch`: Initial alpha must be > 0.\""
+        );
+    }
+
+    #[test]
+    fn test_init() {
+        let mut nmls: NonmonotoneLineSearch<Vec<f64>, Vec<f64>, f64> = NonmonotoneLineSearch::new();
+        let res = nmls.init(&mut Problem::new(Parabola {}), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`NonmonotoneLineSearch`: Search direction not initialized. ",
+                "Call `search_direction` before executing the solver.\""
+            )
+        );
+
+        nmls.search_direction(vec![-1.0]);
+        let res = nmls.init(&mut Problem::new(Parabola {}), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`NonmonotoneLineSearch` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+
+        let res = nmls.init(
+            &mut Problem::new(Parabola {}),
+            IterState::new().param(vec![-1.0]),
+        );
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Condition violated: \"`NonmonotoneLineSearch`: ",
+                "Search direction must be a descent direction.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_monotone() {
+        // With memory 1, the Armijo condition must hold: steps 2.0 and 1.0 overshoot to the
+        // other side of the minimum and are rejected, 0.5 lands in the minimum.
+        let mut nmls = NonmonotoneLineSearch::new().with_memory(1).unwrap();
+        nmls.initial_step_length(2.0).unwrap();
+        let (_, x) = run(nmls, 1.0, -2.0);
+        assert_relative_eq!(x, 0.0, epsilon = f64::EPSILON);
+    }
+
+    #[test]
+    fn test_history() {
+        let nmls = NonmonotoneLineSearch::new().with_memory(3).unwrap();
+
+        // The history is kept by the returned line search and holds at most `memory` entries
+        let (nmls, x) = run(nmls, 2.0, -1.0);
+        assert_relative_eq!(x, 1.0, epsilon = f64::EPSILON);
+        let (mut nmls, x) = run(nmls, 1.0, -0.1);
+        assert_relative_eq!(x, 0.9, epsilon = f64::EPSILON);
+        assert_eq!(nmls.history, VecDeque::from(vec![4.0, 1.0]));
+
+        // Reference cost is 4.0, therefore a step to x = -1.5 (cost 2.25) is accepted even
+        // though the cost increases compared to the initial cost 0.81.
+        nmls.initial_step_length(2.4).unwrap();
+        let (mut nmls, x) = run(nmls, 0.9, -1.0);
+        assert_relative_eq!(x, -1.5, epsilon = 1e-12);
+        assert_eq!(nmls.history.len(), 3);
+        assert_relative_eq!(nmls.history[2], 0.81, epsilon = 1e-12);
+
+        // The cost 4.0 drops out of the history and the reference cost is 2.25. Therefore the
+        // symmetric step to x = 1.5 is rejected.
+        nmls.initial_step_length(3.0).unwrap();
+        let (nmls, x) = run(nmls, -1.5, 1.0);
+        assert_relative_eq!(x, 0.0, epsilon = 1e-12);
+        assert_eq!(nmls.history, VecDeque::from(vec![1.0, 0.81, 2.25]));
+
+        // A new instance starts with an empty history
+        let (nmls, _) = run(
+            NonmonotoneLineSearch::new().with_memory(3).unwrap(),
+            2.0,
+            -1.0,
+        );
+        assert_eq!(nmls.history, VecDeque::from(vec![4.0]));
+    }
+
+    #[test]
+    fn test_steepest_descent() {
+        let solver = SteepestDescent::new(NonmonotoneLineSearch::new());
+        let run = |solver: SteepestDescent<Nmls>| {
+            Executor::new(Rosenbrock {}, solver)
+                .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(100))
+                .run()
+                .unwrap()
+                .state
+                .get_best_cost()
+        };
+        let cost = run(solver.clone());
+        assert!(cost < rosenbrock_2d(&[-1.2, 1.0], 1.0, 100.0));
+
+        // The history does not leak into other runs of the same solver
+        assert_eq!(run(solver).to_ne_bytes(), cost.to_ne_bytes());
+    }
+}
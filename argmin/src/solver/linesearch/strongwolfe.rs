@@ -0,0 +1,785 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, Gradient, IterState, LineSearch, Problem, SerializeAlias,
+    Solver, State, TerminationReason, KV,
+};
+use alloc::string::ToString;
+use argmin_math::{ArgminDot, ArgminScaledAdd};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Step length together with cost and directional derivative
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+struct Point<F> {
+    x: F,
+    fx: F,
+    gx: F,
+}
+
+/// Stage of the line search
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+enum Stage<F> {
+    /// Step lengths are increased until an interval containing acceptable step lengths is found.
+    /// Holds the previous trial point.
+    Bracketing(Point<F>),
+    /// The bracketing interval is narrowed down. `lo` is the best point so far which satisfies
+    /// the sufficient decrease condition, `hi` is the other end of the interval.
+    Zoom { lo: Point<F>, hi: Point<F> },
+}
+
+/// # Strong Wolfe line search
+///
+/// Finds a step length which satisfies the strong Wolfe conditions
+///
+/// `f(x + alpha * d) <= f(x) + c1 * alpha * g(x)^T d` and
+/// `|g(x + alpha * d)^T d| <= c2 * |g(x)^T d|`
+///
+/// using the two-stage algorithm of Nocedal and Wright. In the bracketing stage, the step length
+/// is increased by a constant factor (see
+/// [`with_extrapolation`](`StrongWolfeLineSearch::with_extrapolation`)) until an interval which
+/// contains acceptable step lengths is found. In the zoom stage, this interval is narrowed down
+/// by minimizing the cubic interpolant of the function values and directional derivatives at its
+/// end points. Interpolated step lengths too close to the end points are replaced by bisection.
+/// Each iteration requires one evaluation of the cost function and the gradient.
+///
+/// Compared to [`MoreThuenteLineSearch`](`crate::solver::linesearch::MoreThuenteLineSearch`),
+/// this algorithm is considerably simpler and its behavior is easier to follow and tune, while
+/// it typically requires a similar number of function evaluations.
+///
+/// With the method [`with_c`](`StrongWolfeLineSearch::with_c`) the scaling factors for the
+/// sufficient decrease condition and the curvature condition can be supplied. By default they are
+/// set to `c1 = 1e-4` and `c2 = 0.9`. The step length is bounded from above by
+/// [`with_max_step`](`StrongWolfeLineSearch::with_max_step`). If this bound is reached without
+/// satisfying the curvature condition, or if the relative width of the bracketing interval falls
+/// below [`with_width_tolerance`](`StrongWolfeLineSearch::with_width_tolerance`), the line search
+/// terminates with [`TerminationReason::SolverExit`] and returns the best step length found.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`] and [`Gradient`].
+///
+/// ## Reference
+///
+/// Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
+/// Springer. ISBN 0-387-30303-0.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct StrongWolfeLineSearch<P, G, F> {
+    /// Search direction
+    search_direction: Option<P>,
+    /// Initial parameter vector
+    init_param: Option<P>,
+    /// Initial cost
+    init_cost: F,
+    /// Initial gradient
+    init_grad: Option<G>,
+    /// Initial directional derivative
+    init_dg: F,
+    /// Sufficient decrease parameter
+    c1: F,
+    /// Curvature parameter
+    c2: F,
+    /// Factor by which the step length is increased in the bracketing stage
+    extrapolation: F,
+    /// Maximum step length
+    max_step: F,
+    /// Relative tolerance on the width of the bracketing interval
+    width_tol: F,
+    /// Initial step length
+    init_alpha: F,
+    /// Current trial step length
+    alpha: F,
+    /// Current stage
+    stage: Stage<F>,
+}
+
+impl<P, G, F> StrongWolfeLineSearch<P, G, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of `StrongWolfeLineSearch`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::linesearch::StrongWolfeLineSearch;
+    /// let swls: StrongWolfeLineSearch<Vec<f64>, Vec<f64>, f64> = StrongWolfeLineSearch::new();
+    /// ```
+    pub fn new() -> Self {
+        StrongWolfeLineSearch {
+            search_direction: None,
+            init_param: None,
+            init_cost: F::infinity(),
+            init_grad: None,
+            init_dg: F::nan(),
+            c1: float!(1e-4),
+            c2: float!(0.9),
+            extrapolation: float!(2.0),
+            max_step: F::infinity(),
+            width_tol: float!(1e-10),
+            init_alpha: float!(1.0),
+            alpha: float!(1.0),
+            stage: Stage::Bracketing(Point {
+                x: float!(0.0),
+                fx: F::nan(),
+                gx: F::nan(),
+            }),
+        }
+    }
+
+    /// Set the constants c1 and c2 for the sufficient decrease and curvature conditions,
+    /// respectively. `0 < c1 < c2 < 1` must hold.
+    ///
+    /// The default values are `c1 = 1e-4` and `c2 = 0.9`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::linesearch::StrongWolfeLineSearch;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let swls: StrongWolfeLineSearch<Vec<f64>, Vec<f64>, f64> =
+    ///     StrongWolfeLineSearch::new().with_c(1e-3, 0.1)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_c(mut self, c1: F, c2: F) -> Result<Self, Error> {
+        if c1 <= float!(0.0) || c1 >= c2 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`StrongWolfeLineSearch`: Parameter c1 must be in (0, c2)."
+            ));
+        }
+        if c2 >= float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`StrongWolfeLineSearch`: Parameter c2 must be in (c1, 1)."
+            ));
+        }
+        self.c1 = c1;
+        self.c2 = c2;
+        Ok(self)
+    }
+
+    /// Set the factor by which the step length is increased in the bracketing stage
+    ///
+    /// Must be larger than 1 and defaults to `2.0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::linesearch::StrongWolfeLineSearch;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let swls: StrongWolfeLineSearch<Vec<f64>, Vec<f64>, f64> =
+    ///     StrongWolfeLineSearch::new().with_extrapolation(4.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_extrapolation(mut self, factor: F) -> Result<Self, Error> {
+        if factor <= float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`StrongWolfeLineSearch`: extrapolation factor must be > 1."
+            ));
+        }
+        self.extrapolation = factor;
+        Ok(self)
+    }
+
+    /// Set the maximum step length
+    ///
+    /// Must be larger than 0 and defaults to infinity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::linesearch::StrongWolfeLineSearch;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let swls: StrongWolfeLineSearch<Vec<f64>, Vec<f64>, f64> =
+    ///     StrongWolfeLineSearch::new().with_max_step(10.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_max_step(mut self, max_step: F) -> Result<Self, Error> {
+        if max_step <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`StrongWolfeLineSearch`: maximum step length must be > 0."
+            ));
+        }
+        self.max_step = max_step;
+        Ok(self)
+    }
+
+    /// Set relative tolerance on the width of the bracketing interval
+    ///
+    /// Must be non-negative and defaults to `1e-10`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::linesearch::StrongWolfeLineSearch;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let swls: StrongWolfeLineSearch<Vec<f64>, Vec<f64>, f64> =
+    ///     StrongWolfeLineSearch::new().with_width_tolerance(1e-8)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_width_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`StrongWolfeLineSearch`: relative width tolerance must be >= 0.0."
+            ));
+        }
+        self.width_tol = tol;
+        Ok(self)
+    }
+
+    /// Whether the sufficient decrease condition holds at `p`
+    fn sufficient_decrease(&self, p: &Point<F>) -> bool {
+        p.fx <= self.init_cost + self.c1 * p.x * self.init_dg
+    }
+
+    /// Whether the strong curvature condition holds at `p`
+    fn curvature(&self, p: &Point<F>) -> bool {
+        p.gx.abs() <= -self.c2 * self.init_dg
+    }
+}
+
+impl<P, G, F> Default for StrongWolfeLineSearch<P, G, F>
+where
+    F: ArgminFloat,
+{
+    fn default() -> Self {
+        StrongWolfeLineSearch::new()
+    }
+}
+
+impl<P, G, F> LineSearch<P, F> for StrongWolfeLineSearch<P, G, F>
+where
+    F: ArgminFloat,
+{
+    /// Set search direction
+    fn search_direction(&mut self, search_direction: P) {
+        self.search_direction = Some(search_direction);
+    }
+
+    /// Set initial alpha value
+    fn initial_step_length(&mut self, alpha: F) -> Result<(), Error> {
+        if alpha <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "StrongWolfeLineSearch: Initial alpha must be > 0."
+            ));
+        }
+        self.init_alpha = alpha;
+        self.alpha = alpha;
+        Ok(())
+    }
+
+    /// Accepted step length
+    fn step_length(&self) -> Option<F> {
+        Some(self.alpha)
+    }
+}
+
+impl<P, G, O, F> Solver<O, IterState<P, G, (), (), F>> for StrongWolfeLineSearch<P, G, F>
+where
+    O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
+    P: Clone + SerializeAlias + ArgminDot<G, F> + ArgminScaledAdd<P, F, P>,
+    G: Clone + SerializeAlias + ArgminDot<P, F>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Strong Wolfe line search";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        check_param!(
+            self.search_direction,
+            concat!(
+                "`StrongWolfeLineSearch`: Search direction not initialized. ",
+                "Call `search_direction` before executing the solver."
+            )
+        );
+
+        let init_param = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`StrongWolfeLineSearch` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+
+        let cost = state.get_cost();
+        self.init_cost = if cost.is_infinite() {
+            problem.cost(&init_param)?
+        } else {
+            cost
+        };
+
+        let init_grad = state
+            .take_gradient()
+            .map(Result::Ok)
+            .unwrap_or_else(|| problem.gradient(&init_param))?;
+
+        self.init_dg = init_grad.dot(self.search_direction.as_ref().unwrap());
+        if self.init_dg >= float!(0.0) {
+            return Err(argmin_error!(
+                ConditionViolated,
+                "`StrongWolfeLineSearch`: Search direction must be a descent direction."
+            ));
+        }
+
+        self.alpha = self.init_alpha.min(self.max_step);
+        self.stage = Stage::Bracketing(Point {
+            x: float!(0.0),
+            fx: self.init_cost,
+            gx: self.init_dg,
+        });
+        self.init_param = Some(init_param);
+        self.init_grad = Some(init_grad);
+
+        Ok((state, None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        let init_param = self.init_param.as_ref().unwrap();
+        let direction = self.search_direction.as_ref().unwrap();
+
+        let param = init_param.scaled_add(&self.alpha, direction);
+        let cost = problem.cost(&param)?;
+        let grad = problem.gradient(&param)?;
+        let cur = Point {
+            x: self.alpha,
+            fx: cost,
+            gx: grad.dot(direction),
+        };
+
+        if self.sufficient_decrease(&cur) && self.curvature(&cur) {
+            return Ok((
+                state
+                    .param(param)
+                    .cost(cost)
+                    .gradient(grad)
+                    .terminate_with(TerminationReason::SolverConverged),
+                None,
+            ));
+        }
+
+        let (lo, hi) = match self.stage {
+            Stage::Bracketing(prev) => {
+                if !self.sufficient_decrease(&cur) || (prev.x > float!(0.0) && cur.fx >= prev.fx) {
+                    (prev, cur)
+                } else if cur.gx >= float!(0.0) {
+                    (cur, prev)
+                } else if self.alpha >= self.max_step {
+                    return Ok((
+                        state.param(param).cost(cost).gradient(grad).terminate_with(
+                            TerminationReason::SolverExit(
+                                "Maximum step length reached".to_string(),
+                            ),
+                        ),
+                        None,
+                    ));
+                } else {
+                    // No bracket yet; increase step length
+                    self.stage = Stage::Bracketing(cur);
+                    self.alpha = (self.alpha * self.extrapolation).min(self.max_step);
+                    return Ok((state.param(param).cost(cost).gradient(grad), None));
+                }
+            }
+            Stage::Zoom { lo, hi } => {
+                if !self.sufficient_decrease(&cur) || cur.fx >= lo.fx {
+                    (lo, cur)
+                } else if cur.gx * (hi.x - lo.x) >= float!(0.0) {
+                    (cur, lo)
+                } else {
+                    (cur, hi)
+                }
+            }
+        };
+
+        if (hi.x - lo.x).abs() <= self.width_tol * lo.x.max(hi.x) {
+            // Return the best point found so far
+            let param = init_param.scaled_add(&lo.x, direction);
+            self.alpha = lo.x;
+            let grad = problem.gradient(&param)?;
+            return Ok((
+                state
+                    .param(param)
+                    .cost(lo.fx)
+                    .gradient(grad)
+                    .terminate_with(TerminationReason::SolverExit(
+                        "Interval of uncertainty too small".to_string(),
+                    )),
+                None,
+            ));
+        }
+
+        self.stage = Stage::Zoom { lo, hi };
+        self.alpha = cubic_minimizer(&lo, &hi);
+        Ok((state.param(param).cost(cost).gradient(grad), None))
+    }
+}
+
+/// Minimizer of the cubic interpolating the function values and derivatives at `a` and `b`,
+/// safeguarded to lie within the middle 80% of the interval. Falls back to bisection.
+fn cubic_minimizer<F: ArgminFloat>(a: &Point<F>, b: &Point<F>) -> F {
+    let d1 = a.gx + b.gx - float!(3.0) * (a.fx - b.fx) / (a.x - b.x);
+    let rad = d1 * d1 - a.gx * b.gx;
+    let (lower, upper) = (a.x.min(b.x), a.x.max(b.x));
+    let margin = float!(0.1) * (upper - lower);
+    if rad >= float!(0.0) {
+        let d2 = (b.x - a.x).signum() * rad.sqrt();
+        let x = b.x - (b.x - a.x) * (b.gx + d2 - d1) / (b.gx - a.gx + float!(2.0) * d2);
+        if x.is_finite() && x >= lower + margin && x <= upper - margin {
+            return x;
+        }
+    }
+    float!(0.5) * (a.x + b.x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Executor};
+    use crate::solver::gradientdescent::SteepestDescent;
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+    use argmin_testfunctions::{rosenbrock_2d, rosenbrock_2d_derivative};
+
+    test_trait_impl!(strongwolfe, StrongWolfeLineSearch<Vec<f64>, Vec<f64>, f64>);
+
+    #[derive(Clone)]
+    struct Rosenbrock {}
+
+    impl CostFunction for Rosenbrock {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(rosenbrock_2d(p, 1.0, 100.0))
+        }
+    }
+
+    impl Gradient for Rosenbrock {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(rosenbrock_2d_derivative(p, 1.0, 100.0))
+        }
+    }
+
+    /// `f(x) = (x - 3)^2` in one dimension
+    #[derive(Clone)]
+    struct Parabola {}
+
+    impl CostFunction for Parabola {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok((p[0] - 3.0).powi(2))
+        }
+    }
+
+    impl Gradient for Parabola {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(vec![2.0 * (p[0] - 3.0)])
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let StrongWolfeLineSearch {
+            search_direction,
+            init_param,
+            init_cost,
+            init_grad,
+            init_dg,
+            c1,
+            c2,
+            extrapolation,
+            max_step,
+            width_tol,
+            init_alpha,
+            alpha,
+            stage,
+        }: StrongWolfeLineSearch<Vec<f64>, Vec<f64>, f64> = StrongWolfeLineSearch::new();
+
+        assert!(search_direction.is_none());
+        assert!(init_param.is_none());
+        assert!(init_cost.is_infinite());
+        assert!(init_grad.is_none());
+        assert!(init_dg.is_nan());
+        assert_eq!(c1.to_ne_bytes(), 1e-4f64.to_ne_bytes());
+        assert_eq!(c2.to_ne_bytes(), 0.9f64.to_ne_bytes());
+        assert_eq!(extrapolation.to_ne_bytes(), 2.0f64.to_ne_bytes());
+        assert!(max_step.is_infinite());
+        assert_eq!(width_tol.to_ne_bytes(), 1e-10f64.to_ne_bytes());
+        assert_eq!(init_alpha.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(alpha.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert!(matches!(stage, Stage::Bracketing(Point { x, .. }) if x == 0.0));
+    }
+
+    #[test]
+    fn test_with_c() {
+        let swls: StrongWolfeLineSearch<Vec<f64>, Vec<f64>, f64> =
+            StrongWolfeLineSearch::new().with_c(0.1, 0.5).unwrap();
+        assert_eq!(swls.c1.to_ne_bytes(), 0.1f64.to_ne_bytes());
+        assert_eq!(swls.c2.to_ne_bytes(), 0.5f64.to_ne_bytes());
+
+        for (c1, c2) in [(0.0, 0.5), (-0.1, 0.5), (0.5, 0.5), (0.9, 0.1)] {
+            let res: Result<StrongWolfeLineSearch<Vec<f64>, Vec<f64>, f64>, _> =
+                StrongWolfeLineSearch::new().with_c(c1, c2);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`StrongWolfeLineSearch`: Parameter c1 must be in (0, c2).\""
+            );
+        }
+
+        for c2 in [1.0, 1.5] {
+            let res: Result<StrongWolfeLineSearch<Vec<f64>, Vec<f64>, f64>, _> =
+                StrongWolfeLineSearch::new().with_c(0.1, c2);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`StrongWolfeLineSearch`: Parameter c2 must be in (c1, 1).\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_extrapolation() {
+        let swls: StrongWolfeLineSearch<Vec<f64>, Vec<f64>, f64> = StrongWolfeLineSearch::new()
+            .with_extrapolation(3.0)
+            .unwrap();
+        assert_eq!(swls.extrapolation.to_ne_bytes(), 3.0f64.to_ne_bytes());
+
+        for factor in [1.0, 0.5, -1.0] {
+            let res: Result<StrongWolfeLineSearch<Vec<f64>, Vec<f64>, f64>, _> =
+                StrongWolfeLineSearch::new().with_extrapolation(factor);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`StrongWolfeLineSearch`: extrapolation factor must be > 1.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_max_step() {
+        let swls: StrongWolfeLineSearch<Vec<f64>, Vec<f64>, f64> =
+            StrongWolfeLineSearch::new().with_max_step(10.0).unwrap();
+        assert_eq!(swls.max_step.to_ne_bytes(), 10.0f64.to_ne_bytes());
+
+        for max_step in [0.0, -1.0] {
+            let res: Result<StrongWolfeLineSearch<Vec<f64>, Vec<f64>, f64>, _> =
+                StrongWolfeLineSearch::new().with_max_step(max_step);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`StrongWolfeLineSearch`: maximum step length must be > 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_width_tolerance() {
+        let swls: StrongWolfeLineSearch<Vec<f64>, Vec<f64>, f64> = StrongWolfeLineSearch::new()
+            .with_width_tolerance(0.0)
+            .unwrap();
+        assert_eq!(swls.width_tol.to_ne_bytes(), 0.0f64.to_ne_bytes());
+
+        let res: Result<StrongWolfeLineSearch<Vec<f64>, Vec<f64>, f64>, _> =
+            StrongWolfeLineSearch::new().with_width_tolerance(-1e-10);
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`StrongWolfeLineSearch`: ",
+                "relative width tolerance must be >= 0.0.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_initial_step_length() {
+        let mut swls: StrongWolfeLineSearch<Vec<f64>, Vec<f64>, f64> = StrongWolfeLineSearch::new();
+        assert!(swls.initial_step_length(0.5).is_ok());
+        assert_eq!(swls.alpha.to_ne_bytes(), 0.5f64.to_ne_bytes());
+
+        assert_error!(
+            swls.initial_step_length(0.0),
+            ArgminError,
+            "Invalid parameter: \"StrongWolfeLineSearch: Initial alpha must be > 0.\""
+        );
+    }
+
+    #[test]
+    fn test_init() {
+        let mut swls: StrongWolfeLineSearch<Vec<f64>, Vec<f64>, f64> = StrongWolfeLineSearch::new();
+        let res = swls.init(&mut Problem::new(Parabola {}), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`StrongWolfeLineSearch`: Search direction not initialized. ",
+                "Call `search_direction` before executing the solver.\""
+            )
+        );
+
+        swls.search_direction(vec![1.0]);
+        let res = swls.init(&mut Problem::new(Parabola {}), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`StrongWolfeLineSearch` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+
+        swls.search_direction(vec![-1.0]);
+        let res = swls.init(
+            &mut Problem::new(Parabola {}),
+            IterState::new().param(vec![0.0]),
+        );
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Condition violated: \"`StrongWolfeLineSearch`: ",
+                "Search direction must be a descent direction.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_cubic_minimizer() {
+        // Cubic interpolation is exact for a quadratic
+        let f = |x: f64| (x - 0.3).powi(2);
+        let g = |x: f64| 2.0 * (x - 0.3);
+        let a = Point {
+            x: 0.0,
+            fx: f(0.0),
+            gx: g(0.0),
+        };
+        let b = Point {
+            x: 1.0,
+            fx: f(1.0),
+            gx: g(1.0),
+        };
+        assert_relative_eq!(cubic_minimizer(&a, &b), 0.3, epsilon = 1e-12);
+        assert_relative_eq!(cubic_minimizer(&b, &a), 0.3, epsilon = 1e-12);
+
+        // Minimizer close to the end point is replaced by bisection
+        let f = |x: f64| (x - 0.01).powi(2);
+        let g = |x: f64| 2.0 * (x - 0.01);
+        let a = Point {
+            x: 0.0,
+            fx: f(0.0),
+            gx: g(0.0),
+        };
+        let b = Point {
+            x: 1.0,
+            fx: f(1.0),
+            gx: g(1.0),
+        };
+        assert_relative_eq!(cubic_minimizer(&a, &b), 0.5, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_strong_wolfe_conditions() {
+        // Step lengths which are too small (extrapolation) and too large (zoom)
+        for alpha in [1e-3, 1.0, 100.0] {
+            let mut swls = StrongWolfeLineSearch::new().with_c(1e-4, 0.1).unwrap();
+            swls.search_direction(vec![1.0]);
+            swls.initial_step_length(alpha).unwrap();
+            let res = Executor::new(Parabola {}, swls)
+                .configure(|state| state.param(vec![0.0]).max_iters(100))
+                .run()
+                .unwrap();
+            assert_eq!(
+                res.state.get_termination_reason(),
+                Some(&TerminationReason::SolverConverged)
+            );
+            let x = res.state.get_param().unwrap()[0];
+            assert!((x - 3.0).powi(2) <= 9.0 - 1e-4 * x * 6.0);
+            assert!((2.0 * (x - 3.0)).abs() <= 0.1 * 6.0);
+        }
+    }
+
+    #[test]
+    fn test_max_step() {
+        let mut swls = StrongWolfeLineSearch::new()
+            .with_c(1e-4, 0.1)
+            .unwrap()
+            .with_max_step(1.0)
+            .unwrap();
+        swls.search_direction(vec![1.0]);
+        swls.initial_step_length(0.1).unwrap();
+        let res = Executor::new(Parabola {}, swls)
+            .configure(|state| state.param(vec![0.0]).max_iters(100))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverExit(
+                "Maximum step length reached".to_string()
+            ))
+        );
+        assert_eq!(
+            res.state.get_param().unwrap()[0].to_ne_bytes(),
+            1.0f64.to_ne_bytes()
+        );
+    }
+
+    #[test]
+    fn test_steepest_descent() {
+        let solver = SteepestDescent::new(StrongWolfeLineSearch::new());
+        let res = Executor::new(Rosenbrock {}, solver)
+            .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(10))
+            .run()
+            .unwrap();
+        assert!(res.state.get_best_cost() < rosenbrock_2d(&[-1.2, 1.0], 1.0, 100.0));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_lbfgs() {
+        use crate::solver::quasinewton::LBFGS;
+        let solver = LBFGS::new(StrongWolfeLineSearch::new(), 7);
+        let res = Executor::new(Rosenbrock {}, solver)
+            .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(100))
+            .run()
+            .unwrap();
+        let best = res.state.get_best_param().unwrap();
+        assert_relative_eq!(best[0], 1.0, epsilon = 1e-6);
+        assert_relative_eq!(best[1], 1.0, epsilon = 1e-6);
+    }
+}
@@ -0,0 +1,431 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Majorization-minimization
+//!
+//! Meta-solver which minimizes a function by repeatedly minimizing surrogate functions which
+//! majorize it. Many well-known algorithms such as expectation-maximization or iteratively
+//! reweighted least squares are instances of this framework.
+//! See [`MajorizationMinimization`] for details.
+//!
+//! ## Reference
+//!
+//! Kenneth Lange (2016). MM Optimization Algorithms. SIAM. ISBN 978-1-611974-39-3.
+
+use crate::core::{
+    ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, Executor, IterState,
+    OptimizationResult, Problem, SerializeAlias, Solver, State, TerminationReason,
+    TerminationStatus, KV,
+};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Construction of a surrogate function. Problems which are to be solved using
+/// [`MajorizationMinimization`] must implement this trait.
+///
+/// The surrogate `g(x | x_k)` constructed at `x_k` must majorize the cost function `f`, i.e.
+/// `g(x | x_k) >= f(x)` for all `x` and `g(x_k | x_k) = f(x_k)`.
+pub trait Majorize {
+    /// Type of the parameter vector
+    type Param;
+    /// Type of the surrogate problem
+    type Surrogate;
+
+    /// Construct the surrogate problem at `param`
+    fn majorize(&self, param: &Self::Param) -> Result<Self::Surrogate, Error>;
+}
+
+/// Wraps a call to `majorize` defined in the `Majorize` trait and as such allows to call
+/// `majorize` on an instance of `Problem`. Internally, the number of evaluations of `majorize` is
+/// counted.
+impl<O: Majorize> Problem<O> {
+    /// Calls `majorize` defined in the `Majorize` trait and keeps track of the number of
+    /// evaluations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Problem, Error};
+    /// # use argmin::solver::majorizationminimization::Majorize;
+    /// #
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl Majorize for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type Surrogate = Vec<f64>;
+    /// #
+    /// #     fn majorize(&self, param: &Self::Param) -> Result<Self::Surrogate, Error> {
+    /// #         Ok(param.clone())
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `Majorize`.
+    /// let mut problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let param = vec![2.0f64, 1.0f64];
+    ///
+    /// let res = problem1.majorize(&param);
+    ///
+    /// assert_eq!(problem1.counts["majorize_count"], 1);
+    /// # assert_eq!(res.unwrap(), vec![2.0f64, 1.0f64]);
+    /// ```
+    pub fn majorize(&mut self, param: &O::Param) -> Result<O::Surrogate, Error> {
+        self.problem("majorize_count", |problem| problem.majorize(param))
+    }
+}
+
+/// # Majorization-minimization
+///
+/// Meta-solver which minimizes a cost function `f` by iterating
+///
+/// `x_{k+1} = argmin_x g(x | x_k)`,
+///
+/// where the surrogate `g(. | x_k)` majorizes `f` and touches it at `x_k` (see [`Majorize`]).
+/// This guarantees that the cost function decreases monotonically. Expectation-maximization
+/// (where the surrogate is the negative expected complete-data log-likelihood) and iteratively
+/// reweighted least squares (where the surrogate is a weighted least-squares problem) are
+/// prominent examples.
+///
+/// In each iteration, the surrogate problem is minimized by running the inner solver to
+/// completion, starting from a copy of the provided initial state of the inner solver with the
+/// parameter vector set to `x_k`. The initial state therefore mainly serves to set the maximum
+/// number of iterations of the inner solver. The surrogate problem must implement all traits
+/// required by the inner solver. The solver terminates once the decrease of the cost function
+/// falls below the tolerance (see
+/// [`with_tolerance`](`MajorizationMinimization::with_tolerance`)).
+///
+/// The initial parameter vector needs to be provided via the `configure` method of the
+/// [`Executor`].
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`] and [`Majorize`].
+///
+/// ## Reference
+///
+/// Kenneth Lange (2016). MM Optimization Algorithms. SIAM. ISBN 978-1-611974-39-3.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct MajorizationMinimization<S, I, F> {
+    /// Inner solver
+    solver: S,
+    /// Initial state of the inner solver
+    init_state: I,
+    /// Tolerance on the relative decrease of the cost function
+    tol: F,
+}
+
+impl<S, I, F> MajorizationMinimization<S, I, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`MajorizationMinimization`]
+    ///
+    /// Takes the inner solver used to minimize the surrogate problems and the initial state of
+    /// the inner solver.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::majorizationminimization::MajorizationMinimization;
+    /// # use argmin::solver::neldermead::NelderMead;
+    /// # use argmin::core::{IterState, State};
+    /// let inner: NelderMead<Vec<f64>, f64> = NelderMead::new(vec![vec![0.0], vec![1.0]]);
+    /// let init_state: IterState<Vec<f64>, (), (), (), f64> = IterState::new().max_iters(100);
+    /// let mm: MajorizationMinimization<_, _, f64> = MajorizationMinimization::new(inner, init_state);
+    /// ```
+    pub fn new(solver: S, init_state: I) -> Self {
+        MajorizationMinimization {
+            solver,
+            init_state,
+            tol: F::epsilon().sqrt(),
+        }
+    }
+
+    /// Set tolerance on the relative decrease of the cost function.
+    ///
+    /// The solver terminates once `f(x_{k-1}) - f(x_k) <= tol * max(|f(x_{k-1})|, 1)`. Must be
+    /// non-negative and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::majorizationminimization::MajorizationMinimization;
+    /// # use argmin::solver::neldermead::NelderMead;
+    /// # use argmin::core::{Error, IterState, State};
+    /// # fn main() -> Result<(), Error> {
+    /// # let inner: NelderMead<Vec<f64>, f64> = NelderMead::new(vec![vec![0.0], vec![1.0]]);
+    /// # let init_state: IterState<Vec<f64>, (), (), (), f64> = IterState::new().max_iters(100);
+    /// let mm = MajorizationMinimization::new(inner, init_state).with_tolerance(1e-10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`MajorizationMinimization`: Tolerance must be non-negative."
+            ));
+        }
+        self.tol = tol;
+        Ok(self)
+    }
+}
+
+impl<O, Q, S, P, G, J, H, F> Solver<O, IterState<P, (), (), (), F>>
+    for MajorizationMinimization<S, IterState<P, G, J, H, F>, F>
+where
+    O: Majorize<Param = P, Surrogate = Q> + CostFunction<Param = P, Output = F>,
+    S: Clone + Solver<Q, IterState<P, G, J, H, F>>,
+    P: Clone + SerializeAlias + DeserializeOwnedAlias,
+    G: Clone + SerializeAlias + DeserializeOwnedAlias,
+    J: Clone + SerializeAlias + DeserializeOwnedAlias,
+    H: Clone + SerializeAlias + DeserializeOwnedAlias,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Majorization-minimization";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        let param = state.get_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`MajorizationMinimization` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        let cost = problem.cost(param)?;
+        Ok((state.cost(cost), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`MajorizationMinimization`: Parameter vector in state not set."
+        ))?;
+        let surrogate = problem.majorize(&param)?;
+        let init_state = self.init_state.clone().param(param);
+
+        let OptimizationResult {
+            problem: inner_problem,
+            state: mut inner_state,
+            ..
+        } = Executor::new(surrogate, self.solver.clone())
+            .configure(|_| init_state)
+            .ctrlc(false)
+            .run()?;
+
+        problem.consume_func_counts(inner_problem);
+
+        let surrogate_cost = inner_state.get_best_cost();
+        let new_param = inner_state
+            .take_best_param()
+            .ok_or_else(argmin_error_closure!(
+                PotentialBug,
+                "`MajorizationMinimization`: No `param` returned by inner solver"
+            ))?;
+        let cost = problem.cost(&new_param)?;
+
+        Ok((
+            state.param(new_param).cost(cost),
+            Some(kv!(
+                "surrogate_cost" => surrogate_cost;
+                "inner_iters" => inner_state.get_iter();
+            )),
+        ))
+    }
+
+    fn terminate(&mut self, state: &IterState<P, (), (), (), F>) -> TerminationStatus {
+        let prev_cost = state.get_prev_cost();
+        if state.get_iter() > 0
+            && prev_cost - state.get_cost() <= self.tol * prev_cost.abs().max(float!(1.0))
+        {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Gradient};
+    use crate::solver::linesearch::MoreThuenteLineSearch;
+    use crate::solver::quasinewton::LBFGS;
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    type Inner = LBFGS<MoreThuenteLineSearch<Vec<f64>, Vec<f64>, f64>, Vec<f64>, Vec<f64>, f64>;
+
+    test_trait_impl!(
+        majorization_minimization,
+        MajorizationMinimization<Inner, IterState<Vec<f64>, Vec<f64>, (), (), f64>, f64>
+    );
+
+    /// Geometric median: `f(x) = sum_i ||x - a_i||`
+    struct GeometricMedian {
+        points: Vec<Vec<f64>>,
+    }
+
+    /// Surrogate of the geometric median at `x_k`:
+    /// `g(x | x_k) = sum_i w_i / 2 * ||x - a_i||^2 + const` with `w_i = 1 / ||x_k - a_i||`
+    /// (Weiszfeld's algorithm)
+    struct WeightedSquares {
+        points: Vec<Vec<f64>>,
+        weights: Vec<f64>,
+    }
+
+    fn dist(x: &[f64], a: &[f64]) -> f64 {
+        x.iter()
+            .zip(a.iter())
+            .map(|(xi, ai)| (xi - ai).powi(2))
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    impl CostFunction for GeometricMedian {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(self.points.iter().map(|a| dist(x, a)).sum())
+        }
+    }
+
+    impl Majorize for GeometricMedian {
+        type Param = Vec<f64>;
+        type Surrogate = WeightedSquares;
+
+        fn majorize(&self, x: &Self::Param) -> Result<Self::Surrogate, Error> {
+            Ok(WeightedSquares {
+                points: self.points.clone(),
+                weights: self.points.iter().map(|a| 1.0 / dist(x, a)).collect(),
+            })
+        }
+    }
+
+    impl CostFunction for WeightedSquares {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(self
+                .points
+                .iter()
+                .zip(self.weights.iter())
+                .map(|(a, w)| 0.5 * w * dist(x, a).powi(2))
+                .sum())
+        }
+    }
+
+    impl Gradient for WeightedSquares {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, x: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok((0..x.len())
+                .map(|j| {
+                    self.points
+                        .iter()
+                        .zip(self.weights.iter())
+                        .map(|(a, w)| w * (x[j] - a[j]))
+                        .sum()
+                })
+                .collect())
+        }
+    }
+
+    fn problem() -> GeometricMedian {
+        GeometricMedian {
+            points: vec![
+                vec![0.0, 0.0],
+                vec![4.0, 0.0],
+                vec![0.0, 2.0],
+                vec![4.0, 2.0],
+            ],
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let inner: Inner = LBFGS::new(MoreThuenteLineSearch::new(), 5);
+        let init_state: IterState<Vec<f64>, Vec<f64>, (), (), f64> = IterState::new().max_iters(10);
+        let MajorizationMinimization {
+            solver: _,
+            init_state,
+            tol,
+        }: MajorizationMinimization<_, _, f64> = MajorizationMinimization::new(inner, init_state);
+        assert_eq!(init_state.get_max_iters(), 10);
+        assert_eq!(tol.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+    }
+
+    #[test]
+    fn test_with_tolerance() {
+        let inner: Inner = LBFGS::new(MoreThuenteLineSearch::new(), 5);
+        let init_state: IterState<Vec<f64>, Vec<f64>, (), (), f64> = IterState::new();
+        let mm = MajorizationMinimization::new(inner.clone(), init_state.clone())
+            .with_tolerance(1e-4f64)
+            .unwrap();
+        assert_eq!(mm.tol.to_ne_bytes(), 1e-4f64.to_ne_bytes());
+
+        let res = MajorizationMinimization::new(inner, init_state).with_tolerance(-1.0);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`MajorizationMinimization`: Tolerance must be non-negative.\""
+        );
+    }
+
+    #[test]
+    fn test_not_initialized() {
+        let inner: Inner = LBFGS::new(MoreThuenteLineSearch::new(), 5);
+        let init_state: IterState<Vec<f64>, Vec<f64>, (), (), f64> = IterState::new();
+        let res = Executor::new(problem(), MajorizationMinimization::new(inner, init_state)).run();
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`MajorizationMinimization` requires an initial parameter ",
+                "vector. Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_solver() {
+        let inner: Inner = LBFGS::new(MoreThuenteLineSearch::new(), 5);
+        let init_state: IterState<Vec<f64>, Vec<f64>, (), (), f64> =
+            IterState::new().max_iters(100);
+        let mm = MajorizationMinimization::new(inner, init_state)
+            .with_tolerance(1e-12)
+            .unwrap();
+        let res = Executor::new(problem(), mm)
+            .configure(|state| state.param(vec![0.5, 1.7]).max_iters(200))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(param[0], 2.0, epsilon = 1e-4);
+        assert_relative_eq!(param[1], 1.0, epsilon = 1e-4);
+        assert_relative_eq!(
+            res.state.get_best_cost(),
+            4.0 * 5.0f64.sqrt(),
+            epsilon = 1e-8
+        );
+        assert!(res.problem.counts["majorize_count"] > 0);
+        assert!(res.problem.counts["gradient_count"] > 0);
+    }
+}
@@ -0,0 +1,240 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{ArgminFloat, Error};
+use argmin_math::{ArgminScaledSub, Projection};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Interface of a mirror map, which defines the Bregman divergence `D` used in mirror descent.
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::Error;
+/// use argmin::solver::mirrordescent::MirrorMap;
+///
+/// /// Squared Euclidean distance restricted to the nonnegative orthant
+/// struct Nonnegative {}
+///
+/// impl MirrorMap<Vec<f64>, Vec<f64>, f64> for Nonnegative {
+///     fn step(
+///         &self,
+///         param: &Vec<f64>,
+///         gradient: &Vec<f64>,
+///         step_length: f64,
+///     ) -> Result<Vec<f64>, Error> {
+///         Ok(param
+///             .iter()
+///             .zip(gradient.iter())
+///             .map(|(x, g)| (x - step_length * g).max(0.0))
+///             .collect())
+///     }
+/// }
+/// ```
+pub trait MirrorMap<P, G, F> {
+    /// Computes the mirror descent step
+    ///
+    /// `argmin_x step_length * <gradient, x> + D(x, param)`.
+    fn step(&self, param: &P, gradient: &G, step_length: F) -> Result<P, Error>;
+}
+
+/// # Squared Euclidean mirror map
+///
+/// Corresponds to the Bregman divergence `D(x, y) = ||x - y||^2 / 2`, for which mirror descent
+/// reduces to (unconstrained) gradient descent.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::solver::mirrordescent::{EuclideanMirrorMap, MirrorMap};
+/// let map = EuclideanMirrorMap::new();
+/// assert_eq!(map.step(&vec![1.0f64, 1.0], &vec![2.0, -2.0], 0.5).unwrap(), vec![0.0, 2.0]);
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct EuclideanMirrorMap {}
+
+impl EuclideanMirrorMap {
+    /// Construct a new instance of [`EuclideanMirrorMap`]
+    pub fn new() -> Self {
+        EuclideanMirrorMap {}
+    }
+}
+
+impl<P, G, F> MirrorMap<P, G, F> for EuclideanMirrorMap
+where
+    P: ArgminScaledSub<G, F, P>,
+{
+    fn step(&self, param: &P, gradient: &G, step_length: F) -> Result<P, Error> {
+        Ok(param.scaled_sub(&step_length, gradient))
+    }
+}
+
+/// # Projected squared Euclidean mirror map
+///
+/// Corresponds to the Bregman divergence `D(x, y) = ||x - y||^2 / 2` restricted to a closed convex
+/// set, for which mirror descent reduces to the projected subgradient method
+///
+/// `x_{k+1} = P(x_k - alpha * g)`.
+///
+/// The projection `P` onto the set is provided as an implementation of the
+/// [`Projection`] trait, for instance one of the projections of the
+/// [`projectedgradient`](`crate::solver::projectedgradient`) module.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::solver::mirrordescent::{MirrorMap, ProjectedMirrorMap};
+/// # use argmin::solver::projectedgradient::BoxProjection;
+/// let map = ProjectedMirrorMap::new(BoxProjection::new(vec![0.0f64, 0.0], vec![1.0, 1.0]));
+/// assert_eq!(map.step(&vec![1.0f64, 1.0], &vec![2.0, -2.0], 0.5).unwrap(), vec![0.0, 1.0]);
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct ProjectedMirrorMap<Q> {
+    /// Projection onto the feasible set
+    projection: Q,
+}
+
+impl<Q> ProjectedMirrorMap<Q> {
+    /// Construct a new instance of [`ProjectedMirrorMap`]
+    ///
+    /// Takes the projection onto the feasible set.
+    pub fn new(projection: Q) -> Self {
+        ProjectedMirrorMap { projection }
+    }
+}
+
+impl<P, G, F, Q> MirrorMap<P, G, F> for ProjectedMirrorMap<Q>
+where
+    P: ArgminScaledSub<G, F, P>,
+    Q: Projection<P>,
+{
+    fn step(&self, param: &P, gradient: &G, step_length: F) -> Result<P, Error> {
+        self.projection
+            .project(&param.scaled_sub(&step_length, gradient))
+    }
+}
+
+/// # Entropic mirror map
+///
+/// Corresponds to the Kullback-Leibler divergence `D(x, y) = sum_i x_i ln(x_i / y_i)` on the
+/// simplex, for which mirror descent becomes the exponentiated gradient method
+///
+/// `x_{k+1,i} = x_{k,i} exp(-alpha * g_i) / sum_j x_{k,j} exp(-alpha * g_j)`.
+///
+/// The sum of the elements of the parameter vector is preserved, therefore iterates stay on the
+/// simplex the initial parameter vector lies on. All elements of the initial parameter vector
+/// must be positive.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::solver::mirrordescent::{EntropicMirrorMap, MirrorMap};
+/// let map = EntropicMirrorMap::new();
+/// let p = map.step(&vec![0.5f64, 0.5], &vec![0.0, 2.0f64.ln()], 1.0).unwrap();
+/// assert!((p[0] - 2.0 / 3.0).abs() < 1e-15);
+/// assert!((p[1] - 1.0 / 3.0).abs() < 1e-15);
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct EntropicMirrorMap {}
+
+impl EntropicMirrorMap {
+    /// Construct a new instance of [`EntropicMirrorMap`]
+    pub fn new() -> Self {
+        EntropicMirrorMap {}
+    }
+}
+
+impl<F> MirrorMap<Vec<F>, Vec<F>, F> for EntropicMirrorMap
+where
+    F: ArgminFloat,
+{
+    fn step(&self, param: &Vec<F>, gradient: &Vec<F>, step_length: F) -> Result<Vec<F>, Error> {
+        let total = param.iter().fold(float!(0.0), |a, b| a + *b);
+        // Shifting the exponents by their minimum does not change the result but avoids overflow
+        let shift = gradient
+            .iter()
+            .fold(F::infinity(), |acc, g| acc.min(step_length * *g));
+        let weighted: Vec<F> = param
+            .iter()
+            .zip(gradient.iter())
+            .map(|(x, g)| *x * (shift - step_length * *g).exp())
+            .collect();
+        let norm = weighted.iter().fold(float!(0.0), |a, b| a + *b);
+        if norm <= float!(0.0) || !norm.is_finite() {
+            return Err(argmin_error!(
+                ConditionViolated,
+                "`EntropicMirrorMap`: parameter vector must have positive elements."
+            ));
+        }
+        Ok(weighted.iter().map(|w| *w * total / norm).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+    use crate::solver::projectedgradient::SimplexProjection;
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(euclidean_mirror_map, EuclideanMirrorMap);
+    test_trait_impl!(
+        projected_mirror_map,
+        ProjectedMirrorMap<crate::solver::projectedgradient::BoxProjection<Vec<f64>>>
+    );
+    test_trait_impl!(entropic_mirror_map, EntropicMirrorMap);
+
+    #[test]
+    fn test_euclidean() {
+        let map = EuclideanMirrorMap::new();
+        let p: Vec<f64> = map.step(&vec![1.0, 2.0], &vec![1.0, -1.0], 2.0).unwrap();
+        assert_eq!(p, vec![-1.0, 4.0]);
+    }
+
+    #[test]
+    fn test_projected() {
+        let projection = SimplexProjection::new(1.0f64).unwrap();
+        let map = ProjectedMirrorMap::new(projection);
+        let p = map
+            .step(&vec![0.5, 0.5, 0.0], &vec![1.0, -1.0, 0.0], 0.25)
+            .unwrap();
+        assert_relative_eq!(p[0], 0.25, epsilon = f64::EPSILON);
+        assert_relative_eq!(p[1], 0.75, epsilon = f64::EPSILON);
+        assert_relative_eq!(p[2], 0.0, epsilon = f64::EPSILON);
+    }
+
+    #[test]
+    fn test_entropic() {
+        let map = EntropicMirrorMap::new();
+        // zero gradient does not change the parameter vector
+        let p = map.step(&vec![0.2f64, 0.8], &vec![0.0, 0.0], 1.0).unwrap();
+        assert_relative_eq!(p[0], 0.2, epsilon = f64::EPSILON);
+        assert_relative_eq!(p[1], 0.8, epsilon = f64::EPSILON);
+
+        // sum is preserved, also for large gradients
+        let p = map
+            .step(&vec![1.0f64, 1.0, 1.0], &vec![-1000.0, 0.0, 1000.0], 1.0)
+            .unwrap();
+        assert_relative_eq!(p.iter().sum::<f64>(), 3.0, epsilon = f64::EPSILON);
+        assert_relative_eq!(p[0], 3.0, epsilon = f64::EPSILON);
+
+        let res = map.step(&vec![0.0f64, 0.0], &vec![1.0, 1.0], 1.0);
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Condition violated: \"`EntropicMirrorMap`: parameter vector must have ",
+                "positive elements.\""
+            )
+        );
+    }
+}
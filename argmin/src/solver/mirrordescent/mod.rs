@@ -0,0 +1,315 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Mirror descent
+//!
+//! First order method which replaces the Euclidean geometry of gradient descent with a Bregman
+//! divergence adapted to the feasible set. See [`MirrorDescent`] for details.
+//!
+//! # Available mirror maps
+//!
+//! * [`EuclideanMirrorMap`]: Squared Euclidean distance (gradient descent)
+//! * [`EntropicMirrorMap`]: Kullback-Leibler divergence on the simplex (exponentiated gradient)
+//! * [`ProjectedMirrorMap`]: Squared Euclidean distance restricted to a closed convex set
+//!   (projected subgradient method)
+//!
+//! Other Bregman divergences are supported by implementing the [`MirrorMap`] trait.
+//!
+//! ## Reference
+//!
+//! Amir Beck and Marc Teboulle (2003). Mirror descent and nonlinear projected subgradient methods
+//! for convex optimization. Operations Research Letters, 31(3), 167-175.
+
+mod mirrormap;
+
+pub use mirrormap::{EntropicMirrorMap, EuclideanMirrorMap, MirrorMap, ProjectedMirrorMap};
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, Gradient, IterState, Problem, Solver, State, KV,
+};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Mirror descent
+///
+/// Computes the iterates
+///
+/// `x_{k+1} = argmin_x alpha_k * <grad f(x_k), x> + D(x, x_k)`
+///
+/// where `D` is the Bregman divergence defined by a mirror map, which is provided as an
+/// implementation of the [`MirrorMap`] trait. With the [`EntropicMirrorMap`], problems
+/// constrained to the probability simplex are handled without any projection. Other closed convex
+/// sets can be handled with the [`ProjectedMirrorMap`] and a projection onto the set.
+///
+/// By default, the diminishing step length `alpha_k = alpha_0 / sqrt(k + 1)` is used, which is
+/// suitable for nonsmooth convex problems. A constant step length `alpha_0` can be chosen via
+/// [`with_constant_step_length`](`MirrorDescent::with_constant_step_length`). The initial step
+/// length `alpha_0` is set via [`with_step_length`](`MirrorDescent::with_step_length`).
+///
+/// The algorithm has no convergence criterion on its own and stops when the maximum number of
+/// iterations or the target cost function value is reached. As the cost function is not
+/// necessarily decreasing, the best parameter vector found so far is tracked in the state.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`] and [`Gradient`] (a
+/// subgradient is sufficient).
+///
+/// ## Reference
+///
+/// Amir Beck and Marc Teboulle (2003). Mirror descent and nonlinear projected subgradient methods
+/// for convex optimization. Operations Research Letters, 31(3), 167-175.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct MirrorDescent<M, F> {
+    /// Mirror map
+    mirror_map: M,
+    /// Initial step length
+    step_length: F,
+    /// Whether the step length is kept constant
+    constant: bool,
+}
+
+impl<M, F> MirrorDescent<M, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`MirrorDescent`]
+    ///
+    /// Takes the mirror map.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::mirrordescent::{EntropicMirrorMap, MirrorDescent};
+    /// let md: MirrorDescent<_, f64> = MirrorDescent::new(EntropicMirrorMap::new());
+    /// ```
+    pub fn new(mirror_map: M) -> Self {
+        MirrorDescent {
+            mirror_map,
+            step_length: float!(1.0),
+            constant: false,
+        }
+    }
+
+    /// Set the initial step length `alpha_0`
+    ///
+    /// Must be larger than 0 and defaults to `1.0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::mirrordescent::{EntropicMirrorMap, MirrorDescent};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let md: MirrorDescent<_, f64> =
+    ///     MirrorDescent::new(EntropicMirrorMap::new()).with_step_length(0.1)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_step_length(mut self, step_length: F) -> Result<Self, Error> {
+        if step_length <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`MirrorDescent`: step length must be > 0."
+            ));
+        }
+        self.step_length = step_length;
+        Ok(self)
+    }
+
+    /// Keep the step length constant instead of decreasing it with `1 / sqrt(k + 1)`
+    ///
+    /// Defaults to `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::mirrordescent::{EntropicMirrorMap, MirrorDescent};
+    /// let md: MirrorDescent<_, f64> =
+    ///     MirrorDescent::new(EntropicMirrorMap::new()).with_constant_step_length(true);
+    /// ```
+    #[must_use]
+    pub fn with_constant_step_length(mut self, constant: bool) -> Self {
+        self.constant = constant;
+        self
+    }
+}
+
+impl<O, M, P, G, F> Solver<O, IterState<P, G, (), (), F>> for MirrorDescent<M, F>
+where
+    O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
+    M: MirrorMap<P, G, F>,
+    P: Clone,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Mirror descent";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        let param = state.get_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`MirrorDescent` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        let cost = state.get_cost();
+        let cost = if cost.is_infinite() {
+            problem.cost(param)?
+        } else {
+            cost
+        };
+        Ok((state.cost(cost), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`MirrorDescent`: Parameter vector in state not set."
+        ))?;
+        let grad = problem.gradient(&param)?;
+        let step_length = if self.constant {
+            self.step_length
+        } else {
+            self.step_length / (F::from_u64(state.get_iter()).unwrap() + float!(1.0)).sqrt()
+        };
+        let new_param = self.mirror_map.step(&param, &grad, step_length)?;
+        let new_cost = problem.cost(&new_param)?;
+        Ok((
+            state.param(new_param).cost(new_cost),
+            Some(kv!("step_length" => step_length;)),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Executor};
+    use crate::solver::projectedgradient::BoxProjection;
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(mirror_descent, MirrorDescent<EntropicMirrorMap, f64>);
+
+    /// f(x) = (x - 0.7)^2 + (y - 0.1)^2 + (z - 0.2)^2
+    struct Quadratic {}
+
+    impl CostFunction for Quadratic {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok((p[0] - 0.7).powi(2) + (p[1] - 0.1).powi(2) + (p[2] - 0.2).powi(2))
+        }
+    }
+
+    impl Gradient for Quadratic {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(vec![
+                2.0 * (p[0] - 0.7),
+                2.0 * (p[1] - 0.1),
+                2.0 * (p[2] - 0.2),
+            ])
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let MirrorDescent {
+            mirror_map: _,
+            step_length,
+            constant,
+        } = MirrorDescent::<_, f64>::new(EntropicMirrorMap::new());
+        assert_eq!(step_length.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert!(!constant);
+    }
+
+    #[test]
+    fn test_with_step_length() {
+        for step_length in [f64::EPSILON, 0.1, 10.0] {
+            let md = MirrorDescent::<_, f64>::new(EntropicMirrorMap::new())
+                .with_step_length(step_length)
+                .unwrap();
+            assert_eq!(md.step_length.to_ne_bytes(), step_length.to_ne_bytes());
+        }
+
+        for step_length in [0.0, -1.0] {
+            let res = MirrorDescent::new(EntropicMirrorMap::new()).with_step_length(step_length);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`MirrorDescent`: step length must be > 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_constant_step_length() {
+        let md =
+            MirrorDescent::<_, f64>::new(EntropicMirrorMap::new()).with_constant_step_length(true);
+        assert!(md.constant);
+    }
+
+    #[test]
+    fn test_solver_entropic() {
+        let res = Executor::new(Quadratic {}, MirrorDescent::new(EntropicMirrorMap::new()))
+            .configure(|state| state.param(vec![1.0 / 3.0; 3]).max_iters(1000))
+            .run()
+            .unwrap();
+        let best = res.state.get_best_param().unwrap();
+        assert_relative_eq!(best[0], 0.7, epsilon = 1e-6);
+        assert_relative_eq!(best[1], 0.1, epsilon = 1e-6);
+        assert_relative_eq!(best[2], 0.2, epsilon = 1e-6);
+        assert_relative_eq!(best.iter().sum::<f64>(), 1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_solver_projected() {
+        // Minimizer of `Quadratic` over the box [0, 0.5]^3
+        let projection = BoxProjection::new(vec![0.0; 3], vec![0.5; 3]);
+        let solver = MirrorDescent::new(ProjectedMirrorMap::new(projection))
+            .with_step_length(0.25)
+            .unwrap()
+            .with_constant_step_length(true);
+        let res = Executor::new(Quadratic {}, solver)
+            .configure(|state| state.param(vec![0.0; 3]).max_iters(100))
+            .run()
+            .unwrap();
+        let best = res.state.get_best_param().unwrap();
+        assert_relative_eq!(best[0], 0.5, epsilon = 1e-6);
+        assert_relative_eq!(best[1], 0.1, epsilon = 1e-6);
+        assert_relative_eq!(best[2], 0.2, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_solver_euclidean() {
+        let solver = MirrorDescent::new(EuclideanMirrorMap::new())
+            .with_step_length(0.25)
+            .unwrap()
+            .with_constant_step_length(true);
+        let res = Executor::new(Quadratic {}, solver)
+            .configure(|state| state.param(vec![0.0; 3]).max_iters(100))
+            .run()
+            .unwrap();
+        let best = res.state.get_best_param().unwrap();
+        assert_relative_eq!(best[0], 0.7, epsilon = 1e-10);
+        assert_relative_eq!(best[1], 0.1, epsilon = 1e-10);
+        assert_relative_eq!(best[2], 0.2, epsilon = 1e-10);
+    }
+}
@@ -5,16 +5,78 @@
 // http://opensource.org/licenses/MIT>, at your option. This file may not be
 // copied, modified, or distributed except according to those terms.
 
+#[cfg(feature = "std")]
+pub mod activeset;
+#[cfg(feature = "std")]
+pub mod barrier;
+#[cfg(feature = "std")]
+pub mod bracketing;
+#[cfg(feature = "std")]
+pub mod branchandbound;
+#[cfg(feature = "std")]
 pub mod brent;
+#[cfg(feature = "std")]
 pub mod conjugategradient;
+#[cfg(feature = "std")]
+pub mod crossentropy;
+#[cfg(feature = "std")]
+pub mod cubicregularization;
+#[cfg(feature = "std")]
+pub mod epsilonconstraint;
+#[cfg(feature = "std")]
+pub mod evolutionstrategy;
+#[cfg(feature = "std")]
+pub mod fixedpoint;
+#[cfg(feature = "std")]
+pub mod frankwolfe;
+#[cfg(feature = "std")]
 pub mod gaussnewton;
+#[cfg(feature = "std")]
 pub mod goldensectionsearch;
 pub mod gradientdescent;
+#[cfg(feature = "std")]
+pub mod interiorpoint;
+#[cfg(feature = "std")]
 pub mod landweber;
+#[cfg(feature = "std")]
+pub mod linearsolver;
 pub mod linesearch;
+#[cfg(feature = "std")]
+pub mod majorizationminimization;
+#[cfg(feature = "std")]
+pub mod mirrordescent;
+#[cfg(feature = "std")]
+pub mod moead;
+#[cfg(feature = "std")]
+pub mod naturalgradient;
 pub mod neldermead;
+#[cfg(feature = "std")]
 pub mod newton;
+#[cfg(feature = "std")]
+pub mod nnls;
+#[cfg(feature = "std")]
+pub mod nsga2;
+#[cfg(feature = "std")]
 pub mod particleswarm;
+#[cfg(feature = "std")]
+pub mod penalty;
+#[cfg(feature = "std")]
+pub mod projectedgradient;
+#[cfg(feature = "std")]
 pub mod quasinewton;
+#[cfg(feature = "std")]
+pub mod randomsearch;
+#[cfg(feature = "std")]
+pub mod riemannian;
+#[cfg(feature = "std")]
+pub mod rootfinding;
+#[cfg(feature = "std")]
+pub mod simplex;
+#[cfg(feature = "std")]
 pub mod simulatedannealing;
+#[cfg(feature = "std")]
 pub mod trustregion;
+#[cfg(feature = "std")]
+pub mod variancereduction;
+#[cfg(feature = "std")]
+pub mod weightedsum;
@@ -0,0 +1,883 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # MOEA/D
+//!
+//! Multi-objective evolutionary algorithm based on decomposition. See [`MOEAD`] for details.
+//!
+//! ## Reference
+//!
+//! Qingfu Zhang and Hui Li (2007). MOEA/D: A Multiobjective Evolutionary Algorithm Based on
+//! Decomposition. IEEE Transactions on Evolutionary Computation, 11(6), 712-731.
+
+use crate::core::{
+    ArgminFloat, Error, MultiCostFunction, ParetoState, Problem, SerializeAlias, Solver, SyncAlias,
+    KV,
+};
+use crate::solver::nsga2::{
+    dominates, non_dominated_sort, polynomial_mutation, random_individual,
+    simulated_binary_crossover,
+};
+use rand::prelude::*;
+use rand_xoshiro::Xoshiro256PlusPlus;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// Scalarization functions used to decompose a multi-objective problem into subproblems.
+///
+/// Given the weight vector `w` of a subproblem, the objective values `f` and the ideal point `z`
+/// (the smallest value of each objective found so far), the subproblem objective is
+///
+/// * `Decomposition::Tchebycheff`: `max_j w_j * |f_j - z_j|`
+/// * `Decomposition::WeightedSum`: `sum_j w_j * f_j`
+///
+/// The weighted sum can only reach points on convex parts of the Pareto front.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub enum Decomposition {
+    /// `max_j w_j * |f_j - z_j|`
+    #[default]
+    Tchebycheff,
+    /// `sum_j w_j * f_j`
+    WeightedSum,
+}
+
+/// # MOEA/D
+///
+/// Approximates the Pareto front of a multi-objective problem with box constraints on the
+/// parameters by decomposing it into scalar subproblems, one for each of the given weight
+/// vectors. Each individual of the population is the current solution of one subproblem.
+///
+/// In each iteration, a new solution is created for each subproblem via simulated binary
+/// crossover and polynomial mutation of two solutions of neighboring subproblems, where
+/// neighborhoods are defined by the distances between the weight vectors. The new solution
+/// replaces the solutions of all neighboring subproblems for which it is not worse. Since the
+/// population is not ranked by dominance, MOEA/D scales better to problems with many objectives
+/// than [`NSGA2`](`crate::solver::nsga2::NSGA2`).
+///
+/// The scalarization function is chosen via
+/// [`with_decomposition`](`MOEAD::with_decomposition`) (see [`Decomposition`]). Evenly spread
+/// weight vectors can be created with [`simplex_lattice_weights`].
+///
+/// All non-dominated solutions found so far are kept in an external archive, which is stored as
+/// the Pareto front in the [`ParetoState`] and can be retrieved from the result via
+/// [`get_pareto_front`](`crate::core::ParetoState::get_pareto_front`) and
+/// [`get_pareto_front_costs`](`crate::core::ParetoState::get_pareto_front_costs`).
+///
+/// An initial population (one individual per weight vector) can be provided via
+/// [`ParetoState::population`](`crate::core::ParetoState::population`), otherwise it is sampled
+/// uniformly from within the bounds.
+///
+/// The algorithm has no convergence criterion on its own and stops when the maximum number of
+/// iterations is reached.
+///
+/// The `rayon` feature enables parallel computation of the objectives of the population.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`MultiCostFunction`].
+///
+/// ## Reference
+///
+/// Qingfu Zhang and Hui Li (2007). MOEA/D: A Multiobjective Evolutionary Algorithm Based on
+/// Decomposition. IEEE Transactions on Evolutionary Computation, 11(6), 712-731.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct MOEAD<F, R> {
+    /// Bounds on parameter space
+    bounds: (Vec<F>, Vec<F>),
+    /// Weight vectors of the subproblems
+    weights: Vec<Vec<F>>,
+    /// Number of neighboring subproblems (including the subproblem itself)
+    neighborhood_size: usize,
+    /// Scalarization function
+    decomposition: Decomposition,
+    /// Probability of performing crossover
+    crossover_probability: F,
+    /// Distribution index of simulated binary crossover
+    crossover_distribution_index: F,
+    /// Probability of mutating each element (defaults to `1/n` if `None`)
+    mutation_probability: Option<F>,
+    /// Distribution index of polynomial mutation
+    mutation_distribution_index: F,
+    /// Indices of the neighboring subproblems of each subproblem
+    neighborhoods: Vec<Vec<usize>>,
+    /// Smallest value of each objective found so far
+    ideal_point: Vec<F>,
+    /// Random number generator
+    rng: R,
+}
+
+impl<F> MOEAD<F, Xoshiro256PlusPlus>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`MOEAD`]
+    ///
+    /// Takes the bounds on the parameter space as a tuple `(lower_bound, upper_bound)` and one
+    /// weight vector per subproblem. The number of weight vectors determines the population size
+    /// and must be at least 2. The length of the weight vectors must equal the number of
+    /// objectives.
+    ///
+    /// Uses the `Xoshiro256PlusPlus` RNG internally. For use of another RNG, consider using
+    /// [`MOEAD::new_with_rng`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::moead::{simplex_lattice_weights, MOEAD};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let weights = simplex_lattice_weights(3, 12);
+    /// let moead = MOEAD::new((vec![0.0f64, 0.0], vec![1.0, 1.0]), weights)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(bounds: (Vec<F>, Vec<F>), weights: Vec<Vec<F>>) -> Result<Self, Error> {
+        MOEAD::new_with_rng(bounds, weights, Xoshiro256PlusPlus::from_entropy())
+    }
+}
+
+impl<F, R> MOEAD<F, R>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`MOEAD`]
+    ///
+    /// Takes the bounds on the parameter space as a tuple `(lower_bound, upper_bound)`, one
+    /// weight vector per subproblem and a RNG which must implement `rand::Rng` (and
+    /// `serde::Serialize` if the `serde1` feature is enabled).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::moead::{simplex_lattice_weights, MOEAD};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let my_rng = ();
+    /// let weights = simplex_lattice_weights(2, 99);
+    /// let moead = MOEAD::new_with_rng((vec![0.0f64], vec![1.0]), weights, my_rng)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_with_rng(
+        bounds: (Vec<F>, Vec<F>),
+        weights: Vec<Vec<F>>,
+        rng: R,
+    ) -> Result<Self, Error> {
+        if bounds.0.len() != bounds.1.len() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`MOEAD`: lower and upper bound must have the same length."
+            ));
+        }
+        if bounds.0.iter().zip(bounds.1.iter()).any(|(l, u)| l > u) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`MOEAD`: lower bound must be smaller than or equal to upper bound."
+            ));
+        }
+        if weights.len() < 2 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`MOEAD`: at least two weight vectors are required."
+            ));
+        }
+        if weights
+            .iter()
+            .any(|w| w.is_empty() || w.len() != weights[0].len())
+        {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`MOEAD`: all weight vectors must be non-empty and of the same length."
+            ));
+        }
+        if weights.iter().flatten().any(|w| *w < float!(0.0)) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`MOEAD`: weights must be >= 0."
+            ));
+        }
+        Ok(MOEAD {
+            bounds,
+            neighborhood_size: weights.len().min(20),
+            weights,
+            decomposition: Decomposition::default(),
+            crossover_probability: float!(1.0),
+            crossover_distribution_index: float!(20.0),
+            mutation_probability: None,
+            mutation_distribution_index: float!(20.0),
+            neighborhoods: vec![],
+            ideal_point: vec![],
+            rng,
+        })
+    }
+
+    /// Set the number of neighboring subproblems (including the subproblem itself)
+    ///
+    /// Must be at least 2 and at most the number of weight vectors. Defaults to 20 or the number
+    /// of weight vectors, whichever is smaller.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::moead::{simplex_lattice_weights, MOEAD};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let moead = MOEAD::new((vec![0.0f64], vec![1.0]), simplex_lattice_weights(2, 99))?
+    ///     .with_neighborhood_size(10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_neighborhood_size(mut self, size: usize) -> Result<Self, Error> {
+        if size < 2 || size > self.weights.len() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`MOEAD`: neighborhood size must be >= 2 and <= number of weight vectors."
+            ));
+        }
+        self.neighborhood_size = size;
+        Ok(self)
+    }
+
+    /// Set the scalarization function
+    ///
+    /// Defaults to [`Decomposition::Tchebycheff`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::moead::{simplex_lattice_weights, Decomposition, MOEAD};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let moead = MOEAD::new((vec![0.0f64], vec![1.0]), simplex_lattice_weights(2, 99))?
+    ///     .with_decomposition(Decomposition::WeightedSum);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_decomposition(mut self, decomposition: Decomposition) -> Self {
+        self.decomposition = decomposition;
+        self
+    }
+
+    /// Set the probability of performing crossover of two parents
+    ///
+    /// Must be in `[0, 1]` and defaults to `1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::moead::{simplex_lattice_weights, MOEAD};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let moead = MOEAD::new((vec![0.0f64], vec![1.0]), simplex_lattice_weights(2, 99))?
+    ///     .with_crossover_probability(0.9)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_crossover_probability(mut self, probability: F) -> Result<Self, Error> {
+        if probability < float!(0.0) || probability > float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`MOEAD`: crossover probability must be in [0, 1]."
+            ));
+        }
+        self.crossover_probability = probability;
+        Ok(self)
+    }
+
+    /// Set the distribution index of simulated binary crossover
+    ///
+    /// Larger values create offspring closer to the parents. Must be non-negative and defaults to
+    /// `20`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::moead::{simplex_lattice_weights, MOEAD};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let moead = MOEAD::new((vec![0.0f64], vec![1.0]), simplex_lattice_weights(2, 99))?
+    ///     .with_crossover_distribution_index(15.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_crossover_distribution_index(mut self, eta: F) -> Result<Self, Error> {
+        if eta < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`MOEAD`: crossover distribution index must be >= 0."
+            ));
+        }
+        self.crossover_distribution_index = eta;
+        Ok(self)
+    }
+
+    /// Set the probability of mutating an element of an offspring
+    ///
+    /// Must be in `[0, 1]` and defaults to `1/n`, where `n` is the number of parameters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::moead::{simplex_lattice_weights, MOEAD};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let moead = MOEAD::new((vec![0.0f64], vec![1.0]), simplex_lattice_weights(2, 99))?
+    ///     .with_mutation_probability(0.1)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_mutation_probability(mut self, probability: F) -> Result<Self, Error> {
+        if probability < float!(0.0) || probability > float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`MOEAD`: mutation probability must be in [0, 1]."
+            ));
+        }
+        self.mutation_probability = Some(probability);
+        Ok(self)
+    }
+
+    /// Set the distribution index of polynomial mutation
+    ///
+    /// Larger values create mutants closer to the original individual. Must be non-negative and
+    /// defaults to `20`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::moead::{simplex_lattice_weights, MOEAD};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let moead = MOEAD::new((vec![0.0f64], vec![1.0]), simplex_lattice_weights(2, 99))?
+    ///     .with_mutation_distribution_index(15.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_mutation_distribution_index(mut self, eta: F) -> Result<Self, Error> {
+        if eta < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`MOEAD`: mutation distribution index must be >= 0."
+            ));
+        }
+        self.mutation_distribution_index = eta;
+        Ok(self)
+    }
+
+    /// Value of the scalarized objective of the subproblem with the given weight vector
+    fn scalarize(&self, costs: &[F], weight: &[F]) -> F {
+        match self.decomposition {
+            Decomposition::Tchebycheff => costs
+                .iter()
+                .zip(weight.iter())
+                .zip(self.ideal_point.iter())
+                .map(|((f, w), z)| w.max(float!(1e-6)) * (*f - *z).abs())
+                .fold(F::neg_infinity(), F::max),
+            Decomposition::WeightedSum => costs
+                .iter()
+                .zip(weight.iter())
+                .fold(float!(0.0), |acc, (f, w)| acc + *f * *w),
+        }
+    }
+
+    /// Computes the neighborhood of each subproblem from the distances between weight vectors
+    fn compute_neighborhoods(&mut self) {
+        self.neighborhoods = self
+            .weights
+            .iter()
+            .map(|wi| {
+                let distances: Vec<F> = self
+                    .weights
+                    .iter()
+                    .map(|wj| {
+                        wi.iter()
+                            .zip(wj.iter())
+                            .fold(float!(0.0), |acc, (a, b)| acc + (*a - *b).powi(2))
+                    })
+                    .collect();
+                let mut order: Vec<usize> = (0..self.weights.len()).collect();
+                order.sort_by(|&a, &b| {
+                    distances[a]
+                        .partial_cmp(&distances[b])
+                        .unwrap_or(Ordering::Equal)
+                });
+                order.truncate(self.neighborhood_size);
+                order
+            })
+            .collect();
+    }
+
+    /// Checks that the problem returns one objective value per weight
+    fn check_num_objectives(&self, costs: &[F]) -> Result<(), Error> {
+        if costs.len() != self.weights[0].len() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                format!(
+                    "`MOEAD`: Number of objectives ({}) does not match length of weight vectors ({})",
+                    costs.len(),
+                    self.weights[0].len()
+                )
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl<O, F, R> Solver<O, ParetoState<Vec<F>, F>> for MOEAD<F, R>
+where
+    O: MultiCostFunction<Param = Vec<F>, Float = F> + SyncAlias,
+    F: ArgminFloat + SyncAlias,
+    R: Rng + SerializeAlias,
+{
+    const NAME: &'static str = "MOEA/D";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: ParetoState<Vec<F>, F>,
+    ) -> Result<(ParetoState<Vec<F>, F>, Option<KV>), Error> {
+        let num_subproblems = self.weights.len();
+        // Users can provide a population or it will be randomly created.
+        let population = match state.take_population() {
+            Some(population) if population.len() == num_subproblems => population,
+            Some(population) => {
+                return Err(argmin_error!(
+                    InvalidParameter,
+                    format!(
+                        "`MOEAD`: Provided population is of size {}, expected {}",
+                        population.len(),
+                        num_subproblems
+                    )
+                ))
+            }
+            None => (0..num_subproblems)
+                .map(|_| random_individual(&mut self.rng, &self.bounds))
+                .collect(),
+        };
+        let costs = problem.bulk_multi_cost(&population)?;
+        for c in costs.iter() {
+            self.check_num_objectives(c)?;
+        }
+
+        self.ideal_point = costs.iter().skip(1).fold(costs[0].clone(), |z, c| {
+            z.iter().zip(c.iter()).map(|(a, b)| a.min(*b)).collect()
+        });
+        self.compute_neighborhoods();
+
+        let front_idx = &non_dominated_sort(&costs)[0];
+        let front: Vec<Vec<F>> = front_idx.iter().map(|&i| population[i].clone()).collect();
+        let front_costs: Vec<Vec<F>> = front_idx.iter().map(|&i| costs[i].clone()).collect();
+        let front_size = front.len();
+
+        Ok((
+            state
+                .population(population, costs)
+                .pareto_front(front, front_costs),
+            Some(kv!("pareto_front_size" => front_size as u64;)),
+        ))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: ParetoState<Vec<F>, F>,
+    ) -> Result<(ParetoState<Vec<F>, F>, Option<KV>), Error> {
+        let mut population = state.take_population().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`MOEAD`: No population in state."
+        ))?;
+        let mut costs = state
+            .take_population_costs()
+            .ok_or_else(argmin_error_closure!(
+                PotentialBug,
+                "`MOEAD`: No population costs in state."
+            ))?;
+        let mut front = state.take_pareto_front().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`MOEAD`: No Pareto front in state."
+        ))?;
+        let mut front_costs = state
+            .take_pareto_front_costs()
+            .ok_or_else(argmin_error_closure!(
+                PotentialBug,
+                "`MOEAD`: No Pareto front costs in state."
+            ))?;
+
+        for i in 0..self.weights.len() {
+            // Create a new solution from two neighboring solutions
+            let k = self.neighborhoods[i][self.rng.gen_range(0..self.neighborhood_size)];
+            let l = self.neighborhoods[i][self.rng.gen_range(0..self.neighborhood_size)];
+            let (mut child, _) = simulated_binary_crossover(
+                &mut self.rng,
+                &self.bounds,
+                &population[k],
+                &population[l],
+                self.crossover_probability,
+                self.crossover_distribution_index,
+            );
+            polynomial_mutation(
+                &mut self.rng,
+                &self.bounds,
+                &mut child,
+                self.mutation_probability,
+                self.mutation_distribution_index,
+            );
+            let child_costs = problem.multi_cost(&child)?;
+            self.check_num_objectives(&child_costs)?;
+
+            // Update ideal point
+            for (z, f) in self.ideal_point.iter_mut().zip(child_costs.iter()) {
+                *z = z.min(*f);
+            }
+
+            // Update neighboring solutions
+            for &j in self.neighborhoods[i].iter() {
+                if self.scalarize(&child_costs, &self.weights[j])
+                    <= self.scalarize(&costs[j], &self.weights[j])
+                {
+                    population[j] = child.clone();
+                    costs[j] = child_costs.clone();
+                }
+            }
+
+            // Update external archive of non-dominated solutions
+            if !front_costs
+                .iter()
+                .any(|c| dominates(c, &child_costs) || *c == child_costs)
+            {
+                let keep: Vec<bool> = front_costs
+                    .iter()
+                    .map(|c| !dominates(&child_costs, c))
+                    .collect();
+                let mut keep_iter = keep.iter();
+                front.retain(|_| *keep_iter.next().unwrap());
+                let mut keep_iter = keep.iter();
+                front_costs.retain(|_| *keep_iter.next().unwrap());
+                front.push(child);
+                front_costs.push(child_costs);
+            }
+        }
+        let front_size = front.len();
+
+        Ok((
+            state
+                .population(population, costs)
+                .pareto_front(front, front_costs),
+            Some(kv!("pareto_front_size" => front_size as u64;)),
+        ))
+    }
+}
+
+/// Evenly spread weight vectors on the unit simplex
+///
+/// Creates all vectors of length `num_objectives` whose elements are multiples of
+/// `1 / divisions` and sum up to one (simplex-lattice design). The number of weight vectors is
+/// `binomial(divisions + num_objectives - 1, num_objectives - 1)`.
+///
+/// ## Reference
+///
+/// Indraneel Das and J. E. Dennis (1998). Normal-Boundary Intersection: A New Method for
+/// Generating the Pareto Surface in Nonlinear Multicriteria Optimization Problems. SIAM Journal on
+/// Optimization, 8(3), 631-657.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::solver::moead::simplex_lattice_weights;
+/// let weights: Vec<Vec<f64>> = simplex_lattice_weights(2, 2);
+/// assert_eq!(weights, vec![vec![0.0, 1.0], vec![0.5, 0.5], vec![1.0, 0.0]]);
+/// assert_eq!(simplex_lattice_weights::<f64>(3, 12).len(), 91);
+/// ```
+pub fn simplex_lattice_weights<F: ArgminFloat>(
+    num_objectives: usize,
+    divisions: usize,
+) -> Vec<Vec<F>> {
+    /// Appends all compositions of `remaining` into `len` further non-negative parts
+    fn compositions(
+        len: usize,
+        remaining: usize,
+        prefix: &mut Vec<usize>,
+        out: &mut Vec<Vec<usize>>,
+    ) {
+        if len == 1 {
+            prefix.push(remaining);
+            out.push(prefix.clone());
+            prefix.pop();
+            return;
+        }
+        for k in 0..=remaining {
+            prefix.push(k);
+            compositions(len - 1, remaining - k, prefix, out);
+            prefix.pop();
+        }
+    }
+
+    if num_objectives == 0 {
+        return vec![];
+    }
+    let mut out = vec![];
+    compositions(num_objectives, divisions, &mut vec![], &mut out);
+    let divisions = F::from_usize(divisions.max(1)).unwrap();
+    out.into_iter()
+        .map(|c| {
+            c.into_iter()
+                .map(|k| F::from_usize(k).unwrap() / divisions)
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Executor, State};
+    use crate::test_trait_impl;
+
+    test_trait_impl!(moead, MOEAD<f64, Xoshiro256PlusPlus>);
+
+    /// Schaffer function N. 1, Pareto optimal set is `[0, 2]`
+    struct Schaffer {}
+
+    impl MultiCostFunction for Schaffer {
+        type Param = Vec<f64>;
+        type Float = f64;
+
+        fn multi_cost(&self, p: &Self::Param) -> Result<Vec<Self::Float>, Error> {
+            Ok(vec![p[0].powi(2), (p[0] - 2.0).powi(2)])
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let weights: Vec<Vec<f64>> = simplex_lattice_weights(2, 29);
+        let moead = MOEAD::new((vec![-1.0, -2.0], vec![1.0, 2.0]), weights.clone()).unwrap();
+        let MOEAD {
+            bounds,
+            weights: w,
+            neighborhood_size,
+            decomposition,
+            crossover_probability,
+            crossover_distribution_index,
+            mutation_probability,
+            mutation_distribution_index,
+            neighborhoods,
+            ideal_point,
+            rng: _,
+        } = moead;
+        assert_eq!(bounds, (vec![-1.0, -2.0], vec![1.0, 2.0]));
+        assert_eq!(w, weights);
+        assert_eq!(neighborhood_size, 20);
+        assert_eq!(decomposition, Decomposition::Tchebycheff);
+        assert_eq!(crossover_probability.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(
+            crossover_distribution_index.to_ne_bytes(),
+            20.0f64.to_ne_bytes()
+        );
+        assert!(mutation_probability.is_none());
+        assert_eq!(
+            mutation_distribution_index.to_ne_bytes(),
+            20.0f64.to_ne_bytes()
+        );
+        assert!(neighborhoods.is_empty());
+        assert!(ideal_point.is_empty());
+
+        let moead = MOEAD::new((vec![0.0f64], vec![1.0]), simplex_lattice_weights(2, 4)).unwrap();
+        assert_eq!(moead.neighborhood_size, 5);
+    }
+
+    #[test]
+    fn test_new_invalid() {
+        let weights: Vec<Vec<f64>> = simplex_lattice_weights(2, 9);
+        assert_error!(
+            MOEAD::new((vec![0.0f64], vec![1.0, 1.0]), weights.clone()),
+            ArgminError,
+            "Invalid parameter: \"`MOEAD`: lower and upper bound must have the same length.\""
+        );
+        assert_error!(
+            MOEAD::new((vec![0.0f64, 2.0], vec![1.0, 1.0]), weights),
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`MOEAD`: lower bound must be smaller than or equal to ",
+                "upper bound.\""
+            )
+        );
+        assert_error!(
+            MOEAD::new((vec![0.0f64], vec![1.0]), vec![vec![1.0, 0.0]]),
+            ArgminError,
+            "Invalid parameter: \"`MOEAD`: at least two weight vectors are required.\""
+        );
+        assert_error!(
+            MOEAD::new((vec![0.0f64], vec![1.0]), vec![vec![1.0, 0.0], vec![1.0]]),
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`MOEAD`: all weight vectors must be non-empty and of the ",
+                "same length.\""
+            )
+        );
+        assert_error!(
+            MOEAD::new(
+                (vec![0.0f64], vec![1.0]),
+                vec![vec![1.0, 0.0], vec![-1.0, 2.0]]
+            ),
+            ArgminError,
+            "Invalid parameter: \"`MOEAD`: weights must be >= 0.\""
+        );
+    }
+
+    #[test]
+    fn test_with_neighborhood_size() {
+        let moead = MOEAD::new((vec![0.0f64], vec![1.0]), simplex_lattice_weights(2, 9)).unwrap();
+        for size in [2, 5, 10] {
+            let m = moead.clone().with_neighborhood_size(size).unwrap();
+            assert_eq!(m.neighborhood_size, size);
+        }
+        for size in [0, 1, 11] {
+            assert_error!(
+                moead.clone().with_neighborhood_size(size),
+                ArgminError,
+                concat!(
+                    "Invalid parameter: \"`MOEAD`: neighborhood size must be >= 2 and <= number ",
+                    "of weight vectors.\""
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_decomposition() {
+        let moead = MOEAD::new((vec![0.0f64], vec![1.0]), simplex_lattice_weights(2, 9))
+            .unwrap()
+            .with_decomposition(Decomposition::WeightedSum);
+        assert_eq!(moead.decomposition, Decomposition::WeightedSum);
+    }
+
+    #[test]
+    fn test_with_variation_parameters() {
+        let moead = MOEAD::new((vec![0.0f64], vec![1.0]), simplex_lattice_weights(2, 9))
+            .unwrap()
+            .with_crossover_probability(0.5)
+            .unwrap()
+            .with_crossover_distribution_index(5.0)
+            .unwrap()
+            .with_mutation_probability(0.25)
+            .unwrap()
+            .with_mutation_distribution_index(7.0)
+            .unwrap();
+        assert_eq!(
+            moead.crossover_probability.to_ne_bytes(),
+            0.5f64.to_ne_bytes()
+        );
+        assert_eq!(
+            moead.crossover_distribution_index.to_ne_bytes(),
+            5.0f64.to_ne_bytes()
+        );
+        assert_eq!(
+            moead.mutation_probability.unwrap().to_ne_bytes(),
+            0.25f64.to_ne_bytes()
+        );
+        assert_eq!(
+            moead.mutation_distribution_index.to_ne_bytes(),
+            7.0f64.to_ne_bytes()
+        );
+
+        assert_error!(
+            moead.clone().with_crossover_probability(1.5),
+            ArgminError,
+            "Invalid parameter: \"`MOEAD`: crossover probability must be in [0, 1].\""
+        );
+        assert_error!(
+            moead.clone().with_crossover_distribution_index(-1.0),
+            ArgminError,
+            "Invalid parameter: \"`MOEAD`: crossover distribution index must be >= 0.\""
+        );
+        assert_error!(
+            moead.clone().with_mutation_probability(-0.5),
+            ArgminError,
+            "Invalid parameter: \"`MOEAD`: mutation probability must be in [0, 1].\""
+        );
+        assert_error!(
+            moead.with_mutation_distribution_index(-1.0),
+            ArgminError,
+            "Invalid parameter: \"`MOEAD`: mutation distribution index must be >= 0.\""
+        );
+    }
+
+    #[test]
+    fn test_simplex_lattice_weights() {
+        let weights: Vec<Vec<f64>> = simplex_lattice_weights(3, 2);
+        assert_eq!(weights.len(), 6);
+        for w in weights {
+            assert_eq!(w.len(), 3);
+            assert_eq!(w.iter().sum::<f64>().to_ne_bytes(), 1.0f64.to_ne_bytes());
+        }
+        assert!(simplex_lattice_weights::<f64>(0, 3).is_empty());
+        assert_eq!(simplex_lattice_weights::<f64>(1, 3), vec![vec![1.0]]);
+    }
+
+    #[test]
+    fn test_neighborhoods() {
+        let mut moead = MOEAD::new((vec![0.0f64], vec![1.0]), simplex_lattice_weights(2, 4))
+            .unwrap()
+            .with_neighborhood_size(3)
+            .unwrap();
+        moead.compute_neighborhoods();
+        assert_eq!(moead.neighborhoods[0], vec![0, 1, 2]);
+        assert_eq!(moead.neighborhoods[2], vec![2, 1, 3]);
+        assert_eq!(moead.neighborhoods[4], vec![4, 3, 2]);
+    }
+
+    #[test]
+    fn test_wrong_number_of_objectives() {
+        let solver =
+            MOEAD::new((vec![-10.0f64], vec![10.0]), simplex_lattice_weights(3, 4)).unwrap();
+        let res = Executor::new(Schaffer {}, solver).run();
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`MOEAD`: Number of objectives (2) does not match length ",
+                "of weight vectors (3)\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_solver() {
+        let solver = MOEAD::new_with_rng(
+            (vec![-10.0f64], vec![10.0]),
+            simplex_lattice_weights(2, 19),
+            Xoshiro256PlusPlus::seed_from_u64(42),
+        )
+        .unwrap();
+        let res = Executor::new(Schaffer {}, solver)
+            .configure(|state| state.max_iters(100))
+            .run()
+            .unwrap();
+        let population = res.state.get_population().unwrap();
+        for p in population {
+            assert!(p[0] >= -1e-2 && p[0] <= 2.0 + 1e-2);
+        }
+        let front = res.state.get_pareto_front().unwrap();
+        let front_costs = res.state.get_pareto_front_costs().unwrap();
+        assert!(front.len() >= 20);
+        assert_eq!(front.len(), front_costs.len());
+        for (p, c) in front.iter().zip(front_costs.iter()) {
+            assert!(p[0] >= -1e-2 && p[0] <= 2.0 + 1e-2);
+            assert_eq!(*c, vec![p[0].powi(2), (p[0] - 2.0).powi(2)]);
+        }
+        for (i, a) in front_costs.iter().enumerate() {
+            for b in front_costs.iter().skip(i + 1) {
+                assert!(!dominates(a, b) && !dominates(b, a));
+            }
+        }
+        assert_eq!(res.problem.counts["multi_cost_count"], 20 * 101);
+    }
+}
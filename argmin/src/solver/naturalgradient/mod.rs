@@ -0,0 +1,274 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Natural gradient descent
+//!
+//! Gradient descent preconditioned with the Fisher information matrix. See [`NaturalGradient`]
+//! for details.
+//!
+//! ## Reference
+//!
+//! Shun-ichi Amari (1998). Natural Gradient Works Efficiently in Learning.
+//! Neural Computation, 10(2), 251-276.
+
+use crate::core::{
+    ArgminFloat, Error, FisherInformation, Gradient, IterState, Problem, Solver, KV,
+};
+use argmin_math::{ArgminDot, ArgminEye, ArgminInv, ArgminScaledAdd, ArgminScaledSub};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Natural gradient descent
+///
+/// Computes the iterates
+///
+/// `x_{k+1} = x_k - gamma * (F(x_k) + lambda * I)^-1 grad f(x_k)`
+///
+/// where `F(x_k)` is the Fisher information matrix (or any other Riemannian metric) provided by
+/// the problem via the [`FisherInformation`] trait. The resulting update is invariant to smooth
+/// reparameterizations of the model, which makes it well suited for fitting statistical models
+/// and policies.
+///
+/// The step size `gamma` can be set via [`with_step_size`](`NaturalGradient::with_step_size`)
+/// and defaults to `1`. As the empirical Fisher information matrix is often singular, a damping
+/// term `lambda` can be added to its diagonal via
+/// [`with_damping`](`NaturalGradient::with_damping`) (defaults to `0`).
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`Gradient`] and [`FisherInformation`].
+///
+/// ## Reference
+///
+/// Shun-ichi Amari (1998). Natural Gradient Works Efficiently in Learning.
+/// Neural Computation, 10(2), 251-276.
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct NaturalGradient<F> {
+    /// Step size
+    gamma: F,
+    /// Damping added to the diagonal of the Fisher information matrix
+    damping: F,
+}
+
+impl<F> NaturalGradient<F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`NaturalGradient`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::naturalgradient::NaturalGradient;
+    /// let ng: NaturalGradient<f64> = NaturalGradient::new();
+    /// ```
+    pub fn new() -> Self {
+        NaturalGradient {
+            gamma: float!(1.0),
+            damping: float!(0.0),
+        }
+    }
+
+    /// Set the step size `gamma`
+    ///
+    /// Must be larger than 0 and defaults to `1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::naturalgradient::NaturalGradient;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let ng: NaturalGradient<f64> = NaturalGradient::new().with_step_size(0.1)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_step_size(mut self, gamma: F) -> Result<Self, Error> {
+        if gamma <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`NaturalGradient`: step size must be > 0."
+            ));
+        }
+        self.gamma = gamma;
+        Ok(self)
+    }
+
+    /// Set the damping `lambda` which is added to the diagonal of the Fisher information matrix
+    ///
+    /// Must be non-negative and defaults to `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::naturalgradient::NaturalGradient;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let ng: NaturalGradient<f64> = NaturalGradient::new().with_damping(1e-4)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_damping(mut self, damping: F) -> Result<Self, Error> {
+        if damping < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`NaturalGradient`: damping must be >= 0."
+            ));
+        }
+        self.damping = damping;
+        Ok(self)
+    }
+}
+
+impl<F> Default for NaturalGradient<F>
+where
+    F: ArgminFloat,
+{
+    fn default() -> NaturalGradient<F> {
+        NaturalGradient::new()
+    }
+}
+
+impl<O, P, G, H, F> Solver<O, IterState<P, G, (), (), F>> for NaturalGradient<F>
+where
+    O: Gradient<Param = P, Gradient = G> + FisherInformation<Param = P, FisherInformation = H>,
+    P: Clone + ArgminScaledSub<P, F, P>,
+    H: ArgminEye + ArgminScaledAdd<H, F, H> + ArgminInv<H> + ArgminDot<G, P>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Natural gradient descent";
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`NaturalGradient` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        let grad = problem.gradient(&param)?;
+        let fisher = problem.fisher_information(&param)?;
+        let fisher = if self.damping > float!(0.0) {
+            fisher.scaled_add(&self.damping, &fisher.eye_like())
+        } else {
+            fisher
+        };
+        let new_param = param.scaled_sub(&self.gamma, &fisher.inv()?.dot(&grad));
+        Ok((state.param(new_param), None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+    use crate::test_trait_impl;
+
+    test_trait_impl!(natural_gradient, NaturalGradient<f64>);
+
+    #[test]
+    fn test_new() {
+        let NaturalGradient { gamma, damping } = NaturalGradient::<f64>::new();
+        assert_eq!(gamma.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(damping.to_ne_bytes(), 0.0f64.to_ne_bytes());
+    }
+
+    #[test]
+    fn test_with_step_size() {
+        for gamma in [f64::EPSILON, 0.1, 1.0, 2.0] {
+            let ng: NaturalGradient<f64> = NaturalGradient::new().with_step_size(gamma).unwrap();
+            assert_eq!(ng.gamma.to_ne_bytes(), gamma.to_ne_bytes());
+        }
+
+        for gamma in [0.0, -1.0] {
+            let res = NaturalGradient::new().with_step_size(gamma);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`NaturalGradient`: step size must be > 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_damping() {
+        for damping in [0.0, 1e-4, 1.0] {
+            let ng: NaturalGradient<f64> = NaturalGradient::new().with_damping(damping).unwrap();
+            assert_eq!(ng.damping.to_ne_bytes(), damping.to_ne_bytes());
+        }
+
+        for damping in [-f64::EPSILON, -1.0] {
+            let res = NaturalGradient::new().with_damping(damping);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`NaturalGradient`: damping must be >= 0.\""
+            );
+        }
+    }
+
+    #[cfg(feature = "_ndarrayl")]
+    #[test]
+    fn test_solver() {
+        use crate::core::{Executor, State};
+        use approx::assert_relative_eq;
+        use ndarray::{array, Array1, Array2};
+
+        /// Maximum likelihood estimation of the mean and the log standard deviation of a normal
+        /// distribution (negative log-likelihood per sample)
+        struct Normal {
+            mean: f64,
+            var: f64,
+        }
+
+        impl Gradient for Normal {
+            type Param = Array1<f64>;
+            type Gradient = Array1<f64>;
+
+            fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+                let s2 = (2.0 * p[1]).exp();
+                Ok(array![
+                    (p[0] - self.mean) / s2,
+                    1.0 - (self.var + (p[0] - self.mean).powi(2)) / s2
+                ])
+            }
+        }
+
+        impl FisherInformation for Normal {
+            type Param = Array1<f64>;
+            type FisherInformation = Array2<f64>;
+
+            fn fisher_information(&self, p: &Self::Param) -> Result<Array2<f64>, Error> {
+                let s2 = (2.0 * p[1]).exp();
+                Ok(array![[1.0 / s2, 0.0], [0.0, 2.0]])
+            }
+        }
+
+        let problem = Normal {
+            mean: 3.0,
+            var: 4.0,
+        };
+        let solver = NaturalGradient::new()
+            .with_step_size(0.5)
+            .unwrap()
+            .with_damping(1e-12)
+            .unwrap();
+        let res = Executor::new(problem, solver)
+            .configure(|state| state.param(array![0.0, 0.0]).max_iters(100))
+            .run()
+            .unwrap();
+        let best = res.state.get_best_param().unwrap();
+        assert_relative_eq!(best[0], 3.0, epsilon = 1e-8);
+        assert_relative_eq!(best[1], 2.0f64.ln(), epsilon = 1e-8);
+        assert_eq!(res.problem.counts["fisher_information_count"], 100);
+    }
+}
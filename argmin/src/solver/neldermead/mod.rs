@@ -22,10 +22,11 @@ use crate::core::{
     ArgminFloat, CostFunction, Error, IterState, Problem, SerializeAlias, Solver,
     TerminationReason, TerminationStatus, KV,
 };
-use argmin_math::{ArgminAdd, ArgminMul, ArgminSub};
+use alloc::{format, vec::Vec};
+use argmin_math::{ArgminAdd, ArgminDot, ArgminMul, ArgminSub};
+use core::fmt;
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
-use std::fmt;
 
 /// # Nelder-Mead method
 ///
@@ -49,6 +50,16 @@ use std::fmt;
 /// 4) Shrink (Parameter `sigma`, defaults to `0.5`, configurable via
 ///    [`with_sigma`](`NelderMead::with_sigma`))
 ///
+/// The standard parameters perform poorly on problems with more than about 10 dimensions. For
+/// such problems, the dimension-dependent parameters of Gao and Han can be used instead (see
+/// [`with_adaptive_parameters`](`NelderMead::with_adaptive_parameters`)).
+///
+/// The simplex may degenerate (collapse into a lower-dimensional subspace), in which case the
+/// method stalls without converging. Restarts on degenerate simplices can be enabled via
+/// [`with_restart_tolerance`](`NelderMead::with_restart_tolerance`), which requires the parameter
+/// vector to implement [`ArgminDot`] in addition. With restarts enabled, the diameter of the
+/// simplex (maximum distance of a vertex to the best vertex) is logged with the key `diameter`.
+///
 /// ## Requirements on the optimization problem
 ///
 /// The optimization problem is required to implement [`CostFunction`].
@@ -58,9 +69,15 @@ use std::fmt;
 /// <https://en.wikipedia.org/wiki/Nelder%E2%80%93Mead_method>
 ///
 /// <http://www.scholarpedia.org/article/Nelder-Mead_algorithm#Simplex_transformation_algorithm>
+///
+/// Fuchang Gao and Lixing Han (2012). Implementing the Nelder-Mead simplex algorithm with
+/// adaptive parameters. Computational Optimization and Applications, 51(1), 259-277.
+///
+/// C. T. Kelley (1999). Detection and remediation of stagnation in the Nelder-Mead algorithm
+/// using a sufficient decrease condition. SIAM Journal on Optimization, 10(1), 43-55.
 #[derive(Clone)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
-pub struct NelderMead<P, F> {
+pub struct NelderMead<P, F, R = NoRestart> {
     /// alpha
     alpha: F,
     /// gamma
@@ -73,6 +90,8 @@ pub struct NelderMead<P, F> {
     params: Vec<(P, F)>,
     /// Sample standard deviation tolerance
     sd_tolerance: F,
+    /// Restart strategy for degenerate simplices
+    restart: R,
 }
 
 impl<P, F> NelderMead<P, F>
@@ -100,9 +119,16 @@ where
             sigma: float!(0.5),
             params: params.into_iter().map(|p| (p, F::nan())).collect(),
             sd_tolerance: F::epsilon(),
+            restart: NoRestart,
         }
     }
+}
 
+impl<P, F, R> NelderMead<P, F, R>
+where
+    P: Clone + ArgminAdd<P, P> + ArgminSub<P, P> + ArgminMul<F, P>,
+    F: ArgminFloat,
+{
     /// Set sample standard deviation tolerance
     ///
     /// Must be non-negative and defaults to `EPSILON`.
@@ -238,10 +264,90 @@ where
         Ok(self)
     }
 
+    /// Use the adaptive parameters of Gao and Han
+    ///
+    /// Sets the parameters depending on the number of dimensions `n` of the problem:
+    ///
+    /// * `alpha = 1`
+    /// * `gamma = 1 + 2/n`
+    /// * `rho = 0.75 - 1/(2n)`
+    /// * `sigma = 1 - 1/n`
+    ///
+    /// The number of dimensions is derived from the number of parameter vectors passed to
+    /// [`new`](`NelderMead::new`), which must therefore be set before. For `n = 2`, the adaptive
+    /// parameters coincide with the standard parameters. For `n = 1` they are not defined
+    /// (`sigma` would be `0`), therefore the current parameters are left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::neldermead::NelderMead;
+    /// # let vec_of_parameters = vec![vec![1.0, 0.0], vec![0.0, 1.0], vec![0.0, 0.0]];
+    /// let nm: NelderMead<Vec<f64>, f64> =
+    ///     NelderMead::new(vec_of_parameters).with_adaptive_parameters();
+    /// ```
+    #[must_use]
+    pub fn with_adaptive_parameters(mut self) -> Self {
+        let n = self.params.len().saturating_sub(1);
+        if n >= 2 {
+            let n = float!(n as f64);
+            self.alpha = float!(1.0);
+            self.gamma = float!(1.0) + float!(2.0) / n;
+            self.rho = float!(0.75) - float!(1.0) / (float!(2.0) * n);
+            self.sigma = float!(1.0) - float!(1.0) / n;
+        }
+        self
+    }
+
+    /// Enable restarts on degenerate simplices
+    ///
+    /// After each iteration, the normalized volume of the simplex is computed, which is the
+    /// volume of the simplex divided by the product of the lengths of its edges emanating from the
+    /// best vertex. It equals `1` if these edges are orthogonal and `0` if the simplex has
+    /// collapsed. If it falls below `tol`, the simplex is restarted: The best vertex is kept and
+    /// the other vertices are placed along orthogonal directions, pointing away from the
+    /// previous (worse) vertices, at a distance equal to the diameter of the previous simplex.
+    ///
+    /// `tol` must be in `(0, 1)`. Restarts are disabled by default. The parameter vector is
+    /// required to implement [`ArgminDot`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::neldermead::{NelderMead, VolumeRestart};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let vec_of_parameters = vec![vec![1.0, 0.0], vec![0.0, 1.0], vec![0.0, 0.0]];
+    /// let nm: NelderMead<Vec<f64>, f64, VolumeRestart<f64>> =
+    ///     NelderMead::new(vec_of_parameters).with_restart_tolerance(1e-8)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_restart_tolerance(self, tol: F) -> Result<NelderMead<P, F, VolumeRestart<F>>, Error>
+    where
+        P: ArgminDot<P, F>,
+    {
+        if tol <= float!(0.0) || tol >= float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Nelder-Mead`: restart tolerance must be in (0, 1)."
+            ));
+        }
+        Ok(NelderMead {
+            alpha: self.alpha,
+            gamma: self.gamma,
+            rho: self.rho,
+            sigma: self.sigma,
+            params: self.params,
+            sd_tolerance: self.sd_tolerance,
+            restart: VolumeRestart { tolerance: tol },
+        })
+    }
+
     /// Sort parameters vectors based on their cost function values
     fn sort_param_vecs(&mut self) {
         self.params
-            .sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+            .sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(core::cmp::Ordering::Equal));
     }
 
     /// Calculate centroid of all vectors but the worst
@@ -295,6 +401,140 @@ where
     }
 }
 
+/// Restart strategy of [`NelderMead`] for degenerate simplices
+///
+/// Called after each iteration with the simplex (sorted by cost) and may replace some of its
+/// vertices. The returned `KV` is added to the values logged by [`NelderMead`].
+pub trait SimplexRestart<P, F> {
+    /// Restart the simplex if necessary
+    fn restart<S>(&self, simplex: &mut [(P, F)], cost: S) -> Result<Option<KV>, Error>
+    where
+        S: FnMut(&P) -> Result<F, Error>;
+}
+
+/// Never restart the simplex (default)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct NoRestart;
+
+impl<P, F> SimplexRestart<P, F> for NoRestart {
+    fn restart<S>(&self, _simplex: &mut [(P, F)], _cost: S) -> Result<Option<KV>, Error>
+    where
+        S: FnMut(&P) -> Result<F, Error>,
+    {
+        Ok(None)
+    }
+}
+
+/// Restart the simplex once its normalized volume falls below a tolerance
+///
+/// See [`with_restart_tolerance`](`NelderMead::with_restart_tolerance`).
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct VolumeRestart<F> {
+    /// Normalized volume below which the simplex is restarted
+    tolerance: F,
+}
+
+impl<P, F> SimplexRestart<P, F> for VolumeRestart<F>
+where
+    P: Clone + ArgminSub<P, P> + ArgminMul<F, P> + ArgminDot<P, F>,
+    F: ArgminFloat,
+{
+    fn restart<S>(&self, simplex: &mut [(P, F)], cost: S) -> Result<Option<KV>, Error>
+    where
+        S: FnMut(&P) -> Result<F, Error>,
+    {
+        let mut diameter = diameter(simplex);
+        let (volume, ortho) = normalized_volume(simplex);
+        let restarted = volume < self.tolerance && diameter > float!(0.0);
+        if restarted {
+            restart(simplex, ortho, diameter, cost)?;
+            diameter = self::diameter(simplex);
+        }
+        Ok(Some(kv!(
+            "diameter" => diameter;
+            "restarted" => restarted;
+        )))
+    }
+}
+
+/// Maximum distance of a vertex to the best vertex
+fn diameter<P, F>(simplex: &[(P, F)]) -> F
+where
+    P: ArgminSub<P, P> + ArgminDot<P, F>,
+    F: ArgminFloat,
+{
+    let x0 = &simplex[0].0;
+    simplex
+        .iter()
+        .skip(1)
+        .map(|(p, _)| {
+            let edge = p.sub(x0);
+            edge.dot(&edge).sqrt()
+        })
+        .fold(float!(0.0), |acc, d| acc.max(d))
+}
+
+/// Normalized volume of the simplex together with the orthogonalized edges (and their squared
+/// lengths) emanating from the best vertex (modified Gram-Schmidt)
+fn normalized_volume<P, F>(simplex: &[(P, F)]) -> (F, Vec<(P, F)>)
+where
+    P: ArgminSub<P, P> + ArgminMul<F, P> + ArgminDot<P, F>,
+    F: ArgminFloat,
+{
+    let x0 = &simplex[0].0;
+    let mut volume = float!(1.0);
+    let mut ortho: Vec<(P, F)> = Vec::with_capacity(simplex.len() - 1);
+    for (p, _) in simplex.iter().skip(1) {
+        let edge = p.sub(x0);
+        let edge_norm2 = edge.dot(&edge);
+        let u = ortho.iter().fold(edge, |u, (q, q_norm2)| {
+            if *q_norm2 > float!(0.0) {
+                let coeff = u.dot(q) / *q_norm2;
+                u.sub(&q.mul(&coeff))
+            } else {
+                u
+            }
+        });
+        let u_norm2 = u.dot(&u);
+        volume = if edge_norm2 > float!(0.0) {
+            volume * (u_norm2 / edge_norm2).sqrt()
+        } else {
+            float!(0.0)
+        };
+        ortho.push((u, u_norm2));
+    }
+    (volume, ortho)
+}
+
+/// Restart the simplex around the best vertex along the given orthogonal directions
+fn restart<P, F, S>(
+    simplex: &mut [(P, F)],
+    ortho: Vec<(P, F)>,
+    length: F,
+    mut cost: S,
+) -> Result<(), Error>
+where
+    P: Clone + ArgminSub<P, P> + ArgminMul<F, P>,
+    F: ArgminFloat,
+    S: FnMut(&P) -> Result<F, Error>,
+{
+    let x0 = simplex[0].0.clone();
+    simplex
+        .iter_mut()
+        .skip(1)
+        .zip(ortho)
+        .filter(|(_, (_, u_norm2))| *u_norm2 > float!(0.0))
+        .try_for_each(|((p, c), (u, u_norm2))| -> Result<(), Error> {
+            *p = x0.sub(&u.mul(&(length / u_norm2.sqrt())));
+            *c = (cost)(p)?;
+            Ok(())
+        })?;
+    simplex.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(core::cmp::Ordering::Equal));
+    Ok(())
+}
+
 #[derive(Debug)]
 enum Action {
     Reflection,
@@ -316,11 +556,12 @@ impl fmt::Display for Action {
     }
 }
 
-impl<O, P, F> Solver<O, IterState<P, (), (), (), F>> for NelderMead<P, F>
+impl<O, P, F, R> Solver<O, IterState<P, (), (), (), F>> for NelderMead<P, F, R>
 where
     O: CostFunction<Param = P, Output = F>,
     P: Clone + SerializeAlias + ArgminSub<P, P> + ArgminAdd<P, P> + ArgminMul<F, P>,
-    F: ArgminFloat + std::iter::Sum<F>,
+    F: ArgminFloat + core::iter::Sum<F>,
+    R: SimplexRestart<P, F> + SerializeAlias,
 {
     const NAME: &'static str = "Nelder-Mead method";
 
@@ -407,9 +648,18 @@ where
 
         self.sort_param_vecs();
 
+        let kv = kv!("action" => format!("{action}"););
+        let kv = match self
+            .restart
+            .restart(&mut self.params, |x| problem.cost(x))?
+        {
+            Some(restart_kv) => kv.merge(restart_kv),
+            None => kv,
+        };
+
         Ok((
             state.param(self.params[0].0.clone()).cost(self.params[0].1),
-            Some(kv!("action" => format!("{action}");)),
+            Some(kv),
         ))
     }
 
@@ -462,6 +712,7 @@ mod tests {
             sigma,
             params,
             sd_tolerance,
+            restart,
         } = nm;
 
         assert_eq!(alpha.to_ne_bytes(), 1.0f64.to_ne_bytes());
@@ -473,6 +724,57 @@ mod tests {
         assert_eq!(params[0].1.to_ne_bytes(), f64::NAN.to_ne_bytes());
         assert_eq!(params[1].1.to_ne_bytes(), f64::NAN.to_ne_bytes());
         assert_eq!(sd_tolerance.to_ne_bytes(), f64::EPSILON.to_ne_bytes());
+        assert_eq!(restart, NoRestart);
+    }
+
+    #[test]
+    fn test_with_restart_tolerance() {
+        for tol in [f64::EPSILON, 1e-8, 0.5] {
+            let nm: NelderMead<Vec<f64>, f64, VolumeRestart<f64>> =
+                NelderMead::new(vec![vec![1.0], vec![2.0]])
+                    .with_restart_tolerance(tol)
+                    .unwrap();
+            assert_eq!(nm.restart.tolerance.to_ne_bytes(), tol.to_ne_bytes());
+        }
+
+        for tol in [0.0, 1.0, -1.0, 2.0] {
+            let res = NelderMead::new(vec![vec![1.0], vec![2.0]]).with_restart_tolerance(tol);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`Nelder-Mead`: restart tolerance must be in (0, 1).\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_simplex_geometry() {
+        let simplex = vec![
+            (vec![0.0, 0.0], 0.0),
+            (vec![2.0, 0.0], 0.0),
+            (vec![0.0, 1.0], 0.0),
+        ];
+        assert_relative_eq!(diameter(&simplex), 2.0, epsilon = f64::EPSILON);
+        let (volume, _) = normalized_volume(&simplex);
+        assert_relative_eq!(volume, 1.0, epsilon = f64::EPSILON);
+
+        // Collapsed simplex
+        let mut simplex = vec![
+            (vec![0.0f64, 0.0], -1.0),
+            (vec![2.0, 0.0], 0.0),
+            (vec![1.0, 1e-10], 0.0),
+        ];
+        let (volume, ortho) = normalized_volume(&simplex);
+        assert!(volume < 1e-9);
+
+        // Restart keeps best vertex and places the others orthogonally at the given distance
+        restart(&mut simplex, ortho, 2.0, |_| Ok(10.0)).unwrap();
+        let (volume, _) = normalized_volume(&simplex);
+        assert_relative_eq!(volume, 1.0, epsilon = 1e-6);
+        assert_relative_eq!(diameter(&simplex), 2.0, epsilon = 1e-6);
+        assert!(simplex
+            .iter()
+            .any(|(p, _)| p[0].abs() < 1e-12 && p[1].abs() < 1e-12));
     }
 
     #[test]
@@ -591,6 +893,154 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_with_adaptive_parameters() {
+        let params: Vec<Vec<f64>> = (0..11)
+            .map(|i| (0..10).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+            .collect();
+        let nm: NelderMead<Vec<f64>, f64> = NelderMead::new(params).with_adaptive_parameters();
+        assert_relative_eq!(nm.alpha, 1.0, epsilon = f64::EPSILON);
+        assert_relative_eq!(nm.gamma, 1.2, epsilon = f64::EPSILON);
+        assert_relative_eq!(nm.rho, 0.7, epsilon = f64::EPSILON);
+        assert_relative_eq!(nm.sigma, 0.9, epsilon = f64::EPSILON);
+
+        // Parameters are left untouched in one dimension
+        let nm: NelderMead<Vec<f64>, f64> = NelderMead::new(vec![vec![1.0], vec![2.0]])
+            .with_alpha(0.9)
+            .unwrap()
+            .with_gamma(1.5)
+            .unwrap()
+            .with_rho(0.4)
+            .unwrap()
+            .with_sigma(0.9)
+            .unwrap()
+            .with_adaptive_parameters();
+        assert_eq!(nm.alpha.to_ne_bytes(), 0.9f64.to_ne_bytes());
+        assert_eq!(nm.gamma.to_ne_bytes(), 1.5f64.to_ne_bytes());
+        assert_eq!(nm.rho.to_ne_bytes(), 0.4f64.to_ne_bytes());
+        assert_eq!(nm.sigma.to_ne_bytes(), 0.9f64.to_ne_bytes());
+
+        let nm: NelderMead<Vec<f64>, f64> =
+            NelderMead::new(vec![vec![1.0], vec![2.0]]).with_adaptive_parameters();
+        assert_eq!(nm.alpha.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(nm.gamma.to_ne_bytes(), 2.0f64.to_ne_bytes());
+        assert_eq!(nm.rho.to_ne_bytes(), 0.5f64.to_ne_bytes());
+        assert_eq!(nm.sigma.to_ne_bytes(), 0.5f64.to_ne_bytes());
+    }
+
+    #[test]
+    fn test_adaptive_parameters_high_dimension() {
+        use crate::core::Executor;
+
+        // 20-dimensional sphere function
+        let n = 20;
+        let params: Vec<Vec<f64>> = (0..=n)
+            .map(|i| (0..n).map(|j| if i == j { 2.0 } else { 1.0 }).collect())
+            .collect();
+        let solver = NelderMead::new(params)
+            .with_adaptive_parameters()
+            .with_sd_tolerance(1e-12)
+            .unwrap();
+        let res = Executor::new(MwProblem {}, solver)
+            .configure(|state| state.max_iters(20000))
+            .run()
+            .unwrap();
+        assert!(res.state.get_best_cost() < 1e-8);
+    }
+
+    #[test]
+    fn test_without_dot_product() {
+        use crate::core::Executor;
+
+        /// Parameter vector which does not implement `ArgminDot`
+        #[derive(Clone, Debug)]
+        #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+        struct NoDot(Vec<f64>);
+
+        impl ArgminAdd<NoDot, NoDot> for NoDot {
+            fn add(&self, other: &NoDot) -> NoDot {
+                NoDot(self.0.add(&other.0))
+            }
+        }
+
+        impl ArgminSub<NoDot, NoDot> for NoDot {
+            fn sub(&self, other: &NoDot) -> NoDot {
+                NoDot(self.0.sub(&other.0))
+            }
+        }
+
+        impl ArgminMul<f64, NoDot> for NoDot {
+            fn mul(&self, other: &f64) -> NoDot {
+                NoDot(self.0.mul(other))
+            }
+        }
+
+        struct Sphere {}
+
+        impl CostFunction for Sphere {
+            type Param = NoDot;
+            type Output = f64;
+
+            fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(p.0.iter().map(|x| x.powi(2)).sum())
+            }
+        }
+
+        let simplex = vec![
+            NoDot(vec![1.0, 0.5]),
+            NoDot(vec![2.0, 1.0]),
+            NoDot(vec![0.5, 2.0]),
+        ];
+        let res = Executor::new(Sphere {}, NelderMead::new(simplex))
+            .configure(|state| state.max_iters(200))
+            .run()
+            .unwrap();
+        assert!(res.state.get_best_cost() < 1e-8);
+    }
+
+    #[test]
+    fn test_restart_mckinnon() {
+        use crate::core::Executor;
+
+        /// McKinnon's function, for which the Nelder-Mead method converges to a nonstationary
+        /// point from the initial simplex below
+        struct McKinnon {}
+
+        impl CostFunction for McKinnon {
+            type Param = Vec<f64>;
+            type Output = f64;
+
+            fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                let (x, y) = (p[0], p[1]);
+                let fx = if x <= 0.0 {
+                    360.0 * x.powi(2)
+                } else {
+                    6.0 * x.powi(2)
+                };
+                Ok(fx + y + y.powi(2))
+            }
+        }
+
+        let l1 = (1.0 + 33.0f64.sqrt()) / 8.0;
+        let l2 = (1.0 - 33.0f64.sqrt()) / 8.0;
+        let simplex = vec![vec![0.0, 0.0], vec![1.0, 1.0], vec![l1, l2]];
+
+        let res = Executor::new(McKinnon {}, NelderMead::new(simplex.clone()))
+            .configure(|state| state.max_iters(1000))
+            .run()
+            .unwrap();
+        assert!(res.state.get_best_cost() > -0.1);
+
+        let solver = NelderMead::new(simplex)
+            .with_restart_tolerance(1e-3)
+            .unwrap();
+        let res = Executor::new(McKinnon {}, solver)
+            .configure(|state| state.max_iters(1000))
+            .run()
+            .unwrap();
+        assert_relative_eq!(res.state.get_best_cost(), -0.25, epsilon = 1e-8);
+    }
+
     #[test]
     fn test_sort_param_vecs() {
         let params: Vec<Vec<f64>> = vec![vec![2.0], vec![1.0], vec![3.0]];
@@ -7,12 +7,16 @@
 
 use crate::core::{
     ArgminFloat, DeserializeOwnedAlias, Error, Executor, Gradient, Hessian, IterState, LineSearch,
-    Operator, OptimizationResult, Problem, SerializeAlias, Solver, State, TerminationReason,
+    OptimizationResult, Problem, SerializeAlias, Solver, State, TerminationReason,
     TerminationStatus, KV,
 };
-use crate::solver::conjugategradient::ConjugateGradient;
+use crate::solver::conjugategradient::{
+    ConjugateGradient, IdentityPreconditioner, LinearOperatorProblem, Preconditioner,
+};
+use crate::solver::linesearch::linesearch_kv;
 use argmin_math::{
     ArgminConj, ArgminDot, ArgminL2Norm, ArgminMul, ArgminScaledAdd, ArgminSub, ArgminZeroLike,
+    LinearOperator,
 };
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
@@ -24,7 +28,13 @@ use serde::{Deserialize, Serialize};
 ///
 /// ## Requirements on the optimization problem
 ///
-/// The optimization problem is required to implement [`Gradient`] and [`Hessian`].
+/// The optimization problem is required to implement [`Gradient`] and [`Hessian`]. The Hessian
+/// is only used via matrix-vector products and can therefore be any [`LinearOperator`].
+///
+/// The CG subproblem can be preconditioned by setting a [`Preconditioner`] via
+/// [`with_preconditioner`](`NewtonCG::with_preconditioner`). The same preconditioner is used in
+/// all iterations; it should therefore approximate the Hessian in the whole region traversed by
+/// the solver.
 ///
 /// ## Reference
 ///
@@ -32,9 +42,11 @@ use serde::{Deserialize, Serialize};
 /// Springer. ISBN 0-387-30303-0.
 #[derive(Clone)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
-pub struct NewtonCG<L, F> {
+pub struct NewtonCG<L, F, M = IdentityPreconditioner> {
     /// line search
     linesearch: L,
+    /// Preconditioner of the CG subproblem
+    preconditioner: M,
     /// curvature_threshold
     curvature_threshold: F,
     /// Tolerance for the stopping criterion based on cost difference
@@ -57,10 +69,39 @@ where
     pub fn new(linesearch: L) -> Self {
         NewtonCG {
             linesearch,
+            preconditioner: IdentityPreconditioner::new(),
             curvature_threshold: float!(0.0),
             tol: F::epsilon(),
         }
     }
+}
+
+impl<L, F, M> NewtonCG<L, F, M>
+where
+    F: ArgminFloat,
+{
+    /// Set preconditioner of the CG subproblem
+    ///
+    /// `preconditioner` must implement [`Preconditioner`], i.e. apply the inverse of an
+    /// approximation of the Hessian to a vector.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::newton::NewtonCG;
+    /// # use argmin::solver::conjugategradient::JacobiPreconditioner;
+    /// # let linesearch = ();
+    /// let preconditioner = JacobiPreconditioner::new(vec![100.0f64, 1.0]);
+    /// let ncg: NewtonCG<_, f64, _> = NewtonCG::new(linesearch).with_preconditioner(preconditioner);
+    /// ```
+    pub fn with_preconditioner<M2>(self, preconditioner: M2) -> NewtonCG<L, F, M2> {
+        NewtonCG {
+            linesearch: self.linesearch,
+            preconditioner,
+            curvature_threshold: self.curvature_threshold,
+            tol: self.tol,
+        }
+    }
 
     /// Set curvature threshold
     ///
@@ -106,7 +147,7 @@ where
     }
 }
 
-impl<O, L, P, G, H, F> Solver<O, IterState<P, G, (), H, F>> for NewtonCG<L, F>
+impl<O, L, P, G, H, F, M> Solver<O, IterState<P, G, (), H, F>> for NewtonCG<L, F, M>
 where
     O: Gradient<Param = P, Gradient = G> + Hessian<Param = P, Hessian = H>,
     P: Clone
@@ -119,8 +160,9 @@ where
         + ArgminConj
         + ArgminZeroLike,
     G: SerializeAlias + DeserializeOwnedAlias + ArgminL2Norm<F> + ArgminMul<F, P>,
-    H: Clone + SerializeAlias + DeserializeOwnedAlias + ArgminDot<P, P>,
+    H: Clone + SerializeAlias + DeserializeOwnedAlias + LinearOperator<P>,
     L: Clone + LineSearch<P, F> + Solver<O, IterState<P, G, (), (), F>>,
+    M: Clone + Preconditioner<P>,
     F: ArgminFloat + ArgminL2Norm<F>,
 {
     const NAME: &'static str = "Newton-CG";
@@ -147,11 +189,12 @@ where
             .unwrap_or_else(|| problem.hessian(&param))?;
 
         // Solve CG subproblem
-        let mut cg_problem = Problem::new(CGSubProblem::new(&hessian));
+        let mut cg_problem = Problem::new(LinearOperatorProblem::new(&hessian));
 
         let mut x_p = param.zero_like();
         let mut x = param.zero_like();
-        let mut cg = ConjugateGradient::new(grad.mul(&(float!(-1.0))));
+        let mut cg = ConjugateGradient::new(grad.mul(&(float!(-1.0))))
+            .with_preconditioner(self.preconditioner.clone());
 
         let (mut cg_state, _) = cg.init(&mut cg_problem, IterState::new().param(x_p.clone()))?;
 
@@ -165,7 +208,7 @@ where
             x = cg_state.take_param().unwrap();
             let p = cg.get_prev_p()?;
 
-            let curvature = p.dot(&hessian.dot(p));
+            let curvature = p.dot(&hessian.apply(p)?);
             if curvature <= self.curvature_threshold {
                 if iter == 0 {
                     x = grad.mul(&(float!(-1.0)));
@@ -192,12 +235,14 @@ where
         // Run solver
         let OptimizationResult {
             problem: line_problem,
+            solver: linesearch,
             state: mut linesearch_state,
             ..
         } = Executor::new(problem.take_problem().unwrap(), self.linesearch.clone())
             .configure(|state| state.param(param).gradient(grad).cost(line_cost))
             .ctrlc(false)
             .run()?;
+        self.linesearch = linesearch;
 
         problem.consume_problem(line_problem);
 
@@ -205,7 +250,7 @@ where
             state
                 .param(linesearch_state.take_param().unwrap())
                 .cost(linesearch_state.get_cost()),
-            None,
+            Some(linesearch_kv(&self.linesearch, &linesearch_state)),
         ))
     }
 
@@ -218,40 +263,13 @@ where
     }
 }
 
-#[derive(Clone)]
-struct CGSubProblem<'a, P, H> {
-    hessian: &'a H,
-    phantom: std::marker::PhantomData<P>,
-}
-
-impl<'a, P, H> CGSubProblem<'a, P, H> {
-    /// Constructor
-    fn new(hessian: &'a H) -> Self {
-        CGSubProblem {
-            hessian,
-            phantom: std::marker::PhantomData,
-        }
-    }
-}
-
-impl<'a, P, H> Operator for CGSubProblem<'a, P, H>
-where
-    H: ArgminDot<P, P>,
-{
-    type Param = P;
-    type Output = P;
-
-    fn apply(&self, p: &P) -> Result<P, Error> {
-        Ok(self.hessian.dot(p))
-    }
-}
-
 #[cfg(test)]
 mod tests {
     #![allow(clippy::let_unit_value)]
 
     use super::*;
     use crate::core::{test_utils::TestProblem, ArgminError};
+    use crate::solver::conjugategradient::JacobiPreconditioner;
     use crate::solver::linesearch::MoreThuenteLineSearch;
     use crate::test_trait_impl;
 
@@ -260,8 +278,6 @@ mod tests {
         NewtonCG<MoreThuenteLineSearch<Vec<f64>, Vec<f64>, f64>, f64>
     );
 
-    test_trait_impl!(cg_subproblem, CGSubProblem<Vec<f64>, Vec<Vec<f64>>>);
-
     #[test]
     fn test_tolerance() {
         let tol1: f64 = 1e-4;
@@ -283,6 +299,7 @@ mod tests {
         let ncg: NewtonCG<_, f64> = NewtonCG::new(ls);
         let NewtonCG {
             linesearch,
+            preconditioner: _,
             curvature_threshold,
             tol,
         } = ncg;
@@ -291,6 +308,29 @@ mod tests {
         assert_eq!(tol.to_ne_bytes(), f64::EPSILON.to_ne_bytes());
     }
 
+    #[test]
+    fn test_with_preconditioner() {
+        #[derive(Eq, PartialEq, Debug, Copy, Clone)]
+        struct LineSearch {}
+        let ls = LineSearch {};
+        let ncg: NewtonCG<_, f64, _> = NewtonCG::new(ls)
+            .with_curvature_threshold(1e-6)
+            .with_preconditioner(JacobiPreconditioner::new(vec![2.0f64, 4.0]));
+        let NewtonCG {
+            linesearch,
+            preconditioner,
+            curvature_threshold,
+            tol,
+        } = ncg;
+        assert_eq!(linesearch, ls);
+        assert_eq!(
+            preconditioner.apply_inverse(&vec![2.0, 2.0]).unwrap(),
+            vec![1.0, 0.5]
+        );
+        assert_eq!(curvature_threshold.to_ne_bytes(), 1e-6f64.to_ne_bytes());
+        assert_eq!(tol.to_ne_bytes(), f64::EPSILON.to_ne_bytes());
+    }
+
     #[test]
     fn test_with_curvature_threshold() {
         #[derive(Eq, PartialEq, Debug, Copy, Clone)]
@@ -299,6 +339,7 @@ mod tests {
         let ncg: NewtonCG<_, f64> = NewtonCG::new(ls).with_curvature_threshold(1e-6);
         let NewtonCG {
             linesearch,
+            preconditioner: _,
             curvature_threshold,
             tol,
         } = ncg;
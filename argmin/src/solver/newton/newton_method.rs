@@ -6,7 +6,8 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::core::{ArgminFloat, Error, Gradient, Hessian, IterState, Problem, Solver, KV};
-use argmin_math::{ArgminDot, ArgminInv, ArgminScaledSub};
+use crate::solver::linearsolver::{InverseSolver, LinearSolver};
+use argmin_math::ArgminScaledSub;
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 use std::default::Default;
@@ -19,19 +20,29 @@ use std::default::Default;
 /// The stepsize `gamma` can be adjusted with the [`with_gamma`](`Newton::with_gamma`) method. It
 /// must be in `(0, 1])` and defaults to `1`.
 ///
+/// By default the Newton equations are solved by computing the inverse of the Hessian. A
+/// different [`LinearSolver`] (for instance a Cholesky factorization or CG) can be set via
+/// [`with_linear_solver`](`Newton::with_linear_solver`), which is both faster and numerically more
+/// stable.
+///
 /// ## Requirements on the optimization problem
 ///
 /// The optimization problem is required to implement [`Gradient`] and [`Hessian`].
 ///
+/// The Hessian must be supported by the linear solver; the default [`InverseSolver`] requires
+/// `ArgminInv`.
+///
 /// ## Reference
 ///
 /// Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
 /// Springer. ISBN 0-387-30303-0.
 #[derive(Clone, Copy)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
-pub struct Newton<F> {
+pub struct Newton<F, S = InverseSolver> {
     /// gamma
     gamma: F,
+    /// Solver for the Newton equations
+    linear_solver: S,
 }
 
 impl<F> Newton<F>
@@ -47,9 +58,17 @@ where
     /// let newton: Newton<f64> = Newton::new();
     /// ```
     pub fn new() -> Self {
-        Newton { gamma: float!(1.0) }
+        Newton {
+            gamma: float!(1.0),
+            linear_solver: InverseSolver::new(),
+        }
     }
+}
 
+impl<F, S> Newton<F, S>
+where
+    F: ArgminFloat,
+{
     /// Set step size gamma
     ///
     /// Gamma must be in `(0, 1]` and defaults to `1`.
@@ -74,6 +93,24 @@ where
         self.gamma = gamma;
         Ok(self)
     }
+
+    /// Set the solver for the Newton equations `H * p = g`
+    ///
+    /// Defaults to [`InverseSolver`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::newton::Newton;
+    /// # use argmin::solver::linearsolver::CholeskySolver;
+    /// let newton: Newton<f64, _> = Newton::new().with_linear_solver(CholeskySolver::new());
+    /// ```
+    pub fn with_linear_solver<S2>(self, linear_solver: S2) -> Newton<F, S2> {
+        Newton {
+            gamma: self.gamma,
+            linear_solver,
+        }
+    }
 }
 
 impl<F> Default for Newton<F>
@@ -85,11 +122,11 @@ where
     }
 }
 
-impl<O, P, G, H, F> Solver<O, IterState<P, G, (), H, F>> for Newton<F>
+impl<O, P, G, H, F, S> Solver<O, IterState<P, G, (), H, F>> for Newton<F, S>
 where
     O: Gradient<Param = P, Gradient = G> + Hessian<Param = P, Hessian = H>,
-    P: Clone + ArgminScaledSub<P, F, P>,
-    H: ArgminInv<H> + ArgminDot<G, P>,
+    P: Clone + ArgminScaledSub<G, F, P>,
+    S: LinearSolver<H, G>,
     F: ArgminFloat,
 {
     const NAME: &'static str = "Newton method";
@@ -108,7 +145,8 @@ where
         ))?;
         let grad = problem.gradient(&param)?;
         let hessian = problem.hessian(&param)?;
-        let new_param = param.scaled_sub(&self.gamma, &hessian.inv()?.dot(&grad));
+        let step = self.linear_solver.solve(&hessian, &grad)?;
+        let new_param = param.scaled_sub(&self.gamma, &step);
         Ok((state.param(new_param), None))
     }
 }
@@ -285,4 +323,59 @@ mod tests {
         assert_relative_eq!(param[0], -1.0, epsilon = f64::EPSILON);
         assert_relative_eq!(param[1], -2.0, epsilon = f64::EPSILON);
     }
+
+    #[test]
+    fn test_with_linear_solver() {
+        use crate::core::{Executor, State};
+        use crate::solver::linearsolver::{CGSolver, CholeskySolver, LUSolver, QRSolver};
+        use approx::assert_relative_eq;
+
+        // f(x) = 0.5 * x^T A x - b^T x with minimum at x = A^-1 b = [1, 2, 1]
+        struct Quadratic {}
+
+        const A: [[f64; 3]; 3] = [[4.0, 2.0, -2.0], [2.0, 10.0, 4.0], [-2.0, 4.0, 9.0]];
+        const B: [f64; 3] = [6.0, 26.0, 15.0];
+
+        impl Gradient for Quadratic {
+            type Param = Vec<f64>;
+            type Gradient = Vec<f64>;
+
+            fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+                Ok((0..3)
+                    .map(|i| (0..3).map(|j| A[i][j] * p[j]).sum::<f64>() - B[i])
+                    .collect())
+            }
+        }
+
+        impl Hessian for Quadratic {
+            type Param = Vec<f64>;
+            type Hessian = Vec<Vec<f64>>;
+
+            fn hessian(&self, _p: &Self::Param) -> Result<Self::Hessian, Error> {
+                Ok(A.iter().map(|row| row.to_vec()).collect())
+            }
+        }
+
+        fn run<S: LinearSolver<Vec<Vec<f64>>, Vec<f64>>>(solver: Newton<f64, S>) -> Vec<f64> {
+            Executor::new(Quadratic {}, solver)
+                .configure(|config| config.param(vec![0.0, 0.0, 0.0]).max_iters(1))
+                .run()
+                .unwrap()
+                .state
+                .get_best_param()
+                .unwrap()
+                .clone()
+        }
+
+        for param in [
+            run(Newton::new().with_linear_solver(CholeskySolver::new())),
+            run(Newton::new().with_linear_solver(LUSolver::new())),
+            run(Newton::new().with_linear_solver(QRSolver::new())),
+            run(Newton::new().with_linear_solver(CGSolver::new().with_tolerance(1e-12))),
+        ] {
+            assert_relative_eq!(param[0], 1.0, epsilon = 1e-10);
+            assert_relative_eq!(param[1], 2.0, epsilon = 1e-10);
+            assert_relative_eq!(param[2], 1.0, epsilon = 1e-10);
+        }
+    }
 }
@@ -0,0 +1,533 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Non-negative least squares
+//!
+//! Lawson-Hanson active-set algorithm for linear least squares problems with non-negativity
+//! constraints. See [`NNLS`] for details.
+//!
+//! ## Reference
+//!
+//! Charles L. Lawson and Richard J. Hanson (1995). Solving Least Squares Problems.
+//! SIAM. ISBN 0-89871-356-0.
+
+use crate::core::{
+    ArgminFloat, Error, IterState, Jacobian, Operator, Problem, Solver, TerminationReason,
+    TerminationStatus, KV,
+};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Non-negative least squares (NNLS)
+///
+/// Solves linear least squares problems with non-negativity constraints
+///
+/// `min_x ||A x - b||` subject to `x >= 0`
+///
+/// using the active-set algorithm of Lawson and Hanson.
+///
+/// The problem is defined like for [`GaussNewton`](`crate::solver::gaussnewton::GaussNewton`):
+/// [`Operator`] computes the residuals `r(x) = A x - b` and [`Jacobian`] returns the matrix `A`
+/// as a vector of rows. Both are evaluated once at the initial parameter vector, which needs to
+/// be provided via the `configure` method of the [`Executor`](`crate::core::Executor`) and
+/// determines the number of variables. The algorithm itself always starts at `x = 0`.
+///
+/// The variables are split into a passive set of (positive) free variables and an active set of
+/// variables fixed at zero. In each iteration, the active variable with the largest component of
+/// the negative gradient `A^T (b - A x)` is freed and the unconstrained least squares problem on
+/// the passive set is solved via a QR decomposition. Passive variables which would become
+/// negative are moved back to the active set. The solver terminates once no component of the
+/// negative gradient of an active variable exceeds the tolerance (see
+/// [`with_tolerance`](`NNLS::with_tolerance`)), which means that the KKT conditions are
+/// satisfied. The number of passive variables is reported via the observers (`passive`).
+///
+/// The cost function value stored in the state is the norm of the residuals.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`Operator`] and [`Jacobian`].
+///
+/// ## Reference
+///
+/// Charles L. Lawson and Richard J. Hanson (1995). Solving Least Squares Problems.
+/// SIAM. ISBN 0-89871-356-0.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct NNLS<F> {
+    /// Tolerance on the gradient of active variables
+    tol: Option<F>,
+    /// Matrix `A` as a vector of rows
+    a: Vec<Vec<F>>,
+    /// Right-hand side `b`
+    b: Vec<F>,
+    /// Whether a variable is in the passive set
+    passive: Vec<bool>,
+    /// Whether the KKT conditions are satisfied
+    converged: bool,
+}
+
+impl<F> NNLS<F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`NNLS`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::nnls::NNLS;
+    /// let nnls: NNLS<f64> = NNLS::new();
+    /// ```
+    pub fn new() -> Self {
+        NNLS {
+            tol: None,
+            a: vec![],
+            b: vec![],
+            passive: vec![],
+            converged: false,
+        }
+    }
+
+    /// Set the tolerance on the components of the negative gradient of active variables
+    ///
+    /// Must be larger than 0. Defaults to `10 * EPSILON * ||A||_1 * max(m, n)`, where `A` is an
+    /// `m x n` matrix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::nnls::NNLS;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let nnls = NNLS::new().with_tolerance(1e-10f64)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`NNLS`: tolerance must be > 0."
+            ));
+        }
+        self.tol = Some(tol);
+        Ok(self)
+    }
+
+    /// Residuals `A x - b`
+    fn residuals(&self, x: &[F]) -> Vec<F> {
+        self.a
+            .iter()
+            .zip(self.b.iter())
+            .map(|(row, b)| {
+                row.iter()
+                    .zip(x.iter())
+                    .fold(-*b, |acc, (a, x)| acc + *a * *x)
+            })
+            .collect()
+    }
+
+    /// Negative gradient `A^T (b - A x)`
+    fn negative_gradient(&self, x: &[F]) -> Vec<F> {
+        let r = self.residuals(x);
+        (0..x.len())
+            .map(|j| {
+                self.a
+                    .iter()
+                    .zip(r.iter())
+                    .fold(float!(0.0), |acc, (row, r)| acc - row[j] * *r)
+            })
+            .collect()
+    }
+
+    /// Solves the unconstrained least squares problem on the passive set. Active variables are
+    /// zero.
+    fn solve_passive(&self) -> Result<Vec<F>, Error> {
+        let cols: Vec<usize> = (0..self.passive.len())
+            .filter(|&j| self.passive[j])
+            .collect();
+        let m: Vec<Vec<F>> = self
+            .a
+            .iter()
+            .map(|row| cols.iter().map(|&j| row[j]).collect())
+            .collect();
+        let s = least_squares(m, self.b.clone()).ok_or_else(argmin_error_closure!(
+            ConditionViolated,
+            "`NNLS`: columns of `A` in the passive set are linearly dependent."
+        ))?;
+        let mut x = vec![float!(0.0); self.passive.len()];
+        for (&j, s) in cols.iter().zip(s) {
+            x[j] = s;
+        }
+        Ok(x)
+    }
+}
+
+impl<F> Default for NNLS<F>
+where
+    F: ArgminFloat,
+{
+    fn default() -> Self {
+        NNLS::new()
+    }
+}
+
+impl<O, F> Solver<O, IterState<Vec<F>, (), (), (), F>> for NNLS<F>
+where
+    O: Operator<Param = Vec<F>, Output = Vec<F>> + Jacobian<Param = Vec<F>, Jacobian = Vec<Vec<F>>>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "NNLS";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<Vec<F>, (), (), (), F>,
+    ) -> Result<(IterState<Vec<F>, (), (), (), F>, Option<KV>), Error> {
+        let x0 = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`NNLS` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        let n = x0.len();
+        let r0 = problem.apply(&x0)?;
+        let a = problem.jacobian(&x0)?;
+        if a.len() != r0.len() || a.iter().any(|row| row.len() != n) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                concat!(
+                    "`NNLS`: Jacobian must have as many rows as there are residuals and as many ",
+                    "columns as there are parameters."
+                )
+            ));
+        }
+        // b = A x0 - r(x0)
+        self.b = a
+            .iter()
+            .zip(r0.iter())
+            .map(|(row, r)| {
+                row.iter()
+                    .zip(x0.iter())
+                    .fold(-*r, |acc, (a, x)| acc + *a * *x)
+            })
+            .collect();
+        if self.tol.is_none() {
+            let norm_1 = (0..n)
+                .map(|j| a.iter().fold(float!(0.0), |acc, row| acc + row[j].abs()))
+                .fold(float!(0.0), |acc: F, v| acc.max(v));
+            let size = F::from_usize(a.len().max(n)).unwrap();
+            self.tol = Some(float!(10.0) * F::epsilon() * norm_1 * size);
+        }
+        self.a = a;
+        self.passive = vec![false; n];
+        self.converged = false;
+
+        let x = vec![float!(0.0); n];
+        let cost = norm(&self.residuals(&x));
+        Ok((state.param(x).cost(cost), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        _problem: &mut Problem<O>,
+        mut state: IterState<Vec<F>, (), (), (), F>,
+    ) -> Result<(IterState<Vec<F>, (), (), (), F>, Option<KV>), Error> {
+        let mut x = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`NNLS`: Parameter vector in state not set."
+        ))?;
+        let tol = self.tol.ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`NNLS`: Tolerance not initialized."
+        ))?;
+
+        // Free the active variable with the largest component of the negative gradient
+        let w = self.negative_gradient(&x);
+        let entering = w
+            .iter()
+            .enumerate()
+            .filter(|(j, w)| !self.passive[*j] && **w > tol)
+            .fold(None, |acc: Option<(usize, F)>, (j, w)| match acc {
+                Some((_, best)) if best >= *w => acc,
+                _ => Some((j, *w)),
+            });
+        let t = match entering {
+            Some((t, _)) => t,
+            None => {
+                self.converged = true;
+                let cost = norm(&self.residuals(&x));
+                return Ok((
+                    state.param(x).cost(cost),
+                    Some(kv!("passive" => self.passive.iter().filter(|p| **p).count() as u64;)),
+                ));
+            }
+        };
+        self.passive[t] = true;
+
+        let mut s = self.solve_passive()?;
+        // Move back towards `x` until all passive variables are positive
+        while let Some(alpha) = self
+            .passive
+            .iter()
+            .zip(x.iter().zip(s.iter()))
+            .filter(|(p, (_, s))| **p && **s <= float!(0.0))
+            .map(|(_, (x, s))| *x / (*x - *s))
+            .fold(None, |acc: Option<F>, v| Some(acc.map_or(v, |a| a.min(v))))
+        {
+            for (xj, sj) in x.iter_mut().zip(s.iter()) {
+                *xj = *xj + alpha * (*sj - *xj);
+            }
+            for (p, xj) in self.passive.iter_mut().zip(x.iter_mut()) {
+                if *p && *xj <= tol {
+                    *p = false;
+                    *xj = float!(0.0);
+                }
+            }
+            s = self.solve_passive()?;
+        }
+        x = s;
+
+        let cost = norm(&self.residuals(&x));
+        Ok((
+            state.param(x).cost(cost),
+            Some(kv!("passive" => self.passive.iter().filter(|p| **p).count() as u64;)),
+        ))
+    }
+
+    fn terminate(&mut self, _state: &IterState<Vec<F>, (), (), (), F>) -> TerminationStatus {
+        if self.converged {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+fn norm<F: ArgminFloat>(v: &[F]) -> F {
+    v.iter().fold(float!(0.0), |acc, x| acc + *x * *x).sqrt()
+}
+
+/// Solves `min_x ||A x - b||` for an `m x k` matrix `A` (given as a vector of rows) with full
+/// column rank via Householder QR decomposition. Returns `None` if `A` is rank deficient.
+fn least_squares<F: ArgminFloat>(mut a: Vec<Vec<F>>, mut b: Vec<F>) -> Option<Vec<F>> {
+    let m = a.len();
+    let k = a.first().map_or(0, |row| row.len());
+    if k > m {
+        return None;
+    }
+    let scale = a
+        .iter()
+        .flat_map(|row| row.iter())
+        .fold(float!(0.0), |acc: F, v| acc.max(v.abs()));
+    for j in 0..k {
+        let alpha = a[j..]
+            .iter()
+            .fold(float!(0.0), |acc, row| acc + row[j] * row[j])
+            .sqrt();
+        if alpha <= F::epsilon() * F::from_usize(m).unwrap() * scale {
+            return None;
+        }
+        let alpha = if a[j][j] > float!(0.0) { -alpha } else { alpha };
+        // Householder vector v = a_j - alpha e_j
+        let mut v: Vec<F> = a[j..].iter().map(|row| row[j]).collect();
+        v[0] = v[0] - alpha;
+        let beta = v.iter().fold(float!(0.0), |acc, vi| acc + *vi * *vi);
+        for l in j..k {
+            let proj = a[j..]
+                .iter()
+                .zip(v.iter())
+                .fold(float!(0.0), |acc, (row, vi)| acc + row[l] * *vi);
+            let factor = float!(2.0) * proj / beta;
+            for (row, vi) in a[j..].iter_mut().zip(v.iter()) {
+                row[l] = row[l] - factor * *vi;
+            }
+        }
+        let proj = b[j..]
+            .iter()
+            .zip(v.iter())
+            .fold(float!(0.0), |acc, (bi, vi)| acc + *bi * *vi);
+        let factor = float!(2.0) * proj / beta;
+        for (bi, vi) in b[j..].iter_mut().zip(v.iter()) {
+            *bi = *bi - factor * *vi;
+        }
+    }
+    let mut x = vec![float!(0.0); k];
+    for j in (0..k).rev() {
+        let sum = a[j][j + 1..k]
+            .iter()
+            .zip(x[j + 1..].iter())
+            .fold(b[j], |acc, (r, xi)| acc - *r * *xi);
+        x[j] = sum / a[j][j];
+    }
+    Some(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Executor, State};
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(nnls, NNLS<f64>);
+
+    struct Linear {
+        a: Vec<Vec<f64>>,
+        b: Vec<f64>,
+    }
+
+    impl Operator for Linear {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+
+        fn apply(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(self
+                .a
+                .iter()
+                .zip(self.b.iter())
+                .map(|(row, b)| row.iter().zip(x.iter()).map(|(a, x)| a * x).sum::<f64>() - b)
+                .collect())
+        }
+    }
+
+    impl Jacobian for Linear {
+        type Param = Vec<f64>;
+        type Jacobian = Vec<Vec<f64>>;
+
+        fn jacobian(&self, _x: &Self::Param) -> Result<Self::Jacobian, Error> {
+            Ok(self.a.clone())
+        }
+    }
+
+    fn run(a: Vec<Vec<f64>>, b: Vec<f64>, x0: Vec<f64>) -> Vec<f64> {
+        let res = Executor::new(Linear { a, b }, NNLS::new())
+            .configure(|state| state.param(x0).max_iters(100))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        assert_eq!(res.problem.counts["operator_count"], 1);
+        assert_eq!(res.problem.counts["jacobian_count"], 1);
+        res.state.get_best_param().unwrap().clone()
+    }
+
+    #[test]
+    fn test_new() {
+        let NNLS {
+            tol,
+            a,
+            b,
+            passive,
+            converged,
+        } = NNLS::<f64>::new();
+        assert!(tol.is_none());
+        assert!(a.is_empty());
+        assert!(b.is_empty());
+        assert!(passive.is_empty());
+        assert!(!converged);
+    }
+
+    #[test]
+    fn test_with_tolerance() {
+        let nnls = NNLS::new().with_tolerance(1e-10f64).unwrap();
+        assert_eq!(nnls.tol.unwrap().to_ne_bytes(), 1e-10f64.to_ne_bytes());
+
+        for tol in [0.0, -1.0] {
+            let res = NNLS::new().with_tolerance(tol);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`NNLS`: tolerance must be > 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_not_initialized() {
+        let problem = Linear {
+            a: vec![vec![1.0]],
+            b: vec![1.0],
+        };
+        let res = Executor::new(problem, NNLS::<f64>::new()).run();
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`NNLS` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_dimensions() {
+        let problem = Linear {
+            a: vec![vec![1.0, 2.0]],
+            b: vec![1.0],
+        };
+        let res = Executor::new(problem, NNLS::<f64>::new())
+            .configure(|state| state.param(vec![0.0]))
+            .run();
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`NNLS`: Jacobian must have as many rows as there are ",
+                "residuals and as many columns as there are parameters.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_least_squares() {
+        // Overdetermined consistent system with solution [1, 2]
+        let a = vec![vec![1.0, 1.0], vec![1.0, -1.0], vec![2.0, 1.0]];
+        let x = least_squares(a, vec![3.0, -1.0, 4.0]).unwrap();
+        assert_relative_eq!(x[0], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(x[1], 2.0, epsilon = 1e-12);
+
+        let a = vec![vec![1.0, 2.0], vec![2.0, 4.0]];
+        assert!(least_squares(a, vec![1.0, 2.0]).is_none());
+    }
+
+    #[test]
+    fn test_unconstrained_solution() {
+        // The unconstrained solution [1, 2] is non-negative
+        let a = vec![vec![1.0, 1.0], vec![1.0, -1.0], vec![2.0, 1.0]];
+        let x = run(a, vec![3.0, -1.0, 4.0], vec![5.0, 5.0]);
+        assert_relative_eq!(x[0], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(x[1], 2.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_active_constraints() {
+        // Unconstrained solution is [1, -1, 2]; with x >= 0 the second variable is zero and the
+        // others solve the reduced problem
+        let a = vec![
+            vec![1.0, 0.0, 0.0],
+            vec![0.0, 1.0, 0.0],
+            vec![0.0, 0.0, 1.0],
+            vec![1.0, 1.0, 1.0],
+        ];
+        let b = vec![1.0, -1.0, 2.0, 2.0];
+        let x = run(a, b, vec![0.0, 0.0, 0.0]);
+        // min (x1 - 1)^2 + (x3 - 2)^2 + (x1 + x3 - 2)^2 -> x1 = 2/3, x3 = 5/3
+        assert_relative_eq!(x[0], 2.0 / 3.0, epsilon = 1e-12);
+        assert_eq!(x[1].to_ne_bytes(), 0.0f64.to_ne_bytes());
+        assert_relative_eq!(x[2], 5.0 / 3.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_all_zero() {
+        let a = vec![vec![1.0, 0.0], vec![0.0, 1.0]];
+        let x = run(a, vec![-1.0, -2.0], vec![1.0, 1.0]);
+        assert_eq!(x, vec![0.0, 0.0]);
+    }
+}
@@ -0,0 +1,757 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # NSGA-II
+//!
+//! Elitist multi-objective evolutionary algorithm based on non-dominated sorting and crowding
+//! distance. See [`NSGA2`] for details.
+//!
+//! The building blocks [`dominates`], [`non_dominated_sort`] and [`crowding_distance`] are
+//! exposed for use in other multi-objective solvers.
+//!
+//! ## Reference
+//!
+//! Kalyanmoy Deb, Amrit Pratap, Sameer Agarwal and T. Meyarivan (2002). A fast and elitist
+//! multiobjective genetic algorithm: NSGA-II. IEEE Transactions on Evolutionary Computation,
+//! 6(2), 182-197.
+
+mod variation;
+
+pub(crate) use variation::{polynomial_mutation, random_individual, simulated_binary_crossover};
+
+use crate::core::{
+    ArgminFloat, Error, MultiCostFunction, ParetoState, Problem, SerializeAlias, Solver, SyncAlias,
+    KV,
+};
+use rand::prelude::*;
+use rand_xoshiro::Xoshiro256PlusPlus;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// # NSGA-II
+///
+/// Approximates the Pareto front of a multi-objective problem with box constraints on the
+/// parameters.
+///
+/// In each iteration, an offspring population is created from the current population via binary
+/// tournament selection, simulated binary crossover (SBX) and polynomial mutation. Parents and
+/// offspring are then ranked by non-dominated sorting and the best individuals form the next
+/// population, where ties within a front are broken by preferring individuals with a larger
+/// crowding distance in order to maintain diversity.
+///
+/// The non-dominated members of the population are stored in the [`ParetoState`] and can be
+/// retrieved from the result via
+/// [`get_pareto_front`](`crate::core::ParetoState::get_pareto_front`) and
+/// [`get_pareto_front_costs`](`crate::core::ParetoState::get_pareto_front_costs`).
+///
+/// An initial population can be provided via
+/// [`ParetoState::population`](`crate::core::ParetoState::population`), otherwise it is sampled
+/// uniformly from within the bounds.
+///
+/// The algorithm has no convergence criterion on its own and stops when the maximum number of
+/// iterations is reached.
+///
+/// The `rayon` feature enables parallel computation of the objectives of the population.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`MultiCostFunction`].
+///
+/// ## Reference
+///
+/// Kalyanmoy Deb, Amrit Pratap, Sameer Agarwal and T. Meyarivan (2002). A fast and elitist
+/// multiobjective genetic algorithm: NSGA-II. IEEE Transactions on Evolutionary Computation,
+/// 6(2), 182-197.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct NSGA2<F, R> {
+    /// Bounds on parameter space
+    bounds: (Vec<F>, Vec<F>),
+    /// Number of individuals
+    population_size: usize,
+    /// Probability of performing crossover
+    crossover_probability: F,
+    /// Distribution index of simulated binary crossover
+    crossover_distribution_index: F,
+    /// Probability of mutating each element (defaults to `1/n` if `None`)
+    mutation_probability: Option<F>,
+    /// Distribution index of polynomial mutation
+    mutation_distribution_index: F,
+    /// Random number generator
+    rng: R,
+}
+
+impl<F> NSGA2<F, Xoshiro256PlusPlus>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`NSGA2`]
+    ///
+    /// Takes the bounds on the parameter space as a tuple `(lower_bound, upper_bound)` and the
+    /// number of individuals in the population, which must be at least 2.
+    ///
+    /// Uses the `Xoshiro256PlusPlus` RNG internally. For use of another RNG, consider using
+    /// [`NSGA2::new_with_rng`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::nsga2::NSGA2;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let nsga2 = NSGA2::new((vec![0.0f64, 0.0], vec![1.0, 1.0]), 100)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(bounds: (Vec<F>, Vec<F>), population_size: usize) -> Result<Self, Error> {
+        NSGA2::new_with_rng(bounds, population_size, Xoshiro256PlusPlus::from_entropy())
+    }
+}
+
+impl<F, R> NSGA2<F, R>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`NSGA2`]
+    ///
+    /// Takes the bounds on the parameter space as a tuple `(lower_bound, upper_bound)`, the
+    /// number of individuals in the population, which must be at least 2, and a RNG which must
+    /// implement `rand::Rng` (and `serde::Serialize` if the `serde1` feature is enabled).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::nsga2::NSGA2;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let my_rng = ();
+    /// let nsga2 = NSGA2::new_with_rng((vec![0.0f64, 0.0], vec![1.0, 1.0]), 100, my_rng)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_with_rng(
+        bounds: (Vec<F>, Vec<F>),
+        population_size: usize,
+        rng: R,
+    ) -> Result<Self, Error> {
+        if bounds.0.len() != bounds.1.len() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`NSGA2`: lower and upper bound must have the same length."
+            ));
+        }
+        if bounds.0.iter().zip(bounds.1.iter()).any(|(l, u)| l > u) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`NSGA2`: lower bound must be smaller than or equal to upper bound."
+            ));
+        }
+        if population_size < 2 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`NSGA2`: population size must be >= 2."
+            ));
+        }
+        Ok(NSGA2 {
+            bounds,
+            population_size,
+            crossover_probability: float!(0.9),
+            crossover_distribution_index: float!(20.0),
+            mutation_probability: None,
+            mutation_distribution_index: float!(20.0),
+            rng,
+        })
+    }
+
+    /// Set the probability of performing crossover of two parents
+    ///
+    /// Must be in `[0, 1]` and defaults to `0.9`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::nsga2::NSGA2;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let nsga2 = NSGA2::new((vec![0.0f64], vec![1.0]), 100)?.with_crossover_probability(0.8)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_crossover_probability(mut self, probability: F) -> Result<Self, Error> {
+        if probability < float!(0.0) || probability > float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`NSGA2`: crossover probability must be in [0, 1]."
+            ));
+        }
+        self.crossover_probability = probability;
+        Ok(self)
+    }
+
+    /// Set the distribution index of simulated binary crossover
+    ///
+    /// Larger values create offspring closer to the parents. Must be non-negative and defaults to
+    /// `20`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::nsga2::NSGA2;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let nsga2 =
+    ///     NSGA2::new((vec![0.0f64], vec![1.0]), 100)?.with_crossover_distribution_index(15.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_crossover_distribution_index(mut self, eta: F) -> Result<Self, Error> {
+        if eta < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`NSGA2`: crossover distribution index must be >= 0."
+            ));
+        }
+        self.crossover_distribution_index = eta;
+        Ok(self)
+    }
+
+    /// Set the probability of mutating an element of an offspring
+    ///
+    /// Must be in `[0, 1]` and defaults to `1/n`, where `n` is the number of parameters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::nsga2::NSGA2;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let nsga2 = NSGA2::new((vec![0.0f64], vec![1.0]), 100)?.with_mutation_probability(0.1)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_mutation_probability(mut self, probability: F) -> Result<Self, Error> {
+        if probability < float!(0.0) || probability > float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`NSGA2`: mutation probability must be in [0, 1]."
+            ));
+        }
+        self.mutation_probability = Some(probability);
+        Ok(self)
+    }
+
+    /// Set the distribution index of polynomial mutation
+    ///
+    /// Larger values create mutants closer to the original individual. Must be non-negative and
+    /// defaults to `20`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::nsga2::NSGA2;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let nsga2 =
+    ///     NSGA2::new((vec![0.0f64], vec![1.0]), 100)?.with_mutation_distribution_index(15.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_mutation_distribution_index(mut self, eta: F) -> Result<Self, Error> {
+        if eta < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`NSGA2`: mutation distribution index must be >= 0."
+            ));
+        }
+        self.mutation_distribution_index = eta;
+        Ok(self)
+    }
+}
+
+impl<F, R> NSGA2<F, R>
+where
+    F: ArgminFloat,
+    R: Rng,
+{
+    /// Binary tournament selection based on rank and crowding distance
+    fn tournament(&mut self, rank: &[usize], crowding: &[F]) -> usize {
+        let a = self.rng.gen_range(0..rank.len());
+        let b = self.rng.gen_range(0..rank.len());
+        if rank[b] < rank[a] || (rank[b] == rank[a] && crowding[b] > crowding[a]) {
+            b
+        } else {
+            a
+        }
+    }
+
+    /// Creates two offspring from two parents via crossover and mutation
+    fn offspring(&mut self, parent1: &[F], parent2: &[F]) -> (Vec<F>, Vec<F>) {
+        let (mut child1, mut child2) = simulated_binary_crossover(
+            &mut self.rng,
+            &self.bounds,
+            parent1,
+            parent2,
+            self.crossover_probability,
+            self.crossover_distribution_index,
+        );
+        for child in [&mut child1, &mut child2] {
+            polynomial_mutation(
+                &mut self.rng,
+                &self.bounds,
+                child,
+                self.mutation_probability,
+                self.mutation_distribution_index,
+            );
+        }
+        (child1, child2)
+    }
+}
+
+impl<O, F, R> Solver<O, ParetoState<Vec<F>, F>> for NSGA2<F, R>
+where
+    O: MultiCostFunction<Param = Vec<F>, Float = F> + SyncAlias,
+    F: ArgminFloat + SyncAlias,
+    R: Rng + SerializeAlias,
+{
+    const NAME: &'static str = "NSGA-II";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: ParetoState<Vec<F>, F>,
+    ) -> Result<(ParetoState<Vec<F>, F>, Option<KV>), Error> {
+        // Users can provide a population or it will be randomly created.
+        let population = match state.take_population() {
+            Some(population) if population.len() == self.population_size => population,
+            Some(population) => {
+                return Err(argmin_error!(
+                    InvalidParameter,
+                    format!(
+                        "`NSGA2`: Provided population is of size {}, expected {}",
+                        population.len(),
+                        self.population_size
+                    )
+                ))
+            }
+            None => (0..self.population_size)
+                .map(|_| random_individual(&mut self.rng, &self.bounds))
+                .collect(),
+        };
+        let costs = problem.bulk_multi_cost(&population)?;
+        let fronts = non_dominated_sort(&costs);
+        let front: Vec<Vec<F>> = fronts[0].iter().map(|&i| population[i].clone()).collect();
+        let front_costs: Vec<Vec<F>> = fronts[0].iter().map(|&i| costs[i].clone()).collect();
+        let front_size = front.len();
+
+        Ok((
+            state
+                .population(population, costs)
+                .pareto_front(front, front_costs),
+            Some(kv!("pareto_front_size" => front_size as u64;)),
+        ))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: ParetoState<Vec<F>, F>,
+    ) -> Result<(ParetoState<Vec<F>, F>, Option<KV>), Error> {
+        let mut population = state.take_population().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`NSGA2`: No population in state."
+        ))?;
+        let mut costs = state
+            .take_population_costs()
+            .ok_or_else(argmin_error_closure!(
+                PotentialBug,
+                "`NSGA2`: No population costs in state."
+            ))?;
+
+        // Rank and crowding distance of the current population for the tournament selection
+        let mut rank = vec![0; population.len()];
+        let mut crowding = vec![float!(0.0); population.len()];
+        for (r, front) in non_dominated_sort(&costs).iter().enumerate() {
+            for (&i, d) in front.iter().zip(crowding_distance(&costs, front)) {
+                rank[i] = r;
+                crowding[i] = d;
+            }
+        }
+
+        // Create offspring
+        let mut offspring = Vec::with_capacity(self.population_size);
+        while offspring.len() < self.population_size {
+            let p1 = self.tournament(&rank, &crowding);
+            let p2 = self.tournament(&rank, &crowding);
+            let (c1, c2) = self.offspring(&population[p1], &population[p2]);
+            offspring.push(c1);
+            if offspring.len() < self.population_size {
+                offspring.push(c2);
+            }
+        }
+        let offspring_costs = problem.bulk_multi_cost(&offspring)?;
+        population.extend(offspring);
+        costs.extend(offspring_costs);
+
+        // Select the next population from parents and offspring
+        let fronts = non_dominated_sort(&costs);
+        let mut selected: Vec<usize> = Vec::with_capacity(self.population_size);
+        for front in fronts.iter() {
+            if selected.len() + front.len() <= self.population_size {
+                selected.extend(front);
+            } else {
+                let distances = crowding_distance(&costs, front);
+                let mut order: Vec<usize> = (0..front.len()).collect();
+                order.sort_by(|&a, &b| {
+                    distances[b]
+                        .partial_cmp(&distances[a])
+                        .unwrap_or(Ordering::Equal)
+                });
+                let remaining = self.population_size - selected.len();
+                selected.extend(order.iter().take(remaining).map(|&i| front[i]));
+                break;
+            }
+        }
+
+        let mut population: Vec<Option<Vec<F>>> = population.into_iter().map(Some).collect();
+        let mut costs: Vec<Option<Vec<F>>> = costs.into_iter().map(Some).collect();
+        let new_population: Vec<Vec<F>> = selected
+            .iter()
+            .map(|&i| population[i].take().unwrap())
+            .collect();
+        let new_costs: Vec<Vec<F>> = selected.iter().map(|&i| costs[i].take().unwrap()).collect();
+
+        // Since individuals are selected front by front, the non-dominated ones come first.
+        let front_size = fronts[0].len().min(self.population_size);
+        let front = new_population[..front_size].to_vec();
+        let front_costs = new_costs[..front_size].to_vec();
+
+        Ok((
+            state
+                .population(new_population, new_costs)
+                .pareto_front(front, front_costs),
+            Some(kv!("pareto_front_size" => front_size as u64;)),
+        ))
+    }
+}
+
+/// Returns `true` if the objective values `a` Pareto-dominate the objective values `b`, i.e. if
+/// `a` is not worse than `b` in any objective and strictly better in at least one objective.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::solver::nsga2::dominates;
+/// assert!(dominates(&[1.0f64, 2.0], &[1.0, 3.0]));
+/// assert!(!dominates(&[1.0f64, 2.0], &[1.0, 2.0]));
+/// assert!(!dominates(&[1.0f64, 4.0], &[2.0, 3.0]));
+/// ```
+pub fn dominates<F: ArgminFloat>(a: &[F], b: &[F]) -> bool {
+    let mut strictly_better = false;
+    for (x, y) in a.iter().zip(b.iter()) {
+        if x > y {
+            return false;
+        }
+        if x < y {
+            strictly_better = true;
+        }
+    }
+    strictly_better
+}
+
+/// Fast non-dominated sorting
+///
+/// Partitions the indices of `costs` into fronts: The first front contains all non-dominated
+/// individuals, the second front contains all individuals which are only dominated by members of
+/// the first front, and so on.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::solver::nsga2::non_dominated_sort;
+/// let costs = vec![vec![1.0f64, 4.0], vec![2.0, 2.0], vec![3.0, 3.0], vec![4.0, 1.0]];
+/// assert_eq!(non_dominated_sort(&costs), vec![vec![0, 1, 3], vec![2]]);
+/// ```
+pub fn non_dominated_sort<F: ArgminFloat>(costs: &[Vec<F>]) -> Vec<Vec<usize>> {
+    let n = costs.len();
+    let mut dominated: Vec<Vec<usize>> = vec![vec![]; n];
+    let mut domination_count = vec![0usize; n];
+    for p in 0..n {
+        for q in (p + 1)..n {
+            if dominates(&costs[p], &costs[q]) {
+                dominated[p].push(q);
+                domination_count[q] += 1;
+            } else if dominates(&costs[q], &costs[p]) {
+                dominated[q].push(p);
+                domination_count[p] += 1;
+            }
+        }
+    }
+
+    let mut fronts: Vec<Vec<usize>> = vec![];
+    let mut current: Vec<usize> = (0..n).filter(|&p| domination_count[p] == 0).collect();
+    while !current.is_empty() {
+        let mut next = vec![];
+        for &p in current.iter() {
+            for &q in dominated[p].iter() {
+                domination_count[q] -= 1;
+                if domination_count[q] == 0 {
+                    next.push(q);
+                }
+            }
+        }
+        next.sort_unstable();
+        fronts.push(current);
+        current = next;
+    }
+    fronts
+}
+
+/// Crowding distance of the members of a front
+///
+/// `front` holds indices into `costs`. The returned distances are in the same order as `front`.
+/// The extreme individuals of each objective are assigned an infinite distance.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::solver::nsga2::crowding_distance;
+/// let costs = vec![vec![1.0f64, 4.0], vec![2.0, 2.0], vec![4.0, 1.0]];
+/// let distances = crowding_distance(&costs, &[0, 1, 2]);
+/// assert!(distances[0].is_infinite());
+/// assert_eq!(distances[1], 2.0);
+/// assert!(distances[2].is_infinite());
+/// ```
+pub fn crowding_distance<F: ArgminFloat>(costs: &[Vec<F>], front: &[usize]) -> Vec<F> {
+    let n = front.len();
+    let mut distances = vec![float!(0.0); n];
+    if n == 0 {
+        return distances;
+    }
+    // objective values of the members of the front, one objective at a time
+    let objectives =
+        (0..costs[front[0]].len()).map(|k| front.iter().map(|&i| costs[i][k]).collect::<Vec<F>>());
+    for values in objectives {
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&a, &b| values[a].partial_cmp(&values[b]).unwrap_or(Ordering::Equal));
+        distances[order[0]] = F::infinity();
+        distances[order[n - 1]] = F::infinity();
+        let range = values[order[n - 1]] - values[order[0]];
+        if range <= float!(0.0) {
+            continue;
+        }
+        for i in 1..(n - 1) {
+            distances[order[i]] =
+                distances[order[i]] + (values[order[i + 1]] - values[order[i - 1]]) / range;
+        }
+    }
+    distances
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Executor, State};
+    use crate::test_trait_impl;
+
+    test_trait_impl!(nsga2, NSGA2<f64, Xoshiro256PlusPlus>);
+
+    /// Schaffer function N. 1, Pareto optimal set is `[0, 2]`
+    struct Schaffer {}
+
+    impl MultiCostFunction for Schaffer {
+        type Param = Vec<f64>;
+        type Float = f64;
+
+        fn multi_cost(&self, p: &Self::Param) -> Result<Vec<Self::Float>, Error> {
+            Ok(vec![p[0].powi(2), (p[0] - 2.0).powi(2)])
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let nsga2: NSGA2<f64, _> = NSGA2::new((vec![-1.0, -2.0], vec![1.0, 2.0]), 10).unwrap();
+        let NSGA2 {
+            bounds,
+            population_size,
+            crossover_probability,
+            crossover_distribution_index,
+            mutation_probability,
+            mutation_distribution_index,
+            rng: _,
+        } = nsga2;
+        assert_eq!(bounds, (vec![-1.0, -2.0], vec![1.0, 2.0]));
+        assert_eq!(population_size, 10);
+        assert_eq!(crossover_probability.to_ne_bytes(), 0.9f64.to_ne_bytes());
+        assert_eq!(
+            crossover_distribution_index.to_ne_bytes(),
+            20.0f64.to_ne_bytes()
+        );
+        assert!(mutation_probability.is_none());
+        assert_eq!(
+            mutation_distribution_index.to_ne_bytes(),
+            20.0f64.to_ne_bytes()
+        );
+    }
+
+    #[test]
+    fn test_new_invalid() {
+        assert_error!(
+            NSGA2::new((vec![0.0f64], vec![1.0, 1.0]), 10),
+            ArgminError,
+            "Invalid parameter: \"`NSGA2`: lower and upper bound must have the same length.\""
+        );
+        assert_error!(
+            NSGA2::new((vec![0.0f64, 2.0], vec![1.0, 1.0]), 10),
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`NSGA2`: lower bound must be smaller than or equal to ",
+                "upper bound.\""
+            )
+        );
+        assert_error!(
+            NSGA2::new((vec![0.0f64], vec![1.0]), 1),
+            ArgminError,
+            "Invalid parameter: \"`NSGA2`: population size must be >= 2.\""
+        );
+    }
+
+    #[test]
+    fn test_with_probabilities() {
+        for p in [0.0, 0.5, 1.0] {
+            let nsga2 = NSGA2::new((vec![0.0f64], vec![1.0]), 10)
+                .unwrap()
+                .with_crossover_probability(p)
+                .unwrap()
+                .with_mutation_probability(p)
+                .unwrap();
+            assert_eq!(nsga2.crossover_probability.to_ne_bytes(), p.to_ne_bytes());
+            assert_eq!(
+                nsga2.mutation_probability.unwrap().to_ne_bytes(),
+                p.to_ne_bytes()
+            );
+        }
+
+        for p in [-f64::EPSILON, 1.0 + f64::EPSILON] {
+            let nsga2 = NSGA2::new((vec![0.0f64], vec![1.0]), 10).unwrap();
+            assert_error!(
+                nsga2.clone().with_crossover_probability(p),
+                ArgminError,
+                "Invalid parameter: \"`NSGA2`: crossover probability must be in [0, 1].\""
+            );
+            assert_error!(
+                nsga2.with_mutation_probability(p),
+                ArgminError,
+                "Invalid parameter: \"`NSGA2`: mutation probability must be in [0, 1].\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_distribution_indices() {
+        let nsga2 = NSGA2::new((vec![0.0f64], vec![1.0]), 10)
+            .unwrap()
+            .with_crossover_distribution_index(5.0)
+            .unwrap()
+            .with_mutation_distribution_index(7.0)
+            .unwrap();
+        assert_eq!(
+            nsga2.crossover_distribution_index.to_ne_bytes(),
+            5.0f64.to_ne_bytes()
+        );
+        assert_eq!(
+            nsga2.mutation_distribution_index.to_ne_bytes(),
+            7.0f64.to_ne_bytes()
+        );
+
+        let nsga2 = NSGA2::new((vec![0.0f64], vec![1.0]), 10).unwrap();
+        assert_error!(
+            nsga2.clone().with_crossover_distribution_index(-1.0),
+            ArgminError,
+            "Invalid parameter: \"`NSGA2`: crossover distribution index must be >= 0.\""
+        );
+        assert_error!(
+            nsga2.with_mutation_distribution_index(-1.0),
+            ArgminError,
+            "Invalid parameter: \"`NSGA2`: mutation distribution index must be >= 0.\""
+        );
+    }
+
+    #[test]
+    fn test_non_dominated_sort() {
+        let costs = vec![
+            vec![3.0f64, 3.0],
+            vec![1.0, 1.0],
+            vec![2.0, 2.0],
+            vec![0.5, 4.0],
+            vec![2.0, 2.0],
+        ];
+        assert_eq!(
+            non_dominated_sort(&costs),
+            vec![vec![1, 3], vec![2, 4], vec![0]]
+        );
+        assert!(non_dominated_sort::<f64>(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_crowding_distance() {
+        let costs = vec![
+            vec![0.0f64, 4.0],
+            vec![1.0, 3.0],
+            vec![3.0, 1.0],
+            vec![4.0, 0.0],
+        ];
+        let distances = crowding_distance(&costs, &[3, 1, 0, 2]);
+        assert!(distances[0].is_infinite());
+        assert_eq!(distances[1].to_ne_bytes(), 1.5f64.to_ne_bytes());
+        assert!(distances[2].is_infinite());
+        assert_eq!(distances[3].to_ne_bytes(), 1.5f64.to_ne_bytes());
+        assert!(crowding_distance::<f64>(&costs, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_population() {
+        let solver = NSGA2::new((vec![-10.0f64], vec![10.0]), 4).unwrap();
+        let res = Executor::new(Schaffer {}, solver)
+            .configure(|state| state.population(vec![vec![0.0]], vec![]))
+            .run();
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`NSGA2`: Provided population is of size 1, expected 4\""
+        );
+    }
+
+    #[test]
+    fn test_solver() {
+        let solver = NSGA2::new_with_rng(
+            (vec![-10.0f64], vec![10.0]),
+            20,
+            Xoshiro256PlusPlus::seed_from_u64(42),
+        )
+        .unwrap();
+        let res = Executor::new(Schaffer {}, solver)
+            .configure(|state| state.max_iters(100))
+            .run()
+            .unwrap();
+        let front = res.state.get_pareto_front().unwrap();
+        let front_costs = res.state.get_pareto_front_costs().unwrap();
+        assert_eq!(front.len(), 20);
+        assert_eq!(front_costs.len(), 20);
+        for (p, c) in front.iter().zip(front_costs.iter()) {
+            assert!(p[0] >= -1e-3 && p[0] <= 2.0 + 1e-3);
+            assert_eq!(*c, vec![p[0].powi(2), (p[0] - 2.0).powi(2)]);
+        }
+        for (i, a) in front_costs.iter().enumerate() {
+            for b in front_costs.iter().skip(i + 1) {
+                assert!(!dominates(a, b) && !dominates(b, a));
+            }
+        }
+        assert_eq!(res.state.get_param(), Some(&front[0]));
+        assert_eq!(res.problem.counts["multi_cost_count"], 20 * 101);
+    }
+}
@@ -0,0 +1,175 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Variation operators for real-valued individuals with box constraints, shared by the
+//! evolutionary multi-objective solvers.
+//!
+//! ## Reference
+//!
+//! Kalyanmoy Deb and Ram Bhushan Agrawal (1995). Simulated binary crossover for continuous search
+//! space. Complex Systems, 9(2), 115-148.
+
+use crate::core::ArgminFloat;
+use rand::Rng;
+
+/// Uniformly distributed random number in `[0, 1)`
+fn rand_float<F: ArgminFloat, R: Rng>(rng: &mut R) -> F {
+    float!(rng.gen::<f64>())
+}
+
+/// Samples an individual uniformly from within the bounds
+pub(crate) fn random_individual<F, R>(rng: &mut R, bounds: &(Vec<F>, Vec<F>)) -> Vec<F>
+where
+    F: ArgminFloat,
+    R: Rng,
+{
+    bounds
+        .0
+        .iter()
+        .zip(bounds.1.iter())
+        .map(|(&lower, &upper)| lower + rand_float::<F, R>(rng) * (upper - lower))
+        .collect()
+}
+
+/// Spread factor of simulated binary crossover for a given distance to the bound
+fn sbx_beta<F: ArgminFloat>(r: F, eta: F, dist: F, diff: F) -> F {
+    let beta = float!(1.0) + float!(2.0) * dist / diff;
+    let alpha = float!(2.0) - beta.powf(-eta);
+    if r <= float!(1.0) / alpha {
+        (r * alpha).powf(float!(1.0) / eta)
+    } else {
+        (float!(1.0) / (float!(2.0) - r * alpha)).powf(float!(1.0) / eta)
+    }
+}
+
+/// Simulated binary crossover respecting the bounds
+///
+/// Crossover is performed with the given `probability`, otherwise copies of the parents are
+/// returned. `distribution_index` controls how close the offspring are to their parents.
+pub(crate) fn simulated_binary_crossover<F, R>(
+    rng: &mut R,
+    bounds: &(Vec<F>, Vec<F>),
+    parent1: &[F],
+    parent2: &[F],
+    probability: F,
+    distribution_index: F,
+) -> (Vec<F>, Vec<F>)
+where
+    F: ArgminFloat,
+    R: Rng,
+{
+    let mut child1 = parent1.to_vec();
+    let mut child2 = parent2.to_vec();
+    if rand_float::<F, R>(rng) > probability {
+        return (child1, child2);
+    }
+    let eta = distribution_index + float!(1.0);
+    for i in 0..child1.len() {
+        if rand_float::<F, R>(rng) > float!(0.5) || (parent1[i] - parent2[i]).abs() <= F::epsilon()
+        {
+            continue;
+        }
+        let (lower, upper) = (bounds.0[i], bounds.1[i]);
+        let y1 = parent1[i].min(parent2[i]);
+        let y2 = parent1[i].max(parent2[i]);
+        let r = rand_float(rng);
+        let beta1 = sbx_beta(r, eta, y1 - lower, y2 - y1);
+        let beta2 = sbx_beta(r, eta, upper - y2, y2 - y1);
+        let c1 = (float!(0.5) * (y1 + y2 - beta1 * (y2 - y1)))
+            .max(lower)
+            .min(upper);
+        let c2 = (float!(0.5) * (y1 + y2 + beta2 * (y2 - y1)))
+            .max(lower)
+            .min(upper);
+        if rand_float::<F, R>(rng) <= float!(0.5) {
+            child1[i] = c2;
+            child2[i] = c1;
+        } else {
+            child1[i] = c1;
+            child2[i] = c2;
+        }
+    }
+    (child1, child2)
+}
+
+/// Polynomial mutation respecting the bounds
+///
+/// Each element is mutated with the given `probability`, which defaults to `1/n` if `None`.
+/// `distribution_index` controls how close the mutant is to the original individual.
+pub(crate) fn polynomial_mutation<F, R>(
+    rng: &mut R,
+    bounds: &(Vec<F>, Vec<F>),
+    individual: &mut [F],
+    probability: Option<F>,
+    distribution_index: F,
+) where
+    F: ArgminFloat,
+    R: Rng,
+{
+    let probability = probability
+        .unwrap_or_else(|| float!(1.0) / F::from_usize(individual.len().max(1)).unwrap());
+    let eta = distribution_index + float!(1.0);
+    for (i, x) in individual.iter_mut().enumerate() {
+        if rand_float::<F, R>(rng) >= probability {
+            continue;
+        }
+        let (lower, upper) = (bounds.0[i], bounds.1[i]);
+        let range = upper - lower;
+        if range <= float!(0.0) {
+            continue;
+        }
+        let r = rand_float(rng);
+        let deltaq = if r < float!(0.5) {
+            let xy = float!(1.0) - (*x - lower) / range;
+            let val = float!(2.0) * r + (float!(1.0) - float!(2.0) * r) * xy.powf(eta);
+            val.powf(float!(1.0) / eta) - float!(1.0)
+        } else {
+            let xy = float!(1.0) - (upper - *x) / range;
+            let val =
+                float!(2.0) * (float!(1.0) - r) + float!(2.0) * (r - float!(0.5)) * xy.powf(eta);
+            float!(1.0) - val.powf(float!(1.0) / eta)
+        };
+        *x = (*x + deltaq * range).max(lower).min(upper);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_xoshiro::Xoshiro256PlusPlus;
+
+    #[test]
+    fn test_variation_respects_bounds() {
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(1);
+        let bounds = (vec![-1.0f64, 0.0, 2.0], vec![1.0, 0.0, 5.0]);
+        for _ in 0..1000 {
+            let p1 = random_individual(&mut rng, &bounds);
+            let p2 = random_individual(&mut rng, &bounds);
+            let (mut c1, mut c2) =
+                simulated_binary_crossover(&mut rng, &bounds, &p1, &p2, 1.0, 2.0);
+            polynomial_mutation(&mut rng, &bounds, &mut c1, Some(1.0), 2.0);
+            polynomial_mutation(&mut rng, &bounds, &mut c2, None, 2.0);
+            for x in [p1, p2, c1, c2] {
+                for ((xi, lower), upper) in x.iter().zip(bounds.0.iter()).zip(bounds.1.iter()) {
+                    assert!(xi >= lower && xi <= upper);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_no_variation() {
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(1);
+        let bounds = (vec![-1.0f64, -1.0], vec![1.0, 1.0]);
+        let (p1, p2) = (vec![0.5, -0.5], vec![-0.2, 0.3]);
+        let (mut c1, c2) = simulated_binary_crossover(&mut rng, &bounds, &p1, &p2, 0.0, 20.0);
+        assert_eq!((&c1, &c2), (&p1, &p2));
+        polynomial_mutation(&mut rng, &bounds, &mut c1, Some(0.0), 20.0);
+        assert_eq!(c1, p1);
+    }
+}
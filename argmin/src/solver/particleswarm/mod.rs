@@ -21,10 +21,12 @@
 //! \[1\] <https://en.wikipedia.org/wiki/Particle_swarm_optimization>
 
 use crate::core::{
-    ArgminFloat, CostFunction, Error, PopulationState, Problem, SerializeAlias, Solver, SyncAlias,
-    KV,
+    ArgminFloat, Bounds, CostFunction, Error, PopulationState, Problem, SerializeAlias, Solver,
+    SyncAlias, KV,
 };
 use argmin_math::{ArgminAdd, ArgminMinMax, ArgminMul, ArgminRandom, ArgminSub, ArgminZeroLike};
+use rand::{Rng, SeedableRng};
+use rand_xoshiro::Xoshiro256PlusPlus;
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 
@@ -33,6 +35,14 @@ use serde::{Deserialize, Serialize};
 /// Canonical implementation of the particle swarm optimization method as outlined in \[0\] in
 /// chapter II, section A.
 ///
+/// By default, all particles are attracted by the best position found by the whole swarm
+/// (global-best topology). As this tends to stagnate on multimodal problems, local-best
+/// topologies in which particles only share information with their neighbors can be chosen via
+/// [`with_topology`](`ParticleSwarm::with_topology`) (see [`Topology`]). Additionally, the
+/// constriction coefficient variant of the velocity update can be enabled with
+/// [`with_constriction`](`ParticleSwarm::with_constriction`) and the velocity can be clamped via
+/// [`with_velocity_clamping`](`ParticleSwarm::with_velocity_clamping`).
+///
 /// The `rayon` feature enables parallel computation of the cost function. This can be beneficial
 /// for expensive cost functions, but may cause a drop in performance for cheap cost functions. Be
 /// sure to benchmark both parallel and sequential computation.
@@ -48,9 +58,17 @@ use serde::{Deserialize, Serialize};
 /// Computation. <https://doi.org/10.1109/CEC.2013.6557848>
 ///
 /// \[1\] <https://en.wikipedia.org/wiki/Particle_swarm_optimization>
+///
+/// \[2\] Clerc, M. and Kennedy, J. (2002): The particle swarm - explosion, stability, and
+/// convergence in a multidimensional complex space. IEEE Transactions on Evolutionary
+/// Computation, 6(1), 58-73. <https://doi.org/10.1109/4235.985692>
+///
+/// \[3\] Kennedy, J. and Mendes, R. (2002): Population structure and particle swarm
+/// performance. Proceedings of the 2002 Congress on Evolutionary Computation, 1671-1676.
+/// <https://doi.org/10.1109/CEC.2002.1004493>
 #[derive(Clone)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
-pub struct ParticleSwarm<P, F> {
+pub struct ParticleSwarm<P, F, R = Xoshiro256PlusPlus> {
     /// Inertia weight
     weight_inertia: F,
     /// Cognitive acceleration coefficient
@@ -58,21 +76,66 @@ pub struct ParticleSwarm<P, F> {
     /// Social acceleration coefficient
     weight_social: F,
     /// Bounds on parameter space
-    bounds: (P, P),
+    bounds: Bounds<P>,
     /// Number of particles
     num_particles: usize,
+    /// Neighborhood topology
+    topology: Topology,
+    /// Maximum velocity as a fraction of the extent of the search space
+    velocity_clamp: Option<F>,
+    /// Random number generator
+    rng: R,
+}
+
+/// Neighborhood topology of [`ParticleSwarm`]
+///
+/// Defines which particles share their best positions with each other. Each particle is attracted
+/// by the best position found within its neighborhood. Particles are arranged by their index in
+/// the population.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub enum Topology {
+    /// All particles are neighbors of each other (global best)
+    #[default]
+    Global,
+    /// Each particle is a neighbor of the particles right before and after it (local best)
+    Ring,
+    /// Particles are arranged on a two-dimensional grid with wrap-around and each particle is a
+    /// neighbor of the particles above, below, left and right of it
+    VonNeumann,
 }
 
-impl<P, F> ParticleSwarm<P, F>
+impl Topology {
+    /// Indices of the neighbors of particle `i` (including `i` itself) in a swarm of `n`
+    /// particles. Returns `None` for the global topology.
+    fn neighbors(&self, i: usize, n: usize) -> Option<Vec<usize>> {
+        match self {
+            Topology::Global => None,
+            Topology::Ring => Some(vec![(i + n - 1) % n, i, (i + 1) % n]),
+            Topology::VonNeumann => {
+                let cols = (n as f64).sqrt().ceil() as usize;
+                Some(vec![
+                    (i + n - cols % n) % n,
+                    (i + n - 1) % n,
+                    i,
+                    (i + 1) % n,
+                    (i + cols) % n,
+                ])
+            }
+        }
+    }
+}
+
+impl<P, F> ParticleSwarm<P, F, Xoshiro256PlusPlus>
 where
     P: Clone + SyncAlias + ArgminSub<P, P> + ArgminMul<F, P> + ArgminRandom + ArgminZeroLike,
     F: ArgminFloat,
 {
     /// Construct a new instance of `ParticleSwarm`
     ///
-    /// Takes the number of particles and bounds on the search space as inputs. `bounds` is a tuple
-    /// `(lower_bound, upper_bound)`, where `lower_bound` and `upper_bound` are of the same type as
-    /// the position of a particle (`P`) and of the same length as the problem as dimensions.
+    /// Takes the [`Bounds`] on the search space and the number of particles as inputs. The lower
+    /// and upper bound are of the same type as the position of a particle (`P`) and of the same
+    /// length as the problem has dimensions.
     ///
     /// The inertia weight on velocity and the social and cognitive acceleration factors can be
     /// adapted with [`with_inertia_factor`](`ParticleSwarm::with_inertia_factor`),
@@ -89,17 +152,54 @@ where
     ///
     /// ```
     /// # use argmin::solver::particleswarm::ParticleSwarm;
-    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
-    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
-    /// let pso: ParticleSwarm<_, f64> = ParticleSwarm::new((lower_bound, upper_bound), 40);
+    /// # use argmin::core::{Bounds, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let bounds = Bounds::new(vec![-1.0f64, -1.0], vec![1.0, 1.0])?;
+    /// let pso: ParticleSwarm<_, f64> = ParticleSwarm::new(bounds, 40);
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn new(bounds: (P, P), num_particles: usize) -> Self {
+    pub fn new(bounds: Bounds<P>, num_particles: usize) -> Self {
+        ParticleSwarm::new_with_rng(bounds, num_particles, Xoshiro256PlusPlus::from_entropy())
+    }
+}
+
+impl<P, F, R> ParticleSwarm<P, F, R>
+where
+    P: Clone + SyncAlias + ArgminSub<P, P> + ArgminMul<F, P> + ArgminRandom + ArgminZeroLike,
+    F: ArgminFloat,
+    R: Rng,
+{
+    /// Construct a new instance of `ParticleSwarm` which draws its random numbers from `rng`
+    ///
+    /// Same as [`ParticleSwarm::new`], but takes a RNG which must implement `rand::Rng` (and
+    /// `serde::Serialize` if the `serde1` feature is enabled). A seeded RNG makes runs
+    /// reproducible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::particleswarm::ParticleSwarm;
+    /// # use argmin::core::{Bounds, Error};
+    /// # use rand::SeedableRng;
+    /// # use rand_xoshiro::Xoshiro256PlusPlus;
+    /// # fn main() -> Result<(), Error> {
+    /// let bounds = Bounds::new(vec![-1.0f64, -1.0], vec![1.0, 1.0])?;
+    /// let rng = Xoshiro256PlusPlus::seed_from_u64(42);
+    /// let pso: ParticleSwarm<_, f64, _> = ParticleSwarm::new_with_rng(bounds, 40, rng);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_with_rng(bounds: Bounds<P>, num_particles: usize, rng: R) -> Self {
         ParticleSwarm {
             weight_inertia: float!(1.0f64 / (2.0 * 2.0f64.ln())),
             weight_cognitive: float!(0.5 + 2.0f64.ln()),
             weight_social: float!(0.5 + 2.0f64.ln()),
             bounds,
             num_particles,
+            topology: Topology::Global,
+            velocity_clamp: None,
+            rng,
         }
     }
 
@@ -111,12 +211,12 @@ where
     ///
     /// ```
     /// # use argmin::solver::particleswarm::ParticleSwarm;
-    /// # use argmin::core::Error;
+    /// # use argmin::core::{Bounds, Error};
     /// # fn main() -> Result<(), Error> {
     /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
     /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
     /// let pso: ParticleSwarm<_, f64> =
-    ///     ParticleSwarm::new((lower_bound, upper_bound), 40).with_inertia_factor(0.5)?;
+    ///     ParticleSwarm::new(Bounds::new(lower_bound, upper_bound)?, 40).with_inertia_factor(0.5)?;
     /// # Ok(())
     /// # }
     /// ```
@@ -139,12 +239,12 @@ where
     ///
     /// ```
     /// # use argmin::solver::particleswarm::ParticleSwarm;
-    /// # use argmin::core::Error;
+    /// # use argmin::core::{Bounds, Error};
     /// # fn main() -> Result<(), Error> {
     /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
     /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
     /// let pso: ParticleSwarm<_, f64> =
-    ///     ParticleSwarm::new((lower_bound, upper_bound), 40).with_cognitive_factor(1.1)?;
+    ///     ParticleSwarm::new(Bounds::new(lower_bound, upper_bound)?, 40).with_cognitive_factor(1.1)?;
     /// # Ok(())
     /// # }
     /// ```
@@ -167,12 +267,12 @@ where
     ///
     /// ```
     /// # use argmin::solver::particleswarm::ParticleSwarm;
-    /// # use argmin::core::Error;
+    /// # use argmin::core::{Bounds, Error};
     /// # fn main() -> Result<(), Error> {
     /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
     /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
     /// let pso: ParticleSwarm<_, f64> =
-    ///     ParticleSwarm::new((lower_bound, upper_bound), 40).with_social_factor(1.1)?;
+    ///     ParticleSwarm::new(Bounds::new(lower_bound, upper_bound)?, 40).with_social_factor(1.1)?;
     /// # Ok(())
     /// # }
     /// ```
@@ -187,6 +287,98 @@ where
         Ok(self)
     }
 
+    /// Set the neighborhood topology
+    ///
+    /// Defaults to [`Topology::Global`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::particleswarm::{ParticleSwarm, Topology};
+    /// # use argmin::core::{Bounds, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
+    /// let pso: ParticleSwarm<_, f64> =
+    ///     ParticleSwarm::new(Bounds::new(lower_bound, upper_bound)?, 40).with_topology(Topology::Ring);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn with_topology(mut self, topology: Topology) -> Self {
+        self.topology = topology;
+        self
+    }
+
+    /// Use the constriction coefficient variant of the velocity update
+    ///
+    /// Takes the cognitive and social acceleration coefficients `phi_1` and `phi_2`, whose sum
+    /// `phi` must be larger than 4. The velocity update is scaled by the constriction coefficient
+    ///
+    /// `chi = 2 / |2 - phi - sqrt(phi^2 - 4 * phi)|`,
+    ///
+    /// which guarantees convergence of the swarm without the need for velocity clamping \[2\].
+    /// This replaces the inertia, cognitive and social factors by `chi`, `chi * phi_1` and
+    /// `chi * phi_2`, respectively. The common choice is `phi_1 = phi_2 = 2.05`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::particleswarm::ParticleSwarm;
+    /// # use argmin::core::{Bounds, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
+    /// let pso: ParticleSwarm<_, f64> =
+    ///     ParticleSwarm::new(Bounds::new(lower_bound, upper_bound)?, 40).with_constriction(2.05, 2.05)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_constriction(mut self, phi_cognitive: F, phi_social: F) -> Result<Self, Error> {
+        let phi = phi_cognitive + phi_social;
+        if phi_cognitive < float!(0.0) || phi_social < float!(0.0) || phi <= float!(4.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ParticleSwarm`: acceleration coefficients must be >=0 and sum up to >4."
+            ));
+        }
+        let chi = float!(2.0) / (float!(2.0) - phi - (phi * phi - float!(4.0) * phi).sqrt()).abs();
+        self.weight_inertia = chi;
+        self.weight_cognitive = chi * phi_cognitive;
+        self.weight_social = chi * phi_social;
+        Ok(self)
+    }
+
+    /// Limit the velocity of the particles
+    ///
+    /// In each dimension, the velocity is clamped to `fraction` times the extent of the search
+    /// space (`upper_bound - lower_bound`). `fraction` must be larger than 0. By default, the
+    /// velocity is not clamped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::particleswarm::ParticleSwarm;
+    /// # use argmin::core::{Bounds, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// # let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+    /// # let upper_bound: Vec<f64> = vec![1.0, 1.0];
+    /// let pso: ParticleSwarm<_, f64> =
+    ///     ParticleSwarm::new(Bounds::new(lower_bound, upper_bound)?, 40).with_velocity_clamping(0.2)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_velocity_clamping(mut self, fraction: F) -> Result<Self, Error> {
+        if fraction <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ParticleSwarm`: velocity clamping fraction must be >0."
+            ));
+        }
+        self.velocity_clamp = Some(fraction);
+        Ok(self)
+    }
+
     /// Initializes all particles randomly and sorts them by their cost function values
     fn initialize_particles<O: CostFunction<Param = P, Output = F> + SyncAlias>(
         &mut self,
@@ -214,23 +406,23 @@ where
     }
 
     /// Initializes positions and velocities for all particles
-    fn initialize_positions_and_velocities(&self) -> (Vec<P>, Vec<P>) {
-        let (min, max) = &self.bounds;
+    fn initialize_positions_and_velocities(&mut self) -> (Vec<P>, Vec<P>) {
+        let (min, max) = (self.bounds.lower(), self.bounds.upper());
         let delta = max.sub(min);
         let delta_neg = delta.mul(&float!(-1.0));
 
         (
             (0..self.num_particles)
-                .map(|_| P::rand_from_range(min, max))
+                .map(|_| P::rand_from_range(min, max, &mut self.rng))
                 .collect(),
             (0..self.num_particles)
-                .map(|_| P::rand_from_range(&delta_neg, &delta))
+                .map(|_| P::rand_from_range(&delta_neg, &delta, &mut self.rng))
                 .collect(),
         )
     }
 }
 
-impl<O, P, F> Solver<O, PopulationState<Particle<P, F>, F>> for ParticleSwarm<P, F>
+impl<O, P, F, R> Solver<O, PopulationState<Particle<P, F>, F>> for ParticleSwarm<P, F, R>
 where
     O: CostFunction<Param = P, Output = F> + SyncAlias,
     P: SerializeAlias
@@ -243,6 +435,7 @@ where
         + ArgminRandom
         + ArgminMinMax,
     F: ArgminFloat,
+    R: Rng + SerializeAlias,
 {
     const NAME: &'static str = "Particle Swarm Optimization";
 
@@ -302,32 +495,69 @@ where
 
         let zero = P::zero_like(&best_particle.position);
 
+        // Best positions within the neighborhood of each particle (for local-best topologies)
+        let num_particles = particles.len();
+        let neighborhood_best: Option<Vec<P>> = (0..num_particles)
+            .map(|i| {
+                self.topology.neighbors(i, num_particles).map(|neighbors| {
+                    let best = neighbors
+                        .into_iter()
+                        .min_by(|&a, &b| {
+                            particles[a]
+                                .best_cost
+                                .partial_cmp(&particles[b].best_cost)
+                                .unwrap_or(std::cmp::Ordering::Equal)
+                        })
+                        .unwrap();
+                    particles[best].best_position.clone()
+                })
+            })
+            .collect();
+
+        let velocity_limits = self.velocity_clamp.map(|fraction| {
+            let v_max = self.bounds.upper().sub(self.bounds.lower()).mul(&fraction);
+            let v_min = v_max.mul(&float!(-1.0));
+            (v_min, v_max)
+        });
+
         let positions: Vec<_> = particles
             .iter_mut()
-            .map(|p| {
+            .enumerate()
+            .map(|(i, p)| {
                 // New velocity is composed of
                 // 1) previous velocity (momentum),
                 // 2) motion toward particle optimum and
-                // 3) motion toward global optimum.
+                // 3) motion toward global (or neighborhood) optimum.
 
                 // ad 1)
                 let momentum = p.velocity.mul(&self.weight_inertia);
 
                 // ad 2)
                 let to_optimum = p.best_position.sub(&p.position);
-                let pull_to_optimum = P::rand_from_range(&zero, &to_optimum);
+                let pull_to_optimum = P::rand_from_range(&zero, &to_optimum, &mut self.rng);
                 let pull_to_optimum = pull_to_optimum.mul(&self.weight_cognitive);
 
                 // ad 3)
-                let to_global_optimum = best_particle.position.sub(&p.position);
+                let social_optimum = match &neighborhood_best {
+                    Some(neighborhood_best) => &neighborhood_best[i],
+                    None => &best_particle.position,
+                };
+                let to_global_optimum = social_optimum.sub(&p.position);
                 let pull_to_global_optimum =
-                    P::rand_from_range(&zero, &to_global_optimum).mul(&self.weight_social);
+                    P::rand_from_range(&zero, &to_global_optimum, &mut self.rng)
+                        .mul(&self.weight_social);
 
                 p.velocity = momentum.add(&pull_to_optimum).add(&pull_to_global_optimum);
+                if let Some((v_min, v_max)) = &velocity_limits {
+                    p.velocity = P::min(&P::max(&p.velocity, v_min), v_max);
+                }
                 let new_position = p.position.add(&p.velocity);
 
                 // Limit to search window
-                p.position = P::min(&P::max(&new_position, &self.bounds.0), &self.bounds.1);
+                p.position = P::min(
+                    &P::max(&new_position, self.bounds.lower()),
+                    self.bounds.upper(),
+                );
                 &p.position
             })
             .collect();
@@ -414,14 +644,19 @@ mod tests {
     fn test_new() {
         let lower_bound: Vec<f64> = vec![-1.0, -1.0];
         let upper_bound: Vec<f64> = vec![1.0, 1.0];
-        let pso: ParticleSwarm<_, f64> =
-            ParticleSwarm::new((lower_bound.clone(), upper_bound.clone()), 40);
+        let pso: ParticleSwarm<_, f64> = ParticleSwarm::new(
+            Bounds::new(lower_bound.clone(), upper_bound.clone()).unwrap(),
+            40,
+        );
         let ParticleSwarm {
             weight_inertia,
             weight_cognitive,
             weight_social,
             bounds,
             num_particles,
+            topology,
+            velocity_clamp,
+            rng: _,
         } = pso;
 
         assert_relative_eq!(
@@ -439,11 +674,130 @@ mod tests {
             (0.5f64 + 2.0f64.ln()),
             epsilon = f64::EPSILON
         );
-        assert_eq!(lower_bound[0].to_ne_bytes(), bounds.0[0].to_ne_bytes());
-        assert_eq!(lower_bound[1].to_ne_bytes(), bounds.0[1].to_ne_bytes());
-        assert_eq!(upper_bound[0].to_ne_bytes(), bounds.1[0].to_ne_bytes());
-        assert_eq!(upper_bound[1].to_ne_bytes(), bounds.1[1].to_ne_bytes());
+        assert_eq!(
+            lower_bound[0].to_ne_bytes(),
+            bounds.lower()[0].to_ne_bytes()
+        );
+        assert_eq!(
+            lower_bound[1].to_ne_bytes(),
+            bounds.lower()[1].to_ne_bytes()
+        );
+        assert_eq!(
+            upper_bound[0].to_ne_bytes(),
+            bounds.upper()[0].to_ne_bytes()
+        );
+        assert_eq!(
+            upper_bound[1].to_ne_bytes(),
+            bounds.upper()[1].to_ne_bytes()
+        );
         assert_eq!(num_particles, 40);
+        assert_eq!(topology, Topology::Global);
+        assert!(velocity_clamp.is_none());
+    }
+
+    #[test]
+    fn test_with_topology() {
+        let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+        let upper_bound: Vec<f64> = vec![1.0, 1.0];
+
+        for topology in [Topology::Global, Topology::Ring, Topology::VonNeumann] {
+            let pso: ParticleSwarm<_, f64> = ParticleSwarm::new(
+                Bounds::new(lower_bound.clone(), upper_bound.clone()).unwrap(),
+                40,
+            )
+            .with_topology(topology);
+            assert_eq!(pso.topology, topology);
+        }
+    }
+
+    #[test]
+    fn test_topology_neighbors() {
+        assert_eq!(Topology::Global.neighbors(3, 10), None);
+        assert_eq!(Topology::Ring.neighbors(0, 10), Some(vec![9, 0, 1]));
+        assert_eq!(Topology::Ring.neighbors(9, 10), Some(vec![8, 9, 0]));
+        assert_eq!(
+            Topology::VonNeumann.neighbors(0, 9),
+            Some(vec![6, 8, 0, 1, 3])
+        );
+        assert_eq!(
+            Topology::VonNeumann.neighbors(4, 10),
+            Some(vec![0, 3, 4, 5, 8])
+        );
+    }
+
+    #[test]
+    fn test_with_constriction() {
+        let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+        let upper_bound: Vec<f64> = vec![1.0, 1.0];
+
+        let pso: ParticleSwarm<_, f64> = ParticleSwarm::new(
+            Bounds::new(lower_bound.clone(), upper_bound.clone()).unwrap(),
+            40,
+        )
+        .with_constriction(2.05, 2.05)
+        .unwrap();
+        assert_relative_eq!(pso.weight_inertia, 0.7298437881283576, epsilon = 1e-12);
+        assert_relative_eq!(
+            pso.weight_cognitive,
+            0.7298437881283576 * 2.05,
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(
+            pso.weight_social,
+            0.7298437881283576 * 2.05,
+            epsilon = 1e-12
+        );
+
+        for (c1, c2) in [(2.0, 2.0), (1.0, 1.0), (-1.0, 6.0), (6.0, -1.0)] {
+            let res = ParticleSwarm::new(
+                Bounds::new(lower_bound.clone(), upper_bound.clone()).unwrap(),
+                40,
+            )
+            .with_constriction(c1, c2);
+            assert_error!(
+                res,
+                ArgminError,
+                concat!(
+                    "Invalid parameter: \"`ParticleSwarm`: ",
+                    "acceleration coefficients must be >=0 and sum up to >4.\""
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_velocity_clamping() {
+        let lower_bound: Vec<f64> = vec![-1.0, -1.0];
+        let upper_bound: Vec<f64> = vec![1.0, 1.0];
+
+        for fraction in [f64::EPSILON, 0.2, 1.0, 2.0] {
+            let pso: ParticleSwarm<_, f64> = ParticleSwarm::new(
+                Bounds::new(lower_bound.clone(), upper_bound.clone()).unwrap(),
+                40,
+            )
+            .with_velocity_clamping(fraction)
+            .unwrap();
+            assert_eq!(
+                pso.velocity_clamp.unwrap().to_ne_bytes(),
+                fraction.to_ne_bytes()
+            );
+        }
+
+        for fraction in [0.0, -f64::EPSILON, -1.0] {
+            let res = ParticleSwarm::new(
+                Bounds::new(lower_bound.clone(), upper_bound.clone()).unwrap(),
+                40,
+            )
+            .with_velocity_clamping(fraction);
+            assert_error!(
+                res,
+                ArgminError,
+                concat!(
+                    "Invalid parameter: \"`ParticleSwarm`: ",
+                    "velocity clamping fraction must be >0.\""
+                )
+            );
+        }
     }
 
     #[test]
@@ -452,8 +806,11 @@ mod tests {
         let upper_bound: Vec<f64> = vec![1.0, 1.0];
 
         for inertia in [0.0, f64::EPSILON, 0.5, 1.0, 1.2, 3.0] {
-            let res = ParticleSwarm::new((lower_bound.clone(), upper_bound.clone()), 40)
-                .with_inertia_factor(inertia);
+            let res = ParticleSwarm::new(
+                Bounds::new(lower_bound.clone(), upper_bound.clone()).unwrap(),
+                40,
+            )
+            .with_inertia_factor(inertia);
             assert!(res.is_ok());
             assert_eq!(
                 res.unwrap().weight_inertia.to_ne_bytes(),
@@ -462,8 +819,11 @@ mod tests {
         }
 
         for inertia in [-f64::EPSILON, -0.5, -1.0, -1.2, -3.0] {
-            let res = ParticleSwarm::new((lower_bound.clone(), upper_bound.clone()), 40)
-                .with_inertia_factor(inertia);
+            let res = ParticleSwarm::new(
+                Bounds::new(lower_bound.clone(), upper_bound.clone()).unwrap(),
+                40,
+            )
+            .with_inertia_factor(inertia);
             assert_error!(
                 res,
                 ArgminError,
@@ -481,8 +841,11 @@ mod tests {
         let upper_bound: Vec<f64> = vec![1.0, 1.0];
 
         for cognitive in [0.0, f64::EPSILON, 0.5, 1.0, 1.2, 3.0] {
-            let res = ParticleSwarm::new((lower_bound.clone(), upper_bound.clone()), 40)
-                .with_cognitive_factor(cognitive);
+            let res = ParticleSwarm::new(
+                Bounds::new(lower_bound.clone(), upper_bound.clone()).unwrap(),
+                40,
+            )
+            .with_cognitive_factor(cognitive);
             assert!(res.is_ok());
             assert_eq!(
                 res.unwrap().weight_cognitive.to_ne_bytes(),
@@ -491,8 +854,11 @@ mod tests {
         }
 
         for cognitive in [-f64::EPSILON, -0.5, -1.0, -1.2, -3.0] {
-            let res = ParticleSwarm::new((lower_bound.clone(), upper_bound.clone()), 40)
-                .with_cognitive_factor(cognitive);
+            let res = ParticleSwarm::new(
+                Bounds::new(lower_bound.clone(), upper_bound.clone()).unwrap(),
+                40,
+            )
+            .with_cognitive_factor(cognitive);
             assert_error!(
                 res,
                 ArgminError,
@@ -510,8 +876,11 @@ mod tests {
         let upper_bound: Vec<f64> = vec![1.0, 1.0];
 
         for social in [0.0, f64::EPSILON, 0.5, 1.0, 1.2, 3.0] {
-            let res = ParticleSwarm::new((lower_bound.clone(), upper_bound.clone()), 40)
-                .with_social_factor(social);
+            let res = ParticleSwarm::new(
+                Bounds::new(lower_bound.clone(), upper_bound.clone()).unwrap(),
+                40,
+            )
+            .with_social_factor(social);
             assert!(res.is_ok());
             assert_eq!(
                 res.unwrap().weight_social.to_ne_bytes(),
@@ -520,8 +889,11 @@ mod tests {
         }
 
         for social in [-f64::EPSILON, -0.5, -1.0, -1.2, -3.0] {
-            let res = ParticleSwarm::new((lower_bound.clone(), upper_bound.clone()), 40)
-                .with_social_factor(social);
+            let res = ParticleSwarm::new(
+                Bounds::new(lower_bound.clone(), upper_bound.clone()).unwrap(),
+                40,
+            )
+            .with_social_factor(social);
             assert_error!(
                 res,
                 ArgminError,
@@ -538,8 +910,10 @@ mod tests {
         let lower_bound: Vec<f64> = vec![-1.0, -1.0];
         let upper_bound: Vec<f64> = vec![1.0, 1.0];
         let num_particles = 100;
-        let pso: ParticleSwarm<_, f64> =
-            ParticleSwarm::new((lower_bound, upper_bound), num_particles);
+        let mut pso: ParticleSwarm<_, f64> = ParticleSwarm::new(
+            Bounds::new(lower_bound, upper_bound).unwrap(),
+            num_particles,
+        );
 
         let (positions, velocities) = pso.initialize_positions_and_velocities();
         assert_eq!(positions.len(), num_particles);
@@ -565,8 +939,10 @@ mod tests {
         let lower_bound: Vec<f64> = vec![-1.0, -1.0];
         let upper_bound: Vec<f64> = vec![1.0, 1.0];
         let num_particles = 10;
-        let mut pso: ParticleSwarm<_, f64> =
-            ParticleSwarm::new((lower_bound, upper_bound), num_particles);
+        let mut pso: ParticleSwarm<_, f64> = ParticleSwarm::new(
+            Bounds::new(lower_bound, upper_bound).unwrap(),
+            num_particles,
+        );
 
         struct PsoProblem {
             counter: std::sync::Arc<std::sync::Mutex<usize>>,
@@ -630,7 +1006,8 @@ mod tests {
     fn test_init_provided_population_wrong_size() {
         let lower_bound: Vec<f64> = vec![-1.0, -1.0];
         let upper_bound: Vec<f64> = vec![1.0, 1.0];
-        let mut pso: ParticleSwarm<_, f64> = ParticleSwarm::new((lower_bound, upper_bound), 40);
+        let mut pso: ParticleSwarm<_, f64> =
+            ParticleSwarm::new(Bounds::new(lower_bound, upper_bound).unwrap(), 40);
         let state: PopulationState<Particle<Vec<f64>, f64>, f64> = PopulationState::new()
             .population(vec![Particle::new(vec![1.0, 2.0], 12.0, vec![0.1, 0.3])]);
         let res = pso.init(&mut Problem::new(TestProblem::new()), state);
@@ -650,7 +1027,8 @@ mod tests {
         let upper_bound: Vec<f64> = vec![1.0, 1.0];
         let particle_a = Particle::new(vec![1.0, 2.0], 12.0, vec![0.1, 0.3]);
         let particle_b = Particle::new(vec![2.0, 3.0], 10.0, vec![0.2, 0.4]);
-        let mut pso: ParticleSwarm<_, f64> = ParticleSwarm::new((lower_bound, upper_bound), 2);
+        let mut pso: ParticleSwarm<_, f64> =
+            ParticleSwarm::new(Bounds::new(lower_bound, upper_bound).unwrap(), 2);
         let state: PopulationState<Particle<Vec<f64>, f64>, f64> =
             PopulationState::new().population(vec![particle_a.clone(), particle_b.clone()]);
         let res = pso.init(&mut Problem::new(TestProblem::new()), state);
@@ -668,7 +1046,8 @@ mod tests {
     fn test_init_random_population() {
         let lower_bound: Vec<f64> = vec![-1.0, -1.0];
         let upper_bound: Vec<f64> = vec![1.0, 1.0];
-        let mut pso: ParticleSwarm<_, f64> = ParticleSwarm::new((lower_bound, upper_bound), 40);
+        let mut pso: ParticleSwarm<_, f64> =
+            ParticleSwarm::new(Bounds::new(lower_bound, upper_bound).unwrap(), 40);
         let state: PopulationState<Particle<Vec<f64>, f64>, f64> = PopulationState::new();
         let res = pso.init(&mut Problem::new(TestProblem::new()), state);
         assert!(res.is_ok());
@@ -707,7 +1086,8 @@ mod tests {
         // setup
         let lower_bound: Vec<f64> = vec![-1.0, -1.0];
         let upper_bound: Vec<f64> = vec![1.0, 1.0];
-        let mut pso: ParticleSwarm<_, f64> = ParticleSwarm::new((lower_bound, upper_bound), 100);
+        let mut pso: ParticleSwarm<_, f64> =
+            ParticleSwarm::new(Bounds::new(lower_bound, upper_bound).unwrap(), 100);
         let state: PopulationState<Particle<Vec<f64>, f64>, f64> = PopulationState::new();
 
         // init
@@ -727,4 +1107,108 @@ mod tests {
             assert_eq!(state.get_cost().to_ne_bytes(), (-3.0f64).to_ne_bytes());
         }
     }
+
+    #[test]
+    fn test_solver_variants() {
+        use crate::core::Executor;
+
+        struct Sphere {}
+
+        impl CostFunction for Sphere {
+            type Param = Vec<f64>;
+            type Output = f64;
+
+            fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(p.iter().map(|x| (x - 0.5).powi(2)).sum())
+            }
+        }
+
+        for topology in [Topology::Global, Topology::Ring, Topology::VonNeumann] {
+            let pso =
+                ParticleSwarm::new(Bounds::new(vec![-2.0, -2.0], vec![2.0, 2.0]).unwrap(), 20)
+                    .with_topology(topology)
+                    .with_constriction(2.05, 2.05)
+                    .unwrap()
+                    .with_velocity_clamping(0.5)
+                    .unwrap();
+            let res = Executor::new(Sphere {}, pso)
+                .configure(|state| state.max_iters(300))
+                .run()
+                .unwrap();
+            let best = res.state.get_best_param().unwrap();
+            assert_relative_eq!(best.position[0], 0.5, epsilon = 1e-4);
+            assert_relative_eq!(best.position[1], 0.5, epsilon = 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_function_counts() {
+        use crate::core::Executor;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        // Counts the evaluations itself, which are run in parallel with the `rayon` feature
+        struct Sphere {
+            evaluations: AtomicUsize,
+        }
+
+        impl CostFunction for Sphere {
+            type Param = Vec<f64>;
+            type Output = f64;
+
+            fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                self.evaluations.fetch_add(1, Ordering::Relaxed);
+                Ok(p.iter().map(|x| x.powi(2)).sum())
+            }
+        }
+
+        let pso = ParticleSwarm::new(Bounds::new(vec![-2.0, -2.0], vec![2.0, 2.0]).unwrap(), 20);
+        let res = Executor::new(
+            Sphere {
+                evaluations: AtomicUsize::new(0),
+            },
+            pso,
+        )
+        .configure(|state| state.max_iters(10))
+        .run()
+        .unwrap();
+        // One evaluation of each particle during initialization and in each iteration
+        assert_eq!(res.state.get_func_counts()["cost_count"], 20 * 11);
+        assert_eq!(
+            res.problem.problem.unwrap().evaluations.into_inner(),
+            20 * 11
+        );
+    }
+
+    #[test]
+    fn test_seeded_rng() {
+        use crate::core::Executor;
+
+        struct Sphere {}
+
+        impl CostFunction for Sphere {
+            type Param = Vec<f64>;
+            type Output = f64;
+
+            fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(p.iter().map(|x| x.powi(2)).sum())
+            }
+        }
+
+        // Runs with the same seed are identical
+        let run = || {
+            let pso = ParticleSwarm::new_with_rng(
+                Bounds::new(vec![-2.0, -2.0], vec![2.0, 2.0]).unwrap(),
+                20,
+                Xoshiro256PlusPlus::seed_from_u64(42),
+            );
+            let res = Executor::new(Sphere {}, pso)
+                .configure(|state| state.max_iters(10))
+                .run()
+                .unwrap();
+            res.state.get_best_param().unwrap().position.clone()
+        };
+        let (a, b) = (run(), run());
+        assert_eq!(a[0].to_ne_bytes(), b[0].to_ne_bytes());
+        assert_eq!(a[1].to_ne_bytes(), b[1].to_ne_bytes());
+    }
 }
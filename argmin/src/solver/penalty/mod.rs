@@ -0,0 +1,710 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Quadratic penalty method
+//!
+//! Meta-solver which turns a problem with inequality constraints `g_i(x) <= 0` into a sequence of
+//! unconstrained problems which can be solved by any of the unconstrained solvers. See
+//! [`PenaltyMethod`] for details.
+//!
+//! ## Reference
+//!
+//! Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
+//! Springer. ISBN 0-387-30303-0.
+
+use crate::core::{
+    ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, Executor, Gradient, Hessian,
+    InequalityConstraint, IterState, OptimizationResult, Problem, SerializeAlias, Solver, State,
+    TerminationReason, TerminationStatus, KV,
+};
+use argmin_math::{ArgminAdd, ArgminDot, ArgminMul, ArgminScaledAdd};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Quadratic penalty problem
+///
+/// Wraps a problem with inequality constraints `g_i(x) <= 0` and turns it into the unconstrained
+/// problem
+///
+/// `min_x f(x) + rho/2 * sum_i max(0, g_i(x))^2`
+///
+/// for a given penalty parameter `rho`. This is the problem the inner solver of
+/// [`PenaltyMethod`] operates on.
+///
+/// [`CostFunction`] is implemented if the wrapped problem implements [`CostFunction`] and
+/// [`InequalityConstraint`]. [`Gradient`] is implemented if the wrapped problem additionally
+/// implements [`Gradient`] and
+/// [`inequality_jacobian`](`InequalityConstraint::inequality_jacobian`). [`Hessian`] is
+/// implemented if the wrapped problem additionally implements [`Hessian`]; the curvature of the
+/// constraints is neglected (Gauss-Newton approximation of the penalty term).
+#[derive(Clone, Debug)]
+pub struct PenaltyProblem<O, F> {
+    /// Wrapped problem
+    problem: O,
+    /// Penalty parameter
+    rho: F,
+}
+
+impl<O, F> PenaltyProblem<O, F> {
+    /// Construct a new instance of [`PenaltyProblem`] from a problem and a penalty parameter
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::penalty::PenaltyProblem;
+    /// # struct UserDefinedProblem {};
+    /// let problem = PenaltyProblem::new(UserDefinedProblem {}, 10.0f64);
+    /// ```
+    pub fn new(problem: O, rho: F) -> Self {
+        PenaltyProblem { problem, rho }
+    }
+
+    /// Returns the wrapped problem
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::penalty::PenaltyProblem;
+    /// # #[derive(Debug, Eq, PartialEq)]
+    /// # struct UserDefinedProblem {};
+    /// let problem = PenaltyProblem::new(UserDefinedProblem {}, 10.0f64);
+    /// assert_eq!(problem.into_inner(), UserDefinedProblem {});
+    /// ```
+    pub fn into_inner(self) -> O {
+        self.problem
+    }
+}
+
+impl<O, P, F> CostFunction for PenaltyProblem<O, F>
+where
+    O: CostFunction<Param = P, Output = F> + InequalityConstraint<Param = P, Float = F>,
+    F: ArgminFloat,
+{
+    type Param = P;
+    type Output = F;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        let penalty = self
+            .problem
+            .inequality_constraints(param)?
+            .iter()
+            .map(|c| c.max(float!(0.0)).powi(2))
+            .fold(float!(0.0), |a, b| a + b);
+        Ok(self.problem.cost(param)? + self.rho * float!(0.5) * penalty)
+    }
+}
+
+impl<O, P, G, F> Gradient for PenaltyProblem<O, F>
+where
+    O: Gradient<Param = P, Gradient = G> + InequalityConstraint<Param = P, Gradient = G, Float = F>,
+    G: ArgminScaledAdd<G, F, G>,
+    F: ArgminFloat,
+{
+    type Param = P;
+    type Gradient = G;
+
+    fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+        let grad = self.problem.gradient(param)?;
+        let constraints = self.problem.inequality_constraints(param)?;
+        // The Jacobian of the constraints is only needed if any constraint is violated
+        if constraints.iter().all(|c| *c <= float!(0.0)) {
+            return Ok(grad);
+        }
+        let jacobian = self.problem.inequality_jacobian(param)?;
+        Ok(constraints
+            .iter()
+            .zip(jacobian.iter())
+            .filter(|(c, _)| **c > float!(0.0))
+            .fold(grad, |acc, (c, a)| acc.scaled_add(&(self.rho * *c), a)))
+    }
+}
+
+impl<O, P, G, H, F> Hessian for PenaltyProblem<O, F>
+where
+    O: Hessian<Param = P, Hessian = H> + InequalityConstraint<Param = P, Gradient = G, Float = F>,
+    G: ArgminMul<F, G> + ArgminDot<G, H>,
+    H: ArgminAdd<H, H>,
+    F: ArgminFloat,
+{
+    type Param = P;
+    type Hessian = H;
+
+    fn hessian(&self, param: &Self::Param) -> Result<Self::Hessian, Error> {
+        let hessian = self.problem.hessian(param)?;
+        let constraints = self.problem.inequality_constraints(param)?;
+        if constraints.iter().all(|c| *c <= float!(0.0)) {
+            return Ok(hessian);
+        }
+        let jacobian = self.problem.inequality_jacobian(param)?;
+        Ok(constraints
+            .iter()
+            .zip(jacobian.iter())
+            .filter(|(c, _)| **c > float!(0.0))
+            .fold(hessian, |acc, (_, a)| acc.add(&a.mul(&self.rho).dot(a))))
+    }
+}
+
+/// # Quadratic penalty method
+///
+/// Solves problems of the form
+///
+/// `min_x f(x)` subject to `g_i(x) <= 0` for `i = 1, ..., m`
+///
+/// by solving a sequence of unconstrained problems
+///
+/// `min_x f(x) + rho_k/2 * sum_i max(0, g_i(x))^2`
+///
+/// (see [`PenaltyProblem`]) with an increasing penalty parameter `rho_k`. Each of these
+/// unconstrained problems is solved by an inner solver, which can be any solver operating on an
+/// [`IterState`], for instance [`SteepestDescent`](`crate::solver::gradientdescent::SteepestDescent`),
+/// [`LBFGS`](`crate::solver::quasinewton::LBFGS`) or
+/// [`NelderMead`](`crate::solver::neldermead::NelderMead`). The inner solver is run for at most
+/// [`with_inner_max_iters`](`PenaltyMethod::with_inner_max_iters`) iterations, starting from the
+/// solution of the previous unconstrained problem. If an initial Hessian or inverse Hessian is
+/// provided via the [`configure`](`crate::core::Executor::configure`) method of the
+/// [`Executor`], it is passed on to each run of the inner solver.
+///
+/// After each outer iteration the penalty parameter is multiplied by the factor set via
+/// [`with_penalty_factor`](`PenaltyMethod::with_penalty_factor`). The algorithm stops once the
+/// maximum constraint violation `max_i max(0, g_i(x))` falls below the tolerance set via
+/// [`with_tolerance`](`PenaltyMethod::with_tolerance`).
+///
+/// The iterates of a penalty method are usually infeasible and approach the feasible region from
+/// the outside. Therefore a feasible-first rule is used to determine the best parameter vector:
+/// The value of the objective function is only stored as cost in the state if the maximum
+/// constraint violation of an iterate is within the tolerance; otherwise the cost is infinite.
+/// This way, the best parameter vector is the feasible iterate with the lowest objective function
+/// value, and the last iterate as long as no feasible iterate has been found. The value of the
+/// objective function and the maximum constraint violation are reported via the observers
+/// (`objective` and `max_violation`).
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`] and
+/// [`InequalityConstraint`]. Depending on the inner solver, [`Gradient`], [`Hessian`] and
+/// [`inequality_jacobian`](`InequalityConstraint::inequality_jacobian`) may be required as well.
+///
+/// ## Reference
+///
+/// Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
+/// Springer. ISBN 0-387-30303-0.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct PenaltyMethod<S, F> {
+    /// Inner solver
+    solver: S,
+    /// Penalty parameter
+    rho: F,
+    /// Factor by which the penalty parameter is increased
+    rho_factor: F,
+    /// Tolerance on the constraint violation
+    tol: F,
+    /// Maximum number of iterations of the inner solver
+    inner_max_iters: u64,
+    /// Maximum constraint violation of the current iterate
+    violation: F,
+}
+
+impl<S, F> PenaltyMethod<S, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`PenaltyMethod`]
+    ///
+    /// Takes the inner solver used to solve the unconstrained problems.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::penalty::PenaltyMethod;
+    /// # use argmin::solver::neldermead::NelderMead;
+    /// # let inner: NelderMead<Vec<f64>, f64> = NelderMead::new(vec![vec![0.0], vec![1.0]]);
+    /// let penalty: PenaltyMethod<_, f64> = PenaltyMethod::new(inner);
+    /// ```
+    pub fn new(solver: S) -> Self {
+        PenaltyMethod {
+            solver,
+            rho: float!(1.0),
+            rho_factor: float!(10.0),
+            tol: F::epsilon().sqrt(),
+            inner_max_iters: 1000,
+            violation: F::infinity(),
+        }
+    }
+
+    /// Set the initial penalty parameter
+    ///
+    /// Must be larger than 0 and defaults to `1.0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::penalty::PenaltyMethod;
+    /// # use argmin::solver::neldermead::NelderMead;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let inner: NelderMead<Vec<f64>, f64> = NelderMead::new(vec![vec![0.0], vec![1.0]]);
+    /// let penalty: PenaltyMethod<_, f64> = PenaltyMethod::new(inner).with_penalty(100.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_penalty(mut self, rho: F) -> Result<Self, Error> {
+        if rho <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`PenaltyMethod`: penalty parameter must be > 0."
+            ));
+        }
+        self.rho = rho;
+        Ok(self)
+    }
+
+    /// Set the factor by which the penalty parameter is increased after each outer iteration
+    ///
+    /// Must be larger than 1 and defaults to `10.0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::penalty::PenaltyMethod;
+    /// # use argmin::solver::neldermead::NelderMead;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let inner: NelderMead<Vec<f64>, f64> = NelderMead::new(vec![vec![0.0], vec![1.0]]);
+    /// let penalty: PenaltyMethod<_, f64> = PenaltyMethod::new(inner).with_penalty_factor(5.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_penalty_factor(mut self, factor: F) -> Result<Self, Error> {
+        if factor <= float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`PenaltyMethod`: penalty factor must be > 1."
+            ));
+        }
+        self.rho_factor = factor;
+        Ok(self)
+    }
+
+    /// Set the tolerance on the maximum constraint violation
+    ///
+    /// Must be non-negative and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::penalty::PenaltyMethod;
+    /// # use argmin::solver::neldermead::NelderMead;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let inner: NelderMead<Vec<f64>, f64> = NelderMead::new(vec![vec![0.0], vec![1.0]]);
+    /// let penalty: PenaltyMethod<_, f64> = PenaltyMethod::new(inner).with_tolerance(1e-6)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`PenaltyMethod`: tolerance must be >= 0."
+            ));
+        }
+        self.tol = tol;
+        Ok(self)
+    }
+
+    /// Set the maximum number of iterations of the inner solver per outer iteration
+    ///
+    /// Must be larger than 0 and defaults to `1000`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::penalty::PenaltyMethod;
+    /// # use argmin::solver::neldermead::NelderMead;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let inner: NelderMead<Vec<f64>, f64> = NelderMead::new(vec![vec![0.0], vec![1.0]]);
+    /// let penalty: PenaltyMethod<_, f64> = PenaltyMethod::new(inner).with_inner_max_iters(100)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_inner_max_iters(mut self, iters: u64) -> Result<Self, Error> {
+        if iters == 0 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`PenaltyMethod`: maximum number of inner iterations must be > 0."
+            ));
+        }
+        self.inner_max_iters = iters;
+        Ok(self)
+    }
+}
+
+impl<O, S, P, G, J, H, F> Solver<O, IterState<P, G, J, H, F>> for PenaltyMethod<S, F>
+where
+    O: CostFunction<Param = P, Output = F> + InequalityConstraint<Param = P, Float = F>,
+    S: Clone + Solver<PenaltyProblem<O, F>, IterState<P, G, J, H, F>>,
+    P: Clone + SerializeAlias + DeserializeOwnedAlias,
+    G: SerializeAlias + DeserializeOwnedAlias,
+    J: SerializeAlias + DeserializeOwnedAlias,
+    H: Clone + SerializeAlias + DeserializeOwnedAlias,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Quadratic penalty method";
+
+    fn init(
+        &mut self,
+        _problem: &mut Problem<O>,
+        state: IterState<P, G, J, H, F>,
+    ) -> Result<(IterState<P, G, J, H, F>, Option<KV>), Error> {
+        if state.get_param().is_none() {
+            return Err(argmin_error!(
+                NotInitialized,
+                concat!(
+                    "`PenaltyMethod` requires an initial parameter vector. ",
+                    "Please provide an initial guess via `Executor`s `configure` method."
+                )
+            ));
+        }
+        // At least one unconstrained problem is solved, even for a feasible initial guess
+        self.violation = F::infinity();
+        Ok((state, None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, J, H, F>,
+    ) -> Result<(IterState<P, G, J, H, F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`PenaltyMethod`: Parameter vector in state not set."
+        ))?;
+        let hessian = state.get_hessian().cloned();
+        let inv_hessian = state.get_inv_hessian().cloned();
+        let inner_max_iters = self.inner_max_iters;
+
+        let OptimizationResult {
+            problem: mut inner_problem,
+            state: mut inner_state,
+            ..
+        } = Executor::new(
+            PenaltyProblem::new(
+                problem.take_problem().ok_or_else(argmin_error_closure!(
+                    PotentialBug,
+                    "`PenaltyMethod`: Failed to take `problem` for inner solver"
+                ))?,
+                self.rho,
+            ),
+            self.solver.clone(),
+        )
+        .configure(|config| {
+            let config = config.param(param).max_iters(inner_max_iters);
+            let config = match hessian {
+                Some(hessian) => config.hessian(hessian),
+                None => config,
+            };
+            match inv_hessian {
+                Some(inv_hessian) => config.inv_hessian(inv_hessian),
+                None => config,
+            }
+        })
+        .ctrlc(false)
+        .run()?;
+
+        // Get back problem and function evaluation counts
+        problem.problem = inner_problem.take_problem().map(PenaltyProblem::into_inner);
+        problem.consume_func_counts(inner_problem);
+
+        let new_param = inner_state
+            .take_best_param()
+            .ok_or_else(argmin_error_closure!(
+                PotentialBug,
+                "`PenaltyMethod`: No `param` returned by inner solver"
+            ))?;
+
+        self.violation = max_violation(&problem.inequality_constraints(&new_param)?);
+        let objective = problem.cost(&new_param)?;
+
+        let kv = kv!(
+            "penalty" => self.rho;
+            "objective" => objective;
+            "max_violation" => self.violation;
+            "inner_iters" => inner_state.get_iter();
+        );
+
+        self.rho = self.rho * self.rho_factor;
+
+        let cost = feasible_first_cost(objective, self.violation, self.tol);
+
+        Ok((state.param(new_param).cost(cost), Some(kv)))
+    }
+
+    fn terminate(&mut self, _state: &IterState<P, G, J, H, F>) -> TerminationStatus {
+        if self.violation <= self.tol {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+/// Maximum violation `max_i max(0, g_i)` of the inequality constraints `g_i <= 0`.
+pub(crate) fn max_violation<F: ArgminFloat>(constraints: &[F]) -> F {
+    constraints.iter().fold(float!(0.0), |acc, c| acc.max(*c))
+}
+
+/// Cost of an iterate according to the feasible-first rule
+///
+/// Returns the value of the objective function if the constraint violation is within the
+/// tolerance and infinity otherwise. An iterate with infinite cost only replaces the best
+/// parameter vector as long as no feasible iterate has been found.
+pub(crate) fn feasible_first_cost<F: ArgminFloat>(objective: F, violation: F, tol: F) -> F {
+    if violation <= tol {
+        objective
+    } else {
+        F::infinity()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+    use crate::solver::linesearch::MoreThuenteLineSearch;
+    use crate::solver::quasinewton::LBFGS;
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    type Inner = LBFGS<MoreThuenteLineSearch<Vec<f64>, Vec<f64>, f64>, Vec<f64>, Vec<f64>, f64>;
+
+    test_trait_impl!(penalty_method, PenaltyMethod<Inner, f64>);
+
+    /// min (x - 2)^2 + (y - 1)^2 s.t. x + y <= 1
+    struct HalfPlaneProblem {}
+
+    impl CostFunction for HalfPlaneProblem {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok((p[0] - 2.0).powi(2) + (p[1] - 1.0).powi(2))
+        }
+    }
+
+    impl Gradient for HalfPlaneProblem {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(vec![2.0 * (p[0] - 2.0), 2.0 * (p[1] - 1.0)])
+        }
+    }
+
+    impl InequalityConstraint for HalfPlaneProblem {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+        type Float = f64;
+
+        fn inequality_constraints(&self, p: &Self::Param) -> Result<Vec<f64>, Error> {
+            Ok(vec![p[0] + p[1] - 1.0])
+        }
+
+        fn inequality_jacobian(&self, _p: &Self::Param) -> Result<Vec<Vec<f64>>, Error> {
+            Ok(vec![vec![1.0, 1.0]])
+        }
+    }
+
+    fn inner() -> Inner {
+        LBFGS::new(MoreThuenteLineSearch::new(), 5)
+    }
+
+    #[test]
+    fn test_new() {
+        let PenaltyMethod {
+            solver: _,
+            rho,
+            rho_factor,
+            tol,
+            inner_max_iters,
+            violation,
+        } = PenaltyMethod::<_, f64>::new(inner());
+        assert_eq!(rho.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(rho_factor.to_ne_bytes(), 10.0f64.to_ne_bytes());
+        assert_eq!(tol.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert_eq!(inner_max_iters, 1000);
+        assert!(violation.is_infinite());
+    }
+
+    #[test]
+    fn test_with_penalty() {
+        for rho in [f64::EPSILON, 1.0, 100.0] {
+            let pm = PenaltyMethod::<_, f64>::new(inner())
+                .with_penalty(rho)
+                .unwrap();
+            assert_eq!(pm.rho.to_ne_bytes(), rho.to_ne_bytes());
+        }
+
+        for rho in [0.0, -1.0] {
+            let res = PenaltyMethod::new(inner()).with_penalty(rho);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`PenaltyMethod`: penalty parameter must be > 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_penalty_factor() {
+        for factor in [1.0 + f64::EPSILON, 2.0, 100.0] {
+            let pm = PenaltyMethod::<_, f64>::new(inner())
+                .with_penalty_factor(factor)
+                .unwrap();
+            assert_eq!(pm.rho_factor.to_ne_bytes(), factor.to_ne_bytes());
+        }
+
+        for factor in [1.0, 0.5, 0.0, -1.0] {
+            let res = PenaltyMethod::new(inner()).with_penalty_factor(factor);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`PenaltyMethod`: penalty factor must be > 1.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_feasible_first_cost() {
+        assert_eq!(
+            feasible_first_cost(2.0f64, 0.0, 0.0).to_ne_bytes(),
+            2.0f64.to_ne_bytes()
+        );
+        assert_eq!(
+            feasible_first_cost(2.0f64, 1e-9, 1e-8).to_ne_bytes(),
+            2.0f64.to_ne_bytes()
+        );
+        assert!(feasible_first_cost(-2.0f64, 1e-7, 1e-8).is_infinite());
+        assert_eq!(
+            max_violation(&[-1.0f64, 0.5, 0.25]).to_ne_bytes(),
+            0.5f64.to_ne_bytes()
+        );
+        assert_eq!(
+            max_violation(&[-1.0f64]).to_ne_bytes(),
+            0.0f64.to_ne_bytes()
+        );
+    }
+
+    #[test]
+    fn test_with_tolerance() {
+        for tol in [0.0, 1e-10, 1.0] {
+            let pm = PenaltyMethod::<_, f64>::new(inner())
+                .with_tolerance(tol)
+                .unwrap();
+            assert_eq!(pm.tol.to_ne_bytes(), tol.to_ne_bytes());
+        }
+
+        for tol in [-f64::EPSILON, -1.0] {
+            let res = PenaltyMethod::new(inner()).with_tolerance(tol);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`PenaltyMethod`: tolerance must be >= 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_inner_max_iters() {
+        for iters in [1, 10, 1000] {
+            let pm = PenaltyMethod::<_, f64>::new(inner())
+                .with_inner_max_iters(iters)
+                .unwrap();
+            assert_eq!(pm.inner_max_iters, iters);
+        }
+
+        let res = PenaltyMethod::<_, f64>::new(inner()).with_inner_max_iters(0);
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`PenaltyMethod`: maximum number of inner iterations ",
+                "must be > 0.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_penalty_problem() {
+        let problem = PenaltyProblem::new(HalfPlaneProblem {}, 10.0);
+
+        // feasible: no penalty
+        let p = vec![0.0, 0.0];
+        assert_relative_eq!(problem.cost(&p).unwrap(), 5.0, epsilon = f64::EPSILON);
+        assert_eq!(problem.gradient(&p).unwrap(), vec![-4.0, -2.0]);
+
+        // infeasible: g = 1
+        let p = vec![1.0, 1.0];
+        assert_relative_eq!(problem.cost(&p).unwrap(), 6.0, epsilon = f64::EPSILON);
+        assert_eq!(problem.gradient(&p).unwrap(), vec![8.0, 10.0]);
+    }
+
+    #[test]
+    fn test_solver() {
+        let res = Executor::new(HalfPlaneProblem {}, PenaltyMethod::new(inner()))
+            .configure(|state| state.param(vec![0.0, 0.0]).max_iters(100))
+            .run();
+        assert_error!(
+            Executor::new(HalfPlaneProblem {}, PenaltyMethod::new(inner()))
+                .configure(|state| state.max_iters(100))
+                .run(),
+            ArgminError,
+            concat!(
+                "Not initialized: \"`PenaltyMethod` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+
+        let res = res.unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let best = res.state.get_best_param().unwrap();
+        assert_relative_eq!(best[0], 1.0, epsilon = 1e-6);
+        assert_relative_eq!(best[1], 0.0, epsilon = 1e-6);
+        assert_relative_eq!(
+            res.state.get_best_cost(),
+            HalfPlaneProblem {}.cost(best).unwrap(),
+            epsilon = f64::EPSILON
+        );
+        assert!(res.problem.counts["cost_count"] > 0);
+        assert!(res.problem.counts["gradient_count"] > 0);
+    }
+
+    #[test]
+    fn test_best_param_feasible_first() {
+        let mut pm = PenaltyMethod::new(inner()).with_inner_max_iters(1).unwrap();
+        let mut problem = Problem::new(HalfPlaneProblem {});
+
+        // A feasible iterate has been found before
+        let mut state = IterState::new().param(vec![2.0, 1.0]);
+        state.best_param = Some(vec![0.0, 0.0]);
+        state.best_cost = 5.0;
+
+        // The new iterate is infeasible and has a lower objective function value, but must not
+        // become the best parameter vector
+        let (mut state, kv) = pm.next_iter(&mut problem, state).unwrap();
+        let kv = kv.unwrap();
+        assert!(kv.get("max_violation").unwrap().get_float().unwrap() > 0.0);
+        assert!(kv.get("objective").unwrap().get_float().unwrap() < 5.0);
+        assert!(state.get_cost().is_infinite());
+        state.update();
+        assert_eq!(state.get_best_param().unwrap(), &vec![0.0, 0.0]);
+        assert_eq!(state.get_best_cost().to_ne_bytes(), 5.0f64.to_ne_bytes());
+    }
+}
@@ -0,0 +1,493 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Projected gradient descent
+//!
+//! Gradient descent for problems constrained to a closed convex set, which is defined via a
+//! projection onto the set. See [`ProjectedGradient`] for details.
+//!
+//! # Available projections
+//!
+//! * [`BoxProjection`]
+//! * [`BallProjection`]
+//! * [`SimplexProjection`]
+//! * [`HalfSpaceProjection`]
+//! * [`IdentityProjection`]
+//!
+//! User-defined sets are supported by implementing the [`Projection`] trait of `argmin-math`,
+//! which is re-exported here. The same projections can be used with
+//! [`FrankWolfe`](`crate::solver::frankwolfe::FrankWolfe`) and
+//! [`ProjectedMirrorMap`](`crate::solver::mirrordescent::ProjectedMirrorMap`).
+//!
+//! ## Reference
+//!
+//! Dimitri P. Bertsekas (1999). Nonlinear Programming. Athena Scientific.
+//! ISBN 1-886529-00-0.
+
+mod projection;
+
+pub use argmin_math::Projection;
+pub use projection::{
+    BallProjection, BoxProjection, HalfSpaceProjection, IdentityProjection, SimplexProjection,
+};
+
+use crate::core::{
+    ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, Gradient, IterState, Problem,
+    SerializeAlias, Solver, State, TerminationReason, TerminationStatus, KV,
+};
+use argmin_math::{ArgminDot, ArgminL2Norm, ArgminScaledSub, ArgminSub};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Projected gradient descent
+///
+/// Minimizes a function over a closed convex set `C` via the iteration
+///
+/// `x_{k+1} = P_C(x_k - alpha_k * grad f(x_k))`
+///
+/// where `P_C` is the projection onto `C`, which is provided as an implementation of the
+/// [`Projection`] trait. The step length `alpha_k` is found by an Armijo-type backtracking line
+/// search along the projection arc `alpha -> P_C(x_k - alpha * grad f(x_k))`: Starting from the
+/// initial step length set via [`with_step_length`](`ProjectedGradient::with_step_length`),
+/// the step length is reduced by the factor set via
+/// [`with_backtracking_factor`](`ProjectedGradient::with_backtracking_factor`) until
+///
+/// `f(x(alpha)) <= f(x_k) + c * grad f(x_k)^T (x(alpha) - x_k)`
+///
+/// holds, where `c` is set via
+/// [`with_sufficient_decrease`](`ProjectedGradient::with_sufficient_decrease`).
+///
+/// The initial parameter vector is projected onto the set before the first iteration, therefore
+/// it does not need to be feasible. The algorithm stops once the norm of the projected gradient
+/// step `||x_k - P_C(x_k - grad f(x_k))||` falls below the tolerance set via
+/// [`with_tolerance`](`ProjectedGradient::with_tolerance`).
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`] and [`Gradient`].
+///
+/// ## Reference
+///
+/// Dimitri P. Bertsekas (1999). Nonlinear Programming. Athena Scientific.
+/// ISBN 1-886529-00-0.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct ProjectedGradient<Q, F> {
+    /// Projection onto the feasible set
+    projection: Q,
+    /// Initial step length of the line search
+    step_length: F,
+    /// Sufficient decrease parameter
+    c: F,
+    /// Backtracking factor
+    rho: F,
+    /// Tolerance on the norm of the projected gradient step
+    tol: F,
+    /// Norm of the projected gradient step of the current iterate
+    pg_norm: F,
+}
+
+impl<Q, F> ProjectedGradient<Q, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`ProjectedGradient`]
+    ///
+    /// Takes the projection onto the feasible set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::projectedgradient::{BoxProjection, ProjectedGradient};
+    /// let projection = BoxProjection::new(vec![0.0, 0.0], vec![1.0, 1.0]);
+    /// let pg: ProjectedGradient<_, f64> = ProjectedGradient::new(projection);
+    /// ```
+    pub fn new(projection: Q) -> Self {
+        ProjectedGradient {
+            projection,
+            step_length: float!(1.0),
+            c: float!(1e-4),
+            rho: float!(0.5),
+            tol: F::epsilon().sqrt(),
+            pg_norm: F::infinity(),
+        }
+    }
+
+    /// Set the initial step length of the line search
+    ///
+    /// Must be larger than 0 and defaults to `1.0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::projectedgradient::{BoxProjection, ProjectedGradient};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let projection = BoxProjection::new(vec![0.0, 0.0], vec![1.0, 1.0]);
+    /// let pg: ProjectedGradient<_, f64> = ProjectedGradient::new(projection).with_step_length(0.1)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_step_length(mut self, step_length: F) -> Result<Self, Error> {
+        if step_length <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ProjectedGradient`: step length must be > 0."
+            ));
+        }
+        self.step_length = step_length;
+        Ok(self)
+    }
+
+    /// Set the sufficient decrease parameter `c` of the line search
+    ///
+    /// Must be in `(0, 1)` and defaults to `1e-4`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::projectedgradient::{BoxProjection, ProjectedGradient};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let projection = BoxProjection::new(vec![0.0, 0.0], vec![1.0, 1.0]);
+    /// let pg: ProjectedGradient<_, f64> =
+    ///     ProjectedGradient::new(projection).with_sufficient_decrease(0.1)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_sufficient_decrease(mut self, c: F) -> Result<Self, Error> {
+        if c <= float!(0.0) || c >= float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ProjectedGradient`: sufficient decrease parameter must be in (0, 1)."
+            ));
+        }
+        self.c = c;
+        Ok(self)
+    }
+
+    /// Set the factor by which the step length is reduced in the line search
+    ///
+    /// Must be in `(0, 1)` and defaults to `0.5`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::projectedgradient::{BoxProjection, ProjectedGradient};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let projection = BoxProjection::new(vec![0.0, 0.0], vec![1.0, 1.0]);
+    /// let pg: ProjectedGradient<_, f64> =
+    ///     ProjectedGradient::new(projection).with_backtracking_factor(0.8)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_backtracking_factor(mut self, rho: F) -> Result<Self, Error> {
+        if rho <= float!(0.0) || rho >= float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ProjectedGradient`: backtracking factor must be in (0, 1)."
+            ));
+        }
+        self.rho = rho;
+        Ok(self)
+    }
+
+    /// Set the tolerance on the norm of the projected gradient step
+    ///
+    /// Must be non-negative and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::projectedgradient::{BoxProjection, ProjectedGradient};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let projection = BoxProjection::new(vec![0.0, 0.0], vec![1.0, 1.0]);
+    /// let pg: ProjectedGradient<_, f64> = ProjectedGradient::new(projection).with_tolerance(1e-6)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ProjectedGradient`: tolerance must be >= 0."
+            ));
+        }
+        self.tol = tol;
+        Ok(self)
+    }
+}
+
+impl<O, Q, P, G, F> Solver<O, IterState<P, G, (), (), F>> for ProjectedGradient<Q, F>
+where
+    O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
+    Q: Projection<P>,
+    P: Clone
+        + SerializeAlias
+        + DeserializeOwnedAlias
+        + ArgminSub<P, P>
+        + ArgminScaledSub<G, F, P>
+        + ArgminL2Norm<F>,
+    G: Clone + SerializeAlias + DeserializeOwnedAlias + ArgminDot<P, F>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Projected gradient descent";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`ProjectedGradient` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        let param = self.projection.project(&param)?;
+        let cost = problem.cost(&param)?;
+        let grad = problem.gradient(&param)?;
+        self.pg_norm = F::infinity();
+        Ok((state.param(param).cost(cost).gradient(grad), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, G, (), (), F>,
+    ) -> Result<(IterState<P, G, (), (), F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`ProjectedGradient`: Parameter vector in state not set."
+        ))?;
+        let grad = state.take_gradient().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`ProjectedGradient`: Gradient in state not set."
+        ))?;
+        let cost = state.get_cost();
+
+        // Stationarity measure: norm of the projected gradient step with unit step length
+        self.pg_norm = param
+            .sub(
+                &self
+                    .projection
+                    .project(&param.scaled_sub(&float!(1.0), &grad))?,
+            )
+            .l2_norm();
+        if self.pg_norm <= self.tol {
+            return Ok((
+                state.param(param).gradient(grad).cost(cost),
+                Some(kv!("projected_gradient_norm" => self.pg_norm;)),
+            ));
+        }
+
+        // Backtracking along the projection arc
+        let mut alpha = self.step_length;
+        loop {
+            let new_param = self.projection.project(&param.scaled_sub(&alpha, &grad))?;
+            let new_cost = problem.cost(&new_param)?;
+            let decrease: F = grad.dot(&new_param.sub(&param));
+            if new_cost <= cost + self.c * decrease {
+                let new_grad = problem.gradient(&new_param)?;
+                let kv = kv!(
+                    "projected_gradient_norm" => self.pg_norm;
+                    "step_length" => alpha;
+                );
+                return Ok((
+                    state.param(new_param).gradient(new_grad).cost(new_cost),
+                    Some(kv),
+                ));
+            }
+            alpha = alpha * self.rho;
+            if alpha <= F::epsilon() {
+                return Ok((
+                    state.param(param).gradient(grad).cost(cost).terminate_with(
+                        TerminationReason::SolverExit(
+                            "Line search failed to find a sufficient decrease".to_string(),
+                        ),
+                    ),
+                    Some(kv!("projected_gradient_norm" => self.pg_norm;)),
+                ));
+            }
+        }
+    }
+
+    fn terminate(&mut self, _state: &IterState<P, G, (), (), F>) -> TerminationStatus {
+        if self.pg_norm <= self.tol {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Executor};
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(
+        projected_gradient,
+        ProjectedGradient<BoxProjection<Vec<f64>>, f64>
+    );
+
+    /// f(x) = (x - 2)^2 + (y - 1)^2
+    struct Quadratic {}
+
+    impl CostFunction for Quadratic {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok((p[0] - 2.0).powi(2) + (p[1] - 1.0).powi(2))
+        }
+    }
+
+    impl Gradient for Quadratic {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(vec![2.0 * (p[0] - 2.0), 2.0 * (p[1] - 1.0)])
+        }
+    }
+
+    fn box_projection() -> BoxProjection<Vec<f64>> {
+        BoxProjection::new(vec![0.0, 0.0], vec![1.0, 0.5])
+    }
+
+    #[test]
+    fn test_new() {
+        let ProjectedGradient {
+            projection: _,
+            step_length,
+            c,
+            rho,
+            tol,
+            pg_norm,
+        } = ProjectedGradient::<_, f64>::new(box_projection());
+        assert_eq!(step_length.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(c.to_ne_bytes(), 1e-4f64.to_ne_bytes());
+        assert_eq!(rho.to_ne_bytes(), 0.5f64.to_ne_bytes());
+        assert_eq!(tol.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert!(pg_norm.is_infinite());
+    }
+
+    #[test]
+    fn test_with_step_length() {
+        for step_length in [f64::EPSILON, 0.1, 10.0] {
+            let pg = ProjectedGradient::<_, f64>::new(box_projection())
+                .with_step_length(step_length)
+                .unwrap();
+            assert_eq!(pg.step_length.to_ne_bytes(), step_length.to_ne_bytes());
+        }
+
+        for step_length in [0.0, -1.0] {
+            let res = ProjectedGradient::new(box_projection()).with_step_length(step_length);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`ProjectedGradient`: step length must be > 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_sufficient_decrease() {
+        for c in [f64::EPSILON, 0.5, 1.0 - f64::EPSILON] {
+            let pg = ProjectedGradient::<_, f64>::new(box_projection())
+                .with_sufficient_decrease(c)
+                .unwrap();
+            assert_eq!(pg.c.to_ne_bytes(), c.to_ne_bytes());
+        }
+
+        for c in [0.0, 1.0, -1.0, 2.0] {
+            let res = ProjectedGradient::new(box_projection()).with_sufficient_decrease(c);
+            assert_error!(
+                res,
+                ArgminError,
+                concat!(
+                    "Invalid parameter: \"`ProjectedGradient`: sufficient decrease parameter ",
+                    "must be in (0, 1).\""
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_backtracking_factor() {
+        for rho in [f64::EPSILON, 0.5, 1.0 - f64::EPSILON] {
+            let pg = ProjectedGradient::<_, f64>::new(box_projection())
+                .with_backtracking_factor(rho)
+                .unwrap();
+            assert_eq!(pg.rho.to_ne_bytes(), rho.to_ne_bytes());
+        }
+
+        for rho in [0.0, 1.0, -1.0, 2.0] {
+            let res = ProjectedGradient::new(box_projection()).with_backtracking_factor(rho);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`ProjectedGradient`: backtracking factor must be in (0, 1).\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_tolerance() {
+        for tol in [0.0, 1e-10, 1.0] {
+            let pg = ProjectedGradient::<_, f64>::new(box_projection())
+                .with_tolerance(tol)
+                .unwrap();
+            assert_eq!(pg.tol.to_ne_bytes(), tol.to_ne_bytes());
+        }
+
+        for tol in [-f64::EPSILON, -1.0] {
+            let res = ProjectedGradient::new(box_projection()).with_tolerance(tol);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`ProjectedGradient`: tolerance must be >= 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_solver_box() {
+        let res = Executor::new(Quadratic {}, ProjectedGradient::new(box_projection()))
+            .configure(|state| state.param(vec![-1.0, 3.0]).max_iters(100))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let best = res.state.get_best_param().unwrap();
+        assert_relative_eq!(best[0], 1.0, epsilon = 1e-8);
+        assert_relative_eq!(best[1], 0.5, epsilon = 1e-8);
+    }
+
+    #[test]
+    fn test_solver_simplex() {
+        let projection = SimplexProjection::new(1.0).unwrap();
+        let res = Executor::new(Quadratic {}, ProjectedGradient::new(projection))
+            .configure(|state| state.param(vec![0.5, 0.5]).max_iters(100))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let best = res.state.get_best_param().unwrap();
+        assert_relative_eq!(best[0], 1.0, epsilon = 1e-8);
+        assert_relative_eq!(best[1], 0.0, epsilon = 1e-8);
+    }
+}
@@ -0,0 +1,397 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{ArgminFloat, Bounds, Error};
+use argmin_math::{ArgminDot, ArgminL2Norm, ArgminMinMax, ArgminScaledAdd, ArgminSub, Projection};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Identity projection
+///
+/// Projects onto the whole space, i.e. returns the parameter vector unchanged. Serves as the
+/// default projection of solvers where a projection is optional, such as
+/// [`FrankWolfe`](`crate::solver::frankwolfe::FrankWolfe`).
+///
+/// # Example
+///
+/// ```
+/// # use argmin::solver::projectedgradient::{IdentityProjection, Projection};
+/// let projection = IdentityProjection::new();
+/// assert_eq!(projection.project(&vec![-1.0f64, 0.5]).unwrap(), vec![-1.0, 0.5]);
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct IdentityProjection {}
+
+impl IdentityProjection {
+    /// Construct a new instance of [`IdentityProjection`]
+    pub fn new() -> Self {
+        IdentityProjection {}
+    }
+}
+
+impl<P> Projection<P> for IdentityProjection
+where
+    P: Clone,
+{
+    fn project(&self, param: &P) -> Result<P, Error> {
+        Ok(param.clone())
+    }
+}
+
+/// # Projection onto a box
+///
+/// Projects onto the set `{x : lower <= x <= upper}` (element-wise) by clamping each element.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::solver::projectedgradient::{BoxProjection, Projection};
+/// let projection = BoxProjection::new(vec![0.0f64, 0.0], vec![1.0, 1.0]);
+/// assert_eq!(projection.project(&vec![-1.0, 0.5]).unwrap(), vec![0.0, 0.5]);
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct BoxProjection<P> {
+    /// Lower bound
+    lower: P,
+    /// Upper bound
+    upper: P,
+}
+
+impl<P> BoxProjection<P> {
+    /// Construct a new instance of [`BoxProjection`] from lower and upper bounds
+    ///
+    /// The lower bound must be smaller than or equal to the upper bound in every element. Use the
+    /// `From<Bounds<P>>` implementation to construct the projection from validated [`Bounds`].
+    pub fn new(lower: P, upper: P) -> Self {
+        BoxProjection { lower, upper }
+    }
+}
+
+impl<P> From<Bounds<P>> for BoxProjection<P> {
+    fn from(bounds: Bounds<P>) -> Self {
+        let (lower, upper) = bounds.into_inner();
+        BoxProjection::new(lower, upper)
+    }
+}
+
+impl<P> Projection<P> for BoxProjection<P>
+where
+    P: ArgminMinMax,
+{
+    fn project(&self, param: &P) -> Result<P, Error> {
+        Ok(P::max(&P::min(param, &self.upper), &self.lower))
+    }
+}
+
+/// # Projection onto an L2 ball
+///
+/// Projects onto the set `{x : ||x - center|| <= radius}` by moving points outside of the ball
+/// radially onto its surface.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::solver::projectedgradient::{BallProjection, Projection};
+/// # use argmin::core::Error;
+/// # fn main() -> Result<(), Error> {
+/// let projection = BallProjection::new(vec![0.0f64, 0.0], 1.0)?;
+/// assert_eq!(projection.project(&vec![2.0, 0.0])?, vec![1.0, 0.0]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct BallProjection<P, F> {
+    /// Center of the ball
+    center: P,
+    /// Radius of the ball
+    radius: F,
+}
+
+impl<P, F> BallProjection<P, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`BallProjection`] from the center and the radius of the ball
+    ///
+    /// The radius must be non-negative.
+    pub fn new(center: P, radius: F) -> Result<Self, Error> {
+        if radius < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`BallProjection`: radius must be >= 0."
+            ));
+        }
+        Ok(BallProjection { center, radius })
+    }
+}
+
+impl<P, F> Projection<P> for BallProjection<P, F>
+where
+    P: Clone + ArgminSub<P, P> + ArgminScaledAdd<P, F, P> + ArgminL2Norm<F>,
+    F: ArgminFloat,
+{
+    fn project(&self, param: &P) -> Result<P, Error> {
+        let diff = param.sub(&self.center);
+        let norm = diff.l2_norm();
+        if norm <= self.radius {
+            return Ok(param.clone());
+        }
+        Ok(self.center.scaled_add(&(self.radius / norm), &diff))
+    }
+}
+
+/// # Projection onto a simplex
+///
+/// Projects onto the set `{x : x_i >= 0, sum_i x_i = radius}`, which for `radius = 1` is the
+/// probability simplex.
+///
+/// ## Reference
+///
+/// John Duchi, Shai Shalev-Shwartz, Yoram Singer and Tushar Chandra (2008). Efficient projections
+/// onto the l1-ball for learning in high dimensions. International Conference on Machine Learning.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::solver::projectedgradient::{SimplexProjection, Projection};
+/// # use argmin::core::Error;
+/// # fn main() -> Result<(), Error> {
+/// let projection = SimplexProjection::new(1.0f64)?;
+/// assert_eq!(projection.project(&vec![1.0, 0.0, -1.0])?, vec![1.0, 0.0, 0.0]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct SimplexProjection<F> {
+    /// Sum of the elements
+    radius: F,
+}
+
+impl<F> SimplexProjection<F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`SimplexProjection`]
+    ///
+    /// The radius is the sum of the elements of the points in the simplex and must be larger than
+    /// 0.
+    pub fn new(radius: F) -> Result<Self, Error> {
+        if radius <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`SimplexProjection`: radius must be > 0."
+            ));
+        }
+        Ok(SimplexProjection { radius })
+    }
+}
+
+impl<F> Projection<Vec<F>> for SimplexProjection<F>
+where
+    F: ArgminFloat,
+{
+    fn project(&self, param: &Vec<F>) -> Result<Vec<F>, Error> {
+        let mut sorted = param.clone();
+        sorted.sort_by(|a, b| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal));
+        let mut cumsum = float!(0.0);
+        let mut theta = float!(0.0);
+        for (j, u) in sorted.iter().enumerate() {
+            cumsum = cumsum + *u;
+            let t = (cumsum - self.radius) / F::from_usize(j + 1).unwrap();
+            if *u > t {
+                theta = t;
+            }
+        }
+        Ok(param
+            .iter()
+            .map(|x| (*x - theta).max(float!(0.0)))
+            .collect())
+    }
+}
+
+/// # Projection onto a half-space
+///
+/// Projects onto the set `{x : a^T x <= b}` by moving points outside of the half-space along the
+/// normal `a` onto the bounding hyperplane.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::solver::projectedgradient::{HalfSpaceProjection, Projection};
+/// # use argmin::core::Error;
+/// # fn main() -> Result<(), Error> {
+/// // x + y <= 1
+/// let projection = HalfSpaceProjection::new(vec![1.0f64, 1.0], 1.0)?;
+/// assert_eq!(projection.project(&vec![1.0, 1.0])?, vec![0.5, 0.5]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct HalfSpaceProjection<P, F> {
+    /// Normal vector `a`
+    normal: P,
+    /// Offset `b`
+    offset: F,
+    /// Squared norm of the normal vector
+    normal_norm_sq: F,
+}
+
+impl<P, F> HalfSpaceProjection<P, F>
+where
+    P: ArgminDot<P, F>,
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`HalfSpaceProjection`] from the normal `a` and the offset `b`
+    ///
+    /// The normal vector must not be zero and the offset must be finite.
+    pub fn new(normal: P, offset: F) -> Result<Self, Error> {
+        let normal_norm_sq = normal.dot(&normal);
+        if normal_norm_sq <= float!(0.0) || !normal_norm_sq.is_finite() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`HalfSpaceProjection`: normal must be nonzero and finite."
+            ));
+        }
+        if !offset.is_finite() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`HalfSpaceProjection`: offset must be finite."
+            ));
+        }
+        Ok(HalfSpaceProjection {
+            normal,
+            offset,
+            normal_norm_sq,
+        })
+    }
+}
+
+impl<P, F> Projection<P> for HalfSpaceProjection<P, F>
+where
+    P: Clone + ArgminDot<P, F> + ArgminScaledAdd<P, F, P>,
+    F: ArgminFloat,
+{
+    fn project(&self, param: &P) -> Result<P, Error> {
+        let excess = param.dot(&self.normal) - self.offset;
+        if excess <= float!(0.0) {
+            return Ok(param.clone());
+        }
+        Ok(param.scaled_add(&(-excess / self.normal_norm_sq), &self.normal))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(identity_projection, IdentityProjection);
+    test_trait_impl!(box_projection, BoxProjection<Vec<f64>>);
+    test_trait_impl!(ball_projection, BallProjection<Vec<f64>, f64>);
+    test_trait_impl!(simplex_projection, SimplexProjection<f64>);
+    test_trait_impl!(half_space_projection, HalfSpaceProjection<Vec<f64>, f64>);
+
+    #[test]
+    fn test_identity_projection() {
+        let projection = IdentityProjection::new();
+        assert_eq!(
+            projection.project(&vec![-2.0f64, 3.0]).unwrap(),
+            vec![-2.0, 3.0]
+        );
+    }
+
+    #[test]
+    fn test_box_projection() {
+        let projection = BoxProjection::new(vec![-1.0f64, 0.0], vec![1.0, 2.0]);
+        assert_eq!(
+            projection.project(&vec![-2.0, 3.0]).unwrap(),
+            vec![-1.0, 2.0]
+        );
+        assert_eq!(projection.project(&vec![0.5, 1.0]).unwrap(), vec![0.5, 1.0]);
+    }
+
+    #[test]
+    fn test_box_projection_from_bounds() {
+        let bounds = Bounds::new(vec![-1.0f64, 0.0], vec![1.0, 2.0]).unwrap();
+        let projection = BoxProjection::from(bounds.clone());
+        let param = vec![-2.0, 3.0];
+        assert_eq!(projection.project(&param).unwrap(), bounds.clamp(&param));
+    }
+
+    #[test]
+    fn test_ball_projection() {
+        let projection = BallProjection::new(vec![1.0f64, 1.0], 2.0).unwrap();
+        assert_eq!(projection.project(&vec![2.0, 1.0]).unwrap(), vec![2.0, 1.0]);
+        let p = projection.project(&vec![1.0, 5.0]).unwrap();
+        assert_relative_eq!(p[0], 1.0, epsilon = f64::EPSILON);
+        assert_relative_eq!(p[1], 3.0, epsilon = f64::EPSILON);
+
+        let res = BallProjection::new(vec![0.0f64], -1.0);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`BallProjection`: radius must be >= 0.\""
+        );
+    }
+
+    #[test]
+    fn test_simplex_projection() {
+        let projection = SimplexProjection::new(1.0f64).unwrap();
+        let p = projection.project(&vec![0.5, 0.5, 0.5]).unwrap();
+        for x in p {
+            assert_relative_eq!(x, 1.0 / 3.0, epsilon = f64::EPSILON);
+        }
+        let p = projection.project(&vec![0.2, 0.1, 0.3]).unwrap();
+        assert_relative_eq!(p[0], 0.3333333333333333, epsilon = 1e-15);
+        assert_relative_eq!(p[1], 0.2333333333333333, epsilon = 1e-15);
+        assert_relative_eq!(p[2], 0.4333333333333333, epsilon = 1e-15);
+        let p = projection.project(&vec![2.0, 0.0, -1.0]).unwrap();
+        assert_eq!(p, vec![1.0, 0.0, 0.0]);
+
+        let res = SimplexProjection::new(0.0f64);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`SimplexProjection`: radius must be > 0.\""
+        );
+    }
+
+    #[test]
+    fn test_half_space_projection() {
+        let projection = HalfSpaceProjection::new(vec![0.0f64, 2.0], 2.0).unwrap();
+        assert_eq!(projection.project(&vec![5.0, 1.0]).unwrap(), vec![5.0, 1.0]);
+        assert_eq!(
+            projection.project(&vec![5.0, -3.0]).unwrap(),
+            vec![5.0, -3.0]
+        );
+        assert_eq!(projection.project(&vec![5.0, 3.0]).unwrap(), vec![5.0, 1.0]);
+        let p = HalfSpaceProjection::new(vec![1.0f64, 1.0], 0.0)
+            .unwrap()
+            .project(&vec![1.0, 0.0])
+            .unwrap();
+        assert_relative_eq!(p[0], 0.5, epsilon = f64::EPSILON);
+        assert_relative_eq!(p[1], -0.5, epsilon = f64::EPSILON);
+
+        assert_error!(
+            HalfSpaceProjection::new(vec![0.0f64, 0.0], 1.0),
+            ArgminError,
+            "Invalid parameter: \"`HalfSpaceProjection`: normal must be nonzero and finite.\""
+        );
+        assert_error!(
+            HalfSpaceProjection::new(vec![1.0f64], f64::INFINITY),
+            ArgminError,
+            "Invalid parameter: \"`HalfSpaceProjection`: offset must be finite.\""
+        );
+    }
+}
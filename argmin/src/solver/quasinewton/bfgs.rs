@@ -5,13 +5,16 @@
 // http://opensource.org/licenses/MIT>, at your option. This file may not be
 // copied, modified, or distributed except according to those terms.
 
+use super::powell_damping;
 use crate::core::{
-    ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, Executor, Gradient, IterState,
-    LineSearch, OptimizationResult, Problem, SerializeAlias, Solver, TerminationReason,
-    TerminationStatus, KV,
+    ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, EuclideanMetric, Executor, Gradient,
+    IterState, LineSearch, Metric, OptimizationResult, Problem, SerializeAlias, Solver,
+    TerminationReason, TerminationStatus, KV,
 };
+use crate::solver::linesearch::linesearch_kv;
 use argmin_math::{
-    ArgminAdd, ArgminDot, ArgminEye, ArgminL2Norm, ArgminMul, ArgminSub, ArgminTranspose,
+    ArgminAdd, ArgminDot, ArgminEye, ArgminL2Norm, ArgminMul, ArgminScaledAdd, ArgminSub,
+    ArgminTranspose,
 };
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
@@ -37,6 +40,18 @@ use serde::{Deserialize, Serialize};
 /// The other one is a tolerance on the change of the cost function from one iteration to the
 /// other. If the change is below this tolerance (default: `EPSILON`), the algorithm stops. This
 /// parameter can be set via [`with_tolerance_cost`](`BFGS::with_tolerance_cost`).
+/// The norm of the gradient is the Euclidean norm by default; a [`Metric`] which accounts for
+/// the scales of the variables can be set via
+/// [`with_gradient_metric`](`BFGS::with_gradient_metric`).
+///
+/// On nonconvex problems or with inexact line searches the curvature condition `s_k^T y_k > 0`
+/// may fail, in which case the update of the inverse Hessian is not well-defined. Powell damping
+/// (enabled via [`with_powell_damping`](`BFGS::with_powell_damping`)) replaces `y_k` by a convex
+/// combination of `y_k` and `B_k s_k` which always satisfies the curvature condition.
+///
+/// The inverse Hessian approximation is kept in the state and therefore available after a run
+/// via [`get_inv_hessian`](`IterState::get_inv_hessian`), for instance for computing approximate
+/// parameter covariances.
 ///
 /// ## Requirements on the optimization problem
 ///
@@ -48,13 +63,17 @@ use serde::{Deserialize, Serialize};
 /// Springer. ISBN 0-387-30303-0.
 #[derive(Clone)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
-pub struct BFGS<L, F> {
+pub struct BFGS<L, F, N = EuclideanMetric> {
     /// line search
     linesearch: L,
     /// Tolerance for the stopping criterion based on the change of the norm on the gradient
     tol_grad: F,
+    /// Metric in which the norm of the gradient is measured
+    gradient_metric: N,
     /// Tolerance for the stopping criterion based on the change of the cost stopping criterion
     tol_cost: F,
+    /// Whether Powell damping is applied to the update
+    powell_damping: bool,
 }
 
 impl<L, F> BFGS<L, F>
@@ -74,7 +93,38 @@ where
         BFGS {
             linesearch,
             tol_grad: F::epsilon().sqrt(),
+            gradient_metric: EuclideanMetric::new(),
             tol_cost: F::epsilon(),
+            powell_damping: false,
+        }
+    }
+}
+
+impl<L, F, N> BFGS<L, F, N>
+where
+    F: ArgminFloat,
+{
+    /// Set the metric in which the norm of the gradient is measured in the stopping criterion
+    ///
+    /// Defaults to [`EuclideanMetric`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::quasinewton::BFGS;
+    /// # use argmin::core::DiagonalMetric;
+    /// # let linesearch = ();
+    /// let metric = DiagonalMetric::new(vec![1.0f64, 1e-6]);
+    /// let solver: BFGS<_, f64, _> =
+    ///     BFGS::new(linesearch).with_gradient_metric(metric);
+    /// ```
+    pub fn with_gradient_metric<N2>(self, gradient_metric: N2) -> BFGS<L, F, N2> {
+        BFGS {
+            linesearch: self.linesearch,
+            tol_grad: self.tol_grad,
+            gradient_metric,
+            tol_cost: self.tol_cost,
+            powell_damping: self.powell_damping,
         }
     }
 
@@ -129,9 +179,28 @@ where
         self.tol_cost = tol_cost;
         Ok(self)
     }
+
+    /// Enables or disables Powell damping of the inverse Hessian update.
+    ///
+    /// If enabled, the gradient difference `y_k` is replaced by `theta * y_k + (1 - theta) * B_k
+    /// s_k`, with `theta` chosen such that `s_k^T y_k >= 0.2 * s_k^T B_k s_k`. Defaults to
+    /// `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::quasinewton::BFGS;
+    /// # let linesearch = ();
+    /// let bfgs: BFGS<_, f64> = BFGS::new(linesearch).with_powell_damping(true);
+    /// ```
+    #[must_use]
+    pub fn with_powell_damping(mut self, powell_damping: bool) -> Self {
+        self.powell_damping = powell_damping;
+        self
+    }
 }
 
-impl<O, L, P, G, H, F> Solver<O, IterState<P, G, (), H, F>> for BFGS<L, F>
+impl<O, L, P, G, H, F, N> Solver<O, IterState<P, G, (), H, F>> for BFGS<L, F, N>
 where
     O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
     P: Clone
@@ -146,7 +215,8 @@ where
         + ArgminL2Norm<F>
         + ArgminMul<F, P>
         + ArgminDot<P, F>
-        + ArgminSub<G, G>,
+        + ArgminSub<G, G>
+        + ArgminScaledAdd<G, F, G>,
     H: SerializeAlias
         + DeserializeOwnedAlias
         + ArgminSub<H, H>
@@ -157,6 +227,7 @@ where
         + ArgminTranspose<H>
         + ArgminEye,
     L: Clone + LineSearch<P, F> + Solver<O, IterState<P, G, (), (), F>>,
+    N: Metric<G, F>,
     F: ArgminFloat,
 {
     const NAME: &'static str = "BFGS";
@@ -228,11 +299,12 @@ where
 
         let p = inv_hessian.dot(&prev_grad).mul(&float!(-1.0));
 
-        self.linesearch.search_direction(p);
+        self.linesearch.search_direction(p.clone());
 
         // Run solver
         let OptimizationResult {
             problem: line_problem,
+            solver: linesearch,
             state: mut sub_state,
             ..
         } = Executor::new(problem.take_problem().unwrap(), self.linesearch.clone())
@@ -244,6 +316,7 @@ where
             })
             .ctrlc(false)
             .run()?;
+        self.linesearch = linesearch;
 
         let xk1 = sub_state.take_param().ok_or_else(argmin_error_closure!(
             PotentialBug,
@@ -261,6 +334,12 @@ where
 
         let sk = xk1.sub(&param);
 
+        let yk = if self.powell_damping {
+            powell_damping(&sk, yk, &prev_grad, &p)
+        } else {
+            yk
+        };
+
         let yksk: F = yk.dot(&sk);
         let rhok = float!(1.0) / yksk;
 
@@ -290,12 +369,12 @@ where
                 .cost(next_cost)
                 .gradient(grad)
                 .inv_hessian(inv_hessian),
-            None,
+            Some(linesearch_kv(&self.linesearch, &sub_state)),
         ))
     }
 
     fn terminate(&mut self, state: &IterState<P, G, (), H, F>) -> TerminationStatus {
-        if state.get_gradient().unwrap().l2_norm() < self.tol_grad {
+        if self.gradient_metric.norm(state.get_gradient().unwrap()) < self.tol_grad {
             return TerminationStatus::Terminated(TerminationReason::SolverConverged);
         }
         if (state.get_prev_cost() - state.cost).abs() < self.tol_cost {
@@ -309,8 +388,12 @@ where
 mod tests {
     use super::*;
     use crate::core::{test_utils::TestProblem, ArgminError, IterState, State};
-    use crate::solver::linesearch::MoreThuenteLineSearch;
+    use crate::solver::linesearch::{
+        condition::ArmijoCondition, BacktrackingLineSearch, MoreThuenteLineSearch,
+    };
     use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+    use argmin_testfunctions::{rosenbrock_2d, rosenbrock_2d_derivative};
 
     test_trait_impl!(
         bfgs,
@@ -325,13 +408,72 @@ mod tests {
         let bfgs: BFGS<_, f64> = BFGS::new(MyFakeLineSearch {});
         let BFGS {
             linesearch,
+            gradient_metric: _,
             tol_grad,
             tol_cost,
+            powell_damping,
         } = bfgs;
 
         assert_eq!(linesearch, MyFakeLineSearch {});
         assert_eq!(tol_grad.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
         assert_eq!(tol_cost.to_ne_bytes(), f64::EPSILON.to_ne_bytes());
+        assert!(!powell_damping);
+    }
+
+    #[test]
+    fn test_with_powell_damping() {
+        #[derive(Eq, PartialEq, Debug, Clone, Copy)]
+        struct MyFakeLineSearch {}
+
+        let bfgs: BFGS<_, f64> = BFGS::new(MyFakeLineSearch {}).with_powell_damping(true);
+        assert!(bfgs.powell_damping);
+
+        let bfgs = bfgs.with_powell_damping(false);
+        assert!(!bfgs.powell_damping);
+    }
+
+    #[test]
+    fn test_powell_damping() {
+        struct Rosenbrock {}
+
+        impl CostFunction for Rosenbrock {
+            type Param = Vec<f64>;
+            type Output = f64;
+
+            fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(rosenbrock_2d(p, 1.0, 100.0))
+            }
+        }
+
+        impl Gradient for Rosenbrock {
+            type Param = Vec<f64>;
+            type Gradient = Vec<f64>;
+
+            fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+                Ok(rosenbrock_2d_derivative(p, 1.0, 100.0))
+            }
+        }
+
+        // Backtracking only enforces the Armijo condition, therefore the curvature condition
+        // may fail.
+        let linesearch = BacktrackingLineSearch::new(ArmijoCondition::new(1e-4).unwrap());
+        let bfgs: BFGS<_, f64> = BFGS::new(linesearch).with_powell_damping(true);
+        let res = Executor::new(Rosenbrock {}, bfgs)
+            .configure(|state| {
+                state
+                    .param(vec![-1.2, 1.0])
+                    .inv_hessian(vec![vec![1.0, 0.0], vec![0.0, 1.0]])
+                    .max_iters(1000)
+            })
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(param[0], 1.0, epsilon = 1e-4);
+        assert_relative_eq!(param[1], 1.0, epsilon = 1e-4);
     }
 
     #[test]
@@ -507,4 +649,45 @@ mod tests {
             assert_eq!(s.to_ne_bytes(), g.to_ne_bytes());
         }
     }
+
+    /// `1/2 x^T A x` with `A = [[4, 1], [1, 2]]`, whose inverse Hessian is `[[2, -1], [-1, 4]] / 7`
+    struct Quadratic {}
+
+    impl CostFunction for Quadratic {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(2.0 * p[0].powi(2) + p[0] * p[1] + p[1].powi(2))
+        }
+    }
+
+    impl Gradient for Quadratic {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(vec![4.0 * p[0] + p[1], p[0] + 2.0 * p[1]])
+        }
+    }
+
+    #[test]
+    fn test_inv_hessian_in_result() {
+        let linesearch: MoreThuenteLineSearch<Vec<f64>, Vec<f64>, f64> =
+            MoreThuenteLineSearch::new();
+        let res = Executor::new(Quadratic {}, BFGS::new(linesearch))
+            .configure(|state| {
+                state
+                    .param(vec![1.0, 2.0])
+                    .inv_hessian(vec![vec![1.0, 0.0], vec![0.0, 1.0]])
+                    .max_iters(10)
+            })
+            .run()
+            .unwrap();
+        let inv_hessian = res.state().get_inv_hessian().unwrap();
+        let target = [2.0 / 7.0, -1.0 / 7.0, -1.0 / 7.0, 4.0 / 7.0];
+        for (h, t) in inv_hessian.iter().flatten().zip(target.iter()) {
+            assert_relative_eq!(*h, *t, epsilon = 1e-6);
+        }
+    }
 }
@@ -10,6 +10,7 @@ use crate::core::{
     LineSearch, OptimizationResult, Problem, SerializeAlias, Solver, TerminationReason,
     TerminationStatus, KV,
 };
+use crate::solver::linesearch::linesearch_kv;
 use argmin_math::{ArgminAdd, ArgminDot, ArgminL2Norm, ArgminMul, ArgminSub};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
@@ -194,6 +195,7 @@ where
 
         let OptimizationResult {
             problem: line_problem,
+            solver: linesearch,
             state: mut linesearch_state,
             ..
         } = Executor::new(problem.take_problem().unwrap(), self.linesearch.clone())
@@ -205,6 +207,7 @@ where
             })
             .ctrlc(false)
             .run()?;
+        self.linesearch = linesearch;
 
         let xk1 = linesearch_state
             .take_param()
@@ -240,7 +243,7 @@ where
                 .cost(next_cost)
                 .gradient(grad)
                 .inv_hessian(inv_hessian),
-            None,
+            Some(linesearch_kv(&self.linesearch, &linesearch_state)),
         ))
     }
 
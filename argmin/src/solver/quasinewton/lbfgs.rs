@@ -5,14 +5,16 @@
 // http://opensource.org/licenses/MIT>, at your option. This file may not be
 // copied, modified, or distributed except according to those terms.
 
+use super::powell_damping;
 use crate::core::{
-    ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, Executor, Gradient, IterState,
-    LineSearch, OptimizationResult, Problem, SerializeAlias, Solver, State, TerminationReason,
-    TerminationStatus, KV,
+    ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, EuclideanMetric, Executor, Gradient,
+    IterState, LineSearch, Metric, OptimizationResult, Problem, SerializeAlias, Solver, State,
+    TerminationReason, TerminationStatus, KV,
 };
+use crate::solver::linesearch::linesearch_kv;
 use argmin_math::{
-    ArgminAdd, ArgminDot, ArgminL1Norm, ArgminL2Norm, ArgminMinMax, ArgminMul, ArgminSignum,
-    ArgminSub, ArgminZeroLike,
+    ArgminAdd, ArgminDot, ArgminEye, ArgminL1Norm, ArgminL2Norm, ArgminMinMax, ArgminMul,
+    ArgminScaledAdd, ArgminScaledSub, ArgminSignum, ArgminSub, ArgminZeroLike,
 };
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
@@ -53,6 +55,19 @@ where
 /// The other one is a tolerance on the change of the cost function from one iteration to the
 /// other. If the change is below this tolerance (default: `EPSILON`), the algorithm stops. This
 /// parameter can be set via [`with_tolerance_cost`](`LBFGS::with_tolerance_cost`).
+/// The norm of the gradient is the Euclidean norm by default; a [`Metric`] which accounts for
+/// the scales of the variables can be set via
+/// [`with_gradient_metric`](`LBFGS::with_gradient_metric`).
+///
+/// If the curvature condition `s_k^T y_k > 0` fails, which may happen on nonconvex problems or
+/// with inexact line searches, the stored pair `(s_k, y_k)` leads to an indefinite inverse Hessian
+/// approximation. Powell damping (enabled via
+/// [`with_powell_damping`](`LBFGS::with_powell_damping`)) replaces `y_k` by a convex combination
+/// of `y_k` and `B_k s_k` which always satisfies the curvature condition.
+///
+/// The inverse Hessian approximation represented by the stored pairs can be obtained as a dense
+/// matrix via [`inv_hessian`](`LBFGS::inv_hessian`), for instance from the solver returned in the
+/// [`OptimizationResult`] after a run.
 ///
 /// ## Orthant-Wise Limited-memory Quasi-Newton (OWL-QN) method
 ///
@@ -76,7 +91,7 @@ where
 /// International Conference on Machine Learning.
 #[derive(Clone)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
-pub struct LBFGS<L, P, G, F> {
+pub struct LBFGS<L, P, G, F, N = EuclideanMetric> {
     /// line search
     linesearch: L,
     /// m
@@ -85,14 +100,20 @@ pub struct LBFGS<L, P, G, F> {
     s: VecDeque<P>,
     /// y_{k-1}
     y: VecDeque<G>,
+    /// rho_{k-1} = 1 / (y_{k-1}^T s_{k-1}), computed once when a pair is stored
+    rho: VecDeque<F>,
     /// Tolerance for the stopping criterion based on the change of the norm on the gradient
     tol_grad: F,
+    /// Metric in which the norm of the gradient is measured
+    gradient_metric: N,
     /// Tolerance for the stopping criterion based on the change of the cost stopping criterion
     tol_cost: F,
     /// Coefficient of L1-regularization
     l1_coeff: Option<F>,
     /// Unregularized gradient used for calculation of `y`.
     l1_prev_unreg_grad: Option<G>,
+    /// Whether Powell damping is applied to `y`
+    powell_damping: bool,
 }
 
 impl<L, P, G, F> LBFGS<L, P, G, F>
@@ -114,10 +135,48 @@ where
             m,
             s: VecDeque::with_capacity(m),
             y: VecDeque::with_capacity(m),
+            rho: VecDeque::with_capacity(m),
             tol_grad: F::epsilon().sqrt(),
+            gradient_metric: EuclideanMetric::new(),
             tol_cost: F::epsilon(),
             l1_coeff: None,
             l1_prev_unreg_grad: None,
+            powell_damping: false,
+        }
+    }
+}
+
+impl<L, P, G, F, N> LBFGS<L, P, G, F, N>
+where
+    F: ArgminFloat,
+{
+    /// Set the metric in which the norm of the gradient is measured in the stopping criterion
+    ///
+    /// Defaults to [`EuclideanMetric`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::quasinewton::LBFGS;
+    /// # use argmin::core::DiagonalMetric;
+    /// # let linesearch = ();
+    /// let metric = DiagonalMetric::new(vec![1.0f64, 1e-6]);
+    /// let solver: LBFGS<_, Vec<f64>, Vec<f64>, f64, _> =
+    ///     LBFGS::new(linesearch, 5).with_gradient_metric(metric);
+    /// ```
+    pub fn with_gradient_metric<N2>(self, gradient_metric: N2) -> LBFGS<L, P, G, F, N2> {
+        LBFGS {
+            linesearch: self.linesearch,
+            m: self.m,
+            s: self.s,
+            y: self.y,
+            rho: self.rho,
+            tol_grad: self.tol_grad,
+            gradient_metric,
+            tol_cost: self.tol_cost,
+            l1_coeff: self.l1_coeff,
+            l1_prev_unreg_grad: self.l1_prev_unreg_grad,
+            powell_damping: self.powell_damping,
         }
     }
 
@@ -197,6 +256,66 @@ where
         self.l1_coeff = Some(l1_coeff);
         Ok(self)
     }
+
+    /// Enables or disables Powell damping of the stored gradient differences.
+    ///
+    /// If enabled, the gradient difference `y_k` is replaced by `theta * y_k + (1 - theta) * B_k
+    /// s_k`, with `theta` chosen such that `s_k^T y_k >= 0.2 * s_k^T B_k s_k`. Defaults to
+    /// `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::quasinewton::LBFGS;
+    /// # let linesearch = ();
+    /// let lbfgs: LBFGS<_, Vec<f64>, Vec<f64>, f64> = LBFGS::new(linesearch, 3).with_powell_damping(true);
+    /// ```
+    #[must_use]
+    pub fn with_powell_damping(mut self, powell_damping: bool) -> Self {
+        self.powell_damping = powell_damping;
+        self
+    }
+
+    /// Returns the dense inverse Hessian approximation represented by the stored pairs
+    /// `(s_k, y_k)`.
+    ///
+    /// Starting from `gamma * I` as in the two-loop recursion, the BFGS update is applied for each
+    /// stored pair. After a run, this can be obtained from the solver of the
+    /// [`OptimizationResult`], for instance to compute approximate parameter covariances. Since
+    /// only the last `m` pairs are used, the approximation is only accurate in the subspace they
+    /// span; for a full approximation, `m` should be at least the number of parameters. Returns
+    /// `None` if no pair has been stored yet.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::quasinewton::LBFGS;
+    /// # let linesearch = ();
+    /// let lbfgs: LBFGS<_, Vec<f64>, Vec<f64>, f64> = LBFGS::new(linesearch, 3);
+    /// let inv_hessian: Option<Vec<Vec<f64>>> = lbfgs.inv_hessian();
+    /// # assert!(inv_hessian.is_none());
+    /// ```
+    pub fn inv_hessian<H>(&self) -> Option<H>
+    where
+        P: ArgminDot<P, H> + ArgminDot<G, H>,
+        G: ArgminDot<G, F> + ArgminDot<P, H>,
+        H: ArgminEye + ArgminSub<H, H> + ArgminAdd<H, H> + ArgminMul<F, H> + ArgminDot<H, H>,
+    {
+        let (sk, yk, rho) = (self.s.back()?, self.y.back()?, self.rho.back()?);
+        let ykyk: F = yk.dot(yk);
+        let sksk: H = sk.dot(sk);
+        let eye = sksk.eye_like();
+        let mut inv_hessian = eye.mul(&(float!(1.0) / (*rho * ykyk)));
+        for ((sk, yk), rho) in self.s.iter().zip(self.y.iter()).zip(self.rho.iter()) {
+            let skyk: H = sk.dot(yk);
+            let yksk: H = yk.dot(sk);
+            let sksk: H = sk.dot(sk);
+            let tmp1 = eye.sub(&skyk.mul(rho));
+            let tmp2 = eye.sub(&yksk.mul(rho));
+            inv_hessian = tmp1.dot(&inv_hessian.dot(&tmp2)).add(&sksk.mul(rho));
+        }
+        Some(inv_hessian)
+    }
 }
 
 /// Wrapper problem for supporting constrained line search.
@@ -302,7 +421,7 @@ where
     }
 }
 
-impl<O, L, P, G, F> Solver<O, IterState<P, G, (), (), F>> for LBFGS<L, P, G, F>
+impl<O, L, P, G, F, N> Solver<O, IterState<P, G, (), (), F>> for LBFGS<L, P, G, F, N>
 where
     O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = G>,
     P: Clone
@@ -317,6 +436,7 @@ where
         + ArgminMul<F, P>
         + ArgminMul<P, P>
         + ArgminMul<G, P>
+        + ArgminScaledAdd<P, F, P>
         + ArgminL1Norm<F>
         + ArgminSignum
         + ArgminZeroLike
@@ -333,9 +453,12 @@ where
         + ArgminDot<P, F>
         + ArgminMul<F, G>
         + ArgminMul<F, P>
+        + ArgminScaledAdd<G, F, G>
+        + ArgminScaledSub<G, F, G>
         + ArgminZeroLike
         + ArgminMinMax,
     L: Clone + LineSearch<P, F> + Solver<LineSearchProblem<O, P, G, F>, IterState<P, G, (), (), F>>,
+    N: Metric<G, F>,
     F: ArgminFloat,
 {
     const NAME: &'static str = "L-BFGS";
@@ -395,32 +518,42 @@ where
             }
         }
 
-        let gamma: F = if let (Some(sk), Some(yk)) = (self.s.back(), self.y.back()) {
-            sk.dot(yk) / yk.dot(yk)
+        let gamma: F = if let (Some(rho), Some(yk)) = (self.rho.back(), self.y.back()) {
+            float!(1.0) / (*rho * yk.dot(yk))
         } else {
             float!(1.0)
         };
 
-        // L-BFGS two-loop recursion
+        // L-BFGS two-loop recursion. The updates of `q` and `r` use the fused `scaled_sub` and
+        // `scaled_add` in order to avoid allocating the intermediate `alpha * y_k` and
+        // `(alpha - beta) * s_k`.
         #[allow(clippy::redundant_clone)]
         let mut q = prev_grad.clone();
         let cur_m = self.s.len();
         let mut alpha: Vec<F> = vec![float!(0.0); cur_m];
-        let mut rho: Vec<F> = vec![float!(0.0); cur_m];
-        for (i, (sk, yk)) in self.s.iter().rev().zip(self.y.iter().rev()).enumerate() {
-            let yksk: F = yk.dot(sk);
-            let rho_t = float!(1.0) / yksk;
+        for (i, ((sk, yk), rho)) in self
+            .s
+            .iter()
+            .zip(self.y.iter())
+            .zip(self.rho.iter())
+            .rev()
+            .enumerate()
+        {
             let skq: F = sk.dot(&q);
-            let alpha_t = skq.mul(rho_t);
-            q = q.sub(&yk.mul(&alpha_t));
-            rho[cur_m - i - 1] = rho_t;
+            let alpha_t = skq.mul(*rho);
+            q = q.scaled_sub(&alpha_t, yk);
             alpha[cur_m - i - 1] = alpha_t;
         }
         let mut r: P = q.mul(&gamma);
-        for (i, (sk, yk)) in self.s.iter().zip(self.y.iter()).enumerate() {
+        for ((sk, yk), (rho, alpha)) in self
+            .s
+            .iter()
+            .zip(self.y.iter())
+            .zip(self.rho.iter().zip(alpha.iter()))
+        {
             let beta: F = yk.dot(&r);
-            let beta = beta.mul(rho[i]);
-            r = r.add(&sk.mul(&(alpha[i] - beta)));
+            let beta = beta.mul(*rho);
+            r = r.scaled_add(&(*alpha - beta), sk);
         }
 
         let mut line_problem = LineSearchProblem::new(problem.take_problem().unwrap());
@@ -437,11 +570,12 @@ where
             r.mul(&float!(-1.0))
         };
 
-        self.linesearch.search_direction(d);
+        self.linesearch.search_direction(d.clone());
 
         // Run solver
         let OptimizationResult {
             problem: mut line_problem,
+            solver: linesearch,
             state: mut linesearch_state,
             ..
         } = Executor::new(line_problem, self.linesearch.clone())
@@ -453,6 +587,7 @@ where
             })
             .ctrlc(false)
             .run()?;
+        self.linesearch = linesearch;
 
         let mut xk1 = linesearch_state.take_param().unwrap();
         let next_cost = linesearch_state.get_cost();
@@ -470,31 +605,38 @@ where
         if state.get_iter() >= self.m as u64 {
             self.s.pop_front();
             self.y.pop_front();
+            self.rho.pop_front();
         }
 
         let grad = problem.gradient(&xk1)?;
 
-        self.s.push_back(xk1.sub(&param));
-        let grad = if let Some(l1_coeff) = self.l1_coeff {
+        let sk = xk1.sub(&param);
+        let (yk, grad) = if let Some(l1_coeff) = self.l1_coeff {
             // Stores unregularized gradient and returns L1 gradient.
             let pseudo_grad = calculate_pseudo_gradient(l1_coeff, &xk1, &grad);
-            self.y
-                .push_back(grad.sub(self.l1_prev_unreg_grad.as_ref().unwrap()));
+            let yk = grad.sub(self.l1_prev_unreg_grad.as_ref().unwrap());
             self.l1_prev_unreg_grad = Some(grad);
-            pseudo_grad
+            (yk, pseudo_grad)
+        } else {
+            (grad.sub(&prev_grad), grad)
+        };
+        let yk = if self.powell_damping {
+            powell_damping(&sk, yk, &prev_grad, &d)
         } else {
-            self.y.push_back(grad.sub(&prev_grad));
-            grad
+            yk
         };
+        self.rho.push_back(float!(1.0) / yk.dot(&sk));
+        self.s.push_back(sk);
+        self.y.push_back(yk);
 
         Ok((
             state.param(xk1).cost(next_cost).gradient(grad),
-            Some(kv!("gamma" => gamma;)),
+            Some(kv!("gamma" => gamma;).merge(linesearch_kv(&self.linesearch, &linesearch_state))),
         ))
     }
 
     fn terminate(&mut self, state: &IterState<P, G, (), (), F>) -> TerminationStatus {
-        if state.get_gradient().unwrap().l2_norm() < self.tol_grad {
+        if self.gradient_metric.norm(state.get_gradient().unwrap()) < self.tol_grad {
             return TerminationStatus::Terminated(TerminationReason::SolverConverged);
         }
         if (state.get_prev_cost() - state.get_cost()).abs() < self.tol_cost {
@@ -511,8 +653,12 @@ mod tests {
         test_utils::{TestProblem, TestSparseProblem},
         ArgminError, IterState, State,
     };
-    use crate::solver::linesearch::MoreThuenteLineSearch;
+    use crate::solver::linesearch::{
+        condition::ArmijoCondition, BacktrackingLineSearch, MoreThuenteLineSearch,
+    };
     use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+    use argmin_testfunctions::{rosenbrock_2d, rosenbrock_2d_derivative};
 
     test_trait_impl!(
         lbfgs,
@@ -527,13 +673,16 @@ mod tests {
         let lbfgs: LBFGS<_, Vec<f64>, Vec<f64>, f64> = LBFGS::new(MyFakeLineSearch {}, 3);
         let LBFGS {
             linesearch,
+            gradient_metric: _,
             tol_grad,
             tol_cost,
             m,
             s,
             y,
+            rho,
             l1_coeff,
             l1_prev_unreg_grad,
+            powell_damping,
         } = lbfgs;
 
         assert_eq!(linesearch, MyFakeLineSearch {});
@@ -542,8 +691,63 @@ mod tests {
         assert_eq!(m, 3);
         assert!(s.capacity() >= 3);
         assert!(y.capacity() >= 3);
+        assert!(rho.capacity() >= 3);
         assert!(l1_coeff.is_none());
         assert!(l1_prev_unreg_grad.is_none());
+        assert!(!powell_damping);
+    }
+
+    #[test]
+    fn test_with_powell_damping() {
+        #[derive(Eq, PartialEq, Debug, Clone, Copy)]
+        struct MyFakeLineSearch {}
+
+        let lbfgs: LBFGS<_, Vec<f64>, Vec<f64>, f64> =
+            LBFGS::new(MyFakeLineSearch {}, 3).with_powell_damping(true);
+        assert!(lbfgs.powell_damping);
+
+        let lbfgs = lbfgs.with_powell_damping(false);
+        assert!(!lbfgs.powell_damping);
+    }
+
+    #[test]
+    fn test_powell_damping() {
+        struct Rosenbrock {}
+
+        impl CostFunction for Rosenbrock {
+            type Param = Vec<f64>;
+            type Output = f64;
+
+            fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(rosenbrock_2d(p, 1.0, 100.0))
+            }
+        }
+
+        impl Gradient for Rosenbrock {
+            type Param = Vec<f64>;
+            type Gradient = Vec<f64>;
+
+            fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+                Ok(rosenbrock_2d_derivative(p, 1.0, 100.0))
+            }
+        }
+
+        // Backtracking only enforces the Armijo condition, therefore the curvature condition
+        // may fail.
+        let linesearch = BacktrackingLineSearch::new(ArmijoCondition::new(1e-4).unwrap());
+        let lbfgs: LBFGS<_, Vec<f64>, Vec<f64>, f64> =
+            LBFGS::new(linesearch, 5).with_powell_damping(true);
+        let res = Executor::new(Rosenbrock {}, lbfgs)
+            .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(1000))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let param = res.state.get_best_param().unwrap();
+        assert_relative_eq!(param[0], 1.0, epsilon = 1e-4);
+        assert_relative_eq!(param[1], 1.0, epsilon = 1e-4);
     }
 
     #[test]
@@ -733,4 +937,95 @@ mod tests {
             assert!((result_param[3]).abs() < 1e-6);
         }
     }
+
+    #[test]
+    fn test_complex_param() {
+        use num_complex::Complex;
+
+        /// `f(z) = sum_i w_i |z_i - c_i|^2` with gradient `2 df/dconj(z) = 2 w (z - c)`
+        struct WeightedDistance {
+            center: Vec<Complex<f64>>,
+            weights: Vec<f64>,
+        }
+
+        impl CostFunction for WeightedDistance {
+            type Param = Vec<Complex<f64>>;
+            type Output = f64;
+
+            fn cost(&self, z: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(z.iter()
+                    .zip(self.center.iter())
+                    .zip(self.weights.iter())
+                    .map(|((z, c), w)| w * (z - c).norm_sqr())
+                    .sum())
+            }
+        }
+
+        impl Gradient for WeightedDistance {
+            type Param = Vec<Complex<f64>>;
+            type Gradient = Vec<Complex<f64>>;
+
+            fn gradient(&self, z: &Self::Param) -> Result<Self::Gradient, Error> {
+                Ok(z.iter()
+                    .zip(self.center.iter())
+                    .zip(self.weights.iter())
+                    .map(|((z, c), w)| 2.0 * w * (z - c))
+                    .collect())
+            }
+        }
+
+        let center = vec![Complex::new(1.0, -2.0), Complex::new(-0.5, 3.0)];
+        let problem = WeightedDistance {
+            center: center.clone(),
+            weights: vec![1.0, 10.0],
+        };
+        let linesearch = MoreThuenteLineSearch::new();
+        let lbfgs: LBFGS<_, Vec<Complex<f64>>, Vec<Complex<f64>>, f64> = LBFGS::new(linesearch, 3);
+        let res = Executor::new(problem, lbfgs)
+            .configure(|state| state.param(vec![Complex::new(0.0, 0.0); 2]).max_iters(20))
+            .run()
+            .unwrap();
+
+        let result_param = res.state.best_param.unwrap();
+        for (z, c) in result_param.iter().zip(center.iter()) {
+            assert_relative_eq!(z.re, c.re, epsilon = 1e-6);
+            assert_relative_eq!(z.im, c.im, epsilon = 1e-6);
+        }
+    }
+
+    /// `1/2 x^T A x` with `A = [[4, 1], [1, 2]]`, whose inverse Hessian is `[[2, -1], [-1, 4]] / 7`
+    struct Quadratic {}
+
+    impl CostFunction for Quadratic {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(2.0 * p[0].powi(2) + p[0] * p[1] + p[1].powi(2))
+        }
+    }
+
+    impl Gradient for Quadratic {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(vec![4.0 * p[0] + p[1], p[0] + 2.0 * p[1]])
+        }
+    }
+
+    #[test]
+    fn test_inv_hessian() {
+        let linesearch: MoreThuenteLineSearch<Vec<f64>, Vec<f64>, f64> =
+            MoreThuenteLineSearch::new();
+        let res = Executor::new(Quadratic {}, LBFGS::new(linesearch, 5))
+            .configure(|state| state.param(vec![1.0, 2.0]).max_iters(10))
+            .run()
+            .unwrap();
+        let inv_hessian: Vec<Vec<f64>> = res.solver().inv_hessian().unwrap();
+        let target = [2.0 / 7.0, -1.0 / 7.0, -1.0 / 7.0, 4.0 / 7.0];
+        for (h, t) in inv_hessian.iter().flatten().zip(target.iter()) {
+            assert_relative_eq!(*h, *t, epsilon = 1e-6);
+        }
+    }
 }
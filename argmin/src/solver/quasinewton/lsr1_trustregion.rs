@@ -0,0 +1,697 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{
+    ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, Executor, Gradient, IterState,
+    OptimizationResult, Problem, SerializeAlias, Solver, TerminationReason, TerminationStatus,
+    TrustRegionRadius, KV,
+};
+use argmin_math::{
+    ArgminAdd, ArgminDot, ArgminL2Norm, ArgminMul, ArgminScaledAdd, ArgminSub, ArgminZeroLike,
+    LinearOperator,
+};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Limited-memory SR1 approximation of the Hessian
+///
+/// Represents `B = gamma * I + sum_k u_k u_k^T / (u_k^T s_k)`, where the vectors
+/// `u_k = y_k - B_k s_k` are obtained by successively applying the SR1 update with the stored
+/// pairs `(s_k, y_k)` to the initial approximation `gamma * I`. The matrix is never formed
+/// explicitly; only products with vectors are available via [`LinearOperator`]. It is passed as the
+/// Hessian to the trust region subproblem by [`LSR1TrustRegion`].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct LSR1Hessian<P, F> {
+    /// Scaling of the initial approximation
+    gamma: F,
+    /// Update vectors `u_k`
+    u: Vec<P>,
+    /// Denominators `u_k^T s_k`
+    d: Vec<F>,
+}
+
+impl<P, F> LSR1Hessian<P, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`LSR1Hessian`] equal to `gamma * I`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::quasinewton::LSR1Hessian;
+    /// let hessian: LSR1Hessian<Vec<f64>, f64> = LSR1Hessian::new(1.0);
+    /// ```
+    pub fn new(gamma: F) -> Self {
+        LSR1Hessian {
+            gamma,
+            u: vec![],
+            d: vec![],
+        }
+    }
+
+    /// Returns the number of rank one updates applied to the initial approximation
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::quasinewton::LSR1Hessian;
+    /// let hessian: LSR1Hessian<Vec<f64>, f64> = LSR1Hessian::new(1.0);
+    /// assert_eq!(hessian.num_updates(), 0);
+    /// ```
+    pub fn num_updates(&self) -> usize {
+        self.u.len()
+    }
+}
+
+impl<P, F> LinearOperator<P> for LSR1Hessian<P, F>
+where
+    P: ArgminDot<P, F> + ArgminMul<F, P> + ArgminScaledAdd<P, F, P>,
+    F: ArgminFloat,
+{
+    fn apply(&self, other: &P) -> Result<P, Error> {
+        Ok(self
+            .u
+            .iter()
+            .zip(self.d.iter())
+            .fold(other.mul(&self.gamma), |acc, (u, d)| {
+                let uv: F = u.dot(other);
+                acc.scaled_add(&(uv / *d), u)
+            }))
+    }
+
+    fn apply_transpose(&self, other: &P) -> Result<P, Error> {
+        // symmetric
+        self.apply(other)
+    }
+}
+
+/// # Limited-memory SR1 trust region method
+///
+/// A Quasi-Newton method which uses a limited-memory symmetric rank 1 (L-SR1) approximation of
+/// the Hessian in a trust region framework. Like [`LBFGS`](`crate::solver::quasinewton::LBFGS`),
+/// only the last `m` pairs of parameter and gradient differences `(s_k, y_k)` are stored.
+/// In contrast to BFGS updates, SR1 updates do not enforce positive definiteness, which allows the
+/// approximation to capture negative curvature on nonconvex problems. The trust region ensures
+/// that the resulting steps are well-defined nevertheless.
+///
+/// The initial approximation is `gamma * I`, where `gamma = y^T y / s^T y` is computed from the
+/// most recent pair with `s^T y > 0` (and `gamma = 1` if there is no such pair). Pairs for which
+/// the denominator of the SR1 update is too small are skipped
+/// (see [`with_denominator_factor`](`LSR1TrustRegion::with_denominator_factor`)).
+///
+/// The Hessian approximation is handed to the
+/// [trust region sub problem](`crate::solver::trustregion`) as an [`LSR1Hessian`], which only
+/// provides Hessian-vector products. Therefore the subproblem solver must not require an explicit
+/// Hessian, which is for instance the case for [`Steihaug`](`crate::solver::trustregion::Steihaug`).
+///
+/// An initial parameter vector must be provided, initial cost and gradient are optional and will
+/// be computed if not provided.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`] and [`Gradient`].
+///
+/// ## Reference
+///
+/// Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
+/// Springer. ISBN 0-387-30303-0.
+///
+/// Richard H. Byrd, Jorge Nocedal and Robert B. Schnabel (1994). Representations of quasi-Newton
+/// matrices and their use in limited memory methods. Mathematical Programming 63, 129-156.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct LSR1TrustRegion<R, P, F> {
+    /// subproblem
+    subproblem: R,
+    /// m
+    m: usize,
+    /// s_{k-1}
+    s: VecDeque<P>,
+    /// y_{k-1}
+    y: VecDeque<P>,
+    /// parameter for skipping rule
+    denominator_factor: F,
+    /// Radius
+    radius: F,
+    /// eta \in (0, 10^-3)
+    eta: F,
+    /// Tolerance for the stopping criterion based on the change of the norm on the gradient
+    tol_grad: F,
+}
+
+impl<R, P, F> LSR1TrustRegion<R, P, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`LSR1TrustRegion`]
+    ///
+    /// Takes the trust region subproblem and the number of stored pairs `m`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::quasinewton::LSR1TrustRegion;
+    /// let subproblem = argmin::solver::trustregion::Steihaug::new().with_max_iters(20);
+    /// # // The next line defines the type of `subproblem`. This is done here hidden in order to
+    /// # // not litter the docs. When all of this is fed into an Executor, the compiler will
+    /// # // figure out the types.
+    /// # let subproblem: argmin::solver::trustregion::Steihaug<Vec<f64>, f64> = subproblem;
+    /// let lsr1: LSR1TrustRegion<_, Vec<f64>, f64> = LSR1TrustRegion::new(subproblem, 5);
+    /// ```
+    pub fn new(subproblem: R, m: usize) -> Self {
+        LSR1TrustRegion {
+            subproblem,
+            m,
+            s: VecDeque::with_capacity(m),
+            y: VecDeque::with_capacity(m),
+            denominator_factor: float!(1e-8),
+            radius: float!(1.0),
+            eta: float!(0.5 * 1e-3),
+            tol_grad: float!(1e-3),
+        }
+    }
+
+    /// Set denominator factor
+    ///
+    /// If the denominator of an update is below the `denominator_factor` (scaled with other
+    /// factors derived from the parameter vectors and the gradients), then the corresponding pair
+    /// is skipped.
+    ///
+    /// Must be in `(0, 1)` and defaults to `1e-8`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::quasinewton::LSR1TrustRegion;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let subproblem = ();
+    /// let lsr1: LSR1TrustRegion<_, Vec<f64>, f64> =
+    ///     LSR1TrustRegion::new(subproblem, 5).with_denominator_factor(1e-7)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_denominator_factor(mut self, denominator_factor: F) -> Result<Self, Error> {
+        if denominator_factor <= float!(0.0) || denominator_factor >= float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`LSR1TrustRegion`: denominator_factor must be in (0, 1)."
+            ));
+        }
+        self.denominator_factor = denominator_factor;
+        Ok(self)
+    }
+
+    /// Set initial radius
+    ///
+    /// Defaults to 1.0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::quasinewton::LSR1TrustRegion;
+    /// # let subproblem = ();
+    /// let lsr1: LSR1TrustRegion<_, Vec<f64>, f64> =
+    ///     LSR1TrustRegion::new(subproblem, 5).with_radius(2.0);
+    /// ```
+    #[must_use]
+    pub fn with_radius(mut self, radius: F) -> Self {
+        self.radius = radius.abs();
+        self
+    }
+
+    /// Set eta
+    ///
+    /// A step is taken if the actual reduction over the predicted reduction exceeds eta.
+    /// Must be in (0, 10^-3) and defaults to 0.5 * 10^-3.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::quasinewton::LSR1TrustRegion;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let subproblem = ();
+    /// let lsr1: LSR1TrustRegion<_, Vec<f64>, f64> =
+    ///     LSR1TrustRegion::new(subproblem, 5).with_eta(1e-4)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_eta(mut self, eta: F) -> Result<Self, Error> {
+        if eta >= float!(1e-3) || eta <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`LSR1TrustRegion`: eta must be in (0, 10^-3)."
+            ));
+        }
+        self.eta = eta;
+        Ok(self)
+    }
+
+    /// The algorithm stops if the norm of the gradient is below `tol_grad`.
+    ///
+    /// The provided value must be non-negative. Defaults to `10^-3`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::quasinewton::LSR1TrustRegion;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let subproblem = ();
+    /// let lsr1: LSR1TrustRegion<_, Vec<f64>, f64> =
+    ///     LSR1TrustRegion::new(subproblem, 5).with_tolerance_grad(1e-6)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance_grad(mut self, tol_grad: F) -> Result<Self, Error> {
+        if tol_grad < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`LSR1TrustRegion`: gradient tolerance must be >= 0."
+            ));
+        }
+        self.tol_grad = tol_grad;
+        Ok(self)
+    }
+
+    /// Builds the limited-memory Hessian approximation from the stored pairs.
+    fn hessian(&self) -> Result<LSR1Hessian<P, F>, Error>
+    where
+        P: ArgminSub<P, P>
+            + ArgminDot<P, F>
+            + ArgminMul<F, P>
+            + ArgminScaledAdd<P, F, P>
+            + ArgminL2Norm<F>,
+    {
+        let gamma = self
+            .s
+            .iter()
+            .zip(self.y.iter())
+            .rev()
+            .find_map(|(sk, yk)| {
+                let sy: F = sk.dot(yk);
+                if sy > float!(0.0) {
+                    let yy: F = yk.dot(yk);
+                    Some(yy / sy)
+                } else {
+                    None
+                }
+            })
+            .unwrap_or_else(|| float!(1.0));
+
+        let mut hessian = LSR1Hessian::new(gamma);
+        for (sk, yk) in self.s.iter().zip(self.y.iter()) {
+            let uk = yk.sub(&hessian.apply(sk)?);
+            let dk: F = uk.dot(sk);
+            if dk.abs() > self.denominator_factor * sk.l2_norm() * uk.l2_norm() {
+                hessian.u.push(uk);
+                hessian.d.push(dk);
+            }
+        }
+        Ok(hessian)
+    }
+}
+
+impl<O, R, P, F> Solver<O, IterState<P, P, (), (), F>> for LSR1TrustRegion<R, P, F>
+where
+    O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = P>,
+    P: Clone
+        + SerializeAlias
+        + DeserializeOwnedAlias
+        + ArgminSub<P, P>
+        + ArgminAdd<P, P>
+        + ArgminDot<P, F>
+        + ArgminMul<F, P>
+        + ArgminScaledAdd<P, F, P>
+        + ArgminL2Norm<F>
+        + ArgminZeroLike,
+    R: Clone + TrustRegionRadius<F> + Solver<O, IterState<P, P, (), LSR1Hessian<P, F>, F>>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "L-SR1 trust region";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, P, (), (), F>,
+    ) -> Result<(IterState<P, P, (), (), F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`LSR1TrustRegion` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+
+        let cost = state.get_cost();
+        let cost = if cost.is_infinite() {
+            problem.cost(&param)?
+        } else {
+            cost
+        };
+
+        let grad = state
+            .take_gradient()
+            .map(Result::Ok)
+            .unwrap_or_else(|| problem.gradient(&param))?;
+
+        Ok((state.param(param).cost(cost).gradient(grad), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, P, (), (), F>,
+    ) -> Result<(IterState<P, P, (), (), F>, Option<KV>), Error> {
+        let xk = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`LSR1TrustRegion`: Parameter vector in state not set."
+        ))?;
+
+        let cost = state.get_cost();
+
+        let prev_grad = state.take_gradient().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`LSR1TrustRegion`: Gradient in state not set."
+        ))?;
+
+        let hessian = self.hessian()?;
+        let num_updates = hessian.num_updates();
+
+        self.subproblem.set_radius(self.radius);
+
+        let OptimizationResult {
+            problem: sub_problem,
+            state: mut sub_state,
+            ..
+        } = Executor::new(problem.take_problem().unwrap(), self.subproblem.clone())
+            .configure(|config| {
+                config
+                    .param(xk.zero_like())
+                    .hessian(hessian.clone())
+                    .gradient(prev_grad.clone())
+                    .cost(cost)
+            })
+            .ctrlc(false)
+            .run()?;
+
+        let sk = sub_state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`LSR1TrustRegion`: No parameters returned by subproblem."
+        ))?;
+
+        problem.consume_problem(sub_problem);
+
+        let xksk = xk.add(&sk);
+        let dfk1 = problem.gradient(&xksk)?;
+        let yk = dfk1.sub(&prev_grad);
+        let fk1 = problem.cost(&xksk)?;
+
+        let ared = cost - fk1;
+        let tmp1: F = prev_grad.dot(&sk);
+        let tmp2: F = sk.dot(&hessian.apply(&sk)?);
+        let tmp2: F = tmp2.mul(float!(0.5));
+        let pred = -tmp1 - tmp2;
+        let ap = ared / pred;
+
+        let (xk1, fk1, dfk1) = if ap > self.eta {
+            (xksk, fk1, dfk1)
+        } else {
+            (xk, cost, prev_grad)
+        };
+
+        self.radius = if ap > float!(0.75) {
+            if sk.l2_norm() <= float!(0.8) * self.radius {
+                self.radius
+            } else {
+                float!(2.0) * self.radius
+            }
+        } else if ap <= float!(0.75) && ap >= float!(0.1) {
+            self.radius
+        } else {
+            float!(0.5) * self.radius
+        };
+
+        if self.s.len() >= self.m {
+            self.s.pop_front();
+            self.y.pop_front();
+        }
+        self.s.push_back(sk);
+        self.y.push_back(yk);
+
+        Ok((
+            state.param(xk1).cost(fk1).gradient(dfk1),
+            Some(kv!["ared" => ared;
+                     "pred" => pred;
+                     "ap" => ap;
+                     "radius" => self.radius;
+                     "num_updates" => num_updates as u64;]),
+        ))
+    }
+
+    fn terminate(&mut self, state: &IterState<P, P, (), (), F>) -> TerminationStatus {
+        if state.get_gradient().unwrap().l2_norm() < self.tol_grad {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{test_utils::TestProblem, ArgminError, State};
+    use crate::solver::trustregion::Steihaug;
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+    use argmin_testfunctions::{rosenbrock_2d, rosenbrock_2d_derivative};
+
+    test_trait_impl!(
+        lsr1,
+        LSR1TrustRegion<Steihaug<Vec<f64>, f64>, Vec<f64>, f64>
+    );
+
+    #[test]
+    fn test_new() {
+        #[derive(Eq, PartialEq, Debug)]
+        struct MyFakeSubProblem {}
+
+        let lsr1: LSR1TrustRegion<_, Vec<f64>, f64> = LSR1TrustRegion::new(MyFakeSubProblem {}, 5);
+        let LSR1TrustRegion {
+            subproblem,
+            m,
+            s,
+            y,
+            denominator_factor,
+            radius,
+            eta,
+            tol_grad,
+        } = lsr1;
+
+        assert_eq!(subproblem, MyFakeSubProblem {});
+        assert_eq!(m, 5);
+        assert!(s.is_empty());
+        assert!(y.is_empty());
+        assert_eq!(denominator_factor.to_ne_bytes(), 1e-8f64.to_ne_bytes());
+        assert_eq!(radius.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(eta.to_ne_bytes(), (0.5f64 * 1e-3f64).to_ne_bytes());
+        assert_eq!(tol_grad.to_ne_bytes(), 1e-3f64.to_ne_bytes());
+    }
+
+    #[test]
+    fn test_with_denominator_factor() {
+        #[derive(Eq, PartialEq, Debug, Clone, Copy)]
+        struct MyFakeSubProblem {}
+
+        // correct parameters
+        for tol in [f64::EPSILON, 1e-8, 1e-6, 1e-2, 1.0 - f64::EPSILON] {
+            let lsr1: LSR1TrustRegion<_, Vec<f64>, f64> =
+                LSR1TrustRegion::new(MyFakeSubProblem {}, 5);
+            let res = lsr1.with_denominator_factor(tol);
+            assert!(res.is_ok());
+
+            let nm = res.unwrap();
+            assert_eq!(nm.denominator_factor.to_ne_bytes(), tol.to_ne_bytes());
+        }
+
+        // incorrect parameters
+        for tol in [-f64::EPSILON, 0.0, -1.0, 1.0] {
+            let lsr1: LSR1TrustRegion<_, Vec<f64>, f64> =
+                LSR1TrustRegion::new(MyFakeSubProblem {}, 5);
+            let res = lsr1.with_denominator_factor(tol);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`LSR1TrustRegion`: denominator_factor must be in (0, 1).\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_eta() {
+        #[derive(Eq, PartialEq, Debug, Clone, Copy)]
+        struct MyFakeSubProblem {}
+
+        let lsr1: LSR1TrustRegion<_, Vec<f64>, f64> = LSR1TrustRegion::new(MyFakeSubProblem {}, 5);
+        let res = lsr1.with_eta(1e-4);
+        assert_eq!(res.unwrap().eta.to_ne_bytes(), 1e-4f64.to_ne_bytes());
+
+        for eta in [0.0, -1.0, 1e-3, 1.0] {
+            let lsr1: LSR1TrustRegion<_, Vec<f64>, f64> =
+                LSR1TrustRegion::new(MyFakeSubProblem {}, 5);
+            let res = lsr1.with_eta(eta);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`LSR1TrustRegion`: eta must be in (0, 10^-3).\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_tolerance_grad() {
+        #[derive(Eq, PartialEq, Debug, Clone, Copy)]
+        struct MyFakeSubProblem {}
+
+        // correct parameters
+        for tol in [1e-6, 0.0, 1e-2, 1.0, 2.0] {
+            let lsr1: LSR1TrustRegion<_, Vec<f64>, f64> =
+                LSR1TrustRegion::new(MyFakeSubProblem {}, 5);
+            let res = lsr1.with_tolerance_grad(tol);
+            assert!(res.is_ok());
+
+            let nm = res.unwrap();
+            assert_eq!(nm.tol_grad.to_ne_bytes(), tol.to_ne_bytes());
+        }
+
+        // incorrect parameters
+        for tol in [-f64::EPSILON, -1.0, -100.0, -42.0] {
+            let lsr1: LSR1TrustRegion<_, Vec<f64>, f64> =
+                LSR1TrustRegion::new(MyFakeSubProblem {}, 5);
+            let res = lsr1.with_tolerance_grad(tol);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`LSR1TrustRegion`: gradient tolerance must be >= 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_hessian() {
+        let mut lsr1: LSR1TrustRegion<(), Vec<f64>, f64> = LSR1TrustRegion::new((), 5);
+
+        // Without any pairs the approximation is the identity
+        let hessian = lsr1.hessian().unwrap();
+        assert_eq!(hessian.num_updates(), 0);
+        let hv = hessian.apply(&vec![1.0, 2.0]).unwrap();
+        assert_eq!(hv[0].to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(hv[1].to_ne_bytes(), 2.0f64.to_ne_bytes());
+
+        // Pairs obtained from the indefinite quadratic with Hessian [[2, 0], [0, -1]]
+        lsr1.s.push_back(vec![1.0, 0.0]);
+        lsr1.y.push_back(vec![2.0, 0.0]);
+        lsr1.s.push_back(vec![1.0, 1.0]);
+        lsr1.y.push_back(vec![2.0, -1.0]);
+        let hessian = lsr1.hessian().unwrap();
+        assert_eq!(hessian.num_updates(), 2);
+
+        // Secant equations are satisfied
+        for (sk, yk) in lsr1.s.iter().zip(lsr1.y.iter()) {
+            let bs = hessian.apply(sk).unwrap();
+            assert_relative_eq!(bs[0], yk[0], epsilon = 1e-12);
+            assert_relative_eq!(bs[1], yk[1], epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_init() {
+        let subproblem: Steihaug<Vec<f64>, f64> = Steihaug::new();
+
+        let param: Vec<f64> = vec![-1.0, 1.0];
+
+        let mut lsr1: LSR1TrustRegion<_, Vec<f64>, f64> = LSR1TrustRegion::new(subproblem, 5);
+
+        // Forgot to initialize the parameter vector
+        let state: IterState<Vec<f64>, Vec<f64>, (), (), f64> = IterState::new();
+        let problem = TestProblem::new();
+        let res = lsr1.init(&mut Problem::new(problem), state);
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`LSR1TrustRegion` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+
+        // All good.
+        let state: IterState<Vec<f64>, Vec<f64>, (), (), f64> =
+            IterState::new().param(param.clone());
+        let problem = TestProblem::new();
+        let (mut state_out, kv) = lsr1.init(&mut Problem::new(problem), state).unwrap();
+
+        assert!(kv.is_none());
+
+        let s_param = state_out.take_param().unwrap();
+
+        for (s, p) in s_param.iter().zip(param.iter()) {
+            assert_eq!(s.to_ne_bytes(), p.to_ne_bytes());
+        }
+
+        let s_grad = state_out.take_gradient().unwrap();
+
+        for (s, p) in s_grad.iter().zip(param.iter()) {
+            assert_eq!(s.to_ne_bytes(), p.to_ne_bytes());
+        }
+
+        assert_eq!(state_out.get_cost().to_ne_bytes(), 1.0f64.to_ne_bytes())
+    }
+
+    #[test]
+    fn test_solver() {
+        struct Rosenbrock {}
+
+        impl CostFunction for Rosenbrock {
+            type Param = Vec<f64>;
+            type Output = f64;
+
+            fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(rosenbrock_2d(p, 1.0, 100.0))
+            }
+        }
+
+        impl Gradient for Rosenbrock {
+            type Param = Vec<f64>;
+            type Gradient = Vec<f64>;
+
+            fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+                Ok(rosenbrock_2d_derivative(p, 1.0, 100.0))
+            }
+        }
+
+        let subproblem = Steihaug::new().with_max_iters(20);
+        let lsr1 = LSR1TrustRegion::new(subproblem, 5)
+            .with_tolerance_grad(1e-6)
+            .unwrap();
+        let res = Executor::new(Rosenbrock {}, lsr1)
+            .configure(|state| state.param(vec![-1.2, 1.0]).max_iters(1000))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        for p in res.state.get_best_param().unwrap() {
+            assert_relative_eq!(*p, 1.0, epsilon = 1e-4);
+        }
+    }
+}
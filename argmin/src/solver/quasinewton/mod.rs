@@ -9,7 +9,9 @@
 //!
 //! * [`BFGS`]
 //! * [`DFP`]
-//! * [`LBFGS`]
+//! * [`LBFGS`] (switches to OWL-QN if L1-regularization is enabled via
+//!   [`with_l1_regularization`](`LBFGS::with_l1_regularization`))
+//! * [`LSR1TrustRegion`]
 //! * [`SR1`]
 //! * [`SR1TrustRegion`]
 //!
@@ -17,15 +19,83 @@
 //!
 //! Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
 //! Springer. ISBN 0-387-30303-0.
+//!
+//! Galen Andrew and Jianfeng Gao (2007). Scalable Training of L1-Regularized Log-Linear Models,
+//! International Conference on Machine Learning.
 
 mod bfgs;
 mod dfp;
 mod lbfgs;
+mod lsr1_trustregion;
 mod sr1;
 mod sr1_trustregion;
 
 pub use self::bfgs::BFGS;
 pub use self::dfp::DFP;
 pub use self::lbfgs::LBFGS;
+pub use self::lsr1_trustregion::{LSR1Hessian, LSR1TrustRegion};
 pub use self::sr1::SR1;
 pub use self::sr1_trustregion::SR1TrustRegion;
+
+use crate::core::ArgminFloat;
+use argmin_math::{ArgminDot, ArgminScaledAdd};
+
+/// Powell damping of the gradient difference `y_k = g_{k+1} - g_k`.
+///
+/// Returns `r_k = theta * y_k + (1 - theta) * B_k s_k`, where `theta` in `(0, 1]` is chosen such
+/// that `s_k^T r_k >= 0.2 * s_k^T B_k s_k`. This keeps the BFGS update well-defined (and the
+/// approximation positive definite) even if the curvature condition `s_k^T y_k > 0` fails.
+/// Since the step `s_k = alpha_k * d_k` was taken along the quasi-Newton direction
+/// `d_k = -H_k g_k`, the product of the Hessian approximation `B_k = H_k^{-1}` and `s_k` is
+/// available without any matrix operations as `B_k s_k = -alpha_k * g_k`.
+///
+/// If `d_k` is not a descent direction, `y_k` is returned unchanged.
+///
+/// ## Reference
+///
+/// Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
+/// Springer. ISBN 0-387-30303-0. Procedure 18.2.
+fn powell_damping<P, G, F>(sk: &P, yk: G, prev_grad: &G, direction: &P) -> G
+where
+    G: ArgminDot<P, F> + ArgminScaledAdd<G, F, G>,
+    F: ArgminFloat,
+{
+    let gd: F = prev_grad.dot(direction);
+    let gs: F = prev_grad.dot(sk);
+    let alpha = gs / gd;
+    let sbs = -alpha * gs;
+    if !sbs.is_finite() || sbs <= float!(0.0) {
+        return yk;
+    }
+    let sy: F = yk.dot(sk);
+    if sy >= float!(0.2) * sbs {
+        return yk;
+    }
+    let theta = float!(0.8) * sbs / (sbs - sy);
+    // theta * y + (1 - theta) * (-alpha * g) = y + (theta - 1) * (y + alpha * g)
+    yk.scaled_add(&(theta - float!(1.0)), &yk.scaled_add(&alpha, prev_grad))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_powell_damping() {
+        // B_k s_k = [1, 0], s_k^T B_k s_k = 1
+        let sk = vec![1.0f64, 0.0];
+        let direction = vec![1.0f64, 0.0];
+        let prev_grad = vec![-1.0f64, 0.0];
+
+        // Curvature condition satisfied: no damping
+        let yk = powell_damping::<_, _, f64>(&sk, vec![0.5f64, 1.0], &prev_grad, &direction);
+        assert_eq!(yk[0].to_ne_bytes(), 0.5f64.to_ne_bytes());
+        assert_eq!(yk[1].to_ne_bytes(), 1.0f64.to_ne_bytes());
+
+        // Curvature condition violated: theta = 0.4
+        let yk = powell_damping::<_, _, f64>(&sk, vec![-1.0f64, 1.0], &prev_grad, &direction);
+        let sy: f64 = yk.dot(&sk);
+        assert!((sy - 0.2).abs() < 1e-12);
+        assert!((yk[1] - 0.4).abs() < 1e-12);
+    }
+}
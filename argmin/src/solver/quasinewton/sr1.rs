@@ -10,6 +10,7 @@ use crate::core::{
     LineSearch, OptimizationResult, Problem, SerializeAlias, Solver, TerminationReason,
     TerminationStatus, KV,
 };
+use crate::solver::linesearch::linesearch_kv;
 use argmin_math::{ArgminAdd, ArgminDot, ArgminL2Norm, ArgminMul, ArgminSub};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
@@ -244,6 +245,7 @@ where
         // Run solver
         let OptimizationResult {
             problem: line_problem,
+            solver: linesearch,
             state: mut linesearch_state,
             ..
         } = Executor::new(problem.take_problem().unwrap(), self.linesearch.clone())
@@ -255,6 +257,7 @@ where
             })
             .ctrlc(false)
             .run()?;
+        self.linesearch = linesearch;
 
         let xk1 = linesearch_state.take_param().unwrap();
         let next_cost = linesearch_state.get_cost();
@@ -287,7 +290,10 @@ where
                 .cost(next_cost)
                 .gradient(grad)
                 .inv_hessian(inv_hessian),
-            Some(kv!["denominator" => b; "hessian_update" => hessian_update;]),
+            Some(
+                kv!["denominator" => b; "hessian_update" => hessian_update;]
+                    .merge(linesearch_kv(&self.linesearch, &linesearch_state)),
+            ),
         ))
     }
 
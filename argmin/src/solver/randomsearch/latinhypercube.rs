@@ -0,0 +1,209 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::{evaluate_samples, latin_hypercube};
+use crate::core::{
+    ArgminFloat, Bounds, CostFunction, Error, IterState, Problem, SerializeAlias, Solver, State,
+    SyncAlias, KV,
+};
+use rand::prelude::*;
+use rand_xoshiro::Xoshiro256PlusPlus;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Latin hypercube sampling
+///
+/// Evaluates a Latin hypercube design of `num_samples` points within the bounds in each
+/// iteration (see [`latin_hypercube`]) and keeps the best one. Compared to pure random search
+/// ([`RandomSearch`](`crate::solver::randomsearch::RandomSearch`)), the samples of a design cover
+/// the range of each parameter evenly. The parameter vector in the state is the best sample of the
+/// current design, the best sample found overall is available as the best parameter vector of the
+/// state.
+///
+/// An initial parameter vector is optional. If provided via the `configure` method of the
+/// [`Executor`](`crate::core::Executor`), it is evaluated during initialization and serves as the
+/// initial best parameter vector.
+///
+/// The algorithm has no convergence criterion on its own and stops when the maximum number of
+/// iterations or the target cost is reached. Often, a single iteration is sufficient.
+///
+/// The `rayon` feature enables parallel computation of the cost function of the samples.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`].
+///
+/// ## Reference
+///
+/// Michael D. McKay, Richard J. Beckman and William J. Conover (1979). A comparison of three
+/// methods for selecting values of input variables in the analysis of output from a computer
+/// code. Technometrics 21(2), 239-245.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct LatinHypercube<F, R> {
+    /// Bounds on parameter space
+    bounds: Bounds<Vec<F>>,
+    /// Number of samples per design
+    num_samples: usize,
+    /// Random number generator
+    rng: R,
+}
+
+impl<F> LatinHypercube<F, Xoshiro256PlusPlus>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`LatinHypercube`]
+    ///
+    /// Takes the bounds on the parameter space and the number of samples per design, which must
+    /// be larger than 0.
+    ///
+    /// Uses the `Xoshiro256PlusPlus` RNG internally. For use of another RNG, consider using
+    /// [`LatinHypercube::new_with_rng`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::randomsearch::LatinHypercube;
+    /// # use argmin::core::{Bounds, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let lhs = LatinHypercube::new(Bounds::new(vec![-1.0f64, -1.0], vec![1.0, 1.0])?, 100)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(bounds: Bounds<Vec<F>>, num_samples: usize) -> Result<Self, Error> {
+        LatinHypercube::new_with_rng(bounds, num_samples, Xoshiro256PlusPlus::from_entropy())
+    }
+}
+
+impl<F, R> LatinHypercube<F, R>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`LatinHypercube`]
+    ///
+    /// Takes the bounds on the parameter space, the number of samples per design, which must be
+    /// larger than 0, and a RNG which must implement `rand::Rng` (and `serde::Serialize` if the
+    /// `serde1` feature is enabled).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::randomsearch::LatinHypercube;
+    /// # use argmin::core::{Bounds, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// # let my_rng = ();
+    /// let bounds = Bounds::new(vec![-1.0f64, -1.0], vec![1.0, 1.0])?;
+    /// let lhs = LatinHypercube::new_with_rng(bounds, 100, my_rng)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_with_rng(bounds: Bounds<Vec<F>>, num_samples: usize, rng: R) -> Result<Self, Error> {
+        if num_samples == 0 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`LatinHypercube`: number of samples must be > 0."
+            ));
+        }
+        Ok(LatinHypercube {
+            bounds,
+            num_samples,
+            rng,
+        })
+    }
+}
+
+impl<O, F, R> Solver<O, IterState<Vec<F>, (), (), (), F>> for LatinHypercube<F, R>
+where
+    O: CostFunction<Param = Vec<F>, Output = F> + SyncAlias,
+    F: ArgminFloat + SyncAlias,
+    R: Rng + SerializeAlias,
+{
+    const NAME: &'static str = "Latin hypercube sampling";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<Vec<F>, (), (), (), F>,
+    ) -> Result<(IterState<Vec<F>, (), (), (), F>, Option<KV>), Error> {
+        let cost = match state.get_param() {
+            Some(param) if state.get_cost().is_infinite() => problem.cost(param)?,
+            _ => state.get_cost(),
+        };
+        Ok((state.cost(cost), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<Vec<F>, (), (), (), F>,
+    ) -> Result<(IterState<Vec<F>, (), (), (), F>, Option<KV>), Error> {
+        let samples = latin_hypercube(&mut self.rng, &self.bounds, self.num_samples);
+        Ok((evaluate_samples(problem, state, samples)?, None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Executor};
+    use crate::test_trait_impl;
+
+    test_trait_impl!(latin_hypercube, LatinHypercube<f64, Xoshiro256PlusPlus>);
+
+    struct Sphere {}
+
+    impl CostFunction for Sphere {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(p.iter().map(|x| x.powi(2)).sum())
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let bounds = Bounds::new(vec![-1.0f64, -2.0], vec![1.0, 2.0]).unwrap();
+        let lhs = LatinHypercube::new(bounds.clone(), 50).unwrap();
+        let LatinHypercube {
+            bounds: lhs_bounds,
+            num_samples,
+            rng: _,
+        } = lhs;
+        assert_eq!(lhs_bounds, bounds);
+        assert_eq!(num_samples, 50);
+
+        let res = LatinHypercube::new(bounds, 0);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`LatinHypercube`: number of samples must be > 0.\""
+        );
+    }
+
+    #[test]
+    fn test_solver() {
+        let bounds = Bounds::new(vec![-1.0f64, -1.0], vec![2.0, 2.0]).unwrap();
+        let lhs = LatinHypercube::new_with_rng(
+            bounds.clone(),
+            100,
+            Xoshiro256PlusPlus::seed_from_u64(42),
+        )
+        .unwrap();
+        let res = Executor::new(Sphere {}, lhs)
+            .configure(|state| state.max_iters(10))
+            .run()
+            .unwrap();
+        assert_eq!(res.problem.counts["cost_count"], 1000);
+        let best = res.state.get_best_param().unwrap();
+        assert!(bounds.contains(best));
+        for x in best {
+            assert!(x.abs() < 0.2);
+        }
+    }
+}
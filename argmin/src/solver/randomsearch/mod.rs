@@ -0,0 +1,122 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Random search
+//!
+//! Sampling-based solvers over box-constrained domains, which are useful as cheap baselines for
+//! other solvers and for generating initial guesses.
+//!
+//! * [`RandomSearch`]: Pure random search with uniformly distributed samples
+//! * [`LatinHypercube`]: Latin hypercube sampling
+//!
+//! The samples of a Latin hypercube design can also be obtained directly via
+//! [`latin_hypercube`], for instance to create initial populations for population-based solvers.
+//!
+//! ## References
+//!
+//! Michael D. McKay, Richard J. Beckman and William J. Conover (1979). A comparison of three
+//! methods for selecting values of input variables in the analysis of output from a computer
+//! code. Technometrics 21(2), 239-245.
+
+mod latinhypercube;
+mod random;
+
+pub use latinhypercube::LatinHypercube;
+pub use random::RandomSearch;
+
+use crate::core::{ArgminFloat, Bounds, CostFunction, Error, IterState, Problem, SyncAlias};
+use rand::prelude::*;
+
+/// Evaluates the cost function for all samples and updates the state with the best one
+fn evaluate_samples<O, F>(
+    problem: &mut Problem<O>,
+    state: IterState<Vec<F>, (), (), (), F>,
+    samples: Vec<Vec<F>>,
+) -> Result<IterState<Vec<F>, (), (), (), F>, Error>
+where
+    O: CostFunction<Param = Vec<F>, Output = F> + SyncAlias,
+    F: ArgminFloat + SyncAlias,
+{
+    let costs = problem.bulk_cost(&samples)?;
+    let (best, best_cost) = costs
+        .into_iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+        .ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "No samples to evaluate."
+        ))?;
+    Ok(state
+        .param(samples.into_iter().nth(best).unwrap())
+        .cost(best_cost))
+}
+
+/// Latin hypercube sample of `num_samples` points within `bounds`
+///
+/// The range of each parameter is divided into `num_samples` intervals of equal width. Each
+/// interval contains exactly one sample, which is placed uniformly at random within it. The
+/// intervals are assigned to the samples via independent random permutations for each parameter.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::core::{Bounds, Error};
+/// # use argmin::solver::randomsearch::latin_hypercube;
+/// # use rand::SeedableRng;
+/// # fn main() -> Result<(), Error> {
+/// let mut rng = rand_xoshiro::Xoshiro256PlusPlus::seed_from_u64(42);
+/// let bounds = Bounds::new(vec![0.0f64, -1.0], vec![1.0, 1.0])?;
+/// let samples = latin_hypercube(&mut rng, &bounds, 10);
+/// # assert_eq!(samples.len(), 10);
+/// # Ok(())
+/// # }
+/// ```
+pub fn latin_hypercube<F, R>(
+    rng: &mut R,
+    bounds: &Bounds<Vec<F>>,
+    num_samples: usize,
+) -> Vec<Vec<F>>
+where
+    F: ArgminFloat,
+    R: Rng,
+{
+    let n = F::from_usize(num_samples).unwrap();
+    let mut samples = vec![Vec::with_capacity(bounds.lower().len()); num_samples];
+    for (&lower, &upper) in bounds.lower().iter().zip(bounds.upper().iter()) {
+        let mut strata: Vec<usize> = (0..num_samples).collect();
+        strata.shuffle(rng);
+        for (sample, stratum) in samples.iter_mut().zip(strata) {
+            let u: F = float!(rng.gen::<f64>());
+            let t = (F::from_usize(stratum).unwrap() + u) / n;
+            sample.push(lower + t * (upper - lower));
+        }
+    }
+    samples
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_xoshiro::Xoshiro256PlusPlus;
+
+    #[test]
+    fn test_latin_hypercube() {
+        let mut rng = Xoshiro256PlusPlus::seed_from_u64(42);
+        let bounds = Bounds::new(vec![0.0f64, -2.0], vec![1.0, 2.0]).unwrap();
+        let samples = latin_hypercube(&mut rng, &bounds, 8);
+        assert_eq!(samples.len(), 8);
+        // Each of the 8 intervals of each parameter contains exactly one sample
+        for (i, (lower, upper)) in bounds.lower().iter().zip(bounds.upper()).enumerate() {
+            let mut strata: Vec<usize> = samples
+                .iter()
+                .map(|s| ((s[i] - lower) / (upper - lower) * 8.0).floor() as usize)
+                .collect();
+            strata.sort_unstable();
+            assert_eq!(strata, (0..8).collect::<Vec<_>>());
+        }
+    }
+}
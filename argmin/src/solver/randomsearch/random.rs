@@ -0,0 +1,254 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::evaluate_samples;
+use crate::core::{
+    ArgminFloat, Bounds, CostFunction, Error, IterState, Problem, SerializeAlias, Solver, State,
+    SyncAlias, KV,
+};
+use rand::prelude::*;
+use rand_xoshiro::Xoshiro256PlusPlus;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Random search
+///
+/// Samples parameter vectors uniformly from within the bounds and keeps the best one. In each
+/// iteration, `samples_per_iter` samples are evaluated (see
+/// [`with_samples_per_iter`](`RandomSearch::with_samples_per_iter`)). The parameter vector in the
+/// state is the best sample of the current iteration, the best sample found overall is available
+/// as the best parameter vector of the state.
+///
+/// An initial parameter vector is optional. If provided via the `configure` method of the
+/// [`Executor`](`crate::core::Executor`), it is evaluated during initialization and serves as the
+/// initial best parameter vector.
+///
+/// The algorithm has no convergence criterion on its own and stops when the maximum number of
+/// iterations or the target cost is reached.
+///
+/// The `rayon` feature enables parallel computation of the cost function of the samples.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`].
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct RandomSearch<F, R> {
+    /// Bounds on parameter space
+    bounds: Bounds<Vec<F>>,
+    /// Number of samples per iteration
+    samples_per_iter: usize,
+    /// Random number generator
+    rng: R,
+}
+
+impl<F> RandomSearch<F, Xoshiro256PlusPlus>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`RandomSearch`]
+    ///
+    /// Takes the bounds on the parameter space.
+    ///
+    /// Uses the `Xoshiro256PlusPlus` RNG internally. For use of another RNG, consider using
+    /// [`RandomSearch::new_with_rng`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::randomsearch::RandomSearch;
+    /// # use argmin::core::{Bounds, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let rs = RandomSearch::new(Bounds::new(vec![-1.0f64, -1.0], vec![1.0, 1.0])?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(bounds: Bounds<Vec<F>>) -> Self {
+        RandomSearch::new_with_rng(bounds, Xoshiro256PlusPlus::from_entropy())
+    }
+}
+
+impl<F, R> RandomSearch<F, R>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`RandomSearch`]
+    ///
+    /// Takes the bounds on the parameter space and a RNG which must implement `rand::Rng` (and
+    /// `serde::Serialize` if the `serde1` feature is enabled).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::randomsearch::RandomSearch;
+    /// # use argmin::core::{Bounds, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// # let my_rng = ();
+    /// let bounds = Bounds::new(vec![-1.0f64, -1.0], vec![1.0, 1.0])?;
+    /// let rs = RandomSearch::new_with_rng(bounds, my_rng);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_with_rng(bounds: Bounds<Vec<F>>, rng: R) -> Self {
+        RandomSearch {
+            bounds,
+            samples_per_iter: 1,
+            rng,
+        }
+    }
+
+    /// Set the number of samples evaluated per iteration
+    ///
+    /// Must be larger than 0 and defaults to `1`. Larger values allow the samples to be evaluated
+    /// in parallel with the `rayon` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::randomsearch::RandomSearch;
+    /// # use argmin::core::{Bounds, Error};
+    /// # fn main() -> Result<(), Error> {
+    /// let bounds = Bounds::new(vec![-1.0f64, -1.0], vec![1.0, 1.0])?;
+    /// let rs = RandomSearch::new(bounds).with_samples_per_iter(100)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_samples_per_iter(mut self, samples_per_iter: usize) -> Result<Self, Error> {
+        if samples_per_iter == 0 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`RandomSearch`: number of samples per iteration must be > 0."
+            ));
+        }
+        self.samples_per_iter = samples_per_iter;
+        Ok(self)
+    }
+}
+
+impl<O, F, R> Solver<O, IterState<Vec<F>, (), (), (), F>> for RandomSearch<F, R>
+where
+    O: CostFunction<Param = Vec<F>, Output = F> + SyncAlias,
+    F: ArgminFloat + SyncAlias,
+    R: Rng + SerializeAlias,
+{
+    const NAME: &'static str = "Random search";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<Vec<F>, (), (), (), F>,
+    ) -> Result<(IterState<Vec<F>, (), (), (), F>, Option<KV>), Error> {
+        let cost = match state.get_param() {
+            Some(param) if state.get_cost().is_infinite() => problem.cost(param)?,
+            _ => state.get_cost(),
+        };
+        Ok((state.cost(cost), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<Vec<F>, (), (), (), F>,
+    ) -> Result<(IterState<Vec<F>, (), (), (), F>, Option<KV>), Error> {
+        let (lower, upper) = (self.bounds.lower(), self.bounds.upper());
+        let samples = (0..self.samples_per_iter)
+            .map(|_| {
+                lower
+                    .iter()
+                    .zip(upper.iter())
+                    .map(|(&l, &u)| l + float!(self.rng.gen::<f64>()) * (u - l))
+                    .collect()
+            })
+            .collect();
+        Ok((evaluate_samples(problem, state, samples)?, None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Executor};
+    use crate::test_trait_impl;
+
+    test_trait_impl!(random_search, RandomSearch<f64, Xoshiro256PlusPlus>);
+
+    struct Sphere {}
+
+    impl CostFunction for Sphere {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(p.iter().map(|x| x.powi(2)).sum())
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let bounds = Bounds::new(vec![-1.0f64, -2.0], vec![1.0, 2.0]).unwrap();
+        let rs = RandomSearch::new(bounds.clone());
+        let RandomSearch {
+            bounds: rs_bounds,
+            samples_per_iter,
+            rng: _,
+        } = rs;
+        assert_eq!(rs_bounds, bounds);
+        assert_eq!(samples_per_iter, 1);
+    }
+
+    #[test]
+    fn test_with_samples_per_iter() {
+        let rs = RandomSearch::new(Bounds::new(vec![-1.0f64], vec![1.0]).unwrap());
+        let rs = rs.with_samples_per_iter(10).unwrap();
+        assert_eq!(rs.samples_per_iter, 10);
+
+        let rs = RandomSearch::new(Bounds::new(vec![-1.0f64], vec![1.0]).unwrap());
+        let res = rs.with_samples_per_iter(0);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`RandomSearch`: number of samples per iteration must be > 0.\""
+        );
+    }
+
+    #[test]
+    fn test_solver() {
+        let bounds = Bounds::new(vec![-1.0f64, -1.0], vec![2.0, 2.0]).unwrap();
+        let rs = RandomSearch::new_with_rng(bounds.clone(), Xoshiro256PlusPlus::seed_from_u64(42))
+            .with_samples_per_iter(10)
+            .unwrap();
+        let res = Executor::new(Sphere {}, rs)
+            .configure(|state| state.max_iters(100))
+            .run()
+            .unwrap();
+        assert_eq!(res.problem.counts["cost_count"], 1000);
+        let best = res.state.get_best_param().unwrap();
+        assert!(bounds.contains(best));
+        for x in best {
+            assert!(x.abs() < 0.2);
+        }
+    }
+
+    #[test]
+    fn test_solver_initial_param() {
+        let rs = RandomSearch::new_with_rng(
+            Bounds::new(vec![-1.0f64, -1.0], vec![1.0, 1.0]).unwrap(),
+            Xoshiro256PlusPlus::seed_from_u64(42),
+        );
+        // The initial parameter vector is the optimum and can therefore not be improved upon
+        let res = Executor::new(Sphere {}, rs)
+            .configure(|state| state.param(vec![0.0, 0.0]).max_iters(10))
+            .run()
+            .unwrap();
+        assert_eq!(res.problem.counts["cost_count"], 11);
+        assert_eq!(res.state.get_best_param().unwrap(), &vec![0.0, 0.0]);
+        assert_eq!(
+            res.state.get_best_cost().to_ne_bytes(),
+            0.0f64.to_ne_bytes()
+        );
+    }
+}
@@ -0,0 +1,440 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::{riemannian_gradient, Backtracking, Manifold};
+use crate::core::{
+    ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, Gradient, IterState, Problem,
+    SerializeAlias, Solver, State, TerminationReason, TerminationStatus, KV,
+};
+use argmin_math::{ArgminMul, ArgminSub};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Riemannian conjugate gradient
+///
+/// Nonlinear conjugate gradient method on a manifold, which is provided as an implementation of
+/// the [`Manifold`] trait. The iterates are
+///
+/// `x_{k+1} = R_{x_k}(alpha_k * d_k)`
+///
+/// with the search directions
+///
+/// `d_{k+1} = -grad f(x_{k+1}) + beta_{k+1} * T(d_k)`
+///
+/// where `R` is the retraction and `T` the vector transport from `x_k` to `x_{k+1}` of the
+/// manifold and `grad f` the Riemannian gradient, i.e. the projection of the Euclidean gradient
+/// onto the tangent space. `beta` follows the Polak-Ribiere+ rule
+///
+/// `beta_{k+1} = max(0, <g_{k+1}, g_{k+1} - T(g_k)> / <g_k, g_k>)`
+///
+/// and the method restarts with the negative gradient whenever `d_k` is not a descent direction.
+/// The step length `alpha_k` is found by an Armijo backtracking line search along the retraction
+/// curve, which is configured as for
+/// [`RiemannianGradientDescent`](`super::RiemannianGradientDescent`): Starting from the initial
+/// step length set via [`with_step_length`](`RiemannianConjugateGradient::with_step_length`), the
+/// step length is reduced by the factor set via
+/// [`with_backtracking_factor`](`RiemannianConjugateGradient::with_backtracking_factor`) until
+///
+/// `f(x(alpha)) <= f(x_k) + c * alpha * <grad f(x_k), d_k>`
+///
+/// holds, where `c` is set via
+/// [`with_sufficient_decrease`](`RiemannianConjugateGradient::with_sufficient_decrease`).
+///
+/// The initial parameter vector is mapped onto the manifold before the first iteration. The
+/// algorithm stops once the norm of the Riemannian gradient falls below the tolerance set via
+/// [`with_tolerance`](`RiemannianConjugateGradient::with_tolerance`). The gradient stored in the
+/// state is the Riemannian gradient.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`] and [`Gradient`], where the
+/// gradient is the Euclidean gradient in the embedding space.
+///
+/// ## Reference
+///
+/// P.-A. Absil, R. Mahony and R. Sepulchre (2008). Optimization Algorithms on Matrix Manifolds.
+/// Princeton University Press. ISBN 978-0-691-13298-3.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct RiemannianConjugateGradient<M, P, F> {
+    /// Manifold
+    manifold: M,
+    /// Line search
+    linesearch: Backtracking<F>,
+    /// Tolerance on the norm of the Riemannian gradient
+    tol: F,
+    /// Norm of the Riemannian gradient of the current iterate
+    grad_norm: F,
+    /// Search direction of the current iterate
+    direction: Option<P>,
+}
+
+impl<M, P, F> RiemannianConjugateGradient<M, P, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`RiemannianConjugateGradient`]
+    ///
+    /// Takes the manifold.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::riemannian::{RiemannianConjugateGradient, Sphere};
+    /// let rgd: RiemannianConjugateGradient<_, Vec<f64>, f64> = RiemannianConjugateGradient::new(Sphere::new());
+    /// ```
+    pub fn new(manifold: M) -> Self {
+        RiemannianConjugateGradient {
+            manifold,
+            linesearch: Backtracking::new(),
+            tol: F::epsilon().sqrt(),
+            grad_norm: F::infinity(),
+            direction: None,
+        }
+    }
+
+    /// Set the initial step length of the line search
+    ///
+    /// Must be larger than 0 and defaults to `1.0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::riemannian::{RiemannianConjugateGradient, Sphere};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let rgd: RiemannianConjugateGradient<_, Vec<f64>, f64> =
+    ///     RiemannianConjugateGradient::new(Sphere::new()).with_step_length(0.1)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_step_length(mut self, step_length: F) -> Result<Self, Error> {
+        if step_length <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`RiemannianConjugateGradient`: step length must be > 0."
+            ));
+        }
+        self.linesearch.step_length = step_length;
+        Ok(self)
+    }
+
+    /// Set the sufficient decrease parameter `c` of the line search
+    ///
+    /// Must be in `(0, 1)` and defaults to `1e-4`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::riemannian::{RiemannianConjugateGradient, Sphere};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let rgd: RiemannianConjugateGradient<_, Vec<f64>, f64> =
+    ///     RiemannianConjugateGradient::new(Sphere::new()).with_sufficient_decrease(0.1)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_sufficient_decrease(mut self, c: F) -> Result<Self, Error> {
+        if c <= float!(0.0) || c >= float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`RiemannianConjugateGradient`: sufficient decrease parameter must be in (0, 1)."
+            ));
+        }
+        self.linesearch.c = c;
+        Ok(self)
+    }
+
+    /// Set the factor by which the step length is reduced in the line search
+    ///
+    /// Must be in `(0, 1)` and defaults to `0.5`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::riemannian::{RiemannianConjugateGradient, Sphere};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let rgd: RiemannianConjugateGradient<_, Vec<f64>, f64> =
+    ///     RiemannianConjugateGradient::new(Sphere::new()).with_backtracking_factor(0.8)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_backtracking_factor(mut self, rho: F) -> Result<Self, Error> {
+        if rho <= float!(0.0) || rho >= float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`RiemannianConjugateGradient`: backtracking factor must be in (0, 1)."
+            ));
+        }
+        self.linesearch.rho = rho;
+        Ok(self)
+    }
+
+    /// Set the tolerance on the norm of the Riemannian gradient
+    ///
+    /// Must be non-negative and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::riemannian::{RiemannianConjugateGradient, Sphere};
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let rgd: RiemannianConjugateGradient<_, Vec<f64>, f64> =
+    ///     RiemannianConjugateGradient::new(Sphere::new()).with_tolerance(1e-6)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`RiemannianConjugateGradient`: tolerance must be >= 0."
+            ));
+        }
+        self.tol = tol;
+        Ok(self)
+    }
+}
+
+impl<O, M, P, F> Solver<O, IterState<P, P, (), (), F>> for RiemannianConjugateGradient<M, P, F>
+where
+    O: CostFunction<Param = P, Output = F> + Gradient<Param = P, Gradient = P>,
+    M: Manifold<P, F>,
+    P: Clone + SerializeAlias + DeserializeOwnedAlias + ArgminMul<F, P> + ArgminSub<P, P>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Riemannian conjugate gradient";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, P, (), (), F>,
+    ) -> Result<(IterState<P, P, (), (), F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`RiemannianConjugateGradient` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        let param = self.manifold.project(&param)?;
+        let cost = problem.cost(&param)?;
+        let (grad, grad_norm) = riemannian_gradient(problem, &self.manifold, &param)?;
+        self.grad_norm = grad_norm;
+        self.direction = None;
+        Ok((state.param(param).cost(cost).gradient(grad), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, P, (), (), F>,
+    ) -> Result<(IterState<P, P, (), (), F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`RiemannianConjugateGradient`: Parameter vector in state not set."
+        ))?;
+        let grad = state.take_gradient().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`RiemannianConjugateGradient`: Gradient in state not set."
+        ))?;
+        let cost = state.get_cost();
+
+        // Restart with the negative gradient if there is no previous direction or if it is not a
+        // descent direction
+        let (direction, slope) = match self.direction.take() {
+            Some(direction) => {
+                let slope = self.manifold.inner(&param, &grad, &direction);
+                if slope < float!(0.0) {
+                    (direction, slope)
+                } else {
+                    (grad.mul(&float!(-1.0)), -self.grad_norm * self.grad_norm)
+                }
+            }
+            None => (grad.mul(&float!(-1.0)), -self.grad_norm * self.grad_norm),
+        };
+        match self
+            .linesearch
+            .search(problem, &self.manifold, &param, &direction, cost, slope)?
+        {
+            Some((new_param, new_cost, step_length)) => {
+                let (new_grad, grad_norm) =
+                    riemannian_gradient(problem, &self.manifold, &new_param)?;
+                // Polak-Ribiere+
+                let transported_grad = self.manifold.transport(&param, &new_param, &grad);
+                let beta = ((grad_norm * grad_norm
+                    - self
+                        .manifold
+                        .inner(&new_param, &new_grad, &transported_grad))
+                    / (self.grad_norm * self.grad_norm))
+                    .max(float!(0.0));
+                let transported_direction = self.manifold.transport(&param, &new_param, &direction);
+                self.direction = Some(transported_direction.mul(&beta).sub(&new_grad));
+                self.grad_norm = grad_norm;
+                Ok((
+                    state.param(new_param).gradient(new_grad).cost(new_cost),
+                    Some(kv!(
+                        "gradient_norm" => grad_norm;
+                        "step_length" => step_length;
+                        "beta" => beta;
+                    )),
+                ))
+            }
+            None => Ok((
+                state.param(param).gradient(grad).cost(cost).terminate_with(
+                    TerminationReason::SolverExit(
+                        "Line search failed to find a sufficient decrease".to_string(),
+                    ),
+                ),
+                Some(kv!("gradient_norm" => self.grad_norm;)),
+            )),
+        }
+    }
+
+    fn terminate(&mut self, _state: &IterState<P, P, (), (), F>) -> TerminationStatus {
+        if self.grad_norm <= self.tol {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{BlockRayleigh, Rayleigh};
+    use super::super::{Sphere, Stiefel};
+    use super::*;
+    use crate::core::{ArgminError, Executor};
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(
+        riemannian_conjugate_gradient,
+        RiemannianConjugateGradient<Sphere, Vec<f64>, f64>
+    );
+
+    #[test]
+    fn test_new() {
+        let RiemannianConjugateGradient {
+            manifold: _,
+            linesearch,
+            tol,
+            grad_norm,
+            direction,
+        } = RiemannianConjugateGradient::<_, Vec<f64>, f64>::new(Sphere::new());
+        assert_eq!(linesearch.step_length.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(linesearch.c.to_ne_bytes(), 1e-4f64.to_ne_bytes());
+        assert_eq!(linesearch.rho.to_ne_bytes(), 0.5f64.to_ne_bytes());
+        assert_eq!(tol.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert!(grad_norm.is_infinite());
+        assert!(direction.is_none());
+    }
+
+    #[test]
+    fn test_setters() {
+        let rgd = RiemannianConjugateGradient::<_, Vec<f64>, f64>::new(Sphere::new())
+            .with_step_length(0.1)
+            .unwrap()
+            .with_sufficient_decrease(0.2)
+            .unwrap()
+            .with_backtracking_factor(0.3)
+            .unwrap()
+            .with_tolerance(0.0)
+            .unwrap();
+        assert_eq!(
+            rgd.linesearch.step_length.to_ne_bytes(),
+            0.1f64.to_ne_bytes()
+        );
+        assert_eq!(rgd.linesearch.c.to_ne_bytes(), 0.2f64.to_ne_bytes());
+        assert_eq!(rgd.linesearch.rho.to_ne_bytes(), 0.3f64.to_ne_bytes());
+        assert_eq!(rgd.tol.to_ne_bytes(), 0.0f64.to_ne_bytes());
+
+        let rgd = || RiemannianConjugateGradient::<_, Vec<f64>, f64>::new(Sphere::new());
+        for step_length in [0.0, -1.0] {
+            assert_error!(
+                rgd().with_step_length(step_length),
+                ArgminError,
+                "Invalid parameter: \"`RiemannianConjugateGradient`: step length must be > 0.\""
+            );
+        }
+        for c in [0.0, 1.0] {
+            assert_error!(
+                rgd().with_sufficient_decrease(c),
+                ArgminError,
+                concat!(
+                    "Invalid parameter: \"`RiemannianConjugateGradient`: sufficient decrease ",
+                    "parameter must be in (0, 1).\""
+                )
+            );
+        }
+        for rho in [0.0, 1.0] {
+            assert_error!(
+                rgd().with_backtracking_factor(rho),
+                ArgminError,
+                concat!(
+                    "Invalid parameter: \"`RiemannianConjugateGradient`: backtracking factor ",
+                    "must be in (0, 1).\""
+                )
+            );
+        }
+        assert_error!(
+            rgd().with_tolerance(-1.0),
+            ArgminError,
+            "Invalid parameter: \"`RiemannianConjugateGradient`: tolerance must be >= 0.\""
+        );
+    }
+
+    #[test]
+    fn test_solver_sphere() {
+        // The minimum of the Rayleigh quotient is the smallest eigenvalue, attained at the
+        // eigenvector (1, sqrt(2), 1) / 2 (up to the sign)
+        let res = Executor::new(Rayleigh {}, RiemannianConjugateGradient::new(Sphere::new()))
+            .configure(|state| state.param(vec![1.0, 1.0, 2.0]).max_iters(1000))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let best = res.state.get_best_param().unwrap();
+        assert_relative_eq!(
+            res.state.get_best_cost(),
+            2.0 - 2.0f64.sqrt(),
+            epsilon = 1e-12
+        );
+        assert_relative_eq!(best[0], 0.5, epsilon = 1e-7);
+        assert_relative_eq!(best[1], 0.5 * 2.0f64.sqrt(), epsilon = 1e-7);
+        assert_relative_eq!(best[2], 0.5, epsilon = 1e-7);
+    }
+
+    #[test]
+    fn test_solver_stiefel() {
+        // The minimum is minus the sum of the two largest eigenvalues
+        let res = Executor::new(
+            BlockRayleigh {},
+            RiemannianConjugateGradient::new(Stiefel::new()),
+        )
+        .configure(|state| {
+            state
+                .param(vec![vec![1.0, 0.0], vec![0.0, 1.0], vec![0.0, 0.0]])
+                .max_iters(1000)
+        })
+        .run()
+        .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        assert_relative_eq!(
+            res.state.get_best_cost(),
+            -4.0 - 2.0f64.sqrt(),
+            epsilon = 1e-12
+        );
+    }
+}
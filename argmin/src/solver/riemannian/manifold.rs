@@ -0,0 +1,412 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{ArgminFloat, Error, Periodic};
+use argmin_math::{ArgminAdd, ArgminDot, ArgminL2Norm, ArgminMul, ArgminScaledSub};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Interface of a Riemannian manifold embedded in the space of parameter vectors `P`.
+///
+/// Points on the manifold and tangent vectors are both represented as `P`. The Riemannian
+/// gradient of a function is obtained by projecting its Euclidean gradient onto the tangent space.
+///
+/// # Example
+///
+/// ```
+/// use argmin::core::Error;
+/// use argmin::solver::riemannian::Manifold;
+///
+/// /// The real line with the Euclidean geometry
+/// struct Line {}
+///
+/// impl Manifold<f64, f64> for Line {
+///     fn project(&self, param: &f64) -> Result<f64, Error> {
+///         Ok(*param)
+///     }
+///
+///     fn project_tangent(&self, _param: &f64, vector: &f64) -> f64 {
+///         *vector
+///     }
+///
+///     fn retract(&self, param: &f64, tangent: &f64) -> Result<f64, Error> {
+///         Ok(param + tangent)
+///     }
+///
+///     fn inner(&self, _param: &f64, u: &f64, v: &f64) -> f64 {
+///         u * v
+///     }
+///
+///     fn transport(&self, _from: &f64, _to: &f64, tangent: &f64) -> f64 {
+///         *tangent
+///     }
+/// }
+/// ```
+pub trait Manifold<P, F> {
+    /// Maps a parameter vector onto the manifold
+    ///
+    /// Used to make the initial parameter vector feasible.
+    fn project(&self, param: &P) -> Result<P, Error>;
+
+    /// Projects `vector` onto the tangent space at `param`
+    fn project_tangent(&self, param: &P, vector: &P) -> P;
+
+    /// Retraction: maps the tangent vector `tangent` at `param` to a point on the manifold
+    fn retract(&self, param: &P, tangent: &P) -> Result<P, Error>;
+
+    /// Riemannian metric: inner product of the tangent vectors `u` and `v` at `param`
+    fn inner(&self, param: &P, u: &P, v: &P) -> F;
+
+    /// Vector transport of `tangent` from the tangent space at `from` to the one at `to`
+    fn transport(&self, from: &P, to: &P, tangent: &P) -> P;
+}
+
+/// # Unit sphere
+///
+/// `{x : ||x|| = 1}` with the metric inherited from the embedding space. Tangent vectors at `x`
+/// are orthogonal to `x`, the retraction normalizes `x + v` and vectors are transported by
+/// projecting them onto the tangent space at the destination.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::solver::riemannian::{Manifold, Sphere};
+/// let sphere = Sphere::new();
+/// let x = vec![1.0f64, 0.0];
+/// assert_eq!(sphere.project_tangent(&x, &vec![2.0, 3.0]), vec![0.0, 3.0]);
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct Sphere {}
+
+impl Sphere {
+    /// Construct a new instance of [`Sphere`]
+    pub fn new() -> Self {
+        Sphere {}
+    }
+}
+
+impl<P, F> Manifold<P, F> for Sphere
+where
+    P: ArgminAdd<P, P> + ArgminMul<F, P> + ArgminScaledSub<P, F, P> + ArgminDot<P, F>,
+    P: ArgminL2Norm<F>,
+    F: ArgminFloat,
+{
+    fn project(&self, param: &P) -> Result<P, Error> {
+        let norm = param.l2_norm();
+        if norm <= float!(0.0) || !norm.is_finite() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Sphere`: parameter vector must have a finite, nonzero norm."
+            ));
+        }
+        Ok(param.mul(&(float!(1.0) / norm)))
+    }
+
+    fn project_tangent(&self, param: &P, vector: &P) -> P {
+        vector.scaled_sub(&param.dot(vector), param)
+    }
+
+    fn retract(&self, param: &P, tangent: &P) -> Result<P, Error> {
+        self.project(&param.add(tangent))
+    }
+
+    fn inner(&self, _param: &P, u: &P, v: &P) -> F {
+        u.dot(v)
+    }
+
+    fn transport(&self, _from: &P, to: &P, tangent: &P) -> P {
+        self.project_tangent(to, tangent)
+    }
+}
+
+/// # Stiefel manifold
+///
+/// `{X in R^(n x p) : X^T X = I}`, the `n x p` matrices with orthonormal columns (`p <= n`),
+/// stored as `Vec<Vec<F>>` of `n` rows. The metric is inherited from the embedding space
+/// (`<U, V> = trace(U^T V)`), the tangent space projection is `Z - X sym(X^T Z)` and the
+/// retraction is the Q factor of the QR decomposition of `X + V` (with a positive diagonal of R).
+/// Vectors are transported by projecting them onto the tangent space at the destination.
+///
+/// For `p = 1` this is the unit sphere, for `p = n` the orthogonal group.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::solver::riemannian::{Manifold, Stiefel};
+/// let stiefel = Stiefel::new();
+/// let x: Vec<Vec<f64>> = stiefel.project(&vec![vec![2.0, 0.0], vec![1.0, 1.0], vec![0.0, 0.0]])
+///     .unwrap();
+/// assert!((x[0][0] - 2.0 / 5.0f64.sqrt()).abs() < 1e-15);
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct Stiefel {}
+
+impl Stiefel {
+    /// Construct a new instance of [`Stiefel`]
+    pub fn new() -> Self {
+        Stiefel {}
+    }
+}
+
+impl<F> Manifold<Vec<Vec<F>>, F> for Stiefel
+where
+    F: ArgminFloat,
+{
+    /// Orthonormalizes the columns by modified Gram-Schmidt (the Q factor of the QR decomposition)
+    ///
+    /// Fails if a column loses all but a fraction `sqrt(EPSILON)` of its norm in the process, i.e.
+    /// if the columns are (numerically) linearly dependent.
+    fn project(&self, param: &Vec<Vec<F>>) -> Result<Vec<Vec<F>>, Error> {
+        let n = param.len();
+        let p = param.first().map_or(0, Vec::len);
+        if p == 0 || p > n || param.iter().any(|row| row.len() != p) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Stiefel`: parameter must be an n x p matrix with 0 < p <= n."
+            ));
+        }
+        let mut q = param.clone();
+        for j in 0..p {
+            let length = column_norm(&q, j);
+            for k in 0..j {
+                let r = (0..n).fold(float!(0.0), |acc, i| acc + q[i][k] * q[i][j]);
+                for row in q.iter_mut() {
+                    row[j] = row[j] - r * row[k];
+                }
+            }
+            let norm = column_norm(&q, j);
+            if norm <= F::epsilon().sqrt() * length || !norm.is_finite() {
+                return Err(argmin_error!(
+                    InvalidParameter,
+                    "`Stiefel`: columns of the parameter must be linearly independent."
+                ));
+            }
+            for row in q.iter_mut() {
+                row[j] = row[j] / norm;
+            }
+        }
+        Ok(q)
+    }
+
+    fn project_tangent(&self, param: &Vec<Vec<F>>, vector: &Vec<Vec<F>>) -> Vec<Vec<F>> {
+        let p = param.first().map_or(0, Vec::len);
+        // sym(X^T Z)
+        let mut sym = vec![vec![float!(0.0); p]; p];
+        for (x, z) in param.iter().zip(vector.iter()) {
+            for k in 0..p {
+                for l in 0..p {
+                    sym[k][l] = sym[k][l] + float!(0.5) * (x[k] * z[l] + z[k] * x[l]);
+                }
+            }
+        }
+        param
+            .iter()
+            .zip(vector.iter())
+            .map(|(x, z)| {
+                (0..p)
+                    .map(|l| z[l] - (0..p).fold(float!(0.0), |acc, k| acc + x[k] * sym[k][l]))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn retract(&self, param: &Vec<Vec<F>>, tangent: &Vec<Vec<F>>) -> Result<Vec<Vec<F>>, Error> {
+        let sum: Vec<Vec<F>> = param
+            .iter()
+            .zip(tangent.iter())
+            .map(|(x, v)| x.iter().zip(v.iter()).map(|(a, b)| *a + *b).collect())
+            .collect();
+        self.project(&sum)
+    }
+
+    fn inner(&self, _param: &Vec<Vec<F>>, u: &Vec<Vec<F>>, v: &Vec<Vec<F>>) -> F {
+        u.iter()
+            .zip(v.iter())
+            .flat_map(|(a, b)| a.iter().zip(b.iter()))
+            .fold(float!(0.0), |acc, (a, b)| acc + *a * *b)
+    }
+
+    fn transport(
+        &self,
+        _from: &Vec<Vec<F>>,
+        to: &Vec<Vec<F>>,
+        tangent: &Vec<Vec<F>>,
+    ) -> Vec<Vec<F>> {
+        self.project_tangent(to, tangent)
+    }
+}
+
+/// Periodic variables form a flat manifold (a product of circles and lines): The tangent space is
+/// the whole space, the metric is the Euclidean one and the retraction `wrap(x + v)` maps every
+/// step back into the ranges of the periodic variables.
+impl<F> Manifold<Vec<F>, F> for Periodic<F>
+where
+    F: ArgminFloat,
+{
+    fn project(&self, param: &Vec<F>) -> Result<Vec<F>, Error> {
+        self.wrap(param)
+    }
+
+    fn project_tangent(&self, _param: &Vec<F>, vector: &Vec<F>) -> Vec<F> {
+        vector.clone()
+    }
+
+    fn retract(&self, param: &Vec<F>, tangent: &Vec<F>) -> Result<Vec<F>, Error> {
+        let moved: Vec<F> = param
+            .iter()
+            .zip(tangent.iter())
+            .map(|(x, v)| *x + *v)
+            .collect();
+        self.wrap(&moved)
+    }
+
+    fn inner(&self, _param: &Vec<F>, u: &Vec<F>, v: &Vec<F>) -> F {
+        u.iter()
+            .zip(v.iter())
+            .fold(float!(0.0), |acc, (u, v)| acc + *u * *v)
+    }
+
+    fn transport(&self, _from: &Vec<F>, _to: &Vec<F>, tangent: &Vec<F>) -> Vec<F> {
+        tangent.clone()
+    }
+}
+
+/// Euclidean norm of column `j` of the matrix `x`
+fn column_norm<F: ArgminFloat>(x: &[Vec<F>], j: usize) -> F {
+    x.iter()
+        .fold(float!(0.0), |acc: F, row| acc + row[j] * row[j])
+        .sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(sphere, Sphere);
+    test_trait_impl!(stiefel, Stiefel);
+
+    /// X^T Y
+    fn gram(x: &[Vec<f64>], y: &[Vec<f64>]) -> Vec<Vec<f64>> {
+        let p = x[0].len();
+        (0..p)
+            .map(|k| {
+                (0..p)
+                    .map(|l| x.iter().zip(y.iter()).map(|(a, b)| a[k] * b[l]).sum())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_sphere() {
+        let sphere = Sphere::new();
+        let x: Vec<f64> = sphere.project(&vec![3.0, 0.0, 4.0]).unwrap();
+        assert_relative_eq!(x[0], 0.6, epsilon = f64::EPSILON);
+        assert_relative_eq!(x[2], 0.8, epsilon = f64::EPSILON);
+
+        // tangent vectors are orthogonal to the point
+        let v = sphere.project_tangent(&x, &vec![1.0, 2.0, 3.0]);
+        assert_relative_eq!(
+            v.iter().zip(&x).map(|(a, b)| a * b).sum::<f64>(),
+            0.0,
+            epsilon = 1e-15
+        );
+        assert_relative_eq!(v[1], 2.0, epsilon = f64::EPSILON);
+
+        // retractions stay on the sphere and vanish for zero tangent vectors
+        let y = sphere.retract(&x, &v).unwrap();
+        assert_relative_eq!(y.l2_norm(), 1.0, epsilon = 1e-15);
+        assert_eq!(sphere.retract(&x, &vec![0.0; 3]).unwrap(), x);
+
+        let w = sphere.transport(&x, &y, &v);
+        assert_relative_eq!(
+            w.iter().zip(&y).map(|(a, b)| a * b).sum::<f64>(),
+            0.0,
+            epsilon = 1e-15
+        );
+        assert_relative_eq!(sphere.inner(&x, &v, &v), 5.0, epsilon = 1e-14);
+
+        assert_error!(
+            Manifold::<Vec<f64>, f64>::project(&sphere, &vec![0.0, 0.0]),
+            ArgminError,
+            "Invalid parameter: \"`Sphere`: parameter vector must have a finite, nonzero norm.\""
+        );
+    }
+
+    #[test]
+    fn test_stiefel() {
+        let stiefel = Stiefel::new();
+        let x = stiefel
+            .project(&vec![vec![1.0, 2.0], vec![0.0, 1.0], vec![1.0, 0.0]])
+            .unwrap();
+        let xtx = gram(&x, &x);
+        assert_relative_eq!(xtx[0][0], 1.0, epsilon = 1e-15);
+        assert_relative_eq!(xtx[0][1], 0.0, epsilon = 1e-15);
+        assert_relative_eq!(xtx[1][1], 1.0, epsilon = 1e-15);
+
+        // X^T V is skew-symmetric for tangent vectors V
+        let z = vec![vec![1.0, -1.0], vec![2.0, 0.5], vec![0.0, 3.0]];
+        let v = stiefel.project_tangent(&x, &z);
+        let xtv = gram(&x, &v);
+        assert_relative_eq!(xtv[0][0], 0.0, epsilon = 1e-15);
+        assert_relative_eq!(xtv[1][1], 0.0, epsilon = 1e-15);
+        assert_relative_eq!(xtv[0][1], -xtv[1][0], epsilon = 1e-15);
+        // projecting twice does not change anything
+        let vv = stiefel.project_tangent(&x, &v);
+        assert_relative_eq!(
+            stiefel.inner(&x, &v, &v),
+            stiefel.inner(&x, &v, &vv),
+            epsilon = 1e-14
+        );
+
+        let y = stiefel.retract(&x, &v).unwrap();
+        let yty = gram(&y, &y);
+        assert_relative_eq!(yty[0][0], 1.0, epsilon = 1e-15);
+        assert_relative_eq!(yty[0][1], 0.0, epsilon = 1e-15);
+        assert_relative_eq!(yty[1][1], 1.0, epsilon = 1e-15);
+        let w = stiefel.transport(&x, &y, &v);
+        let ytw = gram(&y, &w);
+        assert_relative_eq!(ytw[0][1], -ytw[1][0], epsilon = 1e-15);
+
+        assert_error!(
+            stiefel.project(&vec![vec![1.0, 2.0], vec![1.0, 2.0]]),
+            ArgminError,
+            "Invalid parameter: \"`Stiefel`: columns of the parameter must be linearly independent.\""
+        );
+        assert_error!(
+            stiefel.project(&vec![vec![1.0, 2.0]]),
+            ArgminError,
+            "Invalid parameter: \"`Stiefel`: parameter must be an n x p matrix with 0 < p <= n.\""
+        );
+    }
+
+    #[test]
+    fn test_periodic() {
+        use std::f64::consts::PI;
+        let periodic = Periodic::new(2).with_angle(1).unwrap();
+        let x = periodic.project(&vec![1.0, 3.0]).unwrap();
+        assert_eq!(x, vec![1.0, 3.0]);
+        // steps across the end of the range are wrapped
+        let y = periodic.retract(&x, &vec![0.5, 1.0]).unwrap();
+        assert_relative_eq!(y[0], 1.5, epsilon = 1e-15);
+        assert_relative_eq!(y[1], 4.0 - 2.0 * PI, epsilon = 1e-15);
+        let v = vec![1.0, -2.0];
+        assert_eq!(periodic.project_tangent(&y, &v), v);
+        assert_eq!(periodic.transport(&x, &y, &v), v);
+        assert_relative_eq!(periodic.inner(&x, &v, &v), 5.0, epsilon = 1e-15);
+        assert_error!(
+            periodic.project(&vec![0.0]),
+            ArgminError,
+            "Invalid parameter: \"`Periodic`: parameter vector must have 2 entries.\""
+        );
+    }
+}
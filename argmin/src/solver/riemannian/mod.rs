@@ -0,0 +1,224 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Riemannian optimization
+//!
+//! Minimization of functions whose parameters are constrained to a smooth manifold, such as
+//! vectors of unit norm or matrices with orthonormal columns. Instead of projecting iterates back
+//! onto the feasible set, the solvers move along the manifold: The Riemannian gradient is the
+//! projection of the Euclidean gradient onto the tangent space, steps are taken via a retraction
+//! and search directions of previous iterations are carried along via vector transport.
+//!
+//! # Solvers
+//!
+//! * [`RiemannianGradientDescent`]
+//! * [`RiemannianConjugateGradient`]
+//!
+//! # Available manifolds
+//!
+//! * [`Sphere`]: Vectors of unit norm
+//! * [`Stiefel`]: Matrices with orthonormal columns
+//! * [`SO3`]: Rotations in three dimensions, stored as unit quaternions
+//! * [`SE3`]: Rigid body motions, stored as unit quaternion and translation
+//!
+//! Other manifolds are supported by implementing the [`Manifold`] trait.
+//!
+//! ## Reference
+//!
+//! P.-A. Absil, R. Mahony and R. Sepulchre (2008). Optimization Algorithms on Matrix Manifolds.
+//! Princeton University Press. ISBN 978-0-691-13298-3.
+
+mod conjugategradient;
+mod gradientdescent;
+mod manifold;
+mod rotation;
+
+pub use conjugategradient::RiemannianConjugateGradient;
+pub use gradientdescent::RiemannianGradientDescent;
+pub use manifold::{Manifold, Sphere, Stiefel};
+pub use rotation::{SE3, SO3};
+
+use crate::core::{ArgminFloat, CostFunction, Error, Gradient, Problem};
+use argmin_math::ArgminMul;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Armijo backtracking line search along the retraction curve `t -> R_x(t * d)`
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+struct Backtracking<F> {
+    /// Initial step length
+    step_length: F,
+    /// Sufficient decrease parameter
+    c: F,
+    /// Backtracking factor
+    rho: F,
+}
+
+impl<F> Backtracking<F>
+where
+    F: ArgminFloat,
+{
+    /// Initial step length `1.0`, sufficient decrease parameter `1e-4`, backtracking factor `0.5`
+    fn new() -> Self {
+        Backtracking {
+            step_length: float!(1.0),
+            c: float!(1e-4),
+            rho: float!(0.5),
+        }
+    }
+
+    /// Reduces the step length until `f(R_x(t * d)) <= f(x) + c * t * slope`, where `slope` is the
+    /// inner product of the Riemannian gradient and `direction`
+    ///
+    /// Returns the new parameter vector, its cost and the step length, or `None` if the step
+    /// length dropped below `EPSILON`.
+    fn search<O, M, P>(
+        &self,
+        problem: &mut Problem<O>,
+        manifold: &M,
+        param: &P,
+        direction: &P,
+        cost: F,
+        slope: F,
+    ) -> Result<Option<(P, F, F)>, Error>
+    where
+        O: CostFunction<Param = P, Output = F>,
+        M: Manifold<P, F>,
+        P: ArgminMul<F, P>,
+    {
+        let mut t = self.step_length;
+        while t > F::epsilon() {
+            let new_param = manifold.retract(param, &direction.mul(&t))?;
+            let new_cost = problem.cost(&new_param)?;
+            if new_cost <= cost + self.c * t * slope {
+                return Ok(Some((new_param, new_cost, t)));
+            }
+            t = t * self.rho;
+        }
+        Ok(None)
+    }
+}
+
+/// Returns the Riemannian gradient at `param` (the projection of the Euclidean gradient onto the
+/// tangent space) and its norm
+fn riemannian_gradient<O, M, P, F>(
+    problem: &mut Problem<O>,
+    manifold: &M,
+    param: &P,
+) -> Result<(P, F), Error>
+where
+    O: Gradient<Param = P, Gradient = P>,
+    M: Manifold<P, F>,
+    F: ArgminFloat,
+{
+    let grad = manifold.project_tangent(param, &problem.gradient(param)?);
+    let norm = manifold.inner(param, &grad, &grad).sqrt();
+    Ok((grad, norm))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{CostFunction, Error, Gradient};
+    use approx::assert_relative_eq;
+
+    /// Rayleigh quotient `x^T A x` of the `3 x 3` matrix with `2` on the diagonal and `-1` on the
+    /// off-diagonals, with eigenvalues `2 - sqrt(2)`, `2` and `2 + sqrt(2)`
+    pub(super) struct Rayleigh {}
+
+    /// `A x`
+    fn apply(x: &[f64]) -> Vec<f64> {
+        vec![
+            2.0 * x[0] - x[1],
+            -x[0] + 2.0 * x[1] - x[2],
+            -x[1] + 2.0 * x[2],
+        ]
+    }
+
+    impl CostFunction for Rayleigh {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(apply(p).iter().zip(p.iter()).map(|(a, b)| a * b).sum())
+        }
+    }
+
+    impl Gradient for Rayleigh {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok(apply(p).iter().map(|a| 2.0 * a).collect())
+        }
+    }
+
+    /// Negative trace `-trace(X^T A X)` of the `3 x 2` matrix `X`, with `A` as in [`Rayleigh`];
+    /// minimized over the Stiefel manifold by the eigenvectors of the two largest eigenvalues
+    pub(super) struct BlockRayleigh {}
+
+    impl CostFunction for BlockRayleigh {
+        type Param = Vec<Vec<f64>>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok((0..2)
+                .map(|j| {
+                    let col: Vec<f64> = p.iter().map(|row| row[j]).collect();
+                    -Rayleigh {}.cost(&col).unwrap()
+                })
+                .sum())
+        }
+    }
+
+    impl Gradient for BlockRayleigh {
+        type Param = Vec<Vec<f64>>;
+        type Gradient = Vec<Vec<f64>>;
+
+        fn gradient(&self, p: &Self::Param) -> Result<Self::Gradient, Error> {
+            let cols: Vec<Vec<f64>> = (0..2)
+                .map(|j| {
+                    let col: Vec<f64> = p.iter().map(|row| row[j]).collect();
+                    Rayleigh {}.gradient(&col).unwrap()
+                })
+                .collect();
+            Ok((0..3).map(|i| vec![-cols[0][i], -cols[1][i]]).collect())
+        }
+    }
+
+    #[test]
+    fn test_backtracking() {
+        let linesearch: Backtracking<f64> = Backtracking::new();
+        let sphere = Sphere::new();
+        let mut problem = Problem::new(Rayleigh {});
+        let x = vec![1.0, 0.0, 0.0];
+        let (grad, norm) = riemannian_gradient(&mut problem, &sphere, &x).unwrap();
+        assert_eq!(grad, vec![0.0, -2.0, 0.0]);
+        assert_eq!(norm.to_ne_bytes(), 2.0f64.to_ne_bytes());
+
+        let direction = grad.mul(&-1.0);
+        let cost = problem.cost(&x).unwrap();
+        let (new_param, new_cost, t) = linesearch
+            .search(&mut problem, &sphere, &x, &direction, cost, -norm * norm)
+            .unwrap()
+            .unwrap();
+        assert!(new_cost < cost);
+        assert!(t <= 1.0);
+        assert_relative_eq!(
+            new_param.iter().map(|x| x * x).sum::<f64>(),
+            1.0,
+            epsilon = 1e-15
+        );
+
+        // Ascent directions never satisfy the sufficient decrease condition
+        assert!(linesearch
+            .search(&mut problem, &sphere, &x, &grad, cost, -1.0)
+            .unwrap()
+            .is_none());
+    }
+}
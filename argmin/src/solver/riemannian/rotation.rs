@@ -0,0 +1,688 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::Manifold;
+use crate::core::{ArgminFloat, Error};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Rotations in three dimensions, SO(3)
+///
+/// Rotations are stored as unit quaternions `[w, x, y, z]` (Hamilton convention) in a `Vec<F>` of
+/// length 4, where `q` and `-q` describe the same rotation. As a manifold this is the unit sphere
+/// in four dimensions: Tangent vectors at `q` are the vectors orthogonal to `q` (for an angular
+/// velocity `omega` in body coordinates, the tangent vector is `q * [0, omega / 2]`) and the
+/// retraction is the exponential map, `R_q(q * [0, omega / 2]) = q * exp(omega)`. Therefore the
+/// cost function and its (Euclidean) gradient can be written in terms of the quaternion
+/// components, without switching between local charts.
+///
+/// The associated functions convert between quaternions, rotation vectors (via
+/// [`exp`](`SO3::exp`) and [`log`](`SO3::log`)) and rotation matrices, compose rotations and
+/// rotate points.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::solver::riemannian::SO3;
+/// let q = SO3::exp(&[0.0f64, 0.0, std::f64::consts::FRAC_PI_2]);
+/// let p = SO3::rotate(&q, &[1.0, 0.0, 0.0]);
+/// assert!(p[0].abs() < 1e-15 && (p[1] - 1.0).abs() < 1e-15);
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+#[allow(clippy::upper_case_acronyms)]
+pub struct SO3 {}
+
+impl SO3 {
+    /// Construct a new instance of [`SO3`]
+    pub fn new() -> Self {
+        SO3 {}
+    }
+
+    /// The identity rotation `[1, 0, 0, 0]`
+    pub fn identity<F: ArgminFloat>() -> Vec<F> {
+        vec![float!(1.0), float!(0.0), float!(0.0), float!(0.0)]
+    }
+
+    /// Exponential map: the unit quaternion of a rotation by the angle `|omega|` around the axis
+    /// `omega / |omega|`
+    pub fn exp<F: ArgminFloat>(omega: &[F; 3]) -> Vec<F> {
+        let theta = norm3(omega);
+        let half = theta / float!(2.0);
+        // sin(theta / 2) / theta, with its Taylor expansion for small angles
+        let scale = if theta < F::epsilon().sqrt() {
+            float!(0.5) - theta * theta / float!(48.0)
+        } else {
+            half.sin() / theta
+        };
+        vec![
+            half.cos(),
+            scale * omega[0],
+            scale * omega[1],
+            scale * omega[2],
+        ]
+    }
+
+    /// Logarithm map: the rotation vector `omega` (with `|omega|` in `[0, pi]`) of the unit
+    /// quaternion `q`
+    pub fn log<F: ArgminFloat>(q: &[F]) -> [F; 3] {
+        // q and -q are the same rotation; choose the one with the smaller angle
+        let sign = if q[0] < float!(0.0) {
+            float!(-1.0)
+        } else {
+            float!(1.0)
+        };
+        let (w, v) = (sign * q[0], [sign * q[1], sign * q[2], sign * q[3]]);
+        let sin_half = norm3(&v);
+        // theta / sin(theta / 2), with its Taylor expansion for small angles
+        let scale = if sin_half < F::epsilon().sqrt() {
+            float!(2.0) / w * (float!(1.0) + sin_half * sin_half / (float!(6.0) * w * w))
+        } else {
+            float!(2.0) * sin_half.atan2(w) / sin_half
+        };
+        [scale * v[0], scale * v[1], scale * v[2]]
+    }
+
+    /// Hamilton product `a * b`, the rotation `b` followed by `a`
+    pub fn compose<F: ArgminFloat>(a: &[F], b: &[F]) -> Vec<F> {
+        vec![
+            a[0] * b[0] - a[1] * b[1] - a[2] * b[2] - a[3] * b[3],
+            a[0] * b[1] + a[1] * b[0] + a[2] * b[3] - a[3] * b[2],
+            a[0] * b[2] - a[1] * b[3] + a[2] * b[0] + a[3] * b[1],
+            a[0] * b[3] + a[1] * b[2] - a[2] * b[1] + a[3] * b[0],
+        ]
+    }
+
+    /// Conjugate quaternion, the inverse rotation
+    pub fn inverse<F: ArgminFloat>(q: &[F]) -> Vec<F> {
+        vec![q[0], -q[1], -q[2], -q[3]]
+    }
+
+    /// Rotates `point` by the unit quaternion `q`
+    pub fn rotate<F: ArgminFloat>(q: &[F], point: &[F; 3]) -> [F; 3] {
+        // p + 2 w (u x p) + 2 u x (u x p) with q = [w, u]
+        let u = [q[1], q[2], q[3]];
+        let t = cross(&u, point).map(|x| float!(2.0) * x);
+        let ut = cross(&u, &t);
+        [
+            point[0] + q[0] * t[0] + ut[0],
+            point[1] + q[0] * t[1] + ut[1],
+            point[2] + q[0] * t[2] + ut[2],
+        ]
+    }
+
+    /// Rotation matrix of the unit quaternion `q`
+    pub fn to_matrix<F: ArgminFloat>(q: &[F]) -> [[F; 3]; 3] {
+        let (w, x, y, z) = (q[0], q[1], q[2], q[3]);
+        let two = float!(2.0);
+        let one = float!(1.0);
+        [
+            [
+                one - two * (y * y + z * z),
+                two * (x * y - w * z),
+                two * (x * z + w * y),
+            ],
+            [
+                two * (x * y + w * z),
+                one - two * (x * x + z * z),
+                two * (y * z - w * x),
+            ],
+            [
+                two * (x * z - w * y),
+                two * (y * z + w * x),
+                one - two * (x * x + y * y),
+            ],
+        ]
+    }
+
+    /// Unit quaternion (with nonnegative `w`) of the rotation matrix `m`
+    pub fn from_matrix<F: ArgminFloat>(m: &[[F; 3]; 3]) -> Vec<F> {
+        // Shepperd's method: start from the largest of the four squared components
+        let one = float!(1.0);
+        let quarter = float!(0.25);
+        let trace = m[0][0] + m[1][1] + m[2][2];
+        let q = if trace > m[0][0].max(m[1][1]).max(m[2][2]) {
+            let s = (one + trace).sqrt() * float!(2.0);
+            vec![
+                quarter * s,
+                (m[2][1] - m[1][2]) / s,
+                (m[0][2] - m[2][0]) / s,
+                (m[1][0] - m[0][1]) / s,
+            ]
+        } else if m[0][0] >= m[1][1] && m[0][0] >= m[2][2] {
+            let s = (one + m[0][0] - m[1][1] - m[2][2]).sqrt() * float!(2.0);
+            vec![
+                (m[2][1] - m[1][2]) / s,
+                quarter * s,
+                (m[0][1] + m[1][0]) / s,
+                (m[0][2] + m[2][0]) / s,
+            ]
+        } else if m[1][1] >= m[2][2] {
+            let s = (one + m[1][1] - m[0][0] - m[2][2]).sqrt() * float!(2.0);
+            vec![
+                (m[0][2] - m[2][0]) / s,
+                (m[0][1] + m[1][0]) / s,
+                quarter * s,
+                (m[1][2] + m[2][1]) / s,
+            ]
+        } else {
+            let s = (one + m[2][2] - m[0][0] - m[1][1]).sqrt() * float!(2.0);
+            vec![
+                (m[1][0] - m[0][1]) / s,
+                (m[0][2] + m[2][0]) / s,
+                (m[1][2] + m[2][1]) / s,
+                quarter * s,
+            ]
+        };
+        if q[0] < float!(0.0) {
+            q.iter().map(|x| -*x).collect()
+        } else {
+            q
+        }
+    }
+}
+
+impl<F> Manifold<Vec<F>, F> for SO3
+where
+    F: ArgminFloat,
+{
+    fn project(&self, param: &Vec<F>) -> Result<Vec<F>, Error> {
+        if param.len() != 4 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`SO3`: parameter must be a quaternion [w, x, y, z]."
+            ));
+        }
+        normalize(param).ok_or_else(|| -> Error {
+            argmin_error!(
+                InvalidParameter,
+                "`SO3`: quaternion must have a finite, nonzero norm."
+            )
+        })
+    }
+
+    fn project_tangent(&self, param: &Vec<F>, vector: &Vec<F>) -> Vec<F> {
+        let d = dot(param, vector);
+        vector
+            .iter()
+            .zip(param.iter())
+            .map(|(v, q)| *v - d * *q)
+            .collect()
+    }
+
+    /// Exponential map of the unit sphere, `cos(|v|) q + sin(|v|) v / |v|`
+    fn retract(&self, param: &Vec<F>, tangent: &Vec<F>) -> Result<Vec<F>, Error> {
+        let theta = dot(tangent, tangent).sqrt();
+        if !theta.is_finite() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`SO3`: tangent vector must be finite."
+            ));
+        }
+        // sin(theta) / theta, with its Taylor expansion for small angles
+        let scale = if theta < F::epsilon().sqrt() {
+            float!(1.0) - theta * theta / float!(6.0)
+        } else {
+            theta.sin() / theta
+        };
+        let q: Vec<F> = param
+            .iter()
+            .zip(tangent.iter())
+            .map(|(q, v)| theta.cos() * *q + scale * *v)
+            .collect();
+        // Renormalize to avoid the accumulation of rounding errors
+        self.project(&q)
+    }
+
+    fn inner(&self, _param: &Vec<F>, u: &Vec<F>, v: &Vec<F>) -> F {
+        dot(u, v)
+    }
+
+    fn transport(&self, _from: &Vec<F>, to: &Vec<F>, tangent: &Vec<F>) -> Vec<F> {
+        self.project_tangent(to, tangent)
+    }
+}
+
+/// # Rigid body motions in three dimensions, SE(3)
+///
+/// A pose is stored as a `Vec<F>` of length 7: the unit quaternion `[w, x, y, z]` of the rotation
+/// (see [`SO3`]) followed by the translation `[tx, ty, tz]`. It maps a point `p` to
+/// `R p + t`. As a manifold this is the product of [`SO3`] and the Euclidean space, with the
+/// retraction of [`SO3`] on the rotation and addition on the translation.
+///
+/// The associated functions [`exp`](`SE3::exp`) and [`log`](`SE3::log`) convert between poses and
+/// twists `[omega, rho]` (rotation vector followed by the translational part), composing poses and
+/// transforming points is supported as well.
+///
+/// # Example
+///
+/// ```
+/// # use argmin::solver::riemannian::SE3;
+/// let pose = vec![1.0f64, 0.0, 0.0, 0.0, 1.0, 2.0, 3.0];
+/// assert_eq!(SE3::transform(&pose, &[1.0, 1.0, 1.0]), [2.0, 3.0, 4.0]);
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+#[allow(clippy::upper_case_acronyms)]
+pub struct SE3 {}
+
+impl SE3 {
+    /// Construct a new instance of [`SE3`]
+    pub fn new() -> Self {
+        SE3 {}
+    }
+
+    /// The identity pose
+    pub fn identity<F: ArgminFloat>() -> Vec<F> {
+        let mut pose = SO3::identity();
+        pose.extend([float!(0.0); 3]);
+        pose
+    }
+
+    /// Exponential map: the pose of the twist `xi = [omega, rho]`
+    ///
+    /// The rotation is `exp(omega)` and the translation `V(omega) rho`, where
+    /// `V = I + (1 - cos(theta)) / theta^2 [omega]x + (theta - sin(theta)) / theta^3 [omega]x^2`
+    /// with `theta = |omega|`.
+    pub fn exp<F: ArgminFloat>(xi: &[F; 6]) -> Vec<F> {
+        let omega = [xi[0], xi[1], xi[2]];
+        let rho = [xi[3], xi[4], xi[5]];
+        let theta = norm3(&omega);
+        let (a, b) = if theta < F::epsilon().sqrt().sqrt() {
+            let t2 = theta * theta;
+            (
+                float!(0.5) - t2 / float!(24.0),
+                float!(1.0) / float!(6.0) - t2 / float!(120.0),
+            )
+        } else {
+            (
+                (float!(1.0) - theta.cos()) / (theta * theta),
+                (theta - theta.sin()) / (theta * theta * theta),
+            )
+        };
+        let wr = cross(&omega, &rho);
+        let wwr = cross(&omega, &wr);
+        let mut pose = SO3::exp(&omega);
+        pose.extend((0..3).map(|i| rho[i] + a * wr[i] + b * wwr[i]));
+        pose
+    }
+
+    /// Logarithm map: the twist `[omega, rho]` of `pose`, the inverse of [`exp`](`SE3::exp`)
+    pub fn log<F: ArgminFloat>(pose: &[F]) -> [F; 6] {
+        let omega = SO3::log(&pose[..4]);
+        let t = [pose[4], pose[5], pose[6]];
+        let theta = norm3(&omega);
+        // V^-1 = I - [omega]x / 2 + c [omega]x^2
+        let c = if theta < F::epsilon().sqrt().sqrt() {
+            float!(1.0) / float!(12.0) + theta * theta / float!(720.0)
+        } else {
+            let half = theta / float!(2.0);
+            (float!(1.0) - half / half.tan()) / (theta * theta)
+        };
+        let wt = cross(&omega, &t);
+        let wwt = cross(&omega, &wt);
+        let mut xi = [float!(0.0); 6];
+        xi[..3].copy_from_slice(&omega);
+        for i in 0..3 {
+            xi[3 + i] = t[i] - float!(0.5) * wt[i] + c * wwt[i];
+        }
+        xi
+    }
+
+    /// Composition `a * b`, the pose `b` followed by `a`
+    pub fn compose<F: ArgminFloat>(a: &[F], b: &[F]) -> Vec<F> {
+        let mut pose = SO3::compose(&a[..4], &b[..4]);
+        let t = SO3::rotate(&a[..4], &[b[4], b[5], b[6]]);
+        pose.extend((0..3).map(|i| t[i] + a[4 + i]));
+        pose
+    }
+
+    /// Inverse pose
+    pub fn inverse<F: ArgminFloat>(pose: &[F]) -> Vec<F> {
+        let mut inverse = SO3::inverse(&pose[..4]);
+        let t = SO3::rotate(&inverse, &[pose[4], pose[5], pose[6]]);
+        inverse.extend(t.iter().map(|x| -*x));
+        inverse
+    }
+
+    /// Transforms `point` by `pose`, `R p + t`
+    pub fn transform<F: ArgminFloat>(pose: &[F], point: &[F; 3]) -> [F; 3] {
+        let p = SO3::rotate(&pose[..4], point);
+        [p[0] + pose[4], p[1] + pose[5], p[2] + pose[6]]
+    }
+}
+
+impl<F> Manifold<Vec<F>, F> for SE3
+where
+    F: ArgminFloat,
+{
+    fn project(&self, param: &Vec<F>) -> Result<Vec<F>, Error> {
+        if param.len() != 7 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`SE3`: parameter must be a quaternion followed by a translation."
+            ));
+        }
+        let mut pose = SO3::new().project(&param[..4].to_vec())?;
+        pose.extend_from_slice(&param[4..]);
+        Ok(pose)
+    }
+
+    fn project_tangent(&self, param: &Vec<F>, vector: &Vec<F>) -> Vec<F> {
+        let mut tangent = SO3::new().project_tangent(&param[..4].to_vec(), &vector[..4].to_vec());
+        tangent.extend_from_slice(&vector[4..]);
+        tangent
+    }
+
+    fn retract(&self, param: &Vec<F>, tangent: &Vec<F>) -> Result<Vec<F>, Error> {
+        let mut pose = SO3::new().retract(&param[..4].to_vec(), &tangent[..4].to_vec())?;
+        pose.extend((4..7).map(|i| param[i] + tangent[i]));
+        Ok(pose)
+    }
+
+    fn inner(&self, _param: &Vec<F>, u: &Vec<F>, v: &Vec<F>) -> F {
+        dot(u, v)
+    }
+
+    fn transport(&self, _from: &Vec<F>, to: &Vec<F>, tangent: &Vec<F>) -> Vec<F> {
+        self.project_tangent(to, tangent)
+    }
+}
+
+/// Dot product
+fn dot<F: ArgminFloat>(a: &[F], b: &[F]) -> F {
+    a.iter()
+        .zip(b.iter())
+        .fold(float!(0.0), |acc, (a, b)| acc + *a * *b)
+}
+
+/// Euclidean norm of a three dimensional vector
+fn norm3<F: ArgminFloat>(v: &[F; 3]) -> F {
+    dot(v, v).sqrt()
+}
+
+/// Cross product
+fn cross<F: ArgminFloat>(a: &[F; 3], b: &[F; 3]) -> [F; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+/// `v / |v|`, or `None` if the norm is zero or not finite
+fn normalize<F: ArgminFloat>(v: &[F]) -> Option<Vec<F>> {
+    let norm = dot(v, v).sqrt();
+    if norm <= float!(0.0) || !norm.is_finite() {
+        return None;
+    }
+    Some(v.iter().map(|x| *x / norm).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::RiemannianConjugateGradient;
+    use super::*;
+    use crate::core::{ArgminError, CostFunction, Executor, Gradient, State, TerminationReason};
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+    use std::f64::consts::PI;
+
+    test_trait_impl!(so3, SO3);
+    test_trait_impl!(se3, SE3);
+
+    fn assert_slices_eq(a: &[f64], b: &[f64], epsilon: f64) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.iter().zip(b.iter()) {
+            assert_relative_eq!(a, b, epsilon = epsilon);
+        }
+    }
+
+    #[test]
+    fn test_so3_exp_log() {
+        for omega in [
+            [0.0, 0.0, 0.0],
+            [1e-10, -2e-10, 3e-10],
+            [0.3, -0.2, 0.1],
+            [1.0, 2.0, -0.5],
+            [0.0, 0.0, PI - 1e-6],
+        ] {
+            let q = SO3::exp(&omega);
+            assert_relative_eq!(dot(&q, &q), 1.0, epsilon = 1e-15);
+            assert_slices_eq(&SO3::log(&q), &omega, 1e-9);
+            // q and -q are the same rotation
+            let minus_q: Vec<f64> = q.iter().map(|x| -x).collect();
+            assert_slices_eq(&SO3::log(&minus_q), &omega, 1e-9);
+        }
+        assert_eq!(SO3::exp(&[0.0f64; 3]), SO3::identity::<f64>());
+    }
+
+    #[test]
+    fn test_so3_matrix() {
+        let q = SO3::exp(&[0.4, -1.1, 0.7]);
+        let r = SO3::to_matrix(&q);
+        // orthonormal
+        for i in 0..3 {
+            for j in 0..3 {
+                let d: f64 = (0..3).map(|k| r[k][i] * r[k][j]).sum();
+                assert_relative_eq!(d, if i == j { 1.0 } else { 0.0 }, epsilon = 1e-15);
+            }
+        }
+        // consistent with `rotate`
+        let p = [0.3, -2.0, 1.5];
+        let rp = SO3::rotate(&q, &p);
+        for i in 0..3 {
+            let d: f64 = (0..3).map(|k| r[i][k] * p[k]).sum();
+            assert_relative_eq!(rp[i], d, epsilon = 1e-14);
+        }
+        assert_slices_eq(&SO3::from_matrix(&r), &q, 1e-15);
+        // rotations by (almost) pi around each axis
+        for omega in [[PI - 1e-3, 0.0, 0.0], [0.0, PI, 0.0], [0.0, 0.0, PI]] {
+            let q = SO3::exp(&omega);
+            let q2 = SO3::from_matrix(&SO3::to_matrix(&q));
+            assert_relative_eq!(dot(&q, &q2).abs(), 1.0, epsilon = 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_so3_compose() {
+        let a = SO3::exp(&[0.1, 0.2, 0.3]);
+        let b = SO3::exp(&[-0.5, 0.0, 1.0]);
+        let p = [1.0, 2.0, 3.0];
+        let ab = SO3::compose(&a, &b);
+        assert_slices_eq(
+            &SO3::rotate(&ab, &p),
+            &SO3::rotate(&a, &SO3::rotate(&b, &p)),
+            1e-14,
+        );
+        assert_slices_eq(
+            &SO3::compose(&a, &SO3::inverse(&a)),
+            &SO3::identity(),
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn test_so3_manifold() {
+        let so3 = SO3::new();
+        let q = SO3::exp(&[0.3, 0.2, -0.1]);
+        // the retraction of q * [0, omega / 2] is q * exp(omega)
+        let omega = [0.5, -0.4, 0.2];
+        let tangent = SO3::compose(&q, &[0.0, omega[0] / 2.0, omega[1] / 2.0, omega[2] / 2.0]);
+        assert_relative_eq!(dot(&tangent, &q), 0.0, epsilon = 1e-15);
+        assert_slices_eq(&so3.project_tangent(&q, &tangent), &tangent, 1e-15);
+        assert_slices_eq(
+            &so3.retract(&q, &tangent).unwrap(),
+            &SO3::compose(&q, &SO3::exp(&omega)),
+            1e-14,
+        );
+        assert_slices_eq(&so3.retract(&q, &vec![0.0; 4]).unwrap(), &q, 1e-15);
+
+        let v = so3.project_tangent(&q, &vec![1.0, 2.0, 3.0, 4.0]);
+        assert_relative_eq!(dot(&v, &q), 0.0, epsilon = 1e-15);
+        assert_slices_eq(
+            &so3.project(&vec![2.0, 0.0, 0.0, 0.0]).unwrap(),
+            &SO3::identity(),
+            0.0,
+        );
+        assert_error!(
+            so3.project(&vec![0.0f64; 4]),
+            ArgminError,
+            "Invalid parameter: \"`SO3`: quaternion must have a finite, nonzero norm.\""
+        );
+        assert_error!(
+            so3.project(&vec![1.0f64; 3]),
+            ArgminError,
+            "Invalid parameter: \"`SO3`: parameter must be a quaternion [w, x, y, z].\""
+        );
+    }
+
+    #[test]
+    fn test_se3_exp_log() {
+        for xi in [
+            [0.0, 0.0, 0.0, 1.0, -2.0, 3.0],
+            [1e-6, 0.0, -1e-6, 0.5, 0.5, 0.5],
+            [0.3, -0.2, 0.1, 1.0, 0.0, -1.0],
+            [1.0, 2.0, -0.5, -3.0, 2.0, 1.0],
+        ] {
+            let pose = SE3::exp(&xi);
+            assert_slices_eq(&SE3::log(&pose), &xi, 1e-12);
+        }
+        // a pure rotation of pi / 2 around z applied to the twist's translation direction
+        let pose = SE3::exp(&[0.0, 0.0, PI / 2.0, PI / 2.0, 0.0, 0.0]);
+        assert_slices_eq(&pose[4..], &[1.0, 1.0, 0.0], 1e-15);
+    }
+
+    #[test]
+    fn test_se3_compose() {
+        let a = SE3::exp(&[0.1, 0.2, 0.3, 1.0, 2.0, 3.0]);
+        let b = SE3::exp(&[-0.5, 0.0, 1.0, 0.0, -1.0, 0.5]);
+        let p = [1.0, -2.0, 0.5];
+        assert_slices_eq(
+            &SE3::transform(&SE3::compose(&a, &b), &p),
+            &SE3::transform(&a, &SE3::transform(&b, &p)),
+            1e-14,
+        );
+        assert_slices_eq(
+            &SE3::compose(&SE3::inverse(&a), &a),
+            &SE3::identity(),
+            1e-15,
+        );
+    }
+
+    #[test]
+    fn test_se3_manifold() {
+        let se3 = SE3::new();
+        let pose = SE3::exp(&[0.3, 0.2, -0.1, 1.0, 2.0, 3.0]);
+        let v = se3.project_tangent(&pose, &vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]);
+        assert_relative_eq!(dot(&v[..4], &pose[..4]), 0.0, epsilon = 1e-15);
+        assert_slices_eq(&v[4..], &[5.0, 6.0, 7.0], 0.0);
+        let moved = se3.retract(&pose, &v).unwrap();
+        assert_relative_eq!(dot(&moved[..4], &moved[..4]), 1.0, epsilon = 1e-15);
+        assert_slices_eq(
+            &moved[4..],
+            &[pose[4] + 5.0, pose[5] + 6.0, pose[6] + 7.0],
+            1e-15,
+        );
+        assert_error!(
+            se3.project(&vec![1.0f64; 4]),
+            ArgminError,
+            "Invalid parameter: \"`SE3`: parameter must be a quaternion followed by a translation.\""
+        );
+    }
+
+    /// Point set registration: the pose which maps `source` onto `target` in the least squares
+    /// sense
+    struct Registration {
+        source: Vec<[f64; 3]>,
+        target: Vec<[f64; 3]>,
+    }
+
+    impl CostFunction for Registration {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, pose: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(self
+                .source
+                .iter()
+                .zip(self.target.iter())
+                .map(|(s, t)| {
+                    let p = SE3::transform(pose, s);
+                    (0..3).map(|i| (p[i] - t[i]).powi(2)).sum::<f64>()
+                })
+                .sum())
+        }
+    }
+
+    impl Gradient for Registration {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        /// Euclidean gradient with respect to the quaternion components and the translation
+        fn gradient(&self, pose: &Self::Param) -> Result<Self::Gradient, Error> {
+            let w = pose[0];
+            let u = [pose[1], pose[2], pose[3]];
+            let mut grad = vec![0.0; 7];
+            for (s, t) in self.source.iter().zip(self.target.iter()) {
+                let p = SE3::transform(pose, s);
+                let r: Vec<f64> = (0..3).map(|i| 2.0 * (p[i] - t[i])).collect();
+                // R(q) s = (w^2 - |u|^2) s + 2 (u . s) u + 2 w (u x s) on unit quaternions
+                let us = dot(&u, s);
+                let uxs = cross(&u, s);
+                let d_w: Vec<f64> = (0..3).map(|i| 2.0 * w * s[i] + 2.0 * uxs[i]).collect();
+                grad[0] += dot(&r, &d_w);
+                for k in 0..3 {
+                    let mut e = [0.0; 3];
+                    e[k] = 1.0;
+                    let exs = cross(&e, s);
+                    let d_k: Vec<f64> = (0..3)
+                        .map(|i| {
+                            -2.0 * u[k] * s[i]
+                                + 2.0 * s[k] * u[i]
+                                + 2.0 * us * e[i]
+                                + 2.0 * w * exs[i]
+                        })
+                        .collect();
+                    grad[1 + k] += dot(&r, &d_k);
+                    grad[4 + k] += r[k];
+                }
+            }
+            Ok(grad)
+        }
+    }
+
+    #[test]
+    fn test_registration() {
+        let truth = SE3::exp(&[0.4, -0.3, 1.2, 0.5, -1.0, 2.0]);
+        let source = vec![
+            [1.0, 0.0, 0.0],
+            [0.0, 2.0, 0.0],
+            [0.0, 0.0, 3.0],
+            [1.0, 1.0, 1.0],
+            [-1.0, 0.5, 2.0],
+        ];
+        let target = source.iter().map(|s| SE3::transform(&truth, s)).collect();
+        let res = Executor::new(
+            Registration { source, target },
+            RiemannianConjugateGradient::new(SE3::new()),
+        )
+        .configure(|state| state.param(SE3::identity()).max_iters(2000))
+        .run()
+        .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let best = res.state.get_best_param().unwrap();
+        let sign = best[0].signum() * truth[0].signum();
+        for i in 0..4 {
+            assert_relative_eq!(sign * best[i], truth[i], epsilon = 1e-7);
+        }
+        assert_slices_eq(&best[4..], &truth[4..], 1e-7);
+    }
+}
@@ -0,0 +1,341 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, IterState, Problem, Solver, TerminationReason,
+    TerminationStatus, KV,
+};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Bisection method
+///
+/// The simplest bracketing root finding method. In each iteration, the function is evaluated at
+/// the midpoint of the current interval, which is then replaced by the half containing a sign
+/// change. The interval is therefore halved in each iteration, which guarantees convergence for
+/// continuous functions, albeit only linearly.
+///
+/// The initial interval `[min, max]` must bracket a root, i.e. `f(min)` and `f(max)` must have
+/// different signs. The method terminates once the width of the bracketing interval falls below
+/// the tolerance on `x` (see [`with_tolerance_x`](`Bisection::with_tolerance_x`)) or the
+/// absolute value of the function falls below the tolerance on `f` (see
+/// [`with_tolerance_f`](`Bisection::with_tolerance_f`)).
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`].
+///
+/// ##  Reference
+///
+/// <https://en.wikipedia.org/wiki/Bisection_method>
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct Bisection<F> {
+    /// Tolerance on the width of the bracketing interval
+    tol_x: F,
+    /// Tolerance on the absolute value of the function
+    tol_f: F,
+    /// Left boundary of current interval
+    a: F,
+    /// Right boundary of current interval
+    b: F,
+    /// Function value at `a`
+    fa: F,
+    /// Function value at `b`
+    fb: F,
+}
+
+impl<F> Bisection<F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`Bisection`].
+    ///
+    /// The values `min` and `max` must bracket the root of the function and `min` must be
+    /// smaller than `max`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::rootfinding::Bisection;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let bisection = Bisection::new(-4.0f64, 0.5)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(min: F, max: F) -> Result<Self, Error> {
+        if !min.is_finite() || !max.is_finite() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Bisection`: `min` and `max` must be finite."
+            ));
+        }
+        if max <= min {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Bisection`: `min` must be smaller than `max`."
+            ));
+        }
+        Ok(Bisection {
+            tol_x: F::epsilon().sqrt(),
+            tol_f: float!(0.0),
+            a: min,
+            b: max,
+            fa: F::nan(),
+            fb: F::nan(),
+        })
+    }
+
+    /// Set tolerance on the width of the bracketing interval.
+    ///
+    /// Must be larger than `0` and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::rootfinding::Bisection;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let bisection = Bisection::new(-4.0f64, 0.5)?.with_tolerance_x(1e-12)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance_x(mut self, tol_x: F) -> Result<Self, Error> {
+        if tol_x <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Bisection`: Tolerance on x must be larger than 0."
+            ));
+        }
+        self.tol_x = tol_x;
+        Ok(self)
+    }
+
+    /// Set tolerance on the absolute value of the function.
+    ///
+    /// Must be non-negative and defaults to `0`, which means that only an exact root stops the
+    /// method before the bracketing interval is small enough.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::rootfinding::Bisection;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let bisection = Bisection::new(-4.0f64, 0.5)?.with_tolerance_f(1e-10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance_f(mut self, tol_f: F) -> Result<Self, Error> {
+        if tol_f < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Bisection`: Tolerance on f must be non-negative."
+            ));
+        }
+        self.tol_f = tol_f;
+        Ok(self)
+    }
+}
+
+impl<O, F> Solver<O, IterState<F, (), (), (), F>> for Bisection<F>
+where
+    O: CostFunction<Param = F, Output = F>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Bisection";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        // Bisection maintains its own state
+        state: IterState<F, (), (), (), F>,
+    ) -> Result<(IterState<F, (), (), (), F>, Option<KV>), Error> {
+        self.fa = problem.cost(&self.a)?;
+        self.fb = problem.cost(&self.b)?;
+        if self.fa.is_nan() || self.fb.is_nan() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Bisection`: f(min) and f(max) must not be NaN."
+            ));
+        }
+        if self.fa * self.fb > float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Bisection`: f(min) and f(max) must have different signs."
+            ));
+        }
+        let (x, fx) = if self.fa.abs() <= self.fb.abs() {
+            (self.a, self.fa)
+        } else {
+            (self.b, self.fb)
+        };
+        Ok((state.param(x).cost(fx.abs()), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        // Bisection maintains its own state
+        state: IterState<F, (), (), (), F>,
+    ) -> Result<(IterState<F, (), (), (), F>, Option<KV>), Error> {
+        let mid = self.a + float!(0.5) * (self.b - self.a);
+        let fmid = problem.cost(&mid)?;
+        if fmid * self.fa > float!(0.0) {
+            self.a = mid;
+            self.fa = fmid;
+        } else {
+            self.b = mid;
+            self.fb = fmid;
+        }
+        Ok((
+            state.param(mid).cost(fmid.abs()),
+            Some(kv!("interval_width" => self.b - self.a;)),
+        ))
+    }
+
+    fn terminate(&mut self, state: &IterState<F, (), (), (), F>) -> TerminationStatus {
+        if state.get_cost() <= self.tol_f || self.b - self.a <= self.tol_x {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Executor, State};
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(bisection, Bisection<f64>);
+
+    struct Cubic {}
+
+    impl CostFunction for Cubic {
+        type Param = f64;
+        type Output = f64;
+
+        fn cost(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+            Ok((x + 3.0) * (x - 1.0).powi(2))
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let Bisection {
+            tol_x,
+            tol_f,
+            a,
+            b,
+            fa,
+            fb,
+        } = Bisection::new(-4.0f64, 0.5).unwrap();
+        assert_eq!(tol_x.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert_eq!(tol_f.to_ne_bytes(), 0.0f64.to_ne_bytes());
+        assert_eq!(a.to_ne_bytes(), (-4.0f64).to_ne_bytes());
+        assert_eq!(b.to_ne_bytes(), 0.5f64.to_ne_bytes());
+        assert!(fa.is_nan());
+        assert!(fb.is_nan());
+
+        for (min, max) in [(1.0f64, 1.0), (2.0, 1.0)] {
+            let res = Bisection::new(min, max);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`Bisection`: `min` must be smaller than `max`.\""
+            );
+        }
+
+        for (min, max) in [(f64::NAN, 1.0), (0.0, f64::INFINITY)] {
+            let res = Bisection::new(min, max);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`Bisection`: `min` and `max` must be finite.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_tolerances() {
+        let bisection = Bisection::new(-4.0f64, 0.5)
+            .unwrap()
+            .with_tolerance_x(1e-4)
+            .unwrap()
+            .with_tolerance_f(1e-6)
+            .unwrap();
+        assert_eq!(bisection.tol_x.to_ne_bytes(), 1e-4f64.to_ne_bytes());
+        assert_eq!(bisection.tol_f.to_ne_bytes(), 1e-6f64.to_ne_bytes());
+
+        for tol in [0.0, -1.0] {
+            let res = Bisection::new(-4.0f64, 0.5).unwrap().with_tolerance_x(tol);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`Bisection`: Tolerance on x must be larger than 0.\""
+            );
+        }
+
+        let res = Bisection::new(-4.0f64, 0.5).unwrap().with_tolerance_f(-1.0);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`Bisection`: Tolerance on f must be non-negative.\""
+        );
+    }
+
+    #[test]
+    fn test_solver() {
+        let res = Executor::new(
+            Cubic {},
+            Bisection::new(-4.0, 0.5)
+                .unwrap()
+                .with_tolerance_x(1e-10)
+                .unwrap(),
+        )
+        .configure(|state| state.max_iters(100))
+        .run()
+        .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        // 4.5 / 2^36 < 1e-10
+        assert_eq!(res.state.get_iter(), 36);
+        assert_relative_eq!(*res.state.get_param().unwrap(), -3.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_exact_root() {
+        let res = Executor::new(Cubic {}, Bisection::new(-5.0, -1.0).unwrap())
+            .configure(|state| state.max_iters(100))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        assert_eq!(res.state.get_iter(), 1);
+        assert_eq!(
+            res.state.get_param().unwrap().to_ne_bytes(),
+            (-3.0f64).to_ne_bytes()
+        );
+    }
+
+    #[test]
+    fn test_wrong_sign() {
+        let res = Executor::new(Cubic {}, Bisection::new(0.0, 0.5).unwrap()).run();
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`Bisection`: f(min) and f(max) must have different signs.\""
+        );
+    }
+}
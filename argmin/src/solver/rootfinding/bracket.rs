@@ -0,0 +1,79 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::ArgminFloat;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Bracketing interval used as a safeguard by the derivative-based root finders.
+///
+/// Every evaluated point shrinks the interval such that it keeps bracketing a root. Steps which
+/// would leave the interval are replaced by a bisection step.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub(super) struct Bracket<F> {
+    /// Left boundary
+    pub(super) a: F,
+    /// Right boundary
+    pub(super) b: F,
+    /// Function value at `a`
+    pub(super) fa: F,
+}
+
+impl<F> Bracket<F>
+where
+    F: ArgminFloat,
+{
+    /// Replace the boundary which has the same sign as `fx` with `x`
+    pub(super) fn update(&mut self, x: F, fx: F) {
+        if fx * self.fa > float!(0.0) {
+            self.a = x;
+            self.fa = fx;
+        } else {
+            self.b = x;
+        }
+    }
+
+    /// Returns `x` if it lies strictly inside the interval and the midpoint otherwise (which
+    /// includes non-finite `x`). The second return value indicates whether the midpoint was taken.
+    pub(super) fn safeguard(&self, x: F) -> (F, bool) {
+        if x > self.a && x < self.b {
+            (x, false)
+        } else {
+            (self.a + float!(0.5) * (self.b - self.a), true)
+        }
+    }
+
+    /// Width of the interval
+    pub(super) fn width(&self) -> F {
+        self.b - self.a
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bracket() {
+        let mut bracket = Bracket {
+            a: -1.0f64,
+            b: 3.0,
+            fa: -2.0,
+        };
+        assert_eq!(bracket.safeguard(0.5), (0.5, false));
+        assert_eq!(bracket.safeguard(3.0), (1.0, true));
+        assert_eq!(bracket.safeguard(f64::NAN), (1.0, true));
+
+        bracket.update(0.0, -1.0);
+        assert_eq!(bracket.a.to_ne_bytes(), 0.0f64.to_ne_bytes());
+        assert_eq!(bracket.fa.to_ne_bytes(), (-1.0f64).to_ne_bytes());
+        bracket.update(2.0, 1.0);
+        assert_eq!(bracket.b.to_ne_bytes(), 2.0f64.to_ne_bytes());
+        assert_eq!(bracket.width().to_ne_bytes(), 2.0f64.to_ne_bytes());
+    }
+}
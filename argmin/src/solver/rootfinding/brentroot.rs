@@ -26,6 +26,10 @@ pub enum BrentRootError {
 /// and inverse quadratic interpolation. It has the reliability of bisection
 /// but it can be as quick as some of the less-reliable methods.
 ///
+/// The initial interval `[min, max]` must bracket a root, i.e. `f(min)` and `f(max)` must have
+/// different signs. The method terminates once the bracketing interval is smaller than the
+/// requested tolerance or an exact root was found.
+///
 /// ## Requirements on the optimization problem
 ///
 /// The optimization problem is required to implement [`CostFunction`].
@@ -60,6 +64,13 @@ impl<F: ArgminFloat> BrentRoot<F> {
     /// Constructor
     /// The values `min` and `max` must bracketing the root of the function.
     /// The parameter `tol` specifies the relative error to be targeted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::rootfinding::BrentRoot;
+    /// let brent = BrentRoot::new(-4.0f64, 0.5, 1e-11);
+    /// ```
     pub fn new(min: F, max: F, tol: F) -> Self {
         BrentRoot {
             tol,
@@ -184,4 +195,42 @@ mod tests {
     use crate::test_trait_impl;
 
     test_trait_impl!(brent, BrentRoot<f64>);
+
+    struct Cubic {}
+
+    impl CostFunction for Cubic {
+        type Param = f64;
+        type Output = f64;
+
+        fn cost(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+            Ok((x + 3.0) * (x - 1.0).powi(2))
+        }
+    }
+
+    #[test]
+    fn test_solver() {
+        use crate::core::Executor;
+        use approx::assert_relative_eq;
+
+        let res = Executor::new(Cubic {}, BrentRoot::new(-4.0, 0.5, 1e-11))
+            .configure(|state| state.max_iters(100))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        assert_relative_eq!(*res.state.get_param().unwrap(), -3.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_wrong_sign() {
+        use crate::core::Executor;
+
+        let res = Executor::new(Cubic {}, BrentRoot::new(0.0, 0.5, 1e-11)).run();
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "BrentRoot error: f(min) and f(max) must have different signs."
+        );
+    }
 }
@@ -0,0 +1,517 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::bracket::Bracket;
+use crate::core::{
+    ArgminFloat, CostFunction, Error, Gradient, Hessian, IterState, Problem, Solver, State,
+    TerminationReason, TerminationStatus, KV,
+};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Halley's method for root finding
+///
+/// Finds a root of a scalar function `f` using its first and second derivative:
+///
+/// `x_{k+1} = x_k - 2 f(x_k) f'(x_k) / (2 f'(x_k)^2 - f(x_k) f''(x_k))`
+///
+/// Close to a simple root, the method converges cubically, at the cost of evaluating the second
+/// derivative. Far away from a root, Halley steps may diverge. As a safeguard, a bracketing
+/// interval can be provided via [`with_bracket`](`HalleyRoot::with_bracket`). The interval is
+/// shrunk with every iterate and whenever a Halley step would leave it (or is undefined), a
+/// bisection step is taken instead. Without a bracketing interval, the solver terminates with
+/// [`TerminationReason::SolverExit`] if a step is not finite.
+///
+/// The initial guess needs to be provided via the `configure` method of the
+/// [`Executor`](`crate::core::Executor`). The method terminates once the step size (or the width
+/// of the bracketing interval) falls below the tolerance on `x` (see
+/// [`with_tolerance_x`](`HalleyRoot::with_tolerance_x`)) or the absolute value of the function
+/// falls below the tolerance on `f` (see [`with_tolerance_f`](`HalleyRoot::with_tolerance_f`)).
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`] (returning `f(x)`),
+/// [`Gradient`] (returning `f'(x)`) and [`Hessian`] (returning `f''(x)`).
+///
+/// ## Reference
+///
+/// <https://en.wikipedia.org/wiki/Halley%27s_method>
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct HalleyRoot<F> {
+    /// Tolerance on the step size
+    tol_x: F,
+    /// Tolerance on the absolute value of the function
+    tol_f: F,
+    /// Optional bracketing interval
+    bracket: Option<Bracket<F>>,
+    /// Function value at the current iterate
+    fx: F,
+    /// Most recent step
+    step: F,
+}
+
+impl<F> HalleyRoot<F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`HalleyRoot`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::rootfinding::HalleyRoot;
+    /// let halley: HalleyRoot<f64> = HalleyRoot::new();
+    /// ```
+    pub fn new() -> Self {
+        HalleyRoot {
+            tol_x: F::epsilon().sqrt(),
+            tol_f: float!(0.0),
+            bracket: None,
+            fx: F::nan(),
+            step: F::nan(),
+        }
+    }
+
+    /// Provide a bracketing interval `[min, max]` which safeguards the Halley steps.
+    ///
+    /// `min` must be smaller than `max`, `f(min)` and `f(max)` must have different signs and the
+    /// initial guess must lie within the interval.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::rootfinding::HalleyRoot;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let halley = HalleyRoot::new().with_bracket(-4.0f64, 0.5)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_bracket(mut self, min: F, max: F) -> Result<Self, Error> {
+        if !min.is_finite() || !max.is_finite() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`HalleyRoot`: `min` and `max` must be finite."
+            ));
+        }
+        if max <= min {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`HalleyRoot`: `min` must be smaller than `max`."
+            ));
+        }
+        self.bracket = Some(Bracket {
+            a: min,
+            b: max,
+            fa: F::nan(),
+        });
+        Ok(self)
+    }
+
+    /// Set tolerance on the step size.
+    ///
+    /// Must be larger than `0` and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::rootfinding::HalleyRoot;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let halley = HalleyRoot::new().with_tolerance_x(1e-12f64)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance_x(mut self, tol_x: F) -> Result<Self, Error> {
+        if tol_x <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`HalleyRoot`: Tolerance on x must be larger than 0."
+            ));
+        }
+        self.tol_x = tol_x;
+        Ok(self)
+    }
+
+    /// Set tolerance on the absolute value of the function.
+    ///
+    /// Must be non-negative and defaults to `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::rootfinding::HalleyRoot;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let halley = HalleyRoot::new().with_tolerance_f(1e-10f64)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance_f(mut self, tol_f: F) -> Result<Self, Error> {
+        if tol_f < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`HalleyRoot`: Tolerance on f must be non-negative."
+            ));
+        }
+        self.tol_f = tol_f;
+        Ok(self)
+    }
+}
+
+impl<F> Default for HalleyRoot<F>
+where
+    F: ArgminFloat,
+{
+    fn default() -> HalleyRoot<F> {
+        HalleyRoot::new()
+    }
+}
+
+impl<O, F> Solver<O, IterState<F, F, (), F, F>> for HalleyRoot<F>
+where
+    O: CostFunction<Param = F, Output = F>
+        + Gradient<Param = F, Gradient = F>
+        + Hessian<Param = F, Hessian = F>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Halley method (root finding)";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<F, F, (), F, F>,
+    ) -> Result<(IterState<F, F, (), F, F>, Option<KV>), Error> {
+        let x = *state.get_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`HalleyRoot` requires an initial guess. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        if let Some(bracket) = self.bracket.as_mut() {
+            if x < bracket.a || x > bracket.b {
+                return Err(argmin_error!(
+                    InvalidParameter,
+                    "`HalleyRoot`: Initial guess must lie within the bracketing interval."
+                ));
+            }
+            bracket.fa = problem.cost(&bracket.a)?;
+            let fb = problem.cost(&bracket.b)?;
+            if bracket.fa * fb > float!(0.0) {
+                return Err(argmin_error!(
+                    InvalidParameter,
+                    "`HalleyRoot`: f(min) and f(max) must have different signs."
+                ));
+            }
+        }
+        self.fx = problem.cost(&x)?;
+        Ok((state.cost(self.fx.abs()), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<F, F, (), F, F>,
+    ) -> Result<(IterState<F, F, (), F, F>, Option<KV>), Error> {
+        let x = *state.get_param().unwrap();
+        let df = problem.gradient(&x)?;
+        let d2f = problem.hessian(&x)?;
+        let mut x_new = x - float!(2.0) * self.fx * df / (float!(2.0) * df.powi(2) - self.fx * d2f);
+        let mut bisection = false;
+        if let Some(bracket) = self.bracket.as_mut() {
+            bracket.update(x, self.fx);
+            (x_new, bisection) = bracket.safeguard(x_new);
+        } else if !x_new.is_finite() {
+            return Ok((
+                state.terminate_with(TerminationReason::SolverExit(
+                    "Halley step is not finite".to_string(),
+                )),
+                None,
+            ));
+        }
+        self.step = x_new - x;
+        self.fx = problem.cost(&x_new)?;
+        Ok((
+            state
+                .param(x_new)
+                .gradient(df)
+                .hessian(d2f)
+                .cost(self.fx.abs()),
+            Some(kv!("bisection" => bisection;)),
+        ))
+    }
+
+    fn terminate(&mut self, state: &IterState<F, F, (), F, F>) -> TerminationStatus {
+        if state.get_cost() <= self.tol_f
+            || self.step.abs() <= self.tol_x
+            || self
+                .bracket
+                .map(|bracket| bracket.width() <= self.tol_x)
+                .unwrap_or(false)
+        {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Executor};
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(halley_root, HalleyRoot<f64>);
+
+    struct Cubic {}
+
+    impl CostFunction for Cubic {
+        type Param = f64;
+        type Output = f64;
+
+        fn cost(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+            Ok((x + 3.0) * (x - 1.0).powi(2))
+        }
+    }
+
+    impl Gradient for Cubic {
+        type Param = f64;
+        type Gradient = f64;
+
+        fn gradient(&self, x: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok((x - 1.0).powi(2) + 2.0 * (x + 3.0) * (x - 1.0))
+        }
+    }
+
+    impl Hessian for Cubic {
+        type Param = f64;
+        type Hessian = f64;
+
+        fn hessian(&self, x: &Self::Param) -> Result<Self::Hessian, Error> {
+            Ok(6.0 * x + 2.0)
+        }
+    }
+
+    /// `f(x) = x * exp(-x^2)`
+    struct Gauss {}
+
+    impl CostFunction for Gauss {
+        type Param = f64;
+        type Output = f64;
+
+        fn cost(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(x * (-x.powi(2)).exp())
+        }
+    }
+
+    impl Gradient for Gauss {
+        type Param = f64;
+        type Gradient = f64;
+
+        fn gradient(&self, x: &Self::Param) -> Result<Self::Gradient, Error> {
+            Ok((1.0 - 2.0 * x.powi(2)) * (-x.powi(2)).exp())
+        }
+    }
+
+    impl Hessian for Gauss {
+        type Param = f64;
+        type Hessian = f64;
+
+        fn hessian(&self, x: &Self::Param) -> Result<Self::Hessian, Error> {
+            Ok((4.0 * x.powi(3) - 6.0 * x) * (-x.powi(2)).exp())
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let HalleyRoot {
+            tol_x,
+            tol_f,
+            bracket,
+            fx,
+            step,
+        } = HalleyRoot::<f64>::new();
+        assert_eq!(tol_x.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert_eq!(tol_f.to_ne_bytes(), 0.0f64.to_ne_bytes());
+        assert!(bracket.is_none());
+        assert!(fx.is_nan());
+        assert!(step.is_nan());
+    }
+
+    #[test]
+    fn test_with_bracket() {
+        let bracket = HalleyRoot::new()
+            .with_bracket(-4.0f64, 0.5)
+            .unwrap()
+            .bracket
+            .unwrap();
+        assert_eq!(bracket.a.to_ne_bytes(), (-4.0f64).to_ne_bytes());
+        assert_eq!(bracket.b.to_ne_bytes(), 0.5f64.to_ne_bytes());
+
+        for (min, max) in [(1.0f64, 1.0), (2.0, 1.0)] {
+            let res = HalleyRoot::new().with_bracket(min, max);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`HalleyRoot`: `min` must be smaller than `max`.\""
+            );
+        }
+
+        let res = HalleyRoot::new().with_bracket(f64::NEG_INFINITY, 1.0);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`HalleyRoot`: `min` and `max` must be finite.\""
+        );
+    }
+
+    #[test]
+    fn test_tolerances() {
+        let halley = HalleyRoot::new()
+            .with_tolerance_x(1e-4f64)
+            .unwrap()
+            .with_tolerance_f(1e-6)
+            .unwrap();
+        assert_eq!(halley.tol_x.to_ne_bytes(), 1e-4f64.to_ne_bytes());
+        assert_eq!(halley.tol_f.to_ne_bytes(), 1e-6f64.to_ne_bytes());
+
+        for tol in [0.0f64, -1.0] {
+            let res = HalleyRoot::new().with_tolerance_x(tol);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`HalleyRoot`: Tolerance on x must be larger than 0.\""
+            );
+        }
+
+        let res = HalleyRoot::new().with_tolerance_f(-1.0f64);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`HalleyRoot`: Tolerance on f must be non-negative.\""
+        );
+    }
+
+    #[test]
+    fn test_not_initialized() {
+        let res = Executor::new(Cubic {}, HalleyRoot::new()).run();
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`HalleyRoot` requires an initial guess. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_invalid_bracket() {
+        let res = Executor::new(Cubic {}, HalleyRoot::new().with_bracket(0.0, 0.5).unwrap())
+            .configure(|state| state.param(0.2))
+            .run();
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`HalleyRoot`: f(min) and f(max) must have different signs.\""
+        );
+
+        let res = Executor::new(Cubic {}, HalleyRoot::new().with_bracket(-4.0, 0.5).unwrap())
+            .configure(|state| state.param(1.0))
+            .run();
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`HalleyRoot`: ",
+                "Initial guess must lie within the bracketing interval.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_solver() {
+        let res = Executor::new(Cubic {}, HalleyRoot::new())
+            .configure(|state| state.param(-5.0).max_iters(100))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        assert!(res.state.get_iter() < 10);
+        assert_relative_eq!(*res.state.get_param().unwrap(), -3.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_safeguard() {
+        // Halley's method runs away from the root when starting too far away from it
+        let res = Executor::new(Gauss {}, HalleyRoot::new())
+            .configure(|state| state.param(2.0).max_iters(100))
+            .run()
+            .unwrap();
+        assert!(*res.state.get_param().unwrap() > 5.0);
+
+        // The bracketing interval prevents this
+        let res = Executor::new(Gauss {}, HalleyRoot::new().with_bracket(-1.0, 3.0).unwrap())
+            .configure(|state| state.param(2.0).max_iters(100))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        assert_relative_eq!(*res.state.get_param().unwrap(), 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_not_finite() {
+        // Both derivatives of `f(x) = x^3 + 1` vanish at `x = 0`
+        struct Cube {}
+
+        impl CostFunction for Cube {
+            type Param = f64;
+            type Output = f64;
+
+            fn cost(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+                Ok(x.powi(3) + 1.0)
+            }
+        }
+
+        impl Gradient for Cube {
+            type Param = f64;
+            type Gradient = f64;
+
+            fn gradient(&self, x: &Self::Param) -> Result<Self::Gradient, Error> {
+                Ok(3.0 * x.powi(2))
+            }
+        }
+
+        impl Hessian for Cube {
+            type Param = f64;
+            type Hessian = f64;
+
+            fn hessian(&self, x: &Self::Param) -> Result<Self::Hessian, Error> {
+                Ok(6.0 * x)
+            }
+        }
+
+        let res = Executor::new(Cube {}, HalleyRoot::new())
+            .configure(|state| state.param(0.0).max_iters(100))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverExit(
+                "Halley step is not finite".to_string()
+            ))
+        );
+    }
+}
@@ -0,0 +1,50 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Root finding methods
+//!
+//! Solvers for scalar equations `f(x) = 0`. The function `f` is provided via the
+//! [`CostFunction`](`crate::core::CostFunction`) trait with `Param` and `Output` both being the
+//! floating point type. As for the minimizers, the solvers are run with an
+//! [`Executor`](`crate::core::Executor`), which means that observers, checkpointing and the
+//! usual termination criteria are available. The parameter of the state holds the current
+//! approximation of the root and the cost holds the absolute value of the function at this
+//! point, such that a tolerance on the residual can be set via `target_cost`.
+//!
+//! The derivative-based methods additionally require the first (and second) derivative of `f`
+//! via the [`Gradient`](`crate::core::Gradient`) (and [`Hessian`](`crate::core::Hessian`))
+//! trait. They can be safeguarded with a bracketing interval, in which case steps leaving the
+//! interval are replaced by bisection steps.
+//!
+//! ## Available solvers
+//!
+//! * [`Bisection`]: Bisection method, halving the bracketing interval in each iteration
+//! * [`BrentRoot`]: Brent-Dekker method, combining bisection, the secant method and inverse
+//!   quadratic interpolation
+//! * [`HalleyRoot`]: Halley's method, using the first and second derivative
+//! * [`NewtonRoot`]: Newton's method, using the first derivative
+//! * [`RegulaFalsi`]: Illinois variant of the regula falsi (false position) method
+//! * [`Secant`]: Secant method, which does not require the starting points to bracket a root
+//!
+//! ## References
+//!
+//! <https://en.wikipedia.org/wiki/Root-finding_algorithms>
+
+mod bisection;
+mod bracket;
+mod brentroot;
+mod halleyroot;
+mod newtonroot;
+mod regulafalsi;
+mod secant;
+
+pub use bisection::Bisection;
+pub use brentroot::{BrentRoot, BrentRootError};
+pub use halleyroot::HalleyRoot;
+pub use newtonroot::NewtonRoot;
+pub use regulafalsi::RegulaFalsi;
+pub use secant::Secant;
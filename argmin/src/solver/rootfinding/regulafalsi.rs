@@ -0,0 +1,347 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, IterState, Problem, Solver, TerminationReason,
+    TerminationStatus, KV,
+};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Regula falsi (Illinois variant)
+///
+/// Derivative-free bracketing root finding method. In each iteration, the bracketing interval
+/// `[a, b]` is split at the root of the secant through `(a, f(a))` and `(b, f(b))` and replaced
+/// by the part containing a sign change. Like bisection, a root therefore always remains
+/// bracketed.
+///
+/// The plain regula falsi method tends to retain one of the endpoints for many iterations, which
+/// reduces its convergence to linear. The Illinois variant halves the function value of an
+/// endpoint whenever it is retained twice in a row, which restores superlinear convergence.
+///
+/// The initial interval `[min, max]` must bracket a root, i.e. `f(min)` and `f(max)` must have
+/// different signs. The method terminates once the width of the bracketing interval falls below
+/// the tolerance on `x` (see [`with_tolerance_x`](`RegulaFalsi::with_tolerance_x`)) or the
+/// absolute value of the function falls below the tolerance on `f` (see
+/// [`with_tolerance_f`](`RegulaFalsi::with_tolerance_f`)).
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`].
+///
+/// ##  Reference
+///
+/// <https://en.wikipedia.org/wiki/Regula_falsi#The_Illinois_algorithm>
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct RegulaFalsi<F> {
+    /// Tolerance on the width of the bracketing interval
+    tol_x: F,
+    /// Tolerance on the absolute value of the function
+    tol_f: F,
+    /// Left boundary of current interval
+    a: F,
+    /// Right boundary of current interval
+    b: F,
+    /// (Possibly scaled) function value at `a`
+    fa: F,
+    /// (Possibly scaled) function value at `b`
+    fb: F,
+    /// Boundary replaced in the last iteration (`-1`: left, `1`: right, `0`: none)
+    side: i8,
+}
+
+impl<F> RegulaFalsi<F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`RegulaFalsi`].
+    ///
+    /// The values `min` and `max` must bracket the root of the function and `min` must be
+    /// smaller than `max`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::rootfinding::RegulaFalsi;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let regula_falsi = RegulaFalsi::new(-4.0f64, 0.5)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(min: F, max: F) -> Result<Self, Error> {
+        if !min.is_finite() || !max.is_finite() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`RegulaFalsi`: `min` and `max` must be finite."
+            ));
+        }
+        if max <= min {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`RegulaFalsi`: `min` must be smaller than `max`."
+            ));
+        }
+        Ok(RegulaFalsi {
+            tol_x: F::epsilon().sqrt(),
+            tol_f: float!(0.0),
+            a: min,
+            b: max,
+            fa: F::nan(),
+            fb: F::nan(),
+            side: 0,
+        })
+    }
+
+    /// Set tolerance on the width of the bracketing interval.
+    ///
+    /// Must be larger than `0` and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::rootfinding::RegulaFalsi;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let regula_falsi = RegulaFalsi::new(-4.0f64, 0.5)?.with_tolerance_x(1e-12)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance_x(mut self, tol_x: F) -> Result<Self, Error> {
+        if tol_x <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`RegulaFalsi`: Tolerance on x must be larger than 0."
+            ));
+        }
+        self.tol_x = tol_x;
+        Ok(self)
+    }
+
+    /// Set tolerance on the absolute value of the function.
+    ///
+    /// Must be non-negative and defaults to `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::rootfinding::RegulaFalsi;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let regula_falsi = RegulaFalsi::new(-4.0f64, 0.5)?.with_tolerance_f(1e-10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance_f(mut self, tol_f: F) -> Result<Self, Error> {
+        if tol_f < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`RegulaFalsi`: Tolerance on f must be non-negative."
+            ));
+        }
+        self.tol_f = tol_f;
+        Ok(self)
+    }
+}
+
+impl<O, F> Solver<O, IterState<F, (), (), (), F>> for RegulaFalsi<F>
+where
+    O: CostFunction<Param = F, Output = F>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Regula falsi (Illinois)";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        // RegulaFalsi maintains its own state
+        state: IterState<F, (), (), (), F>,
+    ) -> Result<(IterState<F, (), (), (), F>, Option<KV>), Error> {
+        self.fa = problem.cost(&self.a)?;
+        self.fb = problem.cost(&self.b)?;
+        if self.fa.is_nan() || self.fb.is_nan() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`RegulaFalsi`: f(min) and f(max) must not be NaN."
+            ));
+        }
+        if self.fa * self.fb > float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`RegulaFalsi`: f(min) and f(max) must have different signs."
+            ));
+        }
+        let (x, fx) = if self.fa.abs() <= self.fb.abs() {
+            (self.a, self.fa)
+        } else {
+            (self.b, self.fb)
+        };
+        Ok((state.param(x).cost(fx.abs()), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        // RegulaFalsi maintains its own state
+        state: IterState<F, (), (), (), F>,
+    ) -> Result<(IterState<F, (), (), (), F>, Option<KV>), Error> {
+        // Clamp to the interval to guard against rounding errors
+        let c = ((self.a * self.fb - self.b * self.fa) / (self.fb - self.fa))
+            .max(self.a)
+            .min(self.b);
+        let fc = problem.cost(&c)?;
+        if fc * self.fb > float!(0.0) {
+            self.b = c;
+            self.fb = fc;
+            if self.side == 1 {
+                self.fa = self.fa * float!(0.5);
+            }
+            self.side = 1;
+        } else if fc * self.fa > float!(0.0) {
+            self.a = c;
+            self.fa = fc;
+            if self.side == -1 {
+                self.fb = self.fb * float!(0.5);
+            }
+            self.side = -1;
+        }
+        Ok((
+            state.param(c).cost(fc.abs()),
+            Some(kv!("interval_width" => self.b - self.a;)),
+        ))
+    }
+
+    fn terminate(&mut self, state: &IterState<F, (), (), (), F>) -> TerminationStatus {
+        if state.get_cost() <= self.tol_f || self.b - self.a <= self.tol_x {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Executor, State};
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(regula_falsi, RegulaFalsi<f64>);
+
+    struct Cubic {}
+
+    impl CostFunction for Cubic {
+        type Param = f64;
+        type Output = f64;
+
+        fn cost(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+            Ok((x + 3.0) * (x - 1.0).powi(2))
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let RegulaFalsi {
+            tol_x,
+            tol_f,
+            a,
+            b,
+            fa,
+            fb,
+            side,
+        } = RegulaFalsi::new(-4.0f64, 0.5).unwrap();
+        assert_eq!(tol_x.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert_eq!(tol_f.to_ne_bytes(), 0.0f64.to_ne_bytes());
+        assert_eq!(a.to_ne_bytes(), (-4.0f64).to_ne_bytes());
+        assert_eq!(b.to_ne_bytes(), 0.5f64.to_ne_bytes());
+        assert!(fa.is_nan());
+        assert!(fb.is_nan());
+        assert_eq!(side, 0);
+
+        for (min, max) in [(1.0f64, 1.0), (2.0, 1.0)] {
+            let res = RegulaFalsi::new(min, max);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`RegulaFalsi`: `min` must be smaller than `max`.\""
+            );
+        }
+
+        for (min, max) in [(f64::NAN, 1.0), (0.0, f64::INFINITY)] {
+            let res = RegulaFalsi::new(min, max);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`RegulaFalsi`: `min` and `max` must be finite.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_tolerances() {
+        let regula_falsi = RegulaFalsi::new(-4.0f64, 0.5)
+            .unwrap()
+            .with_tolerance_x(1e-4)
+            .unwrap()
+            .with_tolerance_f(1e-6)
+            .unwrap();
+        assert_eq!(regula_falsi.tol_x.to_ne_bytes(), 1e-4f64.to_ne_bytes());
+        assert_eq!(regula_falsi.tol_f.to_ne_bytes(), 1e-6f64.to_ne_bytes());
+
+        for tol in [0.0, -1.0] {
+            let res = RegulaFalsi::new(-4.0f64, 0.5)
+                .unwrap()
+                .with_tolerance_x(tol);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`RegulaFalsi`: Tolerance on x must be larger than 0.\""
+            );
+        }
+
+        let res = RegulaFalsi::new(-4.0f64, 0.5)
+            .unwrap()
+            .with_tolerance_f(-1.0);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`RegulaFalsi`: Tolerance on f must be non-negative.\""
+        );
+    }
+
+    #[test]
+    fn test_solver() {
+        let res = Executor::new(
+            Cubic {},
+            RegulaFalsi::new(-4.0, 0.5)
+                .unwrap()
+                .with_tolerance_x(1e-10)
+                .unwrap(),
+        )
+        .configure(|state| state.max_iters(100))
+        .run()
+        .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        // Bisection requires 36 iterations for the same tolerance
+        assert!(res.state.get_iter() < 36);
+        assert_relative_eq!(*res.state.get_param().unwrap(), -3.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_wrong_sign() {
+        let res = Executor::new(Cubic {}, RegulaFalsi::new(0.0, 0.5).unwrap()).run();
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`RegulaFalsi`: f(min) and f(max) must have different signs.\""
+        );
+    }
+}
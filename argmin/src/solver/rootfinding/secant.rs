@@ -0,0 +1,314 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{
+    ArgminFloat, CostFunction, Error, IterState, Problem, Solver, State, TerminationReason,
+    TerminationStatus, KV,
+};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Secant method
+///
+/// Derivative-free root finding method which replaces the derivative in Newton's method by the
+/// finite difference of the two most recent iterates:
+///
+/// `x_{k+1} = x_k - f(x_k) * (x_k - x_{k-1}) / (f(x_k) - f(x_{k-1}))`
+///
+/// Close to a simple root, the method converges superlinearly (with order of the golden ratio).
+/// In contrast to bracketing methods, the two starting points do not need to enclose a root,
+/// but convergence is not guaranteed. If the function values of the two most recent iterates are
+/// equal, the secant step is undefined and the solver terminates with
+/// [`TerminationReason::SolverExit`].
+///
+/// The method terminates once the step size falls below the tolerance on `x` (see
+/// [`with_tolerance_x`](`Secant::with_tolerance_x`)) or the absolute value of the function falls
+/// below the tolerance on `f` (see [`with_tolerance_f`](`Secant::with_tolerance_f`)).
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`].
+///
+/// ##  Reference
+///
+/// <https://en.wikipedia.org/wiki/Secant_method>
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct Secant<F> {
+    /// Tolerance on the step size
+    tol_x: F,
+    /// Tolerance on the absolute value of the function
+    tol_f: F,
+    /// Previous iterate
+    x0: F,
+    /// Current iterate
+    x1: F,
+    /// Function value at `x0`
+    f0: F,
+    /// Function value at `x1`
+    f1: F,
+}
+
+impl<F> Secant<F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`Secant`].
+    ///
+    /// Takes the two starting points `x0` and `x1`, which must be finite and distinct.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::rootfinding::Secant;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let secant = Secant::new(1.0f64, 2.0)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(x0: F, x1: F) -> Result<Self, Error> {
+        if !x0.is_finite() || !x1.is_finite() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Secant`: `x0` and `x1` must be finite."
+            ));
+        }
+        if x0 == x1 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Secant`: `x0` and `x1` must be distinct."
+            ));
+        }
+        Ok(Secant {
+            tol_x: F::epsilon().sqrt(),
+            tol_f: float!(0.0),
+            x0,
+            x1,
+            f0: F::nan(),
+            f1: F::nan(),
+        })
+    }
+
+    /// Set tolerance on the step size.
+    ///
+    /// Must be larger than `0` and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::rootfinding::Secant;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let secant = Secant::new(1.0f64, 2.0)?.with_tolerance_x(1e-12)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance_x(mut self, tol_x: F) -> Result<Self, Error> {
+        if tol_x <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Secant`: Tolerance on x must be larger than 0."
+            ));
+        }
+        self.tol_x = tol_x;
+        Ok(self)
+    }
+
+    /// Set tolerance on the absolute value of the function.
+    ///
+    /// Must be non-negative and defaults to `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::rootfinding::Secant;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let secant = Secant::new(1.0f64, 2.0)?.with_tolerance_f(1e-10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance_f(mut self, tol_f: F) -> Result<Self, Error> {
+        if tol_f < float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Secant`: Tolerance on f must be non-negative."
+            ));
+        }
+        self.tol_f = tol_f;
+        Ok(self)
+    }
+}
+
+impl<O, F> Solver<O, IterState<F, (), (), (), F>> for Secant<F>
+where
+    O: CostFunction<Param = F, Output = F>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Secant";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        // Secant maintains its own state
+        state: IterState<F, (), (), (), F>,
+    ) -> Result<(IterState<F, (), (), (), F>, Option<KV>), Error> {
+        self.f0 = problem.cost(&self.x0)?;
+        self.f1 = problem.cost(&self.x1)?;
+        Ok((state.param(self.x1).cost(self.f1.abs()), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        // Secant maintains its own state
+        state: IterState<F, (), (), (), F>,
+    ) -> Result<(IterState<F, (), (), (), F>, Option<KV>), Error> {
+        if self.f1 == self.f0 {
+            return Ok((
+                state.terminate_with(TerminationReason::SolverExit(
+                    "Function values of the last two iterates are equal".to_string(),
+                )),
+                None,
+            ));
+        }
+        let x2 = self.x1 - self.f1 * (self.x1 - self.x0) / (self.f1 - self.f0);
+        let f2 = problem.cost(&x2)?;
+        self.x0 = self.x1;
+        self.f0 = self.f1;
+        self.x1 = x2;
+        self.f1 = f2;
+        Ok((
+            state.param(self.x1).cost(self.f1.abs()),
+            Some(kv!("step" => (self.x1 - self.x0).abs();)),
+        ))
+    }
+
+    fn terminate(&mut self, state: &IterState<F, (), (), (), F>) -> TerminationStatus {
+        if state.get_cost() <= self.tol_f || (self.x1 - self.x0).abs() <= self.tol_x {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Executor};
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(secant, Secant<f64>);
+
+    struct Quadratic {}
+
+    impl CostFunction for Quadratic {
+        type Param = f64;
+        type Output = f64;
+
+        fn cost(&self, x: &Self::Param) -> Result<Self::Output, Error> {
+            Ok(x.powi(2) - 2.0)
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let Secant {
+            tol_x,
+            tol_f,
+            x0,
+            x1,
+            f0,
+            f1,
+        } = Secant::new(1.0f64, 2.0).unwrap();
+        assert_eq!(tol_x.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert_eq!(tol_f.to_ne_bytes(), 0.0f64.to_ne_bytes());
+        assert_eq!(x0.to_ne_bytes(), 1.0f64.to_ne_bytes());
+        assert_eq!(x1.to_ne_bytes(), 2.0f64.to_ne_bytes());
+        assert!(f0.is_nan());
+        assert!(f1.is_nan());
+
+        let res = Secant::new(1.0f64, 1.0);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`Secant`: `x0` and `x1` must be distinct.\""
+        );
+
+        for (x0, x1) in [(f64::NAN, 1.0), (0.0, f64::INFINITY)] {
+            let res = Secant::new(x0, x1);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`Secant`: `x0` and `x1` must be finite.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_tolerances() {
+        let secant = Secant::new(1.0f64, 2.0)
+            .unwrap()
+            .with_tolerance_x(1e-4)
+            .unwrap()
+            .with_tolerance_f(1e-6)
+            .unwrap();
+        assert_eq!(secant.tol_x.to_ne_bytes(), 1e-4f64.to_ne_bytes());
+        assert_eq!(secant.tol_f.to_ne_bytes(), 1e-6f64.to_ne_bytes());
+
+        for tol in [0.0, -1.0] {
+            let res = Secant::new(1.0f64, 2.0).unwrap().with_tolerance_x(tol);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`Secant`: Tolerance on x must be larger than 0.\""
+            );
+        }
+
+        let res = Secant::new(1.0f64, 2.0).unwrap().with_tolerance_f(-1.0);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`Secant`: Tolerance on f must be non-negative.\""
+        );
+    }
+
+    #[test]
+    fn test_solver() {
+        // Starting points do not need to bracket the root
+        let res = Executor::new(Quadratic {}, Secant::new(3.0, 4.0).unwrap())
+            .configure(|state| state.max_iters(100))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        assert!(res.state.get_iter() < 15);
+        assert_relative_eq!(
+            *res.state.get_param().unwrap(),
+            2.0f64.sqrt(),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_equal_function_values() {
+        let res = Executor::new(Quadratic {}, Secant::new(-1.0, 1.0).unwrap())
+            .configure(|state| state.max_iters(100))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverExit(
+                "Function values of the last two iterates are equal".to_string()
+            ))
+        );
+    }
+}
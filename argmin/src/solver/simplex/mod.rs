@@ -0,0 +1,639 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Simplex method
+//!
+//! Dense revised simplex method for small linear programs. See [`Simplex`] for details.
+//!
+//! ## Reference
+//!
+//! Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
+//! Springer. ISBN 0-387-30303-0.
+
+use crate::core::{
+    ArgminFloat, Error, LinearProgram, LinearProgramState, Problem, Solver, State,
+    TerminationReason, TerminationStatus, KV,
+};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Phase of the simplex method
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+enum Phase {
+    /// Search for a feasible basis
+    One,
+    /// Optimization of the objective
+    Two,
+    /// Optimal basis found
+    Done,
+}
+
+/// # Simplex method
+///
+/// Solves linear programs in standard form
+///
+/// `min_x c^T x` subject to `A x = b` and `x >= 0`
+///
+/// as defined by the [`LinearProgram`] trait. Inequality constraints can be brought into this
+/// form by adding slack variables.
+///
+/// This is a dense implementation of the revised simplex method, which keeps the inverse of the
+/// basis matrix explicitly and is therefore only suitable for small problems. In phase one, a
+/// feasible basis is found by minimizing the sum of artificial variables; in phase two, the
+/// objective is minimized starting from this basis. Entering and leaving variables are chosen
+/// according to Bland's rule, which prevents cycling on degenerate problems. Each iteration of
+/// the solver performs a single pivot.
+///
+/// No initial parameter vector is required. The current basic solution and its objective value
+/// are stored in the state; during phase one, the cost is infinite. The solver terminates with
+/// [`TerminationReason::SolverConverged`] once an optimal basis has been found and with
+/// [`TerminationReason::SolverExit`] if the problem is infeasible or unbounded. The current phase
+/// is reported via the observers (`phase`).
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`LinearProgram`].
+///
+/// ## Example
+///
+/// ```
+/// # use argmin::core::{Error, Executor, LinearProgram, State};
+/// # use argmin::solver::simplex::Simplex;
+/// /// max 3 x + 2 y subject to x + y <= 4, x <= 3, x, y >= 0 (with slacks s1, s2)
+/// struct Problem {}
+///
+/// impl LinearProgram for Problem {
+///     type Param = Vec<f64>;
+///     type Float = f64;
+///
+///     fn c(&self) -> Result<Vec<f64>, Error> {
+///         Ok(vec![-3.0, -2.0, 0.0, 0.0])
+///     }
+///
+///     fn b(&self) -> Result<Vec<f64>, Error> {
+///         Ok(vec![4.0, 3.0])
+///     }
+///
+///     fn A(&self) -> Result<Vec<Vec<f64>>, Error> {
+///         Ok(vec![vec![1.0, 1.0, 1.0, 0.0], vec![1.0, 0.0, 0.0, 1.0]])
+///     }
+/// }
+///
+/// # fn main() -> Result<(), Error> {
+/// let res = Executor::new(Problem {}, Simplex::new()).run()?;
+/// assert_eq!(res.state.get_best_param().unwrap(), &vec![3.0, 1.0, 0.0, 0.0]);
+/// assert_eq!(res.state.get_best_cost(), -11.0);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// ## Reference
+///
+/// Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
+/// Springer. ISBN 0-387-30303-0.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct Simplex<F> {
+    /// Tolerance on reduced costs and pivot elements
+    tol: F,
+    /// Constraint matrix, with rows negated where `b` is negative
+    a: Vec<Vec<F>>,
+    /// Right-hand side, made non-negative
+    b: Vec<F>,
+    /// Objective coefficients
+    c: Vec<F>,
+    /// Indices of basic variables; artificial variables have indices `n..n+m`
+    basis: Vec<usize>,
+    /// Inverse of the basis matrix
+    binv: Vec<Vec<F>>,
+    /// Values of basic variables
+    x_b: Vec<F>,
+    /// Current phase
+    phase: Phase,
+}
+
+impl<F> Simplex<F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`Simplex`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::simplex::Simplex;
+    /// let simplex: Simplex<f64> = Simplex::new();
+    /// ```
+    pub fn new() -> Self {
+        Simplex {
+            tol: F::epsilon().sqrt(),
+            a: vec![],
+            b: vec![],
+            c: vec![],
+            basis: vec![],
+            binv: vec![],
+            x_b: vec![],
+            phase: Phase::One,
+        }
+    }
+
+    /// Set the tolerance on reduced costs and pivot elements
+    ///
+    /// Must be larger than 0 and defaults to `sqrt(EPSILON)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::simplex::Simplex;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let simplex = Simplex::new().with_tolerance(1e-10f64)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_tolerance(mut self, tol: F) -> Result<Self, Error> {
+        if tol <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Simplex`: tolerance must be > 0."
+            ));
+        }
+        self.tol = tol;
+        Ok(self)
+    }
+
+    /// Column `j` of the constraint matrix extended by the identity of the artificial variables
+    fn column(&self, j: usize) -> Vec<F> {
+        let n = self.c.len();
+        self.a
+            .iter()
+            .enumerate()
+            .map(|(i, row)| match j.checked_sub(n) {
+                None => row[j],
+                Some(k) if k == i => float!(1.0),
+                Some(_) => float!(0.0),
+            })
+            .collect()
+    }
+
+    /// Cost of variable `j` in the current phase
+    fn cost_of(&self, j: usize) -> F {
+        match (self.phase, j < self.c.len()) {
+            (Phase::One, true) => float!(0.0),
+            (Phase::One, false) => float!(1.0),
+            (_, true) => self.c[j],
+            // Artificial variables are zero after phase one
+            (_, false) => float!(0.0),
+        }
+    }
+
+    /// Computes `B^{-1} v`
+    fn solve(&self, v: &[F]) -> Vec<F> {
+        self.binv
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .zip(v.iter())
+                    .fold(float!(0.0), |acc, (a, b)| acc + *a * *b)
+            })
+            .collect()
+    }
+
+    /// Replaces the basic variable in row `r` by variable `j` with column `d = B^{-1} A_j`
+    fn pivot(&mut self, r: usize, j: usize, d: &[F]) {
+        let pivot = d[r];
+        for v in self.binv[r].iter_mut() {
+            *v = *v / pivot;
+        }
+        self.x_b[r] = self.x_b[r] / pivot;
+        let row = self.binv[r].clone();
+        let x_r = self.x_b[r];
+        for (i, (binv, x)) in self.binv.iter_mut().zip(self.x_b.iter_mut()).enumerate() {
+            if i != r && d[i] != float!(0.0) {
+                for (v, w) in binv.iter_mut().zip(row.iter()) {
+                    *v = *v - d[i] * *w;
+                }
+                *x = *x - d[i] * x_r;
+            }
+        }
+        self.basis[r] = j;
+    }
+
+    /// Current basic solution (without artificial variables)
+    fn param(&self) -> Vec<F> {
+        let mut x = vec![float!(0.0); self.c.len()];
+        for (&j, &v) in self.basis.iter().zip(self.x_b.iter()) {
+            if j < x.len() {
+                x[j] = v;
+            }
+        }
+        x
+    }
+
+    /// Objective value of the current basic solution
+    fn objective(&self, x: &[F]) -> F {
+        self.c
+            .iter()
+            .zip(x.iter())
+            .fold(float!(0.0), |acc, (c, x)| acc + *c * *x)
+    }
+
+    /// Smallest nonbasic variable with negative reduced cost, if any
+    fn entering(&self) -> Option<usize> {
+        let num_vars = match self.phase {
+            Phase::One => self.c.len() + self.b.len(),
+            _ => self.c.len(),
+        };
+        let c_b: Vec<F> = self.basis.iter().map(|&j| self.cost_of(j)).collect();
+        // Simplex multipliers y = B^{-T} c_B
+        let y: Vec<F> = (0..self.b.len())
+            .map(|k| {
+                self.binv
+                    .iter()
+                    .zip(c_b.iter())
+                    .fold(float!(0.0), |acc, (row, c)| acc + *c * row[k])
+            })
+            .collect();
+        (0..num_vars)
+            .filter(|j| !self.basis.contains(j))
+            .find(|&j| {
+                let reduced = self
+                    .column(j)
+                    .iter()
+                    .zip(y.iter())
+                    .fold(self.cost_of(j), |acc, (a, y)| acc - *a * *y);
+                reduced < -self.tol
+            })
+    }
+
+    /// Pivots artificial variables out of the basis after phase one where possible. Artificial
+    /// variables which remain belong to redundant constraints and stay zero.
+    fn remove_artificials(&mut self) {
+        let n = self.c.len();
+        for r in 0..self.basis.len() {
+            if self.basis[r] < n {
+                continue;
+            }
+            let candidate = (0..n)
+                .filter(|j| !self.basis.contains(j))
+                .map(|j| (j, self.solve(&self.column(j))))
+                .find(|(_, d)| d[r].abs() > self.tol);
+            if let Some((j, d)) = candidate {
+                self.pivot(r, j, &d);
+            }
+        }
+    }
+}
+
+impl<F> Default for Simplex<F>
+where
+    F: ArgminFloat,
+{
+    fn default() -> Self {
+        Simplex::new()
+    }
+}
+
+impl<O, F> Solver<O, LinearProgramState<Vec<F>, F>> for Simplex<F>
+where
+    O: LinearProgram<Param = Vec<F>, Float = F>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Simplex";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: LinearProgramState<Vec<F>, F>,
+    ) -> Result<(LinearProgramState<Vec<F>, F>, Option<KV>), Error> {
+        let c = problem.c()?;
+        let mut b = problem.b()?;
+        let mut a = problem.A()?;
+        if a.len() != b.len() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Simplex`: number of rows of `A` must match the length of `b`."
+            ));
+        }
+        if a.iter().any(|row| row.len() != c.len()) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`Simplex`: number of columns of `A` must match the length of `c`."
+            ));
+        }
+        // Artificial variables require a non-negative right-hand side
+        for (row, b) in a.iter_mut().zip(b.iter_mut()) {
+            if *b < float!(0.0) {
+                *b = -*b;
+                for v in row.iter_mut() {
+                    *v = -*v;
+                }
+            }
+        }
+        let (n, m) = (c.len(), b.len());
+        self.basis = (n..n + m).collect();
+        self.binv = (0..m)
+            .map(|i| {
+                (0..m)
+                    .map(|k| if i == k { float!(1.0) } else { float!(0.0) })
+                    .collect()
+            })
+            .collect();
+        self.x_b = b.clone();
+        self.a = a;
+        self.b = b;
+        self.c = c;
+        self.phase = Phase::One;
+        Ok((state.param(self.param()), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        _problem: &mut Problem<O>,
+        state: LinearProgramState<Vec<F>, F>,
+    ) -> Result<(LinearProgramState<Vec<F>, F>, Option<KV>), Error> {
+        let kv = kv!("phase" => if self.phase == Phase::One { 1u64 } else { 2u64 };);
+
+        let j = match self.entering() {
+            Some(j) => j,
+            None if self.phase == Phase::One => {
+                let infeasibility = self
+                    .basis
+                    .iter()
+                    .zip(self.x_b.iter())
+                    .filter(|(j, _)| **j >= self.c.len())
+                    .fold(float!(0.0), |acc, (_, x)| acc + *x);
+                if infeasibility > self.tol {
+                    return Ok((
+                        state.terminate_with(TerminationReason::SolverExit(
+                            "Linear program is infeasible".to_string(),
+                        )),
+                        Some(kv),
+                    ));
+                }
+                self.remove_artificials();
+                self.phase = Phase::Two;
+                let param = self.param();
+                let cost = self.objective(&param);
+                return Ok((state.param(param).cost(cost), Some(kv)));
+            }
+            None => {
+                self.phase = Phase::Done;
+                return Ok((state, Some(kv)));
+            }
+        };
+
+        let d = self.solve(&self.column(j));
+        // Ratio test; ties are broken by the smallest index of the leaving variable
+        let leaving = d
+            .iter()
+            .zip(self.x_b.iter())
+            .enumerate()
+            .filter(|(_, (d, _))| **d > self.tol)
+            .map(|(i, (d, x))| (i, *x / *d))
+            .fold(None, |acc: Option<(usize, F)>, (i, t)| match acc {
+                Some((k, s)) if s < t || (s == t && self.basis[k] < self.basis[i]) => acc,
+                _ => Some((i, t)),
+            });
+        let r = match leaving {
+            Some((r, _)) => r,
+            None => {
+                return Ok((
+                    state.terminate_with(TerminationReason::SolverExit(
+                        "Linear program is unbounded".to_string(),
+                    )),
+                    Some(kv),
+                ))
+            }
+        };
+        self.pivot(r, j, &d);
+
+        let param = self.param();
+        let state = match self.phase {
+            Phase::One => state.param(param),
+            _ => {
+                let cost = self.objective(&param);
+                state.param(param).cost(cost)
+            }
+        };
+        Ok((state, Some(kv)))
+    }
+
+    fn terminate(&mut self, _state: &LinearProgramState<Vec<F>, F>) -> TerminationStatus {
+        if self.phase == Phase::Done {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Executor, OptimizationResult};
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(simplex, Simplex<f64>);
+
+    struct Lp {
+        c: Vec<f64>,
+        b: Vec<f64>,
+        a: Vec<Vec<f64>>,
+    }
+
+    impl LinearProgram for Lp {
+        type Param = Vec<f64>;
+        type Float = f64;
+
+        fn c(&self) -> Result<Vec<f64>, Error> {
+            Ok(self.c.clone())
+        }
+
+        fn b(&self) -> Result<Vec<f64>, Error> {
+            Ok(self.b.clone())
+        }
+
+        fn A(&self) -> Result<Vec<Vec<f64>>, Error> {
+            Ok(self.a.clone())
+        }
+    }
+
+    fn run(
+        c: Vec<f64>,
+        b: Vec<f64>,
+        a: Vec<Vec<f64>>,
+    ) -> OptimizationResult<Lp, Simplex<f64>, LinearProgramState<Vec<f64>, f64>> {
+        Executor::new(Lp { c, b, a }, Simplex::new())
+            .configure(|state| state.max_iters(100))
+            .run()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        let Simplex {
+            tol,
+            a,
+            b,
+            c,
+            basis,
+            binv,
+            x_b,
+            phase,
+        } = Simplex::<f64>::new();
+        assert_eq!(tol.to_ne_bytes(), f64::EPSILON.sqrt().to_ne_bytes());
+        assert!(a.is_empty());
+        assert!(b.is_empty());
+        assert!(c.is_empty());
+        assert!(basis.is_empty());
+        assert!(binv.is_empty());
+        assert!(x_b.is_empty());
+        assert_eq!(phase, Phase::One);
+    }
+
+    #[test]
+    fn test_with_tolerance() {
+        let simplex = Simplex::new().with_tolerance(1e-10f64).unwrap();
+        assert_eq!(simplex.tol.to_ne_bytes(), 1e-10f64.to_ne_bytes());
+
+        for tol in [0.0, -1.0] {
+            let res = Simplex::new().with_tolerance(tol);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`Simplex`: tolerance must be > 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_dimensions() {
+        let res = Executor::new(
+            Lp {
+                c: vec![1.0, 1.0],
+                b: vec![1.0],
+                a: vec![vec![1.0, 1.0], vec![1.0, 1.0]],
+            },
+            Simplex::new(),
+        )
+        .run();
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`Simplex`: number of rows of `A` must match the length of `b`.\""
+        );
+
+        let res = Executor::new(
+            Lp {
+                c: vec![1.0, 1.0],
+                b: vec![1.0],
+                a: vec![vec![1.0]],
+            },
+            Simplex::new(),
+        )
+        .run();
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Invalid parameter: \"`Simplex`: number of columns of `A` must match the length ",
+                "of `c`.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_equality_constraints() {
+        // min x + 2 y + 3 z s.t. x + y + z = 1, x - y = -0.5
+        let res = run(
+            vec![1.0, 2.0, 3.0],
+            vec![1.0, -0.5],
+            vec![vec![1.0, 1.0, 1.0], vec![1.0, -1.0, 0.0]],
+        );
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let x = res.state.get_best_param().unwrap();
+        assert_relative_eq!(x[0], 0.25, epsilon = 1e-12);
+        assert_relative_eq!(x[1], 0.75, epsilon = 1e-12);
+        assert_relative_eq!(x[2], 0.0, epsilon = 1e-12);
+        assert_relative_eq!(res.state.get_best_cost(), 1.75, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_redundant_constraints() {
+        let res = run(
+            vec![1.0, 2.0],
+            vec![1.0, 2.0],
+            vec![vec![1.0, 1.0], vec![2.0, 2.0]],
+        );
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        assert_eq!(res.state.get_best_param().unwrap(), &vec![1.0, 0.0]);
+        assert_eq!(
+            res.state.get_best_cost().to_ne_bytes(),
+            1.0f64.to_ne_bytes()
+        );
+    }
+
+    #[test]
+    fn test_degenerate() {
+        // Beale's example, on which the simplex method cycles with Dantzig's rule
+        let res = run(
+            vec![0.0, 0.0, 0.0, -0.75, 20.0, -0.5, 6.0],
+            vec![0.0, 0.0, 1.0],
+            vec![
+                vec![1.0, 0.0, 0.0, 0.25, -8.0, -1.0, 9.0],
+                vec![0.0, 1.0, 0.0, 0.5, -12.0, -0.5, 3.0],
+                vec![0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0],
+            ],
+        );
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        assert_relative_eq!(res.state.get_best_cost(), -1.25, epsilon = 1e-12);
+        let x = res.state.get_best_param().unwrap();
+        assert_relative_eq!(x[0], 0.75, epsilon = 1e-12);
+        assert_relative_eq!(x[3], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(x[5], 1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_infeasible() {
+        // x + y = 1 and x + y = 2
+        let res = run(
+            vec![1.0, 1.0],
+            vec![1.0, 2.0],
+            vec![vec![1.0, 1.0], vec![1.0, 1.0]],
+        );
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverExit(
+                "Linear program is infeasible".to_string()
+            ))
+        );
+        assert!(res.state.get_best_cost().is_infinite());
+    }
+
+    #[test]
+    fn test_unbounded() {
+        // min -x s.t. x - y = 1
+        let res = run(vec![-1.0, 0.0], vec![1.0], vec![vec![1.0, -1.0]]);
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverExit(
+                "Linear program is unbounded".to_string()
+            ))
+        );
+    }
+}
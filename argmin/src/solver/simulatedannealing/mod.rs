@@ -10,6 +10,9 @@
 //! Simulated Annealing (SA) is a stochastic optimization method which imitates annealing in
 //! metallurgy. For details see [`SimulatedAnnealing`].
 //!
+//! [`ParallelTempering`] runs several replicas at different temperatures and exchanges their
+//! states, which improves the exploration of multimodal landscapes.
+//!
 //! ## References
 //!
 //! [Wikipedia](https://en.wikipedia.org/wiki/Simulated_annealing)
@@ -18,6 +21,12 @@
 //! Science 13 May 1983, Vol. 220, Issue 4598, pp. 671-680
 //! DOI: 10.1126/science.220.4598.671
 
+mod paralleltempering;
+mod schedule;
+
+pub use paralleltempering::ParallelTempering;
+pub use schedule::{AdaptiveCooling, SATempFunc, TemperatureSchedule};
+
 use crate::core::{
     ArgminFloat, CostFunction, Error, IterState, Problem, SerializeAlias, Solver,
     TerminationReason, TerminationStatus, KV,
@@ -79,29 +88,6 @@ impl<O: Anneal> Problem<O> {
     }
 }
 
-/// Temperature functions for Simulated Annealing.
-///
-/// Given the initial temperature `t_init` and the iteration number `i`, the current temperature
-/// `t_i` is given as follows:
-///
-/// * `SATempFunc::TemperatureFast`: `t_i = t_init / i`
-/// * `SATempFunc::Boltzmann`: `t_i = t_init / ln(i)`
-/// * `SATempFunc::Exponential`: `t_i = t_init * 0.95^i`
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
-#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
-pub enum SATempFunc<F> {
-    /// `t_i = t_init / i`
-    TemperatureFast,
-    /// `t_i = t_init / ln(i)`
-    #[default]
-    Boltzmann,
-    /// `t_i = t_init * x^i`
-    Exponential(F),
-    // /// User-provided temperature function. The first parameter must be the current temperature and
-    // /// the second parameter must be the iteration number.
-    // Custom(Box<dyn Fn(f64, u64) -> f64 + 'static>),
-}
-
 /// # Simulated Annealing
 ///
 /// Simulated Annealing (SA) is a stochastic optimization method which imitates annealing in
@@ -119,8 +105,10 @@ pub enum SATempFunc<F> {
 /// vector (via [`configure`](`crate::core::Executor::configure`) of
 /// [`Executor`](`crate::core::Executor`).
 ///
-/// The cooling schedule can be set with [`SimulatedAnnealing::with_temp_func`]. For the available
-/// choices please see [`SATempFunc`].
+/// The cooling schedule can be set with [`SimulatedAnnealing::with_temp_func`]. Any
+/// implementation of the [`TemperatureSchedule`] trait can be used. Available choices are the
+/// fixed temperature functions of [`SATempFunc`] and the [`AdaptiveCooling`] schedule, which
+/// reheats when the search stagnates. The temperature is logged with the key `t`.
 ///
 /// Reannealing can be performed if no new best solution was found for `N` iterations
 /// ([`SimulatedAnnealing::with_reannealing_best`]), or if no new accepted solution was found for
@@ -144,11 +132,11 @@ pub enum SATempFunc<F> {
 /// DOI: 10.1126/science.220.4598.671
 #[derive(Clone)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
-pub struct SimulatedAnnealing<F, R> {
+pub struct SimulatedAnnealing<F, R, T = SATempFunc<F>> {
     /// Initial temperature
     init_temp: F,
-    /// Temperature function used for decreasing the temperature
-    temp_func: SATempFunc<F>,
+    /// Temperature schedule used for decreasing the temperature
+    temp_func: T,
     /// Number of iterations used for the calculation of temperature. Needed for reannealing
     temp_iter: u64,
     /// Number of iterations since the last accepted solution
@@ -250,27 +238,54 @@ where
             })
         }
     }
+}
 
+impl<F, R, T> SimulatedAnnealing<F, R, T>
+where
+    F: ArgminFloat,
+{
     /// Set temperature function
     ///
     /// The temperature function defines how the temperature is decreased over the course of the
-    /// iterations.
-    /// See [`SATempFunc`] for the available options. Defaults to [`SATempFunc::TemperatureFast`].
+    /// iterations. Accepts any implementation of [`TemperatureSchedule`], see [`SATempFunc`] and
+    /// [`AdaptiveCooling`] for the available options. Defaults to [`SATempFunc::TemperatureFast`].
     ///
     /// # Example
     ///
     /// ```
-    /// # use argmin::solver::simulatedannealing::{SimulatedAnnealing, SATempFunc};
+    /// # use argmin::solver::simulatedannealing::{
+    /// #     AdaptiveCooling, SimulatedAnnealing, SATempFunc,
+    /// # };
     /// # use argmin::core::Error;
     /// # fn main() -> Result<(), Error> {
     /// let sa = SimulatedAnnealing::new(100.0f64)?.with_temp_func(SATempFunc::Boltzmann);
+    /// let sa = SimulatedAnnealing::new(100.0f64)?
+    ///     .with_temp_func(AdaptiveCooling::new(0.95, 2.0, 100)?);
     /// # Ok(())
     /// # }
     /// ```
     #[must_use]
-    pub fn with_temp_func(mut self, temperature_func: SATempFunc<F>) -> Self {
-        self.temp_func = temperature_func;
-        self
+    pub fn with_temp_func<T2>(self, temperature_func: T2) -> SimulatedAnnealing<F, R, T2>
+    where
+        T2: TemperatureSchedule<F>,
+    {
+        SimulatedAnnealing {
+            init_temp: self.init_temp,
+            temp_func: temperature_func,
+            temp_iter: self.temp_iter,
+            stall_iter_accepted: self.stall_iter_accepted,
+            stall_iter_accepted_limit: self.stall_iter_accepted_limit,
+            stall_iter_best: self.stall_iter_best,
+            stall_iter_best_limit: self.stall_iter_best_limit,
+            reanneal_fixed: self.reanneal_fixed,
+            reanneal_iter_fixed: self.reanneal_iter_fixed,
+            reanneal_accepted: self.reanneal_accepted,
+            reanneal_iter_accepted: self.reanneal_iter_accepted,
+            reanneal_best: self.reanneal_best,
+            reanneal_iter_best: self.reanneal_iter_best,
+            cur_temp: self.cur_temp,
+            rng: self.rng,
+        }
     }
 
     /// If there are no accepted solutions for `iter` iterations, the algorithm stops.
@@ -386,21 +401,25 @@ where
 
     /// Update the temperature based on the current iteration number.
     ///
-    /// Updates are performed based on specific update functions. See `SATempFunc` for details.
-    fn update_temperature(&mut self) {
-        self.cur_temp = match self.temp_func {
-            SATempFunc::TemperatureFast => {
-                self.init_temp / F::from_u64(self.temp_iter + 1).unwrap()
-            }
-            SATempFunc::Boltzmann => self.init_temp / F::from_u64(self.temp_iter + 1).unwrap().ln(),
-            SATempFunc::Exponential(x) => {
-                self.init_temp * x.powf(F::from_u64(self.temp_iter + 1).unwrap())
-            }
-        };
+    /// Updates are performed by the temperature schedule. See [`TemperatureSchedule`] for details.
+    fn update_temperature(&mut self, accepted: bool, new_best: bool)
+    where
+        T: TemperatureSchedule<F>,
+    {
+        self.cur_temp = self.temp_func.temperature(
+            self.init_temp,
+            self.cur_temp,
+            self.temp_iter,
+            accepted,
+            new_best,
+        );
     }
 
     /// Perform reannealing
-    fn reanneal(&mut self) -> (bool, bool, bool) {
+    fn reanneal(&mut self) -> (bool, bool, bool)
+    where
+        T: TemperatureSchedule<F>,
+    {
         let out = (
             self.reanneal_iter_fixed >= self.reanneal_fixed,
             self.reanneal_iter_accepted >= self.reanneal_accepted,
@@ -412,6 +431,7 @@ where
             self.reanneal_iter_best = 0;
             self.cur_temp = self.init_temp;
             self.temp_iter = 0;
+            self.temp_func.reset();
         }
         out
     }
@@ -435,12 +455,13 @@ where
     }
 }
 
-impl<O, P, F, R> Solver<O, IterState<P, (), (), (), F>> for SimulatedAnnealing<F, R>
+impl<O, P, F, R, T> Solver<O, IterState<P, (), (), (), F>> for SimulatedAnnealing<F, R, T>
 where
     O: CostFunction<Param = P, Output = F> + Anneal<Param = P, Output = P, Float = F>,
     P: Clone,
     F: ArgminFloat,
     R: Rng + SerializeAlias,
+    T: TemperatureSchedule<F>,
 {
     const NAME: &'static str = "Simulated Annealing";
     fn init(
@@ -528,7 +549,7 @@ where
         // better readability.
         self.reanneal_iter_fixed += 1;
 
-        self.update_temperature();
+        self.update_temperature(accepted, new_best_found);
 
         Ok((
             if accepted {
@@ -687,6 +708,14 @@ mod tests {
 
             assert_eq!(sa.temp_func, func);
         }
+
+        let schedule = AdaptiveCooling::new(0.9f64, 2.0, 10).unwrap();
+        let sa = SimulatedAnnealing::new(100.0f64)
+            .unwrap()
+            .with_stall_best(20)
+            .with_temp_func(schedule);
+        assert_eq!(sa.temp_func, schedule);
+        assert_eq!(sa.stall_iter_best_limit, 20);
     }
 
     #[test]
@@ -751,7 +780,7 @@ mod tests {
                 .with_temp_func(func);
             sa.temp_iter = 1;
 
-            sa.update_temperature();
+            sa.update_temperature(true, false);
 
             assert_relative_eq!(sa.cur_temp, val, epsilon = f64::EPSILON);
         }
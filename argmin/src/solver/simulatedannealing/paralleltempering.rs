@@ -0,0 +1,461 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::Anneal;
+use crate::core::{
+    ArgminFloat, CostFunction, Error, IterState, Problem, SerializeAlias, Solver, State, KV,
+};
+use rand::prelude::*;
+use rand_xoshiro::Xoshiro256PlusPlus;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Parallel tempering
+///
+/// Parallel tempering (also known as replica exchange) runs several annealing chains (replicas)
+/// at different, fixed temperatures. In each iteration, every replica performs one move: a new
+/// parameter vector is created via [`Anneal`], where the temperature of the replica is passed as
+/// the `extent`, and it is accepted with the Metropolis criterion
+///
+/// `min(1, exp(-(new_cost - cost) / temperature))`.
+///
+/// Every `swap_interval` iterations, exchanges of the states of replicas with adjacent
+/// temperatures `T_i < T_{i+1}` are proposed and accepted with probability
+///
+/// `min(1, exp((cost_i - cost_{i+1}) * (1/T_i - 1/T_{i+1})))`.
+///
+/// Hot replicas explore the parameter space broadly and can leave local minima, while cold
+/// replicas refine the solutions handed down to them. This typically explores multimodal
+/// landscapes much better than a single annealing chain.
+///
+/// All replicas start from the initial parameter vector, which needs to be provided via the
+/// `configure` method of the [`Executor`](`crate::core::Executor`). The parameter vector in the
+/// state is the one of the coldest replica. The algorithm has no convergence criterion on its own
+/// and stops when the maximum number of iterations or the target cost is reached.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`CostFunction`] and [`Anneal`].
+///
+/// ## References
+///
+/// Robert H. Swendsen and Jian-Sheng Wang (1986). Replica Monte Carlo simulation of spin-glasses.
+/// Physical Review Letters 57, 2607-2609.
+///
+/// David J. Earl and Michael W. Deem (2005). Parallel tempering: Theory, applications, and new
+/// perspectives. Physical Chemistry Chemical Physics 7, 3910-3916.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct ParallelTempering<P, F, R> {
+    /// Temperatures of the replicas in ascending order
+    temperatures: Vec<F>,
+    /// Number of iterations between swap attempts
+    swap_interval: u64,
+    /// Current parameter vectors of the replicas
+    replicas: Vec<P>,
+    /// Current cost function values of the replicas
+    costs: Vec<F>,
+    /// Number of proposed swaps
+    swaps_proposed: u64,
+    /// Number of accepted swaps
+    swaps_accepted: u64,
+    /// Random number generator
+    rng: R,
+}
+
+impl<P, F> ParallelTempering<P, F, Xoshiro256PlusPlus>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`ParallelTempering`]
+    ///
+    /// Takes the temperatures of the replicas, which must be > 0 and in ascending order. At least
+    /// one temperature is required.
+    ///
+    /// Uses the `Xoshiro256PlusPlus` RNG internally. For use of another RNG, consider using
+    /// [`ParallelTempering::new_with_rng`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::simulatedannealing::ParallelTempering;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let pt: ParallelTempering<Vec<f64>, f64, _> = ParallelTempering::new(vec![0.1, 1.0, 10.0])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(temperatures: Vec<F>) -> Result<Self, Error> {
+        ParallelTempering::new_with_rng(temperatures, Xoshiro256PlusPlus::from_entropy())
+    }
+
+    /// Construct a new instance of [`ParallelTempering`] with a geometric temperature ladder
+    ///
+    /// Creates `num_replicas` temperatures which are spaced geometrically between `t_min` and
+    /// `t_max` (both > 0 and `t_min < t_max`). At least two replicas are required.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::simulatedannealing::ParallelTempering;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let pt: ParallelTempering<Vec<f64>, f64, _> = ParallelTempering::geometric(0.1, 10.0, 8)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn geometric(t_min: F, t_max: F, num_replicas: usize) -> Result<Self, Error> {
+        if num_replicas < 2 || t_min <= float!(0.0) || t_min >= t_max {
+            return Err(argmin_error!(
+                InvalidParameter,
+                concat!(
+                    "`ParallelTempering`: Geometric temperature ladder requires ",
+                    "0 < t_min < t_max and at least two replicas."
+                )
+            ));
+        }
+        let ratio = (t_max / t_min).powf(float!(1.0) / F::from_usize(num_replicas - 1).unwrap());
+        let temperatures = (0..num_replicas)
+            .map(|i| t_min * ratio.powi(i as i32))
+            .collect();
+        ParallelTempering::new(temperatures)
+    }
+}
+
+impl<P, F, R> ParallelTempering<P, F, R>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`ParallelTempering`]
+    ///
+    /// Takes the temperatures of the replicas, which must be > 0 and in ascending order, and a RNG
+    /// which must implement `rand::Rng` (and `serde::Serialize` if the `serde1` feature is
+    /// enabled).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::simulatedannealing::ParallelTempering;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let my_rng = ();
+    /// let pt: ParallelTempering<Vec<f64>, f64, _> =
+    ///     ParallelTempering::new_with_rng(vec![0.1, 1.0, 10.0], my_rng)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_with_rng(temperatures: Vec<F>, rng: R) -> Result<Self, Error> {
+        if temperatures.is_empty() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ParallelTempering`: At least one temperature is required."
+            ));
+        }
+        if temperatures.iter().any(|&t| t <= float!(0.0))
+            || temperatures.windows(2).any(|w| w[0] >= w[1])
+        {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ParallelTempering`: Temperatures must be > 0 and in ascending order."
+            ));
+        }
+        Ok(ParallelTempering {
+            temperatures,
+            swap_interval: 1,
+            replicas: vec![],
+            costs: vec![],
+            swaps_proposed: 0,
+            swaps_accepted: 0,
+            rng,
+        })
+    }
+
+    /// Set the number of iterations between swap attempts
+    ///
+    /// Must be larger than 0 and defaults to `1` (swaps are proposed in every iteration).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::simulatedannealing::ParallelTempering;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let pt: ParallelTempering<Vec<f64>, f64, _> =
+    ///     ParallelTempering::new(vec![0.1, 1.0, 10.0])?.with_swap_interval(10)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_swap_interval(mut self, swap_interval: u64) -> Result<Self, Error> {
+        if swap_interval == 0 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`ParallelTempering`: Swap interval must be > 0."
+            ));
+        }
+        self.swap_interval = swap_interval;
+        Ok(self)
+    }
+}
+
+impl<O, P, F, R> Solver<O, IterState<P, (), (), (), F>> for ParallelTempering<P, F, R>
+where
+    O: CostFunction<Param = P, Output = F> + Anneal<Param = P, Output = P, Float = F>,
+    P: Clone + SerializeAlias,
+    F: ArgminFloat,
+    R: Rng + SerializeAlias,
+{
+    const NAME: &'static str = "Parallel Tempering";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        let param = state.take_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`ParallelTempering` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+
+        let cost = state.get_cost();
+        let cost = if cost.is_infinite() {
+            problem.cost(&param)?
+        } else {
+            cost
+        };
+
+        self.replicas = vec![param.clone(); self.temperatures.len()];
+        self.costs = vec![cost; self.temperatures.len()];
+        self.swaps_proposed = 0;
+        self.swaps_accepted = 0;
+
+        Ok((
+            state.param(param).cost(cost),
+            Some(kv!("num_replicas" => self.temperatures.len() as u64;)),
+        ))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        // Metropolis step of each replica at its own temperature
+        for i in 0..self.temperatures.len() {
+            let temp = self.temperatures[i];
+            let new_param = problem.anneal(&self.replicas[i], temp)?;
+            let new_cost = problem.cost(&new_param)?;
+            let prob: f64 = self.rng.gen();
+            if new_cost <= self.costs[i]
+                || (-(new_cost - self.costs[i]) / temp).exp() > float!(prob)
+            {
+                self.replicas[i] = new_param;
+                self.costs[i] = new_cost;
+            }
+        }
+
+        // Propose exchanges between replicas with adjacent temperatures
+        if (state.get_iter() + 1).is_multiple_of(self.swap_interval) {
+            for i in 0..self.temperatures.len().saturating_sub(1) {
+                let exponent = (self.costs[i] - self.costs[i + 1])
+                    * (float!(1.0) / self.temperatures[i] - float!(1.0) / self.temperatures[i + 1]);
+                let prob: f64 = self.rng.gen();
+                self.swaps_proposed += 1;
+                if exponent >= float!(0.0) || exponent.exp() > float!(prob) {
+                    self.replicas.swap(i, i + 1);
+                    self.costs.swap(i, i + 1);
+                    self.swaps_accepted += 1;
+                }
+            }
+        }
+
+        let swap_rate = if self.swaps_proposed > 0 {
+            self.swaps_accepted as f64 / self.swaps_proposed as f64
+        } else {
+            0.0
+        };
+
+        Ok((
+            state.param(self.replicas[0].clone()).cost(self.costs[0]),
+            Some(kv!("swap_acceptance_rate" => swap_rate;)),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{test_utils::TestProblem, ArgminError, Executor, State};
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+    use std::sync::Mutex;
+
+    test_trait_impl!(
+        parallel_tempering,
+        ParallelTempering<Vec<f64>, f64, Xoshiro256PlusPlus>
+    );
+
+    /// Tilted double well `f(x) = (x^2 - 1)^2 + 0.3 x` with a local minimum close to `x = 1` and
+    /// the global minimum close to `x = -1`
+    struct DoubleWell {
+        rng: Mutex<Xoshiro256PlusPlus>,
+    }
+
+    impl CostFunction for DoubleWell {
+        type Param = Vec<f64>;
+        type Output = f64;
+
+        fn cost(&self, p: &Self::Param) -> Result<Self::Output, Error> {
+            Ok((p[0].powi(2) - 1.0).powi(2) + 0.3 * p[0])
+        }
+    }
+
+    impl Anneal for DoubleWell {
+        type Param = Vec<f64>;
+        type Output = Vec<f64>;
+        type Float = f64;
+
+        fn anneal(&self, param: &Self::Param, extent: Self::Float) -> Result<Self::Output, Error> {
+            let step: f64 = self.rng.lock().unwrap().gen_range(-1.0..1.0);
+            Ok(vec![param[0] + 0.5 * extent.sqrt() * step])
+        }
+    }
+
+    #[test]
+    fn test_new() {
+        let pt: ParallelTempering<Vec<f64>, f64, _> =
+            ParallelTempering::new(vec![0.1, 1.0, 10.0]).unwrap();
+        let ParallelTempering {
+            temperatures,
+            swap_interval,
+            replicas,
+            costs,
+            swaps_proposed,
+            swaps_accepted,
+            rng: _,
+        } = pt;
+        assert_eq!(temperatures, vec![0.1, 1.0, 10.0]);
+        assert_eq!(swap_interval, 1);
+        assert!(replicas.is_empty());
+        assert!(costs.is_empty());
+        assert_eq!(swaps_proposed, 0);
+        assert_eq!(swaps_accepted, 0);
+
+        let res: Result<ParallelTempering<Vec<f64>, f64, _>, _> = ParallelTempering::new(vec![]);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`ParallelTempering`: At least one temperature is required.\""
+        );
+
+        for temperatures in [vec![0.0, 1.0], vec![1.0, 0.1], vec![1.0, 1.0]] {
+            let res: Result<ParallelTempering<Vec<f64>, f64, _>, _> =
+                ParallelTempering::new(temperatures);
+            assert_error!(
+                res,
+                ArgminError,
+                concat!(
+                    "Invalid parameter: \"`ParallelTempering`: ",
+                    "Temperatures must be > 0 and in ascending order.\""
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_geometric() {
+        let pt: ParallelTempering<Vec<f64>, f64, _> =
+            ParallelTempering::geometric(0.1, 10.0, 3).unwrap();
+        assert_eq!(pt.temperatures.len(), 3);
+        assert_relative_eq!(pt.temperatures[0], 0.1, epsilon = 1e-12);
+        assert_relative_eq!(pt.temperatures[1], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(pt.temperatures[2], 10.0, epsilon = 1e-12);
+
+        for (t_min, t_max, n) in [(0.1, 10.0, 1), (0.0, 10.0, 3), (10.0, 0.1, 3)] {
+            let res: Result<ParallelTempering<Vec<f64>, f64, _>, _> =
+                ParallelTempering::geometric(t_min, t_max, n);
+            assert_error!(
+                res,
+                ArgminError,
+                concat!(
+                    "Invalid parameter: \"`ParallelTempering`: Geometric temperature ladder ",
+                    "requires 0 < t_min < t_max and at least two replicas.\""
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_with_swap_interval() {
+        let pt: ParallelTempering<Vec<f64>, f64, _> =
+            ParallelTempering::new(vec![0.1, 1.0]).unwrap();
+        let pt = pt.with_swap_interval(10).unwrap();
+        assert_eq!(pt.swap_interval, 10);
+
+        let pt: ParallelTempering<Vec<f64>, f64, _> =
+            ParallelTempering::new(vec![0.1, 1.0]).unwrap();
+        let res = pt.with_swap_interval(0);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`ParallelTempering`: Swap interval must be > 0.\""
+        );
+    }
+
+    #[test]
+    fn test_init_param_not_initialized() {
+        let mut pt: ParallelTempering<Vec<f64>, f64, _> =
+            ParallelTempering::new(vec![0.1, 1.0]).unwrap();
+        let res = pt.init(&mut Problem::new(TestProblem::new()), IterState::new());
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`ParallelTempering` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_init() {
+        let mut pt: ParallelTempering<Vec<f64>, f64, _> =
+            ParallelTempering::new(vec![0.1, 1.0, 10.0]).unwrap();
+        let mut problem = Problem::new(DoubleWell {
+            rng: Mutex::new(Xoshiro256PlusPlus::seed_from_u64(0)),
+        });
+        let (state, _) = pt
+            .init(&mut problem, IterState::new().param(vec![1.0]))
+            .unwrap();
+        assert_eq!(pt.replicas, vec![vec![1.0]; 3]);
+        assert_eq!(pt.costs, vec![0.3; 3]);
+        assert_eq!(state.get_cost().to_ne_bytes(), 0.3f64.to_ne_bytes());
+        assert_eq!(problem.counts["cost_count"], 1);
+    }
+
+    #[test]
+    fn test_solver() {
+        let problem = DoubleWell {
+            rng: Mutex::new(Xoshiro256PlusPlus::seed_from_u64(42)),
+        };
+        let pt = ParallelTempering::new_with_rng(
+            vec![0.005, 0.05, 0.5],
+            Xoshiro256PlusPlus::seed_from_u64(42),
+        )
+        .unwrap();
+        let res = Executor::new(problem, pt)
+            .configure(|state| state.param(vec![1.0]).max_iters(2000))
+            .run()
+            .unwrap();
+        assert_eq!(res.problem.counts["anneal_count"], 3 * 2000);
+        // The coldest replica starts in the basin of the local minimum and needs to be handed
+        // the global one by the hotter replicas.
+        assert!(res.state.get_best_param().unwrap()[0] < -0.9);
+    }
+}
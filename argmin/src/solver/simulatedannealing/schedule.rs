@@ -0,0 +1,257 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{ArgminFloat, Error};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// Cooling schedule of [`SimulatedAnnealing`](`super::SimulatedAnnealing`)
+///
+/// After each iteration, [`temperature`](`TemperatureSchedule::temperature`) is called to compute
+/// the temperature of the next iteration. Schedules may keep internal state (for instance to
+/// detect stagnation), which is reset via [`reset`](`TemperatureSchedule::reset`) whenever
+/// reannealing is performed.
+///
+/// Available schedules are [`SATempFunc`] (fixed functions of the iteration number, including
+/// exponential and logarithmic cooling) and [`AdaptiveCooling`] (exponential cooling with
+/// reheating on stagnation).
+///
+/// # Example
+///
+/// ```
+/// use argmin::solver::simulatedannealing::TemperatureSchedule;
+///
+/// /// Linear decrease of the temperature down to a minimum temperature
+/// struct Linear {
+///     step: f64,
+///     min_temp: f64,
+/// }
+///
+/// impl TemperatureSchedule<f64> for Linear {
+///     fn temperature(
+///         &mut self,
+///         _init_temp: f64,
+///         cur_temp: f64,
+///         _iter: u64,
+///         _accepted: bool,
+///         _new_best: bool,
+///     ) -> f64 {
+///         (cur_temp - self.step).max(self.min_temp)
+///     }
+/// }
+/// ```
+pub trait TemperatureSchedule<F> {
+    /// Compute the temperature of the next iteration
+    ///
+    /// Takes the initial temperature, the current temperature, the number of iterations since
+    /// the start or the last reannealing (at least `1`), and whether the last candidate was
+    /// accepted and whether it was a new best solution.
+    fn temperature(
+        &mut self,
+        init_temp: F,
+        cur_temp: F,
+        iter: u64,
+        accepted: bool,
+        new_best: bool,
+    ) -> F;
+
+    /// Reset the internal state of the schedule. Called when reannealing is performed.
+    fn reset(&mut self) {}
+}
+
+/// Temperature functions for Simulated Annealing.
+///
+/// Given the initial temperature `t_init` and the iteration number `i`, the current temperature
+/// `t_i` is given as follows:
+///
+/// * `SATempFunc::TemperatureFast`: `t_i = t_init / i`
+/// * `SATempFunc::Boltzmann`: `t_i = t_init / ln(i)`
+/// * `SATempFunc::Exponential`: `t_i = t_init * 0.95^i`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub enum SATempFunc<F> {
+    /// `t_i = t_init / i`
+    TemperatureFast,
+    /// `t_i = t_init / ln(i)`
+    #[default]
+    Boltzmann,
+    /// `t_i = t_init * x^i`
+    Exponential(F),
+    // /// User-provided temperature function. The first parameter must be the current temperature and
+    // /// the second parameter must be the iteration number.
+    // Custom(Box<dyn Fn(f64, u64) -> f64 + 'static>),
+}
+
+impl<F> TemperatureSchedule<F> for SATempFunc<F>
+where
+    F: ArgminFloat,
+{
+    fn temperature(&mut self, init_temp: F, _cur_temp: F, iter: u64, _: bool, _: bool) -> F {
+        match *self {
+            SATempFunc::TemperatureFast => init_temp / F::from_u64(iter + 1).unwrap(),
+            SATempFunc::Boltzmann => init_temp / F::from_u64(iter + 1).unwrap().ln(),
+            SATempFunc::Exponential(x) => init_temp * x.powf(F::from_u64(iter + 1).unwrap()),
+        }
+    }
+}
+
+/// Adaptive cooling schedule with reheating on stagnation
+///
+/// The temperature is decreased by the cooling factor `alpha` in each iteration
+/// (`t_{i+1} = alpha * t_i`). If no new best solution was found for `patience` iterations, the
+/// temperature is instead increased by the reheating factor `beta` (`t_{i+1} = beta * t_i`), but
+/// never beyond the initial temperature. This allows the search to escape from local minima
+/// without restarting from the initial temperature, as reannealing would.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct AdaptiveCooling<F> {
+    /// Cooling factor
+    alpha: F,
+    /// Reheating factor
+    beta: F,
+    /// Number of iterations without new best solution after which reheating is performed
+    patience: u64,
+    /// Number of iterations since the last new best solution or reheating
+    stall_iter: u64,
+}
+
+impl<F> AdaptiveCooling<F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`AdaptiveCooling`]
+    ///
+    /// Takes the cooling factor `alpha`, which must be in `(0, 1)`, the reheating factor `beta`,
+    /// which must be larger than 1, and the number of iterations without a new best solution
+    /// after which reheating is performed (`patience`), which must be larger than 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::simulatedannealing::AdaptiveCooling;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let schedule = AdaptiveCooling::new(0.95f64, 2.0, 100)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(alpha: F, beta: F, patience: u64) -> Result<Self, Error> {
+        if alpha <= float!(0.0) || alpha >= float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`AdaptiveCooling`: cooling factor must be in (0, 1)."
+            ));
+        }
+        if beta <= float!(1.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`AdaptiveCooling`: reheating factor must be > 1."
+            ));
+        }
+        if patience == 0 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`AdaptiveCooling`: patience must be > 0."
+            ));
+        }
+        Ok(AdaptiveCooling {
+            alpha,
+            beta,
+            patience,
+            stall_iter: 0,
+        })
+    }
+}
+
+impl<F> TemperatureSchedule<F> for AdaptiveCooling<F>
+where
+    F: ArgminFloat,
+{
+    fn temperature(&mut self, init_temp: F, cur_temp: F, _iter: u64, _: bool, new_best: bool) -> F {
+        self.stall_iter = if new_best { 0 } else { self.stall_iter + 1 };
+        if self.stall_iter >= self.patience {
+            self.stall_iter = 0;
+            (cur_temp * self.beta).min(init_temp)
+        } else {
+            cur_temp * self.alpha
+        }
+    }
+
+    fn reset(&mut self) {
+        self.stall_iter = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_adaptive_cooling_new() {
+        let AdaptiveCooling {
+            alpha,
+            beta,
+            patience,
+            stall_iter,
+        } = AdaptiveCooling::new(0.9f64, 2.0, 10).unwrap();
+        assert_eq!(alpha.to_ne_bytes(), 0.9f64.to_ne_bytes());
+        assert_eq!(beta.to_ne_bytes(), 2.0f64.to_ne_bytes());
+        assert_eq!(patience, 10);
+        assert_eq!(stall_iter, 0);
+
+        for alpha in [0.0, 1.0, -0.5, 1.5] {
+            let res = AdaptiveCooling::new(alpha, 2.0f64, 10);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`AdaptiveCooling`: cooling factor must be in (0, 1).\""
+            );
+        }
+
+        for beta in [1.0, 0.5] {
+            let res = AdaptiveCooling::new(0.9f64, beta, 10);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`AdaptiveCooling`: reheating factor must be > 1.\""
+            );
+        }
+
+        let res = AdaptiveCooling::new(0.9f64, 2.0, 0);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`AdaptiveCooling`: patience must be > 0.\""
+        );
+    }
+
+    #[test]
+    fn test_adaptive_cooling_temperature() {
+        let mut schedule = AdaptiveCooling::new(0.5f64, 4.0, 2).unwrap();
+
+        // Cooling
+        let t = schedule.temperature(100.0, 40.0, 1, true, true);
+        assert_relative_eq!(t, 20.0, epsilon = f64::EPSILON);
+        let t = schedule.temperature(100.0, t, 2, false, false);
+        assert_relative_eq!(t, 10.0, epsilon = f64::EPSILON);
+        // Reheating after two iterations without new best solution
+        let t = schedule.temperature(100.0, t, 3, false, false);
+        assert_relative_eq!(t, 40.0, epsilon = f64::EPSILON);
+        let t = schedule.temperature(100.0, t, 4, false, false);
+        assert_relative_eq!(t, 20.0, epsilon = f64::EPSILON);
+        // Reheating never exceeds the initial temperature
+        let t = schedule.temperature(30.0, t, 5, false, false);
+        assert_relative_eq!(t, 30.0, epsilon = f64::EPSILON);
+
+        schedule.temperature(100.0, t, 6, false, false);
+        assert_eq!(schedule.stall_iter, 1);
+        schedule.reset();
+        assert_eq!(schedule.stall_iter, 0);
+    }
+}
@@ -6,10 +6,10 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::core::{
-    ArgminFloat, Error, Gradient, Hessian, IterState, Problem, Solver, State, TerminationReason,
-    TerminationStatus, TrustRegionRadius, KV,
+    ArgminFloat, Error, EuclideanMetric, Gradient, Hessian, IterState, Metric, Problem, Solver,
+    State, TerminationReason, TerminationStatus, TrustRegionRadius, KV,
 };
-use argmin_math::{ArgminL2Norm, ArgminMul, ArgminWeightedDot};
+use argmin_math::{ArgminDot, ArgminMul, LinearOperator};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
@@ -19,6 +19,10 @@ use std::fmt::Debug;
 /// The Cauchy point is the minimum of the quadratic approximation of the cost function within the
 /// trust region along the direction given by the first derivative.
 ///
+/// The size of the trust region is measured in the Euclidean norm by default. A different
+/// [`Metric`] can be set via [`with_metric`](`CauchyPoint::with_metric`); it must be the same as
+/// the one of [`TrustRegion`](`crate::solver::trustregion::TrustRegion`).
+///
 /// ## Requirements on the optimization problem
 ///
 /// The optimization problem is required to implement [`Gradient`] and [`Hessian`].
@@ -29,9 +33,11 @@ use std::fmt::Debug;
 /// Springer. ISBN 0-387-30303-0.
 #[derive(Clone, Debug, Copy, PartialEq, Eq, PartialOrd, Default)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
-pub struct CauchyPoint<F> {
+pub struct CauchyPoint<F, N = EuclideanMetric> {
     /// Radius
     radius: F,
+    /// Metric in which the size of the trust region is measured
+    metric: N,
 }
 
 impl<F> CauchyPoint<F>
@@ -47,15 +53,44 @@ where
     /// let cp: CauchyPoint<f64> = CauchyPoint::new();
     /// ```
     pub fn new() -> Self {
-        CauchyPoint { radius: F::nan() }
+        CauchyPoint {
+            radius: F::nan(),
+            metric: EuclideanMetric::new(),
+        }
+    }
+}
+
+impl<F, N> CauchyPoint<F, N>
+where
+    F: ArgminFloat,
+{
+    /// Set the metric in which the size of the trust region is measured
+    ///
+    /// Defaults to [`EuclideanMetric`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::trustregion::CauchyPoint;
+    /// # use argmin::core::DiagonalMetric;
+    /// let metric = DiagonalMetric::new(vec![1.0f64, 1e-6]);
+    /// let cp: CauchyPoint<f64, _> = CauchyPoint::new().with_metric(metric);
+    /// ```
+    pub fn with_metric<N2>(self, metric: N2) -> CauchyPoint<F, N2> {
+        CauchyPoint {
+            radius: self.radius,
+            metric,
+        }
     }
 }
 
-impl<O, F, P, G, H> Solver<O, IterState<P, G, (), H, F>> for CauchyPoint<F>
+impl<O, F, P, G, H, N> Solver<O, IterState<P, G, (), H, F>> for CauchyPoint<F, N>
 where
     O: Gradient<Param = P, Gradient = G> + Hessian<Param = P, Hessian = H>,
-    P: Clone + ArgminMul<F, P> + ArgminWeightedDot<P, F, H>,
-    G: ArgminMul<F, P> + ArgminWeightedDot<G, F, H> + ArgminL2Norm<F>,
+    P: Clone + ArgminMul<F, P>,
+    G: ArgminMul<F, P> + ArgminDot<G, F>,
+    H: LinearOperator<G>,
+    N: Metric<G, F>,
     F: ArgminFloat,
 {
     const NAME: &'static str = "Cauchy Point";
@@ -78,19 +113,20 @@ where
             .map(Result::Ok)
             .unwrap_or_else(|| problem.gradient(&param))?;
 
-        let grad_norm = grad.l2_norm();
+        let grad_norm = self.metric.norm(&grad);
+        let gtg: F = grad.dot(&grad);
 
         let hessian = state
             .take_hessian()
             .map(Result::Ok)
             .unwrap_or_else(|| problem.hessian(&param))?;
 
-        let wdp = grad.weighted_dot(&hessian, &grad);
+        let wdp = grad.dot(&hessian.apply(&grad)?);
 
         let tau: F = if wdp <= float!(0.0) {
             float!(1.0)
         } else {
-            float!(1.0).min(grad_norm.powi(3) / (self.radius * wdp))
+            float!(1.0).min(gtg * grad_norm / (self.radius * wdp))
         };
 
         let new_param = grad.mul(&(-tau * self.radius / grad_norm));
@@ -107,7 +143,7 @@ where
     }
 }
 
-impl<F> TrustRegionRadius<F> for CauchyPoint<F>
+impl<F, N> TrustRegionRadius<F> for CauchyPoint<F, N>
 where
     F: ArgminFloat,
 {
@@ -140,7 +176,7 @@ mod tests {
     fn test_new() {
         let cp: CauchyPoint<f64> = CauchyPoint::new();
 
-        let CauchyPoint { radius } = cp;
+        let CauchyPoint { radius, metric: _ } = cp;
 
         assert_eq!(radius.to_ne_bytes(), f64::NAN.to_ne_bytes());
     }
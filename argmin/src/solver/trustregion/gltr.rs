@@ -0,0 +1,417 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use crate::core::{
+    ArgminFloat, Error, IterState, Problem, SerializeAlias, Solver, State, TerminationReason,
+    TerminationStatus, TrustRegionRadius, KV,
+};
+use crate::solver::trustregion::tridiagonal;
+use argmin_math::{
+    ArgminDot, ArgminL2Norm, ArgminMul, ArgminScaledAdd, ArgminZeroLike, LinearOperator,
+};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Generalized Lanczos trust region (GLTR) method
+///
+/// The GLTR method finds an approximate solution to the trust region subproblem
+///
+/// `min_p g^T p + 1/2 p^T H p` subject to `||p|| <= radius`
+///
+/// by minimizing the model over a sequence of growing Krylov subspaces
+/// `span{g, H g, H^2 g, ...}`. The Lanczos process provides an orthonormal basis `Q_k` of the
+/// Krylov subspace in which the Hessian is tridiagonal, `T_k = Q_k^T H Q_k`. In each iteration,
+/// the trust region subproblem restricted to the subspace is solved exactly with the Moré-Sorensen
+/// method applied to `T_k`, which is cheap due to the tridiagonal structure.
+///
+/// Like the [`Steihaug`](`crate::solver::trustregion::Steihaug`) method, GLTR initially follows
+/// the conjugate gradient path. Unlike Steihaug, it does not stop when the trust region boundary
+/// is reached or negative curvature is encountered, but keeps improving the solution on the
+/// boundary, which makes it more robust for indefinite Hessians. The hard case of the restricted
+/// subproblem is handled by adding a multiple of the eigenvector corresponding to the leftmost
+/// eigenvalue of `T_k`. Note that directions of negative curvature which are orthogonal to the
+/// Krylov subspace (for instance if `g` is orthogonal to the corresponding eigenspace of `H`)
+/// cannot be detected by any Krylov method.
+///
+/// The algorithm stops if the residual `||(H + lambda I) p + g||` of the current solution is
+/// below `epsilon * ||g||` (see [`with_epsilon`](`GLTR::with_epsilon`)), or if the maximum number
+/// of iterations is reached (see [`with_max_iters`](`GLTR::with_max_iters`)). All Lanczos vectors
+/// are stored, therefore the maximum number of iterations also limits the memory consumption.
+///
+/// The Hessian is only used in Hessian-vector products and is required to implement
+/// [`LinearOperator`], which allows matrix-free Hessians.
+///
+/// ## Reference
+///
+/// Nicholas I. M. Gould, Stefano Lucidi, Massimo Roma and Philippe L. Toint (1999). Solving the
+/// trust-region subproblem using the Lanczos method. SIAM Journal on Optimization 9(2), 504-525.
+///
+/// Jorge Nocedal and Stephen J. Wright (2006). Numerical Optimization.
+/// Springer. ISBN 0-387-30303-0.
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct GLTR<P, F> {
+    /// Radius
+    radius: F,
+    /// epsilon
+    epsilon: F,
+    /// max iters
+    max_iters: u64,
+    /// Lanczos vectors
+    q: Vec<P>,
+    /// Diagonal of the tridiagonal matrix
+    alpha: Vec<F>,
+    /// Off-diagonal of the tridiagonal matrix
+    beta: Vec<F>,
+    /// Norm of the gradient
+    g_norm: F,
+}
+
+impl<P, F> GLTR<P, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`GLTR`]
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::trustregion::GLTR;
+    /// let gltr: GLTR<Vec<f64>, f64> = GLTR::new();
+    /// ```
+    pub fn new() -> Self {
+        GLTR {
+            radius: F::nan(),
+            epsilon: float!(10e-10),
+            max_iters: u64::MAX,
+            q: vec![],
+            alpha: vec![],
+            beta: vec![],
+            g_norm: F::nan(),
+        }
+    }
+
+    /// Set epsilon
+    ///
+    /// The algorithm stops when the residual is smaller than `epsilon` times the norm of the
+    /// gradient.
+    ///
+    /// Must be larger than 0 and defaults to 10^-10.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::trustregion::GLTR;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let gltr: GLTR<Vec<f64>, f64> = GLTR::new().with_epsilon(10e-9)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_epsilon(mut self, epsilon: F) -> Result<Self, Error> {
+        if epsilon <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`GLTR`: epsilon must be > 0.0."
+            ));
+        }
+        self.epsilon = epsilon;
+        Ok(self)
+    }
+
+    /// Set maximum number of iterations
+    ///
+    /// The algorithm stops after `iter` iterations.
+    ///
+    /// Defaults to `u64::MAX`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::trustregion::GLTR;
+    /// let gltr: GLTR<Vec<f64>, f64> = GLTR::new().with_max_iters(100);
+    /// ```
+    #[must_use]
+    pub fn with_max_iters(mut self, iters: u64) -> Self {
+        self.max_iters = iters;
+        self
+    }
+}
+
+impl<P, O, F, H> Solver<O, IterState<P, P, (), H, F>> for GLTR<P, F>
+where
+    P: Clone
+        + SerializeAlias
+        + ArgminMul<F, P>
+        + ArgminL2Norm<F>
+        + ArgminDot<P, F>
+        + ArgminScaledAdd<P, F, P>
+        + ArgminZeroLike,
+    H: LinearOperator<P>,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "GLTR";
+
+    fn init(
+        &mut self,
+        _problem: &mut Problem<O>,
+        state: IterState<P, P, (), H, F>,
+    ) -> Result<(IterState<P, P, (), H, F>, Option<KV>), Error> {
+        let g = state.get_gradient().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`GLTR` requires an initial gradient. ",
+                "Please provide an initial gradient via `Executor`s `configure` method."
+            )
+        ))?;
+
+        if state.get_hessian().is_none() {
+            return Err(argmin_error!(
+                NotInitialized,
+                concat!(
+                    "`GLTR` requires an initial Hessian. ",
+                    "Please provide an initial Hessian via `Executor`s `configure` method."
+                )
+            ));
+        }
+
+        self.g_norm = g.l2_norm();
+        let p = g.zero_like();
+        self.q = if self.g_norm > float!(0.0) {
+            vec![g.mul(&(float!(1.0) / self.g_norm))]
+        } else {
+            vec![]
+        };
+        self.alpha = vec![];
+        self.beta = vec![];
+
+        Ok((state.param(p), None))
+    }
+
+    fn next_iter(
+        &mut self,
+        _problem: &mut Problem<O>,
+        mut state: IterState<P, P, (), H, F>,
+    ) -> Result<(IterState<P, P, (), H, F>, Option<KV>), Error> {
+        let h = state.take_hessian().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`GLTR`: Hessian in state not set."
+        ))?;
+
+        // Lanczos step
+        let j = self.alpha.len();
+        let qj = &self.q[j];
+        let mut w = h.apply(qj)?;
+        if j > 0 {
+            w = w.scaled_add(&(-self.beta[j - 1]), &self.q[j - 1]);
+        }
+        let alpha_j: F = qj.dot(&w);
+        let w = w.scaled_add(&(-alpha_j), qj);
+        let beta_j = w.l2_norm();
+        self.alpha.push(alpha_j);
+
+        // Solve the subproblem restricted to the Krylov subspace
+        let (hk, lambda) =
+            tridiagonal::trust_region(&self.alpha, &self.beta, self.g_norm, self.radius);
+        let cost = tridiagonal::model(&self.alpha, &self.beta, self.g_norm, &hk);
+        let p = self
+            .q
+            .iter()
+            .zip(hk.iter())
+            .fold(self.q[0].zero_like(), |acc, (qi, hi)| {
+                acc.scaled_add(hi, qi)
+            });
+
+        // Residual of the full problem: ||(H + lambda I) p + g|| = beta_j * |h_j|
+        let residual = beta_j * hk[j].abs();
+        let kv = kv!("lambda" => lambda; "residual" => residual;);
+        if residual <= self.epsilon * self.g_norm || beta_j <= F::epsilon() * self.g_norm {
+            return Ok((
+                state
+                    .param(p)
+                    .cost(cost)
+                    .hessian(h)
+                    .terminate_with(TerminationReason::SolverConverged),
+                Some(kv),
+            ));
+        }
+
+        self.beta.push(beta_j);
+        self.q.push(w.mul(&(float!(1.0) / beta_j)));
+
+        Ok((state.param(p).cost(cost).hessian(h), Some(kv)))
+    }
+
+    fn terminate(&mut self, state: &IterState<P, P, (), H, F>) -> TerminationStatus {
+        if self.g_norm < self.epsilon {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        if state.get_iter() >= self.max_iters {
+            return TerminationStatus::Terminated(TerminationReason::MaxItersReached);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+impl<P, F: ArgminFloat> TrustRegionRadius<F> for GLTR<P, F> {
+    /// Set current radius.
+    ///
+    /// Needed by [`TrustRegion`](`crate::solver::trustregion::TrustRegion`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use argmin::solver::trustregion::{GLTR, TrustRegionRadius};
+    /// let mut gltr: GLTR<Vec<f64>, f64> = GLTR::new();
+    /// gltr.set_radius(0.8);
+    /// ```
+    fn set_radius(&mut self, radius: F) {
+        self.radius = radius;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::test_utils::TestProblem;
+    use crate::core::{ArgminError, Executor};
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(gltr, GLTR<TestProblem, f64>);
+
+    fn run(grad: Vec<f64>, hessian: Vec<Vec<f64>>, radius: f64) -> Vec<f64> {
+        let mut gltr: GLTR<Vec<f64>, f64> = GLTR::new();
+        gltr.set_radius(radius);
+        let res = Executor::new(TestProblem::new(), gltr)
+            .configure(|state| state.gradient(grad).hessian(hessian))
+            .run()
+            .unwrap();
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        res.state.get_param().unwrap().clone()
+    }
+
+    #[test]
+    fn test_new() {
+        let gltr: GLTR<Vec<f64>, f64> = GLTR::new();
+
+        let GLTR {
+            radius,
+            epsilon,
+            max_iters,
+            q,
+            alpha,
+            beta,
+            g_norm,
+        } = gltr;
+
+        assert_eq!(radius.to_ne_bytes(), f64::NAN.to_ne_bytes());
+        assert_eq!(epsilon.to_ne_bytes(), 10e-10f64.to_ne_bytes());
+        assert_eq!(max_iters, u64::MAX);
+        assert!(q.is_empty());
+        assert!(alpha.is_empty());
+        assert!(beta.is_empty());
+        assert_eq!(g_norm.to_ne_bytes(), f64::NAN.to_ne_bytes());
+    }
+
+    #[test]
+    fn test_with_epsilon() {
+        for tolerance in [f64::EPSILON, 1e-10, 1e-12, 1e-6, 1.0, 10.0, 100.0] {
+            let gltr: GLTR<Vec<f64>, f64> = GLTR::new().with_epsilon(tolerance).unwrap();
+            assert_eq!(gltr.epsilon.to_ne_bytes(), tolerance.to_ne_bytes());
+        }
+
+        for tolerance in [-f64::EPSILON, 0.0, -1.0] {
+            let res: Result<GLTR<Vec<f64>, f64>, _> = GLTR::new().with_epsilon(tolerance);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`GLTR`: epsilon must be > 0.0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_max_iters() {
+        for iters in [1, 2, 3, 5, 8, 13, 21, 34, 55, 89, 144] {
+            let gltr: GLTR<Vec<f64>, f64> = GLTR::new().with_max_iters(iters);
+            assert_eq!(gltr.max_iters, iters);
+        }
+    }
+
+    #[test]
+    fn test_init() {
+        let grad: Vec<f64> = vec![1.0, 2.0];
+        let hessian: Vec<Vec<f64>> = vec![vec![4.0, 3.0], vec![2.0, 1.0]];
+
+        let mut gltr: GLTR<Vec<f64>, f64> = GLTR::new();
+        gltr.set_radius(1.0);
+
+        // Forgot to initialize gradient
+        let state: IterState<Vec<f64>, Vec<f64>, (), Vec<Vec<f64>>, f64> = IterState::new();
+        let problem = TestProblem::new();
+        let res = gltr.init(&mut Problem::new(problem), state);
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`GLTR` requires an initial gradient. Please ",
+                "provide an initial gradient via `Executor`s `configure` method.\""
+            )
+        );
+
+        // Forgot to initialize Hessian
+        let state: IterState<Vec<f64>, Vec<f64>, (), Vec<Vec<f64>>, f64> =
+            IterState::new().gradient(grad.clone());
+        let problem = TestProblem::new();
+        let res = gltr.init(&mut Problem::new(problem), state);
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`GLTR` requires an initial Hessian. Please ",
+                "provide an initial Hessian via `Executor`s `configure` method.\""
+            )
+        );
+
+        // All good.
+        let state: IterState<Vec<f64>, Vec<f64>, (), Vec<Vec<f64>>, f64> =
+            IterState::new().gradient(grad).hessian(hessian);
+        let problem = TestProblem::new();
+        let (mut state_out, kv) = gltr.init(&mut Problem::new(problem), state).unwrap();
+
+        assert!(kv.is_none());
+        let s_param = state_out.take_param().unwrap();
+        assert_eq!(s_param[0].to_ne_bytes(), 0.0f64.to_ne_bytes());
+        assert_eq!(s_param[1].to_ne_bytes(), 0.0f64.to_ne_bytes());
+        assert_eq!(gltr.g_norm.to_ne_bytes(), 5.0f64.sqrt().to_ne_bytes());
+        assert_relative_eq!(gltr.q[0][0], 1.0 / 5.0f64.sqrt(), epsilon = f64::EPSILON);
+        assert_relative_eq!(gltr.q[0][1], 2.0 / 5.0f64.sqrt(), epsilon = f64::EPSILON);
+    }
+
+    #[test]
+    fn test_interior() {
+        // Positive definite Hessian with the Newton step inside the trust region
+        let p = run(vec![1.0, 2.0], vec![vec![4.0, 1.0], vec![1.0, 3.0]], 10.0);
+        assert_relative_eq!(p[0], -1.0 / 11.0, epsilon = 1e-10);
+        assert_relative_eq!(p[1], -7.0 / 11.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_boundary_indefinite() {
+        // Indefinite Hessian: The solution lies on the boundary and satisfies
+        // (H + lambda * I) p = -g for some lambda >= 2.
+        let p = run(vec![1.0, 1.0], vec![vec![-2.0, 0.0], vec![0.0, 1.0]], 1.0);
+        assert_relative_eq!(p[0].hypot(p[1]), 1.0, epsilon = 1e-6);
+        let lambda = -1.0 / p[1] - 1.0;
+        assert!(lambda >= 2.0);
+        assert_relative_eq!(p[0], -1.0 / (lambda - 2.0), epsilon = 1e-6);
+    }
+}
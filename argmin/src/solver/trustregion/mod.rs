@@ -22,13 +22,18 @@
 mod cauchypoint;
 /// Dogleg method
 mod dogleg;
+/// Generalized Lanczos trust region method
+mod gltr;
 /// Steihaug method
 mod steihaug;
+/// Subproblems restricted to Krylov subspaces
+pub(crate) mod tridiagonal;
 /// Trust region solver
 mod trustregion_method;
 
 pub use self::cauchypoint::*;
 pub use self::dogleg::*;
+pub use self::gltr::*;
 pub use self::steihaug::*;
 pub use self::trustregion_method::*;
 
@@ -9,9 +9,7 @@ use crate::core::{
     ArgminFloat, Error, IterState, Problem, SerializeAlias, Solver, State, TerminationReason,
     TerminationStatus, TrustRegionRadius, KV,
 };
-use argmin_math::{
-    ArgminAdd, ArgminDot, ArgminL2Norm, ArgminMul, ArgminWeightedDot, ArgminZeroLike,
-};
+use argmin_math::{ArgminAdd, ArgminDot, ArgminL2Norm, ArgminMul, ArgminZeroLike, LinearOperator};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 
@@ -118,19 +116,21 @@ where
     }
 
     /// evaluate m(p) (without considering f_init because it is not available)
-    fn eval_m<H>(&self, p: &P, g: &P, h: &H) -> F
+    fn eval_m<H>(&self, p: &P, g: &P, h: &H) -> Result<F, Error>
     where
-        P: ArgminWeightedDot<P, F, H>,
+        P: ArgminDot<P, F>,
+        H: LinearOperator<P>,
     {
-        g.dot(p) + float!(0.5) * p.weighted_dot(h, p)
+        Ok(g.dot(p) + float!(0.5) * p.dot(&h.apply(p)?))
     }
 
     /// calculate all possible step lengths
     #[allow(clippy::many_single_char_names)]
-    fn tau<G, H>(&self, filter_func: G, eval: bool, g: &P, h: &H) -> F
+    fn tau<G, H>(&self, filter_func: G, eval: bool, g: &P, h: &H) -> Result<F, Error>
     where
         G: Fn(F) -> bool,
-        P: ArgminWeightedDot<P, F, H>,
+        P: ArgminDot<P, F>,
+        H: LinearOperator<P>,
     {
         let p = self.p.as_ref().unwrap();
         let d = self.d.as_ref().unwrap();
@@ -157,8 +157,10 @@ where
                 .filter(|(_, tau)| (!tau.is_nan() || !tau.is_infinite()) && filter_func(*tau))
                 .map(|(i, tau)| {
                     let p_local = p.add(&d.mul(&tau));
-                    (i, self.eval_m(&p_local, g, h))
+                    Ok((i, self.eval_m(&p_local, g, h)?))
                 })
+                .collect::<Result<Vec<(usize, F)>, Error>>()?
+                .into_iter()
                 .filter(|(_, m)| !m.is_nan() || !m.is_infinite())
                 .collect::<Vec<(usize, F)>>();
             v.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
@@ -174,7 +176,7 @@ where
             v
         };
 
-        t[v[0].0]
+        Ok(t[v[0].0])
     }
 }
 
@@ -187,7 +189,7 @@ where
         + ArgminDot<P, F>
         + ArgminAdd<P, P>
         + ArgminZeroLike,
-    H: ArgminDot<P, P>,
+    H: LinearOperator<P>,
     F: ArgminFloat,
 {
     const NAME: &'static str = "Steihaug";
@@ -245,12 +247,13 @@ where
         ))?;
 
         let d = self.d.as_ref().unwrap();
-        let dhd = d.weighted_dot(&h, d);
+        let hd = h.apply(d)?;
+        let dhd = d.dot(&hd);
 
         // Current search direction d is a direction of zero curvature or negative curvature
         let p = self.p.as_ref().unwrap();
         if dhd <= float!(0.0) {
-            let tau = self.tau(|_| true, true, &grad, &h);
+            let tau = self.tau(|_| true, true, &grad, &h)?;
             return Ok((
                 state
                     .param(p.add(&d.mul(&tau)))
@@ -264,7 +267,7 @@ where
 
         // new p violates trust region bound
         if p_n.l2_norm() >= self.radius {
-            let tau = self.tau(|x| x >= float!(0.0), false, &grad, &h);
+            let tau = self.tau(|x| x >= float!(0.0), false, &grad, &h)?;
             return Ok((
                 state
                     .param(p.add(&d.mul(&tau)))
@@ -274,7 +277,7 @@ where
         }
 
         let r = self.r.as_ref().unwrap();
-        let r_n = r.add(&h.dot(d).mul(&alpha));
+        let r_n = r.add(&hd.mul(&alpha));
 
         if r_n.l2_norm() < self.epsilon * self.r_0_norm {
             return Ok((
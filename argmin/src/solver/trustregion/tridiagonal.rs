@@ -0,0 +1,299 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! Solvers for the trust region and cubic regularization subproblems restricted to a Krylov
+//! subspace, where the Hessian is a symmetric tridiagonal matrix `T` (given by its diagonal `a`
+//! and off-diagonal `b`) and the gradient is `g_norm * e_1`.
+
+use crate::core::ArgminFloat;
+
+/// `LDL^T` factorization of the tridiagonal matrix `T + shift * I`. Returns the diagonal of `D`
+/// and the subdiagonal of `L`.
+fn ldlt<F: ArgminFloat>(a: &[F], b: &[F], shift: F) -> (Vec<F>, Vec<F>) {
+    let mut d = Vec::with_capacity(a.len());
+    let mut l = Vec::with_capacity(b.len());
+    d.push(a[0] + shift);
+    for i in 1..a.len() {
+        let li = b[i - 1] / d[i - 1];
+        d.push(a[i] + shift - li * b[i - 1]);
+        l.push(li);
+    }
+    (d, l)
+}
+
+/// Solves `(T + shift * I) x = rhs` given the `LDL^T` factorization. Additionally returns
+/// `x^T (T + shift * I)^{-1} x`.
+fn ldlt_solve<F: ArgminFloat>(d: &[F], l: &[F], rhs: &[F]) -> (Vec<F>, F) {
+    let n = d.len();
+    let mut x = rhs.to_vec();
+    for i in 1..n {
+        x[i] = x[i] - l[i - 1] * x[i - 1];
+    }
+    for i in 0..n {
+        x[i] = x[i] / d[i];
+    }
+    for i in (0..n - 1).rev() {
+        x[i] = x[i] - l[i] * x[i + 1];
+    }
+    // x^T (L D L^T)^{-1} x = z^T D^{-1} z with L z = x
+    let mut z = x.clone();
+    for i in 1..n {
+        z[i] = z[i] - l[i - 1] * z[i - 1];
+    }
+    let xtx = z
+        .iter()
+        .zip(d.iter())
+        .fold(float!(0.0), |acc, (zi, di)| acc + *zi * *zi / *di);
+    (x, xtx)
+}
+
+/// Solves `(T + shift * I) h = -g_norm * e_1`. Additionally returns `h^T (T + shift * I)^{-1} h`.
+fn solve<F: ArgminFloat>(a: &[F], b: &[F], g_norm: F, shift: F) -> (Vec<F>, F) {
+    let mut rhs = vec![float!(0.0); a.len()];
+    rhs[0] = -g_norm;
+    let (d, l) = ldlt(a, b, shift);
+    ldlt_solve(&d, &l, &rhs)
+}
+
+/// Euclidean norm
+pub(crate) fn norm<F: ArgminFloat>(x: &[F]) -> F {
+    x.iter().fold(float!(0.0), |acc, xi| acc + *xi * *xi).sqrt()
+}
+
+/// Computes the smallest eigenvalue of `T` with bisection based on Sylvester's law of inertia.
+fn min_eigenvalue<F: ArgminFloat>(a: &[F], b: &[F]) -> F {
+    let n = a.len();
+    let offdiag = |i: usize| {
+        let left = if i > 0 { b[i - 1].abs() } else { float!(0.0) };
+        let right = if i + 1 < n { b[i].abs() } else { float!(0.0) };
+        left + right
+    };
+    let mut lo = (0..n)
+        .map(|i| a[i] - offdiag(i))
+        .fold(F::infinity(), |acc, x| acc.min(x));
+    let mut hi = (0..n)
+        .map(|i| a[i] + offdiag(i))
+        .fold(F::neg_infinity(), |acc, x| acc.max(x));
+    let tol = F::epsilon() * (lo.abs().max(hi.abs()).max(float!(1.0)));
+    for _ in 0..200 {
+        if hi - lo <= tol {
+            break;
+        }
+        let mid = float!(0.5) * (lo + hi);
+        // T - mid * I is positive definite iff all pivots are positive
+        let (d, _) = ldlt(a, b, -mid);
+        if d.iter().all(|di| *di > float!(0.0)) {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+/// Value of the quadratic model `g_norm * h_0 + 1/2 h^T T h`
+pub(crate) fn model<F: ArgminFloat>(a: &[F], b: &[F], g_norm: F, h: &[F]) -> F {
+    let quad = (0..h.len()).fold(float!(0.0), |acc, i| {
+        let mut th = a[i] * h[i];
+        if i > 0 {
+            th = th + b[i - 1] * h[i - 1];
+        }
+        if i + 1 < h.len() {
+            th = th + b[i] * h[i + 1];
+        }
+        acc + h[i] * th
+    });
+    g_norm * h[0] + float!(0.5) * quad
+}
+
+/// Hard case: Adds a multiple of the eigenvector corresponding to the leftmost eigenvalue
+/// `lambda_min` to `h` such that the result has norm `target`. Of the two possible solutions, the
+/// one with the lower value of the quadratic model is returned.
+fn hard_case<F: ArgminFloat>(
+    a: &[F],
+    b: &[F],
+    g_norm: F,
+    h: &[F],
+    lambda_min: F,
+    target: F,
+) -> Vec<F> {
+    // Inverse iteration with a shift slightly below the leftmost eigenvalue
+    let shift = -lambda_min + F::epsilon() * (float!(1.0) + lambda_min.abs());
+    let (d, l) = ldlt(a, b, shift);
+    let mut z = vec![float!(1.0); a.len()];
+    for _ in 0..3 {
+        let (zn, _) = ldlt_solve(&d, &l, &z);
+        let zn_norm = norm(&zn);
+        z = zn.iter().map(|zi| *zi / zn_norm).collect();
+    }
+    let hz = h
+        .iter()
+        .zip(z.iter())
+        .fold(float!(0.0), |acc, (hi, zi)| acc + *hi * *zi);
+    let hh = norm(h).powi(2);
+    let disc = (hz * hz - hh + target * target).max(float!(0.0)).sqrt();
+    [-hz + disc, -hz - disc]
+        .iter()
+        .map(|tau| {
+            h.iter()
+                .zip(z.iter())
+                .map(|(hi, zi)| *hi + *tau * *zi)
+                .collect::<Vec<F>>()
+        })
+        .min_by(|x, y| {
+            model(a, b, g_norm, x)
+                .partial_cmp(&model(a, b, g_norm, y))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .unwrap()
+}
+
+/// Solves the trust region subproblem `min g_norm * h_0 + 1/2 h^T T h` s.t. `||h|| <= radius`
+/// with the Moré-Sorensen method. Returns the solution and the Lagrange multiplier.
+pub(crate) fn trust_region<F: ArgminFloat>(a: &[F], b: &[F], g_norm: F, radius: F) -> (Vec<F>, F) {
+    let lambda_min = min_eigenvalue(a, b);
+
+    // Interior solution
+    if lambda_min > float!(0.0) {
+        let (h, _) = solve(a, b, g_norm, float!(0.0));
+        if norm(&h) <= radius {
+            return (h, float!(0.0));
+        }
+    }
+
+    // Boundary solution: find lambda > max(0, -lambda_min) with ||h(lambda)|| = radius
+    let lambda_lo = float!(0.0).max(-lambda_min);
+    let mut lo = lambda_lo;
+    let mut hi = lambda_lo + g_norm / radius;
+    // Safeguard the lower bound slightly to keep `T + lambda * I` positive definite
+    let mut lambda = lo + F::epsilon().sqrt() * (float!(1.0) + lambda_lo);
+    let tol = F::epsilon().sqrt() * radius;
+
+    let (mut h, _) = solve(a, b, g_norm, lambda);
+    if lambda_min <= float!(0.0) && norm(&h) < radius {
+        return (hard_case(a, b, g_norm, &h, lambda_min, radius), lambda_lo);
+    }
+
+    for _ in 0..100 {
+        let (h_new, hth) = solve(a, b, g_norm, lambda);
+        h = h_new;
+        let h_norm = norm(&h);
+        if (h_norm - radius).abs() <= tol {
+            break;
+        }
+        if h_norm > radius {
+            lo = lambda;
+        } else {
+            hi = lambda;
+        }
+        // Newton step on the secular equation 1/||h(lambda)|| - 1/radius = 0
+        let step = (h_norm * h_norm / hth) * (h_norm - radius) / radius;
+        let lambda_new = lambda + step;
+        lambda = if lambda_new > lo && lambda_new < hi && lambda_new.is_finite() {
+            lambda_new
+        } else {
+            float!(0.5) * (lo + hi)
+        };
+    }
+    (h, lambda)
+}
+
+/// Solves the cubic regularization subproblem
+/// `min g_norm * h_0 + 1/2 h^T T h + sigma/3 ||h||^3`. The solution satisfies
+/// `(T + lambda * I) h = -g_norm * e_1` with `lambda = sigma * ||h||`. Returns the solution and
+/// `lambda`.
+pub(crate) fn cubic_regularization<F: ArgminFloat>(
+    a: &[F],
+    b: &[F],
+    g_norm: F,
+    sigma: F,
+) -> (Vec<F>, F) {
+    let lambda_min = min_eigenvalue(a, b);
+
+    // Find lambda > max(0, -lambda_min) with ||h(lambda)|| = lambda / sigma
+    let lambda_lo = float!(0.0).max(-lambda_min);
+    let mut lo = lambda_lo;
+    let mut hi = lambda_lo + (sigma * g_norm).sqrt();
+    // Safeguard the lower bound slightly to keep `T + lambda * I` positive definite
+    let mut lambda = lo + F::epsilon().sqrt() * (float!(1.0) + lambda_lo);
+
+    let (mut h, _) = solve(a, b, g_norm, lambda);
+    if norm(&h) < lambda / sigma {
+        return if lambda_min < float!(0.0) {
+            (
+                hard_case(a, b, g_norm, &h, lambda_min, lambda_lo / sigma),
+                lambda_lo,
+            )
+        } else {
+            (h, lambda)
+        };
+    }
+
+    for _ in 0..100 {
+        let (h_new, hth) = solve(a, b, g_norm, lambda);
+        h = h_new;
+        let h_norm = norm(&h);
+        let target = lambda / sigma;
+        if (h_norm - target).abs() <= F::epsilon().sqrt() * h_norm.max(target) {
+            break;
+        }
+        if h_norm > target {
+            lo = lambda;
+        } else {
+            hi = lambda;
+        }
+        // Newton step on the secular equation 1/||h(lambda)|| - sigma/lambda = 0
+        let phi = float!(1.0) / h_norm - sigma / lambda;
+        let dphi = hth / h_norm.powi(3) + sigma / lambda.powi(2);
+        let lambda_new = lambda - phi / dphi;
+        lambda = if lambda_new > lo && lambda_new < hi && lambda_new.is_finite() {
+            lambda_new
+        } else {
+            float!(0.5) * (lo + hi)
+        };
+    }
+    (h, lambda)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_min_eigenvalue() {
+        // Eigenvalues of [[2, 1], [1, 2]] are 1 and 3
+        assert_relative_eq!(min_eigenvalue(&[2.0f64, 2.0], &[1.0]), 1.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_trust_region_hard_case() {
+        // T = diag(1, -1) (reduced), the gradient is orthogonal to the eigenvector of -1
+        let (h, lambda) = trust_region(&[1.0f64, -1.0], &[0.0], 1.0, 2.0);
+        assert_relative_eq!(lambda, 1.0, epsilon = 1e-6);
+        assert_relative_eq!(h[0], -0.5, epsilon = 1e-6);
+        assert_relative_eq!(h[1].abs(), (4.0f64 - 0.25).sqrt(), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_cubic_regularization() {
+        // T = diag(1, 2): (1 + lambda) h_0 = -1, h_1 = 0 and lambda = sigma * |h_0|
+        let (h, lambda) = cubic_regularization(&[1.0f64, 2.0], &[0.0], 1.0, 2.0);
+        assert_relative_eq!(h[1], 0.0, epsilon = 1e-12);
+        assert_relative_eq!((1.0 + lambda) * h[0], -1.0, epsilon = 1e-6);
+        assert_relative_eq!(lambda, 2.0 * h[0].abs(), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_cubic_regularization_hard_case() {
+        // T = diag(1, -1) (reduced): lambda = 1 and ||h|| = lambda / sigma
+        let (h, lambda) = cubic_regularization(&[1.0f64, -1.0], &[0.0], 1.0, 0.5);
+        assert_relative_eq!(lambda, 1.0, epsilon = 1e-6);
+        assert_relative_eq!(h[0], -0.5, epsilon = 1e-6);
+        assert_relative_eq!(h[1].abs(), (4.0f64 - 0.25).sqrt(), epsilon = 1e-6);
+    }
+}
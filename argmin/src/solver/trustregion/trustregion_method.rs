@@ -6,12 +6,12 @@
 // copied, modified, or distributed except according to those terms.
 
 use crate::core::{
-    ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, Executor, Gradient, Hessian,
-    IterState, OptimizationResult, Problem, SerializeAlias, Solver, TerminationStatus,
-    TrustRegionRadius, KV,
+    ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, EuclideanMetric, Executor, Gradient,
+    Hessian, IterState, Metric, OptimizationResult, Problem, SerializeAlias, Solver,
+    TerminationStatus, TrustRegionRadius, KV,
 };
 use crate::solver::trustregion::reduction_ratio;
-use argmin_math::{ArgminAdd, ArgminDot, ArgminL2Norm, ArgminWeightedDot};
+use argmin_math::{ArgminAdd, ArgminDot, LinearOperator};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 
@@ -27,11 +27,19 @@ use serde::{Deserialize, Serialize};
 /// * [Cauchy point](`crate::solver::trustregion::CauchyPoint`)
 /// * [Dogleg method](`crate::solver::trustregion::Dogleg`)
 /// * [Steihaug method](`crate::solver::trustregion::Steihaug`)
+/// * [Generalized Lanczos trust region method](`crate::solver::trustregion::GLTR`)
+///
+/// The size of the trust region is measured in the Euclidean norm by default. If the variables
+/// have very different scales, a [`Metric`] which takes the scales into account can be set via
+/// [`with_metric`](`TrustRegion::with_metric`). The subproblem solver must then bound the step in
+/// the same metric, which is currently supported by
+/// [`CauchyPoint`](`crate::solver::trustregion::CauchyPoint`).
 ///
 /// ## Requirements on the optimization problem
 ///
 /// The optimization problem is required to implement [`CostFunction`], [`Gradient`] and
-/// [`Hessian`].
+/// [`Hessian`]. Except for the Dogleg method, the Hessian is only used via matrix-vector products
+/// and can be any [`LinearOperator`].
 ///
 /// ## Reference
 ///
@@ -39,7 +47,7 @@ use serde::{Deserialize, Serialize};
 /// Springer. ISBN 0-387-30303-0.
 #[derive(Clone)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
-pub struct TrustRegion<R, F> {
+pub struct TrustRegion<R, F, N = EuclideanMetric> {
     /// Radius
     radius: F,
     /// Maximum radius
@@ -48,6 +56,8 @@ pub struct TrustRegion<R, F> {
     eta: F,
     /// subproblem (must implement [`crate::solver::trustregion::TrustRegionRadius`])
     subproblem: R,
+    /// Metric in which the size of the trust region is measured
+    metric: N,
     /// f(xk)
     fxk: F,
     /// mk(0)
@@ -73,10 +83,41 @@ where
             max_radius: float!(100.0),
             eta: float!(0.125),
             subproblem,
+            metric: EuclideanMetric::new(),
             fxk: F::nan(),
             mk0: F::nan(),
         }
     }
+}
+
+impl<R, F, N> TrustRegion<R, F, N>
+where
+    F: ArgminFloat,
+{
+    /// Set the metric in which the size of the trust region is measured
+    ///
+    /// Defaults to [`EuclideanMetric`]. The subproblem solver must use the same metric.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::trustregion::{CauchyPoint, TrustRegion};
+    /// # use argmin::core::DiagonalMetric;
+    /// let metric = DiagonalMetric::new(vec![1.0f64, 1e-6]);
+    /// let cp: CauchyPoint<f64, _> = CauchyPoint::new().with_metric(metric.clone());
+    /// let tr: TrustRegion<_, f64, _> = TrustRegion::new(cp).with_metric(metric);
+    /// ```
+    pub fn with_metric<N2>(self, metric: N2) -> TrustRegion<R, F, N2> {
+        TrustRegion {
+            radius: self.radius,
+            max_radius: self.max_radius,
+            eta: self.eta,
+            subproblem: self.subproblem,
+            metric,
+            fxk: self.fxk,
+            mk0: self.mk0,
+        }
+    }
 
     /// Set radius
     ///
@@ -157,7 +198,7 @@ where
     }
 }
 
-impl<O, R, F, P, G, H> Solver<O, IterState<P, G, (), H, F>> for TrustRegion<R, F>
+impl<O, R, F, P, G, H, N> Solver<O, IterState<P, G, (), H, F>> for TrustRegion<R, F, N>
 where
     O: CostFunction<Param = P, Output = F>
         + Gradient<Param = P, Gradient = G>
@@ -166,13 +207,13 @@ where
         + std::fmt::Debug
         + SerializeAlias
         + DeserializeOwnedAlias
-        + ArgminL2Norm<F>
         + ArgminDot<P, F>
         + ArgminDot<G, F>
         + ArgminAdd<P, P>,
     G: Clone + SerializeAlias + DeserializeOwnedAlias,
-    H: Clone + SerializeAlias + DeserializeOwnedAlias + ArgminDot<P, P>,
+    H: Clone + SerializeAlias + DeserializeOwnedAlias + LinearOperator<P>,
     R: Clone + TrustRegionRadius<F> + Solver<O, IterState<P, G, (), H, F>>,
+    N: Metric<P, F>,
     F: ArgminFloat,
 {
     const NAME: &'static str = "Trust region";
@@ -261,11 +302,11 @@ where
 
         let new_param = pk.add(&param);
         let fxkpk = problem.cost(&new_param)?;
-        let mkpk = self.fxk + pk.dot(&grad) + float!(0.5) * pk.weighted_dot(&hessian, &pk);
+        let mkpk = self.fxk + pk.dot(&grad) + float!(0.5) * pk.dot(&hessian.apply(&pk)?);
 
         let rho = reduction_ratio(self.fxk, fxkpk, self.mk0, mkpk);
 
-        let pk_norm = pk.l2_norm();
+        let pk_norm = self.metric.norm(&pk);
 
         let cur_radius = self.radius;
 
@@ -325,6 +366,7 @@ mod tests {
             max_radius,
             eta,
             subproblem: _,
+            metric: _,
             fxk,
             mk0,
         } = tr;
@@ -432,6 +474,7 @@ mod tests {
             max_radius,
             eta,
             subproblem: _,
+            metric: _,
             fxk,
             mk0,
         } = tr;
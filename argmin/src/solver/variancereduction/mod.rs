@@ -0,0 +1,186 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Variance-reduced stochastic gradient methods
+//!
+//! Solvers for finite-sum problems
+//!
+//! `f(x) = 1/n * sum_{i=1}^n f_i(x)`,
+//!
+//! as they typically arise in machine learning, where each summand corresponds to one sample of
+//! a data set. Like stochastic gradient descent, these methods only evaluate the gradient of a
+//! single randomly chosen summand per step, but they reduce the variance of the resulting
+//! gradient estimate by reusing previously computed gradients, which allows for constant step
+//! sizes and linear convergence on strongly convex problems.
+//!
+//! The problem is required to implement [`FiniteSumGradient`].
+//!
+//! ## Available solvers
+//!
+//! * [`SAG`]: Stochastic average gradient
+//! * [`SAGA`]: Unbiased variant of SAG
+//! * [`SVRG`]: Stochastic variance reduced gradient, based on periodic full-gradient snapshots
+//!
+//! ## References
+//!
+//! Mark Schmidt, Nicolas Le Roux and Francis Bach (2017). Minimizing finite sums with the
+//! stochastic average gradient. Mathematical Programming 162, 83-112.
+//!
+//! Aaron Defazio, Francis Bach and Simon Lacoste-Julien (2014). SAGA: A fast incremental gradient
+//! method with support for non-strongly convex composite objectives. NIPS 2014.
+//!
+//! Rie Johnson and Tong Zhang (2013). Accelerating stochastic gradient descent using predictive
+//! variance reduction. NIPS 2013.
+
+mod sag;
+mod saga;
+mod svrg;
+
+pub use sag::SAG;
+pub use saga::SAGA;
+pub use svrg::SVRG;
+
+use crate::core::{Error, Problem};
+
+/// Gradients of the summands of a finite-sum problem `f(x) = 1/n * sum_{i=1}^n f_i(x)`.
+/// Problems which are to be solved using the variance-reduced stochastic gradient methods must
+/// implement this trait.
+pub trait FiniteSumGradient {
+    /// Type of the parameter vector
+    type Param;
+    /// Type of the gradient
+    type Gradient;
+
+    /// Number of summands `n`
+    fn num_samples(&self) -> usize;
+
+    /// Compute the gradient of the summand `f_idx` at `param`
+    fn sample_gradient(&self, param: &Self::Param, idx: usize) -> Result<Self::Gradient, Error>;
+}
+
+/// Wraps calls to the methods of the `FiniteSumGradient` trait and as such allows to call them on
+/// an instance of `Problem`. Internally, the number of evaluations of `sample_gradient` is
+/// counted.
+impl<O: FiniteSumGradient> Problem<O> {
+    /// Calls `sample_gradient` defined in the `FiniteSumGradient` trait and keeps track of the
+    /// number of evaluations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Problem, Error};
+    /// # use argmin::solver::variancereduction::FiniteSumGradient;
+    /// #
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl FiniteSumGradient for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type Gradient = Vec<f64>;
+    /// #
+    /// #     fn num_samples(&self) -> usize {
+    /// #         3
+    /// #     }
+    /// #
+    /// #     fn sample_gradient(&self, param: &Self::Param, idx: usize) -> Result<Self::Gradient, Error> {
+    /// #         Ok(vec![idx as f64; param.len()])
+    /// #     }
+    /// # }
+    /// // `UserDefinedProblem` implements `FiniteSumGradient`.
+    /// let mut problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// let param = vec![2.0f64, 1.0f64];
+    ///
+    /// let res = problem1.sample_gradient(&param, 1);
+    ///
+    /// assert_eq!(problem1.counts["sample_gradient_count"], 1);
+    /// # assert_eq!(res.unwrap(), vec![1.0f64, 1.0f64]);
+    /// ```
+    pub fn sample_gradient(&mut self, param: &O::Param, idx: usize) -> Result<O::Gradient, Error> {
+        self.problem("sample_gradient_count", |problem| {
+            problem.sample_gradient(param, idx)
+        })
+    }
+
+    /// Returns the number of summands as defined in the `FiniteSumGradient` trait.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::core::{Problem, Error};
+    /// # use argmin::solver::variancereduction::FiniteSumGradient;
+    /// #
+    /// # struct UserDefinedProblem {};
+    /// #
+    /// # impl FiniteSumGradient for UserDefinedProblem {
+    /// #     type Param = Vec<f64>;
+    /// #     type Gradient = Vec<f64>;
+    /// #
+    /// #     fn num_samples(&self) -> usize {
+    /// #         3
+    /// #     }
+    /// #
+    /// #     fn sample_gradient(&self, param: &Self::Param, idx: usize) -> Result<Self::Gradient, Error> {
+    /// #         Ok(vec![idx as f64; param.len()])
+    /// #     }
+    /// # }
+    /// let problem1 = Problem::new(UserDefinedProblem {});
+    ///
+    /// assert_eq!(problem1.num_samples().unwrap(), 3);
+    /// ```
+    pub fn num_samples(&self) -> Result<usize, Error> {
+        self.problem
+            .as_ref()
+            .map(|problem| problem.num_samples())
+            .ok_or_else(argmin_error_closure!(
+                PotentialBug,
+                "`Problem`: No problem set to query the number of samples from."
+            ))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_utils {
+    use super::FiniteSumGradient;
+    use crate::core::Error;
+
+    /// Consistent linear least-squares problem `f_i(x) = 1/2 * (a_i^T x - b_i)^2` with solution
+    /// `x = [1, -2]`
+    pub(crate) struct LeastSquares {
+        a: Vec<Vec<f64>>,
+        b: Vec<f64>,
+    }
+
+    impl LeastSquares {
+        pub(crate) fn new() -> Self {
+            let a = vec![
+                vec![1.0, 0.5],
+                vec![-0.3, 1.0],
+                vec![0.8, -0.6],
+                vec![0.2, 0.9],
+                vec![-1.0, -0.4],
+                vec![0.6, 0.1],
+            ];
+            let b = a.iter().map(|ai| ai[0] - 2.0 * ai[1]).collect();
+            LeastSquares { a, b }
+        }
+    }
+
+    impl FiniteSumGradient for LeastSquares {
+        type Param = Vec<f64>;
+        type Gradient = Vec<f64>;
+
+        fn num_samples(&self) -> usize {
+            self.a.len()
+        }
+
+        fn sample_gradient(&self, x: &Self::Param, idx: usize) -> Result<Self::Gradient, Error> {
+            let ai = &self.a[idx];
+            let r = ai[0] * x[0] + ai[1] * x[1] - self.b[idx];
+            Ok(vec![r * ai[0], r * ai[1]])
+        }
+    }
+}
@@ -0,0 +1,258 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::FiniteSumGradient;
+use crate::core::{ArgminFloat, Error, IterState, Problem, SerializeAlias, Solver, State, KV};
+use argmin_math::{ArgminAdd, ArgminScaledSub, ArgminSub, ArgminZeroLike};
+use rand::prelude::*;
+use rand_xoshiro::Xoshiro256PlusPlus;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Stochastic average gradient (SAG)
+///
+/// Variance-reduced stochastic gradient method for finite-sum problems (see
+/// [`FiniteSumGradient`]). SAG memorizes the most recent gradient `y_i` of each summand. In each
+/// step, a summand `i` is chosen uniformly at random, its memorized gradient is replaced by
+/// `∇f_i(x)` and the parameter vector is updated with the average of all memorized gradients:
+///
+/// `x_{k+1} = x_k - step_size / m * sum_i y_i`,
+///
+/// where `m` is the number of summands visited so far (the memory is initially empty). The
+/// resulting gradient estimate is biased, but its variance vanishes as the iterates converge.
+///
+/// One iteration of the solver corresponds to `n` steps (one epoch), where `n` is the number of
+/// summands. A suitable step size is `1 / (16 L)`, where `L` is the largest Lipschitz constant
+/// of the gradients of the summands.
+///
+/// The initial parameter vector needs to be provided via the `configure` method of the
+/// [`Executor`](`crate::core::Executor`).
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`FiniteSumGradient`].
+///
+/// ## Reference
+///
+/// Mark Schmidt, Nicolas Le Roux and Francis Bach (2017). Minimizing finite sums with the
+/// stochastic average gradient. Mathematical Programming 162, 83-112.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct SAG<P, F, R> {
+    /// Step size
+    step_size: F,
+    /// Most recent gradient of each summand
+    memory: Vec<Option<P>>,
+    /// Sum of the memorized gradients
+    sum: Option<P>,
+    /// Number of summands visited so far
+    num_seen: usize,
+    /// Random number generator
+    rng: R,
+}
+
+impl<P, F> SAG<P, F, Xoshiro256PlusPlus>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`SAG`]
+    ///
+    /// Takes the step size, which must be larger than 0.
+    ///
+    /// Uses the `Xoshiro256PlusPlus` RNG internally. For use of another RNG, consider using
+    /// [`SAG::new_with_rng`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::variancereduction::SAG;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let sag: SAG<Vec<f64>, f64, _> = SAG::new(0.01)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(step_size: F) -> Result<Self, Error> {
+        SAG::new_with_rng(step_size, Xoshiro256PlusPlus::from_entropy())
+    }
+}
+
+impl<P, F, R> SAG<P, F, R>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`SAG`]
+    ///
+    /// Takes the step size, which must be larger than 0.
+    /// Requires a RNG which must implement `rand::Rng` (and `serde::Serialize` if the `serde1`
+    /// feature is enabled).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::variancereduction::SAG;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let my_rng = ();
+    /// let sag: SAG<Vec<f64>, f64, _> = SAG::new_with_rng(0.01, my_rng)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_with_rng(step_size: F, rng: R) -> Result<Self, Error> {
+        if step_size <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`SAG`: Step size must be > 0."
+            ));
+        }
+        Ok(SAG {
+            step_size,
+            memory: vec![],
+            sum: None,
+            num_seen: 0,
+            rng,
+        })
+    }
+}
+
+impl<O, P, F, R> Solver<O, IterState<P, (), (), (), F>> for SAG<P, F, R>
+where
+    O: FiniteSumGradient<Param = P, Gradient = P>,
+    P: Clone
+        + SerializeAlias
+        + ArgminAdd<P, P>
+        + ArgminSub<P, P>
+        + ArgminScaledSub<P, F, P>
+        + ArgminZeroLike,
+    F: ArgminFloat,
+    R: Rng + SerializeAlias,
+{
+    const NAME: &'static str = "SAG";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        let param = state.get_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`SAG` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        let num_samples = problem.num_samples()?;
+        if num_samples == 0 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`SAG`: Problem must have at least one sample."
+            ));
+        }
+        self.memory = vec![None; num_samples];
+        self.sum = Some(param.zero_like());
+        self.num_seen = 0;
+        Ok((state, None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        let mut param = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`SAG`: Parameter vector in state not set."
+        ))?;
+        let mut sum = self.sum.take().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`SAG`: Sum of gradients not initialized."
+        ))?;
+        let num_samples = self.memory.len();
+
+        for _ in 0..num_samples {
+            let idx = self.rng.gen_range(0..num_samples);
+            let grad = problem.sample_gradient(&param, idx)?;
+            sum = match self.memory[idx].replace(grad.clone()) {
+                Some(old) => sum.sub(&old).add(&grad),
+                None => {
+                    self.num_seen += 1;
+                    sum.add(&grad)
+                }
+            };
+            let scale = self.step_size / F::from_usize(self.num_seen).unwrap();
+            param = param.scaled_sub(&scale, &sum);
+        }
+
+        self.sum = Some(sum);
+        Ok((
+            state.param(param),
+            Some(kv!("num_seen" => self.num_seen as u64;)),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Executor};
+    use crate::solver::variancereduction::test_utils::LeastSquares;
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(sag, SAG<Vec<f64>, f64, StdRng>);
+
+    #[test]
+    fn test_new() {
+        let SAG {
+            step_size,
+            memory,
+            sum,
+            num_seen,
+            rng: _,
+        }: SAG<Vec<f64>, f64, _> = SAG::new(0.1).unwrap();
+        assert_eq!(step_size.to_ne_bytes(), 0.1f64.to_ne_bytes());
+        assert!(memory.is_empty());
+        assert!(sum.is_none());
+        assert_eq!(num_seen, 0);
+
+        for step_size in [0.0, -1.0] {
+            let res: Result<SAG<Vec<f64>, f64, _>, _> = SAG::new(step_size);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`SAG`: Step size must be > 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_not_initialized() {
+        let sag: SAG<Vec<f64>, f64, _> = SAG::new(0.1).unwrap();
+        let res = Executor::new(LeastSquares::new(), sag).run();
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`SAG` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_solver() {
+        let sag = SAG::new_with_rng(0.05, Xoshiro256PlusPlus::seed_from_u64(42)).unwrap();
+        let res = Executor::new(LeastSquares::new(), sag)
+            .configure(|state| state.param(vec![0.0, 0.0]).max_iters(500))
+            .run()
+            .unwrap();
+        let param = res.state.get_param().unwrap();
+        assert_relative_eq!(param[0], 1.0, epsilon = 1e-6);
+        assert_relative_eq!(param[1], -2.0, epsilon = 1e-6);
+        assert_eq!(res.problem.counts["sample_gradient_count"], 500 * 6);
+    }
+}
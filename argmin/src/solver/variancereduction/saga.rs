@@ -0,0 +1,253 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::FiniteSumGradient;
+use crate::core::{ArgminFloat, Error, IterState, Problem, SerializeAlias, Solver, State, KV};
+use argmin_math::{ArgminAdd, ArgminMul, ArgminScaledSub, ArgminSub};
+use rand::prelude::*;
+use rand_xoshiro::Xoshiro256PlusPlus;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # SAGA
+///
+/// Variance-reduced stochastic gradient method for finite-sum problems (see
+/// [`FiniteSumGradient`]). Like [`SAG`](`super::SAG`), SAGA memorizes the most recent gradient
+/// `y_i` of each summand. In each step, a summand `i` is chosen uniformly at random and the
+/// parameter vector is updated with an unbiased estimate of the full gradient:
+///
+/// `x_{k+1} = x_k - step_size * (∇f_i(x_k) - y_i + 1/n * sum_j y_j)`,
+///
+/// after which `y_i` is replaced by `∇f_i(x_k)`. The memory is initialized with the gradients of
+/// all summands at the initial parameter vector, which requires one pass over all summands in
+/// `init`.
+///
+/// One iteration of the solver corresponds to `n` steps (one epoch), where `n` is the number of
+/// summands. A suitable step size is `1 / (3 L)`, where `L` is the largest Lipschitz constant of
+/// the gradients of the summands.
+///
+/// The initial parameter vector needs to be provided via the `configure` method of the
+/// [`Executor`](`crate::core::Executor`).
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`FiniteSumGradient`].
+///
+/// ## Reference
+///
+/// Aaron Defazio, Francis Bach and Simon Lacoste-Julien (2014). SAGA: A fast incremental gradient
+/// method with support for non-strongly convex composite objectives. NIPS 2014.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct SAGA<P, F, R> {
+    /// Step size
+    step_size: F,
+    /// Most recent gradient of each summand
+    memory: Vec<P>,
+    /// Sum of the memorized gradients
+    sum: Option<P>,
+    /// Random number generator
+    rng: R,
+}
+
+impl<P, F> SAGA<P, F, Xoshiro256PlusPlus>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`SAGA`]
+    ///
+    /// Takes the step size, which must be larger than 0.
+    ///
+    /// Uses the `Xoshiro256PlusPlus` RNG internally. For use of another RNG, consider using
+    /// [`SAGA::new_with_rng`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::variancereduction::SAGA;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let saga: SAGA<Vec<f64>, f64, _> = SAGA::new(0.01)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(step_size: F) -> Result<Self, Error> {
+        SAGA::new_with_rng(step_size, Xoshiro256PlusPlus::from_entropy())
+    }
+}
+
+impl<P, F, R> SAGA<P, F, R>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`SAGA`]
+    ///
+    /// Takes the step size, which must be larger than 0.
+    /// Requires a RNG which must implement `rand::Rng` (and `serde::Serialize` if the `serde1`
+    /// feature is enabled).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::variancereduction::SAGA;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let my_rng = ();
+    /// let saga: SAGA<Vec<f64>, f64, _> = SAGA::new_with_rng(0.01, my_rng)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_with_rng(step_size: F, rng: R) -> Result<Self, Error> {
+        if step_size <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`SAGA`: Step size must be > 0."
+            ));
+        }
+        Ok(SAGA {
+            step_size,
+            memory: vec![],
+            sum: None,
+            rng,
+        })
+    }
+}
+
+impl<O, P, F, R> Solver<O, IterState<P, (), (), (), F>> for SAGA<P, F, R>
+where
+    O: FiniteSumGradient<Param = P, Gradient = P>,
+    P: Clone
+        + SerializeAlias
+        + ArgminAdd<P, P>
+        + ArgminSub<P, P>
+        + ArgminMul<F, P>
+        + ArgminScaledSub<P, F, P>,
+    F: ArgminFloat,
+    R: Rng + SerializeAlias,
+{
+    const NAME: &'static str = "SAGA";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        let param = state.get_param().ok_or_else(argmin_error_closure!(
+            NotInitialized,
+            concat!(
+                "`SAGA` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method."
+            )
+        ))?;
+        let num_samples = problem.num_samples()?;
+        if num_samples == 0 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`SAGA`: Problem must have at least one sample."
+            ));
+        }
+        self.memory = (0..num_samples)
+            .map(|idx| problem.sample_gradient(param, idx))
+            .collect::<Result<Vec<_>, _>>()?;
+        let sum = self.memory[1..]
+            .iter()
+            .fold(self.memory[0].clone(), |acc, grad| acc.add(grad));
+        self.sum = Some(sum);
+        Ok((state, None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        let mut param = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`SAGA`: Parameter vector in state not set."
+        ))?;
+        let mut sum = self.sum.take().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`SAGA`: Sum of gradients not initialized."
+        ))?;
+        let num_samples = self.memory.len();
+
+        let inv_num_samples = float!(1.0) / F::from_usize(num_samples).unwrap();
+
+        for _ in 0..num_samples {
+            let idx = self.rng.gen_range(0..num_samples);
+            let grad = problem.sample_gradient(&param, idx)?;
+            let diff = grad.sub(&self.memory[idx]);
+            param = param.scaled_sub(&self.step_size, &diff.add(&sum.mul(&inv_num_samples)));
+            sum = sum.add(&diff);
+            self.memory[idx] = grad;
+        }
+
+        self.sum = Some(sum);
+        Ok((state.param(param), None))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Executor};
+    use crate::solver::variancereduction::test_utils::LeastSquares;
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(saga, SAGA<Vec<f64>, f64, StdRng>);
+
+    #[test]
+    fn test_new() {
+        let SAGA {
+            step_size,
+            memory,
+            sum,
+            rng: _,
+        }: SAGA<Vec<f64>, f64, _> = SAGA::new(0.1).unwrap();
+        assert_eq!(step_size.to_ne_bytes(), 0.1f64.to_ne_bytes());
+        assert!(memory.is_empty());
+        assert!(sum.is_none());
+
+        for step_size in [0.0, -1.0] {
+            let res: Result<SAGA<Vec<f64>, f64, _>, _> = SAGA::new(step_size);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`SAGA`: Step size must be > 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_not_initialized() {
+        let saga: SAGA<Vec<f64>, f64, _> = SAGA::new(0.1).unwrap();
+        let res = Executor::new(LeastSquares::new(), saga).run();
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`SAGA` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_solver() {
+        let saga = SAGA::new_with_rng(0.1, Xoshiro256PlusPlus::seed_from_u64(42)).unwrap();
+        let res = Executor::new(LeastSquares::new(), saga)
+            .configure(|state| state.param(vec![0.0, 0.0]).max_iters(500))
+            .run()
+            .unwrap();
+        let param = res.state.get_param().unwrap();
+        assert_relative_eq!(param[0], 1.0, epsilon = 1e-6);
+        assert_relative_eq!(param[1], -2.0, epsilon = 1e-6);
+        // Initialization requires one additional pass over all samples
+        assert_eq!(res.problem.counts["sample_gradient_count"], 501 * 6);
+    }
+}
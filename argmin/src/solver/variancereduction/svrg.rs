@@ -0,0 +1,378 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+use super::FiniteSumGradient;
+use crate::core::{ArgminFloat, Error, IterState, Problem, SerializeAlias, Solver, State, KV};
+use argmin_math::{ArgminAdd, ArgminMul, ArgminScaledSub, ArgminSub};
+use rand::prelude::*;
+use rand_xoshiro::Xoshiro256PlusPlus;
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Stochastic variance reduced gradient (SVRG)
+///
+/// Variance-reduced stochastic gradient method for finite-sum problems (see
+/// [`FiniteSumGradient`]). Instead of memorizing the gradients of all summands like
+/// [`SAG`](`super::SAG`) and [`SAGA`](`super::SAGA`), SVRG periodically stores a snapshot `x_s`
+/// of the parameter vector together with the full gradient `μ = ∇f(x_s)`. In each step of the
+/// inner loop, a summand `i` is chosen uniformly at random and the parameter vector is updated
+/// according to
+///
+/// `x_{k+1} = x_k - step_size * (∇f_i(x_k) - ∇f_i(x_s) + μ)`.
+///
+/// One iteration of the solver corresponds to one run of the inner loop, whose length defaults
+/// to `2n` steps, where `n` is the number of summands (see
+/// [`with_inner_iters`](`SVRG::with_inner_iters`)). By default, a new snapshot is taken at the
+/// start of every iteration, which requires one pass over all summands. The snapshot can be
+/// taken less frequently (see [`with_snapshot_frequency`](`SVRG::with_snapshot_frequency`)).
+/// SVRG requires no memory proportional to the number of summands, at the cost of two gradient
+/// evaluations per step. A suitable step size is `1 / (10 L)`, where `L` is the largest
+/// Lipschitz constant of the gradients of the summands.
+///
+/// The initial parameter vector needs to be provided via the `configure` method of the
+/// [`Executor`](`crate::core::Executor`).
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`FiniteSumGradient`].
+///
+/// ## Reference
+///
+/// Rie Johnson and Tong Zhang (2013). Accelerating stochastic gradient descent using predictive
+/// variance reduction. NIPS 2013.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct SVRG<P, F, R> {
+    /// Step size
+    step_size: F,
+    /// Length of the inner loop (defaults to twice the number of summands)
+    inner_iters: Option<usize>,
+    /// Number of iterations after which a new snapshot is taken
+    snapshot_frequency: u64,
+    /// Number of summands
+    num_samples: usize,
+    /// Snapshot of the parameter vector
+    snapshot: Option<P>,
+    /// Full gradient at the snapshot
+    full_gradient: Option<P>,
+    /// Random number generator
+    rng: R,
+}
+
+impl<P, F> SVRG<P, F, Xoshiro256PlusPlus>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`SVRG`]
+    ///
+    /// Takes the step size, which must be larger than 0.
+    ///
+    /// Uses the `Xoshiro256PlusPlus` RNG internally. For use of another RNG, consider using
+    /// [`SVRG::new_with_rng`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::variancereduction::SVRG;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let svrg: SVRG<Vec<f64>, f64, _> = SVRG::new(0.01)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(step_size: F) -> Result<Self, Error> {
+        SVRG::new_with_rng(step_size, Xoshiro256PlusPlus::from_entropy())
+    }
+}
+
+impl<P, F, R> SVRG<P, F, R>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`SVRG`]
+    ///
+    /// Takes the step size, which must be larger than 0.
+    /// Requires a RNG which must implement `rand::Rng` (and `serde::Serialize` if the `serde1`
+    /// feature is enabled).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::variancereduction::SVRG;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// # let my_rng = ();
+    /// let svrg: SVRG<Vec<f64>, f64, _> = SVRG::new_with_rng(0.01, my_rng)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new_with_rng(step_size: F, rng: R) -> Result<Self, Error> {
+        if step_size <= float!(0.0) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`SVRG`: Step size must be > 0."
+            ));
+        }
+        Ok(SVRG {
+            step_size,
+            inner_iters: None,
+            snapshot_frequency: 1,
+            num_samples: 0,
+            snapshot: None,
+            full_gradient: None,
+            rng,
+        })
+    }
+
+    /// Set the length of the inner loop.
+    ///
+    /// Must be larger than 0 and defaults to twice the number of summands.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::variancereduction::SVRG;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let svrg: SVRG<Vec<f64>, f64, _> = SVRG::new(0.01)?.with_inner_iters(100)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_inner_iters(mut self, inner_iters: usize) -> Result<Self, Error> {
+        if inner_iters == 0 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`SVRG`: Length of inner loop must be > 0."
+            ));
+        }
+        self.inner_iters = Some(inner_iters);
+        Ok(self)
+    }
+
+    /// Set the number of iterations (runs of the inner loop) after which a new snapshot is taken.
+    ///
+    /// Must be larger than 0 and defaults to 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::variancereduction::SVRG;
+    /// # use argmin::core::Error;
+    /// # fn main() -> Result<(), Error> {
+    /// let svrg: SVRG<Vec<f64>, f64, _> = SVRG::new(0.01)?.with_snapshot_frequency(2)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_snapshot_frequency(mut self, snapshot_frequency: u64) -> Result<Self, Error> {
+        if snapshot_frequency == 0 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`SVRG`: Snapshot frequency must be > 0."
+            ));
+        }
+        self.snapshot_frequency = snapshot_frequency;
+        Ok(self)
+    }
+}
+
+impl<O, P, F, R> Solver<O, IterState<P, (), (), (), F>> for SVRG<P, F, R>
+where
+    O: FiniteSumGradient<Param = P, Gradient = P>,
+    P: Clone
+        + SerializeAlias
+        + ArgminAdd<P, P>
+        + ArgminSub<P, P>
+        + ArgminMul<F, P>
+        + ArgminScaledSub<P, F, P>,
+    F: ArgminFloat,
+    R: Rng + SerializeAlias,
+{
+    const NAME: &'static str = "SVRG";
+
+    fn init(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        if state.get_param().is_none() {
+            return Err(argmin_error!(
+                NotInitialized,
+                concat!(
+                    "`SVRG` requires an initial parameter vector. ",
+                    "Please provide an initial guess via `Executor`s `configure` method."
+                )
+            ));
+        }
+        self.num_samples = problem.num_samples()?;
+        if self.num_samples == 0 {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`SVRG`: Problem must have at least one sample."
+            ));
+        }
+        self.snapshot = None;
+        self.full_gradient = None;
+        Ok((state, None))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        mut state: IterState<P, (), (), (), F>,
+    ) -> Result<(IterState<P, (), (), (), F>, Option<KV>), Error> {
+        let mut param = state.take_param().ok_or_else(argmin_error_closure!(
+            PotentialBug,
+            "`SVRG`: Parameter vector in state not set."
+        ))?;
+        let num_samples = self.num_samples;
+
+        let new_snapshot =
+            self.snapshot.is_none() || state.get_iter().is_multiple_of(self.snapshot_frequency);
+        if new_snapshot {
+            let sum = (1..num_samples).try_fold(
+                problem.sample_gradient(&param, 0)?,
+                |acc, idx| -> Result<P, Error> {
+                    Ok(acc.add(&problem.sample_gradient(&param, idx)?))
+                },
+            )?;
+            self.full_gradient =
+                Some(sum.mul(&(float!(1.0) / F::from_usize(num_samples).unwrap())));
+            self.snapshot = Some(param.clone());
+        }
+        let snapshot = self.snapshot.as_ref().unwrap();
+        let full_gradient = self.full_gradient.as_ref().unwrap();
+
+        for _ in 0..self.inner_iters.unwrap_or(2 * num_samples) {
+            let idx = self.rng.gen_range(0..num_samples);
+            let grad = problem.sample_gradient(&param, idx)?;
+            let grad_snapshot = problem.sample_gradient(snapshot, idx)?;
+            let direction = grad.sub(&grad_snapshot).add(full_gradient);
+            param = param.scaled_sub(&self.step_size, &direction);
+        }
+
+        Ok((
+            state.param(param),
+            Some(kv!("new_snapshot" => new_snapshot;)),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{ArgminError, Executor};
+    use crate::solver::variancereduction::test_utils::LeastSquares;
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    test_trait_impl!(svrg, SVRG<Vec<f64>, f64, StdRng>);
+
+    #[test]
+    fn test_new() {
+        let SVRG {
+            step_size,
+            inner_iters,
+            snapshot_frequency,
+            num_samples,
+            snapshot,
+            full_gradient,
+            rng: _,
+        }: SVRG<Vec<f64>, f64, _> = SVRG::new(0.1).unwrap();
+        assert_eq!(step_size.to_ne_bytes(), 0.1f64.to_ne_bytes());
+        assert!(inner_iters.is_none());
+        assert_eq!(snapshot_frequency, 1);
+        assert_eq!(num_samples, 0);
+        assert!(snapshot.is_none());
+        assert!(full_gradient.is_none());
+
+        for step_size in [0.0, -1.0] {
+            let res: Result<SVRG<Vec<f64>, f64, _>, _> = SVRG::new(step_size);
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`SVRG`: Step size must be > 0.\""
+            );
+        }
+    }
+
+    #[test]
+    fn test_builders() {
+        let svrg: SVRG<Vec<f64>, f64, _> = SVRG::new(0.1)
+            .unwrap()
+            .with_inner_iters(20)
+            .unwrap()
+            .with_snapshot_frequency(3)
+            .unwrap();
+        assert_eq!(svrg.inner_iters, Some(20));
+        assert_eq!(svrg.snapshot_frequency, 3);
+
+        let res: Result<SVRG<Vec<f64>, f64, _>, _> = SVRG::new(0.1).unwrap().with_inner_iters(0);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`SVRG`: Length of inner loop must be > 0.\""
+        );
+
+        let res: Result<SVRG<Vec<f64>, f64, _>, _> =
+            SVRG::new(0.1).unwrap().with_snapshot_frequency(0);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`SVRG`: Snapshot frequency must be > 0.\""
+        );
+    }
+
+    #[test]
+    fn test_not_initialized() {
+        let svrg: SVRG<Vec<f64>, f64, _> = SVRG::new(0.1).unwrap();
+        let res = Executor::new(LeastSquares::new(), svrg).run();
+        assert_error!(
+            res,
+            ArgminError,
+            concat!(
+                "Not initialized: \"`SVRG` requires an initial parameter vector. ",
+                "Please provide an initial guess via `Executor`s `configure` method.\""
+            )
+        );
+    }
+
+    #[test]
+    fn test_solver() {
+        let svrg = SVRG::new_with_rng(0.1, Xoshiro256PlusPlus::seed_from_u64(42)).unwrap();
+        let res = Executor::new(LeastSquares::new(), svrg)
+            .configure(|state| state.param(vec![0.0, 0.0]).max_iters(200))
+            .run()
+            .unwrap();
+        let param = res.state.get_param().unwrap();
+        assert_relative_eq!(param[0], 1.0, epsilon = 1e-6);
+        assert_relative_eq!(param[1], -2.0, epsilon = 1e-6);
+        // One pass for the snapshot and two gradient evaluations per inner step
+        assert_eq!(
+            res.problem.counts["sample_gradient_count"],
+            200 * (6 + 2 * 12)
+        );
+    }
+
+    #[test]
+    fn test_snapshot_frequency() {
+        let svrg = SVRG::new_with_rng(0.1, Xoshiro256PlusPlus::seed_from_u64(42))
+            .unwrap()
+            .with_inner_iters(10)
+            .unwrap()
+            .with_snapshot_frequency(4)
+            .unwrap();
+        let res = Executor::new(LeastSquares::new(), svrg)
+            .configure(|state| state.param(vec![0.0, 0.0]).max_iters(8))
+            .run()
+            .unwrap();
+        // Snapshots in iterations 0 and 4
+        assert_eq!(
+            res.problem.counts["sample_gradient_count"],
+            2 * 6 + 8 * 2 * 10
+        );
+    }
+}
@@ -0,0 +1,473 @@
+// Copyright 2018-2022 argmin developers
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://apache.org/licenses/LICENSE-2.0> or the MIT license <LICENSE-MIT or
+// http://opensource.org/licenses/MIT>, at your option. This file may not be
+// copied, modified, or distributed except according to those terms.
+
+//! # Weighted-sum scalarization
+//!
+//! Turns a multi-objective problem into a series of single-objective problems by minimizing
+//! weighted sums of the objectives. See [`WeightedSumProblem`] and [`WeightedSum`] for details.
+//!
+//! ## Reference
+//!
+//! Kaisa Miettinen (1999). Nonlinear Multiobjective Optimization.
+//! Springer. ISBN 0-7923-8278-1.
+
+use crate::core::{
+    ArgminFloat, CostFunction, DeserializeOwnedAlias, Error, Executor, Gradient, IterState,
+    Jacobian, MultiCostFunction, OptimizationResult, ParetoState, Problem, SerializeAlias, Solver,
+    State, TerminationReason, TerminationStatus, KV,
+};
+use crate::solver::nsga2::non_dominated_sort;
+use argmin_math::{ArgminMul, ArgminScaledAdd};
+#[cfg(feature = "serde1")]
+use serde::{Deserialize, Serialize};
+
+/// # Weighted-sum problem
+///
+/// Wraps a multi-objective problem with objectives `f_i(x)` and turns it into the
+/// single-objective problem
+///
+/// `min_x sum_i w_i * f_i(x)`
+///
+/// for given weights `w_i`. This is the problem the inner solver of [`WeightedSum`] operates on,
+/// but it can also be used on its own with any single-objective solver.
+///
+/// [`CostFunction`] is implemented if the wrapped problem implements [`MultiCostFunction`].
+/// [`Gradient`] is implemented if the wrapped problem implements [`Jacobian`], where the Jacobian
+/// is given as a vector holding the gradient of each objective.
+#[derive(Clone, Debug)]
+pub struct WeightedSumProblem<O, F> {
+    /// Wrapped problem
+    problem: O,
+    /// Weights of the objectives
+    weights: Vec<F>,
+}
+
+impl<O, F> WeightedSumProblem<O, F> {
+    /// Construct a new instance of [`WeightedSumProblem`] from a problem and a weight vector
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::weightedsum::WeightedSumProblem;
+    /// # struct UserDefinedProblem {};
+    /// let problem = WeightedSumProblem::new(UserDefinedProblem {}, vec![0.3f64, 0.7]);
+    /// ```
+    pub fn new(problem: O, weights: Vec<F>) -> Self {
+        WeightedSumProblem { problem, weights }
+    }
+
+    /// Return the wrapped problem
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::weightedsum::WeightedSumProblem;
+    /// # struct UserDefinedProblem {};
+    /// # let problem = WeightedSumProblem::new(UserDefinedProblem {}, vec![0.3f64, 0.7]);
+    /// let inner: UserDefinedProblem = problem.into_inner();
+    /// ```
+    pub fn into_inner(self) -> O {
+        self.problem
+    }
+
+    /// Check that the number of objectives matches the number of weights
+    fn check_len(&self, num_objectives: usize) -> Result<(), Error> {
+        if num_objectives != self.weights.len() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                format!(
+                    "`WeightedSumProblem`: Number of objectives ({}) does not match number of weights ({})",
+                    num_objectives,
+                    self.weights.len()
+                )
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl<O, P, F> CostFunction for WeightedSumProblem<O, F>
+where
+    O: MultiCostFunction<Param = P, Float = F>,
+    F: ArgminFloat,
+{
+    type Param = P;
+    type Output = F;
+
+    fn cost(&self, param: &Self::Param) -> Result<Self::Output, Error> {
+        let costs = self.problem.multi_cost(param)?;
+        self.check_len(costs.len())?;
+        Ok(costs
+            .iter()
+            .zip(self.weights.iter())
+            .fold(float!(0.0), |acc, (c, w)| acc + *w * *c))
+    }
+}
+
+impl<O, P, G, F> Gradient for WeightedSumProblem<O, F>
+where
+    O: Jacobian<Param = P, Jacobian = Vec<G>>,
+    G: ArgminMul<F, G> + ArgminScaledAdd<G, F, G>,
+    F: ArgminFloat,
+{
+    type Param = P;
+    type Gradient = G;
+
+    fn gradient(&self, param: &Self::Param) -> Result<Self::Gradient, Error> {
+        let jacobian = self.problem.jacobian(param)?;
+        self.check_len(jacobian.len())?;
+        let mut terms = jacobian.iter().zip(self.weights.iter());
+        let (grad, weight) = terms.next().ok_or_else(argmin_error_closure!(
+            InvalidParameter,
+            "`WeightedSumProblem`: At least one objective is required."
+        ))?;
+        Ok(terms.fold(grad.mul(weight), |acc, (g, w)| acc.scaled_add(w, g)))
+    }
+}
+
+/// # Weighted-sum method
+///
+/// Meta-solver which approximates the Pareto front of a multi-objective problem by minimizing
+/// the weighted sum of the objectives (see [`WeightedSumProblem`]) for a number of weight
+/// vectors, using any single-objective solver.
+///
+/// In each iteration, the subproblem for the next weight vector is solved by running the inner
+/// solver to completion, starting from a copy of the provided initial state of the inner solver.
+/// This state is used to provide the initial parameter vector and the maximum number of
+/// iterations of the inner solver. The solution of each subproblem is stored in the population
+/// of the [`ParetoState`], its non-dominated subset forms the Pareto front. The solver terminates
+/// once all weight vectors have been processed.
+///
+/// Evenly spread weight vectors can be created with
+/// [`simplex_lattice_weights`](`crate::solver::moead::simplex_lattice_weights`). Note that only
+/// the convex parts of the Pareto front can be found by minimizing weighted sums.
+///
+/// ## Requirements on the optimization problem
+///
+/// The optimization problem is required to implement [`MultiCostFunction`] as well as all traits
+/// the inner solver requires from the [`WeightedSumProblem`].
+///
+/// ## Reference
+///
+/// Kaisa Miettinen (1999). Nonlinear Multiobjective Optimization.
+/// Springer. ISBN 0-7923-8278-1.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct WeightedSum<S, I, F> {
+    /// Inner solver
+    solver: S,
+    /// Weight vectors
+    weights: Vec<Vec<F>>,
+    /// Initial state of the inner solver
+    init_state: I,
+}
+
+impl<S, I, F> WeightedSum<S, I, F>
+where
+    F: ArgminFloat,
+{
+    /// Construct a new instance of [`WeightedSum`]
+    ///
+    /// Takes the inner solver, the weight vectors and the initial state of the inner solver. All
+    /// weight vectors must be of the same length (the number of objectives) and must not contain
+    /// negative weights.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use argmin::solver::weightedsum::WeightedSum;
+    /// # use argmin::solver::neldermead::NelderMead;
+    /// # use argmin::core::{Error, IterState, State};
+    /// # fn main() -> Result<(), Error> {
+    /// let inner: NelderMead<Vec<f64>, f64> = NelderMead::new(vec![vec![0.0], vec![1.0]]);
+    /// let weights = vec![vec![1.0, 0.0], vec![0.5, 0.5], vec![0.0, 1.0]];
+    /// let init_state: IterState<Vec<f64>, (), (), (), f64> = IterState::new().max_iters(100);
+    /// let solver = WeightedSum::new(inner, weights, init_state)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(solver: S, weights: Vec<Vec<F>>, init_state: I) -> Result<Self, Error> {
+        if weights.is_empty() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`WeightedSum`: At least one weight vector is required."
+            ));
+        }
+        let num_objectives = weights[0].len();
+        if num_objectives == 0 || weights.iter().any(|w| w.len() != num_objectives) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`WeightedSum`: Weight vectors must be non-empty and of equal length."
+            ));
+        }
+        if weights.iter().flatten().any(|w| *w < float!(0.0)) {
+            return Err(argmin_error!(
+                InvalidParameter,
+                "`WeightedSum`: Weights must be >= 0."
+            ));
+        }
+        Ok(WeightedSum {
+            solver,
+            weights,
+            init_state,
+        })
+    }
+}
+
+impl<O, S, P, G, J, H, F> Solver<O, ParetoState<P, F>>
+    for WeightedSum<S, IterState<P, G, J, H, F>, F>
+where
+    O: MultiCostFunction<Param = P, Float = F>,
+    S: Clone + Solver<WeightedSumProblem<O, F>, IterState<P, G, J, H, F>>,
+    P: Clone + SerializeAlias + DeserializeOwnedAlias,
+    G: Clone + SerializeAlias + DeserializeOwnedAlias,
+    J: Clone + SerializeAlias + DeserializeOwnedAlias,
+    H: Clone + SerializeAlias + DeserializeOwnedAlias,
+    F: ArgminFloat,
+{
+    const NAME: &'static str = "Weighted-sum method";
+
+    fn init(
+        &mut self,
+        _problem: &mut Problem<O>,
+        state: ParetoState<P, F>,
+    ) -> Result<(ParetoState<P, F>, Option<KV>), Error> {
+        Ok((
+            state
+                .population(vec![], vec![])
+                .pareto_front(vec![], vec![]),
+            None,
+        ))
+    }
+
+    fn next_iter(
+        &mut self,
+        problem: &mut Problem<O>,
+        state: ParetoState<P, F>,
+    ) -> Result<(ParetoState<P, F>, Option<KV>), Error> {
+        let weights = self
+            .weights
+            .get(state.get_iter() as usize)
+            .ok_or_else(argmin_error_closure!(
+                PotentialBug,
+                "`WeightedSum`: No weight vector left."
+            ))?
+            .clone();
+        let init_state = self.init_state.clone();
+
+        let OptimizationResult {
+            problem: mut inner_problem,
+            state: mut inner_state,
+            ..
+        } = Executor::new(
+            WeightedSumProblem::new(
+                problem.take_problem().ok_or_else(argmin_error_closure!(
+                    PotentialBug,
+                    "`WeightedSum`: Failed to take `problem` for inner solver"
+                ))?,
+                weights,
+            ),
+            self.solver.clone(),
+        )
+        .configure(|_| init_state)
+        .ctrlc(false)
+        .run()?;
+
+        // Get back problem and function evaluation counts
+        problem.problem = inner_problem
+            .take_problem()
+            .map(WeightedSumProblem::into_inner);
+        problem.consume_func_counts(inner_problem);
+
+        let weighted_cost = inner_state.get_best_cost();
+        let param = inner_state
+            .take_best_param()
+            .ok_or_else(argmin_error_closure!(
+                PotentialBug,
+                "`WeightedSum`: No `param` returned by inner solver"
+            ))?;
+        let costs = problem.multi_cost(&param)?;
+        if costs.len() != self.weights[0].len() {
+            return Err(argmin_error!(
+                InvalidParameter,
+                format!(
+                    "`WeightedSum`: Number of objectives ({}) does not match length of weight vectors ({})",
+                    costs.len(),
+                    self.weights[0].len()
+                )
+            ));
+        }
+
+        let state = push_solution(state, param, costs);
+        let front_size = state.get_pareto_front().map(Vec::len).unwrap_or(0);
+
+        let kv = kv!(
+            "weighted_cost" => weighted_cost;
+            "inner_iters" => inner_state.get_iter();
+            "pareto_front_size" => front_size as u64;
+        );
+
+        Ok((state, Some(kv)))
+    }
+
+    fn terminate(&mut self, state: &ParetoState<P, F>) -> TerminationStatus {
+        if state.get_iter() >= self.weights.len() as u64 {
+            return TerminationStatus::Terminated(TerminationReason::SolverConverged);
+        }
+        TerminationStatus::NotTerminated
+    }
+}
+
+/// Add a solution to the population and recompute the Pareto front as its non-dominated subset.
+pub(crate) fn push_solution<P, F>(
+    mut state: ParetoState<P, F>,
+    param: P,
+    costs: Vec<F>,
+) -> ParetoState<P, F>
+where
+    P: Clone,
+    F: ArgminFloat,
+{
+    let mut population = state.take_population().unwrap_or_default();
+    let mut population_costs = state.take_population_costs().unwrap_or_default();
+    population.push(param);
+    population_costs.push(costs);
+
+    let front_idx = non_dominated_sort(&population_costs).swap_remove(0);
+    let front: Vec<P> = front_idx.iter().map(|&i| population[i].clone()).collect();
+    let front_costs: Vec<Vec<F>> = front_idx
+        .iter()
+        .map(|&i| population_costs[i].clone())
+        .collect();
+
+    state
+        .population(population, population_costs)
+        .pareto_front(front, front_costs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::ArgminError;
+    use crate::solver::linesearch::MoreThuenteLineSearch;
+    use crate::solver::quasinewton::LBFGS;
+    use crate::test_trait_impl;
+    use approx::assert_relative_eq;
+
+    type Inner = LBFGS<MoreThuenteLineSearch<Vec<f64>, Vec<f64>, f64>, Vec<f64>, Vec<f64>, f64>;
+
+    test_trait_impl!(
+        weighted_sum,
+        WeightedSum<Inner, IterState<Vec<f64>, Vec<f64>, (), (), f64>, f64>
+    );
+
+    /// f_1(x) = x^2, f_2(x) = (x - 2)^2
+    struct Schaffer {}
+
+    impl MultiCostFunction for Schaffer {
+        type Param = Vec<f64>;
+        type Float = f64;
+
+        fn multi_cost(&self, p: &Self::Param) -> Result<Vec<Self::Float>, Error> {
+            Ok(vec![p[0].powi(2), (p[0] - 2.0).powi(2)])
+        }
+    }
+
+    impl Jacobian for Schaffer {
+        type Param = Vec<f64>;
+        type Jacobian = Vec<Vec<f64>>;
+
+        fn jacobian(&self, p: &Self::Param) -> Result<Self::Jacobian, Error> {
+            Ok(vec![vec![2.0 * p[0]], vec![2.0 * (p[0] - 2.0)]])
+        }
+    }
+
+    #[test]
+    fn test_problem() {
+        let problem = WeightedSumProblem::new(Schaffer {}, vec![0.25, 0.75]);
+        assert_relative_eq!(
+            problem.cost(&vec![1.0]).unwrap(),
+            1.0,
+            epsilon = f64::EPSILON
+        );
+        assert_relative_eq!(
+            problem.gradient(&vec![1.0]).unwrap()[0],
+            -1.0,
+            epsilon = f64::EPSILON
+        );
+
+        let res = WeightedSumProblem::new(Schaffer {}, vec![1.0]).cost(&vec![1.0]);
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`WeightedSumProblem`: Number of objectives (2) does not match number of weights (1)\""
+        );
+    }
+
+    #[test]
+    fn test_new() {
+        let inner: Inner = LBFGS::new(MoreThuenteLineSearch::new(), 5);
+        let init_state: IterState<Vec<f64>, Vec<f64>, (), (), f64> = IterState::new();
+
+        let res = WeightedSum::new(inner.clone(), Vec::<Vec<f64>>::new(), init_state.clone());
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`WeightedSum`: At least one weight vector is required.\""
+        );
+
+        for weights in [vec![vec![]], vec![vec![1.0, 0.0], vec![1.0]]] {
+            let res = WeightedSum::new(inner.clone(), weights, init_state.clone());
+            assert_error!(
+                res,
+                ArgminError,
+                "Invalid parameter: \"`WeightedSum`: Weight vectors must be non-empty and of equal length.\""
+            );
+        }
+
+        let res = WeightedSum::new(
+            inner.clone(),
+            vec![vec![1.0, 0.0], vec![-0.5, 1.5]],
+            init_state.clone(),
+        );
+        assert_error!(
+            res,
+            ArgminError,
+            "Invalid parameter: \"`WeightedSum`: Weights must be >= 0.\""
+        );
+
+        let solver = WeightedSum::new(inner, vec![vec![1.0, 0.0]], init_state).unwrap();
+        assert_eq!(solver.weights.len(), 1);
+    }
+
+    #[test]
+    fn test_solver() {
+        let inner: Inner = LBFGS::new(MoreThuenteLineSearch::new(), 5);
+        let weights = vec![
+            vec![1.0, 0.0],
+            vec![0.75, 0.25],
+            vec![0.5, 0.5],
+            vec![0.0, 1.0],
+        ];
+        let init_state = IterState::new().param(vec![5.0]).max_iters(100);
+        let solver = WeightedSum::new(inner, weights, init_state).unwrap();
+        let res = Executor::new(Schaffer {}, solver).run().unwrap();
+
+        assert_eq!(res.state.get_iter(), 4);
+        assert_eq!(
+            res.state.get_termination_reason(),
+            Some(&TerminationReason::SolverConverged)
+        );
+        let population = res.state.get_population().unwrap();
+        for (x, expected) in population.iter().zip([0.0, 0.5, 1.0, 2.0]) {
+            assert_relative_eq!(x[0], expected, epsilon = 1e-6);
+        }
+        // All solutions are Pareto optimal
+        assert_eq!(res.state.get_pareto_front().unwrap().len(), 4);
+        assert_eq!(res.problem.counts["multi_cost_count"], 4);
+    }
+}